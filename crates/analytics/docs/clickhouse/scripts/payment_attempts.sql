@@ -38,6 +38,26 @@ CREATE TABLE payment_attempts_queue (
     `unified_code`  Nullable(String),
     `unified_message`  Nullable(String),
     `mandate_data`  Nullable(String),
+    `routing_algorithm_id` Nullable(String),
+    `authentication_connector` LowCardinality(Nullable(String)),
+    `authentication_id` Nullable(String),
+    `external_three_ds_authentication_attempted` Nullable(Bool),
+    `three_ds_outcome` LowCardinality(Nullable(String)),
+    `retry_index` Nullable(Int16),
+    `card_network` LowCardinality(Nullable(String)),
+    `card_type` LowCardinality(Nullable(String)),
+    `card_issuing_country` LowCardinality(Nullable(String)),
+    `three_ds_decision` LowCardinality(Nullable(String)),
+    `sca_exemption` LowCardinality(Nullable(String)),
+    `card_fingerprint` Nullable(String),
+    `payment_method_selection` LowCardinality(Nullable(String)),
+    `settlement_currency` LowCardinality(Nullable(String)),
+    `settlement_amount` Nullable(UInt32),
+    `fx_rate` Nullable(String),
+    `avs_result_code` LowCardinality(Nullable(String)),
+    `avs_result` LowCardinality(Nullable(String)),
+    `cvv_result_code` LowCardinality(Nullable(String)),
+    `cvv_result` LowCardinality(Nullable(String)),
     `sign_flag` Int8
 ) ENGINE = Kafka SETTINGS kafka_broker_list = 'kafka0:29092',
 kafka_topic_list = 'hyperswitch-payment-attempt-events',
@@ -85,13 +105,34 @@ CREATE TABLE payment_attempt_dist (
     `unified_code`  Nullable(String),
     `unified_message`  Nullable(String),
     `mandate_data`  Nullable(String),
+    `routing_algorithm_id` Nullable(String),
+    `authentication_connector` LowCardinality(Nullable(String)),
+    `authentication_id` Nullable(String),
+    `external_three_ds_authentication_attempted` Nullable(Bool),
+    `three_ds_outcome` LowCardinality(Nullable(String)),
+    `retry_index` Nullable(Int16),
+    `card_network` LowCardinality(Nullable(String)),
+    `card_type` LowCardinality(Nullable(String)),
+    `card_issuing_country` LowCardinality(Nullable(String)),
+    `three_ds_decision` LowCardinality(Nullable(String)),
+    `sca_exemption` LowCardinality(Nullable(String)),
+    `card_fingerprint` Nullable(String),
+    `payment_method_selection` LowCardinality(Nullable(String)),
+    `settlement_currency` LowCardinality(Nullable(String)),
+    `settlement_amount` Nullable(UInt32),
+    `fx_rate` Nullable(String),
+    `avs_result_code` LowCardinality(Nullable(String)),
+    `avs_result` LowCardinality(Nullable(String)),
+    `cvv_result_code` LowCardinality(Nullable(String)),
+    `cvv_result` LowCardinality(Nullable(String)),
     `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `sign_flag` Int8,
     INDEX connectorIndex connector TYPE bloom_filter GRANULARITY 1,
     INDEX paymentMethodIndex payment_method TYPE bloom_filter GRANULARITY 1,
     INDEX authenticationTypeIndex authentication_type TYPE bloom_filter GRANULARITY 1,
     INDEX currencyIndex currency TYPE bloom_filter GRANULARITY 1,
-    INDEX statusIndex status TYPE bloom_filter GRANULARITY 1
+    INDEX statusIndex status TYPE bloom_filter GRANULARITY 1,
+    INDEX authenticationConnectorIndex authentication_connector TYPE bloom_filter GRANULARITY 1
 ) ENGINE = CollapsingMergeTree(
     sign_flag
 )
@@ -142,6 +183,26 @@ CREATE MATERIALIZED VIEW kafka_parse_pa TO payment_attempt_dist (
     `unified_code`  Nullable(String),
     `unified_message`  Nullable(String),
     `mandate_data`  Nullable(String),
+    `routing_algorithm_id` Nullable(String),
+    `authentication_connector` LowCardinality(Nullable(String)),
+    `authentication_id` Nullable(String),
+    `external_three_ds_authentication_attempted` Nullable(Bool),
+    `three_ds_outcome` LowCardinality(Nullable(String)),
+    `retry_index` Nullable(Int16),
+    `card_network` LowCardinality(Nullable(String)),
+    `card_type` LowCardinality(Nullable(String)),
+    `card_issuing_country` LowCardinality(Nullable(String)),
+    `three_ds_decision` LowCardinality(Nullable(String)),
+    `sca_exemption` LowCardinality(Nullable(String)),
+    `card_fingerprint` Nullable(String),
+    `payment_method_selection` LowCardinality(Nullable(String)),
+    `settlement_currency` LowCardinality(Nullable(String)),
+    `settlement_amount` Nullable(UInt32),
+    `fx_rate` Nullable(String),
+    `avs_result_code` LowCardinality(Nullable(String)),
+    `avs_result` LowCardinality(Nullable(String)),
+    `cvv_result_code` LowCardinality(Nullable(String)),
+    `cvv_result` LowCardinality(Nullable(String)),
     `inserted_at` DateTime64(3),
     `sign_flag` Int8
 ) AS
@@ -185,6 +246,26 @@ SELECT
     unified_code,
     unified_message,
     mandate_data,
+    routing_algorithm_id,
+    authentication_connector,
+    authentication_id,
+    external_three_ds_authentication_attempted,
+    three_ds_outcome,
+    retry_index,
+    card_network,
+    card_type,
+    card_issuing_country,
+    three_ds_decision,
+    sca_exemption,
+    card_fingerprint,
+    payment_method_selection,
+    settlement_currency,
+    settlement_amount,
+    fx_rate,
+    avs_result_code,
+    avs_result,
+    cvv_result_code,
+    cvv_result,
     now() as inserted_at,
     sign_flag
 FROM
@@ -15,6 +15,7 @@ CREATE TABLE payment_intents_queue (
     `off_session` Nullable(Bool),
     `client_secret` Nullable(String),
     `active_attempt_id` String,
+    `attempt_count` Int16,
     `business_country` String,
     `business_label` String,
     `modified_at` DateTime CODEC(T64, LZ4),
@@ -45,6 +46,7 @@ CREATE TABLE payment_intents_dist (
     `off_session` Nullable(Bool),
     `client_secret` Nullable(String),
     `active_attempt_id` String,
+    `attempt_count` Int16,
     `business_country` LowCardinality(String),
     `business_label` String,
     `modified_at` DateTime DEFAULT now() CODEC(T64, LZ4),
@@ -81,6 +83,7 @@ CREATE MATERIALIZED VIEW kafka_parse_payment_intent TO payment_intents_dist (
     `off_session` Nullable(Bool),
     `client_secret` Nullable(String),
     `active_attempt_id` String,
+    `attempt_count` Int16,
     `business_country` LowCardinality(String),
     `business_label` String,
     `modified_at` DateTime64(3),
@@ -106,6 +109,7 @@ SELECT
     off_session,
     client_secret,
     active_attempt_id,
+    attempt_count,
     business_country,
     business_label,
     modified_at,
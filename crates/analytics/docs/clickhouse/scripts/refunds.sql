@@ -18,7 +18,11 @@ CREATE TABLE refund_queue (
     `attempt_id` String,
     `description` Nullable(String),
     `refund_reason` Nullable(String),
+    `refund_reason_code` LowCardinality(Nullable(String)),
     `refund_error_code` Nullable(String),
+    `payout_id` Nullable(String),
+    `retry_count` Nullable(Int16),
+    `retry_status` LowCardinality(Nullable(String)),
     `created_at` DateTime CODEC(T64, LZ4),
     `modified_at` DateTime CODEC(T64, LZ4),
     `sign_flag` Int8
@@ -49,7 +53,11 @@ CREATE TABLE refund_dist (
     `attempt_id` String,
     `description` Nullable(String),
     `refund_reason` Nullable(String),
+    `refund_reason_code` LowCardinality(Nullable(String)),
     `refund_error_code` Nullable(String),
+    `payout_id` Nullable(String),
+    `retry_count` Nullable(Int16),
+    `retry_status` LowCardinality(Nullable(String)),
     `created_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `modified_at` DateTime DEFAULT now() CODEC(T64, LZ4),
     `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
@@ -87,7 +95,11 @@ CREATE MATERIALIZED VIEW kafka_parse_refund TO refund_dist (
     `attempt_id` String,
     `description` Nullable(String),
     `refund_reason` Nullable(String),
+    `refund_reason_code` LowCardinality(Nullable(String)),
     `refund_error_code` Nullable(String),
+    `payout_id` Nullable(String),
+    `retry_count` Nullable(Int16),
+    `retry_status` LowCardinality(Nullable(String)),
     `created_at` DateTime64(3),
     `modified_at` DateTime64(3),
     `inserted_at` DateTime64(3),
@@ -113,7 +125,11 @@ SELECT
     attempt_id,
     description,
     refund_reason,
+    refund_reason_code,
     refund_error_code,
+    payout_id,
+    retry_count,
+    retry_status,
     created_at,
     modified_at,
     now() as inserted_at,
@@ -0,0 +1,126 @@
+CREATE TABLE sdk_events_queue (
+    `merchant_id` String,
+    `payment_id` String,
+    `sdk_session_id` Nullable(String),
+    `event_name` LowCardinality(Nullable(String)),
+    `log_type` LowCardinality(Nullable(String)),
+    `first_event` Bool DEFAULT false,
+    `platform` LowCardinality(Nullable(String)),
+    `browser_name` LowCardinality(Nullable(String)),
+    `browser_version` Nullable(String),
+    `source` LowCardinality(Nullable(String)),
+    `category` LowCardinality(Nullable(String)),
+    `component` LowCardinality(Nullable(String)),
+    `version` LowCardinality(Nullable(String)),
+    `payment_method` LowCardinality(Nullable(String)),
+    `payment_experience` LowCardinality(Nullable(String)),
+    `latency` Nullable(UInt32),
+    `value` Nullable(String),
+    `created_at_precise` DateTime64(3),
+    `created_at` DateTime
+) ENGINE = Kafka SETTINGS kafka_broker_list = 'kafka0:29092',
+kafka_topic_list = 'hyperswitch-sdk-events',
+kafka_group_name = 'hyper-c1',
+kafka_format = 'JSONEachRow',
+kafka_handle_error_mode = 'stream';
+
+
+CREATE TABLE sdk_events_audit (
+    `merchant_id` String,
+    `payment_id` String,
+    `sdk_session_id` Nullable(String),
+    `event_name` LowCardinality(Nullable(String)),
+    `log_type` LowCardinality(Nullable(String)),
+    `first_event` Bool DEFAULT false,
+    `platform` LowCardinality(Nullable(String)),
+    `browser_name` LowCardinality(Nullable(String)),
+    `browser_version` Nullable(String),
+    `source` LowCardinality(Nullable(String)),
+    `category` LowCardinality(Nullable(String)),
+    `component` LowCardinality(Nullable(String)),
+    `version` LowCardinality(Nullable(String)),
+    `payment_method` LowCardinality(Nullable(String)),
+    `payment_experience` LowCardinality(Nullable(String)),
+    `latency` Nullable(UInt32),
+    `value` Nullable(String),
+    `created_at_precise` DateTime64(3),
+    `created_at` DateTime DEFAULT now() CODEC(T64, LZ4),
+    `inserted_at` DateTime DEFAULT now() CODEC(T64, LZ4),
+    INDEX eventIndex event_name TYPE bloom_filter GRANULARITY 1,
+    INDEX sessionIndex sdk_session_id TYPE bloom_filter GRANULARITY 1,
+    INDEX paymentMethodIndex payment_method TYPE bloom_filter GRANULARITY 1
+) ENGINE = MergeTree
+PARTITION BY toStartOfDay(created_at)
+ORDER BY
+    (created_at, merchant_id, payment_id)
+TTL created_at + toIntervalMonth(6)
+;
+
+CREATE MATERIALIZED VIEW sdk_events_mv TO sdk_events_audit (
+    `merchant_id` String,
+    `payment_id` String,
+    `sdk_session_id` Nullable(String),
+    `event_name` LowCardinality(Nullable(String)),
+    `log_type` LowCardinality(Nullable(String)),
+    `first_event` Bool,
+    `platform` LowCardinality(Nullable(String)),
+    `browser_name` LowCardinality(Nullable(String)),
+    `browser_version` Nullable(String),
+    `source` LowCardinality(Nullable(String)),
+    `category` LowCardinality(Nullable(String)),
+    `component` LowCardinality(Nullable(String)),
+    `version` LowCardinality(Nullable(String)),
+    `payment_method` LowCardinality(Nullable(String)),
+    `payment_experience` LowCardinality(Nullable(String)),
+    `latency` Nullable(UInt32),
+    `value` Nullable(String),
+    `created_at_precise` DateTime64(3),
+    `created_at` DateTime,
+    `inserted_at` DateTime
+) AS
+SELECT
+    merchant_id,
+    payment_id,
+    sdk_session_id,
+    event_name,
+    log_type,
+    first_event,
+    platform,
+    browser_name,
+    browser_version,
+    source,
+    category,
+    component,
+    version,
+    payment_method,
+    payment_experience,
+    latency,
+    value,
+    created_at_precise,
+    created_at,
+    now() as inserted_at
+FROM
+    sdk_events_queue
+where length(_error) = 0;
+
+
+CREATE MATERIALIZED VIEW sdk_events_parse_errors
+(
+    `topic` String,
+    `partition` Int64,
+    `offset` Int64,
+    `raw` String,
+    `error` String
+)
+ENGINE = MergeTree
+ORDER BY (topic, partition, offset)
+SETTINGS index_granularity = 8192 AS
+SELECT
+    _topic AS topic,
+    _partition AS partition,
+    _offset AS offset,
+    _raw_message AS raw,
+    _error AS error
+FROM sdk_events_queue
+WHERE length(_error) > 0
+;
@@ -20,6 +20,7 @@ use super::{
     metrics::ApiEventMetricRow,
 };
 use crate::{
+    core::get_time_series_or_default,
     errors::{AnalyticsError, AnalyticsResult},
     metrics,
     types::FiltersError,
@@ -32,6 +33,7 @@ pub async fn api_events_core(
     req: ApiLogsRequest,
     merchant_id: String,
 ) -> AnalyticsResult<Vec<ApiLogsResult>> {
+    let _query_permit = pool.guardrails().acquire_query_permit(&merchant_id)?;
     let data = match pool {
         AnalyticsProvider::Sqlx(_) => Err(FiltersError::NotImplemented(
             "API Events not implemented for SQLX",
@@ -53,6 +55,7 @@ pub async fn get_filters(
     req: GetApiEventFiltersRequest,
     merchant_id: String,
 ) -> AnalyticsResult<ApiEventFiltersResponse> {
+    let _query_permit = pool.guardrails().acquire_query_permit(&merchant_id)?;
     use api_models::analytics::{api_event::ApiEventDimensions, ApiEventFilterValue};
 
     use super::filters::get_api_event_filter_for_dimension;
@@ -96,6 +99,11 @@ pub async fn get_api_event_metrics(
     merchant_id: &str,
     req: GetApiEventMetricRequest,
 ) -> AnalyticsResult<MetricsResponse<ApiMetricsBucketResponse>> {
+    let _query_permit = pool.guardrails().acquire_query_permit(merchant_id)?;
+    let req = GetApiEventMetricRequest {
+        time_series: Some(get_time_series_or_default(req.time_series, &req.time_range)),
+        ..req
+    };
     let mut metrics_accumulator: HashMap<ApiEventMetricsBucketIdentifier, ApiEventMetricRow> =
         HashMap::new();
 
@@ -176,6 +184,7 @@ pub async fn get_api_event_metrics(
         query_data,
         meta_data: [AnalyticsMetadata {
             current_time_range: req.time_range,
+            granularity: req.time_series.map(|series| series.granularity),
         }],
     })
 }
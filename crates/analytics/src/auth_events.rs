@@ -0,0 +1,8 @@
+pub mod accumulator;
+mod core;
+pub mod metrics;
+pub mod types;
+pub use accumulator::{AuthEventMetricAccumulator, AuthEventMetricsAccumulator};
+
+pub trait AuthEventAnalytics: metrics::AuthEventMetricAnalytics {}
+pub use self::core::get_metrics;
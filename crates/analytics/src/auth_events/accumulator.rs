@@ -0,0 +1,140 @@
+use api_models::analytics::auth_events::AuthEventMetricsBucketValue;
+use diesel_models::enums as storage_enums;
+
+use super::metrics::AuthEventMetricRow;
+
+#[derive(Debug, Default)]
+pub struct AuthEventMetricsAccumulator {
+    pub authentication_attempt_count: CountAccumulator,
+    pub frictionless_rate: AuthenticationTypeRateAccumulator,
+    pub challenge_rate: AuthenticationTypeRateAccumulator,
+    pub challenge_success_rate: ChallengeSuccessRateAccumulator,
+    pub three_ds_method_completion_rate: ThreeDsMethodCompletionRateAccumulator,
+}
+
+#[derive(Debug, Default)]
+#[repr(transparent)]
+pub struct CountAccumulator {
+    pub count: Option<i64>,
+}
+
+#[derive(Debug, Default)]
+pub struct AuthenticationTypeRateAccumulator {
+    pub frictionless: i64,
+    pub challenge: i64,
+}
+
+#[derive(Debug, Default)]
+pub struct ChallengeSuccessRateAccumulator {
+    pub success: i64,
+    pub total: i64,
+}
+
+#[derive(Debug, Default)]
+pub struct ThreeDsMethodCompletionRateAccumulator {
+    pub completed: i64,
+    pub total: i64,
+}
+
+pub trait AuthEventMetricAccumulator {
+    type MetricOutput;
+
+    fn add_metrics_bucket(&mut self, metrics: &AuthEventMetricRow);
+
+    fn collect(self) -> Self::MetricOutput;
+}
+
+fn get_rate(count: i64, total: i64) -> Option<f64> {
+    if total <= 0 {
+        None
+    } else {
+        Some(f64::from(u32::try_from(count).ok()?) * 100.0 / f64::from(u32::try_from(total).ok()?))
+    }
+}
+
+impl AuthEventMetricAccumulator for CountAccumulator {
+    type MetricOutput = Option<u64>;
+    #[inline]
+    fn add_metrics_bucket(&mut self, metrics: &AuthEventMetricRow) {
+        self.count = match (self.count, metrics.count) {
+            (None, None) => None,
+            (None, i @ Some(_)) | (i @ Some(_), None) => i,
+            (Some(a), Some(b)) => Some(a + b),
+        }
+    }
+    #[inline]
+    fn collect(self) -> Self::MetricOutput {
+        self.count.and_then(|i| u64::try_from(i).ok())
+    }
+}
+
+impl AuthEventMetricAccumulator for AuthenticationTypeRateAccumulator {
+    type MetricOutput = (Option<f64>, Option<f64>);
+
+    fn add_metrics_bucket(&mut self, metrics: &AuthEventMetricRow) {
+        match metrics.authentication_type {
+            Some(storage_enums::DecoupledAuthenticationType::Frictionless) => {
+                self.frictionless += metrics.count.unwrap_or_default();
+            }
+            Some(storage_enums::DecoupledAuthenticationType::Challenge) => {
+                self.challenge += metrics.count.unwrap_or_default();
+            }
+            None => {}
+        }
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        let total = self.frictionless + self.challenge;
+        (
+            get_rate(self.frictionless, total),
+            get_rate(self.challenge, total),
+        )
+    }
+}
+
+impl AuthEventMetricAccumulator for ChallengeSuccessRateAccumulator {
+    type MetricOutput = Option<f64>;
+
+    fn add_metrics_bucket(&mut self, metrics: &AuthEventMetricRow) {
+        if metrics.authentication_status == Some(storage_enums::AuthenticationStatus::Success) {
+            self.success += metrics.count.unwrap_or_default();
+        }
+        self.total += metrics.count.unwrap_or_default();
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        get_rate(self.success, self.total)
+    }
+}
+
+impl AuthEventMetricAccumulator for ThreeDsMethodCompletionRateAccumulator {
+    type MetricOutput = Option<f64>;
+
+    fn add_metrics_bucket(&mut self, metrics: &AuthEventMetricRow) {
+        // Authentications without a 3DS method are not considered for the rate
+        if let Some(three_ds_method_completed) = metrics.three_ds_method_completed {
+            if three_ds_method_completed {
+                self.completed += metrics.count.unwrap_or_default();
+            }
+            self.total += metrics.count.unwrap_or_default();
+        }
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        get_rate(self.completed, self.total)
+    }
+}
+
+impl AuthEventMetricsAccumulator {
+    pub fn collect(self) -> AuthEventMetricsBucketValue {
+        let (frictionless_rate, _) = self.frictionless_rate.collect();
+        let (_, challenge_rate) = self.challenge_rate.collect();
+        AuthEventMetricsBucketValue {
+            authentication_attempt_count: self.authentication_attempt_count.collect(),
+            frictionless_rate,
+            challenge_rate,
+            challenge_success_rate: self.challenge_success_rate.collect(),
+            three_ds_method_completion_rate: self.three_ds_method_completion_rate.collect(),
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+
+use api_models::analytics::{
+    auth_events::{
+        AuthEventMetrics, AuthEventMetricsBucketIdentifier, AuthEventMetricsBucketResponse,
+    },
+    AnalyticsMetadata, GetAuthEventMetricRequest, MetricsResponse,
+};
+use error_stack::{IntoReport, ResultExt};
+use router_env::{
+    logger,
+    tracing::{self, Instrument},
+};
+
+use super::AuthEventMetricsAccumulator;
+use crate::{
+    auth_events::AuthEventMetricAccumulator,
+    core::get_time_series_or_default,
+    errors::{AnalyticsError, AnalyticsResult},
+    metrics, AnalyticsProvider,
+};
+
+pub async fn get_metrics(
+    pool: &AnalyticsProvider,
+    merchant_id: &String,
+    req: GetAuthEventMetricRequest,
+) -> AnalyticsResult<MetricsResponse<AuthEventMetricsBucketResponse>> {
+    let req = GetAuthEventMetricRequest {
+        time_series: Some(get_time_series_or_default(req.time_series, &req.time_range)),
+        ..req
+    };
+    let _query_permit = pool.guardrails().acquire_query_permit(merchant_id)?;
+    pool.guardrails().validate_time_range(
+        req.time_series.as_ref().map(|series| &series.granularity),
+        &req.time_range,
+    )?;
+
+    let mut metrics_accumulator: HashMap<
+        AuthEventMetricsBucketIdentifier,
+        AuthEventMetricsAccumulator,
+    > = HashMap::new();
+    let mut set = tokio::task::JoinSet::new();
+    for metric_type in req.metrics.iter().cloned() {
+        let req = req.clone();
+        let pool = pool.clone();
+        let task_span = tracing::debug_span!(
+            "analytics_auth_events_query",
+            auth_event_metric = metric_type.as_ref()
+        );
+        // Currently JoinSet works with only static lifetime references even if the task pool does not outlive the given reference
+        // We can optimize away this clone once that is fixed
+        let merchant_id_scoped = merchant_id.to_owned();
+        set.spawn(
+            async move {
+                let data = pool
+                    .get_auth_event_metrics(
+                        &metric_type,
+                        &req.group_by_names.clone(),
+                        &merchant_id_scoped,
+                        &req.filters,
+                        &req.time_series.map(|t| t.granularity),
+                        &req.time_range,
+                    )
+                    .await
+                    .change_context(AnalyticsError::UnknownError);
+                (metric_type, data)
+            }
+            .instrument(task_span),
+        );
+    }
+
+    while let Some((metric, data)) = set
+        .join_next()
+        .await
+        .transpose()
+        .into_report()
+        .change_context(AnalyticsError::UnknownError)?
+    {
+        let data = data?;
+        let attributes = &[
+            metrics::request::add_attributes("metric_type", metric.to_string()),
+            metrics::request::add_attributes("source", pool.to_string()),
+        ];
+
+        let value = u64::try_from(data.len());
+        if let Ok(val) = value {
+            metrics::BUCKETS_FETCHED.record(&metrics::CONTEXT, val, attributes);
+            logger::debug!("Attributes: {:?}, Buckets fetched: {}", attributes, val);
+        }
+
+        for (id, value) in data {
+            logger::debug!(bucket_id=?id, bucket_value=?value, "Bucket row for metric {metric}");
+            let metrics_builder = metrics_accumulator.entry(id).or_default();
+            match metric {
+                AuthEventMetrics::AuthenticationAttemptCount => metrics_builder
+                    .authentication_attempt_count
+                    .add_metrics_bucket(&value),
+                AuthEventMetrics::FrictionlessRate => {
+                    metrics_builder.frictionless_rate.add_metrics_bucket(&value)
+                }
+                AuthEventMetrics::ChallengeRate => {
+                    metrics_builder.challenge_rate.add_metrics_bucket(&value)
+                }
+                AuthEventMetrics::ChallengeSuccessRate => metrics_builder
+                    .challenge_success_rate
+                    .add_metrics_bucket(&value),
+                AuthEventMetrics::ThreeDsMethodCompletionRate => metrics_builder
+                    .three_ds_method_completion_rate
+                    .add_metrics_bucket(&value),
+            }
+        }
+
+        logger::debug!(
+            "Analytics Accumulated Results: metric: {}, results: {:#?}",
+            metric,
+            metrics_accumulator
+        );
+    }
+    let query_data: Vec<AuthEventMetricsBucketResponse> = metrics_accumulator
+        .into_iter()
+        .map(|(id, val)| AuthEventMetricsBucketResponse {
+            values: val.collect(),
+            dimensions: id,
+        })
+        .collect();
+
+    Ok(MetricsResponse {
+        query_data,
+        meta_data: [AnalyticsMetadata {
+            current_time_range: req.time_range,
+            granularity: req.time_series.map(|series| series.granularity),
+        }],
+    })
+}
@@ -0,0 +1,156 @@
+mod authentication_attempt_count;
+mod challenge_rate;
+mod challenge_success_rate;
+mod frictionless_rate;
+mod three_ds_method_completion_rate;
+
+use api_models::analytics::{
+    auth_events::{
+        AuthEventDimensions, AuthEventFilters, AuthEventMetrics, AuthEventMetricsBucketIdentifier,
+    },
+    Granularity, TimeRange,
+};
+use diesel_models::enums as storage_enums;
+use time::PrimitiveDateTime;
+
+use self::{
+    authentication_attempt_count::AuthenticationAttemptCount, challenge_rate::ChallengeRate,
+    challenge_success_rate::ChallengeSuccessRate, frictionless_rate::FrictionlessRate,
+    three_ds_method_completion_rate::ThreeDsMethodCompletionRate,
+};
+use crate::{
+    query::{Aggregate, GroupByClause, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, LoadRow, MetricsResult},
+};
+
+#[derive(Debug, Eq, PartialEq, serde::Deserialize)]
+pub struct AuthEventMetricRow {
+    pub authentication_connector: Option<String>,
+    pub message_version: Option<String>,
+    pub trans_status: Option<String>,
+    pub authentication_type: Option<storage_enums::DecoupledAuthenticationType>,
+    pub authentication_status: Option<storage_enums::AuthenticationStatus>,
+    pub three_ds_method_completed: Option<bool>,
+    pub count: Option<i64>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub start_bucket: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub end_bucket: Option<PrimitiveDateTime>,
+}
+
+pub trait AuthEventMetricAnalytics: LoadRow<AuthEventMetricRow> {}
+
+/// Column selected for the dimension, the message version stored as JSON is selected as text
+fn get_dimension_column(dimension: &AuthEventDimensions) -> String {
+    match dimension {
+        AuthEventDimensions::MessageVersion => format!("{dimension} #>> '{{}}' AS {dimension}"),
+        AuthEventDimensions::AuthenticationConnector | AuthEventDimensions::TransStatus => {
+            dimension.to_string()
+        }
+    }
+}
+
+#[async_trait::async_trait]
+pub trait AuthEventMetric<T>
+where
+    T: AnalyticsDataSource + AuthEventMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[AuthEventDimensions],
+        merchant_id: &str,
+        filters: &AuthEventFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<Vec<(AuthEventMetricsBucketIdentifier, AuthEventMetricRow)>>;
+}
+
+#[async_trait::async_trait]
+impl<T> AuthEventMetric<T> for AuthEventMetrics
+where
+    T: AnalyticsDataSource + AuthEventMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[AuthEventDimensions],
+        merchant_id: &str,
+        filters: &AuthEventFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<Vec<(AuthEventMetricsBucketIdentifier, AuthEventMetricRow)>> {
+        match self {
+            Self::AuthenticationAttemptCount => {
+                AuthenticationAttemptCount::default()
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::FrictionlessRate => {
+                FrictionlessRate::default()
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::ChallengeRate => {
+                ChallengeRate::default()
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::ChallengeSuccessRate => {
+                ChallengeSuccessRate::default()
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::ThreeDsMethodCompletionRate => {
+                ThreeDsMethodCompletionRate::default()
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use api_models::analytics::auth_events::{AuthEventDimensions, AuthEventFilters};
+use error_stack::ResultExt;
+
+use crate::{
+    query::{QueryBuilder, QueryFilter, QueryResult, ToSql},
+    types::{AnalyticsCollection, AnalyticsDataSource},
+};
+
+impl<T> QueryFilter<T> for AuthEventFilters
+where
+    T: AnalyticsDataSource,
+    AnalyticsCollection: ToSql<T>,
+{
+    fn set_filter_clause(&self, builder: &mut QueryBuilder<T>) -> QueryResult<()> {
+        if !self.authentication_connector.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    AuthEventDimensions::AuthenticationConnector,
+                    &self.authentication_connector,
+                )
+                .attach_printable("Error adding authentication connector filter")?;
+        }
+
+        if !self.trans_status.is_empty() {
+            builder
+                .add_filter_in_range_clause(AuthEventDimensions::TransStatus, &self.trans_status)
+                .attach_printable("Error adding trans status filter")?;
+        }
+
+        Ok(())
+    }
+}
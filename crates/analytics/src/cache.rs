@@ -0,0 +1,287 @@
+use std::{fmt, future::Future, sync::Arc};
+
+use api_models::analytics::{Granularity, TimeRange};
+use common_utils::crypto::{GenerateDigest, Sha256};
+use error_stack::{IntoReport, ResultExt};
+use redis_interface::RedisConnectionPool;
+use router_env::logger;
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{
+    metrics,
+    query::SeriesBucket,
+    types::{AnalyticsDomain, MetricsError, MetricsResult},
+};
+
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(default)]
+pub struct AnalyticsCacheConfig {
+    /// Whether the results of metric queries are cached in redis
+    pub enabled: bool,
+    /// Time for which results are cached, as a fraction of the duration of a bucket of the
+    /// granularity of the query, so that coarser time series are cached for longer
+    pub ttl_factor: f64,
+    /// Upper bound on the time for which results are cached, so that coarse time series covering
+    /// the current time are not served stale for too long
+    pub max_ttl_in_secs: u32,
+}
+
+impl AnalyticsCacheConfig {
+    pub fn validate(&self) -> Result<(), &'static str> {
+        use common_utils::fp_utils::when;
+
+        when(
+            !self.ttl_factor.is_finite() || self.ttl_factor <= 0.0,
+            || Err("analytics cache ttl_factor must be a positive number"),
+        )?;
+
+        when(self.max_ttl_in_secs == 0, || {
+            Err("analytics cache max_ttl_in_secs must be greater than 0")
+        })
+    }
+}
+
+impl Default for AnalyticsCacheConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            ttl_factor: 0.5,
+            max_ttl_in_secs: 3600,
+        }
+    }
+}
+
+/// Identifies the result of a single metric query, results are shared by all the requests asking
+/// for the same metric over the same buckets.
+#[derive(Debug, Serialize)]
+pub(crate) struct MetricCacheKey<'a, M, D, F> {
+    pub domain: AnalyticsDomain,
+    pub metric: &'a M,
+    pub merchant_ids: &'a [String],
+    pub dimensions: &'a [D],
+    pub filters: &'a F,
+    pub granularity: Option<Granularity>,
+    pub time_range: TimeRange,
+}
+
+/// Caches the results of metric queries in redis, the state is shared across all the clones of
+/// the analytics provider.
+#[derive(Clone, Default)]
+pub struct AnalyticsCache {
+    config: Arc<AnalyticsCacheConfig>,
+    redis: Option<Arc<RedisConnectionPool>>,
+}
+
+impl fmt::Debug for AnalyticsCache {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AnalyticsCache")
+            .field("config", &self.config)
+            .field("redis_available", &self.redis.is_some())
+            .finish()
+    }
+}
+
+impl AnalyticsCache {
+    pub fn new(config: AnalyticsCacheConfig, redis: Option<Arc<RedisConnectionPool>>) -> Self {
+        Self {
+            config: Arc::new(config),
+            redis,
+        }
+    }
+
+    /// Returns the cached result of the metric query identified by the key, or loads and caches
+    /// it on a miss.
+    ///
+    /// Cached queries are run over the time range of the key clipped to the bucket boundaries of
+    /// its granularity, so that requests for the same buckets share their results. Queries
+    /// without a granularity, requests bypassing the cache and failures of redis fall back to
+    /// loading the result over the requested time range.
+    pub(crate) async fn get_or_load<M, D, F, V, L, Fut>(
+        &self,
+        key: MetricCacheKey<'_, M, D, F>,
+        bypass_cache: bool,
+        load: L,
+    ) -> MetricsResult<V>
+    where
+        M: Serialize + AsRef<str>,
+        D: Serialize,
+        F: Serialize,
+        V: Serialize + DeserializeOwned + fmt::Debug,
+        L: FnOnce(TimeRange) -> Fut,
+        Fut: Future<Output = MetricsResult<V>>,
+    {
+        let (redis, granularity) = match (&self.redis, key.granularity) {
+            (Some(redis), Some(granularity)) if self.config.enabled && !bypass_cache => {
+                (redis, granularity)
+            }
+            _ => return load(key.time_range).await,
+        };
+
+        let time_range = clip_time_range(&granularity, &key.time_range)?;
+        let key = MetricCacheKey { time_range, ..key };
+        let attributes = &[metrics::request::add_attributes(
+            "metric_type",
+            key.metric.as_ref().to_string(),
+        )];
+        let redis_key = match get_redis_key(&key) {
+            Ok(redis_key) => redis_key,
+            Err(error) => {
+                logger::error!(?error, "Failed to build the analytics cache key");
+                return load(time_range).await;
+            }
+        };
+
+        match redis
+            .get_and_deserialize_key::<V>(&redis_key, "AnalyticsCachedMetric")
+            .await
+        {
+            Ok(cached) => {
+                metrics::CACHE_HIT.add(&metrics::CONTEXT, 1, attributes);
+                return Ok(cached);
+            }
+            Err(error) => {
+                if !matches!(
+                    error.current_context(),
+                    redis_interface::errors::RedisError::NotFound
+                ) {
+                    logger::error!(?error, "Failed to read the cached analytics metric");
+                }
+            }
+        }
+        metrics::CACHE_MISS.add(&metrics::CONTEXT, 1, attributes);
+
+        let loaded = load(time_range).await?;
+        redis
+            .serialize_and_set_key_with_expiry(
+                &redis_key,
+                &loaded,
+                self.get_ttl_in_secs(&granularity),
+            )
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to cache the analytics metric"))
+            .ok();
+
+        Ok(loaded)
+    }
+
+    fn get_ttl_in_secs(&self, granularity: &Granularity) -> i64 {
+        let bucket_duration_in_secs = get_bucket_duration(granularity).whole_seconds();
+        // Truncating the fraction of a second is fine, the ttl only needs to be in the ballpark
+        #[allow(clippy::as_conversions)]
+        let ttl_in_secs = (bucket_duration_in_secs as f64 * self.config.ttl_factor) as i64;
+        ttl_in_secs.clamp(1, i64::from(self.config.max_ttl_in_secs))
+    }
+}
+
+fn clip_time_range(granularity: &Granularity, time_range: &TimeRange) -> MetricsResult<TimeRange> {
+    Ok(TimeRange {
+        start_time: granularity
+            .clip_to_start(time_range.start_time)
+            .change_context(MetricsError::PostProcessingFailure)?,
+        end_time: time_range
+            .end_time
+            .map(|end_time| granularity.clip_to_end(end_time))
+            .transpose()
+            .change_context(MetricsError::PostProcessingFailure)?,
+    })
+}
+
+fn get_bucket_duration(granularity: &Granularity) -> time::Duration {
+    match granularity {
+        Granularity::OneMin => time::Duration::minutes(1),
+        Granularity::FiveMin => time::Duration::minutes(5),
+        Granularity::FifteenMin => time::Duration::minutes(15),
+        Granularity::ThirtyMin => time::Duration::minutes(30),
+        Granularity::OneHour => time::Duration::hours(1),
+        Granularity::OneDay => time::Duration::days(1),
+        Granularity::OneWeek => time::Duration::weeks(1),
+    }
+}
+
+fn get_redis_key<M, D, F>(key: &MetricCacheKey<'_, M, D, F>) -> MetricsResult<String>
+where
+    M: Serialize + AsRef<str>,
+    D: Serialize,
+    F: Serialize,
+{
+    let serialized_key = serde_json::to_vec(key)
+        .into_report()
+        .change_context(MetricsError::QueryBuildingError)
+        .attach_printable("Failed to serialize the analytics cache key")?;
+    let digest = Sha256
+        .generate_digest(&serialized_key)
+        .change_context(MetricsError::QueryBuildingError)
+        .attach_printable("Failed to hash the analytics cache key")?;
+
+    Ok(format!(
+        "analytics_cache_{}_{}",
+        key.metric.as_ref(),
+        hex::encode(digest)
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use api_models::analytics::{
+        payments::{PaymentDimensions, PaymentFilters, PaymentMetrics},
+        Granularity, TimeRange,
+    };
+    use time::{Date, Month, PrimitiveDateTime, Time};
+
+    use super::{get_redis_key, AnalyticsCache, AnalyticsCacheConfig, MetricCacheKey};
+    use crate::types::AnalyticsDomain;
+
+    fn at(hour: u8, minute: u8) -> PrimitiveDateTime {
+        PrimitiveDateTime::new(
+            Date::from_calendar_date(2024, Month::April, 18).unwrap(),
+            Time::from_hms(hour, minute, 0).unwrap(),
+        )
+    }
+
+    fn get_key(time_range: TimeRange) -> String {
+        get_redis_key(&MetricCacheKey {
+            domain: AnalyticsDomain::Payments,
+            metric: &PaymentMetrics::PaymentCount,
+            merchant_ids: &["merchant_1".to_string()],
+            dimensions: &[PaymentDimensions::Connector],
+            filters: &PaymentFilters::default(),
+            granularity: Some(Granularity::OneHour),
+            time_range: super::clip_time_range(&Granularity::OneHour, &time_range).unwrap(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_requests_for_the_same_buckets_share_the_key() {
+        let key = get_key(TimeRange {
+            start_time: at(10, 5),
+            end_time: Some(at(12, 10)),
+        });
+
+        assert!(key.starts_with("analytics_cache_payment_count_"));
+        assert_eq!(
+            key,
+            get_key(TimeRange {
+                start_time: at(10, 45),
+                end_time: Some(at(12, 50)),
+            })
+        );
+        assert_ne!(
+            key,
+            get_key(TimeRange {
+                start_time: at(10, 5),
+                end_time: Some(at(13, 10)),
+            })
+        );
+    }
+
+    #[test]
+    fn test_ttl_is_proportional_to_the_granularity() {
+        let cache = AnalyticsCache::new(AnalyticsCacheConfig::default(), None);
+
+        assert_eq!(cache.get_ttl_in_secs(&Granularity::OneMin), 30);
+        assert_eq!(cache.get_ttl_in_secs(&Granularity::FifteenMin), 450);
+        assert_eq!(cache.get_ttl_in_secs(&Granularity::OneDay), 3600);
+    }
+}
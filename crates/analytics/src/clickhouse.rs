@@ -7,11 +7,13 @@ use router_env::logger;
 use time::PrimitiveDateTime;
 
 use super::{
+    cache::AnalyticsCache,
+    guardrails::AnalyticsGuardrails,
     health_check::HealthCheck,
     payments::{
         distribution::PaymentDistributionRow, filters::FilterRow, metrics::PaymentMetricRow,
     },
-    query::{Aggregate, ToSql, Window},
+    query::{get_quantile, Aggregate, QueryParam, ToSql, Window},
     refunds::{filters::RefundFilterRow, metrics::RefundMetricRow},
     sdk_events::{filters::SdkEventFilter, metrics::SdkEventMetricRow},
     types::{AnalyticsCollection, AnalyticsDataSource, LoadRow, QueryExecutionError},
@@ -34,6 +36,8 @@ pub type ClickhouseResult<T> = error_stack::Result<T, ClickhouseError>;
 #[derive(Clone, Debug)]
 pub struct ClickhouseClient {
     pub config: Arc<ClickhouseConfig>,
+    pub guardrails: AnalyticsGuardrails,
+    pub cache: AnalyticsCache,
 }
 
 #[derive(Clone, Debug, serde::Deserialize)]
@@ -56,7 +60,11 @@ impl Default for ClickhouseConfig {
 }
 
 impl ClickhouseClient {
-    async fn execute_query(&self, query: &str) -> ClickhouseResult<Vec<serde_json::Value>> {
+    async fn execute_query(
+        &self,
+        query: &str,
+        query_params: &[QueryParam],
+    ) -> ClickhouseResult<Vec<serde_json::Value>> {
         logger::debug!("Executing query: {query}");
         let client = reqwest::Client::new();
         let params = CkhQuery {
@@ -64,9 +72,15 @@ impl ClickhouseClient {
             output_format_json_quote_64bit_integers: 0,
             database: self.config.database_name.clone(),
         };
+        let query_params = query_params
+            .iter()
+            .enumerate()
+            .map(|(index, param)| Ok((format!("param_p{index}"), get_param_value(param)?)))
+            .collect::<ClickhouseResult<Vec<_>>>()?;
         let response = client
             .post(&self.config.host)
             .query(&params)
+            .query(&query_params)
             .basic_auth(self.config.username.clone(), self.config.password.clone())
             .body(format!("{query}\nFORMAT JSON"))
             .send()
@@ -93,6 +107,30 @@ impl ClickhouseClient {
                 .data)
         }
     }
+
+    async fn log_query_plan(
+        &self,
+        query: &str,
+        params: &[QueryParam],
+        elapsed: std::time::Duration,
+    ) {
+        match self
+            .execute_query(&format!("EXPLAIN {query}"), params)
+            .await
+        {
+            Ok(rows) => {
+                let query_plan = rows
+                    .iter()
+                    .filter_map(|row| row.get("explain").and_then(|line| line.as_str()))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                logger::warn!(?elapsed, query, %query_plan, "Slow analytics query");
+            }
+            Err(error) => {
+                logger::error!(?error, query, "Failed to explain slow analytics query")
+            }
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -100,7 +138,7 @@ impl HealthCheck for ClickhouseClient {
     async fn deep_health_check(
         &self,
     ) -> common_utils::errors::CustomResult<(), QueryExecutionError> {
-        self.execute_query("SELECT 1")
+        self.execute_query("SELECT 1", &[])
             .await
             .map(|_| ())
             .change_context(QueryExecutionError::DatabaseError)
@@ -114,19 +152,42 @@ impl AnalyticsDataSource for ClickhouseClient {
     async fn load_results<T>(
         &self,
         query: &str,
+        params: &[QueryParam],
     ) -> common_utils::errors::CustomResult<Vec<T>, QueryExecutionError>
     where
         Self: LoadRow<T>,
     {
-        self.execute_query(query)
+        let start_time = std::time::Instant::now();
+        let rows = self
+            .execute_query(query, params)
             .await
-            .change_context(QueryExecutionError::DatabaseError)?
-            .into_iter()
+            .change_context(QueryExecutionError::DatabaseError)?;
+
+        let elapsed = start_time.elapsed();
+        if self
+            .guardrails
+            .explain_latency_threshold()
+            .is_some_and(|threshold| elapsed > threshold)
+        {
+            self.log_query_plan(query, params, elapsed).await;
+        }
+
+        rows.into_iter()
             .map(Self::load_row)
             .collect::<Result<Vec<_>, _>>()
             .change_context(QueryExecutionError::RowExtractionFailure)
     }
 
+    fn get_placeholder(index: usize, param: &QueryParam) -> String {
+        let param_type = match param {
+            QueryParam::Text(_) => "String",
+            QueryParam::Int(_) => "Int64",
+            QueryParam::Bool(_) => "Bool",
+            QueryParam::DateTime(_) => "DateTime",
+        };
+        format!("{{p{index}:{param_type}}}")
+    }
+
     fn get_table_engine(table: AnalyticsCollection) -> TableEngine {
         match table {
             AnalyticsCollection::Payment
@@ -139,6 +200,9 @@ impl AnalyticsDataSource for ClickhouseClient {
             AnalyticsCollection::ApiEvents => TableEngine::BasicTree,
             AnalyticsCollection::ConnectorEvents => TableEngine::BasicTree,
             AnalyticsCollection::OutgoingWebhookEvent => TableEngine::BasicTree,
+            AnalyticsCollection::Authentications => TableEngine::BasicTree,
+            // The attempts are collapsed before being joined
+            AnalyticsCollection::PaymentAttemptRetries => TableEngine::BasicTree,
         }
     }
 }
@@ -172,6 +236,23 @@ impl super::outgoing_webhook_event::events::OutgoingWebhookLogsFilterAnalytics
 impl super::disputes::filters::DisputeFilterAnalytics for ClickhouseClient {}
 impl super::disputes::metrics::DisputeMetricAnalytics for ClickhouseClient {}
 
+/// Value of a query parameter as sent over HTTP, which ClickHouse parses in the escaped text format
+fn get_param_value(param: &QueryParam) -> ClickhouseResult<String> {
+    match param {
+        QueryParam::Text(text) => Ok(text
+            .replace('\\', "\\\\")
+            .replace('\t', "\\t")
+            .replace('\n', "\\n")),
+        QueryParam::Int(int) => Ok(int.to_string()),
+        QueryParam::Bool(boolean) => Ok(boolean.to_string()),
+        QueryParam::DateTime(date_time) => <PrimitiveDateTime as ToSql<ClickhouseClient>>::to_sql(
+            date_time,
+            &TableEngine::BasicTree,
+        )
+        .change_context(ClickhouseError::QueryParamError),
+    }
+}
+
 #[derive(Debug, serde::Serialize)]
 struct CkhQuery {
     date_time_output_format: String,
@@ -402,6 +483,19 @@ impl ToSql<ClickhouseClient> for AnalyticsCollection {
             Self::ConnectorEvents => Ok("connector_events_audit".to_string()),
             Self::OutgoingWebhookEvent => Ok("outgoing_webhook_events_audit".to_string()),
             Self::Dispute => Ok("dispute".to_string()),
+            Self::Authentications => Err(error_stack::report!(ParsingError::UnknownError)
+                .attach_printable("Authentications table is not implemented for Clickhouse"))?,
+            Self::PaymentAttemptRetries => Ok(
+                "(SELECT retry.merchant_id AS merchant_id, retry.connector AS connector, \
+                retry.status AS status, retry.amount AS amount, retry.currency AS currency, \
+                retry.created_at AS created_at, original.error_code AS original_error_code \
+                FROM payment_attempts AS retry FINAL \
+                INNER JOIN (SELECT merchant_id, payment_id, error_code FROM payment_attempts FINAL \
+                WHERE retry_index = 0) AS original \
+                ON retry.merchant_id = original.merchant_id AND retry.payment_id = original.payment_id \
+                WHERE retry.retry_index > 0) AS payment_attempt_retries"
+                    .to_string(),
+            ),
         }
     }
 }
@@ -460,6 +554,42 @@ where
                     alias.map_or_else(|| "".to_owned(), |alias| format!(" as {}", alias))
                 )
             }
+            Self::Percentile {
+                field,
+                alias,
+                percentile,
+            } => {
+                format!(
+                    "quantile({})({}){}",
+                    get_quantile(*percentile)?,
+                    field
+                        .to_sql(table_engine)
+                        .attach_printable("Failed to percentile aggregate")?,
+                    alias.map_or_else(|| "".to_owned(), |alias| format!(" as {}", alias))
+                )
+            }
+            Self::DistinctCount { field, alias } => {
+                format!(
+                    "uniqExact({}){}",
+                    field
+                        .to_sql(table_engine)
+                        .attach_printable("Failed to distinct count aggregate")?,
+                    alias.map_or_else(|| "".to_owned(), |alias| format!(" as {}", alias))
+                )
+            }
+            Self::DistinctCountIf {
+                field,
+                condition,
+                alias,
+            } => {
+                format!(
+                    "uniqExactIf({}, {condition}){}",
+                    field
+                        .to_sql(table_engine)
+                        .attach_printable("Failed to conditional distinct count aggregate")?,
+                    alias.map_or_else(|| "".to_owned(), |alias| format!(" as {}", alias))
+                )
+            }
         })
     }
 }
@@ -531,4 +661,6 @@ pub enum ClickhouseError {
     ResponseNotOK(String),
     #[error("Clickhouse response error")]
     ResponseError,
+    #[error("Failed to serialize query parameter")]
+    QueryParamError,
 }
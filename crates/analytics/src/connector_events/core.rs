@@ -10,6 +10,7 @@ pub async fn connector_events_core(
     req: ConnectorEventsRequest,
     merchant_id: String,
 ) -> AnalyticsResult<Vec<ConnectorEventsResult>> {
+    let _query_permit = pool.guardrails().acquire_query_permit(&merchant_id)?;
     let data = match pool {
         AnalyticsProvider::Sqlx(_) => Err(FiltersError::NotImplemented(
             "Connector Events not implemented for SQLX",
@@ -1,4 +1,4 @@
-use api_models::analytics::GetInfoResponse;
+use api_models::analytics::{GetInfoResponse, Granularity, TimeRange, TimeSeries};
 
 use crate::{types::AnalyticsDomain, utils};
 
@@ -31,6 +31,38 @@ pub async fn get_domain_info(
             download_dimensions: None,
             dimensions: utils::get_dispute_dimensions(),
         },
+        AnalyticsDomain::AuthEvents => GetInfoResponse {
+            metrics: utils::get_auth_event_metrics_info(),
+            download_dimensions: None,
+            dimensions: utils::get_auth_event_dimensions(),
+        },
     };
     Ok(info)
 }
+
+/// Returns the requested time series, or one with a granularity picked based on the length of the
+/// time range when the request does not specify one. An open ended time range is considered to
+/// end at the current time.
+pub fn get_time_series_or_default(
+    time_series: Option<TimeSeries>,
+    time_range: &TimeRange,
+) -> TimeSeries {
+    time_series.unwrap_or_else(|| {
+        let end_time = time_range
+            .end_time
+            .unwrap_or_else(common_utils::date_time::now);
+        let range = end_time - time_range.start_time;
+
+        let granularity = if range <= time::Duration::hours(3) {
+            Granularity::OneMin
+        } else if range <= time::Duration::days(3) {
+            Granularity::OneHour
+        } else if range <= time::Duration::days(90) {
+            Granularity::OneDay
+        } else {
+            Granularity::OneWeek
+        };
+
+        TimeSeries { granularity }
+    })
+}
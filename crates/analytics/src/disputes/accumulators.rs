@@ -7,6 +7,8 @@ pub struct DisputeMetricsAccumulator {
     pub disputes_status_rate: RateAccumulator,
     pub total_amount_disputed: SumAccumulator,
     pub total_dispute_lost_amount: SumAccumulator,
+    pub dispute_rate: DisputeRateAccumulator,
+    pub amount_under_dispute: SumAccumulator,
 }
 #[derive(Debug, Default)]
 pub struct RateAccumulator {
@@ -16,6 +18,11 @@ pub struct RateAccumulator {
     pub total: i64,
 }
 #[derive(Debug, Default)]
+pub struct DisputeRateAccumulator {
+    pub disputes: i64,
+    pub settled_payments: i64,
+}
+#[derive(Debug, Default)]
 #[repr(transparent)]
 pub struct SumAccumulator {
     pub total: Option<i64>,
@@ -84,6 +91,26 @@ impl DisputeMetricAccumulator for RateAccumulator {
     }
 }
 
+impl DisputeMetricAccumulator for DisputeRateAccumulator {
+    type MetricOutput = Option<f64>;
+
+    fn add_metrics_bucket(&mut self, metrics: &DisputeMetricRow) {
+        self.disputes += metrics.count.unwrap_or_default();
+        self.settled_payments += metrics.settled_payment_count.unwrap_or_default();
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.settled_payments <= 0 {
+            None
+        } else {
+            Some(
+                f64::from(u32::try_from(self.disputes).ok()?) * 100.0
+                    / f64::from(u32::try_from(self.settled_payments).ok()?),
+            )
+        }
+    }
+}
+
 impl DisputeMetricsAccumulator {
     pub fn collect(self) -> DisputeMetricsBucketValue {
         let (challenge_rate, won_rate, lost_rate, total_dispute) =
@@ -95,6 +122,8 @@ impl DisputeMetricsAccumulator {
             total_amount_disputed: self.total_amount_disputed.collect(),
             total_dispute_lost_amount: self.total_dispute_lost_amount.collect(),
             total_dispute,
+            dispute_rate: self.dispute_rate.collect(),
+            amount_under_dispute: self.amount_under_dispute.collect(),
         }
     }
 }
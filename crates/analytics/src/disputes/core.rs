@@ -19,6 +19,7 @@ use super::{
     DisputeMetricsAccumulator,
 };
 use crate::{
+    core::get_time_series_or_default,
     disputes::DisputeMetricAccumulator,
     errors::{AnalyticsError, AnalyticsResult},
     metrics, AnalyticsProvider,
@@ -29,6 +30,16 @@ pub async fn get_metrics(
     merchant_id: &String,
     req: GetDisputeMetricRequest,
 ) -> AnalyticsResult<MetricsResponse<DisputeMetricsBucketResponse>> {
+    let req = GetDisputeMetricRequest {
+        time_series: Some(get_time_series_or_default(req.time_series, &req.time_range)),
+        ..req
+    };
+    let _query_permit = pool.guardrails().acquire_query_permit(merchant_id)?;
+    pool.guardrails().validate_time_range(
+        req.time_series.as_ref().map(|series| &series.granularity),
+        &req.time_range,
+    )?;
+
     let mut metrics_accumulator: HashMap<
         DisputeMetricsBucketIdentifier,
         DisputeMetricsAccumulator,
@@ -95,6 +106,12 @@ pub async fn get_metrics(
                 DisputeMetrics::TotalDisputeLostAmount => metrics_builder
                     .total_dispute_lost_amount
                     .add_metrics_bucket(&value),
+                DisputeMetrics::DisputeRate => {
+                    metrics_builder.dispute_rate.add_metrics_bucket(&value)
+                }
+                DisputeMetrics::AmountUnderDispute => metrics_builder
+                    .amount_under_dispute
+                    .add_metrics_bucket(&value),
             }
         }
 
@@ -116,6 +133,7 @@ pub async fn get_metrics(
         query_data,
         meta_data: [AnalyticsMetadata {
             current_time_range: req.time_range,
+            granularity: req.time_series.map(|series| series.granularity),
         }],
     })
 }
@@ -125,6 +143,7 @@ pub async fn get_filters(
     req: GetDisputeFilterRequest,
     merchant_id: &String,
 ) -> AnalyticsResult<DisputeFiltersResponse> {
+    let _query_permit = pool.guardrails().acquire_query_permit(merchant_id)?;
     let mut res = DisputeFiltersResponse::default();
     for dim in req.group_by_names {
         let values = match pool {
@@ -188,6 +207,7 @@ pub async fn get_filters(
         .filter_map(|fil: DisputeFilterRow| match dim {
             DisputeDimensions::DisputeStage => fil.dispute_stage,
             DisputeDimensions::Connector => fil.connector,
+            DisputeDimensions::Currency => fil.currency,
         })
         .collect::<Vec<String>>();
         res.query_data.push(DisputeFilterValue {
@@ -49,4 +49,5 @@ pub struct DisputeFilterRow {
     pub dispute_status: Option<String>,
     pub connector_status: Option<String>,
     pub dispute_stage: Option<String>,
+    pub currency: Option<String>,
 }
@@ -1,3 +1,5 @@
+mod amount_under_dispute;
+mod dispute_rate;
 mod dispute_status_metric;
 mod total_amount_disputed;
 mod total_dispute_lost_amount;
@@ -15,6 +17,7 @@ use diesel_models::enums as storage_enums;
 use time::PrimitiveDateTime;
 
 use self::{
+    amount_under_dispute::AmountUnderDispute, dispute_rate::DisputeRate,
     dispute_status_metric::DisputeStatusMetric, total_amount_disputed::TotalAmountDisputed,
     total_dispute_lost_amount::TotalDisputeLostAmount,
 };
@@ -27,8 +30,11 @@ pub struct DisputeMetricRow {
     pub dispute_stage: Option<DBEnumWrapper<storage_enums::DisputeStage>>,
     pub dispute_status: Option<DBEnumWrapper<storage_enums::DisputeStatus>>,
     pub connector: Option<String>,
+    pub currency: Option<DBEnumWrapper<storage_enums::Currency>>,
     pub total: Option<bigdecimal::BigDecimal>,
     pub count: Option<i64>,
+    /// Number of settled payments in the bucket, set only for the dispute rate
+    pub settled_payment_count: Option<i64>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub start_bucket: Option<PrimitiveDateTime>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
@@ -114,6 +120,30 @@ where
                     )
                     .await
             }
+            Self::DisputeRate => {
+                DisputeRate::default()
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::AmountUnderDispute => {
+                AmountUnderDispute::default()
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
         }
     }
 }
@@ -0,0 +1,226 @@
+use std::collections::HashMap;
+
+use api_models::analytics::{
+    disputes::{DisputeDimensions, DisputeFilters, DisputeMetricsBucketIdentifier},
+    Granularity, TimeRange,
+};
+use common_utils::errors::ReportSwitchExt;
+use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use super::DisputeMetricRow;
+use crate::{
+    query::{
+        Aggregate, GroupByClause, PostProcessingError, QueryBuilder, QueryFilter, SeriesBucket,
+        ToSql, Window,
+    },
+    types::{AnalyticsCollection, AnalyticsDataSource, MetricsError, MetricsResult},
+};
+
+/// Percentage of the settled payments which were disputed. The disputes created in a time bucket
+/// are compared with the payments settled in the same time bucket through the same connector and
+/// in the same currency, payments having no dispute stage.
+#[derive(Default)]
+pub(super) struct DisputeRate {}
+
+fn get_bucket_time_range(
+    granularity: &Option<Granularity>,
+    time_range: &TimeRange,
+    row: &DisputeMetricRow,
+) -> error_stack::Result<TimeRange, PostProcessingError> {
+    Ok(TimeRange {
+        start_time: match (granularity, row.start_bucket) {
+            (Some(g), Some(st)) => g.clip_to_start(st)?,
+            _ => time_range.start_time,
+        },
+        end_time: granularity.as_ref().map_or_else(
+            || Ok(time_range.end_time),
+            |g| row.end_bucket.map(|et| g.clip_to_end(et)).transpose(),
+        )?,
+    })
+}
+
+#[async_trait::async_trait]
+impl<T> super::DisputeMetric<T> for DisputeRate
+where
+    T: AnalyticsDataSource + super::DisputeMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[DisputeDimensions],
+        merchant_id: &str,
+        filters: &DisputeFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<Vec<(DisputeMetricsBucketIdentifier, DisputeMetricRow)>>
+    where
+        T: AnalyticsDataSource + super::DisputeMetricAnalytics,
+    {
+        let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::Dispute);
+
+        for dim in dimensions.iter() {
+            query_builder.add_select_column(dim).switch()?;
+        }
+
+        query_builder
+            .add_select_column(Aggregate::Count {
+                field: None,
+                alias: Some("count"),
+            })
+            .switch()?;
+        query_builder
+            .add_select_column(Aggregate::Min {
+                field: "created_at",
+                alias: Some("start_bucket"),
+            })
+            .switch()?;
+        query_builder
+            .add_select_column(Aggregate::Max {
+                field: "created_at",
+                alias: Some("end_bucket"),
+            })
+            .switch()?;
+
+        filters.set_filter_clause(&mut query_builder).switch()?;
+
+        query_builder
+            .add_filter_clause("merchant_id", merchant_id)
+            .switch()?;
+
+        time_range
+            .set_filter_clause(&mut query_builder)
+            .attach_printable("Error filtering time range")
+            .switch()?;
+
+        for dim in dimensions.iter() {
+            query_builder.add_group_by_clause(dim).switch()?;
+        }
+
+        if let Some(granularity) = granularity.as_ref() {
+            granularity
+                .set_group_by_clause(&mut query_builder)
+                .switch()?;
+        }
+
+        let disputes = query_builder
+            .execute_query::<DisputeMetricRow, _>(pool)
+            .await
+            .change_context(MetricsError::QueryBuildingError)?
+            .change_context(MetricsError::QueryExecutionFailure)?;
+
+        let payment_dimensions = dimensions
+            .iter()
+            .filter(|dim| **dim != DisputeDimensions::DisputeStage)
+            .collect::<Vec<_>>();
+        let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::Payment);
+
+        for dim in payment_dimensions.iter() {
+            query_builder.add_select_column(*dim).switch()?;
+        }
+
+        query_builder
+            .add_select_column(Aggregate::Count {
+                field: None,
+                alias: Some("settled_payment_count"),
+            })
+            .switch()?;
+        query_builder
+            .add_select_column(Aggregate::Min {
+                field: "created_at",
+                alias: Some("start_bucket"),
+            })
+            .switch()?;
+        query_builder
+            .add_select_column(Aggregate::Max {
+                field: "created_at",
+                alias: Some("end_bucket"),
+            })
+            .switch()?;
+
+        if !filters.connector.is_empty() {
+            query_builder
+                .add_filter_in_range_clause(DisputeDimensions::Connector, &filters.connector)
+                .switch()?;
+        }
+        if !filters.currency.is_empty() {
+            query_builder
+                .add_filter_in_range_clause(DisputeDimensions::Currency, &filters.currency)
+                .switch()?;
+        }
+
+        query_builder
+            .add_filter_clause("merchant_id", merchant_id)
+            .switch()?;
+        query_builder
+            .add_filter_clause("status", storage_enums::AttemptStatus::Charged)
+            .switch()?;
+
+        time_range
+            .set_filter_clause(&mut query_builder)
+            .attach_printable("Error filtering time range")
+            .switch()?;
+
+        for dim in payment_dimensions.iter() {
+            query_builder.add_group_by_clause(*dim).switch()?;
+        }
+
+        if let Some(granularity) = granularity.as_ref() {
+            granularity
+                .set_group_by_clause(&mut query_builder)
+                .switch()?;
+        }
+
+        let settled_payment_counts = query_builder
+            .execute_query::<DisputeMetricRow, _>(pool)
+            .await
+            .change_context(MetricsError::QueryBuildingError)?
+            .change_context(MetricsError::QueryExecutionFailure)?
+            .into_iter()
+            .map(|i| {
+                Ok((
+                    DisputeMetricsBucketIdentifier::new(
+                        None,
+                        i.connector.clone(),
+                        i.currency.as_ref().map(|i| i.0),
+                        get_bucket_time_range(granularity, time_range, &i)?,
+                    ),
+                    i.settled_payment_count,
+                ))
+            })
+            .collect::<error_stack::Result<HashMap<_, _>, PostProcessingError>>()
+            .change_context(MetricsError::PostProcessingFailure)?;
+
+        disputes
+            .into_iter()
+            .map(|mut i| {
+                let bucket_time_range = get_bucket_time_range(granularity, time_range, &i)?;
+                i.settled_payment_count = settled_payment_counts
+                    .get(&DisputeMetricsBucketIdentifier::new(
+                        None,
+                        i.connector.clone(),
+                        i.currency.as_ref().map(|i| i.0),
+                        bucket_time_range,
+                    ))
+                    .copied()
+                    .flatten();
+                Ok((
+                    DisputeMetricsBucketIdentifier::new(
+                        i.dispute_stage.as_ref().map(|i| i.0),
+                        i.connector.clone(),
+                        i.currency.as_ref().map(|i| i.0),
+                        bucket_time_range,
+                    ),
+                    i,
+                ))
+            })
+            .collect::<error_stack::Result<Vec<_>, PostProcessingError>>()
+            .change_context(MetricsError::PostProcessingFailure)
+    }
+}
@@ -10,6 +10,10 @@ pub enum AnalyticsError {
     NotImplemented(&'static str),
     #[error("Unknown Analytics Error")]
     UnknownError,
+    #[error("Invalid request: {0}")]
+    InvalidRequest(String),
+    #[error("Too many concurrent analytics queries")]
+    TooManyRequests,
 }
 
 impl ErrorSwitch<ApiErrorResponse> for AnalyticsError {
@@ -27,6 +31,15 @@ impl ErrorSwitch<ApiErrorResponse> for AnalyticsError {
                 "Something went wrong",
                 None,
             )),
+            Self::InvalidRequest(message) => {
+                ApiErrorResponse::BadRequest(ApiError::new("IR", 6, message.clone(), None))
+            }
+            Self::TooManyRequests => ApiErrorResponse::TooManyRequests(ApiError::new(
+                "IR",
+                7,
+                "Too many analytics queries are in progress, please try again later",
+                None,
+            )),
         }
     }
 }
@@ -0,0 +1,313 @@
+//! Export of the results of metric queries in the formats ingested by BI tools.
+//!
+//! Each bucket of the results is serialized to a JSON object and written as a row. Nested objects
+//! are flattened into columns named by joining the keys with a `.`, and arrays are written as JSON
+//! strings. The columns are the union of the keys of all the buckets, a bucket without a key has
+//! an empty value in its column.
+
+use std::{collections::HashMap, sync::Arc};
+
+use error_stack::{IntoReport, ResultExt};
+use parquet::{
+    basic::{LogicalType, Repetition, Type as PhysicalType},
+    data_type::{BoolType, ByteArray, ByteArrayType, DoubleType, Int64Type},
+    file::{properties::WriterProperties, writer::SerializedFileWriter},
+    schema::types::Type,
+};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::errors::{AnalyticsError, AnalyticsResult};
+
+struct ExportTable {
+    columns: Vec<String>,
+    rows: Vec<HashMap<String, Value>>,
+}
+
+impl ExportTable {
+    fn new<T: Serialize>(buckets: &[T]) -> AnalyticsResult<Self> {
+        let mut table = Self {
+            columns: Vec::new(),
+            rows: Vec::with_capacity(buckets.len()),
+        };
+
+        for bucket in buckets {
+            let value = serde_json::to_value(bucket)
+                .into_report()
+                .change_context(AnalyticsError::UnknownError)
+                .attach_printable("Failed to serialize metrics bucket")?;
+            let mut row = HashMap::new();
+            table.flatten_into_row(None, value, &mut row);
+            table.rows.push(row);
+        }
+
+        Ok(table)
+    }
+
+    fn flatten_into_row(
+        &mut self,
+        key: Option<String>,
+        value: Value,
+        row: &mut HashMap<String, Value>,
+    ) {
+        match value {
+            Value::Object(fields) => {
+                for (field, value) in fields {
+                    let key = match &key {
+                        Some(prefix) => format!("{prefix}.{field}"),
+                        None => field,
+                    };
+                    self.flatten_into_row(Some(key), value, row);
+                }
+            }
+            value => {
+                let key = key.unwrap_or_else(|| "value".to_string());
+                if !self.columns.contains(&key) {
+                    self.columns.push(key.clone());
+                }
+                row.insert(key, value);
+            }
+        }
+    }
+
+    fn get_column_values<'a>(
+        &'a self,
+        column: &'a str,
+    ) -> impl Iterator<Item = Option<&'a Value>> + Clone + 'a {
+        self.rows
+            .iter()
+            .map(move |row| row.get(column).filter(|value| !value.is_null()))
+    }
+}
+
+fn get_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        value => value.to_string(),
+    }
+}
+
+/// Quotes a field if it contains a separator, a quote or a line break
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Writes the buckets as a CSV file with a header line
+pub fn to_csv<T: Serialize>(buckets: &[T]) -> AnalyticsResult<Vec<u8>> {
+    let table = ExportTable::new(buckets)?;
+
+    let mut lines = Vec::with_capacity(table.rows.len().saturating_add(1));
+    lines.push(
+        table
+            .columns
+            .iter()
+            .map(|column| escape_csv_field(column))
+            .collect::<Vec<_>>()
+            .join(","),
+    );
+    for row in &table.rows {
+        lines.push(
+            table
+                .columns
+                .iter()
+                .map(|column| {
+                    row.get(column)
+                        .filter(|value| !value.is_null())
+                        .map(|value| escape_csv_field(&get_text(value)))
+                        .unwrap_or_default()
+                })
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+    }
+
+    let mut csv = lines.join("\n");
+    csv.push('\n');
+    Ok(csv.into_bytes())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ColumnType {
+    Boolean,
+    Int64,
+    Double,
+    Utf8,
+}
+
+/// Picks the narrowest type the values of a column can be written as, integers are widened to
+/// doubles when the column has both, and any other mix of types is written as text
+fn get_column_type<'a>(values: impl Iterator<Item = Option<&'a Value>>) -> ColumnType {
+    values
+        .flatten()
+        .map(|value| match value {
+            Value::Bool(_) => ColumnType::Boolean,
+            Value::Number(number) if number.is_i64() => ColumnType::Int64,
+            Value::Number(_) => ColumnType::Double,
+            _ => ColumnType::Utf8,
+        })
+        .reduce(|column_type, value_type| match (column_type, value_type) {
+            (column_type, value_type) if column_type == value_type => column_type,
+            (ColumnType::Int64, ColumnType::Double) | (ColumnType::Double, ColumnType::Int64) => {
+                ColumnType::Double
+            }
+            _ => ColumnType::Utf8,
+        })
+        .unwrap_or(ColumnType::Utf8)
+}
+
+fn get_parquet_field(name: &str, column_type: ColumnType) -> parquet::errors::Result<Type> {
+    let (physical_type, logical_type) = match column_type {
+        ColumnType::Boolean => (PhysicalType::BOOLEAN, None),
+        ColumnType::Int64 => (PhysicalType::INT64, None),
+        ColumnType::Double => (PhysicalType::DOUBLE, None),
+        ColumnType::Utf8 => (PhysicalType::BYTE_ARRAY, Some(LogicalType::String)),
+    };
+
+    Type::primitive_type_builder(name, physical_type)
+        .with_repetition(Repetition::OPTIONAL)
+        .with_logical_type(logical_type)
+        .build()
+}
+
+/// Writes the buckets as a Parquet file with a single row group
+pub fn to_parquet<T: Serialize>(buckets: &[T]) -> AnalyticsResult<Vec<u8>> {
+    write_parquet(&ExportTable::new(buckets)?)
+        .into_report()
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to write metrics as parquet")
+}
+
+fn write_parquet(table: &ExportTable) -> parquet::errors::Result<Vec<u8>> {
+    let column_types = table
+        .columns
+        .iter()
+        .map(|column| get_column_type(table.get_column_values(column)))
+        .collect::<Vec<_>>();
+
+    let fields = table
+        .columns
+        .iter()
+        .zip(&column_types)
+        .map(|(column, column_type)| get_parquet_field(column, *column_type).map(Arc::new))
+        .collect::<parquet::errors::Result<Vec<_>>>()?;
+    let schema = Type::group_type_builder("metrics")
+        .with_fields(fields)
+        .build()?;
+
+    let mut writer = SerializedFileWriter::new(
+        Vec::new(),
+        Arc::new(schema),
+        Arc::new(WriterProperties::builder().build()),
+    )?;
+    let mut row_group_writer = writer.next_row_group()?;
+
+    for (column, column_type) in table.columns.iter().zip(column_types) {
+        let Some(mut column_writer) = row_group_writer.next_column()? else {
+            break;
+        };
+        let values = table.get_column_values(column);
+        let definition_levels = values
+            .clone()
+            .map(|value| i16::from(value.is_some()))
+            .collect::<Vec<_>>();
+        let values = values.flatten();
+
+        match column_type {
+            ColumnType::Boolean => column_writer.typed::<BoolType>().write_batch(
+                &values.filter_map(Value::as_bool).collect::<Vec<_>>(),
+                Some(&definition_levels),
+                None,
+            ),
+            ColumnType::Int64 => column_writer.typed::<Int64Type>().write_batch(
+                &values.filter_map(Value::as_i64).collect::<Vec<_>>(),
+                Some(&definition_levels),
+                None,
+            ),
+            ColumnType::Double => column_writer.typed::<DoubleType>().write_batch(
+                &values.filter_map(Value::as_f64).collect::<Vec<_>>(),
+                Some(&definition_levels),
+                None,
+            ),
+            ColumnType::Utf8 => column_writer.typed::<ByteArrayType>().write_batch(
+                &values
+                    .map(|value| ByteArray::from(get_text(value).into_bytes()))
+                    .collect::<Vec<_>>(),
+                Some(&definition_levels),
+                None,
+            ),
+        }?;
+        column_writer.close()?;
+    }
+
+    row_group_writer.close()?;
+    writer.into_inner()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    // The keys are in alphabetical order, so that the order of the columns does not depend on
+    // whether the `preserve_order` feature of `serde_json` is enabled
+    fn get_buckets() -> Vec<Value> {
+        vec![
+            serde_json::json!({
+                "connector": "stripe",
+                "payment_count": 10,
+                "time_range": {"end_time": null, "start_time": "2024-04-01T00:00:00"},
+            }),
+            serde_json::json!({
+                "connector": "adyen, eu",
+                "payment_count": null,
+                "success_rate": 92.5,
+            }),
+        ]
+    }
+
+    #[test]
+    fn test_metrics_are_flattened_into_csv() {
+        let csv = String::from_utf8(to_csv(&get_buckets()).unwrap()).unwrap();
+
+        assert_eq!(
+            csv,
+            "connector,payment_count,time_range.end_time,time_range.start_time,success_rate\n\
+             stripe,10,,2024-04-01T00:00:00,\n\
+             \"adyen, eu\",,,,92.5\n"
+        );
+    }
+
+    #[test]
+    fn test_column_types() {
+        let int = serde_json::json!(1);
+        let float = serde_json::json!(1.5);
+        let text = serde_json::json!("1");
+
+        assert_eq!(
+            get_column_type([Some(&int), None].into_iter()),
+            ColumnType::Int64
+        );
+        assert_eq!(
+            get_column_type([Some(&int), Some(&float)].into_iter()),
+            ColumnType::Double
+        );
+        assert_eq!(
+            get_column_type([Some(&int), Some(&text)].into_iter()),
+            ColumnType::Utf8
+        );
+        assert_eq!(get_column_type([None].into_iter()), ColumnType::Utf8);
+    }
+
+    #[test]
+    fn test_metrics_are_written_as_parquet() {
+        let parquet = to_parquet(&get_buckets()).unwrap();
+
+        assert!(parquet.starts_with(b"PAR1"));
+        assert!(parquet.ends_with(b"PAR1"));
+    }
+}
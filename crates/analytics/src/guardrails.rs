@@ -0,0 +1,187 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use api_models::analytics::{Granularity, TimeRange};
+use error_stack::IntoReport;
+use router_env::logger;
+
+use crate::errors::{AnalyticsError, AnalyticsResult};
+
+#[derive(Clone, Debug, serde::Deserialize)]
+#[serde(default)]
+pub struct AnalyticsGuardrailsConfig {
+    /// Queries taking longer than this are explained and their query plan is logged, query plans
+    /// are not captured if this is not set
+    pub explain_latency_threshold_in_ms: Option<u64>,
+    /// Maximum number of analytics queries a merchant can have in flight at any point of time on
+    /// each router instance, the limit is not shared across instances
+    pub max_concurrent_queries_per_merchant: usize,
+    /// Maximum time range which can be queried for each granularity
+    pub max_time_range_in_hours: MaxTimeRangeInHours,
+}
+
+impl AnalyticsGuardrailsConfig {
+    pub fn validate(&self) -> Result<(), &'static str> {
+        use common_utils::fp_utils::when;
+
+        when(self.max_concurrent_queries_per_merchant == 0, || {
+            Err("analytics guardrails max_concurrent_queries_per_merchant must be greater than 0")
+        })
+    }
+}
+
+impl Default for AnalyticsGuardrailsConfig {
+    fn default() -> Self {
+        Self {
+            explain_latency_threshold_in_ms: None,
+            max_concurrent_queries_per_merchant: 10,
+            max_time_range_in_hours: MaxTimeRangeInHours::default(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, serde::Deserialize)]
+#[serde(default)]
+pub struct MaxTimeRangeInHours {
+    pub one_min: u32,
+    pub five_min: u32,
+    pub fifteen_min: u32,
+    pub thirty_min: u32,
+    pub one_hour: u32,
+    pub one_day: u32,
+    pub one_week: u32,
+}
+
+impl Default for MaxTimeRangeInHours {
+    fn default() -> Self {
+        Self {
+            // 1 day
+            one_min: 24,
+            // 3 days
+            five_min: 72,
+            // 7 days
+            fifteen_min: 168,
+            // 14 days
+            thirty_min: 336,
+            // 31 days
+            one_hour: 744,
+            // 366 days
+            one_day: 8784,
+            // 1827 days
+            one_week: 43848,
+        }
+    }
+}
+
+impl MaxTimeRangeInHours {
+    fn get(&self, granularity: &Granularity) -> u32 {
+        match granularity {
+            Granularity::OneMin => self.one_min,
+            Granularity::FiveMin => self.five_min,
+            Granularity::FifteenMin => self.fifteen_min,
+            Granularity::ThirtyMin => self.thirty_min,
+            Granularity::OneHour => self.one_hour,
+            Granularity::OneDay => self.one_day,
+            Granularity::OneWeek => self.one_week,
+        }
+    }
+}
+
+/// Protects the shared analytics infrastructure from pathological requests, the state is shared
+/// across all the clones of the analytics provider. The in flight queries are tracked in memory, so
+/// the concurrency limit applies per process: a deployment running `n` router instances allows up
+/// to `n` times the configured number of concurrent queries for a merchant.
+#[derive(Clone, Debug, Default)]
+pub struct AnalyticsGuardrails {
+    config: Arc<AnalyticsGuardrailsConfig>,
+    in_flight_queries: Arc<Mutex<HashMap<String, usize>>>,
+}
+
+impl AnalyticsGuardrails {
+    pub fn new(config: AnalyticsGuardrailsConfig) -> Self {
+        Self {
+            config: Arc::new(config),
+            in_flight_queries: Arc::default(),
+        }
+    }
+
+    pub fn explain_latency_threshold(&self) -> Option<Duration> {
+        self.config
+            .explain_latency_threshold_in_ms
+            .map(Duration::from_millis)
+    }
+
+    /// Rejects time ranges larger than the one allowed for the requested granularity, an open
+    /// ended time range is considered to end at the current time
+    pub fn validate_time_range(
+        &self,
+        granularity: Option<&Granularity>,
+        time_range: &TimeRange,
+    ) -> AnalyticsResult<()> {
+        let Some(granularity) = granularity else {
+            return Ok(());
+        };
+        let max_hours = self.config.max_time_range_in_hours.get(granularity);
+        let end_time = time_range
+            .end_time
+            .unwrap_or_else(common_utils::date_time::now);
+
+        if end_time - time_range.start_time > time::Duration::hours(i64::from(max_hours)) {
+            return Err(AnalyticsError::InvalidRequest(format!(
+                "Time range cannot exceed {max_hours} hours for granularity {granularity:?}"
+            )))
+            .into_report();
+        }
+        Ok(())
+    }
+
+    /// Reserves a slot for a query of the merchant in this process, the slot is released once the
+    /// permit is dropped. Every analytics query path is expected to hold a permit while querying.
+    pub fn acquire_query_permit(&self, merchant_id: &str) -> AnalyticsResult<QueryPermit> {
+        let mut in_flight_queries = self
+            .in_flight_queries
+            .lock()
+            .map_err(|_| AnalyticsError::UnknownError)
+            .into_report()?;
+        let count = in_flight_queries
+            .entry(merchant_id.to_string())
+            .or_default();
+
+        if *count >= self.config.max_concurrent_queries_per_merchant {
+            logger::warn!(
+                merchant_id,
+                in_flight_queries = *count,
+                "Rejecting analytics query, concurrent query limit reached"
+            );
+            return Err(AnalyticsError::TooManyRequests).into_report();
+        }
+        *count = count.saturating_add(1);
+
+        Ok(QueryPermit {
+            merchant_id: merchant_id.to_string(),
+            in_flight_queries: self.in_flight_queries.clone(),
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct QueryPermit {
+    merchant_id: String,
+    in_flight_queries: Arc<Mutex<HashMap<String, usize>>>,
+}
+
+impl Drop for QueryPermit {
+    fn drop(&mut self) {
+        if let Ok(mut in_flight_queries) = self.in_flight_queries.lock() {
+            if let Some(count) = in_flight_queries.get_mut(&self.merchant_id) {
+                *count = count.saturating_sub(1);
+                if *count == 0 {
+                    in_flight_queries.remove(&self.merchant_id);
+                }
+            }
+        }
+    }
+}
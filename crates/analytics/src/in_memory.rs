@@ -0,0 +1,170 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use api_models::analytics::Granularity;
+use common_utils::errors::{CustomResult, ParsingError};
+use error_stack::{Report, ResultExt};
+use time::PrimitiveDateTime;
+
+use crate::{
+    clickhouse::ClickhouseClient,
+    query::{Aggregate, GroupByClause, QueryBuilder, QueryParam, QueryResult, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, LoadRow, QueryExecutionError, TableEngine},
+};
+
+/// An analytics data source which serves fixture rows from memory instead of running the query
+/// against a database, for unit testing metric implementations.
+///
+/// Queries are built using the clickhouse dialect, every executed query is recorded so that the
+/// query building can be asserted upon along with the post processing of the returned rows.
+#[derive(Debug, Default)]
+pub struct InMemoryClient {
+    fixtures: HashMap<String, Vec<serde_json::Value>>,
+    executed_queries: Mutex<Vec<ExecutedQuery>>,
+}
+
+/// A query executed against the in memory data source, along with the values bound to it
+#[derive(Debug, Clone)]
+pub struct ExecutedQuery {
+    pub query: String,
+    pub params: Vec<QueryParam>,
+}
+
+impl InMemoryClient {
+    /// Adds rows which are returned for every query made against the given collection
+    pub fn with_rows(
+        mut self,
+        collection: AnalyticsCollection,
+        rows: impl IntoIterator<Item = serde_json::Value>,
+    ) -> Self {
+        self.fixtures
+            .entry(get_table_name(collection))
+            .or_default()
+            .extend(rows);
+        self
+    }
+
+    /// Returns the queries executed against this data source, in the order they were made
+    pub fn executed_queries(&self) -> Vec<ExecutedQuery> {
+        self.executed_queries
+            .lock()
+            .map(|queries| queries.clone())
+            .unwrap_or_default()
+    }
+
+    fn get_fixture_rows(&self, query: &str) -> Vec<serde_json::Value> {
+        query
+            .split_once(" FROM ")
+            .and_then(|(_, rest)| rest.split_whitespace().next())
+            .and_then(|table| self.fixtures.get(table))
+            .cloned()
+            .unwrap_or_default()
+    }
+}
+
+fn get_table_name(collection: AnalyticsCollection) -> String {
+    <AnalyticsCollection as ToSql<ClickhouseClient>>::to_sql(&collection, &TableEngine::BasicTree)
+        .unwrap_or_else(|_| collection.to_string())
+}
+
+#[async_trait::async_trait]
+impl AnalyticsDataSource for InMemoryClient {
+    type Row = serde_json::Value;
+
+    async fn load_results<T>(
+        &self,
+        query: &str,
+        params: &[QueryParam],
+    ) -> CustomResult<Vec<T>, QueryExecutionError>
+    where
+        Self: LoadRow<T>,
+    {
+        self.executed_queries
+            .lock()
+            .map_err(|_| Report::new(QueryExecutionError::DatabaseError))
+            .attach_printable("Failed to record executed query")?
+            .push(ExecutedQuery {
+                query: query.to_string(),
+                params: params.to_vec(),
+            });
+
+        self.get_fixture_rows(query)
+            .into_iter()
+            .map(Self::load_row)
+            .collect::<Result<Vec<_>, _>>()
+            .change_context(QueryExecutionError::RowExtractionFailure)
+    }
+
+    fn get_placeholder(index: usize, param: &QueryParam) -> String {
+        <ClickhouseClient as AnalyticsDataSource>::get_placeholder(index, param)
+    }
+}
+
+impl<T, E> LoadRow<T> for InMemoryClient
+where
+    Self::Row: TryInto<T, Error = Report<E>>,
+{
+    fn load_row(row: Self::Row) -> CustomResult<T, QueryExecutionError> {
+        row.try_into()
+            .change_context(QueryExecutionError::RowExtractionFailure)
+    }
+}
+
+impl crate::payments::filters::PaymentFilterAnalytics for InMemoryClient {}
+impl crate::payments::metrics::PaymentMetricAnalytics for InMemoryClient {}
+impl crate::payments::distribution::PaymentDistributionAnalytics for InMemoryClient {}
+impl crate::refunds::metrics::RefundMetricAnalytics for InMemoryClient {}
+impl crate::refunds::filters::RefundFilterAnalytics for InMemoryClient {}
+impl crate::sdk_events::filters::SdkEventFilterAnalytics for InMemoryClient {}
+impl crate::sdk_events::metrics::SdkEventMetricAnalytics for InMemoryClient {}
+impl crate::api_event::filters::ApiEventFilterAnalytics for InMemoryClient {}
+impl crate::api_event::metrics::ApiEventMetricAnalytics for InMemoryClient {}
+impl crate::disputes::filters::DisputeFilterAnalytics for InMemoryClient {}
+impl crate::disputes::metrics::DisputeMetricAnalytics for InMemoryClient {}
+
+impl ToSql<InMemoryClient> for PrimitiveDateTime {
+    fn to_sql(&self, table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
+        <Self as ToSql<ClickhouseClient>>::to_sql(self, table_engine)
+    }
+}
+
+impl ToSql<InMemoryClient> for AnalyticsCollection {
+    fn to_sql(&self, table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
+        <Self as ToSql<ClickhouseClient>>::to_sql(self, table_engine)
+    }
+}
+
+impl<T> ToSql<InMemoryClient> for Aggregate<T>
+where
+    T: ToSql<ClickhouseClient>,
+{
+    fn to_sql(&self, table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
+        <Self as ToSql<ClickhouseClient>>::to_sql(self, table_engine)
+    }
+}
+
+impl<T> ToSql<InMemoryClient> for Window<T>
+where
+    T: ToSql<ClickhouseClient>,
+{
+    fn to_sql(&self, table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
+        <Self as ToSql<ClickhouseClient>>::to_sql(self, table_engine)
+    }
+}
+
+impl GroupByClause<InMemoryClient> for Granularity {
+    fn set_group_by_clause(&self, builder: &mut QueryBuilder<InMemoryClient>) -> QueryResult<()> {
+        let interval = match self {
+            Self::OneMin => "toStartOfMinute(created_at)",
+            Self::FiveMin => "toStartOfFiveMinutes(created_at)",
+            Self::FifteenMin => "toStartOfFifteenMinutes(created_at)",
+            Self::ThirtyMin => "toStartOfInterval(created_at, INTERVAL 30 minute)",
+            Self::OneHour => "toStartOfHour(created_at)",
+            Self::OneDay => "toStartOfDay(created_at)",
+            Self::OneWeek => "toStartOfWeek(created_at, 1)",
+        };
+
+        builder
+            .add_group_by_clause(interval)
+            .attach_printable("Error adding interval group by")
+    }
+}
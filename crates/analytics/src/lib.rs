@@ -1,11 +1,18 @@
+pub mod auth_events;
+pub mod cache;
 mod clickhouse;
 pub mod core;
 pub mod disputes;
 pub mod errors;
+pub mod export;
+pub mod guardrails;
+#[cfg(test)]
+mod in_memory;
 pub mod metrics;
 pub mod payments;
 mod query;
 pub mod refunds;
+pub mod reporting_currency;
 
 pub mod api_event;
 pub mod connector_events;
@@ -16,6 +23,7 @@ pub mod search;
 mod sqlx;
 mod types;
 use api_event::metrics::{ApiEventMetric, ApiEventMetricRow};
+use auth_events::metrics::{AuthEventMetric, AuthEventMetricRow};
 use common_utils::errors::CustomResult;
 use disputes::metrics::{DisputeMetric, DisputeMetricRow};
 use hyperswitch_interfaces::secrets_interface::{
@@ -33,6 +41,9 @@ use api_models::analytics::{
     api_event::{
         ApiEventDimensions, ApiEventFilters, ApiEventMetrics, ApiEventMetricsBucketIdentifier,
     },
+    auth_events::{
+        AuthEventDimensions, AuthEventFilters, AuthEventMetrics, AuthEventMetricsBucketIdentifier,
+    },
     disputes::{DisputeDimensions, DisputeFilters, DisputeMetrics, DisputeMetricsBucketIdentifier},
     payments::{PaymentDimensions, PaymentFilters, PaymentMetrics, PaymentMetricsBucketIdentifier},
     refunds::{RefundDimensions, RefundFilters, RefundMetrics, RefundMetricsBucketIdentifier},
@@ -41,9 +52,11 @@ use api_models::analytics::{
     },
     Distribution, Granularity, TimeRange,
 };
+use cache::AnalyticsCache;
 use clickhouse::ClickhouseClient;
 pub use clickhouse::ClickhouseConfig;
 use error_stack::IntoReport;
+use guardrails::{AnalyticsGuardrails, AnalyticsGuardrailsConfig};
 use router_env::{
     logger,
     tracing::{self, instrument},
@@ -92,7 +105,7 @@ impl AnalyticsProvider {
         &self,
         metric: &PaymentMetrics,
         dimensions: &[PaymentDimensions],
-        merchant_id: &str,
+        merchant_ids: &[String],
         filters: &PaymentFilters,
         granularity: &Option<Granularity>,
         time_range: &TimeRange,
@@ -105,7 +118,7 @@ impl AnalyticsProvider {
                         metric
                             .load_metrics(
                                 dimensions,
-                                merchant_id,
+                                merchant_ids,
                                 filters,
                                 granularity,
                                 time_range,
@@ -117,7 +130,7 @@ impl AnalyticsProvider {
                         metric
                             .load_metrics(
                                 dimensions,
-                                merchant_id,
+                                merchant_ids,
                                 filters,
                                 granularity,
                                 time_range,
@@ -129,7 +142,7 @@ impl AnalyticsProvider {
                         let (ckh_result, sqlx_result) = tokio::join!(metric
                             .load_metrics(
                                 dimensions,
-                                merchant_id,
+                                merchant_ids,
                                 filters,
                                 granularity,
                                 time_range,
@@ -138,7 +151,7 @@ impl AnalyticsProvider {
                             metric
                             .load_metrics(
                                 dimensions,
-                                merchant_id,
+                                merchant_ids,
                                 filters,
                                 granularity,
                                 time_range,
@@ -158,7 +171,7 @@ impl AnalyticsProvider {
                         let (ckh_result, sqlx_result) = tokio::join!(metric
                             .load_metrics(
                                 dimensions,
-                                merchant_id,
+                                merchant_ids,
                                 filters,
                                 granularity,
                                 time_range,
@@ -167,7 +180,7 @@ impl AnalyticsProvider {
                             metric
                             .load_metrics(
                                 dimensions,
-                                merchant_id,
+                                merchant_ids,
                                 filters,
                                 granularity,
                                 time_range,
@@ -196,7 +209,7 @@ impl AnalyticsProvider {
         &self,
         distribution: &Distribution,
         dimensions: &[PaymentDimensions],
-        merchant_id: &str,
+        merchant_ids: &[String],
         filters: &PaymentFilters,
         granularity: &Option<Granularity>,
         time_range: &TimeRange,
@@ -210,7 +223,7 @@ impl AnalyticsProvider {
                             .load_distribution(
                                 distribution,
                                 dimensions,
-                                merchant_id,
+                                merchant_ids,
                                 filters,
                                 granularity,
                                 time_range,
@@ -223,7 +236,7 @@ impl AnalyticsProvider {
                             .load_distribution(
                                 distribution,
                                 dimensions,
-                                merchant_id,
+                                merchant_ids,
                                 filters,
                                 granularity,
                                 time_range,
@@ -236,7 +249,7 @@ impl AnalyticsProvider {
                             .load_distribution(
                                 distribution,
                                 dimensions,
-                                merchant_id,
+                                merchant_ids,
                                 filters,
                                 granularity,
                                 time_range,
@@ -246,7 +259,7 @@ impl AnalyticsProvider {
                             .load_distribution(
                                 distribution,
                                 dimensions,
-                                merchant_id,
+                                merchant_ids,
                                 filters,
                                 granularity,
                                 time_range,
@@ -267,7 +280,7 @@ impl AnalyticsProvider {
                             .load_distribution(
                                 distribution,
                                 dimensions,
-                                merchant_id,
+                                merchant_ids,
                                 filters,
                                 granularity,
                                 time_range,
@@ -277,7 +290,7 @@ impl AnalyticsProvider {
                             .load_distribution(
                                 distribution,
                                 dimensions,
-                                merchant_id,
+                                merchant_ids,
                                 filters,
                                 granularity,
                                 time_range,
@@ -534,6 +547,35 @@ impl AnalyticsProvider {
         }
     }
 
+    pub async fn get_auth_event_metrics(
+        &self,
+        metric: &AuthEventMetrics,
+        dimensions: &[AuthEventDimensions],
+        merchant_id: &str,
+        filters: &AuthEventFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+    ) -> types::MetricsResult<Vec<(AuthEventMetricsBucketIdentifier, AuthEventMetricRow)>> {
+        match self {
+            Self::Clickhouse(_pool) => Err(MetricsError::NotImplemented).into_report(),
+            Self::Sqlx(sqlx_pool)
+            | Self::CombinedCkh(sqlx_pool, _)
+            | Self::CombinedSqlx(sqlx_pool, _) => {
+                // Since authentications are not available in ckh use sqlx here
+                metric
+                    .load_metrics(
+                        dimensions,
+                        merchant_id,
+                        filters,
+                        granularity,
+                        time_range,
+                        sqlx_pool,
+                    )
+                    .await
+            }
+        }
+    }
+
     pub async fn get_api_event_metrics(
         &self,
         metric: &ApiEventMetrics,
@@ -563,26 +605,60 @@ impl AnalyticsProvider {
         }
     }
 
-    pub async fn from_conf(config: &AnalyticsConfig) -> Self {
+    pub async fn from_conf(
+        config: &AnalyticsConfig,
+        guardrails_config: &AnalyticsGuardrailsConfig,
+        cache: AnalyticsCache,
+    ) -> Self {
+        let guardrails = AnalyticsGuardrails::new(guardrails_config.clone());
         match config {
-            AnalyticsConfig::Sqlx { sqlx } => Self::Sqlx(SqlxClient::from_conf(sqlx).await),
+            AnalyticsConfig::Sqlx { sqlx } => {
+                Self::Sqlx(SqlxClient::from_conf(sqlx, guardrails, cache).await)
+            }
             AnalyticsConfig::Clickhouse { clickhouse } => Self::Clickhouse(ClickhouseClient {
                 config: Arc::new(clickhouse.clone()),
+                guardrails,
+                cache,
             }),
             AnalyticsConfig::CombinedCkh { sqlx, clickhouse } => Self::CombinedCkh(
-                SqlxClient::from_conf(sqlx).await,
+                SqlxClient::from_conf(sqlx, guardrails.clone(), cache.clone()).await,
                 ClickhouseClient {
                     config: Arc::new(clickhouse.clone()),
+                    guardrails,
+                    cache,
                 },
             ),
             AnalyticsConfig::CombinedSqlx { sqlx, clickhouse } => Self::CombinedSqlx(
-                SqlxClient::from_conf(sqlx).await,
+                SqlxClient::from_conf(sqlx, guardrails.clone(), cache.clone()).await,
                 ClickhouseClient {
                     config: Arc::new(clickhouse.clone()),
+                    guardrails,
+                    cache,
                 },
             ),
         }
     }
+
+    /// The guardrails protecting the analytics data sources, shared by all the clients of the
+    /// provider
+    pub fn guardrails(&self) -> &AnalyticsGuardrails {
+        match self {
+            Self::Sqlx(pool) | Self::CombinedCkh(pool, _) | Self::CombinedSqlx(pool, _) => {
+                pool.guardrails()
+            }
+            Self::Clickhouse(ckh_pool) => &ckh_pool.guardrails,
+        }
+    }
+
+    /// The cache of metric query results, shared by all the clients of the provider
+    pub fn cache(&self) -> &AnalyticsCache {
+        match self {
+            Self::Sqlx(pool) | Self::CombinedCkh(pool, _) | Self::CombinedSqlx(pool, _) => {
+                pool.cache()
+            }
+            Self::Clickhouse(ckh_pool) => &ckh_pool.cache,
+        }
+    }
 }
 
 #[derive(Clone, Debug, serde::Deserialize)]
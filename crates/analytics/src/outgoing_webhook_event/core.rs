@@ -10,6 +10,7 @@ pub async fn outgoing_webhook_events_core(
     req: OutgoingWebhookLogsRequest,
     merchant_id: String,
 ) -> AnalyticsResult<Vec<OutgoingWebhookLogsResult>> {
+    let _query_permit = pool.guardrails().acquire_query_permit(&merchant_id)?;
     let data = match pool {
         AnalyticsProvider::Sqlx(_) => Err(FiltersError::NotImplemented(
             "Outgoing Webhook Events Logs not implemented for SQLX",
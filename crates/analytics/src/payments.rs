@@ -1,4 +1,5 @@
 pub mod accumulator;
+pub mod connector_uptime;
 mod core;
 pub mod distribution;
 pub mod filters;
@@ -13,4 +14,4 @@ pub trait PaymentAnalytics:
 {
 }
 
-pub use self::core::{get_filters, get_metrics};
+pub use self::core::{get_connector_uptime, get_filters, get_metrics};
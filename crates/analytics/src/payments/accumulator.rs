@@ -1,4 +1,7 @@
-use api_models::analytics::payments::{ErrorResult, PaymentMetricsBucketValue};
+use api_models::analytics::payments::{
+    AmountBucketResult, ErrorResult, PaymentFunnel, PaymentFunnelDropOff, PaymentFunnelStage,
+    PaymentMetricsBucketValue,
+};
 use bigdecimal::ToPrimitive;
 use diesel_models::enums as storage_enums;
 use router_env::logger;
@@ -13,9 +16,20 @@ pub struct PaymentMetricsAccumulator {
     pub processed_amount: SumAccumulator,
     pub avg_ticket_size: AverageAccumulator,
     pub payment_error_message: ErrorDistributionAccumulator,
+    pub amount_distribution: AmountDistributionAccumulator,
     pub retries_count: CountAccumulator,
     pub retries_amount_processed: SumAccumulator,
     pub connector_success_rate: SuccessRateAccumulator,
+    pub avg_retry_depth: RetryDepthAccumulator,
+    pub retry_attempt_success_count: CountAccumulator,
+    pub surcharge_amount: SumAccumulator,
+    pub tax_on_surcharge_amount: SumAccumulator,
+    pub blocklist_hit_count: CountAccumulator,
+    pub payment_funnel: PaymentFunnelAccumulator,
+    pub retried_attempt_count: CountAccumulator,
+    pub retry_success_count: CountAccumulator,
+    pub retry_recovered_amount: SumAccumulator,
+    pub refunded_payment_count: CountAccumulator,
 }
 
 #[derive(Debug, Default)]
@@ -30,6 +44,11 @@ pub struct ErrorDistributionAccumulator {
     pub error_vec: Vec<ErrorDistributionRow>,
 }
 
+#[derive(Debug, Default)]
+pub struct AmountDistributionAccumulator {
+    pub buckets: Vec<AmountBucketResult>,
+}
+
 #[derive(Debug, Default)]
 pub struct SuccessRateAccumulator {
     pub success: i64,
@@ -54,6 +73,21 @@ pub struct AverageAccumulator {
     pub count: u32,
 }
 
+#[derive(Debug, Default)]
+pub struct PaymentFunnelAccumulator {
+    pub created: i64,
+    pub customer_action_required: i64,
+    pub processing: i64,
+    pub succeeded: i64,
+    pub drop_offs: Vec<(PaymentFunnelStage, storage_enums::AttemptStatus, i64)>,
+}
+
+#[derive(Debug, Default)]
+pub struct RetryDepthAccumulator {
+    pub total_attempts: u32,
+    pub payments: u32,
+}
+
 pub trait PaymentMetricAccumulator {
     type MetricOutput;
 
@@ -107,6 +141,29 @@ impl PaymentDistributionAccumulator for ErrorDistributionAccumulator {
     }
 }
 
+impl PaymentDistributionAccumulator for AmountDistributionAccumulator {
+    type DistributionOutput = Option<Vec<AmountBucketResult>>;
+
+    fn add_distribution_bucket(&mut self, distribution: &PaymentDistributionRow) {
+        if let Some(start) = distribution.amount_bucket_start {
+            self.buckets.push(AmountBucketResult {
+                start,
+                end: distribution.amount_bucket_end,
+                count: distribution.count.unwrap_or_default(),
+            })
+        }
+    }
+
+    fn collect(mut self) -> Self::DistributionOutput {
+        if self.buckets.is_empty() {
+            None
+        } else {
+            self.buckets.sort_by_key(|bucket| bucket.start);
+            Some(self.buckets)
+        }
+    }
+}
+
 impl PaymentMetricAccumulator for SuccessRateAccumulator {
     type MetricOutput = Option<f64>;
 
@@ -199,6 +256,148 @@ impl PaymentMetricAccumulator for AverageAccumulator {
     }
 }
 
+impl PaymentMetricAccumulator for RetryDepthAccumulator {
+    type MetricOutput = Option<f64>;
+
+    fn add_metrics_bucket(&mut self, metrics: &PaymentMetricRow) {
+        let attempt_count = metrics
+            .attempt_count
+            .and_then(|attempts| u32::try_from(attempts).ok());
+        let count = metrics.count.and_then(|count| u32::try_from(count).ok());
+
+        match (attempt_count, count) {
+            (Some(attempt_count), Some(count)) => {
+                self.total_attempts += attempt_count * count;
+                self.payments += count;
+            }
+            _ => {
+                logger::error!(message="Dropping metrics for retry depth accumulator", metric=?metrics);
+            }
+        }
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        if self.payments == 0 {
+            None
+        } else {
+            Some(f64::from(self.total_attempts) / f64::from(self.payments))
+        }
+    }
+}
+
+/// Furthest stage of the funnel reached by an attempt with the status
+fn get_funnel_stage(status: storage_enums::AttemptStatus) -> PaymentFunnelStage {
+    match status {
+        storage_enums::AttemptStatus::Started
+        | storage_enums::AttemptStatus::RouterDeclined
+        | storage_enums::AttemptStatus::PaymentMethodAwaited
+        | storage_enums::AttemptStatus::ConfirmationAwaited => PaymentFunnelStage::Created,
+        storage_enums::AttemptStatus::AuthenticationPending
+        | storage_enums::AttemptStatus::AuthenticationFailed
+        | storage_enums::AttemptStatus::AuthenticationSuccessful
+        | storage_enums::AttemptStatus::DeviceDataCollectionPending => {
+            PaymentFunnelStage::CustomerActionRequired
+        }
+        storage_enums::AttemptStatus::Authorizing
+        | storage_enums::AttemptStatus::AuthorizationFailed
+        | storage_enums::AttemptStatus::CodInitiated
+        | storage_enums::AttemptStatus::Pending
+        | storage_enums::AttemptStatus::Failure
+        | storage_enums::AttemptStatus::CaptureFailed
+        | storage_enums::AttemptStatus::Voided
+        | storage_enums::AttemptStatus::VoidInitiated
+        | storage_enums::AttemptStatus::VoidFailed
+        | storage_enums::AttemptStatus::AutoRefunded
+        | storage_enums::AttemptStatus::Unresolved => PaymentFunnelStage::Processing,
+        storage_enums::AttemptStatus::Authorized
+        | storage_enums::AttemptStatus::PartiallyAuthorized
+        | storage_enums::AttemptStatus::Charged
+        | storage_enums::AttemptStatus::PartialCharged
+        | storage_enums::AttemptStatus::PartialChargedAndChargeable
+        | storage_enums::AttemptStatus::CaptureInitiated => PaymentFunnelStage::Succeeded,
+    }
+}
+
+impl PaymentMetricAccumulator for PaymentFunnelAccumulator {
+    type MetricOutput = Option<PaymentFunnel>;
+
+    fn add_metrics_bucket(&mut self, metrics: &PaymentMetricRow) {
+        let Some(status) = metrics.status.as_ref().map(|status| status.0) else {
+            return;
+        };
+        let count = metrics.count.unwrap_or_default();
+        let stage = get_funnel_stage(status);
+        // Attempts authenticated with 3DS went through a customer action before being processed
+        let is_three_ds = metrics
+            .authentication_type
+            .as_ref()
+            .map(|auth_type| auth_type.0)
+            == Some(storage_enums::AuthenticationType::ThreeDs);
+
+        self.created += count;
+        if stage == PaymentFunnelStage::CustomerActionRequired
+            || (stage > PaymentFunnelStage::CustomerActionRequired && is_three_ds)
+        {
+            self.customer_action_required += count;
+        }
+        if stage >= PaymentFunnelStage::Processing {
+            self.processing += count;
+        }
+        if stage == PaymentFunnelStage::Succeeded {
+            self.succeeded += count;
+            return;
+        }
+
+        match self
+            .drop_offs
+            .iter_mut()
+            .find(|(_, drop_off_status, _)| *drop_off_status == status)
+        {
+            Some((_, _, drop_off_count)) => *drop_off_count += count,
+            None => self.drop_offs.push((stage, status, count)),
+        }
+    }
+
+    fn collect(mut self) -> Self::MetricOutput {
+        if self.created <= 0 {
+            return None;
+        }
+        let created = f64::from(u32::try_from(self.created).ok()?);
+        let get_rate = |count: i64| {
+            u32::try_from(count)
+                .ok()
+                .map(|count| (f64::from(count) * 10000.0 / created).round() / 100.0)
+        };
+
+        self.drop_offs
+            .sort_by(|(stage_a, _, count_a), (stage_b, _, count_b)| {
+                stage_a.cmp(stage_b).then(count_b.cmp(count_a))
+            });
+
+        Some(PaymentFunnel {
+            created: u64::try_from(self.created).ok()?,
+            customer_action_required: u64::try_from(self.customer_action_required).ok()?,
+            processing: u64::try_from(self.processing).ok()?,
+            succeeded: u64::try_from(self.succeeded).ok()?,
+            customer_action_required_rate: get_rate(self.customer_action_required),
+            processing_rate: get_rate(self.processing),
+            success_rate: get_rate(self.succeeded),
+            drop_offs: self
+                .drop_offs
+                .into_iter()
+                .filter_map(|(stage, status, count)| {
+                    Some(PaymentFunnelDropOff {
+                        stage,
+                        status,
+                        count: u64::try_from(count).ok()?,
+                        percentage: get_rate(count)?,
+                    })
+                })
+                .collect(),
+        })
+    }
+}
+
 impl PaymentMetricsAccumulator {
     pub fn collect(self) -> PaymentMetricsBucketValue {
         PaymentMetricsBucketValue {
@@ -208,9 +407,20 @@ impl PaymentMetricsAccumulator {
             payment_processed_amount: self.processed_amount.collect(),
             avg_ticket_size: self.avg_ticket_size.collect(),
             payment_error_message: self.payment_error_message.collect(),
+            amount_distribution: self.amount_distribution.collect(),
             retries_count: self.retries_count.collect(),
             retries_amount_processed: self.retries_amount_processed.collect(),
             connector_success_rate: self.connector_success_rate.collect(),
+            avg_retry_depth: self.avg_retry_depth.collect(),
+            retry_attempt_success_count: self.retry_attempt_success_count.collect(),
+            surcharge_amount: self.surcharge_amount.collect(),
+            tax_on_surcharge_amount: self.tax_on_surcharge_amount.collect(),
+            blocklist_hit_count: self.blocklist_hit_count.collect(),
+            payment_funnel: self.payment_funnel.collect(),
+            retried_attempt_count: self.retried_attempt_count.collect(),
+            retry_success_count: self.retry_success_count.collect(),
+            retry_recovered_amount: self.retry_recovered_amount.collect(),
+            refunded_payment_count: self.refunded_payment_count.collect(),
         }
     }
 }
@@ -0,0 +1,91 @@
+use api_models::analytics::{
+    payments::{PaymentDimensions, PaymentFilters},
+    Granularity, TimeRange,
+};
+use common_utils::errors::ReportSwitchExt;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use super::metrics::{PaymentMetricAnalytics, PaymentMetricRow};
+use crate::{
+    query::{Aggregate, GroupByClause, QueryBuilder, QueryFilter, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, MetricsError, MetricsResult},
+};
+
+/// Loads the number of payment attempts per connector and attempt status for every time bucket
+/// of the given granularity, the error rate of each bucket is derived from these rows.
+pub async fn get_connector_status_buckets<T>(
+    merchant_id: &str,
+    filters: &PaymentFilters,
+    granularity: &Granularity,
+    time_range: &TimeRange,
+    pool: &T,
+) -> MetricsResult<Vec<PaymentMetricRow>>
+where
+    T: AnalyticsDataSource + PaymentMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::Payment);
+
+    for dim in [
+        PaymentDimensions::Connector,
+        PaymentDimensions::PaymentStatus,
+    ] {
+        query_builder.add_select_column(dim).switch()?;
+    }
+    query_builder
+        .add_select_column(Aggregate::Count {
+            field: None,
+            alias: Some("count"),
+        })
+        .switch()?;
+    query_builder
+        .add_select_column(Aggregate::Min {
+            field: "created_at",
+            alias: Some("start_bucket"),
+        })
+        .switch()?;
+    query_builder
+        .add_select_column(Aggregate::Max {
+            field: "created_at",
+            alias: Some("end_bucket"),
+        })
+        .switch()?;
+
+    filters.set_filter_clause(&mut query_builder).switch()?;
+
+    query_builder
+        .add_filter_clause("merchant_id", merchant_id)
+        .switch()?;
+    query_builder
+        .add_is_not_null_filter_clause(PaymentDimensions::Connector)
+        .switch()?;
+    time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+
+    for dim in [
+        PaymentDimensions::Connector,
+        PaymentDimensions::PaymentStatus,
+    ] {
+        query_builder
+            .add_group_by_clause(dim)
+            .attach_printable("Error grouping by dimensions")
+            .switch()?;
+    }
+    granularity
+        .set_group_by_clause(&mut query_builder)
+        .attach_printable("Error adding granularity")
+        .switch()?;
+
+    query_builder
+        .execute_query::<PaymentMetricRow, _>(pool)
+        .await
+        .change_context(MetricsError::QueryBuildingError)?
+        .change_context(MetricsError::QueryExecutionFailure)
+}
@@ -1,13 +1,14 @@
 #![allow(dead_code)]
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use api_models::analytics::{
     payments::{
-        MetricsBucketResponse, PaymentDimensions, PaymentDistributions, PaymentMetrics,
-        PaymentMetricsBucketIdentifier,
+        ConnectorUptimeValue, MetricsBucketResponse, PaymentDimensions, PaymentDistributions,
+        PaymentMetrics, PaymentMetricsBucketIdentifier,
     },
-    AnalyticsMetadata, FilterValue, GetPaymentFiltersRequest, GetPaymentMetricRequest,
-    MetricsResponse, PaymentFiltersResponse,
+    AmountBuckets, AnalyticsMetadata, FilterValue, GetConnectorUptimeRequest,
+    GetPaymentFiltersRequest, GetPaymentMetricRequest, MetricsResponse, PaymentFiltersResponse,
+    MAX_AMOUNT_BUCKET_BOUNDARIES,
 };
 use common_utils::errors::CustomResult;
 use error_stack::{IntoReport, ResultExt};
@@ -17,18 +18,32 @@ use router_env::{
 };
 
 use super::{
+    connector_uptime::get_connector_status_buckets,
     distribution::PaymentDistributionRow,
     filters::{get_payment_filter_for_dimension, FilterRow},
     metrics::PaymentMetricRow,
     PaymentMetricsAccumulator,
 };
 use crate::{
+    cache::MetricCacheKey,
+    core::get_time_series_or_default,
     errors::{AnalyticsError, AnalyticsResult},
     metrics,
     payments::{PaymentDistributionAccumulator, PaymentMetricAccumulator},
-    AnalyticsProvider,
+    query::SeriesBucket,
+    reporting_currency::ReportingCurrencyRates,
+    types::MetricsResult,
+    AnalyticsDomain, AnalyticsProvider,
 };
 
+/// Attempt statuses which are counted as errors of the connector while computing its uptime
+const CONNECTOR_ERROR_STATUSES: [diesel_models::enums::AttemptStatus; 4] = [
+    diesel_models::enums::AttemptStatus::Failure,
+    diesel_models::enums::AttemptStatus::AuthorizationFailed,
+    diesel_models::enums::AttemptStatus::CaptureFailed,
+    diesel_models::enums::AttemptStatus::VoidFailed,
+];
+
 #[derive(Debug)]
 pub enum TaskType {
     MetricTask(
@@ -41,12 +56,64 @@ pub enum TaskType {
     ),
 }
 
+/// Loads the requested payment metrics for the given merchants.
+///
+/// `query_scope` identifies the owner of the query for the concurrency guardrails, this is the
+/// merchant id for merchant dashboards and the organization id for organization dashboards.
 #[instrument(skip_all)]
 pub async fn get_metrics(
     pool: &AnalyticsProvider,
-    merchant_id: &str,
+    query_scope: &str,
+    merchant_ids: &[String],
     req: GetPaymentMetricRequest,
+    reporting_currency_rates: Option<ReportingCurrencyRates>,
 ) -> AnalyticsResult<MetricsResponse<MetricsBucketResponse>> {
+    if merchant_ids.is_empty() {
+        return Err(AnalyticsError::InvalidRequest(
+            "No merchants found to load the metrics for".to_string(),
+        ))
+        .into_report();
+    }
+    if let Some(AmountBuckets::Custom { boundaries }) = req
+        .distribution
+        .as_ref()
+        .map(|distribution| &distribution.amount_buckets)
+    {
+        if boundaries.is_empty()
+            || boundaries.len() > MAX_AMOUNT_BUCKET_BOUNDARIES
+            || boundaries.iter().any(|boundary| *boundary <= 0)
+        {
+            return Err(AnalyticsError::InvalidRequest(format!(
+                "Amount bucket boundaries must be between 1 and {MAX_AMOUNT_BUCKET_BOUNDARIES} positive amounts"
+            )))
+            .into_report();
+        }
+    }
+    // The retry depth metrics are loaded from the payment intents, which do not carry the payment
+    // dimensions
+    if req.metrics.iter().any(|metric| {
+        matches!(
+            metric,
+            PaymentMetrics::AvgRetryDepth | PaymentMetrics::RetryAttemptSuccessCount
+        )
+    }) && (!req.group_by_names.is_empty() || !req.filters.is_empty())
+    {
+        return Err(AnalyticsError::InvalidRequest(
+            "Dimensions and filters are not supported for the avg_retry_depth and retry_attempt_success_count metrics"
+                .to_string(),
+        ))
+        .into_report();
+    }
+    let req = GetPaymentMetricRequest {
+        time_series: Some(get_time_series_or_default(req.time_series, &req.time_range)),
+        ..req
+    };
+    let _query_permit = pool.guardrails().acquire_query_permit(query_scope)?;
+    pool.guardrails().validate_time_range(
+        req.time_series.as_ref().map(|series| &series.granularity),
+        &req.time_range,
+    )?;
+
     let mut metrics_accumulator: HashMap<
         PaymentMetricsBucketIdentifier,
         PaymentMetricsAccumulator,
@@ -56,6 +123,11 @@ pub async fn get_metrics(
     for metric_type in req.metrics.iter().cloned() {
         let req = req.clone();
         let pool = pool.clone();
+        let group_by_names = get_group_by_names_for_metric(
+            &metric_type,
+            &req.group_by_names,
+            reporting_currency_rates.is_some(),
+        );
         let task_span = tracing::debug_span!(
             "analytics_payments_metrics_query",
             payment_metric = metric_type.as_ref()
@@ -63,20 +135,18 @@ pub async fn get_metrics(
 
         // TODO: lifetime issues with joinset,
         // can be optimized away if joinset lifetime requirements are relaxed
-        let merchant_id_scoped = merchant_id.to_owned();
+        let merchant_ids_scoped = merchant_ids.to_vec();
         set.spawn(
             async move {
-                let data = pool
-                    .get_payment_metrics(
-                        &metric_type,
-                        &req.group_by_names.clone(),
-                        &merchant_id_scoped,
-                        &req.filters,
-                        &req.time_series.map(|t| t.granularity),
-                        &req.time_range,
-                    )
-                    .await
-                    .change_context(AnalyticsError::UnknownError);
+                let data = get_cached_payment_metrics(
+                    &pool,
+                    &metric_type,
+                    &group_by_names,
+                    &merchant_ids_scoped,
+                    &req,
+                )
+                .await
+                .change_context(AnalyticsError::UnknownError);
                 TaskType::MetricTask(metric_type, data)
             }
             .instrument(task_span),
@@ -91,14 +161,14 @@ pub async fn get_metrics(
             payment_distribution = distribution.distribution_for.as_ref()
         );
 
-        let merchant_id_scoped = merchant_id.to_owned();
+        let merchant_ids_scoped = merchant_ids.to_vec();
         set.spawn(
             async move {
                 let data = pool
                     .get_payment_distribution(
                         &distribution,
                         &req.group_by_names.clone(),
-                        &merchant_id_scoped,
+                        &merchant_ids_scoped,
                         &req.filters,
                         &req.time_series.map(|t| t.granularity),
                         &req.time_range,
@@ -132,7 +202,18 @@ pub async fn get_metrics(
                     logger::debug!("Attributes: {:?}, Buckets fetched: {}", attributes, val);
                 }
 
-                for (id, value) in data {
+                for (mut id, mut value) in data {
+                    if let Some(rates) = reporting_currency_rates
+                        .as_ref()
+                        .filter(|_| is_amount_metric(&metric))
+                    {
+                        convert_to_reporting_currency(
+                            rates,
+                            &mut id,
+                            &mut value,
+                            &req.group_by_names,
+                        )?;
+                    }
                     logger::debug!(bucket_id=?id, bucket_value=?value, "Bucket row for metric {metric}");
                     let metrics_builder = metrics_accumulator.entry(id).or_default();
                     match metric {
@@ -162,6 +243,36 @@ pub async fn get_metrics(
                                 .connector_success_rate
                                 .add_metrics_bucket(&value);
                         }
+                        PaymentMetrics::AvgRetryDepth => {
+                            metrics_builder.avg_retry_depth.add_metrics_bucket(&value)
+                        }
+                        PaymentMetrics::RetryAttemptSuccessCount => metrics_builder
+                            .retry_attempt_success_count
+                            .add_metrics_bucket(&value),
+                        PaymentMetrics::SurchargeAmount => {
+                            metrics_builder.surcharge_amount.add_metrics_bucket(&value)
+                        }
+                        PaymentMetrics::TaxOnSurchargeAmount => metrics_builder
+                            .tax_on_surcharge_amount
+                            .add_metrics_bucket(&value),
+                        PaymentMetrics::BlocklistHitCount => metrics_builder
+                            .blocklist_hit_count
+                            .add_metrics_bucket(&value),
+                        PaymentMetrics::PaymentFunnel => {
+                            metrics_builder.payment_funnel.add_metrics_bucket(&value)
+                        }
+                        PaymentMetrics::RetriedAttemptCount => metrics_builder
+                            .retried_attempt_count
+                            .add_metrics_bucket(&value),
+                        PaymentMetrics::RetrySuccessCount => metrics_builder
+                            .retry_success_count
+                            .add_metrics_bucket(&value),
+                        PaymentMetrics::RetryRecoveredAmount => metrics_builder
+                            .retry_recovered_amount
+                            .add_metrics_bucket(&value),
+                        PaymentMetrics::RefundedPaymentCount => metrics_builder
+                            .refunded_payment_count
+                            .add_metrics_bucket(&value),
                     }
                 }
 
@@ -191,6 +302,9 @@ pub async fn get_metrics(
                         PaymentDistributions::PaymentErrorMessage => metrics_accumulator
                             .payment_error_message
                             .add_distribution_bucket(&value),
+                        PaymentDistributions::AmountDistribution => metrics_accumulator
+                            .amount_distribution
+                            .add_distribution_bucket(&value),
                     }
                 }
 
@@ -215,25 +329,109 @@ pub async fn get_metrics(
         query_data,
         meta_data: [AnalyticsMetadata {
             current_time_range: req.time_range,
+            granularity: req.time_series.map(|series| series.granularity),
         }],
     })
 }
 
+/// Metrics whose values are amounts, these are converted to the reporting currency when one is
+/// requested
+fn is_amount_metric(metric: &PaymentMetrics) -> bool {
+    matches!(
+        metric,
+        PaymentMetrics::PaymentProcessedAmount
+            | PaymentMetrics::AvgTicketSize
+            | PaymentMetrics::RetriesCount
+            | PaymentMetrics::SurchargeAmount
+            | PaymentMetrics::TaxOnSurchargeAmount
+            | PaymentMetrics::RetryRecoveredAmount
+    )
+}
+
+/// Amounts can only be converted per currency, so amount metrics are always grouped by currency
+/// when they have to be converted to a reporting currency
+fn get_group_by_names_for_metric(
+    metric: &PaymentMetrics,
+    group_by_names: &[PaymentDimensions],
+    convert_to_reporting_currency: bool,
+) -> Vec<PaymentDimensions> {
+    let mut group_by_names = group_by_names.to_vec();
+    if convert_to_reporting_currency
+        && is_amount_metric(metric)
+        && !group_by_names.contains(&PaymentDimensions::Currency)
+    {
+        group_by_names.push(PaymentDimensions::Currency);
+    }
+    group_by_names
+}
+
+/// Returns the cached result of the metric, querying the data source only on a cache miss
+async fn get_cached_payment_metrics(
+    pool: &AnalyticsProvider,
+    metric: &PaymentMetrics,
+    group_by_names: &[PaymentDimensions],
+    merchant_ids: &[String],
+    req: &GetPaymentMetricRequest,
+) -> MetricsResult<Vec<(PaymentMetricsBucketIdentifier, PaymentMetricRow)>> {
+    let granularity = req.time_series.as_ref().map(|t| t.granularity);
+    let cache_key = MetricCacheKey {
+        domain: AnalyticsDomain::Payments,
+        metric,
+        merchant_ids,
+        dimensions: group_by_names,
+        filters: &req.filters,
+        granularity,
+        time_range: req.time_range,
+    };
+    pool.cache()
+        .get_or_load(cache_key, req.bypass_cache, |time_range| async move {
+            pool.get_payment_metrics(
+                metric,
+                group_by_names,
+                merchant_ids,
+                &req.filters,
+                &granularity,
+                &time_range,
+            )
+            .await
+        })
+        .await
+}
+
+/// Converts the bucket total to the reporting currency using the rates in effect at the start of
+/// the bucket, the bucket is merged with the other currencies unless grouped by currency
+fn convert_to_reporting_currency(
+    rates: &ReportingCurrencyRates,
+    id: &mut PaymentMetricsBucketIdentifier,
+    row: &mut PaymentMetricRow,
+    group_by_names: &[PaymentDimensions],
+) -> AnalyticsResult<()> {
+    if let (Some(total), Some(currency)) = (row.total.as_ref(), id.currency) {
+        row.total = Some(rates.convert_amount(total, currency, id.start_time.date())?);
+    }
+    if !group_by_names.contains(&PaymentDimensions::Currency) {
+        id.currency = None;
+        row.currency = None;
+    }
+    Ok(())
+}
+
 pub async fn get_filters(
     pool: &AnalyticsProvider,
     req: GetPaymentFiltersRequest,
     merchant_id: &String,
 ) -> AnalyticsResult<PaymentFiltersResponse> {
+    let _query_permit = pool.guardrails().acquire_query_permit(merchant_id)?;
     let mut res = PaymentFiltersResponse::default();
 
     for dim in req.group_by_names {
         let values = match pool {
                         AnalyticsProvider::Sqlx(pool) => {
-                get_payment_filter_for_dimension(dim, merchant_id, &req.time_range, pool)
+                get_payment_filter_for_dimension(dim, merchant_id, &req.time_range, &req.value_search, pool)
                     .await
             }
                         AnalyticsProvider::Clickhouse(pool) => {
-                get_payment_filter_for_dimension(dim, merchant_id, &req.time_range, pool)
+                get_payment_filter_for_dimension(dim, merchant_id, &req.time_range, &req.value_search, pool)
                     .await
             }
                     AnalyticsProvider::CombinedCkh(sqlx_poll, ckh_pool) => {
@@ -241,6 +439,7 @@ pub async fn get_filters(
                     dim,
                     merchant_id,
                     &req.time_range,
+                    &req.value_search,
                     ckh_pool,
                 )
                 .await;
@@ -248,6 +447,7 @@ pub async fn get_filters(
                     dim,
                     merchant_id,
                     &req.time_range,
+                    &req.value_search,
                     sqlx_poll,
                 )
                 .await;
@@ -264,6 +464,7 @@ pub async fn get_filters(
                     dim,
                     merchant_id,
                     &req.time_range,
+                    &req.value_search,
                     ckh_pool,
                 )
                 .await;
@@ -271,6 +472,7 @@ pub async fn get_filters(
                     dim,
                     merchant_id,
                     &req.time_range,
+                    &req.value_search,
                     sqlx_poll,
                 )
                 .await;
@@ -292,6 +494,16 @@ pub async fn get_filters(
             PaymentDimensions::AuthType => fil.authentication_type.map(|i| i.as_ref().to_string()),
             PaymentDimensions::PaymentMethod => fil.payment_method,
             PaymentDimensions::PaymentMethodType => fil.payment_method_type,
+            PaymentDimensions::CardNetwork => fil.card_network,
+            PaymentDimensions::CardType => fil.card_type,
+            PaymentDimensions::CardIssuingCountry => fil.card_issuing_country,
+            PaymentDimensions::PaymentMethodSelection => fil
+                .payment_method_selection
+                .map(|i| i.as_ref().to_string()),
+            PaymentDimensions::SettlementCurrency => {
+                fil.settlement_currency.map(|i| i.as_ref().to_string())
+            }
+            PaymentDimensions::MerchantId => fil.merchant_id,
         })
         .collect::<Vec<String>>();
         res.query_data.push(FilterValue {
@@ -301,3 +513,102 @@ pub async fn get_filters(
     }
     Ok(res)
 }
+
+#[instrument(skip_all)]
+pub async fn get_connector_uptime(
+    pool: &AnalyticsProvider,
+    merchant_id: &str,
+    req: GetConnectorUptimeRequest,
+) -> AnalyticsResult<MetricsResponse<ConnectorUptimeValue>> {
+    if !(0.0..=100.0).contains(&req.error_rate_threshold) {
+        return Err(AnalyticsError::InvalidRequest(
+            "errorRateThreshold must be between 0 and 100".to_string(),
+        ))
+        .into_report();
+    }
+    let _query_permit = pool.guardrails().acquire_query_permit(merchant_id)?;
+    pool.guardrails()
+        .validate_time_range(Some(&req.granularity), &req.time_range)?;
+
+    let rows = match pool {
+        AnalyticsProvider::Sqlx(pool) | AnalyticsProvider::CombinedSqlx(pool, _) => {
+            get_connector_status_buckets(
+                merchant_id,
+                &req.filters,
+                &req.granularity,
+                &req.time_range,
+                pool,
+            )
+            .await
+        }
+        AnalyticsProvider::Clickhouse(pool) | AnalyticsProvider::CombinedCkh(_, pool) => {
+            get_connector_status_buckets(
+                merchant_id,
+                &req.filters,
+                &req.granularity,
+                &req.time_range,
+                pool,
+            )
+            .await
+        }
+    }
+    .change_context(AnalyticsError::UnknownError)?;
+
+    // (total attempts, errored attempts) for every connector and time bucket
+    let mut buckets: HashMap<(String, time::PrimitiveDateTime), (u32, u32)> = HashMap::new();
+    for row in rows {
+        let (connector, start_bucket) = match (row.connector, row.start_bucket) {
+            (Some(connector), Some(start_bucket)) => (connector, start_bucket),
+            _ => continue,
+        };
+        let bucket_start = req
+            .granularity
+            .clip_to_start(start_bucket)
+            .change_context(AnalyticsError::UnknownError)?;
+        let count = row
+            .count
+            .and_then(|count| u32::try_from(count).ok())
+            .unwrap_or_default();
+        let is_error = row
+            .status
+            .is_some_and(|status| CONNECTOR_ERROR_STATUSES.contains(&status.0));
+
+        let (total, errors) = buckets.entry((connector, bucket_start)).or_default();
+        *total = total.saturating_add(count);
+        if is_error {
+            *errors = errors.saturating_add(count);
+        }
+    }
+
+    // (total buckets, down buckets) for every connector
+    let mut connectors: BTreeMap<String, (u32, u32)> = BTreeMap::new();
+    for ((connector, _), (total, errors)) in buckets {
+        let is_down = f64::from(errors) * 100.0 > req.error_rate_threshold * f64::from(total);
+        let (total_buckets, down_buckets) = connectors.entry(connector).or_default();
+        *total_buckets = total_buckets.saturating_add(1);
+        if is_down {
+            *down_buckets = down_buckets.saturating_add(1);
+        }
+    }
+
+    let query_data = connectors
+        .into_iter()
+        .map(
+            |(connector, (total_buckets, down_buckets))| ConnectorUptimeValue {
+                connector,
+                total_buckets,
+                down_buckets,
+                uptime_percentage: f64::from(total_buckets.saturating_sub(down_buckets)) * 100.0
+                    / f64::from(total_buckets),
+            },
+        )
+        .collect();
+
+    Ok(MetricsResponse {
+        query_data,
+        meta_data: [AnalyticsMetadata {
+            current_time_range: req.time_range,
+            granularity: Some(req.granularity),
+        }],
+    })
+}
@@ -12,8 +12,10 @@ use crate::{
     types::{AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, LoadRow, MetricsResult},
 };
 
+mod amount_distribution;
 mod payment_error_message;
 
+use amount_distribution::AmountDistribution;
 use payment_error_message::PaymentErrorMessage;
 
 #[derive(Debug, PartialEq, Eq, serde::Deserialize)]
@@ -24,9 +26,21 @@ pub struct PaymentDistributionRow {
     pub authentication_type: Option<DBEnumWrapper<storage_enums::AuthenticationType>>,
     pub payment_method: Option<String>,
     pub payment_method_type: Option<String>,
+    pub card_network: Option<String>,
+    pub card_type: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub payment_method_selection: Option<DBEnumWrapper<storage_enums::PaymentMethodSelection>>,
+    pub settlement_currency: Option<DBEnumWrapper<storage_enums::Currency>>,
+    pub merchant_id: Option<String>,
     pub total: Option<bigdecimal::BigDecimal>,
     pub count: Option<i64>,
     pub error_message: Option<String>,
+    /// Lower bound of the bucket of the amount distribution
+    pub amount_bucket_start: Option<i64>,
+    /// Upper bound of the bucket of the amount distribution, set from the bucket boundaries
+    /// after the query as the last bucket has no upper bound
+    #[serde(default)]
+    pub amount_bucket_end: Option<i64>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub start_bucket: Option<PrimitiveDateTime>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
@@ -45,7 +59,7 @@ where
         &self,
         distribution: &Distribution,
         dimensions: &[PaymentDimensions],
-        merchant_id: &str,
+        merchant_ids: &[String],
         filters: &PaymentFilters,
         granularity: &Option<Granularity>,
         time_range: &TimeRange,
@@ -67,7 +81,7 @@ where
         &self,
         distribution: &Distribution,
         dimensions: &[PaymentDimensions],
-        merchant_id: &str,
+        merchant_ids: &[String],
         filters: &PaymentFilters,
         granularity: &Option<Granularity>,
         time_range: &TimeRange,
@@ -79,7 +93,20 @@ where
                     .load_distribution(
                         distribution,
                         dimensions,
-                        merchant_id,
+                        merchant_ids,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::AmountDistribution => {
+                AmountDistribution
+                    .load_distribution(
+                        distribution,
+                        dimensions,
+                        merchant_ids,
                         filters,
                         granularity,
                         time_range,
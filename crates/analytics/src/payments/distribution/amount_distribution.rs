@@ -0,0 +1,249 @@
+use api_models::analytics::{
+    payments::{PaymentDimensions, PaymentFilters, PaymentMetricsBucketIdentifier},
+    Distribution, Granularity, TimeRange,
+};
+use common_utils::errors::ReportSwitchExt;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use super::{PaymentDistribution, PaymentDistributionRow};
+use crate::{
+    query::{
+        Aggregate, GroupByClause, QueryBuilder, QueryFilter, RangeBucket, SeriesBucket, ToSql,
+        Window,
+    },
+    types::{AnalyticsCollection, AnalyticsDataSource, MetricsError, MetricsResult},
+};
+
+/// Counts the payments in each bucket of amounts, per currency as amounts in different currencies
+/// cannot be compared
+#[derive(Default)]
+pub(super) struct AmountDistribution;
+
+#[async_trait::async_trait]
+impl<T> PaymentDistribution<T> for AmountDistribution
+where
+    T: AnalyticsDataSource + super::PaymentDistributionAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_distribution(
+        &self,
+        distribution: &Distribution,
+        dimensions: &[PaymentDimensions],
+        merchant_ids: &[String],
+        filters: &PaymentFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<Vec<(PaymentMetricsBucketIdentifier, PaymentDistributionRow)>> {
+        let boundaries = distribution.amount_buckets.get_boundaries();
+        let dimensions = dimensions
+            .iter()
+            .filter(|dim| **dim != PaymentDimensions::Currency)
+            .chain([&PaymentDimensions::Currency])
+            .collect::<Vec<_>>();
+
+        let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::Payment);
+
+        for dim in dimensions.iter() {
+            query_builder.add_select_column(*dim).switch()?;
+        }
+
+        query_builder
+            .add_select_column(RangeBucket {
+                field: "amount",
+                boundaries: &boundaries,
+                alias: Some("amount_bucket_start"),
+            })
+            .switch()?;
+        query_builder
+            .add_select_column(Aggregate::Count {
+                field: None,
+                alias: Some("count"),
+            })
+            .switch()?;
+        query_builder
+            .add_select_column(Aggregate::Min {
+                field: "created_at",
+                alias: Some("start_bucket"),
+            })
+            .switch()?;
+        query_builder
+            .add_select_column(Aggregate::Max {
+                field: "created_at",
+                alias: Some("end_bucket"),
+            })
+            .switch()?;
+
+        filters.set_filter_clause(&mut query_builder).switch()?;
+
+        query_builder
+            .add_filter_in_range_clause("merchant_id", merchant_ids)
+            .switch()?;
+
+        time_range
+            .set_filter_clause(&mut query_builder)
+            .attach_printable("Error filtering time range")
+            .switch()?;
+
+        for dim in dimensions.iter() {
+            query_builder
+                .add_group_by_clause(*dim)
+                .attach_printable("Error grouping by dimensions")
+                .switch()?;
+        }
+
+        query_builder
+            .add_group_by_clause("amount_bucket_start")
+            .attach_printable("Error grouping by amount bucket")
+            .switch()?;
+
+        if let Some(granularity) = granularity.as_ref() {
+            granularity
+                .set_group_by_clause(&mut query_builder)
+                .attach_printable("Error adding granularity")
+                .switch()?;
+        }
+
+        query_builder
+            .execute_query::<PaymentDistributionRow, _>(pool)
+            .await
+            .change_context(MetricsError::QueryBuildingError)?
+            .change_context(MetricsError::QueryExecutionFailure)?
+            .into_iter()
+            .map(|mut i| {
+                i.amount_bucket_end = i.amount_bucket_start.and_then(|start| {
+                    boundaries
+                        .iter()
+                        .find(|boundary| **boundary > start)
+                        .copied()
+                });
+                Ok((
+                    PaymentMetricsBucketIdentifier::new(
+                        i.currency.as_ref().map(|i| i.0),
+                        i.status.as_ref().map(|i| i.0),
+                        i.connector.clone(),
+                        i.authentication_type.as_ref().map(|i| i.0),
+                        i.payment_method.clone(),
+                        i.payment_method_type.clone(),
+                        i.card_network.clone(),
+                        i.card_type.clone(),
+                        i.card_issuing_country.clone(),
+                        i.payment_method_selection.as_ref().map(|i| i.0),
+                        i.settlement_currency.as_ref().map(|i| i.0),
+                        i.merchant_id.clone(),
+                        None,
+                        None,
+                        TimeRange {
+                            start_time: match (granularity, i.start_bucket) {
+                                (Some(g), Some(st)) => g.clip_to_start(st)?,
+                                _ => time_range.start_time,
+                            },
+                            end_time: granularity.as_ref().map_or_else(
+                                || Ok(time_range.end_time),
+                                |g| i.end_bucket.map(|et| g.clip_to_end(et)).transpose(),
+                            )?,
+                        },
+                    ),
+                    i,
+                ))
+            })
+            .collect::<error_stack::Result<
+                Vec<(PaymentMetricsBucketIdentifier, PaymentDistributionRow)>,
+                crate::query::PostProcessingError,
+            >>()
+            .change_context(MetricsError::PostProcessingFailure)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use api_models::analytics::{
+        payments::{PaymentDistributions, PaymentFilters},
+        AmountBuckets, Distribution, QueryLimit, TimeRange,
+    };
+    use common_utils::date_time;
+    use diesel_models::enums as storage_enums;
+
+    use super::AmountDistribution;
+    use crate::{
+        in_memory::InMemoryClient, payments::distribution::PaymentDistribution,
+        types::AnalyticsCollection,
+    };
+
+    #[tokio::test]
+    async fn test_amount_distribution_buckets_amounts_per_currency() {
+        let pool = InMemoryClient::default().with_rows(
+            AnalyticsCollection::Payment,
+            [
+                serde_json::json!({
+                    "currency": "USD",
+                    "amount_bucket_start": 1000,
+                    "count": 4,
+                    "start_bucket": null,
+                    "end_bucket": null,
+                }),
+                serde_json::json!({
+                    "currency": "USD",
+                    "amount_bucket_start": 5000,
+                    "count": 1,
+                    "start_bucket": null,
+                    "end_bucket": null,
+                }),
+            ],
+        );
+        let distribution = Distribution {
+            distribution_for: PaymentDistributions::AmountDistribution,
+            distribution_cardinality: QueryLimit::Top5,
+            amount_buckets: AmountBuckets::Custom {
+                boundaries: vec![5000, 1000],
+            },
+        };
+        let time_range = TimeRange {
+            start_time: date_time::now(),
+            end_time: None,
+        };
+
+        let buckets = AmountDistribution
+            .load_distribution(
+                &distribution,
+                &[],
+                &["merchant_1".to_string()],
+                &PaymentFilters::default(),
+                &None,
+                &time_range,
+                &pool,
+            )
+            .await
+            .expect("Failed to load amount distribution");
+
+        let queries = pool.executed_queries();
+        let query = &queries.first().expect("No query was executed").query;
+        assert!(query.contains(
+            "CAST(CASE WHEN amount < 1000 THEN 0 WHEN amount < 5000 THEN 1000 ELSE 5000 END AS BIGINT) as amount_bucket_start"
+        ));
+        assert!(query.contains("GROUP BY currency, amount_bucket_start"));
+
+        let bucket_ends = buckets
+            .iter()
+            .map(|(identifier, row)| {
+                (
+                    identifier.currency,
+                    row.amount_bucket_start,
+                    row.amount_bucket_end,
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            bucket_ends,
+            vec![
+                (Some(storage_enums::Currency::USD), Some(1000), Some(5000)),
+                (Some(storage_enums::Currency::USD), Some(5000), None),
+            ]
+        );
+    }
+}
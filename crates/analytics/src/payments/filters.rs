@@ -1,6 +1,8 @@
-use api_models::analytics::{payments::PaymentDimensions, Granularity, TimeRange};
+use api_models::analytics::{
+    payments::PaymentDimensions, FilterValueSearch, Granularity, TimeRange,
+};
 use common_utils::errors::ReportSwitchExt;
-use diesel_models::enums::{AttemptStatus, AuthenticationType, Currency};
+use diesel_models::enums::{AttemptStatus, AuthenticationType, Currency, PaymentMethodSelection};
 use error_stack::ResultExt;
 use time::PrimitiveDateTime;
 
@@ -18,6 +20,7 @@ pub async fn get_payment_filter_for_dimension<T>(
     dimension: PaymentDimensions,
     merchant: &String,
     time_range: &TimeRange,
+    value_search: &FilterValueSearch,
     pool: &T,
 ) -> FiltersResult<Vec<FilterRow>>
 where
@@ -40,6 +43,10 @@ where
         .add_filter_clause("merchant_id", merchant)
         .switch()?;
 
+    query_builder
+        .add_filter_value_search(&dimension, value_search)
+        .switch()?;
+
     query_builder.set_distinct();
 
     query_builder
@@ -57,4 +64,10 @@ pub struct FilterRow {
     pub authentication_type: Option<DBEnumWrapper<AuthenticationType>>,
     pub payment_method: Option<String>,
     pub payment_method_type: Option<String>,
+    pub card_network: Option<String>,
+    pub card_type: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub payment_method_selection: Option<DBEnumWrapper<PaymentMethodSelection>>,
+    pub settlement_currency: Option<DBEnumWrapper<Currency>>,
+    pub merchant_id: Option<String>,
 }
@@ -10,24 +10,44 @@ use crate::{
     types::{AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, LoadRow, MetricsResult},
 };
 
+mod avg_retry_depth;
 mod avg_ticket_size;
+mod blocklist_hit_count;
 mod connector_success_rate;
 mod payment_count;
+mod payment_funnel;
 mod payment_processed_amount;
 mod payment_success_count;
+mod refunded_payment_count;
+mod retried_attempt_count;
 mod retries_count;
+mod retry_attempt_success_count;
+mod retry_recovered_amount;
+mod retry_success_count;
 mod success_rate;
+mod surcharge_amount;
+mod tax_on_surcharge_amount;
 
+use avg_retry_depth::AvgRetryDepth;
 use avg_ticket_size::AvgTicketSize;
+use blocklist_hit_count::BlocklistHitCount;
 use connector_success_rate::ConnectorSuccessRate;
 use payment_count::PaymentCount;
+use payment_funnel::PaymentFunnel;
 use payment_processed_amount::PaymentProcessedAmount;
 use payment_success_count::PaymentSuccessCount;
+use refunded_payment_count::RefundedPaymentCount;
+use retried_attempt_count::RetriedAttemptCount;
+use retry_attempt_success_count::RetryAttemptSuccessCount;
+use retry_recovered_amount::RetryRecoveredAmount;
+use retry_success_count::RetrySuccessCount;
 use success_rate::PaymentSuccessRate;
+use surcharge_amount::SurchargeAmount;
+use tax_on_surcharge_amount::TaxOnSurchargeAmount;
 
 use self::retries_count::RetriesCount;
 
-#[derive(Debug, PartialEq, Eq, serde::Deserialize)]
+#[derive(Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 pub struct PaymentMetricRow {
     pub currency: Option<DBEnumWrapper<storage_enums::Currency>>,
     pub status: Option<DBEnumWrapper<storage_enums::AttemptStatus>>,
@@ -35,6 +55,14 @@ pub struct PaymentMetricRow {
     pub authentication_type: Option<DBEnumWrapper<storage_enums::AuthenticationType>>,
     pub payment_method: Option<String>,
     pub payment_method_type: Option<String>,
+    pub card_network: Option<String>,
+    pub card_type: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub payment_method_selection: Option<DBEnumWrapper<storage_enums::PaymentMethodSelection>>,
+    pub settlement_currency: Option<DBEnumWrapper<storage_enums::Currency>>,
+    pub merchant_id: Option<String>,
+    pub attempt_count: Option<i64>,
+    pub original_error_code: Option<String>,
     pub total: Option<bigdecimal::BigDecimal>,
     pub count: Option<i64>,
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
@@ -53,7 +81,7 @@ where
     async fn load_metrics(
         &self,
         dimensions: &[PaymentDimensions],
-        merchant_id: &str,
+        merchant_ids: &[String],
         filters: &PaymentFilters,
         granularity: &Option<Granularity>,
         time_range: &TimeRange,
@@ -74,7 +102,7 @@ where
     async fn load_metrics(
         &self,
         dimensions: &[PaymentDimensions],
-        merchant_id: &str,
+        merchant_ids: &[String],
         filters: &PaymentFilters,
         granularity: &Option<Granularity>,
         time_range: &TimeRange,
@@ -85,7 +113,7 @@ where
                 PaymentSuccessRate
                     .load_metrics(
                         dimensions,
-                        merchant_id,
+                        merchant_ids,
                         filters,
                         granularity,
                         time_range,
@@ -97,7 +125,7 @@ where
                 PaymentCount
                     .load_metrics(
                         dimensions,
-                        merchant_id,
+                        merchant_ids,
                         filters,
                         granularity,
                         time_range,
@@ -109,7 +137,7 @@ where
                 PaymentSuccessCount
                     .load_metrics(
                         dimensions,
-                        merchant_id,
+                        merchant_ids,
                         filters,
                         granularity,
                         time_range,
@@ -121,7 +149,7 @@ where
                 PaymentProcessedAmount
                     .load_metrics(
                         dimensions,
-                        merchant_id,
+                        merchant_ids,
                         filters,
                         granularity,
                         time_range,
@@ -133,7 +161,7 @@ where
                 AvgTicketSize
                     .load_metrics(
                         dimensions,
-                        merchant_id,
+                        merchant_ids,
                         filters,
                         granularity,
                         time_range,
@@ -145,7 +173,7 @@ where
                 RetriesCount
                     .load_metrics(
                         dimensions,
-                        merchant_id,
+                        merchant_ids,
                         filters,
                         granularity,
                         time_range,
@@ -157,7 +185,127 @@ where
                 ConnectorSuccessRate
                     .load_metrics(
                         dimensions,
-                        merchant_id,
+                        merchant_ids,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::AvgRetryDepth => {
+                AvgRetryDepth
+                    .load_metrics(
+                        dimensions,
+                        merchant_ids,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::RetryAttemptSuccessCount => {
+                RetryAttemptSuccessCount
+                    .load_metrics(
+                        dimensions,
+                        merchant_ids,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::SurchargeAmount => {
+                SurchargeAmount
+                    .load_metrics(
+                        dimensions,
+                        merchant_ids,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::BlocklistHitCount => {
+                BlocklistHitCount
+                    .load_metrics(
+                        dimensions,
+                        merchant_ids,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::TaxOnSurchargeAmount => {
+                TaxOnSurchargeAmount
+                    .load_metrics(
+                        dimensions,
+                        merchant_ids,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::PaymentFunnel => {
+                PaymentFunnel
+                    .load_metrics(
+                        dimensions,
+                        merchant_ids,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::RetriedAttemptCount => {
+                RetriedAttemptCount
+                    .load_metrics(
+                        dimensions,
+                        merchant_ids,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::RetrySuccessCount => {
+                RetrySuccessCount
+                    .load_metrics(
+                        dimensions,
+                        merchant_ids,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::RetryRecoveredAmount => {
+                RetryRecoveredAmount
+                    .load_metrics(
+                        dimensions,
+                        merchant_ids,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
+            Self::RefundedPaymentCount => {
+                RefundedPaymentCount
+                    .load_metrics(
+                        dimensions,
+                        merchant_ids,
                         filters,
                         granularity,
                         time_range,
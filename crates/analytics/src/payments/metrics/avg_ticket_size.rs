@@ -29,7 +29,7 @@ where
     async fn load_metrics(
         &self,
         dimensions: &[PaymentDimensions],
-        merchant_id: &str,
+        merchant_ids: &[String],
         filters: &PaymentFilters,
         granularity: &Option<Granularity>,
         time_range: &TimeRange,
@@ -69,7 +69,7 @@ where
         filters.set_filter_clause(&mut query_builder).switch()?;
 
         query_builder
-            .add_filter_clause("merchant_id", merchant_id)
+            .add_filter_in_range_clause("merchant_id", merchant_ids)
             .switch()?;
 
         time_range
@@ -113,6 +113,14 @@ where
                         i.authentication_type.as_ref().map(|i| i.0),
                         i.payment_method.clone(),
                         i.payment_method_type.clone(),
+                        i.card_network.clone(),
+                        i.card_type.clone(),
+                        i.card_issuing_country.clone(),
+                        i.payment_method_selection.as_ref().map(|i| i.0),
+                        i.settlement_currency.as_ref().map(|i| i.0),
+                        i.merchant_id.clone(),
+                        None,
+                        None,
                         TimeRange {
                             start_time: match (granularity, i.start_bucket) {
                                 (Some(g), Some(st)) => g.clip_to_start(st)?,
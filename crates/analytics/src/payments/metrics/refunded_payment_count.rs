@@ -0,0 +1,235 @@
+use api_models::analytics::{
+    payments::{PaymentDimensions, PaymentFilters, PaymentMetricsBucketIdentifier},
+    refunds::RefundDimensions,
+    Granularity, TimeRange,
+};
+use common_utils::errors::ReportSwitchExt;
+use diesel_models::enums as storage_enums;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use super::PaymentMetricRow;
+use crate::{
+    query::{
+        Aggregate, FilterTypes, GroupByClause, JoinClause, JoinType, QueryBuilder, QueryFilter,
+        SeriesBucket, ToSql, Window,
+    },
+    types::{AnalyticsCollection, AnalyticsDataSource, MetricsError, MetricsResult},
+};
+
+#[derive(Default)]
+pub(super) struct RefundedPaymentCount;
+
+#[async_trait::async_trait]
+impl<T> super::PaymentMetric<T> for RefundedPaymentCount
+where
+    T: AnalyticsDataSource + super::PaymentMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[PaymentDimensions],
+        merchant_ids: &[String],
+        filters: &PaymentFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<Vec<(PaymentMetricsBucketIdentifier, PaymentMetricRow)>> {
+        let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::Payment);
+
+        for dim in dimensions.iter() {
+            query_builder.add_select_column(dim).switch()?;
+        }
+
+        query_builder
+            .add_select_column(Aggregate::DistinctCount {
+                field: "payment_id",
+                alias: Some("count"),
+            })
+            .switch()?;
+        query_builder
+            .add_select_column(Aggregate::Min {
+                field: "created_at",
+                alias: Some("start_bucket"),
+            })
+            .switch()?;
+        query_builder
+            .add_select_column(Aggregate::Max {
+                field: "created_at",
+                alias: Some("end_bucket"),
+            })
+            .switch()?;
+
+        // Refunds of the payments made within the time range cannot have been made before it
+        // starts, but can have been made after it ends
+        query_builder
+            .add_join_clause(
+                JoinClause {
+                    join_type: JoinType::Inner,
+                    table: AnalyticsCollection::Refund,
+                    alias: "refund",
+                    on: &[("merchant_id", "merchant_id"), ("payment_id", "payment_id")],
+                    columns: &[],
+                },
+                |refunds| {
+                    refunds.add_filter_in_range_clause("merchant_id", merchant_ids)?;
+                    refunds.add_filter_clause(
+                        RefundDimensions::RefundStatus,
+                        storage_enums::RefundStatus::Success,
+                    )?;
+                    refunds.add_custom_filter_clause(
+                        "created_at",
+                        time_range.start_time,
+                        FilterTypes::Gte,
+                    )
+                },
+            )
+            .switch()?;
+
+        filters.set_filter_clause(&mut query_builder).switch()?;
+
+        query_builder
+            .add_filter_in_range_clause("merchant_id", merchant_ids)
+            .switch()?;
+
+        time_range
+            .set_filter_clause(&mut query_builder)
+            .attach_printable("Error filtering time range")
+            .switch()?;
+
+        for dim in dimensions.iter() {
+            query_builder
+                .add_group_by_clause(dim)
+                .attach_printable("Error grouping by dimensions")
+                .switch()?;
+        }
+
+        if let Some(granularity) = granularity.as_ref() {
+            granularity
+                .set_group_by_clause(&mut query_builder)
+                .attach_printable("Error adding granularity")
+                .switch()?;
+        }
+
+        query_builder
+            .add_filter_clause(
+                PaymentDimensions::PaymentStatus,
+                storage_enums::AttemptStatus::Charged,
+            )
+            .switch()?;
+        query_builder
+            .execute_query::<PaymentMetricRow, _>(pool)
+            .await
+            .change_context(MetricsError::QueryBuildingError)?
+            .change_context(MetricsError::QueryExecutionFailure)?
+            .into_iter()
+            .map(|i| {
+                Ok((
+                    PaymentMetricsBucketIdentifier::new(
+                        i.currency.as_ref().map(|i| i.0),
+                        None,
+                        i.connector.clone(),
+                        i.authentication_type.as_ref().map(|i| i.0),
+                        i.payment_method.clone(),
+                        i.payment_method_type.clone(),
+                        i.card_network.clone(),
+                        i.card_type.clone(),
+                        i.card_issuing_country.clone(),
+                        i.payment_method_selection.as_ref().map(|i| i.0),
+                        i.settlement_currency.as_ref().map(|i| i.0),
+                        i.merchant_id.clone(),
+                        None,
+                        None,
+                        TimeRange {
+                            start_time: match (granularity, i.start_bucket) {
+                                (Some(g), Some(st)) => g.clip_to_start(st)?,
+                                _ => time_range.start_time,
+                            },
+                            end_time: granularity.as_ref().map_or_else(
+                                || Ok(time_range.end_time),
+                                |g| i.end_bucket.map(|et| g.clip_to_end(et)).transpose(),
+                            )?,
+                        },
+                    ),
+                    i,
+                ))
+            })
+            .collect::<error_stack::Result<
+                Vec<(PaymentMetricsBucketIdentifier, PaymentMetricRow)>,
+                crate::query::PostProcessingError,
+            >>()
+            .change_context(MetricsError::PostProcessingFailure)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use api_models::analytics::{payments::PaymentFilters, TimeRange};
+    use common_utils::date_time;
+
+    use super::RefundedPaymentCount;
+    use crate::{
+        in_memory::InMemoryClient, payments::metrics::PaymentMetric, query::QueryParam,
+        types::AnalyticsCollection,
+    };
+
+    #[tokio::test]
+    async fn test_refunded_payment_count_joins_filtered_successful_refunds() {
+        let pool = InMemoryClient::default().with_rows(
+            AnalyticsCollection::Payment,
+            [serde_json::json!({
+                "count": 2,
+                "start_bucket": null,
+                "end_bucket": null,
+            })],
+        );
+        let merchant_ids = ["merchant_1".to_string()];
+        let time_range = TimeRange {
+            start_time: date_time::now(),
+            end_time: None,
+        };
+
+        let buckets = RefundedPaymentCount
+            .load_metrics(
+                &[],
+                &merchant_ids,
+                &PaymentFilters::default(),
+                &None,
+                &time_range,
+                &pool,
+            )
+            .await
+            .expect("Failed to load refunded payment count");
+
+        let queries = pool.executed_queries();
+        let executed = queries.first().expect("No query was executed");
+        assert!(executed.query.contains(
+            "SELECT uniqExact(payment_id) as count, min(created_at) as start_bucket, \
+             max(created_at) as end_bucket FROM payment_attempts INNER JOIN \
+             (SELECT merchant_id AS refund_merchant_id, payment_id AS refund_payment_id \
+             FROM refunds FINAL WHERE merchant_id IN ({p0:String}) AND \
+             refund_status = {p1:String} AND created_at >= {p2:DateTime}) AS refund \
+             ON merchant_id = refund_merchant_id AND payment_id = refund_payment_id \
+             WHERE merchant_id IN ({p3:String}) AND created_at >= {p4:DateTime} AND \
+             status = {p5:String}"
+        ));
+        assert_eq!(
+            executed.params,
+            vec![
+                QueryParam::Text("merchant_1".to_string()),
+                QueryParam::Text("success".to_string()),
+                QueryParam::DateTime(time_range.start_time),
+                QueryParam::Text("merchant_1".to_string()),
+                QueryParam::DateTime(time_range.start_time),
+                QueryParam::Text("charged".to_string()),
+            ]
+        );
+
+        let (_, row) = buckets.first().expect("No metric bucket was returned");
+        assert_eq!(row.count, Some(2));
+    }
+}
@@ -31,7 +31,7 @@ where
     async fn load_metrics(
         &self,
         _dimensions: &[PaymentDimensions],
-        merchant_id: &str,
+        merchant_ids: &[String],
         _filters: &PaymentFilters,
         granularity: &Option<Granularity>,
         time_range: &TimeRange,
@@ -64,10 +64,10 @@ where
             })
             .switch()?;
         query_builder
-            .add_filter_clause("merchant_id", merchant_id)
+            .add_filter_in_range_clause("merchant_id", merchant_ids)
             .switch()?;
         query_builder
-            .add_custom_filter_clause("attempt_count", "1", FilterTypes::Gt)
+            .add_custom_filter_clause("attempt_count", 1, FilterTypes::Gt)
             .switch()?;
         query_builder
             .add_custom_filter_clause("status", "succeeded", FilterTypes::Equal)
@@ -99,6 +99,14 @@ where
                         i.authentication_type.as_ref().map(|i| i.0),
                         i.payment_method.clone(),
                         i.payment_method_type.clone(),
+                        i.card_network.clone(),
+                        i.card_type.clone(),
+                        i.card_issuing_country.clone(),
+                        i.payment_method_selection.as_ref().map(|i| i.0),
+                        i.settlement_currency.as_ref().map(|i| i.0),
+                        i.merchant_id.clone(),
+                        None,
+                        None,
                         TimeRange {
                             start_time: match (granularity, i.start_bucket) {
                                 (Some(g), Some(st)) => g.clip_to_start(st)?,
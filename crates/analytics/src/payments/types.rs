@@ -50,6 +50,51 @@ where
                 )
                 .attach_printable("Error adding payment method filter")?;
         }
+
+        if !self.card_network.is_empty() {
+            builder
+                .add_filter_in_range_clause(PaymentDimensions::CardNetwork, &self.card_network)
+                .attach_printable("Error adding card network filter")?;
+        }
+
+        if !self.card_type.is_empty() {
+            builder
+                .add_filter_in_range_clause(PaymentDimensions::CardType, &self.card_type)
+                .attach_printable("Error adding card type filter")?;
+        }
+
+        if !self.card_issuing_country.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    PaymentDimensions::CardIssuingCountry,
+                    &self.card_issuing_country,
+                )
+                .attach_printable("Error adding card issuing country filter")?;
+        }
+
+        if !self.payment_method_selection.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    PaymentDimensions::PaymentMethodSelection,
+                    &self.payment_method_selection,
+                )
+                .attach_printable("Error adding payment method selection filter")?;
+        }
+
+        if !self.settlement_currency.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    PaymentDimensions::SettlementCurrency,
+                    &self.settlement_currency,
+                )
+                .attach_printable("Error adding settlement currency filter")?;
+        }
+
+        if !self.merchant_id.is_empty() {
+            builder
+                .add_filter_in_range_clause(PaymentDimensions::MerchantId, &self.merchant_id)
+                .attach_printable("Error adding merchant id filter")?;
+        }
         Ok(())
     }
 }
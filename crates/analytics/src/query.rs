@@ -4,21 +4,22 @@ use api_models::{
     analytics::{
         self as analytics_api,
         api_event::ApiEventDimensions,
+        auth_events::AuthEventDimensions,
         disputes::DisputeDimensions,
         payments::{PaymentDimensions, PaymentDistributions},
         refunds::{RefundDimensions, RefundType},
         sdk_events::{SdkEventDimensions, SdkEventNames},
-        Granularity,
+        FilterSearchType, FilterValueSearch, Granularity,
     },
     enums::{
         AttemptStatus, AuthenticationType, Connector, Currency, DisputeStage, PaymentMethod,
-        PaymentMethodType,
+        PaymentMethodSelection, PaymentMethodType, RefundReasonCode,
     },
     refunds::RefundStatus,
 };
 use common_utils::errors::{CustomResult, ParsingError};
 use diesel_models::enums as storage_enums;
-use error_stack::{IntoReport, ResultExt};
+use error_stack::{report, IntoReport, ResultExt};
 use router_env::{logger, Flow};
 
 use super::types::{AnalyticsCollection, AnalyticsDataSource, LoadRow, TableEngine};
@@ -85,7 +86,7 @@ impl GroupByClause<super::SqlxClient> for Granularity {
         let granularity_bucket_scale = match self {
             Self::OneMin => None,
             Self::FiveMin | Self::FifteenMin | Self::ThirtyMin => Some("minute"),
-            Self::OneHour | Self::OneDay => None,
+            Self::OneHour | Self::OneDay | Self::OneWeek => None,
         };
 
         let granularity_divisor = self.get_bucket_size();
@@ -116,6 +117,7 @@ impl GroupByClause<super::ClickhouseClient> for Granularity {
             Self::ThirtyMin => "toStartOfInterval(created_at, INTERVAL 30 minute)",
             Self::OneHour => "toStartOfHour(created_at)",
             Self::OneDay => "toStartOfDay(created_at)",
+            Self::OneWeek => "toStartOfWeek(created_at, 1)",
         };
 
         builder
@@ -130,6 +132,7 @@ pub enum TimeGranularityLevel {
     Minute,
     Hour,
     Day,
+    Week,
 }
 
 impl SeriesBucket for Granularity {
@@ -144,6 +147,7 @@ impl SeriesBucket for Granularity {
                 TimeGranularityLevel::Hour
             }
             Self::OneDay => TimeGranularityLevel::Day,
+            Self::OneWeek => TimeGranularityLevel::Week,
         }
     }
 
@@ -155,6 +159,7 @@ impl SeriesBucket for Granularity {
             Self::ThirtyMin => 30,
             Self::OneHour => 60,
             Self::OneDay => 24,
+            Self::OneWeek => 7,
         }
     }
 
@@ -178,6 +183,13 @@ impl SeriesBucket for Granularity {
             (TimeGranularityLevel::Day, i) => {
                 time::Time::MIDNIGHT.replace_hour(clip_start(value.hour(), i))
             }
+            (TimeGranularityLevel::Week, _) => {
+                let days_since_monday = value.weekday().number_days_from_monday();
+                return Ok(value
+                    .date()
+                    .saturating_sub(time::Duration::days(days_since_monday.into()))
+                    .midnight());
+            }
         }
         .into_report()
         .change_context(PostProcessingError::BucketClipping)?;
@@ -205,6 +217,15 @@ impl SeriesBucket for Granularity {
             (TimeGranularityLevel::Day, i) => {
                 time::Time::MIDNIGHT.replace_hour(clip_end(value.hour(), i))
             }
+            (TimeGranularityLevel::Week, i) => {
+                let days_until_sunday = i
+                    .saturating_sub(1)
+                    .saturating_sub(value.weekday().number_days_from_monday());
+                return Ok(value
+                    .date()
+                    .saturating_add(time::Duration::days(days_until_sunday.into()))
+                    .midnight());
+            }
         }
         .into_report()
         .change_context(PostProcessingError::BucketClipping)
@@ -249,6 +270,34 @@ pub enum Aggregate<R> {
         field: R,
         alias: Option<&'static str>,
     },
+    /// Value of the field below which the percentage of values given by the percentile fall,
+    /// the percentile being between 0 and 100
+    Percentile {
+        field: R,
+        alias: Option<&'static str>,
+        percentile: u8,
+    },
+    /// Number of distinct values of the field
+    DistinctCount {
+        field: R,
+        alias: Option<&'static str>,
+    },
+    /// Number of distinct values of the field among the rows matching the condition
+    DistinctCountIf {
+        field: R,
+        condition: String,
+        alias: Option<&'static str>,
+    },
+}
+
+/// Quantile of the percentile, as taken by the quantile functions of the data sources
+pub fn get_quantile(percentile: u8) -> error_stack::Result<f64, ParsingError> {
+    if percentile > 100 {
+        Err(report!(ParsingError::EncodeError("quantile")))
+            .attach_printable_lazy(|| format!("Invalid percentile {percentile}"))
+    } else {
+        Ok(f64::from(percentile) / 100.0)
+    }
 }
 
 // Window functions in query
@@ -288,6 +337,12 @@ pub enum Order {
     Descending,
 }
 
+/// Lowercases the search text to be matched by a `LIKE` pattern. Backslashes are dropped as they
+/// escape the wildcards of the pattern, `%` and `_` in the search text act as wildcards.
+fn escape_search_text(search: &str) -> String {
+    search.trim().to_lowercase().replace('\\', "")
+}
+
 impl ToString for Order {
     fn to_string(&self) -> String {
         String::from(match self {
@@ -321,6 +376,66 @@ pub struct TopN {
     pub order: Order,
 }
 
+/// Buckets the values of a numeric field by ascending boundaries, providing the lower bound of the
+/// bucket a value falls in. Values below the first boundary fall in the bucket starting at 0.
+#[derive(Debug)]
+pub struct RangeBucket<'a> {
+    pub field: &'a str,
+    pub boundaries: &'a [i64],
+    pub alias: Option<&'a str>,
+}
+
+impl<T: AnalyticsDataSource> ToSql<T> for RangeBucket<'_> {
+    fn to_sql(&self, _table_engine: &TableEngine) -> error_stack::Result<String, ParsingError> {
+        let conditions = self
+            .boundaries
+            .iter()
+            .scan(0, |lower_bound, boundary| {
+                let condition = format!(" WHEN {} < {boundary} THEN {lower_bound}", self.field);
+                *lower_bound = *boundary;
+                Some(condition)
+            })
+            .collect::<String>();
+        let last_lower_bound = self.boundaries.last().copied().unwrap_or_default();
+        let bucket = if conditions.is_empty() {
+            last_lower_bound.to_string()
+        } else {
+            format!("CASE{conditions} ELSE {last_lower_bound} END")
+        };
+
+        Ok(format!(
+            "CAST({bucket} AS BIGINT){}",
+            self.alias
+                .map_or_else(|| "".to_owned(), |alias| format!(" as {}", alias))
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum JoinType {
+    Inner,
+    Left,
+}
+
+/// Join of another collection to the collection a query is made on.
+///
+/// Only the join keys and the listed columns of the joined collection are made available to the
+/// query, the join keys being renamed to `{alias}_{column}`. The columns of the queried collection
+/// can thus keep being referred to without being qualified, as long as the listed columns do not
+/// share their names. Collapsing collections are collapsed, and the rows of the joined collection
+/// are filtered, before being joined.
+#[derive(Debug)]
+pub struct JoinClause<'a> {
+    pub join_type: JoinType,
+    pub table: AnalyticsCollection,
+    pub alias: &'a str,
+    /// Pairs of a column of the queried collection and the column of the joined collection it
+    /// is matched with
+    pub on: &'a [(&'a str, &'a str)],
+    /// Columns of the joined collection selected along with the join keys
+    pub columns: &'a [&'a str],
+}
+
 #[derive(Debug)]
 pub struct QueryBuilder<T>
 where
@@ -328,13 +443,19 @@ where
     AnalyticsCollection: ToSql<T>,
 {
     columns: Vec<String>,
+    joins: Vec<String>,
     filters: Vec<(String, FilterTypes, String)>,
     group_by: Vec<String>,
     having: Option<Vec<(String, FilterTypes, String)>>,
     outer_select: Vec<String>,
     top_n: Option<TopN>,
+    order_by: Vec<String>,
+    limit: Option<u64>,
+    offset: Option<u64>,
     table: AnalyticsCollection,
     distinct: bool,
+    /// Values bound to the parameter placeholders of the query, in the order of their indices
+    params: Vec<QueryParam>,
     db_type: PhantomData<T>,
     table_engine: TableEngine,
 }
@@ -343,6 +464,20 @@ pub trait ToSql<T: AnalyticsDataSource> {
     fn to_sql(&self, table_engine: &TableEngine) -> error_stack::Result<String, ParsingError>;
 }
 
+/// Value bound to a parameter of a query. Values are never formatted into the query itself, the
+/// query only refers to them through the placeholders of the data source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QueryParam {
+    Text(String),
+    Int(i64),
+    Bool(bool),
+    DateTime(time::PrimitiveDateTime),
+}
+
+pub trait ToSqlParam {
+    fn to_sql_param(&self) -> error_stack::Result<QueryParam, ParsingError>;
+}
+
 /// Implement `ToSql` on arrays of types that impl `ToString`.
 macro_rules! impl_to_sql_for_to_string {
     ($($type:ty),+) => {
@@ -356,7 +491,23 @@ macro_rules! impl_to_sql_for_to_string {
      };
 }
 
-impl_to_sql_for_to_string!(
+/// Implement `ToSql` on arrays of types that impl `ToString`, binding them as text parameters.
+macro_rules! impl_to_sql_and_param_for_to_string {
+    ($($type:ty),+) => {
+        impl_to_sql_for_to_string!($($type),+);
+        $(
+            impl ToSqlParam for $type {
+                fn to_sql_param(&self) -> error_stack::Result<QueryParam, ParsingError> {
+                    Ok(QueryParam::Text(self.to_string()))
+                }
+            }
+        )+
+     };
+}
+
+impl_to_sql_for_to_string!(&bool, &u64, u64, Order);
+
+impl_to_sql_and_param_for_to_string!(
     String,
     &str,
     &PaymentDimensions,
@@ -372,48 +523,96 @@ impl_to_sql_for_to_string!(
     RefundStatus,
     storage_enums::RefundStatus,
     Currency,
+    PaymentMethodSelection,
     RefundType,
+    RefundReasonCode,
     Flow,
-    &String,
-    &bool,
-    &u64,
-    u64,
-    Order
+    &String
+);
+
+impl_to_sql_and_param_for_to_string!(&SdkEventDimensions, SdkEventDimensions, SdkEventNames);
+
+impl_to_sql_and_param_for_to_string!(&ApiEventDimensions, ApiEventDimensions);
+
+impl_to_sql_and_param_for_to_string!(&DisputeDimensions, DisputeDimensions, DisputeStage);
+
+impl_to_sql_and_param_for_to_string!(
+    &AuthEventDimensions,
+    AuthEventDimensions,
+    storage_enums::DecoupledAuthenticationType
 );
 
-impl_to_sql_for_to_string!(&SdkEventDimensions, SdkEventDimensions, SdkEventNames);
+impl ToSqlParam for i32 {
+    fn to_sql_param(&self) -> error_stack::Result<QueryParam, ParsingError> {
+        Ok(QueryParam::Int(i64::from(*self)))
+    }
+}
+
+impl ToSqlParam for i64 {
+    fn to_sql_param(&self) -> error_stack::Result<QueryParam, ParsingError> {
+        Ok(QueryParam::Int(*self))
+    }
+}
+
+impl ToSqlParam for u64 {
+    fn to_sql_param(&self) -> error_stack::Result<QueryParam, ParsingError> {
+        i64::try_from(*self)
+            .map(QueryParam::Int)
+            .into_report()
+            .change_context(ParsingError::EncodeError("64 bit signed integer"))
+    }
+}
 
-impl_to_sql_for_to_string!(&ApiEventDimensions, ApiEventDimensions);
+impl ToSqlParam for bool {
+    fn to_sql_param(&self) -> error_stack::Result<QueryParam, ParsingError> {
+        Ok(QueryParam::Bool(*self))
+    }
+}
 
-impl_to_sql_for_to_string!(&DisputeDimensions, DisputeDimensions, DisputeStage);
+impl ToSqlParam for time::PrimitiveDateTime {
+    fn to_sql_param(&self) -> error_stack::Result<QueryParam, ParsingError> {
+        Ok(QueryParam::DateTime(*self))
+    }
+}
 
 #[derive(Debug)]
 pub enum FilterTypes {
     Equal,
-    EqualBool,
     In,
     Gte,
     Lte,
     Gt,
     Like,
     NotLike,
+    StartsWith,
     IsNotNull,
 }
 
+/// Renders the filter, the right hand side being the placeholders of the bound values
 pub fn filter_type_to_sql(l: &String, op: &FilterTypes, r: &String) -> String {
     match op {
-        FilterTypes::EqualBool => format!("{l} = {r}"),
-        FilterTypes::Equal => format!("{l} = '{r}'"),
+        FilterTypes::Equal => format!("{l} = {r}"),
         FilterTypes::In => format!("{l} IN ({r})"),
-        FilterTypes::Gte => format!("{l} >= '{r}'"),
+        FilterTypes::Gte => format!("{l} >= {r}"),
         FilterTypes::Gt => format!("{l} > {r}"),
-        FilterTypes::Lte => format!("{l} <= '{r}'"),
-        FilterTypes::Like => format!("{l} LIKE '%{r}%'"),
-        FilterTypes::NotLike => format!("{l} NOT LIKE '%{r}%'"),
+        FilterTypes::Lte => format!("{l} <= {r}"),
+        FilterTypes::Like | FilterTypes::StartsWith => format!("{l} LIKE {r}"),
+        FilterTypes::NotLike => format!("{l} NOT LIKE {r}"),
         FilterTypes::IsNotNull => format!("{l} IS NOT NULL"),
     }
 }
 
+/// Turns the value of a pattern matching filter into the pattern it is matched against
+fn get_filter_pattern(comparison: &FilterTypes, param: QueryParam) -> QueryParam {
+    match (comparison, param) {
+        (FilterTypes::Like | FilterTypes::NotLike, QueryParam::Text(text)) => {
+            QueryParam::Text(format!("%{text}%"))
+        }
+        (FilterTypes::StartsWith, QueryParam::Text(text)) => QueryParam::Text(format!("{text}%")),
+        (_, param) => param,
+    }
+}
+
 impl<T> QueryBuilder<T>
 where
     T: AnalyticsDataSource,
@@ -422,13 +621,18 @@ where
     pub fn new(table: AnalyticsCollection) -> Self {
         Self {
             columns: Default::default(),
+            joins: Default::default(),
             filters: Default::default(),
             group_by: Default::default(),
             having: Default::default(),
             outer_select: Default::default(),
             top_n: Default::default(),
+            order_by: Default::default(),
+            limit: Default::default(),
+            offset: Default::default(),
             table,
             distinct: Default::default(),
+            params: Default::default(),
             db_type: Default::default(),
             table_engine: T::get_table_engine(table),
         }
@@ -487,63 +691,211 @@ where
         Ok(())
     }
 
+    /// Joins the collection to the queried collection, the filters set on the builder passed to
+    /// `set_filters` are applied to the joined collection within the subquery it is selected by
+    pub fn add_join_clause(
+        &mut self,
+        join: JoinClause<'_>,
+        set_filters: impl FnOnce(&mut Self) -> QueryResult<()>,
+    ) -> QueryResult<()> {
+        if join.on.is_empty() {
+            Err(QueryBuildingError::InvalidQuery("No join keys provided")).into_report()?;
+        }
+        let table_engine = T::get_table_engine(join.table);
+        let table = join
+            .table
+            .to_sql(&table_engine)
+            .change_context(QueryBuildingError::SqlSerializeError)
+            .attach_printable("Error serializing joined table")?;
+        let collapse = match table_engine {
+            TableEngine::CollapsingMergeTree { .. } => " FINAL",
+            TableEngine::BasicTree => "",
+        };
+        let alias = join.alias;
+
+        let columns = join
+            .on
+            .iter()
+            .map(|(_, joined_column)| format!("{joined_column} AS {alias}_{joined_column}"))
+            .chain(join.columns.iter().map(|column| column.to_string()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let condition = join
+            .on
+            .iter()
+            .map(|(column, joined_column)| format!("{column} = {alias}_{joined_column}"))
+            .collect::<Vec<_>>()
+            .join(" AND ");
+        let join_type = match join.join_type {
+            JoinType::Inner => "INNER JOIN",
+            JoinType::Left => "LEFT JOIN",
+        };
+
+        let mut joined = Self::new(join.table);
+        joined.params = std::mem::take(&mut self.params);
+        let filters = set_filters(&mut joined).map(|()| joined.get_filter_clause());
+        self.params = joined.params;
+        let filters = filters?;
+        let where_clause = if filters.is_empty() {
+            String::new()
+        } else {
+            format!(" WHERE {filters}")
+        };
+
+        self.joins.push(format!(
+            "{join_type} (SELECT {columns} FROM {table}{collapse}{where_clause}) AS {alias} ON {condition}"
+        ));
+        Ok(())
+    }
+
     pub fn set_distinct(&mut self) {
         self.distinct = true
     }
 
-    pub fn add_filter_clause(
+    pub fn add_order_by_clause(
         &mut self,
-        key: impl ToSql<T>,
-        value: impl ToSql<T>,
+        column: impl ToSql<T>,
+        order: impl ToSql<T>,
     ) -> QueryResult<()> {
-        self.add_custom_filter_clause(key, value, FilterTypes::Equal)
+        let column_sql = column
+            .to_sql(&self.table_engine)
+            .change_context(QueryBuildingError::SqlSerializeError)
+            .attach_printable("Error serializing order by column")?;
+        let order_sql = order
+            .to_sql(&self.table_engine)
+            .change_context(QueryBuildingError::SqlSerializeError)
+            .attach_printable("Error serializing order by direction")?;
+        self.order_by.push(format!("{column_sql} {order_sql}"));
+        Ok(())
+    }
+
+    pub fn set_limit_by(&mut self, limit: u64) {
+        self.limit = Some(limit)
+    }
+
+    pub fn set_offset(&mut self, offset: u64) {
+        self.offset = Some(offset)
+    }
+
+    /// Restricts the values of the column to the ones matching the search text, and pages through
+    /// them in the order of the values. The column is compared as lowercase text so that enum
+    /// columns can be searched as well.
+    pub fn add_filter_value_search(
+        &mut self,
+        column: impl ToSql<T>,
+        value_search: &FilterValueSearch,
+    ) -> QueryResult<()> {
+        let column_sql = column
+            .to_sql(&self.table_engine)
+            .change_context(QueryBuildingError::SqlSerializeError)
+            .attach_printable("Error serializing search column")?;
+
+        if let Some(search) = value_search
+            .search
+            .as_deref()
+            .map(escape_search_text)
+            .filter(|search| !search.is_empty())
+        {
+            let filter_type = match value_search.search_type {
+                FilterSearchType::Prefix => FilterTypes::StartsWith,
+                FilterSearchType::Substring => FilterTypes::Like,
+            };
+            self.add_custom_filter_clause(
+                format!("LOWER(CAST({column_sql} AS TEXT))"),
+                search,
+                filter_type,
+            )?;
+        }
+
+        if value_search.limit.is_some() || value_search.offset.is_some() {
+            self.add_order_by_clause(column_sql, Order::Ascending)?;
+        }
+        if let Some(limit) = value_search.limit {
+            self.set_limit_by(limit);
+        }
+        if let Some(offset) = value_search.offset {
+            self.set_offset(offset);
+        }
+        Ok(())
+    }
+
+    /// Binds the value as a parameter of the query, returning the placeholder referring to it
+    pub fn add_param(&mut self, value: impl ToSqlParam) -> QueryResult<String> {
+        let param = value
+            .to_sql_param()
+            .change_context(QueryBuildingError::SqlSerializeError)
+            .attach_printable("Error serializing query parameter")?;
+        Ok(self.bind_param(param))
+    }
+
+    fn bind_param(&mut self, param: QueryParam) -> String {
+        let placeholder = T::get_placeholder(self.params.len(), &param);
+        self.params.push(param);
+        placeholder
     }
 
-    pub fn add_bool_filter_clause(
+    pub fn add_filter_clause(
         &mut self,
         key: impl ToSql<T>,
-        value: impl ToSql<T>,
+        value: impl ToSqlParam,
     ) -> QueryResult<()> {
-        self.add_custom_filter_clause(key, value, FilterTypes::EqualBool)
+        self.add_custom_filter_clause(key, value, FilterTypes::Equal)
+    }
+
+    pub fn add_bool_filter_clause(&mut self, key: impl ToSql<T>, value: bool) -> QueryResult<()> {
+        self.add_custom_filter_clause(key, value, FilterTypes::Equal)
+    }
+
+    pub fn add_is_not_null_filter_clause(&mut self, key: impl ToSql<T>) -> QueryResult<()> {
+        self.push_filter(key, FilterTypes::IsNotNull, String::new())
     }
 
     pub fn add_custom_filter_clause(
         &mut self,
         lhs: impl ToSql<T>,
-        rhs: impl ToSql<T>,
+        rhs: impl ToSqlParam,
         comparison: FilterTypes,
     ) -> QueryResult<()> {
-        self.filters.push((
-            lhs.to_sql(&self.table_engine)
-                .change_context(QueryBuildingError::SqlSerializeError)
-                .attach_printable("Error serializing filter key")?,
-            comparison,
-            rhs.to_sql(&self.table_engine)
-                .change_context(QueryBuildingError::SqlSerializeError)
-                .attach_printable("Error serializing filter value")?,
-        ));
-        Ok(())
+        let param = rhs
+            .to_sql_param()
+            .change_context(QueryBuildingError::SqlSerializeError)
+            .attach_printable("Error serializing filter value")?;
+        let placeholder = self.bind_param(get_filter_pattern(&comparison, param));
+        self.push_filter(lhs, comparison, placeholder)
     }
 
     pub fn add_filter_in_range_clause(
         &mut self,
         key: impl ToSql<T>,
-        values: &[impl ToSql<T>],
+        values: &[impl ToSqlParam],
     ) -> QueryResult<()> {
         let list = values
             .iter()
-            .map(|i| {
-                // trimming whitespaces from the filter values received in request, to prevent a possibility of an SQL injection
-                i.to_sql(&self.table_engine).map(|s| {
-                    let trimmed_str = s.replace(' ', "");
-                    format!("'{trimmed_str}'")
-                })
-            })
-            .collect::<error_stack::Result<Vec<String>, ParsingError>>()
+            .map(|value| value.to_sql_param())
+            .collect::<error_stack::Result<Vec<QueryParam>, ParsingError>>()
             .change_context(QueryBuildingError::SqlSerializeError)
             .attach_printable("Error serializing range filter value")?
+            .into_iter()
+            .map(|param| self.bind_param(param))
+            .collect::<Vec<String>>()
             .join(", ");
-        self.add_custom_filter_clause(key, list, FilterTypes::In)
+        self.push_filter(key, FilterTypes::In, list)
+    }
+
+    fn push_filter(
+        &mut self,
+        lhs: impl ToSql<T>,
+        comparison: FilterTypes,
+        placeholders: String,
+    ) -> QueryResult<()> {
+        self.filters.push((
+            lhs.to_sql(&self.table_engine)
+                .change_context(QueryBuildingError::SqlSerializeError)
+                .attach_printable("Error serializing filter key")?,
+            comparison,
+            placeholders,
+        ));
+        Ok(())
     }
 
     pub fn add_group_by_clause(&mut self, column: impl ToSql<T>) -> QueryResult<()> {
@@ -558,15 +910,17 @@ where
 
     pub fn add_granularity_in_mins(&mut self, granularity: &Granularity) -> QueryResult<()> {
         let interval = match granularity {
-            Granularity::OneMin => "1",
-            Granularity::FiveMin => "5",
-            Granularity::FifteenMin => "15",
-            Granularity::ThirtyMin => "30",
-            Granularity::OneHour => "60",
-            Granularity::OneDay => "1440",
+            Granularity::OneMin => "1 MINUTE",
+            Granularity::FiveMin => "5 MINUTE",
+            Granularity::FifteenMin => "15 MINUTE",
+            Granularity::ThirtyMin => "30 MINUTE",
+            Granularity::OneHour => "60 MINUTE",
+            Granularity::OneDay => "1440 MINUTE",
+            // Week long intervals are aligned to mondays instead of the unix epoch
+            Granularity::OneWeek => "1 WEEK",
         };
         let _ = self.add_select_column(format!(
-            "toStartOfInterval(created_at, INTERVAL {interval} MINUTE) as time_bucket"
+            "toStartOfInterval(created_at, INTERVAL {interval}) as time_bucket"
         ));
         Ok(())
     }
@@ -591,11 +945,15 @@ where
         self.outer_select.join(", ")
     }
 
+    fn get_order_by_clause(&self) -> String {
+        self.order_by.join(", ")
+    }
+
     pub fn add_having_clause<R>(
         &mut self,
         aggregate: Aggregate<R>,
         filter_type: FilterTypes,
-        value: impl ToSql<T>,
+        value: impl ToSqlParam,
     ) -> QueryResult<()>
     where
         Aggregate<R>: ToSql<T>,
@@ -604,9 +962,8 @@ where
             .to_sql(&self.table_engine)
             .change_context(QueryBuildingError::SqlSerializeError)
             .attach_printable("Error serializing having aggregate")?;
-        let value = value
-            .to_sql(&self.table_engine)
-            .change_context(QueryBuildingError::SqlSerializeError)
+        let value = self
+            .add_param(value)
             .attach_printable("Error serializing having value")?;
         let entry = (aggregate, filter_type, value);
         if let Some(having) = &mut self.having {
@@ -665,6 +1022,11 @@ where
                 .attach_printable("Error serializing table value")?,
         );
 
+        for join in self.joins.iter() {
+            query.push(' ');
+            query.push_str(join);
+        }
+
         if !self.filters.is_empty() {
             query.push_str(" WHERE ");
             query.push_str(&self.get_filter_clause());
@@ -680,7 +1042,7 @@ where
                         alias: None,
                     },
                     FilterTypes::Gte,
-                    "1",
+                    1,
                 )?;
             }
         }
@@ -692,6 +1054,21 @@ where
             }
         }
 
+        if !self.order_by.is_empty() {
+            query.push_str(" ORDER BY ");
+            query.push_str(&self.get_order_by_clause());
+        }
+
+        if let Some(limit) = self.limit {
+            query.push_str(" LIMIT ");
+            query.push_str(&limit.to_string());
+        }
+
+        if let Some(offset) = self.offset {
+            query.push_str(" OFFSET ");
+            query.push_str(&offset.to_string());
+        }
+
         if !self.outer_select.is_empty() {
             query.insert_str(
                 0,
@@ -710,6 +1087,24 @@ where
         Ok(query)
     }
 
+    /// Builds a query over the collection to be nested within this query, the values bound by the
+    /// subquery are bound along with the ones of this query.
+    pub fn build_subquery(
+        &mut self,
+        table: AnalyticsCollection,
+        build: impl FnOnce(&mut Self) -> QueryResult<()>,
+    ) -> QueryResult<String>
+    where
+        Aggregate<&'static str>: ToSql<T>,
+        Window<&'static str>: ToSql<T>,
+    {
+        let mut subquery = Self::new(table);
+        subquery.params = std::mem::take(&mut self.params);
+        let query = build(&mut subquery).and_then(|()| subquery.build_query());
+        self.params = subquery.params;
+        query
+    }
+
     pub async fn execute_query<R, P: AnalyticsDataSource>(
         &mut self,
         store: &P,
@@ -724,6 +1119,95 @@ where
             .change_context(QueryBuildingError::SqlSerializeError)
             .attach_printable("Failed to execute query")?;
         logger::debug!(?query);
-        Ok(store.load_results(query.as_str()).await)
+        Ok(store.load_results(query.as_str(), &self.params).await)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use api_models::analytics::TimeRange;
+    use time::{Date, Month, PrimitiveDateTime, Time};
+
+    use super::{JoinClause, JoinType, QueryBuilder, QueryFilter};
+    use crate::{clickhouse::ClickhouseClient, types::AnalyticsCollection};
+
+    fn on_day(day: u8) -> PrimitiveDateTime {
+        PrimitiveDateTime::new(
+            Date::from_calendar_date(2024, Month::April, day).unwrap(),
+            Time::MIDNIGHT,
+        )
+    }
+
+    #[test]
+    fn test_joined_collection_is_collapsed_and_keys_are_renamed() {
+        let mut query_builder: QueryBuilder<ClickhouseClient> =
+            QueryBuilder::new(AnalyticsCollection::Payment);
+        query_builder.add_select_column("connector").unwrap();
+        query_builder.add_select_column("business_label").unwrap();
+        query_builder
+            .add_join_clause(
+                JoinClause {
+                    join_type: JoinType::Inner,
+                    table: AnalyticsCollection::PaymentIntent,
+                    alias: "intent",
+                    on: &[("merchant_id", "merchant_id"), ("payment_id", "payment_id")],
+                    columns: &["business_label"],
+                },
+                |_| Ok(()),
+            )
+            .unwrap();
+        query_builder
+            .add_filter_clause("merchant_id", "merchant_1")
+            .unwrap();
+
+        assert_eq!(
+            query_builder.build_query().unwrap(),
+            "SELECT connector, business_label FROM payment_attempts \
+             INNER JOIN (SELECT merchant_id AS intent_merchant_id, payment_id AS intent_payment_id, \
+             business_label FROM payment_intents FINAL) AS intent \
+             ON merchant_id = intent_merchant_id AND payment_id = intent_payment_id \
+             WHERE merchant_id = {p0:String}"
+        );
+    }
+
+    #[test]
+    fn test_joined_collection_is_filtered_within_the_subquery() {
+        let mut query_builder: QueryBuilder<ClickhouseClient> =
+            QueryBuilder::new(AnalyticsCollection::Payment);
+        query_builder.add_select_column("connector").unwrap();
+        let time_range = TimeRange {
+            start_time: on_day(1),
+            end_time: Some(on_day(2)),
+        };
+        query_builder
+            .add_join_clause(
+                JoinClause {
+                    join_type: JoinType::Inner,
+                    table: AnalyticsCollection::PaymentIntent,
+                    alias: "intent",
+                    on: &[("payment_id", "payment_id")],
+                    columns: &[],
+                },
+                |intents| {
+                    intents.add_filter_clause("merchant_id", "merchant_1")?;
+                    time_range.set_filter_clause(intents)
+                },
+            )
+            .unwrap();
+        query_builder
+            .add_filter_clause("merchant_id", "merchant_1")
+            .unwrap();
+        time_range.set_filter_clause(&mut query_builder).unwrap();
+
+        assert_eq!(
+            query_builder.build_query().unwrap(),
+            "SELECT connector FROM payment_attempts \
+             INNER JOIN (SELECT payment_id AS intent_payment_id FROM payment_intents FINAL \
+             WHERE merchant_id = {p0:String} AND created_at >= {p1:DateTime} \
+             AND created_at <= {p2:DateTime}) AS intent ON payment_id = intent_payment_id \
+             WHERE merchant_id = {p3:String} AND created_at >= {p4:DateTime} \
+             AND created_at <= {p5:DateTime}"
+        );
     }
 }
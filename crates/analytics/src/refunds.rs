@@ -3,8 +3,9 @@ mod core;
 
 pub mod filters;
 pub mod metrics;
+pub mod top_reasons;
 pub mod types;
 pub use accumulator::{RefundMetricAccumulator, RefundMetricsAccumulator};
 
 pub trait RefundAnalytics: metrics::RefundMetricAnalytics {}
-pub use self::core::{get_filters, get_metrics};
+pub use self::core::{get_filters, get_metrics, get_top_reasons};
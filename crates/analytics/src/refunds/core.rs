@@ -3,10 +3,11 @@ use std::collections::HashMap;
 
 use api_models::analytics::{
     refunds::{
-        RefundDimensions, RefundMetrics, RefundMetricsBucketIdentifier, RefundMetricsBucketResponse,
+        RefundDimensions, RefundMetrics, RefundMetricsBucketIdentifier,
+        RefundMetricsBucketResponse, RefundReasonValue,
     },
-    AnalyticsMetadata, GetRefundFilterRequest, GetRefundMetricRequest, MetricsResponse,
-    RefundFilterValue, RefundFiltersResponse,
+    AnalyticsMetadata, GetRefundFilterRequest, GetRefundMetricRequest, GetRefundTopReasonsRequest,
+    MetricsResponse, QueryLimit, RefundFilterValue, RefundFiltersResponse,
 };
 use error_stack::{IntoReport, ResultExt};
 use router_env::{
@@ -16,26 +17,48 @@ use router_env::{
 
 use super::{
     filters::{get_refund_filter_for_dimension, RefundFilterRow},
+    metrics::RefundMetricRow,
+    top_reasons::get_top_refund_reasons,
     RefundMetricsAccumulator,
 };
 use crate::{
+    cache::MetricCacheKey,
+    core::get_time_series_or_default,
     errors::{AnalyticsError, AnalyticsResult},
     metrics,
     refunds::RefundMetricAccumulator,
-    AnalyticsProvider,
+    reporting_currency::ReportingCurrencyRates,
+    types::MetricsResult,
+    AnalyticsDomain, AnalyticsProvider,
 };
 
 pub async fn get_metrics(
     pool: &AnalyticsProvider,
     merchant_id: &String,
     req: GetRefundMetricRequest,
+    reporting_currency_rates: Option<ReportingCurrencyRates>,
 ) -> AnalyticsResult<MetricsResponse<RefundMetricsBucketResponse>> {
+    let req = GetRefundMetricRequest {
+        time_series: Some(get_time_series_or_default(req.time_series, &req.time_range)),
+        ..req
+    };
+    let _query_permit = pool.guardrails().acquire_query_permit(merchant_id)?;
+    pool.guardrails().validate_time_range(
+        req.time_series.as_ref().map(|series| &series.granularity),
+        &req.time_range,
+    )?;
+
     let mut metrics_accumulator: HashMap<RefundMetricsBucketIdentifier, RefundMetricsAccumulator> =
         HashMap::new();
     let mut set = tokio::task::JoinSet::new();
     for metric_type in req.metrics.iter().cloned() {
         let req = req.clone();
         let pool = pool.clone();
+        let group_by_names = get_group_by_names_for_metric(
+            &metric_type,
+            &req.group_by_names,
+            reporting_currency_rates.is_some(),
+        );
         let task_span = tracing::debug_span!(
             "analytics_refund_query",
             refund_metric = metric_type.as_ref()
@@ -45,17 +68,15 @@ pub async fn get_metrics(
         let merchant_id_scoped = merchant_id.to_owned();
         set.spawn(
             async move {
-                let data = pool
-                    .get_refund_metrics(
-                        &metric_type,
-                        &req.group_by_names.clone(),
-                        &merchant_id_scoped,
-                        &req.filters,
-                        &req.time_series.map(|t| t.granularity),
-                        &req.time_range,
-                    )
-                    .await
-                    .change_context(AnalyticsError::UnknownError);
+                let data = get_cached_refund_metrics(
+                    &pool,
+                    &metric_type,
+                    &group_by_names,
+                    &merchant_id_scoped,
+                    &req,
+                )
+                .await
+                .change_context(AnalyticsError::UnknownError);
                 (metric_type, data)
             }
             .instrument(task_span),
@@ -81,7 +102,13 @@ pub async fn get_metrics(
             logger::debug!("Attributes: {:?}, Buckets fetched: {}", attributes, val);
         }
 
-        for (id, value) in data {
+        for (mut id, mut value) in data {
+            if let Some(rates) = reporting_currency_rates
+                .as_ref()
+                .filter(|_| is_amount_metric(&metric))
+            {
+                convert_to_reporting_currency(rates, &mut id, &mut value, &req.group_by_names)?;
+            }
             logger::debug!(bucket_id=?id, bucket_value=?value, "Bucket row for metric {metric}");
             let metrics_builder = metrics_accumulator.entry(id).or_default();
             match metric {
@@ -118,24 +145,101 @@ pub async fn get_metrics(
         query_data,
         meta_data: [AnalyticsMetadata {
             current_time_range: req.time_range,
+            granularity: req.time_series.map(|series| series.granularity),
         }],
     })
 }
 
+/// Metrics whose values are amounts, these are converted to the reporting currency when one is
+/// requested
+fn is_amount_metric(metric: &RefundMetrics) -> bool {
+    matches!(metric, RefundMetrics::RefundProcessedAmount)
+}
+
+/// Amounts can only be converted per currency, so amount metrics are always grouped by currency
+/// when they have to be converted to a reporting currency
+fn get_group_by_names_for_metric(
+    metric: &RefundMetrics,
+    group_by_names: &[RefundDimensions],
+    convert_to_reporting_currency: bool,
+) -> Vec<RefundDimensions> {
+    let mut group_by_names = group_by_names.to_vec();
+    if convert_to_reporting_currency
+        && is_amount_metric(metric)
+        && !group_by_names.contains(&RefundDimensions::Currency)
+    {
+        group_by_names.push(RefundDimensions::Currency);
+    }
+    group_by_names
+}
+
+/// Returns the cached result of the metric, querying the data source only on a cache miss
+async fn get_cached_refund_metrics(
+    pool: &AnalyticsProvider,
+    metric: &RefundMetrics,
+    group_by_names: &[RefundDimensions],
+    merchant_id: &str,
+    req: &GetRefundMetricRequest,
+) -> MetricsResult<Vec<(RefundMetricsBucketIdentifier, RefundMetricRow)>> {
+    let granularity = req.time_series.as_ref().map(|t| t.granularity);
+    let merchant_ids = [merchant_id.to_string()];
+    let cache_key = MetricCacheKey {
+        domain: AnalyticsDomain::Refunds,
+        metric,
+        merchant_ids: &merchant_ids,
+        dimensions: group_by_names,
+        filters: &req.filters,
+        granularity,
+        time_range: req.time_range,
+    };
+    pool.cache()
+        .get_or_load(cache_key, req.bypass_cache, |time_range| async move {
+            pool.get_refund_metrics(
+                metric,
+                group_by_names,
+                merchant_id,
+                &req.filters,
+                &granularity,
+                &time_range,
+            )
+            .await
+        })
+        .await
+}
+
+/// Converts the bucket total to the reporting currency using the rates in effect at the start of
+/// the bucket, the bucket is merged with the other currencies unless grouped by currency
+fn convert_to_reporting_currency(
+    rates: &ReportingCurrencyRates,
+    id: &mut RefundMetricsBucketIdentifier,
+    row: &mut RefundMetricRow,
+    group_by_names: &[RefundDimensions],
+) -> AnalyticsResult<()> {
+    if let (Some(total), Some(currency)) = (row.total.as_ref(), id.currency) {
+        row.total = Some(rates.convert_amount(total, currency, id.start_time.date())?);
+    }
+    if !group_by_names.contains(&RefundDimensions::Currency) {
+        id.currency = None;
+        row.currency = None;
+    }
+    Ok(())
+}
+
 pub async fn get_filters(
     pool: &AnalyticsProvider,
     req: GetRefundFilterRequest,
     merchant_id: &String,
 ) -> AnalyticsResult<RefundFiltersResponse> {
+    let _query_permit = pool.guardrails().acquire_query_permit(merchant_id)?;
     let mut res = RefundFiltersResponse::default();
     for dim in req.group_by_names {
         let values = match pool {
                         AnalyticsProvider::Sqlx(pool) => {
-                get_refund_filter_for_dimension(dim, merchant_id, &req.time_range, pool)
+                get_refund_filter_for_dimension(dim, merchant_id, &req.time_range, &req.value_search, pool)
                     .await
             }
                         AnalyticsProvider::Clickhouse(pool) => {
-                get_refund_filter_for_dimension(dim, merchant_id, &req.time_range, pool)
+                get_refund_filter_for_dimension(dim, merchant_id, &req.time_range, &req.value_search, pool)
                     .await
             }
                     AnalyticsProvider::CombinedCkh(sqlx_pool, ckh_pool) => {
@@ -143,6 +247,7 @@ pub async fn get_filters(
                     dim,
                     merchant_id,
                     &req.time_range,
+                    &req.value_search,
                     ckh_pool,
                 )
                 .await;
@@ -150,6 +255,7 @@ pub async fn get_filters(
                     dim,
                     merchant_id,
                     &req.time_range,
+                    &req.value_search,
                     sqlx_pool,
                 )
                 .await;
@@ -166,6 +272,7 @@ pub async fn get_filters(
                     dim,
                     merchant_id,
                     &req.time_range,
+                    &req.value_search,
                     ckh_pool,
                 )
                 .await;
@@ -173,6 +280,7 @@ pub async fn get_filters(
                     dim,
                     merchant_id,
                     &req.time_range,
+                    &req.value_search,
                     sqlx_pool,
                 )
                 .await;
@@ -192,6 +300,10 @@ pub async fn get_filters(
             RefundDimensions::RefundStatus => fil.refund_status.map(|i| i.as_ref().to_string()),
             RefundDimensions::Connector => fil.connector,
             RefundDimensions::RefundType => fil.refund_type.map(|i| i.as_ref().to_string()),
+            RefundDimensions::RefundReason => fil.refund_reason,
+            RefundDimensions::RefundReasonCode => {
+                fil.refund_reason_code.map(|i| i.as_ref().to_string())
+            }
         })
         .collect::<Vec<String>>();
         res.query_data.push(RefundFilterValue {
@@ -201,3 +313,63 @@ pub async fn get_filters(
     }
     Ok(res)
 }
+
+pub async fn get_top_reasons(
+    pool: &AnalyticsProvider,
+    merchant_id: &str,
+    req: GetRefundTopReasonsRequest,
+) -> AnalyticsResult<MetricsResponse<RefundReasonValue>> {
+    let _query_permit = pool.guardrails().acquire_query_permit(merchant_id)?;
+    let limit: u64 = req.limit.unwrap_or(QueryLimit::Top10).into();
+    let rows = match pool {
+        AnalyticsProvider::Sqlx(pool) | AnalyticsProvider::CombinedSqlx(pool, _) => {
+            get_top_refund_reasons(
+                merchant_id,
+                &req.filters,
+                &req.time_range,
+                req.order_by,
+                limit,
+                pool,
+            )
+            .await
+        }
+        AnalyticsProvider::Clickhouse(pool) | AnalyticsProvider::CombinedCkh(_, pool) => {
+            get_top_refund_reasons(
+                merchant_id,
+                &req.filters,
+                &req.time_range,
+                req.order_by,
+                limit,
+                pool,
+            )
+            .await
+        }
+    }
+    .change_context(AnalyticsError::UnknownError)?;
+
+    let query_data = rows
+        .into_iter()
+        .filter_map(|row| {
+            row.refund_reason.map(|refund_reason| RefundReasonValue {
+                refund_reason,
+                refund_count: row
+                    .count
+                    .and_then(|count| u64::try_from(count).ok())
+                    .unwrap_or_default(),
+                refund_amount: row
+                    .total
+                    .as_ref()
+                    .and_then(bigdecimal::ToPrimitive::to_u64)
+                    .unwrap_or_default(),
+            })
+        })
+        .collect();
+
+    Ok(MetricsResponse {
+        query_data,
+        meta_data: [AnalyticsMetadata {
+            current_time_range: req.time_range,
+            granularity: None,
+        }],
+    })
+}
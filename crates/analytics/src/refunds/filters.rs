@@ -1,9 +1,9 @@
 use api_models::analytics::{
     refunds::{RefundDimensions, RefundType},
-    Granularity, TimeRange,
+    FilterValueSearch, Granularity, TimeRange,
 };
 use common_utils::errors::ReportSwitchExt;
-use diesel_models::enums::{Currency, RefundStatus};
+use diesel_models::enums::{Currency, RefundReasonCode, RefundStatus};
 use error_stack::ResultExt;
 use time::PrimitiveDateTime;
 
@@ -20,6 +20,7 @@ pub async fn get_refund_filter_for_dimension<T>(
     dimension: RefundDimensions,
     merchant: &String,
     time_range: &TimeRange,
+    value_search: &FilterValueSearch,
     pool: &T,
 ) -> FiltersResult<Vec<RefundFilterRow>>
 where
@@ -42,6 +43,10 @@ where
         .add_filter_clause("merchant_id", merchant)
         .switch()?;
 
+    query_builder
+        .add_filter_value_search(&dimension, value_search)
+        .switch()?;
+
     query_builder.set_distinct();
 
     query_builder
@@ -56,4 +61,6 @@ pub struct RefundFilterRow {
     pub refund_status: Option<DBEnumWrapper<RefundStatus>>,
     pub connector: Option<String>,
     pub refund_type: Option<DBEnumWrapper<RefundType>>,
+    pub refund_reason: Option<String>,
+    pub refund_reason_code: Option<DBEnumWrapper<RefundReasonCode>>,
 }
@@ -97,6 +97,8 @@ where
                         i.refund_status.as_ref().map(|i| i.0.to_string()),
                         i.connector.clone(),
                         i.refund_type.as_ref().map(|i| i.0.to_string()),
+                        i.refund_reason.clone(),
+                        i.refund_reason_code.as_ref().map(|i| i.0.to_string()),
                         TimeRange {
                             start_time: match (granularity, i.start_bucket) {
                                 (Some(g), Some(st)) => g.clip_to_start(st)?,
@@ -100,6 +100,8 @@ where
                         None,
                         i.connector.clone(),
                         i.refund_type.as_ref().map(|i| i.0.to_string()),
+                        i.refund_reason.clone(),
+                        i.refund_reason_code.as_ref().map(|i| i.0.to_string()),
                         TimeRange {
                             start_time: match (granularity, i.start_bucket) {
                                 (Some(g), Some(st)) => g.clip_to_start(st)?,
@@ -121,3 +123,85 @@ where
             .change_context(MetricsError::PostProcessingFailure)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use api_models::analytics::{
+        refunds::{RefundFilters, RefundMetricsBucketIdentifier},
+        TimeRange,
+    };
+    use common_utils::date_time;
+    use diesel_models::enums as storage_enums;
+
+    use super::RefundSuccessCount;
+    use crate::{
+        in_memory::InMemoryClient, query::QueryParam, refunds::metrics::RefundMetric,
+        types::AnalyticsCollection,
+    };
+
+    #[tokio::test]
+    async fn test_refund_success_count_filters_successful_refunds() {
+        let pool = InMemoryClient::default().with_rows(
+            AnalyticsCollection::Refund,
+            [serde_json::json!({
+                "currency": "USD",
+                "refund_status": "success",
+                "connector": "stripe",
+                "refund_type": null,
+                "refund_reason": null,
+                "refund_reason_code": null,
+                "total": null,
+                "count": 3,
+                "start_bucket": null,
+                "end_bucket": null,
+            })],
+        );
+        let time_range = TimeRange {
+            start_time: date_time::now(),
+            end_time: None,
+        };
+
+        let buckets = RefundSuccessCount::default()
+            .load_metrics(
+                &[],
+                "merchant_1",
+                &RefundFilters::default(),
+                &None,
+                &time_range,
+                &pool,
+            )
+            .await
+            .expect("Failed to load refund success count");
+
+        let queries = pool.executed_queries();
+        let executed = queries.first().expect("No query was executed");
+        assert!(executed.query.contains("FROM refunds"));
+        assert!(executed.query.contains("merchant_id = {p0:String}"));
+        assert!(executed.query.contains("created_at >= {p1:DateTime}"));
+        assert!(executed.query.contains("refund_status = {p2:String}"));
+        assert!(!executed.query.contains("merchant_1"));
+        assert_eq!(
+            executed.params,
+            vec![
+                QueryParam::Text("merchant_1".to_string()),
+                QueryParam::DateTime(time_range.start_time),
+                QueryParam::Text("success".to_string()),
+            ]
+        );
+
+        let (identifier, row) = buckets.first().expect("No metric bucket was returned");
+        assert_eq!(
+            identifier,
+            &RefundMetricsBucketIdentifier::new(
+                Some(storage_enums::Currency::USD),
+                None,
+                Some("stripe".to_string()),
+                None,
+                None,
+                None,
+                time_range,
+            )
+        );
+        assert_eq!(row.count, Some(3));
+    }
+}
@@ -0,0 +1,96 @@
+use api_models::analytics::{
+    refunds::{RefundDimensions, RefundFilters, RefundReasonOrderBy},
+    Granularity, TimeRange,
+};
+use common_utils::errors::ReportSwitchExt;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use super::metrics::{RefundMetricAnalytics, RefundMetricRow};
+use crate::{
+    query::{Aggregate, GroupByClause, Order, QueryBuilder, QueryFilter, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, MetricsError, MetricsResult},
+};
+
+/// Loads the refund count and refunded amount per refund reason, ordered by the requested
+/// measure and limited to the top `limit` reasons.
+pub async fn get_top_refund_reasons<T>(
+    merchant_id: &str,
+    filters: &RefundFilters,
+    time_range: &TimeRange,
+    order_by: RefundReasonOrderBy,
+    limit: u64,
+    pool: &T,
+) -> MetricsResult<Vec<RefundMetricRow>>
+where
+    T: AnalyticsDataSource + RefundMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::Refund);
+
+    query_builder
+        .add_select_column(RefundDimensions::RefundReason)
+        .switch()?;
+    query_builder
+        .add_select_column(Aggregate::Count {
+            field: None,
+            alias: Some("count"),
+        })
+        .switch()?;
+    query_builder
+        .add_select_column(Aggregate::Sum {
+            field: "refund_amount",
+            alias: Some("total"),
+        })
+        .switch()?;
+    query_builder
+        .add_select_column(Aggregate::Min {
+            field: "created_at",
+            alias: Some("start_bucket"),
+        })
+        .switch()?;
+    query_builder
+        .add_select_column(Aggregate::Max {
+            field: "created_at",
+            alias: Some("end_bucket"),
+        })
+        .switch()?;
+
+    filters.set_filter_clause(&mut query_builder).switch()?;
+
+    query_builder
+        .add_filter_clause("merchant_id", merchant_id)
+        .switch()?;
+
+    query_builder
+        .add_is_not_null_filter_clause(RefundDimensions::RefundReason)
+        .switch()?;
+
+    time_range
+        .set_filter_clause(&mut query_builder)
+        .attach_printable("Error filtering time range")
+        .switch()?;
+
+    query_builder
+        .add_group_by_clause(RefundDimensions::RefundReason)
+        .switch()?;
+
+    let order_column = match order_by {
+        RefundReasonOrderBy::Count => "count",
+        RefundReasonOrderBy::Amount => "total",
+    };
+    query_builder
+        .add_order_by_clause(order_column, Order::Descending)
+        .switch()?;
+    query_builder.set_limit_by(limit);
+
+    query_builder
+        .execute_query::<RefundMetricRow, _>(pool)
+        .await
+        .change_context(MetricsError::QueryBuildingError)?
+        .change_context(MetricsError::QueryExecutionFailure)
+}
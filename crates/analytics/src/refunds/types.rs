@@ -36,6 +36,21 @@ where
                 .attach_printable("Error adding auth type filter")?;
         }
 
+        if !self.refund_reason.is_empty() {
+            builder
+                .add_filter_in_range_clause(RefundDimensions::RefundReason, &self.refund_reason)
+                .attach_printable("Error adding refund reason filter")?;
+        }
+
+        if !self.refund_reason_code.is_empty() {
+            builder
+                .add_filter_in_range_clause(
+                    RefundDimensions::RefundReasonCode,
+                    &self.refund_reason_code,
+                )
+                .attach_printable("Error adding refund reason code filter")?;
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,88 @@
+use std::collections::BTreeMap;
+
+use bigdecimal::{BigDecimal, ToPrimitive};
+use common_utils::errors::CustomResult;
+use currency_conversion::{conversion::convert, types::ExchangeRates};
+use diesel_models::enums::Currency;
+use error_stack::{IntoReport, ResultExt};
+use rust_decimal::{prelude::ToPrimitive as _, Decimal};
+use time::Date;
+
+use crate::errors::AnalyticsError;
+
+/// Daily exchange rates used to convert the amount metrics to a single reporting currency
+#[derive(Debug, Clone)]
+pub struct ReportingCurrencyRates {
+    pub reporting_currency: Currency,
+    pub rates: BTreeMap<Date, ExchangeRates>,
+}
+
+impl ReportingCurrencyRates {
+    pub fn new(reporting_currency: Currency, rates: BTreeMap<Date, ExchangeRates>) -> Self {
+        Self {
+            reporting_currency,
+            rates,
+        }
+    }
+
+    /// Rates in effect on the given date, which are the latest rates stored on or before it.
+    /// Dates before the first stored rates fall back to the earliest rates available.
+    fn get_rates_for_date(&self, date: Date) -> Option<&ExchangeRates> {
+        self.rates
+            .range(..=date)
+            .next_back()
+            .or_else(|| self.rates.iter().next())
+            .map(|(_, rates)| rates)
+    }
+
+    /// Converts an amount in the minor unit of `currency` to the minor unit of the reporting
+    /// currency using the rates in effect on the given date
+    pub fn convert_amount(
+        &self,
+        amount: &BigDecimal,
+        currency: Currency,
+        date: Date,
+    ) -> CustomResult<BigDecimal, AnalyticsError> {
+        if currency == self.reporting_currency {
+            return Ok(amount.clone());
+        }
+
+        let rates = self
+            .get_rates_for_date(date)
+            .ok_or(AnalyticsError::UnknownError)
+            .into_report()
+            .attach_printable_lazy(|| format!("Exchange rates not available for {date}"))?;
+        let amount = amount
+            .to_i64()
+            .ok_or(AnalyticsError::UnknownError)
+            .into_report()
+            .attach_printable("Amount exceeds the range supported for currency conversion")?;
+
+        let converted_amount = convert(rates, currency, self.reporting_currency, amount)
+            .into_report()
+            .change_context(AnalyticsError::UnknownError)
+            .attach_printable_lazy(|| {
+                format!(
+                    "Failed to convert amount from {currency} to {}",
+                    self.reporting_currency
+                )
+            })?;
+
+        // The converted amount is in the major unit of the reporting currency
+        let minor_unit_factor = if self.reporting_currency.is_zero_decimal_currency() {
+            Decimal::ONE
+        } else if self.reporting_currency.is_three_decimal_currency() {
+            Decimal::ONE_THOUSAND
+        } else {
+            Decimal::ONE_HUNDRED
+        };
+
+        converted_amount
+            .checked_mul(minor_unit_factor)
+            .and_then(|amount| amount.round().to_i64())
+            .map(BigDecimal::from)
+            .ok_or(AnalyticsError::UnknownError)
+            .into_report()
+            .attach_printable("Converted amount exceeds the supported range")
+    }
+}
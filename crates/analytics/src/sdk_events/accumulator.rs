@@ -13,6 +13,7 @@ pub struct SdkEventMetricsAccumulator {
     pub sdk_rendered_count: CountAccumulator,
     pub payment_method_selected_count: CountAccumulator,
     pub payment_data_filled_count: CountAccumulator,
+    pub session_funnel: SessionFunnelAccumulator,
 }
 
 #[derive(Debug, Default)]
@@ -27,6 +28,27 @@ pub struct AverageAccumulator {
     pub count: u32,
 }
 
+/// Number of SDK sessions reaching each step of the checkout
+#[derive(Debug, Default)]
+pub struct SessionFunnelAccumulator {
+    pub has_sessions: bool,
+    pub payment_sheet_opened: u64,
+    pub payment_method_selected: u64,
+    pub confirm_clicked: u64,
+    pub three_ds_challenge_shown: u64,
+    pub payment_succeeded: u64,
+}
+
+#[derive(Debug)]
+pub struct SessionFunnelOutput {
+    pub payment_sheet_opened_sessions: Option<u64>,
+    pub payment_method_selected_sessions: Option<u64>,
+    pub confirm_clicked_sessions: Option<u64>,
+    pub three_ds_challenge_shown_sessions: Option<u64>,
+    pub payment_succeeded_sessions: Option<u64>,
+    pub session_drop_off_rate: Option<f64>,
+}
+
 pub trait SdkEventMetricAccumulator {
     type MetricOutput;
 
@@ -81,9 +103,58 @@ impl SdkEventMetricAccumulator for AverageAccumulator {
     }
 }
 
+impl SdkEventMetricAccumulator for SessionFunnelAccumulator {
+    type MetricOutput = SessionFunnelOutput;
+
+    fn add_metrics_bucket(&mut self, metrics: &SdkEventMetricRow) {
+        self.has_sessions = true;
+        let add = |total: &mut u64, sessions: Option<i64>| {
+            *total += sessions.and_then(|i| u64::try_from(i).ok()).unwrap_or(0);
+        };
+        add(
+            &mut self.payment_sheet_opened,
+            metrics.payment_sheet_opened_sessions,
+        );
+        add(
+            &mut self.payment_method_selected,
+            metrics.payment_method_selected_sessions,
+        );
+        add(&mut self.confirm_clicked, metrics.confirm_clicked_sessions);
+        add(
+            &mut self.three_ds_challenge_shown,
+            metrics.three_ds_challenge_shown_sessions,
+        );
+        add(
+            &mut self.payment_succeeded,
+            metrics.payment_succeeded_sessions,
+        );
+    }
+
+    fn collect(self) -> Self::MetricOutput {
+        let sessions = |count: u64| self.has_sessions.then_some(count);
+        // Sessions which did not report opening the payment sheet are not part of the drop-off
+        #[allow(clippy::as_conversions)]
+        let session_drop_off_rate = (self.payment_sheet_opened > 0).then(|| {
+            let dropped = self
+                .payment_sheet_opened
+                .saturating_sub(self.payment_succeeded);
+            dropped as f64 * 100.0 / self.payment_sheet_opened as f64
+        });
+        SessionFunnelOutput {
+            payment_sheet_opened_sessions: sessions(self.payment_sheet_opened),
+            payment_method_selected_sessions: sessions(self.payment_method_selected),
+            confirm_clicked_sessions: sessions(self.confirm_clicked),
+            three_ds_challenge_shown_sessions: sessions(self.three_ds_challenge_shown),
+            payment_succeeded_sessions: sessions(self.payment_succeeded),
+            session_drop_off_rate,
+        }
+    }
+}
+
 impl SdkEventMetricsAccumulator {
     #[allow(dead_code)]
     pub fn collect(self) -> SdkEventMetricsBucketValue {
+        let session_funnel = self.session_funnel.collect();
         SdkEventMetricsBucketValue {
             payment_attempts: self.payment_attempts.collect(),
             payment_success_count: self.payment_success.collect(),
@@ -93,6 +164,12 @@ impl SdkEventMetricsAccumulator {
             sdk_rendered_count: self.sdk_rendered_count.collect(),
             payment_method_selected_count: self.payment_method_selected_count.collect(),
             payment_data_filled_count: self.payment_data_filled_count.collect(),
+            payment_sheet_opened_sessions: session_funnel.payment_sheet_opened_sessions,
+            payment_method_selected_sessions: session_funnel.payment_method_selected_sessions,
+            confirm_clicked_sessions: session_funnel.confirm_clicked_sessions,
+            three_ds_challenge_shown_sessions: session_funnel.three_ds_challenge_shown_sessions,
+            payment_succeeded_sessions: session_funnel.payment_succeeded_sessions,
+            session_drop_off_rate: session_funnel.session_drop_off_rate,
         }
     }
 }
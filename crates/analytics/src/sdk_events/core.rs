@@ -16,6 +16,7 @@ use super::{
     SdkEventMetricsAccumulator,
 };
 use crate::{
+    core::get_time_series_or_default,
     errors::{AnalyticsError, AnalyticsResult},
     sdk_events::SdkEventMetricAccumulator,
     types::FiltersError,
@@ -28,6 +29,7 @@ pub async fn sdk_events_core(
     req: SdkEventsRequest,
     publishable_key: String,
 ) -> AnalyticsResult<Vec<SdkEventsResult>> {
+    let _query_permit = pool.guardrails().acquire_query_permit(&publishable_key)?;
     match pool {
         AnalyticsProvider::Sqlx(_) => Err(FiltersError::NotImplemented(
             "SDK Events not implemented for SQLX",
@@ -49,12 +51,17 @@ pub async fn get_metrics(
     publishable_key: Option<&String>,
     req: GetSdkEventMetricRequest,
 ) -> AnalyticsResult<MetricsResponse<MetricsBucketResponse>> {
+    let req = GetSdkEventMetricRequest {
+        time_series: Some(get_time_series_or_default(req.time_series, &req.time_range)),
+        ..req
+    };
     let mut metrics_accumulator: HashMap<
         SdkEventMetricsBucketIdentifier,
         SdkEventMetricsAccumulator,
     > = HashMap::new();
 
     if let Some(publishable_key) = publishable_key {
+        let _query_permit = pool.guardrails().acquire_query_permit(publishable_key)?;
         let mut set = tokio::task::JoinSet::new();
         for metric_type in req.metrics.iter().cloned() {
             let req = req.clone();
@@ -111,6 +118,9 @@ pub async fn get_metrics(
                     SdkEventMetrics::AveragePaymentTime => metrics_builder
                         .average_payment_time
                         .add_metrics_bucket(&value),
+                    SdkEventMetrics::SessionFunnel => {
+                        metrics_builder.session_funnel.add_metrics_bucket(&value)
+                    }
                 }
             }
 
@@ -133,6 +143,7 @@ pub async fn get_metrics(
             query_data,
             meta_data: [AnalyticsMetadata {
                 current_time_range: req.time_range,
+                granularity: req.time_series.map(|series| series.granularity),
             }],
         })
     } else {
@@ -141,6 +152,7 @@ pub async fn get_metrics(
             query_data: vec![],
             meta_data: [AnalyticsMetadata {
                 current_time_range: req.time_range,
+                granularity: req.time_series.map(|series| series.granularity),
             }],
         })
     }
@@ -160,6 +172,7 @@ pub async fn get_filters(
     let mut res = SdkEventFiltersResponse::default();
 
     if let Some(publishable_key) = publishable_key {
+        let _query_permit = pool.guardrails().acquire_query_permit(publishable_key)?;
         for dim in req.group_by_names {
             let values = match pool {
                 AnalyticsProvider::Sqlx(_pool) => Err(FiltersError::NotImplemented(
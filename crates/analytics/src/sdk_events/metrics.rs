@@ -19,6 +19,7 @@ mod payment_methods_call_count;
 mod payment_success_count;
 mod sdk_initiated_count;
 mod sdk_rendered_count;
+mod session_funnel;
 
 use average_payment_time::AveragePaymentTime;
 use payment_attempts::PaymentAttempts;
@@ -28,6 +29,7 @@ use payment_methods_call_count::PaymentMethodsCallCount;
 use payment_success_count::PaymentSuccessCount;
 use sdk_initiated_count::SdkInitiatedCount;
 use sdk_rendered_count::SdkRenderedCount;
+use session_funnel::SessionFunnel;
 
 #[derive(Debug, PartialEq, Eq, serde::Deserialize)]
 pub struct SdkEventMetricRow {
@@ -40,6 +42,11 @@ pub struct SdkEventMetricRow {
     pub source: Option<String>,
     pub component: Option<String>,
     pub payment_experience: Option<String>,
+    pub payment_sheet_opened_sessions: Option<i64>,
+    pub payment_method_selected_sessions: Option<i64>,
+    pub confirm_clicked_sessions: Option<i64>,
+    pub three_ds_challenge_shown_sessions: Option<i64>,
+    pub payment_succeeded_sessions: Option<i64>,
 }
 
 pub trait SdkEventMetricAnalytics: LoadRow<SdkEventMetricRow> {}
@@ -176,6 +183,18 @@ where
                     )
                     .await
             }
+            Self::SessionFunnel => {
+                SessionFunnel
+                    .load_metrics(
+                        dimensions,
+                        publishable_key,
+                        filters,
+                        granularity,
+                        time_range,
+                        pool,
+                    )
+                    .await
+            }
         }
     }
 }
@@ -63,7 +63,7 @@ where
             .switch()?;
 
         query_builder
-            .add_bool_filter_clause("first_event", 1)
+            .add_bool_filter_clause("first_event", true)
             .switch()?;
 
         query_builder
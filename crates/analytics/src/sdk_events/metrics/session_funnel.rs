@@ -0,0 +1,172 @@
+use api_models::{
+    analytics::{
+        payments::PaymentDimensions,
+        sdk_events::{
+            SdkEventDimensions, SdkEventFilters, SdkEventMetricsBucketIdentifier, SdkEventNames,
+        },
+        Granularity, TimeRange,
+    },
+    enums::AttemptStatus,
+};
+use common_utils::errors::ReportSwitchExt;
+use error_stack::ResultExt;
+use time::PrimitiveDateTime;
+
+use super::SdkEventMetricRow;
+use crate::{
+    query::{Aggregate, FilterTypes, GroupByClause, QueryBuilder, QueryFilter, ToSql, Window},
+    types::{AnalyticsCollection, AnalyticsDataSource, MetricsError, MetricsResult},
+};
+
+#[derive(Default)]
+pub(super) struct SessionFunnel;
+
+#[async_trait::async_trait]
+impl<T> super::SdkEventMetric<T> for SessionFunnel
+where
+    T: AnalyticsDataSource + super::SdkEventMetricAnalytics,
+    PrimitiveDateTime: ToSql<T>,
+    AnalyticsCollection: ToSql<T>,
+    Granularity: GroupByClause<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    async fn load_metrics(
+        &self,
+        dimensions: &[SdkEventDimensions],
+        publishable_key: &str,
+        filters: &SdkEventFilters,
+        granularity: &Option<Granularity>,
+        time_range: &TimeRange,
+        pool: &T,
+    ) -> MetricsResult<Vec<(SdkEventMetricsBucketIdentifier, SdkEventMetricRow)>> {
+        let mut query_builder: QueryBuilder<T> = QueryBuilder::new(AnalyticsCollection::SdkEvents);
+        let dimensions = dimensions.to_vec();
+
+        for dim in dimensions.iter() {
+            query_builder.add_select_column(dim).switch()?;
+        }
+
+        let [sheet_opened, method_selected, confirm_clicked, three_ds_challenge_shown] =
+            SdkEventNames::FUNNEL_STEPS;
+        for (event_name, alias) in [
+            (sheet_opened, "payment_sheet_opened_sessions"),
+            (method_selected, "payment_method_selected_sessions"),
+            (confirm_clicked, "confirm_clicked_sessions"),
+            (
+                three_ds_challenge_shown,
+                "three_ds_challenge_shown_sessions",
+            ),
+        ] {
+            let event_name = query_builder.add_param(event_name).switch()?;
+            query_builder
+                .add_select_column(Aggregate::DistinctCountIf {
+                    field: "sdk_session_id",
+                    condition: format!("event_name = {event_name}"),
+                    alias: Some(alias),
+                })
+                .switch()?;
+        }
+
+        // The outcome of the payment is taken from the payment attempts rather than from the
+        // SDK, as the SDK may be closed before the payment completes
+        let successful_payments_query =
+            get_successful_payments_query(&mut query_builder, time_range)?;
+        query_builder
+            .add_select_column(Aggregate::DistinctCountIf {
+                field: "sdk_session_id",
+                condition: format!("payment_id IN ({successful_payments_query})"),
+                alias: Some("payment_succeeded_sessions"),
+            })
+            .switch()?;
+
+        if let Some(granularity) = granularity.as_ref() {
+            query_builder
+                .add_granularity_in_mins(granularity)
+                .switch()?;
+        }
+
+        filters.set_filter_clause(&mut query_builder).switch()?;
+
+        query_builder
+            .add_filter_clause("merchant_id", publishable_key)
+            .switch()?;
+
+        query_builder
+            .add_is_not_null_filter_clause("sdk_session_id")
+            .switch()?;
+
+        query_builder
+            .add_filter_in_range_clause("event_name", &SdkEventNames::FUNNEL_STEPS)
+            .switch()?;
+
+        time_range
+            .set_filter_clause(&mut query_builder)
+            .attach_printable("Error filtering time range")
+            .switch()?;
+
+        for dim in dimensions.iter() {
+            query_builder
+                .add_group_by_clause(dim)
+                .attach_printable("Error grouping by dimensions")
+                .switch()?;
+        }
+
+        if let Some(_granularity) = granularity.as_ref() {
+            query_builder
+                .add_group_by_clause("time_bucket")
+                .attach_printable("Error adding granularity")
+                .switch()?;
+        }
+
+        query_builder
+            .execute_query::<SdkEventMetricRow, _>(pool)
+            .await
+            .change_context(MetricsError::QueryBuildingError)?
+            .change_context(MetricsError::QueryExecutionFailure)?
+            .into_iter()
+            .map(|i| {
+                Ok((
+                    SdkEventMetricsBucketIdentifier::new(
+                        i.payment_method.clone(),
+                        i.platform.clone(),
+                        i.browser_name.clone(),
+                        i.source.clone(),
+                        i.component.clone(),
+                        i.payment_experience.clone(),
+                        i.time_bucket.clone(),
+                    ),
+                    i,
+                ))
+            })
+            .collect::<error_stack::Result<
+                Vec<(SdkEventMetricsBucketIdentifier, SdkEventMetricRow)>,
+                crate::query::PostProcessingError,
+            >>()
+            .change_context(MetricsError::PostProcessingFailure)
+    }
+}
+
+/// Query for the payments which were charged, among the payments attempted since the start of
+/// the time range. Payments are not filtered by merchant, as SDK events are only identified by
+/// the publishable key and payment ids are unique across merchants.
+fn get_successful_payments_query<T>(
+    query_builder: &mut QueryBuilder<T>,
+    time_range: &TimeRange,
+) -> MetricsResult<String>
+where
+    T: AnalyticsDataSource,
+    AnalyticsCollection: ToSql<T>,
+    Aggregate<&'static str>: ToSql<T>,
+    Window<&'static str>: ToSql<T>,
+{
+    query_builder
+        .build_subquery(AnalyticsCollection::Payment, |subquery| {
+            subquery.add_select_column("payment_id")?;
+            subquery.add_filter_clause(PaymentDimensions::PaymentStatus, AttemptStatus::Charged)?;
+            // Payments confirmed right before the end of the time range may complete after it,
+            // so that only the start of the time range is applied
+            subquery.add_custom_filter_clause("created_at", time_range.start_time, FilterTypes::Gte)
+        })
+        .switch()
+}
@@ -17,7 +17,10 @@ use opensearch::{
 use serde_json::{json, Value};
 use strum::IntoEnumIterator;
 
-use crate::{errors::AnalyticsError, OpensearchAuth, OpensearchConfig, OpensearchIndexes};
+use crate::{
+    errors::AnalyticsError, guardrails::AnalyticsGuardrails, OpensearchAuth, OpensearchConfig,
+    OpensearchIndexes,
+};
 
 #[derive(Debug, thiserror::Error)]
 pub enum OpensearchError {
@@ -72,7 +75,9 @@ pub async fn msearch_results(
     req: GetGlobalSearchRequest,
     merchant_id: &String,
     config: OpensearchConfig,
+    guardrails: &AnalyticsGuardrails,
 ) -> CustomResult<Vec<GetSearchResponse>, AnalyticsError> {
+    let _query_permit = guardrails.acquire_query_permit(merchant_id)?;
     let client = get_opensearch_client(config.clone())
         .await
         .map_err(|_| AnalyticsError::UnknownError)?;
@@ -117,7 +122,9 @@ pub async fn search_results(
     req: GetSearchRequestWithIndex,
     merchant_id: &String,
     config: OpensearchConfig,
+    guardrails: &AnalyticsGuardrails,
 ) -> CustomResult<GetSearchResponse, AnalyticsError> {
+    let _query_permit = guardrails.acquire_query_permit(merchant_id)?;
     let search_req = req.search_req;
 
     let client = get_opensearch_client(config.clone())
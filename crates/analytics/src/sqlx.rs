@@ -6,12 +6,16 @@ use api_models::{
 };
 use common_utils::errors::{CustomResult, ParsingError};
 use diesel_models::enums::{
-    AttemptStatus, AuthenticationType, Currency, PaymentMethod, RefundStatus,
+    AttemptStatus, AuthenticationStatus, AuthenticationType, Currency, DecoupledAuthenticationType,
+    PaymentMethod, PaymentMethodSelection, RefundReasonCode, RefundStatus,
 };
 use error_stack::{IntoReport, ResultExt};
 use masking::PeekInterface;
+use router_env::logger;
 use sqlx::{
-    postgres::{PgArgumentBuffer, PgPoolOptions, PgRow, PgTypeInfo, PgValueRef},
+    encode::IsNull,
+    postgres::{PgArgumentBuffer, PgArguments, PgPoolOptions, PgRow, PgTypeInfo, PgValueRef},
+    query::Query,
     Decode, Encode,
     Error::ColumnNotFound,
     FromRow, Pool, Postgres, Row,
@@ -20,8 +24,10 @@ use storage_impl::config::Database;
 use time::PrimitiveDateTime;
 
 use super::{
+    cache::AnalyticsCache,
+    guardrails::AnalyticsGuardrails,
     health_check::HealthCheck,
-    query::{Aggregate, ToSql, Window},
+    query::{get_quantile, Aggregate, QueryParam, ToSql, Window},
     types::{
         AnalyticsCollection, AnalyticsDataSource, DBEnumWrapper, LoadRow, QueryExecutionError,
         TableEngine,
@@ -31,6 +37,8 @@ use super::{
 #[derive(Debug, Clone)]
 pub struct SqlxClient {
     pool: Pool<Postgres>,
+    guardrails: AnalyticsGuardrails,
+    cache: AnalyticsCache,
 }
 
 impl Default for SqlxClient {
@@ -44,12 +52,18 @@ impl Default for SqlxClient {
             pool: PgPoolOptions::new()
                 .connect_lazy(&database_url)
                 .expect("SQLX Pool Creation failed"),
+            guardrails: AnalyticsGuardrails::default(),
+            cache: AnalyticsCache::default(),
         }
     }
 }
 
 impl SqlxClient {
-    pub async fn from_conf(conf: &Database) -> Self {
+    pub async fn from_conf(
+        conf: &Database,
+        guardrails: AnalyticsGuardrails,
+        cache: AnalyticsCache,
+    ) -> Self {
         let password = &conf.password.peek();
         let database_url = format!(
             "postgres://{}:{}@{}:{}/{}",
@@ -61,10 +75,77 @@ impl SqlxClient {
             .acquire_timeout(std::time::Duration::from_secs(conf.connection_timeout))
             .connect_lazy(&database_url)
             .expect("SQLX Pool Creation failed");
-        Self { pool }
+        Self {
+            pool,
+            guardrails,
+            cache,
+        }
+    }
+
+    pub fn guardrails(&self) -> &AnalyticsGuardrails {
+        &self.guardrails
+    }
+
+    pub fn cache(&self) -> &AnalyticsCache {
+        &self.cache
+    }
+
+    async fn log_query_plan(
+        &self,
+        query: &str,
+        params: &[QueryParam],
+        elapsed: std::time::Duration,
+    ) {
+        match bind_params(sqlx::query(&format!("EXPLAIN {query};")), params)
+            .fetch_all(&self.pool)
+            .await
+        {
+            Ok(rows) => {
+                let query_plan = rows
+                    .iter()
+                    .filter_map(|row| row.try_get::<String, _>(0).ok())
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                logger::warn!(?elapsed, query, %query_plan, "Slow analytics query");
+            }
+            Err(error) => {
+                logger::error!(?error, query, "Failed to explain slow analytics query")
+            }
+        }
+    }
+}
+
+/// Text parameter whose type is left for Postgres to infer from where it is used, as it does for
+/// string literals, so that text parameters can be compared with both text and enum columns
+struct UntypedText(String);
+
+impl sqlx::Type<Postgres> for UntypedText {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("unknown")
+    }
+}
+
+impl Encode<'_, Postgres> for UntypedText {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        <&str as Encode<'_, Postgres>>::encode(self.0.as_str(), buf)
+    }
+    fn size_hint(&self) -> usize {
+        self.0.len()
     }
 }
 
+fn bind_params<'q>(
+    query: Query<'q, Postgres, PgArguments>,
+    params: &[QueryParam],
+) -> Query<'q, Postgres, PgArguments> {
+    params.iter().fold(query, |query, param| match param {
+        QueryParam::Text(text) => query.bind(UntypedText(text.clone())),
+        QueryParam::Int(int) => query.bind(*int),
+        QueryParam::Bool(boolean) => query.bind(*boolean),
+        QueryParam::DateTime(date_time) => query.bind(*date_time),
+    })
+}
+
 pub trait DbType {
     fn name() -> &'static str;
 }
@@ -90,8 +171,10 @@ db_type!(Currency);
 db_type!(AuthenticationType);
 db_type!(AttemptStatus);
 db_type!(PaymentMethod, TEXT);
+db_type!(PaymentMethodSelection, TEXT);
 db_type!(RefundStatus);
 db_type!(RefundType);
+db_type!(RefundReasonCode, TEXT);
 db_type!(DisputeStage);
 db_type!(DisputeStatus);
 
@@ -151,26 +234,46 @@ impl super::refunds::metrics::RefundMetricAnalytics for SqlxClient {}
 impl super::refunds::filters::RefundFilterAnalytics for SqlxClient {}
 impl super::disputes::filters::DisputeFilterAnalytics for SqlxClient {}
 impl super::disputes::metrics::DisputeMetricAnalytics for SqlxClient {}
+impl super::auth_events::metrics::AuthEventMetricAnalytics for SqlxClient {}
 
 #[async_trait::async_trait]
 impl AnalyticsDataSource for SqlxClient {
     type Row = PgRow;
 
-    async fn load_results<T>(&self, query: &str) -> CustomResult<Vec<T>, QueryExecutionError>
+    async fn load_results<T>(
+        &self,
+        query: &str,
+        params: &[QueryParam],
+    ) -> CustomResult<Vec<T>, QueryExecutionError>
     where
         Self: LoadRow<T>,
     {
-        sqlx::query(&format!("{query};"))
+        let start_time = std::time::Instant::now();
+        let rows = bind_params(sqlx::query(&format!("{query};")), params)
             .fetch_all(&self.pool)
             .await
             .into_report()
             .change_context(QueryExecutionError::DatabaseError)
-            .attach_printable_lazy(|| format!("Failed to run query {query}"))?
-            .into_iter()
+            .attach_printable_lazy(|| format!("Failed to run query {query}"))?;
+
+        let elapsed = start_time.elapsed();
+        if self
+            .guardrails
+            .explain_latency_threshold()
+            .is_some_and(|threshold| elapsed > threshold)
+        {
+            self.log_query_plan(query, params, elapsed).await;
+        }
+
+        rows.into_iter()
             .map(Self::load_row)
             .collect::<Result<Vec<_>, _>>()
             .change_context(QueryExecutionError::RowExtractionFailure)
     }
+
+    fn get_placeholder(index: usize, _param: &QueryParam) -> String {
+        format!("${}", index + 1)
+    }
 }
 #[async_trait::async_trait]
 impl HealthCheck for SqlxClient {
@@ -205,6 +308,15 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::metrics::RefundMetricRow {
                 ColumnNotFound(_) => Ok(Default::default()),
                 e => Err(e),
             })?;
+        let refund_reason: Option<String> = row.try_get("refund_reason").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let refund_reason_code: Option<DBEnumWrapper<RefundReasonCode>> =
+            row.try_get("refund_reason_code").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
@@ -225,6 +337,8 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::metrics::RefundMetricRow {
             refund_status,
             connector,
             refund_type,
+            refund_reason,
+            refund_reason_code,
             total,
             count,
             start_bucket,
@@ -264,6 +378,47 @@ impl<'a> FromRow<'a, PgRow> for super::payments::metrics::PaymentMetricRow {
                 ColumnNotFound(_) => Ok(Default::default()),
                 e => Err(e),
             })?;
+        let card_network: Option<String> = row.try_get("card_network").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let card_type: Option<String> = row.try_get("card_type").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let card_issuing_country: Option<String> =
+            row.try_get("card_issuing_country").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let payment_method_selection: Option<DBEnumWrapper<PaymentMethodSelection>> = row
+            .try_get("payment_method_selection")
+            .or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let settlement_currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("settlement_currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let merchant_id: Option<String> = row.try_get("merchant_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        // attempt_count is stored as a smallint in postgres
+        let attempt_count: Option<i64> = row
+            .try_get::<Option<i16>, _>("attempt_count")
+            .or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?
+            .map(i64::from);
+        let original_error_code: Option<String> =
+            row.try_get("original_error_code").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
@@ -286,6 +441,14 @@ impl<'a> FromRow<'a, PgRow> for super::payments::metrics::PaymentMetricRow {
             authentication_type,
             payment_method,
             payment_method_type,
+            card_network,
+            card_type,
+            card_issuing_country,
+            payment_method_selection,
+            settlement_currency,
+            merchant_id,
+            attempt_count,
+            original_error_code,
             total,
             count,
             start_bucket,
@@ -325,6 +488,34 @@ impl<'a> FromRow<'a, PgRow> for super::payments::distribution::PaymentDistributi
                 ColumnNotFound(_) => Ok(Default::default()),
                 e => Err(e),
             })?;
+        let card_network: Option<String> = row.try_get("card_network").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let card_type: Option<String> = row.try_get("card_type").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let card_issuing_country: Option<String> =
+            row.try_get("card_issuing_country").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let payment_method_selection: Option<DBEnumWrapper<PaymentMethodSelection>> = row
+            .try_get("payment_method_selection")
+            .or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let settlement_currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("settlement_currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let merchant_id: Option<String> = row.try_get("merchant_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
         let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
@@ -337,6 +528,16 @@ impl<'a> FromRow<'a, PgRow> for super::payments::distribution::PaymentDistributi
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
         })?;
+        let amount_bucket_start: Option<i64> =
+            row.try_get("amount_bucket_start").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let amount_bucket_end: Option<i64> =
+            row.try_get("amount_bucket_end").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         // Removing millisecond precision to get accurate diffs against clickhouse
         let start_bucket: Option<PrimitiveDateTime> = row
             .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
@@ -351,9 +552,17 @@ impl<'a> FromRow<'a, PgRow> for super::payments::distribution::PaymentDistributi
             authentication_type,
             payment_method,
             payment_method_type,
+            card_network,
+            card_type,
+            card_issuing_country,
+            payment_method_selection,
+            settlement_currency,
+            merchant_id,
             total,
             count,
             error_message,
+            amount_bucket_start,
+            amount_bucket_end,
             start_bucket,
             end_bucket,
         })
@@ -391,6 +600,34 @@ impl<'a> FromRow<'a, PgRow> for super::payments::filters::FilterRow {
                 ColumnNotFound(_) => Ok(Default::default()),
                 e => Err(e),
             })?;
+        let card_network: Option<String> = row.try_get("card_network").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let card_type: Option<String> = row.try_get("card_type").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let card_issuing_country: Option<String> =
+            row.try_get("card_issuing_country").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let payment_method_selection: Option<DBEnumWrapper<PaymentMethodSelection>> = row
+            .try_get("payment_method_selection")
+            .or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let settlement_currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("settlement_currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let merchant_id: Option<String> = row.try_get("merchant_id").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
         Ok(Self {
             currency,
             status,
@@ -398,6 +635,12 @@ impl<'a> FromRow<'a, PgRow> for super::payments::filters::FilterRow {
             authentication_type,
             payment_method,
             payment_method_type,
+            card_network,
+            card_type,
+            card_issuing_country,
+            payment_method_selection,
+            settlement_currency,
+            merchant_id,
         })
     }
 }
@@ -423,11 +666,22 @@ impl<'a> FromRow<'a, PgRow> for super::refunds::filters::RefundFilterRow {
                 ColumnNotFound(_) => Ok(Default::default()),
                 e => Err(e),
             })?;
+        let refund_reason: Option<String> = row.try_get("refund_reason").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let refund_reason_code: Option<DBEnumWrapper<RefundReasonCode>> =
+            row.try_get("refund_reason_code").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         Ok(Self {
             currency,
             refund_status,
             connector,
             refund_type,
+            refund_reason,
+            refund_reason_code,
         })
     }
 }
@@ -452,11 +706,16 @@ impl<'a> FromRow<'a, PgRow> for super::disputes::filters::DisputeFilterRow {
                 ColumnNotFound(_) => Ok(Default::default()),
                 e => Err(e),
             })?;
+        let currency: Option<String> = row.try_get("currency").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
         Ok(Self {
             dispute_stage,
             dispute_status,
             connector,
             connector_status,
+            currency,
         })
     }
 }
@@ -476,6 +735,11 @@ impl<'a> FromRow<'a, PgRow> for super::disputes::metrics::DisputeMetricRow {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
         })?;
+        let currency: Option<DBEnumWrapper<Currency>> =
+            row.try_get("currency").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         let total: Option<bigdecimal::BigDecimal> = row.try_get("total").or_else(|e| match e {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
@@ -484,6 +748,11 @@ impl<'a> FromRow<'a, PgRow> for super::disputes::metrics::DisputeMetricRow {
             ColumnNotFound(_) => Ok(Default::default()),
             e => Err(e),
         })?;
+        let settled_payment_count: Option<i64> =
+            row.try_get("settled_payment_count").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
         // Removing millisecond precision to get accurate diffs against clickhouse
         let start_bucket: Option<PrimitiveDateTime> = row
             .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
@@ -495,7 +764,75 @@ impl<'a> FromRow<'a, PgRow> for super::disputes::metrics::DisputeMetricRow {
             dispute_stage,
             dispute_status,
             connector,
+            currency,
             total,
+            settled_payment_count,
+            count,
+            start_bucket,
+            end_bucket,
+        })
+    }
+}
+
+impl<'a> FromRow<'a, PgRow> for super::auth_events::metrics::AuthEventMetricRow {
+    fn from_row(row: &'a PgRow) -> sqlx::Result<Self> {
+        let authentication_connector: Option<String> = row
+            .try_get("authentication_connector")
+            .or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let message_version: Option<String> =
+            row.try_get("message_version").or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let trans_status: Option<String> = row.try_get("trans_status").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        let authentication_type: Option<DecoupledAuthenticationType> = row
+            .try_get::<Option<String>, _>("authentication_type")
+            .or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?
+            .map(|authentication_type| DecoupledAuthenticationType::from_str(&authentication_type))
+            .transpose()
+            .map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        let authentication_status: Option<AuthenticationStatus> = row
+            .try_get::<Option<String>, _>("authentication_status")
+            .or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?
+            .map(|authentication_status| AuthenticationStatus::from_str(&authentication_status))
+            .transpose()
+            .map_err(|e| sqlx::Error::Decode(Box::new(e)))?;
+        let three_ds_method_completed: Option<bool> = row
+            .try_get("three_ds_method_completed")
+            .or_else(|e| match e {
+                ColumnNotFound(_) => Ok(Default::default()),
+                e => Err(e),
+            })?;
+        let count: Option<i64> = row.try_get("count").or_else(|e| match e {
+            ColumnNotFound(_) => Ok(Default::default()),
+            e => Err(e),
+        })?;
+        // Removing millisecond precision to get accurate diffs against clickhouse
+        let start_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("start_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        let end_bucket: Option<PrimitiveDateTime> = row
+            .try_get::<Option<PrimitiveDateTime>, _>("end_bucket")?
+            .and_then(|dt| dt.replace_millisecond(0).ok());
+        Ok(Self {
+            authentication_connector,
+            message_version,
+            trans_status,
+            authentication_type,
+            authentication_status,
+            three_ds_method_completed,
             count,
             start_bucket,
             end_bucket,
@@ -524,6 +861,18 @@ impl ToSql<SqlxClient> for AnalyticsCollection {
             Self::OutgoingWebhookEvent => Err(error_stack::report!(ParsingError::UnknownError)
                 .attach_printable("OutgoingWebhookEvents table is not implemented for Sqlx"))?,
             Self::Dispute => Ok("dispute".to_string()),
+            Self::Authentications => Ok("authentication".to_string()),
+            // Attempt ids are generated as `{payment_id}_{attempt_count}`
+            Self::PaymentAttemptRetries => Ok(
+                "(SELECT retry.merchant_id, retry.connector, retry.status, retry.amount, \
+                retry.currency, retry.created_at, original.error_code AS original_error_code \
+                FROM payment_attempt AS retry \
+                INNER JOIN payment_attempt AS original \
+                ON retry.merchant_id = original.merchant_id AND retry.payment_id = original.payment_id \
+                AND original.attempt_id = retry.payment_id || '_1' \
+                WHERE retry.attempt_id <> original.attempt_id) AS payment_attempt_retries"
+                    .to_string(),
+            ),
         }
     }
 }
@@ -567,6 +916,42 @@ where
                     alias.map_or_else(|| "".to_owned(), |alias| format!(" as {}", alias))
                 )
             }
+            Self::Percentile {
+                field,
+                alias,
+                percentile,
+            } => {
+                format!(
+                    "percentile_cont({}) within group (order by {}){}",
+                    get_quantile(*percentile)?,
+                    field
+                        .to_sql(table_engine)
+                        .attach_printable("Failed to percentile aggregate")?,
+                    alias.map_or_else(|| "".to_owned(), |alias| format!(" as {}", alias))
+                )
+            }
+            Self::DistinctCount { field, alias } => {
+                format!(
+                    "count(distinct {}){}",
+                    field
+                        .to_sql(table_engine)
+                        .attach_printable("Failed to distinct count aggregate")?,
+                    alias.map_or_else(|| "".to_owned(), |alias| format!(" as {}", alias))
+                )
+            }
+            Self::DistinctCountIf {
+                field,
+                condition,
+                alias,
+            } => {
+                format!(
+                    "count(distinct case when {condition} then {} end){}",
+                    field
+                        .to_sql(table_engine)
+                        .attach_printable("Failed to conditional distinct count aggregate")?,
+                    alias.map_or_else(|| "".to_owned(), |alias| format!(" as {}", alias))
+                )
+            }
         })
     }
 }
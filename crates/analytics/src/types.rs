@@ -7,7 +7,7 @@ use common_utils::{
 };
 use error_stack::{report, Report, ResultExt};
 
-use super::query::QueryBuildingError;
+use super::query::{QueryBuildingError, QueryParam};
 use crate::errors::AnalyticsError;
 
 #[derive(serde::Deserialize, Debug, serde::Serialize)]
@@ -18,6 +18,7 @@ pub enum AnalyticsDomain {
     SdkEvents,
     ApiEvents,
     Dispute,
+    AuthEvents,
 }
 
 #[derive(Debug, strum::AsRefStr, strum::Display, Clone, Copy)]
@@ -30,6 +31,10 @@ pub enum AnalyticsCollection {
     ConnectorEvents,
     OutgoingWebhookEvent,
     Dispute,
+    Authentications,
+    /// Attempts made by retrying a payment, along with the error code of the first attempt of the
+    /// payment
+    PaymentAttemptRetries,
 }
 
 #[allow(dead_code)]
@@ -74,10 +79,17 @@ where
     Self: Sized + Sync + Send,
 {
     type Row;
-    async fn load_results<T>(&self, query: &str) -> CustomResult<Vec<T>, QueryExecutionError>
+    async fn load_results<T>(
+        &self,
+        query: &str,
+        params: &[QueryParam],
+    ) -> CustomResult<Vec<T>, QueryExecutionError>
     where
         Self: LoadRow<T>;
 
+    /// Placeholder referring to the parameter at the given index within a query
+    fn get_placeholder(index: usize, param: &QueryParam) -> String;
+
     fn get_table_engine(_table: AnalyticsCollection) -> TableEngine {
         TableEngine::BasicTree
     }
@@ -1,5 +1,6 @@
 use api_models::analytics::{
     api_event::{ApiEventDimensions, ApiEventMetrics},
+    auth_events::{AuthEventDimensions, AuthEventMetrics},
     disputes::{DisputeDimensions, DisputeMetrics},
     payments::{PaymentDimensions, PaymentMetrics},
     refunds::{RefundDimensions, RefundMetrics},
@@ -47,3 +48,11 @@ pub fn get_dispute_metrics_info() -> Vec<NameDescription> {
 pub fn get_dispute_dimensions() -> Vec<NameDescription> {
     DisputeDimensions::iter().map(Into::into).collect()
 }
+
+pub fn get_auth_event_metrics_info() -> Vec<NameDescription> {
+    AuthEventMetrics::iter().map(Into::into).collect()
+}
+
+pub fn get_auth_event_dimensions() -> Vec<NameDescription> {
+    AuthEventDimensions::iter().map(Into::into).collect()
+}
@@ -98,13 +98,28 @@ pub struct MerchantAccountCreate {
 
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 pub struct AuthenticationConnectorDetails {
-    /// List of authentication connectors
+    /// List of authentication connectors. Authentications are performed with the first connector,
+    /// and retried with the second one when the first connector is unavailable.
     #[schema(value_type = Vec<AuthenticationConnectors>)]
     pub authentication_connectors: Vec<enums::AuthenticationConnectors>,
     /// URL of the (customer service) website that will be shown to the shopper in case of technical errors during the 3D Secure 2 process.
     pub three_ds_requestor_url: String,
 }
 
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CardVerificationPolicy {
+    /// Void the authorization when the connector reports an AVS mismatch
+    #[serde(default)]
+    pub void_on_avs_mismatch: bool,
+    /// Void the authorization when the connector reports an AVS partial match
+    #[serde(default)]
+    pub void_on_avs_partial_match: bool,
+    /// Void the authorization when the connector reports a CVV mismatch
+    #[serde(default)]
+    pub void_on_cvv_mismatch: bool,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
 pub struct MerchantAccountMetadata {
     pub compatible_connector: Option<api_enums::Connector>,
@@ -276,6 +291,11 @@ pub struct MerchantAccountResponse {
     /// Used to indicate the status of the recon module for a merchant account
     #[schema(value_type = ReconStatus, example = "not_requested")]
     pub recon_status: enums::ReconStatus,
+
+    /// The time at which the merchant account was deleted, present only for deleted merchant accounts which can still be restored
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub deleted_at: Option<time::PrimitiveDateTime>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -358,6 +378,31 @@ pub struct WebhookDetails {
     /// If this property is true, a webhook message is posted whenever a payment fails
     #[schema(example = true)]
     pub payment_failed_enabled: Option<bool>,
+
+    /// Slack or Microsoft Teams channels to which operational alerts are posted
+    pub notification_channels: Option<Vec<NotificationChannel>>,
+}
+
+#[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct NotificationChannel {
+    /// The type of the channel
+    #[schema(value_type = NotificationChannelType, example = "slack")]
+    pub channel_type: api_enums::NotificationChannelType,
+
+    /// The incoming webhook url of the channel
+    #[schema(value_type = String, example = "https://hooks.slack.com/services/T000/B000/XXXX")]
+    pub webhook_url: Secret<String>,
+
+    /// The alerts posted to the channel
+    #[schema(value_type = Vec<OperationalAlert>, example = json!(["dispute_received"]))]
+    pub alerts: Vec<api_enums::OperationalAlert>,
+
+    /// The minimum amount of a failed payment for it to be alerted as a high value payment
+    /// failure, in the lowest denomination of the currency. Required for the
+    /// `high_value_payment_failed` alert.
+    #[schema(example = 100000)]
+    pub high_value_amount_threshold: Option<i64>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -368,6 +413,23 @@ pub struct MerchantAccountDeleteResponse {
     /// If the connector is deleted or not
     #[schema(example = false)]
     pub deleted: bool,
+    /// The time after which the merchant account can no longer be restored and is permanently deleted
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub restorable_until: Option<time::PrimitiveDateTime>,
+}
+
+/// Export of the data of a merchant account, along with its business profiles, connector accounts and API keys
+#[derive(Debug, Serialize, ToSchema)]
+pub struct MerchantAccountDataExport {
+    /// The merchant account
+    pub merchant_account: MerchantAccountResponse,
+    /// The business profiles of the merchant account
+    pub business_profiles: Vec<BusinessProfileResponse>,
+    /// The connector accounts of the merchant account
+    pub merchant_connector_accounts: Vec<MerchantConnectorResponse>,
+    /// The API keys of the merchant account, without the key values
+    pub api_keys: Vec<crate::api_keys::RetrieveApiKeyResponse>,
 }
 
 #[derive(Default, Debug, Deserialize, Serialize)]
@@ -375,6 +437,97 @@ pub struct MerchantId {
     pub merchant_id: String,
 }
 
+/// Request to rotate the key used for generating the card fingerprints of a merchant account
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CardFingerprintKeyRotateRequest {
+    /// Duration in days for which card fingerprints generated with the current key continue to be matched after rotation. Defaults to 30 days
+    #[schema(example = 30)]
+    pub transition_period_in_days: Option<u16>,
+}
+
+/// Version of the key used for generating the card fingerprints of a merchant account
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CardFingerprintKeyResponse {
+    /// The identifier for the merchant account
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+    /// Version of the key with which card fingerprints are generated
+    #[schema(example = 2)]
+    pub key_version: u16,
+    /// Version of the previous key, card fingerprints generated with which continue to be matched until the end of the transition period
+    #[schema(example = 1)]
+    pub previous_key_version: Option<u16>,
+    /// The end of the transition period of the previous key
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub transition_ends_at: Option<time::PrimitiveDateTime>,
+}
+
+/// Status of the registration of a token requestor ID with the card scheme
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum TokenRequestorIdStatus {
+    /// The registration with the card scheme is in progress, network tokens cannot be provisioned yet
+    Pending,
+    /// Network tokens are provisioned and used with the token requestor ID
+    Active,
+    /// Network tokens are temporarily neither provisioned nor used with the token requestor ID
+    Suspended,
+    /// The token requestor ID has been deregistered with the card scheme, a new token requestor ID can be registered for the card network
+    Deactivated,
+}
+
+/// Request to register the token requestor ID assigned to a merchant account by a card scheme for network tokenization
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TokenRequestorIdCreateRequest {
+    /// The card network which assigned the token requestor ID
+    #[schema(value_type = CardNetwork, example = "Visa")]
+    pub card_network: api_enums::CardNetwork,
+    /// The 11 digit token requestor ID assigned by the card scheme
+    #[schema(example = "40010075001")]
+    pub token_requestor_id: String,
+    /// Status of the registration with the card scheme. Defaults to `pending`
+    #[schema(value_type = Option<TokenRequestorIdStatus>, example = "pending")]
+    pub status: Option<TokenRequestorIdStatus>,
+}
+
+/// Request to update the status of the token requestor ID of a merchant account
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TokenRequestorIdUpdateRequest {
+    /// Status of the registration with the card scheme
+    #[schema(value_type = TokenRequestorIdStatus, example = "active")]
+    pub status: TokenRequestorIdStatus,
+}
+
+/// Token requestor ID assigned to a merchant account by a card scheme
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TokenRequestorIdResponse {
+    /// The identifier for the merchant account
+    #[schema(max_length = 64, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+    /// The card network which assigned the token requestor ID
+    #[schema(value_type = CardNetwork, example = "Visa")]
+    pub card_network: api_enums::CardNetwork,
+    /// The token requestor ID assigned by the card scheme
+    #[schema(example = "40010075001")]
+    pub token_requestor_id: String,
+    /// Status of the registration with the card scheme
+    #[schema(value_type = TokenRequestorIdStatus, example = "active")]
+    pub status: TokenRequestorIdStatus,
+    /// Time at which the token requestor ID was registered
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+    /// Time at which the status of the token requestor ID was last updated
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: time::PrimitiveDateTime,
+}
+
 #[derive(Default, Debug, Deserialize, ToSchema, Serialize)]
 pub struct MerchantConnectorId {
     pub merchant_id: String,
@@ -516,6 +669,110 @@ pub struct MerchantConnectorWebhookDetails {
     pub merchant_secret: Secret<String>,
     #[schema(value_type = String, example = "12345678900987654321")]
     pub additional_secret: Option<Secret<String>>,
+    /// Secret which was in use before the last rotation, still accepted until `previous_secret_expires_at`
+    #[schema(value_type = Option<String>, example = "12345678900987654321")]
+    pub previous_merchant_secret: Option<Secret<String>>,
+    /// Additional secret which was in use before the last rotation
+    #[schema(value_type = Option<String>, example = "12345678900987654321")]
+    pub previous_additional_secret: Option<Secret<String>>,
+    /// Time until which webhooks signed with the previous secrets are accepted
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub previous_secret_expires_at: Option<time::PrimitiveDateTime>,
+}
+
+impl MerchantConnectorWebhookDetails {
+    /// Returns the webhook details to be used for verification with the previous secrets, if they
+    /// are still within their validity window
+    pub fn get_previous_webhook_details(&self, now: time::PrimitiveDateTime) -> Option<Self> {
+        let previous_merchant_secret = self.previous_merchant_secret.clone()?;
+        self.previous_secret_expires_at
+            .filter(|expires_at| *expires_at > now)
+            .map(|_| Self {
+                merchant_secret: previous_merchant_secret,
+                additional_secret: self.previous_additional_secret.clone(),
+                previous_merchant_secret: None,
+                previous_additional_secret: None,
+                previous_secret_expires_at: None,
+            })
+    }
+}
+
+/// Request to rotate the incoming webhook secrets of a merchant connector account
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantConnectorWebhookSecretRotateRequest {
+    /// The new secret used for verifying incoming webhooks
+    #[schema(value_type = String, example = "12345678900987654321")]
+    pub merchant_secret: Secret<String>,
+    /// The new additional secret, for connectors which require one
+    #[schema(value_type = Option<String>, example = "12345678900987654321")]
+    pub additional_secret: Option<Secret<String>>,
+    /// Duration in seconds for which the current secrets continue to be accepted after rotation. Defaults to 24 hours
+    #[schema(example = 86400)]
+    pub overlap_window_in_secs: Option<u32>,
+}
+
+/// Request to rotate the secret used for signing the outgoing webhooks of a business profile
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BusinessProfileWebhookSecretRotateRequest {
+    /// Duration in seconds for which outgoing webhooks continue to be signed with the current secret as well after rotation. Defaults to 24 hours
+    #[schema(example = 86400)]
+    pub overlap_window_in_secs: Option<u32>,
+}
+
+/// Health status of a connector, derived from the rate of errors and timeouts in its responses
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ConnectorHealthStatus {
+    /// The connector is included in routing
+    Healthy,
+    /// The connector is excluded from routing, as its error rate crossed the threshold
+    Degraded,
+}
+
+/// Health of a connector configured under a business profile
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct ConnectorHealthResponse {
+    /// Name of the Connector
+    #[schema(value_type = Connector, example = "stripe")]
+    pub connector_name: String,
+
+    /// Unique ID of the merchant connector account
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub merchant_connector_id: Option<String>,
+
+    /// The business profile under which the connector is configured
+    #[schema(example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<String>,
+
+    #[schema(value_type = ConnectorHealthStatus, example = "healthy")]
+    pub status: ConnectorHealthStatus,
+
+    /// Number of responses returned by the connector within the current window
+    #[schema(example = 120)]
+    pub total_count: i64,
+
+    /// Number of errors and timeouts returned by the connector within the current window
+    #[schema(example = 3)]
+    pub error_count: i64,
+
+    /// Percentage of responses within the current window being errors or timeouts, absent when
+    /// the connector hasn't returned any responses within the window
+    #[schema(example = 2)]
+    pub error_rate_percentage: Option<i64>,
+
+    /// The time at which the health status of the connector last changed
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub status_changed_at: Option<time::PrimitiveDateTime>,
+
+    /// The time until which the degraded connector is excluded from routing
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub excluded_until: Option<time::PrimitiveDateTime>,
 }
 
 /// Response of creating a new Merchant Connector for the merchant account."
@@ -796,6 +1053,247 @@ pub struct MerchantConnectorDeleteResponse {
     pub deleted: bool,
 }
 
+/// Request to create and update several merchant connector accounts of the merchant at once
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct MerchantConnectorBulkRequest {
+    /// The merchant connector accounts to be created or updated, processed in the given order
+    pub items: Vec<MerchantConnectorBulkItem>,
+
+    /// If true, the items are only validated, without creating or updating any merchant connector account
+    #[serde(default)]
+    #[schema(default = false, example = false)]
+    pub dry_run: bool,
+}
+
+/// A merchant connector account to be created or updated as part of a bulk request
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "operation", rename_all = "snake_case")]
+pub enum MerchantConnectorBulkItem {
+    Create {
+        connector: MerchantConnectorCreate,
+    },
+    Update {
+        /// Unique ID of the merchant connector account to be updated
+        #[schema(example = "mca_5apGeP94tMts6rg3U3kR")]
+        merchant_connector_id: String,
+        connector: MerchantConnectorUpdate,
+    },
+}
+
+/// Outcome of an item of a bulk merchant connector account request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum MerchantConnectorBulkItemStatus {
+    /// The merchant connector account was created or updated
+    Succeeded,
+    /// The item is valid, and was not applied as the request is a dry run
+    Valid,
+    /// The item is invalid, or could not be applied
+    Failed,
+}
+
+/// Error encountered while processing an item of a bulk merchant connector account request
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MerchantConnectorBulkItemError {
+    /// The error code, same as the one returned when creating or updating the merchant connector account individually
+    #[schema(example = "IR_06")]
+    pub code: String,
+    /// Description of the error
+    #[schema(example = "Missing required param: connector_account_details")]
+    pub message: String,
+}
+
+/// Result of an item of a bulk merchant connector account request
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MerchantConnectorBulkItemResult {
+    /// Position of the item in the request
+    #[schema(example = 0)]
+    pub index: usize,
+
+    #[schema(value_type = MerchantConnectorBulkItemStatus, example = "succeeded")]
+    pub status: MerchantConnectorBulkItemStatus,
+
+    /// The merchant connector account created or updated, absent for dry runs and failed items
+    pub merchant_connector_account: Option<MerchantConnectorResponse>,
+
+    /// The error encountered, present only for failed items
+    pub error: Option<MerchantConnectorBulkItemError>,
+}
+
+/// Response of a bulk merchant connector account request
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MerchantConnectorBulkResponse {
+    /// If true, none of the items were applied
+    #[schema(example = false)]
+    pub dry_run: bool,
+
+    /// Results of the items, in the order of the request
+    pub results: Vec<MerchantConnectorBulkItemResult>,
+}
+
+/// Create a template of the business profiles, connectors, routing and webhook settings of merchant accounts, which can be instantiated for each new merchant account
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AccountTemplateCreate {
+    /// Name of the template
+    #[schema(max_length = 64, example = "marketplace_seller")]
+    pub template_name: String,
+
+    /// The URL to redirect after the completion of the operation, for merchant accounts instantiated from the template
+    #[schema(value_type = Option<String>, max_length = 255, example = "https://www.example.com/success")]
+    pub return_url: Option<url::Url>,
+
+    /// Webhook related details of the merchant accounts instantiated from the template
+    pub webhook_details: Option<WebhookDetails>,
+
+    /// The business profiles created for each merchant account instantiated from the template
+    pub business_profiles: Vec<AccountTemplateBusinessProfile>,
+}
+
+/// A business profile of an account template, along with its connectors
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AccountTemplateBusinessProfile {
+    /// The name of the business profile, unique within the template
+    #[schema(max_length = 64, example = "online")]
+    pub profile_name: String,
+
+    /// The URL to redirect after the completion of the operation
+    #[schema(value_type = Option<String>, max_length = 255, example = "https://www.example.com/success")]
+    pub return_url: Option<url::Url>,
+
+    /// A boolean value to indicate if payment response hash needs to be enabled
+    #[schema(default = true, example = true)]
+    pub enable_payment_response_hash: Option<bool>,
+
+    /// A boolean value to indicate if redirect to merchant with http post needs to be enabled
+    #[schema(default = false, example = true)]
+    pub redirect_to_merchant_with_http_post: Option<bool>,
+
+    /// Webhook related details
+    pub webhook_details: Option<WebhookDetails>,
+
+    /// The routing algorithm to be used for routing payments to the connectors of the business profile
+    #[schema(value_type = Option<Object>, example = json!({"type": "single", "data": "stripe"}))]
+    pub routing_algorithm: Option<serde_json::Value>,
+
+    /// The connectors created under the business profile, in the order of the default fallback routing
+    pub connectors: Vec<AccountTemplateConnector>,
+}
+
+/// A connector of an account template. Templates don't hold the credentials of the connectors, which are provided when the template is instantiated.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AccountTemplateConnector {
+    /// Type of the Connector for the financial use case. Could range from Payments to Accounting to Banking.
+    #[schema(value_type = ConnectorType, example = "payment_processor")]
+    pub connector_type: api_enums::ConnectorType,
+
+    /// Name of the Connector
+    #[schema(value_type = Connector, example = "stripe")]
+    pub connector_name: api_enums::Connector,
+
+    /// The label of the connector account, unique within the template. The credentials of the connector are provided against this label when the template is instantiated.
+    #[schema(example = "stripe_online")]
+    pub connector_label: String,
+
+    /// The payment methods enabled for the connector
+    pub payment_methods_enabled: Option<Vec<PaymentMethodsEnabled>>,
+
+    /// Metadata is useful for storing additional, unstructured information on an object.
+    #[schema(value_type = Option<Object>, max_length = 255, example = json!({ "city": "NY", "unit": "245" }))]
+    pub metadata: Option<pii::SecretSerdeValue>,
+
+    /// A boolean value to indicate if the connector is in Test mode. By default, its value is false.
+    #[schema(default = false, example = false)]
+    pub test_mode: Option<bool>,
+
+    /// A boolean value to indicate if the connector is disabled. By default, its value is false.
+    #[schema(default = false, example = false)]
+    pub disabled: Option<bool>,
+
+    /// Contains the frm configs for the merchant connector
+    pub frm_configs: Option<Vec<FrmConfigs>>,
+}
+
+/// An account template
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AccountTemplateResponse {
+    /// The identifier of the template
+    #[schema(example = "tmpl_5apGeP94tMts6rg3U3kR")]
+    pub template_id: String,
+
+    /// Name of the template
+    #[schema(example = "marketplace_seller")]
+    pub template_name: String,
+
+    /// The URL to redirect after the completion of the operation, for merchant accounts instantiated from the template
+    #[schema(value_type = Option<String>, example = "https://www.example.com/success")]
+    pub return_url: Option<url::Url>,
+
+    /// Webhook related details of the merchant accounts instantiated from the template
+    pub webhook_details: Option<WebhookDetails>,
+
+    /// The business profiles created for each merchant account instantiated from the template
+    pub business_profiles: Vec<AccountTemplateBusinessProfile>,
+
+    /// The time at which the template was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: time::PrimitiveDateTime,
+}
+
+/// Create a merchant account from an account template
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AccountTemplateInstantiateRequest {
+    /// The identifier for the Merchant Account
+    #[schema(max_length = 255, example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+
+    /// Name of the Merchant Account
+    #[schema(value_type = Option<String>, example = "NewAge Retailer")]
+    pub merchant_name: Option<Secret<String>>,
+
+    /// Details about the merchant
+    pub merchant_details: Option<MerchantDetails>,
+
+    /// The id of the organization to which the merchant belongs to
+    pub organization_id: Option<String>,
+
+    /// The credentials of each connector of the template, keyed by its connector label
+    pub connectors: HashMap<String, AccountTemplateConnectorCredentials>,
+}
+
+/// Credentials of a connector of an account template
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AccountTemplateConnectorCredentials {
+    /// An object containing the required details/credentials for a Connector account.
+    #[schema(value_type = MerchantConnectorDetails, example = json!({ "auth_type": "HeaderKey","api_key": "Basic MyVerySecretApiKey" }))]
+    pub connector_account_details: pii::SecretSerdeValue,
+
+    /// Webhook details of the connector account
+    pub connector_webhook_details: Option<MerchantConnectorWebhookDetails>,
+}
+
+/// The merchant account created from an account template
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct AccountTemplateInstantiateResponse {
+    /// The identifier of the template the merchant account was created from
+    #[schema(example = "tmpl_5apGeP94tMts6rg3U3kR")]
+    pub template_id: String,
+
+    pub merchant_account: MerchantAccountResponse,
+
+    /// The business profiles created for the merchant account
+    pub business_profiles: Vec<BusinessProfileResponse>,
+
+    /// The merchant connector accounts created under the business profiles
+    pub merchant_connector_accounts: Vec<MerchantConnectorResponse>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct ToggleKVResponse {
     /// The identifier for the Merchant Account
@@ -902,6 +1400,13 @@ pub struct BusinessProfileCreate {
 
     /// External 3DS authentication details
     pub authentication_connector_details: Option<AuthenticationConnectorDetails>,
+
+    /// Policy for voiding authorizations based on the AVS and CVV results returned by the connector
+    pub card_verification_policy: Option<CardVerificationPolicy>,
+
+    /// The action taken on card payments authorized for less than the amount of the payment
+    #[schema(value_type = Option<PartialAuthorizationAction>, example = "allow_capture")]
+    pub partial_authorization_action: Option<api_enums::PartialAuthorizationAction>,
 }
 
 #[derive(Clone, Debug, ToSchema, Serialize)]
@@ -971,6 +1476,13 @@ pub struct BusinessProfileResponse {
 
     /// External 3DS authentication details
     pub authentication_connector_details: Option<AuthenticationConnectorDetails>,
+
+    /// Policy for voiding authorizations based on the AVS and CVV results returned by the connector
+    pub card_verification_policy: Option<CardVerificationPolicy>,
+
+    /// The action taken on card payments authorized for less than the amount of the payment
+    #[schema(value_type = Option<PartialAuthorizationAction>, example = "allow_capture")]
+    pub partial_authorization_action: Option<api_enums::PartialAuthorizationAction>,
 }
 
 #[derive(Clone, Debug, Deserialize, ToSchema, Serialize)]
@@ -1032,6 +1544,13 @@ pub struct BusinessProfileUpdate {
 
     /// External 3DS authentication details
     pub authentication_connector_details: Option<AuthenticationConnectorDetails>,
+
+    /// Policy for voiding authorizations based on the AVS and CVV results returned by the connector
+    pub card_verification_policy: Option<CardVerificationPolicy>,
+
+    /// The action taken on card payments authorized for less than the amount of the payment
+    #[schema(value_type = Option<PartialAuthorizationAction>, example = "allow_capture")]
+    pub partial_authorization_action: Option<api_enums::PartialAuthorizationAction>,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize, PartialEq, ToSchema)]
@@ -1,10 +1,12 @@
 use std::collections::HashSet;
 
+use common_enums::Currency;
 use common_utils::pii::EmailStrategy;
 use masking::Secret;
 
 use self::{
     api_event::{ApiEventDimensions, ApiEventMetrics},
+    auth_events::{AuthEventDimensions, AuthEventMetrics},
     disputes::{DisputeDimensions, DisputeMetrics},
     payments::{PaymentDimensions, PaymentDistributions, PaymentMetrics},
     refunds::{RefundDimensions, RefundMetrics},
@@ -13,13 +15,16 @@ use self::{
 pub use crate::payments::TimeRange;
 
 pub mod api_event;
+pub mod auth_events;
 pub mod connector_events;
 pub mod disputes;
 pub mod outgoing_webhook_event;
 pub mod payments;
 pub mod refunds;
+pub mod scheduled_reports;
 pub mod sdk_events;
 pub mod search;
+pub mod success_rate_alerts;
 
 #[derive(Debug, serde::Serialize)]
 pub struct NameDescription {
@@ -54,6 +59,8 @@ pub enum Granularity {
     OneHour,
     #[serde(rename = "G_ONEDAY")]
     OneDay,
+    #[serde(rename = "G_ONEWEEK")]
+    OneWeek,
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -69,6 +76,11 @@ pub struct GetPaymentMetricRequest {
     pub distribution: Option<Distribution>,
     #[serde(default)]
     pub delta: bool,
+    /// Currency to which the amount metrics are converted using the daily exchange rates
+    pub reporting_currency: Option<Currency>,
+    /// Loads the metrics from the data source even if their results are cached
+    #[serde(default)]
+    pub bypass_cache: bool,
 }
 
 #[derive(Clone, Copy, Debug, serde::Deserialize, serde::Serialize)]
@@ -94,6 +106,40 @@ impl Into<u64> for QueryLimit {
 pub struct Distribution {
     pub distribution_for: PaymentDistributions,
     pub distribution_cardinality: QueryLimit,
+    /// Buckets the amounts are counted in for the amount distribution
+    #[serde(default)]
+    pub amount_buckets: AmountBuckets,
+}
+
+/// Maximum number of boundaries accepted for the buckets of the amount distribution
+pub const MAX_AMOUNT_BUCKET_BOUNDARIES: usize = 50;
+
+/// Buckets of the amount distribution, the amounts being in the minor unit of the currency. Amounts
+/// below the first boundary are counted in a bucket starting at 0, and amounts at or above the last
+/// boundary in a bucket without an end.
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AmountBuckets {
+    /// Buckets whose boundaries are the powers of ten from 100 to 1,000,000,000
+    #[default]
+    LogScale,
+    /// Buckets between the given boundaries
+    Custom { boundaries: Vec<i64> },
+}
+
+impl AmountBuckets {
+    /// Provides the boundaries of the buckets in ascending order
+    pub fn get_boundaries(&self) -> Vec<i64> {
+        match self {
+            Self::LogScale => (2..=9).map(|exponent| 10_i64.pow(exponent)).collect(),
+            Self::Custom { boundaries } => {
+                let mut boundaries = boundaries.clone();
+                boundaries.sort_unstable();
+                boundaries.dedup();
+                boundaries
+            }
+        }
+    }
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -122,6 +168,39 @@ pub struct GetRefundMetricRequest {
     pub metrics: HashSet<RefundMetrics>,
     #[serde(default)]
     pub delta: bool,
+    /// Currency to which the amount metrics are converted using the daily exchange rates
+    pub reporting_currency: Option<Currency>,
+    /// Loads the metrics from the data source even if their results are cached
+    #[serde(default)]
+    pub bypass_cache: bool,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetRefundTopReasonsRequest {
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub filters: refunds::RefundFilters,
+    #[serde(default)]
+    pub order_by: refunds::RefundReasonOrderBy,
+    pub limit: Option<QueryLimit>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetConnectorUptimeRequest {
+    pub time_range: TimeRange,
+    /// Size of the time buckets over which the error rate is computed
+    pub granularity: Granularity,
+    #[serde(default)]
+    pub filters: payments::PaymentFilters,
+    /// Error rate (in percent) above which a connector is considered down for a time bucket
+    #[serde(default = "default_error_rate_threshold")]
+    pub error_rate_threshold: f64,
+}
+
+fn default_error_rate_threshold() -> f64 {
+    20.0
 }
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -141,6 +220,10 @@ pub struct GetSdkEventMetricRequest {
 #[derive(Debug, serde::Serialize)]
 pub struct AnalyticsMetadata {
     pub current_time_range: TimeRange,
+    /// Granularity of the time buckets in the response, this is picked based on the size of the
+    /// time range when the request does not specify one
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub granularity: Option<Granularity>,
 }
 
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
@@ -149,6 +232,34 @@ pub struct GetPaymentFiltersRequest {
     pub time_range: TimeRange,
     #[serde(default)]
     pub group_by_names: Vec<PaymentDimensions>,
+    #[serde(flatten)]
+    pub value_search: FilterValueSearch,
+}
+
+/// How the search text of a filter values request is matched against the values
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FilterSearchType {
+    /// Values starting with the search text
+    Prefix,
+    /// Values containing the search text
+    #[default]
+    Substring,
+}
+
+/// Narrows down the values returned for each dimension of a filters request, for dimensions
+/// having too many distinct values to be listed at once
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FilterValueSearch {
+    /// Text the values are matched against, case insensitively
+    pub search: Option<String>,
+    #[serde(default)]
+    pub search_type: FilterSearchType,
+    /// Maximum number of values returned for each dimension, the values are sorted when paginated
+    pub limit: Option<u64>,
+    /// Number of values skipped for each dimension
+    pub offset: Option<u64>,
 }
 
 #[derive(Debug, Default, serde::Serialize)]
@@ -171,6 +282,8 @@ pub struct GetRefundFilterRequest {
     pub time_range: TimeRange,
     #[serde(default)]
     pub group_by_names: Vec<RefundDimensions>,
+    #[serde(flatten)]
+    pub value_search: FilterValueSearch,
 }
 
 #[derive(Debug, Default, serde::Serialize, Eq, PartialEq)]
@@ -215,6 +328,24 @@ pub struct MetricsResponse<T> {
     pub meta_data: [AnalyticsMetadata; 1],
 }
 
+/// Format the results of a metric query are returned in
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    #[default]
+    Json,
+    /// CSV file with a header line, the metadata of the results is not included
+    Csv,
+    /// Parquet file with a single row group, the metadata of the results is not included
+    Parquet,
+}
+
+#[derive(Clone, Copy, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct ExportFormatQuery {
+    #[serde(default)]
+    pub format: ExportFormat,
+}
+
 #[derive(Debug, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct GetApiEventFiltersRequest {
@@ -285,3 +416,17 @@ pub struct GetDisputeMetricRequest {
     #[serde(default)]
     pub delta: bool,
 }
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GetAuthEventMetricRequest {
+    pub time_series: Option<TimeSeries>,
+    pub time_range: TimeRange,
+    #[serde(default)]
+    pub group_by_names: Vec<AuthEventDimensions>,
+    #[serde(default)]
+    pub filters: auth_events::AuthEventFilters,
+    pub metrics: HashSet<AuthEventMetrics>,
+    #[serde(default)]
+    pub delta: bool,
+}
@@ -0,0 +1,143 @@
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+};
+
+use super::{NameDescription, TimeRange};
+
+#[derive(
+    Clone,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumIter,
+    strum::AsRefStr,
+)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum AuthEventMetrics {
+    AuthenticationAttemptCount,
+    FrictionlessRate,
+    ChallengeRate,
+    ChallengeSuccessRate,
+    ThreeDsMethodCompletionRate,
+}
+
+#[derive(
+    Debug,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::AsRefStr,
+    PartialEq,
+    PartialOrd,
+    Eq,
+    Ord,
+    strum::Display,
+    strum::EnumIter,
+    Clone,
+    Copy,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuthEventDimensions {
+    // Do not change the order of these enums
+    // Consult the Dashboard FE folks since these also affects the order of metrics on FE
+    AuthenticationConnector,
+    MessageVersion,
+    TransStatus,
+}
+
+impl From<AuthEventDimensions> for NameDescription {
+    fn from(value: AuthEventDimensions) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+impl From<AuthEventMetrics> for NameDescription {
+    fn from(value: AuthEventMetrics) -> Self {
+        Self {
+            name: value.to_string(),
+            desc: String::new(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+pub struct AuthEventFilters {
+    #[serde(default)]
+    pub authentication_connector: Vec<String>,
+    #[serde(default)]
+    pub trans_status: Vec<String>,
+}
+
+#[derive(Debug, serde::Serialize, Eq)]
+pub struct AuthEventMetricsBucketIdentifier {
+    pub authentication_connector: Option<String>,
+    pub message_version: Option<String>,
+    pub trans_status: Option<String>,
+    #[serde(rename = "time_range")]
+    pub time_bucket: TimeRange,
+    #[serde(rename = "time_bucket")]
+    #[serde(with = "common_utils::custom_serde::iso8601custom")]
+    pub start_time: time::PrimitiveDateTime,
+}
+
+impl Hash for AuthEventMetricsBucketIdentifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.authentication_connector.hash(state);
+        self.message_version.hash(state);
+        self.trans_status.hash(state);
+        self.time_bucket.hash(state);
+    }
+}
+
+impl PartialEq for AuthEventMetricsBucketIdentifier {
+    fn eq(&self, other: &Self) -> bool {
+        let mut left = DefaultHasher::new();
+        self.hash(&mut left);
+        let mut right = DefaultHasher::new();
+        other.hash(&mut right);
+        left.finish() == right.finish()
+    }
+}
+
+impl AuthEventMetricsBucketIdentifier {
+    pub fn new(
+        authentication_connector: Option<String>,
+        message_version: Option<String>,
+        trans_status: Option<String>,
+        normalized_time_range: TimeRange,
+    ) -> Self {
+        Self {
+            authentication_connector,
+            message_version,
+            trans_status,
+            time_bucket: normalized_time_range,
+            start_time: normalized_time_range.start_time,
+        }
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct AuthEventMetricsBucketValue {
+    pub authentication_attempt_count: Option<u64>,
+    pub frictionless_rate: Option<f64>,
+    pub challenge_rate: Option<f64>,
+    pub challenge_success_rate: Option<f64>,
+    pub three_ds_method_completion_rate: Option<f64>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct AuthEventMetricsBucketResponse {
+    #[serde(flatten)]
+    pub values: AuthEventMetricsBucketValue,
+    #[serde(flatten)]
+    pub dimensions: AuthEventMetricsBucketIdentifier,
+}
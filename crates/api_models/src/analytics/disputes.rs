@@ -4,7 +4,7 @@ use std::{
 };
 
 use super::{NameDescription, TimeRange};
-use crate::enums::DisputeStage;
+use crate::enums::{Currency, DisputeStage};
 
 #[derive(
     Clone,
@@ -24,6 +24,8 @@ pub enum DisputeMetrics {
     DisputeStatusMetric,
     TotalAmountDisputed,
     TotalDisputeLostAmount,
+    DisputeRate,
+    AmountUnderDispute,
 }
 
 #[derive(
@@ -47,6 +49,7 @@ pub enum DisputeDimensions {
     // Consult the Dashboard FE folks since these also affects the order of metrics on FE
     Connector,
     DisputeStage,
+    Currency,
 }
 
 impl From<DisputeDimensions> for NameDescription {
@@ -72,12 +75,15 @@ pub struct DisputeFilters {
     #[serde(default)]
     pub dispute_stage: Vec<DisputeStage>,
     pub connector: Vec<String>,
+    #[serde(default)]
+    pub currency: Vec<Currency>,
 }
 
 #[derive(Debug, serde::Serialize, Eq)]
 pub struct DisputeMetricsBucketIdentifier {
     pub dispute_stage: Option<DisputeStage>,
     pub connector: Option<String>,
+    pub currency: Option<Currency>,
     #[serde(rename = "time_range")]
     pub time_bucket: TimeRange,
     #[serde(rename = "time_bucket")]
@@ -89,6 +95,7 @@ impl Hash for DisputeMetricsBucketIdentifier {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.dispute_stage.hash(state);
         self.connector.hash(state);
+        self.currency.hash(state);
         self.time_bucket.hash(state);
     }
 }
@@ -106,11 +113,13 @@ impl DisputeMetricsBucketIdentifier {
     pub fn new(
         dispute_stage: Option<DisputeStage>,
         connector: Option<String>,
+        currency: Option<Currency>,
         normalized_time_range: TimeRange,
     ) -> Self {
         Self {
             dispute_stage,
             connector,
+            currency,
             time_bucket: normalized_time_range,
             start_time: normalized_time_range.start_time,
         }
@@ -125,6 +134,10 @@ pub struct DisputeMetricsBucketValue {
     pub total_amount_disputed: Option<u64>,
     pub total_dispute_lost_amount: Option<u64>,
     pub total_dispute: Option<u64>,
+    /// Percentage of the settled payments which were disputed
+    pub dispute_rate: Option<f64>,
+    /// Amount of the disputes which are open or challenged and not yet resolved
+    pub amount_under_dispute: Option<u64>,
 }
 #[derive(Debug, serde::Serialize)]
 pub struct DisputeMetricsBucketResponse {
@@ -5,7 +5,8 @@ use std::{
 
 use super::{NameDescription, TimeRange};
 use crate::enums::{
-    AttemptStatus, AuthenticationType, Connector, Currency, PaymentMethod, PaymentMethodType,
+    AttemptStatus, AuthenticationType, Connector, Currency, PaymentMethod, PaymentMethodSelection,
+    PaymentMethodType,
 };
 
 #[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
@@ -22,6 +23,49 @@ pub struct PaymentFilters {
     pub payment_method: Vec<PaymentMethod>,
     #[serde(default)]
     pub payment_method_type: Vec<PaymentMethodType>,
+    #[serde(default)]
+    pub card_network: Vec<String>,
+    #[serde(default)]
+    pub card_type: Vec<String>,
+    #[serde(default)]
+    pub card_issuing_country: Vec<String>,
+    #[serde(default)]
+    pub payment_method_selection: Vec<PaymentMethodSelection>,
+    pub settlement_currency: Vec<Currency>,
+    #[serde(default)]
+    pub merchant_id: Vec<String>,
+}
+
+impl PaymentFilters {
+    pub fn is_empty(&self) -> bool {
+        let Self {
+            currency,
+            status,
+            connector,
+            auth_type,
+            payment_method,
+            payment_method_type,
+            card_network,
+            card_type,
+            card_issuing_country,
+            payment_method_selection,
+            settlement_currency,
+            merchant_id,
+        } = self;
+
+        currency.is_empty()
+            && status.is_empty()
+            && connector.is_empty()
+            && auth_type.is_empty()
+            && payment_method.is_empty()
+            && payment_method_type.is_empty()
+            && card_network.is_empty()
+            && card_type.is_empty()
+            && card_issuing_country.is_empty()
+            && payment_method_selection.is_empty()
+            && settlement_currency.is_empty()
+            && merchant_id.is_empty()
+    }
 }
 
 #[derive(
@@ -53,6 +97,12 @@ pub enum PaymentDimensions {
     #[strum(serialize = "status")]
     #[serde(rename = "status")]
     PaymentStatus,
+    CardNetwork,
+    CardType,
+    CardIssuingCountry,
+    PaymentMethodSelection,
+    SettlementCurrency,
+    MerchantId,
 }
 
 #[derive(
@@ -77,6 +127,16 @@ pub enum PaymentMetrics {
     AvgTicketSize,
     RetriesCount,
     ConnectorSuccessRate,
+    AvgRetryDepth,
+    RetryAttemptSuccessCount,
+    SurchargeAmount,
+    TaxOnSurchargeAmount,
+    BlocklistHitCount,
+    PaymentFunnel,
+    RetriedAttemptCount,
+    RetrySuccessCount,
+    RetryRecoveredAmount,
+    RefundedPaymentCount,
 }
 
 #[derive(Debug, Default, serde::Serialize)]
@@ -86,6 +146,56 @@ pub struct ErrorResult {
     pub percentage: f64,
 }
 
+/// Number of payments whose amount falls in a bucket of the amount distribution
+#[derive(Debug, Default, serde::Serialize)]
+pub struct AmountBucketResult {
+    /// Inclusive lower bound of the bucket
+    pub start: i64,
+    /// Exclusive upper bound of the bucket, absent for the last bucket
+    pub end: Option<i64>,
+    pub count: i64,
+}
+
+/// Stages of the payment funnel, an attempt reaching a stage having gone through the earlier ones
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum PaymentFunnelStage {
+    Created,
+    CustomerActionRequired,
+    Processing,
+    Succeeded,
+}
+
+#[derive(Debug, Default, serde::Serialize)]
+pub struct PaymentFunnel {
+    /// Number of attempts created
+    pub created: u64,
+    /// Number of attempts which required an action of the customer, like a 3DS challenge
+    pub customer_action_required: u64,
+    /// Number of attempts sent to the connector for processing
+    pub processing: u64,
+    /// Number of attempts which succeeded
+    pub succeeded: u64,
+    /// Percentage of the created attempts which required an action of the customer
+    pub customer_action_required_rate: Option<f64>,
+    /// Percentage of the created attempts which were sent to the connector
+    pub processing_rate: Option<f64>,
+    /// Percentage of the created attempts which succeeded
+    pub success_rate: Option<f64>,
+    /// Attempts which did not succeed, by the stage at which they stalled and their status
+    pub drop_offs: Vec<PaymentFunnelDropOff>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct PaymentFunnelDropOff {
+    pub stage: PaymentFunnelStage,
+    pub status: AttemptStatus,
+    pub count: u64,
+    /// Percentage of the created attempts which stalled with this status
+    pub percentage: f64,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -104,6 +214,8 @@ pub struct ErrorResult {
 pub enum PaymentDistributions {
     #[strum(serialize = "error_message")]
     PaymentErrorMessage,
+    #[strum(serialize = "amount")]
+    AmountDistribution,
 }
 
 pub mod metric_behaviour {
@@ -132,7 +244,7 @@ impl From<PaymentDimensions> for NameDescription {
     }
 }
 
-#[derive(Debug, serde::Serialize, Eq)]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Eq)]
 pub struct PaymentMetricsBucketIdentifier {
     pub currency: Option<Currency>,
     pub status: Option<AttemptStatus>,
@@ -141,6 +253,17 @@ pub struct PaymentMetricsBucketIdentifier {
     pub auth_type: Option<AuthenticationType>,
     pub payment_method: Option<String>,
     pub payment_method_type: Option<String>,
+    pub card_network: Option<String>,
+    pub card_type: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub payment_method_selection: Option<PaymentMethodSelection>,
+    pub settlement_currency: Option<Currency>,
+    pub merchant_id: Option<String>,
+    /// Index of the attempt which succeeded the payment, set only for retry metrics
+    pub attempt_index: Option<i64>,
+    /// Error code of the first attempt of the retried payments, set only for retry effectiveness
+    /// metrics
+    pub original_error_code: Option<String>,
     #[serde(rename = "time_range")]
     pub time_bucket: TimeRange,
     // Coz FE sucks
@@ -157,6 +280,14 @@ impl PaymentMetricsBucketIdentifier {
         auth_type: Option<AuthenticationType>,
         payment_method: Option<String>,
         payment_method_type: Option<String>,
+        card_network: Option<String>,
+        card_type: Option<String>,
+        card_issuing_country: Option<String>,
+        payment_method_selection: Option<PaymentMethodSelection>,
+        settlement_currency: Option<Currency>,
+        merchant_id: Option<String>,
+        attempt_index: Option<i64>,
+        original_error_code: Option<String>,
         normalized_time_range: TimeRange,
     ) -> Self {
         Self {
@@ -166,6 +297,14 @@ impl PaymentMetricsBucketIdentifier {
             auth_type,
             payment_method,
             payment_method_type,
+            card_network,
+            card_type,
+            card_issuing_country,
+            payment_method_selection,
+            settlement_currency,
+            merchant_id,
+            attempt_index,
+            original_error_code,
             time_bucket: normalized_time_range,
             start_time: normalized_time_range.start_time,
         }
@@ -180,6 +319,14 @@ impl Hash for PaymentMetricsBucketIdentifier {
         self.auth_type.map(|i| i.to_string()).hash(state);
         self.payment_method.hash(state);
         self.payment_method_type.hash(state);
+        self.card_network.hash(state);
+        self.card_type.hash(state);
+        self.card_issuing_country.hash(state);
+        self.payment_method_selection.hash(state);
+        self.settlement_currency.hash(state);
+        self.merchant_id.hash(state);
+        self.attempt_index.hash(state);
+        self.original_error_code.hash(state);
         self.time_bucket.hash(state);
     }
 }
@@ -202,9 +349,49 @@ pub struct PaymentMetricsBucketValue {
     pub payment_processed_amount: Option<u64>,
     pub avg_ticket_size: Option<f64>,
     pub payment_error_message: Option<Vec<ErrorResult>>,
+    pub amount_distribution: Option<Vec<AmountBucketResult>>,
     pub retries_count: Option<u64>,
     pub retries_amount_processed: Option<u64>,
     pub connector_success_rate: Option<f64>,
+    pub avg_retry_depth: Option<f64>,
+    pub retry_attempt_success_count: Option<u64>,
+    pub surcharge_amount: Option<u64>,
+    pub tax_on_surcharge_amount: Option<u64>,
+    pub blocklist_hit_count: Option<u64>,
+    pub payment_funnel: Option<PaymentFunnel>,
+    /// Number of attempts made by retrying payments whose first attempt failed
+    pub retried_attempt_count: Option<u64>,
+    /// Number of retried attempts which succeeded
+    pub retry_success_count: Option<u64>,
+    /// Amount of the payments recovered by the retried attempts which succeeded
+    pub retry_recovered_amount: Option<u64>,
+    /// Number of successful payments which were refunded successfully
+    pub refunded_payment_count: Option<u64>,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ConnectorUptimeValue {
+    pub connector: String,
+    /// Number of time buckets in which the connector processed payments
+    pub total_buckets: u32,
+    /// Number of time buckets in which the error rate exceeded the threshold
+    pub down_buckets: u32,
+    /// Percentage of time buckets in which the connector was considered up
+    pub uptime_percentage: f64,
+}
+
+/// Number of payments which entered each status over the most recent window, tracked as the
+/// payments are processed rather than queried from the analytics store
+#[derive(Clone, Debug, Default, serde::Serialize)]
+pub struct LivePaymentActivityResponse {
+    /// Payments which started processing at the connector
+    pub processing: i64,
+    /// Payments which are waiting for the customer to authenticate
+    pub requires_customer_action: i64,
+    /// Payments which succeeded
+    pub succeeded: i64,
+    /// Length of the window the payments are counted over
+    pub window_in_secs: i64,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -3,7 +3,10 @@ use std::{
     hash::{Hash, Hasher},
 };
 
-use crate::{enums::Currency, refunds::RefundStatus};
+use crate::{
+    enums::{Currency, RefundReasonCode},
+    refunds::RefundStatus,
+};
 
 #[derive(
     Clone,
@@ -25,6 +28,7 @@ pub enum RefundType {
     #[default]
     RegularRefund,
     RetryRefund,
+    PayoutRefund,
 }
 
 use super::{NameDescription, TimeRange};
@@ -38,6 +42,10 @@ pub struct RefundFilters {
     pub connector: Vec<String>,
     #[serde(default)]
     pub refund_type: Vec<RefundType>,
+    #[serde(default)]
+    pub refund_reason: Vec<String>,
+    #[serde(default)]
+    pub refund_reason_code: Vec<RefundReasonCode>,
 }
 
 #[derive(
@@ -61,6 +69,8 @@ pub enum RefundDimensions {
     RefundStatus,
     Connector,
     RefundType,
+    RefundReason,
+    RefundReasonCode,
 }
 
 #[derive(
@@ -109,13 +119,15 @@ impl From<RefundDimensions> for NameDescription {
     }
 }
 
-#[derive(Debug, serde::Serialize, Eq)]
+#[derive(Debug, serde::Serialize, serde::Deserialize, Eq)]
 pub struct RefundMetricsBucketIdentifier {
     pub currency: Option<Currency>,
     pub refund_status: Option<String>,
     pub connector: Option<String>,
 
     pub refund_type: Option<String>,
+    pub refund_reason: Option<String>,
+    pub refund_reason_code: Option<String>,
     #[serde(rename = "time_range")]
     pub time_bucket: TimeRange,
     #[serde(rename = "time_bucket")]
@@ -129,6 +141,8 @@ impl Hash for RefundMetricsBucketIdentifier {
         self.refund_status.hash(state);
         self.connector.hash(state);
         self.refund_type.hash(state);
+        self.refund_reason.hash(state);
+        self.refund_reason_code.hash(state);
         self.time_bucket.hash(state);
     }
 }
@@ -148,6 +162,8 @@ impl RefundMetricsBucketIdentifier {
         refund_status: Option<String>,
         connector: Option<String>,
         refund_type: Option<String>,
+        refund_reason: Option<String>,
+        refund_reason_code: Option<String>,
         normalized_time_range: TimeRange,
     ) -> Self {
         Self {
@@ -155,6 +171,8 @@ impl RefundMetricsBucketIdentifier {
             refund_status,
             connector,
             refund_type,
+            refund_reason,
+            refund_reason_code,
             time_bucket: normalized_time_range,
             start_time: normalized_time_range.start_time,
         }
@@ -174,3 +192,18 @@ pub struct RefundMetricsBucketResponse {
     #[serde(flatten)]
     pub dimensions: RefundMetricsBucketIdentifier,
 }
+
+#[derive(Clone, Copy, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RefundReasonOrderBy {
+    #[default]
+    Count,
+    Amount,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct RefundReasonValue {
+    pub refund_reason: String,
+    pub refund_count: u64,
+    pub refund_amount: u64,
+}
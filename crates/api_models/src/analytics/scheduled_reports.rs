@@ -0,0 +1,118 @@
+use common_enums::{ReportDeliveryMethod, ReportFrequency, ReportRunStatus, ReportType};
+use common_utils::pii;
+use masking::Secret;
+use time::PrimitiveDateTime;
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportScheduleCreateRequest {
+    pub report_type: ReportType,
+    pub frequency: ReportFrequency,
+    pub delivery: ReportDeliveryConfig,
+}
+
+/// Where the reports generated for a schedule are delivered to
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(tag = "method", rename_all = "snake_case")]
+pub enum ReportDeliveryConfig {
+    Email(EmailDeliveryConfig),
+    Sftp(SftpDeliveryConfig),
+}
+
+impl ReportDeliveryConfig {
+    pub fn get_delivery_method(&self) -> ReportDeliveryMethod {
+        match self {
+            Self::Email(_) => ReportDeliveryMethod::Email,
+            Self::Sftp(_) => ReportDeliveryMethod::Sftp,
+        }
+    }
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailDeliveryConfig {
+    pub recipients: Vec<pii::Email>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SftpDeliveryConfig {
+    pub host: String,
+    #[serde(default = "default_sftp_port")]
+    pub port: u16,
+    pub username: String,
+    /// Password of the user, either this or the private key must be provided
+    pub password: Option<Secret<String>>,
+    /// PEM encoded private key of the user
+    pub private_key: Option<Secret<String>>,
+    pub private_key_passphrase: Option<Secret<String>>,
+    /// SHA-256 fingerprint of the host key of the server as shown by `ssh-keygen -l`, the
+    /// report is not uploaded if the server presents a different key
+    pub host_key_fingerprint: String,
+    /// Directory on the server the reports are uploaded to, defaults to the home directory of
+    /// the user
+    pub directory: Option<String>,
+}
+
+fn default_sftp_port() -> u16 {
+    22
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportScheduleId {
+    pub schedule_id: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportScheduleResponse {
+    pub schedule_id: String,
+    pub report_type: ReportType,
+    pub frequency: ReportFrequency,
+    pub delivery_method: ReportDeliveryMethod,
+    pub is_active: bool,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub next_run_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_run_at: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportRunListRequest {
+    pub schedule_id: Option<String>,
+    pub status: Option<Vec<ReportRunStatus>>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportRunResponse {
+    pub run_id: String,
+    pub schedule_id: String,
+    pub report_type: ReportType,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_start: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub period_end: PrimitiveDateTime,
+    pub status: ReportRunStatus,
+    /// The file the report is stored in, which can be downloaded from the files API
+    pub file_id: Option<String>,
+    pub row_count: Option<i32>,
+    pub error_message: Option<String>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub delivered_at: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportRunListResponse {
+    pub count: usize,
+    pub data: Vec<ReportRunResponse>,
+}
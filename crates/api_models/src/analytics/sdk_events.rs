@@ -3,6 +3,9 @@ use std::{
     hash::{Hash, Hasher},
 };
 
+use masking::Secret;
+use time::PrimitiveDateTime;
+
 use super::{NameDescription, TimeRange};
 
 #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
@@ -78,6 +81,8 @@ pub enum SdkEventMetrics {
     PaymentMethodSelectedCount,
     PaymentDataFilledCount,
     AveragePaymentTime,
+    /// Number of SDK sessions reaching each step of the checkout, up to a successful payment
+    SessionFunnel,
 }
 
 #[derive(
@@ -108,6 +113,20 @@ pub enum SdkEventNames {
     RedirectingUser,
     DisplayBankTransferInfoPage,
     DisplayQrCodeInfoPage,
+    PaymentSheetOpened,
+    PaymentMethodSelected,
+    ConfirmClicked,
+    ThreeDsChallengeShown,
+}
+
+impl SdkEventNames {
+    /// Checkout steps tracked by the session funnel, in the order they are reached
+    pub const FUNNEL_STEPS: [Self; 4] = [
+        Self::PaymentSheetOpened,
+        Self::PaymentMethodSelected,
+        Self::ConfirmClicked,
+        Self::ThreeDsChallengeShown,
+    ];
 }
 
 pub mod metric_behaviour {
@@ -119,6 +138,7 @@ pub mod metric_behaviour {
     pub struct PaymentMethodSelectedCount;
     pub struct PaymentDataFilledCount;
     pub struct AveragePaymentTime;
+    pub struct SessionFunnel;
 }
 
 impl From<SdkEventMetrics> for NameDescription {
@@ -204,6 +224,13 @@ pub struct SdkEventMetricsBucketValue {
     pub sdk_initiated_count: Option<u64>,
     pub payment_method_selected_count: Option<u64>,
     pub payment_data_filled_count: Option<u64>,
+    pub payment_sheet_opened_sessions: Option<u64>,
+    pub payment_method_selected_sessions: Option<u64>,
+    pub confirm_clicked_sessions: Option<u64>,
+    pub three_ds_challenge_shown_sessions: Option<u64>,
+    pub payment_succeeded_sessions: Option<u64>,
+    /// Percentage of the sessions which opened the payment sheet without a successful payment
+    pub session_drop_off_rate: Option<f64>,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -213,3 +240,36 @@ pub struct MetricsBucketResponse {
     #[serde(flatten)]
     pub dimensions: SdkEventMetricsBucketIdentifier,
 }
+
+/// Checkout telemetry events sent by the SDKs for a single SDK session
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct SdkTelemetryEventsRequest {
+    /// Identifier of the payment being checked out
+    #[serde(skip)]
+    pub payment_id: String,
+    /// Client secret of the payment being checked out
+    pub client_secret: Secret<String>,
+    /// Identifier generated by the SDK, shared by all the events of the same checkout session
+    pub sdk_session_id: String,
+    pub platform: Option<String>,
+    pub browser_name: Option<String>,
+    pub browser_version: Option<String>,
+    pub source: Option<String>,
+    pub component: Option<String>,
+    /// Version of the SDK sending the events
+    pub version: Option<String>,
+    pub events: Vec<SdkTelemetryEvent>,
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+pub struct SdkTelemetryEvent {
+    pub event_name: SdkEventNames,
+    /// Time at which the event was raised, as reported by the SDK
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub timestamp: PrimitiveDateTime,
+    pub payment_method: Option<String>,
+    pub payment_experience: Option<String>,
+    /// Time taken by the step the event completes, in milliseconds
+    pub latency: Option<u64>,
+    pub value: Option<String>,
+}
@@ -0,0 +1,80 @@
+use common_utils::pii;
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums::Connector;
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessRateAlertCreateRequest {
+    /// Connector whose success rate is evaluated, all the connectors of the merchant are
+    /// evaluated separately when not provided
+    pub connector: Option<Connector>,
+    /// Length of the window of the most recent payments whose success rate is evaluated
+    pub evaluation_window_in_mins: u32,
+    /// Length of the window preceding the evaluation window whose success rate is the baseline
+    pub baseline_window_in_mins: u32,
+    /// Drop of the success rate from the baseline, in percentage points, at which the alert fires
+    pub drop_threshold: f64,
+    /// Minimum number of payments in each of the windows for a connector to be evaluated, so
+    /// that a few failed payments of a connector with little traffic do not fire the alert
+    #[serde(default = "default_min_payment_count")]
+    pub min_payment_count: u32,
+    /// Recipients emailed when the alert fires, in addition to the outgoing webhook
+    #[serde(default)]
+    pub email_recipients: Vec<pii::Email>,
+}
+
+fn default_min_payment_count() -> u32 {
+    20
+}
+
+#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessRateAlertId {
+    pub alert_id: String,
+}
+
+#[derive(Clone, Debug, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessRateAlertResponse {
+    pub alert_id: String,
+    pub connector: Option<String>,
+    pub evaluation_window_in_mins: i32,
+    pub baseline_window_in_mins: i32,
+    pub drop_threshold: f64,
+    pub min_payment_count: i32,
+    pub is_active: bool,
+    /// Drops of connectors whose success rate has not recovered since the alert fired
+    pub ongoing_drops: Vec<SuccessRateDropDetails>,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_evaluated_at: Option<PrimitiveDateTime>,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+/// Drop of the success rate of a connector which fired an alert, delivered in the outgoing
+/// webhooks of the alert
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize, ToSchema)]
+pub struct SuccessRateDropDetails {
+    /// The identifier of the alert which fired
+    pub alert_id: String,
+    /// The connector whose success rate dropped
+    pub connector: String,
+    /// Success rate in percentage over the evaluation window
+    pub success_rate: f64,
+    /// Success rate in percentage over the baseline window
+    pub baseline_success_rate: f64,
+    /// Number of payments over the evaluation window
+    pub payment_count: u64,
+    /// Number of payments over the baseline window
+    pub baseline_payment_count: u64,
+    /// The time at which the drop was detected
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub triggered_at: PrimitiveDateTime,
+    /// The time at which the success rate was evaluated to be back within the threshold
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub recovered_at: Option<PrimitiveDateTime>,
+}
@@ -4,6 +4,8 @@ use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
+use crate::enums::ApiKeyScope;
+
 /// The request body for creating an API Key.
 #[derive(Debug, Deserialize, ToSchema, Serialize)]
 #[serde(deny_unknown_fields)]
@@ -23,6 +25,16 @@ pub struct CreateApiKeyRequest {
     /// rotating your keys once every 6 months.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The identifier of the business profile to which the API Key is restricted. When provided,
+    /// the API Key can only access resources belonging to this business profile.
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<String>,
+
+    /// The APIs to which the API Key is restricted. When provided, the API Key can only access the
+    /// APIs covered by these scopes, otherwise it can access all APIs.
+    #[schema(value_type = Option<Vec<ApiKeyScope>>, example = json!(["payments:read"]))]
+    pub scopes: Option<Vec<ApiKeyScope>>,
 }
 
 /// The response body for creating an API Key.
@@ -60,6 +72,14 @@ pub struct CreateApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
+
+    /// The identifier of the business profile to which the API Key is restricted, if any.
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<String>,
+
+    /// The APIs to which the API Key is restricted, if any.
+    #[schema(value_type = Option<Vec<ApiKeyScope>>, example = json!(["payments:read"]))]
+    pub scopes: Option<Vec<ApiKeyScope>>,
     /*
     /// The date and time indicating when the API Key was last used.
     #[schema(example = "2022-09-10T10:11:12Z")]
@@ -102,12 +122,20 @@ pub struct RetrieveApiKeyResponse {
     /// The expiration date for the API Key.
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: ApiKeyExpiration,
-    /*
-    /// The date and time indicating when the API Key was last used.
+
+    /// The identifier of the business profile to which the API Key is restricted, if any.
+    #[schema(max_length = 64, example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<String>,
+
+    /// The APIs to which the API Key is restricted, if any.
+    #[schema(value_type = Option<Vec<ApiKeyScope>>, example = json!(["payments:read"]))]
+    pub scopes: Option<Vec<ApiKeyScope>>,
+
+    /// The date and time indicating when the API Key was last used. This is updated at most once
+    /// a minute.
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(with = "common_utils::custom_serde::iso8601::option")]
     pub last_used: Option<PrimitiveDateTime>,
-    */
 }
 
 /// The request body for updating an API Key.
@@ -130,6 +158,30 @@ pub struct UpdateApiKeyRequest {
     #[schema(example = "2022-09-10T10:11:12Z")]
     pub expiration: Option<ApiKeyExpiration>,
 
+    /// The APIs to which the API Key is restricted. An empty list removes the restriction.
+    #[schema(value_type = Option<Vec<ApiKeyScope>>, example = json!(["payments:read"]))]
+    pub scopes: Option<Vec<ApiKeyScope>>,
+
+    #[serde(skip_deserializing)]
+    pub key_id: String,
+
+    #[serde(skip_deserializing)]
+    pub merchant_id: String,
+}
+
+/// The request body for rotating an API Key.
+#[derive(Debug, Deserialize, ToSchema, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct RotateApiKeyRequest {
+    /// An expiration date for the new API Key.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    pub expiration: ApiKeyExpiration,
+
+    /// The number of seconds for which the API Key being rotated remains active alongside the new
+    /// API Key, giving time to replace it. The API Key being rotated expires immediately if zero.
+    #[schema(example = 86400, maximum = 2592000)]
+    pub grace_period_in_seconds: Option<u32>,
+
     #[serde(skip_deserializing)]
     pub key_id: String,
 
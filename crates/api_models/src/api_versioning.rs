@@ -0,0 +1,61 @@
+/// The versions of the shapes of the request bodies accepted by the API, named by the date they
+/// were released on. Clients pin the version their integration was built against in the
+/// `Hyperswitch-Version` header, requests without the header are treated as using the current
+/// version.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    strum::Display,
+    strum::EnumString,
+    strum::EnumIter,
+)]
+pub enum ApiVersion {
+    /// The customer details of a payment are passed as the top level `customer_id`, `email`,
+    /// `name`, `phone` and `phone_country_code` fields
+    #[strum(serialize = "2023-10-01")]
+    V2023_10_01,
+    /// The customer details of a payment are passed in the `customer` object
+    #[strum(serialize = "2024-05-01")]
+    V2024_05_01,
+}
+
+impl ApiVersion {
+    pub const CURRENT: Self = Self::V2024_05_01;
+
+    pub fn is_deprecated(self) -> bool {
+        self < Self::CURRENT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::str::FromStr;
+
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    #[test]
+    fn test_only_the_latest_version_is_current() {
+        assert_eq!(ApiVersion::iter().max(), Some(ApiVersion::CURRENT));
+        assert!(ApiVersion::V2023_10_01.is_deprecated());
+        assert!(!ApiVersion::CURRENT.is_deprecated());
+    }
+
+    #[test]
+    fn test_version_is_parsed_from_release_date() {
+        assert_eq!(
+            ApiVersion::from_str("2023-10-01").unwrap(),
+            ApiVersion::V2023_10_01
+        );
+        assert_eq!(ApiVersion::CURRENT.to_string(), "2024-05-01");
+        assert!(ApiVersion::from_str("2023-01-01").is_err());
+    }
+}
@@ -0,0 +1,88 @@
+use common_enums::{AuditActorType, AuditOperation};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The constraints to apply when listing the audit logs of a merchant.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AuditLogListConstraints {
+    /// Filter audit logs created after the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Filter audit logs created before the specified time.
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// Filter audit logs of the specified operation.
+    pub operation: Option<AuditOperation>,
+
+    /// Filter audit logs of the specified entity, such as a merchant connector ID or an API key ID.
+    pub entity_id: Option<String>,
+
+    /// Filter audit logs of the operations performed by the specified API key or user.
+    pub actor_id: Option<String>,
+
+    /// Include at most the specified number of audit logs.
+    pub limit: Option<u16>,
+
+    /// Include audit logs after the specified offset.
+    pub offset: Option<u16>,
+}
+
+/// An operation recorded in the audit log of a merchant.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AuditLogResponse {
+    /// The identifier of the audit log.
+    #[schema(example = "audit_018e31720d1b7a2b82677d3032cab959")]
+    pub audit_id: String,
+
+    /// The identifier of the merchant.
+    #[schema(example = "y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub merchant_id: String,
+
+    /// The operation performed.
+    pub operation: AuditOperation,
+
+    /// The identifier of the entity changed by the operation.
+    #[schema(example = "mca_5apGeP94tMts6rg3U3kR")]
+    pub entity_id: String,
+
+    /// The kind of actor who performed the operation.
+    pub actor_type: AuditActorType,
+
+    /// The API key ID or the user ID of the actor who performed the operation.
+    pub actor_id: Option<String>,
+
+    /// The identifier of the request performing the operation.
+    pub request_id: Option<String>,
+
+    /// The fields changed by the operation, each with its value before and after the operation.
+    /// Sensitive values are masked.
+    #[schema(value_type = Object, example = json!({"return_url": {"before": "https://example.com", "after": "https://example.com/return"}}))]
+    pub changes: serde_json::Value,
+
+    /// Time at which the operation was performed.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Serialize)]
+pub struct AuditLogListRequestInternal {
+    pub merchant_id: String,
+    pub constraints: AuditLogListConstraints,
+}
+
+impl common_utils::events::ApiEventMetric for AuditLogListRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::ResourceListAPI)
+    }
+}
+
+impl common_utils::events::ApiEventMetric for AuditLogResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::ResourceListAPI)
+    }
+}
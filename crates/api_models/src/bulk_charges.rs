@@ -0,0 +1,143 @@
+use common_utils::events::ApiEventMetric;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+/// A row of a bulk charge file, for a single merchant initiated payment
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BulkChargeRow {
+    /// The identifier for the customer being charged
+    #[schema(max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: String,
+
+    /// The identifier of the active mandate used to create the off-session payment
+    #[schema(example = "mandate_iwer89rnjef349dni3")]
+    pub mandate_id: String,
+
+    /// The amount to be charged, in the lowest denomination of the currency
+    #[schema(example = 6540)]
+    pub amount: i64,
+
+    /// The currency of the amount to be charged
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The reference of the merchant for the charge, returned along with the result of the row
+    #[schema(example = "invoice_2024_04_001")]
+    pub reference: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BulkChargeCreateRequest {
+    /// The bulk charge file in CSV format, with a header row naming the columns of
+    /// `BulkChargeRow`
+    pub csv_data: Option<String>,
+
+    /// The rows of the bulk charge file, when uploaded as JSON instead of CSV
+    pub rows: Option<Vec<BulkChargeRow>>,
+
+    /// A description passed on to every payment created by this bulk charge
+    pub description: Option<String>,
+
+    /// The business profile to be used for the payments created by this bulk charge
+    pub profile_id: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BulkChargeStatus {
+    /// Payments of the rows are being created
+    Processing,
+    /// A payment was attempted for every row
+    Completed,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema, strum::Display)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum BulkChargeRowStatus {
+    /// The payment of the row succeeded, or is being processed by the connector
+    Succeeded,
+    /// The payment of the row failed, or could not be created
+    Failed,
+}
+
+/// The result of a row of a bulk charge file
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct BulkChargeRowResult {
+    /// The position of the row in the bulk charge file, starting from 1
+    pub row_number: usize,
+
+    /// The reference of the merchant for the charge
+    pub reference: String,
+
+    /// The identifier for the customer charged
+    pub customer_id: String,
+
+    /// The identifier of the mandate used to create the payment
+    pub mandate_id: String,
+
+    /// The amount charged
+    pub amount: i64,
+
+    /// The currency of the amount charged
+    #[schema(value_type = Currency)]
+    pub currency: api_enums::Currency,
+
+    /// The identifier of the payment created for the row
+    pub payment_id: String,
+
+    /// The result of the row
+    pub status: BulkChargeRowStatus,
+
+    /// The status of the payment created for the row, not set if the payment could not be
+    /// created
+    #[schema(value_type = Option<IntentStatus>)]
+    pub payment_status: Option<api_enums::IntentStatus>,
+
+    /// The reason the payment of the row could not be created
+    pub error_message: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BulkChargeId {
+    pub batch_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct BulkChargeResponse {
+    /// The identifier for the bulk charge
+    #[schema(example = "bulk_Hgt8Bhd21HkDw32qZ4sS")]
+    pub batch_id: String,
+
+    /// The identifier for the merchant
+    pub merchant_id: String,
+
+    /// The status of the bulk charge
+    pub status: BulkChargeStatus,
+
+    /// The number of rows in the bulk charge file
+    pub total_rows: usize,
+
+    /// The number of rows for which a payment was attempted
+    pub processed_rows: usize,
+
+    /// The number of rows whose payment succeeded
+    pub succeeded_rows: usize,
+
+    /// The number of rows whose payment failed
+    pub failed_rows: usize,
+
+    /// The time at which the bulk charge was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for BulkChargeCreateRequest {}
+impl ApiEventMetric for BulkChargeId {}
+impl ApiEventMetric for BulkChargeResponse {}
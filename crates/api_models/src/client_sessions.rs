@@ -0,0 +1,56 @@
+use masking::Secret;
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+/// The request body for creating a client session.
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ClientSessionCreateRequest {
+    /// The identifier of the payment to which the client session is bound.
+    #[schema(max_length = 64, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+}
+
+/// A short lived session of a client such as the SDK, bound to a single payment and to the
+/// customer of the payment. The token authenticates the client in place of the client secret of
+/// the payment, and is consumed by the operations changing the payment, such as confirming it.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ClientSessionResponse {
+    /// The token authenticating the client, to be sent as the API key.
+    #[schema(value_type = String, example = "cst_mbabizu24mvu3mela5njyhpit4")]
+    pub token: Secret<String>,
+
+    /// The identifier of the payment to which the client session is bound.
+    #[schema(max_length = 64, example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+
+    /// The identifier of the customer to which the client session is bound, if any.
+    #[schema(max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: Option<String>,
+
+    /// The publishable key of the merchant, to initialize the client with.
+    #[schema(example = "pk_snd_3b33cd9404234113804aa1accaabe22f")]
+    pub publishable_key: Option<String>,
+
+    /// Time at which the client session expires.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub expires_at: PrimitiveDateTime,
+}
+
+impl common_utils::events::ApiEventMetric for ClientSessionCreateRequest {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
+
+impl common_utils::events::ApiEventMetric for ClientSessionResponse {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Payment {
+            payment_id: self.payment_id.clone(),
+        })
+    }
+}
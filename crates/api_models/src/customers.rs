@@ -39,6 +39,8 @@ pub struct CustomerRequest {
     /// object.
     #[schema(value_type = Option<Object>,example = json!({ "city": "NY", "unit": "245" }))]
     pub metadata: Option<pii::SecretSerdeValue>,
+    /// The tax identification or identity document of the customer
+    pub document: Option<payments::CustomerDocumentDetails>,
 }
 
 #[derive(Debug, Clone, Serialize, ToSchema)]
@@ -76,6 +78,8 @@ pub struct CustomerResponse {
     /// The identifier for the default payment method.
     #[schema(max_length = 64, example = "pm_djh2837dwduh890123")]
     pub default_payment_method_id: Option<String>,
+    /// The tax identification or identity document of the customer
+    pub document: Option<payments::CustomerDocumentDetails>,
 }
 
 #[derive(Default, Clone, Debug, Deserialize, Serialize)]
@@ -99,6 +103,69 @@ pub struct CustomerDeleteResponse {
     pub payment_methods_deleted: bool,
 }
 
+/// The customers to be merged
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomerMergeRequest {
+    /// The identifier of the customer the duplicate customers are merged into
+    #[schema(max_length = 255, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: String,
+    /// The identifiers of the duplicate customers, which are deleted once merged. Details missing
+    /// on the customer are taken from the duplicate customers, in the given order.
+    #[schema(example = json!(["cus_abcdefghijklmnopqrstuvwxyz"]))]
+    pub duplicate_customer_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct CustomerMergeResponse {
+    /// The identifier of the customer the duplicate customers were merged into
+    #[schema(max_length = 255, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: String,
+    /// The identifiers of the duplicate customers which were merged and deleted
+    #[schema(example = json!(["cus_abcdefghijklmnopqrstuvwxyz"]))]
+    pub merged_customer_ids: Vec<String>,
+    /// The number of payment methods moved to the customer
+    #[schema(example = 2)]
+    pub payment_methods_moved: usize,
+    /// The number of cards of the duplicate customers deleted as the customer already had them saved
+    #[schema(example = 1)]
+    pub payment_methods_deduplicated: usize,
+    /// The number of mandates moved to the customer
+    #[schema(example = 1)]
+    pub mandates_moved: usize,
+    /// The number of payments moved to the customer
+    #[schema(example = 5)]
+    pub payments_moved: usize,
+}
+
+/// The detail duplicate customers were matched on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum CustomerMatchField {
+    Email,
+    Phone,
+}
+
+/// Customers which are likely duplicates of each other
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CustomerMergeSuggestion {
+    /// The detail the customers have in common
+    pub matched_on: CustomerMatchField,
+    /// The identifiers of the customers, the least recently created customer first
+    #[schema(example = json!(["cus_y3oqhf46pyzuxjbcn2giaqnb44", "cus_abcdefghijklmnopqrstuvwxyz"]))]
+    pub customer_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CustomerMergeSuggestionsResponse {
+    /// Time at which the suggestions were computed by the last deduplication scan
+    #[schema(value_type = PrimitiveDateTime, example = "2023-01-18T11:04:09.922Z")]
+    #[serde(with = "custom_serde::iso8601")]
+    pub computed_at: time::PrimitiveDateTime,
+    /// The groups of customers which are likely duplicates of each other
+    pub suggestions: Vec<CustomerMergeSuggestion>,
+}
+
 pub fn generate_customer_id() -> String {
     common_utils::generate_id(consts::ID_LENGTH, "cus")
 }
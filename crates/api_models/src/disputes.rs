@@ -2,7 +2,7 @@ use masking::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
-use super::enums::{DisputeStage, DisputeStatus};
+use super::enums::{Currency, DisputeRepresentmentStatus, DisputeStage, DisputeStatus};
 use crate::files;
 
 #[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
@@ -47,6 +47,8 @@ pub struct DisputeResponse {
     pub profile_id: Option<String>,
     /// The `merchant_connector_id` of the connector / processor through which the dispute was processed
     pub merchant_connector_id: Option<String>,
+    /// Outcome of the automatic representment of the dispute, if the merchant has representment rules configured
+    pub representment_status: Option<DisputeRepresentmentStatus>,
 }
 
 #[derive(Clone, Debug, Serialize, ToSchema, Eq, PartialEq)]
@@ -137,6 +139,8 @@ pub struct DisputeListConstraints {
     #[schema(example = "2022-09-10T10:11:12Z")]
     #[serde(rename = "received_time.gte")]
     pub received_time_gte: Option<PrimitiveDateTime>,
+    /// Outcome of the automatic representment of the dispute
+    pub representment_status: Option<DisputeRepresentmentStatus>,
 }
 
 #[derive(Default, Clone, Debug, Serialize, Deserialize, ToSchema)]
@@ -204,3 +208,198 @@ pub struct DeleteEvidenceRequest {
     /// Evidence Type to be deleted
     pub evidence_type: EvidenceType,
 }
+
+/// Rules used to automatically assemble and submit evidence for the disputes of a merchant
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeRepresentmentRules {
+    /// Rules evaluated in order, evidence is submitted using the first rule matching the dispute.
+    /// Disputes which do not match any rule are left for review by the merchant.
+    pub rules: Vec<DisputeRepresentmentRule>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DisputeRepresentmentRule {
+    /// Name of the rule
+    #[schema(example = "digital_goods")]
+    pub name: String,
+    /// Maximum dispute amount in the lowest denomination of the currency, up to which the rule applies
+    #[schema(example = 5000)]
+    pub max_amount: Option<i64>,
+    /// Currencies of the disputes the rule applies to, the rule applies to all currencies if not set
+    pub currencies: Option<Vec<Currency>>,
+    /// Connectors of the disputes the rule applies to, the rule applies to all connectors if not set
+    #[schema(example = json!(["stripe"]))]
+    pub connectors: Option<Vec<String>>,
+    /// Dispute reason codes sent by the connector, the rule applies to all reason codes if not set
+    pub reason_codes: Option<Vec<String>>,
+    /// URL called with the dispute details to fetch the evidence of the dispute, such as the delivery logs of digital goods.
+    /// The dispute is left for review if the callback does not return any evidence.
+    #[schema(example = "https://merchant.example.com/disputes/evidence")]
+    pub evidence_callback_url: Option<String>,
+    /// Evidence submitted for every dispute the rule applies to, the evidence returned by the callback takes precedence over it
+    pub evidence: Option<DisputeRepresentmentEvidence>,
+}
+
+/// Evidence submitted for a dispute, the file evidence refers to files uploaded through the files API
+#[derive(Clone, Debug, Default, Serialize, Deserialize, ToSchema, PartialEq, Eq)]
+pub struct DisputeRepresentmentEvidence {
+    /// Logs showing the usage of service by customer
+    pub access_activity_log: Option<String>,
+    /// Billing address of the customer
+    pub billing_address: Option<String>,
+    /// File Id of cancellation policy
+    pub cancellation_policy: Option<String>,
+    /// Details of showing cancellation policy to customer before purchase
+    pub cancellation_policy_disclosure: Option<String>,
+    /// Details telling why customer's subscription was not cancelled
+    pub cancellation_rebuttal: Option<String>,
+    /// File Id of customer communication
+    pub customer_communication: Option<String>,
+    /// Customer email address
+    pub customer_email_address: Option<String>,
+    /// Customer name
+    pub customer_name: Option<String>,
+    /// IP address of the customer
+    pub customer_purchase_ip: Option<String>,
+    /// Fild Id of customer signature
+    pub customer_signature: Option<String>,
+    /// Product Description
+    pub product_description: Option<String>,
+    /// File Id of receipt
+    pub receipt: Option<String>,
+    /// File Id of refund policy
+    pub refund_policy: Option<String>,
+    /// Details of showing refund policy to customer before purchase
+    pub refund_policy_disclosure: Option<String>,
+    /// Details why customer is not entitled to refund
+    pub refund_refusal_explanation: Option<String>,
+    /// Customer service date
+    pub service_date: Option<String>,
+    /// File Id service documentation
+    pub service_documentation: Option<String>,
+    /// Shipping address of the customer
+    pub shipping_address: Option<String>,
+    /// Delivery service that shipped the product
+    pub shipping_carrier: Option<String>,
+    /// Shipping date
+    pub shipping_date: Option<String>,
+    /// File Id shipping documentation
+    pub shipping_documentation: Option<String>,
+    /// Tracking number of shipped product
+    pub shipping_tracking_number: Option<String>,
+    /// File Id showing two distinct transactions when customer claims a payment was charged twice
+    pub invoice_showing_distinct_transactions: Option<String>,
+    /// File Id of recurring transaction agreement
+    pub recurring_transaction_agreement: Option<String>,
+    /// Any additional supporting file
+    pub uncategorized_file: Option<String>,
+    /// Any additional evidence statements
+    pub uncategorized_text: Option<String>,
+}
+
+impl DisputeRepresentmentEvidence {
+    pub fn is_empty(&self) -> bool {
+        self == &Self::default()
+    }
+
+    /// Fills the evidence which is not present with the evidence from `other`
+    pub fn or(self, other: Self) -> Self {
+        Self {
+            access_activity_log: self.access_activity_log.or(other.access_activity_log),
+            billing_address: self.billing_address.or(other.billing_address),
+            cancellation_policy: self.cancellation_policy.or(other.cancellation_policy),
+            cancellation_policy_disclosure: self
+                .cancellation_policy_disclosure
+                .or(other.cancellation_policy_disclosure),
+            cancellation_rebuttal: self.cancellation_rebuttal.or(other.cancellation_rebuttal),
+            customer_communication: self.customer_communication.or(other.customer_communication),
+            customer_email_address: self.customer_email_address.or(other.customer_email_address),
+            customer_name: self.customer_name.or(other.customer_name),
+            customer_purchase_ip: self.customer_purchase_ip.or(other.customer_purchase_ip),
+            customer_signature: self.customer_signature.or(other.customer_signature),
+            product_description: self.product_description.or(other.product_description),
+            receipt: self.receipt.or(other.receipt),
+            refund_policy: self.refund_policy.or(other.refund_policy),
+            refund_policy_disclosure: self
+                .refund_policy_disclosure
+                .or(other.refund_policy_disclosure),
+            refund_refusal_explanation: self
+                .refund_refusal_explanation
+                .or(other.refund_refusal_explanation),
+            service_date: self.service_date.or(other.service_date),
+            service_documentation: self.service_documentation.or(other.service_documentation),
+            shipping_address: self.shipping_address.or(other.shipping_address),
+            shipping_carrier: self.shipping_carrier.or(other.shipping_carrier),
+            shipping_date: self.shipping_date.or(other.shipping_date),
+            shipping_documentation: self.shipping_documentation.or(other.shipping_documentation),
+            shipping_tracking_number: self
+                .shipping_tracking_number
+                .or(other.shipping_tracking_number),
+            invoice_showing_distinct_transactions: self
+                .invoice_showing_distinct_transactions
+                .or(other.invoice_showing_distinct_transactions),
+            recurring_transaction_agreement: self
+                .recurring_transaction_agreement
+                .or(other.recurring_transaction_agreement),
+            uncategorized_file: self.uncategorized_file.or(other.uncategorized_file),
+            uncategorized_text: self.uncategorized_text.or(other.uncategorized_text),
+        }
+    }
+
+    pub fn into_submit_evidence_request(self, dispute_id: String) -> SubmitEvidenceRequest {
+        SubmitEvidenceRequest {
+            dispute_id,
+            access_activity_log: self.access_activity_log,
+            billing_address: self.billing_address,
+            cancellation_policy: self.cancellation_policy,
+            cancellation_policy_disclosure: self.cancellation_policy_disclosure,
+            cancellation_rebuttal: self.cancellation_rebuttal,
+            customer_communication: self.customer_communication,
+            customer_email_address: self.customer_email_address,
+            customer_name: self.customer_name,
+            customer_purchase_ip: self.customer_purchase_ip,
+            customer_signature: self.customer_signature,
+            product_description: self.product_description,
+            receipt: self.receipt,
+            refund_policy: self.refund_policy,
+            refund_policy_disclosure: self.refund_policy_disclosure,
+            refund_refusal_explanation: self.refund_refusal_explanation,
+            service_date: self.service_date,
+            service_documentation: self.service_documentation,
+            shipping_address: self.shipping_address,
+            shipping_carrier: self.shipping_carrier,
+            shipping_date: self.shipping_date,
+            shipping_documentation: self.shipping_documentation,
+            shipping_tracking_number: self.shipping_tracking_number,
+            invoice_showing_distinct_transactions: self.invoice_showing_distinct_transactions,
+            recurring_transaction_agreement: self.recurring_transaction_agreement,
+            uncategorized_file: self.uncategorized_file,
+            uncategorized_text: self.uncategorized_text,
+        }
+    }
+}
+
+/// Details of the dispute sent to the evidence callback of a representment rule
+#[derive(Clone, Debug, Serialize)]
+pub struct DisputeRepresentmentCallbackRequest {
+    /// The identifier for dispute
+    pub dispute_id: String,
+    /// The identifier for payment_intent
+    pub payment_id: String,
+    /// The identifier for payment_attempt
+    pub attempt_id: String,
+    /// The dispute amount
+    pub amount: String,
+    /// The three-letter ISO currency code
+    pub currency: String,
+    /// connector to which dispute is associated with
+    pub connector: String,
+    /// Reason of dispute sent by connector
+    pub connector_reason: Option<String>,
+    /// Reason code of dispute sent by connector
+    pub connector_reason_code: Option<String>,
+    /// Name of the representment rule which matched the dispute
+    pub rule_name: String,
+}
@@ -656,6 +656,76 @@ pub enum RetryAction {
     Requeue,
 }
 
+/// Outcome of the verification of a card which is set up for future usage without a payment
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CardVerificationStatus {
+    /// The card has been verified by the connector and can be used for future payments
+    Verified,
+    /// The card was declined by the connector or the verification could not be completed
+    Failed,
+    /// The verification is awaiting an action from the customer or the connector
+    Pending,
+}
+
+/// Channels through which merchants are notified of operational alerts
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum NotificationChannelType {
+    /// Slack incoming webhook
+    Slack,
+    /// Microsoft Teams incoming webhook
+    MsTeams,
+}
+
+/// Operational alerts which can be posted to a notification channel
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    PartialEq,
+    serde::Serialize,
+    serde::Deserialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum OperationalAlert {
+    /// A payment with an amount above the threshold of the channel failed
+    HighValuePaymentFailed,
+    /// A new dispute was received from the connector
+    DisputeReceived,
+    /// A connector was found to be degraded and is excluded from routing
+    ConnectorCircuitOpened,
+}
+
 #[derive(Clone, Copy)]
 pub enum LockerChoice {
     HyperswitchCardVault,
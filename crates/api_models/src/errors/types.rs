@@ -95,6 +95,8 @@ pub enum ApiErrorResponse {
     MethodNotAllowed(ApiError),
     BadRequest(ApiError),
     DomainError(ApiError),
+    TooManyRequests(ApiError),
+    ServiceUnavailable(ApiError),
 }
 
 impl ::core::fmt::Display for ApiErrorResponse {
@@ -124,6 +126,8 @@ impl ApiErrorResponse {
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
+            | Self::TooManyRequests(i)
+            | Self::ServiceUnavailable(i)
             | Self::ConnectorError(i, _) => i,
         }
     }
@@ -142,6 +146,8 @@ impl ApiErrorResponse {
             | Self::MethodNotAllowed(i)
             | Self::BadRequest(i)
             | Self::DomainError(i)
+            | Self::TooManyRequests(i)
+            | Self::ServiceUnavailable(i)
             | Self::ConnectorError(i, _) => i,
         }
     }
@@ -157,8 +163,9 @@ impl ApiErrorResponse {
             | Self::NotImplemented(_)
             | Self::MethodNotAllowed(_)
             | Self::NotFound(_)
-            | Self::BadRequest(_) => "invalid_request",
-            Self::InternalServerError(_) => "api",
+            | Self::BadRequest(_)
+            | Self::TooManyRequests(_) => "invalid_request",
+            Self::InternalServerError(_) | Self::ServiceUnavailable(_) => "api",
             Self::DomainError(_) => "blocked",
             Self::ConnectorError(_, _) => "connector",
         }
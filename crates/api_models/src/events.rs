@@ -23,7 +23,8 @@ use crate::{
     admin::*,
     analytics::{
         api_event::*, connector_events::ConnectorEventsRequest,
-        outgoing_webhook_event::OutgoingWebhookLogsRequest, sdk_events::*, search::*, *,
+        outgoing_webhook_event::OutgoingWebhookLogsRequest, payments::LivePaymentActivityResponse,
+        scheduled_reports::*, sdk_events::*, search::*, success_rate_alerts::*, *,
     },
     api_keys::*,
     cards_info::*,
@@ -53,6 +54,7 @@ impl_misc_api_event_type!(
     PaymentLinkListConstraints,
     MandateId,
     DisputeListConstraints,
+    DisputeRepresentmentRules,
     RetrieveApiKeyResponse,
     BusinessProfileResponse,
     BusinessProfileUpdate,
@@ -61,14 +63,28 @@ impl_misc_api_event_type!(
     ToggleKVResponse,
     ToggleKVRequest,
     MerchantAccountDeleteResponse,
+    MerchantAccountDataExport,
     MerchantAccountUpdate,
     CardInfoResponse,
     CreateApiKeyResponse,
     CreateApiKeyRequest,
     MerchantConnectorDeleteResponse,
     MerchantConnectorUpdate,
+    MerchantConnectorWebhookSecretRotateRequest,
+    MerchantConnectorBulkRequest,
+    MerchantConnectorBulkResponse,
+    AccountTemplateCreate,
+    AccountTemplateResponse,
+    AccountTemplateInstantiateRequest,
+    AccountTemplateInstantiateResponse,
+    BusinessProfileWebhookSecretRotateRequest,
     MerchantConnectorCreate,
     MerchantId,
+    CardFingerprintKeyRotateRequest,
+    CardFingerprintKeyResponse,
+    TokenRequestorIdCreateRequest,
+    TokenRequestorIdUpdateRequest,
+    TokenRequestorIdResponse,
     CardsInfoRequest,
     MerchantAccountResponse,
     MerchantAccountListRequest,
@@ -78,11 +94,14 @@ impl_misc_api_event_type!(
     ApplepayMerchantResponse,
     ApplepayVerifiedDomainsResponse,
     UpdateApiKeyRequest,
+    RotateApiKeyRequest,
     GetApiEventFiltersRequest,
     ApiEventFiltersResponse,
     GetInfoResponse,
     GetPaymentMetricRequest,
     GetRefundMetricRequest,
+    GetRefundTopReasonsRequest,
+    GetConnectorUptimeRequest,
     GetSdkEventMetricRequest,
     GetPaymentFiltersRequest,
     PaymentFiltersResponse,
@@ -93,6 +112,7 @@ impl_misc_api_event_type!(
     ApiLogsRequest,
     GetApiEventMetricRequest,
     SdkEventsRequest,
+    SdkTelemetryEventsRequest,
     ReportRequest,
     ConnectorEventsRequest,
     OutgoingWebhookLogsRequest,
@@ -102,7 +122,17 @@ impl_misc_api_event_type!(
     GetSearchRequestWithIndex,
     GetDisputeFilterRequest,
     DisputeFiltersResponse,
-    GetDisputeMetricRequest
+    GetDisputeMetricRequest,
+    GetAuthEventMetricRequest,
+    ReportScheduleCreateRequest,
+    ReportScheduleId,
+    ReportScheduleResponse,
+    ReportRunListRequest,
+    ReportRunListResponse,
+    LivePaymentActivityResponse,
+    SuccessRateAlertCreateRequest,
+    SuccessRateAlertId,
+    SuccessRateAlertResponse
 );
 
 #[cfg(feature = "stripe")]
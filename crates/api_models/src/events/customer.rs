@@ -1,6 +1,9 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
-use crate::customers::{CustomerDeleteResponse, CustomerId, CustomerRequest, CustomerResponse};
+use crate::customers::{
+    CustomerDeleteResponse, CustomerId, CustomerMergeRequest, CustomerMergeResponse,
+    CustomerMergeSuggestionsResponse, CustomerRequest, CustomerResponse,
+};
 
 impl ApiEventMetric for CustomerDeleteResponse {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
@@ -33,3 +36,25 @@ impl ApiEventMetric for CustomerId {
         })
     }
 }
+
+impl ApiEventMetric for CustomerMergeRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for CustomerMergeResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Customer {
+            customer_id: self.customer_id.clone(),
+        })
+    }
+}
+
+impl ApiEventMetric for CustomerMergeSuggestionsResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::ResourceListAPI)
+    }
+}
@@ -1,6 +1,10 @@
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 
-use crate::recon::{ReconStatusResponse, ReconTokenResponse, ReconUpdateMerchantRequest};
+use crate::recon::{
+    ReconStatusResponse, ReconTokenResponse, ReconUpdateMerchantRequest,
+    SettlementRecordListConstraints, SettlementRecordResponse, SettlementReportUploadRequest,
+    SettlementReportUploadResponse, UnreconciledItemsReport, UnreconciledItemsReportRequest,
+};
 
 impl ApiEventMetric for ReconUpdateMerchantRequest {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
@@ -19,3 +23,39 @@ impl ApiEventMetric for ReconStatusResponse {
         Some(ApiEventsType::Recon)
     }
 }
+
+impl ApiEventMetric for SettlementReportUploadRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Recon)
+    }
+}
+
+impl ApiEventMetric for SettlementReportUploadResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Recon)
+    }
+}
+
+impl ApiEventMetric for SettlementRecordResponse {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Recon)
+    }
+}
+
+impl ApiEventMetric for SettlementRecordListConstraints {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Recon)
+    }
+}
+
+impl ApiEventMetric for UnreconciledItemsReport {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Recon)
+    }
+}
+
+impl ApiEventMetric for UnreconciledItemsReportRequest {
+    fn get_api_event_type(&self) -> Option<ApiEventsType> {
+        Some(ApiEventsType::Recon)
+    }
+}
@@ -12,9 +12,10 @@ use crate::user::{
     },
     AcceptInviteFromEmailRequest, AuthorizeResponse, ChangePasswordRequest, ConnectAccountRequest,
     CreateInternalUserRequest, DashboardEntryResponse, ForgotPasswordRequest,
-    GetUserDetailsRequest, GetUserDetailsResponse, InviteUserRequest, InviteUserResponse,
-    ListUsersResponse, ReInviteUserRequest, ResetPasswordRequest, SendVerifyEmailRequest,
-    SignInResponse, SignUpRequest, SignUpWithMerchantIdRequest, SwitchMerchantIdRequest,
+    GetUserDetailsRequest, GetUserDetailsResponse, ImpersonateMerchantRequest,
+    ImpersonateMerchantResponse, InviteUserRequest, InviteUserResponse, ListUsersResponse,
+    ReInviteUserRequest, ResetPasswordRequest, SendVerifyEmailRequest, SignInResponse,
+    SignUpRequest, SignUpWithMerchantIdRequest, SwitchMerchantIdRequest,
     UpdateUserAccountDetailsRequest, UserMerchantCreate, VerifyEmailRequest,
 };
 
@@ -46,6 +47,8 @@ common_utils::impl_misc_api_event_type!(
     GetMetaDataRequest,
     SetMetaDataRequest,
     SwitchMerchantIdRequest,
+    ImpersonateMerchantRequest,
+    ImpersonateMerchantResponse,
     CreateInternalUserRequest,
     UserMerchantCreate,
     ListUsersResponse,
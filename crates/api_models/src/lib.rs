@@ -2,9 +2,13 @@
 pub mod admin;
 pub mod analytics;
 pub mod api_keys;
+pub mod api_versioning;
+pub mod audit_logs;
 pub mod bank_accounts;
 pub mod blocklist;
+pub mod bulk_charges;
 pub mod cards_info;
+pub mod client_sessions;
 pub mod conditional_configs;
 pub mod connector_onboarding;
 pub mod currency;
@@ -19,7 +23,9 @@ pub mod files;
 pub mod gsm;
 pub mod health_check;
 pub mod locker_migration;
+pub mod log_filter;
 pub mod mandates;
+pub mod manual_review_rules;
 pub mod organization;
 pub mod payment_methods;
 pub mod payments;
@@ -28,11 +34,14 @@ pub mod payouts;
 pub mod pm_auth;
 #[cfg(feature = "recon")]
 pub mod recon;
+pub mod recurring_payments;
 pub mod refunds;
 pub mod routing;
 pub mod surcharge_decision_configs;
+pub mod three_ds_decision_configs;
 pub mod user;
 pub mod user_role;
+pub mod velocity_rules;
 pub mod verifications;
 pub mod verify_connector;
 pub mod webhook_events;
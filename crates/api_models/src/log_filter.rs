@@ -0,0 +1,40 @@
+use time::PrimitiveDateTime;
+
+/// Overrides the filtering directives of the logs for a while
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LogFilterOverrideRequest {
+    /// Directives added on top of the configured directives, in the syntax of the `RUST_LOG`
+    /// environment variable, e.g. `router::core::payments=trace`
+    pub directives: String,
+    /// Seconds after which the configured directives are restored, defaults to the configured
+    /// duration
+    pub revert_after_in_secs: Option<u64>,
+}
+
+impl common_utils::events::ApiEventMetric for LogFilterOverrideRequest {}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct LogFilterResponse {
+    /// Configured directives of each log output
+    pub default_directives: Vec<LogOutputDirectives>,
+    /// Directives overriding the configured directives, if any
+    pub active_override: Option<LogFilterOverride>,
+}
+
+impl common_utils::events::ApiEventMetric for LogFilterResponse {}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct LogOutputDirectives {
+    /// Log output, `console` or `file`
+    pub output: String,
+    pub directives: String,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct LogFilterOverride {
+    pub directives: String,
+    /// Time at which the configured directives are restored
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub reverts_at: PrimitiveDateTime,
+}
@@ -0,0 +1,38 @@
+use common_utils::events;
+
+use crate::enums;
+
+/// Rules holding the payments of a merchant for a manual review by the merchant
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ManualReviewRules {
+    /// Rules evaluated for every payment attempt once its connector is decided by routing. The
+    /// capture of attempts matching any rule is held after authorization until the payment is
+    /// approved or rejected by the merchant.
+    pub rules: Vec<ManualReviewRule>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ManualReviewRule {
+    /// Name of the rule
+    pub name: String,
+    /// Minimum amount of the payment in the lowest denomination of the currency, from which the
+    /// rule applies
+    pub min_amount: Option<i64>,
+    /// Maximum amount of the payment in the lowest denomination of the currency, up to which the
+    /// rule applies
+    pub max_amount: Option<i64>,
+    /// Currencies of the payments the rule applies to, the rule applies to all currencies if not set
+    pub currencies: Option<Vec<enums::Currency>>,
+    /// Payment methods the rule applies to, the rule applies to all payment methods if not set
+    pub payment_methods: Option<Vec<enums::PaymentMethod>>,
+    /// Connectors routed to the rule applies to, the rule applies to all connectors if not set
+    pub connectors: Option<Vec<enums::Connector>>,
+}
+
+impl events::ApiEventMetric for ManualReviewRules {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Routing)
+    }
+}
@@ -191,6 +191,22 @@ pub struct CustomerDetails {
     /// The country code for the customer's phone number
     #[schema(max_length = 2, example = "+1")]
     pub phone_country_code: Option<String>,
+
+    /// The tax identification or identity document of the customer
+    pub document: Option<CustomerDocumentDetails>,
+}
+
+/// The tax identification or identity document of a customer, required by some connectors for
+/// payments in the country issuing the document
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone, PartialEq, Eq, ToSchema)]
+pub struct CustomerDocumentDetails {
+    /// The type of the document
+    #[schema(value_type = CustomerDocumentType, example = "CPF")]
+    pub document_type: api_enums::CustomerDocumentType,
+
+    /// The number of the document
+    #[schema(value_type = String, max_length = 32, example = "529.982.247-25")]
+    pub document_number: Secret<String>,
 }
 
 #[derive(
@@ -459,6 +475,11 @@ pub struct PaymentsRequest {
     /// Whether to perform external authentication (if applicable)
     #[schema(example = true)]
     pub request_external_three_ds_authentication: Option<bool>,
+
+    /// The strong customer authentication exemption requested for the payment, passed on to the
+    /// 3DS server and to the connectors supporting exemptions
+    #[schema(value_type = Option<ScaExemptionType>, example = "low_value")]
+    pub sca_exemption: Option<api_enums::ScaExemptionType>,
 }
 
 impl PaymentsRequest {
@@ -593,6 +614,48 @@ pub struct PaymentAttemptResponse {
     pub unified_code: Option<String>,
     /// error message unified across the connectors is received here if there was an error while calling connector
     pub unified_message: Option<String>,
+    /// The card network resolved from the card BIN for this attempt
+    #[schema(example = "Visa")]
+    pub card_network: Option<String>,
+    /// The card type (credit / debit) resolved from the card BIN for this attempt
+    #[schema(example = "CREDIT")]
+    pub card_type: Option<String>,
+    /// The issuing country of the card resolved from the card BIN for this attempt
+    #[schema(example = "INDIA")]
+    pub card_issuing_country: Option<String>,
+    /// The way this attempt was authenticated, as decided by the 3DS decision rules
+    #[schema(value_type = Option<ThreeDsDecision>, example = "connector_three_ds")]
+    pub three_ds_decision: Option<enums::ThreeDsDecision>,
+    /// The strong customer authentication exemption applied to this attempt
+    #[schema(value_type = Option<ScaExemptionType>, example = "low_value")]
+    pub sca_exemption: Option<enums::ScaExemptionType>,
+    /// Merchant scoped fingerprint of the card used for this attempt, the same card results in the same fingerprint across the payments of the merchant
+    #[schema(example = "8f14e45fceea167a5a36dedd4bea2543e6d8c5d3b9a4f0e7a1c2b3d4e5f60718")]
+    pub card_fingerprint: Option<String>,
+    /// Whether the payment method of this attempt was passed by the merchant or chosen by the customer
+    #[schema(value_type = Option<PaymentMethodSelection>, example = "customer")]
+    pub payment_method_selection: Option<enums::PaymentMethodSelection>,
+    /// The currency this attempt is settled in, when the connector converted it from the presentment currency
+    #[schema(value_type = Option<Currency>, example = "EUR")]
+    pub settlement_currency: Option<enums::Currency>,
+    /// The amount settled for this attempt in the lowest denomination of the settlement currency
+    #[schema(example = 6050)]
+    pub settlement_amount: Option<i64>,
+    /// The exchange rate applied by the connector to convert the presentment currency to the settlement currency
+    #[schema(example = "0.9234")]
+    pub fx_rate: Option<String>,
+    /// The address verification (AVS) result code returned by the connector for this attempt
+    #[schema(example = "Y")]
+    pub avs_result_code: Option<String>,
+    /// The outcome of the address verification, interpreted from the AVS result code
+    #[schema(value_type = Option<CardCheckResult>, example = "match")]
+    pub avs_result: Option<enums::CardCheckResult>,
+    /// The security code (CVV) result code returned by the connector for this attempt
+    #[schema(example = "M")]
+    pub cvv_result_code: Option<String>,
+    /// The outcome of the security code check, interpreted from the CVV result code
+    #[schema(value_type = Option<CardCheckResult>, example = "match")]
+    pub cvv_result: Option<enums::CardCheckResult>,
 }
 
 #[derive(
@@ -815,6 +878,15 @@ pub struct MandateAmountData {
         "frequency": "DAILY"
     }"#)]
     pub metadata: Option<pii::SecretSerdeValue>,
+    /// The maximum amount to be debited by a single transaction of the mandate
+    #[schema(example = 1000)]
+    pub max_amount_per_charge: Option<i64>,
+    /// The maximum amount to be debited by the transactions of the mandate within a `limit_period`
+    #[schema(example = 3000)]
+    pub max_amount_per_period: Option<i64>,
+    /// The period over which `max_amount_per_period` is applicable
+    #[schema(value_type = Option<MandateLimitPeriod>, example = "month")]
+    pub limit_period: Option<api_enums::MandateLimitPeriod>,
 }
 
 #[derive(Eq, PartialEq, Debug, serde::Deserialize, serde::Serialize, Clone, ToSchema)]
@@ -2913,6 +2985,11 @@ pub struct PaymentsResponse {
     #[schema(minimum = 100, example = 6540)]
     pub amount_received: Option<i64>,
 
+    /// The amount which can still be refunded from the payment, that is the amount captured less
+    /// the amount refunded or being refunded. Populated only when retrieving the payment
+    #[schema(example = 6540)]
+    pub amount_refundable: Option<i64>,
+
     /// The connector used for the payment
     #[schema(example = "stripe")]
     pub connector: Option<String>,
@@ -3159,6 +3236,42 @@ pub struct PaymentsResponse {
     /// Payment Method Status
     #[schema(value_type = Option<PaymentMethodStatus>)]
     pub payment_method_status: Option<common_enums::PaymentMethodStatus>,
+
+    /// Status of the card verification, present only for zero amount payments which set up a card for future usage
+    #[schema(value_type = Option<CardVerificationStatus>)]
+    pub card_verification_status: Option<enums::CardVerificationStatus>,
+
+    /// Whether the payment method was passed by the merchant, or chosen by the customer on the hosted payment page or the SDK for a payment created without one
+    #[schema(value_type = Option<PaymentMethodSelection>, example = "customer")]
+    pub payment_method_selection: Option<enums::PaymentMethodSelection>,
+
+    /// The currency the merchant is settled in for this payment, when the connector converted it from the presentment currency
+    #[schema(value_type = Option<Currency>, example = "EUR")]
+    pub settlement_currency: Option<enums::Currency>,
+
+    /// The amount settled to the merchant in the lowest denomination of the settlement currency
+    #[schema(example = 6050)]
+    pub settlement_amount: Option<i64>,
+
+    /// The exchange rate applied by the connector to convert the presentment currency to the settlement currency
+    #[schema(example = "0.9234")]
+    pub fx_rate: Option<String>,
+
+    /// The address verification (AVS) result code returned by the connector
+    #[schema(example = "Y")]
+    pub avs_result_code: Option<String>,
+
+    /// The outcome of the address verification, interpreted from the AVS result code
+    #[schema(value_type = Option<CardCheckResult>, example = "match")]
+    pub avs_result: Option<enums::CardCheckResult>,
+
+    /// The security code (CVV) result code returned by the connector
+    #[schema(example = "M")]
+    pub cvv_result_code: Option<String>,
+
+    /// The outcome of the security code check, interpreted from the CVV result code
+    #[schema(value_type = Option<CardCheckResult>, example = "match")]
+    pub cvv_result: Option<enums::CardCheckResult>,
 }
 
 #[derive(Setter, Clone, Default, Debug, PartialEq, serde::Serialize, ToSchema)]
@@ -3181,6 +3294,29 @@ pub struct ExternalAuthenticationDetailsResponse {
     pub error_message: Option<String>,
 }
 
+/// The external 3DS authentication of a payment, sent in the webhooks of the authentication
+/// lifecycle
+#[derive(Clone, Debug, PartialEq, serde::Serialize, ToSchema)]
+pub struct ExternalAuthenticationResponse {
+    /// Identifier of the authentication
+    #[schema(example = "authn_fc5ZWa6Vcsvb9bDm7rMs")]
+    pub authentication_id: String,
+    /// Identifier of the payment the authentication is performed for
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: Option<String>,
+    /// The connector the authentication is performed through
+    #[schema(example = "threedsecureio")]
+    pub authentication_connector: String,
+    /// Details of the authentication
+    pub authentication_details: ExternalAuthenticationDetailsResponse,
+    /// URL of the ACS the cardholder is challenged at, present when a challenge is required
+    pub acs_url: Option<String>,
+    /// Time at which the authentication was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
 #[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct PaymentListConstraints {
@@ -3248,6 +3384,44 @@ pub struct PaymentListResponse {
     pub data: Vec<PaymentsResponse>,
 }
 
+#[derive(Clone, Debug, serde::Deserialize, ToSchema, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct FrmReviewQueueConstraints {
+    /// limit on the number of payments to return
+    #[schema(default = 10, maximum = 100)]
+    #[serde(default = "default_payments_list_limit")]
+    pub limit: u32,
+
+    /// The number of payments to skip when retrieving the list of payments
+    pub offset: Option<u32>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct FrmReviewQueueResponse {
+    /// The number of payments included in the list
+    pub size: usize,
+    /// The payments held for manual review by the fraud check, most recent first
+    pub data: Vec<FrmReviewQueueItem>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, ToSchema)]
+pub struct FrmReviewQueueItem {
+    /// Unique identifier for the payment
+    #[schema(example = "pay_mbabizu24mvu3mela5njyhpit4")]
+    pub payment_id: String,
+    /// The payment amount, in the lowest denomination of the currency
+    #[schema(example = 6540)]
+    pub amount: i64,
+    #[schema(value_type = Option<Currency>, example = "USD")]
+    pub currency: Option<api_enums::Currency>,
+    /// Time at which the payment was created
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created: PrimitiveDateTime,
+    /// Outcome of the fraud check the payment is held for
+    pub frm_message: Option<FrmMessage>,
+}
+
 #[derive(Setter, Clone, Default, Debug, PartialEq, serde::Serialize, ToSchema)]
 pub struct IncrementalAuthorizationResponse {
     /// The unique identifier of authorization
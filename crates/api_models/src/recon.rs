@@ -1,5 +1,6 @@
 use common_utils::pii;
 use masking::Secret;
+use time::PrimitiveDateTime;
 
 use crate::enums;
 
@@ -19,3 +20,112 @@ pub struct ReconTokenResponse {
 pub struct ReconStatusResponse {
     pub recon_status: enums::ReconStatus,
 }
+
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SettlementReportUploadRequest {
+    /// The connector which generated the settlement report
+    pub connector: enums::Connector,
+    /// Identifier of the settlement report, generated if not provided
+    pub report_id: Option<String>,
+    /// The settlement report in CSV format, with a header row naming the columns of
+    /// `SettlementReportRow`
+    pub csv_data: Option<String>,
+    /// The rows of the settlement report, when pushed as JSON instead of CSV
+    pub rows: Option<Vec<SettlementReportRow>>,
+}
+
+/// A row of a connector settlement report, for a single payment or refund
+#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+pub struct SettlementReportRow {
+    pub record_type: enums::SettlementRecordType,
+    /// The connector transaction ID of the payment, or the connector refund ID of the refund
+    pub connector_transaction_id: String,
+    pub currency: enums::Currency,
+    /// The settled amount, before fees
+    pub gross_amount: i64,
+    /// The fees charged by the connector
+    pub fee_amount: Option<i64>,
+    /// The settled amount, after fees
+    pub net_amount: Option<i64>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub settled_at: Option<PrimitiveDateTime>,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SettlementReportUploadResponse {
+    pub report_id: String,
+    pub connector: String,
+    pub summary: SettlementMatchSummary,
+}
+
+/// Number of settlement records by match status
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct SettlementMatchSummary {
+    pub total_count: usize,
+    pub matched: usize,
+    pub amount_mismatch: usize,
+    pub currency_mismatch: usize,
+    pub fee_mismatch: usize,
+    pub unmatched: usize,
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct SettlementRecordResponse {
+    pub record_id: String,
+    pub report_id: String,
+    pub connector: String,
+    pub record_type: enums::SettlementRecordType,
+    pub connector_transaction_id: String,
+    pub currency: enums::Currency,
+    pub gross_amount: i64,
+    pub fee_amount: i64,
+    pub net_amount: i64,
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub settled_at: Option<PrimitiveDateTime>,
+    pub payment_id: Option<String>,
+    pub attempt_id: Option<String>,
+    pub refund_id: Option<String>,
+    /// The amount of the matched payment attempt or refund
+    pub expected_amount: Option<i64>,
+    pub match_status: enums::SettlementMatchStatus,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct SettlementRecordListConstraints {
+    /// Only list the records ingested from this settlement report
+    pub report_id: Option<String>,
+    /// Only list the records of this connector
+    pub connector: Option<String>,
+    /// Only list the records with this match status
+    pub match_status: Option<enums::SettlementMatchStatus>,
+    /// The maximum number of records to list
+    pub limit: Option<i64>,
+    /// The number of records to skip
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default, serde::Deserialize, serde::Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct UnreconciledItemsReportRequest {
+    /// Only report the records ingested from this settlement report
+    pub report_id: Option<String>,
+    /// Only report the records of this connector
+    pub connector: Option<String>,
+    /// The maximum number of unreconciled records to list
+    pub limit: Option<i64>,
+    /// The number of unreconciled records to skip
+    pub offset: Option<i64>,
+}
+
+/// The settlement records which could not be reconciled with a payment attempt or refund
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct UnreconciledItemsReport {
+    pub summary: SettlementMatchSummary,
+    pub records: Vec<SettlementRecordResponse>,
+}
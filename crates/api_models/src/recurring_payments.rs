@@ -0,0 +1,147 @@
+use common_utils::{events::ApiEventMetric, pii};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+use utoipa::ToSchema;
+
+use crate::enums as api_enums;
+
+/// The unit of time between two consecutive charges of a recurring payment schedule
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RecurringInterval {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RecurringPaymentScheduleStatus {
+    /// Charges are being created on schedule
+    Active,
+    /// All the requested charges have been made
+    Completed,
+    /// The schedule was cancelled by the merchant
+    Cancelled,
+    /// A charge could not be collected after exhausting all dunning retries
+    Failed,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RecurringPaymentScheduleCreateRequest {
+    /// The identifier for the customer being charged
+    #[schema(max_length = 64, example = "cus_y3oqhf46pyzuxjbcn2giaqnb44")]
+    pub customer_id: String,
+
+    /// The identifier of the active mandate used to create the off-session payments
+    #[schema(example = "mandate_iwer89rnjef349dni3")]
+    pub mandate_id: String,
+
+    /// The amount to be charged on every cycle, in the lowest denomination of the currency
+    #[schema(example = 6540)]
+    pub amount: i64,
+
+    /// The currency of the amount to be charged
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: api_enums::Currency,
+
+    /// The unit of time between two charges
+    pub interval: RecurringInterval,
+
+    /// The number of `interval` units between two charges, defaults to 1
+    #[schema(example = 1)]
+    pub interval_count: Option<u16>,
+
+    /// The time at which the first charge is created, defaults to the current time
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub start_time: Option<PrimitiveDateTime>,
+
+    /// The total number of successful charges after which the schedule completes. The schedule
+    /// keeps charging until it is cancelled if this is not provided
+    #[schema(example = 12)]
+    pub total_charges: Option<u32>,
+
+    /// Hours to wait before each dunning retry of a failed charge, in order. The configured
+    /// default is used if this is not provided, an empty list disables dunning retries
+    #[schema(example = json!([24, 72, 168]))]
+    pub dunning_retry_intervals_in_hours: Option<Vec<u32>>,
+
+    /// A description passed on to every payment created by this schedule
+    pub description: Option<String>,
+
+    /// The business profile to be used for the payments created by this schedule
+    pub profile_id: Option<String>,
+
+    /// Metadata passed on to every payment created by this schedule
+    #[schema(value_type = Option<Object>, example = r#"{ "plan": "gold" }"#)]
+    pub metadata: Option<pii::SecretSerdeValue>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RecurringPaymentScheduleId {
+    pub schedule_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, ToSchema)]
+pub struct RecurringPaymentScheduleResponse {
+    /// The identifier for the recurring payment schedule
+    #[schema(example = "rps_Hgt8Bhd21HkDw32qZ4sS")]
+    pub schedule_id: String,
+
+    /// The identifier for the merchant
+    pub merchant_id: String,
+
+    /// The identifier for the customer being charged
+    pub customer_id: String,
+
+    /// The identifier of the mandate used to create the payments
+    pub mandate_id: String,
+
+    /// The amount charged on every cycle
+    pub amount: i64,
+
+    /// The currency of the amount charged
+    #[schema(value_type = Currency)]
+    pub currency: api_enums::Currency,
+
+    /// The unit of time between two charges
+    pub interval: RecurringInterval,
+
+    /// The number of `interval` units between two charges
+    pub interval_count: u16,
+
+    /// The status of the schedule
+    pub status: RecurringPaymentScheduleStatus,
+
+    /// The total number of successful charges after which the schedule completes
+    pub total_charges: Option<u32>,
+
+    /// The number of successful charges made so far
+    pub charges_made: u32,
+
+    /// The number of dunning retries made for the current charge
+    pub dunning_attempts_made: u32,
+
+    /// Hours to wait before each dunning retry of a failed charge
+    pub dunning_retry_intervals_in_hours: Vec<u32>,
+
+    /// The time at which the next charge or dunning retry will be attempted
+    #[schema(value_type = Option<PrimitiveDateTime>, example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub next_attempt_at: Option<PrimitiveDateTime>,
+
+    /// The identifier of the payment created by the latest attempt
+    pub last_payment_id: Option<String>,
+
+    /// The time at which the schedule was created
+    #[schema(value_type = PrimitiveDateTime, example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+}
+
+impl ApiEventMetric for RecurringPaymentScheduleCreateRequest {}
+impl ApiEventMetric for RecurringPaymentScheduleId {}
+impl ApiEventMetric for RecurringPaymentScheduleResponse {}
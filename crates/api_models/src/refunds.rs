@@ -37,6 +37,10 @@ pub struct RefundRequest {
     #[schema(max_length = 255, example = "Customer returned the product")]
     pub reason: Option<String>,
 
+    /// The reason for the refund, from a fixed set of reasons, which is sent to the connector wherever the connector accepts a refund reason. The `reason` field is required along with `other`
+    #[schema(value_type = Option<RefundReasonCode>, example = "requested_by_customer")]
+    pub reason_code: Option<enums::RefundReasonCode>,
+
     /// To indicate whether to refund needs to be instant or scheduled. Default value is instant
     #[schema(default = "Instant", example = "Instant")]
     pub refund_type: Option<RefundType>,
@@ -48,6 +52,50 @@ pub struct RefundRequest {
     /// Merchant connector details used to make payments.
     #[schema(value_type = Option<MerchantConnectorDetailsWrap>)]
     pub merchant_connector_details: Option<admin::MerchantConnectorDetailsWrap>,
+
+    /// The destination of the refunded amount. Refunds are made to the payment method of the payment by default, refunds to an alternate payment method are made through a payout to the `alternate_destination`
+    #[schema(value_type = Option<RefundDestination>, example = "original_payment_method")]
+    pub refund_destination: Option<RefundDestination>,
+
+    /// The payment method of the customer receiving the refund through a payout, when the refund is made to an alternate payment method. Refunds to the payment method of the payment fall back to this destination when the payment method cannot accept the refund
+    #[cfg(feature = "payouts")]
+    pub alternate_destination: Option<AlternateRefundDestination>,
+}
+
+/// The destination of the refunded amount
+#[derive(
+    Default, Debug, Clone, Copy, ToSchema, Deserialize, Serialize, Eq, PartialEq, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RefundDestination {
+    /// The payment method used for the payment, refunded through the connector of the payment
+    #[default]
+    OriginalPaymentMethod,
+    /// An alternate payment method of the customer, refunded through a payout
+    AlternatePaymentMethod,
+}
+
+/// The payment method of the customer receiving a refund through a payout
+#[cfg(feature = "payouts")]
+#[derive(Debug, Clone, ToSchema, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct AlternateRefundDestination {
+    /// The type of the payout made for the refund
+    #[schema(value_type = PayoutType, example = "bank")]
+    pub payout_type: enums::PayoutType,
+
+    /// The payout method details of the customer
+    #[schema(value_type = Option<PayoutMethodData>)]
+    pub payout_method_data: Option<crate::payouts::PayoutMethodData>,
+
+    /// Token of a payout method of the customer saved during a previous payout
+    #[schema(example = "187282ab-40ef-47a9-9206-5099ba31e432")]
+    pub payout_token: Option<String>,
+
+    /// The list of payout connectors to route the payout through, the payout routing of the merchant is used when not provided
+    #[schema(value_type = Option<Vec<PayoutConnectors>>, example = json!(["wise", "adyen"]))]
+    pub connector: Option<Vec<enums::PayoutConnectors>>,
 }
 
 #[derive(Default, Debug, Clone, Deserialize)]
@@ -112,6 +160,9 @@ pub struct RefundResponse {
     pub status: RefundStatus,
     /// An arbitrary string attached to the object. Often useful for displaying to users and your customer support executive
     pub reason: Option<String>,
+    /// The reason for the refund, from a fixed set of reasons
+    #[schema(value_type = Option<RefundReasonCode>)]
+    pub reason_code: Option<enums::RefundReasonCode>,
     /// You can specify up to 50 keys, with key names up to 40 characters long and values up to 500 characters long. Metadata is useful for storing additional, structured information on an object
     #[schema(value_type = Option<Object>)]
     pub metadata: Option<pii::SecretSerdeValue>,
@@ -132,6 +183,18 @@ pub struct RefundResponse {
     pub profile_id: Option<String>,
     /// The merchant_connector_id of the processor through which this payment went through
     pub merchant_connector_id: Option<String>,
+    /// The destination of the refunded amount
+    #[schema(value_type = RefundDestination, example = "original_payment_method")]
+    pub refund_destination: RefundDestination,
+    /// The identifier of the payout made for the refund, when it is made to an alternate payment method
+    #[schema(example = "payout_mbabizu24mvu3mela5njyhpit4")]
+    pub payout_id: Option<String>,
+    /// The number of automatic retries of the refund, made when the connector fails with a transient error
+    #[schema(example = 1)]
+    pub retry_count: Option<i16>,
+    /// The status of the automatic retries of the refund
+    #[schema(value_type = Option<RefundRetryStatus>, example = "scheduled")]
+    pub retry_status: Option<enums::RefundRetryStatus>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize, ToSchema)]
@@ -560,3 +560,101 @@ pub enum RoutingKind {
 #[derive(serde::Serialize, serde::Deserialize, Debug)]
 #[serde(transparent)]
 pub struct RoutingAlgorithmId(pub String);
+
+/// The request body for simulating the routing of a hypothetical payment
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingSimulationRequest {
+    /// The routing algorithm to be simulated, defaults to the active routing algorithm. Allows
+    /// testing a routing algorithm before activating it.
+    #[schema(example = "routing_abcdefghijklmnop")]
+    pub algorithm_id: Option<String>,
+
+    /// The business profile whose routing configuration is to be simulated
+    #[schema(example = "pro_abcdefghijklmnop")]
+    pub profile_id: Option<String>,
+
+    /// The attributes of the hypothetical payment
+    pub payment: RoutingSimulationPaymentData,
+}
+
+/// The attributes of a hypothetical payment considered while routing it
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RoutingSimulationPaymentData {
+    /// The payment amount in the lowest denomination of the currency
+    #[schema(example = 6540)]
+    pub amount: i64,
+
+    #[schema(value_type = Currency, example = "USD")]
+    pub currency: enums::Currency,
+
+    #[schema(value_type = Option<PaymentMethod>, example = "card")]
+    pub payment_method: Option<enums::PaymentMethod>,
+
+    #[schema(value_type = Option<PaymentMethodType>, example = "credit")]
+    pub payment_method_type: Option<enums::PaymentMethodType>,
+
+    #[schema(value_type = Option<CardNetwork>, example = "Visa")]
+    pub card_network: Option<enums::CardNetwork>,
+
+    /// The first six digits of the card number
+    #[schema(example = "424242")]
+    pub card_bin: Option<String>,
+
+    #[schema(value_type = Option<AuthenticationType>, example = "no_three_ds")]
+    pub authentication_type: Option<enums::AuthenticationType>,
+
+    #[schema(value_type = Option<CaptureMethod>, example = "automatic")]
+    pub capture_method: Option<enums::CaptureMethod>,
+
+    #[schema(value_type = Option<CountryAlpha2>, example = "US")]
+    pub business_country: Option<enums::CountryAlpha2>,
+
+    #[schema(value_type = Option<CountryAlpha2>, example = "US")]
+    pub billing_country: Option<enums::CountryAlpha2>,
+
+    #[schema(example = "food")]
+    pub business_label: Option<String>,
+
+    #[schema(value_type = Option<FutureUsage>, example = "off_session")]
+    pub setup_future_usage: Option<enums::FutureUsage>,
+
+    /// The routing parameters which would be provided in the metadata of the payment
+    #[schema(value_type = Option<Object>, example = r#"{ "customer_tier": "gold" }"#)]
+    pub metadata: Option<std::collections::HashMap<String, String>>,
+}
+
+/// The response body for a routing simulation, explaining how the connector was selected
+#[derive(Debug, Clone, serde::Serialize, ToSchema)]
+pub struct RoutingSimulationResponse {
+    /// The routing algorithm which was simulated, absent when no routing algorithm is active and
+    /// the default fallback configuration is used instead
+    pub algorithm_id: Option<String>,
+
+    /// The kind of the routing algorithm which was simulated
+    pub algorithm_kind: Option<RoutingAlgorithmKind>,
+
+    /// The name of the advanced routing rule which matched the payment, absent when the default
+    /// selection of the advanced routing algorithm was used
+    pub matched_rule: Option<String>,
+
+    /// The connectors chosen by the routing algorithm, in order of preference. For volume split
+    /// routing, the connectors are listed in the configured order, whereas payments are
+    /// distributed among them in proportion to the configured split.
+    pub routed_connectors: Vec<RoutableConnectorChoice>,
+
+    /// The routed connectors which were filtered out by the eligibility analysis, as they are not
+    /// configured to process the payment
+    pub ineligible_connectors: Vec<RoutableConnectorChoice>,
+
+    /// The eligible connectors from the default fallback configuration, appended after the
+    /// eligible routed connectors
+    pub fallback_connectors: Vec<RoutableConnectorChoice>,
+
+    /// The connector which would be selected for the payment
+    pub selected_connector: Option<RoutableConnectorChoice>,
+
+    /// The connectors which would be attempted for the payment, in order of preference
+    pub final_connectors: Vec<RoutableConnectorChoice>,
+}
@@ -0,0 +1,41 @@
+use common_utils::events;
+
+use crate::enums;
+
+/// Rules deciding how the payments of a merchant are authenticated
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ThreeDsDecisionRules {
+    /// Rules evaluated in order, the first rule matching the payment attempt decides how it is
+    /// authenticated. Attempts which do not match any rule are authenticated as requested.
+    pub rules: Vec<ThreeDsDecisionRule>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ThreeDsDecisionRule {
+    /// Name of the rule
+    pub name: String,
+    /// Minimum amount of the payment in the lowest denomination of the currency, from which the
+    /// rule applies
+    pub min_amount: Option<i64>,
+    /// Maximum amount of the payment in the lowest denomination of the currency, up to which the
+    /// rule applies
+    pub max_amount: Option<i64>,
+    /// Currencies of the payments the rule applies to, the rule applies to all currencies if not set
+    pub currencies: Option<Vec<enums::Currency>>,
+    /// Issuing countries of the cards the rule applies to, as resolved from the card BIN. The rule
+    /// applies to all cards if not set.
+    pub issuer_countries: Option<Vec<String>>,
+    /// The way the payment attempts matching the rule are authenticated
+    pub decision: enums::ThreeDsDecision,
+    /// The exemption applied to the payment attempts matching the rule, exemptions can only be
+    /// applied by rules which skip 3DS authentication
+    pub exemption: Option<enums::ScaExemptionType>,
+}
+
+impl events::ApiEventMetric for ThreeDsDecisionRules {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Routing)
+    }
+}
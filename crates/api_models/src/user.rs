@@ -129,6 +129,26 @@ pub struct SwitchMerchantIdRequest {
     pub merchant_id: String,
 }
 
+#[derive(Debug, serde::Deserialize, serde::Serialize)]
+pub struct ImpersonateMerchantRequest {
+    pub merchant_id: String,
+    /// The permission groups granted to the session, the session is read only by default
+    pub groups: Option<Vec<PermissionGroup>>,
+    /// The duration after which the session expires
+    pub duration_in_secs: Option<u64>,
+    /// The reason for impersonating the merchant, recorded in the audit log
+    pub reason: String,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct ImpersonateMerchantResponse {
+    pub token: Secret<String>,
+    pub merchant_id: String,
+    pub session_id: String,
+    pub groups: Vec<PermissionGroup>,
+    pub expires_in_secs: u64,
+}
+
 #[derive(serde::Deserialize, Debug, serde::Serialize)]
 pub struct CreateInternalUserRequest {
     pub name: Secret<String>,
@@ -1,4 +1,4 @@
-use common_enums::{AuthenticationType, CountryAlpha2};
+use common_enums::{AuthenticationType, CountryAlpha2, PaymentMethodType};
 use common_utils::{self};
 use time::PrimitiveDateTime;
 
@@ -17,6 +17,7 @@ pub struct SampleDataRequest {
     pub max_amount: Option<i64>,
     pub currency: Option<Vec<common_enums::Currency>>,
     pub auth_type: Option<Vec<AuthenticationType>>,
+    pub payment_method_type: Option<Vec<PaymentMethodType>>,
     pub business_country: Option<CountryAlpha2>,
     pub business_label: Option<String>,
     pub profile_id: Option<String>,
@@ -32,6 +32,7 @@ pub enum Permission {
     UsersWrite,
     MerchantAccountCreate,
     WebhookEventRead,
+    WebhookEventWrite,
     PayoutWrite,
     PayoutRead,
 }
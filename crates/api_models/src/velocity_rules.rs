@@ -0,0 +1,65 @@
+use common_utils::events;
+
+use crate::enums;
+
+/// Velocity limits applied to the payments of a merchant
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VelocityRules {
+    /// Rules evaluated for every payment attempt, attempts exceeding the limits of any rule are
+    /// rejected or flagged according to the action of the rule
+    pub rules: Vec<VelocityRule>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct VelocityRule {
+    /// Name of the rule
+    pub name: String,
+    /// The attribute of the payment attempts the limits of the rule are counted by
+    pub dimension: VelocityDimension,
+    /// Length of the sliding window the limits of the rule apply over, in seconds
+    pub window_in_seconds: u32,
+    /// Maximum number of payment attempts allowed in the window
+    pub max_attempts: Option<u32>,
+    /// Maximum total amount of the payment attempts allowed in the window, in the lowest
+    /// denomination of the currency of the rule
+    pub max_amount: Option<i64>,
+    /// Currency of the payments the rule applies to, the rule applies to all currencies if not set.
+    /// Required for rules limiting the amount.
+    pub currency: Option<enums::Currency>,
+    /// The action taken on the payment attempts exceeding the limits of the rule
+    pub action: VelocityAction,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VelocityDimension {
+    /// Attempts made with the same card, as identified by its card fingerprint
+    Card,
+    /// Attempts made by the same customer
+    Customer,
+    /// Attempts made from the same IP address, as provided in the browser information
+    IpAddress,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize, strum::Display,
+)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum VelocityAction {
+    /// The payment attempt is failed before it is sent to the connector
+    Reject,
+    /// The payment attempt is processed, the limit being exceeded is only recorded
+    Flag,
+}
+
+impl events::ApiEventMetric for VelocityRules {
+    fn get_api_event_type(&self) -> Option<events::ApiEventsType> {
+        Some(events::ApiEventsType::Miscellaneous)
+    }
+}
@@ -1,4 +1,4 @@
-use common_enums::{EventClass, EventType, WebhookDeliveryAttempt};
+use common_enums::{EventClass, EventType, WebhookDeliveryAttempt, WebhookDeliveryStatus};
 use masking::Secret;
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
@@ -21,6 +21,10 @@ pub struct EventListConstraints {
     /// Include events after the specified offset.
     pub offset: Option<u16>,
 
+    /// Filter events by the status of their delivery, events whose delivery failed can be
+    /// retrieved with the `failed` status.
+    pub delivery_status: Option<WebhookDeliveryStatus>,
+
     /// Filter all events associated with the specified object identifier (Payment Intent ID,
     /// Refund ID, etc.)
     pub object_id: Option<String>,
@@ -33,12 +37,49 @@ pub enum EventListConstraintsInternal {
         created_before: Option<PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        delivery_status: Option<WebhookDeliveryStatus>,
     },
     ObjectIdFilter {
         object_id: String,
     },
 }
 
+/// The request body for replaying the delivery of events, either the events with the specified
+/// identifiers, or the events created in the specified time range are replayed.
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EventReplayRequest {
+    /// Replay the events with the specified identifiers. Either only `event_ids` must be
+    /// specified, or `created_after` and `created_before` must be specified.
+    #[schema(example = json!(["evt_018e31720d1b7a2b82677d3032cab959"]))]
+    pub event_ids: Option<Vec<String>>,
+
+    /// Replay events created after the specified time.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_after: Option<PrimitiveDateTime>,
+
+    /// Replay events created before the specified time.
+    #[schema(example = "2022-09-10T12:11:12Z")]
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_before: Option<PrimitiveDateTime>,
+
+    /// Replay only the events of the specified types, among the events created in the time range.
+    pub event_types: Option<Vec<EventType>>,
+}
+
+#[derive(Debug)]
+pub enum EventReplayConstraintsInternal {
+    EventIdFilter {
+        event_ids: Vec<String>,
+    },
+    TimeRangeFilter {
+        created_after: PrimitiveDateTime,
+        created_before: PrimitiveDateTime,
+        event_types: Option<Vec<EventType>>,
+    },
+}
+
 /// The response body for each item when listing events.
 #[derive(Debug, Serialize, ToSchema)]
 pub struct EventListItemResponse {
@@ -67,6 +108,10 @@ pub struct EventListItemResponse {
     /// Indicates whether the webhook delivery attempt was successful.
     pub is_delivery_successful: bool,
 
+    /// Status of the delivery of the event across all of its delivery attempts. This is only
+    /// populated for initial delivery attempts.
+    pub delivery_status: Option<WebhookDeliveryStatus>,
+
     /// The identifier for the initial delivery attempt. This will be the same as `event_id` for
     /// the initial delivery attempt.
     #[schema(max_length = 64, example = "evt_018e31720d1b7a2b82677d3032cab959")]
@@ -130,6 +175,51 @@ pub struct OutgoingWebhookResponseContent {
     pub status_code: u16,
 }
 
+/// Health of a webhook endpoint, derived from the outcome of the recent deliveries to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEndpointHealthStatus {
+    /// The most recent delivery to the endpoint was successful.
+    Healthy,
+    /// Recent deliveries to the endpoint have failed, but fewer than the threshold at which the
+    /// endpoint is considered unhealthy.
+    Degraded,
+    /// Consecutive deliveries to the endpoint have failed, at least as many as the configured
+    /// threshold.
+    Unhealthy,
+    /// No webhooks have been delivered to the endpoint recently.
+    Unknown,
+}
+
+/// The health of the webhook endpoint of a Business Profile.
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookEndpointHealthResponse {
+    /// The identifier for the Business Profile.
+    #[schema(max_length = 64, example = "SqB0zwDGR5wHppWf0bx7GKr1f2")]
+    pub profile_id: String,
+
+    /// The health of the webhook endpoint.
+    pub status: WebhookEndpointHealthStatus,
+
+    /// The number of consecutive failed deliveries to the endpoint.
+    #[schema(example = 0)]
+    pub consecutive_failures: u32,
+
+    /// The HTTP status code received for the most recent delivery, if the endpoint responded.
+    #[schema(example = 200)]
+    pub last_response_status_code: Option<u16>,
+
+    /// Time at which a webhook was last delivered to the endpoint successfully.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_delivered_at: Option<PrimitiveDateTime>,
+
+    /// Time at which the delivery of a webhook to the endpoint last failed.
+    #[schema(example = "2022-09-10T10:11:12Z")]
+    #[serde(with = "common_utils::custom_serde::iso8601::option")]
+    pub last_failed_at: Option<PrimitiveDateTime>,
+}
+
 #[derive(Debug, serde::Serialize)]
 pub struct EventListRequestInternal {
     pub merchant_id_or_profile_id: String,
@@ -157,3 +247,44 @@ impl common_utils::events::ApiEventMetric for WebhookDeliveryAttemptListRequestI
         })
     }
 }
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookDeliveryRetryRequestInternal {
+    pub merchant_id_or_profile_id: String,
+    pub event_id: String,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookDeliveryRetryRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id_or_profile_id: self.merchant_id_or_profile_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct EventReplayRequestInternal {
+    pub merchant_id_or_profile_id: String,
+    pub request: EventReplayRequest,
+}
+
+impl common_utils::events::ApiEventMetric for EventReplayRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id_or_profile_id: self.merchant_id_or_profile_id.clone(),
+        })
+    }
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct WebhookEndpointHealthRequestInternal {
+    pub merchant_id_or_profile_id: String,
+}
+
+impl common_utils::events::ApiEventMetric for WebhookEndpointHealthRequestInternal {
+    fn get_api_event_type(&self) -> Option<common_utils::events::ApiEventsType> {
+        Some(common_utils::events::ApiEventsType::Events {
+            merchant_id_or_profile_id: self.merchant_id_or_profile_id.clone(),
+        })
+    }
+}
@@ -3,7 +3,10 @@ use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 use utoipa::ToSchema;
 
-use crate::{disputes, enums as api_enums, mandates, payments, refunds};
+use crate::{
+    admin, analytics::success_rate_alerts, disputes, enums as api_enums, mandates, payment_methods,
+    payments, refunds,
+};
 
 #[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize, Copy)]
 #[serde(rename_all = "snake_case")]
@@ -177,6 +180,14 @@ pub enum OutgoingWebhookContent {
     DisputeDetails(Box<disputes::DisputeResponse>),
     #[schema(value_type = MandateResponse, title = "MandateResponse")]
     MandateDetails(Box<mandates::MandateResponse>),
+    #[schema(value_type = ConnectorHealthResponse, title = "ConnectorHealthResponse")]
+    ConnectorHealthDetails(Box<admin::ConnectorHealthResponse>),
+    #[schema(value_type = ExternalAuthenticationResponse, title = "ExternalAuthenticationResponse")]
+    AuthenticationDetails(Box<payments::ExternalAuthenticationResponse>),
+    #[schema(value_type = PaymentMethodResponse, title = "PaymentMethodResponse")]
+    PaymentMethodDetails(Box<payment_methods::PaymentMethodResponse>),
+    #[schema(value_type = SuccessRateDropDetails, title = "SuccessRateDropDetails")]
+    SuccessRateDropDetails(Box<success_rate_alerts::SuccessRateDropDetails>),
 }
 
 #[derive(Debug, Clone, Serialize)]
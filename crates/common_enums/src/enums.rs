@@ -41,6 +41,7 @@ pub enum AttemptStatus {
     AuthenticationPending,
     AuthenticationSuccessful,
     Authorized,
+    PartiallyAuthorized,
     AuthorizationFailed,
     Charged,
     Authorizing,
@@ -224,6 +225,154 @@ pub enum AuthenticationType {
     NoThreeDs,
 }
 
+/// The way a payment attempt is authenticated, as decided by the 3DS decision rules of the merchant
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ThreeDsDecision {
+    /// The payment is processed without 3DS authentication
+    NoThreeDs,
+    /// The customer is authenticated through the external 3DS authentication connector of the business profile, before the payment is processed
+    ExternalAuthentication,
+    /// The customer is authenticated through the 3DS flow of the payment connector
+    ConnectorThreeDs,
+}
+
+/// Strong customer authentication exemptions which can be applied to a payment attempt
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ScaExemptionType {
+    /// Exemption for low value payments
+    LowValue,
+    /// Exemption for payments assessed as low risk by transaction risk analysis
+    TransactionRiskAnalysis,
+    /// Exemption for payments made through dedicated corporate processes and protocols
+    SecureCorporatePayment,
+    /// Payments initiated by the merchant without the customer being present, which are out of
+    /// the scope of strong customer authentication
+    MerchantInitiatedTransaction,
+}
+
+/// Who selected the payment method of a payment attempt
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PaymentMethodSelection {
+    /// The payment method was passed by the merchant when creating or confirming the payment
+    Merchant,
+    /// The payment was created without a payment method, and the customer chose it on the hosted payment page or the SDK when confirming the payment
+    Customer,
+}
+
+/// Tax identification and identity documents of customers, required by some connectors for
+/// payments in the country issuing the document
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "UPPERCASE")]
+#[strum(serialize_all = "UPPERCASE")]
+pub enum CustomerDocumentType {
+    /// Cadastro de Pessoas Físicas, the tax ID of individuals in Brazil
+    Cpf,
+    /// Cadastro Nacional da Pessoa Jurídica, the tax ID of companies in Brazil
+    Cnpj,
+    /// Clave Única de Registro de Población, the identity code of individuals in Mexico
+    Curp,
+    /// Registro Federal de Contribuyentes, the tax ID of individuals and companies in Mexico
+    Rfc,
+    /// Documento Nacional de Identidad, the identity document of individuals in Argentina
+    Dni,
+    /// Clave Única de Identificación Tributaria, the tax ID of individuals and companies in
+    /// Argentina
+    Cuit,
+    /// Rol Único Tributario, the tax ID of individuals and companies in Chile
+    Rut,
+    /// Cédula de Ciudadanía, the identity document of individuals in Colombia
+    Cc,
+    /// Número de Identificación Tributaria, the tax ID of companies in Colombia
+    Nit,
+}
+
+/// Outcome of validating an address with the address normalization provider
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AddressValidationStatus {
+    /// The address is valid and already in its normalized form
+    Verified,
+    /// The address is valid once normalized
+    Corrected,
+    /// The address is not valid, for instance the postal code does not match the format of the
+    /// country
+    Invalid,
+    /// The address could not be validated, or has changed since it was validated
+    Unverified,
+}
+
 #[derive(
     Clone,
     Copy,
@@ -298,6 +447,8 @@ pub enum BlocklistDataKind {
     PaymentMethod,
     CardBin,
     ExtendedCardBin,
+    Email,
+    IpAddress,
 }
 
 #[derive(
@@ -1053,6 +1204,10 @@ pub enum EventClass {
     Refunds,
     Disputes,
     Mandates,
+    Connectors,
+    Authentications,
+    PaymentMethods,
+    Alerts,
 }
 
 #[derive(
@@ -1091,6 +1246,27 @@ pub enum EventType {
     DisputeLost,
     MandateActive,
     MandateRevoked,
+    MandateLimitExceeded,
+    /// Connector marked as degraded and excluded from routing
+    ConnectorDegraded,
+    /// Degraded connector recovered and included in routing again
+    ConnectorRecovered,
+    /// External 3DS authentication started for the payment
+    AuthenticationStarted,
+    /// Cardholder challenge required by the issuer to complete the external 3DS authentication
+    ChallengeRequired,
+    AuthenticationSucceeded,
+    AuthenticationFailed,
+    /// Payment held for a manual review by the merchant
+    PaymentReviewRequired,
+    /// Payment held for a manual review approved by the merchant
+    PaymentReviewApproved,
+    /// Payment held for a manual review rejected by the merchant
+    PaymentReviewRejected,
+    /// Saved card of a customer expiring at the end of the next month
+    PaymentMethodExpiring,
+    /// Success rate of a connector dropped beyond the threshold of a success rate alert
+    SuccessRateDropped,
 }
 
 #[derive(
@@ -1114,6 +1290,33 @@ pub enum WebhookDeliveryAttempt {
     ManualRetry,
 }
 
+/// Status of the delivery of an event, across all of its delivery attempts
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    /// The event is yet to be delivered, and delivery attempts are scheduled
+    Pending,
+    /// One of the delivery attempts of the event was successful
+    Delivered,
+    /// All automatic delivery attempts of the event failed, it can only be delivered by manually
+    /// retrying it
+    Failed,
+}
+
 // TODO: This decision about using KV mode or not,
 // should be taken at a top level rather than pushing it down to individual functions via an enum.
 #[derive(
@@ -1252,7 +1455,9 @@ pub enum PaymentMethodStatus {
 impl From<AttemptStatus> for PaymentMethodStatus {
     fn from(attempt_status: AttemptStatus) -> Self {
         match attempt_status {
-            AttemptStatus::Charged | AttemptStatus::Authorized => Self::Active,
+            AttemptStatus::Charged
+            | AttemptStatus::Authorized
+            | AttemptStatus::PartiallyAuthorized => Self::Active,
             AttemptStatus::Failure => Self::Inactive,
             AttemptStatus::Voided
             | AttemptStatus::Started
@@ -1506,6 +1711,142 @@ pub enum RefundStatus {
     TransactionFailure,
 }
 
+/// The reason for which a refund is issued
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RefundReasonCode {
+    /// The payment was charged more than once
+    Duplicate,
+    /// The payment was not authorized by the card holder
+    Fraudulent,
+    /// The customer asked for the refund, for instance on returning the product
+    RequestedByCustomer,
+    /// The customer did not receive the product or service
+    ProductNotReceived,
+    /// Any other reason, described by the free text reason of the refund
+    Other,
+}
+
+/// The outcome of an address (AVS) or security code (CVV) check performed by the issuer, as
+/// interpreted from the result code returned by the connector
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum CardCheckResult {
+    /// The provided details match the details on file with the issuer
+    Match,
+    /// Only a part of the provided address matches, for instance the postal code but not the street
+    PartialMatch,
+    /// The provided details do not match the details on file with the issuer
+    Mismatch,
+    /// The check was not performed or the issuer did not return a result
+    Unavailable,
+}
+
+/// The action taken on a card payment authorized by the issuer for less than the amount of the
+/// payment, which is possible with some prepaid and debit cards
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Default,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum PartialAuthorizationAction {
+    /// Keep the authorization, which the merchant can capture for the authorized amount or void
+    #[default]
+    AllowCapture,
+    /// Void the authorization, releasing the authorized amount to the customer
+    Void,
+}
+
+/// The status of the automatic retries of a refund which failed with a transient connector error
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum RefundRetryStatus {
+    /// A retry of the refund is scheduled
+    Scheduled,
+    /// The refund was retried and the connector returned a final outcome for it
+    Completed,
+    /// The refund failed on every retry allowed
+    Exhausted,
+}
+
+/// The period over which the amount debited through a mandate is limited
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum MandateLimitPeriod {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
 /// The status of the mandate, which indicates whether it can be used to initiate a payment.
 #[derive(
     Clone,
@@ -1615,6 +1956,30 @@ pub enum DisputeStatus {
     DisputeLost,
 }
 
+/// Outcome of the automatic representment of a dispute using the merchant's representment rules
+#[derive(
+    Clone,
+    Debug,
+    Copy,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum DisputeRepresentmentStatus {
+    /// Evidence was assembled and submitted to the connector automatically
+    AutoSubmitted,
+    /// No rule could represent the dispute, the merchant has to review it
+    PendingReview,
+}
+
 #[derive(
     Clone,
     Debug,
@@ -2256,6 +2621,155 @@ pub enum ReconStatus {
     Active,
     Disabled,
 }
+
+/// The transaction a row of a connector settlement report is for
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SettlementRecordType {
+    Payment,
+    Refund,
+}
+
+/// The result of matching a settlement record against the payment attempt or refund it is for
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum SettlementMatchStatus {
+    /// The settled amount and currency match the payment attempt or refund
+    Matched,
+    /// The settled amount differs from the amount of the payment attempt or refund
+    AmountMismatch,
+    /// The settled currency differs from the currency of the payment attempt or refund
+    CurrencyMismatch,
+    /// The net amount reported by the connector does not equal the gross amount less the fees
+    FeeMismatch,
+    /// No payment attempt or refund was found for the connector transaction ID
+    Unmatched,
+}
+
+/// The records a scheduled report is generated from
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportType {
+    Payments,
+    Refunds,
+    Disputes,
+}
+
+/// How often a scheduled report is generated
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportFrequency {
+    /// Generated every day for the previous day
+    Daily,
+    /// Generated every Monday for the previous week
+    Weekly,
+}
+
+/// How a scheduled report is delivered to the merchant
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportDeliveryMethod {
+    Email,
+    Sftp,
+}
+
+/// The status of a run of a scheduled report
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    utoipa::ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum ReportRunStatus {
+    /// The report is yet to be generated
+    Pending,
+    /// The report was generated and stored, but is yet to be delivered
+    Generated,
+    /// The report was delivered to the merchant
+    Delivered,
+    /// The report could not be generated or delivered
+    Failed,
+}
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ApplePayFlow {
     Simplified,
@@ -2482,3 +2996,102 @@ pub enum PermissionGroup {
     MerchantDetailsManage,
     OrganizationManage,
 }
+
+/// The mutating admin operations recorded in the audit log
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditOperation {
+    MerchantAccountUpdate,
+    MerchantAccountDelete,
+    MerchantConnectorAccountCreate,
+    MerchantConnectorAccountUpdate,
+    MerchantConnectorAccountDelete,
+    MerchantConnectorWebhookSecretRotate,
+    RoutingConfigCreate,
+    RoutingConfigActivate,
+    RoutingConfigDeactivate,
+    RoutingDefaultConfigUpdate,
+    ApiKeyCreate,
+    ApiKeyUpdate,
+    ApiKeyRotate,
+    ApiKeyRevoke,
+}
+
+/// The kind of actor performing an operation recorded in the audit log
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case")]
+pub enum AuditActorType {
+    /// Authenticated with the admin API key
+    AdminApiKey,
+    /// Authenticated with an API key of the merchant, identified by the key ID
+    ApiKey,
+    /// A dashboard user, identified by the user ID
+    User,
+    /// A dashboard user impersonating a user of the merchant, identified by the user ID of the
+    /// impersonator
+    ImpersonatingUser,
+    /// Not authenticated as any of the above, such as operations performed by internal workflows
+    Internal,
+}
+
+/// The APIs an API key is restricted to. API keys without any scopes are not restricted.
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Eq,
+    Hash,
+    PartialEq,
+    serde::Deserialize,
+    serde::Serialize,
+    strum::Display,
+    strum::EnumString,
+    ToSchema,
+)]
+#[router_derive::diesel_enum(storage_type = "text")]
+pub enum ApiKeyScope {
+    /// Retrieve and list payments and their refunds
+    #[serde(rename = "payments:read")]
+    #[strum(serialize = "payments:read")]
+    PaymentsRead,
+    /// Create, update, confirm, capture and cancel payments
+    #[serde(rename = "payments:write")]
+    #[strum(serialize = "payments:write")]
+    PaymentsWrite,
+    /// Create and update refunds
+    #[serde(rename = "refunds:write")]
+    #[strum(serialize = "refunds:write")]
+    RefundsWrite,
+    /// Query the analytics of the merchant
+    #[serde(rename = "analytics:read")]
+    #[strum(serialize = "analytics:read")]
+    AnalyticsRead,
+}
@@ -19,6 +19,13 @@ pub static FRM_CONFIGS_EG: &str = r#"
 [{"gateway":"stripe","payment_methods":[{"payment_method":"card","payment_method_types":[{"payment_method_type":"credit","card_networks":["Visa"],"flow":"pre","action":"cancel_txn"},{"payment_method_type":"debit","card_networks":["Visa"],"flow":"pre"}]}]}]
 "#;
 
+/// Error code of the payment attempts declined as their payment method, email or IP address is
+/// blocklisted
+pub const BLOCKLIST_DECLINE_CODE: &str = "HE_BLOCKLISTED";
+
+/// Error code of the payment attempts declined as they exceed the velocity limits of the merchant
+pub const VELOCITY_LIMIT_DECLINE_CODE: &str = "HE_VELOCITY_LIMIT_EXCEEDED";
+
 /// Maximum limit for payments list get api
 pub const PAYMENTS_LIST_MAX_LIMIT_V1: u32 = 100;
 /// Maximum limit for payments list post api with filters
@@ -154,6 +154,22 @@ impl DecodeMessage for NoAlgorithm {
     }
 }
 
+/// Represents verification with a secret shared with the sender, which is sent as is in place of
+/// a signature, as with the credentials of basic auth. The message is not verified.
+#[derive(Debug)]
+pub struct SharedSecret;
+
+impl VerifySignature for SharedSecret {
+    fn verify_signature(
+        &self,
+        secret: &[u8],
+        signature: &[u8],
+        _msg: &[u8],
+    ) -> CustomResult<bool, errors::CryptoError> {
+        Ok(ring::constant_time::verify_slices_are_equal(secret, signature).is_ok())
+    }
+}
+
 /// Represents the HMAC-SHA-1 algorithm
 #[derive(Debug)]
 pub struct HmacSha1;
@@ -694,4 +710,21 @@ mod crypto_tests {
 
         assert!(!wrong_verified);
     }
+
+    #[test]
+    fn test_shared_secret_verify_signature() {
+        let secret = "Basic dXNlcm5hbWU6cGFzc3dvcmQ=".as_bytes();
+
+        let right_verified = super::SharedSecret
+            .verify_signature(secret, secret, &[])
+            .expect("Right signature verification result");
+
+        assert!(right_verified);
+
+        let wrong_verified = super::SharedSecret
+            .verify_signature(secret, "Basic d3Jvbmc6c2VjcmV0".as_bytes(), &[])
+            .expect("Wrong signature verification result");
+
+        assert!(!wrong_verified);
+    }
 }
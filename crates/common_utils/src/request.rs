@@ -38,6 +38,8 @@ pub struct Request {
     pub certificate: Option<String>,
     pub certificate_key: Option<String>,
     pub body: Option<RequestContent>,
+    /// Whether a multipart response is split into its parts, rather than read as a single body
+    pub read_multipart_response: bool,
 }
 
 impl std::fmt::Debug for RequestContent {
@@ -81,6 +83,7 @@ impl Request {
             certificate: None,
             certificate_key: None,
             body: None,
+            read_multipart_response: false,
         }
     }
 
@@ -113,6 +116,7 @@ pub struct RequestBuilder {
     pub certificate: Option<String>,
     pub certificate_key: Option<String>,
     pub body: Option<RequestContent>,
+    pub read_multipart_response: bool,
 }
 
 impl RequestBuilder {
@@ -124,6 +128,7 @@ impl RequestBuilder {
             certificate: None,
             certificate_key: None,
             body: None,
+            read_multipart_response: false,
         }
     }
 
@@ -167,6 +172,11 @@ impl RequestBuilder {
         self
     }
 
+    pub fn read_multipart_response(mut self, read_multipart_response: bool) -> Self {
+        self.read_multipart_response = read_multipart_response;
+        self
+    }
+
     pub fn build(self) -> Request {
         Request {
             method: self.method,
@@ -175,6 +185,7 @@ impl RequestBuilder {
             certificate: self.certificate,
             certificate_key: self.certificate_key,
             body: self.body,
+            read_multipart_response: self.read_multipart_response,
         }
     }
 }
@@ -57,6 +57,165 @@ pub fn validate_email(email: &str) -> CustomResult<(), ValidationError> {
     Ok(())
 }
 
+/// Checks the digit at `position` against the modulo 11 check digit of the digits preceding it,
+/// computed with the given weights applied to the digits from left to right
+fn is_valid_modulo_11_check_digit(digits: &[u32], position: usize, weights: &[u32]) -> bool {
+    let sum: u32 = digits
+        .iter()
+        .take(position)
+        .zip(weights)
+        .map(|(digit, weight)| digit * weight)
+        .sum();
+    let expected_check_digit = match 11 - sum % 11 {
+        10 | 11 => 0,
+        check_digit => check_digit,
+    };
+
+    digits.get(position) == Some(&expected_check_digit)
+}
+
+/// Numbers with all digits equal pass the check digit validation, but are never issued
+fn has_distinct_digits(digits: &[u32]) -> bool {
+    digits
+        .first()
+        .is_some_and(|first| digits.iter().any(|digit| digit != first))
+}
+
+fn is_valid_cpf(digits: &[u32]) -> bool {
+    digits.len() == 11
+        && has_distinct_digits(digits)
+        && is_valid_modulo_11_check_digit(digits, 9, &[10, 9, 8, 7, 6, 5, 4, 3, 2])
+        && is_valid_modulo_11_check_digit(digits, 10, &[11, 10, 9, 8, 7, 6, 5, 4, 3, 2])
+}
+
+fn is_valid_cnpj(digits: &[u32]) -> bool {
+    digits.len() == 14
+        && has_distinct_digits(digits)
+        && is_valid_modulo_11_check_digit(digits, 12, &[5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2])
+        && is_valid_modulo_11_check_digit(digits, 13, &[6, 5, 4, 3, 2, 9, 8, 7, 6, 5, 4, 3, 2])
+}
+
+fn is_valid_cuit(digits: &[u32]) -> bool {
+    digits.len() == 11
+        && is_valid_modulo_11_check_digit(digits, 10, &[5, 4, 3, 2, 7, 6, 5, 4, 3, 2])
+}
+
+fn is_valid_rut(document_number: &str) -> bool {
+    let mut chars = document_number.chars();
+    let Some(verifier) = chars.next_back() else {
+        return false;
+    };
+    let Some(digits) = chars.map(|c| c.to_digit(10)).collect::<Option<Vec<_>>>() else {
+        return false;
+    };
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .zip([2, 3, 4, 5, 6, 7].iter().cycle())
+        .map(|(digit, weight)| digit * weight)
+        .sum();
+    let expected_verifier = match 11 - sum % 11 {
+        11 => Some('0'),
+        10 => Some('K'),
+        check_digit => char::from_digit(check_digit, 10),
+    };
+
+    (7..=8).contains(&digits.len()) && Some(verifier.to_ascii_uppercase()) == expected_verifier
+}
+
+type CharPredicate = fn(&char) -> bool;
+
+/// Checks whether the value consists of runs of characters of the given lengths, each of which
+/// satisfies the predicate of the run
+fn matches_pattern(value: &str, pattern: &[(CharPredicate, usize)]) -> bool {
+    let mut chars = value.chars();
+    pattern.iter().all(|(predicate, length)| {
+        chars
+            .by_ref()
+            .take(*length)
+            .filter(|c| predicate(c))
+            .count()
+            == *length
+    }) && chars.next().is_none()
+}
+
+fn is_valid_curp(document_number: &str) -> bool {
+    matches_pattern(
+        document_number,
+        &[
+            (char::is_ascii_uppercase, 4),
+            (char::is_ascii_digit, 6),
+            (|c| matches!(c, 'H' | 'M' | 'X'), 1),
+            (char::is_ascii_uppercase, 5),
+            (|c| c.is_ascii_uppercase() || c.is_ascii_digit(), 1),
+            (char::is_ascii_digit, 1),
+        ],
+    )
+}
+
+fn is_valid_rfc(document_number: &str) -> bool {
+    // Companies have three letters in the name part of the RFC, and individuals have four
+    let name_length = match document_number.chars().count() {
+        12 => 3,
+        13 => 4,
+        _ => return false,
+    };
+    matches_pattern(
+        document_number,
+        &[
+            (
+                |c| c.is_ascii_uppercase() || matches!(c, '&' | 'Ñ'),
+                name_length,
+            ),
+            (char::is_ascii_digit, 6),
+            (|c| c.is_ascii_uppercase() || c.is_ascii_digit(), 3),
+        ],
+    )
+}
+
+/// Validates the number of a customer document against the format of the document type, along
+/// with its check digits where the document type has them
+///
+/// Separators commonly used when formatting document numbers (`.`, `-`, `/` and spaces) are
+/// ignored
+pub fn validate_customer_document(
+    document_type: common_enums::CustomerDocumentType,
+    document_number: &str,
+) -> Result<(), ValidationError> {
+    use common_enums::CustomerDocumentType;
+
+    let document_number: String = document_number
+        .chars()
+        .filter(|c| !matches!(c, '.' | '-' | '/' | ' '))
+        .collect();
+    let digits = document_number
+        .chars()
+        .map(|c| c.to_digit(10))
+        .collect::<Option<Vec<_>>>();
+
+    let is_valid = match document_type {
+        CustomerDocumentType::Cpf => digits.as_deref().is_some_and(is_valid_cpf),
+        CustomerDocumentType::Cnpj => digits.as_deref().is_some_and(is_valid_cnpj),
+        CustomerDocumentType::Cuit => digits.as_deref().is_some_and(is_valid_cuit),
+        CustomerDocumentType::Dni => digits
+            .as_ref()
+            .is_some_and(|digits| (7..=8).contains(&digits.len())),
+        CustomerDocumentType::Cc => digits
+            .as_ref()
+            .is_some_and(|digits| (6..=10).contains(&digits.len())),
+        CustomerDocumentType::Nit => digits
+            .as_ref()
+            .is_some_and(|digits| (9..=10).contains(&digits.len())),
+        CustomerDocumentType::Rut => is_valid_rut(&document_number),
+        CustomerDocumentType::Curp => is_valid_curp(&document_number),
+        CustomerDocumentType::Rfc => is_valid_rfc(&document_number),
+    };
+
+    is_valid.then_some(()).ok_or(ValidationError::InvalidValue {
+        message: format!("Invalid {document_type} number"),
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use fake::{faker::internet::en::SafeEmail, Fake};
@@ -125,4 +284,32 @@ mod tests {
             prop_assert!(validate_email(&email).is_err());
         }
     }
+
+    #[test_case(common_enums::CustomerDocumentType::Cpf, "529.982.247-25" ; "cpf")]
+    #[test_case(common_enums::CustomerDocumentType::Cnpj, "11.222.333/0001-81" ; "cnpj")]
+    #[test_case(common_enums::CustomerDocumentType::Cuit, "20-12345678-6" ; "cuit")]
+    #[test_case(common_enums::CustomerDocumentType::Rut, "12.345.678-5" ; "rut")]
+    #[test_case(common_enums::CustomerDocumentType::Curp, "GODE561231HDFRRN09" ; "curp")]
+    #[test_case(common_enums::CustomerDocumentType::Rfc, "GODE561231GR8" ; "rfc")]
+    fn test_valid_customer_document(
+        document_type: common_enums::CustomerDocumentType,
+        document_number: &str,
+    ) {
+        let result = validate_customer_document(document_type, document_number);
+        assert!(result.is_ok());
+    }
+
+    #[test_case(common_enums::CustomerDocumentType::Cpf, "529.982.247-26" ; "cpf check digit")]
+    #[test_case(common_enums::CustomerDocumentType::Cpf, "111.111.111-11" ; "cpf repeated digits")]
+    #[test_case(common_enums::CustomerDocumentType::Cnpj, "11.222.333/0001-80" ; "cnpj check digit")]
+    #[test_case(common_enums::CustomerDocumentType::Rut, "12.345.678-K" ; "rut verifier")]
+    #[test_case(common_enums::CustomerDocumentType::Curp, "GODE561231ZDFRRN09" ; "curp sex")]
+    #[test_case(common_enums::CustomerDocumentType::Dni, "12AB5678" ; "dni letters")]
+    fn test_invalid_customer_document(
+        document_type: common_enums::CustomerDocumentType,
+        document_number: &str,
+    ) {
+        let result = validate_customer_document(document_type, document_number);
+        assert!(result.is_err());
+    }
 }
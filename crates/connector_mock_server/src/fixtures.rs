@@ -0,0 +1,123 @@
+//! Canned responses and webhooks of connectors.
+
+use std::{collections::BTreeMap, path::PathBuf};
+
+use serde::Deserialize;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, ResponseTemplate,
+};
+
+#[derive(Debug, thiserror::Error)]
+pub enum FixtureError {
+    #[error("No fixtures found for the connector {connector_name}")]
+    NotFound {
+        connector_name: String,
+        #[source]
+        error: std::io::Error,
+    },
+    #[error("Failed to parse the fixtures of the connector {connector_name}")]
+    ParsingFailed {
+        connector_name: String,
+        #[source]
+        error: serde_json::Error,
+    },
+}
+
+/// Flows of connectors for which responses can be mocked
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FixtureFlow {
+    Authorize,
+    Capture,
+    Void,
+    PaymentSync,
+    Refund,
+    RefundSync,
+}
+
+/// Response of the connector to the requests matching the method, path and, when specified, the
+/// request body of the fixture
+#[derive(Clone, Debug, Deserialize)]
+pub struct Fixture {
+    pub method: String,
+    pub path: String,
+    /// Part of the request body to be matched, the other fields of the body being ignored
+    pub request_body: Option<serde_json::Value>,
+    pub status_code: u16,
+    pub response: Option<serde_json::Value>,
+}
+
+impl Fixture {
+    pub fn to_mock(&self) -> Mock {
+        let mock = Mock::given(method(self.method.as_str())).and(path(self.path.as_str()));
+        let mock = match &self.request_body {
+            Some(request_body) => mock.and(body_partial_json(request_body)),
+            None => mock,
+        };
+
+        let response = ResponseTemplate::new(self.status_code);
+        mock.respond_with(match &self.response {
+            Some(body) => response.set_body_json(body),
+            None => response,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct ConnectorFixtures {
+    #[serde(default)]
+    pub flows: BTreeMap<FixtureFlow, Vec<Fixture>>,
+    /// Payloads of the webhooks sent by the connector, by name
+    #[serde(default)]
+    pub webhooks: BTreeMap<String, serde_json::Value>,
+}
+
+impl ConnectorFixtures {
+    /// Loads the fixtures of the connector from `fixtures/<connector_name>.json`
+    pub fn load(connector_name: &str) -> Result<Self, FixtureError> {
+        let fixtures_path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("fixtures")
+            .join(format!("{connector_name}.json"));
+        let fixtures =
+            std::fs::read_to_string(fixtures_path).map_err(|error| FixtureError::NotFound {
+                connector_name: connector_name.to_string(),
+                error,
+            })?;
+
+        serde_json::from_str(&fixtures).map_err(|error| FixtureError::ParsingFailed {
+            connector_name: connector_name.to_string(),
+            error,
+        })
+    }
+
+    pub fn mocks(&self) -> impl Iterator<Item = Mock> + '_ {
+        self.flows.values().flatten().map(Fixture::to_mock)
+    }
+
+    /// Body of the webhook with the name, as sent by the connector
+    pub fn webhook(&self, name: &str) -> Option<Vec<u8>> {
+        self.webhooks
+            .get(name)
+            .and_then(|webhook| serde_json::to_vec(webhook).ok())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used, clippy::expect_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_all_fixtures_are_valid() {
+        let fixtures_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("fixtures");
+        for entry in std::fs::read_dir(fixtures_dir).unwrap() {
+            let fixtures_path = entry.unwrap().path();
+            let connector_name = fixtures_path.file_stem().unwrap().to_str().unwrap();
+            let fixtures = ConnectorFixtures::load(connector_name)
+                .expect("Fixtures of the connector must be valid");
+            assert!(!fixtures.flows.is_empty());
+        }
+    }
+}
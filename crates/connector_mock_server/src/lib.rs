@@ -0,0 +1,88 @@
+//! Mock connector server for the integration tests of connectors.
+//!
+//! The server serves the canned responses of a connector stored as fixtures in the `fixtures`
+//! directory of this crate, allowing the connector tests to run without access to the sandbox of
+//! the connector.
+
+pub mod fixtures;
+
+use wiremock::MockServer;
+
+pub use crate::fixtures::{ConnectorFixtures, Fixture, FixtureError, FixtureFlow};
+
+/// Mock server serving the fixtures of a connector, the base URL of the connector pointing to the
+/// server until it is dropped
+pub struct MockConnectorServer {
+    connector_name: String,
+    fixtures: ConnectorFixtures,
+    server: MockServer,
+}
+
+impl MockConnectorServer {
+    /// Starts a mock server for the connector on a random local port, serving its fixtures
+    pub async fn start(connector_name: &str) -> Result<Self, FixtureError> {
+        let fixtures = ConnectorFixtures::load(connector_name)?;
+        let server = MockServer::start().await;
+        for mock in fixtures.mocks() {
+            server.register(mock).await;
+        }
+
+        std::env::set_var(
+            get_base_url_env_key(connector_name),
+            format!("{}/", server.uri()),
+        );
+
+        Ok(Self {
+            connector_name: connector_name.to_string(),
+            fixtures,
+            server,
+        })
+    }
+
+    /// Base URL of the server, ending with a slash like the base URLs of connectors
+    pub fn base_url(&self) -> String {
+        format!("{}/", self.server.uri())
+    }
+
+    pub fn fixtures(&self) -> &ConnectorFixtures {
+        &self.fixtures
+    }
+
+    /// Underlying server, to register additional mocks or inspect the received requests
+    pub fn server(&self) -> &MockServer {
+        &self.server
+    }
+}
+
+impl Drop for MockConnectorServer {
+    fn drop(&mut self) {
+        std::env::remove_var(get_base_url_env_key(&self.connector_name));
+    }
+}
+
+/// Environment variable overriding the base URL of the connector in the router configuration
+fn get_base_url_env_key(connector_name: &str) -> String {
+    format!(
+        "ROUTER__CONNECTORS__{}__BASE_URL",
+        connector_name.to_uppercase()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_base_url_points_to_server_while_running() {
+        let server = MockConnectorServer::start("worldpay").await.unwrap();
+        assert_eq!(
+            std::env::var("ROUTER__CONNECTORS__WORLDPAY__BASE_URL").ok(),
+            Some(server.base_url())
+        );
+
+        drop(server);
+        assert!(std::env::var("ROUTER__CONNECTORS__WORLDPAY__BASE_URL").is_err());
+    }
+}
@@ -29,6 +29,9 @@ pub struct MandateAmountData {
     pub start_date: Option<PrimitiveDateTime>,
     pub end_date: Option<PrimitiveDateTime>,
     pub metadata: Option<pii::SecretSerdeValue>,
+    pub max_amount_per_charge: Option<i64>,
+    pub max_amount_per_period: Option<i64>,
+    pub limit_period: Option<common_enums::MandateLimitPeriod>,
 }
 
 // The fields on this struct are optional, as we want to allow the merchant to provide partial
@@ -89,6 +92,9 @@ impl From<ApiMandateAmountData> for MandateAmountData {
             start_date: value.start_date,
             end_date: value.end_date,
             metadata: value.metadata,
+            max_amount_per_charge: value.max_amount_per_charge,
+            max_amount_per_period: value.max_amount_per_period,
+            limit_period: value.limit_period,
         }
     }
 }
@@ -165,6 +165,20 @@ pub struct PaymentAttempt {
     pub mandate_data: Option<MandateDetails>,
     pub payment_method_billing_address_id: Option<String>,
     pub fingerprint_id: Option<String>,
+    pub card_network: Option<String>,
+    pub card_type: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub three_ds_decision: Option<storage_enums::ThreeDsDecision>,
+    pub sca_exemption: Option<storage_enums::ScaExemptionType>,
+    pub card_fingerprint: Option<String>,
+    pub payment_method_selection: Option<storage_enums::PaymentMethodSelection>,
+    pub settlement_currency: Option<storage_enums::Currency>,
+    pub settlement_amount: Option<i64>,
+    pub fx_rate: Option<String>,
+    pub avs_result_code: Option<String>,
+    pub avs_result: Option<storage_enums::CardCheckResult>,
+    pub cvv_result_code: Option<String>,
+    pub cvv_result: Option<storage_enums::CardCheckResult>,
 }
 
 impl PaymentAttempt {
@@ -248,6 +262,20 @@ pub struct PaymentAttemptNew {
     pub mandate_data: Option<MandateDetails>,
     pub payment_method_billing_address_id: Option<String>,
     pub fingerprint_id: Option<String>,
+    pub card_network: Option<String>,
+    pub card_type: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub three_ds_decision: Option<storage_enums::ThreeDsDecision>,
+    pub sca_exemption: Option<storage_enums::ScaExemptionType>,
+    pub card_fingerprint: Option<String>,
+    pub payment_method_selection: Option<storage_enums::PaymentMethodSelection>,
+    pub settlement_currency: Option<storage_enums::Currency>,
+    pub settlement_amount: Option<i64>,
+    pub fx_rate: Option<String>,
+    pub avs_result_code: Option<String>,
+    pub avs_result: Option<storage_enums::CardCheckResult>,
+    pub cvv_result_code: Option<String>,
+    pub cvv_result: Option<storage_enums::CardCheckResult>,
 }
 
 impl PaymentAttemptNew {
@@ -281,6 +309,9 @@ pub enum PaymentAttemptUpdate {
         surcharge_amount: Option<i64>,
         tax_amount: Option<i64>,
         fingerprint_id: Option<String>,
+        card_network: Option<String>,
+        card_type: Option<String>,
+        card_issuing_country: Option<String>,
         updated_by: String,
     },
     UpdateTrackers {
@@ -292,6 +323,9 @@ pub enum PaymentAttemptUpdate {
         tax_amount: Option<i64>,
         updated_by: String,
         merchant_connector_id: Option<String>,
+        three_ds_decision: Option<storage_enums::ThreeDsDecision>,
+        sca_exemption: Option<storage_enums::ScaExemptionType>,
+        card_fingerprint: Option<String>,
     },
     AuthenticationTypeUpdate {
         authentication_type: storage_enums::AuthenticationType,
@@ -324,6 +358,13 @@ pub enum PaymentAttemptUpdate {
         payment_method_billing_address_id: Option<String>,
         fingerprint_id: Option<String>,
         payment_method_id: Option<String>,
+        card_network: Option<String>,
+        card_type: Option<String>,
+        card_issuing_country: Option<String>,
+        three_ds_decision: Option<storage_enums::ThreeDsDecision>,
+        sca_exemption: Option<storage_enums::ScaExemptionType>,
+        card_fingerprint: Option<String>,
+        payment_method_selection: Option<storage_enums::PaymentMethodSelection>,
     },
     RejectUpdate {
         status: storage_enums::AttemptStatus,
@@ -362,6 +403,13 @@ pub enum PaymentAttemptUpdate {
         unified_code: Option<Option<String>>,
         unified_message: Option<Option<String>>,
         payment_method_data: Option<serde_json::Value>,
+        settlement_currency: Option<storage_enums::Currency>,
+        settlement_amount: Option<i64>,
+        fx_rate: Option<String>,
+        avs_result_code: Option<String>,
+        avs_result: Option<storage_enums::CardCheckResult>,
+        cvv_result_code: Option<String>,
+        cvv_result: Option<storage_enums::CardCheckResult>,
     },
     UnresolvedResponseUpdate {
         status: storage_enums::AttemptStatus,
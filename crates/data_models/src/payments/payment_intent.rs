@@ -30,6 +30,15 @@ pub trait PaymentIntentInterface {
         storage_scheme: storage_enums::MerchantStorageScheme,
     ) -> error_stack::Result<PaymentIntent, errors::StorageError>;
 
+    /// Finds the payment intent along with its active attempt, in a single round trip to the
+    /// database when the intent is not stored in the KV store
+    async fn find_payment_intent_with_active_attempt_by_payment_id_merchant_id(
+        &self,
+        payment_id: &str,
+        merchant_id: &str,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<(PaymentIntent, PaymentAttempt), errors::StorageError>;
+
     async fn get_active_payment_attempt(
         &self,
         payment: &mut PaymentIntent,
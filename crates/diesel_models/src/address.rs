@@ -26,6 +26,8 @@ pub struct AddressNew {
     pub modified_at: PrimitiveDateTime,
     pub updated_by: String,
     pub email: Option<Encryption>,
+    pub normalized_address: Option<Encryption>,
+    pub address_validation_status: Option<enums::AddressValidationStatus>,
 }
 
 #[derive(Clone, Debug, Queryable, Identifiable, Serialize, Deserialize)]
@@ -51,6 +53,8 @@ pub struct Address {
     pub payment_id: Option<String>,
     pub updated_by: String,
     pub email: Option<Encryption>,
+    pub normalized_address: Option<Encryption>,
+    pub address_validation_status: Option<enums::AddressValidationStatus>,
 }
 
 #[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay, Serialize, Deserialize)]
@@ -70,6 +74,7 @@ pub struct AddressUpdateInternal {
     pub modified_at: PrimitiveDateTime,
     pub updated_by: String,
     pub email: Option<Encryption>,
+    pub address_validation_status: Option<enums::AddressValidationStatus>,
 }
 
 impl AddressUpdateInternal {
@@ -88,6 +93,7 @@ impl AddressUpdateInternal {
             country_code: self.country_code,
             modified_at: self.modified_at,
             updated_by: self.updated_by,
+            address_validation_status: self.address_validation_status,
             ..source
         }
     }
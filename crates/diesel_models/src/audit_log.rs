@@ -0,0 +1,48 @@
+use diesel::{Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::audit_log};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = audit_log)]
+pub struct AuditLogNew {
+    pub audit_id: String,
+    pub merchant_id: String,
+    pub operation: storage_enums::AuditOperation,
+    pub entity_id: String,
+    pub actor_type: storage_enums::AuditActorType,
+    pub actor_id: Option<String>,
+    pub request_id: Option<String>,
+    pub changes: serde_json::Value,
+    pub created_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, serde::Serialize, serde::Deserialize)]
+#[diesel(table_name = audit_log, primary_key(audit_id))]
+pub struct AuditLog {
+    pub audit_id: String,
+    pub merchant_id: String,
+    pub operation: storage_enums::AuditOperation,
+    /// The identifier of the entity changed by the operation, such as the merchant connector ID
+    pub entity_id: String,
+    pub actor_type: storage_enums::AuditActorType,
+    /// The API key ID or the user ID of the actor, depending on the type of the actor
+    pub actor_id: Option<String>,
+    pub request_id: Option<String>,
+    /// The fields changed by the operation, each with its masked value before and after the
+    /// operation
+    pub changes: serde_json::Value,
+    pub created_at: PrimitiveDateTime,
+}
+
+/// The constraints to apply when listing the audit logs of a merchant.
+#[derive(Clone, Debug, Default)]
+pub struct AuditLogListConstraints {
+    pub created_after: Option<PrimitiveDateTime>,
+    pub created_before: Option<PrimitiveDateTime>,
+    pub operation: Option<storage_enums::AuditOperation>,
+    pub entity_id: Option<String>,
+    pub actor_id: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
@@ -40,6 +40,12 @@ pub struct Authentication {
     pub acs_trans_id: Option<String>,
     pub three_ds_server_trans_id: Option<String>,
     pub acs_signed_content: Option<String>,
+    /// The authentication attempted with the primary authentication connector of the business
+    /// profile, when this authentication is retried with the fallback authentication connector
+    pub previous_authentication_id: Option<String>,
+    /// Whether the 3DS method was completed before the authentication, not set when the 3DS
+    /// method was not available
+    pub three_ds_method_completed: Option<bool>,
 }
 
 impl Authentication {
@@ -82,6 +88,8 @@ pub struct AuthenticationNew {
     pub acs_trans_id: Option<String>,
     pub three_dsserver_trans_id: Option<String>,
     pub acs_signed_content: Option<String>,
+    pub previous_authentication_id: Option<String>,
+    pub three_ds_method_completed: Option<bool>,
 }
 
 #[derive(Debug)]
@@ -109,6 +117,7 @@ pub enum AuthenticationUpdate {
         acs_trans_id: Option<String>,
         acs_signed_content: Option<String>,
         authentication_status: common_enums::AuthenticationStatus,
+        three_ds_method_completed: Option<bool>,
     },
     PostAuthenticationUpdate {
         trans_status: common_enums::TransactionStatus,
@@ -157,6 +166,7 @@ pub struct AuthenticationUpdateInternal {
     pub acs_trans_id: Option<String>,
     pub three_dsserver_trans_id: Option<String>,
     pub acs_signed_content: Option<String>,
+    pub three_ds_method_completed: Option<bool>,
 }
 
 impl Default for AuthenticationUpdateInternal {
@@ -188,6 +198,7 @@ impl Default for AuthenticationUpdateInternal {
             acs_trans_id: Default::default(),
             three_dsserver_trans_id: Default::default(),
             acs_signed_content: Default::default(),
+            three_ds_method_completed: Default::default(),
         }
     }
 }
@@ -221,6 +232,7 @@ impl AuthenticationUpdateInternal {
             acs_trans_id,
             three_dsserver_trans_id,
             acs_signed_content,
+            three_ds_method_completed,
         } = self;
         Authentication {
             connector_authentication_id: connector_authentication_id
@@ -253,6 +265,8 @@ impl AuthenticationUpdateInternal {
             acs_trans_id: acs_trans_id.or(source.acs_trans_id),
             three_ds_server_trans_id: three_dsserver_trans_id.or(source.three_ds_server_trans_id),
             acs_signed_content: acs_signed_content.or(source.acs_signed_content),
+            three_ds_method_completed: three_ds_method_completed
+                .or(source.three_ds_method_completed),
             ..source
         }
     }
@@ -330,6 +344,7 @@ impl From<AuthenticationUpdate> for AuthenticationUpdateInternal {
                 acs_trans_id,
                 acs_signed_content,
                 authentication_status,
+                three_ds_method_completed,
             } => Self {
                 cavv: authentication_value,
                 trans_status: Some(trans_status),
@@ -340,6 +355,7 @@ impl From<AuthenticationUpdate> for AuthenticationUpdateInternal {
                 acs_trans_id,
                 acs_signed_content,
                 authentication_status: Some(authentication_status),
+                three_ds_method_completed,
                 ..Default::default()
             },
             AuthenticationUpdate::PostAuthenticationUpdate {
@@ -35,6 +35,8 @@ pub struct BusinessProfile {
     pub payment_link_config: Option<serde_json::Value>,
     pub session_expiry: Option<i64>,
     pub authentication_connector_details: Option<serde_json::Value>,
+    pub card_verification_policy: Option<serde_json::Value>,
+    pub partial_authorization_action: Option<common_enums::PartialAuthorizationAction>,
 }
 
 #[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
@@ -61,6 +63,8 @@ pub struct BusinessProfileNew {
     pub payment_link_config: Option<serde_json::Value>,
     pub session_expiry: Option<i64>,
     pub authentication_connector_details: Option<serde_json::Value>,
+    pub card_verification_policy: Option<serde_json::Value>,
+    pub partial_authorization_action: Option<common_enums::PartialAuthorizationAction>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -84,6 +88,8 @@ pub struct BusinessProfileUpdateInternal {
     pub payment_link_config: Option<serde_json::Value>,
     pub session_expiry: Option<i64>,
     pub authentication_connector_details: Option<serde_json::Value>,
+    pub card_verification_policy: Option<serde_json::Value>,
+    pub partial_authorization_action: Option<common_enums::PartialAuthorizationAction>,
 }
 
 impl From<BusinessProfileNew> for BusinessProfile {
@@ -109,6 +115,8 @@ impl From<BusinessProfileNew> for BusinessProfile {
             payment_link_config: new.payment_link_config,
             session_expiry: new.session_expiry,
             authentication_connector_details: new.authentication_connector_details,
+            card_verification_policy: new.card_verification_policy,
+            partial_authorization_action: new.partial_authorization_action,
         }
     }
 }
@@ -133,6 +141,8 @@ impl BusinessProfileUpdateInternal {
             payment_link_config,
             session_expiry,
             authentication_connector_details,
+            card_verification_policy,
+            partial_authorization_action,
         } = self;
         BusinessProfile {
             profile_name: profile_name.unwrap_or(source.profile_name),
@@ -154,6 +164,8 @@ impl BusinessProfileUpdateInternal {
             payment_link_config,
             session_expiry,
             authentication_connector_details,
+            card_verification_policy,
+            partial_authorization_action,
             ..source
         }
     }
@@ -0,0 +1,18 @@
+pub struct ClientSessionNew {
+    pub token: String,
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub customer_id: Option<String>,
+}
+
+/// A short lived session of a client such as the SDK, bound to a single payment and to the
+/// customer of the payment.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ClientSession {
+    pub token: String,
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub customer_id: Option<String>,
+    pub created_at: i64,
+    pub expires: i64,
+}
@@ -2,7 +2,7 @@ use common_utils::pii;
 use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
 use time::PrimitiveDateTime;
 
-use crate::{encryption::Encryption, schema::customers};
+use crate::{encryption::Encryption, enums as storage_enums, schema::customers};
 
 #[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
 #[diesel(table_name = customers)]
@@ -19,6 +19,8 @@ pub struct CustomerNew {
     pub created_at: PrimitiveDateTime,
     pub modified_at: PrimitiveDateTime,
     pub address_id: Option<String>,
+    pub document_type: Option<storage_enums::CustomerDocumentType>,
+    pub document_number: Option<Encryption>,
 }
 
 #[derive(Clone, Debug, Identifiable, Queryable)]
@@ -38,6 +40,8 @@ pub struct Customer {
     pub modified_at: PrimitiveDateTime,
     pub address_id: Option<String>,
     pub default_payment_method_id: Option<String>,
+    pub document_type: Option<storage_enums::CustomerDocumentType>,
+    pub document_number: Option<Encryption>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -53,4 +57,6 @@ pub struct CustomerUpdateInternal {
     pub connector_customer: Option<serde_json::Value>,
     pub address_id: Option<String>,
     pub default_payment_method_id: Option<Option<String>>,
+    pub document_type: Option<storage_enums::CustomerDocumentType>,
+    pub document_number: Option<Encryption>,
 }
@@ -61,6 +61,7 @@ pub struct Dispute {
     pub profile_id: Option<String>,
     pub merchant_connector_id: Option<String>,
     pub dispute_amount: i64,
+    pub representment_status: Option<storage_enums::DisputeRepresentmentStatus>,
 }
 
 #[derive(Debug)]
@@ -81,6 +82,9 @@ pub enum DisputeUpdate {
     EvidenceUpdate {
         evidence: Secret<serde_json::Value>,
     },
+    RepresentmentStatusUpdate {
+        representment_status: storage_enums::DisputeRepresentmentStatus,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -95,6 +99,7 @@ pub struct DisputeUpdateInternal {
     connector_updated_at: Option<PrimitiveDateTime>,
     modified_at: Option<PrimitiveDateTime>,
     evidence: Option<Secret<serde_json::Value>>,
+    representment_status: Option<storage_enums::DisputeRepresentmentStatus>,
 }
 
 impl From<DisputeUpdate> for DisputeUpdateInternal {
@@ -132,6 +137,13 @@ impl From<DisputeUpdate> for DisputeUpdateInternal {
                 evidence: Some(evidence),
                 ..Default::default()
             },
+            DisputeUpdate::RepresentmentStatusUpdate {
+                representment_status,
+            } => Self {
+                representment_status: Some(representment_status),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
         }
     }
 }
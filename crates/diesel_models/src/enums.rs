@@ -68,6 +68,10 @@ pub enum EventObjectType {
     RefundDetails,
     DisputeDetails,
     MandateDetails,
+    ConnectorHealthDetails,
+    AuthenticationDetails,
+    PaymentMethodDetails,
+    SuccessRateDropDetails,
 }
 
 #[derive(
@@ -118,6 +122,8 @@ pub enum RefundType {
     #[default]
     RegularRefund,
     RetryRefund,
+    /// Refund made to an alternate payment method of the customer through a payout
+    PayoutRefund,
 }
 
 // Mandate
@@ -220,6 +226,9 @@ pub struct MandateAmountData {
     pub start_date: Option<PrimitiveDateTime>,
     pub end_date: Option<PrimitiveDateTime>,
     pub metadata: Option<pii::SecretSerdeValue>,
+    pub max_amount_per_charge: Option<i64>,
+    pub max_amount_per_period: Option<i64>,
+    pub limit_period: Option<MandateLimitPeriod>,
 }
 
 #[derive(
@@ -23,6 +23,7 @@ pub struct EventNew {
     pub request: Option<Encryption>,
     pub response: Option<Encryption>,
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
+    pub delivery_status: Option<storage_enums::WebhookDeliveryStatus>,
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -30,6 +31,7 @@ pub struct EventNew {
 pub struct EventUpdateInternal {
     pub is_webhook_notified: Option<bool>,
     pub response: Option<Encryption>,
+    pub delivery_status: Option<storage_enums::WebhookDeliveryStatus>,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize, Identifiable, Queryable)]
@@ -53,4 +55,5 @@ pub struct Event {
     pub request: Option<Encryption>,
     pub response: Option<Encryption>,
     pub delivery_attempt: Option<storage_enums::WebhookDeliveryAttempt>,
+    pub delivery_status: Option<storage_enums::WebhookDeliveryStatus>,
 }
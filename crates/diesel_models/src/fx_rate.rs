@@ -0,0 +1,33 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::{Date, PrimitiveDateTime};
+
+use crate::{enums as storage_enums, schema::fx_rate};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = fx_rate)]
+pub struct FxRateNew {
+    pub rate_date: Date,
+    pub base_currency: storage_enums::Currency,
+    pub rates: serde_json::Value,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, serde::Serialize, serde::Deserialize)]
+#[diesel(table_name = fx_rate, primary_key(rate_date))]
+pub struct FxRate {
+    pub rate_date: Date,
+    pub base_currency: storage_enums::Currency,
+    /// Conversion factors of each currency against the base currency
+    pub rates: serde_json::Value,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = fx_rate)]
+pub struct FxRateUpdateInternal {
+    pub base_currency: storage_enums::Currency,
+    pub rates: serde_json::Value,
+    pub modified_at: PrimitiveDateTime,
+}
@@ -1,9 +1,11 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_log;
 pub mod blocklist_lookup;
 pub mod business_profile;
 pub mod capture;
 pub mod cards_info;
+pub mod client_session;
 pub mod configs;
 
 pub mod authentication;
@@ -20,6 +22,7 @@ pub mod events;
 pub mod file;
 #[allow(unused)]
 pub mod fraud_check;
+pub mod fx_rate;
 pub mod gsm;
 #[cfg(feature = "kv_store")]
 pub mod kv;
@@ -39,11 +42,15 @@ pub mod payouts;
 pub mod process_tracker;
 pub mod query;
 pub mod refund;
+pub mod report_run;
+pub mod report_schedule;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
 #[allow(unused_qualifications)]
 pub mod schema;
+pub mod settlement_record;
+pub mod success_rate_alert;
 pub mod user;
 pub mod user_role;
 
@@ -32,6 +32,11 @@ pub struct Mandate {
     pub connector_mandate_ids: Option<pii::SecretSerdeValue>,
     pub original_payment_id: Option<String>,
     pub merchant_connector_id: Option<String>,
+    pub max_amount_per_charge: Option<i64>,
+    pub max_amount_per_period: Option<i64>,
+    pub limit_period: Option<storage_enums::MandateLimitPeriod>,
+    pub period_amount_captured: Option<i64>,
+    pub period_start: Option<PrimitiveDateTime>,
 }
 
 #[derive(
@@ -62,6 +67,11 @@ pub struct MandateNew {
     pub connector_mandate_ids: Option<pii::SecretSerdeValue>,
     pub original_payment_id: Option<String>,
     pub merchant_connector_id: Option<String>,
+    pub max_amount_per_charge: Option<i64>,
+    pub max_amount_per_period: Option<i64>,
+    pub limit_period: Option<storage_enums::MandateLimitPeriod>,
+    pub period_amount_captured: Option<i64>,
+    pub period_start: Option<PrimitiveDateTime>,
 }
 
 #[derive(Debug)]
@@ -71,6 +81,8 @@ pub enum MandateUpdate {
     },
     CaptureAmountUpdate {
         amount_captured: Option<i64>,
+        period_amount_captured: Option<i64>,
+        period_start: Option<PrimitiveDateTime>,
     },
     ConnectorReferenceUpdate {
         connector_mandate_ids: Option<pii::SecretSerdeValue>,
@@ -81,6 +93,9 @@ pub enum MandateUpdate {
         payment_method_id: String,
         original_payment_id: Option<String>,
     },
+    CustomerUpdate {
+        customer_id: String,
+    },
 }
 
 #[derive(Clone, Eq, PartialEq, Copy, Debug, Default, serde::Serialize, serde::Deserialize)]
@@ -98,6 +113,9 @@ pub struct MandateUpdateInternal {
     connector_mandate_id: Option<String>,
     payment_method_id: Option<String>,
     original_payment_id: Option<String>,
+    customer_id: Option<String>,
+    period_amount_captured: Option<i64>,
+    period_start: Option<PrimitiveDateTime>,
 }
 
 impl From<MandateUpdate> for MandateUpdateInternal {
@@ -110,14 +128,24 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 connector_mandate_id: None,
                 payment_method_id: None,
                 original_payment_id: None,
+                customer_id: None,
+                period_amount_captured: None,
+                period_start: None,
             },
-            MandateUpdate::CaptureAmountUpdate { amount_captured } => Self {
+            MandateUpdate::CaptureAmountUpdate {
+                amount_captured,
+                period_amount_captured,
+                period_start,
+            } => Self {
                 mandate_status: None,
                 amount_captured,
                 connector_mandate_ids: None,
                 connector_mandate_id: None,
                 payment_method_id: None,
                 original_payment_id: None,
+                customer_id: None,
+                period_amount_captured,
+                period_start,
             },
             MandateUpdate::ConnectorReferenceUpdate {
                 connector_mandate_ids,
@@ -137,6 +165,10 @@ impl From<MandateUpdate> for MandateUpdateInternal {
                 original_payment_id,
                 ..Default::default()
             },
+            MandateUpdate::CustomerUpdate { customer_id } => Self {
+                customer_id: Some(customer_id),
+                ..Default::default()
+            },
         }
     }
 }
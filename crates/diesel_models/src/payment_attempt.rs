@@ -70,6 +70,20 @@ pub struct PaymentAttempt {
     pub mandate_data: Option<storage_enums::MandateDetails>,
     pub fingerprint_id: Option<String>,
     pub payment_method_billing_address_id: Option<String>,
+    pub card_network: Option<String>,
+    pub card_type: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub three_ds_decision: Option<storage_enums::ThreeDsDecision>,
+    pub sca_exemption: Option<storage_enums::ScaExemptionType>,
+    pub card_fingerprint: Option<String>,
+    pub payment_method_selection: Option<storage_enums::PaymentMethodSelection>,
+    pub settlement_currency: Option<storage_enums::Currency>,
+    pub settlement_amount: Option<i64>,
+    pub fx_rate: Option<String>,
+    pub avs_result_code: Option<String>,
+    pub avs_result: Option<storage_enums::CardCheckResult>,
+    pub cvv_result_code: Option<String>,
+    pub cvv_result: Option<storage_enums::CardCheckResult>,
 }
 
 impl PaymentAttempt {
@@ -150,6 +164,20 @@ pub struct PaymentAttemptNew {
     pub mandate_data: Option<storage_enums::MandateDetails>,
     pub fingerprint_id: Option<String>,
     pub payment_method_billing_address_id: Option<String>,
+    pub card_network: Option<String>,
+    pub card_type: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub three_ds_decision: Option<storage_enums::ThreeDsDecision>,
+    pub sca_exemption: Option<storage_enums::ScaExemptionType>,
+    pub card_fingerprint: Option<String>,
+    pub payment_method_selection: Option<storage_enums::PaymentMethodSelection>,
+    pub settlement_currency: Option<storage_enums::Currency>,
+    pub settlement_amount: Option<i64>,
+    pub fx_rate: Option<String>,
+    pub avs_result_code: Option<String>,
+    pub avs_result: Option<storage_enums::CardCheckResult>,
+    pub cvv_result_code: Option<String>,
+    pub cvv_result: Option<storage_enums::CardCheckResult>,
 }
 
 impl PaymentAttemptNew {
@@ -188,6 +216,9 @@ pub enum PaymentAttemptUpdate {
         surcharge_amount: Option<i64>,
         tax_amount: Option<i64>,
         fingerprint_id: Option<String>,
+        card_network: Option<String>,
+        card_type: Option<String>,
+        card_issuing_country: Option<String>,
         updated_by: String,
     },
     UpdateTrackers {
@@ -199,6 +230,9 @@ pub enum PaymentAttemptUpdate {
         tax_amount: Option<i64>,
         updated_by: String,
         merchant_connector_id: Option<String>,
+        three_ds_decision: Option<storage_enums::ThreeDsDecision>,
+        sca_exemption: Option<storage_enums::ScaExemptionType>,
+        card_fingerprint: Option<String>,
     },
     AuthenticationTypeUpdate {
         authentication_type: storage_enums::AuthenticationType,
@@ -231,6 +265,13 @@ pub enum PaymentAttemptUpdate {
         authentication_connector: Option<String>,
         authentication_id: Option<String>,
         payment_method_billing_address_id: Option<String>,
+        card_network: Option<String>,
+        card_type: Option<String>,
+        card_issuing_country: Option<String>,
+        three_ds_decision: Option<storage_enums::ThreeDsDecision>,
+        sca_exemption: Option<storage_enums::ScaExemptionType>,
+        card_fingerprint: Option<String>,
+        payment_method_selection: Option<storage_enums::PaymentMethodSelection>,
     },
     VoidUpdate {
         status: storage_enums::AttemptStatus,
@@ -269,6 +310,13 @@ pub enum PaymentAttemptUpdate {
         unified_code: Option<Option<String>>,
         unified_message: Option<Option<String>>,
         payment_method_data: Option<serde_json::Value>,
+        settlement_currency: Option<storage_enums::Currency>,
+        settlement_amount: Option<i64>,
+        fx_rate: Option<String>,
+        avs_result_code: Option<String>,
+        avs_result: Option<storage_enums::CardCheckResult>,
+        cvv_result_code: Option<String>,
+        cvv_result: Option<storage_enums::CardCheckResult>,
     },
     UnresolvedResponseUpdate {
         status: storage_enums::AttemptStatus,
@@ -382,6 +430,20 @@ pub struct PaymentAttemptUpdateInternal {
     authentication_id: Option<String>,
     fingerprint_id: Option<String>,
     payment_method_billing_address_id: Option<String>,
+    card_network: Option<String>,
+    card_type: Option<String>,
+    card_issuing_country: Option<String>,
+    three_ds_decision: Option<storage_enums::ThreeDsDecision>,
+    sca_exemption: Option<storage_enums::ScaExemptionType>,
+    card_fingerprint: Option<String>,
+    payment_method_selection: Option<storage_enums::PaymentMethodSelection>,
+    settlement_currency: Option<storage_enums::Currency>,
+    settlement_amount: Option<i64>,
+    fx_rate: Option<String>,
+    avs_result_code: Option<String>,
+    avs_result: Option<storage_enums::CardCheckResult>,
+    cvv_result_code: Option<String>,
+    cvv_result: Option<storage_enums::CardCheckResult>,
 }
 
 impl PaymentAttemptUpdateInternal {
@@ -447,6 +509,20 @@ impl PaymentAttemptUpdate {
             authentication_id,
             payment_method_billing_address_id,
             fingerprint_id,
+            card_network,
+            card_type,
+            card_issuing_country,
+            three_ds_decision,
+            sca_exemption,
+            card_fingerprint,
+            payment_method_selection,
+            settlement_currency,
+            settlement_amount,
+            fx_rate,
+            avs_result_code,
+            avs_result,
+            cvv_result_code,
+            cvv_result,
         } = PaymentAttemptUpdateInternal::from(self).populate_derived_fields(&source);
         PaymentAttempt {
             amount: amount.unwrap_or(source.amount),
@@ -495,6 +571,20 @@ impl PaymentAttemptUpdate {
             payment_method_billing_address_id: payment_method_billing_address_id
                 .or(source.payment_method_billing_address_id),
             fingerprint_id: fingerprint_id.or(source.fingerprint_id),
+            card_network: card_network.or(source.card_network),
+            card_type: card_type.or(source.card_type),
+            card_issuing_country: card_issuing_country.or(source.card_issuing_country),
+            three_ds_decision: three_ds_decision.or(source.three_ds_decision),
+            sca_exemption: sca_exemption.or(source.sca_exemption),
+            card_fingerprint: card_fingerprint.or(source.card_fingerprint),
+            payment_method_selection: payment_method_selection.or(source.payment_method_selection),
+            settlement_currency: settlement_currency.or(source.settlement_currency),
+            settlement_amount: settlement_amount.or(source.settlement_amount),
+            fx_rate: fx_rate.or(source.fx_rate),
+            avs_result_code: avs_result_code.or(source.avs_result_code),
+            avs_result: avs_result.or(source.avs_result),
+            cvv_result_code: cvv_result_code.or(source.cvv_result_code),
+            cvv_result: cvv_result.or(source.cvv_result),
             ..source
         }
     }
@@ -520,6 +610,9 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 surcharge_amount,
                 tax_amount,
                 fingerprint_id,
+                card_network,
+                card_type,
+                card_issuing_country,
                 updated_by,
             } => Self {
                 amount: Some(amount),
@@ -539,6 +632,9 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 surcharge_amount,
                 tax_amount,
                 fingerprint_id,
+                card_network,
+                card_type,
+                card_issuing_country,
                 updated_by,
                 ..Default::default()
             },
@@ -578,6 +674,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 payment_method_billing_address_id,
                 fingerprint_id,
                 payment_method_id,
+                card_network,
+                card_type,
+                card_issuing_country,
+                three_ds_decision,
+                sca_exemption,
+                card_fingerprint,
+                payment_method_selection,
             } => Self {
                 amount: Some(amount),
                 currency: Some(currency),
@@ -606,6 +709,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 payment_method_billing_address_id,
                 fingerprint_id,
                 payment_method_id: payment_method_id.map(Some),
+                card_network,
+                card_type,
+                card_issuing_country,
+                three_ds_decision,
+                sca_exemption,
+                card_fingerprint,
+                payment_method_selection,
                 ..Default::default()
             },
             PaymentAttemptUpdate::VoidUpdate {
@@ -664,6 +774,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 unified_code,
                 unified_message,
                 payment_method_data,
+                settlement_currency,
+                settlement_amount,
+                fx_rate,
+                avs_result_code,
+                avs_result,
+                cvv_result_code,
+                cvv_result,
             } => Self {
                 status: Some(status),
                 connector: connector.map(Some),
@@ -685,6 +802,13 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 unified_code,
                 unified_message,
                 payment_method_data,
+                settlement_currency,
+                settlement_amount,
+                fx_rate,
+                avs_result_code,
+                avs_result,
+                cvv_result_code,
+                cvv_result,
                 ..Default::default()
             },
             PaymentAttemptUpdate::ErrorUpdate {
@@ -728,6 +852,9 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 tax_amount,
                 updated_by,
                 merchant_connector_id,
+                three_ds_decision,
+                sca_exemption,
+                card_fingerprint,
             } => Self {
                 payment_token,
                 connector: connector.map(Some),
@@ -737,6 +864,9 @@ impl From<PaymentAttemptUpdate> for PaymentAttemptUpdateInternal {
                 tax_amount,
                 updated_by,
                 merchant_connector_id: merchant_connector_id.map(Some),
+                three_ds_decision,
+                sca_exemption,
+                card_fingerprint,
                 ..Default::default()
             },
             PaymentAttemptUpdate::UnresolvedResponseUpdate {
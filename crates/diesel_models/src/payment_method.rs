@@ -38,6 +38,10 @@ pub struct PaymentMethod {
     pub connector_mandate_details: Option<serde_json::Value>,
     pub customer_acceptance: Option<pii::SecretSerdeValue>,
     pub status: storage_enums::PaymentMethodStatus,
+    pub card_fingerprint: Option<String>,
+    pub network_token_reference_id: Option<String>,
+    pub network_token_locker_id: Option<String>,
+    pub network_token_requestor_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Insertable, router_derive::DebugAsDisplay)]
@@ -69,6 +73,10 @@ pub struct PaymentMethodNew {
     pub connector_mandate_details: Option<serde_json::Value>,
     pub customer_acceptance: Option<pii::SecretSerdeValue>,
     pub status: storage_enums::PaymentMethodStatus,
+    pub card_fingerprint: Option<String>,
+    pub network_token_reference_id: Option<String>,
+    pub network_token_locker_id: Option<String>,
+    pub network_token_requestor_id: Option<String>,
 }
 
 impl Default for PaymentMethodNew {
@@ -102,6 +110,10 @@ impl Default for PaymentMethodNew {
             connector_mandate_details: Option::default(),
             customer_acceptance: Option::default(),
             status: storage_enums::PaymentMethodStatus::Active,
+            card_fingerprint: Option::default(),
+            network_token_reference_id: Option::default(),
+            network_token_locker_id: Option::default(),
+            network_token_requestor_id: Option::default(),
         }
     }
 }
@@ -126,6 +138,24 @@ pub enum PaymentMethodUpdate {
     StatusUpdate {
         status: Option<storage_enums::PaymentMethodStatus>,
     },
+    NetworkTokenDataUpdate {
+        network_token_reference_id: Option<String>,
+        network_token_locker_id: Option<String>,
+        network_token_requestor_id: Option<String>,
+    },
+    CardFingerprintUpdate {
+        card_fingerprint: Option<String>,
+    },
+    SavedCardUpdate {
+        payment_method_data: Option<Encryption>,
+        connector_mandate_details: Option<serde_json::Value>,
+        card_fingerprint: Option<String>,
+    },
+    CustomerUpdate {
+        customer_id: String,
+        locker_id: Option<String>,
+        network_token_locker_id: Option<String>,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -135,6 +165,13 @@ pub struct PaymentMethodUpdateInternal {
     payment_method_data: Option<Encryption>,
     last_used_at: Option<PrimitiveDateTime>,
     status: Option<storage_enums::PaymentMethodStatus>,
+    network_token_reference_id: Option<String>,
+    network_token_locker_id: Option<String>,
+    network_token_requestor_id: Option<String>,
+    card_fingerprint: Option<String>,
+    customer_id: Option<String>,
+    locker_id: Option<String>,
+    connector_mandate_details: Option<serde_json::Value>,
 }
 
 impl PaymentMethodUpdateInternal {
@@ -153,6 +190,13 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 payment_method_data: None,
                 last_used_at: None,
                 status: None,
+                network_token_reference_id: None,
+                network_token_locker_id: None,
+                network_token_requestor_id: None,
+                card_fingerprint: None,
+                customer_id: None,
+                locker_id: None,
+                connector_mandate_details: None,
             },
             PaymentMethodUpdate::PaymentMethodDataUpdate {
                 payment_method_data,
@@ -161,18 +205,103 @@ impl From<PaymentMethodUpdate> for PaymentMethodUpdateInternal {
                 payment_method_data,
                 last_used_at: None,
                 status: None,
+                network_token_reference_id: None,
+                network_token_locker_id: None,
+                network_token_requestor_id: None,
+                card_fingerprint: None,
+                customer_id: None,
+                locker_id: None,
+                connector_mandate_details: None,
             },
             PaymentMethodUpdate::LastUsedUpdate { last_used_at } => Self {
                 metadata: None,
                 payment_method_data: None,
                 last_used_at: Some(last_used_at),
                 status: None,
+                network_token_reference_id: None,
+                network_token_locker_id: None,
+                network_token_requestor_id: None,
+                card_fingerprint: None,
+                customer_id: None,
+                locker_id: None,
+                connector_mandate_details: None,
             },
             PaymentMethodUpdate::StatusUpdate { status } => Self {
                 metadata: None,
                 payment_method_data: None,
                 last_used_at: None,
                 status,
+                network_token_reference_id: None,
+                network_token_locker_id: None,
+                network_token_requestor_id: None,
+                card_fingerprint: None,
+                customer_id: None,
+                locker_id: None,
+                connector_mandate_details: None,
+            },
+            PaymentMethodUpdate::NetworkTokenDataUpdate {
+                network_token_reference_id,
+                network_token_locker_id,
+                network_token_requestor_id,
+            } => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                status: None,
+                network_token_reference_id,
+                network_token_locker_id,
+                network_token_requestor_id,
+                card_fingerprint: None,
+                customer_id: None,
+                locker_id: None,
+                connector_mandate_details: None,
+            },
+            PaymentMethodUpdate::CardFingerprintUpdate { card_fingerprint } => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                status: None,
+                network_token_reference_id: None,
+                network_token_locker_id: None,
+                network_token_requestor_id: None,
+                card_fingerprint,
+                customer_id: None,
+                locker_id: None,
+                connector_mandate_details: None,
+            },
+            PaymentMethodUpdate::SavedCardUpdate {
+                payment_method_data,
+                connector_mandate_details,
+                card_fingerprint,
+            } => Self {
+                metadata: None,
+                payment_method_data,
+                last_used_at: None,
+                status: None,
+                network_token_reference_id: None,
+                network_token_locker_id: None,
+                network_token_requestor_id: None,
+                card_fingerprint,
+                customer_id: None,
+                locker_id: None,
+                connector_mandate_details,
+            },
+            PaymentMethodUpdate::CustomerUpdate {
+                customer_id,
+                locker_id,
+                network_token_locker_id,
+            } => Self {
+                metadata: None,
+                payment_method_data: None,
+                last_used_at: None,
+                status: None,
+                network_token_reference_id: None,
+                network_token_locker_id,
+                network_token_requestor_id: None,
+                card_fingerprint: None,
+                customer_id: Some(customer_id),
+                locker_id,
+                connector_mandate_details: None,
             },
         }
     }
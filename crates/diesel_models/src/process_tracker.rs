@@ -209,6 +209,17 @@ pub enum ProcessTrackerRunner {
     DeleteTokenizeDataWorkflow,
     ApiKeyExpiryWorkflow,
     OutgoingWebhookRetryWorkflow,
+    RecurringPaymentWorkflow,
+    FxRatesSyncWorkflow,
+    DisputeRepresentmentWorkflow,
+    MerchantAccountDeletionWorkflow,
+    FrmDecisionWorkflow,
+    CustomerDedupWorkflow,
+    CardVerificationVoidWorkflow,
+    BulkChargeWorkflow,
+    ScheduledReportWorkflow,
+    PaymentMethodExpiryWorkflow,
+    SuccessRateAlertWorkflow,
 }
 
 #[cfg(test)]
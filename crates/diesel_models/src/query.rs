@@ -1,5 +1,6 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_log;
 pub mod blocklist_lookup;
 pub mod business_profile;
 mod capture;
@@ -16,6 +17,7 @@ pub mod dispute;
 pub mod events;
 pub mod file;
 pub mod fraud_check;
+pub mod fx_rate;
 pub mod generics;
 pub mod gsm;
 pub mod locker_mock_up;
@@ -32,8 +34,12 @@ pub mod payout_attempt;
 pub mod payouts;
 pub mod process_tracker;
 pub mod refund;
+pub mod report_run;
+pub mod report_schedule;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod settlement_record;
+pub mod success_rate_alert;
 pub mod user;
 pub mod user_role;
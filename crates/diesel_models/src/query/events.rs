@@ -4,6 +4,7 @@ use diesel::{
 
 use super::generics;
 use crate::{
+    enums as storage_enums,
     events::{Event, EventNew, EventUpdateInternal},
     schema::events::dsl,
     PgPooledConn, StorageResult,
@@ -56,6 +57,7 @@ impl Event {
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        delivery_status: Option<storage_enums::WebhookDeliveryStatus>,
     ) -> StorageResult<Vec<Self>> {
         use async_bb8_diesel::AsyncRunQueryDsl;
         use diesel::{debug_query, pg::Pg, QueryDsl};
@@ -83,6 +85,10 @@ impl Event {
             query = query.filter(dsl::created_at.le(created_before));
         }
 
+        if let Some(delivery_status) = delivery_status {
+            query = query.filter(dsl::delivery_status.eq(delivery_status));
+        }
+
         if let Some(limit) = limit {
             query = query.limit(limit);
         }
@@ -143,6 +149,7 @@ impl Event {
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        delivery_status: Option<storage_enums::WebhookDeliveryStatus>,
     ) -> StorageResult<Vec<Self>> {
         use async_bb8_diesel::AsyncRunQueryDsl;
         use diesel::{debug_query, pg::Pg, QueryDsl};
@@ -170,6 +177,10 @@ impl Event {
             query = query.filter(dsl::created_at.le(created_before));
         }
 
+        if let Some(delivery_status) = delivery_status {
+            query = query.filter(dsl::delivery_status.eq(delivery_status));
+        }
+
         if let Some(limit) = limit {
             query = query.limit(limit);
         }
@@ -0,0 +1,68 @@
+use diesel::{associations::HasTable, ExpressionMethods};
+use error_stack::report;
+use time::Date;
+
+use super::generics;
+use crate::{
+    errors,
+    fx_rate::{FxRate, FxRateNew, FxRateUpdateInternal},
+    schema::fx_rate::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl FxRateNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<FxRate> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl FxRate {
+    pub async fn update_by_rate_date(
+        conn: &PgPooledConn,
+        rate_date: Date,
+        fx_rate_update: FxRateUpdateInternal,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(conn, dsl::rate_date.eq(rate_date), fx_rate_update)
+        .await
+    }
+
+    pub async fn find_by_rate_date_range(
+        conn: &PgPooledConn,
+        start_date: Date,
+        end_date: Date,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::rate_date.between(start_date, end_date),
+            None,
+            None,
+            Some(dsl::rate_date.asc()),
+        )
+        .await
+    }
+
+    pub async fn find_latest_on_or_before_date(
+        conn: &PgPooledConn,
+        rate_date: Date,
+    ) -> StorageResult<Self> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::rate_date.le(rate_date),
+            Some(1),
+            None,
+            Some(dsl::rate_date.desc()),
+        )
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| {
+            report!(errors::DatabaseError::NotFound)
+                .attach_printable("No exchange rates found on or before the given date")
+        })
+    }
+}
@@ -1,12 +1,22 @@
-use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+use async_bb8_diesel::AsyncRunQueryDsl;
+use diesel::{
+    associations::HasTable, debug_query, result::Error as DieselError, BoolExpressionMethods,
+    ExpressionMethods, JoinOnDsl, QueryDsl,
+};
+use error_stack::IntoReport;
+use router_env::logger;
 
 use super::generics;
 use crate::{
     errors,
+    payment_attempt::PaymentAttempt,
     payment_intent::{
         PaymentIntent, PaymentIntentNew, PaymentIntentUpdate, PaymentIntentUpdateInternal,
     },
-    schema::payment_intent::dsl,
+    schema::{
+        payment_attempt::{self, dsl as pa_dsl},
+        payment_intent::dsl,
+    },
     PgPooledConn, StorageResult,
 };
 
@@ -55,6 +65,33 @@ impl PaymentIntent {
         .await
     }
 
+    /// Finds the payment intent along with its active attempt in a single query
+    pub async fn find_with_active_attempt_by_payment_id_merchant_id(
+        conn: &PgPooledConn,
+        payment_id: &str,
+        merchant_id: &str,
+    ) -> StorageResult<(Self, PaymentAttempt)> {
+        let query = Self::table()
+            .inner_join(
+                payment_attempt::table.on(pa_dsl::attempt_id
+                    .eq(dsl::active_attempt_id)
+                    .and(pa_dsl::merchant_id.eq(dsl::merchant_id))),
+            )
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .filter(dsl::payment_id.eq(payment_id.to_owned()));
+
+        logger::debug!(query = %debug_query::<diesel::pg::Pg, _>(&query).to_string());
+
+        query
+            .get_result_async::<(Self, PaymentAttempt)>(conn)
+            .await
+            .into_report()
+            .map_err(|err| match err.current_context() {
+                DieselError::NotFound => err.change_context(errors::DatabaseError::NotFound),
+                _ => err.change_context(errors::DatabaseError::Others),
+            })
+    }
+
     pub async fn find_optional_by_payment_id_merchant_id(
         conn: &PgPooledConn,
         payment_id: &str,
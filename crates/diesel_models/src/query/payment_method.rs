@@ -53,6 +53,23 @@ impl PaymentMethod {
         .await
     }
 
+    pub async fn find_by_customer_id_merchant_id_card_fingerprint(
+        conn: &PgPooledConn,
+        customer_id: &str,
+        merchant_id: &str,
+        card_fingerprint: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::customer_id
+                .eq(customer_id.to_owned())
+                .and(dsl::merchant_id.eq(merchant_id.to_owned()))
+                .and(dsl::card_fingerprint.eq(card_fingerprint.to_owned()))
+                .and(dsl::status.eq(storage_enums::PaymentMethodStatus::Active)),
+        )
+        .await
+    }
+
     pub async fn find_by_payment_method_id(
         conn: &PgPooledConn,
         payment_method_id: &str,
@@ -149,6 +166,28 @@ impl PaymentMethod {
         .await
     }
 
+    /// Lists the payment methods of the type and status across merchants having an ID greater
+    /// than `after_id`, ordered by their ID, for going through the table in batches
+    pub async fn find_by_payment_method_status_after_id(
+        conn: &PgPooledConn,
+        payment_method: storage_enums::PaymentMethod,
+        status: storage_enums::PaymentMethodStatus,
+        after_id: i32,
+        limit: i64,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::payment_method
+                .eq(payment_method)
+                .and(dsl::status.eq(status))
+                .and(dsl::id.gt(after_id)),
+            Some(limit),
+            None,
+            Some(dsl::id.asc()),
+        )
+        .await
+    }
+
     pub async fn update_with_payment_method_id(
         self,
         conn: &PgPooledConn,
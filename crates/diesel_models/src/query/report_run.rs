@@ -0,0 +1,98 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums as storage_enums,
+    report_run::{ReportRun, ReportRunNew, ReportRunUpdateInternal},
+    schema::report_run::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ReportRunNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ReportRun> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ReportRun {
+    pub async fn find_by_merchant_id_run_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        run_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::run_id.eq(run_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_run_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        run_id: &str,
+        report_run_update: ReportRunUpdateInternal,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::run_id.eq(run_id.to_owned())),
+            report_run_update,
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        schedule_id: Option<String>,
+        status: Option<Vec<storage_enums::ReportRunStatus>>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::{IntoReport, ResultExt};
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let mut query = Self::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(schedule_id) = schedule_id {
+            query = query.filter(dsl::schedule_id.eq(schedule_id));
+        }
+
+        if let Some(status) = status {
+            query = query.filter(dsl::status.eq_any(status));
+        }
+
+        if let Some(limit) = limit {
+            query = query.limit(limit);
+        }
+
+        if let Some(offset) = offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .into_report()
+            .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering report runs by constraints")
+    }
+}
@@ -0,0 +1,67 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    report_schedule::{ReportSchedule, ReportScheduleNew, ReportScheduleUpdateInternal},
+    schema::report_schedule::dsl,
+    PgPooledConn, StorageResult,
+};
+
+impl ReportScheduleNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<ReportSchedule> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl ReportSchedule {
+    pub async fn find_by_merchant_id_schedule_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        schedule_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::schedule_id.eq(schedule_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_active_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::is_active.eq(true)),
+            None,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_schedule_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        schedule_id: &str,
+        report_schedule_update: ReportScheduleUpdateInternal,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::schedule_id.eq(schedule_id.to_owned())),
+            report_schedule_update,
+        )
+        .await
+    }
+}
@@ -0,0 +1,121 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    enums as storage_enums,
+    schema::settlement_record::dsl,
+    settlement_record::{SettlementRecord, SettlementRecordNew, SettlementRecordUpdateInternal},
+    PgPooledConn, StorageResult,
+};
+
+impl SettlementRecordNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SettlementRecord> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SettlementRecord {
+    pub async fn find_by_merchant_id_record_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        record_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::record_id.eq(record_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn find_by_merchant_id_connector_record_type_connector_transaction_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        connector: &str,
+        record_type: storage_enums::SettlementRecordType,
+        connector_transaction_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::connector.eq(connector.to_owned()))
+                .and(dsl::record_type.eq(record_type))
+                .and(dsl::connector_transaction_id.eq(connector_transaction_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_record_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        record_id: &str,
+        settlement_record_update: SettlementRecordUpdateInternal,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::record_id.eq(record_id.to_owned())),
+            settlement_record_update,
+        )
+        .await
+    }
+
+    pub async fn list_by_merchant_id_constraints(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        report_id: Option<String>,
+        connector: Option<String>,
+        match_status: Option<Vec<storage_enums::SettlementMatchStatus>>,
+        limit: Option<i64>,
+        offset: Option<i64>,
+    ) -> StorageResult<Vec<Self>> {
+        use async_bb8_diesel::AsyncRunQueryDsl;
+        use diesel::{debug_query, pg::Pg, QueryDsl};
+        use error_stack::{IntoReport, ResultExt};
+        use router_env::logger;
+
+        use super::generics::db_metrics::{track_database_call, DatabaseOperation};
+        use crate::errors::DatabaseError;
+
+        let mut query = Self::table()
+            .filter(dsl::merchant_id.eq(merchant_id.to_owned()))
+            .order(dsl::created_at.desc())
+            .into_boxed();
+
+        if let Some(report_id) = report_id {
+            query = query.filter(dsl::report_id.eq(report_id));
+        }
+
+        if let Some(connector) = connector {
+            query = query.filter(dsl::connector.eq(connector));
+        }
+
+        if let Some(match_status) = match_status {
+            query = query.filter(dsl::match_status.eq_any(match_status));
+        }
+
+        if let Some(limit) = limit {
+            query = query.limit(limit);
+        }
+
+        if let Some(offset) = offset {
+            query = query.offset(offset);
+        }
+
+        logger::debug!(query = %debug_query::<Pg, _>(&query).to_string());
+
+        track_database_call::<Self, _, _>(query.get_results_async(conn), DatabaseOperation::Filter)
+            .await
+            .into_report()
+            .change_context(DatabaseError::Others) // Query returns empty Vec when no records are found
+            .attach_printable("Error filtering settlement records by constraints")
+    }
+}
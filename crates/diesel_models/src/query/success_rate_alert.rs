@@ -0,0 +1,67 @@
+use diesel::{associations::HasTable, BoolExpressionMethods, ExpressionMethods};
+
+use super::generics;
+use crate::{
+    schema::success_rate_alert::dsl,
+    success_rate_alert::{SuccessRateAlert, SuccessRateAlertNew, SuccessRateAlertUpdateInternal},
+    PgPooledConn, StorageResult,
+};
+
+impl SuccessRateAlertNew {
+    pub async fn insert(self, conn: &PgPooledConn) -> StorageResult<SuccessRateAlert> {
+        generics::generic_insert(conn, self).await
+    }
+}
+
+impl SuccessRateAlert {
+    pub async fn find_by_merchant_id_alert_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        alert_id: &str,
+    ) -> StorageResult<Self> {
+        generics::generic_find_one::<<Self as HasTable>::Table, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::alert_id.eq(alert_id.to_owned())),
+        )
+        .await
+    }
+
+    pub async fn list_active_by_merchant_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+    ) -> StorageResult<Vec<Self>> {
+        generics::generic_filter::<<Self as HasTable>::Table, _, _, _>(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::is_active.eq(true)),
+            None,
+            None,
+            Some(dsl::created_at.desc()),
+        )
+        .await
+    }
+
+    pub async fn update_by_merchant_id_alert_id(
+        conn: &PgPooledConn,
+        merchant_id: &str,
+        alert_id: &str,
+        success_rate_alert_update: SuccessRateAlertUpdateInternal,
+    ) -> StorageResult<Self> {
+        generics::generic_update_with_unique_predicate_get_result::<
+            <Self as HasTable>::Table,
+            _,
+            _,
+            _,
+        >(
+            conn,
+            dsl::merchant_id
+                .eq(merchant_id.to_owned())
+                .and(dsl::alert_id.eq(alert_id.to_owned())),
+            success_rate_alert_update,
+        )
+        .await
+    }
+}
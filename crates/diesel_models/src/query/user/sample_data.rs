@@ -6,11 +6,11 @@ use router_env::logger;
 use crate::{
     errors,
     schema::{
-        payment_attempt::dsl as payment_attempt_dsl, payment_intent::dsl as payment_intent_dsl,
-        refund::dsl as refund_dsl,
+        dispute::dsl as dispute_dsl, payment_attempt::dsl as payment_attempt_dsl,
+        payment_intent::dsl as payment_intent_dsl, refund::dsl as refund_dsl,
     },
-    user::sample_data::PaymentAttemptBatchNew,
-    PaymentAttempt, PaymentIntent, PaymentIntentNew, PgPooledConn, Refund, RefundNew,
+    user::sample_data::{DisputeBatchNew, PaymentAttemptBatchNew},
+    Dispute, PaymentAttempt, PaymentIntent, PaymentIntentNew, PgPooledConn, Refund, RefundNew,
     StorageResult,
 };
 
@@ -61,6 +61,22 @@ pub async fn insert_refunds(
         .attach_printable("Error while inserting refunds")
 }
 
+pub async fn insert_disputes(
+    conn: &PgPooledConn,
+    batch: Vec<DisputeBatchNew>,
+) -> StorageResult<Vec<Dispute>> {
+    let query = diesel::insert_into(<Dispute>::table()).values(batch);
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg,_>(&query).to_string());
+
+    query
+        .get_results_async(conn)
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while inserting disputes")
+}
+
 pub async fn delete_payment_intents(
     conn: &PgPooledConn,
     merchant_id: &str,
@@ -137,3 +153,21 @@ pub async fn delete_refunds(conn: &PgPooledConn, merchant_id: &str) -> StorageRe
             _ => Ok(result),
         })
 }
+
+pub async fn delete_disputes(
+    conn: &PgPooledConn,
+    merchant_id: &str,
+) -> StorageResult<Vec<Dispute>> {
+    let query = diesel::delete(<Dispute>::table())
+        .filter(dispute_dsl::merchant_id.eq(merchant_id.to_owned()))
+        .filter(dispute_dsl::payment_id.like("test_%"));
+
+    logger::debug!(query = %debug_query::<diesel::pg::Pg,_>(&query).to_string());
+
+    query
+        .get_results_async(conn)
+        .await
+        .into_report()
+        .change_context(errors::DatabaseError::Others)
+        .attach_printable("Error while deleting disputes")
+}
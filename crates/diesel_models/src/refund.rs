@@ -39,6 +39,10 @@ pub struct Refund {
     pub profile_id: Option<String>,
     pub updated_by: String,
     pub merchant_connector_id: Option<String>,
+    pub refund_reason_code: Option<storage_enums::RefundReasonCode>,
+    pub payout_id: Option<String>,
+    pub retry_count: Option<i16>,
+    pub retry_status: Option<storage_enums::RefundRetryStatus>,
 }
 
 #[derive(
@@ -81,6 +85,10 @@ pub struct RefundNew {
     pub profile_id: Option<String>,
     pub updated_by: String,
     pub merchant_connector_id: Option<String>,
+    pub refund_reason_code: Option<storage_enums::RefundReasonCode>,
+    pub payout_id: Option<String>,
+    pub retry_count: Option<i16>,
+    pub retry_status: Option<storage_enums::RefundRetryStatus>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -91,6 +99,7 @@ pub enum RefundUpdate {
         sent_to_gateway: bool,
         refund_error_message: Option<String>,
         refund_arn: String,
+        retry_status: Option<storage_enums::RefundRetryStatus>,
         updated_by: String,
     },
     MetadataAndReasonUpdate {
@@ -110,6 +119,21 @@ pub enum RefundUpdate {
         refund_error_code: Option<String>,
         updated_by: String,
     },
+    PayoutUpdate {
+        payout_id: String,
+        refund_status: storage_enums::RefundStatus,
+        refund_error_message: Option<String>,
+        refund_error_code: Option<String>,
+        updated_by: String,
+    },
+    RetryUpdate {
+        refund_status: storage_enums::RefundStatus,
+        retry_count: Option<i16>,
+        retry_status: storage_enums::RefundRetryStatus,
+        refund_error_message: Option<String>,
+        refund_error_code: Option<String>,
+        updated_by: String,
+    },
 }
 
 #[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
@@ -124,6 +148,9 @@ pub struct RefundUpdateInternal {
     refund_reason: Option<String>,
     refund_error_code: Option<String>,
     updated_by: String,
+    payout_id: Option<String>,
+    retry_count: Option<i16>,
+    retry_status: Option<storage_enums::RefundRetryStatus>,
 }
 
 impl RefundUpdateInternal {
@@ -138,6 +165,9 @@ impl RefundUpdateInternal {
             refund_reason: self.refund_reason,
             refund_error_code: self.refund_error_code,
             updated_by: self.updated_by,
+            payout_id: self.payout_id,
+            retry_count: self.retry_count,
+            retry_status: self.retry_status,
             ..source
         }
     }
@@ -152,6 +182,7 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 sent_to_gateway,
                 refund_error_message,
                 refund_arn,
+                retry_status,
                 updated_by,
             } => Self {
                 connector_refund_id: Some(connector_refund_id),
@@ -159,6 +190,7 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 sent_to_gateway: Some(sent_to_gateway),
                 refund_error_message,
                 refund_arn: Some(refund_arn),
+                retry_status,
                 updated_by,
                 ..Default::default()
             },
@@ -196,6 +228,37 @@ impl From<RefundUpdate> for RefundUpdateInternal {
                 updated_by,
                 ..Default::default()
             },
+            RefundUpdate::PayoutUpdate {
+                payout_id,
+                refund_status,
+                refund_error_message,
+                refund_error_code,
+                updated_by,
+            } => Self {
+                payout_id: Some(payout_id),
+                refund_status: Some(refund_status),
+                sent_to_gateway: Some(true),
+                refund_error_message,
+                refund_error_code,
+                updated_by,
+                ..Default::default()
+            },
+            RefundUpdate::RetryUpdate {
+                refund_status,
+                retry_count,
+                retry_status,
+                refund_error_message,
+                refund_error_code,
+                updated_by,
+            } => Self {
+                refund_status: Some(refund_status),
+                retry_count,
+                retry_status: Some(retry_status),
+                refund_error_message,
+                refund_error_code,
+                updated_by,
+                ..Default::default()
+            },
         }
     }
 }
@@ -212,6 +275,9 @@ impl RefundUpdate {
             refund_reason,
             refund_error_code,
             updated_by,
+            payout_id,
+            retry_count,
+            retry_status,
         } = self.into();
         Refund {
             connector_refund_id: connector_refund_id.or(source.connector_refund_id),
@@ -223,6 +289,9 @@ impl RefundUpdate {
             metadata: metadata.or(source.metadata),
             refund_reason: refund_reason.or(source.refund_reason),
             updated_by,
+            payout_id: payout_id.or(source.payout_id),
+            retry_count: retry_count.or(source.retry_count),
+            retry_status: retry_status.or(source.retry_status),
             ..source
         }
     }
@@ -0,0 +1,54 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::report_run};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = report_run)]
+pub struct ReportRunNew {
+    pub run_id: String,
+    pub schedule_id: String,
+    pub merchant_id: String,
+    pub report_type: storage_enums::ReportType,
+    pub period_start: PrimitiveDateTime,
+    pub period_end: PrimitiveDateTime,
+    pub status: storage_enums::ReportRunStatus,
+    pub file_id: Option<String>,
+    pub row_count: Option<i32>,
+    pub error_message: Option<String>,
+    pub delivered_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, serde::Serialize, serde::Deserialize)]
+#[diesel(table_name = report_run, primary_key(run_id))]
+pub struct ReportRun {
+    pub run_id: String,
+    pub schedule_id: String,
+    pub merchant_id: String,
+    pub report_type: storage_enums::ReportType,
+    /// The start of the period covered by the report, inclusive
+    pub period_start: PrimitiveDateTime,
+    /// The end of the period covered by the report, exclusive
+    pub period_end: PrimitiveDateTime,
+    pub status: storage_enums::ReportRunStatus,
+    /// The file the generated report is stored in
+    pub file_id: Option<String>,
+    pub row_count: Option<i32>,
+    pub error_message: Option<String>,
+    pub delivered_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = report_run)]
+pub struct ReportRunUpdateInternal {
+    pub status: storage_enums::ReportRunStatus,
+    pub file_id: Option<String>,
+    pub row_count: Option<i32>,
+    pub error_message: Option<String>,
+    pub delivered_at: Option<PrimitiveDateTime>,
+    pub modified_at: PrimitiveDateTime,
+}
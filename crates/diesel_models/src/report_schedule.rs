@@ -0,0 +1,49 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{encryption::Encryption, enums as storage_enums, schema::report_schedule};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = report_schedule)]
+pub struct ReportScheduleNew {
+    pub schedule_id: String,
+    pub merchant_id: String,
+    pub report_type: storage_enums::ReportType,
+    pub frequency: storage_enums::ReportFrequency,
+    pub delivery_method: storage_enums::ReportDeliveryMethod,
+    pub delivery_config: Encryption,
+    pub is_active: bool,
+    pub next_run_at: PrimitiveDateTime,
+    pub last_run_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, serde::Serialize, serde::Deserialize)]
+#[diesel(table_name = report_schedule, primary_key(schedule_id))]
+pub struct ReportSchedule {
+    pub schedule_id: String,
+    pub merchant_id: String,
+    pub report_type: storage_enums::ReportType,
+    pub frequency: storage_enums::ReportFrequency,
+    pub delivery_method: storage_enums::ReportDeliveryMethod,
+    /// The recipients or the SFTP server the report is delivered to, encrypted with the key of
+    /// the merchant
+    pub delivery_config: Encryption,
+    pub is_active: bool,
+    /// The end of the period covered by the next run of the report, the run is scheduled at
+    /// this time
+    pub next_run_at: PrimitiveDateTime,
+    pub last_run_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = report_schedule)]
+pub struct ReportScheduleUpdateInternal {
+    pub is_active: Option<bool>,
+    pub next_run_at: Option<PrimitiveDateTime>,
+    pub last_run_at: Option<PrimitiveDateTime>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
@@ -32,6 +32,9 @@ diesel::table! {
         #[max_length = 32]
         updated_by -> Varchar,
         email -> Nullable<Bytea>,
+        normalized_address -> Nullable<Bytea>,
+        #[max_length = 32]
+        address_validation_status -> Nullable<Varchar>,
     }
 }
 
@@ -55,6 +58,33 @@ diesel::table! {
         created_at -> Timestamp,
         expires_at -> Nullable<Timestamp>,
         last_used -> Nullable<Timestamp>,
+        #[max_length = 64]
+        profile_id -> Nullable<Varchar>,
+        scopes -> Nullable<Array<Nullable<Text>>>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    audit_log (audit_id) {
+        #[max_length = 64]
+        audit_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        operation -> Varchar,
+        #[max_length = 64]
+        entity_id -> Varchar,
+        #[max_length = 32]
+        actor_type -> Varchar,
+        #[max_length = 64]
+        actor_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        request_id -> Nullable<Varchar>,
+        changes -> Jsonb,
+        created_at -> Timestamp,
     }
 }
 
@@ -111,6 +141,9 @@ diesel::table! {
         acs_trans_id -> Nullable<Varchar>,
         three_dsserver_trans_id -> Nullable<Varchar>,
         acs_signed_content -> Nullable<Varchar>,
+        #[max_length = 64]
+        previous_authentication_id -> Nullable<Varchar>,
+        three_ds_method_completed -> Nullable<Bool>,
     }
 }
 
@@ -187,6 +220,9 @@ diesel::table! {
         payment_link_config -> Nullable<Jsonb>,
         session_expiry -> Nullable<Int8>,
         authentication_connector_details -> Nullable<Jsonb>,
+        card_verification_policy -> Nullable<Jsonb>,
+        #[max_length = 32]
+        partial_authorization_action -> Nullable<Varchar>,
     }
 }
 
@@ -286,6 +322,9 @@ diesel::table! {
         address_id -> Nullable<Varchar>,
         #[max_length = 64]
         default_payment_method_id -> Nullable<Varchar>,
+        #[max_length = 16]
+        document_type -> Nullable<Varchar>,
+        document_number -> Nullable<Bytea>,
     }
 }
 
@@ -353,6 +392,8 @@ diesel::table! {
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
         dispute_amount -> Int8,
+        #[max_length = 32]
+        representment_status -> Nullable<Varchar>,
     }
 }
 
@@ -382,6 +423,8 @@ diesel::table! {
         request -> Nullable<Bytea>,
         response -> Nullable<Bytea>,
         delivery_attempt -> Nullable<WebhookDeliveryAttempt>,
+        #[max_length = 32]
+        delivery_status -> Nullable<Varchar>,
     }
 }
 
@@ -446,6 +489,19 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    fx_rate (rate_date) {
+        rate_date -> Date,
+        base_currency -> Currency,
+        rates -> Jsonb,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -580,6 +636,12 @@ diesel::table! {
         original_payment_id -> Nullable<Varchar>,
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
+        max_amount_per_charge -> Nullable<Int8>,
+        max_amount_per_period -> Nullable<Int8>,
+        #[max_length = 32]
+        limit_period -> Nullable<Varchar>,
+        period_amount_captured -> Nullable<Int8>,
+        period_start -> Nullable<Timestamp>,
     }
 }
 
@@ -623,6 +685,7 @@ diesel::table! {
         default_profile -> Nullable<Varchar>,
         recon_status -> ReconStatus,
         payment_link_config -> Nullable<Jsonb>,
+        deleted_at -> Nullable<Timestamp>,
     }
 }
 
@@ -768,6 +831,32 @@ diesel::table! {
         fingerprint_id -> Nullable<Varchar>,
         #[max_length = 64]
         payment_method_billing_address_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        card_network -> Nullable<Varchar>,
+        #[max_length = 64]
+        card_type -> Nullable<Varchar>,
+        #[max_length = 64]
+        card_issuing_country -> Nullable<Varchar>,
+        #[max_length = 32]
+        three_ds_decision -> Nullable<Varchar>,
+        #[max_length = 32]
+        sca_exemption -> Nullable<Varchar>,
+        #[max_length = 128]
+        card_fingerprint -> Nullable<Varchar>,
+        #[max_length = 32]
+        payment_method_selection -> Nullable<Varchar>,
+        settlement_currency -> Nullable<Currency>,
+        settlement_amount -> Nullable<Int8>,
+        #[max_length = 32]
+        fx_rate -> Nullable<Varchar>,
+        #[max_length = 8]
+        avs_result_code -> Nullable<Varchar>,
+        #[max_length = 32]
+        avs_result -> Nullable<Varchar>,
+        #[max_length = 8]
+        cvv_result_code -> Nullable<Varchar>,
+        #[max_length = 32]
+        cvv_result -> Nullable<Varchar>,
     }
 }
 
@@ -913,6 +1002,14 @@ diesel::table! {
         customer_acceptance -> Nullable<Jsonb>,
         #[max_length = 64]
         status -> Varchar,
+        #[max_length = 128]
+        card_fingerprint -> Nullable<Varchar>,
+        #[max_length = 128]
+        network_token_reference_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        network_token_locker_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        network_token_requestor_id -> Nullable<Varchar>,
     }
 }
 
@@ -1065,6 +1162,64 @@ diesel::table! {
         updated_by -> Varchar,
         #[max_length = 32]
         merchant_connector_id -> Nullable<Varchar>,
+        #[max_length = 32]
+        refund_reason_code -> Nullable<Varchar>,
+        #[max_length = 64]
+        payout_id -> Nullable<Varchar>,
+        retry_count -> Nullable<Int2>,
+        #[max_length = 32]
+        retry_status -> Nullable<Varchar>,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    report_run (run_id) {
+        #[max_length = 64]
+        run_id -> Varchar,
+        #[max_length = 64]
+        schedule_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 16]
+        report_type -> Varchar,
+        period_start -> Timestamp,
+        period_end -> Timestamp,
+        #[max_length = 16]
+        status -> Varchar,
+        #[max_length = 64]
+        file_id -> Nullable<Varchar>,
+        row_count -> Nullable<Int4>,
+        error_message -> Nullable<Text>,
+        delivered_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    report_schedule (schedule_id) {
+        #[max_length = 64]
+        schedule_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 16]
+        report_type -> Varchar,
+        #[max_length = 16]
+        frequency -> Varchar,
+        #[max_length = 16]
+        delivery_method -> Varchar,
+        delivery_config -> Bytea,
+        is_active -> Bool,
+        next_run_at -> Timestamp,
+        last_run_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
     }
 }
 
@@ -1134,6 +1289,66 @@ diesel::table! {
     }
 }
 
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    settlement_record (record_id) {
+        #[max_length = 64]
+        record_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        report_id -> Varchar,
+        #[max_length = 64]
+        connector -> Varchar,
+        #[max_length = 16]
+        record_type -> Varchar,
+        #[max_length = 128]
+        connector_transaction_id -> Varchar,
+        currency -> Currency,
+        gross_amount -> Int8,
+        fee_amount -> Int8,
+        net_amount -> Int8,
+        settled_at -> Nullable<Timestamp>,
+        #[max_length = 64]
+        payment_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        attempt_id -> Nullable<Varchar>,
+        #[max_length = 64]
+        refund_id -> Nullable<Varchar>,
+        expected_amount -> Nullable<Int8>,
+        #[max_length = 32]
+        match_status -> Varchar,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
+diesel::table! {
+    use diesel::sql_types::*;
+    use crate::enums::diesel_exports::*;
+
+    success_rate_alert (alert_id) {
+        #[max_length = 64]
+        alert_id -> Varchar,
+        #[max_length = 64]
+        merchant_id -> Varchar,
+        #[max_length = 64]
+        connector -> Nullable<Varchar>,
+        evaluation_window_in_mins -> Int4,
+        baseline_window_in_mins -> Int4,
+        drop_threshold -> Float8,
+        min_payment_count -> Int4,
+        email_recipients -> Nullable<Bytea>,
+        is_active -> Bool,
+        triggered_drops -> Jsonb,
+        last_evaluated_at -> Nullable<Timestamp>,
+        created_at -> Timestamp,
+        modified_at -> Timestamp,
+    }
+}
+
 diesel::table! {
     use diesel::sql_types::*;
     use crate::enums::diesel_exports::*;
@@ -1183,6 +1398,7 @@ diesel::table! {
 diesel::allow_tables_to_appear_in_same_query!(
     address,
     api_keys,
+    audit_log,
     authentication,
     blocklist,
     blocklist_fingerprint,
@@ -1197,6 +1413,7 @@ diesel::allow_tables_to_appear_in_same_query!(
     events,
     file_metadata,
     fraud_check,
+    fx_rate,
     gateway_status_map,
     incremental_authorization,
     locker_mock_up,
@@ -1213,9 +1430,13 @@ diesel::allow_tables_to_appear_in_same_query!(
     payouts,
     process_tracker,
     refund,
+    report_run,
+    report_schedule,
     reverse_lookup,
     roles,
     routing_algorithm,
+    settlement_record,
+    success_rate_alert,
     user_roles,
     users,
 );
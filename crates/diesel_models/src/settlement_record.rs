@@ -0,0 +1,71 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{enums as storage_enums, schema::settlement_record};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = settlement_record)]
+pub struct SettlementRecordNew {
+    pub record_id: String,
+    pub merchant_id: String,
+    pub report_id: String,
+    pub connector: String,
+    pub record_type: storage_enums::SettlementRecordType,
+    pub connector_transaction_id: String,
+    pub currency: storage_enums::Currency,
+    pub gross_amount: i64,
+    pub fee_amount: i64,
+    pub net_amount: i64,
+    pub settled_at: Option<PrimitiveDateTime>,
+    pub payment_id: Option<String>,
+    pub attempt_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub expected_amount: Option<i64>,
+    pub match_status: storage_enums::SettlementMatchStatus,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, serde::Serialize, serde::Deserialize)]
+#[diesel(table_name = settlement_record, primary_key(record_id))]
+pub struct SettlementRecord {
+    pub record_id: String,
+    pub merchant_id: String,
+    /// The settlement report the record was last ingested from
+    pub report_id: String,
+    pub connector: String,
+    pub record_type: storage_enums::SettlementRecordType,
+    /// The connector transaction ID of the payment attempt, or the connector refund ID of the
+    /// refund
+    pub connector_transaction_id: String,
+    pub currency: storage_enums::Currency,
+    pub gross_amount: i64,
+    pub fee_amount: i64,
+    pub net_amount: i64,
+    pub settled_at: Option<PrimitiveDateTime>,
+    pub payment_id: Option<String>,
+    pub attempt_id: Option<String>,
+    pub refund_id: Option<String>,
+    /// The amount of the matched payment attempt or refund
+    pub expected_amount: Option<i64>,
+    pub match_status: storage_enums::SettlementMatchStatus,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = settlement_record)]
+pub struct SettlementRecordUpdateInternal {
+    pub report_id: String,
+    pub currency: storage_enums::Currency,
+    pub gross_amount: i64,
+    pub fee_amount: i64,
+    pub net_amount: i64,
+    pub settled_at: Option<PrimitiveDateTime>,
+    pub payment_id: Option<String>,
+    pub attempt_id: Option<String>,
+    pub refund_id: Option<String>,
+    pub expected_amount: Option<i64>,
+    pub match_status: storage_enums::SettlementMatchStatus,
+    pub modified_at: PrimitiveDateTime,
+}
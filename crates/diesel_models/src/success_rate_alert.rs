@@ -0,0 +1,55 @@
+use diesel::{AsChangeset, Identifiable, Insertable, Queryable};
+use time::PrimitiveDateTime;
+
+use crate::{encryption::Encryption, schema::success_rate_alert};
+
+#[derive(Clone, Debug, Insertable, router_derive::DebugAsDisplay)]
+#[diesel(table_name = success_rate_alert)]
+pub struct SuccessRateAlertNew {
+    pub alert_id: String,
+    pub merchant_id: String,
+    pub connector: Option<String>,
+    pub evaluation_window_in_mins: i32,
+    pub baseline_window_in_mins: i32,
+    pub drop_threshold: f64,
+    pub min_payment_count: i32,
+    pub email_recipients: Option<Encryption>,
+    pub is_active: bool,
+    pub triggered_drops: serde_json::Value,
+    pub last_evaluated_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Identifiable, Queryable, serde::Serialize, serde::Deserialize)]
+#[diesel(table_name = success_rate_alert, primary_key(alert_id))]
+pub struct SuccessRateAlert {
+    pub alert_id: String,
+    pub merchant_id: String,
+    /// The connector evaluated by the alert, all the connectors of the merchant are evaluated
+    /// when not set
+    pub connector: Option<String>,
+    pub evaluation_window_in_mins: i32,
+    pub baseline_window_in_mins: i32,
+    /// Drop of the success rate from the baseline, in percentage points, at which the alert fires
+    pub drop_threshold: f64,
+    pub min_payment_count: i32,
+    /// The email addresses notified when the alert fires, encrypted with the key of the merchant
+    pub email_recipients: Option<Encryption>,
+    pub is_active: bool,
+    /// The latest drop detected for each connector, a connector fires the alert again only after
+    /// its latest drop recovered
+    pub triggered_drops: serde_json::Value,
+    pub last_evaluated_at: Option<PrimitiveDateTime>,
+    pub created_at: PrimitiveDateTime,
+    pub modified_at: PrimitiveDateTime,
+}
+
+#[derive(Clone, Debug, Default, AsChangeset, router_derive::DebugAsDisplay)]
+#[diesel(table_name = success_rate_alert)]
+pub struct SuccessRateAlertUpdateInternal {
+    pub is_active: Option<bool>,
+    pub triggered_drops: Option<serde_json::Value>,
+    pub last_evaluated_at: Option<PrimitiveDateTime>,
+    pub modified_at: Option<PrimitiveDateTime>,
+}
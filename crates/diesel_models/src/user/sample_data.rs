@@ -1,14 +1,15 @@
 use common_enums::{
-    AttemptStatus, AuthenticationType, CaptureMethod, Currency, PaymentExperience, PaymentMethod,
-    PaymentMethodType,
+    AttemptStatus, AuthenticationType, CaptureMethod, CardCheckResult, Currency, DisputeStage,
+    DisputeStatus, PaymentExperience, PaymentMethod, PaymentMethodSelection, PaymentMethodType,
+    ScaExemptionType, ThreeDsDecision,
 };
 use serde::{Deserialize, Serialize};
 use time::PrimitiveDateTime;
 
 use crate::{
     enums::{MandateDataType, MandateDetails},
-    schema::payment_attempt,
-    PaymentAttemptNew,
+    schema::{dispute, payment_attempt},
+    DisputeNew, PaymentAttemptNew,
 };
 
 #[derive(
@@ -73,6 +74,20 @@ pub struct PaymentAttemptBatchNew {
     pub mandate_data: Option<MandateDetails>,
     pub payment_method_billing_address_id: Option<String>,
     pub fingerprint_id: Option<String>,
+    pub card_network: Option<String>,
+    pub card_type: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub three_ds_decision: Option<ThreeDsDecision>,
+    pub sca_exemption: Option<ScaExemptionType>,
+    pub card_fingerprint: Option<String>,
+    pub payment_method_selection: Option<PaymentMethodSelection>,
+    pub settlement_currency: Option<Currency>,
+    pub settlement_amount: Option<i64>,
+    pub fx_rate: Option<String>,
+    pub avs_result_code: Option<String>,
+    pub avs_result: Option<CardCheckResult>,
+    pub cvv_result_code: Option<String>,
+    pub cvv_result: Option<CardCheckResult>,
 }
 
 #[allow(dead_code)]
@@ -133,6 +148,82 @@ impl PaymentAttemptBatchNew {
             mandate_data: self.mandate_data,
             payment_method_billing_address_id: self.payment_method_billing_address_id,
             fingerprint_id: self.fingerprint_id,
+            card_network: self.card_network,
+            card_type: self.card_type,
+            card_issuing_country: self.card_issuing_country,
+            three_ds_decision: self.three_ds_decision,
+            sca_exemption: self.sca_exemption,
+            card_fingerprint: self.card_fingerprint,
+            payment_method_selection: self.payment_method_selection,
+            settlement_currency: self.settlement_currency,
+            settlement_amount: self.settlement_amount,
+            fx_rate: self.fx_rate,
+            avs_result_code: self.avs_result_code,
+            avs_result: self.avs_result,
+            cvv_result_code: self.cvv_result_code,
+            cvv_result: self.cvv_result,
+        }
+    }
+}
+
+#[derive(
+    Clone, Debug, diesel::Insertable, router_derive::DebugAsDisplay, Serialize, Deserialize,
+)]
+#[diesel(table_name = dispute)]
+pub struct DisputeBatchNew {
+    pub dispute_id: String,
+    pub amount: String,
+    pub currency: String,
+    pub dispute_stage: DisputeStage,
+    pub dispute_status: DisputeStatus,
+    pub payment_id: String,
+    pub attempt_id: String,
+    pub merchant_id: String,
+    pub connector_status: String,
+    pub connector_dispute_id: String,
+    pub connector_reason: Option<String>,
+    pub connector_reason_code: Option<String>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub challenge_required_by: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub connector_created_at: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub connector_updated_at: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub created_at: Option<PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    pub modified_at: Option<PrimitiveDateTime>,
+    pub connector: String,
+    pub profile_id: Option<String>,
+    pub merchant_connector_id: Option<String>,
+    pub dispute_amount: i64,
+}
+
+#[allow(dead_code)]
+impl DisputeBatchNew {
+    // Used to verify compatibility with DisputeTable
+    fn convert_into_normal_dispute_insert(self) -> DisputeNew {
+        DisputeNew {
+            dispute_id: self.dispute_id,
+            amount: self.amount,
+            currency: self.currency,
+            dispute_stage: self.dispute_stage,
+            dispute_status: self.dispute_status,
+            payment_id: self.payment_id,
+            attempt_id: self.attempt_id,
+            merchant_id: self.merchant_id,
+            connector_status: self.connector_status,
+            connector_dispute_id: self.connector_dispute_id,
+            connector_reason: self.connector_reason,
+            connector_reason_code: self.connector_reason_code,
+            challenge_required_by: self.challenge_required_by,
+            connector_created_at: self.connector_created_at,
+            connector_updated_at: self.connector_updated_at,
+            connector: self.connector,
+            evidence: None,
+            profile_id: self.profile_id,
+            merchant_connector_id: self.merchant_connector_id,
+            dispute_amount: self.dispute_amount,
         }
     }
 }
@@ -58,6 +58,8 @@ Never share your secret api keys. Keep them guarded and secure.
         (name = "Payments", description = "Create and manage one-time payments, recurring payments and mandates"),
         (name = "Refunds", description = "Create and manage refunds for successful payments"),
         (name = "Mandates", description = "Manage mandates"),
+        (name = "Recurring Payments", description = "Create and manage recurring payment schedules"),
+        (name = "Bulk Charges", description = "Create merchant initiated payments in bulk from a file"),
         (name = "Customers", description = "Create and manage customers"),
         (name = "Payment Methods", description = "Create and manage payment methods of customers"),
         (name = "Disputes", description = "Manage disputes"),
@@ -78,6 +80,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::payments::payments_connector_session,
         routes::payments::payments_cancel,
         routes::payments::payments_list,
+        routes::payments::payments_frm_review_queue,
         routes::payments::payments_incremental_authorization,
         routes::payment_link::payment_link_retrieve,
         routes::payments::payments_external_authentication,
@@ -93,13 +96,26 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::merchant_account::retrieve_merchant_account,
         routes::merchant_account::update_merchant_account,
         routes::merchant_account::delete_merchant_account,
+        routes::merchant_account::restore_merchant_account,
+        routes::merchant_account::export_merchant_account,
+        routes::merchant_account::rotate_card_fingerprint_key,
+        routes::merchant_account::create_token_requestor_id,
+        routes::merchant_account::list_token_requestor_ids,
+        routes::merchant_account::update_token_requestor_id,
         routes::merchant_account::merchant_account_kv_status,
+        routes::merchant_account::account_template_create,
+        routes::merchant_account::account_template_retrieve,
+        routes::merchant_account::account_template_delete,
+        routes::merchant_account::account_template_instantiate,
 
         // Routes for merchant connector account
         routes::merchant_connector_account::payment_connector_create,
+        routes::merchant_connector_account::payment_connector_bulk_provision,
         routes::merchant_connector_account::payment_connector_retrieve,
         routes::merchant_connector_account::payment_connector_list,
+        routes::merchant_connector_account::payment_connector_health,
         routes::merchant_connector_account::payment_connector_update,
+        routes::merchant_connector_account::payment_connector_webhook_secret_rotate,
         routes::merchant_connector_account::payment_connector_delete,
 
         //Routes for gsm
@@ -113,12 +129,25 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::mandates::revoke_mandate,
         routes::mandates::customers_mandates_list,
 
+        // Routes for recurring payments
+        routes::recurring_payments::create_recurring_payment_schedule,
+        routes::recurring_payments::retrieve_recurring_payment_schedule,
+        routes::recurring_payments::cancel_recurring_payment_schedule,
+
+        // Routes for bulk charges
+        routes::bulk_charges::create_bulk_charge,
+        routes::bulk_charges::retrieve_bulk_charge,
+        routes::bulk_charges::retrieve_bulk_charge_results,
+
         //Routes for customers
         routes::customers::customers_create,
         routes::customers::customers_retrieve,
         routes::customers::customers_list,
         routes::customers::customers_update,
         routes::customers::customers_delete,
+        routes::customers::customers_merge,
+        routes::customers::customers_merge_suggestions_scan,
+        routes::customers::customers_merge_suggestions_retrieve,
 
         //Routes for payment methods
         routes::payment_method::create_payment_method_api,
@@ -135,11 +164,15 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::business_profile::business_profiles_list,
         routes::business_profile::business_profiles_retrieve,
         routes::business_profile::business_profiles_update,
+        routes::business_profile::business_profile_webhook_secret_rotate,
         routes::business_profile::business_profiles_delete,
 
         // Routes for disputes
         routes::disputes::retrieve_dispute,
         routes::disputes::retrieve_disputes_list,
+        routes::disputes::retrieve_dispute_representment_rules,
+        routes::disputes::update_dispute_representment_rules,
+        routes::disputes::retrieve_dispute_review_queue,
 
         // Routes for routing
         routes::routing::routing_create_config,
@@ -152,6 +185,7 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::routing::routing_retrieve_linked_config,
         routes::routing::routing_retrieve_default_config_for_profiles,
         routes::routing::routing_update_default_config_for_profile,
+        routes::routing::routing_simulate,
 
         // Routes for blocklist
         routes::blocklist::remove_entry_from_blocklist,
@@ -172,26 +206,52 @@ Never share your secret api keys. Keep them guarded and secure.
         routes::api_keys::api_key_create,
         routes::api_keys::api_key_retrieve,
         routes::api_keys::api_key_update,
+        routes::api_keys::api_key_rotate,
         routes::api_keys::api_key_revoke,
 
         // Routes for events
         routes::webhook_events::list_initial_webhook_delivery_attempts,
         routes::webhook_events::list_webhook_delivery_attempts,
+        routes::webhook_events::retry_webhook_delivery_attempt,
+        routes::webhook_events::replay_webhook_events,
+        routes::webhook_events::retrieve_webhook_endpoint_health,
     ),
     components(schemas(
         api_models::refunds::RefundRequest,
         api_models::refunds::RefundType,
+        api_models::refunds::RefundDestination,
+        api_models::refunds::AlternateRefundDestination,
+        api_models::enums::RefundReasonCode,
+        api_models::enums::RefundRetryStatus,
+        api_models::enums::CardCheckResult,
+        api_models::enums::PartialAuthorizationAction,
         api_models::refunds::RefundResponse,
         api_models::refunds::RefundStatus,
         api_models::refunds::RefundUpdateRequest,
         api_models::admin::MerchantAccountCreate,
         api_models::admin::MerchantAccountUpdate,
         api_models::admin::MerchantAccountDeleteResponse,
+        api_models::admin::MerchantAccountDataExport,
+        api_models::admin::CardFingerprintKeyRotateRequest,
+        api_models::admin::CardFingerprintKeyResponse,
+        api_models::admin::TokenRequestorIdStatus,
+        api_models::admin::TokenRequestorIdCreateRequest,
+        api_models::admin::TokenRequestorIdUpdateRequest,
+        api_models::admin::TokenRequestorIdResponse,
         api_models::admin::MerchantConnectorDeleteResponse,
         api_models::admin::MerchantConnectorResponse,
+        api_models::admin::ConnectorHealthResponse,
+        api_models::analytics::success_rate_alerts::SuccessRateDropDetails,
+        api_models::admin::ConnectorHealthStatus,
         api_models::admin::AuthenticationConnectorDetails,
+        api_models::admin::CardVerificationPolicy,
         api_models::customers::CustomerRequest,
         api_models::customers::CustomerDeleteResponse,
+        api_models::customers::CustomerMergeRequest,
+        api_models::customers::CustomerMergeResponse,
+        api_models::customers::CustomerMatchField,
+        api_models::customers::CustomerMergeSuggestion,
+        api_models::customers::CustomerMergeSuggestionsResponse,
         api_models::payment_methods::PaymentMethodCreate,
         api_models::payment_methods::PaymentMethodResponse,
         api_models::payment_methods::PaymentMethodList,
@@ -218,15 +278,21 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::CaptureMethod,
         api_models::enums::FutureUsage,
         api_models::enums::AuthenticationType,
+        api_models::enums::ThreeDsDecision,
+        api_models::enums::ScaExemptionType,
+        api_models::enums::PaymentMethodSelection,
+        api_models::enums::CustomerDocumentType,
         api_models::enums::Connector,
         api_models::enums::PaymentMethod,
         api_models::enums::PaymentMethodIssuerCode,
         api_models::enums::MandateStatus,
+        api_models::enums::MandateLimitPeriod,
         api_models::enums::PaymentExperience,
         api_models::enums::BankNames,
         api_models::enums::CardNetwork,
         api_models::enums::DisputeStage,
         api_models::enums::DisputeStatus,
+        api_models::enums::DisputeRepresentmentStatus,
         api_models::enums::CountryAlpha2,
         api_models::enums::FieldType,
         api_models::enums::FrmAction,
@@ -238,6 +304,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::enums::ConnectorStatus,
         api_models::enums::AuthorizationStatus,
         api_models::enums::PaymentMethodStatus,
+        api_models::enums::CardVerificationStatus,
+        api_models::enums::NotificationChannelType,
+        api_models::enums::OperationalAlert,
         api_models::admin::MerchantConnectorCreate,
         api_models::admin::MerchantConnectorUpdate,
         api_models::admin::PrimaryBusinessDetails,
@@ -248,13 +317,31 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::MerchantConnectorDetailsWrap,
         api_models::admin::MerchantConnectorDetails,
         api_models::admin::MerchantConnectorWebhookDetails,
+        api_models::admin::MerchantConnectorWebhookSecretRotateRequest,
+        api_models::admin::MerchantConnectorBulkRequest,
+        api_models::admin::MerchantConnectorBulkItem,
+        api_models::admin::MerchantConnectorBulkItemStatus,
+        api_models::admin::MerchantConnectorBulkItemError,
+        api_models::admin::MerchantConnectorBulkItemResult,
+        api_models::admin::MerchantConnectorBulkResponse,
+        api_models::admin::AccountTemplateCreate,
+        api_models::admin::AccountTemplateBusinessProfile,
+        api_models::admin::AccountTemplateConnector,
+        api_models::admin::AccountTemplateResponse,
+        api_models::admin::AccountTemplateInstantiateRequest,
+        api_models::admin::AccountTemplateConnectorCredentials,
+        api_models::admin::AccountTemplateInstantiateResponse,
         api_models::admin::BusinessProfileCreate,
         api_models::admin::BusinessProfileResponse,
+        api_models::admin::BusinessProfileWebhookSecretRotateRequest,
         api_models::admin::BusinessPaymentLinkConfig,
         api_models::admin::PaymentLinkConfigRequest,
         api_models::admin::PaymentLinkConfig,
         api_models::disputes::DisputeResponse,
         api_models::disputes::DisputeResponsePaymentsRetrieve,
+        api_models::disputes::DisputeRepresentmentRules,
+        api_models::disputes::DisputeRepresentmentRule,
+        api_models::disputes::DisputeRepresentmentEvidence,
         api_models::gsm::GsmCreateRequest,
         api_models::gsm::GsmRetrieveRequest,
         api_models::gsm::GsmUpdateRequest,
@@ -362,6 +449,8 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::PaymentsCancelRequest,
         api_models::payments::PaymentListConstraints,
         api_models::payments::PaymentListResponse,
+        api_models::payments::FrmReviewQueueResponse,
+        api_models::payments::FrmReviewQueueItem,
         api_models::payments::CashappQr,
         api_models::payments::BankTransferData,
         api_models::payments::BankTransferNextStepsData,
@@ -398,6 +487,7 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::payments::ThreeDsData,
         api_models::payments::ThreeDsMethodData,
         api_models::payments::ExternalAuthenticationDetailsResponse,
+        api_models::payments::ExternalAuthenticationResponse,
         api_models::payment_methods::RequiredFieldInfo,
         api_models::payment_methods::DefaultPaymentMethod,
         api_models::payment_methods::MaskedBankDetails,
@@ -410,8 +500,19 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::mandates::MandateRevokedResponse,
         api_models::mandates::MandateResponse,
         api_models::mandates::MandateCardDetails,
+        api_models::recurring_payments::RecurringInterval,
+        api_models::recurring_payments::RecurringPaymentScheduleStatus,
+        api_models::recurring_payments::RecurringPaymentScheduleCreateRequest,
+        api_models::recurring_payments::RecurringPaymentScheduleResponse,
+        api_models::bulk_charges::BulkChargeRow,
+        api_models::bulk_charges::BulkChargeCreateRequest,
+        api_models::bulk_charges::BulkChargeStatus,
+        api_models::bulk_charges::BulkChargeRowStatus,
+        api_models::bulk_charges::BulkChargeRowResult,
+        api_models::bulk_charges::BulkChargeResponse,
         api_models::ephemeral_key::EphemeralKeyCreateResponse,
         api_models::payments::CustomerDetails,
+        api_models::payments::CustomerDocumentDetails,
         api_models::payments::GiftCardData,
         api_models::payments::GiftCardDetails,
         api_models::payments::Address,
@@ -450,12 +551,15 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::admin::ToggleKVRequest,
         api_models::admin::ToggleKVResponse,
         api_models::admin::WebhookDetails,
+        api_models::admin::NotificationChannel,
         api_models::api_keys::ApiKeyExpiration,
         api_models::api_keys::CreateApiKeyRequest,
         api_models::api_keys::CreateApiKeyResponse,
         api_models::api_keys::RetrieveApiKeyResponse,
         api_models::api_keys::RevokeApiKeyResponse,
         api_models::api_keys::UpdateApiKeyRequest,
+        api_models::api_keys::RotateApiKeyRequest,
+        api_models::enums::ApiKeyScope,
         api_models::payments::RetrievePaymentLinkRequest,
         api_models::payments::PaymentLinkResponse,
         api_models::payments::RetrievePaymentLinkResponse,
@@ -470,6 +574,9 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::routing::MerchantRoutingAlgorithm,
         api_models::routing::RoutingAlgorithmKind,
         api_models::routing::RoutingDictionary,
+        api_models::routing::RoutingSimulationRequest,
+        api_models::routing::RoutingSimulationPaymentData,
+        api_models::routing::RoutingSimulationResponse,
         api_models::routing::RoutingAlgorithm,
         api_models::routing::StraightThroughAlgorithm,
         api_models::routing::ConnectorVolumeSplit,
@@ -492,10 +599,14 @@ Never share your secret api keys. Keep them guarded and secure.
         api_models::blocklist::ListBlocklistQuery,
         api_models::enums::BlocklistDataKind,
         api_models::webhook_events::EventListItemResponse,
+        api_models::webhook_events::EventReplayRequest,
         api_models::webhook_events::EventRetrieveResponse,
         api_models::webhook_events::OutgoingWebhookRequestContent,
         api_models::webhook_events::OutgoingWebhookResponseContent,
+        api_models::webhook_events::WebhookEndpointHealthResponse,
+        api_models::webhook_events::WebhookEndpointHealthStatus,
         api_models::enums::WebhookDeliveryAttempt,
+        api_models::enums::WebhookDeliveryStatus,
     )),
     modifiers(&SecurityAddon)
 )]
@@ -2,6 +2,7 @@
 
 pub mod api_keys;
 pub mod blocklist;
+pub mod bulk_charges;
 pub mod business_profile;
 pub mod customers;
 pub mod disputes;
@@ -13,6 +14,7 @@ pub mod payment_link;
 pub mod payment_method;
 pub mod payments;
 pub mod payouts;
+pub mod recurring_payments;
 pub mod refunds;
 pub mod routing;
 pub mod webhook_events;
@@ -58,6 +58,31 @@ pub async fn api_key_retrieve() {}
 )]
 pub async fn api_key_update() {}
 
+/// API Key - Rotate
+///
+/// Create a new API Key replacing the specified API Key, with the same name, description and
+/// restrictions. The specified API Key remains active for the grace period, giving time to replace
+/// it with the new API Key. The plaintext API Key will be displayed only once on creation, so
+/// ensure you store it securely.
+#[utoipa::path(
+    post,
+    path = "/api_keys/{merchant_id}/{key_id}/rotate",
+    request_body = RotateApiKeyRequest,
+    params (
+        ("merchant_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("key_id" = String, Path, description = "The unique identifier for the API Key")
+    ),
+    responses(
+        (status = 200, description = "API Key rotated", body = CreateApiKeyResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "API Key not found")
+    ),
+    tag = "API Key",
+    operation_id = "Rotate an API Key",
+    security(("admin_api_key" = []))
+)]
+pub async fn api_key_rotate() {}
+
 /// API Key - Revoke
 ///
 /// Revoke the specified API Key. Once revoked, the API Key can no longer be used for
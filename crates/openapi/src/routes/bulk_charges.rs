@@ -0,0 +1,55 @@
+/// Bulk Charges - Create
+///
+/// Creates merchant initiated payments for every row of a bulk charge file
+#[utoipa::path(
+    post,
+    path = "/bulk_charges",
+    request_body = BulkChargeCreateRequest,
+    responses(
+        (status = 200, description = "Bulk charge created", body = BulkChargeResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Bulk Charges",
+    operation_id = "Create a Bulk Charge",
+    security(("api_key" = []))
+)]
+pub async fn create_bulk_charge() {}
+
+/// Bulk Charges - Retrieve
+///
+/// Retrieves a bulk charge along with the progress of its rows
+#[utoipa::path(
+    get,
+    path = "/bulk_charges/{batch_id}",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the bulk charge")
+    ),
+    responses(
+        (status = 200, description = "Bulk charge retrieved", body = BulkChargeResponse),
+        (status = 404, description = "Bulk charge does not exist in our records")
+    ),
+    tag = "Bulk Charges",
+    operation_id = "Retrieve a Bulk Charge",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_bulk_charge() {}
+
+/// Bulk Charges - Retrieve Results
+///
+/// Retrieves the result file of a bulk charge in CSV format, with the payment created for every
+/// row processed so far
+#[utoipa::path(
+    get,
+    path = "/bulk_charges/{batch_id}/results",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the bulk charge")
+    ),
+    responses(
+        (status = 200, description = "Result file of the bulk charge", content_type = "text/csv"),
+        (status = 404, description = "Bulk charge does not exist in our records")
+    ),
+    tag = "Bulk Charges",
+    operation_id = "Retrieve the Results of a Bulk Charge",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_bulk_charge_results() {}
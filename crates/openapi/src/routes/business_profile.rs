@@ -83,6 +83,27 @@ pub async fn business_profiles_list() {}
 )]
 pub async fn business_profiles_update() {}
 
+/// Business Profile - Rotate Webhook Secret
+///
+/// Rotate the secret used for signing the outgoing webhooks of a *business profile*. Outgoing webhooks continue to be signed with the previous secret as well for the overlap window, so that merchants can switch to the new secret without rejecting webhooks.
+#[utoipa::path(
+    post,
+    path = "/account/{account_id}/business_profile/{profile_id}/webhook_secret/rotate",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("profile_id" = String, Path, description = "The unique identifier for the business profile")
+    ),
+    request_body = BusinessProfileWebhookSecretRotateRequest,
+    responses(
+        (status = 200, description = "Business Profile Webhook Secret Rotated", body = BusinessProfileResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Business Profile",
+    operation_id = "Rotate the Webhook Secret of a Business Profile",
+    security(("admin_api_key" = []))
+)]
+pub async fn business_profile_webhook_secret_rotate() {}
+
 /// Business Profile - Delete
 ///
 /// Delete the *business profile*
@@ -100,3 +100,56 @@ pub async fn customers_delete() {}
     security(("api_key" = []))
 )]
 pub async fn customers_list() {}
+
+/// Customers - Merge
+///
+/// Merges duplicate customers into a customer. The payment methods, mandates and payments of the
+/// duplicate customers are moved to the customer and the duplicate customers are deleted. Details
+/// missing on the customer are taken from the duplicate customers, and cards the customer already
+/// has saved are deleted unless they are used by mandates.
+#[utoipa::path(
+    post,
+    path = "/customers/merge",
+    request_body = CustomerMergeRequest,
+    responses(
+        (status = 200, description = "Customers merged", body = CustomerMergeResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Customer was not found")
+    ),
+    tag = "Customers",
+    operation_id = "Merge Customers",
+    security(("api_key" = []))
+)]
+pub async fn customers_merge() {}
+
+/// Customers - Scan for Duplicates
+///
+/// Schedules a scan of the customers of the merchant for customers having the same email or phone
+/// number, which are suggested to be merged once the scan completes.
+#[utoipa::path(
+    post,
+    path = "/customers/merge/suggestions",
+    responses(
+        (status = 200, description = "Scan for duplicate customers scheduled"),
+    ),
+    tag = "Customers",
+    operation_id = "Scan for Duplicate Customers",
+    security(("api_key" = []))
+)]
+pub async fn customers_merge_suggestions_scan() {}
+
+/// Customers - Merge Suggestions
+///
+/// Retrieves the customers suggested to be merged by the last scan for duplicate customers.
+#[utoipa::path(
+    get,
+    path = "/customers/merge/suggestions",
+    responses(
+        (status = 200, description = "Customer merge suggestions retrieved", body = CustomerMergeSuggestionsResponse),
+        (status = 404, description = "No scan for duplicate customers has completed yet")
+    ),
+    tag = "Customers",
+    operation_id = "Retrieve Customer Merge Suggestions",
+    security(("api_key" = []))
+)]
+pub async fn customers_merge_suggestions_retrieve() {}
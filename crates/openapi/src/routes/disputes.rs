@@ -32,6 +32,7 @@ pub async fn retrieve_dispute() {}
         ("received_time.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the dispute received time"),
         ("received_time.lte" = Option<PrimitiveDateTime>, Query, description = "Time less than or equals to the dispute received time"),
         ("received_time.gte" = Option<PrimitiveDateTime>, Query, description = "Time greater than or equals to the dispute received time"),
+        ("representment_status" = Option<DisputeRepresentmentStatus>, Query, description = "Outcome of the automatic representment of the dispute"),
     ),
     responses(
         (status = 200, description = "The dispute list was retrieved successfully", body = Vec<DisputeResponse>),
@@ -42,3 +43,49 @@ pub async fn retrieve_dispute() {}
     security(("api_key" = []))
 )]
 pub async fn retrieve_disputes_list() {}
+
+/// Disputes - Retrieve Dispute Representment Rules
+/// Retrieves the rules used to automatically submit evidence for disputes
+#[utoipa::path(
+    get,
+    path = "/disputes/representment_rules",
+    responses(
+        (status = 200, description = "The dispute representment rules were retrieved successfully", body = DisputeRepresentmentRules),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Disputes",
+    operation_id = "Retrieve Dispute Representment Rules",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_dispute_representment_rules() {}
+
+/// Disputes - Update Dispute Representment Rules
+/// Updates the rules used to automatically submit evidence for disputes, disputes which do not match any rule are added to the review queue
+#[utoipa::path(
+    post,
+    path = "/disputes/representment_rules",
+    request_body = DisputeRepresentmentRules,
+    responses(
+        (status = 200, description = "The dispute representment rules were updated successfully", body = DisputeRepresentmentRules),
+        (status = 400, description = "Invalid representment rules")
+    ),
+    tag = "Disputes",
+    operation_id = "Update Dispute Representment Rules",
+    security(("api_key" = []))
+)]
+pub async fn update_dispute_representment_rules() {}
+
+/// Disputes - Retrieve Dispute Review Queue
+/// Lists the open disputes which could not be represented automatically and need to be reviewed
+#[utoipa::path(
+    get,
+    path = "/disputes/review_queue",
+    responses(
+        (status = 200, description = "The dispute review queue was retrieved successfully", body = Vec<DisputeResponse>),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Disputes",
+    operation_id = "Retrieve Dispute Review Queue",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_dispute_review_queue() {}
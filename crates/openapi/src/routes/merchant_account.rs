@@ -109,7 +109,7 @@ pub async fn update_merchant_account() {}
 
 /// Merchant Account - Delete
 ///
-/// Delete a *merchant* account
+/// Delete a *merchant* account. The merchant account can be restored until the end of the retention period, after which it is permanently deleted
 #[utoipa::path(
     delete,
     path = "/accounts/{account_id}",
@@ -124,6 +124,118 @@ pub async fn update_merchant_account() {}
 )]
 pub async fn delete_merchant_account() {}
 
+/// Merchant Account - Restore
+///
+/// Restore a deleted *merchant* account before the end of its retention period
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/restore",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Merchant Account Restored", body = MerchantAccountResponse),
+        (status = 400, description = "Merchant account is not deleted"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Restore a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn restore_merchant_account() {}
+
+/// Merchant Account - Export
+///
+/// Export the data of a *merchant* account, along with its business profiles, connector accounts and API keys
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/export",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Merchant Account Data Exported", body = MerchantAccountDataExport),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Export a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn export_merchant_account() {}
+
+/// Merchant Account - Rotate Card Fingerprint Key
+///
+/// Rotate the key used for generating the card fingerprints of a merchant account. Card fingerprints generated with the previous key continue to be matched for saved cards and blocklists until the end of the transition period.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/card_fingerprint_key/rotate",
+    request_body = CardFingerprintKeyRotateRequest,
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Card Fingerprint Key Rotated", body = CardFingerprintKeyResponse),
+        (status = 400, description = "Transition period of the previous key has not ended"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Rotate the Card Fingerprint Key of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn rotate_card_fingerprint_key() {}
+
+/// Merchant Account - Create Token Requestor ID
+///
+/// Register the token requestor ID assigned to a merchant account by a card scheme. Network tokens of the merchant account are provisioned and used with the token requestor ID once it is active.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/token_requestor_ids",
+    request_body = TokenRequestorIdCreateRequest,
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Token Requestor ID Created", body = TokenRequestorIdResponse),
+        (status = 400, description = "The merchant account already has a token requestor ID for the card network"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Create a Token Requestor ID of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn create_token_requestor_id() {}
+
+/// Merchant Account - List Token Requestor IDs
+///
+/// List the token requestor IDs of a merchant account, including the deactivated ones.
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/token_requestor_ids",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Token Requestor IDs Retrieved", body = Vec<TokenRequestorIdResponse>),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "List the Token Requestor IDs of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn list_token_requestor_ids() {}
+
+/// Merchant Account - Update Token Requestor ID
+///
+/// Update the status of the token requestor ID of a merchant account for a card network, as its registration with the card scheme progresses. Deactivated token requestor IDs cannot be updated.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/token_requestor_ids/{card_network}",
+    request_body = TokenRequestorIdUpdateRequest,
+    params (
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("card_network" = CardNetwork, Path, description = "The card network which assigned the token requestor ID"),
+    ),
+    responses(
+        (status = 200, description = "Token Requestor ID Updated", body = TokenRequestorIdResponse),
+        (status = 400, description = "Invalid status transition"),
+        (status = 404, description = "Token requestor ID not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Update a Token Requestor ID of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+pub async fn update_token_requestor_id() {}
+
 /// Merchant Account - KV Status
 ///
 /// Toggle KV mode for the Merchant Account
@@ -155,3 +267,73 @@ pub async fn delete_merchant_account() {}
     security(("admin_api_key" = []))
 )]
 pub async fn merchant_account_kv_status() {}
+
+/// Account Template - Create
+///
+/// Create a template of the business profiles, connectors, routing and webhook settings of merchant accounts. Templates don't hold the credentials of the connectors, which are provided when the template is instantiated.
+#[utoipa::path(
+    post,
+    path = "/accounts/templates",
+    request_body = AccountTemplateCreate,
+    responses(
+        (status = 200, description = "Account Template Created", body = AccountTemplateResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Create an Account Template",
+    security(("admin_api_key" = []))
+)]
+pub async fn account_template_create() {}
+
+/// Account Template - Retrieve
+///
+/// Retrieve an account template
+#[utoipa::path(
+    get,
+    path = "/accounts/templates/{template_id}",
+    params (("template_id" = String, Path, description = "The unique identifier for the account template")),
+    responses(
+        (status = 200, description = "Account Template Retrieved", body = AccountTemplateResponse),
+        (status = 404, description = "Account template not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve an Account Template",
+    security(("admin_api_key" = []))
+)]
+pub async fn account_template_retrieve() {}
+
+/// Account Template - Delete
+///
+/// Delete an account template. Merchant accounts already instantiated from the template are not affected.
+#[utoipa::path(
+    delete,
+    path = "/accounts/templates/{template_id}",
+    params (("template_id" = String, Path, description = "The unique identifier for the account template")),
+    responses(
+        (status = 200, description = "Account Template Deleted"),
+        (status = 404, description = "Account template not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Delete an Account Template",
+    security(("admin_api_key" = []))
+)]
+pub async fn account_template_delete() {}
+
+/// Account Template - Instantiate
+///
+/// Create a merchant account along with the business profiles and connectors of an account template, with the credentials of each connector of the template provided against its connector label.
+#[utoipa::path(
+    post,
+    path = "/accounts/templates/{template_id}/instantiate",
+    request_body = AccountTemplateInstantiateRequest,
+    params (("template_id" = String, Path, description = "The unique identifier for the account template")),
+    responses(
+        (status = 200, description = "Merchant Account Created from the Account Template", body = AccountTemplateInstantiateResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Account template not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Instantiate an Account Template",
+    security(("admin_api_key" = []))
+)]
+pub async fn account_template_instantiate() {}
@@ -60,6 +60,27 @@
 )]
 pub async fn payment_connector_create() {}
 
+/// Merchant Connector - Bulk Create or Update
+///
+/// Create and update several Merchant Connectors of the merchant account at once, across business profiles. Each item is processed independently, and its result is returned in the order of the request. With `dry_run` set, the items are only validated.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/connectors/bulk",
+    request_body = MerchantConnectorBulkRequest,
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account")
+    ),
+    responses(
+        (status = 200, description = "Merchant Connectors Processed", body = MerchantConnectorBulkResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Create or Update Merchant Connectors in Bulk",
+    security(("admin_api_key" = []))
+)]
+pub async fn payment_connector_bulk_provision() {}
+
 /// Merchant Connector - Retrieve
 ///
 /// Retrieves details of a Connector account
@@ -101,6 +122,26 @@ pub async fn payment_connector_retrieve() {}
 )]
 pub async fn payment_connector_list() {}
 
+/// Merchant Connector - Health
+///
+/// List the health of the Merchant Connectors, derived from the rate of errors and timeouts in their recent responses
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/connectors/health",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+    ),
+    responses(
+        (status = 200, description = "Merchant Connectors health retrieved successfully", body = Vec<ConnectorHealthResponse>),
+        (status = 404, description = "Merchant Account does not exist in records"),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "List the health of all Merchant Connectors",
+    security(("admin_api_key" = []))
+)]
+pub async fn payment_connector_health() {}
+
 /// Merchant Connector - Update
 ///
 /// To update an existing Merchant Connector account. Helpful in enabling/disabling different payment methods and other settings for the connector
@@ -148,6 +189,28 @@ pub async fn payment_connector_list() {}
 )]
 pub async fn payment_connector_update() {}
 
+/// Merchant Connector - Rotate Webhook Secret
+///
+/// Rotate the secret used for verifying incoming webhooks of a Merchant Connector. The previous secret continues to be accepted for the overlap window, so that no webhooks are dropped during the rotation.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/connectors/{connector_id}/webhook_secret/rotate",
+    request_body = MerchantConnectorWebhookSecretRotateRequest,
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = i32, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Merchant Connector Webhook Secret Rotated", body = MerchantConnectorResponse),
+        (status = 404, description = "Merchant Connector does not exist in records"),
+        (status = 401, description = "Unauthorized request")
+    ),
+   tag = "Merchant Connector Account",
+   operation_id = "Rotate the Webhook Secret of a Merchant Connector",
+   security(("admin_api_key" = []))
+)]
+pub async fn payment_connector_webhook_secret_rotate() {}
+
 /// Merchant Connector - Delete
 ///
 /// Delete or Detach a Merchant Connector from Merchant Account
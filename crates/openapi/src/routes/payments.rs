@@ -447,6 +447,26 @@ pub fn payments_cancel() {}
 )]
 pub fn payments_list() {}
 
+/// Payments - FRM Review Queue
+///
+/// To list the payments held for a manual review by the merchant after their fraud check. The payments are approved with `POST /payments/{payment_id}/approve` or rejected with `POST /payments/{payment_id}/reject`.
+#[utoipa::path(
+    get,
+    path = "/payments/frm_review_queue",
+    params(
+        ("limit" = Option<u32>, Query, description = "Limit on the number of payments to return"),
+        ("offset" = Option<u32>, Query, description = "The number of payments to skip")
+    ),
+    responses(
+        (status = 200, description = "Successfully retrieved the payments held for review", body = FrmReviewQueueResponse),
+        (status = 400, description = "Invalid limit")
+    ),
+    tag = "Payments",
+    operation_id = "List Payments held for FRM Review",
+    security(("api_key" = []))
+)]
+pub fn payments_frm_review_queue() {}
+
 /// Payments - Incremental Authorization
 ///
 /// Authorized amount for a payment can be incremented if it is in status: requires_capture
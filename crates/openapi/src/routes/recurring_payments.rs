@@ -0,0 +1,55 @@
+/// Recurring Payments - Create Schedule
+///
+/// Creates a schedule which charges the customer off-session using the given mandate
+#[utoipa::path(
+    post,
+    path = "/recurring_payments",
+    request_body = RecurringPaymentScheduleCreateRequest,
+    responses(
+        (status = 200, description = "Recurring payment schedule created", body = RecurringPaymentScheduleResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Recurring Payments",
+    operation_id = "Create a Recurring Payment Schedule",
+    security(("api_key" = []))
+)]
+pub async fn create_recurring_payment_schedule() {}
+
+/// Recurring Payments - Retrieve Schedule
+///
+/// Retrieves a recurring payment schedule along with the progress of its charges
+#[utoipa::path(
+    get,
+    path = "/recurring_payments/{schedule_id}",
+    params(
+        ("schedule_id" = String, Path, description = "The identifier for the recurring payment schedule")
+    ),
+    responses(
+        (status = 200, description = "Recurring payment schedule retrieved", body = RecurringPaymentScheduleResponse),
+        (status = 404, description = "Recurring payment schedule does not exist in our records")
+    ),
+    tag = "Recurring Payments",
+    operation_id = "Retrieve a Recurring Payment Schedule",
+    security(("api_key" = []))
+)]
+pub async fn retrieve_recurring_payment_schedule() {}
+
+/// Recurring Payments - Cancel Schedule
+///
+/// Cancels a recurring payment schedule, no further charges or dunning retries are made
+#[utoipa::path(
+    post,
+    path = "/recurring_payments/{schedule_id}/cancel",
+    params(
+        ("schedule_id" = String, Path, description = "The identifier for the recurring payment schedule")
+    ),
+    responses(
+        (status = 200, description = "Recurring payment schedule cancelled", body = RecurringPaymentScheduleResponse),
+        (status = 400, description = "Recurring payment schedule is no longer active"),
+        (status = 404, description = "Recurring payment schedule does not exist in our records")
+    ),
+    tag = "Recurring Payments",
+    operation_id = "Cancel a Recurring Payment Schedule",
+    security(("api_key" = []))
+)]
+pub async fn cancel_recurring_payment_schedule() {}
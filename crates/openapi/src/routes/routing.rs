@@ -200,3 +200,23 @@ pub async fn routing_retrieve_default_config_for_profiles() {}
    security(("api_key" = []), ("jwt_key" = []))
 )]
 pub async fn routing_update_default_config_for_profile() {}
+
+/// Routing - Simulate
+///
+/// Simulate routing for a hypothetical payment, without creating the payment
+#[utoipa::path(
+    post,
+    path = "/routing/simulate",
+    request_body = RoutingSimulationRequest,
+    responses(
+        (status = 200, description = "Successfully simulated routing", body = RoutingSimulationResponse),
+        (status = 500, description = "Internal server error"),
+        (status = 404, description = "Resource missing"),
+        (status = 400, description = "Malformed request"),
+        (status = 403, description = "Forbidden"),
+    ),
+   tag = "Routing",
+   operation_id = "Simulate routing for a payment",
+   security(("api_key" = []), ("jwt_key" = []))
+)]
+pub async fn routing_simulate() {}
@@ -34,6 +34,12 @@
             description = "The number of Events to skip when retrieving the list of Events.
                            Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit` and `offset` must be specified."
         ),
+        (
+            "delivery_status" = Option<WebhookDeliveryStatus>,
+            Query,
+            description = "Only include Events with the specified delivery status, Events whose delivery failed can be listed with the `failed` status. \
+                           Either only `object_id` must be specified, or one or more of `created_after`, `created_before`, `limit`, `offset` and `delivery_status` must be specified."
+        ),
         (
             "object_id" = Option<String>,
             Query,
@@ -68,3 +74,61 @@ pub fn list_initial_webhook_delivery_attempts() {}
     security(("admin_api_key" = []))
 )]
 pub fn list_webhook_delivery_attempts() {}
+
+/// Events - Manual Retry
+///
+/// Manually retry the delivery of the specified Event.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id_or_profile_id}/{event_id}/retry",
+    params(
+        ("merchant_id_or_profile_id" = String, Path, description = "The unique identifier for the Merchant Account or Business Profile"),
+        ("event_id" = String, Path, description = "The unique identifier for the Event"),
+    ),
+    responses(
+        (status = 200, description = "The delivery of the Event was attempted", body = EventRetrieveResponse),
+    ),
+    tag = "Event",
+    operation_id = "Manually retry the delivery of an Event",
+    security(("admin_api_key" = []))
+)]
+pub fn retry_webhook_delivery_attempt() {}
+
+/// Events - Replay
+///
+/// Replay the delivery of historical Events, either the Events with the specified identifiers, or
+/// the Events created in the specified time range, optionally of the specified event types.
+#[utoipa::path(
+    post,
+    path = "/events/{merchant_id_or_profile_id}/replay",
+    params(
+        ("merchant_id_or_profile_id" = String, Path, description = "The unique identifier for the Merchant Account or Business Profile"),
+    ),
+    request_body = EventReplayRequest,
+    responses(
+        (status = 200, description = "The delivery of the Events was scheduled", body = Vec<EventListItemResponse>),
+    ),
+    tag = "Event",
+    operation_id = "Replay the delivery of Events",
+    security(("admin_api_key" = []))
+)]
+pub fn replay_webhook_events() {}
+
+/// Events - Webhook Endpoint Health
+///
+/// Retrieve the health of the webhook endpoints of the Business Profiles, derived from the
+/// outcome of the recent webhook deliveries to them.
+#[utoipa::path(
+    get,
+    path = "/events/{merchant_id_or_profile_id}/endpoint_health",
+    params(
+        ("merchant_id_or_profile_id" = String, Path, description = "The unique identifier for the Merchant Account or Business Profile"),
+    ),
+    responses(
+        (status = 200, description = "Health of the webhook endpoints retrieved successfully", body = Vec<WebhookEndpointHealthResponse>),
+    ),
+    tag = "Event",
+    operation_id = "Retrieve the health of webhook endpoints",
+    security(("admin_api_key" = []))
+)]
+pub fn retrieve_webhook_endpoint_health() {}
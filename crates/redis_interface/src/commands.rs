@@ -15,7 +15,9 @@ use common_utils::{
 };
 use error_stack::{IntoReport, ResultExt};
 use fred::{
-    interfaces::{HashesInterface, KeysInterface, SetsInterface, StreamsInterface},
+    interfaces::{
+        HashesInterface, KeysInterface, SetsInterface, SortedSetsInterface, StreamsInterface,
+    },
     prelude::RedisErrorKind,
     types::{
         Expiration, FromRedis, MultipleIDs, MultipleKeys, MultipleOrderedPairs, MultipleStrings,
@@ -466,6 +468,49 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::JsonDeserializationFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn increment_fields_in_hash<T>(
+        &self,
+        key: &str,
+        fields_to_increment: &[(T, i64)],
+        ttl: Option<i64>,
+    ) -> CustomResult<Vec<i64>, errors::RedisError>
+    where
+        T: Debug + ToString,
+    {
+        let mut values_after_increment = Vec::with_capacity(fields_to_increment.len());
+        for (field, increment) in fields_to_increment.iter() {
+            values_after_increment.push(
+                self.pool
+                    .hincrby(key, field.to_string(), *increment)
+                    .await
+                    .into_report()
+                    .change_context(errors::RedisError::IncrementHashFieldFailed)?,
+            )
+        }
+
+        self.set_expiry(key, ttl.unwrap_or(self.config.default_hash_ttl.into()))
+            .await?;
+
+        Ok(values_after_increment)
+    }
+
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn get_multiple_hash_fields<V>(
+        &self,
+        key: &str,
+        fields: Vec<String>,
+    ) -> CustomResult<Vec<Option<V>>, errors::RedisError>
+    where
+        V: FromRedis + Unpin + Send + 'static,
+    {
+        self.pool
+            .hmget(key, fields)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::GetHashFieldFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn sadd<V>(
         &self,
@@ -483,6 +528,59 @@ impl super::RedisConnectionPool {
             .change_context(errors::RedisError::SetAddMembersFailed)
     }
 
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn zadd_with_score<V>(
+        &self,
+        key: &str,
+        score: f64,
+        member: V,
+    ) -> CustomResult<(), errors::RedisError>
+    where
+        V: TryInto<RedisValue> + Debug + Send,
+        V::Error: Into<fred::error::RedisError> + Send,
+    {
+        self.pool
+            .zadd(key, None, None, false, false, (score, member))
+            .await
+            .into_report()
+            .change_context(errors::RedisError::SortedSetAddMembersFailed)
+    }
+
+    /// Provides the members of the sorted set whose score lies between `min` and `max`, both
+    /// inclusive
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn zrange_by_score<V>(
+        &self,
+        key: &str,
+        min: f64,
+        max: f64,
+    ) -> CustomResult<Vec<V>, errors::RedisError>
+    where
+        V: FromRedis + Unpin + Send + 'static,
+    {
+        self.pool
+            .zrangebyscore(key, min, max, false, None)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::SortedSetGetMembersFailed)
+    }
+
+    /// Removes the members of the sorted set whose score lies between `min` and `max`, both
+    /// inclusive
+    #[instrument(level = "DEBUG", skip(self))]
+    pub async fn zremrange_by_score(
+        &self,
+        key: &str,
+        min: f64,
+        max: f64,
+    ) -> CustomResult<usize, errors::RedisError> {
+        self.pool
+            .zremrangebyscore(key, min, max)
+            .await
+            .into_report()
+            .change_context(errors::RedisError::SortedSetRemoveMembersFailed)
+    }
+
     #[instrument(level = "DEBUG", skip(self))]
     pub async fn stream_append_entry<F>(
         &self,
@@ -54,6 +54,14 @@ pub enum RedisError {
     SetAddMembersFailed,
     #[error("Failed to get hash field in Redis")]
     GetHashFieldFailed,
+    #[error("Failed to increment hash field in Redis")]
+    IncrementHashFieldFailed,
+    #[error("Failed to add members to sorted set in Redis")]
+    SortedSetAddMembersFailed,
+    #[error("Failed to get members of sorted set in Redis")]
+    SortedSetGetMembersFailed,
+    #[error("Failed to remove members from sorted set in Redis")]
+    SortedSetRemoveMembersFailed,
     #[error("The requested value was not found in Redis")]
     NotFound,
     #[error("Invalid RedisEntryId provided")]
@@ -0,0 +1,158 @@
+#![allow(clippy::expect_used)]
+
+//! Compares the lookups of the payment intent and its active attempt done while getting the
+//! trackers of a payments confirm call. Requires the test database of the router to be running.
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use data_models::payments::{
+    payment_attempt::PaymentAttemptInterface, payment_intent::PaymentIntentInterface,
+};
+use router::{
+    configs::settings::Settings,
+    db::StorageImpl,
+    routes::AppState,
+    services,
+    types::storage::{enums, PaymentAttemptNew, PaymentIntentNew},
+};
+use tokio::sync::oneshot;
+
+const MERCHANT_ID: &str = "bench_merchant";
+
+async fn setup_state() -> (AppState, String) {
+    let conf = Settings::new().expect("invalid settings");
+    let tx: oneshot::Sender<()> = oneshot::channel().0;
+    let api_client = Box::new(services::MockApiClient);
+    let state = Box::pin(AppState::with_storage(
+        conf,
+        StorageImpl::PostgresqlTest,
+        tx,
+        api_client,
+    ))
+    .await;
+
+    let payment_id = uuid::Uuid::new_v4().to_string();
+    let attempt_id = format!("{payment_id}_1");
+    let current_time = common_utils::date_time::now();
+
+    state
+        .store
+        .insert_payment_intent(
+            PaymentIntentNew {
+                payment_id: payment_id.clone(),
+                merchant_id: MERCHANT_ID.to_string(),
+                status: enums::IntentStatus::RequiresConfirmation,
+                amount: 6540,
+                currency: Some(enums::Currency::USD),
+                amount_captured: None,
+                customer_id: None,
+                description: None,
+                return_url: None,
+                metadata: None,
+                connector_id: None,
+                shipping_address_id: None,
+                billing_address_id: None,
+                statement_descriptor_name: None,
+                statement_descriptor_suffix: None,
+                created_at: Some(current_time),
+                modified_at: Some(current_time),
+                last_synced: None,
+                setup_future_usage: None,
+                off_session: None,
+                client_secret: None,
+                active_attempt: data_models::RemoteStorageObject::ForeignID(attempt_id.clone()),
+                business_country: None,
+                business_label: None,
+                order_details: None,
+                allowed_payment_method_types: None,
+                connector_metadata: None,
+                feature_metadata: None,
+                attempt_count: 1,
+                profile_id: None,
+                merchant_decision: None,
+                payment_link_id: None,
+                payment_confirm_source: None,
+                updated_by: enums::MerchantStorageScheme::PostgresOnly.to_string(),
+                surcharge_applicable: None,
+                request_incremental_authorization: None,
+                incremental_authorization_allowed: None,
+                authorization_count: None,
+                fingerprint_id: None,
+                session_expiry: None,
+                request_external_three_ds_authentication: None,
+            },
+            enums::MerchantStorageScheme::PostgresOnly,
+        )
+        .await
+        .expect("failed to insert the payment intent");
+
+    state
+        .store
+        .insert_payment_attempt(
+            PaymentAttemptNew {
+                payment_id: payment_id.clone(),
+                merchant_id: MERCHANT_ID.to_string(),
+                attempt_id,
+                created_at: Some(current_time),
+                modified_at: Some(current_time),
+                ..PaymentAttemptNew::default()
+            },
+            enums::MerchantStorageScheme::PostgresOnly,
+        )
+        .await
+        .expect("failed to insert the payment attempt");
+
+    (state, payment_id)
+}
+
+fn payment_confirm_trackers(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().expect("failed to build the runtime");
+    let (state, payment_id) = runtime.block_on(setup_state());
+
+    let mut group = c.benchmark_group("payment_confirm_trackers");
+
+    group.bench_function("intent_then_attempt", |b| {
+        b.to_async(&runtime).iter(|| async {
+            let payment_intent = state
+                .store
+                .find_payment_intent_by_payment_id_merchant_id(
+                    &payment_id,
+                    MERCHANT_ID,
+                    enums::MerchantStorageScheme::PostgresOnly,
+                )
+                .await
+                .expect("payment intent not found");
+            let payment_attempt = state
+                .store
+                .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                    &payment_id,
+                    MERCHANT_ID,
+                    payment_intent.active_attempt.get_id().as_str(),
+                    enums::MerchantStorageScheme::PostgresOnly,
+                )
+                .await
+                .expect("payment attempt not found");
+            black_box((payment_intent, payment_attempt))
+        })
+    });
+
+    group.bench_function("intent_with_active_attempt", |b| {
+        b.to_async(&runtime).iter(|| async {
+            black_box(
+                state
+                    .store
+                    .find_payment_intent_with_active_attempt_by_payment_id_merchant_id(
+                        &payment_id,
+                        MERCHANT_ID,
+                        enums::MerchantStorageScheme::PostgresOnly,
+                    )
+                    .await
+                    .expect("payment intent not found"),
+            )
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, payment_confirm_trackers);
+criterion_main!(benches);
@@ -3,25 +3,37 @@ pub use analytics::*;
 pub mod routes {
     use actix_web::{web, Responder, Scope};
     use analytics::{
-        api_event::api_events_core, connector_events::connector_events_core,
-        errors::AnalyticsError, lambda_utils::invoke_lambda,
-        outgoing_webhook_event::outgoing_webhook_events_core, sdk_events::sdk_events_core,
+        api_event::api_events_core,
+        connector_events::connector_events_core,
+        errors::{AnalyticsError, AnalyticsResult},
+        lambda_utils::invoke_lambda,
+        outgoing_webhook_event::outgoing_webhook_events_core,
+        reporting_currency::ReportingCurrencyRates,
+        sdk_events::sdk_events_core,
     };
     use api_models::analytics::{
+        scheduled_reports::{ReportRunListRequest, ReportScheduleCreateRequest, ReportScheduleId},
         search::{
             GetGlobalSearchRequest, GetSearchRequest, GetSearchRequestWithIndex, SearchIndex,
         },
-        GenerateReportRequest, GetApiEventFiltersRequest, GetApiEventMetricRequest,
+        success_rate_alerts::{SuccessRateAlertCreateRequest, SuccessRateAlertId},
+        ExportFormat, ExportFormatQuery, GenerateReportRequest, GetApiEventFiltersRequest,
+        GetApiEventMetricRequest, GetAuthEventMetricRequest, GetConnectorUptimeRequest,
         GetDisputeMetricRequest, GetPaymentFiltersRequest, GetPaymentMetricRequest,
-        GetRefundFilterRequest, GetRefundMetricRequest, GetSdkEventFiltersRequest,
-        GetSdkEventMetricRequest, ReportRequest,
+        GetRefundFilterRequest, GetRefundMetricRequest, GetRefundTopReasonsRequest,
+        GetSdkEventFiltersRequest, GetSdkEventMetricRequest, MetricsResponse, ReportRequest,
+        TimeRange,
     };
-    use error_stack::ResultExt;
+    use common_enums::{ApiKeyScope, Currency};
+    use diesel_models::enums::UserStatus;
+    use error_stack::{IntoReport, ResultExt};
     use router_env::AnalyticsFlow;
 
     use crate::{
-        core::api_locking,
-        db::user::UserInterface,
+        core::{
+            api_locking, currency, payments::live_activity, scheduled_reports, success_rate_alerts,
+        },
+        db::{user::UserInterface, user_role::UserRoleInterface},
         routes::AppState,
         services::{
             api,
@@ -43,9 +55,21 @@ pub mod routes {
                         web::resource("metrics/payments")
                             .route(web::post().to(get_payment_metrics)),
                     )
+                    .service(
+                        web::resource("metrics/payments/org")
+                            .route(web::post().to(get_org_payment_metrics)),
+                    )
+                    .service(
+                        web::resource("metrics/payments/connector_uptime")
+                            .route(web::post().to(get_connector_uptime)),
+                    )
                     .service(
                         web::resource("metrics/refunds").route(web::post().to(get_refunds_metrics)),
                     )
+                    .service(
+                        web::resource("metrics/refunds/top_reasons")
+                            .route(web::post().to(get_refund_top_reasons)),
+                    )
                     .service(
                         web::resource("filters/payments")
                             .route(web::post().to(get_payment_filters)),
@@ -66,6 +90,29 @@ pub mod routes {
                         web::resource("report/payments")
                             .route(web::post().to(generate_payment_report)),
                     )
+                    .service(
+                        web::resource("report/schedules")
+                            .route(web::post().to(create_report_schedule))
+                            .route(web::get().to(list_report_schedules)),
+                    )
+                    .service(
+                        web::resource("report/schedules/{schedule_id}")
+                            .route(web::delete().to(delete_report_schedule)),
+                    )
+                    .service(web::resource("report/runs").route(web::get().to(list_report_runs)))
+                    .service(
+                        web::resource("live/payments")
+                            .route(web::get().to(get_live_payment_activity)),
+                    )
+                    .service(
+                        web::resource("alerts/success_rate")
+                            .route(web::post().to(create_success_rate_alert))
+                            .route(web::get().to(list_success_rate_alerts)),
+                    )
+                    .service(
+                        web::resource("alerts/success_rate/{alert_id}")
+                            .route(web::delete().to(delete_success_rate_alert)),
+                    )
                     .service(
                         web::resource("metrics/sdk_events")
                             .route(web::post().to(get_sdk_event_metrics)),
@@ -106,6 +153,10 @@ pub mod routes {
                         web::resource("metrics/disputes")
                             .route(web::post().to(get_dispute_metrics)),
                     )
+                    .service(
+                        web::resource("metrics/auth_events")
+                            .route(web::post().to(get_auth_event_metrics)),
+                    )
             }
             route
         }
@@ -139,6 +190,7 @@ pub mod routes {
     pub async fn get_payment_metrics(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
+        export_format: web::Query<ExportFormatQuery>,
         json_payload: web::Json<[GetPaymentMetricRequest; 1]>,
     ) -> impl Responder {
         // safety: This shouldn't panic owing to the data type
@@ -148,6 +200,7 @@ pub mod routes {
             .to_vec()
             .pop()
             .expect("Couldn't get GetPaymentMetricRequest");
+        let export_format = export_format.into_inner().format;
         let flow = AnalyticsFlow::GetPaymentMetrics;
         Box::pin(api::server_wrap(
             flow,
@@ -155,13 +208,66 @@ pub mod routes {
             &req,
             payload,
             |state, auth: AuthenticationData, req| async move {
+                let reporting_currency_rates =
+                    get_reporting_currency_rates(&state, req.reporting_currency, &req.time_range)
+                        .await?;
                 analytics::payments::get_metrics(
                     &state.pool,
                     &auth.merchant_account.merchant_id,
+                    &[auth.merchant_account.merchant_id.clone()],
                     req,
+                    reporting_currency_rates,
                 )
                 .await
-                .map(ApplicationResponse::Json)
+                .and_then(|metrics| get_metrics_response(metrics, export_format))
+            },
+            auth::auth_type(
+                &auth::ScopedApiKeyAuth(ApiKeyScope::AnalyticsRead),
+                &auth::JWTAuth(Permission::Analytics),
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetPaymentMetricRequest` element.
+    pub async fn get_org_payment_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        export_format: web::Query<ExportFormatQuery>,
+        json_payload: web::Json<[GetPaymentMetricRequest; 1]>,
+    ) -> impl Responder {
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetPaymentMetricRequest");
+        let export_format = export_format.into_inner().format;
+        let flow = AnalyticsFlow::GetOrgPaymentMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, user: auth::UserFromToken, req| async move {
+                let merchant_ids = get_org_merchant_ids(&state, &user).await?;
+                let reporting_currency_rates =
+                    get_reporting_currency_rates(&state, req.reporting_currency, &req.time_range)
+                        .await?;
+                analytics::payments::get_metrics(
+                    &state.pool,
+                    &user.org_id,
+                    &merchant_ids,
+                    req,
+                    reporting_currency_rates,
+                )
+                .await
+                .and_then(|metrics| get_metrics_response(metrics, export_format))
             },
             &auth::JWTAuth(Permission::Analytics),
             api_locking::LockAction::NotApplicable,
@@ -169,12 +275,51 @@ pub mod routes {
         .await
     }
 
+    /// Resolves the merchants of the user's organization that the user holds an active role in
+    async fn get_org_merchant_ids(
+        state: &AppState,
+        user: &auth::UserFromToken,
+    ) -> AnalyticsResult<Vec<String>> {
+        let mut merchant_ids = state
+            .store
+            .list_user_roles_by_user_id(&user.user_id)
+            .await
+            .change_context(AnalyticsError::UnknownError)?
+            .into_iter()
+            .filter(|user_role| {
+                user_role.org_id == user.org_id && user_role.status == UserStatus::Active
+            })
+            .map(|user_role| user_role.merchant_id)
+            .collect::<Vec<_>>();
+        merchant_ids.sort();
+        merchant_ids.dedup();
+        Ok(merchant_ids)
+    }
+
+    /// Serializes the results of a metric query in the format requested by the client
+    fn get_metrics_response<T: serde::Serialize>(
+        metrics: MetricsResponse<T>,
+        export_format: ExportFormat,
+    ) -> AnalyticsResult<ApplicationResponse<MetricsResponse<T>>> {
+        match export_format {
+            ExportFormat::Json => Ok(ApplicationResponse::Json(metrics)),
+            ExportFormat::Csv => analytics::export::to_csv(&metrics.query_data)
+                .map(|content| ApplicationResponse::FileData((content, mime::TEXT_CSV))),
+            ExportFormat::Parquet => {
+                analytics::export::to_parquet(&metrics.query_data).map(|content| {
+                    ApplicationResponse::FileData((content, mime::APPLICATION_OCTET_STREAM))
+                })
+            }
+        }
+    }
+
     /// # Panics
     ///
     /// Panics if `json_payload` array does not contain one `GetRefundMetricRequest` element.
     pub async fn get_refunds_metrics(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
+        export_format: web::Query<ExportFormatQuery>,
         json_payload: web::Json<[GetRefundMetricRequest; 1]>,
     ) -> impl Responder {
         #[allow(clippy::expect_used)]
@@ -184,6 +329,7 @@ pub mod routes {
             .to_vec()
             .pop()
             .expect("Couldn't get GetRefundMetricRequest");
+        let export_format = export_format.into_inner().format;
         let flow = AnalyticsFlow::GetRefundsMetrics;
         Box::pin(api::server_wrap(
             flow,
@@ -191,15 +337,116 @@ pub mod routes {
             &req,
             payload,
             |state, auth: AuthenticationData, req| async move {
+                let reporting_currency_rates =
+                    get_reporting_currency_rates(&state, req.reporting_currency, &req.time_range)
+                        .await?;
                 analytics::refunds::get_metrics(
                     &state.pool,
                     &auth.merchant_account.merchant_id,
                     req,
+                    reporting_currency_rates,
+                )
+                .await
+                .and_then(|metrics| get_metrics_response(metrics, export_format))
+            },
+            auth::auth_type(
+                &auth::ScopedApiKeyAuth(ApiKeyScope::AnalyticsRead),
+                &auth::JWTAuth(Permission::Analytics),
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    /// Fetches the daily exchange rates stored for the time range of the metrics request, when the
+    /// amount metrics have to be converted to a reporting currency
+    async fn get_reporting_currency_rates(
+        state: &AppState,
+        reporting_currency: Option<Currency>,
+        time_range: &TimeRange,
+    ) -> AnalyticsResult<Option<ReportingCurrencyRates>> {
+        let Some(reporting_currency) = reporting_currency else {
+            return Ok(None);
+        };
+        let end_time = time_range
+            .end_time
+            .unwrap_or_else(common_utils::date_time::now);
+
+        let rates = currency::get_fx_rates_for_date_range(
+            &*state.store,
+            time_range.start_time.date(),
+            end_time.date(),
+        )
+        .await
+        .change_context(AnalyticsError::UnknownError)
+        .attach_printable("Failed to fetch exchange rates for the reporting currency")?;
+
+        if rates.is_empty() {
+            return Err(AnalyticsError::InvalidRequest(
+                "Exchange rates are not available for the requested time range".to_string(),
+            ))
+            .into_report();
+        }
+
+        Ok(Some(ReportingCurrencyRates::new(reporting_currency, rates)))
+    }
+
+    pub async fn get_connector_uptime(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetConnectorUptimeRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetConnectorUptime;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req: GetConnectorUptimeRequest| async move {
+                analytics::payments::get_connector_uptime(
+                    &state.pool,
+                    &auth.merchant_account.merchant_id,
+                    req,
                 )
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            auth::auth_type(
+                &auth::ScopedApiKeyAuth(ApiKeyScope::AnalyticsRead),
+                &auth::JWTAuth(Permission::Analytics),
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn get_refund_top_reasons(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<GetRefundTopReasonsRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetRefundTopReasons;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req: GetRefundTopReasonsRequest| async move {
+                analytics::refunds::get_top_reasons(
+                    &state.pool,
+                    &auth.merchant_account.merchant_id,
+                    req,
+                )
+                .await
+                .map(ApplicationResponse::Json)
+            },
+            auth::auth_type(
+                &auth::ScopedApiKeyAuth(ApiKeyScope::AnalyticsRead),
+                &auth::JWTAuth(Permission::Analytics),
+                req.headers(),
+            ),
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -211,6 +458,7 @@ pub mod routes {
     pub async fn get_sdk_event_metrics(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
+        export_format: web::Query<ExportFormatQuery>,
         json_payload: web::Json<[GetSdkEventMetricRequest; 1]>,
     ) -> impl Responder {
         // safety: This shouldn't panic owing to the data type
@@ -220,6 +468,7 @@ pub mod routes {
             .to_vec()
             .pop()
             .expect("Couldn't get GetSdkEventMetricRequest");
+        let export_format = export_format.into_inner().format;
         let flow = AnalyticsFlow::GetSdkMetrics;
         Box::pin(api::server_wrap(
             flow,
@@ -233,9 +482,13 @@ pub mod routes {
                     req,
                 )
                 .await
-                .map(ApplicationResponse::Json)
+                .and_then(|metrics| get_metrics_response(metrics, export_format))
             },
-            &auth::JWTAuth(Permission::Analytics),
+            auth::auth_type(
+                &auth::ScopedApiKeyAuth(ApiKeyScope::AnalyticsRead),
+                &auth::JWTAuth(Permission::Analytics),
+                req.headers(),
+            ),
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -261,7 +514,11 @@ pub mod routes {
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            auth::auth_type(
+                &auth::ScopedApiKeyAuth(ApiKeyScope::AnalyticsRead),
+                &auth::JWTAuth(Permission::Analytics),
+                req.headers(),
+            ),
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -287,7 +544,11 @@ pub mod routes {
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            auth::auth_type(
+                &auth::ScopedApiKeyAuth(ApiKeyScope::AnalyticsRead),
+                &auth::JWTAuth(Permission::Analytics),
+                req.headers(),
+            ),
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -313,7 +574,11 @@ pub mod routes {
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            auth::auth_type(
+                &auth::ScopedApiKeyAuth(ApiKeyScope::AnalyticsRead),
+                &auth::JWTAuth(Permission::Analytics),
+                req.headers(),
+            ),
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -335,7 +600,11 @@ pub mod routes {
                     .await
                     .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            auth::auth_type(
+                &auth::ScopedApiKeyAuth(ApiKeyScope::AnalyticsRead),
+                &auth::JWTAuth(Permission::Analytics),
+                req.headers(),
+            ),
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -359,7 +628,11 @@ pub mod routes {
                     .await
                     .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            auth::auth_type(
+                &auth::ScopedApiKeyAuth(ApiKeyScope::AnalyticsRead),
+                &auth::JWTAuth(Permission::Analytics),
+                req.headers(),
+            ),
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -385,7 +658,11 @@ pub mod routes {
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            auth::auth_type(
+                &auth::ScopedApiKeyAuth(ApiKeyScope::AnalyticsRead),
+                &auth::JWTAuth(Permission::Analytics),
+                req.headers(),
+            ),
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -520,9 +797,199 @@ pub mod routes {
     /// # Panics
     ///
     /// Panics if `json_payload` array does not contain one `GetApiEventMetricRequest` element.
+    pub async fn create_report_schedule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<ReportScheduleCreateRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::CreateReportSchedule;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req| {
+                scheduled_reports::create_report_schedule(
+                    state,
+                    auth.merchant_account,
+                    auth.key_store,
+                    req,
+                )
+            },
+            &auth::JWTAuth(Permission::Analytics),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn list_report_schedules(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::ListReportSchedules;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            (),
+            |state, auth: AuthenticationData, _| {
+                scheduled_reports::list_report_schedules(state, auth.merchant_account)
+            },
+            auth::auth_type(
+                &auth::ScopedApiKeyAuth(ApiKeyScope::AnalyticsRead),
+                &auth::JWTAuth(Permission::Analytics),
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn delete_report_schedule(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::DeleteReportSchedule;
+        let payload = ReportScheduleId {
+            schedule_id: path.into_inner(),
+        };
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req| {
+                scheduled_reports::delete_report_schedule(state, auth.merchant_account, req)
+            },
+            &auth::JWTAuth(Permission::Analytics),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn list_report_runs(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        query: web::Query<ReportRunListRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::ListReportRuns;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            query.into_inner(),
+            |state, auth: AuthenticationData, req| {
+                scheduled_reports::list_report_runs(state, auth.merchant_account, req)
+            },
+            auth::auth_type(
+                &auth::ScopedApiKeyAuth(ApiKeyScope::AnalyticsRead),
+                &auth::JWTAuth(Permission::Analytics),
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn get_live_payment_activity(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::GetLivePaymentActivity;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            (),
+            |state, auth: AuthenticationData, _| {
+                live_activity::get_live_payment_activity(state, auth.merchant_account)
+            },
+            auth::auth_type(
+                &auth::ScopedApiKeyAuth(ApiKeyScope::AnalyticsRead),
+                &auth::JWTAuth(Permission::Analytics),
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn create_success_rate_alert(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        json_payload: web::Json<SuccessRateAlertCreateRequest>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::CreateSuccessRateAlert;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            json_payload.into_inner(),
+            |state, auth: AuthenticationData, req| {
+                success_rate_alerts::create_success_rate_alert(
+                    state,
+                    auth.merchant_account,
+                    auth.key_store,
+                    req,
+                )
+            },
+            &auth::JWTAuth(Permission::Analytics),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn list_success_rate_alerts(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::ListSuccessRateAlerts;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            (),
+            |state, auth: AuthenticationData, _| {
+                success_rate_alerts::list_success_rate_alerts(state, auth.merchant_account)
+            },
+            auth::auth_type(
+                &auth::ScopedApiKeyAuth(ApiKeyScope::AnalyticsRead),
+                &auth::JWTAuth(Permission::Analytics),
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    pub async fn delete_success_rate_alert(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        path: web::Path<String>,
+    ) -> impl Responder {
+        let flow = AnalyticsFlow::DeleteSuccessRateAlert;
+        let payload = SuccessRateAlertId {
+            alert_id: path.into_inner(),
+        };
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req| {
+                success_rate_alerts::delete_success_rate_alert(state, auth.merchant_account, req)
+            },
+            &auth::JWTAuth(Permission::Analytics),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
     pub async fn get_api_events_metrics(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
+        export_format: web::Query<ExportFormatQuery>,
         json_payload: web::Json<[GetApiEventMetricRequest; 1]>,
     ) -> impl Responder {
         // safety: This shouldn't panic owing to the data type
@@ -532,6 +999,7 @@ pub mod routes {
             .to_vec()
             .pop()
             .expect("Couldn't get GetApiEventMetricRequest");
+        let export_format = export_format.into_inner().format;
         let flow = AnalyticsFlow::GetApiEventMetrics;
         Box::pin(api::server_wrap(
             flow,
@@ -545,9 +1013,13 @@ pub mod routes {
                     req,
                 )
                 .await
-                .map(ApplicationResponse::Json)
+                .and_then(|metrics| get_metrics_response(metrics, export_format))
             },
-            &auth::JWTAuth(Permission::Analytics),
+            auth::auth_type(
+                &auth::ScopedApiKeyAuth(ApiKeyScope::AnalyticsRead),
+                &auth::JWTAuth(Permission::Analytics),
+                req.headers(),
+            ),
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -573,7 +1045,11 @@ pub mod routes {
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            auth::auth_type(
+                &auth::ScopedApiKeyAuth(ApiKeyScope::AnalyticsRead),
+                &auth::JWTAuth(Permission::Analytics),
+                req.headers(),
+            ),
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -595,7 +1071,11 @@ pub mod routes {
                     .await
                     .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            auth::auth_type(
+                &auth::ScopedApiKeyAuth(ApiKeyScope::AnalyticsRead),
+                &auth::JWTAuth(Permission::Analytics),
+                req.headers(),
+            ),
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -617,11 +1097,16 @@ pub mod routes {
                     req,
                     &auth.merchant_account.merchant_id,
                     state.conf.opensearch.clone(),
+                    state.pool.guardrails(),
                 )
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            auth::auth_type(
+                &auth::ScopedApiKeyAuth(ApiKeyScope::AnalyticsRead),
+                &auth::JWTAuth(Permission::Analytics),
+                req.headers(),
+            ),
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -648,11 +1133,16 @@ pub mod routes {
                     req,
                     &auth.merchant_account.merchant_id,
                     state.conf.opensearch.clone(),
+                    state.pool.guardrails(),
                 )
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            auth::auth_type(
+                &auth::ScopedApiKeyAuth(ApiKeyScope::AnalyticsRead),
+                &auth::JWTAuth(Permission::Analytics),
+                req.headers(),
+            ),
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -678,7 +1168,11 @@ pub mod routes {
                 .await
                 .map(ApplicationResponse::Json)
             },
-            &auth::JWTAuth(Permission::Analytics),
+            auth::auth_type(
+                &auth::ScopedApiKeyAuth(ApiKeyScope::AnalyticsRead),
+                &auth::JWTAuth(Permission::Analytics),
+                req.headers(),
+            ),
             api_locking::LockAction::NotApplicable,
         ))
         .await
@@ -689,6 +1183,7 @@ pub mod routes {
     pub async fn get_dispute_metrics(
         state: web::Data<AppState>,
         req: actix_web::HttpRequest,
+        export_format: web::Query<ExportFormatQuery>,
         json_payload: web::Json<[GetDisputeMetricRequest; 1]>,
     ) -> impl Responder {
         // safety: This shouldn't panic owing to the data type
@@ -698,6 +1193,7 @@ pub mod routes {
             .to_vec()
             .pop()
             .expect("Couldn't get GetDisputeMetricRequest");
+        let export_format = export_format.into_inner().format;
         let flow = AnalyticsFlow::GetDisputeMetrics;
         Box::pin(api::server_wrap(
             flow,
@@ -711,9 +1207,55 @@ pub mod routes {
                     req,
                 )
                 .await
-                .map(ApplicationResponse::Json)
+                .and_then(|metrics| get_metrics_response(metrics, export_format))
             },
-            &auth::JWTAuth(Permission::Analytics),
+            auth::auth_type(
+                &auth::ScopedApiKeyAuth(ApiKeyScope::AnalyticsRead),
+                &auth::JWTAuth(Permission::Analytics),
+                req.headers(),
+            ),
+            api_locking::LockAction::NotApplicable,
+        ))
+        .await
+    }
+
+    /// # Panics
+    ///
+    /// Panics if `json_payload` array does not contain one `GetAuthEventMetricRequest` element.
+    pub async fn get_auth_event_metrics(
+        state: web::Data<AppState>,
+        req: actix_web::HttpRequest,
+        export_format: web::Query<ExportFormatQuery>,
+        json_payload: web::Json<[GetAuthEventMetricRequest; 1]>,
+    ) -> impl Responder {
+        // safety: This shouldn't panic owing to the data type
+        #[allow(clippy::expect_used)]
+        let payload = json_payload
+            .into_inner()
+            .to_vec()
+            .pop()
+            .expect("Couldn't get GetAuthEventMetricRequest");
+        let export_format = export_format.into_inner().format;
+        let flow = AnalyticsFlow::GetAuthEventMetrics;
+        Box::pin(api::server_wrap(
+            flow,
+            state,
+            &req,
+            payload,
+            |state, auth: AuthenticationData, req| async move {
+                analytics::auth_events::get_metrics(
+                    &state.pool,
+                    &auth.merchant_account.merchant_id,
+                    req,
+                )
+                .await
+                .and_then(|metrics| get_metrics_response(metrics, export_format))
+            },
+            auth::auth_type(
+                &auth::ScopedApiKeyAuth(ApiKeyScope::AnalyticsRead),
+                &auth::JWTAuth(Permission::Analytics),
+                req.headers(),
+            ),
             api_locking::LockAction::NotApplicable,
         ))
         .await
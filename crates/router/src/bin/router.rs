@@ -19,7 +19,7 @@ async fn main() -> ApplicationResult<()> {
     #[cfg(feature = "vergen")]
     println!("Starting router (Version: {})", router_env::git_tag!());
 
-    let _guard = router_env::setup(
+    let telemetry_guard = router_env::setup(
         &conf.log,
         router_env::service_name!(),
         [router_env::service_name!(), "actix_server"],
@@ -33,6 +33,11 @@ async fn main() -> ApplicationResult<()> {
         .expect("Failed to create the server");
     let _ = server.await;
 
+    // The server stops once it receives a termination signal, the tail of the logs, spans and
+    // metrics is flushed before the process exits
+    logger::info!("Server shut down, flushing telemetry");
+    telemetry_guard.shutdown();
+
     Err(ApplicationError::from(std::io::Error::new(
         std::io::ErrorKind::Other,
         "Server shut down",
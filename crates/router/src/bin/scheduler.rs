@@ -88,6 +88,21 @@ async fn main() -> CustomResult<(), ProcessTrackerError> {
 
     logger::debug!(startup_config=?state.conf);
 
+    if matches!(scheduler_flow, scheduler::SchedulerFlow::Producer) {
+        if let Err(error) = router::core::currency::add_fx_rates_sync_task(&*state.store).await {
+            logger::error!(?error, "Failed to add the exchange rates sync task");
+        }
+
+        if state.conf.payment_method_expiry.enabled {
+            if let Err(error) =
+                router::core::payment_methods::expiry::add_payment_method_expiry_task(&*state.store)
+                    .await
+            {
+                logger::error!(?error, "Failed to add the payment method expiry task");
+            }
+        }
+    }
+
     start_scheduler(&state, scheduler_flow, (tx, rx)).await?;
 
     eprintln!("Scheduler shut down");
@@ -258,6 +273,65 @@ impl ProcessTrackerWorkflows<routes::AppState> for WorkflowRunner {
                 storage::ProcessTrackerRunner::OutgoingWebhookRetryWorkflow => Ok(Box::new(
                     workflows::outgoing_webhook_retry::OutgoingWebhookRetryWorkflow,
                 )),
+                storage::ProcessTrackerRunner::RecurringPaymentWorkflow => Ok(Box::new(
+                    workflows::recurring_payment::RecurringPaymentWorkflow,
+                )),
+                storage::ProcessTrackerRunner::FxRatesSyncWorkflow => {
+                    Ok(Box::new(workflows::fx_rates_sync::FxRatesSyncWorkflow))
+                }
+                storage::ProcessTrackerRunner::DisputeRepresentmentWorkflow => Ok(Box::new(
+                    workflows::dispute_representment::DisputeRepresentmentWorkflow,
+                )),
+                storage::ProcessTrackerRunner::MerchantAccountDeletionWorkflow => Ok(Box::new(
+                    workflows::merchant_account_deletion::MerchantAccountDeletionWorkflow,
+                )),
+                storage::ProcessTrackerRunner::FrmDecisionWorkflow => {
+                    Ok(Box::new(workflows::frm_decision::FrmDecisionWorkflow))
+                }
+                storage::ProcessTrackerRunner::CustomerDedupWorkflow => {
+                    Ok(Box::new(workflows::customer_dedup::CustomerDedupWorkflow))
+                }
+                storage::ProcessTrackerRunner::CardVerificationVoidWorkflow => Ok(Box::new(
+                    workflows::card_verification_void::CardVerificationVoidWorkflow,
+                )),
+                storage::ProcessTrackerRunner::BulkChargeWorkflow => {
+                    Ok(Box::new(workflows::bulk_charge::BulkChargeWorkflow))
+                }
+                storage::ProcessTrackerRunner::ScheduledReportWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(
+                            workflows::scheduled_report::ScheduledReportWorkflow,
+                        ))
+                    }
+
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run scheduled report workflow when olap feature is disabled",
+                        )
+                    }
+                }
+                storage::ProcessTrackerRunner::PaymentMethodExpiryWorkflow => Ok(Box::new(
+                    workflows::payment_method_expiry::PaymentMethodExpiryWorkflow,
+                )),
+                storage::ProcessTrackerRunner::SuccessRateAlertWorkflow => {
+                    #[cfg(feature = "olap")]
+                    {
+                        Ok(Box::new(
+                            workflows::success_rate_alert::SuccessRateAlertWorkflow,
+                        ))
+                    }
+
+                    #[cfg(not(feature = "olap"))]
+                    {
+                        Err(error_stack::report!(ProcessTrackerError::UnexpectedFlow))
+                            .attach_printable(
+                            "Cannot run success rate alert workflow when olap feature is disabled",
+                        )
+                    }
+                }
             }
         };
 
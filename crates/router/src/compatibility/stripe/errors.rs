@@ -256,6 +256,10 @@ pub enum StripeErrorCode {
     CurrencyConversionFailed,
     #[error(error_type = StripeErrorType::InvalidRequestError, code = "IR_25", message = "Cannot delete the default payment method")]
     PaymentMethodDeleteFailed,
+    #[error(error_type = StripeErrorType::ApiError, code = "HE_06", message = "Payments cannot be created during the scheduled maintenance. Please try again later.")]
+    MaintenanceModeEnabled,
+    #[error(error_type = StripeErrorType::ApiError, code = "", message = "The request could not be processed within its timeout")]
+    RequestDeadlineExceeded,
     // [#216]: https://github.com/juspay/hyperswitch/issues/216
     // Implement the remaining stripe error codes
 
@@ -625,6 +629,8 @@ impl From<errors::ApiErrorResponse> for StripeErrorCode {
             }
             errors::ApiErrorResponse::CurrencyConversionFailed => Self::CurrencyConversionFailed,
             errors::ApiErrorResponse::PaymentMethodDeleteFailed => Self::PaymentMethodDeleteFailed,
+            errors::ApiErrorResponse::MaintenanceModeEnabled => Self::MaintenanceModeEnabled,
+            errors::ApiErrorResponse::RequestDeadlineExceeded => Self::RequestDeadlineExceeded,
         }
     }
 }
@@ -703,7 +709,9 @@ impl actix_web::ResponseError for StripeErrorCode {
             | Self::MandateActive
             | Self::CustomerRedacted
             | Self::WebhookProcessingError => StatusCode::INTERNAL_SERVER_ERROR,
-            Self::ReturnUrlUnavailable => StatusCode::SERVICE_UNAVAILABLE,
+            Self::ReturnUrlUnavailable
+            | Self::MaintenanceModeEnabled
+            | Self::RequestDeadlineExceeded => StatusCode::SERVICE_UNAVAILABLE,
             Self::ExternalConnectorError { status_code, .. } => {
                 StatusCode::from_u16(*status_code).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
             }
@@ -777,6 +785,9 @@ impl ErrorSwitch<StripeErrorCode> for CustomersErrorResponse {
             Self::MandateActive => SC::MandateActive,
             Self::CustomerNotFound => SC::CustomerNotFound,
             Self::CustomerAlreadyExists => SC::DuplicateCustomer,
+            Self::InvalidRequestData { message } => SC::InvalidRequestData {
+                message: message.to_string(),
+            },
         }
     }
 }
@@ -1,12 +1,13 @@
 pub mod types;
 use actix_web::{web, HttpRequest, HttpResponse};
 use api_models::payments as payment_types;
+use common_enums::ApiKeyScope;
 use error_stack::report;
 use router_env::{instrument, tracing, Flow, Tag};
 
 use crate::{
     compatibility::{stripe::errors, wrap},
-    core::{api_locking::GetLockingInput, payment_methods::Oss, payments},
+    core::{api_locking::GetLockingInput, payment_methods::Oss, payments, utils as core_utils},
     logger, routes,
     services::{api, authentication as auth},
     types::api as api_types,
@@ -52,12 +53,17 @@ pub async fn payment_intents_create(
         state.into_inner(),
         &req,
         create_payment_req,
-        |state, auth, req| {
+        |state, auth, mut req| async move {
+            req.profile_id = core_utils::validate_profile_id_from_auth_layer(
+                auth.profile_id.clone(),
+                req.profile_id,
+            )?;
             let eligible_connectors = req.connector.clone();
             payments::payments_core::<api_types::Authorize, api_types::PaymentsResponse, _, _, _,Oss>(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 payments::PaymentCreate,
                 req,
                 api::AuthFlow::Merchant,
@@ -65,8 +71,9 @@ pub async fn payment_intents_create(
                 eligible_connectors,
                 api_types::HeaderPayload::default(),
             )
+            .await
         },
-        &auth::ApiKeyAuth,
+        &auth::ScopedApiKeyAuth(ApiKeyScope::PaymentsWrite),
         locking_action,
     ))
     .await
@@ -90,11 +97,14 @@ pub async fn payment_intents_retrieve(
         expand_captures: None,
     };
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(report!(err)),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Some(ApiKeyScope::PaymentsRead),
+    ) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(report!(err)),
+    };
 
     let flow = Flow::PaymentsRetrieveForceSync;
     let locking_action = payload.get_locking_input(flow.clone());
@@ -118,6 +128,7 @@ pub async fn payment_intents_retrieve(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 payments::PaymentStatus,
                 payload,
                 auth_flow,
@@ -155,10 +166,11 @@ pub async fn payment_intents_retrieve_with_gateway_creds(
         merchant_connector_details: json_payload.merchant_connector_details.clone(),
         ..Default::default()
     };
-    let (auth_type, _auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, _auth_flow) =
+        match auth::get_auth_type_and_flow(req.headers(), Some(ApiKeyScope::PaymentsRead)) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
 
     let flow = match json_payload.force_sync {
         Some(true) => Flow::PaymentsRetrieveForceSync,
@@ -188,6 +200,7 @@ pub async fn payment_intents_retrieve_with_gateway_creds(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 payments::PaymentStatus,
                 req,
                 api::AuthFlow::Merchant,
@@ -226,10 +239,11 @@ pub async fn payment_intents_update(
 
     payload.payment_id = Some(api_types::PaymentIdType::PaymentIntentId(payment_id));
 
-    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, auth_flow) =
+        match auth::get_auth_type_and_flow(req.headers(), Some(ApiKeyScope::PaymentsWrite)) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
 
     let flow = Flow::PaymentsUpdate;
     let locking_action = payload.get_locking_input(flow.clone());
@@ -254,6 +268,7 @@ pub async fn payment_intents_update(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 payments::PaymentUpdate,
                 req,
                 auth_flow,
@@ -297,11 +312,14 @@ pub async fn payment_intents_confirm(
     payload.payment_id = Some(api_types::PaymentIdType::PaymentIntentId(payment_id));
     payload.confirm = Some(true);
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(err),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Some(ApiKeyScope::PaymentsWrite),
+    ) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
 
     let flow = Flow::PaymentsConfirm;
     let locking_action = payload.get_locking_input(flow.clone());
@@ -326,6 +344,7 @@ pub async fn payment_intents_confirm(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 payments::PaymentConfirm,
                 req,
                 auth_flow,
@@ -387,6 +406,7 @@ pub async fn payment_intents_capture(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 payments::PaymentCapture,
                 payload,
                 api::AuthFlow::Merchant,
@@ -395,7 +415,7 @@ pub async fn payment_intents_capture(
                 api_types::HeaderPayload::default(),
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ScopedApiKeyAuth(ApiKeyScope::PaymentsWrite),
         locking_action,
     ))
     .await
@@ -425,10 +445,11 @@ pub async fn payment_intents_cancel(
     let mut payload: payment_types::PaymentsCancelRequest = stripe_payload.into();
     payload.payment_id = payment_id;
 
-    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, auth_flow) =
+        match auth::get_auth_type_and_flow(req.headers(), Some(ApiKeyScope::PaymentsWrite)) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
 
     let flow = Flow::PaymentsCancel;
     let locking_action = payload.get_locking_input(flow.clone());
@@ -452,6 +473,7 @@ pub async fn payment_intents_cancel(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 payments::PaymentCancel,
                 req,
                 auth_flow,
@@ -493,8 +515,10 @@ pub async fn payment_intent_list(
         state.into_inner(),
         &req,
         payload,
-        |state, auth, req| payments::list_payments(state, auth.merchant_account, req),
-        &auth::ApiKeyAuth,
+        |state, auth, req| {
+            payments::list_payments(state, auth.merchant_account, auth.profile_id, req)
+        },
+        &auth::ScopedApiKeyAuth(ApiKeyScope::PaymentsRead),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -741,6 +741,9 @@ impl ForeignTryFrom<(Option<MandateData>, Option<String>)> for Option<payments::
                             start_date: mandate.start_date,
                             end_date: mandate.end_date,
                             metadata: None,
+                            max_amount_per_charge: None,
+                            max_amount_per_period: None,
+                            limit_period: None,
                         },
                     )),
                     StripeMandateType::MultiUse => Some(payments::MandateType::MultiUse(Some(
@@ -750,6 +753,9 @@ impl ForeignTryFrom<(Option<MandateData>, Option<String>)> for Option<payments::
                             start_date: mandate.start_date,
                             end_date: mandate.end_date,
                             metadata: None,
+                            max_amount_per_charge: None,
+                            max_amount_per_period: None,
+                            limit_period: None,
                         },
                     ))),
                 },
@@ -760,6 +766,9 @@ impl ForeignTryFrom<(Option<MandateData>, Option<String>)> for Option<payments::
                         start_date: mandate.start_date,
                         end_date: mandate.end_date,
                         metadata: None,
+                        max_amount_per_charge: None,
+                        max_amount_per_period: None,
+                        limit_period: None,
                     },
                 ))),
             },
@@ -1,11 +1,15 @@
 pub mod types;
 use actix_web::{web, HttpRequest, HttpResponse};
+use common_enums::ApiKeyScope;
 use error_stack::report;
 use router_env::{instrument, tracing, Flow, Tag};
 
 use crate::{
     compatibility::{stripe::errors, wrap},
-    core::{api_locking, refunds},
+    core::{
+        api_locking::{self, GetLockingInput},
+        refunds,
+    },
     logger, routes,
     services::{api, authentication as auth},
     types::api::refunds as refund_types,
@@ -33,6 +37,7 @@ pub async fn refund_create(
     let create_refund_req: refund_types::RefundRequest = payload.into();
 
     let flow = Flow::RefundsCreate;
+    let locking_action = create_refund_req.get_locking_input(flow.clone());
 
     Box::pin(wrap::compatibility_api_wrap::<
         _,
@@ -50,10 +55,16 @@ pub async fn refund_create(
         &req,
         create_refund_req,
         |state, auth, req| {
-            refunds::refund_create_core(state, auth.merchant_account, auth.key_store, req)
+            refunds::refund_create_core(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile_id,
+                req,
+            )
         },
-        &auth::ApiKeyAuth,
-        api_locking::LockAction::NotApplicable,
+        &auth::ScopedApiKeyAuth(ApiKeyScope::RefundsWrite),
+        locking_action,
     ))
     .await
 }
@@ -99,11 +110,12 @@ pub async fn refund_retrieve_with_gateway_creds(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 refund_request,
                 refunds::refund_retrieve_core,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ScopedApiKeyAuth(ApiKeyScope::PaymentsRead),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -142,11 +154,12 @@ pub async fn refund_retrieve(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 refund_request,
                 refunds::refund_retrieve_core,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ScopedApiKeyAuth(ApiKeyScope::PaymentsRead),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -178,8 +191,10 @@ pub async fn refund_update(
         state.into_inner(),
         &req,
         create_refund_update_req,
-        |state, auth, req| refunds::refund_update_core(state, auth.merchant_account, req),
-        &auth::ApiKeyAuth,
+        |state, auth, req| {
+            refunds::refund_update_core(state, auth.merchant_account, auth.profile_id, req)
+        },
+        &auth::ScopedApiKeyAuth(ApiKeyScope::RefundsWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
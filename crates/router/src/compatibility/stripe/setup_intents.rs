@@ -1,6 +1,7 @@
 pub mod types;
 use actix_web::{web, HttpRequest, HttpResponse};
 use api_models::payments as payment_types;
+use common_enums::ApiKeyScope;
 use error_stack::report;
 use router_env::{instrument, tracing, Flow};
 
@@ -9,7 +10,7 @@ use crate::{
         stripe::{errors, payment_intents::types as stripe_payment_types},
         wrap,
     },
-    core::{api_locking, payment_methods::Oss, payments},
+    core::{api_locking, payment_methods::Oss, payments, utils as core_utils},
     routes,
     services::{api, authentication as auth},
     types::api as api_types,
@@ -53,7 +54,11 @@ pub async fn setup_intents_create(
         state.into_inner(),
         &req,
         create_payment_req,
-        |state, auth, req| {
+        |state, auth, mut req| async move {
+            req.profile_id = core_utils::validate_profile_id_from_auth_layer(
+                auth.profile_id.clone(),
+                req.profile_id,
+            )?;
             payments::payments_core::<
                 api_types::SetupMandate,
                 api_types::PaymentsResponse,
@@ -65,6 +70,7 @@ pub async fn setup_intents_create(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 payments::PaymentCreate,
                 req,
                 api::AuthFlow::Merchant,
@@ -72,8 +78,9 @@ pub async fn setup_intents_create(
                 None,
                 api_types::HeaderPayload::default(),
             )
+            .await
         },
-        &auth::ApiKeyAuth,
+        &auth::ScopedApiKeyAuth(ApiKeyScope::PaymentsWrite),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -97,11 +104,14 @@ pub async fn setup_intents_retrieve(
         expand_captures: None,
     };
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(report!(err)),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Some(ApiKeyScope::PaymentsRead),
+    ) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(report!(err)),
+    };
 
     let flow = Flow::PaymentsRetrieveForceSync;
 
@@ -125,6 +135,7 @@ pub async fn setup_intents_retrieve(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 payments::PaymentStatus,
                 payload,
                 auth_flow,
@@ -163,11 +174,14 @@ pub async fn setup_intents_update(
         };
     payload.payment_id = Some(api_types::PaymentIdType::PaymentIntentId(setup_id));
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(err),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Some(ApiKeyScope::PaymentsWrite),
+    ) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
 
     let flow = Flow::PaymentsUpdate;
 
@@ -198,6 +212,7 @@ pub async fn setup_intents_update(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 payments::PaymentUpdate,
                 req,
                 auth_flow,
@@ -237,11 +252,14 @@ pub async fn setup_intents_confirm(
     payload.payment_id = Some(api_types::PaymentIdType::PaymentIntentId(setup_id));
     payload.confirm = Some(true);
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(err),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Some(ApiKeyScope::PaymentsWrite),
+    ) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(err),
+    };
 
     let flow = Flow::PaymentsConfirm;
 
@@ -272,6 +290,7 @@ pub async fn setup_intents_confirm(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 payments::PaymentConfirm,
                 req,
                 auth_flow,
@@ -82,6 +82,10 @@ pub enum StripeWebhookObject {
     Refund(StripeRefundResponse),
     Dispute(StripeDisputeResponse),
     Mandate(StripeMandateResponse),
+    ConnectorHealth(api_models::admin::ConnectorHealthResponse),
+    Authentication(api_models::payments::ExternalAuthenticationResponse),
+    PaymentMethod(api_models::payment_methods::PaymentMethodResponse),
+    SuccessRateDrop(api_models::analytics::success_rate_alerts::SuccessRateDropDetails),
 }
 
 #[derive(Serialize, Debug)]
@@ -191,6 +195,19 @@ fn get_stripe_event_type(event_type: api_models::enums::EventType) -> &'static s
         api_models::enums::EventType::DisputeLost => "dispute.lost",
         api_models::enums::EventType::MandateActive => "mandate.active",
         api_models::enums::EventType::MandateRevoked => "mandate.revoked",
+        api_models::enums::EventType::MandateLimitExceeded => "mandate.limit_exceeded",
+        api_models::enums::EventType::ConnectorDegraded => "connector.degraded",
+        api_models::enums::EventType::ConnectorRecovered => "connector.recovered",
+        api_models::enums::EventType::AuthenticationStarted => "authentication.started",
+        api_models::enums::EventType::ChallengeRequired => "authentication.challenge_required",
+        api_models::enums::EventType::AuthenticationSucceeded => "authentication.succeeded",
+        api_models::enums::EventType::AuthenticationFailed => "authentication.failed",
+        api_models::enums::EventType::PaymentReviewRequired => "review.opened",
+        api_models::enums::EventType::PaymentReviewApproved
+        | api_models::enums::EventType::PaymentReviewRejected => "review.closed",
+        // as per this doc https://stripe.com/docs/api/events/types#event_types-customer.source.expiring
+        api_models::enums::EventType::PaymentMethodExpiring => "customer.source.expiring",
+        api_models::enums::EventType::SuccessRateDropped => "alert.success_rate_dropped",
 
         // as per this doc https://stripe.com/docs/api/events/types#event_types-payment_intent.amount_capturable_updated
         api_models::enums::EventType::PaymentAuthorized => {
@@ -238,6 +255,18 @@ impl From<api::OutgoingWebhookContent> for StripeWebhookObject {
             api::OutgoingWebhookContent::MandateDetails(mandate) => {
                 Self::Mandate((*mandate).into())
             }
+            api::OutgoingWebhookContent::ConnectorHealthDetails(health) => {
+                Self::ConnectorHealth(*health)
+            }
+            api::OutgoingWebhookContent::AuthenticationDetails(authentication) => {
+                Self::Authentication(*authentication)
+            }
+            api::OutgoingWebhookContent::PaymentMethodDetails(payment_method) => {
+                Self::PaymentMethod(*payment_method)
+            }
+            api::OutgoingWebhookContent::SuccessRateDropDetails(success_rate_drop) => {
+                Self::SuccessRateDrop(*success_rate_drop)
+            }
         }
     }
 }
@@ -97,6 +97,8 @@ impl Default for super::settings::Refund {
         Self {
             max_attempts: 10,
             max_age: 365,
+            max_retries: 3,
+            retry_interval: 60,
         }
     }
 }
@@ -107,6 +109,90 @@ impl Default for super::settings::EphemeralConfig {
     }
 }
 
+impl Default for super::settings::ClientSessionConfig {
+    fn default() -> Self {
+        Self {
+            // 15 minutes
+            validity_in_seconds: 15 * 60,
+        }
+    }
+}
+
+impl Default for super::settings::FileUploadConfig {
+    fn default() -> Self {
+        Self {
+            // 10 MB
+            max_file_size: 10 * 1024 * 1024,
+            allowed_mime_types: [
+                "application/pdf",
+                "image/jpeg",
+                "image/png",
+                "text/csv",
+                "application/zip",
+            ]
+            .into_iter()
+            .map(String::from)
+            .collect(),
+        }
+    }
+}
+
+impl Default for super::settings::RecurringPaymentsConfig {
+    fn default() -> Self {
+        Self {
+            dunning_retry_intervals_in_hours: vec![24, 72, 168],
+            max_dunning_retries: 10,
+        }
+    }
+}
+
+impl Default for super::settings::BulkChargesConfig {
+    fn default() -> Self {
+        Self {
+            max_rows: 10000,
+            max_concurrency: 10,
+        }
+    }
+}
+
+impl Default for super::settings::ScheduledReportsConfig {
+    fn default() -> Self {
+        Self {
+            max_schedules_per_merchant: 10,
+            max_report_rows: 100000,
+            sftp_timeout_secs: 30,
+        }
+    }
+}
+
+impl Default for super::settings::SuccessRateAlertsConfig {
+    fn default() -> Self {
+        Self {
+            max_alerts_per_merchant: 10,
+            evaluation_interval_in_secs: 300,
+        }
+    }
+}
+
+impl Default for super::settings::PaymentMethodExpiryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            batch_size: 1000,
+            notify_customers_by_email: false,
+        }
+    }
+}
+
+impl Default for super::settings::RequestDeadlineConfig {
+    fn default() -> Self {
+        Self {
+            default_timeout_ms: 60000,
+            max_timeout_ms: 120000,
+        }
+    }
+}
+
 #[cfg(feature = "kv_store")]
 impl Default for super::settings::DrainerSettings {
     fn default() -> Self {
@@ -222,6 +308,7 @@ impl Default for Mandates {
                 ),
             ])),
             update_mandate_supported: SupportedPaymentMethodsForMandate(HashMap::default()),
+            revoked_mandate_error_codes: Default::default(),
         }
     }
 }
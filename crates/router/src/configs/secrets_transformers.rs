@@ -197,6 +197,63 @@ impl SecretsHandler for settings::PaymentMethodAuth {
     }
 }
 
+#[async_trait::async_trait]
+impl SecretsHandler for settings::NetworkTokenizationService {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let network_tokenization_service = value.get_inner();
+
+        let token_service_api_key = secret_management_client
+            .get_secret(network_tokenization_service.token_service_api_key.clone())
+            .await?;
+
+        Ok(value.transition_state(|network_tokenization_service| Self {
+            token_service_api_key,
+            ..network_tokenization_service
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretsHandler for settings::BinLookup {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let bin_lookup = value.get_inner();
+
+        let external_provider_api_key = secret_management_client
+            .get_secret(bin_lookup.external_provider_api_key.clone())
+            .await?;
+
+        Ok(value.transition_state(|bin_lookup| Self {
+            external_provider_api_key,
+            ..bin_lookup
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl SecretsHandler for settings::AddressNormalization {
+    async fn convert_to_raw_secret(
+        value: SecretStateContainer<Self, SecuredSecret>,
+        secret_management_client: &dyn SecretManagementInterface,
+    ) -> CustomResult<SecretStateContainer<Self, RawSecret>, SecretsManagementError> {
+        let address_normalization = value.get_inner();
+
+        let external_provider_api_key = secret_management_client
+            .get_secret(address_normalization.external_provider_api_key.clone())
+            .await?;
+
+        Ok(value.transition_state(|address_normalization| Self {
+            external_provider_api_key,
+            ..address_normalization
+        }))
+    }
+}
+
 #[async_trait::async_trait]
 impl SecretsHandler for settings::Secrets {
     async fn convert_to_raw_secret(
@@ -302,6 +359,28 @@ pub(crate) async fn fetch_raw_secrets(
     .await
     .expect("Failed to decrypt payment method auth configs");
 
+    #[allow(clippy::expect_used)]
+    let network_tokenization_service = settings::NetworkTokenizationService::convert_to_raw_secret(
+        conf.network_tokenization_service,
+        secret_management_client,
+    )
+    .await
+    .expect("Failed to decrypt network tokenization service configs");
+
+    #[allow(clippy::expect_used)]
+    let bin_lookup =
+        settings::BinLookup::convert_to_raw_secret(conf.bin_lookup, secret_management_client)
+            .await
+            .expect("Failed to decrypt BIN lookup configs");
+
+    #[allow(clippy::expect_used)]
+    let address_normalization = settings::AddressNormalization::convert_to_raw_secret(
+        conf.address_normalization,
+        secret_management_client,
+    )
+    .await
+    .expect("Failed to decrypt address normalization configs");
+
     Settings {
         server: conf.server,
         master_database,
@@ -321,6 +400,7 @@ pub(crate) async fn fetch_raw_secrets(
         forex_api,
         refund: conf.refund,
         eph_key: conf.eph_key,
+        client_session: conf.client_session,
         scheduler: conf.scheduler,
         jwekey,
         webhooks: conf.webhooks,
@@ -328,6 +408,7 @@ pub(crate) async fn fetch_raw_secrets(
         bank_config: conf.bank_config,
         api_keys,
         file_storage: conf.file_storage,
+        file_upload_config: conf.file_upload_config,
         tokenization: conf.tokenization,
         connector_customer: conf.connector_customer,
         #[cfg(feature = "dummy_connector")]
@@ -335,15 +416,33 @@ pub(crate) async fn fetch_raw_secrets(
         #[cfg(feature = "email")]
         email: conf.email,
         mandates: conf.mandates,
+        recurring_payments: conf.recurring_payments,
+        bulk_charges: conf.bulk_charges,
+        scheduled_reports: conf.scheduled_reports,
+        success_rate_alerts: conf.success_rate_alerts,
+        payment_method_expiry: conf.payment_method_expiry,
+        request_deadline: conf.request_deadline,
         required_fields: conf.required_fields,
+        required_fields_validation: conf.required_fields_validation,
         delayed_session_response: conf.delayed_session_response,
         webhook_source_verification_call: conf.webhook_source_verification_call,
+        webhook_source_verification: conf.webhook_source_verification,
         payment_method_auth,
+        network_tokenization_service,
+        network_tokenization_supported_connectors: conf.network_tokenization_supported_connectors,
+        card_verification: conf.card_verification,
+        merchant_deletion: conf.merchant_deletion,
+        success_rate_routing: conf.success_rate_routing,
+        connector_health: conf.connector_health,
+        maintenance_mode: conf.maintenance_mode,
+        bin_lookup,
+        address_normalization,
         connector_request_reference_id_config: conf.connector_request_reference_id_config,
         #[cfg(feature = "payouts")]
         payouts: conf.payouts,
         applepay_decrypt_keys,
         multiple_api_version_supported_connectors: conf.multiple_api_version_supported_connectors,
+        trace_context_propagation: conf.trace_context_propagation,
         applepay_merchant_configs,
         lock_settings: conf.lock_settings,
         temp_locker_enable_config: conf.temp_locker_enable_config,
@@ -351,6 +450,10 @@ pub(crate) async fn fetch_raw_secrets(
         #[cfg(feature = "olap")]
         analytics,
         #[cfg(feature = "olap")]
+        analytics_guardrails: conf.analytics_guardrails,
+        #[cfg(feature = "olap")]
+        analytics_cache: conf.analytics_cache,
+        #[cfg(feature = "olap")]
         opensearch: conf.opensearch,
         #[cfg(feature = "kv_store")]
         kv_config: conf.kv_config,
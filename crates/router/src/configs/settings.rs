@@ -4,7 +4,10 @@ use std::{
 };
 
 #[cfg(feature = "olap")]
-use analytics::{OpensearchConfig, ReportConfig};
+use analytics::{
+    cache::AnalyticsCacheConfig, guardrails::AnalyticsGuardrailsConfig, OpensearchConfig,
+    ReportConfig,
+};
 use api_models::{enums, payment_methods::RequiredFieldInfo};
 use common_utils::ext_traits::ConfigExt;
 use config::{Environment, File};
@@ -72,6 +75,7 @@ pub struct Settings<S: SecretState> {
     pub forex_api: SecretStateContainer<ForexApi, S>,
     pub refund: Refund,
     pub eph_key: EphemeralConfig,
+    pub client_session: ClientSessionConfig,
     pub scheduler: Option<SchedulerSettings>,
     #[cfg(feature = "kv_store")]
     pub drainer: DrainerSettings,
@@ -81,6 +85,7 @@ pub struct Settings<S: SecretState> {
     pub bank_config: BankRedirectConfig,
     pub api_keys: SecretStateContainer<ApiKeys, S>,
     pub file_storage: FileStorageConfig,
+    pub file_upload_config: FileUploadConfig,
     pub encryption_management: EncryptionManagementConfig,
     pub secrets_management: SecretsManagementConfig,
     pub tokenization: TokenizationConfig,
@@ -91,21 +96,43 @@ pub struct Settings<S: SecretState> {
     pub email: EmailSettings,
     pub cors: CorsSettings,
     pub mandates: Mandates,
+    pub recurring_payments: RecurringPaymentsConfig,
+    pub bulk_charges: BulkChargesConfig,
+    pub scheduled_reports: ScheduledReportsConfig,
+    pub success_rate_alerts: SuccessRateAlertsConfig,
+    pub payment_method_expiry: PaymentMethodExpiryConfig,
+    pub request_deadline: RequestDeadlineConfig,
     pub required_fields: RequiredFields,
+    pub required_fields_validation: RequiredFieldsValidation,
     pub delayed_session_response: DelayedSessionConfig,
     pub webhook_source_verification_call: WebhookSourceVerificationCall,
+    pub webhook_source_verification: WebhookSourceVerification,
     pub payment_method_auth: SecretStateContainer<PaymentMethodAuth, S>,
+    pub network_tokenization_service: SecretStateContainer<NetworkTokenizationService, S>,
+    pub network_tokenization_supported_connectors: NetworkTokenizationSupportedConnectors,
+    pub card_verification: CardVerification,
+    pub merchant_deletion: MerchantDeletion,
+    pub success_rate_routing: SuccessRateRouting,
+    pub connector_health: ConnectorHealth,
+    pub maintenance_mode: MaintenanceMode,
+    pub bin_lookup: SecretStateContainer<BinLookup, S>,
+    pub address_normalization: SecretStateContainer<AddressNormalization, S>,
     pub connector_request_reference_id_config: ConnectorRequestReferenceIdConfig,
     #[cfg(feature = "payouts")]
     pub payouts: Payouts,
     pub applepay_decrypt_keys: SecretStateContainer<ApplePayDecryptConifg, S>,
     pub multiple_api_version_supported_connectors: MultipleApiVersionSupportedConnectors,
+    pub trace_context_propagation: TraceContextPropagation,
     pub applepay_merchant_configs: SecretStateContainer<ApplepayMerchantConfigs, S>,
     pub lock_settings: LockSettings,
     pub temp_locker_enable_config: TempLockerEnableConfig,
     pub payment_link: PaymentLink,
     #[cfg(feature = "olap")]
     pub analytics: SecretStateContainer<AnalyticsConfig, S>,
+    #[cfg(feature = "olap")]
+    pub analytics_guardrails: AnalyticsGuardrailsConfig,
+    #[cfg(feature = "olap")]
+    pub analytics_cache: AnalyticsCacheConfig,
     #[cfg(feature = "kv_store")]
     pub kv_config: KvConfig,
     #[cfg(feature = "frm")]
@@ -164,6 +191,156 @@ pub struct PaymentMethodAuth {
     pub pm_auth_key: Secret<String>,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct NetworkTokenizationService {
+    pub enabled: bool,
+    pub token_service_url: String,
+    pub token_service_api_key: Secret<String>,
+    /// Card networks for which network tokens are provisioned when a card is saved
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub supported_card_networks: HashSet<api_models::enums::CardNetwork>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct NetworkTokenizationSupportedConnectors {
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub connector_list: HashSet<api_models::enums::Connector>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct BinLookup {
+    /// Whether the external BIN lookup service is queried for the BINs missing in the
+    /// `cards_info` table
+    pub external_provider_enabled: bool,
+    pub external_provider_url: String,
+    pub external_provider_api_key: Secret<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct AddressNormalization {
+    /// Whether the billing addresses of card payments are normalized on payment creation
+    pub enabled: bool,
+    /// Whether the addresses are normalized by the external address validation service instead
+    /// of the built-in rules
+    pub external_provider_enabled: bool,
+    pub external_provider_url: String,
+    pub external_provider_api_key: Secret<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct CardVerification {
+    /// Connectors which can verify a card through a zero amount authorization
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub supports_zero_auth: HashSet<api_models::enums::Connector>,
+    /// Amount, in the lowest denomination of the currency, which is authorized and voided to
+    /// verify a card with the connectors which do not support zero amount authorizations
+    pub verification_amount: i64,
+}
+
+impl Default for CardVerification {
+    fn default() -> Self {
+        Self {
+            supports_zero_auth: HashSet::new(),
+            verification_amount: 100,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct MerchantDeletion {
+    /// Number of days for which a deleted merchant account can be restored, after which it is
+    /// permanently deleted
+    pub retention_period_in_days: i64,
+}
+
+impl Default for MerchantDeletion {
+    fn default() -> Self {
+        Self {
+            retention_period_in_days: 30,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct SuccessRateRouting {
+    /// Kill switch for success rate based routing, when disabled connectors are chosen by the
+    /// static routing algorithm alone
+    pub enabled: bool,
+    /// Duration of each bucket in which payment outcomes are aggregated
+    pub bucket_size_in_secs: i64,
+    /// Number of most recent buckets considered while computing the success rate of a connector
+    pub window_size_in_buckets: u32,
+    /// Minimum number of payments a connector must have processed within the window for its
+    /// success rate to be considered
+    pub min_sample_size: i64,
+    /// Percentage of payments routed using the static routing order, so that connectors other
+    /// than the best performing one keep receiving traffic
+    pub exploration_percentage: u8,
+}
+
+impl Default for SuccessRateRouting {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            bucket_size_in_secs: 300,
+            window_size_in_buckets: 12,
+            min_sample_size: 20,
+            exploration_percentage: 10,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ConnectorHealth {
+    /// Kill switch for connector health tracking, when disabled degraded connectors are not
+    /// excluded from routing
+    pub enabled: bool,
+    /// Duration of each bucket in which connector responses are aggregated
+    pub bucket_size_in_secs: i64,
+    /// Number of most recent buckets considered while computing the error rate of a connector
+    pub window_size_in_buckets: u32,
+    /// Minimum number of responses a connector must have returned within the window for its
+    /// error rate to be considered
+    pub min_sample_size: i64,
+    /// Percentage of connector responses being errors or timeouts at which the connector is
+    /// marked as degraded
+    pub error_rate_threshold_percentage: u8,
+    /// Duration for which a degraded connector is excluded from routing, after which it receives
+    /// traffic again and is marked as recovered once its error rate is below the threshold
+    pub exclusion_duration_in_secs: i64,
+}
+
+impl Default for ConnectorHealth {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            bucket_size_in_secs: 60,
+            window_size_in_buckets: 5,
+            min_sample_size: 20,
+            error_rate_threshold_percentage: 50,
+            exclusion_duration_in_secs: 300,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct MaintenanceMode {
+    /// When enabled, creation of new payments is rejected while existing payments can still be
+    /// synced, captured and updated through webhooks
+    pub enabled: bool,
+    /// Merchants which can create payments while the maintenance mode is enabled
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub merchant_allowlist: HashSet<String>,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct DefaultExchangeRates {
     pub base_currency: String,
@@ -194,6 +371,17 @@ pub struct MultipleApiVersionSupportedConnectors {
     pub supported_connectors: HashSet<api_models::enums::Connector>,
 }
 
+/// Propagation of the W3C trace context of the payment flows to the connectors called, so that the
+/// traces span the proxies in front of the connectors. The trace context is not propagated to any
+/// connector by default, as some connector endpoints are in PCI scope.
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct TraceContextPropagation {
+    /// Connectors whose requests carry the trace context headers
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub connectors: HashSet<api_models::enums::Connector>,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 #[serde(transparent)]
 pub struct TokenizationConfig(pub HashMap<String, PaymentMethodTokenFilter>);
@@ -249,6 +437,79 @@ pub struct CorsSettings {
 pub struct Mandates {
     pub supported_payment_methods: SupportedPaymentMethodsForMandate,
     pub update_mandate_supported: SupportedPaymentMethodsForMandate,
+    /// Connector error codes which indicate that the mandate has been revoked at the connector
+    #[serde(default)]
+    pub revoked_mandate_error_codes: RevokedMandateErrorCodes,
+}
+
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct RevokedMandateErrorCodes(
+    pub HashMap<api_models::enums::Connector, ErrorCodesForConnector>,
+);
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ErrorCodesForConnector {
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub error_codes: HashSet<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RecurringPaymentsConfig {
+    /// Hours to wait before each dunning retry of a failed recurring charge, used when the
+    /// schedule does not specify its own retry intervals
+    pub dunning_retry_intervals_in_hours: Vec<u32>,
+    /// Maximum number of dunning retries a schedule is allowed to configure
+    pub max_dunning_retries: usize,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct BulkChargesConfig {
+    /// Maximum number of rows accepted in a single bulk charge file
+    pub max_rows: usize,
+    /// Maximum number of payments of a bulk charge created concurrently
+    pub max_concurrency: usize,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ScheduledReportsConfig {
+    /// Maximum number of active report schedules of a merchant
+    pub max_schedules_per_merchant: usize,
+    /// Maximum number of rows of a generated report, the run fails if the period has more records
+    pub max_report_rows: usize,
+    /// Timeout in seconds of the connection to the SFTP servers the reports are uploaded to
+    pub sftp_timeout_secs: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct SuccessRateAlertsConfig {
+    /// Maximum number of active success rate alerts of a merchant
+    pub max_alerts_per_merchant: usize,
+    /// Interval in seconds at which the success rate alerts are evaluated
+    pub evaluation_interval_in_secs: u32,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct PaymentMethodExpiryConfig {
+    /// Whether the scheduler producer adds the task notifying the saved cards expiring next month
+    pub enabled: bool,
+    /// Number of saved cards read from the database at once while looking for expiring cards
+    pub batch_size: i64,
+    /// Whether the customers are notified over email in addition to the webhook to the merchant
+    pub notify_customers_by_email: bool,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct RequestDeadlineConfig {
+    /// Timeout of requests whose clients do not send the `X-Request-Timeout` header
+    pub default_timeout_ms: u64,
+    /// Maximum timeout of requests, bounding the timeouts sent by clients
+    pub max_timeout_ms: u64,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -341,6 +602,10 @@ pub struct CurrencyCountryFlowFilter {
     pub currency: Option<HashSet<api_models::enums::Currency>>,
     #[serde(deserialize_with = "deserialize_optional_hashset")]
     pub country: Option<HashSet<api_models::enums::CountryAlpha2>>,
+    /// The minimum amount, in the lowest denomination of the currency, supported by the connector
+    pub minimum_amount: Option<i64>,
+    /// The maximum amount, in the lowest denomination of the currency, supported by the connector
+    pub maximum_amount: Option<i64>,
     pub not_available_flows: Option<NotAvailableFlows>,
 }
 
@@ -348,11 +613,21 @@ pub struct CurrencyCountryFlowFilter {
 #[serde(default)]
 pub struct NotAvailableFlows {
     pub capture_method: Option<enums::CaptureMethod>,
+    /// Set to `true` if the connector cannot set up a mandate through a zero amount payment
+    pub zero_amount_mandate: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
 pub struct RequiredFields(pub HashMap<enums::PaymentMethod, PaymentMethodType>);
 
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct RequiredFieldsValidation {
+    /// Whether payments are validated against the fields required by the connector before being
+    /// sent to the connector
+    pub enabled: bool,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct PaymentMethodType(pub HashMap<enums::PaymentMethodType, ConnectorFields>);
 
@@ -393,6 +668,10 @@ pub struct Locker {
 pub struct Refund {
     pub max_attempts: usize,
     pub max_age: i64,
+    /// Number of automatic retries of refunds failing with a transient connector error
+    pub max_retries: i16,
+    /// Delay in seconds before the first retry of a refund, doubled on every subsequent retry
+    pub retry_interval: i64,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -401,6 +680,23 @@ pub struct EphemeralConfig {
     pub validity: i64,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ClientSessionConfig {
+    /// Time in seconds for which a client session remains valid after it is created
+    pub validity_in_seconds: i64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct FileUploadConfig {
+    /// Maximum size (in bytes) of a file accepted by the files module
+    pub max_file_size: i32,
+    /// Content types that may be stored through the files module
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub allowed_mime_types: HashSet<String>,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 #[serde(default)]
 pub struct Jwekey {
@@ -585,6 +881,34 @@ pub struct DrainerSettings {
 pub struct WebhooksSettings {
     pub outgoing_enabled: bool,
     pub ignore_error: WebhookIgnoreErrorSettings,
+    pub delivery_worker: WebhookDeliveryWorker,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct WebhookDeliveryWorker {
+    /// Maximum number of outgoing webhooks being delivered at once by the application
+    pub max_concurrent_deliveries: usize,
+    /// Maximum number of outgoing webhooks of a single merchant being delivered at once, so that
+    /// merchants with a large number of events don't delay the delivery of other merchants' events
+    pub max_concurrent_deliveries_per_merchant: usize,
+    /// Maximum number of outgoing webhooks waiting to be delivered, deliveries which can't be
+    /// queued are left to the automatic retries
+    pub queue_size: usize,
+    /// Number of consecutive failed deliveries after which a webhook endpoint is considered as
+    /// unhealthy
+    pub endpoint_unhealthy_threshold: u32,
+}
+
+impl Default for WebhookDeliveryWorker {
+    fn default() -> Self {
+        Self {
+            max_concurrent_deliveries: 100,
+            max_concurrent_deliveries_per_merchant: 10,
+            queue_size: 10000,
+            endpoint_unhealthy_threshold: 5,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Default)]
@@ -618,6 +942,34 @@ pub struct WebhookSourceVerificationCall {
     pub connectors_with_webhook_source_verification_call: HashSet<api_models::enums::Connector>,
 }
 
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct WebhookSourceVerification {
+    /// Whether incoming webhooks failing source verification are rejected, or only logged and
+    /// processed by syncing the resource with the connector
+    pub enforcement: WebhookSourceVerificationEnforcement,
+    /// Connectors whose webhooks failing source verification are only logged regardless of the
+    /// enforcement, while their merchants are configuring the webhook secrets
+    #[serde(deserialize_with = "deserialize_hashset")]
+    pub warn_only_connectors: HashSet<api_models::enums::Connector>,
+}
+
+impl WebhookSourceVerification {
+    /// Whether webhooks of the connector failing source verification are rejected
+    pub fn is_mandatory(&self, connector: &api_models::enums::Connector) -> bool {
+        self.enforcement == WebhookSourceVerificationEnforcement::Mandatory
+            && !self.warn_only_connectors.contains(connector)
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookSourceVerificationEnforcement {
+    #[default]
+    Mandatory,
+    WarnOnly,
+}
+
 #[derive(Debug, Deserialize, Clone, Default)]
 pub struct ApplePayDecryptConifg {
     pub apple_pay_ppc: Secret<String>,
@@ -698,6 +1050,10 @@ impl Settings<SecuredSecret> {
                 ));
             }
         }
+        self.log
+            .file
+            .validate()
+            .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.into()))?;
         self.secrets.get_inner().validate()?;
         self.locker.validate()?;
         self.connectors.validate("connectors")?;
@@ -711,14 +1067,39 @@ impl Settings<SecuredSecret> {
         #[cfg(feature = "kv_store")]
         self.drainer.validate()?;
         self.api_keys.get_inner().validate()?;
+        self.network_tokenization_service.get_inner().validate()?;
+        self.card_verification.validate()?;
+        self.merchant_deletion.validate()?;
+        self.success_rate_routing.validate()?;
+        self.connector_health.validate()?;
+        self.webhooks.delivery_worker.validate()?;
+        self.bin_lookup.get_inner().validate()?;
+        self.address_normalization.get_inner().validate()?;
 
         self.file_storage
             .validate()
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.to_string()))?;
+        self.file_upload_config.validate()?;
+        self.recurring_payments.validate()?;
+        self.bulk_charges.validate()?;
+        self.scheduled_reports.validate()?;
+        self.success_rate_alerts.validate()?;
+        self.payment_method_expiry.validate()?;
+        self.request_deadline.validate()?;
 
         self.lock_settings.validate()?;
         self.events.validate()?;
 
+        #[cfg(feature = "olap")]
+        self.analytics_guardrails
+            .validate()
+            .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.into()))?;
+
+        #[cfg(feature = "olap")]
+        self.analytics_cache
+            .validate()
+            .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.into()))?;
+
         self.encryption_management
             .validate()
             .map_err(|err| ApplicationError::InvalidConfigurationValueError(err.into()))?;
@@ -47,6 +47,171 @@ impl super::settings::Locker {
     }
 }
 
+impl super::settings::NetworkTokenizationService {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.enabled && self.token_service_url.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "network tokenization service url must not be empty when network tokenization is enabled"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::BinLookup {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.external_provider_enabled && self.external_provider_url.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "external BIN lookup provider url must not be empty when the external provider is enabled"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::AddressNormalization {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.external_provider_enabled && self.external_provider_url.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "external address normalization provider url must not be empty when the external provider is enabled"
+                        .into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::CardVerification {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.verification_amount <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "card verification amount must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::MerchantDeletion {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.retention_period_in_days <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "merchant deletion retention period must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::SuccessRateRouting {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.bucket_size_in_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "success rate routing bucket size must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.window_size_in_buckets == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "success rate routing window size must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.exploration_percentage > 100, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "success rate routing exploration percentage must not exceed 100".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::WebhookDeliveryWorker {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.max_concurrent_deliveries == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "webhook delivery worker concurrency must be greater than zero".into(),
+            ))
+        })?;
+
+        when(
+            self.max_concurrent_deliveries_per_merchant == 0
+                || self.max_concurrent_deliveries_per_merchant > self.max_concurrent_deliveries,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "webhook delivery worker concurrency per merchant must be between 1 and the \
+                     overall concurrency"
+                        .into(),
+                ))
+            },
+        )?;
+
+        when(self.queue_size == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "webhook delivery worker queue size must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.endpoint_unhealthy_threshold == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "webhook endpoint unhealthy threshold must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::ConnectorHealth {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.bucket_size_in_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector health bucket size must be greater than zero".into(),
+            ))
+        })?;
+
+        when(self.window_size_in_buckets == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector health window size must be greater than zero".into(),
+            ))
+        })?;
+
+        when(
+            self.error_rate_threshold_percentage == 0 || self.error_rate_threshold_percentage > 100,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "connector health error rate threshold must be between 1 and 100".into(),
+                ))
+            },
+        )?;
+
+        when(self.exclusion_duration_in_secs <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "connector health exclusion duration must be greater than zero".into(),
+            ))
+        })
+    }
+}
+
 impl super::settings::Server {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         use common_utils::fp_utils::when;
@@ -180,3 +345,137 @@ impl super::settings::LockSettings {
         })
     }
 }
+
+impl super::settings::RecurringPaymentsConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(
+            self.dunning_retry_intervals_in_hours.len() > self.max_dunning_retries,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "recurring payments dunning_retry_intervals_in_hours must not have more entries than max_dunning_retries".into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.dunning_retry_intervals_in_hours
+                .iter()
+                .any(|interval| *interval == 0),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "recurring payments dunning retry intervals must be greater than 0".into(),
+                ))
+            },
+        )
+    }
+}
+
+impl super::settings::BulkChargesConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.max_rows == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "bulk charges max_rows must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.max_concurrency == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "bulk charges max_concurrency must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::ScheduledReportsConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.max_schedules_per_merchant == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "scheduled reports max_schedules_per_merchant must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.max_report_rows == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "scheduled reports max_report_rows must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.sftp_timeout_secs == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "scheduled reports sftp_timeout_secs must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::SuccessRateAlertsConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.max_alerts_per_merchant == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "success rate alerts max_alerts_per_merchant must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.evaluation_interval_in_secs == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "success rate alerts evaluation_interval_in_secs must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::PaymentMethodExpiryConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.batch_size <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "payment method expiry batch_size must be greater than 0".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::RequestDeadlineConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.default_timeout_ms == 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "request deadline default_timeout_ms must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.max_timeout_ms < self.default_timeout_ms, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "request deadline max_timeout_ms must not be less than default_timeout_ms".into(),
+            ))
+        })
+    }
+}
+
+impl super::settings::FileUploadConfig {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::fp_utils::when;
+
+        when(self.max_file_size <= 0, || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "file upload max_file_size must be greater than 0".into(),
+            ))
+        })?;
+
+        when(self.allowed_mime_types.is_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "file upload allowed_mime_types must not be empty".into(),
+            ))
+        })
+    }
+}
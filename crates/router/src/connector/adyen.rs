@@ -239,6 +239,10 @@ impl ConnectorValidation for Adyen {
     fn is_webhook_source_verification_mandatory(&self) -> bool {
         true
     }
+    fn is_refund_supported(&self, payment_method: Option<enums::PaymentMethod>) -> bool {
+        // Vouchers are paid in cash, the amount cannot be returned to the voucher
+        !matches!(payment_method, Some(enums::PaymentMethod::Voucher))
+    }
 }
 
 impl api::Payment for Adyen {}
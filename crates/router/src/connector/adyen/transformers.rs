@@ -1,3 +1,5 @@
+use std::str::FromStr;
+
 #[cfg(feature = "payouts")]
 use api_models::payouts::PayoutMethodData;
 use api_models::{enums, payments, webhooks};
@@ -118,6 +120,15 @@ pub struct AdditionalData {
     #[cfg(feature = "payouts")]
     payout_eligible: Option<PayoutEligibility>,
     funds_availability: Option<String>,
+    sca_exemption: Option<AdyenScaExemption>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AdyenScaExemption {
+    LowValue,
+    TransactionRiskAnalysis,
+    SecureCorporate,
 }
 
 #[derive(Default, Debug, Serialize, Deserialize)]
@@ -1201,10 +1212,35 @@ pub struct DokuBankData {
 pub struct AdyenRefundRequest {
     merchant_account: Secret<String>,
     amount: Amount,
-    merchant_refund_reason: Option<String>,
+    merchant_refund_reason: Option<AdyenRefundReason>,
     reference: String,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, strum::EnumString)]
+#[serde(rename_all = "UPPERCASE")]
+#[strum(serialize_all = "UPPERCASE", ascii_case_insensitive)]
+pub enum AdyenRefundReason {
+    Fraud,
+    #[serde(rename = "CUSTOMER REQUEST")]
+    #[strum(serialize = "CUSTOMER REQUEST")]
+    CustomerRequest,
+    Return,
+    Duplicate,
+    Other,
+}
+
+impl From<enums::RefundReasonCode> for AdyenRefundReason {
+    fn from(reason_code: enums::RefundReasonCode) -> Self {
+        match reason_code {
+            enums::RefundReasonCode::Duplicate => Self::Duplicate,
+            enums::RefundReasonCode::Fraudulent => Self::Fraud,
+            enums::RefundReasonCode::RequestedByCustomer
+            | enums::RefundReasonCode::ProductNotReceived => Self::CustomerRequest,
+            enums::RefundReasonCode::Other => Self::Other,
+        }
+    }
+}
+
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct AdyenRefundResponse {
@@ -1708,10 +1744,28 @@ fn get_additional_data(item: &types::PaymentsAuthorizeRouterData) -> Option<Addi
         recurring_detail_reference: None,
         recurring_shopper_reference: None,
         recurring_processing_model: None,
+        sca_exemption: get_sca_exemption(item.request.get_sca_exemption()),
         ..AdditionalData::default()
     })
 }
 
+/// Merchant initiated transactions are flagged through the recurring processing model, and are
+/// not requested as an exemption
+fn get_sca_exemption(
+    sca_exemption: Option<storage_enums::ScaExemptionType>,
+) -> Option<AdyenScaExemption> {
+    match sca_exemption? {
+        storage_enums::ScaExemptionType::LowValue => Some(AdyenScaExemption::LowValue),
+        storage_enums::ScaExemptionType::TransactionRiskAnalysis => {
+            Some(AdyenScaExemption::TransactionRiskAnalysis)
+        }
+        storage_enums::ScaExemptionType::SecureCorporatePayment => {
+            Some(AdyenScaExemption::SecureCorporate)
+        }
+        storage_enums::ScaExemptionType::MerchantInitiatedTransaction => None,
+    }
+}
+
 fn get_channel_type(pm_type: &Option<storage_enums::PaymentMethodType>) -> Option<Channel> {
     pm_type.as_ref().and_then(|pmt| match pmt {
         storage_enums::PaymentMethodType::GoPay => Some(Channel::Web),
@@ -4014,7 +4068,17 @@ impl<F> TryFrom<&AdyenRouterData<&types::RefundsRouterData<F>>> for AdyenRefundR
                 currency: item.router_data.request.currency,
                 value: item.router_data.request.refund_amount,
             },
-            merchant_refund_reason: item.router_data.request.reason.clone(),
+            // Adyen only accepts a fixed set of reasons, the free text reason is sent only when it
+            // is one of them
+            merchant_refund_reason: match item.router_data.request.reason_code {
+                Some(reason_code) => Some(AdyenRefundReason::from(reason_code)),
+                None => item
+                    .router_data
+                    .request
+                    .reason
+                    .as_deref()
+                    .and_then(|reason| AdyenRefundReason::from_str(reason).ok()),
+            },
             reference: item.router_data.request.refund_id.clone(),
         })
     }
@@ -2,9 +2,9 @@ pub mod transformers;
 use std::fmt::Debug;
 
 use base64::Engine;
-use common_utils::request::RequestContent;
+use common_utils::{crypto, request::RequestContent};
 use diesel_models::enums;
-use error_stack::{IntoReport, ResultExt};
+use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
 use transformers as cashtocode;
 
@@ -22,7 +22,7 @@ use crate::{
     types::{
         self,
         api::{self, ConnectorCommon, ConnectorCommonExt},
-        domain, storage, ErrorResponse, Response,
+        storage, ErrorResponse, Response,
     },
     utils::{ByteSliceExt, BytesExt},
 };
@@ -352,6 +352,13 @@ impl ConnectorIntegration<api::Void, types::PaymentsCancelData, types::PaymentsR
 
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Cashtocode {
+    fn get_webhook_source_verification_algorithm(
+        &self,
+        _request: &api::IncomingWebhookRequestDetails<'_>,
+    ) -> CustomResult<Box<dyn crypto::VerifySignature + Send>, errors::ConnectorError> {
+        Ok(Box::new(crypto::SharedSecret))
+    }
+
     fn get_webhook_source_verification_signature(
         &self,
         request: &api::IncomingWebhookRequestDetails<'_>,
@@ -363,37 +370,6 @@ impl api::IncomingWebhook for Cashtocode {
         Ok(signature)
     }
 
-    async fn verify_webhook_source(
-        &self,
-        request: &api::IncomingWebhookRequestDetails<'_>,
-        merchant_account: &domain::MerchantAccount,
-        merchant_connector_account: domain::MerchantConnectorAccount,
-        connector_label: &str,
-    ) -> CustomResult<bool, errors::ConnectorError> {
-        let connector_webhook_secrets = self
-            .get_webhook_source_verification_merchant_secret(
-                merchant_account,
-                connector_label,
-                merchant_connector_account,
-            )
-            .await
-            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
-
-        let signature = self
-            .get_webhook_source_verification_signature(request, &connector_webhook_secrets)
-            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)?;
-
-        let secret_auth = String::from_utf8(connector_webhook_secrets.secret.to_vec())
-            .into_report()
-            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)
-            .attach_printable("Could not convert secret to UTF-8")?;
-        let signature_auth = String::from_utf8(signature.to_vec())
-            .into_report()
-            .change_context(errors::ConnectorError::WebhookSourceVerificationFailed)
-            .attach_printable("Could not convert secret to UTF-8")?;
-        Ok(signature_auth == secret_auth)
-    }
-
     fn get_webhook_object_reference_id(
         &self,
         request: &api::IncomingWebhookRequestDetails<'_>,
@@ -954,6 +954,11 @@ impl api::FileUpload for Checkout {
                     })?
                 }
             }
+            api::FilePurpose::AnalyticsReport => {
+                Err(errors::ConnectorError::FileValidationFailed {
+                    reason: "analytics reports cannot be uploaded to the connector".to_owned(),
+                })?
+            }
         }
         Ok(())
     }
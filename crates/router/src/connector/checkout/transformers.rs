@@ -7,11 +7,11 @@ use url::Url;
 
 use crate::{
     connector::utils::{
-        self, to_connector_meta, ApplePayDecrypt, PaymentsCaptureRequestData, RouterData,
-        WalletData,
+        self, to_connector_meta, ApplePayDecrypt, PaymentsAuthorizeRequestData,
+        PaymentsCaptureRequestData, RouterData, WalletData,
     },
     consts,
-    core::errors,
+    core::{errors, payments},
     services,
     types::{self, api, storage::enums, transformers::ForeignFrom},
 };
@@ -191,12 +191,32 @@ pub struct WalletSource {
     pub token: Secret<String>,
 }
 
+#[derive(Debug, Serialize)]
+pub struct NetworkTokenSource {
+    #[serde(rename = "type")]
+    pub source_type: CheckoutSourceTypes,
+    pub token: cards::CardNumber,
+    pub token_type: CheckoutNetworkTokenType,
+    pub expiry_month: Secret<String>,
+    pub expiry_year: Secret<String>,
+    pub cryptogram: Secret<String>,
+    pub eci: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckoutNetworkTokenType {
+    Vts,
+    Mdes,
+}
+
 #[derive(Debug, Serialize)]
 #[serde(untagged)]
 pub enum PaymentSource {
     Card(CardSource),
     Wallets(WalletSource),
     ApplePayPredecrypt(Box<ApplePayPredecrypt>),
+    NetworkToken(Box<NetworkTokenSource>),
 }
 
 #[derive(Debug, Serialize)]
@@ -216,6 +236,8 @@ pub struct ApplePayPredecrypt {
 pub enum CheckoutSourceTypes {
     Card,
     Token,
+    #[serde(rename = "network_token")]
+    NetworkToken,
 }
 
 pub struct CheckoutAuthType {
@@ -264,6 +286,29 @@ pub struct CheckoutThreeDS {
     cryptogram: Option<String>,
     xid: Option<String>,
     version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exemption: Option<CheckoutThreeDSExemption>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckoutThreeDSExemption {
+    LowValue,
+    TransactionRiskAssessment,
+    SecureCorporatePayment,
+    OutOfScaScope,
+}
+
+impl From<enums::ScaExemptionType> for CheckoutThreeDSExemption {
+    fn from(sca_exemption: enums::ScaExemptionType) -> Self {
+        match sca_exemption {
+            enums::ScaExemptionType::LowValue => Self::LowValue,
+            enums::ScaExemptionType::TransactionRiskAnalysis => Self::TransactionRiskAssessment,
+            enums::ScaExemptionType::SecureCorporatePayment => Self::SecureCorporatePayment,
+            // Merchant initiated transactions are out of the scope of strong customer authentication
+            enums::ScaExemptionType::MerchantInitiatedTransaction => Self::OutOfScaScope,
+        }
+    }
 }
 
 impl TryFrom<&types::ConnectorAuthType> for CheckoutAuthType {
@@ -285,6 +330,26 @@ impl TryFrom<&types::ConnectorAuthType> for CheckoutAuthType {
         }
     }
 }
+fn get_network_token_source(
+    network_token_data: &payments::types::NetworkTokenData,
+) -> Option<PaymentSource> {
+    let token_type = match network_token_data.card_network {
+        enums::CardNetwork::Visa => CheckoutNetworkTokenType::Vts,
+        enums::CardNetwork::Mastercard => CheckoutNetworkTokenType::Mdes,
+        _ => return None,
+    };
+
+    Some(PaymentSource::NetworkToken(Box::new(NetworkTokenSource {
+        source_type: CheckoutSourceTypes::NetworkToken,
+        token: network_token_data.token_number.clone(),
+        token_type,
+        expiry_month: network_token_data.token_exp_month.clone(),
+        expiry_year: network_token_data.token_exp_year.clone(),
+        cryptogram: network_token_data.token_cryptogram.clone(),
+        eci: network_token_data.eci.clone(),
+    })))
+}
+
 impl TryFrom<&CheckoutRouterData<&types::PaymentsAuthorizeRouterData>> for PaymentsRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(
@@ -292,13 +357,21 @@ impl TryFrom<&CheckoutRouterData<&types::PaymentsAuthorizeRouterData>> for Payme
     ) -> Result<Self, Self::Error> {
         let source_var = match item.router_data.request.payment_method_data.clone() {
             api::PaymentMethodData::Card(ccard) => {
-                let a = PaymentSource::Card(CardSource {
-                    source_type: CheckoutSourceTypes::Card,
-                    number: ccard.card_number.clone(),
-                    expiry_month: ccard.card_exp_month.clone(),
-                    expiry_year: ccard.card_exp_year.clone(),
-                    cvv: ccard.card_cvc,
-                });
+                let a = item
+                    .router_data
+                    .request
+                    .network_token_data
+                    .as_ref()
+                    .and_then(get_network_token_source)
+                    .unwrap_or_else(|| {
+                        PaymentSource::Card(CardSource {
+                            source_type: CheckoutSourceTypes::Card,
+                            number: ccard.card_number.clone(),
+                            expiry_month: ccard.card_exp_month.clone(),
+                            expiry_year: ccard.card_exp_year.clone(),
+                            cvv: ccard.card_cvc,
+                        })
+                    });
                 Ok(a)
             }
             api::PaymentMethodData::Wallet(wallet_data) => match wallet_data {
@@ -386,6 +459,11 @@ impl TryFrom<&CheckoutRouterData<&types::PaymentsAuthorizeRouterData>> for Payme
         }?;
 
         let authentication_data = item.router_data.request.authentication_data.as_ref();
+        let exemption = item
+            .router_data
+            .request
+            .get_sca_exemption()
+            .map(CheckoutThreeDSExemption::from);
 
         let three_ds = match item.router_data.auth_type {
             enums::AuthenticationType::ThreeDs => CheckoutThreeDS {
@@ -395,6 +473,7 @@ impl TryFrom<&CheckoutRouterData<&types::PaymentsAuthorizeRouterData>> for Payme
                 cryptogram: authentication_data.map(|auth| auth.cavv.clone()),
                 xid: authentication_data.map(|auth| auth.threeds_server_transaction_id.clone()),
                 version: authentication_data.map(|auth| auth.message_version.clone()),
+                exemption,
             },
             enums::AuthenticationType::NoThreeDs => CheckoutThreeDS {
                 enabled: false,
@@ -403,6 +482,7 @@ impl TryFrom<&CheckoutRouterData<&types::PaymentsAuthorizeRouterData>> for Payme
                 cryptogram: None,
                 xid: None,
                 version: None,
+                exemption,
             },
         };
 
@@ -1399,7 +1399,7 @@ pub enum CybersourcePaymentStatus {
     PendingReview,
     Accepted,
     Cancelled,
-    //PartialAuthorized, not being consumed yet.
+    PartialAuthorized,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -1413,6 +1413,8 @@ pub enum CybersourceIncrementalAuthorizationStatus {
 impl ForeignFrom<(CybersourcePaymentStatus, bool)> for enums::AttemptStatus {
     fn foreign_from((status, capture): (CybersourcePaymentStatus, bool)) -> Self {
         match status {
+            // Partial authorizations are left to be captured or voided, as configured in the profile
+            CybersourcePaymentStatus::PartialAuthorized => Self::Authorized,
             CybersourcePaymentStatus::Authorized
             | CybersourcePaymentStatus::AuthorizedPendingReview => {
                 if capture {
@@ -1476,6 +1478,54 @@ pub struct CybersourceClientReferenceResponse {
     risk_information: Option<ClientRiskInformation>,
     token_information: Option<CybersourceTokenInformation>,
     error_information: Option<CybersourceErrorInformation>,
+    order_information: Option<ClientOrderInformation>,
+}
+
+impl CybersourceClientReferenceResponse {
+    fn get_connector_response(
+        &self,
+        currency: enums::Currency,
+    ) -> Result<Option<types::ConnectorResponseData>, error_stack::Report<errors::ConnectorError>>
+    {
+        let card_verification_data = self
+            .processor_information
+            .as_ref()
+            .and_then(ClientProcessorInformation::get_card_verification_data);
+        let partially_authorized_amount = match self.status {
+            CybersourcePaymentStatus::PartialAuthorized => self
+                .order_information
+                .as_ref()
+                .and_then(|order_information| order_information.amount_details.as_ref())
+                .and_then(|amount_details| amount_details.authorized_amount.as_deref())
+                .map(|authorized_amount| {
+                    utils::get_amount_in_lower_unit(authorized_amount, currency)
+                })
+                .transpose()?,
+            _ => None,
+        };
+        if card_verification_data.is_none() && partially_authorized_amount.is_none() {
+            return Ok(None);
+        }
+        Ok(Some(types::ConnectorResponseData {
+            additional_payment_method_data: None,
+            settlement_data: None,
+            card_verification_data,
+            partially_authorized_amount,
+        }))
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientOrderInformation {
+    amount_details: Option<ClientAmountDetails>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClientAmountDetails {
+    /// Amount approved by the issuer, in the base unit of the currency
+    authorized_amount: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -1532,6 +1582,30 @@ pub struct ClientReferenceInformation {
 #[serde(rename_all = "camelCase")]
 pub struct ClientProcessorInformation {
     avs: Option<Avs>,
+    card_verification: Option<CardVerification>,
+}
+
+impl ClientProcessorInformation {
+    fn get_card_verification_data(&self) -> Option<types::CardVerificationData> {
+        let avs_result_code = self.avs.as_ref().map(|avs| avs.code.clone());
+        let cvv_result_code = self
+            .card_verification
+            .as_ref()
+            .and_then(|card_verification| card_verification.result_code.clone());
+        if avs_result_code.is_none() && cvv_result_code.is_none() {
+            return None;
+        }
+        Some(types::CardVerificationData {
+            avs_result: avs_result_code
+                .as_deref()
+                .map(utils::get_avs_result_from_code),
+            avs_result_code,
+            cvv_result: cvv_result_code
+                .as_deref()
+                .map(utils::get_cvv_result_from_code),
+            cvv_result_code,
+        })
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1541,6 +1615,12 @@ pub struct Avs {
     code_raw: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CardVerification {
+    result_code: Option<String>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ClientRiskInformation {
@@ -1701,9 +1781,12 @@ impl<F>
                     item.data.request.is_auto_capture()?,
                 ));
                 let response = get_payment_response((&info_response, status, item.http_code));
+                let connector_response =
+                    info_response.get_connector_response(item.data.request.currency)?;
                 Ok(Self {
                     status,
                     response,
+                    connector_response,
                     ..item.data
                 })
             }
@@ -2187,9 +2270,12 @@ impl<F>
                     item.data.request.is_auto_capture()?,
                 ));
                 let response = get_payment_response((&info_response, status, item.http_code));
+                let connector_response =
+                    info_response.get_connector_response(item.data.request.currency)?;
                 Ok(Self {
                     status,
                     response,
+                    connector_response,
                     ..item.data
                 })
             }
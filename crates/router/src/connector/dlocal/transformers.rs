@@ -121,8 +121,13 @@ impl TryFrom<&DlocalRouterData<&types::PaymentsAuthorizeRouterData>> for DlocalP
                     payer: Payer {
                         name,
                         email,
-                        // [#589]: Allow securely collecting PII from customer in payments request
-                        document: get_doc_from_currency(country.to_string()),
+                        document: item
+                            .router_data
+                            .request
+                            .customer_document
+                            .as_ref()
+                            .map(|document| document.document_number.clone())
+                            .unwrap_or_else(|| get_doc_from_currency(country.to_string())),
                     },
                     card: Some(Card {
                         holder_name: ccard
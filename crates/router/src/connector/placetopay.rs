@@ -128,6 +128,10 @@ impl ConnectorValidation for Placetopay {
             )),
         }
     }
+
+    fn is_partial_refund_supported(&self) -> bool {
+        false
+    }
 }
 
 impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
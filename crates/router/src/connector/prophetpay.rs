@@ -130,7 +130,9 @@ impl ConnectorCommon for Prophetpay {
 }
 
 impl ConnectorValidation for Prophetpay {
-    //TODO: implement functions when support enabled
+    fn is_partial_refund_supported(&self) -> bool {
+        false
+    }
 }
 
 impl ConnectorIntegration<api::Session, types::PaymentsSessionData, types::PaymentsResponseData>
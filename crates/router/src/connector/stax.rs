@@ -850,6 +850,10 @@ impl ConnectorIntegration<api::RSync, types::RefundsData, types::RefundsResponse
 
 #[async_trait::async_trait]
 impl api::IncomingWebhook for Stax {
+    fn is_webhook_source_verification_supported(&self) -> bool {
+        false
+    }
+
     async fn verify_webhook_source(
         &self,
         _request: &api::IncomingWebhookRequestDetails<'_>,
@@ -1534,6 +1534,11 @@ impl api::FileUpload for Stripe {
                     })?
                 }
             }
+            api::FilePurpose::AnalyticsReport => {
+                Err(errors::ConnectorError::FileValidationFailed {
+                    reason: "analytics reports cannot be uploaded to the connector".to_owned(),
+                })?
+            }
         }
         Ok(())
     }
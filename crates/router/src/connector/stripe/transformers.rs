@@ -2293,7 +2293,59 @@ impl From<AdditionalPaymentMethodDetails> for types::AdditionalPaymentMethodConn
     }
 }
 
+impl StripeAdditionalCardDetails {
+    fn get_check_result(&self, check: &str) -> Option<enums::CardCheckResult> {
+        self.checks
+            .as_ref()
+            .and_then(|checks| checks.get(check))
+            .and_then(Value::as_str)
+            .map(|check_result| match check_result {
+                "pass" => enums::CardCheckResult::Match,
+                "fail" => enums::CardCheckResult::Mismatch,
+                _ => enums::CardCheckResult::Unavailable,
+            })
+    }
+
+    /// Stripe does not return the raw AVS code, the AVS result is derived from the separate checks
+    /// on the first address line and the postal code
+    fn get_card_verification_data(&self) -> Option<types::CardVerificationData> {
+        let avs_result = match (
+            self.get_check_result("address_line1_check"),
+            self.get_check_result("address_postal_code_check"),
+        ) {
+            (None, None) => None,
+            (Some(enums::CardCheckResult::Match), Some(enums::CardCheckResult::Match)) => {
+                Some(enums::CardCheckResult::Match)
+            }
+            (Some(enums::CardCheckResult::Match), _) | (_, Some(enums::CardCheckResult::Match)) => {
+                Some(enums::CardCheckResult::PartialMatch)
+            }
+            (Some(enums::CardCheckResult::Mismatch), _)
+            | (_, Some(enums::CardCheckResult::Mismatch)) => Some(enums::CardCheckResult::Mismatch),
+            _ => Some(enums::CardCheckResult::Unavailable),
+        };
+        let cvv_result = self.get_check_result("cvc_check");
+        if avs_result.is_none() && cvv_result.is_none() {
+            return None;
+        }
+        Some(types::CardVerificationData {
+            avs_result_code: None,
+            avs_result,
+            cvv_result_code: None,
+            cvv_result,
+        })
+    }
+}
+
 impl StripePaymentMethodDetailsResponse {
+    pub fn get_card_verification_data(&self) -> Option<types::CardVerificationData> {
+        if let Self::Card { card } = self {
+            card.get_card_verification_data()
+        } else {
+            None
+        }
+    }
+
     pub fn get_additional_payment_method_data(&self) -> Option<AdditionalPaymentMethodDetails> {
         match self {
             Self::Card { card } => Some(AdditionalPaymentMethodDetails {
@@ -2390,12 +2442,10 @@ fn extract_payment_method_connector_response_from_latest_charge(
         charge_object
             .payment_method_details
             .as_ref()
-            .and_then(StripePaymentMethodDetailsResponse::get_additional_payment_method_data)
+            .and_then(get_connector_response_from_payment_method_details)
     } else {
         None
     }
-    .map(types::AdditionalPaymentMethodConnectorResponse::from)
-    .map(types::ConnectorResponseData::with_additional_payment_method_data)
 }
 
 fn extract_payment_method_connector_response_from_latest_attempt(
@@ -2405,12 +2455,23 @@ fn extract_payment_method_connector_response_from_latest_attempt(
         intent_attempt
             .payment_method_details
             .as_ref()
-            .and_then(StripePaymentMethodDetailsResponse::get_additional_payment_method_data)
+            .and_then(get_connector_response_from_payment_method_details)
     } else {
         None
     }
-    .map(types::AdditionalPaymentMethodConnectorResponse::from)
-    .map(types::ConnectorResponseData::with_additional_payment_method_data)
+}
+
+fn get_connector_response_from_payment_method_details(
+    payment_method_details: &StripePaymentMethodDetailsResponse,
+) -> Option<types::ConnectorResponseData> {
+    payment_method_details
+        .get_additional_payment_method_data()
+        .map(types::AdditionalPaymentMethodConnectorResponse::from)
+        .map(types::ConnectorResponseData::with_additional_payment_method_data)
+        .map(|connector_response| {
+            connector_response
+                .set_card_verification_data(payment_method_details.get_card_verification_data())
+        })
 }
 
 impl ForeignFrom<(Option<StripePaymentMethodOptions>, String)> for types::MandateReference {
@@ -2894,10 +2955,32 @@ pub struct BacsFinancialDetails {
 pub struct RefundRequest {
     pub amount: Option<i64>, //amount in cents, hence passed as integer
     pub payment_intent: String,
+    pub reason: Option<StripeRefundReason>,
     #[serde(flatten)]
     pub meta_data: StripeMetadata,
 }
 
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StripeRefundReason {
+    Duplicate,
+    Fraudulent,
+    RequestedByCustomer,
+}
+
+impl StripeRefundReason {
+    fn from_reason_code(reason_code: api_enums::RefundReasonCode) -> Option<Self> {
+        match reason_code {
+            api_enums::RefundReasonCode::Duplicate => Some(Self::Duplicate),
+            api_enums::RefundReasonCode::Fraudulent => Some(Self::Fraudulent),
+            api_enums::RefundReasonCode::RequestedByCustomer
+            | api_enums::RefundReasonCode::ProductNotReceived => Some(Self::RequestedByCustomer),
+            // Stripe does not accept any other reason
+            api_enums::RefundReasonCode::Other => None,
+        }
+    }
+}
+
 impl<F> TryFrom<&types::RefundsRouterData<F>> for RefundRequest {
     type Error = error_stack::Report<errors::ConnectorError>;
     fn try_from(item: &types::RefundsRouterData<F>) -> Result<Self, Self::Error> {
@@ -2906,6 +2989,10 @@ impl<F> TryFrom<&types::RefundsRouterData<F>> for RefundRequest {
         Ok(Self {
             amount: Some(amount),
             payment_intent,
+            reason: item
+                .request
+                .reason_code
+                .and_then(StripeRefundReason::from_reason_code),
             meta_data: StripeMetadata {
                 order_id: Some(item.request.refund_id.clone()),
                 is_refund_id_as_reference: Some("true".to_string()),
@@ -11,7 +11,10 @@ use serde::{Deserialize, Serialize};
 use serde_json::{json, to_string};
 
 use crate::{
-    connector::utils::{to_connector_meta, AddressDetailsData, CardData, SELECTED_PAYMENT_METHOD},
+    connector::utils::{
+        get_three_ds_requestor_challenge_indicator, to_connector_meta, AddressDetailsData,
+        CardData, SELECTED_PAYMENT_METHOD,
+    },
     consts::{BASE64_ENGINE, NO_ERROR_MESSAGE},
     core::errors,
     types::{
@@ -375,6 +378,9 @@ impl TryFrom<&ThreedsecureioRouterData<&types::authentication::ConnectorAuthenti
             bill_addr_state: billing_state,
             // Indicates the type of Authentication request, "01" for Payment transaction
             three_dsrequestor_authentication_ind: "01".to_string(),
+            three_dsrequestor_challenge_ind: get_three_ds_requestor_challenge_indicator(
+                request.sca_exemption,
+            ),
             device_channel: match item.router_data.request.device_channel.clone() {
                 DeviceChannel::App => "01",
                 DeviceChannel::Browser => "02",
@@ -553,6 +559,8 @@ pub struct ThreedsecureioAuthenticationRequest {
     pub bill_addr_state: Secret<String>,
     pub email: Option<common_utils::pii::Email>,
     pub three_dsrequestor_authentication_ind: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub three_dsrequestor_challenge_ind: Option<String>,
     pub cardholder_name: Option<Secret<String>>,
     pub device_channel: String,
     pub browser_javascript_enabled: Option<bool>,
@@ -398,6 +398,7 @@ pub trait PaymentsAuthorizeRequestData {
     fn get_surcharge_amount(&self) -> Option<i64>;
     fn get_tax_on_surcharge_amount(&self) -> Option<i64>;
     fn get_total_surcharge_amount(&self) -> Option<i64>;
+    fn get_sca_exemption(&self) -> Option<storage_enums::ScaExemptionType>;
 }
 
 pub trait PaymentMethodTokenizationRequestData {
@@ -529,6 +530,26 @@ impl PaymentsAuthorizeRequestData for types::PaymentsAuthorizeData {
     fn is_customer_initiated_mandate_payment(&self) -> bool {
         self.setup_mandate_details.is_some()
     }
+
+    fn get_sca_exemption(&self) -> Option<storage_enums::ScaExemptionType> {
+        self.sca_exemption
+    }
+}
+
+/// Maps the SCA exemption requested for the payment to the `threeDSRequestorChallengeInd` of the
+/// EMV 3DS authentication request. Merchant initiated transactions are not authenticated by the
+/// customer and have no indicator.
+pub fn get_three_ds_requestor_challenge_indicator(
+    sca_exemption: Option<storage_enums::ScaExemptionType>,
+) -> Option<String> {
+    match sca_exemption? {
+        // No challenge requested, transactional risk analysis is already performed
+        storage_enums::ScaExemptionType::TransactionRiskAnalysis => Some("05".to_string()),
+        // No challenge requested
+        storage_enums::ScaExemptionType::LowValue
+        | storage_enums::ScaExemptionType::SecureCorporatePayment => Some("02".to_string()),
+        storage_enums::ScaExemptionType::MerchantInitiatedTransaction => None,
+    }
 }
 
 pub trait ConnectorCustomerData {
@@ -1325,6 +1346,30 @@ pub fn to_currency_lower_unit(
         .change_context(errors::ConnectorError::ResponseHandlingFailed)
 }
 
+/// Converts an amount returned by a connector in the base unit of the currency, like `10.50`, to
+/// the lowest denomination of the currency
+pub fn get_amount_in_lower_unit(
+    amount: &str,
+    currency: diesel_models::enums::Currency,
+) -> Result<i64, error_stack::Report<errors::ConnectorError>> {
+    let multiplier = if currency.is_zero_decimal_currency() {
+        1
+    } else if currency.is_three_decimal_currency() {
+        1000
+    } else {
+        100
+    };
+    let amount = amount
+        .parse::<rust_decimal::Decimal>()
+        .into_report()
+        .change_context(errors::ConnectorError::ResponseHandlingFailed)?;
+    rust_decimal::prelude::ToPrimitive::to_i64(
+        &(amount * rust_decimal::Decimal::from(multiplier)).round(),
+    )
+    .ok_or(errors::ConnectorError::ResponseHandlingFailed)
+    .into_report()
+}
+
 pub fn construct_not_implemented_error_report(
     capture_method: enums::CaptureMethod,
     connector_name: &str,
@@ -1746,6 +1791,7 @@ impl FrmTransactionRouterDataRequest for fraud_check::FrmTransactionRouterData {
             storage_enums::AttemptStatus::AuthenticationSuccessful
             | storage_enums::AttemptStatus::PartialChargedAndChargeable
             | storage_enums::AttemptStatus::Authorized
+            | storage_enums::AttemptStatus::PartiallyAuthorized
             | storage_enums::AttemptStatus::Charged => Some(true),
 
             storage_enums::AttemptStatus::Started
@@ -1777,6 +1823,7 @@ pub fn is_payment_failure(status: enums::AttemptStatus) -> bool {
         | common_enums::AttemptStatus::AuthenticationPending
         | common_enums::AttemptStatus::AuthenticationSuccessful
         | common_enums::AttemptStatus::Authorized
+        | common_enums::AttemptStatus::PartiallyAuthorized
         | common_enums::AttemptStatus::Charged
         | common_enums::AttemptStatus::Authorizing
         | common_enums::AttemptStatus::CodInitiated
@@ -1805,6 +1852,26 @@ pub fn is_refund_failure(status: enums::RefundStatus) -> bool {
     }
 }
 
+/// Interprets the AVS result codes defined by the card networks, which most connectors pass
+/// through as received from the issuer
+pub fn get_avs_result_from_code(avs_result_code: &str) -> enums::CardCheckResult {
+    match avs_result_code.trim().to_uppercase().as_str() {
+        "Y" | "X" | "D" | "M" | "F" => enums::CardCheckResult::Match,
+        "A" | "B" | "P" | "W" | "Z" | "L" => enums::CardCheckResult::PartialMatch,
+        "N" | "C" => enums::CardCheckResult::Mismatch,
+        _ => enums::CardCheckResult::Unavailable,
+    }
+}
+
+/// Interprets the CVV result codes defined by the card networks
+pub fn get_cvv_result_from_code(cvv_result_code: &str) -> enums::CardCheckResult {
+    match cvv_result_code.trim().to_uppercase().as_str() {
+        "M" => enums::CardCheckResult::Match,
+        "N" => enums::CardCheckResult::Mismatch,
+        _ => enums::CardCheckResult::Unavailable,
+    }
+}
+
 #[cfg(test)]
 mod error_code_error_message_tests {
     #![allow(clippy::unwrap_used)]
@@ -1884,3 +1951,30 @@ mod error_code_error_message_tests {
         assert_eq!(error_code_error_message_none, None);
     }
 }
+
+#[cfg(test)]
+mod amount_conversion_tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_get_amount_in_lower_unit() {
+        assert_eq!(
+            get_amount_in_lower_unit("10.50", enums::Currency::USD).unwrap(),
+            1050
+        );
+        assert_eq!(
+            get_amount_in_lower_unit("0.29", enums::Currency::USD).unwrap(),
+            29
+        );
+        assert_eq!(
+            get_amount_in_lower_unit("1500", enums::Currency::JPY).unwrap(),
+            1500
+        );
+        assert_eq!(
+            get_amount_in_lower_unit("1.005", enums::Currency::KWD).unwrap(),
+            1005
+        );
+        assert!(get_amount_in_lower_unit("ten", enums::Currency::USD).is_err());
+    }
+}
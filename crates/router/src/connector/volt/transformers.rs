@@ -628,6 +628,7 @@ fn is_payment_failure(status: enums::AttemptStatus) -> bool {
         | common_enums::AttemptStatus::AuthenticationPending
         | common_enums::AttemptStatus::AuthenticationSuccessful
         | common_enums::AttemptStatus::Authorized
+        | common_enums::AttemptStatus::PartiallyAuthorized
         | common_enums::AttemptStatus::Charged
         | common_enums::AttemptStatus::Authorizing
         | common_enums::AttemptStatus::CodInitiated
@@ -44,6 +44,8 @@ pub(crate) const BASE64_ENGINE: base64::engine::GeneralPurpose =
     base64::engine::general_purpose::STANDARD;
 pub(crate) const BASE64_ENGINE_URL_SAFE: base64::engine::GeneralPurpose =
     base64::engine::general_purpose::URL_SAFE;
+pub(crate) const BASE64_ENGINE_STD_NO_PAD: base64::engine::GeneralPurpose =
+    base64::engine::general_purpose::STANDARD_NO_PAD;
 
 pub(crate) const API_KEY_LENGTH: usize = 64;
 pub(crate) const PUB_SUB_CHANNEL: &str = "hyperswitch_invalidate";
@@ -96,8 +98,32 @@ pub const MAX_SESSION_EXPIRY: u32 = 7890000;
 /// Min payment session expiry
 pub const MIN_SESSION_EXPIRY: u32 = 60;
 
+/// Default duration for which rotated webhook secrets continue to be accepted
+pub const DEFAULT_WEBHOOK_SECRET_OVERLAP_WINDOW_IN_SECS: u32 = 60 * 60 * 24; // 1 day
+
+/// Max duration for which rotated webhook secrets continue to be accepted
+pub const MAX_WEBHOOK_SECRET_OVERLAP_WINDOW_IN_SECS: u32 = 60 * 60 * 24 * 30; // 30 days
+
+/// Name of the business profile created along with a merchant account
+pub const DEFAULT_BUSINESS_PROFILE_NAME: &str = "default";
+
+/// Max number of merchant connector accounts which can be created or updated in a bulk request
+pub const MAX_BULK_MERCHANT_CONNECTOR_ITEMS: usize = 100;
+
+/// Default duration for which card fingerprints of a rotated key continue to be matched
+pub const DEFAULT_CARD_FINGERPRINT_KEY_TRANSITION_PERIOD_IN_DAYS: u16 = 30;
+
+/// Max duration for which card fingerprints of a rotated key continue to be matched
+pub const MAX_CARD_FINGERPRINT_KEY_TRANSITION_PERIOD_IN_DAYS: u16 = 365;
+
 pub const LOCKER_HEALTH_CALL_PATH: &str = "/health";
 
+/// Max size of the buffer allocated upfront for reading a connector response, from its content length
+pub const MAX_CONNECTOR_RESPONSE_PREALLOCATION_SIZE: usize = 16 * 1024 * 1024; // 16 MiB
+
+/// Max size of the body of a connector response included in logs
+pub const MAX_LOGGED_CONNECTOR_RESPONSE_SIZE: usize = 4 * 1024; // 4 KiB
+
 pub const AUTHENTICATION_ID_PREFIX: &str = "authn";
 
 // URL for checking the outgoing call
@@ -1,3 +1,5 @@
 pub const MAX_NAME_LENGTH: usize = 70;
 pub const MAX_COMPANY_NAME_LENGTH: usize = 70;
 pub const BUSINESS_EMAIL: &str = "biz@hyperswitch.io";
+pub const IMPERSONATION_DEFAULT_DURATION_IN_SECS: u64 = 60 * 15; // 15 mins
+pub const IMPERSONATION_MAX_DURATION_IN_SECS: u64 = 60 * 60 * 2; // 2 hours
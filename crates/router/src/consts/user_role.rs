@@ -1,6 +1,9 @@
 // User Roles
 pub const ROLE_ID_INTERNAL_VIEW_ONLY_USER: &str = "internal_view_only";
 pub const ROLE_ID_INTERNAL_ADMIN: &str = "internal_admin";
+/// Role assigned to impersonation sessions, whose permissions are derived from the permission
+/// groups granted to the session rather than from the role
+pub const ROLE_ID_INTERNAL_IMPERSONATION: &str = "internal_impersonation";
 pub const ROLE_ID_MERCHANT_ADMIN: &str = "merchant_admin";
 pub const ROLE_ID_ORGANIZATION_ADMIN: &str = "org_admin";
 pub const ROLE_ID_MERCHANT_VIEW_ONLY: &str = "merchant_view_only";
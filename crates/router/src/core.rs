@@ -1,10 +1,14 @@
+pub mod account_templates;
 pub mod admin;
 pub mod api_keys;
 pub mod api_locking;
+pub mod audit_logs;
 pub mod authentication;
 pub mod blocklist;
+pub mod bulk_charges;
 pub mod cache;
 pub mod cards_info;
+pub mod client_sessions;
 pub mod conditional_config;
 pub mod configs;
 #[cfg(feature = "olap")]
@@ -20,7 +24,9 @@ pub mod fraud_check;
 pub mod gsm;
 pub mod health_check;
 pub mod locker_migration;
+pub mod log_filter;
 pub mod mandate;
+pub mod manual_review_config;
 pub mod metrics;
 pub mod payment_link;
 pub mod payment_methods;
@@ -28,15 +34,25 @@ pub mod payments;
 #[cfg(feature = "payouts")]
 pub mod payouts;
 pub mod pm_auth;
+#[cfg(feature = "recon")]
+pub mod recon;
+pub mod recurring_payments;
 pub mod refunds;
 pub mod routing;
+#[cfg(feature = "olap")]
+pub mod scheduled_reports;
+pub mod sdk_events;
+pub mod success_rate_alerts;
 pub mod surcharge_decision_config;
+pub mod three_ds_decision_config;
 #[cfg(feature = "olap")]
 pub mod user;
 #[cfg(feature = "olap")]
 pub mod user_role;
 pub mod utils;
 #[cfg(feature = "olap")]
+pub mod velocity_rules;
+#[cfg(feature = "olap")]
 pub mod verification;
 #[cfg(feature = "olap")]
 pub mod verify_connector;
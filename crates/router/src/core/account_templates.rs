@@ -0,0 +1,344 @@
+//! Account templates.
+//!
+//! An account template captures the business profiles, connectors, routing and webhook settings
+//! shared by the merchant accounts of a platform. Instantiating a template creates a merchant
+//! account with these settings in a single call, with the credentials of the connectors provided
+//! at instantiation, as templates never hold credentials.
+
+use std::collections::{HashMap, HashSet};
+
+use api_models::admin as admin_types;
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, StringExt, ValueExt},
+};
+use diesel_models::configs;
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    consts,
+    core::{
+        admin,
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    },
+    db::StorageInterface,
+    routes::AppState,
+    services::api as service_api,
+    types, utils,
+};
+
+/// Provides the identifier of the config holding the account template
+#[inline(always)]
+fn get_account_template_config_key(template_id: &str) -> String {
+    format!("account_template_{template_id}")
+}
+
+async fn get_account_template(
+    db: &dyn StorageInterface,
+    template_id: &str,
+) -> RouterResult<admin_types::AccountTemplateResponse> {
+    let config = db
+        .find_config_by_key(&get_account_template_config_key(template_id))
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Account template `{template_id}` not found"),
+        })?;
+
+    config
+        .config
+        .parse_struct("AccountTemplateResponse")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the account template")
+}
+
+#[instrument(skip(state))]
+pub async fn create_account_template(
+    state: AppState,
+    req: admin_types::AccountTemplateCreate,
+) -> RouterResponse<admin_types::AccountTemplateResponse> {
+    validate_account_template(&req)?;
+
+    let template = admin_types::AccountTemplateResponse {
+        template_id: utils::generate_id(consts::ID_LENGTH, "tmpl"),
+        template_name: req.template_name,
+        return_url: req.return_url,
+        webhook_details: req.webhook_details,
+        business_profiles: req.business_profiles,
+        created_at: date_time::now(),
+    };
+
+    state
+        .store
+        .insert_config(configs::ConfigNew {
+            key: get_account_template_config_key(&template.template_id),
+            config: template
+                .encode_to_string_of_json()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize the account template")?,
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the account template")?;
+
+    Ok(service_api::ApplicationResponse::Json(template))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_account_template(
+    state: AppState,
+    template_id: String,
+) -> RouterResponse<admin_types::AccountTemplateResponse> {
+    let template = get_account_template(&*state.store, &template_id).await?;
+    Ok(service_api::ApplicationResponse::Json(template))
+}
+
+#[instrument(skip(state))]
+pub async fn delete_account_template(state: AppState, template_id: String) -> RouterResponse<()> {
+    state
+        .store
+        .delete_config_by_key(&get_account_template_config_key(&template_id))
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("Account template `{template_id}` not found"),
+        })?;
+    Ok(service_api::ApplicationResponse::StatusOk)
+}
+
+fn validate_account_template(template: &admin_types::AccountTemplateCreate) -> RouterResult<()> {
+    utils::when(template.template_name.trim().is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "template_name cannot be empty".to_string(),
+        })
+        .into_report()
+    })?;
+    utils::when(template.business_profiles.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "An account template must have at least one business profile".to_string(),
+        })
+        .into_report()
+    })?;
+
+    let mut profile_names = HashSet::new();
+    let mut connector_labels = HashSet::new();
+    for business_profile in template.business_profiles.iter() {
+        // The default business profile is created along with every merchant account
+        if business_profile.profile_name == consts::DEFAULT_BUSINESS_PROFILE_NAME
+            || !profile_names.insert(business_profile.profile_name.as_str())
+        {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Business profile name `{}` is either reserved or used more than once",
+                    business_profile.profile_name
+                ),
+            })?;
+        }
+
+        if let Some(routing_algorithm) = business_profile.routing_algorithm.clone() {
+            let _: api_models::routing::RoutingAlgorithm = routing_algorithm
+                .parse_value("RoutingAlgorithm")
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "routing_algorithm",
+                })
+                .attach_printable("Invalid routing algorithm given")?;
+        }
+
+        for connector in business_profile.connectors.iter() {
+            if !connector_labels.insert(connector.connector_label.as_str()) {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Connector label `{}` is used more than once",
+                        connector.connector_label
+                    ),
+                })?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Validates the credentials provided for each connector of the template, so that no merchant
+/// account is created from the template with credentials which would be rejected
+fn validate_connector_credentials(
+    template: &admin_types::AccountTemplateResponse,
+    connectors: &HashMap<String, admin_types::AccountTemplateConnectorCredentials>,
+) -> RouterResult<()> {
+    let template_connectors = template
+        .business_profiles
+        .iter()
+        .flat_map(|business_profile| business_profile.connectors.iter())
+        .collect::<Vec<_>>();
+
+    if let Some(connector_label) = connectors.keys().find(|connector_label| {
+        !template_connectors
+            .iter()
+            .any(|connector| &connector.connector_label == *connector_label)
+    }) {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("The template has no connector labelled `{connector_label}`"),
+        })?;
+    }
+
+    for connector in template_connectors {
+        let credentials = connectors.get(&connector.connector_label).ok_or_else(|| {
+            errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Credentials of the connector `{}` are required",
+                    connector.connector_label
+                ),
+            }
+        })?;
+
+        let auth: types::ConnectorAuthType = credentials
+            .connector_account_details
+            .clone()
+            .parse_value("ConnectorAuthType")
+            .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: format!(
+                    "connectors.{}.connector_account_details",
+                    connector.connector_label
+                ),
+                expected_format: "auth_type and api_key".to_string(),
+            })?;
+        admin::validate_connector_auth_and_metadata(
+            connector.connector_name,
+            &auth,
+            &connector.metadata,
+        )?;
+    }
+    Ok(())
+}
+
+fn get_json_response<T>(
+    response: service_api::ApplicationResponse<T>,
+    resource: &str,
+) -> RouterResult<T> {
+    match response {
+        service_api::ApplicationResponse::Json(response) => Ok(response),
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable_lazy(|| format!("Unexpected response when creating the {resource}")),
+    }
+}
+
+#[instrument(skip_all, fields(template_id = %template_id))]
+pub async fn instantiate_account_template(
+    state: AppState,
+    template_id: &str,
+    req: admin_types::AccountTemplateInstantiateRequest,
+) -> RouterResponse<admin_types::AccountTemplateInstantiateResponse> {
+    let template = get_account_template(&*state.store, template_id).await?;
+    validate_connector_credentials(&template, &req.connectors)?;
+
+    let merchant_account = get_json_response(
+        admin::create_merchant_account(
+            state.clone(),
+            admin_types::MerchantAccountCreate {
+                merchant_id: req.merchant_id.clone(),
+                merchant_name: req.merchant_name,
+                merchant_details: req.merchant_details,
+                return_url: template.return_url.clone(),
+                webhook_details: template.webhook_details.clone(),
+                routing_algorithm: None,
+                #[cfg(feature = "payouts")]
+                payout_routing_algorithm: None,
+                sub_merchants_enabled: None,
+                parent_merchant_id: None,
+                enable_payment_response_hash: None,
+                payment_response_hash_key: None,
+                redirect_to_merchant_with_http_post: None,
+                metadata: None,
+                publishable_key: None,
+                locker_id: None,
+                primary_business_details: None,
+                frm_routing_algorithm: None,
+                organization_id: req.organization_id,
+            },
+        )
+        .await?,
+        "merchant account",
+    )?;
+    let merchant_id = merchant_account.merchant_id.clone();
+
+    // Creating the merchant account is not atomic with creating its business profiles and
+    // connectors, the merchant account is left partially provisioned if any of them fails
+    let mut business_profiles = Vec::with_capacity(template.business_profiles.len());
+    let mut merchant_connector_accounts = Vec::new();
+    for template_profile in template.business_profiles {
+        let business_profile = get_json_response(
+            admin::create_business_profile(
+                state.clone(),
+                admin_types::BusinessProfileCreate {
+                    profile_name: Some(template_profile.profile_name),
+                    return_url: template_profile.return_url,
+                    enable_payment_response_hash: template_profile.enable_payment_response_hash,
+                    redirect_to_merchant_with_http_post: template_profile
+                        .redirect_to_merchant_with_http_post,
+                    webhook_details: template_profile.webhook_details,
+                    routing_algorithm: template_profile.routing_algorithm,
+                    ..Default::default()
+                },
+                &merchant_id,
+            )
+            .await
+            .map_err(|error| {
+                logger::error!(?error, %merchant_id, "Failed to create business profile from account template");
+                error
+            })?,
+            "business profile",
+        )?;
+
+        for template_connector in template_profile.connectors {
+            let credentials = req
+                .connectors
+                .get(&template_connector.connector_label)
+                .cloned()
+                .ok_or(errors::ApiErrorResponse::InternalServerError)
+                .into_report()
+                .attach_printable("Missing credentials of a validated connector")?;
+
+            let merchant_connector_account = get_json_response(
+                admin::create_payment_connector(
+                    state.clone(),
+                    admin_types::MerchantConnectorCreate {
+                        connector_type: template_connector.connector_type,
+                        connector_name: template_connector.connector_name,
+                        connector_label: Some(template_connector.connector_label),
+                        profile_id: Some(business_profile.profile_id.clone()),
+                        connector_account_details: Some(credentials.connector_account_details),
+                        payment_methods_enabled: template_connector.payment_methods_enabled,
+                        connector_webhook_details: credentials.connector_webhook_details,
+                        metadata: template_connector.metadata,
+                        test_mode: template_connector.test_mode,
+                        disabled: template_connector.disabled,
+                        frm_configs: template_connector.frm_configs,
+                        business_country: None,
+                        business_label: None,
+                        business_sub_label: None,
+                        merchant_connector_id: None,
+                        pm_auth_config: None,
+                        status: None,
+                    },
+                    &merchant_id,
+                )
+                .await
+                .map_err(|error| {
+                    logger::error!(?error, %merchant_id, "Failed to create merchant connector account from account template");
+                    error
+                })?,
+                "merchant connector account",
+            )?;
+            merchant_connector_accounts.push(merchant_connector_account);
+        }
+        business_profiles.push(business_profile);
+    }
+
+    Ok(service_api::ApplicationResponse::Json(
+        admin_types::AccountTemplateInstantiateResponse {
+            template_id: template.template_id,
+            merchant_account,
+            business_profiles,
+            merchant_connector_accounts,
+        },
+    ))
+}
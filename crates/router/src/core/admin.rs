@@ -7,23 +7,27 @@ use api_models::{
 use common_utils::{
     crypto::{generate_cryptographically_secure_random_string, OptionalSecretValue},
     date_time,
+    errors::ErrorSwitch,
     ext_traits::{AsyncExt, ConfigExt, Encode, ValueExt},
     pii,
 };
 use diesel_models::configs;
 use error_stack::{report, FutureExt, IntoReport, ResultExt};
 use futures::future::try_join_all;
-use masking::{PeekInterface, Secret};
+use masking::{ExposeInterface, PeekInterface, Secret};
 use pm_auth::connector::plaid::transformers::PlaidAuthType;
+use scheduler::utils as pt_utils;
 use uuid::Uuid;
 
 use crate::{
     consts,
     core::{
+        audit_logs,
         errors::{self, RouterResponse, RouterResult, StorageErrorExt},
-        payments::helpers,
+        payments::{helpers, routing::connector_health},
         routing::helpers as routing_helpers,
         utils as core_utils,
+        webhooks::notification_channels,
     },
     db::StorageInterface,
     routes::{metrics, AppState},
@@ -35,11 +39,20 @@ use crate::{
             types::{self as domain_types, AsyncLift},
         },
         storage::{self, enums::MerchantStorageScheme},
-        transformers::{ForeignFrom, ForeignTryFrom},
+        transformers::{ForeignFrom, ForeignInto, ForeignTryFrom},
     },
     utils::{self, OptionExt},
 };
 
+const MERCHANT_ACCOUNT_DELETION_TASK_NAME: &str = "MERCHANT_ACCOUNT_DELETION";
+const MERCHANT_ACCOUNT_DELETION_TAG: &str = "MERCHANT";
+const MERCHANT_ACCOUNT_DELETION_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::MerchantAccountDeletionWorkflow;
+
+/// Business statuses of the merchant account deletion task
+pub const MERCHANT_ACCOUNT_DELETED: &str = "DELETED";
+pub const MERCHANT_ACCOUNT_RESTORED: &str = "RESTORED";
+
 #[inline]
 pub fn create_merchant_publishable_key() -> String {
     format!(
@@ -84,6 +97,10 @@ pub async fn create_merchant_account(
         .transpose()?
         .map(Into::into);
 
+    if let Some(webhook_details) = &req.webhook_details {
+        notification_channels::validate_notification_channels(webhook_details)?;
+    }
+
     let webhook_details = req
         .webhook_details
         .as_ref()
@@ -216,6 +233,7 @@ pub async fn create_merchant_account(
             default_profile: None,
             recon_status: diesel_models::enums::ReconStatus::NotRequested,
             payment_link_config: None,
+            deleted_at: None,
         })
     }
     .await
@@ -441,6 +459,8 @@ pub async fn update_business_profile_cascade(
             payment_link_config: None,
             session_expiry: None,
             authentication_connector_details: None,
+            card_verification_policy: None,
+            partial_authorization_action: None,
         };
 
         let update_futures = business_profiles.iter().map(|business_profile| async {
@@ -487,6 +507,18 @@ pub async fn merchant_account_update(
         }))?;
     }
 
+    let merchant_account_before: api::MerchantAccountResponse = db
+        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?
+        .try_into()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while generating response")?;
+
+    if let Some(webhook_details) = &req.webhook_details {
+        notification_channels::validate_notification_channels(webhook_details)?;
+    }
+
     if let Some(ref routing_algorithm) = req.routing_algorithm {
         let _: api_models::routing::RoutingAlgorithm = routing_algorithm
             .clone()
@@ -601,34 +633,147 @@ pub async fn merchant_account_update(
         payment_link_config: None,
     };
 
-    let response = db
+    let response: api::MerchantAccountResponse = db
         .update_specific_fields_in_merchant(merchant_id, updated_merchant_account, &key_store)
         .await
-        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?
+        .try_into()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while generating response")?;
 
-    // If there are any new business labels generated, create business profile
+    audit_logs::record_audit_log(
+        &state,
+        merchant_id,
+        storage::enums::AuditOperation::MerchantAccountUpdate,
+        merchant_id,
+        Some(&merchant_account_before),
+        Some(&response),
+    )
+    .await;
 
-    Ok(service_api::ApplicationResponse::Json(
-        response
-            .try_into()
-            .change_context(errors::ApiErrorResponse::InternalServerError)
-            .attach_printable("Failed while generating response")?,
-    ))
+    Ok(service_api::ApplicationResponse::Json(response))
 }
 
+/// Soft deletes the merchant account, the merchant account can no longer be used but its data
+/// is retained, so that it can be restored until the end of the retention period, after which it
+/// is permanently deleted by the merchant account deletion workflow
 pub async fn merchant_account_delete(
     state: AppState,
     merchant_id: String,
 ) -> RouterResponse<api::MerchantAccountDeleteResponse> {
-    let mut is_deleted = false;
     let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    utils::when(merchant_account.is_deleted(), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Merchant account is already deleted".to_string(),
+        })
+        .into_report()
+    })?;
+
+    let deleted_at = date_time::now();
+    let restorable_until = deleted_at.saturating_add(time::Duration::days(
+        state.conf.merchant_deletion.retention_period_in_days,
+    ));
+
+    let deleted_merchant_account = db
+        .update_specific_fields_in_merchant(
+            &merchant_id,
+            storage::MerchantAccountUpdate::SoftDeleteUpdate { deleted_at },
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    add_merchant_account_deletion_task(db, &merchant_id, deleted_at, restorable_until).await?;
+
+    let merchant_account_before = api::MerchantAccountResponse::try_from(merchant_account)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while generating response")?;
+    let merchant_account_after = api::MerchantAccountResponse::try_from(deleted_merchant_account)
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while generating response")?;
+    audit_logs::record_audit_log(
+        &state,
+        &merchant_id,
+        storage::enums::AuditOperation::MerchantAccountDelete,
+        &merchant_id,
+        Some(&merchant_account_before),
+        Some(&merchant_account_after),
+    )
+    .await;
+
+    let response = api::MerchantAccountDeleteResponse {
+        merchant_id,
+        deleted: true,
+        restorable_until: Some(restorable_until),
+    };
+    Ok(service_api::ApplicationResponse::Json(response))
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct MerchantAccountDeletionTrackingData {
+    pub merchant_id: String,
+}
+
+async fn add_merchant_account_deletion_task(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    deleted_at: time::PrimitiveDateTime,
+    schedule_time: time::PrimitiveDateTime,
+) -> RouterResult<()> {
+    let tracking_data = MerchantAccountDeletionTrackingData {
+        merchant_id: merchant_id.to_string(),
+    };
+    // A merchant account can be deleted again after being restored, so the time of deletion is
+    // part of the task identifier
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        MERCHANT_ACCOUNT_DELETION_RUNNER,
+        MERCHANT_ACCOUNT_DELETION_TASK_NAME,
+        &deleted_at.assume_utc().unix_timestamp().to_string(),
+        merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        MERCHANT_ACCOUNT_DELETION_TASK_NAME,
+        MERCHANT_ACCOUNT_DELETION_RUNNER,
+        [MERCHANT_ACCOUNT_DELETION_TAG],
+        tracking_data,
+        schedule_time,
+    )
+    .map_err(errors::StorageError::from)
+    .into_report()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the merchant account deletion process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the merchant account deletion process tracker task")?;
+    Ok(())
+}
+
+/// Permanently deletes the merchant account along with its key store, this cannot be undone
+pub async fn hard_delete_merchant_account(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<bool> {
+    let mut is_deleted = false;
     let is_merchant_account_deleted = db
-        .delete_merchant_account_by_merchant_id(&merchant_id)
+        .delete_merchant_account_by_merchant_id(merchant_id)
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
     if is_merchant_account_deleted {
         let is_merchant_key_store_deleted = db
-            .delete_merchant_key_store_by_merchant_id(&merchant_id)
+            .delete_merchant_key_store_by_merchant_id(merchant_id)
             .await
             .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
         is_deleted = is_merchant_account_deleted && is_merchant_key_store_deleted;
@@ -652,11 +797,109 @@ pub async fn merchant_account_delete(
     }
     .ok();
 
-    let response = api::MerchantAccountDeleteResponse {
-        merchant_id,
-        deleted: is_deleted,
-    };
-    Ok(service_api::ApplicationResponse::Json(response))
+    Ok(is_deleted)
+}
+
+pub async fn merchant_account_restore(
+    state: AppState,
+    merchant_id: String,
+) -> RouterResponse<api::MerchantAccountResponse> {
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    utils::when(!merchant_account.is_deleted(), || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "Merchant account is not deleted".to_string(),
+        })
+        .into_report()
+    })?;
+
+    // The pending deletion task finds the merchant account restored when it is picked up, and
+    // completes without deleting it
+    let merchant_account = db
+        .update_specific_fields_in_merchant(
+            &merchant_id,
+            storage::MerchantAccountUpdate::RestoreUpdate,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        merchant_account
+            .try_into()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to construct response")?,
+    ))
+}
+
+pub async fn merchant_account_data_export(
+    state: AppState,
+    merchant_id: String,
+) -> RouterResponse<api::MerchantAccountDataExport> {
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(&merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_account: api::MerchantAccountResponse = db
+        .find_merchant_account_by_merchant_id(&merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?
+        .try_into()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to construct merchant account response")?;
+
+    let business_profiles = db
+        .list_business_profile_by_merchant_id(&merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list business profiles")?
+        .into_iter()
+        .map(api_models::admin::BusinessProfileResponse::foreign_try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse business profile details")?;
+
+    let merchant_connector_accounts = db
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &merchant_id,
+            true,
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list merchant connector accounts")?
+        .into_iter()
+        .map(TryInto::try_into)
+        .collect::<RouterResult<Vec<api_models::admin::MerchantConnectorResponse>>>()?;
+
+    let api_keys = db
+        .list_api_keys_by_merchant_id(&merchant_id, None, None)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list API keys")?
+        .into_iter()
+        .map(ForeignInto::foreign_into)
+        .collect();
+
+    Ok(service_api::ApplicationResponse::Json(
+        api::MerchantAccountDataExport {
+            merchant_account,
+            business_profiles,
+            merchant_connector_accounts,
+            api_keys,
+        },
+    ))
 }
 
 async fn get_parent_merchant(
@@ -732,14 +975,26 @@ fn validate_certificate_in_mca_metadata(
     Ok(())
 }
 
-pub async fn create_payment_connector(
-    state: AppState,
-    req: api::MerchantConnectorCreate,
-    merchant_id: &String,
-) -> RouterResponse<api_models::admin::MerchantConnectorResponse> {
+/// Details of a merchant connector account to be created, obtained while validating the request
+struct ValidatedPaymentConnectorCreate {
+    key_store: domain::MerchantKeyStore,
+    merchant_account: domain::MerchantAccount,
+    profile_id: String,
+    business_profile: storage::business_profile::BusinessProfile,
+    routable_connector: Option<api_enums::RoutableConnectors>,
+    connector_status: api_enums::ConnectorStatus,
+    disabled: Option<bool>,
+}
+
+/// Validates the request to create a merchant connector account, without creating it
+async fn validate_payment_connector_create(
+    state: &AppState,
+    req: &api::MerchantConnectorCreate,
+    merchant_id: &str,
+) -> RouterResult<ValidatedPaymentConnectorCreate> {
     let store = state.store.as_ref();
     #[cfg(feature = "dummy_connector")]
-    validate_dummy_connector_enabled(&state, &req.connector_name).await?;
+    validate_dummy_connector_enabled(state, &req.connector_name).await?;
     let key_store = store
         .get_merchant_key_store_by_merchant_id(
             merchant_id,
@@ -818,6 +1073,82 @@ pub async fn create_payment_connector(
         routable_connector = Some(routable_connector_option);
     };
 
+    // Validate Merchant api details and return error if not in correct format
+    let auth: types::ConnectorAuthType = req
+        .connector_account_details
+        .clone()
+        .parse_value("ConnectorAuthType")
+        .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "connector_account_details".to_string(),
+            expected_format: "auth_type and api_key".to_string(),
+        })?;
+
+    validate_connector_auth_and_metadata(req.connector_name, &auth, &req.metadata)?;
+
+    let (connector_status, disabled) = validate_status_and_disabled(
+        req.status,
+        req.disabled,
+        auth,
+        // The validate_status_and_disabled function will use this value only
+        // when the status can be active. So we are passing this as fallback.
+        api_enums::ConnectorStatus::Active,
+    )?;
+
+    Ok(ValidatedPaymentConnectorCreate {
+        key_store,
+        merchant_account,
+        profile_id,
+        business_profile,
+        routable_connector,
+        connector_status,
+        disabled,
+    })
+}
+
+/// Validates the auth type and metadata of the connector
+pub fn validate_connector_auth_and_metadata(
+    connector_name: api_enums::Connector,
+    auth: &types::ConnectorAuthType,
+    metadata: &Option<pii::SecretSerdeValue>,
+) -> RouterResult<()> {
+    validate_auth_and_metadata_type(connector_name, auth, metadata).map_err(|err| {
+        match *err.current_context() {
+            errors::ConnectorError::InvalidConnectorName => {
+                err.change_context(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "The connector name is invalid".to_string(),
+                })
+            }
+            errors::ConnectorError::InvalidConnectorConfig { config: field_name } => err
+                .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!("The {} is invalid", field_name),
+                }),
+            errors::ConnectorError::FailedToObtainAuthType => {
+                err.change_context(errors::ApiErrorResponse::InvalidRequestData {
+                    message: "The auth type is invalid for the connector".to_string(),
+                })
+            }
+            _ => err.change_context(errors::ApiErrorResponse::InvalidRequestData {
+                message: "The request body is invalid".to_string(),
+            }),
+        }
+    })
+}
+
+pub async fn create_payment_connector(
+    state: AppState,
+    req: api::MerchantConnectorCreate,
+    merchant_id: &String,
+) -> RouterResponse<api_models::admin::MerchantConnectorResponse> {
+    let ValidatedPaymentConnectorCreate {
+        key_store,
+        merchant_account,
+        profile_id,
+        business_profile,
+        routable_connector,
+        connector_status,
+        disabled,
+    } = validate_payment_connector_create(&state, &req, merchant_id).await?;
+
     // If connector label is not passed in the request, generate one
     let connector_label = req
         .connector_label
@@ -849,38 +1180,6 @@ pub async fn create_payment_connector(
         None => None,
     };
 
-    // Validate Merchant api details and return error if not in correct format
-    let auth: types::ConnectorAuthType = req
-        .connector_account_details
-        .clone()
-        .parse_value("ConnectorAuthType")
-        .change_context(errors::ApiErrorResponse::InvalidDataFormat {
-            field_name: "connector_account_details".to_string(),
-            expected_format: "auth_type and api_key".to_string(),
-        })?;
-
-    validate_auth_and_metadata_type(req.connector_name, &auth, &req.metadata).map_err(|err| {
-        match *err.current_context() {
-            errors::ConnectorError::InvalidConnectorName => {
-                err.change_context(errors::ApiErrorResponse::InvalidRequestData {
-                    message: "The connector name is invalid".to_string(),
-                })
-            }
-            errors::ConnectorError::InvalidConnectorConfig { config: field_name } => err
-                .change_context(errors::ApiErrorResponse::InvalidRequestData {
-                    message: format!("The {} is invalid", field_name),
-                }),
-            errors::ConnectorError::FailedToObtainAuthType => {
-                err.change_context(errors::ApiErrorResponse::InvalidRequestData {
-                    message: "The auth type is invalid for the connector".to_string(),
-                })
-            }
-            _ => err.change_context(errors::ApiErrorResponse::InvalidRequestData {
-                message: "The request body is invalid".to_string(),
-            }),
-        }
-    })?;
-
     let frm_configs = get_frm_config_as_secret(req.frm_configs);
 
     // The purpose of this merchant account update is just to update the
@@ -896,15 +1195,6 @@ pub async fn create_payment_connector(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("error updating the merchant account when creating payment connector")?;
 
-    let (connector_status, disabled) = validate_status_and_disabled(
-        req.status,
-        req.disabled,
-        auth,
-        // The validate_status_and_disabled function will use this value only
-        // when the status can be active. So we are passing this as fallback.
-        api_enums::ConnectorStatus::Active,
-    )?;
-
     if req.connector_type != api_enums::ConnectorType::PaymentMethodAuth {
         if let Some(val) = req.pm_auth_config.clone() {
             validate_pm_auth(
@@ -1034,7 +1324,18 @@ pub async fn create_payment_connector(
         ],
     );
 
-    let mca_response = mca.try_into()?;
+    let mca_response: api_models::admin::MerchantConnectorResponse = mca.try_into()?;
+
+    audit_logs::record_audit_log(
+        &state,
+        merchant_id,
+        storage::enums::AuditOperation::MerchantConnectorAccountCreate,
+        &mca_response.merchant_connector_id,
+        None,
+        Some(&mca_response),
+    )
+    .await;
+
     Ok(service_api::ApplicationResponse::Json(mca_response))
 }
 
@@ -1156,13 +1457,66 @@ pub async fn list_payment_connectors(
     Ok(service_api::ApplicationResponse::Json(response))
 }
 
-pub async fn update_payment_connector(
+pub async fn list_payment_connectors_health(
     state: AppState,
+    merchant_id: String,
+) -> RouterResponse<Vec<api_models::admin::ConnectorHealthResponse>> {
+    let store = state.store.as_ref();
+    let key_store = store
+        .get_merchant_key_store_by_merchant_id(
+            &merchant_id,
+            &store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_connector_accounts = store
+        .find_merchant_connector_account_by_merchant_id_and_disabled_list(
+            &merchant_id,
+            false,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::InternalServerError)?;
+
+    let response = try_join_all(merchant_connector_accounts.into_iter().map(|mca| {
+        let state = &state;
+        let merchant_id = &merchant_id;
+        async move {
+            connector_health::get_connector_health(
+                state,
+                merchant_id,
+                mca.profile_id.as_deref(),
+                &mca.connector_name,
+            )
+            .await
+            .map(|health| api_models::admin::ConnectorHealthResponse {
+                merchant_connector_id: Some(mca.merchant_connector_id),
+                ..health
+            })
+        }
+    }))
+    .await?;
+
+    Ok(service_api::ApplicationResponse::Json(response))
+}
+
+/// Details of a merchant connector account to be updated, obtained while validating the request
+struct ValidatedPaymentConnectorUpdate {
+    key_store: domain::MerchantKeyStore,
+    merchant_account: domain::MerchantAccount,
+    mca: domain::MerchantConnectorAccount,
+    connector_status: api_enums::ConnectorStatus,
+    disabled: Option<bool>,
+}
+
+/// Validates the request to update a merchant connector account, without updating it
+async fn validate_payment_connector_update(
+    db: &dyn StorageInterface,
     merchant_id: &str,
     merchant_connector_id: &str,
-    req: api_models::admin::MerchantConnectorUpdate,
-) -> RouterResponse<api_models::admin::MerchantConnectorResponse> {
-    let db = state.store.as_ref();
+    req: &api_models::admin::MerchantConnectorUpdate,
+) -> RouterResult<ValidatedPaymentConnectorUpdate> {
     let key_store = db
         .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
         .await
@@ -1184,15 +1538,6 @@ pub async fn update_payment_connector(
             id: merchant_connector_id.to_string(),
         })?;
 
-    let payment_methods_enabled = req.payment_methods_enabled.map(|pm_enabled| {
-        pm_enabled
-            .iter()
-            .flat_map(Encode::encode_to_value)
-            .collect::<Vec<serde_json::Value>>()
-    });
-
-    let frm_configs = get_frm_config_as_secret(req.frm_configs);
-
     let auth: types::ConnectorAuthType = req
         .connector_account_details
         .clone()
@@ -1210,31 +1555,44 @@ pub async fn update_payment_connector(
             field_name: "connector",
         })
         .attach_printable_lazy(|| format!("unable to parse connector name {connector_name:?}"))?;
-    validate_auth_and_metadata_type(connector_enum, &auth, &metadata).map_err(|err| match *err
-        .current_context()
-    {
-        errors::ConnectorError::InvalidConnectorName => {
-            err.change_context(errors::ApiErrorResponse::InvalidRequestData {
-                message: "The connector name is invalid".to_string(),
-            })
-        }
-        errors::ConnectorError::InvalidConnectorConfig { config: field_name } => err
-            .change_context(errors::ApiErrorResponse::InvalidRequestData {
-                message: format!("The {} is invalid", field_name),
-            }),
-        errors::ConnectorError::FailedToObtainAuthType => {
-            err.change_context(errors::ApiErrorResponse::InvalidRequestData {
-                message: "The auth type is invalid for the connector".to_string(),
-            })
-        }
-        _ => err.change_context(errors::ApiErrorResponse::InvalidRequestData {
-            message: "The request body is invalid".to_string(),
-        }),
-    })?;
+    validate_connector_auth_and_metadata(connector_enum, &auth, &metadata)?;
 
     let (connector_status, disabled) =
         validate_status_and_disabled(req.status, req.disabled, auth, mca.status)?;
 
+    Ok(ValidatedPaymentConnectorUpdate {
+        key_store,
+        merchant_account,
+        mca,
+        connector_status,
+        disabled,
+    })
+}
+
+pub async fn update_payment_connector(
+    state: AppState,
+    merchant_id: &str,
+    merchant_connector_id: &str,
+    req: api_models::admin::MerchantConnectorUpdate,
+) -> RouterResponse<api_models::admin::MerchantConnectorResponse> {
+    let db = state.store.as_ref();
+    let ValidatedPaymentConnectorUpdate {
+        key_store,
+        merchant_account,
+        mca,
+        connector_status,
+        disabled,
+    } = validate_payment_connector_update(db, merchant_id, merchant_connector_id, &req).await?;
+
+    let payment_methods_enabled = req.payment_methods_enabled.map(|pm_enabled| {
+        pm_enabled
+            .iter()
+            .flat_map(Encode::encode_to_value)
+            .collect::<Vec<serde_json::Value>>()
+    });
+
+    let frm_configs = get_frm_config_as_secret(req.frm_configs);
+
     if req.connector_type != api_enums::ConnectorType::PaymentMethodAuth {
         if let Some(val) = req.pm_auth_config.clone() {
             validate_pm_auth(
@@ -1302,6 +1660,8 @@ pub async fn update_payment_connector(
 
     let request_connector_label = req.connector_label;
 
+    let mca_before: api_models::admin::MerchantConnectorResponse = mca.clone().try_into()?;
+
     let updated_mca = db
         .update_merchant_connector_account(mca, payment_connector.into(), &key_store)
         .await
@@ -1315,7 +1675,264 @@ pub async fn update_payment_connector(
             format!("Failed while updating MerchantConnectorAccount: id: {merchant_connector_id}")
         })?;
 
-    let response = updated_mca.try_into()?;
+    let response: api_models::admin::MerchantConnectorResponse = updated_mca.try_into()?;
+
+    audit_logs::record_audit_log(
+        &state,
+        merchant_id,
+        storage::enums::AuditOperation::MerchantConnectorAccountUpdate,
+        merchant_connector_id,
+        Some(&mca_before),
+        Some(&response),
+    )
+    .await;
+
+    Ok(service_api::ApplicationResponse::Json(response))
+}
+
+pub async fn bulk_provision_payment_connectors(
+    state: AppState,
+    merchant_id: &str,
+    req: admin_types::MerchantConnectorBulkRequest,
+) -> RouterResponse<admin_types::MerchantConnectorBulkResponse> {
+    utils::when(req.items.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "items cannot be empty".to_string(),
+        })
+        .into_report()
+    })?;
+    utils::when(
+        req.items.len() > consts::MAX_BULK_MERCHANT_CONNECTOR_ITEMS,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Number of items must not exceed {}",
+                    consts::MAX_BULK_MERCHANT_CONNECTOR_ITEMS
+                ),
+            })
+            .into_report()
+        },
+    )?;
+
+    // Items are processed sequentially, as the default routing configs of the merchant and its
+    // business profiles are updated with each created merchant connector account
+    let mut results = Vec::with_capacity(req.items.len());
+    for (index, item) in req.items.into_iter().enumerate() {
+        let result = provision_payment_connector(&state, merchant_id, item, req.dry_run).await;
+        results.push(match result {
+            Ok(merchant_connector_account) => admin_types::MerchantConnectorBulkItemResult {
+                index,
+                status: if req.dry_run {
+                    admin_types::MerchantConnectorBulkItemStatus::Valid
+                } else {
+                    admin_types::MerchantConnectorBulkItemStatus::Succeeded
+                },
+                merchant_connector_account,
+                error: None,
+            },
+            Err(error) => {
+                crate::logger::info!(
+                    index,
+                    ?error,
+                    "Failed to provision merchant connector account"
+                );
+                let mut api_error: api_models::errors::types::ApiErrorResponse =
+                    error.current_context().switch();
+                let error_info = api_error.get_internal_error_mut();
+                admin_types::MerchantConnectorBulkItemResult {
+                    index,
+                    status: admin_types::MerchantConnectorBulkItemStatus::Failed,
+                    merchant_connector_account: None,
+                    error: Some(admin_types::MerchantConnectorBulkItemError {
+                        code: format!("{}_{:02}", error_info.sub_code, error_info.error_identifier),
+                        message: std::mem::take(&mut error_info.error_message),
+                    }),
+                }
+            }
+        });
+    }
+
+    Ok(service_api::ApplicationResponse::Json(
+        admin_types::MerchantConnectorBulkResponse {
+            dry_run: req.dry_run,
+            results,
+        },
+    ))
+}
+
+/// Creates or updates the merchant connector account of a bulk request item, or only validates
+/// the item for dry runs
+async fn provision_payment_connector(
+    state: &AppState,
+    merchant_id: &str,
+    item: admin_types::MerchantConnectorBulkItem,
+    dry_run: bool,
+) -> RouterResult<Option<admin_types::MerchantConnectorResponse>> {
+    let response = match (item, dry_run) {
+        (admin_types::MerchantConnectorBulkItem::Create { connector }, true) => {
+            validate_payment_connector_create(state, &connector, merchant_id).await?;
+            return Ok(None);
+        }
+        (
+            admin_types::MerchantConnectorBulkItem::Update {
+                merchant_connector_id,
+                connector,
+            },
+            true,
+        ) => {
+            validate_payment_connector_update(
+                state.store.as_ref(),
+                merchant_id,
+                &merchant_connector_id,
+                &connector,
+            )
+            .await?;
+            return Ok(None);
+        }
+        (admin_types::MerchantConnectorBulkItem::Create { connector }, false) => {
+            create_payment_connector(state.clone(), connector, &merchant_id.to_string()).await?
+        }
+        (
+            admin_types::MerchantConnectorBulkItem::Update {
+                merchant_connector_id,
+                connector,
+            },
+            false,
+        ) => {
+            update_payment_connector(
+                state.clone(),
+                merchant_id,
+                &merchant_connector_id,
+                connector,
+            )
+            .await?
+        }
+    };
+
+    match response {
+        service_api::ApplicationResponse::Json(merchant_connector_account) => {
+            Ok(Some(merchant_connector_account))
+        }
+        _ => Err(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("Unexpected response when provisioning merchant connector account"),
+    }
+}
+
+pub async fn rotate_connector_webhook_secret(
+    state: AppState,
+    merchant_id: &str,
+    merchant_connector_id: &str,
+    req: admin_types::MerchantConnectorWebhookSecretRotateRequest,
+) -> RouterResponse<api_models::admin::MerchantConnectorResponse> {
+    let overlap_window_in_secs = req
+        .overlap_window_in_secs
+        .unwrap_or(consts::DEFAULT_WEBHOOK_SECRET_OVERLAP_WINDOW_IN_SECS);
+    utils::when(
+        overlap_window_in_secs > consts::MAX_WEBHOOK_SECRET_OVERLAP_WINDOW_IN_SECS,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "overlap_window_in_secs must not exceed {}",
+                    consts::MAX_WEBHOOK_SECRET_OVERLAP_WINDOW_IN_SECS
+                ),
+            })
+            .into_report()
+        },
+    )?;
+
+    let db = state.store.as_ref();
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let mca = db
+        .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
+            merchant_id,
+            merchant_connector_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
+            id: merchant_connector_id.to_string(),
+        })?;
+
+    let current_webhook_details = mca
+        .connector_webhook_details
+        .clone()
+        .map(|details| {
+            details
+                .expose()
+                .parse_value::<admin_types::MerchantConnectorWebhookDetails>(
+                    "MerchantConnectorWebhookDetails",
+                )
+        })
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deserialize connector webhook details")?;
+
+    // The current secrets remain valid for the overlap window, so that webhooks signed by the
+    // connector with the old secrets are not dropped while the rotation propagates
+    let previous_secret_expires_at =
+        date_time::now().saturating_add(time::Duration::seconds(i64::from(overlap_window_in_secs)));
+    let rotated_webhook_details = admin_types::MerchantConnectorWebhookDetails {
+        merchant_secret: req.merchant_secret,
+        additional_secret: req.additional_secret,
+        previous_merchant_secret: current_webhook_details
+            .as_ref()
+            .map(|details| details.merchant_secret.clone()),
+        previous_additional_secret: current_webhook_details
+            .and_then(|details| details.additional_secret),
+        previous_secret_expires_at: Some(previous_secret_expires_at),
+    };
+
+    let mca_update = storage::MerchantConnectorAccountUpdate::Update {
+        merchant_id: None,
+        connector_type: None,
+        connector_name: None,
+        merchant_connector_id: None,
+        connector_label: None,
+        connector_account_details: None,
+        test_mode: None,
+        disabled: None,
+        payment_methods_enabled: None,
+        metadata: None,
+        frm_configs: None,
+        connector_webhook_details: Some(Secret::new(
+            rotated_webhook_details
+                .encode_to_value()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to serialize connector webhook details")?,
+        )),
+        applepay_verified_domains: None,
+        pm_auth_config: None,
+        status: None,
+    };
+
+    let mca_before: api_models::admin::MerchantConnectorResponse = mca.clone().try_into()?;
+
+    let updated_mca = db
+        .update_merchant_connector_account(mca, mca_update.into(), &key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while rotating webhook secret for MerchantConnectorAccount: id: {merchant_connector_id}"
+            )
+        })?;
+
+    let response: api_models::admin::MerchantConnectorResponse = updated_mca.try_into()?;
+
+    audit_logs::record_audit_log(
+        &state,
+        merchant_id,
+        storage::enums::AuditOperation::MerchantConnectorWebhookSecretRotate,
+        merchant_connector_id,
+        Some(&mca_before),
+        Some(&response),
+    )
+    .await;
 
     Ok(service_api::ApplicationResponse::Json(response))
 }
@@ -1336,7 +1953,7 @@ pub async fn delete_payment_connector(
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
-    let _mca = db
+    let mca_before: api_models::admin::MerchantConnectorResponse = db
         .find_by_merchant_connector_account_merchant_id_merchant_connector_id(
             &merchant_id,
             &merchant_connector_id,
@@ -1345,7 +1962,8 @@ pub async fn delete_payment_connector(
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantConnectorAccountNotFound {
             id: merchant_connector_id.clone(),
-        })?;
+        })?
+        .try_into()?;
 
     let is_deleted = db
         .delete_merchant_connector_account_by_merchant_id_merchant_connector_id(
@@ -1357,6 +1975,18 @@ pub async fn delete_payment_connector(
             id: merchant_connector_id.clone(),
         })?;
 
+    if is_deleted {
+        audit_logs::record_audit_log(
+            &state,
+            &merchant_id,
+            storage::enums::AuditOperation::MerchantConnectorAccountDelete,
+            &merchant_connector_id,
+            Some(&mca_before),
+            None,
+        )
+        .await;
+    }
+
     let response = api::MerchantConnectorDeleteResponse {
         merchant_id,
         merchant_connector_id,
@@ -1505,6 +2135,11 @@ pub async fn create_business_profile(
     if let Some(session_expiry) = &request.session_expiry {
         helpers::validate_session_expiry(session_expiry.to_owned())?;
     }
+
+    if let Some(webhook_details) = &request.webhook_details {
+        notification_channels::validate_notification_channels(webhook_details)?;
+    }
+
     let db = state.store.as_ref();
     let key_store = db
         .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
@@ -1622,6 +2257,10 @@ pub async fn update_business_profile(
         helpers::validate_session_expiry(session_expiry.to_owned())?;
     }
 
+    if let Some(webhook_details) = &request.webhook_details {
+        notification_channels::validate_notification_channels(webhook_details)?;
+    }
+
     let webhook_details = request
         .webhook_details
         .as_ref()
@@ -1684,6 +2323,15 @@ pub async fn update_business_profile(
             .change_context(errors::ApiErrorResponse::InvalidDataValue {
                 field_name: "authentication_connector_details",
             })?,
+        card_verification_policy: request
+            .card_verification_policy
+            .as_ref()
+            .map(Encode::encode_to_value)
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                field_name: "card_verification_policy",
+            })?,
+        partial_authorization_action: request.partial_authorization_action,
     };
 
     let updated_business_profile = db
@@ -3,12 +3,17 @@ use common_utils::date_time;
 use diesel_models::{api_keys::ApiKey, enums as storage_enums};
 use error_stack::{report, IntoReport, ResultExt};
 use masking::{PeekInterface, StrongSecret};
-use router_env::{instrument, tracing};
+use router_env::{instrument, logger, tracing};
 
 use crate::{
     configs::settings,
     consts,
-    core::errors::{self, RouterResponse, StorageErrorExt},
+    core::{
+        audit_logs,
+        errors::{self, RouterResponse, StorageErrorExt},
+        utils as core_utils,
+    },
+    db::StorageInterface,
     routes::{metrics, AppState},
     services::ApplicationResponse,
     types::{api, storage, transformers::ForeignInto},
@@ -23,6 +28,15 @@ const API_KEY_EXPIRY_NAME: &str = "API_KEY_EXPIRY";
 const API_KEY_EXPIRY_RUNNER: diesel_models::ProcessTrackerRunner =
     diesel_models::ProcessTrackerRunner::ApiKeyExpiryWorkflow;
 
+/// The time for which an API key being rotated remains active alongside the new API key, unless
+/// specified otherwise
+const DEFAULT_ROTATION_GRACE_PERIOD_IN_SECONDS: u32 = 24 * 60 * 60;
+const MAX_ROTATION_GRACE_PERIOD_IN_SECONDS: u32 = 30 * 24 * 60 * 60;
+
+/// The time at which an API key was last used is updated at most once within this interval, so
+/// that authenticating every request does not write to the database
+const LAST_USED_UPDATE_INTERVAL: time::Duration = time::Duration::minutes(1);
+
 static HASH_KEY: once_cell::sync::OnceCell<StrongSecret<[u8; PlaintextApiKey::HASH_KEY_LEN]>> =
     once_cell::sync::OnceCell::new();
 
@@ -130,6 +144,10 @@ pub async fn create_api_key(
         .await
         .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
 
+    // Ensure that the business profile the API key is restricted to belongs to the merchant
+    core_utils::validate_and_get_business_profile(store, api_key.profile_id.as_ref(), &merchant_id)
+        .await?;
+
     let hash_key = api_key_config.get_hash_key()?;
     let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
     let api_key = storage::ApiKeyNew {
@@ -142,6 +160,8 @@ pub async fn create_api_key(
         created_at: date_time::now(),
         expires_at: api_key.expiration.into(),
         last_used: None,
+        profile_id: api_key.profile_id,
+        scopes: api_key.scopes.filter(|scopes| !scopes.is_empty()),
     };
 
     let api_key = store
@@ -153,9 +173,23 @@ pub async fn create_api_key(
     metrics::API_KEY_CREATED.add(
         &metrics::CONTEXT,
         1,
-        &[metrics::request::add_attributes("merchant", merchant_id)],
+        &[metrics::request::add_attributes(
+            "merchant",
+            merchant_id.clone(),
+        )],
     );
 
+    let api_key_after: api::RetrieveApiKeyResponse = api_key.clone().foreign_into();
+    audit_logs::record_audit_log(
+        &state,
+        &merchant_id,
+        storage::enums::AuditOperation::ApiKeyCreate,
+        &api_key.key_id,
+        None,
+        Some(&api_key_after),
+    )
+    .await;
+
     // Add process to process_tracker for email reminder, only if expiry is set to future date
     // If the `api_key` is set to expire in less than 7 days, the merchant is not notified about it's expiry
     #[cfg(feature = "email")]
@@ -272,6 +306,14 @@ pub async fn update_api_key(
     let key_id = api_key.key_id.clone();
     let store = state.store.as_ref();
 
+    let api_key_before: api::RetrieveApiKeyResponse = store
+        .find_api_key_by_merchant_id_key_id_optional(&merchant_id, &key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve API key")?
+        .ok_or(report!(errors::ApiErrorResponse::ApiKeyNotFound))?
+        .foreign_into();
+
     let api_key = store
         .update_api_key(
             merchant_id.to_owned(),
@@ -281,6 +323,17 @@ pub async fn update_api_key(
         .await
         .to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound)?;
 
+    let api_key_after: api::RetrieveApiKeyResponse = api_key.clone().foreign_into();
+    audit_logs::record_audit_log(
+        &state,
+        &merchant_id,
+        storage::enums::AuditOperation::ApiKeyUpdate,
+        &key_id,
+        Some(&api_key_before),
+        Some(&api_key_after),
+    )
+    .await;
+
     #[cfg(feature = "email")]
     {
         let expiry_reminder_days = state.conf.api_keys.get_inner().expiry_reminder_days.clone();
@@ -401,6 +454,189 @@ pub async fn update_api_key_expiry_task(
     Ok(())
 }
 
+#[instrument(skip_all)]
+pub async fn rotate_api_key(
+    state: AppState,
+    request: api::RotateApiKeyRequest,
+) -> RouterResponse<api::CreateApiKeyResponse> {
+    let merchant_id = request.merchant_id;
+    let key_id = request.key_id;
+    let grace_period_in_seconds = request
+        .grace_period_in_seconds
+        .unwrap_or(DEFAULT_ROTATION_GRACE_PERIOD_IN_SECONDS);
+    utils::when(
+        grace_period_in_seconds > MAX_ROTATION_GRACE_PERIOD_IN_SECONDS,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "grace_period_in_seconds must not exceed {MAX_ROTATION_GRACE_PERIOD_IN_SECONDS}"
+                ),
+            })
+            .into_report()
+        },
+    )?;
+
+    let store = state.store.as_ref();
+    let api_key = store
+        .find_api_key_by_merchant_id_key_id_optional(&merchant_id, &key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve API key")?
+        .ok_or(report!(errors::ApiErrorResponse::ApiKeyNotFound))?;
+
+    let now = date_time::now();
+    utils::when(
+        api_key
+            .expires_at
+            .is_some_and(|expires_at| expires_at <= now),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Expired API keys cannot be rotated".to_string(),
+            })
+            .into_report()
+        },
+    )?;
+
+    // The new API key inherits the restrictions of the API key being rotated
+    let hash_key = state.conf.api_keys.get_inner().get_hash_key()?;
+    let plaintext_api_key = PlaintextApiKey::new(consts::API_KEY_LENGTH);
+    let new_api_key = store
+        .insert_api_key(storage::ApiKeyNew {
+            key_id: PlaintextApiKey::new_key_id(),
+            merchant_id: merchant_id.clone(),
+            name: api_key.name.clone(),
+            description: api_key.description.clone(),
+            hashed_api_key: plaintext_api_key.keyed_hash(hash_key.peek()).into(),
+            prefix: plaintext_api_key.prefix(),
+            created_at: now,
+            expires_at: request.expiration.into(),
+            last_used: None,
+            profile_id: api_key.profile_id.clone(),
+            scopes: api_key.scopes.clone(),
+        })
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert new API key")?;
+
+    metrics::API_KEY_CREATED.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes(
+            "merchant",
+            merchant_id.clone(),
+        )],
+    );
+
+    // Both API keys remain active until the end of the grace period, unless the API key being
+    // rotated was set to expire earlier
+    let grace_period_ends_at =
+        now.saturating_add(time::Duration::seconds(i64::from(grace_period_in_seconds)));
+    let expires_at = api_key
+        .expires_at
+        .map_or(grace_period_ends_at, |expires_at| {
+            expires_at.min(grace_period_ends_at)
+        });
+    let rotated_api_key = store
+        .update_api_key(
+            merchant_id.clone(),
+            key_id.clone(),
+            storage::ApiKeyUpdate::Update {
+                name: None,
+                description: None,
+                expires_at: Some(Some(expires_at)),
+                last_used: None,
+                scopes: None,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ApiKeyNotFound)?;
+
+    let api_key_before: api::RetrieveApiKeyResponse = api_key.foreign_into();
+    let api_key_after: api::RetrieveApiKeyResponse = rotated_api_key.foreign_into();
+    audit_logs::record_audit_log(
+        &state,
+        &merchant_id,
+        storage::enums::AuditOperation::ApiKeyRotate,
+        &key_id,
+        Some(&api_key_before),
+        Some(&api_key_after),
+    )
+    .await;
+    let new_api_key_after: api::RetrieveApiKeyResponse = new_api_key.clone().foreign_into();
+    audit_logs::record_audit_log(
+        &state,
+        &merchant_id,
+        storage::enums::AuditOperation::ApiKeyCreate,
+        &new_api_key.key_id,
+        None,
+        Some(&new_api_key_after),
+    )
+    .await;
+
+    #[cfg(feature = "email")]
+    {
+        // The merchant is reminded of the expiry of the new API key instead of the rotated one
+        let task_id = generate_task_id_for_api_key_expiry_workflow(&key_id);
+        let existing_process_tracker_task = store
+            .find_process_by_id(task_id.as_str())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable(
+                "Failed to retrieve API key expiry reminder task from process tracker",
+            )?;
+
+        if existing_process_tracker_task.is_some() {
+            revoke_api_key_expiry_task(store, &key_id)
+                .await
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable(
+                    "Failed to revoke API key expiry reminder task in process tracker",
+                )?;
+        }
+
+        if new_api_key.expires_at.is_some() {
+            let expiry_reminder_days = state.conf.api_keys.get_inner().expiry_reminder_days.clone();
+
+            add_api_key_expiry_task(store, &new_api_key, expiry_reminder_days)
+                .await
+                .into_report()
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert API key expiry reminder to process tracker")?;
+        }
+    }
+
+    Ok(ApplicationResponse::Json(
+        (new_api_key, plaintext_api_key).foreign_into(),
+    ))
+}
+
+/// Records the time at which the API key was used, unless it was recorded recently. Failing to
+/// record it does not fail the request.
+pub async fn update_last_used(store: &dyn StorageInterface, api_key: &storage::ApiKey) {
+    let now = date_time::now();
+    if api_key
+        .last_used
+        .is_some_and(|last_used| last_used.saturating_add(LAST_USED_UPDATE_INTERVAL) > now)
+    {
+        return;
+    }
+
+    if let Err(error) = store
+        .update_api_key(
+            api_key.merchant_id.clone(),
+            api_key.key_id.clone(),
+            storage::ApiKeyUpdate::LastUsedUpdate { last_used: now },
+        )
+        .await
+    {
+        logger::error!(
+            ?error,
+            "Failed to update the time at which the API key was last used"
+        );
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn revoke_api_key(
     state: AppState,
@@ -408,6 +644,13 @@ pub async fn revoke_api_key(
     key_id: &str,
 ) -> RouterResponse<api::RevokeApiKeyResponse> {
     let store = state.store.as_ref();
+    let api_key_before: Option<api::RetrieveApiKeyResponse> = store
+        .find_api_key_by_merchant_id_key_id_optional(merchant_id, key_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve API key")?
+        .map(ForeignInto::foreign_into);
+
     let revoked = store
         .revoke_api_key(merchant_id, key_id)
         .await
@@ -415,6 +658,18 @@ pub async fn revoke_api_key(
 
     metrics::API_KEY_REVOKED.add(&metrics::CONTEXT, 1, &[]);
 
+    if revoked {
+        audit_logs::record_audit_log(
+            &state,
+            merchant_id,
+            storage::enums::AuditOperation::ApiKeyRevoke,
+            key_id,
+            api_key_before.as_ref(),
+            None,
+        )
+        .await;
+    }
+
     #[cfg(feature = "email")]
     {
         let task_id = generate_task_id_for_api_key_expiry_workflow(key_id);
@@ -541,4 +796,45 @@ mod tests {
         let new_hashed_api_key = plaintext_api_key.keyed_hash(hash_key.peek());
         assert_eq!(hashed_api_key, new_hashed_api_key)
     }
+
+    #[tokio::test]
+    async fn test_last_used_is_updated_at_most_once_within_the_interval() {
+        use crate::db::{api_keys::ApiKeyInterface, MockDb};
+
+        let db = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create mock DB");
+        let api_key = db
+            .insert_api_key(storage::ApiKeyNew {
+                key_id: "key_id".into(),
+                merchant_id: "merchant".into(),
+                name: "Key".into(),
+                description: None,
+                hashed_api_key: "hashed_key".to_string().into(),
+                prefix: "abc".into(),
+                created_at: date_time::now(),
+                expires_at: None,
+                last_used: None,
+                profile_id: None,
+                scopes: None,
+            })
+            .await
+            .unwrap();
+
+        update_last_used(&db, &api_key).await;
+        let used_api_key = db
+            .find_api_key_by_merchant_id_key_id_optional("merchant", "key_id")
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(used_api_key.last_used.is_some());
+
+        update_last_used(&db, &used_api_key).await;
+        let reused_api_key = db
+            .find_api_key_by_merchant_id_key_id_optional("merchant", "key_id")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(reused_api_key.last_used, used_api_key.last_used);
+    }
 }
@@ -0,0 +1,230 @@
+//! Audit log of the mutating admin operations.
+//!
+//! Every update of a merchant account, change of a merchant connector account, change of the
+//! routing configuration and creation, update or revocation of an API key is recorded along with
+//! the actor who performed it and the fields it changed. The changes are computed from the masked
+//! serialization of the entity before and after the operation, so that secrets such as connector
+//! credentials are never stored in the audit log.
+
+use common_utils::date_time;
+use error_stack::ResultExt;
+use router_env::{instrument, logger, tracing};
+use serde::Serialize;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse},
+    routes::AppState,
+    services::{authentication::AuthenticationType, ApplicationResponse},
+    types::{
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+    utils,
+};
+
+const AUDIT_LOG_LIST_MAX_LIMIT: i64 = 100;
+
+/// Records an operation in the audit log of the merchant, with the changes between the entity
+/// before and after the operation. The entity is absent before its creation and after its
+/// deletion.
+///
+/// The operation has already been performed when it is recorded, so failures to record it are
+/// logged rather than failing the request.
+#[instrument(skip(state, before, after))]
+pub async fn record_audit_log<T: Serialize>(
+    state: &AppState,
+    merchant_id: &str,
+    operation: storage_enums::AuditOperation,
+    entity_id: &str,
+    before: Option<&T>,
+    after: Option<&T>,
+) {
+    let (actor_type, actor_id) = get_actor(state.authentication_type.as_ref());
+    let changes = match (
+        before.map(masking::masked_serialize).transpose(),
+        after.map(masking::masked_serialize).transpose(),
+    ) {
+        (Ok(before), Ok(after)) => compute_changes(before, after),
+        (Err(error), _) | (_, Err(error)) => {
+            logger::error!(
+                ?error,
+                "Failed to serialize the entity changed by {operation}"
+            );
+            return;
+        }
+    };
+
+    let audit_log = storage::AuditLogNew {
+        audit_id: utils::generate_id(consts::ID_LENGTH, "audit"),
+        merchant_id: merchant_id.to_owned(),
+        operation,
+        entity_id: entity_id.to_owned(),
+        actor_type,
+        actor_id,
+        request_id: state.request_id.as_ref().map(ToString::to_string),
+        changes,
+        created_at: date_time::now(),
+    };
+
+    if let Err(error) = state.store.insert_audit_log(audit_log).await {
+        logger::error!(?error, "Failed to record {operation} in the audit log");
+    }
+}
+
+/// The kind and the identifier of the actor who authenticated the request.
+fn get_actor(
+    authentication_type: Option<&AuthenticationType>,
+) -> (storage_enums::AuditActorType, Option<String>) {
+    match authentication_type {
+        Some(AuthenticationType::AdminApiKey) => (storage_enums::AuditActorType::AdminApiKey, None),
+        Some(AuthenticationType::ApiKey { key_id, .. }) => {
+            (storage_enums::AuditActorType::ApiKey, Some(key_id.clone()))
+        }
+        Some(AuthenticationType::MerchantJwt { user_id, .. }) => {
+            (storage_enums::AuditActorType::User, user_id.clone())
+        }
+        Some(AuthenticationType::UserJwt { user_id }) => {
+            (storage_enums::AuditActorType::User, Some(user_id.clone()))
+        }
+        Some(AuthenticationType::ImpersonatedJwt {
+            impersonator_id, ..
+        }) => (
+            storage_enums::AuditActorType::ImpersonatingUser,
+            Some(impersonator_id.clone()),
+        ),
+        Some(
+            AuthenticationType::MerchantId { .. }
+            | AuthenticationType::PublishableKey { .. }
+            | AuthenticationType::ClientSession { .. }
+            | AuthenticationType::WebhookAuth { .. }
+            | AuthenticationType::NoAuth,
+        )
+        | None => (storage_enums::AuditActorType::Internal, None),
+    }
+}
+
+/// The top level fields which differ between the serialized entity before and after the
+/// operation, each with its value before and after the operation.
+fn compute_changes(
+    before: Option<serde_json::Value>,
+    after: Option<serde_json::Value>,
+) -> serde_json::Value {
+    let into_fields = |value: Option<serde_json::Value>| match value {
+        Some(serde_json::Value::Object(fields)) => fields,
+        Some(value) => serde_json::Map::from_iter([("value".to_string(), value)]),
+        None => serde_json::Map::new(),
+    };
+    let before = into_fields(before);
+    let mut after = into_fields(after);
+
+    let mut changes = serde_json::Map::new();
+    for (field, before_value) in before {
+        let after_value = after.remove(&field).unwrap_or_default();
+        if before_value != after_value {
+            changes.insert(
+                field,
+                serde_json::json!({ "before": before_value, "after": after_value }),
+            );
+        }
+    }
+    // The fields remaining are only present after the operation
+    for (field, after_value) in after {
+        if !after_value.is_null() {
+            changes.insert(
+                field,
+                serde_json::json!({ "before": serde_json::Value::Null, "after": after_value }),
+            );
+        }
+    }
+
+    serde_json::Value::Object(changes)
+}
+
+#[instrument(skip(state))]
+pub async fn list_audit_logs(
+    state: AppState,
+    merchant_id: String,
+    constraints: api_models::audit_logs::AuditLogListConstraints,
+) -> RouterResponse<Vec<api_models::audit_logs::AuditLogResponse>> {
+    let limit = match constraints.limit.map(i64::from) {
+        Some(limit) if limit > AUDIT_LOG_LIST_MAX_LIMIT => {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("`limit` must be a number less than {AUDIT_LOG_LIST_MAX_LIMIT}"),
+            })
+        }
+        Some(limit) => Ok(limit),
+        None => Ok(AUDIT_LOG_LIST_MAX_LIMIT),
+    }?;
+    let offset = constraints
+        .offset
+        .map(i64::from)
+        .filter(|offset| *offset > 0);
+
+    let audit_logs = state
+        .store
+        .list_audit_logs_by_merchant_id_constraints(
+            &merchant_id,
+            storage::AuditLogListConstraints {
+                created_after: constraints.created_after,
+                created_before: constraints.created_before,
+                operation: constraints.operation,
+                entity_id: constraints.entity_id,
+                actor_id: constraints.actor_id,
+                limit: Some(limit),
+                offset,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list audit logs with specified constraints")?;
+
+    Ok(ApplicationResponse::Json(
+        audit_logs
+            .into_iter()
+            .map(api_models::audit_logs::AuditLogResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::compute_changes;
+
+    #[test]
+    fn test_only_changed_fields_are_recorded() {
+        let before =
+            json!({ "return_url": "https://example.com", "locker_id": "m0010", "metadata": null });
+        let after = json!({ "return_url": "https://example.com/return", "locker_id": "m0010", "metadata": { "key": "value" } });
+
+        assert_eq!(
+            compute_changes(Some(before), Some(after)),
+            json!({
+                "return_url": { "before": "https://example.com", "after": "https://example.com/return" },
+                "metadata": { "before": null, "after": { "key": "value" } },
+            })
+        );
+    }
+
+    #[test]
+    fn test_created_and_deleted_entities_are_recorded() {
+        let entity = json!({ "key_id": "dev_abc", "name": "key", "description": null });
+
+        assert_eq!(
+            compute_changes(None, Some(entity.clone())),
+            json!({
+                "key_id": { "before": null, "after": "dev_abc" },
+                "name": { "before": null, "after": "key" },
+            })
+        );
+        assert_eq!(
+            compute_changes(Some(entity), None),
+            json!({
+                "key_id": { "before": "dev_abc", "after": null },
+                "name": { "before": "key", "after": null },
+            })
+        );
+    }
+}
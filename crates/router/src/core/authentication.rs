@@ -6,20 +6,27 @@ pub mod types;
 use api_models::payments;
 use common_enums::Currency;
 use common_utils::{errors::CustomResult, ext_traits::ValueExt};
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 use masking::PeekInterface;
 
 use super::errors;
 use crate::{
     core::{errors::ApiErrorResponse, payments as payments_core},
+    errors::RouterResult,
     routes::AppState,
-    types::{self as core_types, api, authentication::AuthenticationResponseData, storage},
+    types::{self as core_types, api, authentication::AuthenticationResponseData, domain, storage},
     utils::OptionExt,
 };
 
+/// Performs the authentication with the authentication connector the pre-authentication was
+/// performed with. When the primary authentication connector of the business profile is
+/// unavailable, the pre-authentication and authentication are performed again with its fallback
+/// authentication connector, and the authentication they are recorded in is provided along with
+/// the response.
 #[allow(clippy::too_many_arguments)]
 pub async fn perform_authentication(
     state: &AppState,
+    key_store: &domain::MerchantKeyStore,
     authentication_connector: String,
     payment_method_data: payments::PaymentMethodData,
     payment_method: common_enums::PaymentMethod,
@@ -37,29 +44,162 @@ pub async fn perform_authentication(
     sdk_information: Option<payments::SdkInformation>,
     threeds_method_comp_ind: api_models::payments::ThreeDsCompletionIndicator,
     email: Option<common_utils::pii::Email>,
-) -> CustomResult<core_types::api::authentication::AuthenticationResponse, ApiErrorResponse> {
-    let router_data = transformers::construct_authentication_router_data(
+    sca_exemption: Option<common_enums::ScaExemptionType>,
+    payment_id: &str,
+) -> CustomResult<
+    (
+        core_types::api::authentication::AuthenticationResponse,
+        storage::Authentication,
+    ),
+    ApiErrorResponse,
+> {
+    let construct_router_data =
+        |authentication_connector: String,
+         merchant_connector_account: payments_core::helpers::MerchantConnectorAccountType,
+         authentication_data: storage::Authentication,
+         threeds_method_comp_ind: api_models::payments::ThreeDsCompletionIndicator| {
+            transformers::construct_authentication_router_data(
+                authentication_connector,
+                payment_method_data.clone(),
+                payment_method,
+                billing_address.clone(),
+                shipping_address.clone(),
+                browser_details.clone(),
+                amount,
+                currency,
+                message_category.clone(),
+                device_channel.clone(),
+                business_profile.clone(),
+                merchant_connector_account,
+                authentication_data,
+                return_url.clone(),
+                sdk_information.clone(),
+                threeds_method_comp_ind,
+                email.clone(),
+                sca_exemption,
+            )
+        };
+
+    let router_data = construct_router_data(
         authentication_connector.clone(),
-        payment_method_data,
-        payment_method,
-        billing_address,
-        shipping_address,
-        browser_details,
-        amount,
-        currency,
-        message_category,
-        device_channel,
-        business_profile,
         merchant_connector_account,
         authentication_data.clone(),
-        return_url,
-        sdk_information,
         threeds_method_comp_ind,
-        email,
     )?;
     let response =
-        utils::do_auth_connector_call(state, authentication_connector.clone(), router_data).await?;
-    utils::update_trackers(state, response.clone(), authentication_data, None, None).await?;
+        utils::do_auth_connector_call(state, authentication_connector.clone(), router_data).await;
+
+    let fallback_authentication_connector =
+        if utils::is_authentication_connector_unavailable(&response) {
+            utils::get_fallback_authentication_connector(
+                state,
+                &business_profile,
+                key_store,
+                &authentication_connector,
+            )
+            .await
+        } else {
+            None
+        };
+
+    let (authentication_connector, authentication_data, response) =
+        match fallback_authentication_connector {
+            Some((fallback_authentication_connector, fallback_connector_account)) => {
+                utils::add_authentication_connector_fallback_metric(
+                    &authentication_connector,
+                    &fallback_authentication_connector,
+                    "authentication",
+                );
+                let previous_authentication = utils::update_trackers_on_connector_unavailable(
+                    state,
+                    response,
+                    authentication_data,
+                )
+                .await?;
+
+                // The fallback authentication connector has no context of the pre-authentication
+                // performed with the primary one, which is performed again. The 3DS method of the
+                // fallback authentication connector is not performed.
+                let card_number = match &payment_method_data {
+                    payments::PaymentMethodData::Card(card) => Ok(card.card_number.clone()),
+                    _ => Err(ApiErrorResponse::InternalServerError)
+                        .into_report()
+                        .attach_printable(
+                            "External 3DS authentication is applicable only for cards",
+                        ),
+                }?;
+                let acquirer_details = previous_authentication
+                    .acquirer_bin
+                    .clone()
+                    .zip(previous_authentication.acquirer_merchant_id.clone())
+                    .map(
+                        |(acquirer_bin, acquirer_merchant_id)| types::AcquirerDetails {
+                            acquirer_bin,
+                            acquirer_merchant_id,
+                        },
+                    )
+                    .ok_or(ApiErrorResponse::InternalServerError)
+                    .into_report()
+                    .attach_printable("Acquirer details not found in the authentication")?;
+
+                let fallback_authentication = utils::create_new_authentication(
+                    state,
+                    business_profile.merchant_id.clone(),
+                    fallback_authentication_connector.clone(),
+                    Some(previous_authentication.authentication_id),
+                )
+                .await?;
+                let pre_authentication_router_data = call_pre_authentication_connector(
+                    state,
+                    fallback_authentication_connector.clone(),
+                    card_number,
+                    &fallback_connector_account,
+                    business_profile.merchant_id.clone(),
+                )
+                .await?;
+                let fallback_authentication = utils::update_trackers(
+                    state,
+                    pre_authentication_router_data,
+                    fallback_authentication,
+                    None,
+                    Some(acquirer_details),
+                    None,
+                )
+                .await?;
+
+                let router_data = construct_router_data(
+                    fallback_authentication_connector.clone(),
+                    fallback_connector_account,
+                    fallback_authentication.clone(),
+                    api_models::payments::ThreeDsCompletionIndicator::NotAvailable,
+                )?;
+                let response = utils::do_auth_connector_call(
+                    state,
+                    fallback_authentication_connector.clone(),
+                    router_data,
+                )
+                .await?;
+                (
+                    fallback_authentication_connector,
+                    fallback_authentication,
+                    response,
+                )
+            }
+            None => (authentication_connector, authentication_data, response?),
+        };
+
+    let threeds_method_comp_ind = Some(response.request.threeds_method_comp_ind.clone());
+    let authentication = utils::update_trackers(
+        state,
+        response.clone(),
+        authentication_data,
+        None,
+        None,
+        threeds_method_comp_ind,
+    )
+    .await?;
+    utils::trigger_authentication_webhook(state, &business_profile, &authentication, payment_id)
+        .await;
     let authentication_response =
         response
             .response
@@ -70,12 +210,12 @@ pub async fn perform_authentication(
                 status_code: err.status_code,
                 reason: err.reason,
             })?;
-    match authentication_response {
+    let authentication_response = match authentication_response {
         AuthenticationResponseData::AuthNResponse {
             authn_flow_type,
             trans_status,
             ..
-        } => Ok(match authn_flow_type {
+        } => match authn_flow_type {
             core_types::authentication::AuthNFlowType::Challenge(challenge_params) => {
                 core_types::api::AuthenticationResponse {
                     trans_status,
@@ -98,10 +238,11 @@ pub async fn perform_authentication(
                     acs_signed_content: None,
                 }
             }
-        }),
+        },
         _ => Err(errors::ApiErrorResponse::InternalServerError.into())
             .attach_printable("unexpected response in authentication flow")?,
-    }
+    };
+    Ok((authentication_response, authentication))
 }
 
 pub async fn perform_post_authentication<F: Clone + Send>(
@@ -122,7 +263,7 @@ pub async fn perform_post_authentication<F: Clone + Send>(
                 if !authentication.authentication_status.is_terminal_status() {
                     let router_data = transformers::construct_post_authentication_router_data(
                         authentication_connector.clone(),
-                        business_profile,
+                        business_profile.clone(),
                         merchant_connector_account,
                         &authentication,
                     )?;
@@ -135,8 +276,16 @@ pub async fn perform_post_authentication<F: Clone + Send>(
                         authentication,
                         payment_data.token.clone(),
                         None,
+                        None,
                     )
                     .await?;
+                    utils::trigger_authentication_webhook(
+                        state,
+                        &business_profile,
+                        &updated_authentication,
+                        &payment_data.payment_intent.payment_id,
+                    )
+                    .await;
                     let authentication_status = updated_authentication.authentication_status;
                     payment_data.authentication = Some(updated_authentication);
                     authentication_status
@@ -155,35 +304,23 @@ pub async fn perform_post_authentication<F: Clone + Send>(
     Ok(())
 }
 
+/// Performs the pre-authentication with the primary authentication connector of the business
+/// profile, and with its fallback authentication connector when the primary one is unavailable
 pub async fn perform_pre_authentication<F: Clone + Send>(
     state: &AppState,
+    key_store: &domain::MerchantKeyStore,
     authentication_connector_name: String,
     authentication_flow_input: types::PreAuthenthenticationFlowInput<'_, F>,
     business_profile: &core_types::storage::BusinessProfile,
     three_ds_connector_account: payments_core::helpers::MerchantConnectorAccountType,
     payment_connector_account: payments_core::helpers::MerchantConnectorAccountType,
 ) -> CustomResult<(), ApiErrorResponse> {
-    let authentication = utils::create_new_authentication(
-        state,
-        business_profile.merchant_id.clone(),
-        authentication_connector_name.clone(),
-    )
-    .await?;
     match authentication_flow_input {
         types::PreAuthenthenticationFlowInput::PaymentAuthNFlow {
             payment_data,
             should_continue_confirm_transaction,
             card_number,
         } => {
-            let router_data = transformers::construct_pre_authentication_router_data(
-                authentication_connector_name.clone(),
-                card_number,
-                &three_ds_connector_account,
-                business_profile.merchant_id.clone(),
-            )?;
-            let router_data =
-                utils::do_auth_connector_call(state, authentication_connector_name, router_data)
-                    .await?;
             let acquirer_details: types::AcquirerDetails = payment_connector_account
                 .get_metadata()
                 .get_required_value("merchant_connector_account.metadata")?
@@ -192,14 +329,85 @@ pub async fn perform_pre_authentication<F: Clone + Send>(
                 .parse_value("AcquirerDetails")
                 .change_context(ApiErrorResponse::PreconditionFailed { message: "acquirer_bin and acquirer_merchant_id not found in Payment Connector's Metadata".to_string()})?;
 
+            let authentication = utils::create_new_authentication(
+                state,
+                business_profile.merchant_id.clone(),
+                authentication_connector_name.clone(),
+                None,
+            )
+            .await?;
+            let router_data = call_pre_authentication_connector(
+                state,
+                authentication_connector_name.clone(),
+                card_number.clone(),
+                &three_ds_connector_account,
+                business_profile.merchant_id.clone(),
+            )
+            .await;
+
+            let fallback_authentication_connector =
+                if utils::is_authentication_connector_unavailable(&router_data) {
+                    utils::get_fallback_authentication_connector(
+                        state,
+                        business_profile,
+                        key_store,
+                        &authentication_connector_name,
+                    )
+                    .await
+                } else {
+                    None
+                };
+
+            let (authentication, router_data) = match fallback_authentication_connector {
+                Some((fallback_authentication_connector, fallback_connector_account)) => {
+                    utils::add_authentication_connector_fallback_metric(
+                        &authentication_connector_name,
+                        &fallback_authentication_connector,
+                        "pre_authentication",
+                    );
+                    let previous_authentication = utils::update_trackers_on_connector_unavailable(
+                        state,
+                        router_data,
+                        authentication,
+                    )
+                    .await?;
+
+                    let fallback_authentication = utils::create_new_authentication(
+                        state,
+                        business_profile.merchant_id.clone(),
+                        fallback_authentication_connector.clone(),
+                        Some(previous_authentication.authentication_id),
+                    )
+                    .await?;
+                    let router_data = call_pre_authentication_connector(
+                        state,
+                        fallback_authentication_connector,
+                        card_number,
+                        &fallback_connector_account,
+                        business_profile.merchant_id.clone(),
+                    )
+                    .await?;
+                    (fallback_authentication, router_data)
+                }
+                None => (authentication, router_data?),
+            };
+
             let authentication = utils::update_trackers(
                 state,
                 router_data,
                 authentication,
                 payment_data.token.clone(),
                 Some(acquirer_details),
+                None,
             )
             .await?;
+            utils::trigger_authentication_webhook(
+                state,
+                business_profile,
+                &authentication,
+                &payment_data.payment_intent.payment_id,
+            )
+            .await;
             if authentication.is_separate_authn_required()
                 || authentication.authentication_status.is_failed()
             {
@@ -216,3 +424,19 @@ pub async fn perform_pre_authentication<F: Clone + Send>(
     };
     Ok(())
 }
+
+async fn call_pre_authentication_connector(
+    state: &AppState,
+    authentication_connector_name: String,
+    card_number: cards::CardNumber,
+    three_ds_connector_account: &payments_core::helpers::MerchantConnectorAccountType,
+    merchant_id: String,
+) -> RouterResult<core_types::authentication::PreAuthNRouterData> {
+    let router_data = transformers::construct_pre_authentication_router_data(
+        authentication_connector_name.clone(),
+        card_number,
+        three_ds_connector_account,
+        merchant_id,
+    )?;
+    utils::do_auth_connector_call(state, authentication_connector_name, router_data).await
+}
@@ -43,6 +43,7 @@ pub fn construct_authentication_router_data(
     sdk_information: Option<api_models::payments::SdkInformation>,
     threeds_method_comp_ind: api_models::payments::ThreeDsCompletionIndicator,
     email: Option<common_utils::pii::Email>,
+    sca_exemption: Option<common_enums::ScaExemptionType>,
 ) -> RouterResult<types::authentication::ConnectorAuthenticationRouterData> {
     let authentication_details: api_models::admin::AuthenticationConnectorDetails =
         business_profile
@@ -72,6 +73,7 @@ pub fn construct_authentication_router_data(
         email,
         three_ds_requestor_url: authentication_details.three_ds_requestor_url,
         threeds_method_comp_ind,
+        sca_exemption,
     };
     construct_router_data(
         authentication_connector,
@@ -1,10 +1,13 @@
+use api_models::{payments::ExternalAuthenticationResponse, webhooks::OutgoingWebhookContent};
+use common_utils::ext_traits::ValueExt;
 use error_stack::ResultExt;
+use router_env::logger;
 
 use crate::{
     consts,
     core::{
         errors::{self, ConnectorErrorExt, StorageErrorExt},
-        payments,
+        metrics, payments, webhooks as webhooks_core,
     },
     errors::RouterResult,
     routes::AppState,
@@ -12,7 +15,8 @@ use crate::{
     types::{
         api::{self, ConnectorCallType},
         authentication::AuthenticationResponseData,
-        storage,
+        domain,
+        storage::{self, enums as storage_enums},
         transformers::ForeignFrom,
         RouterData,
     },
@@ -52,6 +56,7 @@ pub async fn update_trackers<F: Clone, Req>(
     authentication: storage::Authentication,
     token: Option<String>,
     acquirer_details: Option<super::types::AcquirerDetails>,
+    threeds_method_comp_ind: Option<api_models::payments::ThreeDsCompletionIndicator>,
 ) -> RouterResult<storage::Authentication> {
     let authentication_update = match router_data.response {
         Ok(response) => match response {
@@ -96,6 +101,15 @@ pub async fn update_trackers<F: Clone, Req>(
                     acs_signed_content: authn_flow_type.get_acs_signed_content(),
                     authentication_type: authn_flow_type.get_decoupled_authentication_type(),
                     authentication_status,
+                    three_ds_method_completed: threeds_method_comp_ind.and_then(|comp_ind| {
+                        match comp_ind {
+                            api_models::payments::ThreeDsCompletionIndicator::Success => Some(true),
+                            api_models::payments::ThreeDsCompletionIndicator::Failure => {
+                                Some(false)
+                            }
+                            api_models::payments::ThreeDsCompletionIndicator::NotAvailable => None,
+                        }
+                    }),
                 }
             }
             AuthenticationResponseData::PostAuthNResponse {
@@ -144,6 +158,7 @@ pub async fn create_new_authentication(
     state: &AppState,
     merchant_id: String,
     authentication_connector: String,
+    previous_authentication_id: Option<String>,
 ) -> RouterResult<storage::Authentication> {
     let authentication_id =
         common_utils::generate_id_with_default_len(consts::AUTHENTICATION_ID_PREFIX);
@@ -176,6 +191,8 @@ pub async fn create_new_authentication(
         acs_trans_id: None,
         three_dsserver_trans_id: None,
         acs_signed_content: None,
+        previous_authentication_id,
+        three_ds_method_completed: None,
     };
     state
         .store
@@ -215,3 +232,224 @@ where
     .to_payment_failed_response()?;
     Ok(router_data)
 }
+
+/// Provides whether the authentication connector could not be reached or failed with a server
+/// error, in which case the authentication can be retried with the fallback authentication
+/// connector
+pub fn is_authentication_connector_unavailable<F, Req>(
+    router_data: &RouterResult<RouterData<F, Req, AuthenticationResponseData>>,
+) -> bool {
+    match router_data {
+        Ok(router_data) => router_data
+            .response
+            .as_ref()
+            .is_err_and(|error| error.status_code >= 500),
+        Err(_) => true,
+    }
+}
+
+/// Marks the authentication as failed when the authentication connector is unavailable, so that
+/// the authentication retried with the fallback authentication connector records both attempts
+pub async fn update_trackers_on_connector_unavailable<F: Clone, Req>(
+    state: &AppState,
+    router_data: RouterResult<RouterData<F, Req, AuthenticationResponseData>>,
+    authentication: storage::Authentication,
+) -> RouterResult<storage::Authentication> {
+    match router_data {
+        Ok(router_data) => {
+            update_trackers(state, router_data, authentication, None, None, None).await
+        }
+        Err(error) => {
+            logger::error!(?error, "Authentication connector could not be reached");
+            state
+                .store
+                .update_authentication_by_merchant_id_authentication_id(
+                    authentication,
+                    storage::AuthenticationUpdate::ErrorUpdate {
+                        connector_authentication_id: None,
+                        authentication_status: common_enums::AuthenticationStatus::Failed,
+                        error_message: Some("Authentication connector unavailable".to_string()),
+                        error_code: Some(consts::NO_ERROR_CODE.to_string()),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Error while updating authentication")
+        }
+    }
+}
+
+/// Provides the fallback authentication connector of the business profile, the second connector
+/// of its authentication connectors, along with its merchant connector account. Authentications
+/// are retried with the fallback only from the primary authentication connector.
+pub async fn get_fallback_authentication_connector(
+    state: &AppState,
+    business_profile: &storage::BusinessProfile,
+    key_store: &domain::MerchantKeyStore,
+    authentication_connector: &str,
+) -> Option<(String, payments::helpers::MerchantConnectorAccountType)> {
+    let authentication_details: api_models::admin::AuthenticationConnectorDetails =
+        business_profile
+            .authentication_connector_details
+            .clone()?
+            .parse_value("AuthenticationConnectorDetails")
+            .map_err(|error| {
+                logger::error!(?error, "Failed to parse authentication connector details");
+            })
+            .ok()?;
+
+    let fallback_authentication_connector =
+        match authentication_details.authentication_connectors.as_slice() {
+            [primary, fallback, ..]
+                if primary.to_string() == authentication_connector && fallback != primary =>
+            {
+                fallback.to_string()
+            }
+            _ => return None,
+        };
+
+    payments::helpers::get_merchant_connector_account(
+        state,
+        &business_profile.merchant_id,
+        None,
+        key_store,
+        &business_profile.profile_id,
+        &fallback_authentication_connector,
+        None,
+    )
+    .await
+    .map_err(|error| {
+        logger::error!(
+            ?error,
+            %fallback_authentication_connector,
+            "Failed to fetch the merchant connector account of the fallback authentication connector"
+        );
+    })
+    .ok()
+    .map(|merchant_connector_account| {
+        (
+            fallback_authentication_connector,
+            merchant_connector_account,
+        )
+    })
+}
+
+pub fn add_authentication_connector_fallback_metric(
+    authentication_connector: &str,
+    fallback_authentication_connector: &str,
+    stage: &'static str,
+) {
+    metrics::AUTHENTICATION_CONNECTOR_FALLBACK_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[
+            metrics::KeyValue::new("connector", authentication_connector.to_string()),
+            metrics::KeyValue::new(
+                "fallback_connector",
+                fallback_authentication_connector.to_string(),
+            ),
+            metrics::KeyValue::new("stage", stage),
+        ],
+    );
+    logger::warn!(
+        %authentication_connector,
+        %fallback_authentication_connector,
+        stage,
+        "Authentication connector unavailable, retrying with the fallback authentication connector"
+    );
+}
+
+/// Provides the event type of the authentication lifecycle milestone the authentication is at
+pub fn get_authentication_event_type(
+    authentication: &storage::Authentication,
+) -> storage_enums::EventType {
+    match authentication.authentication_status {
+        common_enums::AuthenticationStatus::Started
+        | common_enums::AuthenticationStatus::Pending => {
+            if authentication.acs_url.is_some() {
+                storage_enums::EventType::ChallengeRequired
+            } else {
+                storage_enums::EventType::AuthenticationStarted
+            }
+        }
+        common_enums::AuthenticationStatus::Success => {
+            storage_enums::EventType::AuthenticationSucceeded
+        }
+        common_enums::AuthenticationStatus::Failed => {
+            storage_enums::EventType::AuthenticationFailed
+        }
+    }
+}
+
+pub fn get_authentication_response(
+    authentication: &storage::Authentication,
+    payment_id: Option<String>,
+) -> ExternalAuthenticationResponse {
+    ExternalAuthenticationResponse {
+        authentication_id: authentication.authentication_id.clone(),
+        payment_id,
+        authentication_connector: authentication.authentication_connector.clone(),
+        authentication_details: ForeignFrom::foreign_from(authentication),
+        acs_url: authentication.acs_url.clone(),
+        created_at: authentication.created_at,
+    }
+}
+
+/// Notifies the merchant of the authentication lifecycle milestone the authentication is at.
+/// Failures are only logged, as they must not affect the payment the authentication is performed
+/// for.
+pub async fn trigger_authentication_webhook(
+    state: &AppState,
+    business_profile: &storage::BusinessProfile,
+    authentication: &storage::Authentication,
+    payment_id: &str,
+) {
+    if let Err(error) =
+        create_authentication_event(state, business_profile, authentication, payment_id).await
+    {
+        logger::error!(
+            ?error,
+            authentication_id = %authentication.authentication_id,
+            "Failed to trigger authentication webhook"
+        );
+    }
+}
+
+async fn create_authentication_event(
+    state: &AppState,
+    business_profile: &storage::BusinessProfile,
+    authentication: &storage::Authentication,
+    payment_id: &str,
+) -> RouterResult<()> {
+    let db = &*state.store;
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &authentication.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&authentication.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account,
+        business_profile.clone(),
+        &key_store,
+        get_authentication_event_type(authentication),
+        storage_enums::EventClass::Authentications,
+        authentication.authentication_id.clone(),
+        storage_enums::EventObjectType::AuthenticationDetails,
+        OutgoingWebhookContent::AuthenticationDetails(Box::new(get_authentication_response(
+            authentication,
+            Some(payment_id.to_string()),
+        ))),
+        Some(authentication.created_at),
+    ))
+    .await
+}
@@ -1,18 +1,25 @@
 use api_models::blocklist as api_blocklist;
 use common_enums::MerchantDecision;
-use common_utils::errors::CustomResult;
+use common_utils::{
+    consts::BLOCKLIST_DECLINE_CODE,
+    crypto::{self, SignMessage},
+    errors::CustomResult,
+    pii,
+};
 use diesel_models::configs;
 use error_stack::{IntoReport, ResultExt};
-use masking::StrongSecret;
+use masking::{PeekInterface, StrongSecret};
 
 use super::{errors, transformers::generate_fingerprint, AppState};
 use crate::{
     consts,
     core::{
         errors::{RouterResult, StorageErrorExt},
+        payment_methods::card_fingerprint,
         payments::PaymentData,
     },
     logger,
+    routes::metrics,
     types::{domain, storage, transformers::ForeignInto},
     utils,
 };
@@ -38,6 +45,40 @@ pub async fn delete_entry_from_blocklist(
             .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
                 message: "no blocklist record for the given fingerprint id was found".to_string(),
             })?,
+
+        api_blocklist::DeleteFromBlocklistRequest::Email(email) => {
+            let email_fingerprint =
+                generate_email_fingerprint(state, &merchant_id, &validate_email(&email)?).await?;
+            state
+                .store
+                .delete_blocklist_entry_by_merchant_id_fingerprint_id(
+                    &merchant_id,
+                    &email_fingerprint,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: "no blocklist record for the given email was found".to_string(),
+                })?
+        }
+
+        api_blocklist::DeleteFromBlocklistRequest::IpAddress(ip_address) => {
+            let ip_address_fingerprint = generate_ip_address_fingerprint(
+                state,
+                &merchant_id,
+                validate_ip_address(&ip_address)?,
+            )
+            .await?;
+            state
+                .store
+                .delete_blocklist_entry_by_merchant_id_fingerprint_id(
+                    &merchant_id,
+                    &ip_address_fingerprint,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+                    message: "no blocklist record for the given ip address was found".to_string(),
+                })?
+        }
     };
 
     Ok(blocklist_entry.foreign_into())
@@ -137,6 +178,26 @@ fn validate_extended_card_bin(bin: &str) -> RouterResult<()> {
     }
 }
 
+fn validate_email(email: &str) -> RouterResult<pii::Email> {
+    email
+        .parse::<pii::Email>()
+        .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "data".to_string(),
+            expected_format: "a valid email address".to_string(),
+        })
+}
+
+fn validate_ip_address(ip_address: &str) -> RouterResult<std::net::IpAddr> {
+    ip_address
+        .trim()
+        .parse::<std::net::IpAddr>()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InvalidDataFormat {
+            field_name: "data".to_string(),
+            expected_format: "a valid IPv4 or IPv6 address".to_string(),
+        })
+}
+
 pub async fn insert_entry_into_blocklist(
     state: &AppState,
     merchant_id: String,
@@ -165,6 +226,34 @@ pub async fn insert_entry_into_blocklist(
             .await?
         }
 
+        api_blocklist::AddToBlocklistRequest::Email(email) => {
+            let email_fingerprint =
+                generate_email_fingerprint(state, &merchant_id, &validate_email(email)?).await?;
+            duplicate_check_insert_bin(
+                &email_fingerprint,
+                state,
+                &merchant_id,
+                common_enums::BlocklistDataKind::Email,
+            )
+            .await?
+        }
+
+        api_blocklist::AddToBlocklistRequest::IpAddress(ip_address) => {
+            let ip_address_fingerprint = generate_ip_address_fingerprint(
+                state,
+                &merchant_id,
+                validate_ip_address(ip_address)?,
+            )
+            .await?;
+            duplicate_check_insert_bin(
+                &ip_address_fingerprint,
+                state,
+                &merchant_id,
+                common_enums::BlocklistDataKind::IpAddress,
+            )
+            .await?
+        }
+
         api_blocklist::AddToBlocklistRequest::Fingerprint(fingerprint_id) => {
             let blocklist_entry_result = state
                 .store
@@ -244,6 +333,63 @@ fn get_merchant_fingerprint_secret_key(merchant_id: &str) -> String {
     format!("fingerprint_secret_{merchant_id}")
 }
 
+/// Emails and IP addresses are blocklisted by their HMAC keyed with the merchant fingerprint
+/// secret, so that they are not stored in plain text
+async fn generate_blocklist_fingerprint(
+    state: &AppState,
+    merchant_id: &str,
+    data_kind: common_enums::BlocklistDataKind,
+    data: &str,
+) -> RouterResult<String> {
+    let merchant_fingerprint_secret = get_merchant_fingerprint_secret(state, merchant_id).await?;
+    crypto::HmacSha256::sign_message(
+        &crypto::HmacSha256,
+        merchant_fingerprint_secret.as_bytes(),
+        format!("{data_kind}:{data}").as_bytes(),
+    )
+    .map(hex::encode)
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("error generating blocklist fingerprint")
+}
+
+async fn generate_email_fingerprint(
+    state: &AppState,
+    merchant_id: &str,
+    email: &pii::Email,
+) -> RouterResult<String> {
+    generate_blocklist_fingerprint(
+        state,
+        merchant_id,
+        common_enums::BlocklistDataKind::Email,
+        &email.peek().trim().to_lowercase(),
+    )
+    .await
+}
+
+async fn generate_ip_address_fingerprint(
+    state: &AppState,
+    merchant_id: &str,
+    ip_address: std::net::IpAddr,
+) -> RouterResult<String> {
+    generate_blocklist_fingerprint(
+        state,
+        merchant_id,
+        common_enums::BlocklistDataKind::IpAddress,
+        &ip_address.to_string(),
+    )
+    .await
+}
+
+/// Provides the IP address of the customer from the browser information of the payment attempt
+pub fn get_payment_ip_address(
+    browser_info: Option<&serde_json::Value>,
+) -> Option<std::net::IpAddr> {
+    browser_info
+        .and_then(|browser_info| browser_info.get("ip_address"))
+        .and_then(serde_json::Value::as_str)
+        .and_then(|ip_address| ip_address.parse().ok())
+}
+
 async fn duplicate_check_insert_bin(
     bin: &str,
     state: &AppState,
@@ -258,7 +404,18 @@ async fn duplicate_check_insert_bin(
     match blocklist_entry_result {
         Ok(_) => {
             return Err(errors::ApiErrorResponse::PreconditionFailed {
-                message: "provided bin is already blocked".to_string(),
+                message: match data_kind {
+                    common_enums::BlocklistDataKind::Email => "provided email is already blocked",
+                    common_enums::BlocklistDataKind::IpAddress => {
+                        "provided ip address is already blocked"
+                    }
+                    common_enums::BlocklistDataKind::PaymentMethod
+                    | common_enums::BlocklistDataKind::CardBin
+                    | common_enums::BlocklistDataKind::ExtendedCardBin => {
+                        "provided bin is already blocked"
+                    }
+                }
+                .to_string(),
             })
             .into_report();
         }
@@ -360,6 +517,42 @@ where
                 _ => None,
             });
 
+    // Merchant scoped card fingerprints, as stored on payment attempts and saved cards. During the
+    // transition period of a rotated key, the fingerprint of the previous key is checked as well.
+    let merchant_card_fingerprints = match payment_data.payment_method_data.as_ref() {
+        Some(api_models::payments::PaymentMethodData::Card(card)) => {
+            let key_store = db
+                .get_merchant_key_store_by_merchant_id(
+                    merchant_id,
+                    &db.get_master_key().to_vec().into(),
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+            Some(
+                card_fingerprint::generate_card_fingerprints(
+                    db.as_ref(),
+                    &key_store,
+                    &card.card_number,
+                )
+                .await?,
+            )
+        }
+        _ => None,
+    };
+
+    let email_fingerprint = match payment_data.email.as_ref() {
+        Some(email) => Some(generate_email_fingerprint(state, merchant_id, email).await?),
+        None => None,
+    };
+
+    let ip_address_fingerprint =
+        match get_payment_ip_address(payment_data.payment_attempt.browser_info.as_ref()) {
+            Some(ip_address) => {
+                Some(generate_ip_address_fingerprint(state, merchant_id, ip_address).await?)
+            }
+            None => None,
+        };
+
     //validating the payment method.
     let mut blocklist_futures = Vec::new();
     if let Some(card_number_fingerprint) = card_number_fingerprint.as_ref() {
@@ -369,6 +562,16 @@ where
         ));
     }
 
+    for merchant_card_fingerprint in merchant_card_fingerprints
+        .iter()
+        .flat_map(|fingerprints| fingerprints.iter())
+    {
+        blocklist_futures.push(db.find_blocklist_entry_by_merchant_id_fingerprint_id(
+            merchant_id,
+            merchant_card_fingerprint,
+        ));
+    }
+
     if let Some(card_bin_fingerprint) = card_bin_fingerprint.as_ref() {
         blocklist_futures.push(
             db.find_blocklist_entry_by_merchant_id_fingerprint_id(
@@ -385,20 +588,51 @@ where
         ));
     }
 
+    for fingerprint in [email_fingerprint.as_ref(), ip_address_fingerprint.as_ref()]
+        .into_iter()
+        .flatten()
+    {
+        blocklist_futures
+            .push(db.find_blocklist_entry_by_merchant_id_fingerprint_id(merchant_id, fingerprint));
+    }
+
     let blocklist_lookups = futures::future::join_all(blocklist_futures).await;
 
-    let mut should_payment_be_blocked = false;
+    let mut blocked_data_kinds = Vec::new();
     for lookup in blocklist_lookups {
         match lookup {
-            Ok(_) => {
-                should_payment_be_blocked = true;
+            Ok(blocklist_entry) => {
+                blocked_data_kinds.push(blocklist_entry.data_kind);
             }
+            Err(e) if e.current_context().is_db_not_found() => {}
             Err(e) => {
                 logger::error!(blocklist_db_error=?e, "failed db operations for blocklist");
             }
         }
     }
-    if should_payment_be_blocked {
+    if !blocked_data_kinds.is_empty() {
+        for data_kind in blocked_data_kinds.iter() {
+            metrics::BLOCKLIST_HIT_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[
+                    metrics::request::add_attributes("merchant_id", merchant_id.to_owned()),
+                    metrics::request::add_attributes("data_kind", data_kind.to_string()),
+                ],
+            );
+        }
+        // Blocklisted emails and IP addresses are not disclosed to the customer
+        let error_message = if blocked_data_kinds.iter().all(|data_kind| {
+            matches!(
+                data_kind,
+                common_enums::BlocklistDataKind::Email | common_enums::BlocklistDataKind::IpAddress
+            )
+        }) {
+            "This payment is blocked"
+        } else {
+            "This payment method is blocked"
+        };
+
         // Update db for attempt and intent status.
         db.update_payment_intent(
             payment_data.payment_intent.clone(),
@@ -418,8 +652,8 @@ where
         // If payment is blocked not showing connector details
         let attempt_update = storage::PaymentAttemptUpdate::BlocklistUpdate {
             status: common_enums::AttemptStatus::Failure,
-            error_code: Some(Some("HE-03".to_string())),
-            error_message: Some(Some("This payment method is blocked".to_string())),
+            error_code: Some(Some(BLOCKLIST_DECLINE_CODE.to_string())),
+            error_message: Some(Some(error_message.to_string())),
             updated_by: merchant_account.storage_scheme.to_string(),
         };
         db.update_payment_attempt_with_attempt_id(
@@ -435,7 +669,7 @@ where
 
         Err(errors::ApiErrorResponse::PaymentBlockedError {
             code: 200,
-            message: "This payment method is blocked".to_string(),
+            message: error_message.to_string(),
             status: "Failed".to_string(),
             reason: "Blocked".to_string(),
         }
@@ -0,0 +1,423 @@
+//! Merchant initiated bulk charges.
+//!
+//! A bulk charge file lists off-session charges against the mandates of customers. The rows of
+//! the file are stored in the tracking data of a process tracker task, which creates the payments
+//! of the rows with a bounded concurrency and records the result of every row as it goes, so that
+//! the progress of the bulk charge can be tracked and its result file downloaded.
+
+use std::str::FromStr;
+
+use api_models::{
+    bulk_charges::{
+        BulkChargeCreateRequest, BulkChargeId, BulkChargeResponse, BulkChargeRow,
+        BulkChargeRowResult, BulkChargeRowStatus, BulkChargeStatus,
+    },
+    enums as api_enums,
+};
+use common_utils::{ext_traits::ValueExt, fp_utils::when};
+use diesel_models::enums as storage_enums;
+use error_stack::{report, IntoReport, ResultExt};
+use masking::Secret;
+use router_env::{instrument, tracing};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult},
+    routes::{metrics, AppState},
+    services::ApplicationResponse,
+    types::{api, domain, storage},
+    utils,
+};
+
+const BULK_CHARGE_TASK_NAME: &str = "BULK_CHARGE";
+const BULK_CHARGE_TAG: &str = "BULK_CHARGE";
+const BULK_CHARGE_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::BulkChargeWorkflow;
+
+pub const BULK_CHARGE_COMPLETED: &str = "COMPLETED_BY_PT";
+
+const BULK_CHARGE_RESULT_COLUMNS: [&str; 10] = [
+    "row_number",
+    "reference",
+    "customer_id",
+    "mandate_id",
+    "amount",
+    "currency",
+    "payment_id",
+    "status",
+    "payment_status",
+    "error_message",
+];
+
+/// Tracking data of the bulk charge process tracker task. The results of the rows are appended
+/// in the order of the rows, the rows following the last result are yet to be charged.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BulkChargeTrackingData {
+    pub batch_id: String,
+    pub merchant_id: String,
+    pub description: Option<String>,
+    pub profile_id: Option<String>,
+    pub rows: Vec<BulkChargeRow>,
+    pub results: Vec<BulkChargeRowResult>,
+}
+
+impl BulkChargeTrackingData {
+    /// Payment ID of the row, derived from the bulk charge so that a re-run of the task cannot
+    /// charge the customer twice for the same row.
+    pub fn get_payment_id(&self, row_number: usize) -> String {
+        format!("{}_{row_number}", self.batch_id)
+    }
+
+    /// Rows whose payment is yet to be attempted, along with their row number
+    pub fn get_pending_rows(&self) -> Vec<(usize, BulkChargeRow)> {
+        self.rows
+            .iter()
+            .cloned()
+            .enumerate()
+            .skip(self.results.len())
+            .map(|(index, row)| (index.saturating_add(1), row))
+            .collect()
+    }
+
+    /// Constructs the off-session payment request of the row. The bulk charge and the reference
+    /// of the row are added to the payment metadata so that the merchant can associate the
+    /// payment webhooks with the row.
+    pub fn get_payment_request(
+        &self,
+        row_number: usize,
+        row: &BulkChargeRow,
+    ) -> api::PaymentsRequest {
+        api::PaymentsRequest {
+            payment_id: Some(api::PaymentIdType::PaymentIntentId(
+                self.get_payment_id(row_number),
+            )),
+            merchant_id: Some(self.merchant_id.clone()),
+            amount: Some(row.amount.into()),
+            currency: Some(row.currency),
+            customer_id: Some(row.customer_id.clone()),
+            mandate_id: Some(row.mandate_id.clone()),
+            off_session: Some(true),
+            confirm: Some(true),
+            description: self.description.clone(),
+            profile_id: self.profile_id.clone(),
+            metadata: Some(Secret::new(serde_json::json!({
+                "bulk_charge_id": self.batch_id,
+                "bulk_charge_reference": row.reference,
+            }))),
+            ..Default::default()
+        }
+    }
+
+    /// Records the outcome of the payment of the row
+    pub fn get_row_result(
+        &self,
+        row_number: usize,
+        row: BulkChargeRow,
+        payment_response: RouterResponse<api::PaymentsResponse>,
+    ) -> BulkChargeRowResult {
+        let (payment_status, error_message) = match payment_response {
+            Ok(ApplicationResponse::JsonWithHeaders((response, _)))
+            | Ok(ApplicationResponse::Json(response)) => {
+                (Some(response.status), response.error_message)
+            }
+            Ok(_) => (None, Some("Unexpected response of the payment".to_string())),
+            Err(error) => (None, Some(error.current_context().to_string())),
+        };
+        let status = match payment_status {
+            Some(
+                api_enums::IntentStatus::Succeeded
+                | api_enums::IntentStatus::Processing
+                | api_enums::IntentStatus::RequiresCapture,
+            ) => BulkChargeRowStatus::Succeeded,
+            _ => BulkChargeRowStatus::Failed,
+        };
+
+        BulkChargeRowResult {
+            row_number,
+            payment_id: self.get_payment_id(row_number),
+            reference: row.reference,
+            customer_id: row.customer_id,
+            mandate_id: row.mandate_id,
+            amount: row.amount,
+            currency: row.currency,
+            status,
+            payment_status,
+            error_message,
+        }
+    }
+}
+
+pub fn generate_task_id_for_bulk_charge_workflow(batch_id: &str) -> String {
+    format!("{BULK_CHARGE_TASK_NAME}_{batch_id}")
+}
+
+#[instrument(skip(state))]
+pub async fn create_bulk_charge(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: BulkChargeCreateRequest,
+) -> RouterResponse<BulkChargeResponse> {
+    let rows = match (req.csv_data, req.rows) {
+        (Some(csv_data), None) => parse_csv_rows(&csv_data)?,
+        (None, Some(rows)) => rows,
+        _ => Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Exactly one of `csv_data` or `rows` must be provided".to_string(),
+        }))?,
+    };
+
+    when(rows.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The bulk charge file has no rows".to_string(),
+        })
+        .into_report()
+    })?;
+
+    let max_rows = state.conf.bulk_charges.max_rows;
+    when(rows.len() > max_rows, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("The bulk charge file must not have more than {max_rows} rows"),
+        })
+        .into_report()
+    })?;
+
+    if let Some(row_number) = rows
+        .iter()
+        .position(|row| row.amount <= 0)
+        .map(|index| index.saturating_add(1))
+    {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("amount must be greater than 0 in row {row_number}"),
+        }))?
+    }
+
+    let tracking_data = BulkChargeTrackingData {
+        batch_id: utils::generate_id(consts::ID_LENGTH, "bulk"),
+        merchant_id: merchant_account.merchant_id.clone(),
+        description: req.description,
+        profile_id: req.profile_id,
+        rows,
+        results: Vec::new(),
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        generate_task_id_for_bulk_charge_workflow(&tracking_data.batch_id),
+        BULK_CHARGE_TASK_NAME,
+        BULK_CHARGE_RUNNER,
+        [BULK_CHARGE_TAG],
+        tracking_data.clone(),
+        common_utils::date_time::now(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct bulk charge process tracker task")?;
+
+    let process = state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting bulk charge task to process_tracker: batch_id: {}",
+                tracking_data.batch_id
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes("flow", "BulkCharge")],
+    );
+
+    Ok(ApplicationResponse::Json(get_bulk_charge_response(
+        &process,
+        &tracking_data,
+    )))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_bulk_charge(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: BulkChargeId,
+) -> RouterResponse<BulkChargeResponse> {
+    let (process, tracking_data) =
+        find_bulk_charge(&state, &merchant_account.merchant_id, &req.batch_id).await?;
+
+    Ok(ApplicationResponse::Json(get_bulk_charge_response(
+        &process,
+        &tracking_data,
+    )))
+}
+
+/// Returns the result file of the bulk charge in CSV format, with a row for every row of the
+/// bulk charge file for which a payment was attempted so far
+#[instrument(skip(state))]
+pub async fn retrieve_bulk_charge_results(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: BulkChargeId,
+) -> RouterResponse<serde_json::Value> {
+    let (_, tracking_data) =
+        find_bulk_charge(&state, &merchant_account.merchant_id, &req.batch_id).await?;
+
+    Ok(ApplicationResponse::FileData((
+        get_result_file(&tracking_data.results).into_bytes(),
+        mime::TEXT_CSV,
+    )))
+}
+
+async fn find_bulk_charge(
+    state: &AppState,
+    merchant_id: &str,
+    batch_id: &str,
+) -> RouterResult<(storage::ProcessTracker, BulkChargeTrackingData)> {
+    let not_found_error = || {
+        report!(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Bulk charge does not exist in our records".to_string(),
+        })
+    };
+
+    let process = state
+        .store
+        .find_process_by_id(&generate_task_id_for_bulk_charge_workflow(batch_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve bulk charge task from process tracker")?
+        .ok_or_else(not_found_error)?;
+
+    let tracking_data: BulkChargeTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("BulkChargeTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    // Bulk charges of other merchants are reported as not found
+    when(tracking_data.merchant_id != merchant_id, || {
+        Err(not_found_error())
+    })?;
+
+    Ok((process, tracking_data))
+}
+
+fn get_bulk_charge_response(
+    process: &storage::ProcessTracker,
+    tracking_data: &BulkChargeTrackingData,
+) -> BulkChargeResponse {
+    let status = match process.status {
+        storage_enums::ProcessTrackerStatus::Finish => BulkChargeStatus::Completed,
+        _ => BulkChargeStatus::Processing,
+    };
+    let succeeded_rows = tracking_data
+        .results
+        .iter()
+        .filter(|result| result.status == BulkChargeRowStatus::Succeeded)
+        .count();
+
+    BulkChargeResponse {
+        batch_id: tracking_data.batch_id.clone(),
+        merchant_id: tracking_data.merchant_id.clone(),
+        status,
+        total_rows: tracking_data.rows.len(),
+        processed_rows: tracking_data.results.len(),
+        succeeded_rows,
+        failed_rows: tracking_data.results.len().saturating_sub(succeeded_rows),
+        created_at: process.created_at,
+    }
+}
+
+fn get_result_file(results: &[BulkChargeRowResult]) -> String {
+    std::iter::once(BULK_CHARGE_RESULT_COLUMNS.join(","))
+        .chain(results.iter().map(|result| {
+            [
+                result.row_number.to_string(),
+                result.reference.clone(),
+                result.customer_id.clone(),
+                result.mandate_id.clone(),
+                result.amount.to_string(),
+                result.currency.to_string(),
+                result.payment_id.clone(),
+                result.status.to_string(),
+                result
+                    .payment_status
+                    .map(|payment_status| payment_status.to_string())
+                    .unwrap_or_default(),
+                result.error_message.clone().unwrap_or_default(),
+            ]
+            .iter()
+            .map(|field| utils::csv::escape_field(field))
+            .collect::<Vec<_>>()
+            .join(",")
+        }))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn parse_csv_rows(csv_data: &str) -> RouterResult<Vec<BulkChargeRow>> {
+    let mut lines = csv_data.lines().filter(|line| !line.trim().is_empty());
+    let header = lines.next().map(utils::csv::split_line).ok_or(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: "The bulk charge file has no header row".to_string(),
+        },
+    )?;
+
+    lines
+        .enumerate()
+        .map(|(index, line)| {
+            let row_number = index.saturating_add(1);
+            let fields = utils::csv::split_line(line);
+            let get_required_column = |column: &'static str| {
+                header
+                    .iter()
+                    .position(|name| name == column)
+                    .and_then(|position| fields.get(position))
+                    .map(String::as_str)
+                    .filter(|value| !value.is_empty())
+                    .ok_or(errors::ApiErrorResponse::InvalidRequestData {
+                        message: format!(
+                            "Missing value of `{column}` in row {row_number} of the bulk charge file"
+                        ),
+                    })
+            };
+            let invalid_column = |column: &str| errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Invalid value of `{column}` in row {row_number} of the bulk charge file"
+                ),
+            };
+
+            Ok(BulkChargeRow {
+                customer_id: get_required_column("customer_id")?.to_string(),
+                mandate_id: get_required_column("mandate_id")?.to_string(),
+                amount: get_required_column("amount")?
+                    .parse::<i64>()
+                    .map_err(|_| invalid_column("amount"))?,
+                currency: api_enums::Currency::from_str(get_required_column("currency")?)
+                    .map_err(|_| invalid_column("currency"))?,
+                reference: get_required_column("reference")?.to_string(),
+            })
+        })
+        .collect::<Result<Vec<_>, errors::ApiErrorResponse>>()
+        .into_report()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_rows() {
+        let rows = parse_csv_rows(
+            "reference,customer_id,mandate_id,amount,currency\n\
+             inv_1,cus_1,man_1,1000,USD\n\
+             \"inv_2, april\",cus_2,man_2,2500,EUR\n",
+        )
+        .unwrap();
+
+        let row = rows.get(1).unwrap();
+        assert_eq!(rows.len(), 2);
+        assert_eq!(row.reference, "inv_2, april");
+        assert_eq!(row.amount, 2500);
+        assert_eq!(row.currency, api_enums::Currency::EUR);
+        assert!(parse_csv_rows("reference,customer_id\ninv_1,cus_1\n").is_err());
+    }
+}
@@ -1,3 +1,5 @@
+pub mod bin_lookup;
+
 use common_utils::fp_utils::when;
 use error_stack::{report, ResultExt};
 use router_env::{instrument, tracing};
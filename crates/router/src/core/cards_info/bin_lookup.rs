@@ -0,0 +1,151 @@
+use api_models::enums as api_enums;
+use common_utils::ext_traits::ByteSliceExt;
+use error_stack::{IntoReport, ResultExt};
+use masking::{Mask, Secret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings,
+    core::errors::{self, RouterResult},
+    headers, logger,
+    routes::AppState,
+    services,
+};
+
+/// Issuer details of a card, looked up using the BIN (IIN) of the card
+#[derive(Debug, Clone, Default, serde::Deserialize)]
+pub struct CardBinInfo {
+    pub card_issuer: Option<String>,
+    pub card_network: Option<api_enums::CardNetwork>,
+    /// Type of the card, one of `credit`, `debit` or `prepaid`
+    pub card_type: Option<String>,
+    pub card_issuing_country: Option<String>,
+    pub bank_code: Option<String>,
+}
+
+/// Interface of the sources of card BIN information
+#[async_trait::async_trait]
+pub trait BinLookupProvider: Send + Sync {
+    /// Name of the provider, used for logging
+    fn name(&self) -> &'static str;
+
+    /// Returns the issuer details of the card BIN, `None` if the BIN is unknown to the provider
+    async fn get_card_bin_info(
+        &self,
+        state: &AppState,
+        card_iin: &str,
+    ) -> RouterResult<Option<CardBinInfo>>;
+}
+
+/// Looks up the BIN in the `cards_info` table
+struct LocalBinLookupProvider;
+
+#[async_trait::async_trait]
+impl BinLookupProvider for LocalBinLookupProvider {
+    fn name(&self) -> &'static str {
+        "local"
+    }
+
+    async fn get_card_bin_info(
+        &self,
+        state: &AppState,
+        card_iin: &str,
+    ) -> RouterResult<Option<CardBinInfo>> {
+        let card_info = state
+            .store
+            .get_card_info(card_iin)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to retrieve card information")?;
+
+        Ok(card_info.map(|card_info| CardBinInfo {
+            card_issuer: card_info.card_issuer,
+            card_network: card_info.card_network,
+            card_type: card_info.card_type,
+            card_issuing_country: card_info.card_issuing_country,
+            bank_code: card_info.bank_code,
+        }))
+    }
+}
+
+/// Looks up the BIN with the configured external BIN lookup service
+struct ExternalBinLookupProvider {
+    base_url: String,
+    api_key: Secret<String>,
+}
+
+#[async_trait::async_trait]
+impl BinLookupProvider for ExternalBinLookupProvider {
+    fn name(&self) -> &'static str {
+        "external"
+    }
+
+    async fn get_card_bin_info(
+        &self,
+        state: &AppState,
+        card_iin: &str,
+    ) -> RouterResult<Option<CardBinInfo>> {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Get)
+            .url(&format!("{}/bins/{card_iin}", self.base_url))
+            .attach_default_headers()
+            .headers(vec![(
+                headers::AUTHORIZATION.to_string(),
+                self.api_key.clone().into_masked(),
+            )])
+            .build();
+
+        let response = services::call_connector_api(state, request, "bin_lookup")
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to call the external BIN lookup service")?;
+
+        match response {
+            Ok(response) => response
+                .response
+                .parse_struct("CardBinInfo")
+                .map(Some)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to parse the external BIN lookup response"),
+            // The BIN is not known to the external service
+            Err(error_response) if error_response.status_code == 404 => Ok(None),
+            Err(error_response) => Err(errors::ApiErrorResponse::InternalServerError)
+                .into_report()
+                .attach_printable(format!(
+                    "External BIN lookup failed with status code {}",
+                    error_response.status_code
+                )),
+        }
+    }
+}
+
+/// Returns the configured BIN lookup providers in the order in which they are to be queried, the
+/// `cards_info` table is always queried before the external service
+pub fn get_bin_lookup_providers(config: &settings::BinLookup) -> Vec<Box<dyn BinLookupProvider>> {
+    let mut providers: Vec<Box<dyn BinLookupProvider>> = vec![Box::new(LocalBinLookupProvider)];
+    if config.external_provider_enabled {
+        providers.push(Box::new(ExternalBinLookupProvider {
+            base_url: config.external_provider_url.clone(),
+            api_key: config.external_provider_api_key.clone(),
+        }));
+    }
+    providers
+}
+
+/// Looks up the card BIN with each of the configured providers until one of them knows the BIN.
+/// Provider failures are only logged, as the payment can proceed without the card details.
+#[instrument(skip_all)]
+pub async fn get_card_bin_info(state: &AppState, card_iin: &str) -> Option<CardBinInfo> {
+    for provider in get_bin_lookup_providers(state.conf.bin_lookup.get_inner()) {
+        match provider.get_card_bin_info(state, card_iin).await {
+            Ok(Some(card_bin_info)) => return Some(card_bin_info),
+            Ok(None) => {}
+            Err(error) => logger::warn!(
+                ?error,
+                provider = provider.name(),
+                "Failed to look up the card BIN"
+            ),
+        }
+    }
+    None
+}
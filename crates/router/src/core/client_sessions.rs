@@ -0,0 +1,82 @@
+use api_models::client_sessions;
+use common_utils::{crypto, date_time};
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, tracing};
+use time::OffsetDateTime;
+
+use super::{
+    errors::{self, RouterResponse, StorageErrorExt},
+    payments::helpers,
+};
+use crate::{
+    routes::AppState,
+    services::ApplicationResponse,
+    types::{
+        domain,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+const CLIENT_SESSION_TOKEN_LENGTH: usize = 32;
+
+#[instrument(skip_all)]
+pub async fn create_client_session(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    request: client_sessions::ClientSessionCreateRequest,
+) -> RouterResponse<client_sessions::ClientSessionResponse> {
+    let db = state.store.as_ref();
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &request.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    // Clients can no longer act on payments which have been completed
+    helpers::validate_payment_status_against_not_allowed_statuses(
+        &payment_intent.status,
+        &[
+            storage_enums::IntentStatus::Succeeded,
+            storage_enums::IntentStatus::Failed,
+            storage_enums::IntentStatus::Cancelled,
+        ],
+        "create a client session for",
+    )?;
+
+    let client_session = db
+        .insert_client_session(
+            storage::ClientSessionNew {
+                token: format!(
+                    "cst_{}",
+                    crypto::generate_cryptographically_secure_random_string(
+                        CLIENT_SESSION_TOKEN_LENGTH
+                    )
+                ),
+                merchant_id: merchant_account.merchant_id,
+                payment_id: payment_intent.payment_id,
+                customer_id: payment_intent.customer_id,
+            },
+            state.conf.client_session.validity_in_seconds,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to create client session")?;
+
+    let expires_at = OffsetDateTime::from_unix_timestamp(client_session.expires)
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid expiry of the client session")?;
+
+    Ok(ApplicationResponse::Json(
+        client_sessions::ClientSessionResponse {
+            token: client_session.token.into(),
+            payment_id: client_session.payment_id,
+            customer_id: client_session.customer_id,
+            publishable_key: merchant_account.publishable_key,
+            expires_at: date_time::convert_to_pdt(expires_at),
+        },
+    ))
+}
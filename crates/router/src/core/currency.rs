@@ -1,13 +1,24 @@
+use std::collections::{BTreeMap, HashMap};
+
 use common_utils::errors::CustomResult;
-use error_stack::ResultExt;
+use currency_conversion::types::{CurrencyFactors, ExchangeRates};
+use error_stack::{IntoReport, ResultExt};
 
 use crate::{
-    core::errors::ApiErrorResponse,
+    core::errors::{self, ApiErrorResponse},
+    db::StorageInterface,
     services::ApplicationResponse,
+    types::storage,
     utils::currency::{self, convert_currency, get_forex_rates},
     AppState,
 };
 
+pub const FX_RATES_SYNC_TASK_ID: &str = "FX_RATES_SYNC_TASK";
+const FX_RATES_SYNC_TASK_NAME: &str = "FX_RATES_SYNC";
+const FX_RATES_SYNC_TAG: &str = "FX_RATES";
+const FX_RATES_SYNC_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::FxRatesSyncWorkflow;
+
 pub async fn retrieve_forex(
     state: AppState,
 ) -> CustomResult<ApplicationResponse<currency::FxExchangeRatesCacheEntry>, ApiErrorResponse> {
@@ -46,3 +57,111 @@ pub async fn convert_forex(
         .change_context(ApiErrorResponse::InternalServerError)?,
     ))
 }
+
+/// Stores the exchange rates for the given date, the rates stored earlier for the same date are
+/// overwritten so that the last sync of the day wins
+pub async fn store_fx_rates(
+    db: &dyn StorageInterface,
+    rate_date: time::Date,
+    exchange_rates: &ExchangeRates,
+) -> CustomResult<storage::FxRate, errors::StorageError> {
+    let rates = serde_json::to_value(&exchange_rates.conversion)
+        .into_report()
+        .change_context(errors::StorageError::SerializationFailed)
+        .attach_printable("Failed to serialize exchange rates")?;
+    let current_time = common_utils::date_time::now();
+
+    match db
+        .update_fx_rate_by_rate_date(
+            rate_date,
+            storage::FxRateUpdateInternal {
+                base_currency: exchange_rates.base_currency,
+                rates: rates.clone(),
+                modified_at: current_time,
+            },
+        )
+        .await
+    {
+        Err(error) if error.current_context().is_db_not_found() => {
+            db.insert_fx_rate(storage::FxRateNew {
+                rate_date,
+                base_currency: exchange_rates.base_currency,
+                rates,
+                created_at: current_time,
+                modified_at: current_time,
+            })
+            .await
+        }
+        result => result,
+    }
+}
+
+/// Fetches the daily exchange rates stored for the date range, the latest rates stored before the
+/// start of the range are included so that every date in the range has rates to convert with
+pub async fn get_fx_rates_for_date_range(
+    db: &dyn StorageInterface,
+    start_date: time::Date,
+    end_date: time::Date,
+) -> CustomResult<BTreeMap<time::Date, ExchangeRates>, errors::StorageError> {
+    let mut fx_rates = db
+        .find_fx_rates_by_rate_date_range(start_date, end_date)
+        .await?;
+
+    if fx_rates
+        .first()
+        .map_or(true, |fx_rate| fx_rate.rate_date > start_date)
+    {
+        match db.find_latest_fx_rate_on_or_before_date(start_date).await {
+            Ok(fx_rate) => fx_rates.insert(0, fx_rate),
+            Err(error) if error.current_context().is_db_not_found() => {}
+            Err(error) => return Err(error),
+        }
+    }
+
+    fx_rates
+        .into_iter()
+        .map(|fx_rate| {
+            let conversion: HashMap<api_models::enums::Currency, CurrencyFactors> =
+                serde_json::from_value(fx_rate.rates)
+                    .into_report()
+                    .change_context(errors::StorageError::DeserializationFailed)
+                    .attach_printable("Failed to deserialize stored exchange rates")?;
+            Ok((
+                fx_rate.rate_date,
+                ExchangeRates::new(fx_rate.base_currency, conversion),
+            ))
+        })
+        .collect()
+}
+
+/// Adds the task which syncs the exchange rates to the database every day, if it is not added
+/// already
+pub async fn add_fx_rates_sync_task(
+    db: &dyn StorageInterface,
+) -> CustomResult<(), errors::StorageError> {
+    if db
+        .find_process_by_id(FX_RATES_SYNC_TASK_ID)
+        .await?
+        .is_some()
+    {
+        return Ok(());
+    }
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        FX_RATES_SYNC_TASK_ID,
+        FX_RATES_SYNC_TASK_NAME,
+        FX_RATES_SYNC_RUNNER,
+        [FX_RATES_SYNC_TAG],
+        serde_json::json!({}),
+        common_utils::date_time::now(),
+    )
+    .map_err(errors::StorageError::from)
+    .into_report()
+    .attach_printable("Failed to construct exchange rates sync process tracker task")?;
+
+    match db.insert_process(process_tracker_entry).await {
+        // Another instance may have added the task in the meantime
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        result => result.map(|_| ()),
+    }
+}
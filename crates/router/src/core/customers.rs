@@ -10,6 +10,7 @@ use crate::{
     core::{
         errors::{self, StorageErrorExt},
         payment_methods::cards,
+        payments::helpers,
     },
     pii::PeekInterface,
     routes::{metrics, AppState},
@@ -25,6 +26,10 @@ use crate::{
     utils::CustomerAddress,
 };
 
+pub mod dedup;
+#[cfg(feature = "olap")]
+pub mod merge;
+
 pub const REDACTED: &str = "Redacted";
 
 #[instrument(skip(state))]
@@ -38,6 +43,7 @@ pub async fn create_customer(
     let customer_id = &customer_data.customer_id;
     let merchant_id = &merchant_account.merchant_id;
     customer_data.merchant_id = merchant_id.to_owned();
+    helpers::validate_customer_document(customer_data.document.as_ref()).switch()?;
 
     // We first need to validate whether the customer with the given customer id already exists
     // this may seem like a redundant db call, as the insert_customer will anyway return this error
@@ -109,6 +115,15 @@ pub async fn create_customer(
             created_at: common_utils::date_time::now(),
             modified_at: common_utils::date_time::now(),
             default_payment_method_id: None,
+            document_type: customer_data
+                .document
+                .as_ref()
+                .map(|document| document.document_type),
+            document_number: customer_data
+                .document
+                .map(|document| document.document_number)
+                .async_lift(|inner| types::encrypt_optional(inner, key))
+                .await?,
         })
     }
     .await
@@ -307,6 +322,8 @@ pub async fn delete_customer(
         metadata: None,
         connector_customer: None,
         address_id: None,
+        document_type: None,
+        document_number: Some(redacted_encrypted_value.clone()),
     };
     db.update_customer_by_customer_id_merchant_id(
         req.customer_id.clone(),
@@ -335,6 +352,7 @@ pub async fn update_customer(
     key_store: domain::MerchantKeyStore,
 ) -> errors::CustomerResponse<customers::CustomerResponse> {
     let db = state.store.as_ref();
+    helpers::validate_customer_document(update_customer.document.as_ref()).switch()?;
     //Add this in update call if customer can be updated anywhere else
     let customer = db
         .find_customer_by_customer_id_merchant_id(
@@ -426,6 +444,15 @@ pub async fn update_customer(
                     description: update_customer.description,
                     connector_customer: None,
                     address_id: address.clone().map(|addr| addr.address_id),
+                    document_type: update_customer
+                        .document
+                        .as_ref()
+                        .map(|document| document.document_type),
+                    document_number: update_customer
+                        .document
+                        .map(|document| document.document_number)
+                        .async_lift(|inner| types::encrypt_optional(inner, key))
+                        .await?,
                 })
             }
             .await
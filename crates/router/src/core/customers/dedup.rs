@@ -0,0 +1,245 @@
+//! Suggestions of customers to be merged.
+//!
+//! A background scan groups the customers of the merchant having the same email or phone number,
+//! compared by their hashes keyed with the merchant key, and stores the groups as the merge
+//! suggestions of the merchant until the next scan.
+
+use std::collections::HashMap;
+
+use api_models::customers as customer_types;
+use common_utils::{
+    crypto::{self, SignMessage},
+    date_time,
+    ext_traits::{Encode, StringExt},
+};
+use diesel_models::configs;
+use error_stack::{IntoReport, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, tracing};
+use scheduler::utils as pt_utils;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::AppState,
+    services::api as service_api,
+    types::{domain, storage},
+    utils,
+};
+
+const CUSTOMER_DEDUP_TASK_NAME: &str = "CUSTOMER_DEDUP";
+const CUSTOMER_DEDUP_TAG: &str = "CUSTOMER";
+const CUSTOMER_DEDUP_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::CustomerDedupWorkflow;
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CustomerDedupTrackingData {
+    pub merchant_id: String,
+}
+
+/// Provides the identifier of the config holding the customer merge suggestions of the merchant
+#[inline(always)]
+fn get_customer_merge_suggestions_key(merchant_id: &str) -> String {
+    format!("customer_merge_suggestions_{merchant_id}")
+}
+
+/// Schedules a scan of the customers of the merchant for duplicate customers
+#[instrument(skip(state))]
+pub async fn schedule_customer_dedup_scan(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<()> {
+    let merchant_id = &merchant_account.merchant_id;
+    let tracking_data = CustomerDedupTrackingData {
+        merchant_id: merchant_id.clone(),
+    };
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        CUSTOMER_DEDUP_RUNNER,
+        CUSTOMER_DEDUP_TASK_NAME,
+        &utils::generate_id(consts::ID_LENGTH, "scan"),
+        merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        CUSTOMER_DEDUP_TASK_NAME,
+        CUSTOMER_DEDUP_RUNNER,
+        [CUSTOMER_DEDUP_TAG],
+        tracking_data,
+        date_time::now(),
+    )
+    .map_err(errors::StorageError::from)
+    .into_report()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the customer dedup process tracker task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the customer dedup process tracker task")?;
+
+    Ok(service_api::ApplicationResponse::StatusOk)
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_customer_merge_suggestions(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<customer_types::CustomerMergeSuggestionsResponse> {
+    let config = state
+        .store
+        .find_config_by_key(&get_customer_merge_suggestions_key(
+            &merchant_account.merchant_id,
+        ))
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "No customer deduplication scan has completed yet".to_string(),
+        })?;
+
+    let suggestions = config
+        .config
+        .parse_struct("CustomerMergeSuggestionsResponse")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the customer merge suggestions")?;
+
+    Ok(service_api::ApplicationResponse::Json(suggestions))
+}
+
+/// Groups the customers of the merchant having the same email or phone number, and stores the
+/// groups as the merge suggestions of the merchant
+#[instrument(skip_all)]
+pub async fn compute_customer_merge_suggestions(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+) -> RouterResult<usize> {
+    let db = state.store.as_ref();
+    let merchant_id = &key_store.merchant_id;
+
+    let mut customers = db
+        .list_customers_by_merchant_id(merchant_id, key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the customers of the merchant")?;
+    customers.sort_by_key(|customer| customer.created_at);
+
+    let mut groups: HashMap<(customer_types::CustomerMatchField, String), Vec<String>> =
+        HashMap::new();
+    for customer in customers {
+        let email = customer
+            .email
+            .as_ref()
+            .map(|email| normalize_email(email.get_inner().peek()));
+        let phone = customer.phone.as_ref().map(|phone| {
+            normalize_phone(
+                customer.phone_country_code.as_deref(),
+                phone.get_inner().peek(),
+            )
+        });
+
+        for (matched_on, value) in [
+            (customer_types::CustomerMatchField::Email, email),
+            (customer_types::CustomerMatchField::Phone, phone),
+        ] {
+            let Some(value) = value.filter(|value| !value.is_empty()) else {
+                continue;
+            };
+            groups
+                .entry((matched_on, hash_customer_detail(key_store, &value)?))
+                .or_default()
+                .push(customer.customer_id.clone());
+        }
+    }
+
+    let mut suggestions = groups
+        .into_iter()
+        .filter(|(_, customer_ids)| customer_ids.len() > 1)
+        .map(
+            |((matched_on, _), customer_ids)| customer_types::CustomerMergeSuggestion {
+                matched_on,
+                customer_ids,
+            },
+        )
+        .collect::<Vec<_>>();
+    suggestions.sort_by(|a, b| a.customer_ids.cmp(&b.customer_ids));
+    let suggestions_count = suggestions.len();
+
+    let key = get_customer_merge_suggestions_key(merchant_id);
+    let config = customer_types::CustomerMergeSuggestionsResponse {
+        computed_at: date_time::now(),
+        suggestions,
+    }
+    .encode_to_string_of_json()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to serialize the customer merge suggestions")?;
+
+    match db.find_config_by_key(&key).await {
+        Ok(_) => db
+            .update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the customer merge suggestions")
+            .map(|_| ()),
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_config(configs::ConfigNew { key, config })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the customer merge suggestions")
+            .map(|_| ()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the customer merge suggestions"),
+    }?;
+
+    Ok(suggestions_count)
+}
+
+fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+fn normalize_phone(phone_country_code: Option<&str>, phone: &str) -> String {
+    let digits = phone
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect::<String>();
+    if digits.is_empty() {
+        return digits;
+    }
+    let country_code = phone_country_code
+        .unwrap_or_default()
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect::<String>();
+    format!("{country_code}{digits}")
+}
+
+/// Hashes the customer detail with the merchant key, so that details are compared without being
+/// kept around in plain text
+fn hash_customer_detail(key_store: &domain::MerchantKeyStore, value: &str) -> RouterResult<String> {
+    crypto::HmacSha256::sign_message(
+        &crypto::HmacSha256,
+        key_store.key.get_inner().peek(),
+        value.as_bytes(),
+    )
+    .map(hex::encode)
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to hash the customer detail")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_phone() {
+        assert_eq!(normalize_phone(Some("+1"), "(555) 010-0000"), "15550100000");
+        assert_eq!(normalize_phone(None, "555 010 0000"), "5550100000");
+        assert_eq!(normalize_phone(Some("+1"), "--"), "");
+    }
+}
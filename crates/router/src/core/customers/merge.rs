@@ -0,0 +1,612 @@
+//! Merging duplicate customers.
+//!
+//! The payment methods, mandates and payments of the duplicate customers are moved to the customer
+//! they are merged into, and the duplicate customers are deleted. Details of the customer take
+//! precedence over the details of the duplicate customers, which only fill in the missing ones.
+
+use std::collections::HashSet;
+
+use api_models::{customers as customer_types, enums as api_enums};
+use data_models::payments::payment_intent::{
+    PaymentIntentFetchConstraints, PaymentIntentListParams,
+};
+use diesel_models::encryption::Encryption;
+use error_stack::ResultExt;
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payment_methods::{cards, transformers as payment_methods},
+    },
+    db::StorageInterface,
+    routes::AppState,
+    services::api as service_api,
+    types::{
+        domain::{self, types as domain_types},
+        storage,
+    },
+};
+
+/// Maximum number of duplicate customers that can be merged at once
+const MAX_DUPLICATE_CUSTOMERS: usize = 10;
+
+#[derive(Debug, Default)]
+struct MergeCounts {
+    payment_methods_moved: usize,
+    payment_methods_deduplicated: usize,
+    mandates_moved: usize,
+    payments_moved: usize,
+}
+
+#[instrument(skip(state))]
+pub async fn merge_customers(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: customer_types::CustomerMergeRequest,
+) -> RouterResponse<customer_types::CustomerMergeResponse> {
+    validate_merge_request(&req)?;
+
+    let db = state.store.as_ref();
+    let merchant_id = &merchant_account.merchant_id;
+
+    let customer = db
+        .find_customer_by_customer_id_merchant_id(&req.customer_id, merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    let mut duplicate_customers = Vec::with_capacity(req.duplicate_customer_ids.len());
+    for duplicate_customer_id in req.duplicate_customer_ids.iter() {
+        let duplicate_customer = db
+            .find_customer_by_customer_id_merchant_id(
+                duplicate_customer_id,
+                merchant_id,
+                &key_store,
+            )
+            .await
+            .to_not_found_response(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Customer `{duplicate_customer_id}` does not exist"),
+            })?;
+        duplicate_customers.push(duplicate_customer);
+    }
+
+    let customer_payment_methods = db
+        .find_payment_method_by_customer_id_merchant_id_list(
+            &customer.customer_id,
+            merchant_id,
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payment methods of the customer")?;
+    let mut card_fingerprints = customer_payment_methods
+        .iter()
+        .filter_map(|payment_method| payment_method.card_fingerprint.clone())
+        .collect::<HashSet<_>>();
+
+    let mut counts = MergeCounts::default();
+    let mut default_payment_method_id = customer.default_payment_method_id.clone();
+
+    for duplicate_customer in duplicate_customers.iter() {
+        let moved_payment_method_ids = merge_payment_methods(
+            &state,
+            &key_store,
+            &customer.customer_id,
+            duplicate_customer,
+            &mut card_fingerprints,
+            &mut counts,
+        )
+        .await?;
+
+        if default_payment_method_id.is_none() {
+            default_payment_method_id = duplicate_customer
+                .default_payment_method_id
+                .clone()
+                .filter(|payment_method_id| moved_payment_method_ids.contains(payment_method_id));
+        }
+
+        counts.mandates_moved +=
+            merge_mandates(db, merchant_id, &customer.customer_id, duplicate_customer).await?;
+        counts.payments_moved += merge_payments(
+            db,
+            &merchant_account,
+            &customer.customer_id,
+            duplicate_customer,
+        )
+        .await?;
+    }
+
+    let customer_update = get_merged_customer_update(&customer, &duplicate_customers);
+    db.update_customer_by_customer_id_merchant_id(
+        customer.customer_id.clone(),
+        merchant_id.to_owned(),
+        customer_update,
+        &key_store,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update the merged customer")?;
+
+    if default_payment_method_id != customer.default_payment_method_id {
+        db.update_customer_by_customer_id_merchant_id(
+            customer.customer_id.clone(),
+            merchant_id.to_owned(),
+            domain::CustomerUpdate::UpdateDefaultPaymentMethod {
+                default_payment_method_id: Some(default_payment_method_id),
+            },
+            &key_store,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the default payment method of the merged customer")?;
+    }
+
+    for duplicate_customer in duplicate_customers.iter() {
+        db.delete_customer_by_customer_id_merchant_id(&duplicate_customer.customer_id, merchant_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to delete the merged duplicate customer")?;
+    }
+
+    logger::info!(
+        customer_id = %customer.customer_id,
+        merged_customer_ids = ?req.duplicate_customer_ids,
+        ?counts,
+        "Merged duplicate customers"
+    );
+
+    Ok(service_api::ApplicationResponse::Json(
+        customer_types::CustomerMergeResponse {
+            customer_id: customer.customer_id,
+            merged_customer_ids: req.duplicate_customer_ids,
+            payment_methods_moved: counts.payment_methods_moved,
+            payment_methods_deduplicated: counts.payment_methods_deduplicated,
+            mandates_moved: counts.mandates_moved,
+            payments_moved: counts.payments_moved,
+        },
+    ))
+}
+
+fn validate_merge_request(req: &customer_types::CustomerMergeRequest) -> RouterResult<()> {
+    if req.duplicate_customer_ids.is_empty() {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "duplicate_customer_ids cannot be empty".to_string(),
+        })?;
+    }
+
+    if req.duplicate_customer_ids.len() > MAX_DUPLICATE_CUSTOMERS {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "At most {MAX_DUPLICATE_CUSTOMERS} duplicate customers can be merged at once"
+            ),
+        })?;
+    }
+
+    let mut duplicate_customer_ids = HashSet::new();
+    for duplicate_customer_id in req.duplicate_customer_ids.iter() {
+        if *duplicate_customer_id == req.customer_id {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "A customer cannot be merged into itself".to_string(),
+            })?;
+        }
+
+        if !duplicate_customer_ids.insert(duplicate_customer_id.as_str()) {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Duplicate customer `{duplicate_customer_id}` is repeated"),
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Moves the payment methods of the duplicate customer, along with the data saved in the locker,
+/// to the customer. Cards the customer already has saved are deleted, unless they are used by
+/// mandates. Provides the identifiers of the moved payment methods.
+async fn merge_payment_methods(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    customer_id: &str,
+    duplicate_customer: &domain::Customer,
+    card_fingerprints: &mut HashSet<String>,
+    counts: &mut MergeCounts,
+) -> RouterResult<HashSet<String>> {
+    let db = state.store.as_ref();
+    let merchant_id = &duplicate_customer.merchant_id;
+
+    let payment_methods = db
+        .find_payment_method_by_customer_id_merchant_id_list(
+            &duplicate_customer.customer_id,
+            merchant_id,
+            None,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payment methods of the duplicate customer")?;
+
+    let mandate_payment_method_ids = db
+        .find_mandate_by_merchant_id_customer_id(merchant_id, &duplicate_customer.customer_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the mandates of the duplicate customer")?
+        .into_iter()
+        .map(|mandate| mandate.payment_method_id)
+        .collect::<HashSet<_>>();
+
+    let mut moved_payment_method_ids = HashSet::new();
+    for payment_method in payment_methods {
+        let is_used_for_mandates = mandate_payment_method_ids
+            .contains(&payment_method.payment_method_id)
+            || payment_method.connector_mandate_details.is_some();
+        let is_duplicate_card = payment_method
+            .card_fingerprint
+            .as_ref()
+            .is_some_and(|fingerprint| card_fingerprints.contains(fingerprint));
+
+        if is_duplicate_card && !is_used_for_mandates {
+            delete_duplicate_card(state, &payment_method).await?;
+            counts.payment_methods_deduplicated += 1;
+            continue;
+        }
+
+        let locker_id = match payment_method.payment_method {
+            api_enums::PaymentMethod::Card => Some(
+                move_locker_card(
+                    state,
+                    &payment_method.customer_id,
+                    customer_id,
+                    merchant_id,
+                    payment_method
+                        .locker_id
+                        .as_deref()
+                        .unwrap_or(&payment_method.payment_method_id),
+                )
+                .await?,
+            ),
+            _ => match payment_method.locker_id.as_deref() {
+                Some(locker_id) => Some(
+                    move_locker_payment_method(
+                        state,
+                        key_store,
+                        &payment_method.customer_id,
+                        customer_id,
+                        locker_id,
+                    )
+                    .await?,
+                ),
+                None => None,
+            },
+        };
+        let network_token_locker_id = match payment_method.network_token_locker_id.as_deref() {
+            Some(network_token_locker_id) => Some(
+                move_locker_card(
+                    state,
+                    &payment_method.customer_id,
+                    customer_id,
+                    merchant_id,
+                    network_token_locker_id,
+                )
+                .await?,
+            ),
+            None => None,
+        };
+
+        if let Some(fingerprint) = payment_method.card_fingerprint.clone() {
+            card_fingerprints.insert(fingerprint);
+        }
+        let payment_method_id = payment_method.payment_method_id.clone();
+        db.update_payment_method(
+            payment_method,
+            storage::PaymentMethodUpdate::CustomerUpdate {
+                customer_id: customer_id.to_owned(),
+                locker_id,
+                network_token_locker_id,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to move the payment method to the customer")?;
+
+        moved_payment_method_ids.insert(payment_method_id);
+        counts.payment_methods_moved += 1;
+    }
+
+    Ok(moved_payment_method_ids)
+}
+
+async fn delete_duplicate_card(
+    state: &AppState,
+    payment_method: &storage::PaymentMethod,
+) -> RouterResult<()> {
+    for card_reference in std::iter::once(
+        payment_method
+            .locker_id
+            .as_deref()
+            .unwrap_or(&payment_method.payment_method_id),
+    )
+    .chain(payment_method.network_token_locker_id.as_deref())
+    {
+        cards::delete_card_from_locker(
+            state,
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            card_reference,
+        )
+        .await?;
+    }
+
+    state
+        .store
+        .delete_payment_method_by_merchant_id_payment_method_id(
+            &payment_method.merchant_id,
+            &payment_method.payment_method_id,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to delete the duplicate card of the customer")?;
+    Ok(())
+}
+
+/// Saves the card in the locker for the customer, keeping its reference, and deletes the card of
+/// the duplicate customer. Provides the reference of the card saved for the customer.
+async fn move_locker_card(
+    state: &AppState,
+    duplicate_customer_id: &str,
+    customer_id: &str,
+    merchant_id: &str,
+    card_reference: &str,
+) -> RouterResult<String> {
+    let card =
+        cards::get_card_from_locker(state, duplicate_customer_id, merchant_id, card_reference)
+            .await
+            .attach_printable("Failed to fetch the card of the duplicate customer from locker")?;
+
+    let payload = payment_methods::StoreLockerReq::LockerCard(payment_methods::StoreCardReq {
+        merchant_id,
+        merchant_customer_id: customer_id.to_owned(),
+        requestor_card_reference: Some(card_reference.to_owned()),
+        card,
+    });
+    let stored_card = cards::call_to_locker_hs(
+        state,
+        &payload,
+        customer_id,
+        api_enums::LockerChoice::HyperswitchCardVault,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to save the card of the duplicate customer in locker")?;
+
+    cards::delete_card_from_locker(state, duplicate_customer_id, merchant_id, card_reference)
+        .await
+        .attach_printable("Failed to delete the card of the duplicate customer from locker")?;
+
+    Ok(stored_card.card_reference)
+}
+
+/// Saves the payment method data in the locker for the customer and deletes the data of the
+/// duplicate customer. Provides the reference of the data saved for the customer.
+async fn move_locker_payment_method(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    duplicate_customer_id: &str,
+    customer_id: &str,
+    locker_id: &str,
+) -> RouterResult<String> {
+    let merchant_id = &key_store.merchant_id;
+    let payment_method_data = cards::get_payment_method_from_hs_locker(
+        state,
+        key_store,
+        duplicate_customer_id,
+        merchant_id,
+        locker_id,
+        Some(api_enums::LockerChoice::HyperswitchCardVault),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the payment method of the duplicate customer from locker")?;
+
+    let encrypted_data =
+        domain_types::encrypt(payment_method_data, key_store.key.get_inner().peek())
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encrypt the payment method data")?;
+    let payload =
+        payment_methods::StoreLockerReq::LockerGeneric(payment_methods::StoreGenericReq {
+            merchant_id,
+            merchant_customer_id: customer_id.to_owned(),
+            enc_data: hex::encode(Encryption::from(encrypted_data).into_inner().peek()),
+        });
+    let stored_payment_method = cards::call_to_locker_hs(
+        state,
+        &payload,
+        customer_id,
+        api_enums::LockerChoice::HyperswitchCardVault,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to save the payment method of the duplicate customer in locker")?;
+
+    cards::delete_card_from_locker(state, duplicate_customer_id, merchant_id, locker_id)
+        .await
+        .attach_printable(
+            "Failed to delete the payment method of the duplicate customer from locker",
+        )?;
+
+    Ok(stored_payment_method.card_reference)
+}
+
+async fn merge_mandates(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    customer_id: &str,
+    duplicate_customer: &domain::Customer,
+) -> RouterResult<usize> {
+    let mandates = db
+        .find_mandate_by_merchant_id_customer_id(merchant_id, &duplicate_customer.customer_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the mandates of the duplicate customer")?;
+
+    for mandate in mandates.iter() {
+        db.update_mandate_by_merchant_id_mandate_id(
+            merchant_id,
+            &mandate.mandate_id,
+            storage::MandateUpdate::CustomerUpdate {
+                customer_id: customer_id.to_owned(),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to move the mandate to the customer")?;
+    }
+    Ok(mandates.len())
+}
+
+async fn merge_payments(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    customer_id: &str,
+    duplicate_customer: &domain::Customer,
+) -> RouterResult<usize> {
+    let payment_intents = db
+        .filter_payment_intent_by_constraints(
+            &merchant_account.merchant_id,
+            &PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+                offset: 0,
+                starting_at: None,
+                ending_at: None,
+                connector: None,
+                currency: None,
+                status: None,
+                payment_method: None,
+                payment_method_type: None,
+                authentication_type: None,
+                profile_id: None,
+                customer_id: Some(duplicate_customer.customer_id.clone()),
+                starting_after_id: None,
+                ending_before_id: None,
+                limit: None,
+            })),
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the payments of the duplicate customer")?;
+
+    let payments_moved = payment_intents.len();
+    for payment_intent in payment_intents {
+        db.update_payment_intent(
+            payment_intent,
+            storage::PaymentIntentUpdate::ReturnUrlUpdate {
+                return_url: None,
+                status: None,
+                customer_id: Some(customer_id.to_owned()),
+                shipping_address_id: None,
+                billing_address_id: None,
+                updated_by: merchant_account.storage_scheme.to_string(),
+            },
+            merchant_account.storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to move the payment to the customer")?;
+    }
+    Ok(payments_moved)
+}
+
+/// Fills in the details missing on the customer from the duplicate customers, in order. Keys of the
+/// metadata and connector customers of the customer take precedence over those of the duplicates.
+fn get_merged_customer_update(
+    customer: &domain::Customer,
+    duplicate_customers: &[domain::Customer],
+) -> domain::CustomerUpdate {
+    let mut name = customer.name.clone();
+    let mut email = customer.email.clone();
+    let mut phone = customer.phone.clone();
+    let mut phone_country_code = customer.phone_country_code.clone();
+    let mut description = customer.description.clone();
+    let mut metadata = customer.metadata.clone().map(|metadata| metadata.expose());
+    let mut connector_customer = customer.connector_customer.clone();
+    let mut document_type = customer.document_type;
+    let mut document_number = customer.document_number.clone();
+
+    for duplicate_customer in duplicate_customers {
+        name = name.or_else(|| duplicate_customer.name.clone());
+        email = email.or_else(|| duplicate_customer.email.clone());
+        if phone.is_none() {
+            phone = duplicate_customer.phone.clone();
+            phone_country_code =
+                phone_country_code.or_else(|| duplicate_customer.phone_country_code.clone());
+        }
+        if document_number.is_none() {
+            document_type = duplicate_customer.document_type;
+            document_number = duplicate_customer.document_number.clone();
+        }
+        description = description.or_else(|| duplicate_customer.description.clone());
+        metadata = merge_json_objects(
+            metadata,
+            duplicate_customer
+                .metadata
+                .clone()
+                .map(|metadata| metadata.expose()),
+        );
+        connector_customer = merge_json_objects(
+            connector_customer,
+            duplicate_customer.connector_customer.clone(),
+        );
+    }
+
+    domain::CustomerUpdate::Update {
+        name,
+        email,
+        phone: Box::new(phone),
+        description,
+        phone_country_code,
+        metadata: metadata.map(Secret::new),
+        connector_customer,
+        address_id: customer.address_id.clone(),
+        document_type,
+        document_number,
+    }
+}
+
+fn merge_json_objects(
+    value: Option<serde_json::Value>,
+    duplicate_value: Option<serde_json::Value>,
+) -> Option<serde_json::Value> {
+    match (value, duplicate_value) {
+        (
+            Some(serde_json::Value::Object(mut object)),
+            Some(serde_json::Value::Object(duplicate)),
+        ) => {
+            for (key, duplicate_value) in duplicate {
+                object.entry(key).or_insert(duplicate_value);
+            }
+            Some(serde_json::Value::Object(object))
+        }
+        (value, duplicate_value) => value.or(duplicate_value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_json_objects() {
+        let merged = merge_json_objects(
+            Some(serde_json::json!({ "tier": "gold", "source": "web" })),
+            Some(serde_json::json!({ "tier": "silver", "region": "eu" })),
+        );
+        assert_eq!(
+            merged,
+            Some(serde_json::json!({ "tier": "gold", "source": "web", "region": "eu" }))
+        );
+        assert_eq!(
+            merge_json_objects(None, Some(serde_json::json!({ "tier": "silver" }))),
+            Some(serde_json::json!({ "tier": "silver" }))
+        );
+    }
+}
@@ -1,7 +1,8 @@
 use api_models::{disputes as dispute_models, files as files_api_models};
 use common_utils::ext_traits::{Encode, ValueExt};
-use error_stack::{IntoReport, ResultExt};
+use error_stack::ResultExt;
 use router_env::{instrument, tracing};
+pub mod representment;
 pub mod transformers;
 
 use super::{
@@ -26,6 +27,7 @@ use crate::{
 pub async fn retrieve_dispute(
     state: AppState,
     merchant_account: domain::MerchantAccount,
+    profile_id: Option<String>,
     req: disputes::DisputeId,
 ) -> RouterResponse<api_models::disputes::DisputeResponse> {
     let dispute = state
@@ -35,6 +37,13 @@ pub async fn retrieve_dispute(
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: req.dispute_id,
         })?;
+    core_utils::validate_profile_id_of_resource(
+        profile_id.as_ref(),
+        dispute.profile_id.as_ref(),
+        errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: dispute.dispute_id.clone(),
+        },
+    )?;
     let dispute_response = api_models::disputes::DisputeResponse::foreign_from(dispute);
     Ok(services::ApplicationResponse::Json(dispute_response))
 }
@@ -43,8 +52,11 @@ pub async fn retrieve_dispute(
 pub async fn retrieve_disputes_list(
     state: AppState,
     merchant_account: domain::MerchantAccount,
-    constraints: api_models::disputes::DisputeListConstraints,
+    profile_id: Option<String>,
+    mut constraints: api_models::disputes::DisputeListConstraints,
 ) -> RouterResponse<Vec<api_models::disputes::DisputeResponse>> {
+    constraints.profile_id =
+        core_utils::validate_profile_id_from_auth_layer(profile_id, constraints.profile_id)?;
     let disputes = state
         .store
         .find_disputes_by_merchant_id(&merchant_account.merchant_id, constraints)
@@ -63,6 +75,7 @@ pub async fn accept_dispute(
     state: AppState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
+    profile_id: Option<String>,
     req: disputes::DisputeId,
 ) -> RouterResponse<dispute_models::DisputeResponse> {
     let db = &state.store;
@@ -73,6 +86,13 @@ pub async fn accept_dispute(
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: req.dispute_id,
         })?;
+    core_utils::validate_profile_id_of_resource(
+        profile_id.as_ref(),
+        dispute.profile_id.as_ref(),
+        errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: dispute.dispute_id.clone(),
+        },
+    )?;
     let dispute_id = dispute.dispute_id.clone();
     common_utils::fp_utils::when(
         !(dispute.dispute_stage == storage_enums::DisputeStage::Dispute
@@ -164,6 +184,7 @@ pub async fn submit_evidence(
     state: AppState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
+    profile_id: Option<String>,
     req: dispute_models::SubmitEvidenceRequest,
 ) -> RouterResponse<dispute_models::DisputeResponse> {
     let db = &state.store;
@@ -174,6 +195,13 @@ pub async fn submit_evidence(
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: req.dispute_id.clone(),
         })?;
+    core_utils::validate_profile_id_of_resource(
+        profile_id.as_ref(),
+        dispute.profile_id.as_ref(),
+        errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: dispute.dispute_id.clone(),
+        },
+    )?;
     let dispute_id = dispute.dispute_id.clone();
     common_utils::fp_utils::when(
         !(dispute.dispute_stage == storage_enums::DisputeStage::Dispute
@@ -327,6 +355,7 @@ pub async fn attach_evidence(
     state: AppState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
+    profile_id: Option<String>,
     attach_evidence_request: api::AttachEvidenceRequest,
 ) -> RouterResponse<files_api_models::CreateFileResponse> {
     let db = &state.store;
@@ -341,6 +370,13 @@ pub async fn attach_evidence(
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: dispute_id.clone(),
         })?;
+    core_utils::validate_profile_id_of_resource(
+        profile_id.as_ref(),
+        dispute.profile_id.as_ref(),
+        errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: dispute.dispute_id.clone(),
+        },
+    )?;
     common_utils::fp_utils::when(
         !(dispute.dispute_stage == storage_enums::DisputeStage::Dispute
             && dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened),
@@ -358,19 +394,13 @@ pub async fn attach_evidence(
             })
         },
     )?;
-    let create_file_response = files::files_create_core(
-        state.clone(),
-        merchant_account,
-        key_store,
+    let file_id = files::create_file(
+        &state,
+        &merchant_account,
+        &key_store,
         attach_evidence_request.create_file_request,
     )
     .await?;
-    let file_id = match &create_file_response {
-        services::ApplicationResponse::Json(res) => res.file_id.clone(),
-        _ => Err(errors::ApiErrorResponse::InternalServerError)
-            .into_report()
-            .attach_printable("Unexpected response received from files create core")?,
-    };
     let dispute_evidence: api::DisputeEvidence = dispute
         .evidence
         .clone()
@@ -380,7 +410,7 @@ pub async fn attach_evidence(
     let updated_dispute_evidence = transformers::update_dispute_evidence(
         dispute_evidence,
         attach_evidence_request.evidence_type,
-        file_id,
+        file_id.clone(),
     );
     let update_dispute = diesel_models::dispute::DisputeUpdate::EvidenceUpdate {
         evidence: updated_dispute_evidence
@@ -397,13 +427,16 @@ pub async fn attach_evidence(
         .attach_printable_lazy(|| {
             format!("Unable to update dispute with dispute_id: {dispute_id}")
         })?;
-    Ok(create_file_response)
+    Ok(services::ApplicationResponse::Json(
+        files_api_models::CreateFileResponse { file_id },
+    ))
 }
 
 #[instrument(skip(state))]
 pub async fn retrieve_dispute_evidence(
     state: AppState,
     merchant_account: domain::MerchantAccount,
+    profile_id: Option<String>,
     req: disputes::DisputeId,
 ) -> RouterResponse<Vec<api_models::disputes::DisputeEvidenceBlock>> {
     let dispute = state
@@ -413,6 +446,13 @@ pub async fn retrieve_dispute_evidence(
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: req.dispute_id,
         })?;
+    core_utils::validate_profile_id_of_resource(
+        profile_id.as_ref(),
+        dispute.profile_id.as_ref(),
+        errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: dispute.dispute_id.clone(),
+        },
+    )?;
     let dispute_evidence: api::DisputeEvidence = dispute
         .evidence
         .clone()
@@ -427,6 +467,7 @@ pub async fn retrieve_dispute_evidence(
 pub async fn delete_evidence(
     state: AppState,
     merchant_account: domain::MerchantAccount,
+    profile_id: Option<String>,
     delete_evidence_request: dispute_models::DeleteEvidenceRequest,
 ) -> RouterResponse<serde_json::Value> {
     let dispute_id = delete_evidence_request.dispute_id.clone();
@@ -437,6 +478,13 @@ pub async fn delete_evidence(
         .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
             dispute_id: dispute_id.clone(),
         })?;
+    core_utils::validate_profile_id_of_resource(
+        profile_id.as_ref(),
+        dispute.profile_id.as_ref(),
+        errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: dispute.dispute_id.clone(),
+        },
+    )?;
     let dispute_evidence: api::DisputeEvidence = dispute
         .evidence
         .clone()
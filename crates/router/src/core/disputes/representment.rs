@@ -0,0 +1,436 @@
+use std::str::FromStr;
+
+use api_models::{disputes as dispute_models, enums as api_enums};
+use common_utils::{
+    crypto::SignMessage,
+    ext_traits::{ByteSliceExt, Encode, StringExt},
+    request::RequestContent,
+};
+use diesel_models::configs;
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+use scheduler::utils as pt_utils;
+
+use super::submit_evidence;
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    headers,
+    routes::AppState,
+    services,
+    types::{
+        domain,
+        storage::{self, enums as storage_enums},
+        transformers::ForeignFrom,
+    },
+};
+
+const DISPUTE_REPRESENTMENT_TASK_NAME: &str = "DISPUTE_REPRESENTMENT";
+const DISPUTE_REPRESENTMENT_TAG: &str = "DISPUTE";
+const DISPUTE_REPRESENTMENT_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::DisputeRepresentmentWorkflow;
+
+/// Business statuses of the dispute representment task
+pub const REPRESENTMENT_AUTO_SUBMITTED: &str = "AUTO_SUBMITTED";
+pub const REPRESENTMENT_PENDING_REVIEW: &str = "PENDING_REVIEW";
+pub const REPRESENTMENT_DISPUTE_NOT_OPEN: &str = "DISPUTE_NOT_OPEN";
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DisputeRepresentmentTrackingData {
+    pub merchant_id: String,
+    pub dispute_id: String,
+}
+
+/// Provides the identifier of the config holding the merchant's dispute representment rules
+#[inline(always)]
+fn get_representment_rules_key(merchant_id: &str) -> String {
+    format!("dispute_representment_rules_{merchant_id}")
+}
+
+pub async fn get_representment_rules(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<Option<dispute_models::DisputeRepresentmentRules>> {
+    match db
+        .find_config_by_key(&get_representment_rules_key(merchant_id))
+        .await
+    {
+        Ok(config) => config
+            .config
+            .parse_struct("DisputeRepresentmentRules")
+            .map(Some)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the dispute representment rules"),
+        Err(error) if error.current_context().is_db_not_found() => Ok(None),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the dispute representment rules"),
+    }
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_representment_rules(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<dispute_models::DisputeRepresentmentRules> {
+    let rules = get_representment_rules(&*state.store, &merchant_account.merchant_id)
+        .await?
+        .unwrap_or_default();
+    Ok(services::ApplicationResponse::Json(rules))
+}
+
+#[instrument(skip(state))]
+pub async fn update_representment_rules(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    rules: dispute_models::DisputeRepresentmentRules,
+) -> RouterResponse<dispute_models::DisputeRepresentmentRules> {
+    validate_representment_rules(&rules)?;
+
+    let key = get_representment_rules_key(&merchant_account.merchant_id);
+    let config = rules
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the dispute representment rules")?;
+
+    match state.store.find_config_by_key(&key).await {
+        Ok(_) => state
+            .store
+            .update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the dispute representment rules")
+            .map(|_| ()),
+        Err(error) if error.current_context().is_db_not_found() => state
+            .store
+            .insert_config(configs::ConfigNew { key, config })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the dispute representment rules")
+            .map(|_| ()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the dispute representment rules"),
+    }?;
+
+    Ok(services::ApplicationResponse::Json(rules))
+}
+
+fn validate_representment_rules(
+    rules: &dispute_models::DisputeRepresentmentRules,
+) -> RouterResult<()> {
+    for rule in rules.rules.iter() {
+        if let Some(callback_url) = rule.evidence_callback_url.as_ref() {
+            url::Url::parse(callback_url).into_report().change_context(
+                errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "evidence_callback_url of the rule `{}` is not a valid URL",
+                        rule.name
+                    ),
+                },
+            )?;
+        }
+
+        let has_static_evidence = rule
+            .evidence
+            .as_ref()
+            .map_or(false, |evidence| !evidence.is_empty());
+        if rule.evidence_callback_url.is_none() && !has_static_evidence {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The rule `{}` must have either evidence or an evidence_callback_url",
+                    rule.name
+                ),
+            })?;
+        }
+    }
+    Ok(())
+}
+
+/// Lists the open disputes which could not be represented automatically and need to be reviewed
+/// by the merchant
+#[instrument(skip(state))]
+pub async fn retrieve_dispute_review_queue(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<Vec<dispute_models::DisputeResponse>> {
+    let constraints = dispute_models::DisputeListConstraints {
+        limit: None,
+        profile_id: None,
+        dispute_status: Some(storage_enums::DisputeStatus::DisputeOpened),
+        dispute_stage: None,
+        reason: None,
+        connector: None,
+        received_time: None,
+        received_time_lt: None,
+        received_time_gt: None,
+        received_time_lte: None,
+        received_time_gte: None,
+        representment_status: Some(storage_enums::DisputeRepresentmentStatus::PendingReview),
+    };
+    let disputes = state
+        .store
+        .find_disputes_by_merchant_id(&merchant_account.merchant_id, constraints)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Unable to retrieve the dispute review queue")?;
+    Ok(services::ApplicationResponse::Json(
+        disputes
+            .into_iter()
+            .map(dispute_models::DisputeResponse::foreign_from)
+            .collect(),
+    ))
+}
+
+/// Schedules the automatic representment of a newly opened dispute, if the merchant has
+/// representment rules configured
+pub async fn add_dispute_representment_task(
+    db: &dyn StorageInterface,
+    dispute: &storage::Dispute,
+) -> RouterResult<()> {
+    if !(dispute.dispute_stage == storage_enums::DisputeStage::Dispute
+        && dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened)
+    {
+        return Ok(());
+    }
+
+    let has_rules = get_representment_rules(db, &dispute.merchant_id)
+        .await?
+        .map_or(false, |rules| !rules.rules.is_empty());
+    if !has_rules {
+        return Ok(());
+    }
+
+    let tracking_data = DisputeRepresentmentTrackingData {
+        merchant_id: dispute.merchant_id.clone(),
+        dispute_id: dispute.dispute_id.clone(),
+    };
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        DISPUTE_REPRESENTMENT_RUNNER,
+        DISPUTE_REPRESENTMENT_TASK_NAME,
+        &dispute.dispute_id,
+        &dispute.merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        DISPUTE_REPRESENTMENT_TASK_NAME,
+        DISPUTE_REPRESENTMENT_RUNNER,
+        [DISPUTE_REPRESENTMENT_TAG],
+        tracking_data,
+        common_utils::date_time::now(),
+    )
+    .map_err(errors::StorageError::from)
+    .into_report()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the dispute representment process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the dispute representment process tracker task")?;
+    Ok(())
+}
+
+fn is_rule_applicable(
+    rule: &dispute_models::DisputeRepresentmentRule,
+    dispute: &storage::Dispute,
+) -> bool {
+    let amount_matches = rule
+        .max_amount
+        .map_or(true, |max_amount| dispute.dispute_amount <= max_amount);
+    let currency_matches = rule.currencies.as_ref().map_or(true, |currencies| {
+        api_enums::Currency::from_str(&dispute.currency)
+            .map_or(false, |currency| currencies.contains(&currency))
+    });
+    let connector_matches = rule
+        .connectors
+        .as_ref()
+        .map_or(true, |connectors| connectors.contains(&dispute.connector));
+    let reason_code_matches = rule.reason_codes.as_ref().map_or(true, |reason_codes| {
+        dispute
+            .connector_reason_code
+            .as_ref()
+            .map_or(false, |reason_code| reason_codes.contains(reason_code))
+    });
+
+    amount_matches && currency_matches && connector_matches && reason_code_matches
+}
+
+/// Fetches the evidence of the dispute from the evidence callback of the rule, the request is
+/// signed with the payment response hash key of the business profile like outgoing webhooks
+async fn get_callback_evidence(
+    state: &AppState,
+    callback_url: &str,
+    rule: &dispute_models::DisputeRepresentmentRule,
+    dispute: &storage::Dispute,
+) -> RouterResult<Option<dispute_models::DisputeRepresentmentEvidence>> {
+    let callback_request = dispute_models::DisputeRepresentmentCallbackRequest {
+        dispute_id: dispute.dispute_id.clone(),
+        payment_id: dispute.payment_id.clone(),
+        attempt_id: dispute.attempt_id.clone(),
+        amount: dispute.amount.clone(),
+        currency: dispute.currency.clone(),
+        connector: dispute.connector.clone(),
+        connector_reason: dispute.connector_reason.clone(),
+        connector_reason_code: dispute.connector_reason_code.clone(),
+        rule_name: rule.name.clone(),
+    };
+    let payload = callback_request
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the evidence callback request")?;
+
+    let mut request_headers = vec![(
+        reqwest::header::CONTENT_TYPE.to_string(),
+        mime::APPLICATION_JSON.essence_str().to_string().into(),
+    )];
+    let payment_response_hash_key = match dispute.profile_id.as_ref() {
+        Some(profile_id) => {
+            state
+                .store
+                .find_business_profile_by_profile_id(profile_id)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                    id: profile_id.to_owned(),
+                })?
+                .payment_response_hash_key
+        }
+        None => None,
+    };
+    if let Some(key) = payment_response_hash_key {
+        let signature = common_utils::crypto::HmacSha512
+            .sign_message(key.as_bytes(), payload.as_bytes())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to sign the evidence callback request")?;
+        request_headers.push((
+            headers::X_WEBHOOK_SIGNATURE.to_string(),
+            hex::encode(signature).into(),
+        ));
+    }
+
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(callback_url)
+        .attach_default_headers()
+        .headers(request_headers)
+        .set_body(RequestContent::RawBytes(payload.into_bytes()))
+        .build();
+
+    let response = services::call_connector_api(state, request, "dispute_evidence_callback")
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to call the dispute evidence callback")?;
+
+    match response {
+        Ok(response) => response
+            .response
+            .parse_struct::<dispute_models::DisputeRepresentmentEvidence>(
+                "DisputeRepresentmentEvidence",
+            )
+            .map(|evidence| (!evidence.is_empty()).then_some(evidence))
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to parse the dispute evidence callback response"),
+        // The merchant has no evidence for the dispute
+        Err(error_response) if error_response.status_code == 404 => Ok(None),
+        Err(error_response) => Err(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable(format!(
+                "Dispute evidence callback failed with status code {}",
+                error_response.status_code
+            )),
+    }
+}
+
+/// Assembles the evidence of the dispute using the first applicable representment rule, `None`
+/// if no rule could provide evidence for the dispute
+async fn assemble_evidence(
+    state: &AppState,
+    rules: &dispute_models::DisputeRepresentmentRules,
+    dispute: &storage::Dispute,
+) -> Option<(String, dispute_models::DisputeRepresentmentEvidence)> {
+    let rule = rules
+        .rules
+        .iter()
+        .find(|rule| is_rule_applicable(rule, dispute))?;
+    let static_evidence = rule.evidence.clone().unwrap_or_default();
+
+    let evidence = match rule.evidence_callback_url.as_ref() {
+        Some(callback_url) => {
+            match get_callback_evidence(state, callback_url, rule, dispute).await {
+                Ok(Some(callback_evidence)) => callback_evidence.or(static_evidence),
+                Ok(None) => return None,
+                Err(error) => {
+                    logger::error!(?error, rule = %rule.name, "Failed to fetch evidence from the callback");
+                    return None;
+                }
+            }
+        }
+        None => static_evidence,
+    };
+
+    (!evidence.is_empty()).then(|| (rule.name.clone(), evidence))
+}
+
+/// Submits the evidence assembled using the merchant's representment rules for the dispute, the
+/// dispute is added to the review queue when it cannot be represented automatically
+#[instrument(skip_all, fields(dispute_id = %dispute.dispute_id))]
+pub async fn represent_dispute(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    dispute: storage::Dispute,
+) -> RouterResult<storage_enums::DisputeRepresentmentStatus> {
+    let rules = get_representment_rules(&*state.store, &merchant_account.merchant_id)
+        .await?
+        .unwrap_or_default();
+
+    let representment_status = match assemble_evidence(state, &rules, &dispute).await {
+        Some((rule_name, evidence)) => {
+            let submit_evidence_request =
+                evidence.into_submit_evidence_request(dispute.dispute_id.clone());
+            match Box::pin(submit_evidence(
+                state.clone(),
+                merchant_account,
+                key_store,
+                None,
+                submit_evidence_request,
+            ))
+            .await
+            {
+                Ok(_) => {
+                    logger::info!(rule = %rule_name, "Submitted dispute evidence automatically");
+                    storage_enums::DisputeRepresentmentStatus::AutoSubmitted
+                }
+                Err(error) => {
+                    logger::error!(?error, rule = %rule_name, "Failed to submit dispute evidence");
+                    storage_enums::DisputeRepresentmentStatus::PendingReview
+                }
+            }
+        }
+        None => storage_enums::DisputeRepresentmentStatus::PendingReview,
+    };
+
+    let dispute_id = dispute.dispute_id.clone();
+    state
+        .store
+        .update_dispute(
+            dispute,
+            storage::DisputeUpdate::RepresentmentStatusUpdate {
+                representment_status,
+            },
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::DisputeNotFound {
+            dispute_id: dispute_id.clone(),
+        })
+        .attach_printable_lazy(|| {
+            format!("Unable to update dispute with dispute_id: {dispute_id}")
+        })?;
+
+    Ok(representment_status)
+}
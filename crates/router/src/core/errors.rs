@@ -252,6 +252,32 @@ pub enum AwsKmsError {
     Utf8DecodingFailed,
 }
 
+#[derive(Debug, thiserror::Error)]
+pub enum SftpError {
+    #[error("Failed to connect to the SFTP server")]
+    ConnectionFailed,
+    #[error("Host key of the SFTP server does not match the configured fingerprint")]
+    HostKeyMismatch,
+    #[error("Failed to authenticate with the SFTP server")]
+    AuthenticationFailed,
+    #[error("Failed to upload the file to the SFTP server")]
+    UploadFailed,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ScheduledReportError {
+    #[error("The report period has more than {max_rows} records")]
+    TooManyRecords { max_rows: usize },
+    #[error("Failed to generate the report")]
+    GenerationFailed,
+    #[error("Failed to store the report")]
+    StorageFailed,
+    #[error("Failed to deliver the report")]
+    DeliveryFailed,
+    #[error("Delivery of reports over email is not supported")]
+    EmailDeliveryNotSupported,
+}
+
 #[derive(Debug, thiserror::Error, serde::Serialize)]
 pub enum WebhooksFlowError {
     #[error("Merchant webhook config not found")]
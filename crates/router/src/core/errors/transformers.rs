@@ -287,6 +287,12 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for ApiErrorRespon
             Self::PaymentMethodDeleteFailed => {
                 AER::BadRequest(ApiError::new("IR", 25, "Cannot delete the default payment method", None))
             }
+            Self::RequestDeadlineExceeded => {
+                AER::ServiceUnavailable(ApiError::new("HE", 0, "The request could not be processed within its timeout", None))
+            }
+            Self::MaintenanceModeEnabled => {
+                AER::ServiceUnavailable(ApiError::new("HE", 6, "Payments cannot be created during the scheduled maintenance. Please try again later.", None))
+            }
         }
     }
 }
@@ -340,6 +346,9 @@ impl ErrorSwitch<api_models::errors::types::ApiErrorResponse> for CustomersError
                 "Customer with the given `customer_id` already exists",
                 None,
             )),
+            Self::InvalidRequestData { message } => {
+                AER::Unprocessable(ApiError::new("IR", 6, message.to_string(), None))
+            }
         }
     }
 }
@@ -368,6 +377,9 @@ impl ErrorSwitch<CustomersErrorResponse> for ApiErrorResponse {
             Self::InternalServerError => CER::InternalServerError,
             Self::MandateActive => CER::MandateActive,
             Self::CustomerNotFound => CER::CustomerNotFound,
+            Self::InvalidRequestData { message } => CER::InvalidRequestData {
+                message: message.to_string(),
+            },
             _ => CER::InternalServerError,
         }
     }
@@ -14,6 +14,8 @@ pub enum SampleDataError {
     InvalidParameters,
     #[error["Invalid Records"]]
     InvalidRange,
+    #[error("Sample Data Not Allowed In Production")]
+    NotAllowedInProduction,
 }
 
 impl ErrorSwitch<ApiErrorResponse> for SampleDataError {
@@ -43,6 +45,14 @@ impl ErrorSwitch<ApiErrorResponse> for SampleDataError {
                 "Records to be generated should be between range 10 and 100",
                 None,
             )),
+            Self::NotAllowedInProduction => {
+                ApiErrorResponse::ForbiddenCommonResource(ApiError::new(
+                    "SD",
+                    4,
+                    "Sample data can only be generated for sandbox merchants",
+                    None,
+                ))
+            }
         }
     }
 }
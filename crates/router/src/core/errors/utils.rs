@@ -241,7 +241,7 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
                     errors::ApiErrorResponse::MissingRequiredField { field_name }
                 }
                 errors::ConnectorError::MissingRequiredFields { field_names } => {
-                    errors::ApiErrorResponse::MissingRequiredFields { field_names: field_names.to_vec() }
+                    errors::ApiErrorResponse::MissingRequiredFields { field_names: field_names.iter().map(ToString::to_string).collect() }
                 }
                 errors::ConnectorError::NotImplemented(reason) => {
                     errors::ApiErrorResponse::NotImplemented {
@@ -425,7 +425,7 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
                 }
                 errors::ConnectorError::MissingRequiredFields { field_names } => {
                     errors::ApiErrorResponse::MissingRequiredFields {
-                        field_names: field_names.to_vec(),
+                        field_names: field_names.iter().map(ToString::to_string).collect(),
                     }
                 }
                 _ => errors::ApiErrorResponse::InternalServerError,
@@ -458,7 +458,7 @@ impl<T> ConnectorErrorExt<T> for error_stack::Result<T, errors::ConnectorError>
                 }
                 errors::ConnectorError::MissingRequiredFields { field_names } => {
                     errors::ApiErrorResponse::MissingRequiredFields {
-                        field_names: field_names.to_vec(),
+                        field_names: field_names.iter().map(ToString::to_string).collect(),
                     }
                 }
                 errors::ConnectorError::NotSupported { message, connector } => {
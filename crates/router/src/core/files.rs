@@ -3,7 +3,7 @@ pub mod helpers;
 use api_models::files;
 use error_stack::{IntoReport, ResultExt};
 
-use super::errors::{self, RouterResponse};
+use super::errors::{self, RouterResponse, RouterResult};
 use crate::{
     consts,
     routes::AppState,
@@ -17,12 +17,33 @@ pub async fn files_create_core(
     key_store: domain::MerchantKeyStore,
     create_file_request: api::CreateFileRequest,
 ) -> RouterResponse<files::CreateFileResponse> {
-    helpers::validate_file_upload(
-        &state,
-        merchant_account.clone(),
-        create_file_request.clone(),
-    )
-    .await?;
+    // Analytics reports are only created by the report generation, they cannot be uploaded
+    if matches!(
+        create_file_request.purpose,
+        api::FilePurpose::AnalyticsReport
+    ) {
+        return Err(errors::ApiErrorResponse::FileValidationFailed {
+            reason: "analytics_report files cannot be uploaded".to_string(),
+        })
+        .into_report();
+    }
+    let file_id = create_file(&state, &merchant_account, &key_store, create_file_request).await?;
+    Ok(services::api::ApplicationResponse::Json(
+        files::CreateFileResponse { file_id },
+    ))
+}
+
+/// Validates and stores a file scoped to the merchant, returning the generated `file_id`.
+///
+/// Shared by the files API, dispute evidence submission and analytics report exports.
+pub async fn create_file(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    create_file_request: api::CreateFileRequest,
+) -> RouterResult<String> {
+    helpers::validate_file_upload(state, merchant_account.clone(), create_file_request.clone())
+        .await?;
     let file_id = common_utils::generate_id(consts::ID_LENGTH, "file");
     let file_key = format!("{}/{}", merchant_account.merchant_id, file_id);
     let file_new = diesel_models::file::FileMetadataNew {
@@ -47,9 +68,9 @@ pub async fn files_create_core(
         .attach_printable("Unable to insert file_metadata")?;
     let (provider_file_id, file_upload_provider, profile_id, merchant_connector_id) =
         helpers::upload_and_get_provider_provider_file_id_profile_id(
-            &state,
-            &merchant_account,
-            &key_store,
+            state,
+            merchant_account,
+            key_store,
             &create_file_request,
             file_key.clone(),
         )
@@ -72,9 +93,7 @@ pub async fn files_create_core(
         .attach_printable_lazy(|| {
             format!("Unable to update file_metadata with file_id: {}", file_id)
         })?;
-    Ok(services::api::ApplicationResponse::Json(
-        files::CreateFileResponse { file_id },
-    ))
+    Ok(file_id)
 }
 
 pub async fn files_delete_core(
@@ -1,5 +1,5 @@
 use actix_multipart::Field;
-use common_utils::errors::CustomResult;
+use common_utils::{errors::CustomResult, fp_utils::when};
 use error_stack::{IntoReport, ResultExt};
 use futures::TryStreamExt;
 
@@ -30,11 +30,45 @@ pub async fn get_file_purpose(field: &mut Field) -> Option<api::FilePurpose> {
     }
 }
 
+/// Validates the file against the size and content type limits configured for the files module
+pub fn validate_file_constraints(
+    state: &AppState,
+    file_size: i32,
+    file_type: &mime::Mime,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let file_upload_config = &state.conf.file_upload_config;
+    when(file_size > file_upload_config.max_file_size, || {
+        Err(errors::ApiErrorResponse::FileValidationFailed {
+            reason: format!(
+                "file_size exceeded the max file size of {} bytes",
+                file_upload_config.max_file_size
+            ),
+        })
+        .into_report()
+    })?;
+    when(
+        !file_upload_config
+            .allowed_mime_types
+            .contains(file_type.essence_str()),
+        || {
+            Err(errors::ApiErrorResponse::FileValidationFailed {
+                reason: format!("file_type {} is not supported", file_type.essence_str()),
+            })
+            .into_report()
+        },
+    )
+}
+
 pub async fn validate_file_upload(
     state: &AppState,
     merchant_account: domain::MerchantAccount,
     create_file_request: api::CreateFileRequest,
 ) -> CustomResult<(), errors::ApiErrorResponse> {
+    validate_file_constraints(
+        state,
+        create_file_request.file_size,
+        &create_file_request.file_type,
+    )?;
     //File Validation based on the purpose of file upload
     match create_file_request.purpose {
         api::FilePurpose::DisputeEvidence => {
@@ -77,6 +111,8 @@ pub async fn validate_file_upload(
                 },
             }
         }
+        // Reports are generated by us and always stored with the router, no connector validation is needed
+        api::FilePurpose::AnalyticsReport => Ok(()),
     }
 }
 
@@ -342,5 +378,19 @@ pub async fn upload_and_get_provider_provider_file_id_profile_id(
                 ))
             }
         }
+        api::FilePurpose::AnalyticsReport => {
+            state
+                .file_storage_client
+                .upload_file(&file_key, create_file_request.file.clone())
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to upload analytics report to file storage")?;
+            Ok((
+                file_key,
+                api_models::enums::FileUploadProvider::Router,
+                None,
+                None,
+            ))
+        }
     }
 }
@@ -1,18 +1,19 @@
 use std::fmt::Debug;
 
 use api_models::{admin::FrmConfigs, enums as api_enums, payments::AdditionalPaymentData};
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 use masking::{ExposeInterface, PeekInterface};
 use router_env::{
     logger,
     tracing::{self, instrument},
 };
+use scheduler::utils as pt_utils;
 
 use self::{
     flows::{self as frm_flows, FeatureFrm},
     types::{
-        self as frm_core_types, ConnectorDetailsCore, FrmConfigsObject, FrmData, FrmInfo,
-        PaymentDetails, PaymentToFrmData,
+        self as frm_core_types, ConnectorDetailsCore, FrmConfigsObject, FrmData, FrmDecision,
+        FrmDecisionTrackingData, FrmInfo, PaymentDetails, PaymentToFrmData,
     },
 };
 use super::errors::{ConnectorErrorExt, RouterResponse};
@@ -33,6 +34,7 @@ use crate::{
         api::{routing::FrmRoutingAlgorithm, Connector, FraudCheckConnectorData, Fulfillment},
         domain, fraud_check as frm_types,
         storage::{
+            self,
             enums::{
                 AttemptStatus, FraudCheckLastStep, FraudCheckStatus, FraudCheckType, FrmSuggestion,
                 IntentStatus,
@@ -47,6 +49,11 @@ pub mod flows;
 pub mod operation;
 pub mod types;
 
+const FRM_DECISION_TASK_NAME: &str = "FRM_DECISION";
+const FRM_DECISION_TAG: &str = "FRM";
+const FRM_DECISION_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::FrmDecisionWorkflow;
+
 #[instrument(skip_all)]
 pub async fn call_frm_service<D: Clone, F, Req>(
     state: &AppState,
@@ -112,7 +119,7 @@ where
 pub async fn should_call_frm<F>(
     merchant_account: &domain::MerchantAccount,
     payment_data: &payments::PaymentData<F>,
-    db: &dyn StorageInterface,
+    state: &AppState,
     key_store: domain::MerchantKeyStore,
 ) -> RouterResult<(
     bool,
@@ -123,6 +130,7 @@ pub async fn should_call_frm<F>(
 where
     F: Send + Clone,
 {
+    let db = &*state.store;
     match merchant_account.frm_routing_algorithm.clone() {
         Some(frm_routing_algorithm_value) => {
             let frm_routing_algorithm_struct: FrmRoutingAlgorithm = frm_routing_algorithm_value
@@ -233,7 +241,7 @@ where
                             let additional_payment_data = match &payment_data.payment_method_data {
                                 Some(pmd) => {
                                     let additional_payment_data =
-                                        get_additional_payment_data(pmd, db).await;
+                                        get_additional_payment_data(pmd, state).await;
                                     Some(additional_payment_data)
                                 }
                                 None => payment_data
@@ -467,14 +475,21 @@ where
                 .await?;
             let frm_fraud_check = frm_data_updated.fraud_check.clone();
             payment_data.frm_message = Some(frm_fraud_check.clone());
-            if matches!(frm_fraud_check.frm_status, FraudCheckStatus::Fraud) {
-                if matches!(frm_configs.frm_action, api_enums::FrmAction::CancelTxn) {
+            match (frm_fraud_check.frm_status, &frm_configs.frm_action) {
+                (FraudCheckStatus::Fraud, api_enums::FrmAction::CancelTxn) => {
                     *should_continue_transaction = false;
                     frm_info.suggested_action = Some(FrmSuggestion::FrmCancelTransaction);
-                } else if matches!(frm_configs.frm_action, api_enums::FrmAction::ManualReview) {
+                }
+                // The FRM connector may itself ask for the payment to be reviewed by the merchant
+                (FraudCheckStatus::Fraud, api_enums::FrmAction::ManualReview)
+                | (
+                    FraudCheckStatus::ManualReview,
+                    api_enums::FrmAction::CancelTxn | api_enums::FrmAction::ManualReview,
+                ) => {
                     *should_continue_capture = false;
                     frm_info.suggested_action = Some(FrmSuggestion::FrmManualReview);
                 }
+                _ => (),
             }
             logger::debug!(
                 "frm_updated_data: {:?} {:?}",
@@ -483,6 +498,14 @@ where
             );
             Ok(Some(frm_data_updated))
         } else {
+            // In the post flow, the capture is held until the fraud check after the authorization
+            // decides whether the payment is captured, voided or reviewed by the merchant
+            if matches!(
+                frm_configs.frm_action,
+                api_enums::FrmAction::CancelTxn | api_enums::FrmAction::ManualReview
+            ) {
+                *should_continue_capture = false;
+            }
             Ok(Some(frm_data.to_owned()))
         }
     } else {
@@ -499,15 +522,21 @@ pub async fn post_payment_frm_core<'a, F>(
     frm_configs: FrmConfigsObject,
     customer: &Option<domain::Customer>,
     key_store: domain::MerchantKeyStore,
+    is_capture_held: bool,
 ) -> RouterResult<Option<FrmData>>
 where
     F: Send + Clone,
 {
     if let Some(frm_data) = &mut frm_info.frm_data {
-        // Allow the Post flow only if the payment is succeeded,
+        // Allow the Post flow only if the payment is succeeded, or authorized with its capture held
+        // for the decision of the fraud check,
         // this logic has to be removed if we are going to call /sale or /transaction after failed transaction
         let fraud_check_operation = &mut frm_info.fraud_check_operation;
-        if payment_data.payment_attempt.status == AttemptStatus::Charged {
+        let is_authorized_with_capture_held =
+            is_capture_held && payment_data.payment_attempt.status == AttemptStatus::Authorized;
+        if payment_data.payment_attempt.status == AttemptStatus::Charged
+            || is_authorized_with_capture_held
+        {
             let frm_router_data_opt = fraud_check_operation
                 .to_domain()?
                 .post_payment_frm(
@@ -538,6 +567,7 @@ where
                     payment_data.frm_message
                 );
                 let mut frm_suggestion = None;
+                let frm_action = frm_configs.frm_action.clone();
                 fraud_check_operation
                     .to_domain()?
                     .execute_post_tasks(
@@ -551,6 +581,16 @@ where
                         customer,
                     )
                     .await?;
+                if is_authorized_with_capture_held {
+                    frm_suggestion = execute_post_authorization_decision(
+                        &*state.store,
+                        merchant_account,
+                        payment_data,
+                        &frm_data.fraud_check,
+                        frm_action,
+                    )
+                    .await?;
+                }
                 logger::debug!("frm_post_tasks_data: {:?}", frm_data);
                 let updated_frm_data = fraud_check_operation
                     .to_update_tracker()?
@@ -590,7 +630,7 @@ where
 {
     if is_operation_allowed(operation) {
         let (is_frm_enabled, frm_routing_algorithm, frm_connector_label, frm_configs) =
-            should_call_frm(merchant_account, payment_data, db, key_store.clone()).await?;
+            should_call_frm(merchant_account, payment_data, state, key_store.clone()).await?;
         if let Some((frm_routing_algorithm_val, profile_id)) =
             frm_routing_algorithm.zip(frm_connector_label)
         {
@@ -630,6 +670,64 @@ where
     Ok(None)
 }
 
+/// Acts on the decision of the fraud check of a payment whose capture was held until after its
+/// authorization. Legit payments are captured, and fraudulent payments are voided if the FRM action
+/// is to cancel the transaction, by a process tracker task. Any other payment is held for a manual
+/// review by the merchant.
+async fn execute_post_authorization_decision<F>(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    payment_data: &payments::PaymentData<F>,
+    fraud_check: &FraudCheck,
+    frm_action: api_enums::FrmAction,
+) -> RouterResult<Option<FrmSuggestion>>
+where
+    F: Send + Clone,
+{
+    let decision = match (fraud_check.frm_status, frm_action) {
+        (FraudCheckStatus::Legit, _) => FrmDecision::Capture,
+        (FraudCheckStatus::Fraud, api_enums::FrmAction::CancelTxn) => FrmDecision::Void,
+        _ => return Ok(Some(FrmSuggestion::FrmManualReview)),
+    };
+
+    let payment_id = &payment_data.payment_intent.payment_id;
+    let tracking_data = FrmDecisionTrackingData {
+        merchant_id: merchant_account.merchant_id.clone(),
+        payment_id: payment_id.clone(),
+        decision,
+        reason: fraud_check
+            .frm_reason
+            .as_ref()
+            .map(|reason| reason.to_string()),
+    };
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        FRM_DECISION_RUNNER,
+        FRM_DECISION_TASK_NAME,
+        payment_id,
+        &merchant_account.merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        FRM_DECISION_TASK_NAME,
+        FRM_DECISION_RUNNER,
+        [FRM_DECISION_TAG],
+        tracking_data,
+        common_utils::date_time::now(),
+    )
+    .map_err(errors::StorageError::from)
+    .into_report()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the FRM decision process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the FRM decision process tracker task")?;
+    logger::info!(?decision, "Scheduled the FRM decision of the payment");
+
+    Ok(None)
+}
+
 pub fn is_operation_allowed<Op: Debug>(operation: &Op) -> bool {
     !["PaymentSession", "PaymentApprove", "PaymentReject"]
         .contains(&format!("{operation:?}").as_str())
@@ -647,6 +745,84 @@ impl From<PaymentToFrmData> for PaymentDetails {
     }
 }
 
+/// Lists the payments held for a manual review by the merchant after their fraud check, which are
+/// approved or rejected through the payments approve and reject APIs
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn list_frm_review_queue(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    profile_id: Option<String>,
+    constraints: api_models::payments::FrmReviewQueueConstraints,
+) -> RouterResponse<api_models::payments::FrmReviewQueueResponse> {
+    use common_utils::consts::PAYMENTS_LIST_MAX_LIMIT_V1;
+    use data_models::payments::payment_intent::{
+        PaymentIntentFetchConstraints, PaymentIntentListParams,
+    };
+
+    use crate::{core::errors::StorageErrorExt, types::transformers::ForeignFrom};
+
+    if !(1..=PAYMENTS_LIST_MAX_LIMIT_V1).contains(&constraints.limit) {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!("limit should be in between 1 and {PAYMENTS_LIST_MAX_LIMIT_V1}"),
+        })?;
+    }
+
+    let db = &*state.store;
+    let fetch_constraints =
+        PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+            offset: constraints.offset.unwrap_or_default(),
+            starting_at: None,
+            ending_at: None,
+            connector: None,
+            currency: None,
+            status: Some(vec![IntentStatus::RequiresMerchantAction]),
+            payment_method: None,
+            payment_method_type: None,
+            authentication_type: None,
+            profile_id,
+            customer_id: None,
+            starting_after_id: None,
+            ending_before_id: None,
+            limit: Some(constraints.limit),
+        }));
+    let payment_intents = db
+        .filter_payment_intent_by_constraints(
+            &merchant_account.merchant_id,
+            &fetch_constraints,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    let mut data = Vec::with_capacity(payment_intents.len());
+    for payment_intent in payment_intents {
+        let fraud_check = db
+            .find_fraud_check_by_payment_id_if_present(
+                payment_intent.payment_id.clone(),
+                merchant_account.merchant_id.clone(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the fraud check of the payment")?;
+
+        data.push(api_models::payments::FrmReviewQueueItem {
+            payment_id: payment_intent.payment_id,
+            amount: payment_intent.amount,
+            currency: payment_intent.currency,
+            created: payment_intent.created_at,
+            frm_message: fraud_check.map(api_models::payments::FrmMessage::foreign_from),
+        });
+    }
+
+    Ok(services::ApplicationResponse::Json(
+        api_models::payments::FrmReviewQueueResponse {
+            size: data.len(),
+            data,
+        },
+    ))
+}
+
 #[instrument(skip_all)]
 pub async fn frm_fulfillment_core(
     state: AppState,
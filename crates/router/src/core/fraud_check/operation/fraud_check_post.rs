@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use common_enums::FrmSuggestion;
+use common_enums::{FrmSuggestion, RefundReasonCode};
 use common_utils::ext_traits::Encode;
 use data_models::payments::{
     payment_attempt::PaymentAttemptUpdate, payment_intent::PaymentIntentUpdate,
@@ -203,14 +203,19 @@ impl<F: Send + Clone> Domain<F> for FraudCheckPost {
                     .frm_reason
                     .clone()
                     .map(|data| data.to_string()),
+                reason_code: Some(RefundReasonCode::Fraudulent),
                 refund_type: Some(RefundType::Instant),
                 metadata: None,
                 merchant_connector_details: None,
+                refund_destination: None,
+                #[cfg(feature = "payouts")]
+                alternate_destination: None,
             };
             let refund = Box::pin(refunds::refund_create_core(
                 state.clone(),
                 merchant_account.clone(),
                 key_store.clone(),
+                None,
                 ref_req,
             ))
             .await?;
@@ -441,6 +446,31 @@ impl<F: Clone + Send> UpdateTracker<FrmData, F> for FraudCheckPost {
                 )
                 .await
                 .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+        } else if frm_suggestion == Some(FrmSuggestion::FrmManualReview) {
+            payment_data.payment_attempt = db
+                .update_payment_attempt_with_attempt_id(
+                    payment_data.payment_attempt.clone(),
+                    PaymentAttemptUpdate::StatusUpdate {
+                        status: AttemptStatus::Unresolved,
+                        updated_by: frm_data.merchant_account.storage_scheme.to_string(),
+                    },
+                    frm_data.merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+            payment_data.payment_intent = db
+                .update_payment_intent(
+                    payment_data.payment_intent.clone(),
+                    PaymentIntentUpdate::PGStatusUpdate {
+                        status: IntentStatus::RequiresMerchantAction,
+                        incremental_authorization_allowed: None,
+                        updated_by: frm_data.merchant_account.storage_scheme.to_string(),
+                    },
+                    frm_data.merchant_account.storage_scheme,
+                )
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
         }
         frm_data.fraud_check = match frm_check_update {
             Some(fraud_check_update) => db
@@ -83,6 +83,22 @@ pub struct PaymentToFrmData {
     pub frm_metadata: Option<serde_json::Value>,
 }
 
+/// Action taken on a payment authorized with its capture held, as decided by its fraud check
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FrmDecision {
+    Capture,
+    Void,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FrmDecisionTrackingData {
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub decision: FrmDecision,
+    pub reason: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FrmConfigsObject {
     pub frm_enabled_pm: Option<PaymentMethod>,
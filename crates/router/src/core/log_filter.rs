@@ -0,0 +1,77 @@
+//! Overriding the filtering directives of the logs at runtime, so that a module can be made more
+//! verbose during an incident without restarting the application.
+
+use std::time::Duration;
+
+use api_models::log_filter as log_filter_api;
+use error_stack::{report, IntoReport, ResultExt};
+use router_env::{logger::LogFilterHandle, LogFilterError};
+
+use super::errors::{self, RouterResponse};
+use crate::services::ApplicationResponse;
+
+pub async fn retrieve_log_filter() -> RouterResponse<log_filter_api::LogFilterResponse> {
+    let handle = get_log_filter_handle()?;
+    Ok(ApplicationResponse::Json(get_log_filter_response(handle)))
+}
+
+pub async fn override_log_filter(
+    req: log_filter_api::LogFilterOverrideRequest,
+) -> RouterResponse<log_filter_api::LogFilterResponse> {
+    let handle = get_log_filter_handle()?;
+    handle
+        .apply_override(
+            &req.directives,
+            req.revert_after_in_secs.map(Duration::from_secs),
+        )
+        .map_err(|error| match error {
+            LogFilterError::InvalidDirectives(_) | LogFilterError::DurationTooLong { .. } => {
+                report!(errors::ApiErrorResponse::InvalidRequestData {
+                    message: error.to_string(),
+                })
+            }
+            LogFilterError::ReloadFailed(_) => report!(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to override the log filter"),
+        })?;
+
+    Ok(ApplicationResponse::Json(get_log_filter_response(handle)))
+}
+
+pub async fn revert_log_filter() -> RouterResponse<log_filter_api::LogFilterResponse> {
+    let handle = get_log_filter_handle()?;
+    handle
+        .revert_override()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to revert the log filter")?;
+
+    Ok(ApplicationResponse::Json(get_log_filter_response(handle)))
+}
+
+fn get_log_filter_handle() -> errors::RouterResult<&'static LogFilterHandle> {
+    router_env::log_filter_handle()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("The logging sub-system has not been set up")
+}
+
+fn get_log_filter_response(handle: &LogFilterHandle) -> log_filter_api::LogFilterResponse {
+    log_filter_api::LogFilterResponse {
+        default_directives: handle
+            .default_directives()
+            .into_iter()
+            .map(|(output, directives)| log_filter_api::LogOutputDirectives {
+                output: output.to_string(),
+                directives: directives.to_string(),
+            })
+            .collect(),
+        active_override: handle.active_override().map(|active_override| {
+            let reverts_at = active_override.reverts_at;
+            log_filter_api::LogFilterOverride {
+                directives: active_override.directives,
+                reverts_at: time::PrimitiveDateTime::new(reverts_at.date(), reverts_at.time()),
+            }
+        }),
+    }
+}
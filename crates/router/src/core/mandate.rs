@@ -1,4 +1,5 @@
 pub mod helpers;
+pub mod limits;
 pub mod utils;
 use api_models::payments;
 use common_utils::ext_traits::Encode;
@@ -323,7 +324,29 @@ where
     FData: MandateBehaviour,
 {
     match resp.response {
-        Err(_) => {}
+        Err(ref error_response) => {
+            if let Some(mandate_id) = resp
+                .request
+                .get_mandate_id()
+                .and_then(|mandate_ids| mandate_ids.mandate_id.as_ref())
+            {
+                helpers::sync_mandate_status_on_failure(
+                    state,
+                    &resp.merchant_id,
+                    mandate_id,
+                    &resp.connector,
+                    error_response,
+                )
+                .await
+                .map_err(|error| {
+                    logger::error!(
+                        ?error,
+                        "Failed to sync mandate status after payment failure"
+                    )
+                })
+                .ok();
+            }
+        }
         Ok(_) => match resp.request.get_mandate_id() {
             Some(mandate_id) => {
                 if let Some(ref mandate_id) = mandate_id.mandate_id {
@@ -352,12 +375,11 @@ where
                             .update_mandate_by_merchant_id_mandate_id(
                                 &resp.merchant_id,
                                 mandate_id,
-                                storage::MandateUpdate::CaptureAmountUpdate {
-                                    amount_captured: Some(
-                                        mandate.amount_captured.unwrap_or(0)
-                                            + resp.request.get_amount(),
-                                    ),
-                                },
+                                limits::get_capture_amount_update(
+                                    &mandate,
+                                    resp.request.get_amount(),
+                                    common_utils::date_time::now(),
+                                ),
                             )
                             .await
                             .change_context(errors::ApiErrorResponse::MandateUpdateFailed),
@@ -1,8 +1,15 @@
+use std::str::FromStr;
+
 use common_utils::errors::CustomResult;
 use diesel_models::Mandate;
 use error_stack::ResultExt;
+use router_env::logger;
 
-use crate::{core::errors, routes::AppState, types::domain};
+use crate::{
+    core::errors,
+    routes::AppState,
+    types::{self, domain, storage},
+};
 
 pub async fn get_profile_id_for_mandate(
     state: &AppState,
@@ -33,3 +40,47 @@ pub async fn get_profile_id_for_mandate(
     }?;
     Ok(profile_id)
 }
+
+/// Revokes the mandate locally when an off-session payment made using it fails with an error code
+/// that the connector uses to signal the mandate is no longer valid on its side.
+pub async fn sync_mandate_status_on_failure(
+    state: &AppState,
+    merchant_id: &str,
+    mandate_id: &str,
+    connector: &str,
+    error_response: &types::ErrorResponse,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    let is_revoked_at_connector = api_models::enums::Connector::from_str(connector)
+        .ok()
+        .and_then(|connector| {
+            state
+                .conf
+                .mandates
+                .revoked_mandate_error_codes
+                .0
+                .get(&connector)
+        })
+        .map_or(false, |codes| {
+            codes.error_codes.contains(&error_response.code)
+        });
+
+    if is_revoked_at_connector {
+        logger::info!(
+            mandate_id,
+            error_code = %error_response.code,
+            "Revoking mandate as it is no longer valid at the connector"
+        );
+        state
+            .store
+            .update_mandate_by_merchant_id_mandate_id(
+                merchant_id,
+                mandate_id,
+                storage::MandateUpdate::StatusUpdate {
+                    mandate_status: storage::enums::MandateStatus::Revoked,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::MandateUpdateFailed)?;
+    }
+    Ok(())
+}
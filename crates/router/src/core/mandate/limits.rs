@@ -0,0 +1,200 @@
+//! Limits of mandates enforced on merchant initiated transactions.
+//!
+//! Mandates may limit the amount debited by a single transaction, the amount debited within a
+//! calendar period and the dates within which they can be used. Transactions violating the limits
+//! of their mandate are rejected before being sent to the connector, and the merchant is notified
+//! of the violation by a `mandate_limit_exceeded` webhook.
+
+use diesel_models::Mandate;
+use error_stack::report;
+use router_env::{instrument, logger, tracing};
+use time::PrimitiveDateTime;
+
+use super::helpers as mandate_helpers;
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        webhooks as webhooks_core,
+    },
+    routes::AppState,
+    types::{
+        api::{self, mandates::MandateResponseExt},
+        domain, storage,
+        storage::enums as storage_enums,
+    },
+};
+
+/// Rejects the transaction when its amount or date violates the limits of the mandate, notifying
+/// the merchant of the violation
+#[instrument(skip_all)]
+pub async fn validate_mandate_limits(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    mandate: &Mandate,
+    request_amount: i64,
+) -> RouterResult<()> {
+    let Some(reason) =
+        get_mandate_limit_violation(mandate, request_amount, common_utils::date_time::now())
+    else {
+        return Ok(());
+    };
+
+    trigger_mandate_limit_exceeded_webhook(state, merchant_account, key_store, mandate)
+        .await
+        .map_err(|error| logger::error!(?error, "Failed to trigger mandate limit exceeded webhook"))
+        .ok();
+
+    Err(report!(errors::ApiErrorResponse::MandateValidationFailed {
+        reason
+    }))
+}
+
+/// Returns the update of the amounts captured by the mandate after a successful transaction,
+/// the amount captured within the period being reset when a new period starts
+pub fn get_capture_amount_update(
+    mandate: &Mandate,
+    amount: i64,
+    now: PrimitiveDateTime,
+) -> storage::MandateUpdate {
+    let (period_amount_captured, period_start) = match mandate.limit_period {
+        Some(limit_period) => (
+            Some(get_period_amount_captured(mandate, limit_period, now).saturating_add(amount)),
+            Some(get_period_start(limit_period, now)),
+        ),
+        None => (None, None),
+    };
+
+    storage::MandateUpdate::CaptureAmountUpdate {
+        amount_captured: Some(mandate.amount_captured.unwrap_or(0).saturating_add(amount)),
+        period_amount_captured,
+        period_start,
+    }
+}
+
+fn get_mandate_limit_violation(
+    mandate: &Mandate,
+    request_amount: i64,
+    now: PrimitiveDateTime,
+) -> Option<String> {
+    if mandate
+        .start_date
+        .is_some_and(|start_date| now < start_date)
+    {
+        return Some("mandate is not valid before its start date".into());
+    }
+    if mandate.end_date.is_some_and(|end_date| now > end_date) {
+        return Some("mandate is not valid after its end date".into());
+    }
+    if mandate
+        .max_amount_per_charge
+        .is_some_and(|max_amount| request_amount > max_amount)
+    {
+        return Some("request amount is greater than the maximum amount per charge".into());
+    }
+
+    mandate
+        .max_amount_per_period
+        .zip(mandate.limit_period)
+        .filter(|(max_amount, limit_period)| {
+            get_period_amount_captured(mandate, *limit_period, now).saturating_add(request_amount)
+                > *max_amount
+        })
+        .map(|(_, limit_period)| {
+            format!("request amount exceeds the maximum amount of the mandate per {limit_period}")
+        })
+}
+
+/// Amount captured by the mandate within the period containing `now`
+fn get_period_amount_captured(
+    mandate: &Mandate,
+    limit_period: storage_enums::MandateLimitPeriod,
+    now: PrimitiveDateTime,
+) -> i64 {
+    if mandate.period_start == Some(get_period_start(limit_period, now)) {
+        mandate.period_amount_captured.unwrap_or(0)
+    } else {
+        0
+    }
+}
+
+/// Start of the calendar period containing `now`, weeks starting on Monday
+fn get_period_start(
+    limit_period: storage_enums::MandateLimitPeriod,
+    now: PrimitiveDateTime,
+) -> PrimitiveDateTime {
+    let date = now.date();
+    let days_since_period_start = match limit_period {
+        storage_enums::MandateLimitPeriod::Day => 0,
+        storage_enums::MandateLimitPeriod::Week => date.weekday().number_days_from_monday().into(),
+        storage_enums::MandateLimitPeriod::Month => i64::from(date.day()).saturating_sub(1),
+        storage_enums::MandateLimitPeriod::Year => i64::from(date.ordinal()).saturating_sub(1),
+    };
+
+    date.saturating_sub(time::Duration::days(days_since_period_start))
+        .midnight()
+}
+
+async fn trigger_mandate_limit_exceeded_webhook(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    mandate: &Mandate,
+) -> RouterResult<()> {
+    let profile_id =
+        mandate_helpers::get_profile_id_for_mandate(state, merchant_account, mandate.clone())
+            .await?;
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id,
+        })?;
+    let mandate_response =
+        api::mandates::MandateResponse::from_db_mandate(state, key_store.clone(), mandate.clone())
+            .await?;
+
+    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        business_profile,
+        key_store,
+        storage_enums::EventType::MandateLimitExceeded,
+        storage_enums::EventClass::Mandates,
+        mandate.mandate_id.clone(),
+        storage_enums::EventObjectType::MandateDetails,
+        api::OutgoingWebhookContent::MandateDetails(Box::new(mandate_response)),
+        Some(mandate.created_at),
+    ))
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_get_period_start() {
+        let now = datetime!(2024-04-11 13:45);
+
+        assert_eq!(
+            get_period_start(storage_enums::MandateLimitPeriod::Day, now),
+            datetime!(2024-04-11 0:00)
+        );
+        assert_eq!(
+            get_period_start(storage_enums::MandateLimitPeriod::Week, now),
+            datetime!(2024-04-08 0:00)
+        );
+        assert_eq!(
+            get_period_start(storage_enums::MandateLimitPeriod::Month, now),
+            datetime!(2024-04-01 0:00)
+        );
+        assert_eq!(
+            get_period_start(storage_enums::MandateLimitPeriod::Year, now),
+            datetime!(2024-01-01 0:00)
+        );
+    }
+}
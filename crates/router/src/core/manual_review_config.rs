@@ -0,0 +1,122 @@
+use std::collections::HashSet;
+
+use api_models::manual_review_rules::ManualReviewRules;
+use common_utils::ext_traits::Encode;
+use diesel_models::configs;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::manual_review,
+    },
+    routes::AppState,
+    services::api as service_api,
+    types::domain,
+};
+
+#[instrument(skip(state))]
+pub async fn upsert_manual_review_config(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    rules: ManualReviewRules,
+) -> RouterResponse<ManualReviewRules> {
+    validate_manual_review_rules(&rules)?;
+
+    let key = manual_review::get_manual_review_rules_key(&merchant_account.merchant_id);
+    let config = rules
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the manual review rules")?;
+
+    match state.store.find_config_by_key(&key).await {
+        Ok(_) => state
+            .store
+            .update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the manual review rules")
+            .map(|_| ()),
+        Err(error) if error.current_context().is_db_not_found() => state
+            .store
+            .insert_config(configs::ConfigNew { key, config })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the manual review rules")
+            .map(|_| ()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the manual review rules"),
+    }?;
+
+    Ok(service_api::ApplicationResponse::Json(rules))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_manual_review_config(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<ManualReviewRules> {
+    let rules =
+        manual_review::get_manual_review_rules(&*state.store, &merchant_account.merchant_id)
+            .await?;
+    Ok(service_api::ApplicationResponse::Json(rules))
+}
+
+#[instrument(skip(state))]
+pub async fn delete_manual_review_config(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<()> {
+    let key = manual_review::get_manual_review_rules_key(&merchant_account.merchant_id);
+    state
+        .store
+        .delete_config_by_key(&key)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)
+        .attach_printable("Failed to delete the manual review rules")?;
+    Ok(service_api::ApplicationResponse::StatusOk)
+}
+
+fn validate_manual_review_rules(rules: &ManualReviewRules) -> RouterResult<()> {
+    let mut rule_names = HashSet::new();
+    for rule in rules.rules.iter() {
+        if rule.name.trim().is_empty() {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Name of a manual review rule cannot be empty".to_string(),
+            })?;
+        }
+
+        if !rule_names.insert(rule.name.as_str()) {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Duplicate manual review rule `{}`", rule.name),
+            })?;
+        }
+
+        if rule.min_amount.is_some_and(|amount| amount < 0)
+            || rule.max_amount.is_some_and(|amount| amount < 0)
+        {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Amounts of the rule `{}` cannot be negative", rule.name),
+            })?;
+        }
+
+        if let (Some(min_amount), Some(max_amount)) = (rule.min_amount, rule.max_amount) {
+            if min_amount > max_amount {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "min_amount of the rule `{}` cannot be greater than its max_amount",
+                        rule.name
+                    ),
+                })?;
+            }
+        }
+    }
+    Ok(())
+}
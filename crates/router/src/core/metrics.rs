@@ -1,5 +1,5 @@
 pub use router_env::opentelemetry::KeyValue;
-use router_env::{counter_metric, global_meter, metrics_context};
+use router_env::{counter_metric, global_meter, histogram_metric, metrics_context};
 
 metrics_context!(CONTEXT);
 global_meter!(GLOBAL_METER, "ROUTER_API");
@@ -36,15 +36,28 @@ counter_metric!(
 counter_metric!(WEBHOOK_INCOMING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_INCOMING_FILTERED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_SOURCE_VERIFIED_COUNT, GLOBAL_METER);
+counter_metric!(WEBHOOK_SOURCE_VERIFICATION_FAILURE_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_RECEIVED_COUNT, GLOBAL_METER);
 counter_metric!(WEBHOOK_OUTGOING_NOT_RECEIVED_COUNT, GLOBAL_METER);
+counter_metric!(WEBHOOK_OUTGOING_DELIVERY_QUEUE_FULL_COUNT, GLOBAL_METER); // No. of outgoing webhooks which could not be queued for delivery
+histogram_metric!(WEBHOOK_OUTGOING_DELIVERY_QUEUE_TIME, GLOBAL_METER); // Time outgoing webhooks waited in the delivery queue
+histogram_metric!(WEBHOOK_OUTGOING_DELIVERY_LATENCY, GLOBAL_METER); // Time taken by merchants to respond to outgoing webhooks
 counter_metric!(WEBHOOK_PAYMENT_NOT_FOUND, GLOBAL_METER);
 counter_metric!(
     WEBHOOK_EVENT_TYPE_IDENTIFICATION_FAILURE_COUNT,
     GLOBAL_METER
 );
 
+counter_metric!(ROUTING_VOLUME_SPLIT_CONNECTOR_SELECTED, GLOBAL_METER); // No. of payments routed to each connector by volume split
+counter_metric!(ROUTING_SUCCESS_RATE_CONNECTOR_SELECTED, GLOBAL_METER); // No. of payments routed to each connector by success rate
+counter_metric!(ROUTING_SUCCESS_RATE_EXPLORATION, GLOBAL_METER); // No. of payments which retained the static routing order for exploration
+counter_metric!(CONNECTOR_HEALTH_DEGRADED, GLOBAL_METER); // No. of times a connector was marked as degraded
+counter_metric!(CONNECTOR_HEALTH_RECOVERED, GLOBAL_METER); // No. of times a degraded connector was marked as recovered
+counter_metric!(CONNECTOR_HEALTH_EXCLUDED, GLOBAL_METER); // No. of times a degraded connector was excluded from routing
+counter_metric!(OPERATIONAL_ALERT_SENT_COUNT, GLOBAL_METER); // No. of operational alerts posted to notification channels
+counter_metric!(OPERATIONAL_ALERT_FAILURE_COUNT, GLOBAL_METER); // No. of operational alerts which could not be posted to notification channels
+counter_metric!(AUTHENTICATION_CONNECTOR_FALLBACK_COUNT, GLOBAL_METER); // No. of authentications retried with the fallback authentication connector
 counter_metric!(ROUTING_CREATE_REQUEST_RECEIVED, GLOBAL_METER);
 counter_metric!(ROUTING_CREATE_SUCCESS_RESPONSE, GLOBAL_METER);
 counter_metric!(ROUTING_MERCHANT_DICTIONARY_RETRIEVE, GLOBAL_METER);
@@ -77,3 +90,5 @@ counter_metric!(
     ROUTING_RETRIEVE_CONFIG_FOR_PROFILE_SUCCESS_RESPONSE,
     GLOBAL_METER
 );
+counter_metric!(ROUTING_SIMULATE, GLOBAL_METER);
+counter_metric!(ROUTING_SIMULATE_SUCCESS_RESPONSE, GLOBAL_METER);
@@ -1,5 +1,9 @@
+pub mod card_fingerprint;
 pub mod cards;
+pub mod expiry;
+pub mod network_tokenization;
 pub mod surcharge_decision_configs;
+pub mod token_requestor;
 pub mod transformers;
 pub mod vault;
 
@@ -0,0 +1,260 @@
+//! Merchant scoped card fingerprints.
+//!
+//! A card fingerprint is the HMAC-SHA256 of the card number, keyed with a versioned key derived
+//! from the merchant key, so that the merchant key itself is never used as an HMAC key. The same
+//! card results in the same fingerprint for a merchant, which allows deduplicating saved cards,
+//! blocking cards and velocity checks without exposing the card number, while the fingerprints of
+//! the same card differ across merchants.
+//!
+//! Rotating the key bumps its version. Fingerprints generated with the previous key continue to be
+//! matched until the end of the transition period, and saved cards matched by their previous
+//! fingerprint are updated with the fingerprint of the current key.
+
+use api_models::admin as admin_types;
+use common_utils::{
+    crypto::{self, SignMessage},
+    date_time,
+    ext_traits::{Encode, StringExt},
+};
+use diesel_models::configs;
+use error_stack::{IntoReport, ResultExt};
+use masking::PeekInterface;
+use router_env::{instrument, tracing};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::AppState,
+    services::api as service_api,
+    types::domain,
+    utils,
+};
+
+/// Versions of the keys the card fingerprints of a merchant are generated with
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct CardFingerprintKeyConfig {
+    key_version: u16,
+    previous_key_version: Option<u16>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    transition_ends_at: Option<PrimitiveDateTime>,
+}
+
+impl CardFingerprintKeyConfig {
+    /// Provides the version of the previous key, if it is still in its transition period
+    fn get_previous_key_version(&self, now: PrimitiveDateTime) -> Option<u16> {
+        self.previous_key_version.filter(|_| {
+            self.transition_ends_at
+                .is_some_and(|transition_ends_at| now < transition_ends_at)
+        })
+    }
+}
+
+/// Fingerprints of a card, generated with the current key, and with the previous key during its
+/// transition period
+#[derive(Debug, Clone)]
+pub struct CardFingerprints {
+    pub current: String,
+    pub previous: Option<String>,
+}
+
+impl CardFingerprints {
+    pub fn iter(&self) -> impl Iterator<Item = &String> {
+        std::iter::once(&self.current).chain(self.previous.iter())
+    }
+}
+
+/// Provides the identifier of the config holding the card fingerprint key versions of the merchant
+#[inline(always)]
+fn get_card_fingerprint_key_config_key(merchant_id: &str) -> String {
+    format!("card_fingerprint_key_{merchant_id}")
+}
+
+async fn get_card_fingerprint_key_config(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<CardFingerprintKeyConfig> {
+    let config = db
+        .find_config_by_key_unwrap_or(
+            &get_card_fingerprint_key_config_key(merchant_id),
+            Some(r#"{"key_version":0}"#.to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the card fingerprint key config")?;
+
+    config
+        .config
+        .parse_struct("CardFingerprintKeyConfig")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the card fingerprint key config")
+}
+
+/// Generates the fingerprints of the card number with the current and previous keys of the merchant
+#[instrument(skip_all)]
+pub async fn generate_card_fingerprints(
+    db: &dyn StorageInterface,
+    key_store: &domain::MerchantKeyStore,
+    card_number: &cards::CardNumber,
+) -> RouterResult<CardFingerprints> {
+    let config = get_card_fingerprint_key_config(db, &key_store.merchant_id).await?;
+
+    let current = generate_card_fingerprint(key_store, config.key_version, card_number)?;
+    let previous = config
+        .get_previous_key_version(date_time::now())
+        .map(|key_version| generate_card_fingerprint(key_store, key_version, card_number))
+        .transpose()?;
+
+    Ok(CardFingerprints { current, previous })
+}
+
+fn generate_card_fingerprint(
+    key_store: &domain::MerchantKeyStore,
+    key_version: u16,
+    card_number: &cards::CardNumber,
+) -> RouterResult<String> {
+    let key = get_card_fingerprint_key(key_store, key_version)?;
+    let fingerprint =
+        crypto::HmacSha256::sign_message(&crypto::HmacSha256, &key, card_number.peek().as_bytes())
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to generate card fingerprint")?;
+
+    Ok(hex::encode(fingerprint))
+}
+
+/// Derives the card fingerprint key of the version from the merchant key, the merchant key also
+/// encrypts the data of the merchant and is not used for fingerprinting directly
+fn get_card_fingerprint_key(
+    key_store: &domain::MerchantKeyStore,
+    key_version: u16,
+) -> RouterResult<Vec<u8>> {
+    crypto::HmacSha256::sign_message(
+        &crypto::HmacSha256,
+        key_store.key.get_inner().peek(),
+        format!("card_fingerprint_key_v{key_version}").as_bytes(),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to derive card fingerprint key")
+}
+
+#[instrument(skip(state))]
+pub async fn rotate_card_fingerprint_key(
+    state: AppState,
+    merchant_id: &str,
+    req: admin_types::CardFingerprintKeyRotateRequest,
+) -> RouterResponse<admin_types::CardFingerprintKeyResponse> {
+    let transition_period_in_days = req
+        .transition_period_in_days
+        .unwrap_or(consts::DEFAULT_CARD_FINGERPRINT_KEY_TRANSITION_PERIOD_IN_DAYS);
+    utils::when(
+        transition_period_in_days > consts::MAX_CARD_FINGERPRINT_KEY_TRANSITION_PERIOD_IN_DAYS,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "transition_period_in_days must not exceed {}",
+                    consts::MAX_CARD_FINGERPRINT_KEY_TRANSITION_PERIOD_IN_DAYS
+                ),
+            })
+            .into_report()
+        },
+    )?;
+
+    let db = state.store.as_ref();
+    db.get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let now = date_time::now();
+    let config = get_card_fingerprint_key_config(db, merchant_id).await?;
+
+    // Fingerprints of the previous key would stop being matched before the end of its transition
+    // period if the key is rotated again
+    if config.get_previous_key_version(now).is_some() {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: "The card fingerprint key cannot be rotated before the end of the transition period of the previous key".to_string(),
+        })?;
+    }
+
+    let rotated_config = CardFingerprintKeyConfig {
+        key_version: config
+            .key_version
+            .checked_add(1)
+            .ok_or(errors::ApiErrorResponse::InternalServerError)
+            .into_report()
+            .attach_printable("Card fingerprint key version overflowed")?,
+        previous_key_version: Some(config.key_version),
+        transition_ends_at: Some(
+            now.saturating_add(time::Duration::days(i64::from(transition_period_in_days))),
+        ),
+    };
+
+    db.update_config_by_key(
+        &get_card_fingerprint_key_config_key(merchant_id),
+        configs::ConfigUpdate::Update {
+            config: Some(
+                rotated_config
+                    .encode_to_string_of_json()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to serialize the card fingerprint key config")?,
+            ),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update the card fingerprint key config")?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        admin_types::CardFingerprintKeyResponse {
+            merchant_id: merchant_id.to_string(),
+            key_version: rotated_config.key_version,
+            previous_key_version: rotated_config.previous_key_version,
+            transition_ends_at: rotated_config.transition_ends_at,
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::services;
+
+    #[test]
+    fn test_previous_key_version_in_transition_period() {
+        let now = date_time::now();
+        let config = CardFingerprintKeyConfig {
+            key_version: 2,
+            previous_key_version: Some(1),
+            transition_ends_at: Some(now.saturating_add(time::Duration::days(1))),
+        };
+
+        assert_eq!(config.get_previous_key_version(now), Some(1));
+        assert_eq!(
+            config.get_previous_key_version(now.saturating_add(time::Duration::days(2))),
+            None
+        );
+        assert_eq!(
+            CardFingerprintKeyConfig::default().get_previous_key_version(now),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn test_card_fingerprint_key_is_derived_from_the_merchant_key() {
+        let merchant_key = services::generate_aes256_key().unwrap();
+        let key_store = domain::MerchantKeyStore {
+            merchant_id: "merchant1".into(),
+            key: domain::types::encrypt(merchant_key.to_vec().into(), &merchant_key)
+                .await
+                .unwrap(),
+            created_at: date_time::now(),
+        };
+
+        let key_v0 = get_card_fingerprint_key(&key_store, 0).unwrap();
+        assert_ne!(key_v0, merchant_key.to_vec());
+        assert_ne!(key_v0, get_card_fingerprint_key(&key_store, 1).unwrap());
+    }
+}
@@ -42,6 +42,7 @@ use crate::{
     core::{
         errors::{self, StorageErrorExt},
         payment_methods::{
+            card_fingerprint, network_tokenization,
             transformers::{self as payment_methods},
             vault,
         },
@@ -74,7 +75,7 @@ use crate::{
 #[instrument(skip_all)]
 #[allow(clippy::too_many_arguments)]
 pub async fn create_payment_method(
-    db: &dyn db::StorageInterface,
+    state: &routes::AppState,
     req: &api::PaymentMethodCreate,
     customer_id: &str,
     payment_method_id: &str,
@@ -86,30 +87,84 @@ pub async fn create_payment_method(
     key_store: &domain::MerchantKeyStore,
     connector_mandate_details: Option<serde_json::Value>,
 ) -> errors::CustomResult<storage::PaymentMethod, errors::ApiErrorResponse> {
+    let db = &*state.store;
     let customer = db
         .find_customer_by_customer_id_merchant_id(customer_id, merchant_id, key_store)
         .await
         .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
 
-    let response = db
-        .insert_payment_method(storage::PaymentMethodNew {
-            customer_id: customer_id.to_string(),
-            merchant_id: merchant_id.to_string(),
-            payment_method_id: payment_method_id.to_string(),
-            locker_id,
-            payment_method: req.payment_method,
-            payment_method_type: req.payment_method_type,
-            payment_method_issuer: req.payment_method_issuer.clone(),
-            scheme: req.card_network.clone(),
-            metadata: pm_metadata.map(masking::Secret::new),
-            payment_method_data,
-            connector_mandate_details,
-            customer_acceptance: customer_acceptance.map(masking::Secret::new),
-            ..storage::PaymentMethodNew::default()
+    let card_fingerprints = req
+        .card
+        .as_ref()
+        .async_map(|card| {
+            card_fingerprint::generate_card_fingerprints(db, key_store, &card.card_number)
         })
         .await
-        .change_context(errors::ApiErrorResponse::InternalServerError)
-        .attach_printable("Failed to add payment method in db")?;
+        .transpose()?;
+
+    let mut existing_pm = None;
+    for fingerprint in card_fingerprints
+        .iter()
+        .flat_map(|fingerprints| fingerprints.iter())
+    {
+        match db
+            .find_payment_method_by_customer_id_merchant_id_card_fingerprint(
+                customer_id,
+                merchant_id,
+                fingerprint,
+            )
+            .await
+        {
+            Ok(payment_method) => {
+                existing_pm = Some(payment_method);
+                break;
+            }
+            Err(err) if err.current_context().is_db_not_found() => {}
+            Err(err) => Err(err)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to find payment method by card fingerprint")?,
+        }
+    }
+
+    let response = match existing_pm {
+        Some(existing_pm) => {
+            logger::info!(
+                payment_method_id = %existing_pm.payment_method_id,
+                "Card is already saved for the customer, updating the existing payment method"
+            );
+            update_saved_card(
+                state,
+                existing_pm,
+                customer_id,
+                merchant_id,
+                locker_id,
+                payment_method_data,
+                connector_mandate_details,
+                card_fingerprints,
+            )
+            .await?
+        }
+        None => db
+            .insert_payment_method(storage::PaymentMethodNew {
+                customer_id: customer_id.to_string(),
+                merchant_id: merchant_id.to_string(),
+                payment_method_id: payment_method_id.to_string(),
+                locker_id,
+                payment_method: req.payment_method,
+                payment_method_type: req.payment_method_type,
+                payment_method_issuer: req.payment_method_issuer.clone(),
+                scheme: req.card_network.clone(),
+                metadata: pm_metadata.map(masking::Secret::new),
+                payment_method_data,
+                connector_mandate_details,
+                customer_acceptance: customer_acceptance.map(masking::Secret::new),
+                card_fingerprint: card_fingerprints.map(|fingerprints| fingerprints.current),
+                ..storage::PaymentMethodNew::default()
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to add payment method in db")?,
+    };
 
     if customer.default_payment_method_id.is_none() {
         let _ = set_default_payment_method(
@@ -117,7 +172,7 @@ pub async fn create_payment_method(
             merchant_id.to_string(),
             key_store.clone(),
             customer_id,
-            payment_method_id.to_owned(),
+            response.payment_method_id.clone(),
         )
         .await
         .map_err(|err| logger::error!(error=?err,"Failed to set the payment method as default"));
@@ -125,6 +180,92 @@ pub async fn create_payment_method(
     Ok(response)
 }
 
+/// Updates a saved card matched by its fingerprint with the details of the card being saved again,
+/// the card details stored in the locker for the new payment method are deleted as the existing
+/// payment method keeps referring to its own locker entry
+#[allow(clippy::too_many_arguments)]
+async fn update_saved_card(
+    state: &routes::AppState,
+    existing_pm: storage::PaymentMethod,
+    customer_id: &str,
+    merchant_id: &str,
+    locker_id: Option<String>,
+    payment_method_data: Option<Encryption>,
+    connector_mandate_details: Option<serde_json::Value>,
+    card_fingerprints: Option<card_fingerprint::CardFingerprints>,
+) -> errors::RouterResult<storage::PaymentMethod> {
+    let existing_locker_id = existing_pm
+        .locker_id
+        .clone()
+        .unwrap_or_else(|| existing_pm.payment_method_id.clone());
+    let connector_mandate_details = merge_connector_mandate_details(
+        existing_pm.connector_mandate_details.clone(),
+        connector_mandate_details,
+    )?;
+
+    let updated_pm = state
+        .store
+        .update_payment_method(
+            existing_pm,
+            storage::PaymentMethodUpdate::SavedCardUpdate {
+                // The card data carries the expiry of the card, which may have been renewed
+                payment_method_data,
+                connector_mandate_details,
+                // The fingerprint of the previous key is replaced so that the card continues to be
+                // matched after the transition period of the previous key
+                card_fingerprint: card_fingerprints.map(|fingerprints| fingerprints.current),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update the saved card")?;
+
+    if let Some(duplicate_locker_id) =
+        locker_id.filter(|duplicate_locker_id| *duplicate_locker_id != existing_locker_id)
+    {
+        delete_card_from_locker(state, customer_id, merchant_id, &duplicate_locker_id)
+            .await
+            .map_err(|error| {
+                logger::error!(
+                    ?error,
+                    "Failed to delete the duplicate card details from the locker"
+                )
+            })
+            .ok();
+    }
+
+    Ok(updated_pm)
+}
+
+/// Adds the connector mandates of the card being saved again to the connector mandates of the saved
+/// card, replacing the mandates of the same merchant connector accounts
+fn merge_connector_mandate_details(
+    existing_details: Option<serde_json::Value>,
+    new_details: Option<serde_json::Value>,
+) -> errors::RouterResult<Option<serde_json::Value>> {
+    let Some(new_details) = new_details else {
+        return Ok(existing_details);
+    };
+    let parse = |details: serde_json::Value| {
+        details
+            .parse_value::<storage::PaymentsMandateReference>("PaymentsMandateReference")
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to deserialize the connector mandate details")
+    };
+
+    let mut mandate_details = existing_details
+        .map(parse)
+        .transpose()?
+        .unwrap_or_else(|| storage::PaymentsMandateReference(HashMap::new()));
+    mandate_details.extend(parse(new_details)?.0);
+
+    mandate_details
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the connector mandate details")
+        .map(Some)
+}
+
 pub fn store_default_payment_method(
     req: &api::PaymentMethodCreate,
     customer_id: &str,
@@ -155,13 +296,14 @@ pub fn store_default_payment_method(
 }
 #[instrument(skip_all)]
 pub async fn get_or_insert_payment_method(
-    db: &dyn db::StorageInterface,
+    state: &routes::AppState,
     req: api::PaymentMethodCreate,
     resp: &mut api::PaymentMethodResponse,
     merchant_account: &domain::MerchantAccount,
     customer_id: &str,
     key_store: &domain::MerchantKeyStore,
 ) -> errors::RouterResult<diesel_models::PaymentMethod> {
+    let db = &*state.store;
     let mut payment_method_id = resp.payment_method_id.clone();
     let mut locker_id = None;
     let payment_method = {
@@ -193,7 +335,7 @@ pub async fn get_or_insert_payment_method(
         Err(err) => {
             if err.current_context().is_db_not_found() {
                 insert_payment_method(
-                    db,
+                    state,
                     resp,
                     req,
                     key_store,
@@ -272,7 +414,7 @@ pub async fn add_payment_method(
         Some(duplication_check) => match duplication_check {
             payment_methods::DataDuplicationCheck::Duplicated => {
                 get_or_insert_payment_method(
-                    db,
+                    &state,
                     req.clone(),
                     &mut resp,
                     merchant_account,
@@ -284,7 +426,7 @@ pub async fn add_payment_method(
             payment_methods::DataDuplicationCheck::MetaDataChanged => {
                 if let Some(card) = req.card.clone() {
                     let existing_pm = get_or_insert_payment_method(
-                        db,
+                        &state,
                         req.clone(),
                         &mut resp,
                         merchant_account,
@@ -379,8 +521,9 @@ pub async fn add_payment_method(
                 None
             };
             resp.payment_method_id = generate_id(consts::ID_LENGTH, "pm");
-            insert_payment_method(
-                db,
+            let card = req.card.clone();
+            let pm = insert_payment_method(
+                &state,
                 &resp,
                 req,
                 key_store,
@@ -392,6 +535,19 @@ pub async fn add_payment_method(
                 None,
             )
             .await?;
+            // The card might already be saved for the customer, in which case the existing
+            // payment method is updated and returned instead of creating a new one
+            resp.payment_method_id = pm.payment_method_id.clone();
+
+            if let Some(card) = card {
+                network_tokenization::provision_and_store_network_token(
+                    &state,
+                    merchant_account,
+                    &card,
+                    pm,
+                )
+                .await;
+            }
         }
     }
 
@@ -400,7 +556,7 @@ pub async fn add_payment_method(
 
 #[allow(clippy::too_many_arguments)]
 pub async fn insert_payment_method(
-    db: &dyn db::StorageInterface,
+    state: &routes::AppState,
     resp: &api::PaymentMethodResponse,
     req: api::PaymentMethodCreate,
     key_store: &domain::MerchantKeyStore,
@@ -417,7 +573,7 @@ pub async fn insert_payment_method(
         .map(|card| PaymentMethodsData::Card(CardDetailsPaymentMethod::from(card.clone())));
     let pm_data_encrypted = create_encrypted_payment_method_data(key_store, pm_card_details).await;
     create_payment_method(
-        db,
+        state,
         &req,
         customer_id,
         &resp.payment_method_id,
@@ -1475,6 +1631,7 @@ pub async fn list_payment_methods(
             .unwrap_or_else(|| storage::PaymentRoutingInfo {
                 algorithm: None,
                 pre_routing_results: None,
+                routing_algorithm_id: None,
             });
 
         let mut pre_routing_results: HashMap<
@@ -1597,6 +1754,9 @@ pub async fn list_payment_methods(
             merchant_connector_id: None,
             surcharge_amount: None,
             tax_amount: None,
+            three_ds_decision: None,
+            sca_exemption: None,
+            card_fingerprint: None,
         };
 
         state
@@ -2021,6 +2181,9 @@ pub async fn list_payment_methods(
                             start_date: i.start_date,
                             end_date: i.end_date,
                             metadata: i.metadata,
+                            max_amount_per_charge: i.max_amount_per_charge,
+                            max_amount_per_period: i.max_amount_per_period,
+                            limit_period: i.limit_period,
                         })
                     }
                     data_models::mandates::MandateDataType::MultiUse(Some(i)) => {
@@ -2030,6 +2193,9 @@ pub async fn list_payment_methods(
                             start_date: i.start_date,
                             end_date: i.end_date,
                             metadata: i.metadata,
+                            max_amount_per_charge: i.max_amount_per_charge,
+                            max_amount_per_period: i.max_amount_per_period,
+                            limit_period: i.limit_period,
                         }))
                     }
                     data_models::mandates::MandateDataType::MultiUse(None) => {
@@ -2172,6 +2338,14 @@ pub async fn filter_payment_methods(
                         })
                 });
 
+            let is_mandate_setup = payment_attempt
+                .map(|attempt| attempt.mandate_details.is_some())
+                .unwrap_or(false)
+                || payment_intent
+                    .and_then(|intent| intent.setup_future_usage)
+                    .map(|usage| usage == storage_enums::FutureUsage::OffSession)
+                    .unwrap_or(false);
+
             for payment_method_type_info in payment_methods_enabled
                 .payment_method_types
                 .unwrap_or_default()
@@ -2227,7 +2401,11 @@ pub async fn filter_payment_methods(
                         payment_attempt,
                         &mut payment_method_object.card_networks,
                         &address.and_then(|inner| inner.country),
-                        payment_attempt.and_then(|value| value.currency),
+                        payment_attempt
+                            .and_then(|value| value.currency)
+                            .or(payment_intent.and_then(|intent| intent.currency)),
+                        payment_intent.map(|intent| intent.amount).or(req.amount),
+                        is_mandate_setup,
                     );
 
                     let filter6 = filter_pm_based_on_allowed_types(
@@ -2354,6 +2532,8 @@ fn filter_pm_based_on_config<'a>(
     card_network: &mut Option<Vec<api_enums::CardNetwork>>,
     country: &Option<api_enums::CountryAlpha2>,
     currency: Option<api_enums::Currency>,
+    amount: Option<i64>,
+    is_mandate_setup: bool,
 ) -> bool {
     config
         .0
@@ -2366,7 +2546,10 @@ fn filter_pm_based_on_config<'a>(
                     .get(&settings::PaymentMethodFilterKey::PaymentMethodType(
                         *payment_method_type,
                     ))
-                    .map(|value| global_country_currency_filter(value, country, currency));
+                    .map(|value| {
+                        global_country_currency_filter(value, country, currency)
+                            && connector_capability_filter(value, amount, is_mandate_setup)
+                    });
 
                 card_network_filter(country, currency, card_network, inner);
 
@@ -2388,11 +2571,40 @@ fn filter_pm_based_on_config<'a>(
                 .get(&settings::PaymentMethodFilterKey::PaymentMethodType(
                     *payment_method_type,
                 ))
-                .map(|value| global_country_currency_filter(value, country, currency)),
+                .map(|value| {
+                    global_country_currency_filter(value, country, currency)
+                        && connector_capability_filter(value, amount, is_mandate_setup)
+                }),
         })
         .unwrap_or(true)
 }
 
+/// Filters the payment method list on basis of the amount limits of the connector, and whether
+/// the connector is able to set up a mandate through a zero amount payment using the payment method
+fn connector_capability_filter(
+    item: &settings::CurrencyCountryFlowFilter,
+    amount: Option<i64>,
+    is_mandate_setup: bool,
+) -> bool {
+    match amount {
+        Some(0) => {
+            !is_mandate_setup
+                || !item
+                    .not_available_flows
+                    .and_then(|flows| flows.zero_amount_mandate)
+                    .unwrap_or(false)
+        }
+        Some(amount) => {
+            item.minimum_amount
+                .map_or(true, |min_amount| amount >= min_amount)
+                && item
+                    .maximum_amount
+                    .map_or(true, |max_amount| amount <= max_amount)
+        }
+        None => true,
+    }
+}
+
 ///Filters the payment method list on basis of Capture methods, checks whether the connector issues Manual payments using cards or not if not it won't be visible in payment methods list
 fn filter_pm_based_on_capture_method_used(
     payment_method_filters: &settings::PaymentMethodFilters,
@@ -2785,6 +2997,11 @@ pub async fn list_customer_payment_method(
 
     let requires_cvv = is_requires_cvv.config != "false";
 
+    let hide_expired_payment_methods =
+        super::expiry::should_hide_expired_payment_methods(db, &merchant_account.merchant_id)
+            .await?;
+    let today = common_utils::date_time::now().date();
+
     let resp = db
         .find_payment_method_by_customer_id_merchant_id_status(
             customer_id,
@@ -2803,6 +3020,14 @@ pub async fn list_customer_payment_method(
             enums::PaymentMethod::Card => {
                 let card_details = get_card_details_with_locker_fallback(&pm, key, state).await?;
 
+                let is_card_expired = card_details.as_ref().is_some_and(|card| {
+                    super::expiry::get_card_expiry(card)
+                        .is_some_and(|expiry| expiry.is_expired(today))
+                });
+                if hide_expired_payment_methods && is_card_expired {
+                    continue;
+                }
+
                 if card_details.is_some() {
                     PaymentMethodListContext {
                         card_details,
@@ -3056,6 +3281,7 @@ pub async fn get_card_details_with_locker_fallback(
     Ok(if let Some(mut crd) = card_decrypted {
         if crd.saved_to_locker {
             crd.scheme = pm.scheme.clone();
+            crd.card_fingerprint = pm.card_fingerprint.clone().map(Secret::new);
             Some(crd)
         } else {
             None
@@ -3086,6 +3312,7 @@ pub async fn get_card_details_without_locker_fallback(
 
     Ok(if let Some(mut crd) = card_decrypted {
         crd.scheme = pm.scheme.clone();
+        crd.card_fingerprint = pm.card_fingerprint.clone().map(Secret::new);
         crd
     } else {
         get_card_details_from_locker(state, pm).await?
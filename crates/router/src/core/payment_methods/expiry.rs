@@ -0,0 +1,513 @@
+//! Notifications of saved cards expiring soon.
+//!
+//! A task goes through the active saved cards of all merchants at the start of every month, and
+//! notifies the merchant of each card expiring at the end of the next month through a
+//! `payment_method_expiring` webhook sent to the default business profile of the merchant. The
+//! customer may also be notified over email. The expiry of a card is read from the card details
+//! stored along with the payment method, cards saved without them are not notified.
+//!
+//! Merchants may also hide expired cards from the saved payment methods listed for customers, by
+//! setting the `{merchant_id}_hide_expired_payment_methods` config to `true`.
+
+use std::collections::HashMap;
+
+use api_models::payment_methods::PaymentMethodsData;
+use error_stack::{IntoReport, ResultExt};
+use masking::{ExposeInterface, PeekInterface};
+use router_env::{instrument, logger, tracing};
+
+use crate::{
+    core::{
+        errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        webhooks as webhooks_core,
+    },
+    db::StorageInterface,
+    routes::AppState,
+    types::{
+        api,
+        domain::{self, types::decrypt},
+        storage::{self, enums as storage_enums},
+    },
+};
+
+pub const PAYMENT_METHOD_EXPIRY_TASK_ID: &str = "PAYMENT_METHOD_EXPIRY_TASK";
+const PAYMENT_METHOD_EXPIRY_TASK_NAME: &str = "PAYMENT_METHOD_EXPIRY";
+const PAYMENT_METHOD_EXPIRY_TAG: &str = "PAYMENT_METHOD";
+const PAYMENT_METHOD_EXPIRY_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::PaymentMethodExpiryWorkflow;
+
+/// Month at the end of which a card expires
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd)]
+pub struct CardExpiry {
+    year: i32,
+    month: u8,
+}
+
+impl CardExpiry {
+    /// Parses the expiry month and year of a card, the year may have two or four digits
+    pub fn parse(month: &str, year: &str) -> Option<Self> {
+        let month = month
+            .trim()
+            .parse::<u8>()
+            .ok()
+            .filter(|month| (1..=12).contains(month))?;
+        let year = year.trim();
+        let year = match year.len() {
+            2 => year.parse::<i32>().ok()?.checked_add(2000)?,
+            4 => year.parse::<i32>().ok()?,
+            _ => return None,
+        };
+        Some(Self { year, month })
+    }
+
+    pub fn from_date(date: time::Date) -> Self {
+        Self {
+            year: date.year(),
+            month: u8::from(date.month()),
+        }
+    }
+
+    pub fn next(self) -> Self {
+        if self.month == 12 {
+            Self {
+                year: self.year.saturating_add(1),
+                month: 1,
+            }
+        } else {
+            Self {
+                year: self.year,
+                month: self.month.saturating_add(1),
+            }
+        }
+    }
+
+    /// Start of the first day of the month
+    pub fn get_month_start(self) -> Option<time::PrimitiveDateTime> {
+        let month = time::Month::try_from(self.month).ok()?;
+        time::Date::from_calendar_date(self.year, month, 1)
+            .ok()
+            .map(time::Date::midnight)
+    }
+
+    /// A card can be used until the end of its expiry month
+    pub fn is_expired(self, today: time::Date) -> bool {
+        self < Self::from_date(today)
+    }
+}
+
+pub fn get_card_expiry(card: &api::CardDetailFromLocker) -> Option<CardExpiry> {
+    CardExpiry::parse(
+        card.expiry_month.as_ref()?.peek(),
+        card.expiry_year.as_ref()?.peek(),
+    )
+}
+
+/// The identifier of the outgoing webhook event object, unique for each expiry of the payment
+/// method
+fn get_payment_method_expiry_object_id(payment_method_id: &str, expiry: CardExpiry) -> String {
+    format!("{payment_method_id}_{:04}{:02}", expiry.year, expiry.month)
+}
+
+pub fn get_payment_method_id_from_object_id(object_id: &str) -> Option<&str> {
+    object_id
+        .rsplit_once('_')
+        .map(|(payment_method_id, _expiry)| payment_method_id)
+}
+
+/// Checks whether the merchant hides expired cards from the saved payment methods listed for
+/// customers
+pub async fn should_hide_expired_payment_methods(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<bool> {
+    let config = db
+        .find_config_by_key_unwrap_or(
+            &format!("{merchant_id}_hide_expired_payment_methods"),
+            Some("false".to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch hide_expired_payment_methods config")?;
+
+    Ok(config.config == "true")
+}
+
+/// Adds the task which notifies the saved cards expiring next month at the start of every month,
+/// if it is not added already
+pub async fn add_payment_method_expiry_task(
+    db: &dyn StorageInterface,
+) -> CustomResult<(), errors::StorageError> {
+    if db
+        .find_process_by_id(PAYMENT_METHOD_EXPIRY_TASK_ID)
+        .await?
+        .is_some()
+    {
+        return Ok(());
+    }
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        PAYMENT_METHOD_EXPIRY_TASK_ID,
+        PAYMENT_METHOD_EXPIRY_TASK_NAME,
+        PAYMENT_METHOD_EXPIRY_RUNNER,
+        [PAYMENT_METHOD_EXPIRY_TAG],
+        serde_json::json!({}),
+        common_utils::date_time::now(),
+    )
+    .map_err(errors::StorageError::from)
+    .into_report()
+    .attach_printable("Failed to construct payment method expiry process tracker task")?;
+
+    match db.insert_process(process_tracker_entry).await {
+        // Another instance may have added the task in the meantime
+        Err(error) if error.current_context().is_db_unique_violation() => Ok(()),
+        result => result.map(|_| ()),
+    }
+}
+
+struct MerchantContext {
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    business_profile: Option<diesel_models::business_profile::BusinessProfile>,
+}
+
+async fn get_merchant_context(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<MerchantContext> {
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let business_profile = match merchant_account.default_profile.as_deref() {
+        Some(profile_id) => Some(
+            db.find_business_profile_by_profile_id(profile_id)
+                .await
+                .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                    id: profile_id.to_string(),
+                })?,
+        ),
+        None => None,
+    };
+
+    Ok(MerchantContext {
+        merchant_account,
+        key_store,
+        business_profile,
+    })
+}
+
+async fn get_stored_card_details(
+    payment_method: &storage::PaymentMethod,
+    key: &[u8],
+) -> Option<api::CardDetailFromLocker> {
+    decrypt::<serde_json::Value, masking::WithType>(payment_method.payment_method_data.clone(), key)
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                payment_method_id = %payment_method.payment_method_id,
+                "Failed to decrypt the stored card details"
+            )
+        })
+        .ok()
+        .flatten()
+        .map(|data| data.into_inner().expose())
+        .and_then(|value| serde_json::from_value::<PaymentMethodsData>(value).ok())
+        .and_then(|data| match data {
+            PaymentMethodsData::Card(card) => Some(api::CardDetailFromLocker::from(card)),
+            PaymentMethodsData::BankDetails(_) => None,
+        })
+}
+
+/// Notifies the active saved cards expiring at the end of the month after the one of `today`,
+/// returning the number of cards notified
+#[instrument(skip_all)]
+pub async fn notify_expiring_payment_methods(
+    state: &AppState,
+    today: time::Date,
+) -> RouterResult<usize> {
+    let db = &*state.store;
+    let batch_size = state.conf.payment_method_expiry.batch_size;
+    let expiring = CardExpiry::from_date(today).next();
+
+    // Merchants which could not be fetched are cached as `None`, so that their cards are skipped
+    let mut merchants: HashMap<String, Option<MerchantContext>> = HashMap::new();
+    let mut notified_count = 0_usize;
+    let mut after_id = 0;
+
+    loop {
+        let payment_methods = db
+            .find_payment_method_by_payment_method_status_after_id(
+                storage_enums::PaymentMethod::Card,
+                storage_enums::PaymentMethodStatus::Active,
+                after_id,
+                batch_size,
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch saved cards")?;
+
+        let is_last_batch = i64::try_from(payment_methods.len())
+            .map_or(true, |batch_length| batch_length < batch_size);
+        let Some(last_id) = payment_methods
+            .last()
+            .map(|payment_method| payment_method.id)
+        else {
+            break;
+        };
+        after_id = last_id;
+
+        for payment_method in payment_methods {
+            if !merchants.contains_key(&payment_method.merchant_id) {
+                let merchant_context = get_merchant_context(db, &payment_method.merchant_id)
+                    .await
+                    .map_err(|error| {
+                        logger::error!(
+                            ?error,
+                            merchant_id = %payment_method.merchant_id,
+                            "Failed to fetch the merchant of saved cards"
+                        )
+                    })
+                    .ok();
+                merchants.insert(payment_method.merchant_id.clone(), merchant_context);
+            }
+            let Some(Some(merchant_context)) = merchants.get(&payment_method.merchant_id) else {
+                continue;
+            };
+
+            let Some(card) =
+                get_stored_card_details(&payment_method, merchant_context.key_store.key.peek())
+                    .await
+            else {
+                continue;
+            };
+            if get_card_expiry(&card) != Some(expiring) {
+                continue;
+            }
+
+            let payment_method_id = payment_method.payment_method_id.clone();
+            match notify_payment_method_expiring(
+                state,
+                merchant_context,
+                payment_method,
+                card,
+                expiring,
+            )
+            .await
+            {
+                Ok(()) => notified_count = notified_count.saturating_add(1),
+                Err(error) => logger::error!(
+                    ?error,
+                    %payment_method_id,
+                    "Failed to notify the expiry of the saved card"
+                ),
+            }
+        }
+
+        if is_last_batch {
+            break;
+        }
+    }
+
+    Ok(notified_count)
+}
+
+async fn notify_payment_method_expiring(
+    state: &AppState,
+    merchant_context: &MerchantContext,
+    payment_method: storage::PaymentMethod,
+    card: api::CardDetailFromLocker,
+    expiry: CardExpiry,
+) -> RouterResult<()> {
+    if state.conf.payment_method_expiry.notify_customers_by_email {
+        send_payment_method_expiring_email(state, merchant_context, &payment_method, &card).await?;
+    }
+
+    let Some(business_profile) = merchant_context.business_profile.clone() else {
+        logger::debug!(
+            merchant_id = %payment_method.merchant_id,
+            "Merchant has no default business profile, skipping the payment method expiring webhook"
+        );
+        return Ok(());
+    };
+
+    let object_id = get_payment_method_expiry_object_id(&payment_method.payment_method_id, expiry);
+    let created_at = payment_method.created_at;
+    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_context.merchant_account.clone(),
+        business_profile,
+        &merchant_context.key_store,
+        storage_enums::EventType::PaymentMethodExpiring,
+        storage_enums::EventClass::PaymentMethods,
+        object_id,
+        storage_enums::EventObjectType::PaymentMethodDetails,
+        api::OutgoingWebhookContent::PaymentMethodDetails(Box::new(get_payment_method_response(
+            payment_method,
+            Some(card),
+        ))),
+        Some(created_at),
+    ))
+    .await
+}
+
+#[cfg(feature = "email")]
+async fn send_payment_method_expiring_email(
+    state: &AppState,
+    merchant_context: &MerchantContext,
+    payment_method: &storage::PaymentMethod,
+    card: &api::CardDetailFromLocker,
+) -> RouterResult<()> {
+    use crate::services::email::types as email_types;
+
+    let customer = state
+        .store
+        .find_customer_by_customer_id_merchant_id(
+            &payment_method.customer_id,
+            &payment_method.merchant_id,
+            &merchant_context.key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+    let Some(recipient_email) = customer.email.map(common_utils::pii::Email::from) else {
+        return Ok(());
+    };
+
+    let merchant_account = &merchant_context.merchant_account;
+    let email_contents = email_types::PaymentMethodExpiring {
+        recipient_email,
+        subject: "Your Card Is Expiring Soon",
+        merchant_name: merchant_account
+            .merchant_name
+            .clone()
+            .map(|merchant_name| merchant_name.into_inner().expose())
+            .unwrap_or_else(|| merchant_account.merchant_id.clone()),
+        card_network: card
+            .card_network
+            .as_ref()
+            .map(ToString::to_string)
+            .unwrap_or_default(),
+        last4: card.last4_digits.clone().unwrap_or_default(),
+        expiry_month: card
+            .expiry_month
+            .clone()
+            .map(ExposeInterface::expose)
+            .unwrap_or_default(),
+        expiry_year: card
+            .expiry_year
+            .clone()
+            .map(ExposeInterface::expose)
+            .unwrap_or_default(),
+    };
+
+    state
+        .email_client
+        .clone()
+        .compose_and_send_email(
+            Box::new(email_contents),
+            state.conf.proxy.https_url.as_ref(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to send payment method expiring email")
+}
+
+#[cfg(not(feature = "email"))]
+async fn send_payment_method_expiring_email(
+    _state: &AppState,
+    _merchant_context: &MerchantContext,
+    _payment_method: &storage::PaymentMethod,
+    _card: &api::CardDetailFromLocker,
+) -> RouterResult<()> {
+    logger::warn!("Payment method expiring emails cannot be sent when email feature is disabled");
+    Ok(())
+}
+
+fn get_payment_method_response(
+    payment_method: storage::PaymentMethod,
+    card: Option<api::CardDetailFromLocker>,
+) -> api::PaymentMethodResponse {
+    api::PaymentMethodResponse {
+        merchant_id: payment_method.merchant_id,
+        customer_id: Some(payment_method.customer_id),
+        payment_method_id: payment_method.payment_method_id,
+        payment_method: payment_method.payment_method,
+        payment_method_type: payment_method.payment_method_type,
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        card,
+        metadata: payment_method.metadata,
+        created: Some(payment_method.created_at),
+        recurring_enabled: false,
+        installment_payment_enabled: false,
+        payment_experience: Some(vec![api_models::enums::PaymentExperience::RedirectToUrl]),
+        last_used_at: Some(payment_method.last_used_at),
+    }
+}
+
+/// Provides the content of the webhook notifying the expiry of the payment method, with the card
+/// details stored along with the payment method
+pub async fn get_payment_method_expiring_content(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    payment_method_id: &str,
+) -> RouterResult<api::PaymentMethodResponse> {
+    let payment_method = state
+        .store
+        .find_payment_method(payment_method_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentMethodNotFound)?;
+    let card = get_stored_card_details(&payment_method, key_store.key.peek()).await;
+
+    Ok(get_payment_method_response(payment_method, card))
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::date;
+
+    use super::*;
+
+    #[test]
+    fn test_card_expiry_parsing() {
+        assert_eq!(
+            CardExpiry::parse("03", "30"),
+            Some(CardExpiry {
+                year: 2030,
+                month: 3
+            })
+        );
+        assert_eq!(
+            CardExpiry::parse("3", "2030"),
+            CardExpiry::parse("03", "30")
+        );
+        assert_eq!(CardExpiry::parse("13", "30"), None);
+        assert_eq!(CardExpiry::parse("00", "30"), None);
+        assert_eq!(CardExpiry::parse("03", "030"), None);
+    }
+
+    #[test]
+    fn test_card_expiry_months() {
+        let december = CardExpiry::from_date(date!(2024 - 12 - 15));
+        assert_eq!(
+            december.next(),
+            CardExpiry {
+                year: 2025,
+                month: 1
+            }
+        );
+        assert_eq!(
+            december.next().get_month_start(),
+            Some(date!(2025 - 01 - 01).midnight())
+        );
+
+        assert!(!december.is_expired(date!(2024 - 12 - 31)));
+        assert!(december.is_expired(date!(2025 - 01 - 01)));
+    }
+}
@@ -0,0 +1,409 @@
+use std::str::FromStr;
+
+use api_models::enums as api_enums;
+use common_utils::request::RequestContent;
+use error_stack::ResultExt;
+use masking::{Mask, Secret};
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings,
+    core::{
+        errors::{self, RouterResult},
+        payments::types::NetworkTokenData,
+    },
+    headers, logger,
+    routes::AppState,
+    services,
+    types::{api, domain, storage},
+    utils::ConnectorResponseExt,
+};
+
+/// Interface of the scheme token services (Visa Token Service, Mastercard Digital Enablement
+/// Service) which exchange card numbers for network tokens
+#[async_trait::async_trait]
+pub trait NetworkTokenProvider: Send + Sync {
+    /// Provisions a network token for the card, with the token requestor ID of the merchant if
+    /// any, otherwise with the token requestor ID of the platform
+    async fn provision_token(
+        &self,
+        state: &AppState,
+        card: &api::CardDetail,
+        customer_id: &str,
+        token_requestor_id: Option<&str>,
+    ) -> RouterResult<ProvisionedNetworkToken>;
+
+    /// Generates a single use cryptogram for a payment made with a provisioned network token, with
+    /// the token requestor ID the network token was provisioned with
+    async fn get_token_cryptogram(
+        &self,
+        state: &AppState,
+        token_reference_id: &str,
+        token_requestor_id: Option<&str>,
+    ) -> RouterResult<NetworkTokenCryptogram>;
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct ProvisionedNetworkToken {
+    pub token_reference_id: String,
+    pub token: cards::CardNumber,
+    pub token_expiry_month: Secret<String>,
+    pub token_expiry_year: Secret<String>,
+}
+
+#[derive(Debug, serde::Deserialize)]
+pub struct NetworkTokenCryptogram {
+    pub cryptogram: Secret<String>,
+    pub eci: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct ProvisionTokenRequest {
+    card_number: cards::CardNumber,
+    card_exp_month: Secret<String>,
+    card_exp_year: Secret<String>,
+    card_holder_name: Option<Secret<String>>,
+    customer_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    token_requestor_id: Option<String>,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct TokenCryptogramRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    token_requestor_id: Option<String>,
+}
+
+/// Provider talking to the scheme token service through the configured token service, requests
+/// are routed to the token service of the card network using the scheme path
+struct TokenServiceProvider {
+    base_url: String,
+    api_key: Secret<String>,
+    scheme: &'static str,
+}
+
+impl TokenServiceProvider {
+    fn build_request(&self, url: String, body: Option<RequestContent>) -> services::Request {
+        let mut request_builder = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&url)
+            .attach_default_headers()
+            .headers(vec![
+                (
+                    headers::CONTENT_TYPE.to_string(),
+                    "application/json".to_string().into(),
+                ),
+                (
+                    headers::AUTHORIZATION.to_string(),
+                    self.api_key.clone().into_masked(),
+                ),
+            ]);
+        if let Some(body) = body {
+            request_builder = request_builder.set_body(body);
+        }
+        request_builder.build()
+    }
+}
+
+#[async_trait::async_trait]
+impl NetworkTokenProvider for TokenServiceProvider {
+    async fn provision_token(
+        &self,
+        state: &AppState,
+        card: &api::CardDetail,
+        customer_id: &str,
+        token_requestor_id: Option<&str>,
+    ) -> RouterResult<ProvisionedNetworkToken> {
+        let request_body = ProvisionTokenRequest {
+            card_number: card.card_number.clone(),
+            card_exp_month: card.card_exp_month.clone(),
+            card_exp_year: card.card_exp_year.clone(),
+            card_holder_name: card.card_holder_name.clone(),
+            customer_id: customer_id.to_string(),
+            token_requestor_id: token_requestor_id.map(ToOwned::to_owned),
+        };
+        let request = self.build_request(
+            format!("{}/{}/tokens", self.base_url, self.scheme),
+            Some(RequestContent::Json(Box::new(request_body))),
+        );
+
+        services::call_connector_api(state, request, "provision_network_token")
+            .await
+            .get_response_inner("ProvisionedNetworkToken")
+            .attach_printable("Failed to provision network token")
+    }
+
+    async fn get_token_cryptogram(
+        &self,
+        state: &AppState,
+        token_reference_id: &str,
+        token_requestor_id: Option<&str>,
+    ) -> RouterResult<NetworkTokenCryptogram> {
+        let request_body = TokenCryptogramRequest {
+            token_requestor_id: token_requestor_id.map(ToOwned::to_owned),
+        };
+        let request = self.build_request(
+            format!(
+                "{}/{}/tokens/{token_reference_id}/cryptogram",
+                self.base_url, self.scheme
+            ),
+            Some(RequestContent::Json(Box::new(request_body))),
+        );
+
+        services::call_connector_api(state, request, "get_network_token_cryptogram")
+            .await
+            .get_response_inner("NetworkTokenCryptogram")
+            .attach_printable("Failed to fetch network token cryptogram")
+    }
+}
+
+/// Returns the token service provider for the card network, if network tokenization is enabled
+/// and supported for the card network
+pub fn get_network_token_provider(
+    config: &settings::NetworkTokenizationService,
+    card_network: &api_enums::CardNetwork,
+) -> Option<Box<dyn NetworkTokenProvider>> {
+    if !config.enabled || !config.supported_card_networks.contains(card_network) {
+        return None;
+    }
+
+    let scheme = match card_network {
+        api_enums::CardNetwork::Visa => "vts",
+        api_enums::CardNetwork::Mastercard => "mdes",
+        _ => return None,
+    };
+
+    Some(Box::new(TokenServiceProvider {
+        base_url: config.token_service_url.clone(),
+        api_key: config.token_service_api_key.clone(),
+        scheme,
+    }))
+}
+
+/// Provisions a network token for a saved card and stores the token in the locker. Failures are
+/// only logged, the card has already been saved and can be used without the network token.
+#[instrument(skip_all)]
+pub async fn provision_and_store_network_token(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    card: &api::CardDetail,
+    payment_method: storage::PaymentMethod,
+) {
+    if payment_method.network_token_reference_id.is_some() {
+        return;
+    }
+
+    let payment_method_id = payment_method.payment_method_id.clone();
+    if let Err(error) =
+        provision_and_store_network_token_inner(state, merchant_account, card, payment_method).await
+    {
+        logger::error!(
+            ?error,
+            %payment_method_id,
+            "Failed to provision network token for the saved card"
+        );
+    }
+}
+
+async fn provision_and_store_network_token_inner(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    card: &api::CardDetail,
+    payment_method: storage::PaymentMethod,
+) -> RouterResult<()> {
+    let Some(card_network) = card.card_network.clone() else {
+        return Ok(());
+    };
+    let network_tokenization_service = state.conf.network_tokenization_service.get_inner();
+    let Some(provider) = get_network_token_provider(network_tokenization_service, &card_network)
+    else {
+        return Ok(());
+    };
+
+    // Network tokens are not provisioned while the token requestor ID of the merchant is not
+    // active, as they would not be usable with the token requestor ID once it is active
+    let token_requestor_id = match super::token_requestor::get_token_requestor_id(
+        &*state.store,
+        &merchant_account.merchant_id,
+        &card_network,
+    )
+    .await?
+    {
+        Some(token_requestor_id) if token_requestor_id.is_active() => {
+            Some(token_requestor_id.token_requestor_id)
+        }
+        Some(token_requestor_id) => {
+            logger::info!(
+                status = %token_requestor_id.status,
+                %card_network,
+                "Skipping network tokenization as the token requestor ID of the merchant is not active"
+            );
+            return Ok(());
+        }
+        None => None,
+    };
+
+    let network_token = provider
+        .provision_token(
+            state,
+            card,
+            &payment_method.customer_id,
+            token_requestor_id.as_deref(),
+        )
+        .await?;
+
+    let token_card = api::CardDetail {
+        card_number: network_token.token,
+        card_exp_month: network_token.token_expiry_month,
+        card_exp_year: network_token.token_expiry_year,
+        card_holder_name: card.card_holder_name.clone(),
+        nick_name: None,
+        card_issuing_country: None,
+        card_network: Some(card_network.clone()),
+        card_issuer: None,
+        card_type: None,
+    };
+    let token_card_request = api::PaymentMethodCreate {
+        payment_method: payment_method.payment_method,
+        payment_method_type: payment_method.payment_method_type,
+        payment_method_issuer: None,
+        payment_method_issuer_code: None,
+        #[cfg(feature = "payouts")]
+        bank_transfer: None,
+        #[cfg(feature = "payouts")]
+        wallet: None,
+        card: Some(token_card.clone()),
+        metadata: None,
+        customer_id: Some(payment_method.customer_id.clone()),
+        card_network: Some(card_network.to_string()),
+    };
+
+    let (locker_response, _) = super::cards::add_card_to_locker(
+        state,
+        token_card_request,
+        &token_card,
+        &payment_method.customer_id,
+        merchant_account,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to store network token in locker")?;
+
+    let pm_update = storage::PaymentMethodUpdate::NetworkTokenDataUpdate {
+        network_token_reference_id: Some(network_token.token_reference_id),
+        network_token_locker_id: Some(locker_response.payment_method_id),
+        network_token_requestor_id: token_requestor_id,
+    };
+    state
+        .store
+        .update_payment_method(payment_method, pm_update)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update network token details of the payment method")?;
+
+    Ok(())
+}
+
+/// Returns the network token of the saved card along with a fresh cryptogram, if the connector
+/// accepts network tokens and a network token has been provisioned for the saved card. The card
+/// number is used for the payment whenever the network token cannot be used.
+#[instrument(skip_all)]
+pub async fn get_network_token_data_for_payment(
+    state: &AppState,
+    connector_name: &str,
+    payment_method: Option<&storage::PaymentMethod>,
+    payment_method_data: Option<&api::PaymentMethodData>,
+) -> Option<NetworkTokenData> {
+    let connector = api_enums::Connector::from_str(connector_name).ok()?;
+    if !state
+        .conf
+        .network_tokenization_supported_connectors
+        .connector_list
+        .contains(&connector)
+    {
+        return None;
+    }
+
+    let Some(api::PaymentMethodData::Card(card)) = payment_method_data else {
+        return None;
+    };
+    let payment_method = payment_method?;
+    let card_network = payment_method
+        .scheme
+        .as_deref()
+        .and_then(|scheme| api_enums::CardNetwork::from_str(scheme).ok())
+        .or_else(|| card.card_network.clone())?;
+
+    get_network_token_data(state, payment_method, card_network)
+        .await
+        .map_err(|error| {
+            logger::error!(
+                ?error,
+                payment_method_id = %payment_method.payment_method_id,
+                "Failed to fetch network token, proceeding with the card number"
+            )
+        })
+        .ok()
+        .flatten()
+}
+
+async fn get_network_token_data(
+    state: &AppState,
+    payment_method: &storage::PaymentMethod,
+    card_network: api_enums::CardNetwork,
+) -> RouterResult<Option<NetworkTokenData>> {
+    let (Some(token_reference_id), Some(token_locker_id)) = (
+        payment_method.network_token_reference_id.as_deref(),
+        payment_method.network_token_locker_id.as_deref(),
+    ) else {
+        return Ok(None);
+    };
+    let network_tokenization_service = state.conf.network_tokenization_service.get_inner();
+    let Some(provider) = get_network_token_provider(network_tokenization_service, &card_network)
+    else {
+        return Ok(None);
+    };
+
+    // Network tokens provisioned with a token requestor ID of the merchant are only used while the
+    // token requestor ID is active
+    let token_requestor_id = payment_method.network_token_requestor_id.as_deref();
+    if let Some(token_requestor_id) = token_requestor_id {
+        let is_token_requestor_id_active = super::token_requestor::get_token_requestor_id(
+            &*state.store,
+            &payment_method.merchant_id,
+            &card_network,
+        )
+        .await?
+        .is_some_and(|config| {
+            config.is_active() && config.token_requestor_id == token_requestor_id
+        });
+        if !is_token_requestor_id_active {
+            logger::info!(
+                %card_network,
+                "Token requestor ID of the network token is not active, proceeding with the card number"
+            );
+            return Ok(None);
+        }
+    }
+
+    let token_card = super::cards::get_card_from_locker(
+        state,
+        &payment_method.customer_id,
+        &payment_method.merchant_id,
+        token_locker_id,
+    )
+    .await
+    .attach_printable("Failed to fetch network token from locker")?;
+    let cryptogram = provider
+        .get_token_cryptogram(state, token_reference_id, token_requestor_id)
+        .await?;
+
+    Ok(Some(NetworkTokenData {
+        token_number: token_card.card_number,
+        token_exp_month: token_card.card_exp_month,
+        token_exp_year: token_card.card_exp_year,
+        token_cryptogram: cryptogram.cryptogram,
+        eci: cryptogram.eci,
+        card_network,
+    }))
+}
@@ -0,0 +1,310 @@
+//! Token requestor IDs of merchants.
+//!
+//! Card schemes assign a token requestor ID (TRID) to each merchant registered with their token
+//! service. Network tokens of a merchant with a registered TRID are provisioned with, and
+//! cryptograms are requested with, the TRID of the merchant, otherwise the TRID of the platform
+//! configured with the token service is used.
+//!
+//! A merchant holds at most one TRID per card network which is not deactivated. Deactivated TRIDs
+//! are retained, as network tokens provisioned with them are no longer used for payments.
+
+use api_models::{admin as admin_types, enums as api_enums};
+use common_utils::{
+    date_time,
+    ext_traits::{Encode, StringExt},
+};
+use diesel_models::configs;
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, tracing};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::AppState,
+    services::api as service_api,
+    utils,
+};
+
+/// A token requestor ID of the merchant, along with the status of its registration
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenRequestorIdConfig {
+    pub card_network: api_enums::CardNetwork,
+    pub token_requestor_id: String,
+    pub status: admin_types::TokenRequestorIdStatus,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub created_at: PrimitiveDateTime,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub modified_at: PrimitiveDateTime,
+}
+
+impl TokenRequestorIdConfig {
+    fn is_deactivated(&self) -> bool {
+        self.status == admin_types::TokenRequestorIdStatus::Deactivated
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.status == admin_types::TokenRequestorIdStatus::Active
+    }
+
+    fn into_response(self, merchant_id: &str) -> admin_types::TokenRequestorIdResponse {
+        admin_types::TokenRequestorIdResponse {
+            merchant_id: merchant_id.to_string(),
+            card_network: self.card_network,
+            token_requestor_id: self.token_requestor_id,
+            status: self.status,
+            created_at: self.created_at,
+            modified_at: self.modified_at,
+        }
+    }
+}
+
+/// Provides the identifier of the config holding the token requestor IDs of the merchant
+#[inline(always)]
+fn get_token_requestor_ids_config_key(merchant_id: &str) -> String {
+    format!("token_requestor_ids_{merchant_id}")
+}
+
+async fn get_token_requestor_ids(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<Vec<TokenRequestorIdConfig>> {
+    let config = match db
+        .find_config_by_key(&get_token_requestor_ids_config_key(merchant_id))
+        .await
+    {
+        Ok(config) => config,
+        Err(error) if error.current_context().is_db_not_found() => return Ok(Vec::new()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the token requestor IDs")?,
+    };
+
+    config
+        .config
+        .parse_struct("Vec<TokenRequestorIdConfig>")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the token requestor IDs")
+}
+
+async fn store_token_requestor_ids(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    token_requestor_ids: Vec<TokenRequestorIdConfig>,
+) -> RouterResult<()> {
+    let key = get_token_requestor_ids_config_key(merchant_id);
+    // Creates the config if the merchant has no token requestor IDs yet
+    db.find_config_by_key_unwrap_or(&key, Some("[]".to_string()))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the token requestor IDs")?;
+
+    db.update_config_by_key(
+        &key,
+        configs::ConfigUpdate::Update {
+            config: Some(
+                token_requestor_ids
+                    .encode_to_string_of_json()
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to serialize the token requestor IDs")?,
+            ),
+        },
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update the token requestor IDs")?;
+
+    Ok(())
+}
+
+/// Provides the token requestor ID of the merchant for the card network which is not deactivated,
+/// if any
+#[instrument(skip(db))]
+pub async fn get_token_requestor_id(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+    card_network: &api_enums::CardNetwork,
+) -> RouterResult<Option<TokenRequestorIdConfig>> {
+    Ok(get_token_requestor_ids(db, merchant_id)
+        .await?
+        .into_iter()
+        .find(|config| &config.card_network == card_network && !config.is_deactivated()))
+}
+
+/// Registration of a token requestor ID goes through `pending` to `active`, can be suspended and
+/// resumed when `active`, and can be deactivated at any point, after which it cannot be resumed
+fn is_valid_status_transition(
+    current_status: admin_types::TokenRequestorIdStatus,
+    new_status: admin_types::TokenRequestorIdStatus,
+) -> bool {
+    use admin_types::TokenRequestorIdStatus as Status;
+
+    matches!(
+        (current_status, new_status),
+        (Status::Pending, Status::Active | Status::Deactivated)
+            | (Status::Active, Status::Suspended | Status::Deactivated)
+            | (Status::Suspended, Status::Active | Status::Deactivated)
+    ) || (current_status == new_status && current_status != Status::Deactivated)
+}
+
+#[instrument(skip(state))]
+pub async fn create_token_requestor_id(
+    state: AppState,
+    merchant_id: &str,
+    req: admin_types::TokenRequestorIdCreateRequest,
+) -> RouterResponse<admin_types::TokenRequestorIdResponse> {
+    utils::when(
+        req.token_requestor_id.len() != 11
+            || !req.token_requestor_id.chars().all(|c| c.is_ascii_digit()),
+        || {
+            Err(errors::ApiErrorResponse::InvalidDataFormat {
+                field_name: "token_requestor_id".to_string(),
+                expected_format: "11 digits".to_string(),
+            })
+            .into_report()
+        },
+    )?;
+    let status = req
+        .status
+        .unwrap_or(admin_types::TokenRequestorIdStatus::Pending);
+    utils::when(
+        status == admin_types::TokenRequestorIdStatus::Deactivated,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "A token requestor ID cannot be registered as deactivated".to_string(),
+            })
+            .into_report()
+        },
+    )?;
+
+    let db = state.store.as_ref();
+    db.get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let mut token_requestor_ids = get_token_requestor_ids(db, merchant_id).await?;
+    if token_requestor_ids
+        .iter()
+        .any(|config| config.card_network == req.card_network && !config.is_deactivated())
+    {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!(
+                "The token requestor ID of the merchant for {} must be deactivated before registering another one",
+                req.card_network
+            ),
+        })?;
+    }
+
+    let now = date_time::now();
+    let token_requestor_id = TokenRequestorIdConfig {
+        card_network: req.card_network,
+        token_requestor_id: req.token_requestor_id,
+        status,
+        created_at: now,
+        modified_at: now,
+    };
+    token_requestor_ids.push(token_requestor_id.clone());
+    store_token_requestor_ids(db, merchant_id, token_requestor_ids).await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        token_requestor_id.into_response(merchant_id),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn list_token_requestor_ids(
+    state: AppState,
+    merchant_id: &str,
+) -> RouterResponse<Vec<admin_types::TokenRequestorIdResponse>> {
+    let db = state.store.as_ref();
+    db.get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let token_requestor_ids = get_token_requestor_ids(db, merchant_id).await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        token_requestor_ids
+            .into_iter()
+            .map(|config| config.into_response(merchant_id))
+            .collect(),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn update_token_requestor_id_status(
+    state: AppState,
+    merchant_id: &str,
+    card_network: api_enums::CardNetwork,
+    req: admin_types::TokenRequestorIdUpdateRequest,
+) -> RouterResponse<admin_types::TokenRequestorIdResponse> {
+    let db = state.store.as_ref();
+    let mut token_requestor_ids = get_token_requestor_ids(db, merchant_id).await?;
+
+    let token_requestor_id = token_requestor_ids
+        .iter_mut()
+        .find(|config| config.card_network == card_network && !config.is_deactivated())
+        .ok_or_else(|| errors::ApiErrorResponse::GenericNotFoundError {
+            message: format!("The merchant has no token requestor ID for {card_network}"),
+        })?;
+
+    utils::when(
+        !is_valid_status_transition(token_requestor_id.status, req.status),
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: format!(
+                    "The status of the token requestor ID cannot be updated from {} to {}",
+                    token_requestor_id.status, req.status
+                ),
+            })
+            .into_report()
+        },
+    )?;
+    token_requestor_id.status = req.status;
+    token_requestor_id.modified_at = date_time::now();
+    let token_requestor_id = token_requestor_id.clone();
+
+    store_token_requestor_ids(db, merchant_id, token_requestor_ids).await?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        token_requestor_id.into_response(merchant_id),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_token_requestor_id_status_transitions() {
+        use admin_types::TokenRequestorIdStatus as Status;
+
+        assert!(is_valid_status_transition(Status::Pending, Status::Active));
+        assert!(is_valid_status_transition(
+            Status::Active,
+            Status::Suspended
+        ));
+        assert!(is_valid_status_transition(
+            Status::Suspended,
+            Status::Active
+        ));
+        assert!(is_valid_status_transition(
+            Status::Suspended,
+            Status::Deactivated
+        ));
+        assert!(!is_valid_status_transition(Status::Active, Status::Pending));
+        assert!(!is_valid_status_transition(
+            Status::Pending,
+            Status::Suspended
+        ));
+        assert!(!is_valid_status_transition(
+            Status::Deactivated,
+            Status::Active
+        ));
+        assert!(!is_valid_status_transition(
+            Status::Deactivated,
+            Status::Deactivated
+        ));
+    }
+}
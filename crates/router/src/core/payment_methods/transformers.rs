@@ -561,7 +561,7 @@ pub fn get_card_detail(
         expiry_month: Some(response.card_exp_month),
         expiry_year: Some(response.card_exp_year),
         card_token: None,
-        card_fingerprint: None,
+        card_fingerprint: pm.card_fingerprint.clone().map(masking::Secret::new),
         card_holder_name: response.name_on_card,
         nick_name: response.nick_name.map(masking::Secret::new),
         card_isin: None,
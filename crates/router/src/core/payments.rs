@@ -1,15 +1,23 @@
 pub mod access_token;
+pub mod address_normalization;
+pub mod card_verification;
 pub mod conditional_configs;
 pub mod customers;
 pub mod flows;
 pub mod helpers;
+pub mod live_activity;
+pub mod manual_review;
 pub mod operations;
+pub mod partial_authorization;
+pub mod required_fields;
 #[cfg(feature = "retry")]
 pub mod retry;
 pub mod routing;
+pub mod three_ds_decision;
 pub mod tokenization;
 pub mod transformers;
 pub mod types;
+pub mod velocity;
 
 use std::{fmt::Debug, marker::PhantomData, ops::Deref, time::Instant, vec::IntoIter};
 
@@ -46,7 +54,9 @@ use self::{
     routing::{self as self_routing, SessionFlowRoutingInput},
 };
 use super::{
-    errors::StorageErrorExt, payment_methods::surcharge_decision_configs, routing::TransactionData,
+    errors::StorageErrorExt,
+    payment_methods::{card_fingerprint, surcharge_decision_configs},
+    routing::TransactionData,
 };
 #[cfg(feature = "frm")]
 use crate::core::fraud_check as frm_core;
@@ -78,11 +88,21 @@ use crate::{
 };
 
 #[allow(clippy::too_many_arguments, clippy::type_complexity)]
-#[instrument(skip_all, fields(payment_id, merchant_id))]
+#[instrument(
+    skip_all,
+    fields(
+        payment_id,
+        merchant_id,
+        hyperswitch.merchant_id_hash,
+        hyperswitch.payment_id,
+        hyperswitch.attempt_status
+    )
+)]
 pub async fn payments_operation_core<F, Req, Op, FData, Ctx>(
     state: &AppState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
+    profile_id: Option<String>,
     operation: Op,
     req: Req,
     call_connector_action: CallConnectorAction,
@@ -116,21 +136,27 @@ where
 {
     let operation: BoxedOperation<'_, F, Req, Ctx> = Box::new(operation);
 
-    tracing::Span::current().record("merchant_id", merchant_account.merchant_id.as_str());
+    router_env::record_request_context!(merchant_id = &merchant_account.merchant_id);
     let (operation, validate_result) = operation
         .to_validate_request()?
         .validate_request(&req, &merchant_account)?;
 
     tracing::Span::current().record("payment_id", &format!("{}", validate_result.payment_id));
+    router_env::record_span_attributes!(
+        merchant_id = &merchant_account.merchant_id,
+        payment_id = &format!("{}", validate_result.payment_id),
+    );
 
+    // Fetching the trackers makes up most of the database round trips of the payment flows, its
+    // latency is tracked per operation to catch regressions
+    let operation_name = format!("{operation:?}");
     let operations::GetTrackerResponse {
         operation,
         customer_details,
         mut payment_data,
         business_profile,
-    } = operation
-        .to_get_tracker()?
-        .get_trackers(
+    } = metrics::request::record_operation_time(
+        operation.to_get_tracker()?.get_trackers(
             state,
             &validate_result.payment_id,
             &req,
@@ -139,8 +165,25 @@ where
             &key_store,
             auth_flow,
             header_payload.payment_confirm_source,
-        )
-        .await?;
+        ),
+        &metrics::PAYMENT_GET_TRACKERS_TIME,
+        &[metrics::request::add_attributes(
+            "operation",
+            operation_name,
+        )],
+    )
+    .await?;
+
+    utils::validate_profile_id_of_resource(
+        profile_id.as_ref(),
+        payment_data.payment_intent.profile_id.as_ref(),
+        errors::ApiErrorResponse::PaymentNotFound,
+    )?;
+
+    router_env::record_request_context!(
+        payment_id = &payment_data.payment_attempt.payment_id,
+        attempt_id = &payment_data.payment_attempt.attempt_id,
+    );
 
     let (operation, customer) = operation
         .to_domain()?
@@ -231,8 +274,16 @@ where
             .await?;
 
         if should_continue_transaction {
+            let is_held_for_review = manual_review::hold_capture_for_review_if_required(
+                state,
+                &operation,
+                &merchant_account,
+                &mut payment_data,
+                &connector_details,
+            )
+            .await?;
             #[cfg(feature = "frm")]
-            match (
+            let is_capture_held = match (
                 should_continue_capture,
                 payment_data.payment_attempt.capture_method,
             ) {
@@ -240,8 +291,9 @@ where
                 | (false, Some(storage_enums::CaptureMethod::Scheduled)) => {
                     payment_data.payment_attempt.capture_method =
                         Some(storage_enums::CaptureMethod::Manual);
+                    true
                 }
-                _ => (),
+                _ => false,
             };
             payment_data = match connector_details {
                 api::ConnectorCallType::PreDetermined(connector) => {
@@ -423,9 +475,31 @@ where
                         .attach_printable("Frm configs label not found")?,
                     &customer,
                     key_store.clone(),
+                    is_capture_held,
                 ))
                 .await?;
             }
+
+            let is_void_scheduled = partial_authorization::schedule_void_if_configured(
+                state,
+                &merchant_account,
+                &payment_data,
+                &business_profile,
+            )
+            .await?
+                || card_verification::schedule_void_if_policy_violated(
+                    state,
+                    &merchant_account,
+                    &payment_data,
+                    &business_profile,
+                )
+                .await?;
+
+            // Authorizations being voided are not held for a review by the merchant
+            if is_held_for_review && !is_void_scheduled {
+                manual_review::move_payment_to_review(state, &merchant_account, &mut payment_data)
+                    .await?;
+            }
         } else {
             (_, payment_data) = operation
                 .to_update_tracker()?
@@ -493,6 +567,8 @@ where
     .map_err(|error| logger::warn!(payments_outgoing_webhook_error=?error))
     .ok();
 
+    router_env::record_span_attributes!(attempt_status = &payment_data.payment_attempt.status);
+
     Ok((
         payment_data,
         req,
@@ -511,6 +587,12 @@ pub async fn call_decision_manager<O>(
 where
     O: Send + Clone,
 {
+    // The payment method of a payment created without one is chosen by the customer later, the
+    // decisions depending on it are made when the payment is confirmed
+    if payment_data.confirm != Some(true) && payment_data.payment_attempt.payment_method.is_none() {
+        return Ok(());
+    }
+
     let algorithm_ref: api::routing::RoutingAlgorithmRef = merchant_account
         .routing_algorithm
         .clone()
@@ -529,11 +611,40 @@ where
     .await
     .change_context(errors::ApiErrorResponse::InternalServerError)
     .attach_printable("Could not decode the conditional config")?;
+
+    // The 3DS decision is made once for an attempt, when it is confirmed
+    let should_decide_three_ds = payment_data.confirm == Some(true)
+        && payment_data.payment_attempt.three_ds_decision.is_none();
+
+    // The 3DS decision rules apply only when the authentication type is not passed in the request
+    let matched_rule =
+        if should_decide_three_ds && payment_data.payment_attempt.authentication_type.is_none() {
+            three_ds_decision::perform_three_ds_decision(
+                state,
+                &merchant_account.merchant_id,
+                payment_data,
+            )
+            .await?
+        } else {
+            None
+        };
+
     payment_data.payment_attempt.authentication_type = payment_data
         .payment_attempt
         .authentication_type
+        .or(matched_rule
+            .as_ref()
+            .map(|rule| three_ds_decision::get_authentication_type(rule.decision)))
         .or(output.override_3ds.map(ForeignInto::foreign_into))
         .or(Some(storage_enums::AuthenticationType::NoThreeDs));
+
+    if should_decide_three_ds {
+        three_ds_decision::record_three_ds_decision(
+            &mut payment_data.payment_attempt,
+            &payment_data.payment_intent,
+            matched_rule.as_ref(),
+        );
+    }
     Ok(())
 }
 
@@ -678,6 +789,7 @@ pub async fn payments_core<F, Res, Req, Op, FData, Ctx>(
     state: AppState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
+    profile_id: Option<String>,
     operation: Op,
     req: Req,
     auth_flow: services::AuthFlow,
@@ -714,6 +826,7 @@ where
             &state,
             merchant_account,
             key_store,
+            profile_id,
             operation.clone(),
             req,
             call_connector_action,
@@ -893,6 +1006,7 @@ impl<Ctx: PaymentMethodRetrieve> PaymentRedirectFlow<Ctx> for PaymentRedirectCom
             state.clone(),
             merchant_account,
             merchant_key_store,
+            None,
             payment_complete_authorize::CompleteAuthorize,
             payment_confirm_req,
             services::api::AuthFlow::Merchant,
@@ -1001,6 +1115,7 @@ impl<Ctx: PaymentMethodRetrieve> PaymentRedirectFlow<Ctx> for PaymentRedirectSyn
             state.clone(),
             merchant_account,
             merchant_key_store,
+            None,
             PaymentStatus,
             payment_sync_req,
             services::api::AuthFlow::Merchant,
@@ -1067,6 +1182,7 @@ impl<Ctx: PaymentMethodRetrieve> PaymentRedirectFlow<Ctx> for PaymentAuthenticat
             state.clone(),
             merchant_account,
             merchant_key_store,
+            None,
             PaymentConfirm,
             payment_confirm_req,
             services::api::AuthFlow::Merchant,
@@ -1199,9 +1315,33 @@ where
     .await?;
     *payment_data = pd;
 
+    operation
+        .to_domain()?
+        .validate_required_fields(state, &connector, payment_data)?;
+
+    // Generating the merchant scoped card fingerprint, which is stored on the payment attempt
+    if let Some(api_models::payments::PaymentMethodData::Card(card)) =
+        payment_data.payment_method_data.as_ref()
+    {
+        payment_data.payment_attempt.card_fingerprint = Some(
+            card_fingerprint::generate_card_fingerprints(
+                &*state.store,
+                key_store,
+                &card.card_number,
+            )
+            .await?
+            .current,
+        );
+    }
+
     // Validating the blocklist guard and generate the fingerprint
     blocklist_guard(state, merchant_account, operation, payment_data).await?;
 
+    operation
+        .to_domain()?
+        .guard_payment_against_velocity_limits(state, merchant_account, payment_data)
+        .await?;
+
     let updated_customer = call_create_connector_customer_if_required(
         state,
         customer,
@@ -2121,27 +2261,28 @@ where
     Ctx: PaymentMethodRetrieve,
 {
     // On confirm is false and only router related
-    let is_external_authentication_requested = payment_data
-        .payment_intent
-        .request_external_three_ds_authentication;
-    let payment_data =
-        if !is_operation_confirm(operation) || is_external_authentication_requested == Some(true) {
-            let (_operation, payment_method_data, pm_id) = operation
-                .to_domain()?
-                .make_pm_data(
-                    state,
-                    payment_data,
-                    validate_result.storage_scheme,
-                    merchant_key_store,
-                    customer,
-                )
-                .await?;
-            payment_data.payment_method_data = payment_method_data;
-            payment_data.payment_attempt.payment_method_id = pm_id;
-            payment_data
-        } else {
-            payment_data
-        };
+    let is_external_authentication_requested =
+        three_ds_decision::is_external_authentication_requested(
+            &payment_data.payment_intent,
+            &payment_data.payment_attempt,
+        );
+    let payment_data = if !is_operation_confirm(operation) || is_external_authentication_requested {
+        let (_operation, payment_method_data, pm_id) = operation
+            .to_domain()?
+            .make_pm_data(
+                state,
+                payment_data,
+                validate_result.storage_scheme,
+                merchant_key_store,
+                customer,
+            )
+            .await?;
+        payment_data.payment_method_data = payment_method_data;
+        payment_data.payment_attempt.payment_method_id = pm_id;
+        payment_data
+    } else {
+        payment_data
+    };
     Ok(payment_data.to_owned())
 }
 
@@ -2245,6 +2386,9 @@ where
     pub payment_method_data: Option<api::PaymentMethodData>,
     pub payment_method_info: Option<storage::PaymentMethod>,
     pub refunds: Vec<storage::Refund>,
+    /// Whether the refundable amount is included in the response, set when the refunds of the
+    /// payment are fetched along with it
+    pub include_amount_refundable: bool,
     pub disputes: Vec<storage::Dispute>,
     pub attempts: Option<Vec<storage::PaymentAttempt>>,
     pub sessions_token: Vec<api::SessionToken>,
@@ -2287,6 +2431,7 @@ pub struct CustomerDetails {
     pub email: Option<pii::Email>,
     pub phone: Option<Secret<String, masking::WithType>>,
     pub phone_country_code: Option<String>,
+    pub document: Option<api_models::payments::CustomerDocumentDetails>,
 }
 
 pub fn if_not_create_change_operation<'a, Op, F, Ctx>(
@@ -2394,16 +2539,22 @@ pub fn is_operation_complete_authorize<Op: Debug>(operation: &Op) -> bool {
 pub async fn list_payments(
     state: AppState,
     merchant: domain::MerchantAccount,
+    profile_id: Option<String>,
     constraints: api::PaymentListConstraints,
 ) -> RouterResponse<api::PaymentListResponse> {
     use data_models::errors::StorageError;
     helpers::validate_payment_list_request(&constraints)?;
     let merchant_id = &merchant.merchant_id;
     let db = state.store.as_ref();
-    let payment_intents =
-        helpers::filter_by_constraints(db, &constraints, merchant_id, merchant.storage_scheme)
-            .await
-            .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    let payment_intents = helpers::filter_by_constraints(
+        db,
+        &constraints,
+        merchant_id,
+        profile_id,
+        merchant.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
     let collected_futures = payment_intents.into_iter().map(|pi| {
         async {
@@ -2460,12 +2611,15 @@ pub async fn list_payments(
 pub async fn apply_filters_on_payments(
     state: AppState,
     merchant: domain::MerchantAccount,
-    constraints: api::PaymentListFilterConstraints,
+    profile_id: Option<String>,
+    mut constraints: api::PaymentListFilterConstraints,
 ) -> RouterResponse<api::PaymentListResponseV2> {
     let limit = &constraints.limit;
     helpers::validate_payment_list_request_for_joins(*limit)?;
+    constraints.profile_id =
+        utils::validate_profile_id_from_auth_layer(profile_id, constraints.profile_id)?;
     let db = state.store.as_ref();
-    let list: Vec<(storage::PaymentIntent, storage::PaymentAttempt)> = db
+    let mut list: Vec<(storage::PaymentIntent, storage::PaymentAttempt)> = db
         .get_filtered_payment_intents_attempt(
             &merchant.merchant_id,
             &constraints.clone().into(),
@@ -2474,6 +2628,11 @@ pub async fn apply_filters_on_payments(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
+    // Payments looked up by payment id are not filtered by the business profile in storage
+    if let Some(profile_id) = &constraints.profile_id {
+        list.retain(|(payment_intent, _)| payment_intent.profile_id.as_ref() == Some(profile_id));
+    }
+
     let data: Vec<api::PaymentsResponse> =
         list.into_iter().map(ForeignFrom::foreign_from).collect();
 
@@ -2512,10 +2671,11 @@ pub async fn apply_filters_on_payments(
 pub async fn get_filters_for_payments(
     state: AppState,
     merchant: domain::MerchantAccount,
+    profile_id: Option<String>,
     time_range: api::TimeRange,
 ) -> RouterResponse<api::PaymentListFilters> {
     let db = state.store.as_ref();
-    let pi = db
+    let mut pi = db
         .filter_payment_intents_by_time_range_constraints(
             &merchant.merchant_id,
             &time_range,
@@ -2524,6 +2684,10 @@ pub async fn get_filters_for_payments(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
+    if let Some(profile_id) = &profile_id {
+        pi.retain(|payment_intent| payment_intent.profile_id.as_ref() == Some(profile_id));
+    }
+
     let filters = db
         .get_filters_for_payments(
             pi.as_slice(),
@@ -2736,6 +2900,7 @@ where
             .unwrap_or_else(|| storage::PaymentRoutingInfo {
                 algorithm: None,
                 pre_routing_results: None,
+                routing_algorithm_id: None,
             }),
     };
 
@@ -2864,6 +3029,7 @@ where
         let (mut connectors, check_eligibility) = routing::perform_straight_through_routing(
             &routing_algorithm,
             payment_data.creds_identifier.clone(),
+            Some(&payment_data.payment_intent.payment_id),
         )
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed execution of straight through routing")?;
@@ -2918,6 +3084,7 @@ where
         let (mut connectors, check_eligibility) = routing::perform_straight_through_routing(
             routing_algorithm,
             payment_data.creds_identifier.clone(),
+            Some(&payment_data.payment_intent.payment_id),
         )
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed execution of straight through routing")?;
@@ -3240,6 +3407,7 @@ where
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Could not decode merchant routing algorithm ref")?
         .unwrap_or_default();
+    routing_data.routing_info.routing_algorithm_id = algorithm_ref.algorithm_id.clone();
 
     let connectors = routing::perform_static_routing_v1(
         state,
@@ -3264,6 +3432,31 @@ where
     .change_context(errors::ApiErrorResponse::InternalServerError)
     .attach_printable("failed eligibility analysis and fallback")?;
 
+    let connectors = match &transaction_data {
+        TransactionData::Payment(payment_data) => {
+            let connectors = routing::connector_health::filter_degraded_connectors(
+                state,
+                payment_data,
+                connectors,
+            )
+            .await;
+
+            if routing::success_rate::is_success_rate_routing_enabled(
+                state,
+                &merchant_account.merchant_id,
+            )
+            .await
+            {
+                routing::success_rate::perform_success_rate_routing(state, payment_data, connectors)
+                    .await
+            } else {
+                connectors
+            }
+        }
+        #[cfg(feature = "payouts")]
+        TransactionData::Payout(_) => connectors,
+    };
+
     #[cfg(feature = "payouts")]
     let first_connector_choice = connectors
         .first()
@@ -3462,32 +3655,57 @@ pub async fn payment_external_authentication(
             id: profile_id.to_string(),
         })?;
 
-    let authentication_response = Box::pin(authentication_core::perform_authentication(
-        &state,
-        authentication_connector,
-        payment_method_details.0,
-        payment_method_details.1,
-        billing_address
-            .as_ref()
-            .map(|address| address.into())
-            .ok_or(errors::ApiErrorResponse::MissingRequiredField {
-                field_name: "billing_address",
-            })?,
-        shipping_address.as_ref().map(|address| address.into()),
-        browser_info,
-        business_profile,
-        merchant_connector_account,
-        amount,
-        Some(currency),
-        authentication::MessageCategory::Payment,
-        req.device_channel,
-        authentication,
-        return_url,
-        req.sdk_information,
-        req.threeds_method_comp_ind,
-        optional_customer.and_then(|customer| customer.email.map(common_utils::pii::Email::from)),
-    ))
-    .await?;
+    let (authentication_response, authentication) =
+        Box::pin(authentication_core::perform_authentication(
+            &state,
+            &key_store,
+            authentication_connector,
+            payment_method_details.0,
+            payment_method_details.1,
+            billing_address
+                .as_ref()
+                .map(|address| address.into())
+                .ok_or(errors::ApiErrorResponse::MissingRequiredField {
+                    field_name: "billing_address",
+                })?,
+            shipping_address.as_ref().map(|address| address.into()),
+            browser_info,
+            business_profile,
+            merchant_connector_account,
+            amount,
+            Some(currency),
+            authentication::MessageCategory::Payment,
+            req.device_channel,
+            authentication,
+            return_url,
+            req.sdk_information,
+            req.threeds_method_comp_ind,
+            optional_customer
+                .and_then(|customer| customer.email.map(common_utils::pii::Email::from)),
+            payment_attempt.sca_exemption,
+            &payment_intent.payment_id,
+        ))
+        .await?;
+
+    // The authentication is retried with the fallback authentication connector when the primary
+    // one is unavailable, the payment attempt is updated so that the post-authentication is
+    // performed with the fallback authentication connector
+    if payment_attempt.authentication_id.as_ref() != Some(&authentication.authentication_id) {
+        db.update_payment_attempt_with_attempt_id(
+            payment_attempt.clone(),
+            storage::PaymentAttemptUpdate::AuthenticationUpdate {
+                status: payment_attempt.status,
+                external_three_ds_authentication_attempted: Some(true),
+                authentication_connector: Some(authentication.authentication_connector.clone()),
+                authentication_id: Some(authentication.authentication_id.clone()),
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    }
+
     Ok(services::ApplicationResponse::Json(
         api_models::payments::PaymentsExternalAuthenticationResponse {
             transaction_status: authentication_response.trans_status,
@@ -0,0 +1,401 @@
+//! Validation and normalization of the billing addresses of card payments.
+//!
+//! The billing address of a card payment is normalized on payment creation, and the normalized
+//! address is stored alongside the address received in the request. Connectors are sent the
+//! normalized address when it could be verified or corrected, which improves the address
+//! verification (AVS) results and the billing data sent for 3DS authentication.
+
+use api_models::{enums as api_enums, payments::AddressDetails};
+use common_utils::{ext_traits::Encode, request::RequestContent};
+use error_stack::ResultExt;
+use masking::{ExposeInterface, Mask, PeekInterface, Secret};
+use router_env::{instrument, tracing};
+
+use super::helpers;
+use crate::{
+    configs::settings,
+    core::errors::{self, RouterResult},
+    headers, logger,
+    routes::AppState,
+    services,
+    types::{
+        api,
+        domain::{
+            self,
+            types::{self, AsyncLift},
+        },
+        storage::enums as storage_enums,
+        transformers::ForeignTryFrom,
+    },
+    utils::ConnectorResponseExt,
+};
+
+/// Outcome of the normalization of an address
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct NormalizedAddress {
+    pub status: api_enums::AddressValidationStatus,
+    /// The normalized address, `None` if the address could not be validated
+    pub address: Option<AddressDetails>,
+}
+
+/// Interface of the services validating and normalizing addresses
+#[async_trait::async_trait]
+pub trait AddressNormalizationProvider: Send + Sync {
+    /// Name of the provider, used for logging
+    fn name(&self) -> &'static str;
+
+    async fn normalize_address(
+        &self,
+        state: &AppState,
+        address: &AddressDetails,
+    ) -> RouterResult<NormalizedAddress>;
+}
+
+/// Normalizes the address with the built-in formatting rules of the postal codes and states of
+/// the supported countries
+struct BasicAddressNormalizationProvider;
+
+#[async_trait::async_trait]
+impl AddressNormalizationProvider for BasicAddressNormalizationProvider {
+    fn name(&self) -> &'static str {
+        "basic"
+    }
+
+    async fn normalize_address(
+        &self,
+        _state: &AppState,
+        address: &AddressDetails,
+    ) -> RouterResult<NormalizedAddress> {
+        Ok(normalize_address_details(address))
+    }
+}
+
+/// Normalizes the address with the configured external address validation service
+struct ExternalAddressNormalizationProvider {
+    base_url: String,
+    api_key: Secret<String>,
+}
+
+#[async_trait::async_trait]
+impl AddressNormalizationProvider for ExternalAddressNormalizationProvider {
+    fn name(&self) -> &'static str {
+        "external"
+    }
+
+    async fn normalize_address(
+        &self,
+        state: &AppState,
+        address: &AddressDetails,
+    ) -> RouterResult<NormalizedAddress> {
+        let request = services::RequestBuilder::new()
+            .method(services::Method::Post)
+            .url(&format!("{}/addresses/normalize", self.base_url))
+            .attach_default_headers()
+            .headers(vec![
+                (
+                    headers::CONTENT_TYPE.to_string(),
+                    "application/json".to_string().into(),
+                ),
+                (
+                    headers::AUTHORIZATION.to_string(),
+                    self.api_key.clone().into_masked(),
+                ),
+            ])
+            .set_body(RequestContent::Json(Box::new(address.clone())))
+            .build();
+
+        services::call_connector_api(state, request, "normalize_address")
+            .await
+            .get_response_inner("NormalizedAddress")
+            .attach_printable("Failed to normalize the address with the external service")
+    }
+}
+
+/// Returns the configured address normalization provider, the external service is used in place
+/// of the built-in rules when enabled
+pub fn get_address_normalization_provider(
+    config: &settings::AddressNormalization,
+) -> Box<dyn AddressNormalizationProvider> {
+    if config.external_provider_enabled {
+        Box::new(ExternalAddressNormalizationProvider {
+            base_url: config.external_provider_url.clone(),
+            api_key: config.external_provider_api_key.clone(),
+        })
+    } else {
+        Box::new(BasicAddressNormalizationProvider)
+    }
+}
+
+/// Normalizes the address with the configured provider. Provider failures are only logged, as the
+/// payment can proceed with the address as it was received.
+#[instrument(skip_all)]
+pub async fn normalize_address(
+    state: &AppState,
+    address: &AddressDetails,
+) -> Option<NormalizedAddress> {
+    let provider = get_address_normalization_provider(state.conf.address_normalization.get_inner());
+    provider
+        .normalize_address(state, address)
+        .await
+        .map_err(|error| {
+            logger::warn!(
+                ?error,
+                provider = provider.name(),
+                "Failed to normalize the address"
+            )
+        })
+        .ok()
+}
+
+/// Creates the address of the payment along with its normalized address
+pub async fn create_normalized_address_for_payment(
+    state: &AppState,
+    req_address: Option<&api::Address>,
+    merchant_id: &str,
+    customer_id: Option<&String>,
+    merchant_key_store: &domain::MerchantKeyStore,
+    payment_id: &str,
+    storage_scheme: storage_enums::MerchantStorageScheme,
+) -> RouterResult<Option<domain::Address>> {
+    let Some(address) = req_address else {
+        return Ok(None);
+    };
+    let key = merchant_key_store.key.get_inner().peek();
+
+    let address_details = address.address.clone().unwrap_or_default();
+    let normalized_address = match address.address.as_ref() {
+        Some(address_details) => normalize_address(state, address_details).await,
+        None => None,
+    };
+
+    let mut domain_address = helpers::get_domain_address_for_payments(
+        address_details,
+        address,
+        merchant_id,
+        customer_id,
+        payment_id,
+        key,
+        storage_scheme,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed while encrypting address while insert")?;
+
+    if let Some(normalized_address) = normalized_address {
+        domain_address.normalized_address = normalized_address
+            .address
+            .map(|address| address.encode_to_value())
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize the normalized address")?
+            .map(Secret::new)
+            .async_lift(|inner| types::encrypt_optional(inner, key))
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encrypt the normalized address")?;
+        domain_address.address_validation_status = Some(normalized_address.status);
+    }
+
+    state
+        .store
+        .insert_address_for_payments(
+            payment_id,
+            domain_address,
+            merchant_key_store,
+            storage_scheme,
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed while inserting new address")
+        .map(Some)
+}
+
+/// Trims the value and collapses its whitespace, `None` if nothing remains
+fn normalize_text(value: &str) -> Option<String> {
+    let value = value.split_whitespace().collect::<Vec<_>>().join(" ");
+    (!value.is_empty()).then_some(value)
+}
+
+fn normalize_secret_text(value: Option<&Secret<String>>) -> Option<Secret<String>> {
+    value
+        .and_then(|value| normalize_text(value.peek()))
+        .map(Secret::new)
+}
+
+/// Formats the postal code as per the postal code format of the country. The outer `None`
+/// indicates that there are no formatting rules for the country, the inner `None` that the postal
+/// code does not match the format of the country.
+fn normalize_postal_code(
+    country: api_enums::CountryAlpha2,
+    postal_code: &str,
+) -> Option<Option<String>> {
+    use api_enums::CountryAlpha2 as Country;
+
+    let postal_code = postal_code
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect::<String>()
+        .to_uppercase();
+    let is_digits = |value: &str| value.chars().all(|c| c.is_ascii_digit());
+    // Splits the postal code at the position from the end, for the formats in which the last
+    // characters of the postal code are separated
+    let split_from_end = |position: usize| {
+        postal_code
+            .len()
+            .checked_sub(position)
+            .and_then(|index| Some((postal_code.get(..index)?, postal_code.get(index..)?)))
+    };
+    let with_length = |length: usize| postal_code.len() == length;
+
+    let normalized_postal_code = match country {
+        Country::US => match postal_code.len() {
+            5 if is_digits(&postal_code) => Some(postal_code.clone()),
+            9 if is_digits(&postal_code) => {
+                split_from_end(4).map(|(zip, plus_four)| format!("{zip}-{plus_four}"))
+            }
+            _ => None,
+        },
+        Country::CA => {
+            let is_valid = with_length(6)
+                && postal_code.chars().enumerate().all(|(index, c)| {
+                    if index % 2 == 0 {
+                        c.is_ascii_alphabetic()
+                    } else {
+                        c.is_ascii_digit()
+                    }
+                });
+            is_valid
+                .then(|| split_from_end(3))
+                .flatten()
+                .map(|(area, unit)| format!("{area} {unit}"))
+        }
+        Country::GB => split_from_end(3).and_then(|(outward, inward)| {
+            let mut inward_chars = inward.chars();
+            let is_valid = (2..=4).contains(&outward.len())
+                && outward.starts_with(|c: char| c.is_ascii_alphabetic())
+                && outward.chars().all(|c| c.is_ascii_alphanumeric())
+                && inward_chars.next().is_some_and(|c| c.is_ascii_digit())
+                && inward_chars.all(|c| c.is_ascii_alphabetic());
+            is_valid.then(|| format!("{outward} {inward}"))
+        }),
+        Country::IN => (with_length(6) && is_digits(&postal_code)).then(|| postal_code.clone()),
+        Country::DE | Country::FR | Country::ES | Country::IT => {
+            (with_length(5) && is_digits(&postal_code)).then(|| postal_code.clone())
+        }
+        Country::NL => split_from_end(2).and_then(|(digits, letters)| {
+            let is_valid = with_length(6)
+                && is_digits(digits)
+                && letters.chars().all(|c| c.is_ascii_alphabetic());
+            is_valid.then(|| format!("{digits} {letters}"))
+        }),
+        Country::BR => (with_length(8) && is_digits(&postal_code))
+            .then(|| split_from_end(3))
+            .flatten()
+            .map(|(prefix, suffix)| format!("{prefix}-{suffix}")),
+        Country::AU => (with_length(4) && is_digits(&postal_code)).then(|| postal_code.clone()),
+        Country::JP => (with_length(7) && is_digits(&postal_code))
+            .then(|| split_from_end(4))
+            .flatten()
+            .map(|(prefix, suffix)| format!("{prefix}-{suffix}")),
+        _ => return None,
+    };
+    Some(normalized_postal_code)
+}
+
+/// Converts the state of US and Canadian addresses to its two letter code
+fn normalize_state(country: api_enums::CountryAlpha2, state: String) -> String {
+    let is_code = state.len() == 2 && state.chars().all(|c| c.is_ascii_alphabetic());
+    match country {
+        _ if is_code => state.to_uppercase(),
+        api_enums::CountryAlpha2::US => {
+            api_enums::UsStatesAbbreviation::foreign_try_from(state.clone())
+                .map(|state| state.to_string())
+                .unwrap_or(state)
+        }
+        api_enums::CountryAlpha2::CA => {
+            api_enums::CanadaStatesAbbreviation::foreign_try_from(state.clone())
+                .map(|state| state.to_string())
+                .unwrap_or(state)
+        }
+        _ => state,
+    }
+}
+
+/// Normalizes the address with the built-in rules. Addresses of countries without formatting
+/// rules are only cleaned up and left unverified.
+fn normalize_address_details(address: &AddressDetails) -> NormalizedAddress {
+    let state = normalize_secret_text(address.state.as_ref());
+    let mut normalized_address = AddressDetails {
+        city: address.city.as_deref().and_then(normalize_text),
+        country: address.country,
+        line1: normalize_secret_text(address.line1.as_ref()),
+        line2: normalize_secret_text(address.line2.as_ref()),
+        line3: normalize_secret_text(address.line3.as_ref()),
+        zip: normalize_secret_text(address.zip.as_ref()),
+        state: match address.country {
+            Some(country) => {
+                state.map(|state| Secret::new(normalize_state(country, state.expose())))
+            }
+            None => state,
+        },
+        first_name: normalize_secret_text(address.first_name.as_ref()),
+        last_name: normalize_secret_text(address.last_name.as_ref()),
+    };
+
+    let postal_code = address
+        .country
+        .zip(normalized_address.zip.as_ref())
+        .map(|(country, postal_code)| normalize_postal_code(country, postal_code.peek()));
+    let status = match postal_code {
+        Some(Some(None)) => {
+            return NormalizedAddress {
+                status: api_enums::AddressValidationStatus::Invalid,
+                address: None,
+            }
+        }
+        Some(Some(Some(postal_code))) => {
+            normalized_address.zip = Some(Secret::new(postal_code));
+            if &normalized_address == address {
+                api_enums::AddressValidationStatus::Verified
+            } else {
+                api_enums::AddressValidationStatus::Corrected
+            }
+        }
+        // No postal code, no country, or no formatting rules for the country
+        Some(None) | None => api_enums::AddressValidationStatus::Unverified,
+    };
+
+    NormalizedAddress {
+        status,
+        address: Some(normalized_address),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_postal_code() {
+        use api_enums::CountryAlpha2 as Country;
+
+        assert_eq!(
+            normalize_postal_code(Country::US, "123456789"),
+            Some(Some("12345-6789".to_string()))
+        );
+        assert_eq!(
+            normalize_postal_code(Country::CA, "k1a0b1"),
+            Some(Some("K1A 0B1".to_string()))
+        );
+        assert_eq!(
+            normalize_postal_code(Country::GB, "sw1a2aa"),
+            Some(Some("SW1A 2AA".to_string()))
+        );
+        assert_eq!(
+            normalize_postal_code(Country::NL, "1234ab"),
+            Some(Some("1234 AB".to_string()))
+        );
+        assert_eq!(normalize_postal_code(Country::US, "1234"), Some(None));
+        assert_eq!(normalize_postal_code(Country::AE, "1234"), None);
+    }
+}
@@ -0,0 +1,197 @@
+//! Card verification policy of business profiles.
+//!
+//! Connectors return the result of the address verification (AVS) and security code (CVV) checks
+//! performed by the issuer when authorizing a card payment, which are recorded on the attempt.
+//! Authorizations failing a check rejected by the policy of the profile are voided by a process
+//! tracker task scheduled right after the authorization, releasing the funds of the customer
+//! without any action from the merchant. The same task voids partial authorizations when the
+//! profile is configured to void them.
+
+use api_models::admin::CardVerificationPolicy;
+use common_utils::ext_traits::ValueExt;
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, tracing};
+use scheduler::utils as pt_utils;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    core::{
+        errors::{self, RouterResult},
+        payments::PaymentData,
+    },
+    logger,
+    routes::AppState,
+    types::{
+        domain,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+const CARD_VERIFICATION_VOID_TASK_NAME: &str = "CARD_VERIFICATION_VOID";
+const CARD_VERIFICATION_VOID_TAG: &str = "PAYMENT";
+const CARD_VERIFICATION_VOID_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::CardVerificationVoidWorkflow;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CardVerificationVoidTrackingData {
+    pub merchant_id: String,
+    pub payment_id: String,
+    pub reason: String,
+}
+
+/// Schedules the void of the authorization of the attempt when its AVS or CVV result is rejected
+/// by the card verification policy of the profile, returns whether the void is scheduled
+#[instrument(skip_all)]
+pub async fn schedule_void_if_policy_violated<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_data: &PaymentData<F>,
+    business_profile: &storage::business_profile::BusinessProfile,
+) -> RouterResult<bool> {
+    // Only authorizations pending capture can be voided
+    if !matches!(
+        payment_data.payment_attempt.status,
+        storage_enums::AttemptStatus::Authorized
+            | storage_enums::AttemptStatus::PartiallyAuthorized
+    ) || payment_data.payment_intent.status != storage_enums::IntentStatus::RequiresCapture
+    {
+        return Ok(false);
+    }
+
+    let Some(policy) = business_profile
+        .card_verification_policy
+        .clone()
+        .map(|policy| policy.parse_value::<CardVerificationPolicy>("CardVerificationPolicy"))
+        .transpose()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the card verification policy of the profile")?
+    else {
+        return Ok(false);
+    };
+
+    let Some(reason) = get_policy_violation(
+        &policy,
+        payment_data.payment_attempt.avs_result,
+        payment_data.payment_attempt.cvv_result,
+    ) else {
+        return Ok(false);
+    };
+
+    schedule_authorization_void(
+        state,
+        merchant_account,
+        &payment_data.payment_intent.payment_id,
+        format!("Voided by the card verification policy: {reason}"),
+    )
+    .await?;
+    logger::info!(
+        %reason,
+        "Scheduled the void of the authorization failing the card verification policy"
+    );
+
+    Ok(true)
+}
+
+/// Schedules the void of the authorization of the payment by a process tracker task, the reason
+/// being recorded as the cancellation reason of the payment
+#[instrument(skip_all)]
+pub async fn schedule_authorization_void(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_id: &str,
+    reason: String,
+) -> RouterResult<()> {
+    let tracking_data = CardVerificationVoidTrackingData {
+        merchant_id: merchant_account.merchant_id.clone(),
+        payment_id: payment_id.to_string(),
+        reason,
+    };
+    let process_tracker_id = pt_utils::get_process_tracker_id(
+        CARD_VERIFICATION_VOID_RUNNER,
+        CARD_VERIFICATION_VOID_TASK_NAME,
+        payment_id,
+        &merchant_account.merchant_id,
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        CARD_VERIFICATION_VOID_TASK_NAME,
+        CARD_VERIFICATION_VOID_RUNNER,
+        [CARD_VERIFICATION_VOID_TAG],
+        tracking_data,
+        common_utils::date_time::now(),
+    )
+    .map_err(errors::StorageError::from)
+    .into_report()
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct the card verification void process tracker task")?;
+
+    state
+        .store
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert the card verification void process tracker task")?;
+
+    Ok(())
+}
+
+fn get_policy_violation(
+    policy: &CardVerificationPolicy,
+    avs_result: Option<storage_enums::CardCheckResult>,
+    cvv_result: Option<storage_enums::CardCheckResult>,
+) -> Option<&'static str> {
+    match (avs_result, cvv_result) {
+        (Some(storage_enums::CardCheckResult::Mismatch), _) if policy.void_on_avs_mismatch => {
+            Some("AVS mismatch")
+        }
+        (Some(storage_enums::CardCheckResult::PartialMatch), _)
+            if policy.void_on_avs_partial_match =>
+        {
+            Some("AVS partial match")
+        }
+        (_, Some(storage_enums::CardCheckResult::Mismatch)) if policy.void_on_cvv_mismatch => {
+            Some("CVV mismatch")
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_card_verification_policy_violation() {
+        let policy = CardVerificationPolicy {
+            void_on_avs_mismatch: true,
+            void_on_avs_partial_match: false,
+            void_on_cvv_mismatch: true,
+        };
+
+        assert_eq!(
+            get_policy_violation(
+                &policy,
+                Some(storage_enums::CardCheckResult::Mismatch),
+                Some(storage_enums::CardCheckResult::Match),
+            ),
+            Some("AVS mismatch")
+        );
+        assert_eq!(
+            get_policy_violation(
+                &policy,
+                Some(storage_enums::CardCheckResult::PartialMatch),
+                Some(storage_enums::CardCheckResult::Mismatch),
+            ),
+            Some("CVV mismatch")
+        );
+        assert_eq!(
+            get_policy_violation(
+                &policy,
+                Some(storage_enums::CardCheckResult::PartialMatch),
+                None,
+            ),
+            None
+        );
+        assert_eq!(get_policy_violation(&policy, None, None), None);
+    }
+}
@@ -6,6 +6,7 @@ use crate::{
     core::{
         errors::{self, ConnectorErrorExt, RouterResult},
         mandate,
+        payment_methods::network_tokenization,
         payments::{
             self, access_token, customers, helpers, tokenization, transformers, PaymentData,
         },
@@ -39,7 +40,7 @@ impl
             types::PaymentsResponseData,
         >,
     > {
-        Box::pin(transformers::construct_payment_router_data::<
+        let mut router_data = Box::pin(transformers::construct_payment_router_data::<
             api::Authorize,
             types::PaymentsAuthorizeData,
         >(
@@ -51,7 +52,18 @@ impl
             customer,
             merchant_connector_account,
         ))
-        .await
+        .await?;
+
+        router_data.request.network_token_data =
+            network_tokenization::get_network_token_data_for_payment(
+                state,
+                connector_id,
+                self.payment_method_info.as_ref(),
+                self.payment_method_data.as_ref(),
+            )
+            .await;
+
+        Ok(router_data)
     }
 }
 #[async_trait]
@@ -13,9 +13,10 @@ use crate::{
             self, access_token, customers, helpers, tokenization, transformers, PaymentData,
         },
     },
+    logger,
     routes::AppState,
     services,
-    types::{self, api, domain},
+    types::{self, api, domain, storage::enums},
 };
 
 #[async_trait]
@@ -82,22 +83,40 @@ impl Feature<api::SetupMandate, types::SetupMandateRequestData> for types::Setup
             ))
             .await
         } else {
-            let connector_integration: services::BoxedConnectorIntegration<
-                '_,
-                api::SetupMandate,
-                types::SetupMandateRequestData,
-                types::PaymentsResponseData,
-            > = connector.connector.get_connector_integration();
+            let is_zero_auth_supported = state
+                .conf
+                .card_verification
+                .supports_zero_auth
+                .contains(&connector.connector_name);
+            let is_card_payment =
+                self.request.payment_method_data.get_payment_method() == Some(PaymentMethod::Card);
 
-            let mut resp = services::execute_connector_processing_step(
-                state,
-                connector_integration,
-                &self,
-                call_connector_action.clone(),
-                connector_request,
-            )
-            .await
-            .to_setup_mandate_failed_response()?;
+            let mut resp = if is_card_payment && !is_zero_auth_supported {
+                Box::pin(self.verify_card_with_minimal_amount_authorization(
+                    state,
+                    connector,
+                    call_connector_action,
+                    state.conf.card_verification.verification_amount,
+                ))
+                .await?
+            } else {
+                let connector_integration: services::BoxedConnectorIntegration<
+                    '_,
+                    api::SetupMandate,
+                    types::SetupMandateRequestData,
+                    types::PaymentsResponseData,
+                > = connector.connector.get_connector_integration();
+
+                services::execute_connector_processing_step(
+                    state,
+                    connector_integration,
+                    &self,
+                    call_connector_action.clone(),
+                    connector_request,
+                )
+                .await
+                .to_setup_mandate_failed_response()?
+            };
 
             let (pm_id, payment_method_status) = Box::pin(tokenization::save_payment_method(
                 state,
@@ -267,6 +286,115 @@ impl types::SetupMandateRouterData {
         }
     }
 
+    /// Verifies the card by authorizing the verification amount and voiding the authorization,
+    /// for the connectors which cannot verify a card through a zero amount authorization. The
+    /// card is considered verified once the authorization succeeds, a failure to void the
+    /// authorization is only logged as the authorization expires at the connector.
+    async fn verify_card_with_minimal_amount_authorization(
+        &self,
+        state: &AppState,
+        connector: &api::ConnectorData,
+        call_connector_action: payments::CallConnectorAction,
+        verification_amount: i64,
+    ) -> RouterResult<Self> {
+        let authorize_data = types::PaymentsAuthorizeData {
+            payment_method_data: self.request.payment_method_data.clone(),
+            amount: verification_amount,
+            email: self.request.email.clone(),
+            customer_name: self.request.customer_name.clone(),
+            currency: self.request.currency,
+            confirm: true,
+            statement_descriptor_suffix: self.request.statement_descriptor_suffix.clone(),
+            statement_descriptor: None,
+            capture_method: Some(enums::CaptureMethod::Manual),
+            router_return_url: self.request.router_return_url.clone(),
+            webhook_url: None,
+            complete_authorize_url: None,
+            setup_future_usage: self.request.setup_future_usage,
+            mandate_id: self.request.mandate_id.clone(),
+            off_session: self.request.off_session,
+            customer_acceptance: self.request.customer_acceptance.clone(),
+            setup_mandate_details: self.request.setup_mandate_details.clone(),
+            browser_info: self.request.browser_info.clone(),
+            order_details: None,
+            order_category: None,
+            session_token: None,
+            enrolled_for_3ds: true,
+            related_transaction_id: None,
+            payment_experience: None,
+            payment_method_type: self.request.payment_method_type,
+            surcharge_details: None,
+            customer_id: self.customer_id.clone(),
+            request_incremental_authorization: false,
+            metadata: self.request.metadata.clone(),
+            authentication_data: None,
+            network_token_data: None,
+            sca_exemption: None,
+            customer_document: None,
+        };
+        let authorize_router_data =
+            helpers::router_data_type_conversion::<_, api::Authorize, _, _, _, _>(
+                self.clone(),
+                authorize_data,
+                self.response.clone(),
+            );
+
+        let authorize_integration: services::BoxedConnectorIntegration<
+            '_,
+            api::Authorize,
+            types::PaymentsAuthorizeData,
+            types::PaymentsResponseData,
+        > = connector.connector.get_connector_integration();
+        let authorize_router_data = services::execute_connector_processing_step(
+            state,
+            authorize_integration,
+            &authorize_router_data,
+            call_connector_action.clone(),
+            None,
+        )
+        .await
+        .to_setup_mandate_failed_response()?;
+
+        let status = match authorize_router_data.status {
+            enums::AttemptStatus::Authorized => {
+                if let Ok(types::PaymentsResponseData::TransactionResponse {
+                    resource_id: types::ResponseId::ConnectorTransactionId(connector_transaction_id),
+                    connector_metadata,
+                    ..
+                }) = &authorize_router_data.response
+                {
+                    let cancel_data = types::PaymentsCancelData {
+                        amount: Some(verification_amount),
+                        currency: Some(self.request.currency),
+                        connector_transaction_id: connector_transaction_id.clone(),
+                        cancellation_reason: Some("card_verification".to_string()),
+                        connector_meta: connector_metadata.clone(),
+                        browser_info: self.request.browser_info.clone(),
+                        metadata: self.request.metadata.clone(),
+                    };
+                    void_verification_authorization(
+                        state,
+                        connector,
+                        call_connector_action,
+                        &authorize_router_data,
+                        cancel_data,
+                    )
+                    .await;
+                }
+                enums::AttemptStatus::Charged
+            }
+            status => status,
+        };
+
+        let mut resp = helpers::router_data_type_conversion::<_, api::SetupMandate, _, _, _, _>(
+            authorize_router_data.clone(),
+            self.request.clone(),
+            authorize_router_data.response,
+        );
+        resp.status = status;
+        Ok(resp)
+    }
+
     async fn update_mandate_flow(
         self,
         state: &AppState,
@@ -413,6 +541,48 @@ impl types::SetupMandateRouterData {
     }
 }
 
+async fn void_verification_authorization(
+    state: &AppState,
+    connector: &api::ConnectorData,
+    call_connector_action: payments::CallConnectorAction,
+    authorize_router_data: &types::PaymentsAuthorizeRouterData,
+    cancel_data: types::PaymentsCancelData,
+) {
+    let void_router_data = helpers::router_data_type_conversion::<_, api::Void, _, _, _, _>(
+        authorize_router_data.clone(),
+        cancel_data,
+        authorize_router_data.response.clone(),
+    );
+    let void_integration: services::BoxedConnectorIntegration<
+        '_,
+        api::Void,
+        types::PaymentsCancelData,
+        types::PaymentsResponseData,
+    > = connector.connector.get_connector_integration();
+
+    match services::execute_connector_processing_step(
+        state,
+        void_integration,
+        &void_router_data,
+        call_connector_action,
+        None,
+    )
+    .await
+    {
+        Ok(void_router_data) if void_router_data.status == enums::AttemptStatus::Voided => {}
+        Ok(void_router_data) => logger::warn!(
+            status = ?void_router_data.status,
+            payment_id = %void_router_data.payment_id,
+            "Card verification authorization was not voided by the connector"
+        ),
+        Err(error) => logger::error!(
+            ?error,
+            payment_id = %void_router_data.payment_id,
+            "Failed to void the card verification authorization"
+        ),
+    }
+}
+
 impl mandate::MandateBehaviour for types::SetupMandateRequestData {
     fn get_amount(&self) -> i64 {
         0
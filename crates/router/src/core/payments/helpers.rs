@@ -29,11 +29,15 @@ use super::{
     CustomerDetails, PaymentData,
 };
 use crate::{
-    configs::settings::{ConnectorRequestReferenceIdConfig, Server, TempLockerEnableConfig},
+    configs::settings::{
+        ConnectorRequestReferenceIdConfig, MaintenanceMode, Server, TempLockerEnableConfig,
+    },
     connector,
     consts::{self, BASE64_ENGINE},
     core::{
+        cards_info,
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        mandate::limits as mandate_limits,
         payment_methods::{cards, vault, PaymentMethodRetrieve},
         payments,
         pm_auth::retrieve_payment_method_from_auth_service,
@@ -380,6 +384,8 @@ pub async fn get_domain_address_for_payments(
                 .cloned()
                 .async_lift(|inner| types::encrypt_optional(inner.map(|inner| inner.expose()), key))
                 .await?,
+            normalized_address: None,
+            address_validation_status: None,
         })
     }
     .await
@@ -525,11 +531,20 @@ pub async fn get_token_for_recurring_mandate(
         };
         mandate.payment_method_id.clone()
     };
+    let request_amount: i64 = req.amount.get_required_value("amount")?.into();
     verify_mandate_details(
-        req.amount.get_required_value("amount")?.into(),
+        request_amount,
         req.currency.get_required_value("currency")?,
         mandate.clone(),
     )?;
+    mandate_limits::validate_mandate_limits(
+        state,
+        merchant_account,
+        merchant_key_store,
+        &mandate,
+        request_amount,
+    )
+    .await?;
 
     let payment_method = db
         .find_payment_method(payment_method_id.as_str())
@@ -845,6 +860,18 @@ fn validate_new_mandate_request(
         Some(api_models::payments::MandateType::MultiUse(details)) => details,
         _ => None,
     };
+    if mandate_details
+        .as_ref()
+        .is_some_and(|md| md.max_amount_per_period.is_some() != md.limit_period.is_some())
+    {
+        Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+            message: "`mandate_data.mandate_type.{multi_use|single_use}.max_amount_per_period` \
+                      and `mandate_data.mandate_type.{multi_use|single_use}.limit_period` must \
+                      be provided together"
+                .into()
+        }))?
+    }
+
     mandate_details.and_then(|md| md.start_date.zip(md.end_date)).map(|(start_date, end_date)|
         utils::when (start_date >= end_date, || {
         Err(report!(errors::ApiErrorResponse::PreconditionFailed {
@@ -1253,6 +1280,22 @@ pub fn validate_customer_details_in_request(
     Ok(())
 }
 
+/// Validates the number of the customer document, if passed, against the format of its type
+pub fn validate_customer_document(
+    document: Option<&api_models::payments::CustomerDocumentDetails>,
+) -> CustomResult<(), errors::ApiErrorResponse> {
+    document.map_or(Ok(()), |document| {
+        common_utils::validation::validate_customer_document(
+            document.document_type,
+            document.document_number.peek(),
+        )
+        .map_err(|error| errors::ApiErrorResponse::InvalidRequestData {
+            message: error.to_string(),
+        })
+        .into_report()
+    })
+}
+
 /// Get the customer details from customer field if present
 /// or from the individual fields in `PaymentsRequest`
 #[instrument(skip_all)]
@@ -1289,12 +1332,18 @@ pub fn get_customer_details_from_request(
         .and_then(|customer_details| customer_details.phone_country_code.clone())
         .or(request.phone_country_code.clone());
 
+    let customer_document = request
+        .customer
+        .as_ref()
+        .and_then(|customer_details| customer_details.document.clone());
+
     CustomerDetails {
         customer_id,
         name: customer_name,
         email: customer_email,
         phone: customer_phone,
         phone_country_code: customer_phone_code,
+        document: customer_document,
     }
 }
 
@@ -1343,6 +1392,7 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, Ctx>(
                         | request_customer_details.name.is_some()
                         | request_customer_details.phone.is_some()
                         | request_customer_details.phone_country_code.is_some()
+                        | request_customer_details.document.is_some()
                     {
                         let key = key_store.key.get_inner().peek();
                         let customer_update = async {
@@ -1373,6 +1423,16 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, Ctx>(
                                 connector_customer: None,
                                 metadata: None,
                                 address_id: None,
+                                document_type: request_customer_details
+                                    .document
+                                    .as_ref()
+                                    .map(|document| document.document_type),
+                                document_number: request_customer_details
+                                    .document
+                                    .clone()
+                                    .map(|document| document.document_number)
+                                    .async_lift(|inner| types::encrypt_optional(inner, key))
+                                    .await?,
                             })
                         }
                         .await
@@ -1421,6 +1481,16 @@ pub async fn create_customer_if_not_exist<'a, F: Clone, R, Ctx>(
                             connector_customer: None,
                             address_id: None,
                             default_payment_method_id: None,
+                            document_type: request_customer_details
+                                .document
+                                .as_ref()
+                                .map(|document| document.document_type),
+                            document_number: request_customer_details
+                                .document
+                                .clone()
+                                .map(|document| document.document_number)
+                                .async_lift(|inner| types::encrypt_optional(inner, key))
+                                .await?,
                         })
                     }
                     .await
@@ -1826,8 +1896,10 @@ pub async fn store_payment_method_data_in_vault(
         &state.conf.temp_locker_enable_config,
         payment_attempt.connector.clone(),
         payment_method,
-    ) || payment_intent.request_external_three_ds_authentication == Some(true)
-    {
+    ) || payments::three_ds_decision::is_external_authentication_requested(
+        payment_intent,
+        payment_attempt,
+    ) {
         let parent_payment_method_token = store_in_vault_and_generate_ppmt(
             state,
             payment_method_data,
@@ -2161,14 +2233,19 @@ pub(super) async fn filter_by_constraints(
     db: &dyn StorageInterface,
     constraints: &api::PaymentListConstraints,
     merchant_id: &str,
+    profile_id: Option<String>,
     storage_scheme: storage_enums::MerchantStorageScheme,
 ) -> CustomResult<Vec<PaymentIntent>, errors::DataStorageError> {
+    let mut fetch_constraints: data_models::payments::payment_intent::PaymentIntentFetchConstraints =
+        constraints.clone().into();
+    if let data_models::payments::payment_intent::PaymentIntentFetchConstraints::List(params) =
+        &mut fetch_constraints
+    {
+        params.profile_id = profile_id;
+    }
+
     let result = db
-        .filter_payment_intent_by_constraints(
-            merchant_id,
-            &constraints.clone().into(),
-            storage_scheme,
-        )
+        .filter_payment_intent_by_constraints(merchant_id, &fetch_constraints, storage_scheme)
         .await?;
     Ok(result)
 }
@@ -2315,11 +2392,41 @@ pub async fn make_ephemeral_key(
     Ok(services::ApplicationResponse::Json(ek))
 }
 
+/// Creates an ephemeral key for a customer of the merchant, failing if the customer does not exist.
+pub async fn create_customer_ephemeral_key(
+    state: AppState,
+    customer_id: String,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+) -> errors::RouterResponse<ephemeral_key::EphemeralKey> {
+    state
+        .store
+        .find_customer_by_customer_id_merchant_id(
+            &customer_id,
+            &merchant_account.merchant_id,
+            &key_store,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::CustomerNotFound)?;
+
+    make_ephemeral_key(state, customer_id, merchant_account.merchant_id).await
+}
+
 pub async fn delete_ephemeral_key(
     state: AppState,
     ek_id: String,
+    merchant_id: String,
 ) -> errors::RouterResponse<ephemeral_key::EphemeralKey> {
     let db = state.store.as_ref();
+    let ek = db
+        .get_ephemeral_key(&ek_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::InvalidEphemeralKey)?;
+    // Ephemeral keys of other merchants are reported as not existing
+    if ek.merchant_id != merchant_id {
+        return Err(report!(errors::ApiErrorResponse::InvalidEphemeralKey));
+    }
+
     let ek = db
         .delete_ephemeral_key(&ek_id)
         .await
@@ -2482,7 +2589,10 @@ pub fn generate_mandate(
                             .set_mandate_amount(Some(data.amount))
                             .set_mandate_currency(Some(data.currency))
                             .set_start_date(data.start_date)
-                            .set_end_date(data.end_date),
+                            .set_end_date(data.end_date)
+                            .set_max_amount_per_charge(data.max_amount_per_charge)
+                            .set_max_amount_per_period(data.max_amount_per_period)
+                            .set_limit_period(data.limit_period),
                         // .set_metadata(data.metadata),
                         // we are storing PaymentMethodData in metadata of mandate
                         None => &mut new_mandate,
@@ -2850,6 +2960,25 @@ mod tests {
         let req_cs = Some("1".to_string());
         assert!(authenticate_client_secret(req_cs.as_ref(), &payment_intent).is_err())
     }
+
+    #[test]
+    fn test_payment_method_chosen_on_the_client_is_selected_by_the_customer() {
+        assert_eq!(
+            get_payment_method_selection(None, services::AuthFlow::Client),
+            storage_enums::PaymentMethodSelection::Customer
+        );
+        assert_eq!(
+            get_payment_method_selection(None, services::AuthFlow::Merchant),
+            storage_enums::PaymentMethodSelection::Merchant
+        );
+        assert_eq!(
+            get_payment_method_selection(
+                Some(storage_enums::PaymentMethod::Card),
+                services::AuthFlow::Client
+            ),
+            storage_enums::PaymentMethodSelection::Merchant
+        );
+    }
 }
 
 // This function will be removed after moving this functionality to server_wrap and using cache instead of config
@@ -3101,6 +3230,7 @@ pub fn get_attempt_type(
                     | enums::AttemptStatus::AuthenticationPending
                     | enums::AttemptStatus::AuthenticationSuccessful
                     | enums::AttemptStatus::Authorized
+                    | enums::AttemptStatus::PartiallyAuthorized
                     | enums::AttemptStatus::Charged
                     | enums::AttemptStatus::Authorizing
                     | enums::AttemptStatus::CodInitiated
@@ -3280,6 +3410,22 @@ impl AttemptType {
             // New payment method billing address can be passed for a retry
             payment_method_billing_address_id: None,
             fingerprint_id: None,
+            card_network: None,
+            card_type: None,
+            card_issuing_country: None,
+            // 3DS is decided again for the new attempt
+            three_ds_decision: None,
+            sca_exemption: None,
+            card_fingerprint: None,
+            // The payment method is selected again for the new attempt
+            payment_method_selection: None,
+            settlement_currency: None,
+            settlement_amount: None,
+            fx_rate: None,
+            avs_result_code: None,
+            avs_result: None,
+            cvv_result_code: None,
+            cvv_result: None,
         }
     }
 
@@ -3353,6 +3499,7 @@ pub fn is_manual_retry_allowed(
             | enums::AttemptStatus::AuthenticationPending
             | enums::AttemptStatus::AuthenticationSuccessful
             | enums::AttemptStatus::Authorized
+            | enums::AttemptStatus::PartiallyAuthorized
             | enums::AttemptStatus::Charged
             | enums::AttemptStatus::Authorizing
             | enums::AttemptStatus::CodInitiated
@@ -3423,10 +3570,34 @@ mod test {
     }
 }
 
+/// Card network, type and issuing country of the card used for a payment attempt, these are
+/// stored as separate fields of the attempt so that payments can be filtered and grouped on them
+#[derive(Debug, Clone, Default)]
+pub struct AttemptCardInfo {
+    pub card_network: Option<String>,
+    pub card_type: Option<String>,
+    pub card_issuing_country: Option<String>,
+}
+
+impl AttemptCardInfo {
+    pub fn from_additional_payment_data(
+        additional_payment_data: Option<&api_models::payments::AdditionalPaymentData>,
+    ) -> Self {
+        match additional_payment_data {
+            Some(api_models::payments::AdditionalPaymentData::Card(card_info)) => Self {
+                card_network: card_info.card_network.as_ref().map(ToString::to_string),
+                card_type: card_info.card_type.clone(),
+                card_issuing_country: card_info.card_issuing_country.clone(),
+            },
+            _ => Self::default(),
+        }
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn get_additional_payment_data(
     pm_data: &api_models::payments::PaymentMethodData,
-    db: &dyn StorageInterface,
+    state: &AppState,
 ) -> api_models::payments::AdditionalPaymentData {
     match pm_data {
         api_models::payments::PaymentMethodData::Card(card_data) => {
@@ -3461,13 +3632,9 @@ pub async fn get_additional_payment_data(
                 let card_info = card_isin
                     .clone()
                     .async_and_then(|card_isin| async move {
-                        db.get_card_info(&card_isin)
-                            .await
-                            .map_err(|error| services::logger::warn!(card_info_error=?error))
-                            .ok()
+                        cards_info::bin_lookup::get_card_bin_info(state, &card_isin).await
                     })
                     .await
-                    .flatten()
                     .map(|card_info| {
                         api_models::payments::AdditionalPaymentData::Card(Box::new(
                             api_models::payments::AdditionalCardInfo {
@@ -3842,6 +4009,19 @@ pub fn get_key_params_for_surcharge_details(
     }
 }
 
+/// Provides who selected the payment method of an attempt being confirmed. The customer selects the
+/// payment method when the payment was created without one, and is confirmed from the client
+/// through the payment link or the SDK.
+pub fn get_payment_method_selection(
+    stored_payment_method: Option<storage_enums::PaymentMethod>,
+    auth_flow: services::AuthFlow,
+) -> storage_enums::PaymentMethodSelection {
+    match (stored_payment_method, auth_flow) {
+        (None, services::AuthFlow::Client) => storage_enums::PaymentMethodSelection::Customer,
+        _ => storage_enums::PaymentMethodSelection::Merchant,
+    }
+}
+
 pub fn validate_payment_link_request(
     confirm: Option<bool>,
 ) -> Result<(), errors::ApiErrorResponse> {
@@ -3935,6 +4115,37 @@ pub fn validate_session_expiry(session_expiry: u32) -> Result<(), errors::ApiErr
     }
 }
 
+/// Rejects the creation of payments while the maintenance mode is enabled, unless the merchant is
+/// allowlisted
+pub fn validate_maintenance_mode(
+    maintenance_mode: &MaintenanceMode,
+    merchant_id: &str,
+) -> Result<(), errors::ApiErrorResponse> {
+    if maintenance_mode.enabled && !maintenance_mode.merchant_allowlist.contains(merchant_id) {
+        Err(errors::ApiErrorResponse::MaintenanceModeEnabled)
+    } else {
+        Ok(())
+    }
+}
+
+/// The merchant initiated transaction exemption can be requested only for payments made without
+/// the customer being present
+pub fn validate_sca_exemption(
+    sca_exemption: Option<api_enums::ScaExemptionType>,
+    off_session: Option<bool>,
+) -> Result<(), errors::ApiErrorResponse> {
+    match sca_exemption {
+        Some(api_enums::ScaExemptionType::MerchantInitiatedTransaction)
+            if off_session != Some(true) =>
+        {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "sca_exemption merchant_initiated_transaction can be requested only for off_session payments".to_string(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
 pub fn add_connector_response_to_additional_payment_data(
     additional_payment_data: api_models::payments::AdditionalPaymentData,
     connector_response_payment_method_data: core_types::AdditionalPaymentMethodConnectorResponse,
@@ -0,0 +1,207 @@
+//! Near real time payment activity.
+//!
+//! Payment attempts entering the processing, requires customer action and succeeded statuses are
+//! counted in Redis per merchant, in one minute buckets. The counts over the most recent buckets
+//! are served to the dashboard without querying the analytics store, which lags behind the
+//! payments by the ingestion delay.
+
+#[cfg(feature = "olap")]
+use api_models::analytics::payments::LivePaymentActivityResponse;
+#[cfg(feature = "olap")]
+use error_stack::ResultExt;
+
+#[cfg(feature = "olap")]
+use crate::{
+    core::errors::{self, RouterResponse},
+    services::ApplicationResponse,
+    types::domain,
+};
+use crate::{
+    core::payments as payments_oss, logger, types::storage::enums as storage_enums, AppState,
+};
+
+const BUCKET_SIZE_IN_SECS: i64 = 60;
+const WINDOW_SIZE_IN_BUCKETS: i64 = 5;
+
+/// Buckets outlive the window so that the oldest bucket of the window is complete when read
+const BUCKET_TTL_IN_SECS: i64 =
+    BUCKET_SIZE_IN_SECS.saturating_mul(WINDOW_SIZE_IN_BUCKETS.saturating_add(1));
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, strum::Display)]
+#[strum(serialize_all = "snake_case")]
+enum ActivityCategory {
+    Processing,
+    RequiresCustomerAction,
+    Succeeded,
+}
+
+fn get_activity_category(status: storage_enums::AttemptStatus) -> Option<ActivityCategory> {
+    match status {
+        storage_enums::AttemptStatus::Pending
+        | storage_enums::AttemptStatus::Authorizing
+        | storage_enums::AttemptStatus::CodInitiated => Some(ActivityCategory::Processing),
+        storage_enums::AttemptStatus::AuthenticationPending
+        | storage_enums::AttemptStatus::DeviceDataCollectionPending => {
+            Some(ActivityCategory::RequiresCustomerAction)
+        }
+        storage_enums::AttemptStatus::Charged | storage_enums::AttemptStatus::PartialCharged => {
+            Some(ActivityCategory::Succeeded)
+        }
+        _ => None,
+    }
+}
+
+/// Category an attempt entered, attempts whose status did not change category are not counted
+/// again
+fn get_category_transition(
+    previous_status: storage_enums::AttemptStatus,
+    current_status: storage_enums::AttemptStatus,
+) -> Option<ActivityCategory> {
+    get_activity_category(current_status)
+        .filter(|category| get_activity_category(previous_status) != Some(*category))
+}
+
+fn get_bucket(time: time::PrimitiveDateTime) -> i64 {
+    time.assume_utc()
+        .unix_timestamp()
+        .checked_div(BUCKET_SIZE_IN_SECS)
+        .unwrap_or_default()
+}
+
+fn get_activity_key(merchant_id: &str, bucket: i64) -> String {
+    format!("live_payment_activity_{merchant_id}_{bucket}")
+}
+
+/// Records the status the payment attempt transitioned into, failures are only logged as the
+/// counts are not critical to the payment
+pub async fn record_status_transition<F: Clone>(
+    state: &AppState,
+    payment_data: &payments_oss::PaymentData<F>,
+    previous_status: storage_enums::AttemptStatus,
+) {
+    let Some(category) =
+        get_category_transition(previous_status, payment_data.payment_attempt.status)
+    else {
+        return;
+    };
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(err) => {
+            logger::error!("Failed to get redis connection: {err:?}");
+            return;
+        }
+    };
+
+    let key = get_activity_key(
+        &payment_data.payment_attempt.merchant_id,
+        get_bucket(common_utils::date_time::now()),
+    );
+
+    if let Err(err) = redis_conn
+        .increment_fields_in_hash(&key, &[(category, 1)], Some(BUCKET_TTL_IN_SECS))
+        .await
+    {
+        logger::error!("Failed to record live payment activity: {err:?}");
+    }
+}
+
+/// Provides the number of payments which entered each category over the most recent window
+#[cfg(feature = "olap")]
+pub async fn get_live_payment_activity(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<LivePaymentActivityResponse> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let current_bucket = get_bucket(common_utils::date_time::now());
+    let first_bucket = current_bucket.saturating_sub(WINDOW_SIZE_IN_BUCKETS.saturating_sub(1));
+    let fields = [
+        ActivityCategory::Processing,
+        ActivityCategory::RequiresCustomerAction,
+        ActivityCategory::Succeeded,
+    ]
+    .iter()
+    .map(ToString::to_string)
+    .collect::<Vec<_>>();
+
+    let bucket_counts =
+        futures::future::try_join_all((first_bucket..=current_bucket).map(|bucket| {
+            let key = get_activity_key(&merchant_account.merchant_id, bucket);
+            let fields = fields.clone();
+            let redis_conn = &redis_conn;
+            async move {
+                redis_conn
+                    .get_multiple_hash_fields::<i64>(&key, fields)
+                    .await
+            }
+        }))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch live payment activity")?;
+
+    let activity = bucket_counts.into_iter().fold(
+        LivePaymentActivityResponse {
+            window_in_secs: BUCKET_SIZE_IN_SECS.saturating_mul(WINDOW_SIZE_IN_BUCKETS),
+            ..Default::default()
+        },
+        |activity, counts| match counts.as_slice() {
+            [processing, requires_customer_action, succeeded] => LivePaymentActivityResponse {
+                processing: activity.processing.saturating_add(processing.unwrap_or(0)),
+                requires_customer_action: activity
+                    .requires_customer_action
+                    .saturating_add(requires_customer_action.unwrap_or(0)),
+                succeeded: activity.succeeded.saturating_add(succeeded.unwrap_or(0)),
+                window_in_secs: activity.window_in_secs,
+            },
+            _ => activity,
+        },
+    );
+
+    Ok(ApplicationResponse::Json(activity))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_only_transitions_into_a_category_are_counted() {
+        assert_eq!(
+            get_category_transition(
+                storage_enums::AttemptStatus::Started,
+                storage_enums::AttemptStatus::Pending
+            ),
+            Some(ActivityCategory::Processing)
+        );
+        assert_eq!(
+            get_category_transition(
+                storage_enums::AttemptStatus::Pending,
+                storage_enums::AttemptStatus::Authorizing
+            ),
+            None
+        );
+        assert_eq!(
+            get_category_transition(
+                storage_enums::AttemptStatus::AuthenticationPending,
+                storage_enums::AttemptStatus::Charged
+            ),
+            Some(ActivityCategory::Succeeded)
+        );
+        assert_eq!(
+            get_category_transition(
+                storage_enums::AttemptStatus::Pending,
+                storage_enums::AttemptStatus::Failure
+            ),
+            None
+        );
+        assert_eq!(
+            ActivityCategory::RequiresCustomerAction.to_string(),
+            "requires_customer_action"
+        );
+    }
+}
@@ -0,0 +1,231 @@
+//! Manual review of payments.
+//!
+//! Merchants configure rules matching payment attempts on their amount, currency, payment method
+//! and the connector decided by routing. The capture of an attempt matching any rule is held after
+//! authorization, and the payment moves to `requires_merchant_action` until it is approved or
+//! rejected by the merchant, capturing or voiding the authorization respectively.
+
+use api_models::manual_review_rules::{ManualReviewRule, ManualReviewRules};
+use common_utils::ext_traits::StringExt;
+use data_models::payments::{
+    payment_attempt::PaymentAttemptUpdate, payment_intent::PaymentIntentUpdate,
+};
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payments::{is_operation_confirm, PaymentData},
+    },
+    db::StorageInterface,
+    logger,
+    routes::AppState,
+    types::{api, domain, storage::enums as storage_enums},
+};
+
+/// Provides the identifier of the config holding the merchant's manual review rules
+#[inline(always)]
+pub fn get_manual_review_rules_key(merchant_id: &str) -> String {
+    format!("manual_review_rules_{merchant_id}")
+}
+
+pub async fn get_manual_review_rules(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<ManualReviewRules> {
+    let config = db
+        .find_config_by_key_unwrap_or(
+            &get_manual_review_rules_key(merchant_id),
+            Some(r#"{"rules":[]}"#.to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the manual review rules")?;
+
+    config
+        .config
+        .parse_struct("ManualReviewRules")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the manual review rules")
+}
+
+/// Holds the capture of the attempt being confirmed when it matches any manual review rule of the
+/// merchant, returns whether the capture is held
+#[instrument(skip_all)]
+pub async fn hold_capture_for_review_if_required<F: Clone, Op: std::fmt::Debug>(
+    state: &AppState,
+    operation: &Op,
+    merchant_account: &domain::MerchantAccount,
+    payment_data: &mut PaymentData<F>,
+    connector_call_type: &api::ConnectorCallType,
+) -> RouterResult<bool> {
+    if !is_operation_confirm(operation) || payment_data.confirm != Some(true) {
+        return Ok(false);
+    }
+
+    let connector = match connector_call_type {
+        api::ConnectorCallType::PreDetermined(connector) => connector.connector_name,
+        api::ConnectorCallType::Retryable(connectors) => match connectors.first() {
+            Some(connector) => connector.connector_name,
+            None => return Ok(false),
+        },
+        api::ConnectorCallType::SessionMultiple(_) => return Ok(false),
+    };
+
+    // Attempts captured in multiple parts are left to the merchant
+    if !matches!(
+        payment_data.payment_attempt.capture_method,
+        None | Some(storage_enums::CaptureMethod::Automatic)
+            | Some(storage_enums::CaptureMethod::Scheduled)
+            | Some(storage_enums::CaptureMethod::Manual)
+    ) {
+        return Ok(false);
+    }
+
+    let rules = get_manual_review_rules(&*state.store, &merchant_account.merchant_id)
+        .await?
+        .rules;
+
+    let matched_rule = rules.iter().find(|rule| {
+        is_rule_matching(
+            rule,
+            payment_data.payment_attempt.amount,
+            payment_data.currency,
+            payment_data.payment_attempt.payment_method,
+            connector,
+        )
+    });
+
+    match matched_rule {
+        Some(rule) => {
+            logger::info!(
+                manual_review_rule = %rule.name,
+                "Payment attempt matched a manual review rule"
+            );
+            payment_data.payment_attempt.capture_method =
+                Some(storage_enums::CaptureMethod::Manual);
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Moves the payment held for a manual review to `requires_merchant_action` once its attempt is
+/// authorized
+#[instrument(skip_all)]
+pub async fn move_payment_to_review<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_data: &mut PaymentData<F>,
+) -> RouterResult<()> {
+    if payment_data.payment_intent.status != storage_enums::IntentStatus::RequiresCapture {
+        return Ok(());
+    }
+
+    let db = &*state.store;
+    let storage_scheme = merchant_account.storage_scheme;
+
+    payment_data.payment_attempt = db
+        .update_payment_attempt_with_attempt_id(
+            payment_data.payment_attempt.clone(),
+            PaymentAttemptUpdate::StatusUpdate {
+                status: storage_enums::AttemptStatus::Unresolved,
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    payment_data.payment_intent = db
+        .update_payment_intent(
+            payment_data.payment_intent.clone(),
+            PaymentIntentUpdate::PGStatusUpdate {
+                status: storage_enums::IntentStatus::RequiresMerchantAction,
+                incremental_authorization_allowed: None,
+                updated_by: storage_scheme.to_string(),
+            },
+            storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+
+    Ok(())
+}
+
+fn is_rule_matching(
+    rule: &ManualReviewRule,
+    amount: i64,
+    currency: storage_enums::Currency,
+    payment_method: Option<storage_enums::PaymentMethod>,
+    connector: api_models::enums::Connector,
+) -> bool {
+    rule.min_amount
+        .map_or(true, |min_amount| amount >= min_amount)
+        && rule
+            .max_amount
+            .map_or(true, |max_amount| amount <= max_amount)
+        && rule
+            .currencies
+            .as_ref()
+            .map_or(true, |currencies| currencies.contains(&currency))
+        && rule
+            .payment_methods
+            .as_ref()
+            .map_or(true, |payment_methods| {
+                payment_method
+                    .is_some_and(|payment_method| payment_methods.contains(&payment_method))
+            })
+        && rule
+            .connectors
+            .as_ref()
+            .map_or(true, |connectors| connectors.contains(&connector))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_manual_review_rule_matching() {
+        let rule = ManualReviewRule {
+            name: "high_value_cards".to_string(),
+            min_amount: Some(10000),
+            max_amount: None,
+            currencies: Some(vec![storage_enums::Currency::USD]),
+            payment_methods: Some(vec![storage_enums::PaymentMethod::Card]),
+            connectors: None,
+        };
+        let connector = api_models::enums::Connector::Stripe;
+
+        assert!(is_rule_matching(
+            &rule,
+            15000,
+            storage_enums::Currency::USD,
+            Some(storage_enums::PaymentMethod::Card),
+            connector,
+        ));
+        assert!(!is_rule_matching(
+            &rule,
+            5000,
+            storage_enums::Currency::USD,
+            Some(storage_enums::PaymentMethod::Card),
+            connector,
+        ));
+        assert!(!is_rule_matching(
+            &rule,
+            15000,
+            storage_enums::Currency::EUR,
+            Some(storage_enums::PaymentMethod::Card),
+            connector,
+        ));
+        assert!(!is_rule_matching(
+            &rule,
+            15000,
+            storage_enums::Currency::USD,
+            None,
+            connector,
+        ));
+    }
+}
@@ -160,6 +160,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             payment_method_info: None,
             force_sync: None,
             refunds: vec![],
+            include_amount_refundable: false,
             disputes: vec![],
             attempts: None,
             sessions_token: vec![],
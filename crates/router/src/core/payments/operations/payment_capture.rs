@@ -72,6 +72,13 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             .amount_to_capture
             .update_value(request.amount_to_capture);
 
+        // Partial authorizations are captured for the authorized amount unless specified
+        if payment_attempt.status == enums::AttemptStatus::PartiallyAuthorized {
+            payment_attempt.amount_to_capture = request
+                .amount_to_capture
+                .or(Some(payment_attempt.amount_capturable));
+        }
+
         let capture_method = payment_attempt
             .capture_method
             .get_required_value("capture_method")?;
@@ -213,6 +220,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             payment_method_data: None,
             payment_method_info: None,
             refunds: vec![],
+            include_amount_refundable: false,
             disputes: vec![],
             attempts: None,
             sessions_token: vec![],
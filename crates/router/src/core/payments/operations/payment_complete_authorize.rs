@@ -273,6 +273,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             payment_method_info: None,
             force_sync: None,
             refunds: vec![],
+            include_amount_refundable: false,
             disputes: vec![],
             attempts: None,
             sessions_token: vec![],
@@ -299,6 +300,10 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             email: request.email.clone(),
             phone: request.phone.clone(),
             phone_country_code: request.phone_country_code.clone(),
+            document: request
+                .customer
+                .as_ref()
+                .and_then(|customer_details| customer_details.document.clone()),
         });
 
         let get_trackers_response = operations::GetTrackerResponse {
@@ -17,8 +17,8 @@ use crate::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         payment_methods::PaymentMethodRetrieve,
         payments::{
-            self, helpers, operations, populate_surcharge_details, CustomerDetails, PaymentAddress,
-            PaymentData,
+            self, helpers, operations, populate_surcharge_details, required_fields,
+            three_ds_decision, velocity, CustomerDetails, PaymentAddress, PaymentData,
         },
         utils as core_utils,
     },
@@ -61,17 +61,19 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
 
         // Stage 1
+        // The active attempt is fetched along with the intent, in a single query for merchants
+        // whose payments are not stored in the KV store
         let store = state.clone().store;
         let m_merchant_id = merchant_id.clone();
         let payment_intent_fut = tokio::spawn(
             async move {
                 store
-                    .find_payment_intent_by_payment_id_merchant_id(
+                    .find_payment_intent_with_active_attempt_by_payment_id_merchant_id(
                         &payment_id,
                         m_merchant_id.as_str(),
                         storage_scheme,
                     )
-                    .map(|x| x.change_context(errors::ApiErrorResponse::PaymentNotFound))
+                    .map(|x| x.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))
                     .await
             }
             .in_current_span(),
@@ -98,7 +100,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
         );
 
         // Parallel calls - level 0
-        let (mut payment_intent, mandate_details) = tokio::try_join!(
+        let ((mut payment_intent, mut payment_attempt), mandate_details) = tokio::try_join!(
             utils::flatten_join_error(payment_intent_fut),
             utils::flatten_join_error(mandate_details_fut)
         )?;
@@ -150,7 +152,6 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
         let customer_details = helpers::get_customer_details_from_request(request);
 
         // Stage 2
-        let attempt_id = payment_intent.active_attempt.get_id();
         let profile_id = payment_intent
             .profile_id
             .clone()
@@ -173,26 +174,6 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 .await
         });
 
-        let store = state.store.clone();
-
-        let m_payment_id = payment_intent.payment_id.clone();
-        let m_merchant_id = merchant_id.clone();
-
-        let payment_attempt_fut = tokio::spawn(
-            async move {
-                store
-                    .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
-                        m_payment_id.as_str(),
-                        m_merchant_id.as_str(),
-                        attempt_id.as_str(),
-                        storage_scheme,
-                    )
-                    .map(|x| x.to_not_found_response(errors::ApiErrorResponse::PaymentNotFound))
-                    .await
-            }
-            .in_current_span(),
-        );
-
         let m_merchant_id = merchant_id.clone();
         let m_request_shipping = request.shipping.clone();
         let m_payment_intent_shipping_address_id = payment_intent.shipping_address_id.clone();
@@ -279,14 +260,12 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 | api_models::enums::IntentStatus::RequiresConfirmation => {
                     // Normal payment
                     // Parallel calls - level 1
-                    let (payment_attempt, shipping_address, billing_address, business_profile, _) =
-                        tokio::try_join!(
-                            utils::flatten_join_error(payment_attempt_fut),
-                            utils::flatten_join_error(shipping_address_fut),
-                            utils::flatten_join_error(billing_address_fut),
-                            utils::flatten_join_error(business_profile_fut),
-                            utils::flatten_join_error(config_update_fut)
-                        )?;
+                    let (shipping_address, billing_address, business_profile, _) = tokio::try_join!(
+                        utils::flatten_join_error(shipping_address_fut),
+                        utils::flatten_join_error(billing_address_fut),
+                        utils::flatten_join_error(business_profile_fut),
+                        utils::flatten_join_error(config_update_fut)
+                    )?;
 
                     (
                         payment_attempt,
@@ -297,14 +276,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                 }
                 _ => {
                     // Retry payment
-                    let (
-                        mut payment_attempt,
-                        shipping_address,
-                        billing_address,
-                        business_profile,
-                        _,
-                    ) = tokio::try_join!(
-                        utils::flatten_join_error(payment_attempt_fut),
+                    let (shipping_address, billing_address, business_profile, _) = tokio::try_join!(
                         utils::flatten_join_error(shipping_address_fut),
                         utils::flatten_join_error(billing_address_fut),
                         utils::flatten_join_error(business_profile_fut),
@@ -389,23 +361,41 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             &token,
         )?;
 
-        let (token_data, payment_method_info) = if let Some(token) = token.clone() {
-            let token_data = helpers::retrieve_payment_token_data(
-                state,
-                token,
-                payment_method.or(payment_attempt.payment_method),
-            )
-            .await?;
-
-            let payment_method_info =
-                helpers::retrieve_payment_method_from_db_with_token_data(state, &token_data)
-                    .await?;
-
-            (Some(token_data), payment_method_info)
-        } else {
-            (None, None)
-        };
+        // The token data and the saved payment method are not needed until the payment data is
+        // constructed, they are fetched along with the other level 2 calls
+        let m_state = state.clone();
+        let m_token = token.clone();
+        let m_payment_method = payment_method.or(payment_attempt.payment_method);
+        let token_data_fut = tokio::spawn(
+            async move {
+                match m_token {
+                    Some(token) => {
+                        let token_data =
+                            helpers::retrieve_payment_token_data(&m_state, token, m_payment_method)
+                                .await?;
+
+                        let payment_method_info =
+                            helpers::retrieve_payment_method_from_db_with_token_data(
+                                &m_state,
+                                &token_data,
+                            )
+                            .await?;
+
+                        Ok::<_, error_stack::Report<errors::ApiErrorResponse>>((
+                            Some(token_data),
+                            payment_method_info,
+                        ))
+                    }
+                    None => Ok((None, None)),
+                }
+            }
+            .in_current_span(),
+        );
 
+        payment_attempt.payment_method_selection = Some(helpers::get_payment_method_selection(
+            payment_attempt.payment_method,
+            auth_flow,
+        ));
         payment_attempt.payment_method = payment_method.or(payment_attempt.payment_method);
         payment_attempt.browser_info = browser_info;
         payment_attempt.payment_method_type =
@@ -417,6 +407,12 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
 
         payment_attempt.capture_method = request.capture_method.or(payment_attempt.capture_method);
 
+        helpers::validate_sca_exemption(
+            request.sca_exemption,
+            request.off_session.or(payment_intent.off_session),
+        )?;
+        payment_attempt.sca_exemption = request.sca_exemption.or(payment_attempt.sca_exemption);
+
         currency = payment_attempt.currency.get_required_value("currency")?;
         amount = payment_attempt.get_total_amount().into();
 
@@ -496,12 +492,12 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             .as_ref()
             .map(|pmd| pmd.payment_method_data.clone());
 
-        let store = state.clone().store;
+        let m_state = state.clone();
 
         let additional_pm_data_fut = tokio::spawn(async move {
             Ok(n_request_payment_method_data
                 .async_map(|payment_method_data| async move {
-                    helpers::get_additional_payment_data(&payment_method_data, store.as_ref()).await
+                    helpers::get_additional_payment_data(&payment_method_data, &m_state).await
                 })
                 .await)
         });
@@ -539,10 +535,40 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             .in_current_span(),
         );
 
+        let m_state = state.clone();
+        let m_merchant_id = merchant_id.clone();
+        let m_authentication_id = payment_attempt.authentication_id.clone();
+        let authentication_fut = tokio::spawn(
+            async move {
+                m_authentication_id
+                    .async_map(|authentication_id| async move {
+                        m_state
+                            .store
+                            .find_authentication_by_merchant_id_authentication_id(
+                                m_merchant_id,
+                                authentication_id.clone(),
+                            )
+                            .await
+                            .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
+                            .attach_printable_lazy(|| format!("Error while fetching authentication record with authentication_id {authentication_id}"))
+                    })
+                    .await
+                    .transpose()
+            }
+            .in_current_span(),
+        );
+
         // Parallel calls - level 2
-        let (additional_pm_data, payment_method_billing) = tokio::try_join!(
+        let (
+            additional_pm_data,
+            payment_method_billing,
+            (token_data, payment_method_info),
+            authentication,
+        ) = tokio::try_join!(
             utils::flatten_join_error(additional_pm_data_fut),
             utils::flatten_join_error(payment_method_billing_future),
+            utils::flatten_join_error(token_data_fut),
+            utils::flatten_join_error(authentication_fut),
         )?;
 
         let payment_method_data_after_card_bin_call = request
@@ -554,19 +580,6 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
                     .payment_method_data
                     .apply_additional_payment_data(additional_payment_data)
             });
-        let authentication = payment_attempt.authentication_id.as_ref().async_map(|authentication_id| async move {
-            state
-                .store
-                .find_authentication_by_merchant_id_authentication_id(
-                    merchant_id.to_string(),
-                    authentication_id.clone(),
-                )
-                .await
-                .to_not_found_response(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable_lazy(|| format!("Error while fetching authentication record with authentication_id {authentication_id}"))
-        }).await
-        .transpose()?;
-
         payment_attempt.payment_method_billing_address_id = payment_method_billing
             .as_ref()
             .map(|payment_method_billing| payment_method_billing.address_id.clone());
@@ -594,6 +607,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             payment_method_info,
             force_sync: None,
             refunds: vec![],
+            include_amount_refundable: false,
             disputes: vec![],
             attempts: None,
             sessions_token: vec![],
@@ -745,10 +759,11 @@ impl<F: Clone + Send, Ctx: PaymentMethodRetrieve> Domain<F, api::PaymentsRequest
         let authentication = payment_data.authentication.clone();
         let is_authentication_type_3ds = payment_data.payment_attempt.authentication_type
             == Some(common_enums::AuthenticationType::ThreeDs);
-        let separate_authentication_requested = payment_data
-            .payment_intent
-            .request_external_three_ds_authentication
-            .unwrap_or(false);
+        let separate_authentication_requested =
+            three_ds_decision::is_external_authentication_requested(
+                &payment_data.payment_intent,
+                &payment_data.payment_attempt,
+            );
         let connector_supports_separate_authn =
             authentication::utils::get_connector_name_if_separate_authn_supported(
                 connector_call_type,
@@ -834,6 +849,7 @@ impl<F: Clone + Send, Ctx: PaymentMethodRetrieve> Domain<F, api::PaymentsRequest
                 if let Some(card_number) = card_number {
                     authentication::perform_pre_authentication(
                         state,
+                        key_store,
                         authentication_connector_name,
                         authentication::types::PreAuthenthenticationFlowInput::PaymentAuthNFlow {
                             payment_data,
@@ -862,6 +878,25 @@ impl<F: Clone + Send, Ctx: PaymentMethodRetrieve> Domain<F, api::PaymentsRequest
     ) -> CustomResult<bool, errors::ApiErrorResponse> {
         blocklist_utils::validate_data_for_blocklist(state, merchant_account, payment_data).await
     }
+
+    #[instrument(skip_all)]
+    async fn guard_payment_against_velocity_limits<'a>(
+        &'a self,
+        state: &AppState,
+        merchant_account: &domain::MerchantAccount,
+        payment_data: &mut PaymentData<F>,
+    ) -> CustomResult<(), errors::ApiErrorResponse> {
+        velocity::perform_velocity_checks(state, merchant_account, payment_data).await
+    }
+
+    fn validate_required_fields(
+        &self,
+        state: &AppState,
+        connector: &api::ConnectorData,
+        payment_data: &PaymentData<F>,
+    ) -> CustomResult<(), errors::ApiErrorResponse> {
+        required_fields::validate_required_fields(state, connector, payment_data)
+    }
 }
 
 #[async_trait]
@@ -959,9 +994,12 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
             .payment_method_data
             .as_ref()
             .async_map(|payment_method_data| async {
-                helpers::get_additional_payment_data(payment_method_data, &*state.store).await
+                helpers::get_additional_payment_data(payment_method_data, state).await
             })
-            .await
+            .await;
+        let card_info =
+            helpers::AttemptCardInfo::from_additional_payment_data(additional_pm_data.as_ref());
+        let additional_pm_data = additional_pm_data
             .as_ref()
             .map(Encode::encode_to_value)
             .transpose()
@@ -970,6 +1008,10 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
 
         let business_sub_label = payment_data.payment_attempt.business_sub_label.clone();
         let authentication_type = payment_data.payment_attempt.authentication_type;
+        let three_ds_decision = payment_data.payment_attempt.three_ds_decision;
+        let sca_exemption = payment_data.payment_attempt.sca_exemption;
+        let card_fingerprint = payment_data.payment_attempt.card_fingerprint.clone();
+        let payment_method_selection = payment_data.payment_attempt.payment_method_selection;
 
         let (shipping_address_id, billing_address_id, payment_method_billing_address_id) = (
             payment_data.payment_intent.shipping_address_id.clone(),
@@ -1065,6 +1107,13 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                         payment_method_billing_address_id,
                         fingerprint_id: m_fingerprint_id,
                         payment_method_id: m_payment_method_id,
+                        card_network: card_info.card_network,
+                        card_type: card_info.card_type,
+                        card_issuing_country: card_info.card_issuing_country,
+                        three_ds_decision,
+                        sca_exemption,
+                        card_fingerprint,
+                        payment_method_selection,
                     },
                     storage_scheme,
                 )
@@ -1179,6 +1228,12 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve> ValidateRequest<F, api::Paymen
         operations::ValidateResult<'a>,
     )> {
         helpers::validate_customer_details_in_request(request)?;
+        helpers::validate_customer_document(
+            request
+                .customer
+                .as_ref()
+                .and_then(|customer_details| customer_details.document.as_ref()),
+        )?;
 
         let request_merchant_id = request.merchant_id.as_deref();
         helpers::validate_merchant_id(&merchant_account.merchant_id, request_merchant_id)
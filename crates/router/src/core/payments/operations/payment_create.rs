@@ -21,7 +21,10 @@ use crate::{
         errors::{self, CustomResult, RouterResult, StorageErrorExt},
         payment_link,
         payment_methods::PaymentMethodRetrieve,
-        payments::{self, helpers, operations, CustomerDetails, PaymentAddress, PaymentData},
+        payments::{
+            self, address_normalization, helpers, operations, CustomerDetails, PaymentAddress,
+            PaymentData,
+        },
         utils as core_utils,
     },
     db::StorageInterface,
@@ -61,6 +64,11 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
         _auth_flow: services::AuthFlow,
         _payment_confirm_source: Option<common_enums::PaymentSource>,
     ) -> RouterResult<operations::GetTrackerResponse<'a, F, api::PaymentsRequest, Ctx>> {
+        helpers::validate_maintenance_mode(
+            &state.conf.maintenance_mode,
+            &merchant_account.merchant_id,
+        )?;
+
         let db = &*state.store;
         let ephemeral_key = Self::get_ephemeral_key(request, state, merchant_account).await;
         let merchant_id = &merchant_account.merchant_id;
@@ -69,6 +77,8 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
 
         let money @ (amount, currency) = payments_create_request_validation(request)?;
 
+        helpers::validate_sca_exemption(request.sca_exemption, request.off_session)?;
+
         let payment_id = payment_id
             .get_payment_intent_id()
             .change_context(errors::ApiErrorResponse::PaymentNotFound)?;
@@ -134,33 +144,66 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
         )
         .await?;
 
-        let billing_address = helpers::create_or_find_address_for_payment_by_request(
-            db,
-            request.billing.as_ref(),
-            None,
-            merchant_id,
-            customer_details.customer_id.as_ref(),
-            merchant_key_store,
-            &payment_id,
-            merchant_account.storage_scheme,
-        )
-        .await?;
+        // The billing addresses of card payments are normalized, for better address verification
+        // results and billing data for 3DS authentication
+        let should_normalize_billing_address = state.conf.address_normalization.get_inner().enabled
+            && payment_method == Some(enums::PaymentMethod::Card);
 
-        let payment_method_billing_address =
-            helpers::create_or_find_address_for_payment_by_request(
-                db,
-                request
-                    .payment_method_data
-                    .as_ref()
-                    .and_then(|pmd| pmd.billing.as_ref()),
-                None,
-                merchant_id,
-                customer_details.customer_id.as_ref(),
-                merchant_key_store,
-                &payment_id,
-                merchant_account.storage_scheme,
+        let payment_method_billing = request
+            .payment_method_data
+            .as_ref()
+            .and_then(|pmd| pmd.billing.as_ref());
+
+        let (billing_address, payment_method_billing_address) = if should_normalize_billing_address
+        {
+            (
+                address_normalization::create_normalized_address_for_payment(
+                    state,
+                    request.billing.as_ref(),
+                    merchant_id,
+                    customer_details.customer_id.as_ref(),
+                    merchant_key_store,
+                    &payment_id,
+                    merchant_account.storage_scheme,
+                )
+                .await?,
+                address_normalization::create_normalized_address_for_payment(
+                    state,
+                    payment_method_billing,
+                    merchant_id,
+                    customer_details.customer_id.as_ref(),
+                    merchant_key_store,
+                    &payment_id,
+                    merchant_account.storage_scheme,
+                )
+                .await?,
             )
-            .await?;
+        } else {
+            (
+                helpers::create_or_find_address_for_payment_by_request(
+                    db,
+                    request.billing.as_ref(),
+                    None,
+                    merchant_id,
+                    customer_details.customer_id.as_ref(),
+                    merchant_key_store,
+                    &payment_id,
+                    merchant_account.storage_scheme,
+                )
+                .await?,
+                helpers::create_or_find_address_for_payment_by_request(
+                    db,
+                    payment_method_billing,
+                    None,
+                    merchant_id,
+                    customer_details.customer_id.as_ref(),
+                    merchant_key_store,
+                    &payment_id,
+                    merchant_account.storage_scheme,
+                )
+                .await?,
+            )
+        };
 
         let browser_info = request
             .browser_info
@@ -400,6 +443,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             payment_method_data: payment_method_data_after_card_bin_call,
             payment_method_info: None,
             refunds: vec![],
+            include_amount_refundable: false,
             disputes: vec![],
             attempts: None,
             force_sync: None,
@@ -563,6 +607,9 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
             .clone();
         let authorized_amount = payment_data.payment_attempt.amount;
         let merchant_connector_id = payment_data.payment_attempt.merchant_connector_id.clone();
+        let three_ds_decision = payment_data.payment_attempt.three_ds_decision;
+        let sca_exemption = payment_data.payment_attempt.sca_exemption;
+        let card_fingerprint = payment_data.payment_attempt.card_fingerprint.clone();
 
         let surcharge_amount = payment_data
             .surcharge_details
@@ -589,6 +636,9 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                     tax_amount,
                     updated_by: storage_scheme.to_string(),
                     merchant_connector_id,
+                    three_ds_decision,
+                    sca_exemption,
+                    card_fingerprint,
                 },
                 storage_scheme,
             )
@@ -635,6 +685,12 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve> ValidateRequest<F, api::Paymen
         operations::ValidateResult<'a>,
     )> {
         helpers::validate_customer_details_in_request(request)?;
+        helpers::validate_customer_document(
+            request
+                .customer
+                .as_ref()
+                .and_then(|customer_details| customer_details.document.as_ref()),
+        )?;
         if let Some(session_expiry) = &request.session_expiry {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
@@ -742,11 +798,13 @@ impl PaymentCreate {
             .async_map(|payment_method_data| async {
                 helpers::get_additional_payment_data(
                     &payment_method_data.payment_method_data,
-                    &*state.store,
+                    state,
                 )
                 .await
             })
             .await;
+        let card_info =
+            helpers::AttemptCardInfo::from_additional_payment_data(additional_pm_data.as_ref());
         let additional_pm_data_value = additional_pm_data
             .as_ref()
             .map(Encode::encode_to_value)
@@ -844,6 +902,25 @@ impl PaymentCreate {
                 fingerprint_id: None,
                 authentication_connector: None,
                 authentication_id: None,
+                card_network: card_info.card_network,
+                card_type: card_info.card_type,
+                card_issuing_country: card_info.card_issuing_country,
+                three_ds_decision: None,
+                sca_exemption: request.sca_exemption,
+                card_fingerprint: None,
+                // Payments are created by the merchant, a payment confirmed on creation has the
+                // payment method passed by the merchant
+                payment_method_selection: request
+                    .confirm
+                    .unwrap_or(false)
+                    .then_some(enums::PaymentMethodSelection::Merchant),
+                settlement_currency: None,
+                settlement_amount: None,
+                fx_rate: None,
+                avs_result_code: None,
+                avs_result: None,
+                cvv_result_code: None,
+                cvv_result: None,
             },
             additional_pm_data,
         ))
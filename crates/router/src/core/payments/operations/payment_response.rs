@@ -23,10 +23,13 @@ use crate::{
                 self as payments_helpers,
                 update_additional_payment_data_with_connector_response_pm_data,
             },
+            live_activity,
+            routing::{connector_health, success_rate},
             types::MultipleCaptureData,
             PaymentData,
         },
         utils as core_utils,
+        webhooks::notification_channels,
     },
     routes::{metrics, AppState},
     types::{
@@ -459,6 +462,23 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                     connector_response.additional_payment_method_data.clone()
                 }),
         )?;
+    let settlement_data = router_data
+        .connector_response
+        .as_ref()
+        .and_then(|connector_response| connector_response.settlement_data.clone());
+    let card_verification_data = router_data
+        .connector_response
+        .as_ref()
+        .and_then(|connector_response| connector_response.card_verification_data.clone())
+        .unwrap_or_default();
+    let partially_authorized_amount = router_data
+        .connector_response
+        .as_ref()
+        .and_then(|connector_response| connector_response.partially_authorized_amount)
+        .filter(|partially_authorized_amount| {
+            router_data.status == enums::AttemptStatus::Authorized
+                && *partially_authorized_amount < payment_data.payment_attempt.get_total_amount()
+        });
 
     router_data.payment_method_status.and_then(|status| {
         payment_data
@@ -566,6 +586,13 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                 },
                 _ => router_data.get_attempt_status_for_db_update(&payment_data),
             };
+            // Authorizations approved by the issuer for less than the amount of the payment
+            let updated_attempt_status = match updated_attempt_status {
+                enums::AttemptStatus::Authorized if partially_authorized_amount.is_some() => {
+                    enums::AttemptStatus::PartiallyAuthorized
+                }
+                status => status,
+            };
             match payments_response {
                 types::PaymentsResponseData::PreProcessingResponse {
                     pre_processing_id,
@@ -674,9 +701,12 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                                 connector: None,
                                 connector_transaction_id: connector_transaction_id.clone(),
                                 authentication_type: None,
-                                amount_capturable: router_data
-                                    .request
-                                    .get_amount_capturable(&payment_data, updated_attempt_status),
+                                amount_capturable: partially_authorized_amount.or_else(|| {
+                                    router_data.request.get_amount_capturable(
+                                        &payment_data,
+                                        updated_attempt_status,
+                                    )
+                                }),
                                 payment_method_id: Some(payment_method_id),
                                 mandate_id: payment_data
                                     .mandate_id
@@ -694,6 +724,18 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
                                 authentication_data,
                                 encoded_data,
                                 payment_method_data: additional_payment_method_data,
+                                settlement_currency: settlement_data
+                                    .as_ref()
+                                    .map(|settlement_data| settlement_data.settlement_currency),
+                                settlement_amount: settlement_data
+                                    .as_ref()
+                                    .and_then(|settlement_data| settlement_data.settlement_amount),
+                                fx_rate: settlement_data
+                                    .and_then(|settlement_data| settlement_data.fx_rate),
+                                avs_result_code: card_verification_data.avs_result_code,
+                                avs_result: card_verification_data.avs_result,
+                                cvv_result_code: card_verification_data.cvv_result_code,
+                                cvv_result: card_verification_data.cvv_result,
                             }),
                         ),
                     };
@@ -779,6 +821,7 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
     let m_db = state.clone().store;
     let m_payment_attempt_update = payment_attempt_update.clone();
     let m_payment_attempt = payment_attempt.clone();
+    let previous_attempt_status = payment_attempt.status;
 
     let payment_attempt = payment_attempt_update
         .map(|payment_attempt_update| {
@@ -814,6 +857,26 @@ async fn payment_response_update_tracker<F: Clone, T: types::Capturable>(
 
     payment_data.payment_attempt = payment_attempt;
 
+    if let Some(is_successful) = success_rate::get_authorization_outcome(
+        previous_attempt_status,
+        payment_data.payment_attempt.status,
+    ) {
+        success_rate::record_payment_outcome(state, &payment_data, is_successful).await;
+
+        if !is_successful {
+            notification_channels::send_payment_failed_alert(state, &payment_data).await;
+        }
+    }
+
+    connector_health::record_connector_response(
+        state,
+        &payment_data,
+        router_data.response.as_ref().err(),
+    )
+    .await;
+
+    live_activity::record_status_transition(state, &payment_data, previous_attempt_status).await;
+
     payment_data.authentication = match payment_data.authentication {
         Some(authentication) => {
             let authentication_update = storage::AuthenticationUpdate::PostAuthorizationUpdate {
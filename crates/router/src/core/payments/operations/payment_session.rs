@@ -124,6 +124,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             email: None,
             phone: None,
             phone_country_code: None,
+            document: None,
         };
 
         let creds_identifier = request
@@ -181,6 +182,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             payment_method_info: None,
             force_sync: None,
             refunds: vec![],
+            include_amount_refundable: false,
             disputes: vec![],
             attempts: None,
             sessions_token: vec![],
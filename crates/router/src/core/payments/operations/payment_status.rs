@@ -444,6 +444,7 @@ async fn get_tracker_for_sync<
         ),
         payment_attempt,
         refunds,
+        include_amount_refundable: true,
         disputes,
         attempts,
         sessions_token: vec![],
@@ -408,6 +408,7 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve>
             payment_method_info: None,
             force_sync: None,
             refunds: vec![],
+            include_amount_refundable: false,
             disputes: vec![],
             attempts: None,
             sessions_token: vec![],
@@ -565,9 +566,12 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
             .payment_method_data
             .as_ref()
             .async_map(|payment_method_data| async {
-                helpers::get_additional_payment_data(payment_method_data, &*state.store).await
+                helpers::get_additional_payment_data(payment_method_data, state).await
             })
-            .await
+            .await;
+        let card_info =
+            helpers::AttemptCardInfo::from_additional_payment_data(additional_pm_data.as_ref());
+        let additional_pm_data = additional_pm_data
             .as_ref()
             .map(Encode::encode_to_value)
             .transpose()
@@ -609,6 +613,9 @@ impl<F: Clone, Ctx: PaymentMethodRetrieve>
                     surcharge_amount,
                     tax_amount,
                     fingerprint_id: None,
+                    card_network: card_info.card_network,
+                    card_type: card_info.card_type,
+                    card_issuing_country: card_info.card_issuing_country,
                     updated_by: storage_scheme.to_string(),
                 },
                 storage_scheme,
@@ -708,6 +715,12 @@ impl<F: Send + Clone, Ctx: PaymentMethodRetrieve> ValidateRequest<F, api::Paymen
         operations::ValidateResult<'a>,
     )> {
         helpers::validate_customer_details_in_request(request)?;
+        helpers::validate_customer_document(
+            request
+                .customer
+                .as_ref()
+                .and_then(|customer_details| customer_details.document.as_ref()),
+        )?;
         if let Some(session_expiry) = &request.session_expiry {
             helpers::validate_session_expiry(session_expiry.to_owned())?;
         }
@@ -0,0 +1,57 @@
+//! Partial authorizations of card payments.
+//!
+//! Issuers of some prepaid and debit cards approve the available balance of the card when it does
+//! not cover the amount of the payment. The amount approved by the issuer is recorded as the
+//! capturable amount of the partially authorized attempt, which the merchant can capture or void.
+//! Profiles can instead be configured to void partial authorizations, which is done by the same
+//! process tracker task voiding authorizations rejected by the card verification policy.
+
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::RouterResult,
+        payments::{card_verification, PaymentData},
+    },
+    logger,
+    routes::AppState,
+    types::{
+        domain,
+        storage::{self, enums as storage_enums},
+    },
+};
+
+/// Schedules the void of the partial authorization of the attempt when the profile is configured
+/// to void partial authorizations, returns whether the void is scheduled
+#[instrument(skip_all)]
+pub async fn schedule_void_if_configured<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_data: &PaymentData<F>,
+    business_profile: &storage::business_profile::BusinessProfile,
+) -> RouterResult<bool> {
+    if payment_data.payment_attempt.status != storage_enums::AttemptStatus::PartiallyAuthorized {
+        return Ok(false);
+    }
+
+    match business_profile
+        .partial_authorization_action
+        .unwrap_or_default()
+    {
+        storage_enums::PartialAuthorizationAction::AllowCapture => Ok(false),
+        storage_enums::PartialAuthorizationAction::Void => {
+            card_verification::schedule_authorization_void(
+                state,
+                merchant_account,
+                &payment_data.payment_intent.payment_id,
+                "Voided as the payment was partially authorized".to_string(),
+            )
+            .await?;
+            logger::info!(
+                amount_capturable = payment_data.payment_attempt.amount_capturable,
+                "Scheduled the void of the partial authorization"
+            );
+            Ok(true)
+        }
+    }
+}
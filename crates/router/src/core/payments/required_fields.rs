@@ -0,0 +1,192 @@
+//! Required fields of connectors.
+//!
+//! The fields required by each connector for a payment method type are declared in the
+//! `required_fields` configuration, as fields required for all payments and fields required only
+//! for mandate or non mandate payments. The same fields are listed by the payment methods list for
+//! SDKs to collect them upfront. When the validation of required fields is enabled, payments are
+//! validated against the fields before being sent to the connector, all the missing fields being
+//! returned at once instead of the connector failing to build its request on a missing field.
+
+use std::collections::HashMap;
+
+use api_models::payment_methods::RequiredFieldInfo;
+use error_stack::{report, ResultExt};
+use serde::Serialize;
+
+use crate::{
+    configs::settings,
+    core::{
+        errors::{self, RouterResult},
+        payments::PaymentData,
+    },
+    routes::AppState,
+    types::api::{self, enums as api_enums},
+};
+
+/// Details of the payment against which the required fields are validated, with the same paths
+/// as in the payments request
+#[derive(Serialize)]
+struct RequiredFieldsContext<'a> {
+    payment_method_data: Option<&'a api::PaymentMethodData>,
+    billing: Option<&'a api::Address>,
+    shipping: Option<&'a api::Address>,
+    email: Option<&'a common_utils::pii::Email>,
+}
+
+/// Fields required by the connector for the payment method type, including the fields required
+/// for mandate or non mandate payments when the payment is known to be one of them
+pub fn get_required_fields(
+    required_fields: &settings::RequiredFields,
+    payment_method: api_enums::PaymentMethod,
+    payment_method_type: api_enums::PaymentMethodType,
+    connector: api_enums::Connector,
+    is_mandate: Option<bool>,
+) -> Option<HashMap<String, RequiredFieldInfo>> {
+    let connector_fields = required_fields
+        .0
+        .get(&payment_method)?
+        .0
+        .get(&payment_method_type)?
+        .fields
+        .get(&connector)?;
+
+    let mut fields = connector_fields.common.clone();
+    match is_mandate {
+        Some(true) => fields.extend(connector_fields.mandate.clone()),
+        Some(false) => fields.extend(connector_fields.non_mandate.clone()),
+        None => (),
+    }
+
+    Some(fields)
+}
+
+/// Rejects the payment when fields required by the connector are missing, listing all of them
+pub fn validate_required_fields<F: Clone>(
+    state: &AppState,
+    connector: &api::ConnectorData,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<()> {
+    // Recurring payments are made with the details stored with the mandate
+    if !state.conf.required_fields_validation.enabled || payment_data.mandate_id.is_some() {
+        return Ok(());
+    }
+    let (Some(payment_method), Some(payment_method_type)) = (
+        payment_data.payment_attempt.payment_method,
+        payment_data.payment_attempt.payment_method_type,
+    ) else {
+        return Ok(());
+    };
+    let Some(required_fields) = get_required_fields(
+        &state.conf.required_fields,
+        payment_method,
+        payment_method_type,
+        connector.connector_name,
+        Some(payment_data.setup_mandate.is_some()),
+    ) else {
+        return Ok(());
+    };
+
+    let context = serde_json::to_value(RequiredFieldsContext {
+        payment_method_data: payment_data.payment_method_data.as_ref(),
+        billing: payment_data.address.get_payment_method_billing(),
+        shipping: payment_data.address.get_shipping(),
+        email: payment_data.email.as_ref(),
+    })
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to serialize the payment details for required fields validation")?;
+    // The payment method data of saved payment methods is fetched from the locker, without the
+    // details not stored with the payment method
+    let validate_payment_method_data =
+        payment_data.token.is_none() && payment_data.payment_method_data.is_some();
+
+    let mut missing_fields = required_fields
+        .into_keys()
+        .filter(|field| is_validated_field(field, validate_payment_method_data))
+        .filter(|field| !is_field_present(&context, field))
+        .collect::<Vec<_>>();
+
+    if missing_fields.is_empty() {
+        return Ok(());
+    }
+    missing_fields.sort();
+
+    Err(report!(errors::ApiErrorResponse::MissingRequiredFields {
+        field_names: missing_fields,
+    }))
+    .attach_printable_lazy(|| {
+        format!(
+            "Missing fields required by the connector {}",
+            connector.connector_name
+        )
+    })
+}
+
+/// Only the fields of the payment details known to the router are validated
+fn is_validated_field(field: &str, validate_payment_method_data: bool) -> bool {
+    match field.split('.').next() {
+        Some("payment_method_data") => validate_payment_method_data,
+        Some("billing" | "shipping" | "email") => true,
+        _ => false,
+    }
+}
+
+fn is_field_present(context: &serde_json::Value, field: &str) -> bool {
+    field
+        .split('.')
+        .try_fold(context, |value, key| value.get(key))
+        .is_some_and(|value| match value {
+            serde_json::Value::Null => false,
+            serde_json::Value::String(value) => !value.trim().is_empty(),
+            _ => true,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_field_present() {
+        let context = serde_json::json!({
+            "payment_method_data": {
+                "card": {
+                    "card_number": "4242424242424242",
+                    "card_holder_name": "",
+                }
+            },
+            "billing": {
+                "address": {
+                    "first_name": "John",
+                    "last_name": null,
+                }
+            },
+        });
+
+        assert!(is_field_present(
+            &context,
+            "payment_method_data.card.card_number"
+        ));
+        assert!(!is_field_present(
+            &context,
+            "payment_method_data.card.card_holder_name"
+        ));
+        assert!(is_field_present(&context, "billing.address.first_name"));
+        assert!(!is_field_present(&context, "billing.address.last_name"));
+        assert!(!is_field_present(&context, "billing.address.country"));
+        assert!(!is_field_present(&context, "email"));
+    }
+
+    #[test]
+    fn test_is_validated_field() {
+        assert!(is_validated_field(
+            "payment_method_data.card.card_cvc",
+            true
+        ));
+        assert!(!is_validated_field(
+            "payment_method_data.card.card_cvc",
+            false
+        ));
+        assert!(is_validated_field("billing.address.first_name", false));
+        assert!(!is_validated_field("name", true));
+    }
+}
@@ -349,6 +349,15 @@ where
                 .clone()
                 .and_then(|connector_response| connector_response.additional_payment_method_data),
         )?;
+    let settlement_data = router_data
+        .connector_response
+        .as_ref()
+        .and_then(|connector_response| connector_response.settlement_data.clone());
+    let card_verification_data = router_data
+        .connector_response
+        .as_ref()
+        .and_then(|connector_response| connector_response.card_verification_data.clone())
+        .unwrap_or_default();
 
     match router_data.response {
         Ok(types::PaymentsResponseData::TransactionResponse {
@@ -402,6 +411,17 @@ where
                     unified_code: None,
                     unified_message: None,
                     payment_method_data: additional_payment_method_data,
+                    settlement_currency: settlement_data
+                        .as_ref()
+                        .map(|settlement_data| settlement_data.settlement_currency),
+                    settlement_amount: settlement_data
+                        .as_ref()
+                        .and_then(|settlement_data| settlement_data.settlement_amount),
+                    fx_rate: settlement_data.and_then(|settlement_data| settlement_data.fx_rate),
+                    avs_result_code: card_verification_data.avs_result_code,
+                    avs_result: card_verification_data.avs_result,
+                    cvv_result_code: card_verification_data.cvv_result_code,
+                    cvv_result: card_verification_data.cvv_result,
                 },
                 storage_scheme,
             )
@@ -503,6 +523,13 @@ pub fn make_new_payment_attempt(
         mandate_id: old_payment_attempt.mandate_id,
         browser_info: old_payment_attempt.browser_info,
         payment_token: old_payment_attempt.payment_token,
+        card_network: old_payment_attempt.card_network,
+        card_type: old_payment_attempt.card_type,
+        card_issuing_country: old_payment_attempt.card_issuing_country,
+        three_ds_decision: old_payment_attempt.three_ds_decision,
+        sca_exemption: old_payment_attempt.sca_exemption,
+        card_fingerprint: old_payment_attempt.card_fingerprint,
+        payment_method_selection: old_payment_attempt.payment_method_selection,
 
         created_at,
         modified_at,
@@ -546,6 +573,7 @@ impl<F: Send + Clone + Sync, FData: Send + Sync>
                 | storage_enums::AttemptStatus::AuthenticationPending
                 | storage_enums::AttemptStatus::AuthenticationSuccessful
                 | storage_enums::AttemptStatus::Authorized
+                | storage_enums::AttemptStatus::PartiallyAuthorized
                 | storage_enums::AttemptStatus::Charged
                 | storage_enums::AttemptStatus::Authorizing
                 | storage_enums::AttemptStatus::CodInitiated
@@ -1,3 +1,5 @@
+pub mod connector_health;
+pub mod success_rate;
 mod transformers;
 
 use std::{
@@ -38,7 +40,7 @@ use crate::core::payouts;
 use crate::utils::StringExt;
 use crate::{
     core::{
-        errors, errors as oss_errors, payments as payments_oss,
+        errors, errors as oss_errors, metrics, payments as payments_oss,
         routing::{self, helpers as routing_helpers},
     },
     logger,
@@ -156,6 +158,46 @@ pub fn make_dsl_input_for_payouts(
     })
 }
 
+pub fn make_dsl_input_for_simulation(
+    payment: &api_models::routing::RoutingSimulationPaymentData,
+) -> dsl_inputs::BackendInput {
+    let mandate_data = dsl_inputs::MandateData {
+        mandate_acceptance_type: None,
+        mandate_type: None,
+        payment_type: Some(euclid_enums::PaymentType::NonMandate),
+    };
+
+    let payment_method_input = dsl_inputs::PaymentMethodInput {
+        payment_method: payment.payment_method,
+        payment_method_type: payment.payment_method_type,
+        card_network: payment.card_network.clone(),
+    };
+
+    let payment_input = dsl_inputs::PaymentInput {
+        amount: payment.amount,
+        currency: payment.currency,
+        authentication_type: payment.authentication_type,
+        card_bin: payment.card_bin.clone(),
+        capture_method: payment.capture_method,
+        business_country: payment
+            .business_country
+            .map(api_enums::Country::from_alpha2),
+        billing_country: payment.billing_country.map(api_enums::Country::from_alpha2),
+        business_label: payment.business_label.clone(),
+        setup_future_usage: payment.setup_future_usage,
+    };
+
+    dsl_inputs::BackendInput {
+        metadata: payment
+            .metadata
+            .clone()
+            .map(|metadata| metadata.into_iter().collect()),
+        payment: payment_input,
+        payment_method: payment_method_input,
+        mandate: mandate_data,
+    }
+}
+
 pub fn make_dsl_input<F>(
     payment_data: &payments_oss::PaymentData<F>,
 ) -> RoutingResult<dsl_inputs::BackendInput>
@@ -314,13 +356,17 @@ pub async fn perform_static_routing_v1<F: Clone>(
         .change_context(errors::RoutingError::CacheMiss)
         .attach_printable("Unable to retrieve cached routing algorithm even after refresh")?;
 
+    let volume_split_seed = get_volume_split_seed(transaction_data);
+
     Ok(match cached_algorithm.as_ref() {
         CachedAlgorithm::Single(conn) => vec![(**conn).clone()],
 
         CachedAlgorithm::Priority(plist) => plist.clone(),
 
-        CachedAlgorithm::VolumeSplit(splits) => perform_volume_split(splits.to_vec(), None)
-            .change_context(errors::RoutingError::ConnectorSelectionFailed)?,
+        CachedAlgorithm::VolumeSplit(splits) => {
+            perform_volume_split(splits.to_vec(), Some(volume_split_seed))
+                .change_context(errors::RoutingError::ConnectorSelectionFailed)?
+        }
 
         CachedAlgorithm::Advanced(interpreter) => {
             let backend_input = match transaction_data {
@@ -331,11 +377,23 @@ pub async fn perform_static_routing_v1<F: Clone>(
                 }
             };
 
-            execute_dsl_and_get_connector_v1(backend_input, interpreter)?
+            execute_dsl_and_get_connector_v1(backend_input, interpreter, Some(volume_split_seed))?
         }
     })
 }
 
+/// Returns the identifier used to seed volume split selection for a transaction, so that
+/// every routing attempt for the same payment or payout lands on the same connector.
+fn get_volume_split_seed<'a, F: Clone>(
+    transaction_data: &'a routing::TransactionData<'_, F>,
+) -> &'a str {
+    match transaction_data {
+        routing::TransactionData::Payment(payment_data) => &payment_data.payment_intent.payment_id,
+        #[cfg(feature = "payouts")]
+        routing::TransactionData::Payout(payout_data) => &payout_data.payout_attempt.payout_id,
+    }
+}
+
 async fn ensure_algorithm_cached_v1(
     state: &AppState,
     merchant_id: &str,
@@ -403,6 +461,7 @@ async fn ensure_algorithm_cached_v1(
 pub fn perform_straight_through_routing(
     algorithm: &routing_types::StraightThroughAlgorithm,
     creds_identifier: Option<String>,
+    volume_split_seed: Option<&str>,
 ) -> RoutingResult<(Vec<routing_types::RoutableConnectorChoice>, bool)> {
     Ok(match algorithm {
         routing_types::StraightThroughAlgorithm::Single(conn) => {
@@ -412,7 +471,7 @@ pub fn perform_straight_through_routing(
         routing_types::StraightThroughAlgorithm::Priority(conns) => (conns.clone(), true),
 
         routing_types::StraightThroughAlgorithm::VolumeSplit(splits) => (
-            perform_volume_split(splits.to_vec(), None)
+            perform_volume_split(splits.to_vec(), volume_split_seed)
                 .change_context(errors::RoutingError::ConnectorSelectionFailed)
                 .attach_printable(
                     "Volume Split connector selection error in straight through routing",
@@ -425,6 +484,7 @@ pub fn perform_straight_through_routing(
 fn execute_dsl_and_get_connector_v1(
     backend_input: dsl_inputs::BackendInput,
     interpreter: &backend::VirInterpreterBackend<ConnectorSelection>,
+    volume_split_seed: Option<&str>,
 ) -> RoutingResult<Vec<routing_types::RoutableConnectorChoice>> {
     let routing_output: routing_types::RoutingAlgorithm = interpreter
         .execute(backend_input)
@@ -435,8 +495,10 @@ fn execute_dsl_and_get_connector_v1(
     Ok(match routing_output {
         routing_types::RoutingAlgorithm::Priority(plist) => plist,
 
-        routing_types::RoutingAlgorithm::VolumeSplit(splits) => perform_volume_split(splits, None)
-            .change_context(errors::RoutingError::DslFinalConnectorSelectionFailed)?,
+        routing_types::RoutingAlgorithm::VolumeSplit(splits) => {
+            perform_volume_split(splits, volume_split_seed)
+                .change_context(errors::RoutingError::DslFinalConnectorSelectionFailed)?
+        }
 
         _ => Err(errors::RoutingError::DslIncorrectSelectionAlgorithm)
             .into_report()
@@ -444,6 +506,54 @@ fn execute_dsl_and_get_connector_v1(
     })
 }
 
+/// Executes the routing algorithm against the routing input of a hypothetical payment, returning
+/// the chosen connectors along with the name of the advanced routing rule which matched, if any
+fn perform_static_routing_simulation(
+    algorithm: routing_types::RoutingAlgorithm,
+    backend_input: dsl_inputs::BackendInput,
+) -> RoutingResult<(Vec<routing_types::RoutableConnectorChoice>, Option<String>)> {
+    Ok(match algorithm {
+        routing_types::RoutingAlgorithm::Single(conn) => (vec![*conn], None),
+
+        routing_types::RoutingAlgorithm::Priority(plist) => (plist, None),
+
+        routing_types::RoutingAlgorithm::VolumeSplit(splits) => {
+            (splits.into_iter().map(|sp| sp.connector).collect(), None)
+        }
+
+        routing_types::RoutingAlgorithm::Advanced(program) => {
+            let interpreter = backend::VirInterpreterBackend::with_program(program)
+                .into_report()
+                .change_context(errors::RoutingError::DslBackendInitError)
+                .attach_printable("Error initializing DSL interpreter backend")?;
+
+            let output = interpreter
+                .execute(backend_input)
+                .into_report()
+                .change_context(errors::RoutingError::DslExecutionError)?;
+
+            let routing_output: routing_types::RoutingAlgorithm =
+                output.connector_selection.foreign_into();
+
+            let connectors = match routing_output {
+                routing_types::RoutingAlgorithm::Priority(plist) => plist,
+
+                routing_types::RoutingAlgorithm::VolumeSplit(splits) => {
+                    splits.into_iter().map(|sp| sp.connector).collect()
+                }
+
+                _ => Err(errors::RoutingError::DslIncorrectSelectionAlgorithm)
+                    .into_report()
+                    .attach_printable(
+                        "Unsupported algorithm received as a result of static routing",
+                    )?,
+            };
+
+            (connectors, output.rule_name)
+        }
+    })
+}
+
 pub async fn refresh_routing_cache_v1(
     state: &AppState,
     key: String,
@@ -540,6 +650,16 @@ pub fn perform_volume_split(
     // Panic Safety: We have performed a `get(idx)` operation just above which will
     // ensure that the index is always present, else throw an error.
     let removed = splits.remove(idx);
+
+    metrics::ROUTING_VOLUME_SPLIT_CONNECTOR_SELECTED.add(
+        &metrics::CONTEXT,
+        1,
+        &[
+            metrics::KeyValue::new("connector", removed.connector.connector.to_string()),
+            metrics::KeyValue::new("configured_split", i64::from(removed.split)),
+        ],
+    );
+
     splits.insert(0, removed);
 
     Ok(splits.into_iter().map(|sp| sp.connector).collect())
@@ -850,6 +970,101 @@ pub async fn perform_eligibility_analysis_with_fallback<F: Clone>(
     Ok(final_selection)
 }
 
+/// Routes a hypothetical payment through static routing, eligibility analysis and fallback
+/// routing the same way a payment would be routed, without any side effects, explaining how the
+/// connector was selected
+pub async fn perform_routing_simulation(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    merchant_last_modified: i64,
+    algorithm: Option<(String, routing_types::RoutingAlgorithm)>,
+    payment: &api_models::routing::RoutingSimulationPaymentData,
+    #[cfg(any(
+        feature = "profile_specific_fallback_routing",
+        feature = "business_profile_routing"
+    ))]
+    profile_id: String,
+) -> RoutingResult<api_models::routing::RoutingSimulationResponse> {
+    let backend_input = make_dsl_input_for_simulation(payment);
+
+    let fallback_config = routing_helpers::get_merchant_default_config(
+        &*state.store,
+        #[cfg(not(feature = "profile_specific_fallback_routing"))]
+        &key_store.merchant_id,
+        #[cfg(feature = "profile_specific_fallback_routing")]
+        &profile_id,
+        &api_enums::TransactionType::Payment,
+    )
+    .await
+    .change_context(errors::RoutingError::FallbackConfigFetchFailed)?;
+
+    let (algorithm_id, algorithm_kind, matched_rule, routed_connectors) = match algorithm {
+        Some((algorithm_id, algorithm)) => {
+            let algorithm_kind = algorithm.get_kind();
+            let (routed_connectors, matched_rule) =
+                perform_static_routing_simulation(algorithm, backend_input.clone())?;
+            (
+                Some(algorithm_id),
+                Some(algorithm_kind),
+                matched_rule,
+                routed_connectors,
+            )
+        }
+        None => (None, None, None, fallback_config.clone()),
+    };
+
+    let eligible_connectors = perform_kgraph_filtering(
+        state,
+        key_store,
+        merchant_last_modified,
+        routed_connectors.clone(),
+        backend_input.clone(),
+        None,
+        #[cfg(feature = "business_profile_routing")]
+        Some(profile_id.clone()),
+        &api_enums::TransactionType::Payment,
+    )
+    .await?;
+
+    let ineligible_connectors = routed_connectors
+        .iter()
+        .filter(|&choice| !eligible_connectors.contains(choice))
+        .cloned()
+        .collect::<Vec<_>>();
+
+    let fallback_connectors = perform_kgraph_filtering(
+        state,
+        key_store,
+        merchant_last_modified,
+        fallback_config,
+        backend_input,
+        None,
+        #[cfg(feature = "business_profile_routing")]
+        Some(profile_id),
+        &api_enums::TransactionType::Payment,
+    )
+    .await?
+    .into_iter()
+    .filter(|choice| !eligible_connectors.contains(choice))
+    .collect::<Vec<_>>();
+
+    let final_connectors = eligible_connectors
+        .into_iter()
+        .chain(fallback_connectors.iter().cloned())
+        .collect::<Vec<_>>();
+
+    Ok(api_models::routing::RoutingSimulationResponse {
+        algorithm_id,
+        algorithm_kind,
+        matched_rule,
+        routed_connectors,
+        ineligible_connectors,
+        fallback_connectors,
+        selected_connector: final_connectors.first().cloned(),
+        final_connectors,
+    })
+}
+
 pub async fn perform_session_flow_routing(
     session_input: SessionFlowRoutingInput<'_>,
     transaction_type: &api_enums::TransactionType,
@@ -1045,6 +1260,7 @@ async fn perform_session_routing_for_pm_type(
                     CachedAlgorithm::Advanced(interpreter) => execute_dsl_and_get_connector_v1(
                         session_pm_input.backend_input.clone(),
                         interpreter,
+                        Some(session_pm_input.attempt_id),
                     )?,
                 }
             } else {
@@ -0,0 +1,544 @@
+//! Connector health tracking.
+//!
+//! Connector responses are aggregated in Redis per merchant, business profile and connector, in
+//! time buckets of configurable size. A connector whose rate of errors and timeouts over the most
+//! recent buckets crosses the configured threshold is marked as degraded and excluded from routing
+//! for the configured duration. Once the exclusion is over the connector receives traffic again,
+//! and is marked as recovered when its error rate is below the threshold. Merchants are notified
+//! of both transitions through outgoing webhooks, and of degraded connectors through the
+//! notification channels of the business profile.
+
+use api_models::{
+    admin::{ConnectorHealthResponse, ConnectorHealthStatus},
+    webhooks::OutgoingWebhookContent,
+};
+use error_stack::ResultExt;
+use redis_interface::{RedisConnectionPool, SetnxReply};
+
+use crate::{
+    configs::settings::ConnectorHealth,
+    consts,
+    core::{
+        errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        metrics, payments as payments_oss, webhooks as webhooks_core,
+    },
+    logger,
+    types::{api::routing as routing_types, storage::enums as storage_enums, ErrorResponse},
+    AppState,
+};
+
+const TOTAL_COUNT_FIELD: &str = "total";
+const ERROR_COUNT_FIELD: &str = "error";
+
+/// Health state of a connector persisted in Redis, connectors without a persisted state are
+/// healthy
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct ConnectorHealthState {
+    status: ConnectorHealthStatus,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    changed_at: time::PrimitiveDateTime,
+}
+
+impl ConnectorHealthState {
+    fn get_excluded_until(&self, config: &ConnectorHealth) -> Option<time::PrimitiveDateTime> {
+        (self.status == ConnectorHealthStatus::Degraded).then(|| {
+            self.changed_at
+                .saturating_add(time::Duration::seconds(config.exclusion_duration_in_secs))
+        })
+    }
+
+    fn is_excluded(&self, config: &ConnectorHealth, now: time::PrimitiveDateTime) -> bool {
+        self.get_excluded_until(config)
+            .is_some_and(|excluded_until| now < excluded_until)
+    }
+}
+
+/// Server errors and timeouts are considered as connector errors, declines are not considered as
+/// the connector did process the request
+pub fn is_connector_error(error: &ErrorResponse) -> bool {
+    error.status_code >= 500 || error.code == consts::REQUEST_TIMEOUT_ERROR_CODE
+}
+
+/// Records a response returned by the connector the payment was processed through, and updates
+/// the health status of the connector when its error rate crosses the threshold
+pub async fn record_connector_response<F: Clone>(
+    state: &AppState,
+    payment_data: &payments_oss::PaymentData<F>,
+    response_error: Option<&ErrorResponse>,
+) {
+    if !state.conf.connector_health.enabled {
+        return;
+    }
+
+    let Some(connector) = payment_data.payment_attempt.connector.as_ref() else {
+        return;
+    };
+
+    if let Err(err) = update_connector_health(
+        state,
+        &payment_data.payment_attempt.merchant_id,
+        payment_data.payment_intent.profile_id.as_deref(),
+        connector,
+        response_error.is_some_and(is_connector_error),
+    )
+    .await
+    {
+        logger::error!("Failed to update connector health: {err:?}");
+    }
+}
+
+/// Removes the connectors which are excluded from routing as they are degraded. The connectors
+/// are retained when all of them are degraded, or when their health could not be fetched.
+pub async fn filter_degraded_connectors<F: Clone>(
+    state: &AppState,
+    payment_data: &payments_oss::PaymentData<F>,
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+) -> Vec<routing_types::RoutableConnectorChoice> {
+    let config = &state.conf.connector_health;
+
+    if !config.enabled || connectors.is_empty() {
+        return connectors;
+    }
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(err) => {
+            logger::error!("Failed to get redis connection: {err:?}");
+            return connectors;
+        }
+    };
+
+    let health_states = futures::future::try_join_all(connectors.iter().map(|choice| {
+        let key = get_health_state_key(
+            &payment_data.payment_attempt.merchant_id,
+            payment_data.payment_intent.profile_id.as_deref(),
+            &choice.connector.to_string(),
+        );
+        let redis_conn = redis_conn.clone();
+        async move { get_health_state(&redis_conn, &key).await }
+    }))
+    .await;
+
+    let health_states = match health_states {
+        Ok(health_states) => health_states,
+        Err(err) => {
+            logger::error!("Failed to fetch connector health: {err:?}");
+            return connectors;
+        }
+    };
+
+    let now = common_utils::date_time::now();
+    let (healthy_connectors, degraded_connectors): (Vec<_>, Vec<_>) = connectors
+        .iter()
+        .cloned()
+        .zip(health_states)
+        .partition(|(_, health_state)| {
+            !health_state
+                .as_ref()
+                .is_some_and(|health_state| health_state.is_excluded(config, now))
+        });
+
+    if healthy_connectors.is_empty() {
+        logger::warn!("All connectors are degraded, routing without excluding them");
+        return connectors;
+    }
+
+    for (choice, _) in degraded_connectors {
+        metrics::CONNECTOR_HEALTH_EXCLUDED.add(
+            &metrics::CONTEXT,
+            1,
+            &[metrics::KeyValue::new(
+                "connector",
+                choice.connector.to_string(),
+            )],
+        );
+    }
+
+    healthy_connectors
+        .into_iter()
+        .map(|(choice, _)| choice)
+        .collect()
+}
+
+/// Provides the health of a connector over the current window
+pub async fn get_connector_health(
+    state: &AppState,
+    merchant_id: &str,
+    profile_id: Option<&str>,
+    connector: &str,
+) -> RouterResult<ConnectorHealthResponse> {
+    let config = &state.conf.connector_health;
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let health_state = get_health_state(
+        &redis_conn,
+        &get_health_state_key(merchant_id, profile_id, connector),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch connector health status")?;
+
+    let (total_count, error_count) = get_window_counts(
+        &redis_conn,
+        config,
+        merchant_id,
+        profile_id,
+        connector,
+        health_state.as_ref(),
+        common_utils::date_time::now(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch connector response counts")?;
+
+    Ok(get_connector_health_response(
+        config,
+        connector,
+        profile_id,
+        health_state.as_ref(),
+        total_count,
+        error_count,
+    ))
+}
+
+/// The identifier of the outgoing webhook event object, unique for each health status change of
+/// the connector
+pub fn get_connector_health_object_id(
+    connector: &str,
+    status_changed_at: time::PrimitiveDateTime,
+) -> String {
+    format!(
+        "{connector}_{}",
+        status_changed_at.assume_utc().unix_timestamp()
+    )
+}
+
+pub fn get_connector_from_object_id(object_id: &str) -> Option<&str> {
+    object_id
+        .rsplit_once('_')
+        .map(|(connector, _status_changed_at)| connector)
+}
+
+pub fn get_event_type(status: ConnectorHealthStatus) -> storage_enums::EventType {
+    match status {
+        ConnectorHealthStatus::Healthy => storage_enums::EventType::ConnectorRecovered,
+        ConnectorHealthStatus::Degraded => storage_enums::EventType::ConnectorDegraded,
+    }
+}
+
+async fn update_connector_health(
+    state: &AppState,
+    merchant_id: &str,
+    profile_id: Option<&str>,
+    connector: &str,
+    is_error: bool,
+) -> RouterResult<()> {
+    let config = &state.conf.connector_health;
+    let now = common_utils::date_time::now();
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let fields_to_increment = [
+        (get_count_field(connector, TOTAL_COUNT_FIELD), 1),
+        (
+            get_count_field(connector, ERROR_COUNT_FIELD),
+            i64::from(is_error),
+        ),
+    ];
+    let ttl = config
+        .bucket_size_in_secs
+        .saturating_mul(i64::from(config.window_size_in_buckets));
+
+    redis_conn
+        .increment_fields_in_hash(
+            &get_counts_key(merchant_id, profile_id, get_bucket(config, now)),
+            &fields_to_increment,
+            Some(ttl),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to record connector response")?;
+
+    let health_state_key = get_health_state_key(merchant_id, profile_id, connector);
+    let health_state = get_health_state(&redis_conn, &health_state_key)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch connector health status")?;
+
+    // The status of a degraded connector is not evaluated again until its exclusion is over
+    if health_state
+        .as_ref()
+        .is_some_and(|health_state| health_state.is_excluded(config, now))
+    {
+        return Ok(());
+    }
+
+    let (total_count, error_count) = get_window_counts(
+        &redis_conn,
+        config,
+        merchant_id,
+        profile_id,
+        connector,
+        health_state.as_ref(),
+        now,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch connector response counts")?;
+
+    if total_count < config.min_sample_size {
+        return Ok(());
+    }
+
+    let previous_status = health_state
+        .as_ref()
+        .map_or(ConnectorHealthStatus::Healthy, |health_state| {
+            health_state.status
+        });
+    let is_threshold_crossed = error_count.saturating_mul(100)
+        >= i64::from(config.error_rate_threshold_percentage).saturating_mul(total_count);
+
+    let status = match (previous_status, is_threshold_crossed) {
+        (_, true) => ConnectorHealthStatus::Degraded,
+        (ConnectorHealthStatus::Degraded, false) => ConnectorHealthStatus::Healthy,
+        (ConnectorHealthStatus::Healthy, false) => return Ok(()),
+    };
+
+    // Only one of the concurrent requests observing the threshold being crossed changes the status
+    let lock_reply = redis_conn
+        .set_key_if_not_exists_with_expiry(
+            &format!("{health_state_key}_lock"),
+            "true",
+            Some(config.bucket_size_in_secs),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to acquire connector health status lock")?;
+
+    if lock_reply != SetnxReply::KeySet {
+        return Ok(());
+    }
+
+    let health_state = ConnectorHealthState {
+        status,
+        changed_at: now,
+    };
+
+    redis_conn
+        .serialize_and_set_key(&health_state_key, &health_state)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update connector health status")?;
+
+    // A degraded connector crossing the threshold again is excluded for another period, without
+    // notifying the merchant as the status remains unchanged
+    if status == previous_status {
+        return Ok(());
+    }
+
+    let status_metric = match status {
+        ConnectorHealthStatus::Healthy => &metrics::CONNECTOR_HEALTH_RECOVERED,
+        ConnectorHealthStatus::Degraded => &metrics::CONNECTOR_HEALTH_DEGRADED,
+    };
+    status_metric.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::KeyValue::new("connector", connector.to_string())],
+    );
+
+    logger::info!(
+        %merchant_id,
+        ?profile_id,
+        %connector,
+        %status,
+        "Connector health status changed"
+    );
+
+    match profile_id {
+        Some(profile_id) => {
+            let health = get_connector_health_response(
+                config,
+                connector,
+                Some(profile_id),
+                Some(&health_state),
+                total_count,
+                error_count,
+            );
+            trigger_connector_health_webhook(state, merchant_id, profile_id, health, &health_state)
+                .await
+        }
+        None => Ok(()),
+    }
+}
+
+async fn trigger_connector_health_webhook(
+    state: &AppState,
+    merchant_id: &str,
+    profile_id: &str,
+    health: ConnectorHealthResponse,
+    health_state: &ConnectorHealthState,
+) -> RouterResult<()> {
+    let db = &*state.store;
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(merchant_id, &db.get_master_key().to_vec().into())
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MerchantAccountNotFound)?;
+
+    let business_profile = db
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_string(),
+        })?;
+
+    if health_state.status == ConnectorHealthStatus::Degraded {
+        webhooks_core::notification_channels::send_operational_alert(
+            state,
+            &business_profile,
+            webhooks_core::notification_channels::get_connector_circuit_opened_alert(
+                merchant_id,
+                &health,
+            ),
+        );
+    }
+
+    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account,
+        business_profile,
+        &key_store,
+        get_event_type(health_state.status),
+        storage_enums::EventClass::Connectors,
+        get_connector_health_object_id(&health.connector_name, health_state.changed_at),
+        storage_enums::EventObjectType::ConnectorHealthDetails,
+        OutgoingWebhookContent::ConnectorHealthDetails(Box::new(health)),
+        Some(health_state.changed_at),
+    ))
+    .await
+}
+
+fn get_connector_health_response(
+    config: &ConnectorHealth,
+    connector: &str,
+    profile_id: Option<&str>,
+    health_state: Option<&ConnectorHealthState>,
+    total_count: i64,
+    error_count: i64,
+) -> ConnectorHealthResponse {
+    ConnectorHealthResponse {
+        connector_name: connector.to_string(),
+        merchant_connector_id: None,
+        profile_id: profile_id.map(ToString::to_string),
+        status: health_state.map_or(ConnectorHealthStatus::Healthy, |health_state| {
+            health_state.status
+        }),
+        total_count,
+        error_count,
+        error_rate_percentage: error_count.saturating_mul(100).checked_div(total_count),
+        status_changed_at: health_state.map(|health_state| health_state.changed_at),
+        excluded_until: health_state
+            .and_then(|health_state| health_state.get_excluded_until(config)),
+    }
+}
+
+async fn get_health_state(
+    redis_conn: &RedisConnectionPool,
+    key: &str,
+) -> CustomResult<Option<ConnectorHealthState>, errors::RedisError> {
+    match redis_conn
+        .get_and_deserialize_key::<ConnectorHealthState>(key, "ConnectorHealthState")
+        .await
+    {
+        Ok(health_state) => Ok(Some(health_state)),
+        Err(err) if matches!(err.current_context(), errors::RedisError::NotFound) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Provides the total and error counts of the connector over the current window. Responses
+/// received before the health status last changed are not considered, so that the status is
+/// decided based on how the connector performed after the change.
+async fn get_window_counts(
+    redis_conn: &RedisConnectionPool,
+    config: &ConnectorHealth,
+    merchant_id: &str,
+    profile_id: Option<&str>,
+    connector: &str,
+    health_state: Option<&ConnectorHealthState>,
+    now: time::PrimitiveDateTime,
+) -> CustomResult<(i64, i64), errors::RedisError> {
+    let current_bucket = get_bucket(config, now);
+    let window_start_bucket =
+        current_bucket.saturating_sub(i64::from(config.window_size_in_buckets).saturating_sub(1));
+    let first_bucket = health_state.map_or(window_start_bucket, |health_state| {
+        window_start_bucket.max(get_bucket(config, health_state.changed_at).saturating_add(1))
+    });
+
+    let fields = vec![
+        get_count_field(connector, TOTAL_COUNT_FIELD),
+        get_count_field(connector, ERROR_COUNT_FIELD),
+    ];
+
+    let bucket_counts =
+        futures::future::try_join_all((first_bucket..=current_bucket).map(|bucket| {
+            let key = get_counts_key(merchant_id, profile_id, bucket);
+            let fields = fields.clone();
+            async move {
+                redis_conn
+                    .get_multiple_hash_fields::<i64>(&key, fields)
+                    .await
+            }
+        }))
+        .await?;
+
+    Ok(bucket_counts
+        .into_iter()
+        .fold(
+            (0_i64, 0_i64),
+            |(total_count, error_count), counts| match counts.as_slice() {
+                [total, error] => (
+                    total_count.saturating_add(total.unwrap_or(0)),
+                    error_count.saturating_add(error.unwrap_or(0)),
+                ),
+                _ => (total_count, error_count),
+            },
+        ))
+}
+
+fn get_bucket(config: &ConnectorHealth, time: time::PrimitiveDateTime) -> i64 {
+    time.assume_utc()
+        .unix_timestamp()
+        .checked_div(config.bucket_size_in_secs)
+        .unwrap_or_default()
+}
+
+fn get_counts_key(merchant_id: &str, profile_id: Option<&str>, bucket: i64) -> String {
+    format!(
+        "connector_health_{merchant_id}_{}_{bucket}",
+        profile_id.unwrap_or("default")
+    )
+}
+
+fn get_health_state_key(merchant_id: &str, profile_id: Option<&str>, connector: &str) -> String {
+    format!(
+        "connector_health_status_{merchant_id}_{}_{connector}",
+        profile_id.unwrap_or("default")
+    )
+}
+
+fn get_count_field(connector: &str, count: &str) -> String {
+    format!("{connector}_{count}")
+}
@@ -0,0 +1,300 @@
+//! Success rate based adaptive routing.
+//!
+//! Payment outcomes are aggregated in Redis per merchant, business profile, payment method, card
+//! network and currency, in time buckets of configurable size. When enabled, the connectors chosen
+//! by static routing are reordered by their success rate over the most recent buckets, while a
+//! percentage of payments retain the static order so that other connectors keep receiving traffic.
+
+use std::{
+    collections::hash_map,
+    hash::{Hash, Hasher},
+};
+
+use rand::{Rng, SeedableRng};
+
+use crate::{
+    configs::settings::SuccessRateRouting,
+    core::{errors, metrics, payments as payments_oss},
+    logger,
+    types::{
+        api::{self, routing as routing_types},
+        storage::enums as storage_enums,
+    },
+    AppState,
+};
+
+const TOTAL_COUNT_FIELD: &str = "total";
+const SUCCESS_COUNT_FIELD: &str = "success";
+
+/// Success rates are compared in basis points to avoid floating point arithmetic
+const SUCCESS_RATE_SCALE: i64 = 10_000;
+
+/// Success rate based routing is performed only when the global kill switch is turned on and the
+/// merchant has opted into it.
+pub async fn is_success_rate_routing_enabled(state: &AppState, merchant_id: &str) -> bool {
+    if !state.conf.success_rate_routing.enabled {
+        return false;
+    }
+
+    let config = state
+        .store
+        .find_config_by_key_unwrap_or(
+            format!("success_rate_routing_enabled_{merchant_id}").as_str(),
+            Some("false".to_string()),
+        )
+        .await;
+
+    match config {
+        Ok(conf) => conf.config == "true",
+        Err(err) => {
+            logger::error!("Failed to fetch success rate routing config: {err:?}");
+            false
+        }
+    }
+}
+
+/// Reorders the connectors by their success rate in the current window, connectors which haven't
+/// processed enough payments retain their static order after the ranked connectors. Any failure
+/// while fetching the success rates falls back to the static order.
+pub async fn perform_success_rate_routing<F: Clone>(
+    state: &AppState,
+    payment_data: &payments_oss::PaymentData<F>,
+    connectors: Vec<routing_types::RoutableConnectorChoice>,
+) -> Vec<routing_types::RoutableConnectorChoice> {
+    let config = &state.conf.success_rate_routing;
+
+    if connectors.len() < 2 {
+        return connectors;
+    }
+
+    if should_explore(
+        &payment_data.payment_intent.payment_id,
+        config.exploration_percentage,
+    ) {
+        metrics::ROUTING_SUCCESS_RATE_EXPLORATION.add(&metrics::CONTEXT, 1, &[]);
+        return connectors;
+    }
+
+    let success_rates = match get_connector_success_rates(state, payment_data, &connectors).await {
+        Ok(success_rates) => success_rates,
+        Err(err) => {
+            logger::error!("Failed to fetch connector success rates: {err:?}");
+            return connectors;
+        }
+    };
+
+    let mut ranked_connectors = connectors
+        .into_iter()
+        .zip(success_rates)
+        .collect::<Vec<_>>();
+    // Stable sort, connectors having equal or no success rate retain their static order
+    ranked_connectors.sort_by(|(_, first), (_, second)| second.cmp(first));
+
+    if let Some((connector, success_rate)) = ranked_connectors.first() {
+        metrics::ROUTING_SUCCESS_RATE_CONNECTOR_SELECTED.add(
+            &metrics::CONTEXT,
+            1,
+            &[
+                metrics::KeyValue::new("connector", connector.connector.to_string()),
+                metrics::KeyValue::new("ranked", success_rate.is_some()),
+            ],
+        );
+    }
+
+    ranked_connectors
+        .into_iter()
+        .map(|(connector, _)| connector)
+        .collect()
+}
+
+/// Records the outcome of a payment attempt against the connector it was processed through
+pub async fn record_payment_outcome<F: Clone>(
+    state: &AppState,
+    payment_data: &payments_oss::PaymentData<F>,
+    is_successful: bool,
+) {
+    let config = &state.conf.success_rate_routing;
+
+    if !config.enabled {
+        return;
+    }
+
+    let Some(connector) = payment_data.payment_attempt.connector.as_ref() else {
+        return;
+    };
+
+    let redis_conn = match state.store.get_redis_conn() {
+        Ok(redis_conn) => redis_conn,
+        Err(err) => {
+            logger::error!("Failed to get redis connection: {err:?}");
+            return;
+        }
+    };
+
+    let key = get_success_rate_key(payment_data, get_current_bucket(config));
+    let fields_to_increment = [
+        (get_count_field(connector, TOTAL_COUNT_FIELD), 1),
+        (
+            get_count_field(connector, SUCCESS_COUNT_FIELD),
+            i64::from(is_successful),
+        ),
+    ];
+    let ttl = config
+        .bucket_size_in_secs
+        .saturating_mul(i64::from(config.window_size_in_buckets));
+
+    if let Err(err) = redis_conn
+        .increment_fields_in_hash(&key, &fields_to_increment, Some(ttl))
+        .await
+    {
+        logger::error!("Failed to record payment outcome for success rate routing: {err:?}");
+    }
+}
+
+/// Returns the authorization outcome when an attempt transitions into a terminal authorization
+/// status, outcomes of subsequent operations like captures and voids are not recorded
+pub fn get_authorization_outcome(
+    previous_status: storage_enums::AttemptStatus,
+    current_status: storage_enums::AttemptStatus,
+) -> Option<bool> {
+    match previous_status {
+        storage_enums::AttemptStatus::Authorized
+        | storage_enums::AttemptStatus::PartiallyAuthorized
+        | storage_enums::AttemptStatus::AuthorizationFailed
+        | storage_enums::AttemptStatus::Charged
+        | storage_enums::AttemptStatus::PartialCharged
+        | storage_enums::AttemptStatus::PartialChargedAndChargeable
+        | storage_enums::AttemptStatus::CaptureInitiated
+        | storage_enums::AttemptStatus::CaptureFailed
+        | storage_enums::AttemptStatus::Voided
+        | storage_enums::AttemptStatus::VoidInitiated
+        | storage_enums::AttemptStatus::VoidFailed
+        | storage_enums::AttemptStatus::AutoRefunded
+        | storage_enums::AttemptStatus::Failure => None,
+        _ => match current_status {
+            storage_enums::AttemptStatus::Authorized
+            | storage_enums::AttemptStatus::PartiallyAuthorized
+            | storage_enums::AttemptStatus::Charged
+            | storage_enums::AttemptStatus::PartialCharged
+            | storage_enums::AttemptStatus::PartialChargedAndChargeable => Some(true),
+            storage_enums::AttemptStatus::AuthorizationFailed
+            | storage_enums::AttemptStatus::Failure => Some(false),
+            _ => None,
+        },
+    }
+}
+
+async fn get_connector_success_rates<F: Clone>(
+    state: &AppState,
+    payment_data: &payments_oss::PaymentData<F>,
+    connectors: &[routing_types::RoutableConnectorChoice],
+) -> errors::CustomResult<Vec<Option<i64>>, errors::RedisError> {
+    let config = &state.conf.success_rate_routing;
+    let redis_conn = state.store.get_redis_conn()?;
+
+    let fields = connectors
+        .iter()
+        .flat_map(|choice| {
+            let connector = choice.connector.to_string();
+            [
+                get_count_field(&connector, TOTAL_COUNT_FIELD),
+                get_count_field(&connector, SUCCESS_COUNT_FIELD),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    let current_bucket = get_current_bucket(config);
+    let bucket_counts =
+        futures::future::try_join_all((0..config.window_size_in_buckets).map(|bucket_offset| {
+            let key = get_success_rate_key(
+                payment_data,
+                current_bucket.saturating_sub(i64::from(bucket_offset)),
+            );
+            let redis_conn = redis_conn.clone();
+            let fields = fields.clone();
+            async move {
+                redis_conn
+                    .get_multiple_hash_fields::<i64>(&key, fields)
+                    .await
+            }
+        }))
+        .await?;
+
+    let mut counts = vec![0_i64; fields.len()];
+    for bucket in bucket_counts {
+        counts
+            .iter_mut()
+            .zip(bucket)
+            .for_each(|(count, value)| *count = count.saturating_add(value.unwrap_or(0)));
+    }
+
+    Ok(counts
+        .chunks(2)
+        .map(|chunk| match chunk {
+            [total, success] if *total >= config.min_sample_size => success
+                .saturating_mul(SUCCESS_RATE_SCALE)
+                .checked_div(*total),
+            _ => None,
+        })
+        .collect())
+}
+
+/// Exploration is decided deterministically per payment so that retries of a payment observe the
+/// same connector order
+fn should_explore(payment_id: &str, exploration_percentage: u8) -> bool {
+    let mut hasher = hash_map::DefaultHasher::new();
+    payment_id.hash(&mut hasher);
+
+    let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(hasher.finish());
+    rng.gen_range(0..100) < exploration_percentage
+}
+
+fn get_current_bucket(config: &SuccessRateRouting) -> i64 {
+    common_utils::date_time::now_unix_timestamp()
+        .checked_div(config.bucket_size_in_secs)
+        .unwrap_or_default()
+}
+
+fn get_card_network<F: Clone>(payment_data: &payments_oss::PaymentData<F>) -> Option<String> {
+    payment_data
+        .payment_method_data
+        .as_ref()
+        .and_then(|pm_data| match pm_data {
+            api::PaymentMethodData::Card(card) => {
+                card.card_network.as_ref().map(ToString::to_string)
+            }
+            _ => None,
+        })
+        .or_else(|| payment_data.payment_attempt.card_network.clone())
+}
+
+fn get_success_rate_key<F: Clone>(
+    payment_data: &payments_oss::PaymentData<F>,
+    bucket: i64,
+) -> String {
+    format!(
+        "success_rate_{}_{}_{}_{}_{}_{}",
+        payment_data.payment_attempt.merchant_id,
+        payment_data
+            .payment_intent
+            .profile_id
+            .as_deref()
+            .unwrap_or("default"),
+        payment_data
+            .payment_attempt
+            .payment_method
+            .map(|payment_method| payment_method.to_string())
+            .unwrap_or_else(|| "default".to_string()),
+        get_card_network(payment_data).unwrap_or_else(|| "default".to_string()),
+        payment_data
+            .payment_attempt
+            .currency
+            .map(|currency| currency.to_string())
+            .unwrap_or_else(|| "default".to_string()),
+        bucket
+    )
+}
+
+fn get_count_field(connector: &str, count: &str) -> String {
+    format!("{connector}_{count}")
+}
@@ -0,0 +1,263 @@
+//! 3DS decisioning.
+//!
+//! Merchants configure rules matching payment attempts on their amount, currency and the issuing
+//! country of the card. The first rule matching an attempt decides whether it is processed without
+//! 3DS, authenticated through the external 3DS authentication connector of the business profile, or
+//! through the 3DS flow of the payment connector, along with the SCA exemption applied to it.
+
+use api_models::three_ds_decision_configs::{ThreeDsDecisionRule, ThreeDsDecisionRules};
+use common_utils::ext_traits::StringExt;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        cards_info,
+        errors::{self, RouterResult},
+        payments::PaymentData,
+    },
+    db::StorageInterface,
+    logger,
+    routes::AppState,
+    types::{api, storage, storage::enums as storage_enums},
+};
+
+/// Provides the identifier of the config holding the merchant's 3DS decision rules
+#[inline(always)]
+pub fn get_three_ds_decision_rules_key(merchant_id: &str) -> String {
+    format!("three_ds_decision_rules_{merchant_id}")
+}
+
+pub async fn get_three_ds_decision_rules(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<ThreeDsDecisionRules> {
+    let config = db
+        .find_config_by_key_unwrap_or(
+            &get_three_ds_decision_rules_key(merchant_id),
+            Some(r#"{"rules":[]}"#.to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the 3DS decision rules")?;
+
+    config
+        .config
+        .parse_struct("ThreeDsDecisionRules")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the 3DS decision rules")
+}
+
+/// Provides the first 3DS decision rule of the merchant matching the payment attempt
+#[instrument(skip_all)]
+pub async fn perform_three_ds_decision<F: Clone>(
+    state: &AppState,
+    merchant_id: &str,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<Option<ThreeDsDecisionRule>> {
+    let rules = get_three_ds_decision_rules(&*state.store, merchant_id)
+        .await?
+        .rules;
+
+    if rules.is_empty() {
+        return Ok(None);
+    }
+
+    // The issuing country of the card is looked up only when some rule depends on it
+    let issuer_country = if rules.iter().any(|rule| rule.issuer_countries.is_some()) {
+        get_card_issuing_country(state, payment_data).await
+    } else {
+        None
+    };
+
+    let matched_rule = rules.into_iter().find(|rule| {
+        is_rule_matching(
+            rule,
+            payment_data.payment_attempt.amount,
+            payment_data.currency,
+            issuer_country.as_deref(),
+        )
+    });
+
+    if let Some(rule) = matched_rule.as_ref() {
+        logger::info!(
+            three_ds_decision_rule = %rule.name,
+            three_ds_decision = %rule.decision,
+            sca_exemption = ?rule.exemption,
+            "Payment attempt matched a 3DS decision rule"
+        );
+    }
+
+    Ok(matched_rule)
+}
+
+/// Records the way the attempt is authenticated, as decided by the matched rule or by the
+/// authentication type of the attempt when no rule matched
+pub fn record_three_ds_decision(
+    payment_attempt: &mut storage::PaymentAttempt,
+    payment_intent: &storage::PaymentIntent,
+    matched_rule: Option<&ThreeDsDecisionRule>,
+) {
+    let (three_ds_decision, rule_exemption) = match matched_rule {
+        Some(rule) => (rule.decision, rule.exemption),
+        None => (
+            match payment_attempt.authentication_type {
+                Some(storage_enums::AuthenticationType::ThreeDs)
+                    if payment_intent.request_external_three_ds_authentication == Some(true) =>
+                {
+                    storage_enums::ThreeDsDecision::ExternalAuthentication
+                }
+                Some(storage_enums::AuthenticationType::ThreeDs) => {
+                    storage_enums::ThreeDsDecision::ConnectorThreeDs
+                }
+                Some(storage_enums::AuthenticationType::NoThreeDs) | None => {
+                    storage_enums::ThreeDsDecision::NoThreeDs
+                }
+            },
+            None,
+        ),
+    };
+
+    payment_attempt.three_ds_decision = Some(three_ds_decision);
+    // An exemption requested for the payment takes precedence over the one of the matched rule
+    payment_attempt.sca_exemption = payment_attempt.sca_exemption.or(rule_exemption);
+}
+
+pub fn get_authentication_type(
+    three_ds_decision: storage_enums::ThreeDsDecision,
+) -> storage_enums::AuthenticationType {
+    match three_ds_decision {
+        storage_enums::ThreeDsDecision::NoThreeDs => storage_enums::AuthenticationType::NoThreeDs,
+        storage_enums::ThreeDsDecision::ExternalAuthentication
+        | storage_enums::ThreeDsDecision::ConnectorThreeDs => {
+            storage_enums::AuthenticationType::ThreeDs
+        }
+    }
+}
+
+/// External 3DS authentication is performed when requested by the merchant for the payment, or
+/// when decided by the 3DS decision rules for the attempt
+pub fn is_external_authentication_requested(
+    payment_intent: &storage::PaymentIntent,
+    payment_attempt: &storage::PaymentAttempt,
+) -> bool {
+    payment_intent.request_external_three_ds_authentication == Some(true)
+        || payment_attempt.three_ds_decision
+            == Some(storage_enums::ThreeDsDecision::ExternalAuthentication)
+}
+
+fn is_rule_matching(
+    rule: &ThreeDsDecisionRule,
+    amount: i64,
+    currency: storage_enums::Currency,
+    issuer_country: Option<&str>,
+) -> bool {
+    rule.min_amount
+        .map_or(true, |min_amount| amount >= min_amount)
+        && rule
+            .max_amount
+            .map_or(true, |max_amount| amount <= max_amount)
+        && rule
+            .currencies
+            .as_ref()
+            .map_or(true, |currencies| currencies.contains(&currency))
+        && rule.issuer_countries.as_ref().map_or(true, |countries| {
+            issuer_country.is_some_and(|issuer_country| {
+                countries
+                    .iter()
+                    .any(|country| country.eq_ignore_ascii_case(issuer_country))
+            })
+        })
+}
+
+async fn get_card_issuing_country<F: Clone>(
+    state: &AppState,
+    payment_data: &PaymentData<F>,
+) -> Option<String> {
+    match payment_data.payment_method_data.as_ref() {
+        Some(api::PaymentMethodData::Card(card)) => match card.card_issuing_country.clone() {
+            Some(card_issuing_country) => Some(card_issuing_country),
+            None => cards_info::bin_lookup::get_card_bin_info(
+                state,
+                &card.card_number.clone().get_card_isin(),
+            )
+            .await
+            .and_then(|card_info| card_info.card_issuing_country),
+        },
+        _ => payment_data.payment_attempt.card_issuing_country.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(decision: storage_enums::ThreeDsDecision) -> ThreeDsDecisionRule {
+        ThreeDsDecisionRule {
+            name: "rule".to_string(),
+            min_amount: None,
+            max_amount: None,
+            currencies: None,
+            issuer_countries: None,
+            decision,
+            exemption: None,
+        }
+    }
+
+    #[test]
+    fn test_rule_matching_on_amount_and_currency() {
+        let rule = ThreeDsDecisionRule {
+            max_amount: Some(3000),
+            currencies: Some(vec![storage_enums::Currency::EUR]),
+            exemption: Some(storage_enums::ScaExemptionType::LowValue),
+            ..rule(storage_enums::ThreeDsDecision::NoThreeDs)
+        };
+
+        assert!(is_rule_matching(
+            &rule,
+            3000,
+            storage_enums::Currency::EUR,
+            None
+        ));
+        assert!(!is_rule_matching(
+            &rule,
+            3001,
+            storage_enums::Currency::EUR,
+            None
+        ));
+        assert!(!is_rule_matching(
+            &rule,
+            1000,
+            storage_enums::Currency::USD,
+            None
+        ));
+    }
+
+    #[test]
+    fn test_rule_matching_on_issuer_country() {
+        let rule = ThreeDsDecisionRule {
+            issuer_countries: Some(vec!["INDIA".to_string()]),
+            ..rule(storage_enums::ThreeDsDecision::ExternalAuthentication)
+        };
+
+        assert!(is_rule_matching(
+            &rule,
+            1000,
+            storage_enums::Currency::INR,
+            Some("India")
+        ));
+        assert!(!is_rule_matching(
+            &rule,
+            1000,
+            storage_enums::Currency::INR,
+            Some("UNITED STATES")
+        ));
+        // Rules depending on the issuing country do not match cards with an unknown country
+        assert!(!is_rule_matching(
+            &rule,
+            1000,
+            storage_enums::Currency::INR,
+            None
+        ));
+    }
+}
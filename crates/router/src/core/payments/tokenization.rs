@@ -209,8 +209,8 @@ where
                                     if err.current_context().is_db_not_found() {
                                         let pm_metadata =
                                             create_payment_method_metadata(None, connector_token)?;
-                                        payment_methods::cards::create_payment_method(
-                                            db,
+                                        let pm = payment_methods::cards::create_payment_method(
+                                            state,
                                             &payment_method_create_request,
                                             &customer.customer_id,
                                             &resp.payment_method_id,
@@ -222,14 +222,17 @@ where
                                             key_store,
                                             connector_mandate_details,
                                         )
-                                        .await
+                                        .await?;
+                                        resp.payment_method_id = pm.payment_method_id;
                                     } else {
-                                        Err(err)
+                                        return Err(err
                                             .change_context(
                                                 errors::ApiErrorResponse::InternalServerError,
                                             )
-                                            .attach_printable("Error while finding payment method")
-                                    }?;
+                                            .attach_printable(
+                                                "Error while finding payment method",
+                                            ));
+                                    }
                                 }
                             };
                         }
@@ -274,7 +277,7 @@ where
                                         Err(err) => {
                                             if err.current_context().is_db_not_found() {
                                                 payment_methods::cards::insert_payment_method(
-                                                    db,
+                                                    state,
                                                     &resp,
                                                     payment_method_create_request.clone(),
                                                     key_store,
@@ -390,8 +393,8 @@ where
                         };
 
                         resp.payment_method_id = generate_id(consts::ID_LENGTH, "pm");
-                        payment_methods::cards::create_payment_method(
-                            db,
+                        let pm = payment_methods::cards::create_payment_method(
+                            state,
                             &payment_method_create_request,
                             &customer.customer_id,
                             &resp.payment_method_id,
@@ -404,6 +407,17 @@ where
                             connector_mandate_details,
                         )
                         .await?;
+                        resp.payment_method_id = pm.payment_method_id.clone();
+
+                        if let Some(card) = payment_method_create_request.card.as_ref() {
+                            payment_methods::network_tokenization::provision_and_store_network_token(
+                                state,
+                                merchant_account,
+                                card,
+                                pm,
+                            )
+                            .await;
+                        }
                     }
                 }
 
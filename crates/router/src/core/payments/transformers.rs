@@ -17,7 +17,7 @@ use crate::{
     core::{
         errors::{self, RouterResponse, RouterResult},
         payments::{self, helpers},
-        utils as core_utils,
+        refunds, utils as core_utils,
     },
     routes::{metrics, AppState},
     services::{self, RedirectForm},
@@ -379,6 +379,18 @@ where
             field_name: "amount",
         })?;
     let mandate_id = payment_attempt.mandate_id.clone();
+    // Refunds of the payment are fetched only when retrieving the payment
+    let amount_refundable = (payment_data.include_amount_refundable
+        && matches!(
+            payment_intent.status,
+            enums::IntentStatus::Succeeded | enums::IntentStatus::PartiallyCaptured
+        ))
+    .then(|| {
+        let amount_captured = payment_intent
+            .amount_captured
+            .unwrap_or(payment_attempt.amount);
+        (amount_captured - refunds::validator::get_refunded_amount(&payment_data.refunds)).max(0)
+    });
     let refunds_response = if payment_data.refunds.is_empty() {
         None
     } else {
@@ -420,6 +432,14 @@ where
         .as_ref()
         .map(ForeignInto::foreign_into);
 
+    // Zero amount card payments only set up the card for future usage, the outcome of the
+    // verification of the card is surfaced separately from the payment status
+    let card_verification_status = (payment_intent.amount == 0
+        && payment_attempt.payment_method == Some(enums::PaymentMethod::Card)
+        && payment_intent.setup_future_usage.is_some()
+        && payment_attempt.confirm)
+        .then(|| payment_attempt.status.foreign_into());
+
     let attempts_response = payment_data.attempts.map(|attempts| {
         attempts
             .into_iter()
@@ -634,6 +654,7 @@ where
                         .set_amount(payment_attempt.amount)
                         .set_amount_capturable(Some(payment_attempt.amount_capturable))
                         .set_amount_received(payment_intent.amount_captured)
+                        .set_amount_refundable(amount_refundable)
                         .set_surcharge_details(surcharge_details)
                         .set_connector(routed_through)
                         .set_client_secret(payment_intent.client_secret.map(masking::Secret::new))
@@ -683,6 +704,9 @@ where
                                             start_date: i.start_date,
                                             end_date: i.end_date,
                                             metadata: i.metadata,
+                                            max_amount_per_charge: i.max_amount_per_charge,
+                                            max_amount_per_period: i.max_amount_per_period,
+                                            limit_period: i.limit_period,
                                         }))
                                     }
                                     data_models::mandates::MandateDataType::SingleUse(i) => {
@@ -693,6 +717,9 @@ where
                                                 start_date: i.start_date,
                                                 end_date: i.end_date,
                                                 metadata: i.metadata,
+                                                max_amount_per_charge: i.max_amount_per_charge,
+                                                max_amount_per_period: i.max_amount_per_period,
+                                                limit_period: i.limit_period,
                                             },
                                         )
                                     }
@@ -781,6 +808,15 @@ where
                         .set_payment_method_status(
                             payment_data.payment_method_info.map(|info| info.status),
                         )
+                        .set_card_verification_status(card_verification_status)
+                        .set_payment_method_selection(payment_attempt.payment_method_selection)
+                        .set_settlement_currency(payment_attempt.settlement_currency)
+                        .set_settlement_amount(payment_attempt.settlement_amount)
+                        .set_fx_rate(payment_attempt.fx_rate)
+                        .set_avs_result_code(payment_attempt.avs_result_code)
+                        .set_avs_result(payment_attempt.avs_result)
+                        .set_cvv_result_code(payment_attempt.cvv_result_code)
+                        .set_cvv_result(payment_attempt.cvv_result)
                         .to_owned(),
                     headers,
                 ))
@@ -795,6 +831,7 @@ where
                 amount: payment_attempt.amount,
                 amount_capturable: None,
                 amount_received: payment_intent.amount_captured,
+                amount_refundable,
                 client_secret: payment_intent.client_secret.map(masking::Secret::new),
                 created: Some(payment_intent.created_at),
                 currency: currency.to_string(),
@@ -851,6 +888,15 @@ where
                 expires_on: payment_intent.session_expiry,
                 external_3ds_authentication_attempted: payment_attempt
                     .external_three_ds_authentication_attempted,
+                card_verification_status,
+                payment_method_selection: payment_attempt.payment_method_selection,
+                settlement_currency: payment_attempt.settlement_currency,
+                settlement_amount: payment_attempt.settlement_amount,
+                fx_rate: payment_attempt.fx_rate,
+                avs_result_code: payment_attempt.avs_result_code,
+                avs_result: payment_attempt.avs_result,
+                cvv_result_code: payment_attempt.cvv_result_code,
+                cvv_result: payment_attempt.cvv_result,
                 ..Default::default()
             },
             headers,
@@ -1257,6 +1303,12 @@ impl<F: Clone> TryFrom<PaymentAdditionalData<'_, F>> for types::PaymentsAuthoriz
                 .as_ref()
                 .map(AuthenticationData::foreign_try_from)
                 .transpose()?,
+            network_token_data: None,
+            sca_exemption: attempt.sca_exemption,
+            customer_document: additional_data
+                .customer_data
+                .as_ref()
+                .and_then(|customer| customer.get_document_details()),
             customer_acceptance: payment_data.customer_acceptance,
         })
     }
@@ -10,6 +10,7 @@ use common_utils::{
 use data_models::payments::payment_attempt::PaymentAttempt;
 use diesel_models::business_profile::BusinessProfile;
 use error_stack::{IntoReport, ResultExt};
+use masking::Secret;
 use redis_interface::errors::RedisError;
 use router_env::{instrument, tracing};
 
@@ -377,6 +378,16 @@ impl SurchargeMetadata {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct NetworkTokenData {
+    pub token_number: cards::CardNumber,
+    pub token_exp_month: Secret<String>,
+    pub token_exp_year: Secret<String>,
+    pub token_cryptogram: Secret<String>,
+    pub eci: Option<String>,
+    pub card_network: common_enums::CardNetwork,
+}
+
 #[derive(Debug, Clone)]
 pub struct AuthenticationData {
     pub eci: Option<String>,
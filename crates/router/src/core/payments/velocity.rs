@@ -0,0 +1,278 @@
+//! Velocity limits.
+//!
+//! Merchants configure rules limiting the number of payment attempts, or their total amount, made
+//! with the same card, by the same customer or from the same IP address over a sliding window.
+//! Attempts are recorded in Redis sorted sets scored by the time they were made, one set for each
+//! rule and value of the dimension of the rule. Attempts exceeding the limits of a rule are either
+//! rejected before being sent to the connector or flagged, according to the action of the rule.
+
+use api_models::velocity_rules::{VelocityAction, VelocityDimension, VelocityRule, VelocityRules};
+use common_enums::MerchantDecision;
+use common_utils::{consts::VELOCITY_LIMIT_DECLINE_CODE, date_time, ext_traits::StringExt};
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        blocklist::utils as blocklist_utils,
+        errors::{self, CustomResult, RouterResult, StorageErrorExt},
+        payments::PaymentData,
+    },
+    db::StorageInterface,
+    logger,
+    routes::{metrics, AppState},
+    types::{domain, storage, storage::enums as storage_enums},
+};
+
+/// Provides the identifier of the config holding the merchant's velocity rules
+#[inline(always)]
+pub fn get_velocity_rules_key(merchant_id: &str) -> String {
+    format!("velocity_rules_{merchant_id}")
+}
+
+pub async fn get_velocity_rules(
+    db: &dyn StorageInterface,
+    merchant_id: &str,
+) -> RouterResult<VelocityRules> {
+    let config = db
+        .find_config_by_key_unwrap_or(
+            &get_velocity_rules_key(merchant_id),
+            Some(r#"{"rules":[]}"#.to_string()),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the velocity rules")?;
+
+    config
+        .config
+        .parse_struct("VelocityRules")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the velocity rules")
+}
+
+/// Attempts recorded in the window of a rule, including the attempt being checked
+#[derive(Debug, Default)]
+struct WindowUsage {
+    attempts: usize,
+    amount: i64,
+}
+
+impl WindowUsage {
+    fn exceeds_limits_of(&self, rule: &VelocityRule) -> bool {
+        rule.max_attempts
+            .and_then(|max_attempts| usize::try_from(max_attempts).ok())
+            .is_some_and(|max_attempts| self.attempts > max_attempts)
+            || rule
+                .max_amount
+                .is_some_and(|max_amount| self.amount > max_amount)
+    }
+}
+
+/// Checks the payment attempt against the velocity rules of the merchant. Attempts exceeding the
+/// limits of a rejecting rule are failed, along with the payment.
+///
+/// Rules which cannot be evaluated, as Redis is unavailable, are skipped so that payments continue
+/// to be processed.
+#[instrument(skip_all)]
+pub async fn perform_velocity_checks<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<()> {
+    let merchant_id = &merchant_account.merchant_id;
+    let rules = get_velocity_rules(&*state.store, merchant_id).await?.rules;
+    if rules.is_empty() {
+        return Ok(());
+    }
+
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+
+    let mut is_rejected = false;
+    for rule in rules.iter().filter(|rule| {
+        rule.currency
+            .map_or(true, |currency| currency == payment_data.currency)
+    }) {
+        let Some(dimension_value) = get_dimension_value(rule.dimension, payment_data) else {
+            continue;
+        };
+
+        let key = format!(
+            "velocity_{merchant_id}_{}_{}_{dimension_value}",
+            rule.name, rule.dimension
+        );
+        let usage = match record_attempt(&redis_conn, &key, rule, payment_data).await {
+            Ok(usage) => usage,
+            Err(error) => {
+                logger::error!(?error, rule = %rule.name, "Failed to evaluate velocity rule");
+                continue;
+            }
+        };
+
+        if usage.exceeds_limits_of(rule) {
+            logger::warn!(
+                payment_id = %payment_data.payment_attempt.payment_id,
+                rule = %rule.name,
+                action = %rule.action,
+                "Payment attempt exceeds the limits of the velocity rule"
+            );
+            metrics::VELOCITY_RULE_TRIGGERED_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[
+                    metrics::request::add_attributes("merchant_id", merchant_id.to_owned()),
+                    metrics::request::add_attributes("dimension", rule.dimension.to_string()),
+                    metrics::request::add_attributes("action", rule.action.to_string()),
+                ],
+            );
+            is_rejected |= rule.action == VelocityAction::Reject;
+        }
+    }
+
+    if is_rejected {
+        reject_payment(state, merchant_account, payment_data).await?;
+    }
+    Ok(())
+}
+
+fn get_dimension_value<F: Clone>(
+    dimension: VelocityDimension,
+    payment_data: &PaymentData<F>,
+) -> Option<String> {
+    match dimension {
+        VelocityDimension::Card => payment_data.payment_attempt.card_fingerprint.clone(),
+        VelocityDimension::Customer => payment_data.payment_intent.customer_id.clone(),
+        VelocityDimension::IpAddress => blocklist_utils::get_payment_ip_address(
+            payment_data.payment_attempt.browser_info.as_ref(),
+        )
+        .map(|ip_address| ip_address.to_string()),
+    }
+}
+
+/// Records the payment attempt in the sliding window of the rule, and provides the attempts made
+/// in the window
+async fn record_attempt<F: Clone>(
+    redis_conn: &redis_interface::RedisConnectionPool,
+    key: &str,
+    rule: &VelocityRule,
+    payment_data: &PaymentData<F>,
+) -> CustomResult<WindowUsage, redis_interface::errors::RedisError> {
+    let payment_attempt = &payment_data.payment_attempt;
+    let now = (date_time::now().assume_utc() - time::OffsetDateTime::UNIX_EPOCH).as_seconds_f64();
+    let window_start = now - f64::from(rule.window_in_seconds);
+
+    redis_conn
+        .zremrange_by_score(key, 0.0, window_start)
+        .await?;
+    let members: Vec<String> = redis_conn.zrange_by_score(key, window_start, now).await?;
+
+    // Members are of the form `{attempt_id}:{amount}`, an attempt checked again is counted once
+    let usage = members
+        .iter()
+        .filter_map(|member| member.rsplit_once(':'))
+        .filter(|(attempt_id, _)| *attempt_id != payment_attempt.attempt_id)
+        .fold(
+            WindowUsage {
+                attempts: 1,
+                amount: payment_attempt.amount,
+            },
+            |usage, (_, amount)| WindowUsage {
+                attempts: usage.attempts + 1,
+                amount: usage
+                    .amount
+                    .saturating_add(amount.parse().unwrap_or_default()),
+            },
+        );
+
+    redis_conn
+        .zadd_with_score(
+            key,
+            now,
+            format!("{}:{}", payment_attempt.attempt_id, payment_attempt.amount),
+        )
+        .await?;
+    redis_conn
+        .set_expiry(key, i64::from(rule.window_in_seconds))
+        .await?;
+
+    Ok(usage)
+}
+
+async fn reject_payment<F: Clone>(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    payment_data: &PaymentData<F>,
+) -> RouterResult<()> {
+    let db = &*state.store;
+    // The exceeded limits are not disclosed to the customer
+    let error_message = "This payment exceeds the allowed limits";
+
+    db.update_payment_intent(
+        payment_data.payment_intent.clone(),
+        storage::PaymentIntentUpdate::RejectUpdate {
+            status: storage_enums::IntentStatus::Failed,
+            merchant_decision: Some(MerchantDecision::Rejected.to_string()),
+            updated_by: merchant_account.storage_scheme.to_string(),
+        },
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+    .attach_printable(
+        "Failed to update status in Payment Intent to failed due to velocity limits",
+    )?;
+
+    db.update_payment_attempt_with_attempt_id(
+        payment_data.payment_attempt.clone(),
+        storage::PaymentAttemptUpdate::BlocklistUpdate {
+            status: storage_enums::AttemptStatus::Failure,
+            error_code: Some(Some(VELOCITY_LIMIT_DECLINE_CODE.to_string())),
+            error_message: Some(Some(error_message.to_string())),
+            updated_by: merchant_account.storage_scheme.to_string(),
+        },
+        merchant_account.storage_scheme,
+    )
+    .await
+    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)
+    .attach_printable(
+        "Failed to update status in Payment Attempt to failed due to velocity limits",
+    )?;
+
+    Err(errors::ApiErrorResponse::PaymentBlockedError {
+        code: 200,
+        message: error_message.to_string(),
+        status: "Failed".to_string(),
+        reason: "Velocity limit exceeded".to_string(),
+    }
+    .into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_window_usage_exceeds_limits() {
+        let rule = VelocityRule {
+            name: "card_attempts".to_string(),
+            dimension: VelocityDimension::Card,
+            window_in_seconds: 3600,
+            max_attempts: Some(3),
+            max_amount: Some(10000),
+            currency: Some(storage_enums::Currency::USD),
+            action: VelocityAction::Reject,
+        };
+
+        let usage = |attempts, amount| WindowUsage { attempts, amount };
+        assert!(!usage(3, 10000).exceeds_limits_of(&rule));
+        assert!(usage(4, 100).exceeds_limits_of(&rule));
+        assert!(usage(1, 10001).exceeds_limits_of(&rule));
+        assert!(!usage(100, 0).exceeds_limits_of(&VelocityRule {
+            max_attempts: None,
+            ..rule
+        }));
+    }
+}
@@ -103,6 +103,7 @@ pub async fn get_connector_choice(
                 routing_info: PaymentRoutingInfo {
                     algorithm: None,
                     pre_routing_results: None,
+                    routing_algorithm_id: None,
                 },
             };
             helpers::decide_payout_connector(
@@ -128,6 +129,7 @@ pub async fn get_connector_choice(
                 routing_info: PaymentRoutingInfo {
                     algorithm: None,
                     pre_routing_results: None,
+                    routing_algorithm_id: None,
                 },
             };
             helpers::decide_payout_connector(
@@ -312,6 +314,7 @@ pub async fn payouts_update_core(
     state: AppState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
+    profile_id: Option<String>,
     req: payouts::PayoutCreateRequest,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
     let payout_id = req.payout_id.clone().get_required_value("payout_id")?;
@@ -319,6 +322,7 @@ pub async fn payouts_update_core(
         &state,
         &merchant_account,
         &key_store,
+        profile_id.as_ref(),
         &payouts::PayoutRequest::PayoutCreateRequest(req.to_owned()),
     )
     .await?;
@@ -457,12 +461,14 @@ pub async fn payouts_retrieve_core(
     state: AppState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
+    profile_id: Option<String>,
     req: payouts::PayoutRetrieveRequest,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
     let payout_data = make_payout_data(
         &state,
         &merchant_account,
         &key_store,
+        profile_id.as_ref(),
         &payouts::PayoutRequest::PayoutRetrieveRequest(req.to_owned()),
     )
     .await?;
@@ -481,12 +487,14 @@ pub async fn payouts_cancel_core(
     state: AppState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
+    profile_id: Option<String>,
     req: payouts::PayoutActionRequest,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
     let mut payout_data = make_payout_data(
         &state,
         &merchant_account,
         &key_store,
+        profile_id.as_ref(),
         &payouts::PayoutRequest::PayoutActionRequest(req.to_owned()),
     )
     .await?;
@@ -572,12 +580,14 @@ pub async fn payouts_fulfill_core(
     state: AppState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
+    profile_id: Option<String>,
     req: payouts::PayoutActionRequest,
 ) -> RouterResponse<payouts::PayoutCreateResponse> {
     let mut payout_data = make_payout_data(
         &state,
         &merchant_account,
         &key_store,
+        profile_id.as_ref(),
         &payouts::PayoutRequest::PayoutActionRequest(req.to_owned()),
     )
     .await?;
@@ -665,6 +675,7 @@ pub async fn payouts_fulfill_core(
 pub async fn payouts_list_core(
     state: AppState,
     merchant_account: domain::MerchantAccount,
+    profile_id: Option<String>,
     constraints: payouts::PayoutListConstraints,
 ) -> RouterResponse<payouts::PayoutListResponse> {
     validator::validate_payout_list_request(&constraints)?;
@@ -674,6 +685,7 @@ pub async fn payouts_list_core(
         db,
         &constraints,
         merchant_id,
+        profile_id,
         merchant_account.storage_scheme,
     )
     .await
@@ -728,12 +740,15 @@ pub async fn payouts_list_core(
 pub async fn payouts_filtered_list_core(
     state: AppState,
     merchant_account: domain::MerchantAccount,
-    filters: payouts::PayoutListFilterConstraints,
+    profile_id: Option<String>,
+    mut filters: payouts::PayoutListFilterConstraints,
 ) -> RouterResponse<payouts::PayoutListResponse> {
     let limit = &filters.limit;
     validator::validate_payout_list_request_for_joins(*limit)?;
+    filters.profile_id =
+        core_utils::validate_profile_id_from_auth_layer(profile_id, filters.profile_id)?;
     let db = state.store.as_ref();
-    let list: Vec<(storage::Payouts, storage::PayoutAttempt)> = db
+    let mut list: Vec<(storage::Payouts, storage::PayoutAttempt)> = db
         .filter_payouts_and_attempts(
             &merchant_account.merchant_id,
             &filters.clone().into(),
@@ -742,6 +757,11 @@ pub async fn payouts_filtered_list_core(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
 
+    // Payouts looked up by payout id are not filtered by the business profile in storage
+    if let Some(profile_id) = &filters.profile_id {
+        list.retain(|(payout, _)| &payout.profile_id == profile_id);
+    }
+
     let data: Vec<api::PayoutCreateResponse> =
         list.into_iter().map(ForeignFrom::foreign_from).collect();
 
@@ -757,10 +777,11 @@ pub async fn payouts_filtered_list_core(
 pub async fn payouts_list_available_filters_core(
     state: AppState,
     merchant_account: domain::MerchantAccount,
+    profile_id: Option<String>,
     time_range: api::TimeRange,
 ) -> RouterResponse<api::PayoutListFilters> {
     let db = state.store.as_ref();
-    let payout = db
+    let mut payout = db
         .filter_payouts_by_time_range_constraints(
             &merchant_account.merchant_id,
             &time_range,
@@ -769,6 +790,10 @@ pub async fn payouts_list_available_filters_core(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
+    if let Some(profile_id) = &profile_id {
+        payout.retain(|payout| &payout.profile_id == profile_id);
+    }
+
     let filters = db
         .get_filters_for_payouts(
             payout.as_slice(),
@@ -1494,6 +1519,7 @@ pub async fn payout_create_db_entries(
         email: req.email.to_owned(),
         phone: req.phone.to_owned(),
         phone_country_code: req.phone_country_code.to_owned(),
+        document: None,
     };
     let customer = helpers::get_or_create_customer_details(
         state,
@@ -1629,6 +1655,7 @@ pub async fn make_payout_data(
     state: &AppState,
     merchant_account: &domain::MerchantAccount,
     key_store: &domain::MerchantKeyStore,
+    profile_id: Option<&String>,
     req: &payouts::PayoutRequest,
 ) -> RouterResult<PayoutData> {
     let db = &*state.store;
@@ -1648,6 +1675,12 @@ pub async fn make_payout_data(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PayoutNotFound)?;
 
+    core_utils::validate_profile_id_of_resource(
+        profile_id,
+        Some(&payouts.profile_id),
+        errors::ApiErrorResponse::PayoutNotFound,
+    )?;
+
     let payout_attempt_id = utils::get_payment_attempt_id(payout_id, payouts.attempt_count);
 
     let payout_attempt = db
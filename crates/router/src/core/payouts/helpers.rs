@@ -379,7 +379,7 @@ pub async fn save_payout_data_to_locker(
 
     let payment_method_id = common_utils::generate_id(crate::consts::ID_LENGTH, "pm");
     cards::create_payment_method(
-        db,
+        state,
         &payment_method,
         &payout_attempt.customer_id,
         &payment_method_id,
@@ -440,6 +440,8 @@ pub async fn get_or_create_customer_details(
                 modified_at: common_utils::date_time::now(),
                 address_id: None,
                 default_payment_method_id: None,
+                document_type: None,
+                document_number: None,
             };
 
             Ok(Some(
@@ -479,10 +481,13 @@ pub async fn decide_payout_connector(
 
     // 2. Check routing algorithm passed in the request
     if let Some(routing_algorithm) = request_straight_through {
-        let (mut connectors, check_eligibility) =
-            routing::perform_straight_through_routing(&routing_algorithm, None)
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Failed execution of straight through routing")?;
+        let (mut connectors, check_eligibility) = routing::perform_straight_through_routing(
+            &routing_algorithm,
+            None,
+            Some(&payout_attempt.payout_id),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed execution of straight through routing")?;
 
         if check_eligibility {
             connectors = routing::perform_eligibility_analysis_with_fallback(
@@ -539,10 +544,13 @@ pub async fn decide_payout_connector(
 
     // 3. Check algorithm passed in routing data
     if let Some(ref routing_algorithm) = routing_data.algorithm {
-        let (mut connectors, check_eligibility) =
-            routing::perform_straight_through_routing(routing_algorithm, None)
-                .change_context(errors::ApiErrorResponse::InternalServerError)
-                .attach_printable("Failed execution of straight through routing")?;
+        let (mut connectors, check_eligibility) = routing::perform_straight_through_routing(
+            routing_algorithm,
+            None,
+            Some(&payout_attempt.payout_id),
+        )
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed execution of straight through routing")?;
 
         if check_eligibility {
             connectors = routing::perform_eligibility_analysis_with_fallback(
@@ -732,10 +740,17 @@ pub(super) async fn filter_by_constraints(
     db: &dyn StorageInterface,
     constraints: &api::PayoutListConstraints,
     merchant_id: &str,
+    profile_id: Option<String>,
     storage_scheme: storage::enums::MerchantStorageScheme,
 ) -> CustomResult<Vec<storage::Payouts>, errors::DataStorageError> {
+    let mut fetch_constraints: data_models::payouts::PayoutFetchConstraints =
+        constraints.clone().into();
+    if let data_models::payouts::PayoutFetchConstraints::List(params) = &mut fetch_constraints {
+        params.profile_id = profile_id;
+    }
+
     let result = db
-        .filter_payouts_by_constraints(merchant_id, &constraints.clone().into(), storage_scheme)
+        .filter_payouts_by_constraints(merchant_id, &fetch_constraints, storage_scheme)
         .await?;
     Ok(result)
 }
@@ -0,0 +1,475 @@
+//! Reconciliation of connector settlement reports.
+//!
+//! Each row of a settlement report is stored as a settlement record and matched against the
+//! payment attempt or refund it is for, by the connector transaction ID. A record is reconciled
+//! when its currency and gross amount match the payment attempt or refund, and its net amount
+//! equals the gross amount less the fees charged by the connector.
+//!
+//! Ingesting a row which was already ingested for the connector updates the existing record and
+//! matches it again, so that records which were unmatched because the payment attempt or refund
+//! was not yet updated with the connector transaction ID are reconciled by a later report.
+
+use std::str::FromStr;
+
+use api_models::recon as recon_api;
+use common_utils::date_time;
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, tracing};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    db::StorageInterface,
+    routes::AppState,
+    services::ApplicationResponse,
+    types::{
+        domain,
+        storage::{self, enums as storage_enums},
+    },
+    utils,
+};
+
+/// The payment attempt or refund which a settlement record was matched against
+struct SettlementMatch {
+    payment_id: String,
+    attempt_id: String,
+    refund_id: Option<String>,
+    currency: Option<storage_enums::Currency>,
+    amount: i64,
+}
+
+fn get_match_status(
+    row: &recon_api::SettlementReportRow,
+    fee_amount: i64,
+    net_amount: i64,
+    settlement_match: Option<&SettlementMatch>,
+) -> storage_enums::SettlementMatchStatus {
+    match settlement_match {
+        None => storage_enums::SettlementMatchStatus::Unmatched,
+        Some(settlement_match) if settlement_match.currency != Some(row.currency) => {
+            storage_enums::SettlementMatchStatus::CurrencyMismatch
+        }
+        Some(settlement_match) if settlement_match.amount != row.gross_amount => {
+            storage_enums::SettlementMatchStatus::AmountMismatch
+        }
+        Some(_) if row.gross_amount - fee_amount != net_amount => {
+            storage_enums::SettlementMatchStatus::FeeMismatch
+        }
+        Some(_) => storage_enums::SettlementMatchStatus::Matched,
+    }
+}
+
+async fn find_settlement_match(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    connector: &str,
+    row: &recon_api::SettlementReportRow,
+) -> RouterResult<Option<SettlementMatch>> {
+    match row.record_type {
+        storage_enums::SettlementRecordType::Payment => {
+            let payment_attempt = match db
+                .find_payment_attempt_by_merchant_id_connector_txn_id(
+                    &merchant_account.merchant_id,
+                    &row.connector_transaction_id,
+                    merchant_account.storage_scheme,
+                )
+                .await
+            {
+                Ok(payment_attempt) => payment_attempt,
+                Err(error)
+                    if matches!(
+                        error.current_context(),
+                        errors::DataStorageError::ValueNotFound(_)
+                    ) =>
+                {
+                    return Ok(None)
+                }
+                Err(error) => Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch the payment attempt of settlement record")?,
+            };
+
+            Ok(
+                (payment_attempt.connector.as_deref() == Some(connector)).then(|| {
+                    SettlementMatch {
+                        payment_id: payment_attempt.payment_id,
+                        attempt_id: payment_attempt.attempt_id,
+                        refund_id: None,
+                        currency: payment_attempt.currency,
+                        amount: payment_attempt.net_amount,
+                    }
+                }),
+            )
+        }
+        storage_enums::SettlementRecordType::Refund => {
+            match db
+                .find_refund_by_merchant_id_connector_refund_id_connector(
+                    &merchant_account.merchant_id,
+                    &row.connector_transaction_id,
+                    connector,
+                    merchant_account.storage_scheme,
+                )
+                .await
+            {
+                Ok(refund) => Ok(Some(SettlementMatch {
+                    payment_id: refund.payment_id,
+                    attempt_id: refund.attempt_id,
+                    refund_id: Some(refund.refund_id),
+                    currency: Some(refund.currency),
+                    amount: refund.refund_amount,
+                })),
+                Err(error) if error.current_context().is_db_not_found() => Ok(None),
+                Err(error) => Err(error)
+                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                    .attach_printable("Failed to fetch the refund of settlement record"),
+            }
+        }
+    }
+}
+
+async fn store_settlement_record(
+    db: &dyn StorageInterface,
+    merchant_account: &domain::MerchantAccount,
+    report_id: &str,
+    connector: &str,
+    row: recon_api::SettlementReportRow,
+) -> RouterResult<storage::SettlementRecord> {
+    let settlement_match = find_settlement_match(db, merchant_account, connector, &row).await?;
+    let fee_amount = row.fee_amount.unwrap_or(0);
+    let net_amount = row.net_amount.unwrap_or(row.gross_amount - fee_amount);
+    let match_status = get_match_status(&row, fee_amount, net_amount, settlement_match.as_ref());
+    let (payment_id, attempt_id, refund_id, expected_amount) = settlement_match
+        .map(|settlement_match| {
+            (
+                Some(settlement_match.payment_id),
+                Some(settlement_match.attempt_id),
+                settlement_match.refund_id,
+                Some(settlement_match.amount),
+            )
+        })
+        .unwrap_or_default();
+    let now = date_time::now();
+
+    match db
+        .find_settlement_record_by_merchant_id_connector_transaction_id(
+            &merchant_account.merchant_id,
+            connector,
+            row.record_type,
+            &row.connector_transaction_id,
+        )
+        .await
+    {
+        Ok(settlement_record) => db
+            .update_settlement_record_by_merchant_id_record_id(
+                &merchant_account.merchant_id,
+                &settlement_record.record_id,
+                storage::SettlementRecordUpdateInternal {
+                    report_id: report_id.to_string(),
+                    currency: row.currency,
+                    gross_amount: row.gross_amount,
+                    fee_amount,
+                    net_amount,
+                    settled_at: row.settled_at,
+                    payment_id,
+                    attempt_id,
+                    refund_id,
+                    expected_amount,
+                    match_status,
+                    modified_at: now,
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the settlement record"),
+        Err(error) if error.current_context().is_db_not_found() => db
+            .insert_settlement_record(storage::SettlementRecordNew {
+                record_id: utils::generate_id(consts::ID_LENGTH, "setl"),
+                merchant_id: merchant_account.merchant_id.clone(),
+                report_id: report_id.to_string(),
+                connector: connector.to_string(),
+                record_type: row.record_type,
+                connector_transaction_id: row.connector_transaction_id,
+                currency: row.currency,
+                gross_amount: row.gross_amount,
+                fee_amount,
+                net_amount,
+                settled_at: row.settled_at,
+                payment_id,
+                attempt_id,
+                refund_id,
+                expected_amount,
+                match_status,
+                created_at: now,
+                modified_at: now,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the settlement record"),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the settlement record"),
+    }
+}
+
+fn parse_csv_report(csv_data: &str) -> RouterResult<Vec<recon_api::SettlementReportRow>> {
+    let mut lines = csv_data.lines().filter(|line| !line.trim().is_empty());
+    let header = lines.next().map(utils::csv::split_line).ok_or(
+        errors::ApiErrorResponse::InvalidRequestData {
+            message: "The settlement report has no header row".to_string(),
+        },
+    )?;
+
+    lines
+        .enumerate()
+        .map(|(index, line)| {
+            let fields = utils::csv::split_line(line);
+            let invalid_column = |column: &str| errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "Invalid value of `{column}` in row {} of the settlement report",
+                    index + 1
+                ),
+            };
+            let get_column = |column: &'static str| {
+                header
+                    .iter()
+                    .position(|name| name == column)
+                    .and_then(|position| fields.get(position))
+                    .map(String::as_str)
+                    .filter(|value| !value.is_empty())
+            };
+            let get_required_column = |column: &'static str| {
+                get_column(column).ok_or(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Missing value of `{column}` in row {} of the settlement report",
+                        index + 1
+                    ),
+                })
+            };
+            let parse_amount = |column: &'static str, value: &str| {
+                value.parse::<i64>().map_err(|_| invalid_column(column))
+            };
+
+            Ok(recon_api::SettlementReportRow {
+                record_type: storage_enums::SettlementRecordType::from_str(get_required_column(
+                    "record_type",
+                )?)
+                .map_err(|_| invalid_column("record_type"))?,
+                connector_transaction_id: get_required_column("connector_transaction_id")?
+                    .to_string(),
+                currency: storage_enums::Currency::from_str(get_required_column("currency")?)
+                    .map_err(|_| invalid_column("currency"))?,
+                gross_amount: parse_amount("gross_amount", get_required_column("gross_amount")?)?,
+                fee_amount: get_column("fee_amount")
+                    .map(|value| parse_amount("fee_amount", value))
+                    .transpose()?,
+                net_amount: get_column("net_amount")
+                    .map(|value| parse_amount("net_amount", value))
+                    .transpose()?,
+                settled_at: get_column("settled_at")
+                    .map(|value| {
+                        PrimitiveDateTime::parse(
+                            value,
+                            &time::format_description::well_known::Iso8601::DEFAULT,
+                        )
+                        .map_err(|_| invalid_column("settled_at"))
+                    })
+                    .transpose()?,
+            })
+        })
+        .collect::<Result<Vec<_>, errors::ApiErrorResponse>>()
+        .into_report()
+}
+
+fn get_match_summary(
+    settlement_records: &[storage::SettlementRecord],
+) -> recon_api::SettlementMatchSummary {
+    settlement_records.iter().fold(
+        recon_api::SettlementMatchSummary {
+            total_count: settlement_records.len(),
+            ..Default::default()
+        },
+        |mut summary, settlement_record| {
+            match settlement_record.match_status {
+                storage_enums::SettlementMatchStatus::Matched => summary.matched += 1,
+                storage_enums::SettlementMatchStatus::AmountMismatch => {
+                    summary.amount_mismatch += 1
+                }
+                storage_enums::SettlementMatchStatus::CurrencyMismatch => {
+                    summary.currency_mismatch += 1
+                }
+                storage_enums::SettlementMatchStatus::FeeMismatch => summary.fee_mismatch += 1,
+                storage_enums::SettlementMatchStatus::Unmatched => summary.unmatched += 1,
+            }
+            summary
+        },
+    )
+}
+
+fn to_settlement_record_response(
+    settlement_record: storage::SettlementRecord,
+) -> recon_api::SettlementRecordResponse {
+    recon_api::SettlementRecordResponse {
+        record_id: settlement_record.record_id,
+        report_id: settlement_record.report_id,
+        connector: settlement_record.connector,
+        record_type: settlement_record.record_type,
+        connector_transaction_id: settlement_record.connector_transaction_id,
+        currency: settlement_record.currency,
+        gross_amount: settlement_record.gross_amount,
+        fee_amount: settlement_record.fee_amount,
+        net_amount: settlement_record.net_amount,
+        settled_at: settlement_record.settled_at,
+        payment_id: settlement_record.payment_id,
+        attempt_id: settlement_record.attempt_id,
+        refund_id: settlement_record.refund_id,
+        expected_amount: settlement_record.expected_amount,
+        match_status: settlement_record.match_status,
+        created_at: settlement_record.created_at,
+        modified_at: settlement_record.modified_at,
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn upload_settlement_report(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: recon_api::SettlementReportUploadRequest,
+) -> RouterResponse<recon_api::SettlementReportUploadResponse> {
+    let rows = match (req.csv_data, req.rows) {
+        (Some(csv_data), None) => parse_csv_report(&csv_data)?,
+        (None, Some(rows)) => rows,
+        _ => Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "Exactly one of `csv_data` or `rows` must be provided".to_string(),
+        })?,
+    };
+    utils::when(rows.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "The settlement report has no rows".to_string(),
+        })
+        .into_report()
+    })?;
+
+    let db = &*state.store;
+    let connector = req.connector.to_string();
+    let report_id = req
+        .report_id
+        .unwrap_or_else(|| utils::generate_id(consts::ID_LENGTH, "setl_rpt"));
+
+    let mut settlement_records = Vec::with_capacity(rows.len());
+    for row in rows {
+        settlement_records.push(
+            store_settlement_record(db, &merchant_account, &report_id, &connector, row).await?,
+        );
+    }
+
+    Ok(ApplicationResponse::Json(
+        recon_api::SettlementReportUploadResponse {
+            report_id,
+            connector,
+            summary: get_match_summary(&settlement_records),
+        },
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_settlement_record(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    record_id: String,
+) -> RouterResponse<recon_api::SettlementRecordResponse> {
+    let settlement_record = state
+        .store
+        .find_settlement_record_by_merchant_id_record_id(&merchant_account.merchant_id, &record_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Settlement record not found".to_string(),
+        })?;
+
+    Ok(ApplicationResponse::Json(to_settlement_record_response(
+        settlement_record,
+    )))
+}
+
+#[instrument(skip(state))]
+pub async fn list_settlement_records(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    constraints: recon_api::SettlementRecordListConstraints,
+) -> RouterResponse<Vec<recon_api::SettlementRecordResponse>> {
+    let settlement_records = state
+        .store
+        .list_settlement_records_by_merchant_id_constraints(
+            &merchant_account.merchant_id,
+            storage::SettlementRecordListConstraints {
+                report_id: constraints.report_id,
+                connector: constraints.connector,
+                match_status: constraints
+                    .match_status
+                    .map(|match_status| vec![match_status]),
+                limit: constraints.limit,
+                offset: constraints.offset,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the settlement records")?;
+
+    Ok(ApplicationResponse::Json(
+        settlement_records
+            .into_iter()
+            .map(to_settlement_record_response)
+            .collect(),
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn get_unreconciled_items_report(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: recon_api::UnreconciledItemsReportRequest,
+) -> RouterResponse<recon_api::UnreconciledItemsReport> {
+    let db = &*state.store;
+    let settlement_records = db
+        .list_settlement_records_by_merchant_id_constraints(
+            &merchant_account.merchant_id,
+            storage::SettlementRecordListConstraints {
+                report_id: req.report_id.clone(),
+                connector: req.connector.clone(),
+                ..Default::default()
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the settlement records")?;
+
+    let unreconciled_records = db
+        .list_settlement_records_by_merchant_id_constraints(
+            &merchant_account.merchant_id,
+            storage::SettlementRecordListConstraints {
+                report_id: req.report_id,
+                connector: req.connector,
+                match_status: Some(vec![
+                    storage_enums::SettlementMatchStatus::AmountMismatch,
+                    storage_enums::SettlementMatchStatus::CurrencyMismatch,
+                    storage_enums::SettlementMatchStatus::FeeMismatch,
+                    storage_enums::SettlementMatchStatus::Unmatched,
+                ]),
+                limit: req.limit,
+                offset: req.offset,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list the unreconciled settlement records")?;
+
+    Ok(ApplicationResponse::Json(
+        recon_api::UnreconciledItemsReport {
+            summary: get_match_summary(&settlement_records),
+            records: unreconciled_records
+                .into_iter()
+                .map(to_settlement_record_response)
+                .collect(),
+        },
+    ))
+}
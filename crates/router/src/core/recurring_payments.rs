@@ -0,0 +1,463 @@
+use api_models::{
+    enums as api_enums,
+    recurring_payments::{
+        RecurringInterval, RecurringPaymentScheduleCreateRequest, RecurringPaymentScheduleId,
+        RecurringPaymentScheduleResponse, RecurringPaymentScheduleStatus,
+    },
+};
+use common_utils::{ext_traits::ValueExt, fp_utils::when, pii};
+use diesel_models::enums as storage_enums;
+use error_stack::{report, IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::{instrument, tracing};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+    routes::{metrics, AppState},
+    services::ApplicationResponse,
+    types::{api, domain, storage},
+    utils,
+};
+
+const RECURRING_PAYMENT_TASK_NAME: &str = "RECURRING_PAYMENT";
+const RECURRING_PAYMENT_TAG: &str = "RECURRING_PAYMENT";
+const RECURRING_PAYMENT_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::RecurringPaymentWorkflow;
+
+pub const RECURRING_PAYMENT_COMPLETED: &str = "COMPLETED_BY_PT";
+pub const RECURRING_PAYMENT_CANCELLED: &str = "CANCELLED_BY_MERCHANT";
+pub const RECURRING_PAYMENT_DUNNING_EXHAUSTED: &str = "DUNNING_EXHAUSTED";
+pub const RECURRING_PAYMENT_MANDATE_INACTIVE: &str = "MANDATE_INACTIVE";
+
+/// Tracking data of the recurring payment process tracker task. The task itself acts as the
+/// schedule, it is rescheduled after every charge and finished once the schedule ends.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RecurringPaymentTrackingData {
+    pub schedule_id: String,
+    pub merchant_id: String,
+    pub customer_id: String,
+    pub mandate_id: String,
+    pub amount: i64,
+    pub currency: api_enums::Currency,
+    pub interval: RecurringInterval,
+    pub interval_count: u16,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    pub start_time: PrimitiveDateTime,
+    pub total_charges: Option<u32>,
+    pub charges_made: u32,
+    pub dunning_retry_intervals_in_hours: Vec<u32>,
+    pub dunning_attempts_made: u32,
+    pub description: Option<String>,
+    pub profile_id: Option<String>,
+    pub metadata: Option<pii::SecretSerdeValue>,
+    pub last_payment_id: Option<String>,
+}
+
+impl RecurringPaymentTrackingData {
+    /// Payment ID of the current attempt, derived from the schedule so that a re-run of the same
+    /// attempt cannot charge the customer twice.
+    pub fn get_payment_id_for_current_attempt(&self) -> String {
+        format!(
+            "{}_{}_{}",
+            self.schedule_id,
+            self.charges_made.saturating_add(1),
+            self.dunning_attempts_made
+        )
+    }
+
+    pub fn get_next_charge_time(&self) -> Option<PrimitiveDateTime> {
+        get_charge_time(
+            self.start_time,
+            self.interval,
+            self.interval_count,
+            self.charges_made,
+        )
+    }
+
+    pub fn is_schedule_complete(&self) -> bool {
+        self.total_charges
+            .is_some_and(|total_charges| self.charges_made >= total_charges)
+    }
+
+    /// Constructs the off-session payment request for the current attempt. Information about the
+    /// schedule is added to the payment metadata so that the merchant can associate the payment
+    /// webhooks with the schedule.
+    pub fn get_payment_request(&self) -> api::PaymentsRequest {
+        let mut metadata = self
+            .metadata
+            .as_ref()
+            .and_then(|metadata| match metadata.peek() {
+                serde_json::Value::Object(map) => Some(map.clone()),
+                _ => None,
+            })
+            .unwrap_or_default();
+        metadata.insert(
+            "recurring_schedule_id".to_string(),
+            self.schedule_id.clone().into(),
+        );
+        metadata.insert(
+            "recurring_charge_number".to_string(),
+            self.charges_made.saturating_add(1).into(),
+        );
+        metadata.insert(
+            "recurring_dunning_attempt".to_string(),
+            self.dunning_attempts_made.into(),
+        );
+
+        api::PaymentsRequest {
+            payment_id: Some(api::PaymentIdType::PaymentIntentId(
+                self.get_payment_id_for_current_attempt(),
+            )),
+            merchant_id: Some(self.merchant_id.clone()),
+            amount: Some(self.amount.into()),
+            currency: Some(self.currency),
+            customer_id: Some(self.customer_id.clone()),
+            mandate_id: Some(self.mandate_id.clone()),
+            off_session: Some(true),
+            confirm: Some(true),
+            description: self.description.clone(),
+            profile_id: self.profile_id.clone(),
+            metadata: Some(Secret::new(serde_json::Value::Object(metadata))),
+            ..Default::default()
+        }
+    }
+}
+
+/// Computes the time of the charge following `charges_made` successful charges. Charge times are
+/// always computed from the start time, so that month end dates do not drift across cycles.
+pub fn get_charge_time(
+    start_time: PrimitiveDateTime,
+    interval: RecurringInterval,
+    interval_count: u16,
+    charges_made: u32,
+) -> Option<PrimitiveDateTime> {
+    let units = i64::from(interval_count).checked_mul(i64::from(charges_made))?;
+    match interval {
+        RecurringInterval::Day => start_time.checked_add(time::Duration::days(units)),
+        RecurringInterval::Week => start_time.checked_add(time::Duration::weeks(units)),
+        RecurringInterval::Month => add_months(start_time, units),
+        RecurringInterval::Year => add_months(start_time, units.checked_mul(12)?),
+    }
+}
+
+fn add_months(date_time: PrimitiveDateTime, months: i64) -> Option<PrimitiveDateTime> {
+    let month_index = i64::from(date_time.year())
+        .checked_mul(12)?
+        .checked_add(i64::from(u8::from(date_time.month())) - 1)?
+        .checked_add(months)?;
+    let year = i32::try_from(month_index.div_euclid(12)).ok()?;
+    let month = time::Month::try_from(u8::try_from(month_index.rem_euclid(12) + 1).ok()?).ok()?;
+    let day = date_time
+        .day()
+        .min(time::util::days_in_year_month(year, month));
+    let date = time::Date::from_calendar_date(year, month, day).ok()?;
+
+    Some(PrimitiveDateTime::new(date, date_time.time()))
+}
+
+pub fn generate_task_id_for_recurring_payment_workflow(schedule_id: &str) -> String {
+    format!("{RECURRING_PAYMENT_TASK_NAME}_{schedule_id}")
+}
+
+#[instrument(skip(state))]
+pub async fn create_recurring_payment_schedule(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: RecurringPaymentScheduleCreateRequest,
+) -> RouterResponse<RecurringPaymentScheduleResponse> {
+    let db = state.store.as_ref();
+    let recurring_payments_config = &state.conf.recurring_payments;
+    let current_time = common_utils::date_time::now();
+
+    when(req.amount <= 0, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "amount must be greater than 0".to_string(),
+        })
+        .into_report()
+    })?;
+
+    let interval_count = req.interval_count.unwrap_or(1);
+    when(interval_count == 0, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "interval_count must be greater than 0".to_string(),
+        })
+        .into_report()
+    })?;
+
+    when(req.total_charges == Some(0), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "total_charges must be greater than 0".to_string(),
+        })
+        .into_report()
+    })?;
+
+    let dunning_retry_intervals_in_hours =
+        req.dunning_retry_intervals_in_hours.unwrap_or_else(|| {
+            recurring_payments_config
+                .dunning_retry_intervals_in_hours
+                .clone()
+        });
+    when(
+        dunning_retry_intervals_in_hours.len() > recurring_payments_config.max_dunning_retries,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "dunning_retry_intervals_in_hours must not have more than {} entries",
+                    recurring_payments_config.max_dunning_retries
+                ),
+            })
+            .into_report()
+        },
+    )?;
+    when(
+        dunning_retry_intervals_in_hours
+            .iter()
+            .any(|interval| *interval == 0),
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "dunning_retry_intervals_in_hours must be greater than 0".to_string(),
+            })
+            .into_report()
+        },
+    )?;
+
+    let start_time = req.start_time.unwrap_or(current_time);
+    when(start_time < current_time, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "start_time must not be in the past".to_string(),
+        })
+        .into_report()
+    })?;
+
+    let mandate = db
+        .find_mandate_by_merchant_id_mandate_id(&merchant_account.merchant_id, &req.mandate_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::MandateNotFound)?;
+
+    when(mandate.customer_id != req.customer_id, || {
+        Err(errors::ApiErrorResponse::MandateValidationFailed {
+            reason: "mandate does not belong to the customer".to_string(),
+        })
+        .into_report()
+    })?;
+
+    when(
+        mandate.mandate_status != storage_enums::MandateStatus::Active,
+        || {
+            Err(errors::ApiErrorResponse::MandateValidationFailed {
+                reason: "mandate is not active".to_string(),
+            })
+            .into_report()
+        },
+    )?;
+
+    let tracking_data = RecurringPaymentTrackingData {
+        schedule_id: utils::generate_id(consts::ID_LENGTH, "rps"),
+        merchant_id: merchant_account.merchant_id.clone(),
+        customer_id: req.customer_id,
+        mandate_id: req.mandate_id,
+        amount: req.amount,
+        currency: req.currency,
+        interval: req.interval,
+        interval_count,
+        start_time,
+        total_charges: req.total_charges,
+        charges_made: 0,
+        dunning_retry_intervals_in_hours,
+        dunning_attempts_made: 0,
+        description: req.description,
+        profile_id: req.profile_id,
+        metadata: req.metadata,
+        last_payment_id: None,
+    };
+
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        generate_task_id_for_recurring_payment_workflow(&tracking_data.schedule_id),
+        RECURRING_PAYMENT_TASK_NAME,
+        RECURRING_PAYMENT_RUNNER,
+        [RECURRING_PAYMENT_TAG],
+        tracking_data.clone(),
+        start_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct recurring payment process tracker task")?;
+
+    let process = db
+        .insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting recurring payment task to process_tracker: schedule_id: {}",
+                tracking_data.schedule_id
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes("flow", "RecurringPayment")],
+    );
+
+    Ok(ApplicationResponse::Json(get_schedule_response(
+        &process,
+        tracking_data,
+    )))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_recurring_payment_schedule(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: RecurringPaymentScheduleId,
+) -> RouterResponse<RecurringPaymentScheduleResponse> {
+    let (process, tracking_data) =
+        find_recurring_payment_schedule(&state, &merchant_account.merchant_id, &req.schedule_id)
+            .await?;
+
+    Ok(ApplicationResponse::Json(get_schedule_response(
+        &process,
+        tracking_data,
+    )))
+}
+
+#[instrument(skip(state))]
+pub async fn cancel_recurring_payment_schedule(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: RecurringPaymentScheduleId,
+) -> RouterResponse<RecurringPaymentScheduleResponse> {
+    let (process, tracking_data) =
+        find_recurring_payment_schedule(&state, &merchant_account.merchant_id, &req.schedule_id)
+            .await?;
+
+    when(
+        process.status == storage_enums::ProcessTrackerStatus::Finish,
+        || {
+            Err(errors::ApiErrorResponse::PreconditionFailed {
+                message: "recurring payment schedule is no longer active".to_string(),
+            })
+            .into_report()
+        },
+    )?;
+
+    let process = state
+        .store
+        .update_process(
+            process,
+            storage::ProcessTrackerUpdate::StatusUpdate {
+                status: storage_enums::ProcessTrackerStatus::Finish,
+                business_status: Some(RECURRING_PAYMENT_CANCELLED.to_string()),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to cancel recurring payment task in process tracker")?;
+
+    Ok(ApplicationResponse::Json(get_schedule_response(
+        &process,
+        tracking_data,
+    )))
+}
+
+async fn find_recurring_payment_schedule(
+    state: &AppState,
+    merchant_id: &str,
+    schedule_id: &str,
+) -> RouterResult<(storage::ProcessTracker, RecurringPaymentTrackingData)> {
+    let not_found_error = || {
+        report!(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Recurring payment schedule does not exist in our records".to_string(),
+        })
+    };
+
+    let process = state
+        .store
+        .find_process_by_id(&generate_task_id_for_recurring_payment_workflow(
+            schedule_id,
+        ))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to retrieve recurring payment task from process tracker")?
+        .ok_or_else(not_found_error)?;
+
+    let tracking_data: RecurringPaymentTrackingData = process
+        .tracking_data
+        .clone()
+        .parse_value("RecurringPaymentTrackingData")
+        .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    // Schedules of other merchants are reported as not found
+    when(tracking_data.merchant_id != merchant_id, || {
+        Err(not_found_error())
+    })?;
+
+    Ok((process, tracking_data))
+}
+
+fn get_schedule_response(
+    process: &storage::ProcessTracker,
+    tracking_data: RecurringPaymentTrackingData,
+) -> RecurringPaymentScheduleResponse {
+    let status = match process.status {
+        storage_enums::ProcessTrackerStatus::Finish => match process.business_status.as_str() {
+            RECURRING_PAYMENT_COMPLETED => RecurringPaymentScheduleStatus::Completed,
+            RECURRING_PAYMENT_CANCELLED => RecurringPaymentScheduleStatus::Cancelled,
+            _ => RecurringPaymentScheduleStatus::Failed,
+        },
+        _ => RecurringPaymentScheduleStatus::Active,
+    };
+    let next_attempt_at = match status {
+        RecurringPaymentScheduleStatus::Active => process.schedule_time,
+        RecurringPaymentScheduleStatus::Completed
+        | RecurringPaymentScheduleStatus::Cancelled
+        | RecurringPaymentScheduleStatus::Failed => None,
+    };
+
+    RecurringPaymentScheduleResponse {
+        schedule_id: tracking_data.schedule_id,
+        merchant_id: tracking_data.merchant_id,
+        customer_id: tracking_data.customer_id,
+        mandate_id: tracking_data.mandate_id,
+        amount: tracking_data.amount,
+        currency: tracking_data.currency,
+        interval: tracking_data.interval,
+        interval_count: tracking_data.interval_count,
+        status,
+        total_charges: tracking_data.total_charges,
+        charges_made: tracking_data.charges_made,
+        dunning_attempts_made: tracking_data.dunning_attempts_made,
+        dunning_retry_intervals_in_hours: tracking_data.dunning_retry_intervals_in_hours,
+        next_attempt_at,
+        last_payment_id: tracking_data.last_payment_id,
+        created_at: process.created_at,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_monthly_charge_time_is_clamped_to_month_end() {
+        let start_time = datetime!(2024-01-31 10:00);
+
+        assert_eq!(
+            get_charge_time(start_time, RecurringInterval::Month, 1, 1).unwrap(),
+            datetime!(2024-02-29 10:00)
+        );
+        assert_eq!(
+            get_charge_time(start_time, RecurringInterval::Month, 1, 2).unwrap(),
+            datetime!(2024-03-31 10:00)
+        );
+        assert_eq!(
+            get_charge_time(start_time, RecurringInterval::Year, 1, 1).unwrap(),
+            datetime!(2025-01-31 10:00)
+        );
+    }
+}
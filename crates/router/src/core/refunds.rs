@@ -1,6 +1,9 @@
+#[cfg(feature = "payouts")]
+pub mod payout_refund;
+pub mod retry;
 pub mod validator;
 
-use common_utils::ext_traits::AsyncExt;
+use common_utils::ext_traits::{AsyncExt, StringExt};
 use error_stack::{report, IntoReport, ResultExt};
 use router_env::{instrument, tracing};
 use scheduler::{consumer::types::process_data, utils as process_tracker_utils};
@@ -23,7 +26,6 @@ use crate::{
         transformers::{ForeignFrom, ForeignInto},
     },
     utils::{self, OptionExt},
-    workflows::payment_sync,
 };
 
 // ********************************************** REFUND EXECUTE **********************************************
@@ -33,6 +35,7 @@ pub async fn refund_create_core(
     state: AppState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
+    profile_id: Option<String>,
     req: refunds::RefundRequest,
 ) -> RouterResponse<refunds::RefundResponse> {
     let db = &*state.store;
@@ -40,6 +43,16 @@ pub async fn refund_create_core(
 
     merchant_id = &merchant_account.merchant_id;
 
+    utils::when(
+        req.reason_code == Some(enums::RefundReasonCode::Other) && req.reason.is_none(),
+        || {
+            Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "reason"
+            })
+            .attach_printable("reason is required when the reason_code is other"))
+        },
+    )?;
+
     payment_intent = db
         .find_payment_intent_by_payment_id_merchant_id(
             &req.payment_id,
@@ -49,6 +62,12 @@ pub async fn refund_create_core(
         .await
         .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
 
+    core_utils::validate_profile_id_of_resource(
+        profile_id.as_ref(),
+        payment_intent.profile_id.as_ref(),
+        errors::ApiErrorResponse::PaymentNotFound,
+    )?;
+
     utils::when(
         !(payment_intent.status == enums::IntentStatus::Succeeded
             || payment_intent.status == enums::IntentStatus::PartiallyCaptured),
@@ -120,7 +139,15 @@ pub async fn refund_create_core(
     .map(services::ApplicationResponse::Json)
 }
 
-#[instrument(skip_all)]
+#[instrument(
+    skip_all,
+    fields(
+        hyperswitch.merchant_id_hash,
+        hyperswitch.payment_id,
+        hyperswitch.connector,
+        hyperswitch.attempt_status
+    )
+)]
 pub async fn trigger_refund_to_gateway(
     state: &AppState,
     refund: &storage::Refund,
@@ -136,6 +163,12 @@ pub async fn trigger_refund_to_gateway(
         .ok_or(errors::ApiErrorResponse::InternalServerError)
         .into_report()
         .attach_printable("Failed to retrieve connector from payment attempt")?;
+    router_env::record_span_attributes!(
+        merchant_id = &merchant_account.merchant_id,
+        payment_id = &payment_attempt.payment_id,
+        connector = &routed_through,
+        attempt_status = &payment_attempt.status,
+    );
 
     let storage_scheme = merchant_account.storage_scheme;
     metrics::REFUND_COUNT.add(
@@ -255,12 +288,7 @@ pub async fn trigger_refund_to_gateway(
     };
 
     let refund_update = match router_data_res.response {
-        Err(err) => storage::RefundUpdate::ErrorUpdate {
-            refund_status: Some(enums::RefundStatus::Failure),
-            refund_error_message: err.reason.or(Some(err.message)),
-            refund_error_code: Some(err.code),
-            updated_by: storage_scheme.to_string(),
-        },
+        Err(err) => retry::get_refund_error_update(refund, err, &state.conf.refund, storage_scheme),
         Ok(response) => {
             if response.refund_status == diesel_models::enums::RefundStatus::Success {
                 metrics::SUCCESSFUL_REFUND.add(
@@ -278,6 +306,9 @@ pub async fn trigger_refund_to_gateway(
                 sent_to_gateway: true,
                 refund_error_message: None,
                 refund_arn: "".to_string(),
+                retry_status: refund
+                    .retry_status
+                    .map(|_| enums::RefundRetryStatus::Completed),
                 updated_by: storage_scheme.to_string(),
             }
         }
@@ -307,16 +338,17 @@ pub async fn refund_response_wrapper<'a, F, Fut, T, Req>(
     state: AppState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
+    profile_id: Option<String>,
     request: Req,
     f: F,
 ) -> RouterResponse<refunds::RefundResponse>
 where
-    F: Fn(AppState, domain::MerchantAccount, domain::MerchantKeyStore, Req) -> Fut,
+    F: Fn(AppState, domain::MerchantAccount, domain::MerchantKeyStore, Option<String>, Req) -> Fut,
     Fut: futures::Future<Output = RouterResult<T>>,
     T: ForeignInto<refunds::RefundResponse>,
 {
     Ok(services::ApplicationResponse::Json(
-        f(state, merchant_account, key_store, request)
+        f(state, merchant_account, key_store, profile_id, request)
             .await?
             .foreign_into(),
     ))
@@ -327,6 +359,7 @@ pub async fn refund_retrieve_core(
     state: AppState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
+    profile_id: Option<String>,
     request: refunds::RefundsRetrieveRequest,
 ) -> RouterResult<storage::Refund> {
     let refund_id = request.refund_id;
@@ -344,6 +377,12 @@ pub async fn refund_retrieve_core(
         .await
         .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
 
+    core_utils::validate_profile_id_of_resource(
+        profile_id.as_ref(),
+        refund.profile_id.as_ref(),
+        errors::ApiErrorResponse::RefundNotFound,
+    )?;
+
     let payment_id = refund.payment_id.as_str();
     payment_intent = db
         .find_payment_intent_by_payment_id_merchant_id(
@@ -382,7 +421,11 @@ pub async fn refund_retrieve_core(
         .await
         .transpose()?;
 
-    response = if should_call_refund(&refund, request.force_sync.unwrap_or(false)) {
+    let force_sync = request.force_sync.unwrap_or(false);
+    response = if refund.refund_type == enums::RefundType::PayoutRefund {
+        sync_refund_payout_if_required(&state, &merchant_account, &key_store, refund, force_sync)
+            .await
+    } else if should_call_refund(&refund, force_sync) {
         sync_refund_with_gateway(
             &state,
             &merchant_account,
@@ -400,6 +443,32 @@ pub async fn refund_retrieve_core(
     Ok(response)
 }
 
+async fn sync_refund_payout_if_required(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    refund: storage::Refund,
+    force_sync: bool,
+) -> RouterResult<storage::Refund> {
+    let is_terminal = matches!(
+        refund.refund_status,
+        enums::RefundStatus::Failure | enums::RefundStatus::Success
+    );
+    if is_terminal && !force_sync {
+        return Ok(refund);
+    }
+
+    #[cfg(feature = "payouts")]
+    {
+        payout_refund::sync_refund_payout(state, merchant_account, key_store, refund).await
+    }
+    #[cfg(not(feature = "payouts"))]
+    {
+        let _ = (state, merchant_account, key_store);
+        Ok(refund)
+    }
+}
+
 fn should_call_refund(refund: &diesel_models::refund::Refund, force_sync: bool) -> bool {
     // This implies, we cannot perform a refund sync & `the connector_refund_id`
     // doesn't exist
@@ -500,6 +569,7 @@ pub async fn sync_refund_with_gateway(
             sent_to_gateway: true,
             refund_error_message: None,
             refund_arn: "".to_string(),
+            retry_status: None,
             updated_by: storage_scheme.to_string(),
         },
     };
@@ -527,6 +597,7 @@ pub async fn sync_refund_with_gateway(
 pub async fn refund_update_core(
     state: AppState,
     merchant_account: domain::MerchantAccount,
+    profile_id: Option<String>,
     req: refunds::RefundUpdateRequest,
 ) -> RouterResponse<refunds::RefundResponse> {
     let db = state.store.as_ref();
@@ -539,6 +610,12 @@ pub async fn refund_update_core(
         .await
         .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)?;
 
+    core_utils::validate_profile_id_of_resource(
+        profile_id.as_ref(),
+        refund.profile_id.as_ref(),
+        errors::ApiErrorResponse::RefundNotFound,
+    )?;
+
     let response = db
         .update_refund(
             refund,
@@ -640,6 +717,51 @@ pub async fn validate_and_create_refund(
         .into_report()
         .attach_printable("No connector populated in payment attempt")?;
 
+    let connector_data = api::ConnectorData::get_connector_by_name(
+        &state.conf.connectors,
+        &connector,
+        api::GetToken::Connector,
+        payment_attempt.merchant_connector_id.clone(),
+    )?;
+    let refund_capabilities_check = validator::validate_refund_against_connector_capabilities(
+        connector_data
+            .connector
+            .is_refund_supported(payment_attempt.payment_method),
+        connector_data.connector.is_partial_refund_supported(),
+        connector_data.connector.is_multiple_refunds_supported(),
+        total_amount_captured,
+        &all_refunds,
+        refund_amount,
+    );
+
+    #[cfg(feature = "payouts")]
+    let alternate_destination = req.alternate_destination.clone();
+    #[cfg(feature = "payouts")]
+    let has_alternate_destination = alternate_destination.is_some();
+    #[cfg(not(feature = "payouts"))]
+    let has_alternate_destination = false;
+
+    let refund_destination = validator::get_refund_destination(
+        req.refund_destination.unwrap_or_default(),
+        has_alternate_destination,
+        refund_capabilities_check,
+        &connector,
+    )?;
+
+    let refund_type_to_store = match refund_destination {
+        refunds::RefundDestination::OriginalPaymentMethod => {
+            req.refund_type.unwrap_or_default().foreign_into()
+        }
+        refunds::RefundDestination::AlternatePaymentMethod => {
+            utils::when(payment_intent.customer_id.is_none(), || {
+                Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                    message: "Refunds to an alternate payment method are only supported for payments of a customer".to_string(),
+                }))
+            })?;
+            enums::RefundType::PayoutRefund
+        }
+    };
+
     let refund_create_req = storage::RefundNew::default()
         .set_refund_id(refund_id.to_string())
         .set_internal_reference_id(utils::generate_id(consts::ID_LENGTH, "refid"))
@@ -648,7 +770,7 @@ pub async fn validate_and_create_refund(
         .set_merchant_id(merchant_account.merchant_id.clone())
         .set_connector_transaction_id(connecter_transaction_id.to_string())
         .set_connector(connector)
-        .set_refund_type(req.refund_type.unwrap_or_default().foreign_into())
+        .set_refund_type(refund_type_to_store)
         .set_total_amount(payment_attempt.amount)
         .set_refund_amount(refund_amount)
         .set_currency(currency)
@@ -659,6 +781,7 @@ pub async fn validate_and_create_refund(
         .set_description(req.reason.clone())
         .set_attempt_id(payment_attempt.attempt_id.clone())
         .set_refund_reason(req.reason)
+        .set_refund_reason_code(req.reason_code)
         .set_profile_id(payment_intent.profile_id.clone())
         .set_merchant_connector_id(payment_attempt.merchant_connector_id.clone())
         .to_owned();
@@ -667,19 +790,45 @@ pub async fn validate_and_create_refund(
         .insert_refund(refund_create_req, merchant_account.storage_scheme)
         .await
     {
-        Ok(refund) => {
-            schedule_refund_execution(
-                state,
-                refund.clone(),
-                refund_type,
-                merchant_account,
-                key_store,
-                payment_attempt,
-                payment_intent,
-                creds_identifier,
-            )
-            .await?
-        }
+        Ok(refund) => match refund_destination {
+            refunds::RefundDestination::OriginalPaymentMethod => {
+                schedule_refund_execution(
+                    state,
+                    refund.clone(),
+                    refund_type,
+                    merchant_account,
+                    key_store,
+                    payment_attempt,
+                    payment_intent,
+                    creds_identifier,
+                )
+                .await?
+            }
+            #[cfg(feature = "payouts")]
+            refunds::RefundDestination::AlternatePaymentMethod => {
+                let alternate_destination =
+                    alternate_destination.get_required_value("alternate_destination")?;
+                logger::info!(
+                    refund_id = %refund.refund_id,
+                    "Refunding to an alternate payment method through a payout"
+                );
+                payout_refund::trigger_refund_payout(
+                    state,
+                    merchant_account,
+                    key_store,
+                    payment_intent,
+                    refund,
+                    alternate_destination,
+                )
+                .await?
+            }
+            #[cfg(not(feature = "payouts"))]
+            refunds::RefundDestination::AlternatePaymentMethod => {
+                Err(report!(errors::ApiErrorResponse::NotSupported {
+                    message: "Refunds to an alternate payment method".to_string(),
+                }))?
+            }
+        },
         Err(err) => {
             if err.current_context().is_db_unique_violation() {
                 db.find_refund_by_merchant_id_refund_id(
@@ -710,11 +859,13 @@ pub async fn validate_and_create_refund(
 pub async fn refund_list(
     state: AppState,
     merchant_account: domain::MerchantAccount,
-    req: api_models::refunds::RefundListRequest,
+    profile_id: Option<String>,
+    mut req: api_models::refunds::RefundListRequest,
 ) -> RouterResponse<api_models::refunds::RefundListResponse> {
     let db = state.store;
     let limit = validator::validate_refund_list(req.limit)?;
     let offset = req.offset.unwrap_or_default();
+    req.profile_id = core_utils::validate_profile_id_from_auth_layer(profile_id, req.profile_id)?;
 
     let refund_list = db
         .filter_refund_by_constraints(
@@ -773,12 +924,17 @@ pub async fn refund_filter_list(
 impl ForeignFrom<storage::Refund> for api::RefundResponse {
     fn foreign_from(refund: storage::Refund) -> Self {
         let refund = refund;
+        let refund_destination = match refund.refund_type {
+            enums::RefundType::PayoutRefund => refunds::RefundDestination::AlternatePaymentMethod,
+            _ => refunds::RefundDestination::OriginalPaymentMethod,
+        };
         Self {
             payment_id: refund.payment_id,
             refund_id: refund.refund_id,
             amount: refund.refund_amount,
             currency: refund.currency.to_string(),
             reason: refund.refund_reason,
+            reason_code: refund.refund_reason_code,
             status: refund.refund_status.foreign_into(),
             profile_id: refund.profile_id,
             metadata: refund.metadata,
@@ -788,6 +944,10 @@ impl ForeignFrom<storage::Refund> for api::RefundResponse {
             updated_at: Some(refund.updated_at),
             connector: refund.connector,
             merchant_connector_id: refund.merchant_connector_id,
+            refund_destination,
+            payout_id: refund.payout_id,
+            retry_count: refund.retry_count,
+            retry_status: refund.retry_status,
         }
     }
 }
@@ -833,7 +993,7 @@ pub async fn schedule_refund_execution(
                             Ok(refund)
                         }
                         api_models::refunds::RefundType::Instant => {
-                            trigger_refund_to_gateway(
+                            let updated_refund = trigger_refund_to_gateway(
                                 state,
                                 &refund,
                                 merchant_account,
@@ -842,7 +1002,28 @@ pub async fn schedule_refund_execution(
                                 payment_intent,
                                 creds_identifier,
                             )
-                            .await
+                            .await?;
+
+                            // Refunds failing with a transient connector error are retried later
+                            if updated_refund.retry_status
+                                == Some(enums::RefundRetryStatus::Scheduled)
+                            {
+                                retry::add_refund_retry_task(db, &updated_refund, &state.conf.refund)
+                                    .await
+                                    .attach_printable_lazy(|| format!("Failed while pushing refund retry task in scheduler: refund_id: {}", updated_refund.refund_id))?;
+                            }
+                            // Refunds which settle asynchronously at the connector are synced
+                            // until they reach a terminal status
+                            else if updated_refund.sent_to_gateway
+                                && updated_refund.refund_status == enums::RefundStatus::Pending
+                            {
+                                add_refund_sync_task(db, &updated_refund, runner)
+                                    .await
+                                    .change_context(errors::ApiErrorResponse::InternalServerError)
+                                    .attach_printable_lazy(|| format!("Failed while pushing refund sync task in scheduler: refund_id: {}", updated_refund.refund_id))?;
+                            }
+
+                            Ok(updated_refund)
                         }
                     }
                 }
@@ -905,6 +1086,7 @@ pub async fn sync_refund_with_gateway_workflow(
         state.clone(),
         merchant_account,
         key_store,
+        None,
         refunds::RefundsRetrieveRequest {
             refund_id: refund_core.refund_internal_reference_id,
             force_sync: Some(true),
@@ -929,7 +1111,7 @@ pub async fn sync_refund_with_gateway_workflow(
                 .await?
         }
         _ => {
-            _ = payment_sync::retry_sync_task(
+            _ = retry_refund_sync_task(
                 &*state.store,
                 response.connector,
                 response.merchant_id,
@@ -954,6 +1136,9 @@ pub async fn start_refund_workflow(
         Some("SYNC_REFUND") => {
             Box::pin(sync_refund_with_gateway_workflow(state, refund_tracker)).await
         }
+        Some("RETRY_REFUND") => {
+            Box::pin(retry::trigger_refund_retry_workflow(state, refund_tracker)).await
+        }
         _ => Err(errors::ProcessTrackerError::JobNotFound),
     }
 }
@@ -1033,12 +1218,16 @@ pub async fn trigger_refund_execute_workflow(
                 None,
             )
             .await?;
-            add_refund_sync_task(
-                db,
-                &updated_refund,
-                storage::ProcessTrackerRunner::RefundWorkflowRouter,
-            )
-            .await?;
+            if updated_refund.retry_status == Some(enums::RefundRetryStatus::Scheduled) {
+                retry::add_refund_retry_task(db, &updated_refund, &state.conf.refund).await?;
+            } else {
+                add_refund_sync_task(
+                    db,
+                    &updated_refund,
+                    storage::ProcessTrackerRunner::RefundWorkflowRouter,
+                )
+                .await?;
+            }
         }
         (true, enums::RefundStatus::Pending) => {
             // create sync task
@@ -1082,7 +1271,10 @@ pub async fn add_refund_sync_task(
 ) -> RouterResult<storage::ProcessTracker> {
     let task = "SYNC_REFUND";
     let process_tracker_id = format!("{runner}_{task}_{}", refund.internal_reference_id);
-    let schedule_time = common_utils::date_time::now();
+    let schedule_time =
+        get_refund_sync_process_schedule_time(db, &refund.connector, &refund.merchant_id, 0)
+            .await
+            .unwrap_or_else(common_utils::date_time::now);
     let refund_workflow_tracking_data = refund_to_refund_core_workflow_model(refund);
     let tag = ["REFUND"];
     let process_tracker_entry = storage::ProcessTrackerNew::new(
@@ -1150,30 +1342,215 @@ pub async fn add_refund_execute_task(
     Ok(response)
 }
 
+/// Get the next schedule time of the refund sync
+///
+/// The schedule time can be configured in configs by this key `pt_mapping_refund_sync_adyen`
+/// ```json
+/// {
+///     "default_mapping": {
+///         "start_after": 3600,
+///         "frequency": [21600, 86400],
+///         "count": [4, 5]
+///     },
+///     "max_retries_count": 9
+/// }
+/// ```
+///
+/// This config represents
+///
+/// `start_after`: The first rsync should happen an hour after the refund is sent to the connector
+///
+/// `frequency` and `count`: The next 4 retries should happen every 6 hours, followed by 5 retries
+/// once a day
+///
 pub async fn get_refund_sync_process_schedule_time(
     db: &dyn db::StorageInterface,
     connector: &str,
     merchant_id: &str,
     retry_count: i32,
-) -> Result<Option<time::PrimitiveDateTime>, errors::ProcessTrackerError> {
-    let redis_mapping: errors::CustomResult<process_data::ConnectorPTMapping, errors::RedisError> =
-        db::get_and_deserialize_key(
-            db,
-            &format!("pt_mapping_refund_sync_{connector}"),
-            "ConnectorPTMapping",
-        )
-        .await;
-
-    let mapping = match redis_mapping {
+) -> Option<time::PrimitiveDateTime> {
+    let mapping: errors::CustomResult<process_data::ConnectorPTMapping, errors::StorageError> = db
+        .find_config_by_key(&format!("pt_mapping_refund_sync_{connector}"))
+        .await
+        .map(|value| value.config)
+        .and_then(|config| {
+            config
+                .parse_struct("ConnectorPTMapping")
+                .change_context(errors::StorageError::DeserializationFailed)
+        });
+    let mapping = match mapping {
         Ok(x) => x,
-        Err(err) => {
-            logger::error!("Error: while getting connector mapping: {}", err);
+        Err(error) => {
+            logger::info!(?error, "Refund sync mapping error");
             process_data::ConnectorPTMapping::default()
         }
     };
 
-    let time_delta =
-        process_tracker_utils::get_schedule_time(mapping, merchant_id, retry_count + 1);
+    let time_delta = process_tracker_utils::get_schedule_time(mapping, merchant_id, retry_count);
+
+    process_tracker_utils::get_time_from_delta(time_delta)
+}
+
+/// Schedule the refund sync task for retry
+///
+/// Returns bool which indicates whether this was the last retry or not
+pub async fn retry_refund_sync_task(
+    db: &dyn db::StorageInterface,
+    connector: String,
+    merchant_id: String,
+    pt: storage::ProcessTracker,
+) -> Result<bool, errors::ProcessTrackerError> {
+    let schedule_time =
+        get_refund_sync_process_schedule_time(db, &connector, &merchant_id, pt.retry_count + 1)
+            .await;
+
+    match schedule_time {
+        Some(s_time) => {
+            db.as_scheduler().retry_process(pt, s_time).await?;
+            Ok(false)
+        }
+        None => {
+            db.as_scheduler()
+                .finish_process_with_business_status(pt, "RETRIES_EXCEEDED".to_string())
+                .await?;
+            Ok(true)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use common_utils::date_time;
+
+    use super::*;
+    use crate::{configs::settings, db::StorageImpl};
+
+    fn get_merchant_account() -> domain::MerchantAccount {
+        domain::MerchantAccount {
+            id: None,
+            merchant_id: "merchant_1".to_string(),
+            return_url: None,
+            enable_payment_response_hash: false,
+            payment_response_hash_key: None,
+            redirect_to_merchant_with_http_post: false,
+            merchant_name: None,
+            merchant_details: None,
+            webhook_details: None,
+            sub_merchants_enabled: None,
+            parent_merchant_id: None,
+            publishable_key: None,
+            storage_scheme: enums::MerchantStorageScheme::PostgresOnly,
+            locker_id: None,
+            metadata: None,
+            routing_algorithm: None,
+            primary_business_details: serde_json::json!([]),
+            frm_routing_algorithm: None,
+            created_at: date_time::now(),
+            modified_at: date_time::now(),
+            intent_fulfillment_time: None,
+            payout_routing_algorithm: None,
+            organization_id: "org_1".to_string(),
+            is_recon_enabled: false,
+            default_profile: None,
+            recon_status: diesel_models::enums::ReconStatus::NotRequested,
+            payment_link_config: None,
+            deleted_at: None,
+        }
+    }
+
+    async fn get_merchant_key_store() -> domain::MerchantKeyStore {
+        let key = services::generate_aes256_key().unwrap();
+        domain::MerchantKeyStore {
+            merchant_id: "merchant_1".to_string(),
+            key: domain::types::encrypt(key.to_vec().into(), &key)
+                .await
+                .unwrap(),
+            created_at: date_time::now(),
+        }
+    }
+
+    async fn get_state_with_refund_of_profile(profile_id: &str) -> AppState {
+        let conf = settings::Settings::new().unwrap();
+        let tx = tokio::sync::oneshot::channel().0;
+        let state = Box::pin(AppState::with_storage(
+            conf,
+            StorageImpl::Mock,
+            tx,
+            Box::new(services::MockApiClient),
+        ))
+        .await;
+        state
+            .store
+            .insert_refund(
+                storage::RefundNew {
+                    refund_id: "refund_1".to_string(),
+                    payment_id: "pay_1".to_string(),
+                    merchant_id: "merchant_1".to_string(),
+                    profile_id: Some(profile_id.to_string()),
+                    ..Default::default()
+                },
+                enums::MerchantStorageScheme::PostgresOnly,
+            )
+            .await
+            .unwrap();
+        state
+    }
+
+    #[tokio::test]
+    async fn test_refund_of_another_profile_is_not_found_by_id() {
+        let state = get_state_with_refund_of_profile("profile_1").await;
+
+        let retrieved = Box::pin(refund_retrieve_core(
+            state.clone(),
+            get_merchant_account(),
+            get_merchant_key_store().await,
+            Some("profile_2".to_string()),
+            refunds::RefundsRetrieveRequest {
+                refund_id: "refund_1".to_string(),
+                force_sync: None,
+                merchant_connector_details: None,
+            },
+        ))
+        .await;
+        assert!(matches!(
+            retrieved.unwrap_err().current_context(),
+            errors::ApiErrorResponse::RefundNotFound
+        ));
+
+        let updated = refund_update_core(
+            state,
+            get_merchant_account(),
+            Some("profile_2".to_string()),
+            refunds::RefundUpdateRequest {
+                refund_id: "refund_1".to_string(),
+                reason: Some("Customer returned the product".to_string()),
+                metadata: None,
+            },
+        )
+        .await;
+        assert!(matches!(
+            updated.unwrap_err().current_context(),
+            errors::ApiErrorResponse::RefundNotFound
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_refund_of_the_scoped_profile_is_updated_by_id() {
+        let state = get_state_with_refund_of_profile("profile_1").await;
 
-    Ok(process_tracker_utils::get_time_from_delta(time_delta))
+        let updated = refund_update_core(
+            state,
+            get_merchant_account(),
+            Some("profile_1".to_string()),
+            refunds::RefundUpdateRequest {
+                refund_id: "refund_1".to_string(),
+                reason: Some("Customer returned the product".to_string()),
+                metadata: None,
+            },
+        )
+        .await
+        .unwrap();
+        assert!(matches!(updated, services::ApplicationResponse::Json(_)));
+    }
 }
@@ -0,0 +1,159 @@
+//! Refunds to an alternate payment method of the customer.
+//!
+//! Payment methods which cannot be refunded by their connector, can be refunded instead through a
+//! payout of the refund amount to another payment method of the customer. Such refunds are stored
+//! with the `payout_refund` refund type and follow the status of their payout, which is synced
+//! when the refund is retrieved.
+
+use api_models::{payouts, refunds::AlternateRefundDestination};
+use error_stack::{report, ResultExt};
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        payouts as payouts_core,
+    },
+    logger,
+    routes::AppState,
+    services,
+    types::{
+        domain,
+        storage::{self, enums},
+    },
+    utils::OptionExt,
+};
+
+/// Creates and fulfills the payout of the refund to its alternate destination
+#[instrument(skip_all)]
+pub async fn trigger_refund_payout(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    payment_intent: &storage::PaymentIntent,
+    refund: storage::Refund,
+    alternate_destination: AlternateRefundDestination,
+) -> RouterResult<storage::Refund> {
+    let customer_id = payment_intent
+        .customer_id
+        .clone()
+        .get_required_value("customer_id")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Missing customer of the payment refunded through a payout")?;
+
+    let payout_create_request = payouts::PayoutCreateRequest {
+        amount: Some(refund.refund_amount.into()),
+        currency: Some(refund.currency),
+        customer_id: Some(customer_id),
+        connector: alternate_destination.connector,
+        confirm: Some(true),
+        auto_fulfill: Some(true),
+        payout_type: Some(alternate_destination.payout_type),
+        payout_method_data: alternate_destination.payout_method_data,
+        payout_token: alternate_destination.payout_token,
+        description: Some(format!(
+            "Refund {} of the payment {}",
+            refund.refund_id, refund.payment_id
+        )),
+        profile_id: payment_intent.profile_id.clone(),
+        ..Default::default()
+    };
+
+    let refund_update = match Box::pin(payouts_core::payouts_create_core(
+        state.clone(),
+        merchant_account.clone(),
+        key_store.clone(),
+        payout_create_request,
+    ))
+    .await
+    {
+        Ok(services::ApplicationResponse::Json(payout)) => storage::RefundUpdate::PayoutUpdate {
+            payout_id: payout.payout_id,
+            refund_status: get_refund_status_from_payout_status(payout.status),
+            refund_error_message: payout.error_message,
+            refund_error_code: payout.error_code,
+            updated_by: merchant_account.storage_scheme.to_string(),
+        },
+        Ok(_) => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response from the payout of the refund")?,
+        Err(error) => {
+            logger::error!(?error, "Failed to create the payout of the refund");
+            storage::RefundUpdate::ErrorUpdate {
+                refund_status: Some(enums::RefundStatus::Failure),
+                refund_error_message: Some(error.current_context().to_string()),
+                refund_error_code: Some("PAYOUT_FAILED".to_string()),
+                updated_by: merchant_account.storage_scheme.to_string(),
+            }
+        }
+    };
+
+    update_refund(state, merchant_account, refund, refund_update).await
+}
+
+/// Syncs the status of the refund with the status of its payout
+#[instrument(skip_all)]
+pub async fn sync_refund_payout(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    refund: storage::Refund,
+) -> RouterResult<storage::Refund> {
+    let Some(payout_id) = refund.payout_id.clone() else {
+        return Ok(refund);
+    };
+
+    let payout = match Box::pin(payouts_core::payouts_retrieve_core(
+        state.clone(),
+        merchant_account.clone(),
+        key_store.clone(),
+        None,
+        payouts::PayoutRetrieveRequest {
+            payout_id: payout_id.clone(),
+            force_sync: Some(true),
+        },
+    ))
+    .await?
+    {
+        services::ApplicationResponse::Json(payout) => payout,
+        _ => Err(report!(errors::ApiErrorResponse::InternalServerError))
+            .attach_printable("Unexpected response from the payout of the refund")?,
+    };
+
+    let refund_update = storage::RefundUpdate::PayoutUpdate {
+        payout_id,
+        refund_status: get_refund_status_from_payout_status(payout.status),
+        refund_error_message: payout.error_message,
+        refund_error_code: payout.error_code,
+        updated_by: merchant_account.storage_scheme.to_string(),
+    };
+
+    update_refund(state, merchant_account, refund, refund_update).await
+}
+
+async fn update_refund(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    refund: storage::Refund,
+    refund_update: storage::RefundUpdate,
+) -> RouterResult<storage::Refund> {
+    let refund_id = refund.refund_id.clone();
+    state
+        .store
+        .update_refund(refund, refund_update, merchant_account.storage_scheme)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::RefundNotFound)
+        .attach_printable_lazy(|| format!("Failed while updating refund: refund_id: {refund_id}"))
+}
+
+fn get_refund_status_from_payout_status(payout_status: enums::PayoutStatus) -> enums::RefundStatus {
+    match payout_status {
+        enums::PayoutStatus::Success => enums::RefundStatus::Success,
+        enums::PayoutStatus::Failed
+        | enums::PayoutStatus::Cancelled
+        | enums::PayoutStatus::Ineligible => enums::RefundStatus::Failure,
+        enums::PayoutStatus::Pending
+        | enums::PayoutStatus::RequiresCreation
+        | enums::PayoutStatus::RequiresPayoutMethodData
+        | enums::PayoutStatus::RequiresFulfillment => enums::RefundStatus::Pending,
+    }
+}
@@ -0,0 +1,306 @@
+//! Automatic retries of refunds failing with a transient connector error.
+//!
+//! Refunds rejected by the connector with a server error or a rate limit error are kept pending
+//! and retried by a process tracker task with an exponential backoff, until the connector returns
+//! a final outcome for the refund or the retries configured for refunds are exhausted. The
+//! merchant is notified by a webhook of refunds failing on a retry.
+
+use common_utils::ext_traits::ValueExt;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    configs::settings,
+    core::{
+        errors::{self, RouterResult, StorageErrorExt},
+        webhooks as webhooks_core,
+    },
+    db, logger,
+    routes::AppState,
+    types::{
+        self,
+        api::{self, refunds},
+        domain,
+        storage::{self, enums},
+        transformers::ForeignInto,
+    },
+};
+
+const REFUND_RETRY_TASK_NAME: &str = "RETRY_REFUND";
+const REFUND_RETRY_TAG: &str = "REFUND";
+
+/// Server errors and rate limit errors of connectors are expected to be resolved by a retry
+fn is_transient_error(error_response: &types::ErrorResponse) -> bool {
+    error_response.status_code >= 500 || error_response.status_code == 429
+}
+
+/// Returns the update of a refund rejected by the connector, scheduling a retry of the refund for
+/// transient errors unless the retries configured for refunds are exhausted
+pub fn get_refund_error_update(
+    refund: &storage::Refund,
+    error_response: types::ErrorResponse,
+    refund_config: &settings::Refund,
+    storage_scheme: enums::MerchantStorageScheme,
+) -> storage::RefundUpdate {
+    let retry_count = refund.retry_count.unwrap_or_default();
+    let refund_error_message = error_response
+        .reason
+        .clone()
+        .or(Some(error_response.message.clone()));
+    let refund_error_code = Some(error_response.code.clone());
+
+    match (is_transient_error(&error_response), refund.retry_status) {
+        (true, _) if retry_count < refund_config.max_retries => {
+            storage::RefundUpdate::RetryUpdate {
+                refund_status: enums::RefundStatus::Pending,
+                retry_count: Some(retry_count + 1),
+                retry_status: enums::RefundRetryStatus::Scheduled,
+                refund_error_message,
+                refund_error_code,
+                updated_by: storage_scheme.to_string(),
+            }
+        }
+        (true, Some(_)) => storage::RefundUpdate::RetryUpdate {
+            refund_status: enums::RefundStatus::Failure,
+            retry_count: None,
+            retry_status: enums::RefundRetryStatus::Exhausted,
+            refund_error_message,
+            refund_error_code,
+            updated_by: storage_scheme.to_string(),
+        },
+        (false, Some(_)) => storage::RefundUpdate::RetryUpdate {
+            refund_status: enums::RefundStatus::Failure,
+            retry_count: None,
+            retry_status: enums::RefundRetryStatus::Completed,
+            refund_error_message,
+            refund_error_code,
+            updated_by: storage_scheme.to_string(),
+        },
+        (_, None) => storage::RefundUpdate::ErrorUpdate {
+            refund_status: Some(enums::RefundStatus::Failure),
+            refund_error_message,
+            refund_error_code,
+            updated_by: storage_scheme.to_string(),
+        },
+    }
+}
+
+/// Returns the delay in seconds before the next retry of the refund, the delay between retries
+/// being doubled on every retry
+fn get_refund_retry_delay(refund_config: &settings::Refund, retry_count: Option<i16>) -> i64 {
+    let exponent = retry_count
+        .unwrap_or_default()
+        .saturating_sub(1)
+        .clamp(0, 16)
+        .unsigned_abs();
+
+    refund_config
+        .retry_interval
+        .saturating_mul(2_i64.saturating_pow(u32::from(exponent)))
+}
+
+fn get_refund_retry_schedule_time(
+    refund_config: &settings::Refund,
+    retry_count: Option<i16>,
+) -> time::PrimitiveDateTime {
+    common_utils::date_time::now().saturating_add(time::Duration::seconds(get_refund_retry_delay(
+        refund_config,
+        retry_count,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn add_refund_retry_task(
+    db: &dyn db::StorageInterface,
+    refund: &storage::Refund,
+    refund_config: &settings::Refund,
+) -> RouterResult<storage::ProcessTracker> {
+    let runner = storage::ProcessTrackerRunner::RefundWorkflowRouter;
+    let process_tracker_id = format!(
+        "{runner}_{REFUND_RETRY_TASK_NAME}_{}",
+        refund.internal_reference_id
+    );
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        process_tracker_id,
+        REFUND_RETRY_TASK_NAME,
+        runner,
+        [REFUND_RETRY_TAG],
+        super::refund_to_refund_core_workflow_model(refund),
+        get_refund_retry_schedule_time(refund_config, refund.retry_count),
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct refund retry process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .to_duplicate_response(errors::ApiErrorResponse::DuplicateRefundRequest)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting refund retry task in process_tracker: refund_id: {}",
+                refund.refund_id
+            )
+        })
+}
+
+#[instrument(skip_all)]
+pub async fn trigger_refund_retry_workflow(
+    state: &AppState,
+    refund_tracker: &storage::ProcessTracker,
+) -> Result<(), errors::ProcessTrackerError> {
+    let db = &*state.store;
+    let refund_core: storage::RefundCoreWorkflow = refund_tracker
+        .tracking_data
+        .clone()
+        .parse_value("RefundCoreWorkflow")?;
+
+    let key_store = db
+        .get_merchant_key_store_by_merchant_id(
+            &refund_core.merchant_id,
+            &db.get_master_key().to_vec().into(),
+        )
+        .await?;
+
+    let merchant_account = db
+        .find_merchant_account_by_merchant_id(&refund_core.merchant_id, &key_store)
+        .await?;
+
+    let refund = db
+        .find_refund_by_internal_reference_id_merchant_id(
+            &refund_core.refund_internal_reference_id,
+            &refund_core.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+
+    // Refunds updated since the retry was scheduled, like by a sync, are not retried
+    if refund.sent_to_gateway
+        || refund.refund_status != enums::RefundStatus::Pending
+        || refund.retry_status != Some(enums::RefundRetryStatus::Scheduled)
+    {
+        return db
+            .as_scheduler()
+            .finish_process_with_business_status(
+                refund_tracker.clone(),
+                "COMPLETED_BY_PT".to_string(),
+            )
+            .await
+            .map_err(Into::into);
+    }
+
+    let payment_attempt = db
+        .find_payment_attempt_by_connector_transaction_id_payment_id_merchant_id(
+            &refund.connector_transaction_id,
+            &refund.payment_id,
+            &refund.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+
+    let payment_intent = db
+        .find_payment_intent_by_payment_id_merchant_id(
+            &refund.payment_id,
+            &refund.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await?;
+
+    let updated_refund = Box::pin(super::trigger_refund_to_gateway(
+        state,
+        &refund,
+        &merchant_account,
+        &key_store,
+        &payment_attempt,
+        &payment_intent,
+        None,
+    ))
+    .await?;
+
+    if updated_refund.retry_status == Some(enums::RefundRetryStatus::Scheduled) {
+        let schedule_time =
+            get_refund_retry_schedule_time(&state.conf.refund, updated_refund.retry_count);
+        return db
+            .as_scheduler()
+            .retry_process(refund_tracker.clone(), schedule_time)
+            .await
+            .map_err(Into::into);
+    }
+
+    if updated_refund.sent_to_gateway
+        && updated_refund.refund_status == enums::RefundStatus::Pending
+    {
+        super::add_refund_sync_task(
+            db,
+            &updated_refund,
+            storage::ProcessTrackerRunner::RefundWorkflowRouter,
+        )
+        .await?;
+    }
+
+    if updated_refund.refund_status == enums::RefundStatus::Failure {
+        trigger_refund_failed_webhook(state, merchant_account, &key_store, updated_refund)
+            .await
+            .map_err(|error| logger::error!(?error, "Failed to trigger refund failed webhook"))
+            .ok();
+    }
+
+    db.as_scheduler()
+        .finish_process_with_business_status(refund_tracker.clone(), "COMPLETED_BY_PT".to_string())
+        .await
+        .map_err(Into::into)
+}
+
+/// Notifies the merchant of a refund which failed on a retry, as the failure is not returned to
+/// the merchant in the response of the refund request
+async fn trigger_refund_failed_webhook(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    refund: storage::Refund,
+) -> RouterResult<()> {
+    let Some(profile_id) = refund.profile_id.clone() else {
+        logger::warn!("Refund failed webhook not sent as the refund has no profile");
+        return Ok(());
+    };
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(&profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id,
+        })?;
+
+    let refund_id = refund.refund_id.clone();
+    let created_at = refund.created_at;
+    let refund_response: refunds::RefundResponse = refund.foreign_into();
+
+    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account,
+        business_profile,
+        key_store,
+        enums::EventType::RefundFailed,
+        enums::EventClass::Refunds,
+        refund_id,
+        enums::EventObjectType::RefundDetails,
+        api::OutgoingWebhookContent::RefundDetails(refund_response),
+        Some(created_at),
+    ))
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_refund_retry_delay() {
+        let refund_config = settings::Refund {
+            retry_interval: 60,
+            ..Default::default()
+        };
+
+        assert_eq!(get_refund_retry_delay(&refund_config, None), 60);
+        assert_eq!(get_refund_retry_delay(&refund_config, Some(1)), 60);
+        assert_eq!(get_refund_retry_delay(&refund_config, Some(3)), 240);
+    }
+}
@@ -1,3 +1,4 @@
+use api_models::refunds::RefundDestination;
 use error_stack::{report, IntoReport};
 use router_env::{instrument, tracing};
 use time::PrimitiveDateTime;
@@ -25,6 +26,12 @@ pub enum RefundValidationError {
     MaxRefundCountReached,
     #[error("There is already another refund request for this payment attempt")]
     DuplicateRefund,
+    #[error("Partial refunds are not supported")]
+    PartialRefundNotSupported,
+    #[error("Multiple refunds are not supported")]
+    MultipleRefundsNotSupported,
+    #[error("Refunds are not supported for the payment method")]
+    PaymentMethodRefundNotSupported,
 }
 
 #[instrument(skip_all)]
@@ -38,24 +45,28 @@ pub fn validate_success_transaction(
     Ok(())
 }
 
+/// Refunds which have not failed, including the ones still pending with the connector
+fn get_active_refunds(all_refunds: &[storage::Refund]) -> impl Iterator<Item = &storage::Refund> {
+    all_refunds.iter().filter(|refund| {
+        refund.refund_status != enums::RefundStatus::Failure
+            && refund.refund_status != enums::RefundStatus::TransactionFailure
+    })
+}
+
+/// Provides the amount refunded or being refunded against the payment attempt
+pub fn get_refunded_amount(all_refunds: &[storage::Refund]) -> i64 {
+    get_active_refunds(all_refunds)
+        .map(|refund| refund.refund_amount)
+        .sum()
+}
+
 #[instrument(skip_all)]
 pub fn validate_refund_amount(
     amount_captured: i64,
     all_refunds: &[storage::Refund],
     refund_amount: i64,
 ) -> CustomResult<(), RefundValidationError> {
-    let total_refunded_amount: i64 = all_refunds
-        .iter()
-        .filter_map(|refund| {
-            if refund.refund_status != enums::RefundStatus::Failure
-                && refund.refund_status != enums::RefundStatus::TransactionFailure
-            {
-                Some(refund.refund_amount)
-            } else {
-                None
-            }
-        })
-        .sum();
+    let total_refunded_amount = get_refunded_amount(all_refunds);
 
     utils::when(
         refund_amount > (amount_captured - total_refunded_amount),
@@ -67,6 +78,64 @@ pub fn validate_refund_amount(
     )
 }
 
+/// Validates the refund against the refund flows supported by the connector, a refund being
+/// partial when it does not refund the whole captured amount
+#[instrument(skip_all)]
+pub fn validate_refund_against_connector_capabilities(
+    is_refund_supported: bool,
+    is_partial_refund_supported: bool,
+    is_multiple_refunds_supported: bool,
+    amount_captured: i64,
+    all_refunds: &[storage::Refund],
+    refund_amount: i64,
+) -> CustomResult<(), RefundValidationError> {
+    utils::when(!is_refund_supported, || {
+        Err(report!(
+            RefundValidationError::PaymentMethodRefundNotSupported
+        ))
+    })?;
+
+    utils::when(
+        !is_partial_refund_supported && refund_amount != amount_captured,
+        || Err(report!(RefundValidationError::PartialRefundNotSupported)),
+    )?;
+
+    utils::when(
+        !is_multiple_refunds_supported && get_active_refunds(all_refunds).next().is_some(),
+        || Err(report!(RefundValidationError::MultipleRefundsNotSupported)),
+    )
+}
+
+/// Decides the destination of the refund, the refunds which the connector cannot make to the
+/// payment method of the payment falling back to the alternate destination when it is provided
+pub fn get_refund_destination(
+    requested_destination: RefundDestination,
+    has_alternate_destination: bool,
+    connector_capabilities_check: CustomResult<(), RefundValidationError>,
+    connector: &str,
+) -> RouterResult<RefundDestination> {
+    match (requested_destination, connector_capabilities_check) {
+        (RefundDestination::OriginalPaymentMethod, Ok(())) => {
+            Ok(RefundDestination::OriginalPaymentMethod)
+        }
+        (RefundDestination::OriginalPaymentMethod, Err(_)) if has_alternate_destination => {
+            Ok(RefundDestination::AlternatePaymentMethod)
+        }
+        (RefundDestination::OriginalPaymentMethod, Err(error)) => {
+            let message = format!("{} by the {connector} connector", error.current_context());
+            Err(error.change_context(errors::ApiErrorResponse::NotSupported { message }))
+        }
+        (RefundDestination::AlternatePaymentMethod, _) if has_alternate_destination => {
+            Ok(RefundDestination::AlternatePaymentMethod)
+        }
+        (RefundDestination::AlternatePaymentMethod, _) => {
+            Err(report!(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "alternate_destination"
+            }))
+        }
+    }
+}
+
 #[instrument(skip_all)]
 pub fn validate_payment_order_age(
     created_at: &PrimitiveDateTime,
@@ -24,11 +24,12 @@ use crate::types::transformers::{ForeignInto, ForeignTryInto};
 use crate::{
     consts,
     core::{
+        audit_logs,
         errors::{RouterResponse, StorageErrorExt},
         metrics, utils as core_utils,
     },
     routes::AppState,
-    types::domain,
+    types::{domain, storage::enums as storage_enums},
     utils::{self, OptionExt, ValueExt},
 };
 #[cfg(not(feature = "business_profile_routing"))]
@@ -124,6 +125,8 @@ pub async fn create_routing_config(
         })
         .attach_printable("Algorithm of config not given")?;
 
+    helpers::validate_routing_algorithm_rules(&algorithm)?;
+
     let algorithm_id = common_utils::generate_id(
         consts::ROUTING_CONFIG_ID_LENGTH,
         &format!("routing_{}", &merchant_account.merchant_id),
@@ -159,7 +162,7 @@ pub async fn create_routing_config(
         let algo = RoutingAlgorithm {
             algorithm_id: algorithm_id.clone(),
             profile_id,
-            merchant_id: merchant_account.merchant_id,
+            merchant_id: merchant_account.merchant_id.clone(),
             name: name.clone(),
             description: Some(description.clone()),
             kind: algorithm.get_kind().foreign_into(),
@@ -173,7 +176,17 @@ pub async fn create_routing_config(
             .await
             .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?;
 
-        let new_record = record.foreign_into();
+        let new_record: routing_types::RoutingDictionaryRecord = record.foreign_into();
+
+        audit_logs::record_audit_log(
+            &state,
+            &merchant_account.merchant_id,
+            storage_enums::AuditOperation::RoutingConfigCreate,
+            &new_record.id,
+            None,
+            Some(&new_record),
+        )
+        .await;
 
         metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
         Ok(service_api::ApplicationResponse::Json(new_record))
@@ -243,6 +256,16 @@ pub async fn create_routing_config(
         )
         .await?;
 
+        audit_logs::record_audit_log(
+            &state,
+            &merchant_account.merchant_id,
+            storage_enums::AuditOperation::RoutingConfigCreate,
+            &new_record.id,
+            None,
+            Some(&new_record),
+        )
+        .await;
+
         metrics::ROUTING_CREATE_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
         Ok(service_api::ApplicationResponse::Json(new_record))
     }
@@ -308,15 +331,26 @@ pub async fn link_routing_config(
             },
         )?;
 
-        routing_ref.update_algorithm_id(algorithm_id);
+        let routing_ref_before = routing_ref.clone();
+        routing_ref.update_algorithm_id(algorithm_id.clone());
         helpers::update_business_profile_active_algorithm_ref(
             db,
             business_profile,
-            routing_ref,
+            routing_ref.clone(),
             transaction_type,
         )
         .await?;
 
+        audit_logs::record_audit_log(
+            &state,
+            &merchant_account.merchant_id,
+            storage_enums::AuditOperation::RoutingConfigActivate,
+            &algorithm_id,
+            Some(&routing_ref_before),
+            Some(&routing_ref),
+        )
+        .await;
+
         metrics::ROUTING_LINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
         Ok(service_api::ApplicationResponse::Json(
             routing_algorithm.foreign_into(),
@@ -360,14 +394,25 @@ pub async fn link_routing_config(
         record.modified_at = modified_at;
         merchant_dictionary.active_id = Some(record.id.clone());
         let response = record.clone();
-        routing_ref.update_algorithm_id(algorithm_id);
+        let routing_ref_before = routing_ref.clone();
+        routing_ref.update_algorithm_id(algorithm_id.clone());
         helpers::update_merchant_routing_dictionary(
             db,
             &merchant_account.merchant_id,
             merchant_dictionary,
         )
         .await?;
-        helpers::update_merchant_active_algorithm_ref(db, &key_store, routing_ref).await?;
+        helpers::update_merchant_active_algorithm_ref(db, &key_store, routing_ref.clone()).await?;
+
+        audit_logs::record_audit_log(
+            &state,
+            &merchant_account.merchant_id,
+            storage_enums::AuditOperation::RoutingConfigActivate,
+            &algorithm_id,
+            Some(&routing_ref_before),
+            Some(&routing_ref),
+        )
+        .await;
 
         metrics::ROUTING_LINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
         Ok(service_api::ApplicationResponse::Json(response))
@@ -493,14 +538,16 @@ pub async fn unlink_routing_config(
 
                 let timestamp = common_utils::date_time::now_unix_timestamp();
 
-                match routing_algo_ref.algorithm_id {
+                match routing_algo_ref.algorithm_id.clone() {
                     Some(algorithm_id) => {
                         let routing_algorithm: routing_types::RoutingAlgorithmRef =
                             routing_types::RoutingAlgorithmRef {
                                 algorithm_id: None,
                                 timestamp,
                                 config_algo_id: routing_algo_ref.config_algo_id.clone(),
-                                surcharge_config_algo_id: routing_algo_ref.surcharge_config_algo_id,
+                                surcharge_config_algo_id: routing_algo_ref
+                                    .surcharge_config_algo_id
+                                    .clone(),
                             };
 
                         let record = db
@@ -514,11 +561,21 @@ pub async fn unlink_routing_config(
                         helpers::update_business_profile_active_algorithm_ref(
                             db,
                             business_profile,
-                            routing_algorithm,
+                            routing_algorithm.clone(),
                             transaction_type,
                         )
                         .await?;
 
+                        audit_logs::record_audit_log(
+                            &state,
+                            &merchant_account.merchant_id,
+                            storage_enums::AuditOperation::RoutingConfigDeactivate,
+                            &algorithm_id,
+                            Some(&routing_algo_ref),
+                            Some(&routing_algorithm),
+                        )
+                        .await;
+
                         metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(
                             &metrics::CONTEXT,
                             1,
@@ -567,7 +624,7 @@ pub async fn unlink_routing_config(
                 algorithm_id: None,
                 timestamp,
                 config_algo_id: routing_algo_ref.config_algo_id.clone(),
-                surcharge_config_algo_id: routing_algo_ref.surcharge_config_algo_id,
+                surcharge_config_algo_id: routing_algo_ref.surcharge_config_algo_id.clone(),
             };
 
         let active_algorithm_id = merchant_dictionary
@@ -634,6 +691,16 @@ pub async fn unlink_routing_config(
         .change_context(errors::ApiErrorResponse::InternalServerError)
         .attach_printable("Failed to update routing algorithm ref in merchant account")?;
 
+        audit_logs::record_audit_log(
+            &state,
+            &merchant_account.merchant_id,
+            storage_enums::AuditOperation::RoutingConfigDeactivate,
+            &active_algorithm_id,
+            Some(&routing_algo_ref),
+            Some(&routing_algorithm),
+        )
+        .await;
+
         metrics::ROUTING_UNLINK_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
         Ok(service_api::ApplicationResponse::Json(response))
     }
@@ -686,6 +753,16 @@ pub async fn update_default_routing_config(
     )
     .await?;
 
+    audit_logs::record_audit_log(
+        &state,
+        &merchant_account.merchant_id,
+        storage_enums::AuditOperation::RoutingDefaultConfigUpdate,
+        &merchant_account.merchant_id,
+        Some(&default_config),
+        Some(&updated_config),
+    )
+    .await;
+
     metrics::ROUTING_UPDATE_CONFIG_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
     Ok(service_api::ApplicationResponse::Json(updated_config))
 }
@@ -820,6 +897,89 @@ pub async fn retrieve_linked_routing_config(
     }
 }
 
+pub async fn simulate_routing(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    request: routing_types::RoutingSimulationRequest,
+) -> RouterResponse<routing_types::RoutingSimulationResponse> {
+    metrics::ROUTING_SIMULATE.add(&metrics::CONTEXT, 1, &[]);
+    let db = state.store.as_ref();
+
+    #[cfg(any(
+        feature = "business_profile_routing",
+        feature = "profile_specific_fallback_routing"
+    ))]
+    let business_profile = {
+        let profile_id = request
+            .profile_id
+            .clone()
+            .get_required_value("profile_id")
+            .change_context(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "profile_id",
+            })?;
+
+        core_utils::validate_and_get_business_profile(
+            db,
+            Some(&profile_id),
+            &merchant_account.merchant_id,
+        )
+        .await?
+        .get_required_value("BusinessProfile")
+        .change_context(errors::ApiErrorResponse::BusinessProfileNotFound { id: profile_id })?
+    };
+
+    #[cfg(feature = "business_profile_routing")]
+    let active_algorithm_ref = business_profile.routing_algorithm.clone();
+    #[cfg(not(feature = "business_profile_routing"))]
+    let active_algorithm_ref = merchant_account.routing_algorithm.clone();
+
+    let algorithm_id = match request.algorithm_id {
+        Some(algorithm_id) => Some(algorithm_id),
+        None => active_algorithm_ref
+            .map(|val| val.parse_value::<routing_types::RoutingAlgorithmRef>("RoutingAlgorithmRef"))
+            .transpose()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("unable to deserialize routing algorithm ref")?
+            .and_then(|algorithm_ref| algorithm_ref.algorithm_id),
+    };
+
+    let algorithm = match algorithm_id {
+        Some(algorithm_id) => {
+            let algorithm = helpers::get_routing_algorithm(
+                db,
+                &algorithm_id,
+                #[cfg(feature = "business_profile_routing")]
+                &business_profile.profile_id,
+                #[cfg(not(feature = "business_profile_routing"))]
+                &merchant_account.merchant_id,
+            )
+            .await?;
+            Some((algorithm_id, algorithm))
+        }
+        None => None,
+    };
+
+    let response = payments::routing::perform_routing_simulation(
+        &state,
+        &key_store,
+        merchant_account.modified_at.assume_utc().unix_timestamp(),
+        algorithm,
+        &request.payment,
+        #[cfg(any(
+            feature = "business_profile_routing",
+            feature = "profile_specific_fallback_routing"
+        ))]
+        business_profile.profile_id,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("failed to simulate routing")?;
+
+    metrics::ROUTING_SIMULATE_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
+    Ok(service_api::ApplicationResponse::Json(response))
+}
+
 pub async fn retrieve_default_routing_config_for_profiles(
     state: AppState,
     merchant_account: domain::MerchantAccount,
@@ -934,6 +1094,16 @@ pub async fn update_default_routing_config_for_profile(
     )
     .await?;
 
+    audit_logs::record_audit_log(
+        &state,
+        &merchant_account.merchant_id,
+        storage_enums::AuditOperation::RoutingDefaultConfigUpdate,
+        &business_profile.profile_id,
+        Some(&default_config),
+        Some(&updated_config),
+    )
+    .await;
+
     metrics::ROUTING_UPDATE_CONFIG_FOR_PROFILE_SUCCESS_RESPONSE.add(&metrics::CONTEXT, 1, &[]);
     Ok(service_api::ApplicationResponse::Json(
         routing_types::ProfileDefaultRoutingConfig {
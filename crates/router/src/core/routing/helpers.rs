@@ -8,9 +8,13 @@ use diesel_models::{
     business_profile::{BusinessProfile, BusinessProfileUpdateInternal},
     configs,
 };
-use error_stack::ResultExt;
+use error_stack::{IntoReport, ResultExt};
 use rustc_hash::FxHashSet;
 
+#[cfg(feature = "business_profile_routing")]
+use crate::core::errors::StorageErrorExt;
+#[cfg(feature = "business_profile_routing")]
+use crate::utils::ValueExt;
 use crate::{
     core::errors::{self, RouterResult},
     db::StorageInterface,
@@ -263,6 +267,8 @@ pub async fn update_business_profile_active_algorithm_ref(
         payment_link_config: None,
         session_expiry: None,
         authentication_connector_details: None,
+        card_verification_policy: None,
+        partial_authorization_action: None,
     };
     db.update_business_profile_by_profile_id(current_business_profile, business_profile_update)
         .await
@@ -335,6 +341,23 @@ pub async fn update_merchant_connector_agnostic_mandate_config(
     Ok(mandate_config)
 }
 
+/// Statically analyzes and compiles the rules of an advanced routing algorithm, so that
+/// conflicting or invalid rules are rejected when the config is created rather than when a
+/// payment is being routed
+pub fn validate_routing_algorithm_rules(
+    routing_algorithm: &routing_types::RoutingAlgorithm,
+) -> RouterResult<()> {
+    if let routing_types::RoutingAlgorithm::Advanced(program) = routing_algorithm {
+        euclid::dssa::analyzer::analyze(program.clone(), None)
+            .map_err(|err| errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Invalid routing rules: {err}"),
+            })
+            .into_report()?;
+    }
+
+    Ok(())
+}
+
 pub async fn validate_connectors_in_routing_config(
     db: &dyn StorageInterface,
     key_store: &domain::MerchantKeyStore,
@@ -409,6 +432,24 @@ pub async fn validate_connectors_in_routing_config(
         Ok(())
     };
 
+    let check_volume_split = |splits: &[routing_types::ConnectorVolumeSplit]| -> RouterResult<()> {
+        let total_split: u16 = splits.iter().map(|split| u16::from(split.split)).sum();
+        error_stack::ensure!(
+            total_split == 100,
+            errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "volume split percentages must add up to 100, received a total of {total_split}"
+                )
+            }
+        );
+
+        for split in splits {
+            check_connector_choice(&split.connector)?;
+        }
+
+        Ok(())
+    };
+
     match routing_algorithm {
         routing_types::RoutingAlgorithm::Single(choice) => {
             check_connector_choice(choice)?;
@@ -421,9 +462,7 @@ pub async fn validate_connectors_in_routing_config(
         }
 
         routing_types::RoutingAlgorithm::VolumeSplit(splits) => {
-            for split in splits {
-                check_connector_choice(&split.connector)?;
-            }
+            check_volume_split(splits)?;
         }
 
         routing_types::RoutingAlgorithm::Advanced(program) => {
@@ -431,9 +470,7 @@ pub async fn validate_connectors_in_routing_config(
                 |selection: &routing_types::ConnectorSelection| -> RouterResult<()> {
                     match selection {
                         routing_types::ConnectorSelection::VolumeSplit(splits) => {
-                            for split in splits {
-                                check_connector_choice(&split.connector)?;
-                            }
+                            check_volume_split(splits)?;
                         }
 
                         routing_types::ConnectorSelection::Priority(list) => {
@@ -459,6 +496,49 @@ pub async fn validate_connectors_in_routing_config(
 
 /// Provides the identifier for the specific merchant's routing_dictionary_key
 #[inline(always)]
+/// Fetches a routing algorithm configured for the business profile
+#[cfg(feature = "business_profile_routing")]
+pub async fn get_routing_algorithm(
+    db: &dyn StorageInterface,
+    algorithm_id: &str,
+    profile_id: &str,
+) -> RouterResult<routing_types::RoutingAlgorithm> {
+    db.find_routing_algorithm_by_profile_id_algorithm_id(profile_id, algorithm_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)?
+        .algorithm_data
+        .parse_value("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error deserializing routing algorithm")
+}
+
+/// Fetches a routing algorithm present in the merchant routing dictionary
+#[cfg(not(feature = "business_profile_routing"))]
+pub async fn get_routing_algorithm(
+    db: &dyn StorageInterface,
+    algorithm_id: &str,
+    merchant_id: &str,
+) -> RouterResult<routing_types::RoutingAlgorithm> {
+    let merchant_dictionary = get_merchant_routing_dictionary(db, merchant_id).await?;
+
+    merchant_dictionary
+        .records
+        .iter()
+        .find(|rec| rec.id == algorithm_id)
+        .ok_or(errors::ApiErrorResponse::ResourceIdNotFound)
+        .into_report()
+        .attach_printable("Algorithm with the given ID not found in the merchant dictionary")?;
+
+    db.find_config_by_key(algorithm_id)
+        .await
+        .change_context(errors::ApiErrorResponse::ResourceIdNotFound)
+        .attach_printable("Routing config not found in DB")?
+        .config
+        .parse_struct("RoutingAlgorithm")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Error deserializing routing algorithm config")
+}
+
 pub fn get_routing_dictionary_key(merchant_id: &str) -> String {
     format!("routing_dict_{merchant_id}")
 }
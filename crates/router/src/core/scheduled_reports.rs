@@ -0,0 +1,857 @@
+//! Scheduled analytics reports.
+//!
+//! Merchants schedule daily or weekly reports of their payments, refunds or disputes. Every
+//! schedule has a recurring process tracker task which generates the report of the period that
+//! just ended from the records of the merchant, stores it through the files module and delivers
+//! it over email or SFTP. Each run of a schedule is recorded along with its outcome, so that
+//! failed deliveries can be looked up and the stored reports downloaded.
+
+use api_models::analytics::scheduled_reports::{
+    ReportDeliveryConfig, ReportRunListRequest, ReportRunListResponse, ReportRunResponse,
+    ReportScheduleCreateRequest, ReportScheduleId, ReportScheduleResponse,
+};
+use common_utils::{
+    ext_traits::{Encode, ValueExt},
+    fp_utils::when,
+};
+use data_models::payments::payment_intent::{
+    PaymentIntentFetchConstraints, PaymentIntentListParams,
+};
+use diesel_models::enums as storage_enums;
+use error_stack::{report, IntoReport, ResultExt};
+use masking::{ExposeInterface, PeekInterface, Secret};
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+use time::{format_description::well_known::Rfc3339, Duration, PrimitiveDateTime};
+
+use crate::{
+    consts,
+    core::{
+        errors::{self, CustomResult, RouterResponse, RouterResult, StorageErrorExt},
+        files,
+    },
+    routes::{metrics, AppState},
+    services::{self, ApplicationResponse},
+    types::{api, domain, storage},
+    utils,
+};
+
+const SCHEDULED_REPORT_TASK_NAME: &str = "SCHEDULED_REPORT";
+const SCHEDULED_REPORT_TAG: &str = "SCHEDULED_REPORT";
+const SCHEDULED_REPORT_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::ScheduledReportWorkflow;
+
+pub const REPORT_SCHEDULE_DEACTIVATED: &str = "SCHEDULE_DEACTIVATED";
+
+const REPORT_RUN_LIST_MAX_LIMIT: i64 = 100;
+
+const PAYMENTS_REPORT_COLUMNS: [&str; 10] = [
+    "payment_id",
+    "status",
+    "amount",
+    "currency",
+    "amount_captured",
+    "customer_id",
+    "profile_id",
+    "description",
+    "created_at",
+    "modified_at",
+];
+
+const REFUNDS_REPORT_COLUMNS: [&str; 10] = [
+    "refund_id",
+    "payment_id",
+    "connector",
+    "refund_status",
+    "refund_amount",
+    "currency",
+    "refund_reason",
+    "profile_id",
+    "created_at",
+    "updated_at",
+];
+
+const DISPUTES_REPORT_COLUMNS: [&str; 11] = [
+    "dispute_id",
+    "payment_id",
+    "connector",
+    "dispute_stage",
+    "dispute_status",
+    "amount",
+    "currency",
+    "connector_reason",
+    "profile_id",
+    "created_at",
+    "modified_at",
+];
+
+/// Tracking data of the process tracker task of a report schedule
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ScheduledReportTrackingData {
+    pub merchant_id: String,
+    pub schedule_id: String,
+}
+
+pub fn generate_task_id_for_scheduled_report_workflow(schedule_id: &str) -> String {
+    format!("{SCHEDULED_REPORT_TASK_NAME}_{schedule_id}")
+}
+
+fn get_period_duration(frequency: storage_enums::ReportFrequency) -> Duration {
+    match frequency {
+        storage_enums::ReportFrequency::Daily => Duration::days(1),
+        storage_enums::ReportFrequency::Weekly => Duration::weeks(1),
+    }
+}
+
+/// The end of the first report period ending after the given time. Daily periods end at
+/// midnight UTC, and weekly periods at midnight UTC between Sunday and Monday.
+pub fn get_next_period_end(
+    frequency: storage_enums::ReportFrequency,
+    after: PrimitiveDateTime,
+) -> PrimitiveDateTime {
+    let next_midnight = after.date().midnight().saturating_add(Duration::days(1));
+    match frequency {
+        storage_enums::ReportFrequency::Daily => next_midnight,
+        storage_enums::ReportFrequency::Weekly => {
+            let days_until_monday =
+                7_u8.saturating_sub(next_midnight.weekday().number_days_from_monday()) % 7;
+            next_midnight.saturating_add(Duration::days(days_until_monday.into()))
+        }
+    }
+}
+
+fn format_timestamp(timestamp: PrimitiveDateTime) -> String {
+    timestamp
+        .assume_utc()
+        .format(&Rfc3339)
+        .unwrap_or_else(|_| timestamp.to_string())
+}
+
+fn validate_delivery_config(delivery: &ReportDeliveryConfig) -> RouterResult<()> {
+    let invalid_request = |message: &str| {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message: message.to_string(),
+        }))
+    };
+
+    match delivery {
+        ReportDeliveryConfig::Email(email) => {
+            if cfg!(not(feature = "email")) {
+                return Err(report!(errors::ApiErrorResponse::NotSupported {
+                    message: "Delivery of reports over email".to_string(),
+                }));
+            }
+            if email.recipients.is_empty() {
+                return invalid_request("At least one recipient must be provided");
+            }
+        }
+        ReportDeliveryConfig::Sftp(sftp) => {
+            if sftp.host.trim().is_empty() || sftp.username.trim().is_empty() {
+                return invalid_request("host and username of the SFTP server must be provided");
+            }
+            if sftp.password.is_none() && sftp.private_key.is_none() {
+                return invalid_request("Either password or private_key must be provided");
+            }
+            if sftp.host_key_fingerprint.trim().is_empty() {
+                return invalid_request("host_key_fingerprint of the SFTP server must be provided");
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn to_report_schedule_response(schedule: storage::ReportSchedule) -> ReportScheduleResponse {
+    ReportScheduleResponse {
+        schedule_id: schedule.schedule_id,
+        report_type: schedule.report_type,
+        frequency: schedule.frequency,
+        delivery_method: schedule.delivery_method,
+        is_active: schedule.is_active,
+        next_run_at: schedule.next_run_at,
+        last_run_at: schedule.last_run_at,
+        created_at: schedule.created_at,
+    }
+}
+
+fn to_report_run_response(report_run: storage::ReportRun) -> ReportRunResponse {
+    ReportRunResponse {
+        run_id: report_run.run_id,
+        schedule_id: report_run.schedule_id,
+        report_type: report_run.report_type,
+        period_start: report_run.period_start,
+        period_end: report_run.period_end,
+        status: report_run.status,
+        file_id: report_run.file_id,
+        row_count: report_run.row_count,
+        error_message: report_run.error_message,
+        delivered_at: report_run.delivered_at,
+        created_at: report_run.created_at,
+    }
+}
+
+#[instrument(skip_all)]
+pub async fn create_report_schedule(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: ReportScheduleCreateRequest,
+) -> RouterResponse<ReportScheduleResponse> {
+    validate_delivery_config(&req.delivery)?;
+
+    let db = &*state.store;
+    let active_schedules = db
+        .list_active_report_schedules_by_merchant_id(&merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list report schedules")?;
+    let max_schedules = state.conf.scheduled_reports.max_schedules_per_merchant;
+    when(active_schedules.len() >= max_schedules, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("A merchant can have at most {max_schedules} active report schedules"),
+        })
+        .into_report()
+    })?;
+
+    let delivery_config = req
+        .delivery
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize report delivery config")?;
+    let delivery_config = domain::types::encrypt(
+        Secret::<_, masking::WithType>::new(delivery_config),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to encrypt report delivery config")?;
+
+    let now = common_utils::date_time::now();
+    let report_schedule = storage::ReportScheduleNew {
+        schedule_id: utils::generate_id(consts::ID_LENGTH, "rsch"),
+        merchant_id: merchant_account.merchant_id.clone(),
+        report_type: req.report_type,
+        frequency: req.frequency,
+        delivery_method: req.delivery.get_delivery_method(),
+        delivery_config: delivery_config.into(),
+        is_active: true,
+        next_run_at: get_next_period_end(req.frequency, now),
+        last_run_at: None,
+        created_at: now,
+        modified_at: now,
+    };
+    let report_schedule = db
+        .insert_report_schedule(report_schedule)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert report schedule")?;
+
+    let tracking_data = ScheduledReportTrackingData {
+        merchant_id: report_schedule.merchant_id.clone(),
+        schedule_id: report_schedule.schedule_id.clone(),
+    };
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        generate_task_id_for_scheduled_report_workflow(&report_schedule.schedule_id),
+        SCHEDULED_REPORT_TASK_NAME,
+        SCHEDULED_REPORT_RUNNER,
+        [SCHEDULED_REPORT_TAG],
+        tracking_data,
+        report_schedule.next_run_at,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct scheduled report process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting scheduled report task to process_tracker: schedule_id: {}",
+                report_schedule.schedule_id
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes("flow", "ScheduledReport")],
+    );
+
+    Ok(ApplicationResponse::Json(to_report_schedule_response(
+        report_schedule,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn list_report_schedules(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<Vec<ReportScheduleResponse>> {
+    let report_schedules = state
+        .store
+        .list_active_report_schedules_by_merchant_id(&merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list report schedules")?;
+
+    Ok(ApplicationResponse::Json(
+        report_schedules
+            .into_iter()
+            .map(to_report_schedule_response)
+            .collect(),
+    ))
+}
+
+/// Deactivates the schedule and finishes its process tracker task, the runs of the schedule are
+/// retained
+#[instrument(skip_all)]
+pub async fn delete_report_schedule(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: ReportScheduleId,
+) -> RouterResponse<ReportScheduleResponse> {
+    let db = &*state.store;
+    let report_schedule = db
+        .find_report_schedule_by_merchant_id_schedule_id(
+            &merchant_account.merchant_id,
+            &req.schedule_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Report schedule not found".to_string(),
+        })?;
+
+    let report_schedule = db
+        .update_report_schedule_by_merchant_id_schedule_id(
+            &report_schedule.merchant_id,
+            &report_schedule.schedule_id,
+            storage::ReportScheduleUpdateInternal {
+                is_active: Some(false),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deactivate report schedule")?;
+
+    // The task finishes itself on its next run if this fails
+    let process = db
+        .find_process_by_id(&generate_task_id_for_scheduled_report_workflow(
+            &report_schedule.schedule_id,
+        ))
+        .await;
+    match process {
+        Ok(Some(process)) if process.business_status != REPORT_SCHEDULE_DEACTIVATED => {
+            if let Err(error) = db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    REPORT_SCHEDULE_DEACTIVATED.to_string(),
+                )
+                .await
+            {
+                logger::error!(?error, "Failed to finish scheduled report task");
+            }
+        }
+        Ok(_) => {}
+        Err(error) => logger::error!(?error, "Failed to fetch scheduled report task"),
+    }
+
+    Ok(ApplicationResponse::Json(to_report_schedule_response(
+        report_schedule,
+    )))
+}
+
+#[instrument(skip_all)]
+pub async fn list_report_runs(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: ReportRunListRequest,
+) -> RouterResponse<ReportRunListResponse> {
+    let report_runs = state
+        .store
+        .list_report_runs_by_merchant_id_constraints(
+            &merchant_account.merchant_id,
+            storage::ReportRunListConstraints {
+                schedule_id: req.schedule_id,
+                status: req.status,
+                limit: Some(req.limit.map_or(REPORT_RUN_LIST_MAX_LIMIT, |limit| {
+                    limit.min(REPORT_RUN_LIST_MAX_LIMIT)
+                })),
+                offset: req.offset,
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list report runs")?;
+
+    let data = report_runs
+        .into_iter()
+        .map(to_report_run_response)
+        .collect::<Vec<_>>();
+    Ok(ApplicationResponse::Json(ReportRunListResponse {
+        count: data.len(),
+        data,
+    }))
+}
+
+/// Generates and delivers the report of the period ending at the next run time of the schedule.
+/// The run is recorded under an ID derived from the schedule and the period, so that a retried
+/// task does not deliver the report of a period twice.
+#[instrument(skip_all, fields(schedule_id = %schedule.schedule_id))]
+pub async fn run_report_schedule(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    schedule: &storage::ReportSchedule,
+) -> RouterResult<storage::ReportRun> {
+    let db = &*state.store;
+    let period_end = schedule.next_run_at;
+    let period_start = period_end.saturating_sub(get_period_duration(schedule.frequency));
+    let run_id = format!(
+        "{}_{}",
+        schedule.schedule_id,
+        period_start.assume_utc().unix_timestamp()
+    );
+
+    let report_run = match db
+        .find_report_run_by_merchant_id_run_id(&schedule.merchant_id, &run_id)
+        .await
+    {
+        Ok(report_run) => report_run,
+        Err(error) if error.current_context().is_db_not_found() => {
+            let now = common_utils::date_time::now();
+            db.insert_report_run(storage::ReportRunNew {
+                run_id,
+                schedule_id: schedule.schedule_id.clone(),
+                merchant_id: schedule.merchant_id.clone(),
+                report_type: schedule.report_type,
+                period_start,
+                period_end,
+                status: storage_enums::ReportRunStatus::Pending,
+                file_id: None,
+                row_count: None,
+                error_message: None,
+                delivered_at: None,
+                created_at: now,
+                modified_at: now,
+            })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert report run")?
+        }
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch report run")?,
+    };
+
+    if report_run.status == storage_enums::ReportRunStatus::Delivered {
+        logger::info!(run_id = %report_run.run_id, "Report of the period was already delivered");
+        return Ok(report_run);
+    }
+
+    let report_run_update = match generate_and_deliver_report(
+        state,
+        merchant_account,
+        key_store,
+        schedule,
+        &report_run,
+    )
+    .await
+    {
+        Ok(()) => storage::ReportRunUpdateInternal {
+            status: storage_enums::ReportRunStatus::Delivered,
+            file_id: None,
+            row_count: None,
+            error_message: None,
+            delivered_at: Some(common_utils::date_time::now()),
+            modified_at: common_utils::date_time::now(),
+        },
+        Err(error) => {
+            logger::error!(?error, run_id = %report_run.run_id, "Scheduled report run failed");
+            let error_message = error.downcast_ref::<errors::SftpError>().map_or_else(
+                || error.current_context().to_string(),
+                |sftp_error| format!("{}: {sftp_error}", error.current_context()),
+            );
+            storage::ReportRunUpdateInternal {
+                status: storage_enums::ReportRunStatus::Failed,
+                file_id: None,
+                row_count: None,
+                error_message: Some(error_message),
+                delivered_at: None,
+                modified_at: common_utils::date_time::now(),
+            }
+        }
+    };
+
+    db.update_report_run_by_merchant_id_run_id(
+        &report_run.merchant_id,
+        &report_run.run_id,
+        report_run_update,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to update report run")
+}
+
+async fn generate_and_deliver_report(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    schedule: &storage::ReportSchedule,
+    report_run: &storage::ReportRun,
+) -> CustomResult<(), errors::ScheduledReportError> {
+    let (content, row_count) = generate_report(
+        state,
+        merchant_account,
+        report_run.report_type,
+        report_run.period_start,
+        report_run.period_end,
+    )
+    .await?;
+    let row_count = i32::try_from(row_count)
+        .into_report()
+        .change_context(errors::ScheduledReportError::GenerationFailed)?;
+    let file_name = format!(
+        "{}_report_{}_{}.csv",
+        report_run.report_type,
+        report_run.period_start.date(),
+        report_run.period_end.date()
+    );
+    let content = content.into_bytes();
+
+    let file_id = files::create_file(
+        state,
+        merchant_account,
+        key_store,
+        api::CreateFileRequest {
+            file_size: i32::try_from(content.len())
+                .into_report()
+                .change_context(errors::ScheduledReportError::StorageFailed)?,
+            file: content.clone(),
+            file_name: Some(file_name.clone()),
+            file_type: mime::TEXT_CSV,
+            purpose: api::FilePurpose::AnalyticsReport,
+            dispute_id: None,
+        },
+    )
+    .await
+    .change_context(errors::ScheduledReportError::StorageFailed)?;
+
+    state
+        .store
+        .update_report_run_by_merchant_id_run_id(
+            &report_run.merchant_id,
+            &report_run.run_id,
+            storage::ReportRunUpdateInternal {
+                status: storage_enums::ReportRunStatus::Generated,
+                file_id: Some(file_id.clone()),
+                row_count: Some(row_count),
+                error_message: None,
+                delivered_at: None,
+                modified_at: common_utils::date_time::now(),
+            },
+        )
+        .await
+        .change_context(errors::ScheduledReportError::StorageFailed)
+        .attach_printable("Failed to update report run")?;
+
+    let delivery_config = domain::types::decrypt::<serde_json::Value, masking::WithType>(
+        Some(schedule.delivery_config.clone()),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .change_context(errors::ScheduledReportError::DeliveryFailed)
+    .attach_printable("Failed to decrypt report delivery config")?
+    .ok_or(errors::ScheduledReportError::DeliveryFailed)
+    .into_report()
+    .attach_printable("Missing report delivery config")?
+    .into_inner()
+    .expose()
+    .parse_value::<ReportDeliveryConfig>("ReportDeliveryConfig")
+    .change_context(errors::ScheduledReportError::DeliveryFailed)?;
+
+    match delivery_config {
+        ReportDeliveryConfig::Email(email) => {
+            #[cfg(feature = "email")]
+            {
+                for recipient_email in email.recipients {
+                    let email_contents = services::email::types::ScheduledReport {
+                        recipient_email,
+                        settings: state.conf.clone(),
+                        subject: "Your Scheduled Report Is Ready",
+                        report_type: report_run.report_type.to_string(),
+                        frequency: schedule.frequency.to_string(),
+                        period_start: report_run.period_start.date().to_string(),
+                        period_end: report_run
+                            .period_end
+                            .date()
+                            .previous_day()
+                            .unwrap_or(report_run.period_end.date())
+                            .to_string(),
+                        row_count,
+                        file_id: file_id.clone(),
+                    };
+                    state
+                        .email_client
+                        .clone()
+                        .compose_and_send_email(
+                            Box::new(email_contents),
+                            state.conf.proxy.https_url.as_ref(),
+                        )
+                        .await
+                        .change_context(errors::ScheduledReportError::DeliveryFailed)
+                        .attach_printable("Failed to send scheduled report email")?;
+                }
+                Ok(())
+            }
+
+            #[cfg(not(feature = "email"))]
+            {
+                let _ = email;
+                Err(report!(
+                    errors::ScheduledReportError::EmailDeliveryNotSupported
+                ))
+            }
+        }
+        ReportDeliveryConfig::Sftp(sftp) => services::sftp::upload_file(
+            sftp,
+            file_name,
+            content,
+            std::time::Duration::from_secs(state.conf.scheduled_reports.sftp_timeout_secs.into()),
+        )
+        .await
+        .change_context(errors::ScheduledReportError::DeliveryFailed),
+    }
+}
+
+/// Generates the report of the records of the merchant created in the period, in CSV format.
+/// The generation fails if the period has more records than a report can have.
+async fn generate_report(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    report_type: storage_enums::ReportType,
+    period_start: PrimitiveDateTime,
+    period_end: PrimitiveDateTime,
+) -> CustomResult<(String, usize), errors::ScheduledReportError> {
+    let db = &*state.store;
+    let merchant_id = &merchant_account.merchant_id;
+    let max_rows = state.conf.scheduled_reports.max_report_rows;
+    // The records are filtered by an inclusive end time
+    let last_included_time = period_end.saturating_sub(Duration::microseconds(1));
+    // An extra record is fetched to detect whether the period exceeds the limit
+    let fetch_limit = max_rows.saturating_add(1);
+
+    let (columns, mut rows): (&[&str], Vec<(PrimitiveDateTime, Vec<String>)>) = match report_type {
+        storage_enums::ReportType::Payments => {
+            let constraints =
+                PaymentIntentFetchConstraints::List(Box::new(PaymentIntentListParams {
+                    offset: 0,
+                    starting_at: Some(period_start),
+                    ending_at: Some(last_included_time),
+                    connector: None,
+                    currency: None,
+                    status: None,
+                    payment_method: None,
+                    payment_method_type: None,
+                    authentication_type: None,
+                    profile_id: None,
+                    customer_id: None,
+                    starting_after_id: None,
+                    ending_before_id: None,
+                    limit: Some(u32::try_from(fetch_limit).unwrap_or(u32::MAX)),
+                }));
+            let payment_intents = db
+                .filter_payment_intent_by_constraints(
+                    merchant_id,
+                    &constraints,
+                    merchant_account.storage_scheme,
+                )
+                .await
+                .change_context(errors::ScheduledReportError::GenerationFailed)
+                .attach_printable("Failed to fetch payments of the report period")?;
+            let rows = payment_intents
+                .into_iter()
+                .map(|payment_intent| {
+                    (
+                        payment_intent.created_at,
+                        vec![
+                            payment_intent.payment_id,
+                            payment_intent.status.to_string(),
+                            payment_intent.amount.to_string(),
+                            payment_intent
+                                .currency
+                                .map(|currency| currency.to_string())
+                                .unwrap_or_default(),
+                            payment_intent
+                                .amount_captured
+                                .map(|amount| amount.to_string())
+                                .unwrap_or_default(),
+                            payment_intent.customer_id.unwrap_or_default(),
+                            payment_intent.profile_id.unwrap_or_default(),
+                            payment_intent.description.unwrap_or_default(),
+                            format_timestamp(payment_intent.created_at),
+                            format_timestamp(payment_intent.modified_at),
+                        ],
+                    )
+                })
+                .collect();
+            (&PAYMENTS_REPORT_COLUMNS, rows)
+        }
+        storage_enums::ReportType::Refunds => {
+            let refund_list_request = api_models::refunds::RefundListRequest {
+                payment_id: None,
+                refund_id: None,
+                profile_id: None,
+                limit: None,
+                offset: None,
+                time_range: Some(api_models::payments::TimeRange {
+                    start_time: period_start,
+                    end_time: Some(last_included_time),
+                }),
+                connector: None,
+                currency: None,
+                refund_status: None,
+            };
+            let refunds = db
+                .filter_refund_by_constraints(
+                    merchant_id,
+                    &refund_list_request,
+                    merchant_account.storage_scheme,
+                    i64::try_from(fetch_limit).unwrap_or(i64::MAX),
+                    0,
+                )
+                .await
+                .change_context(errors::ScheduledReportError::GenerationFailed)
+                .attach_printable("Failed to fetch refunds of the report period")?;
+            let rows = refunds
+                .into_iter()
+                .map(|refund| {
+                    (
+                        refund.created_at,
+                        vec![
+                            refund.refund_id,
+                            refund.payment_id,
+                            refund.connector,
+                            refund.refund_status.to_string(),
+                            refund.refund_amount.to_string(),
+                            refund.currency.to_string(),
+                            refund.refund_reason.unwrap_or_default(),
+                            refund.profile_id.unwrap_or_default(),
+                            format_timestamp(refund.created_at),
+                            format_timestamp(refund.updated_at),
+                        ],
+                    )
+                })
+                .collect();
+            (&REFUNDS_REPORT_COLUMNS, rows)
+        }
+        storage_enums::ReportType::Disputes => {
+            let dispute_constraints = api_models::disputes::DisputeListConstraints {
+                limit: Some(i64::try_from(fetch_limit).unwrap_or(i64::MAX)),
+                profile_id: None,
+                dispute_status: None,
+                dispute_stage: None,
+                reason: None,
+                connector: None,
+                received_time: None,
+                received_time_lt: Some(period_end),
+                received_time_gt: None,
+                received_time_lte: None,
+                received_time_gte: Some(period_start),
+            };
+            let disputes = db
+                .find_disputes_by_merchant_id(merchant_id, dispute_constraints)
+                .await
+                .change_context(errors::ScheduledReportError::GenerationFailed)
+                .attach_printable("Failed to fetch disputes of the report period")?;
+            let rows = disputes
+                .into_iter()
+                .map(|dispute| {
+                    (
+                        dispute.created_at,
+                        vec![
+                            dispute.dispute_id,
+                            dispute.payment_id,
+                            dispute.connector,
+                            dispute.dispute_stage.to_string(),
+                            dispute.dispute_status.to_string(),
+                            dispute.amount,
+                            dispute.currency,
+                            dispute.connector_reason.unwrap_or_default(),
+                            dispute.profile_id.unwrap_or_default(),
+                            format_timestamp(dispute.created_at),
+                            format_timestamp(dispute.modified_at),
+                        ],
+                    )
+                })
+                .collect();
+            (&DISPUTES_REPORT_COLUMNS, rows)
+        }
+    };
+
+    when(rows.len() > max_rows, || {
+        Err(errors::ScheduledReportError::TooManyRecords { max_rows }).into_report()
+    })?;
+
+    rows.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let row_count = rows.len();
+    Ok((
+        get_report_file(columns, rows.into_iter().map(|(_, row)| row)),
+        row_count,
+    ))
+}
+
+fn get_report_file(columns: &[&str], rows: impl Iterator<Item = Vec<String>>) -> String {
+    std::iter::once(columns.join(","))
+        .chain(rows.map(|row| {
+            row.iter()
+                .map(|field| utils::csv::escape_field(field))
+                .collect::<Vec<_>>()
+                .join(",")
+        }))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    #[test]
+    fn test_next_period_end() {
+        // 2024-04-17 is a Wednesday
+        let now = datetime!(2024-04-17 10:30);
+        assert_eq!(
+            get_next_period_end(storage_enums::ReportFrequency::Daily, now),
+            datetime!(2024-04-18 0:00)
+        );
+        assert_eq!(
+            get_next_period_end(storage_enums::ReportFrequency::Weekly, now),
+            datetime!(2024-04-22 0:00)
+        );
+
+        // A period ending exactly at the given time is not the next one
+        let sunday_night = datetime!(2024-04-21 23:59);
+        let monday = datetime!(2024-04-22 0:00);
+        assert_eq!(
+            get_next_period_end(storage_enums::ReportFrequency::Weekly, sunday_night),
+            monday
+        );
+        assert_eq!(
+            get_next_period_end(storage_enums::ReportFrequency::Weekly, monday),
+            datetime!(2024-04-29 0:00)
+        );
+    }
+
+    #[test]
+    fn test_report_file_escapes_fields() {
+        let file = get_report_file(
+            &["payment_id", "description"],
+            vec![vec!["pay_1".to_string(), "shoes, socks".to_string()]].into_iter(),
+        );
+        assert_eq!(file, "payment_id,description\npay_1,\"shoes, socks\"");
+    }
+}
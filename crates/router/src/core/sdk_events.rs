@@ -0,0 +1,75 @@
+//! Ingestion of the checkout telemetry events sent by the SDKs.
+//!
+//! Events are authenticated with the publishable key and the client secret of the payment being
+//! checked out, and are pushed to the analytics store through the event handler, tagged with the
+//! SDK session they were raised in.
+
+use api_models::analytics::sdk_events::SdkTelemetryEventsRequest;
+use common_utils::fp_utils::when;
+use error_stack::IntoReport;
+use masking::PeekInterface;
+use router_env::{instrument, tracing};
+use time::OffsetDateTime;
+
+use super::{
+    errors::{self, RouterResponse, StorageErrorExt},
+    payments::helpers,
+};
+use crate::{
+    events::sdk_events::SdkEvent,
+    routes::{app::AppStateInfo, AppState},
+    services::ApplicationResponse,
+    types::domain,
+};
+
+/// Maximum number of events accepted in a single request
+const MAX_EVENTS_PER_REQUEST: usize = 100;
+
+#[instrument(skip_all)]
+pub async fn ingest_sdk_events(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: SdkTelemetryEventsRequest,
+) -> RouterResponse<()> {
+    when(req.events.len() > MAX_EVENTS_PER_REQUEST, || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: format!(
+                "a maximum of {MAX_EVENTS_PER_REQUEST} events can be sent in a single request"
+            ),
+        })
+    })?;
+    when(req.sdk_session_id.is_empty(), || {
+        Err(errors::ApiErrorResponse::InvalidRequestData {
+            message: "sdk_session_id must not be empty".to_string(),
+        })
+    })?;
+
+    let publishable_key = merchant_account
+        .publishable_key
+        .clone()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()?;
+    let payment_intent = state
+        .store
+        .find_payment_intent_by_payment_id_merchant_id(
+            &req.payment_id,
+            &merchant_account.merchant_id,
+            merchant_account.storage_scheme,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+    helpers::authenticate_client_secret(Some(req.client_secret.peek()), &payment_intent)?;
+
+    let received_at = OffsetDateTime::now_utc();
+    let event_handler = state.event_handler();
+    for event in req.events.iter().cloned() {
+        event_handler.log_event(&SdkEvent::new(
+            publishable_key.clone(),
+            &req,
+            event,
+            received_at,
+        ));
+    }
+
+    Ok(ApplicationResponse::StatusOk)
+}
@@ -0,0 +1,806 @@
+//! Alerts on drops of the payment success rate.
+//!
+//! Merchants define alerts which compare the success rate of the payments of each of their
+//! connectors over a recent window with the success rate over the window preceding it. Every
+//! alert has a recurring process tracker task which evaluates it from the analytics store, and
+//! fires the alert for each connector whose success rate dropped from the baseline by more than
+//! the threshold of the alert. A fired alert is delivered as a `success_rate_dropped` webhook to
+//! the default business profile of the merchant, and optionally over email. A connector fires the
+//! alert again only after its success rate was evaluated to be back within the threshold.
+
+#[cfg(feature = "olap")]
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use api_models::analytics::success_rate_alerts::SuccessRateDropDetails;
+#[cfg(feature = "olap")]
+use api_models::analytics::{
+    payments::{PaymentDimensions, PaymentFilters, PaymentMetrics},
+    success_rate_alerts::{
+        SuccessRateAlertCreateRequest, SuccessRateAlertId, SuccessRateAlertResponse,
+    },
+    GetPaymentMetricRequest, TimeRange,
+};
+use common_utils::ext_traits::ValueExt;
+#[cfg(feature = "olap")]
+use common_utils::{ext_traits::Encode, fp_utils::when};
+#[cfg(feature = "olap")]
+use error_stack::report;
+use error_stack::{IntoReport, ResultExt};
+#[cfg(feature = "olap")]
+use masking::{PeekInterface, Secret};
+#[cfg(feature = "olap")]
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "olap")]
+use time::{Duration, PrimitiveDateTime};
+
+#[cfg(feature = "olap")]
+use crate::{
+    consts,
+    core::{
+        errors::{RouterResponse, StorageErrorExt},
+        webhooks as webhooks_core,
+    },
+    routes::metrics,
+    services::ApplicationResponse,
+    types::{api, api::enums as api_enums, domain, storage::enums as storage_enums},
+    utils,
+};
+use crate::{
+    core::errors::{self, RouterResult},
+    routes::AppState,
+    types::storage,
+};
+
+#[cfg(feature = "olap")]
+const SUCCESS_RATE_ALERT_TASK_NAME: &str = "SUCCESS_RATE_ALERT";
+#[cfg(feature = "olap")]
+const SUCCESS_RATE_ALERT_TAG: &str = "SUCCESS_RATE_ALERT";
+#[cfg(feature = "olap")]
+const SUCCESS_RATE_ALERT_RUNNER: storage::ProcessTrackerRunner =
+    storage::ProcessTrackerRunner::SuccessRateAlertWorkflow;
+
+pub const SUCCESS_RATE_ALERT_DEACTIVATED: &str = "ALERT_DEACTIVATED";
+
+/// Longest evaluation or baseline window of an alert, one week
+#[cfg(feature = "olap")]
+const MAX_WINDOW_IN_MINS: u32 = 7 * 24 * 60;
+
+/// Tracking data of the process tracker task of a success rate alert
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SuccessRateAlertTrackingData {
+    pub merchant_id: String,
+    pub alert_id: String,
+}
+
+/// Success rate of the payments of a connector over a window
+#[cfg(feature = "olap")]
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ConnectorSuccessRate {
+    success_rate: f64,
+    payment_count: u64,
+}
+
+#[cfg(feature = "olap")]
+fn generate_task_id_for_success_rate_alert_workflow(alert_id: &str) -> String {
+    format!("{SUCCESS_RATE_ALERT_TASK_NAME}_{alert_id}")
+}
+
+fn get_success_rate_drop_object_id(drop: &SuccessRateDropDetails) -> String {
+    format!(
+        "{}_{}_{}",
+        drop.alert_id,
+        drop.connector,
+        drop.triggered_at.assume_utc().unix_timestamp()
+    )
+}
+
+/// The identifier of the alert a drop was fired by, alert identifiers having a single underscore
+/// between the prefix and the random part
+pub fn get_alert_id_from_object_id(object_id: &str) -> Option<String> {
+    let mut parts = object_id.splitn(3, '_');
+    match (parts.next(), parts.next(), parts.next()) {
+        (Some(prefix), Some(id), Some(_drop)) => Some(format!("{prefix}_{id}")),
+        _ => None,
+    }
+}
+
+fn get_triggered_drops(
+    alert: &storage::SuccessRateAlert,
+) -> RouterResult<Vec<SuccessRateDropDetails>> {
+    alert
+        .triggered_drops
+        .clone()
+        .parse_value("SuccessRateDropDetails")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse the triggered drops of the success rate alert")
+}
+
+/// Provides the content of the webhook of a drop, for the webhook to be retried. The drop is not
+/// found if the connector fired the alert again since.
+pub async fn get_success_rate_drop_content(
+    state: &AppState,
+    merchant_id: &str,
+    object_id: &str,
+) -> RouterResult<SuccessRateDropDetails> {
+    let alert_id = get_alert_id_from_object_id(object_id)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("Invalid success rate drop object id")?;
+    let alert = state
+        .store
+        .find_success_rate_alert_by_merchant_id_alert_id(merchant_id, &alert_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch success rate alert")?;
+
+    get_triggered_drops(&alert)?
+        .into_iter()
+        .find(|drop| get_success_rate_drop_object_id(drop) == object_id)
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("Success rate drop was superseded by a newer drop of the connector")
+}
+
+#[cfg(feature = "olap")]
+fn validate_success_rate_alert(req: &SuccessRateAlertCreateRequest) -> RouterResult<()> {
+    let invalid_request = |message: String| {
+        Err(report!(errors::ApiErrorResponse::InvalidRequestData {
+            message,
+        }))
+    };
+
+    for (field, window) in [
+        ("evaluation_window_in_mins", req.evaluation_window_in_mins),
+        ("baseline_window_in_mins", req.baseline_window_in_mins),
+    ] {
+        if window == 0 || window > MAX_WINDOW_IN_MINS {
+            return invalid_request(format!(
+                "{field} must be between 1 and {MAX_WINDOW_IN_MINS}"
+            ));
+        }
+    }
+    if !req.drop_threshold.is_finite() || req.drop_threshold <= 0.0 || req.drop_threshold > 100.0 {
+        return invalid_request(
+            "drop_threshold must be greater than 0 and at most 100 percentage points".to_string(),
+        );
+    }
+    if req.min_payment_count == 0 {
+        return invalid_request("min_payment_count must be greater than 0".to_string());
+    }
+    if cfg!(not(feature = "email")) && !req.email_recipients.is_empty() {
+        return Err(report!(errors::ApiErrorResponse::NotSupported {
+            message: "Delivery of alerts over email".to_string(),
+        }));
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "olap")]
+fn to_success_rate_alert_response(
+    alert: storage::SuccessRateAlert,
+) -> RouterResult<SuccessRateAlertResponse> {
+    let ongoing_drops = get_triggered_drops(&alert)?
+        .into_iter()
+        .filter(|drop| drop.recovered_at.is_none())
+        .collect();
+
+    Ok(SuccessRateAlertResponse {
+        alert_id: alert.alert_id,
+        connector: alert.connector,
+        evaluation_window_in_mins: alert.evaluation_window_in_mins,
+        baseline_window_in_mins: alert.baseline_window_in_mins,
+        drop_threshold: alert.drop_threshold,
+        min_payment_count: alert.min_payment_count,
+        is_active: alert.is_active,
+        ongoing_drops,
+        last_evaluated_at: alert.last_evaluated_at,
+        created_at: alert.created_at,
+    })
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn create_success_rate_alert(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    req: SuccessRateAlertCreateRequest,
+) -> RouterResponse<SuccessRateAlertResponse> {
+    validate_success_rate_alert(&req)?;
+
+    let db = &*state.store;
+    let active_alerts = db
+        .list_active_success_rate_alerts_by_merchant_id(&merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list success rate alerts")?;
+    let max_alerts = state.conf.success_rate_alerts.max_alerts_per_merchant;
+    when(active_alerts.len() >= max_alerts, || {
+        Err(errors::ApiErrorResponse::PreconditionFailed {
+            message: format!("A merchant can have at most {max_alerts} active success rate alerts"),
+        })
+        .into_report()
+    })?;
+
+    let email_recipients = if req.email_recipients.is_empty() {
+        None
+    } else {
+        let email_recipients = req
+            .email_recipients
+            .encode_to_value()
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to serialize success rate alert email recipients")?;
+        Some(
+            domain::types::encrypt(
+                Secret::<_, masking::WithType>::new(email_recipients),
+                key_store.key.get_inner().peek(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to encrypt success rate alert email recipients")?
+            .into(),
+        )
+    };
+
+    let into_i32 = |value: u32| {
+        i32::try_from(value).into_report().change_context(
+            errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("{value} is too large"),
+            },
+        )
+    };
+    let now = common_utils::date_time::now();
+    let alert = storage::SuccessRateAlertNew {
+        alert_id: utils::generate_id(consts::ID_LENGTH, "sra"),
+        merchant_id: merchant_account.merchant_id.clone(),
+        connector: req.connector.map(|connector| connector.to_string()),
+        evaluation_window_in_mins: into_i32(req.evaluation_window_in_mins)?,
+        baseline_window_in_mins: into_i32(req.baseline_window_in_mins)?,
+        drop_threshold: req.drop_threshold,
+        min_payment_count: into_i32(req.min_payment_count)?,
+        email_recipients,
+        is_active: true,
+        triggered_drops: serde_json::Value::Array(Vec::new()),
+        last_evaluated_at: None,
+        created_at: now,
+        modified_at: now,
+    };
+    let alert = db
+        .insert_success_rate_alert(alert)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert success rate alert")?;
+
+    let tracking_data = SuccessRateAlertTrackingData {
+        merchant_id: alert.merchant_id.clone(),
+        alert_id: alert.alert_id.clone(),
+    };
+    let schedule_time = now.saturating_add(get_evaluation_interval(&state));
+    let process_tracker_entry = storage::ProcessTrackerNew::new(
+        generate_task_id_for_success_rate_alert_workflow(&alert.alert_id),
+        SUCCESS_RATE_ALERT_TASK_NAME,
+        SUCCESS_RATE_ALERT_RUNNER,
+        [SUCCESS_RATE_ALERT_TAG],
+        tracking_data,
+        schedule_time,
+    )
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to construct success rate alert process tracker task")?;
+
+    db.insert_process(process_tracker_entry)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable_lazy(|| {
+            format!(
+                "Failed while inserting success rate alert task to process_tracker: alert_id: {}",
+                alert.alert_id
+            )
+        })?;
+    metrics::TASKS_ADDED_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes("flow", "SuccessRateAlert")],
+    );
+
+    to_success_rate_alert_response(alert).map(ApplicationResponse::Json)
+}
+
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn list_success_rate_alerts(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<Vec<SuccessRateAlertResponse>> {
+    let alerts = state
+        .store
+        .list_active_success_rate_alerts_by_merchant_id(&merchant_account.merchant_id)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list success rate alerts")?;
+
+    alerts
+        .into_iter()
+        .map(to_success_rate_alert_response)
+        .collect::<RouterResult<Vec<_>>>()
+        .map(ApplicationResponse::Json)
+}
+
+/// Deactivates the alert and finishes its process tracker task
+#[cfg(feature = "olap")]
+#[instrument(skip_all)]
+pub async fn delete_success_rate_alert(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    req: SuccessRateAlertId,
+) -> RouterResponse<SuccessRateAlertResponse> {
+    let db = &*state.store;
+    let alert = db
+        .find_success_rate_alert_by_merchant_id_alert_id(
+            &merchant_account.merchant_id,
+            &req.alert_id,
+        )
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::GenericNotFoundError {
+            message: "Success rate alert not found".to_string(),
+        })?;
+
+    let alert = db
+        .update_success_rate_alert_by_merchant_id_alert_id(
+            &alert.merchant_id,
+            &alert.alert_id,
+            storage::SuccessRateAlertUpdateInternal {
+                is_active: Some(false),
+                modified_at: Some(common_utils::date_time::now()),
+                ..Default::default()
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to deactivate success rate alert")?;
+
+    // The task finishes itself on its next run if this fails
+    let process = db
+        .find_process_by_id(&generate_task_id_for_success_rate_alert_workflow(
+            &alert.alert_id,
+        ))
+        .await;
+    match process {
+        Ok(Some(process)) if process.business_status != SUCCESS_RATE_ALERT_DEACTIVATED => {
+            if let Err(error) = db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    SUCCESS_RATE_ALERT_DEACTIVATED.to_string(),
+                )
+                .await
+            {
+                logger::error!(?error, "Failed to finish success rate alert task");
+            }
+        }
+        Ok(_) => {}
+        Err(error) => logger::error!(?error, "Failed to fetch success rate alert task"),
+    }
+
+    to_success_rate_alert_response(alert).map(ApplicationResponse::Json)
+}
+
+#[cfg(feature = "olap")]
+pub fn get_evaluation_interval(state: &AppState) -> Duration {
+    Duration::seconds(
+        state
+            .conf
+            .success_rate_alerts
+            .evaluation_interval_in_secs
+            .into(),
+    )
+}
+
+/// Evaluates the success rate of the connectors of the alert over the evaluation window against
+/// the baseline window preceding it, and fires the alert for the connectors which dropped since
+/// the previous evaluation. The drops are recorded before they are notified, so that a failed
+/// notification is not retried by the next evaluation.
+#[cfg(feature = "olap")]
+#[instrument(skip_all, fields(alert_id = %alert.alert_id))]
+pub async fn evaluate_success_rate_alert(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    alert: &storage::SuccessRateAlert,
+) -> RouterResult<()> {
+    let now = common_utils::date_time::now();
+    let evaluation_start =
+        now.saturating_sub(Duration::minutes(alert.evaluation_window_in_mins.into()));
+    let baseline_start =
+        evaluation_start.saturating_sub(Duration::minutes(alert.baseline_window_in_mins.into()));
+
+    let (success_rates, baseline_success_rates) = futures::future::try_join(
+        get_connector_success_rates(state, alert, evaluation_start, now),
+        get_connector_success_rates(state, alert, baseline_start, evaluation_start),
+    )
+    .await?;
+
+    let (triggered_drops, new_drops) = update_triggered_drops(
+        alert,
+        get_triggered_drops(alert)?,
+        &success_rates,
+        &baseline_success_rates,
+        now,
+    );
+    let triggered_drops = triggered_drops
+        .encode_to_value()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the triggered drops of the success rate alert")?;
+
+    state
+        .store
+        .update_success_rate_alert_by_merchant_id_alert_id(
+            &alert.merchant_id,
+            &alert.alert_id,
+            storage::SuccessRateAlertUpdateInternal {
+                is_active: None,
+                triggered_drops: Some(triggered_drops),
+                last_evaluated_at: Some(now),
+                modified_at: Some(now),
+            },
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to update success rate alert")?;
+
+    for drop in new_drops {
+        logger::info!(
+            connector = %drop.connector,
+            success_rate = drop.success_rate,
+            baseline_success_rate = drop.baseline_success_rate,
+            "Success rate alert fired"
+        );
+        if let Err(error) =
+            notify_success_rate_drop(state, merchant_account, key_store, alert, drop).await
+        {
+            logger::error!(?error, "Failed to notify success rate drop");
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "olap")]
+async fn get_connector_success_rates(
+    state: &AppState,
+    alert: &storage::SuccessRateAlert,
+    start_time: PrimitiveDateTime,
+    end_time: PrimitiveDateTime,
+) -> RouterResult<HashMap<String, ConnectorSuccessRate>> {
+    let connector = alert
+        .connector
+        .as_deref()
+        .map(api_enums::Connector::from_str)
+        .transpose()
+        .into_report()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Invalid connector of the success rate alert")?;
+
+    let request = GetPaymentMetricRequest {
+        time_series: None,
+        time_range: TimeRange {
+            start_time,
+            end_time: Some(end_time),
+        },
+        group_by_names: vec![PaymentDimensions::Connector],
+        filters: PaymentFilters {
+            connector: connector.into_iter().collect(),
+            ..Default::default()
+        },
+        metrics: HashSet::from([
+            PaymentMetrics::PaymentSuccessRate,
+            PaymentMetrics::PaymentCount,
+        ]),
+        distribution: None,
+        delta: false,
+        reporting_currency: None,
+        // Drops are detected on the most recent payments, which a cached result may not cover
+        bypass_cache: true,
+    };
+    let metrics = crate::analytics::payments::get_metrics(
+        &state.pool,
+        &alert.merchant_id,
+        &[alert.merchant_id.clone()],
+        request,
+        None,
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to fetch the success rate of the connectors")?;
+
+    Ok(metrics
+        .query_data
+        .into_iter()
+        .filter_map(|bucket| {
+            Some((
+                bucket.dimensions.connector?,
+                ConnectorSuccessRate {
+                    success_rate: bucket.values.payment_success_rate?,
+                    payment_count: bucket.values.payment_count.unwrap_or_default(),
+                },
+            ))
+        })
+        .collect())
+}
+
+/// Updates the latest drop of each connector with the success rates of the evaluation, and
+/// provides the drops which fire the alert. Connectors with fewer payments than the minimum in
+/// either window are not evaluated, and their ongoing drops are left as they are.
+#[cfg(feature = "olap")]
+fn update_triggered_drops(
+    alert: &storage::SuccessRateAlert,
+    mut triggered_drops: Vec<SuccessRateDropDetails>,
+    success_rates: &HashMap<String, ConnectorSuccessRate>,
+    baseline_success_rates: &HashMap<String, ConnectorSuccessRate>,
+    now: PrimitiveDateTime,
+) -> (Vec<SuccessRateDropDetails>, Vec<SuccessRateDropDetails>) {
+    let min_payment_count = u64::try_from(alert.min_payment_count).unwrap_or_default();
+    let mut new_drops = Vec::new();
+
+    for (connector, current) in success_rates {
+        let Some(baseline) = baseline_success_rates.get(connector) else {
+            continue;
+        };
+        if current.payment_count < min_payment_count || baseline.payment_count < min_payment_count {
+            continue;
+        }
+
+        let is_dropped = baseline.success_rate - current.success_rate >= alert.drop_threshold;
+        let ongoing_drop = triggered_drops
+            .iter_mut()
+            .find(|drop| &drop.connector == connector && drop.recovered_at.is_none());
+        match (is_dropped, ongoing_drop) {
+            (true, None) => {
+                let drop = SuccessRateDropDetails {
+                    alert_id: alert.alert_id.clone(),
+                    connector: connector.clone(),
+                    success_rate: current.success_rate,
+                    baseline_success_rate: baseline.success_rate,
+                    payment_count: current.payment_count,
+                    baseline_payment_count: baseline.payment_count,
+                    triggered_at: now,
+                    recovered_at: None,
+                };
+                triggered_drops.retain(|drop| &drop.connector != connector);
+                triggered_drops.push(drop.clone());
+                new_drops.push(drop);
+            }
+            (false, Some(ongoing_drop)) => ongoing_drop.recovered_at = Some(now),
+            (true, Some(_)) | (false, None) => {}
+        }
+    }
+
+    (triggered_drops, new_drops)
+}
+
+#[cfg(feature = "olap")]
+async fn notify_success_rate_drop(
+    state: &AppState,
+    merchant_account: &domain::MerchantAccount,
+    key_store: &domain::MerchantKeyStore,
+    alert: &storage::SuccessRateAlert,
+    drop: SuccessRateDropDetails,
+) -> RouterResult<()> {
+    if let Err(error) = send_success_rate_drop_emails(state, key_store, alert, &drop).await {
+        logger::error!(?error, "Failed to send success rate drop emails");
+    }
+
+    let Some(profile_id) = merchant_account.default_profile.as_deref() else {
+        logger::debug!(
+            merchant_id = %merchant_account.merchant_id,
+            "Merchant has no default business profile, skipping the success rate dropped webhook"
+        );
+        return Ok(());
+    };
+    let business_profile = state
+        .store
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .change_context(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_string(),
+        })?;
+
+    let object_id = get_success_rate_drop_object_id(&drop);
+    let triggered_at = drop.triggered_at;
+    Box::pin(webhooks_core::create_event_and_trigger_outgoing_webhook(
+        state.clone(),
+        merchant_account.clone(),
+        business_profile,
+        key_store,
+        storage_enums::EventType::SuccessRateDropped,
+        storage_enums::EventClass::Alerts,
+        object_id,
+        storage_enums::EventObjectType::SuccessRateDropDetails,
+        api::OutgoingWebhookContent::SuccessRateDropDetails(Box::new(drop)),
+        Some(triggered_at),
+    ))
+    .await
+}
+
+#[cfg(all(feature = "olap", feature = "email"))]
+async fn send_success_rate_drop_emails(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    alert: &storage::SuccessRateAlert,
+    drop: &SuccessRateDropDetails,
+) -> RouterResult<()> {
+    use masking::ExposeInterface;
+
+    use crate::services::email::types as email_types;
+
+    let Some(email_recipients) = alert.email_recipients.clone() else {
+        return Ok(());
+    };
+    let email_recipients = domain::types::decrypt::<serde_json::Value, masking::WithType>(
+        Some(email_recipients),
+        key_store.key.get_inner().peek(),
+    )
+    .await
+    .change_context(errors::ApiErrorResponse::InternalServerError)
+    .attach_printable("Failed to decrypt success rate alert email recipients")?
+    .map(|email_recipients| email_recipients.into_inner().expose())
+    .unwrap_or_default()
+    .parse_value::<Vec<common_utils::pii::Email>>("EmailRecipients")
+    .change_context(errors::ApiErrorResponse::InternalServerError)?;
+
+    for recipient_email in email_recipients {
+        let email_contents = email_types::SuccessRateDropped {
+            recipient_email,
+            subject: "Success Rate Drop Detected",
+            merchant_id: alert.merchant_id.clone(),
+            alert_id: alert.alert_id.clone(),
+            connector: drop.connector.clone(),
+            success_rate: drop.success_rate,
+            baseline_success_rate: drop.baseline_success_rate,
+            evaluation_window_in_mins: alert.evaluation_window_in_mins,
+            baseline_window_in_mins: alert.baseline_window_in_mins,
+            drop_threshold: alert.drop_threshold,
+        };
+        state
+            .email_client
+            .clone()
+            .compose_and_send_email(
+                Box::new(email_contents),
+                state.conf.proxy.https_url.as_ref(),
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to send success rate drop email")?;
+    }
+
+    Ok(())
+}
+
+#[cfg(all(feature = "olap", not(feature = "email")))]
+async fn send_success_rate_drop_emails(
+    _state: &AppState,
+    _key_store: &domain::MerchantKeyStore,
+    _alert: &storage::SuccessRateAlert,
+    _drop: &SuccessRateDropDetails,
+) -> RouterResult<()> {
+    Ok(())
+}
+
+#[cfg(all(test, feature = "olap"))]
+mod tests {
+    use time::macros::datetime;
+
+    use super::*;
+
+    fn get_alert(triggered_drops: &[SuccessRateDropDetails]) -> storage::SuccessRateAlert {
+        let now = datetime!(2024-04-18 10:00);
+        storage::SuccessRateAlert {
+            alert_id: "sra_abc".to_string(),
+            merchant_id: "merchant_1".to_string(),
+            connector: None,
+            evaluation_window_in_mins: 15,
+            baseline_window_in_mins: 60,
+            drop_threshold: 10.0,
+            min_payment_count: 20,
+            email_recipients: None,
+            is_active: true,
+            triggered_drops: serde_json::to_value(triggered_drops).unwrap_or_default(),
+            last_evaluated_at: None,
+            created_at: now,
+            modified_at: now,
+        }
+    }
+
+    fn get_success_rates(
+        success_rates: &[(&str, f64, u64)],
+    ) -> HashMap<String, ConnectorSuccessRate> {
+        success_rates
+            .iter()
+            .map(|(connector, success_rate, payment_count)| {
+                (
+                    connector.to_string(),
+                    ConnectorSuccessRate {
+                        success_rate: *success_rate,
+                        payment_count: *payment_count,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_drop_fires_once_until_recovered() {
+        let now = datetime!(2024-04-18 10:05);
+        let baseline = get_success_rates(&[("stripe", 90.0, 200), ("adyen", 95.0, 200)]);
+        let alert = get_alert(&[]);
+
+        // Adyen is within the threshold and Stripe dropped by 15 percentage points
+        let current = get_success_rates(&[("stripe", 75.0, 50), ("adyen", 90.0, 50)]);
+        let (triggered_drops, new_drops) =
+            update_triggered_drops(&alert, Vec::new(), &current, &baseline, now);
+        assert_eq!(new_drops.len(), 1);
+        assert_eq!(
+            new_drops.first().map(|drop| drop.connector.as_str()),
+            Some("stripe")
+        );
+        assert_eq!(triggered_drops, new_drops);
+
+        // The ongoing drop does not fire again
+        let (triggered_drops, new_drops) =
+            update_triggered_drops(&alert, triggered_drops, &current, &baseline, now);
+        assert!(new_drops.is_empty());
+
+        // The drop recovers once the success rate is back within the threshold
+        let current = get_success_rates(&[("stripe", 85.0, 50)]);
+        let (triggered_drops, new_drops) =
+            update_triggered_drops(&alert, triggered_drops, &current, &baseline, now);
+        assert!(new_drops.is_empty());
+        assert_eq!(
+            triggered_drops.first().and_then(|drop| drop.recovered_at),
+            Some(now)
+        );
+
+        // A new drop replaces the recovered one
+        let current = get_success_rates(&[("stripe", 70.0, 50)]);
+        let (triggered_drops, new_drops) =
+            update_triggered_drops(&alert, triggered_drops, &current, &baseline, now);
+        assert_eq!(new_drops.len(), 1);
+        assert_eq!(triggered_drops.len(), 1);
+    }
+
+    #[test]
+    fn test_connectors_with_few_payments_are_not_evaluated() {
+        let now = datetime!(2024-04-18 10:05);
+        let alert = get_alert(&[]);
+        let baseline = get_success_rates(&[("stripe", 90.0, 200), ("adyen", 95.0, 10)]);
+        let current = get_success_rates(&[("stripe", 0.0, 5), ("adyen", 0.0, 50)]);
+
+        let (triggered_drops, new_drops) =
+            update_triggered_drops(&alert, Vec::new(), &current, &baseline, now);
+        assert!(triggered_drops.is_empty());
+        assert!(new_drops.is_empty());
+    }
+
+    #[test]
+    fn test_alert_id_from_object_id() {
+        let drop = SuccessRateDropDetails {
+            alert_id: "sra_abc123".to_string(),
+            connector: "dummy_connector_1".to_string(),
+            success_rate: 50.0,
+            baseline_success_rate: 90.0,
+            payment_count: 100,
+            baseline_payment_count: 400,
+            triggered_at: datetime!(2024-04-18 10:05),
+            recovered_at: None,
+        };
+        assert_eq!(
+            get_alert_id_from_object_id(&get_success_rate_drop_object_id(&drop)),
+            Some("sra_abc123".to_string())
+        );
+        assert_eq!(get_alert_id_from_object_id("sra_abc123"), None);
+    }
+}
@@ -0,0 +1,160 @@
+use std::collections::HashSet;
+
+use api_models::three_ds_decision_configs::ThreeDsDecisionRules;
+use common_utils::ext_traits::Encode;
+use diesel_models::configs;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::three_ds_decision,
+    },
+    routes::AppState,
+    services::api as service_api,
+    types::{domain, storage::enums as storage_enums},
+};
+
+#[instrument(skip(state))]
+pub async fn upsert_three_ds_decision_config(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    rules: ThreeDsDecisionRules,
+) -> RouterResponse<ThreeDsDecisionRules> {
+    validate_three_ds_decision_rules(&rules)?;
+
+    let key = three_ds_decision::get_three_ds_decision_rules_key(&merchant_account.merchant_id);
+    let config = rules
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the 3DS decision rules")?;
+
+    match state.store.find_config_by_key(&key).await {
+        Ok(_) => state
+            .store
+            .update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the 3DS decision rules")
+            .map(|_| ()),
+        Err(error) if error.current_context().is_db_not_found() => state
+            .store
+            .insert_config(configs::ConfigNew { key, config })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the 3DS decision rules")
+            .map(|_| ()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the 3DS decision rules"),
+    }?;
+
+    Ok(service_api::ApplicationResponse::Json(rules))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_three_ds_decision_config(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<ThreeDsDecisionRules> {
+    let rules = three_ds_decision::get_three_ds_decision_rules(
+        &*state.store,
+        &merchant_account.merchant_id,
+    )
+    .await?;
+    Ok(service_api::ApplicationResponse::Json(rules))
+}
+
+#[instrument(skip(state))]
+pub async fn delete_three_ds_decision_config(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<()> {
+    let key = three_ds_decision::get_three_ds_decision_rules_key(&merchant_account.merchant_id);
+    state
+        .store
+        .delete_config_by_key(&key)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)
+        .attach_printable("Failed to delete the 3DS decision rules")?;
+    Ok(service_api::ApplicationResponse::StatusOk)
+}
+
+fn validate_three_ds_decision_rules(rules: &ThreeDsDecisionRules) -> RouterResult<()> {
+    let mut rule_names = HashSet::new();
+    for rule in rules.rules.iter() {
+        if rule.name.trim().is_empty() {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Name of a 3DS decision rule cannot be empty".to_string(),
+            })?;
+        }
+
+        if !rule_names.insert(rule.name.as_str()) {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Duplicate 3DS decision rule `{}`", rule.name),
+            })?;
+        }
+
+        if rule.min_amount.is_some_and(|amount| amount < 0)
+            || rule.max_amount.is_some_and(|amount| amount < 0)
+        {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Amounts of the rule `{}` cannot be negative", rule.name),
+            })?;
+        }
+
+        if let (Some(min_amount), Some(max_amount)) = (rule.min_amount, rule.max_amount) {
+            if min_amount > max_amount {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "min_amount of the rule `{}` cannot be greater than its max_amount",
+                        rule.name
+                    ),
+                })?;
+            }
+        }
+
+        // Rules apply only the exemptions depending on the payment itself, for payments which are
+        // not authenticated and bounded by the amount the exemption applies to. Other exemptions
+        // are requested by the merchant for the payment.
+        if let Some(exemption) = rule.exemption {
+            if !matches!(
+                exemption,
+                storage_enums::ScaExemptionType::LowValue
+                    | storage_enums::ScaExemptionType::TransactionRiskAnalysis
+            ) {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "The rule `{}` can apply only the low_value or transaction_risk_analysis exemptions",
+                        rule.name
+                    ),
+                })?;
+            }
+
+            if rule.decision != storage_enums::ThreeDsDecision::NoThreeDs {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "The rule `{}` can have an exemption only with the no_three_ds decision",
+                        rule.name
+                    ),
+                })?;
+            }
+
+            if rule.max_amount.is_none() {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "The rule `{}` must have a max_amount to apply an exemption",
+                        rule.name
+                    ),
+                })?;
+            }
+        }
+    }
+    Ok(())
+}
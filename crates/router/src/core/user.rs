@@ -1144,6 +1144,118 @@ pub async fn switch_merchant_id(
     auth::cookies::set_cookie_response(response, token)
 }
 
+pub async fn impersonate_merchant(
+    state: AppState,
+    request: user_api::ImpersonateMerchantRequest,
+    user_from_token: auth::UserFromToken,
+) -> UserResponse<user_api::ImpersonateMerchantResponse> {
+    let role_info = roles::RoleInfo::from_role_id(
+        &state,
+        &user_from_token.role_id,
+        &user_from_token.merchant_id,
+        &user_from_token.org_id,
+    )
+    .await
+    .to_not_found_response(UserErrors::InvalidRoleOperation)?;
+
+    if !role_info.is_internal() {
+        return Err(UserErrors::InvalidRoleOperationWithMessage(
+            "Only internal users can impersonate merchants".to_string(),
+        )
+        .into());
+    }
+
+    if request.reason.trim().is_empty() {
+        return Err(UserErrors::InvalidRoleOperationWithMessage(
+            "Reason for impersonation cannot be empty".to_string(),
+        )
+        .into());
+    }
+
+    let groups = match request.groups {
+        Some(groups) => groups,
+        None => roles::predefined_roles::PREDEFINED_ROLES
+            .get(consts::user_role::ROLE_ID_INTERNAL_VIEW_ONLY_USER)
+            .map(|view_only_role| view_only_role.get_permission_groups().clone())
+            .ok_or(UserErrors::InternalServerError)
+            .into_report()
+            .attach_printable("Internal view only role not found")?,
+    };
+
+    if groups.is_empty()
+        || groups
+            .iter()
+            .any(|group| !role_info.get_permission_groups().contains(group))
+    {
+        return Err(UserErrors::InvalidRoleOperationWithMessage(
+            "Impersonation session can only be granted permission groups of the user".to_string(),
+        )
+        .into());
+    }
+
+    let duration_in_secs = request
+        .duration_in_secs
+        .unwrap_or(consts::user::IMPERSONATION_DEFAULT_DURATION_IN_SECS);
+
+    if duration_in_secs == 0 || duration_in_secs > consts::user::IMPERSONATION_MAX_DURATION_IN_SECS
+    {
+        return Err(UserErrors::InvalidRoleOperationWithMessage(format!(
+            "Impersonation session duration should be between 1 and {} seconds",
+            consts::user::IMPERSONATION_MAX_DURATION_IN_SECS
+        ))
+        .into());
+    }
+
+    let key_store = state
+        .store
+        .get_merchant_key_store_by_merchant_id(
+            request.merchant_id.as_str(),
+            &state.store.get_master_key().to_vec().into(),
+        )
+        .await
+        .to_not_found_response(UserErrors::MerchantIdNotFound)?;
+
+    let org_id = state
+        .store
+        .find_merchant_account_by_merchant_id(request.merchant_id.as_str(), &key_store)
+        .await
+        .to_not_found_response(UserErrors::MerchantIdNotFound)?
+        .organization_id;
+
+    let session_id = common_utils::generate_id(consts::ID_LENGTH, "imp");
+
+    let token = auth::AuthToken::new_impersonation_token(
+        user_from_token.user_id.clone(),
+        request.merchant_id.clone(),
+        org_id,
+        auth::ImpersonationClaims {
+            session_id: session_id.clone(),
+            groups: groups.clone(),
+        },
+        std::time::Duration::from_secs(duration_in_secs),
+        &state.conf,
+    )
+    .await?;
+
+    logger::info!(
+        impersonator_id = %user_from_token.user_id,
+        merchant_id = %request.merchant_id,
+        session_id = %session_id,
+        reason = %request.reason,
+        "Impersonation session created"
+    );
+
+    Ok(ApplicationResponse::Json(
+        user_api::ImpersonateMerchantResponse {
+            token: token.into(),
+            merchant_id: request.merchant_id,
+            session_id,
+            groups,
+            expires_in_secs: duration_in_secs,
+        },
+    ))
+}
+
 pub async fn create_merchant_account(
     state: AppState,
     user_from_token: auth::UserFromToken,
@@ -1,12 +1,15 @@
 use api_models::user::sample_data::SampleDataRequest;
 use common_utils::errors::ReportSwitchExt;
 use data_models::payments::payment_intent::PaymentIntentNew;
-use diesel_models::{user::sample_data::PaymentAttemptBatchNew, RefundNew};
+use diesel_models::{
+    user::sample_data::{DisputeBatchNew, PaymentAttemptBatchNew},
+    RefundNew,
+};
 
 pub type SampleDataApiResponse<T> = SampleDataResult<ApplicationResponse<T>>;
 
 use crate::{
-    core::errors::sample_data::SampleDataResult,
+    core::errors::sample_data::{SampleDataError, SampleDataResult},
     routes::AppState,
     services::{authentication::UserFromToken, ApplicationResponse},
     utils::user::sample_data::generate_sample_data,
@@ -17,22 +20,75 @@ pub async fn generate_sample_data_for_user(
     user_from_token: UserFromToken,
     req: SampleDataRequest,
 ) -> SampleDataApiResponse<()> {
-    let sample_data =
-        generate_sample_data(&state, req, user_from_token.merchant_id.as_str()).await?;
+    generate_sample_data_for_merchant(&state, user_from_token.merchant_id.as_str(), req).await?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn delete_sample_data_for_user(
+    state: AppState,
+    user_from_token: UserFromToken,
+    _req: SampleDataRequest,
+) -> SampleDataApiResponse<()> {
+    delete_sample_data_for_merchant(&state, user_from_token.merchant_id.as_str()).await?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+/// Generates sample data for a sandbox merchant, meant to be used by admins to populate
+/// dashboards and analytics for demos and integration testing
+pub async fn generate_sandbox_data_for_merchant(
+    state: AppState,
+    merchant_id: &str,
+    req: SampleDataRequest,
+) -> SampleDataApiResponse<()> {
+    validate_sandbox_environment()?;
+    generate_sample_data_for_merchant(&state, merchant_id, req).await?;
 
-    let (payment_intents, payment_attempts, refunds): (
+    Ok(ApplicationResponse::StatusOk)
+}
+
+pub async fn delete_sandbox_data_for_merchant(
+    state: AppState,
+    merchant_id: &str,
+) -> SampleDataApiResponse<()> {
+    validate_sandbox_environment()?;
+    delete_sample_data_for_merchant(&state, merchant_id).await?;
+
+    Ok(ApplicationResponse::StatusOk)
+}
+
+fn validate_sandbox_environment() -> SampleDataResult<()> {
+    match router_env::env::which() {
+        router_env::env::Env::Production => Err(SampleDataError::NotAllowedInProduction.into()),
+        router_env::env::Env::Development | router_env::env::Env::Sandbox => Ok(()),
+    }
+}
+
+async fn generate_sample_data_for_merchant(
+    state: &AppState,
+    merchant_id: &str,
+    req: SampleDataRequest,
+) -> SampleDataResult<()> {
+    let sample_data = generate_sample_data(state, req, merchant_id).await?;
+
+    let (payment_intents, payment_attempts, refunds, disputes): (
         Vec<PaymentIntentNew>,
         Vec<PaymentAttemptBatchNew>,
         Vec<RefundNew>,
+        Vec<DisputeBatchNew>,
     ) = sample_data.into_iter().fold(
-        (Vec::new(), Vec::new(), Vec::new()),
-        |(mut pi, mut pa, mut rf), (payment_intent, payment_attempt, refund)| {
+        (Vec::new(), Vec::new(), Vec::new(), Vec::new()),
+        |(mut pi, mut pa, mut rf, mut dp), (payment_intent, payment_attempt, refund, dispute)| {
             pi.push(payment_intent);
             pa.push(payment_attempt);
             if let Some(refund) = refund {
                 rf.push(refund);
             }
-            (pi, pa, rf)
+            if let Some(dispute) = dispute {
+                dp.push(dispute);
+            }
+            (pi, pa, rf, dp)
         },
     );
 
@@ -51,32 +107,43 @@ pub async fn generate_sample_data_for_user(
         .insert_refunds_batch_for_sample_data(refunds)
         .await
         .switch()?;
+    // Disputes are generated only for a fraction of the successful payments, and can be absent
+    // for smaller data sets
+    if !disputes.is_empty() {
+        state
+            .store
+            .insert_disputes_batch_for_sample_data(disputes)
+            .await
+            .switch()?;
+    }
 
-    Ok(ApplicationResponse::StatusOk)
+    Ok(())
 }
 
-pub async fn delete_sample_data_for_user(
-    state: AppState,
-    user_from_token: UserFromToken,
-    _req: SampleDataRequest,
-) -> SampleDataApiResponse<()> {
-    let merchant_id_del = user_from_token.merchant_id.as_str();
-
+async fn delete_sample_data_for_merchant(
+    state: &AppState,
+    merchant_id: &str,
+) -> SampleDataResult<()> {
     state
         .store
-        .delete_payment_intents_for_sample_data(merchant_id_del)
+        .delete_payment_intents_for_sample_data(merchant_id)
         .await
         .switch()?;
     state
         .store
-        .delete_payment_attempts_for_sample_data(merchant_id_del)
+        .delete_payment_attempts_for_sample_data(merchant_id)
         .await
         .switch()?;
     state
         .store
-        .delete_refunds_for_sample_data(merchant_id_del)
+        .delete_refunds_for_sample_data(merchant_id)
+        .await
+        .switch()?;
+    state
+        .store
+        .delete_disputes_for_sample_data(merchant_id)
         .await
         .switch()?;
 
-    Ok(ApplicationResponse::StatusOk)
+    Ok(())
 }
@@ -159,6 +159,7 @@ pub async fn construct_payout_router_data<'a, F>(
             customer_details: customer_details
                 .to_owned()
                 .map(|c| payments::CustomerDetails {
+                    document: c.get_document_details(),
                     customer_id: Some(c.customer_id),
                     name: c.name.map(Encryptable::into_inner),
                     email: c.email.map(Email::from),
@@ -309,6 +310,7 @@ pub async fn construct_refund_router_data<'a, F>(
             webhook_url,
             connector_metadata: payment_attempt.connector_metadata.clone(),
             reason: refund.refund_reason.clone(),
+            reason_code: refund.refund_reason_code,
             connector_refund_id: refund.connector_refund_id.clone(),
             browser_info,
         },
@@ -422,6 +424,47 @@ mod tests {
         let generated_id = generate_id(consts::ID_LENGTH, "ref");
         assert_eq!(generated_id.len(), consts::ID_LENGTH + 4)
     }
+
+    #[test]
+    fn test_resource_of_another_profile_is_rejected() {
+        let scoped_profile_id = "profile_1".to_string();
+        let other_profile_id = "profile_2".to_string();
+
+        let result = validate_profile_id_of_resource(
+            Some(&scoped_profile_id),
+            Some(&other_profile_id),
+            errors::ApiErrorResponse::PaymentNotFound,
+        );
+        assert!(matches!(
+            result.map_err(|err| err.current_context().clone()),
+            Err(errors::ApiErrorResponse::PaymentNotFound)
+        ));
+
+        let result = validate_profile_id_of_resource(
+            Some(&scoped_profile_id),
+            None,
+            errors::ApiErrorResponse::PaymentNotFound,
+        );
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_resource_of_the_scoped_profile_is_allowed() {
+        let profile_id = "profile_1".to_string();
+
+        assert!(validate_profile_id_of_resource(
+            Some(&profile_id),
+            Some(&profile_id),
+            errors::ApiErrorResponse::PaymentNotFound,
+        )
+        .is_ok());
+        assert!(validate_profile_id_of_resource(
+            None,
+            Some(&profile_id),
+            errors::ApiErrorResponse::PaymentNotFound,
+        )
+        .is_ok());
+    }
 }
 
 // Dispute Stage can move linearly from PreDispute -> Dispute -> PreArbitration
@@ -1009,6 +1052,44 @@ pub async fn validate_and_get_business_profile(
         .into_report()
 }
 
+/// Restricts the business profile requested by the client to the business profile the API key
+/// is scoped to, if any. Requests for any other business profile are forbidden.
+pub fn validate_profile_id_from_auth_layer(
+    profile_id_from_auth_layer: Option<String>,
+    requested_profile_id: Option<String>,
+) -> RouterResult<Option<String>> {
+    match (profile_id_from_auth_layer, requested_profile_id) {
+        (Some(auth_profile_id), Some(requested_profile_id)) => {
+            if auth_profile_id != requested_profile_id {
+                Err(errors::ApiErrorResponse::AccessForbidden {
+                    resource: requested_profile_id,
+                })
+                .into_report()
+            } else {
+                Ok(Some(auth_profile_id))
+            }
+        }
+        (Some(auth_profile_id), None) => Ok(Some(auth_profile_id)),
+        (None, requested_profile_id) => Ok(requested_profile_id),
+    }
+}
+
+/// Ensures that a resource looked up by its id belongs to the business profile the API key is
+/// scoped to, if any. Resources of any other business profile are reported as not found, so that
+/// their existence is not disclosed.
+pub fn validate_profile_id_of_resource(
+    profile_id_from_auth_layer: Option<&String>,
+    resource_profile_id: Option<&String>,
+    not_found_error: errors::ApiErrorResponse,
+) -> RouterResult<()> {
+    match profile_id_from_auth_layer {
+        Some(profile_id) if resource_profile_id != Some(profile_id) => {
+            Err(not_found_error).into_report()
+        }
+        _ => Ok(()),
+    }
+}
+
 fn connector_needs_business_sub_label(connector_name: &str) -> bool {
     let connectors_list = [api_models::enums::Connector::Cybersource];
     connectors_list
@@ -0,0 +1,138 @@
+use std::collections::HashSet;
+
+use api_models::velocity_rules::VelocityRules;
+use common_utils::ext_traits::Encode;
+use diesel_models::configs;
+use error_stack::ResultExt;
+use router_env::{instrument, tracing};
+
+use crate::{
+    core::{
+        errors::{self, RouterResponse, RouterResult, StorageErrorExt},
+        payments::velocity,
+    },
+    routes::AppState,
+    services::api as service_api,
+    types::domain,
+};
+
+/// Maximum length of the sliding window of a velocity rule, in seconds
+const MAX_VELOCITY_WINDOW_IN_SECONDS: u32 = 30 * 24 * 60 * 60;
+
+#[instrument(skip(state))]
+pub async fn upsert_velocity_rules(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+    rules: VelocityRules,
+) -> RouterResponse<VelocityRules> {
+    validate_velocity_rules(&rules)?;
+
+    let key = velocity::get_velocity_rules_key(&merchant_account.merchant_id);
+    let config = rules
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the velocity rules")?;
+
+    match state.store.find_config_by_key(&key).await {
+        Ok(_) => state
+            .store
+            .update_config_by_key(
+                &key,
+                configs::ConfigUpdate::Update {
+                    config: Some(config),
+                },
+            )
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to update the velocity rules")
+            .map(|_| ()),
+        Err(error) if error.current_context().is_db_not_found() => state
+            .store
+            .insert_config(configs::ConfigNew { key, config })
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to insert the velocity rules")
+            .map(|_| ()),
+        Err(error) => Err(error)
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to fetch the velocity rules"),
+    }?;
+
+    Ok(service_api::ApplicationResponse::Json(rules))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_velocity_rules(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<VelocityRules> {
+    let rules = velocity::get_velocity_rules(&*state.store, &merchant_account.merchant_id).await?;
+    Ok(service_api::ApplicationResponse::Json(rules))
+}
+
+#[instrument(skip(state))]
+pub async fn delete_velocity_rules(
+    state: AppState,
+    merchant_account: domain::MerchantAccount,
+) -> RouterResponse<()> {
+    let key = velocity::get_velocity_rules_key(&merchant_account.merchant_id);
+    state
+        .store
+        .delete_config_by_key(&key)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::ResourceIdNotFound)
+        .attach_printable("Failed to delete the velocity rules")?;
+    Ok(service_api::ApplicationResponse::StatusOk)
+}
+
+fn validate_velocity_rules(rules: &VelocityRules) -> RouterResult<()> {
+    let mut rule_names = HashSet::new();
+    for rule in rules.rules.iter() {
+        if rule.name.trim().is_empty() {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "Name of a velocity rule cannot be empty".to_string(),
+            })?;
+        }
+
+        if !rule_names.insert(rule.name.as_str()) {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Duplicate velocity rule `{}`", rule.name),
+            })?;
+        }
+
+        if rule.window_in_seconds == 0 || rule.window_in_seconds > MAX_VELOCITY_WINDOW_IN_SECONDS {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "window_in_seconds of the rule `{}` must be between 1 and {MAX_VELOCITY_WINDOW_IN_SECONDS}",
+                    rule.name
+                ),
+            })?;
+        }
+
+        if rule.max_attempts.is_none() && rule.max_amount.is_none() {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The rule `{}` must limit either max_attempts or max_amount",
+                    rule.name
+                ),
+            })?;
+        }
+
+        if rule.max_attempts == Some(0) || rule.max_amount.is_some_and(|amount| amount <= 0) {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!("Limits of the rule `{}` must be positive", rule.name),
+            })?;
+        }
+
+        // Amounts of payments in different currencies cannot be added up
+        if rule.max_amount.is_some() && rule.currency.is_none() {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "The rule `{}` must have a currency to limit max_amount",
+                    rule.name
+                ),
+            })?;
+        }
+    }
+    Ok(())
+}
@@ -1,3 +1,7 @@
+pub mod delivery_worker;
+pub mod endpoint_health;
+pub mod notification_channels;
+pub mod signing;
 pub mod types;
 pub mod utils;
 #[cfg(feature = "olap")]
@@ -16,11 +20,7 @@ use common_utils::{
 };
 use error_stack::{report, IntoReport, ResultExt};
 use masking::{ExposeInterface, Mask, PeekInterface, Secret};
-use router_env::{
-    instrument,
-    tracing::{self, Instrument},
-    tracing_actix_web::RequestId,
-};
+use router_env::{instrument, tracing, tracing_actix_web::RequestId};
 
 use super::{errors::StorageErrorExt, metrics};
 #[cfg(feature = "stripe")]
@@ -28,7 +28,7 @@ use crate::compatibility::stripe::webhooks as stripe_webhooks;
 use crate::{
     consts,
     core::{
-        api_locking,
+        api_locking, disputes,
         errors::{self, ConnectorErrorExt, CustomResult, RouterResponse},
         payment_methods::PaymentMethodRetrieve,
         payments, refunds,
@@ -101,6 +101,7 @@ pub async fn payments_incoming_webhook_flow<Ctx: PaymentMethodRetrieve>(
                 state.clone(),
                 merchant_account.clone(),
                 key_store.clone(),
+                None,
                 payments::operations::PaymentStatus,
                 api::PaymentsRetrieveRequest {
                     resource_id: id,
@@ -265,6 +266,7 @@ pub async fn refunds_incoming_webhook_flow(
             state.clone(),
             merchant_account.clone(),
             key_store.clone(),
+            None,
             api_models::refunds::RefundsRetrieveRequest {
                 refund_id: refund_id.to_owned(),
                 force_sync: Some(true),
@@ -532,6 +534,7 @@ pub async fn disputes_incoming_webhook_flow(
             )
             .await
             .to_not_found_response(errors::ApiErrorResponse::WebhookResourceNotFound)?;
+        let is_new_dispute = option_dispute.is_none();
         let dispute_object = get_or_update_dispute_object(
             state.clone(),
             option_dispute,
@@ -543,6 +546,18 @@ pub async fn disputes_incoming_webhook_flow(
             connector.id(),
         )
         .await?;
+        if is_new_dispute {
+            if let Err(error) =
+                disputes::representment::add_dispute_representment_task(db, &dispute_object).await
+            {
+                logger::error!(?error, "Failed to schedule automatic dispute representment");
+            }
+            notification_channels::send_operational_alert(
+                &state,
+                &business_profile,
+                notification_channels::get_dispute_received_alert(&dispute_object),
+            );
+        }
         let disputes_response = Box::new(dispute_object.clone().foreign_into());
         let event_type: enums::EventType = dispute_object.dispute_status.foreign_into();
 
@@ -605,6 +620,7 @@ async fn bank_transfer_webhook_flow<Ctx: PaymentMethodRetrieve>(
             state.clone(),
             merchant_account.to_owned(),
             key_store.clone(),
+            None,
             payments::PaymentConfirm,
             request,
             services::api::AuthFlow::Merchant,
@@ -738,6 +754,7 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
         ),
         response: None,
         delivery_attempt: Some(delivery_attempt),
+        delivery_status: Some(enums::WebhookDeliveryStatus::Pending),
     };
 
     let event_insert_result = state
@@ -775,25 +792,17 @@ pub(crate) async fn create_event_and_trigger_outgoing_webhook(
     })
     .ok();
 
-    let cloned_key_store = merchant_key_store.clone();
-    // Using a tokio spawn here and not arbiter because not all caller of this function
-    // may have an actix arbiter
-    tokio::spawn(
-        async move {
-            trigger_webhook_and_raise_event(
-                state,
-                business_profile,
-                &cloned_key_store,
-                event,
-                request_content,
-                delivery_attempt,
-                Some(content),
-                process_tracker,
-            )
-            .await;
-        }
-        .in_current_span(),
-    );
+    delivery_worker::WebhookDeliveryJob {
+        state,
+        business_profile,
+        merchant_key_store: merchant_key_store.clone(),
+        event,
+        request_content,
+        delivery_attempt,
+        content: Some(content),
+        process_tracker,
+    }
+    .schedule();
 
     Ok(())
 }
@@ -860,6 +869,17 @@ async fn trigger_webhook_to_merchant(
                     .change_context(
                         errors::WebhooksFlowError::OutgoingWebhookProcessTrackerTaskUpdateFailed,
                     )?;
+                update_event_delivery_status(
+                    &state,
+                    merchant_key_store,
+                    &business_profile.merchant_id,
+                    event
+                        .initial_attempt_id
+                        .as_deref()
+                        .unwrap_or(&event.event_id),
+                    enums::WebhookDeliveryStatus::Failed,
+                )
+                .await?;
             }
             Err(error)
         }
@@ -867,11 +887,21 @@ async fn trigger_webhook_to_merchant(
     }?;
 
     let event_id = event.event_id;
+    let initial_attempt_id = event.initial_attempt_id.unwrap_or_else(|| event_id.clone());
 
+    // The timestamped signature is generated for each delivery attempt, so that the timestamp
+    // reflects the time the webhook was sent at
+    let signature_header = signing::get_webhook_signature_header(
+        &*state.store,
+        &business_profile,
+        request_content.body.peek(),
+    )
+    .await?;
     let headers = request_content
         .headers
         .into_iter()
         .map(|(name, value)| (name, value.into_masked()))
+        .chain(signature_header)
         .collect();
     let request = services::RequestBuilder::new()
         .method(services::Method::Post)
@@ -883,10 +913,28 @@ async fn trigger_webhook_to_merchant(
         ))
         .build();
 
+    let delivery_start = Instant::now();
     let response = state
         .api_client
         .send_request(&state, request, Some(OUTGOING_WEBHOOK_TIMEOUT_SECS), false)
         .await;
+    metrics::WEBHOOK_OUTGOING_DELIVERY_LATENCY.record(
+        &metrics::CONTEXT,
+        delivery_start.elapsed().as_secs_f64(),
+        &[metrics::KeyValue::new(
+            MERCHANT_ID,
+            business_profile.merchant_id.clone(),
+        )],
+    );
+    endpoint_health::record_delivery_outcome(
+        &state,
+        &business_profile.profile_id,
+        response
+            .as_ref()
+            .ok()
+            .map(|response| response.status().as_u16()),
+    )
+    .await;
 
     metrics::WEBHOOK_OUTGOING_COUNT.add(
         &metrics::CONTEXT,
@@ -986,9 +1034,18 @@ async fn trigger_webhook_to_merchant(
             metrics::WEBHOOK_OUTGOING_RECEIVED_COUNT.add(
                 &metrics::CONTEXT,
                 1,
-                &[metrics::KeyValue::new(MERCHANT_ID, merchant_id)],
+                &[metrics::KeyValue::new(MERCHANT_ID, merchant_id.clone())],
             );
 
+            update_event_delivery_status(
+                &state,
+                merchant_key_store,
+                &merchant_id,
+                &initial_attempt_id,
+                enums::WebhookDeliveryStatus::Delivered,
+            )
+            .await?;
+
             match process_tracker {
                 Some(process_tracker) => state
                     .store
@@ -1017,7 +1074,20 @@ async fn trigger_webhook_to_merchant(
 
     match delivery_attempt {
         enums::WebhookDeliveryAttempt::InitialAttempt => match response {
-            Err(client_error) => api_client_error_handler(client_error, delivery_attempt),
+            Err(client_error) => {
+                api_client_error_handler(client_error, delivery_attempt);
+                // The delivery has failed if no automatic retries could be scheduled
+                if process_tracker.is_none() {
+                    update_event_delivery_status(
+                        &state,
+                        merchant_key_store,
+                        &business_profile.merchant_id,
+                        &initial_attempt_id,
+                        enums::WebhookDeliveryStatus::Failed,
+                    )
+                    .await?;
+                }
+            }
             Ok(response) => {
                 let status_code = response.status();
                 let _updated_event = update_event_in_storage(
@@ -1039,11 +1109,21 @@ async fn trigger_webhook_to_merchant(
                     .await?;
                 } else {
                     error_response_handler(
-                        business_profile.merchant_id,
+                        business_profile.merchant_id.clone(),
                         delivery_attempt,
                         status_code.as_u16(),
                         "Ignoring error when sending webhook to merchant",
                     );
+                    if process_tracker.is_none() {
+                        update_event_delivery_status(
+                            &state,
+                            merchant_key_store,
+                            &business_profile.merchant_id,
+                            &initial_attempt_id,
+                            enums::WebhookDeliveryStatus::Failed,
+                        )
+                        .await?;
+                    }
                 }
             }
         },
@@ -1056,15 +1136,14 @@ async fn trigger_webhook_to_merchant(
                 Err(client_error) => {
                     api_client_error_handler(client_error, delivery_attempt);
                     // Schedule a retry attempt for webhook delivery
-                    outgoing_webhook_retry::retry_webhook_delivery_task(
-                        &*state.store,
+                    retry_webhook_delivery(
+                        &state,
+                        merchant_key_store,
                         &business_profile.merchant_id,
+                        &initial_attempt_id,
                         process_tracker,
                     )
-                    .await
-                    .change_context(
-                        errors::WebhooksFlowError::OutgoingWebhookRetrySchedulingFailed,
-                    )?;
+                    .await?;
                 }
                 Ok(response) => {
                     let status_code = response.status();
@@ -1093,28 +1172,107 @@ async fn trigger_webhook_to_merchant(
                             "An error occurred when sending webhook to merchant",
                         );
                         // Schedule a retry attempt for webhook delivery
-                        outgoing_webhook_retry::retry_webhook_delivery_task(
-                            &*state.store,
+                        retry_webhook_delivery(
+                            &state,
+                            merchant_key_store,
                             &business_profile.merchant_id,
+                            &initial_attempt_id,
                             process_tracker,
                         )
-                        .await
-                        .change_context(
-                            errors::WebhooksFlowError::OutgoingWebhookRetrySchedulingFailed,
-                        )?;
+                        .await?;
                     }
                 }
             }
         }
-        enums::WebhookDeliveryAttempt::ManualRetry => {
-            // Will be updated when manual retry is implemented
-            Err(errors::WebhooksFlowError::NotReceivedByMerchant).into_report()?
-        }
+        enums::WebhookDeliveryAttempt::ManualRetry => match response {
+            Err(client_error) => api_client_error_handler(client_error, delivery_attempt),
+            Ok(response) => {
+                let status_code = response.status();
+                let _updated_event = update_event_in_storage(
+                    state.clone(),
+                    merchant_key_store.clone(),
+                    business_profile.merchant_id.clone(),
+                    event_id.clone(),
+                    response,
+                )
+                .await?;
+
+                if status_code.is_success() {
+                    success_response_handler(
+                        state.clone(),
+                        business_profile.merchant_id,
+                        process_tracker,
+                        "COMPLETED_BY_PT",
+                    )
+                    .await?;
+                } else {
+                    error_response_handler(
+                        business_profile.merchant_id,
+                        delivery_attempt,
+                        status_code.as_u16(),
+                        "Ignoring error when manually retrying webhook delivery to merchant",
+                    );
+                }
+            }
+        },
     }
 
     Ok(())
 }
 
+/// Schedules the next automatic retry of the webhook delivery, marking the delivery of the event
+/// as failed once the retries are exhausted
+async fn retry_webhook_delivery(
+    state: &AppState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    merchant_id: &str,
+    initial_attempt_id: &str,
+    process_tracker: storage::ProcessTracker,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    let is_retry_scheduled = outgoing_webhook_retry::retry_webhook_delivery_task(
+        &*state.store,
+        merchant_id,
+        process_tracker,
+    )
+    .await
+    .change_context(errors::WebhooksFlowError::OutgoingWebhookRetrySchedulingFailed)?;
+
+    if !is_retry_scheduled {
+        update_event_delivery_status(
+            state,
+            merchant_key_store,
+            merchant_id,
+            initial_attempt_id,
+            enums::WebhookDeliveryStatus::Failed,
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+/// Updates the status of the delivery of the event across all of its delivery attempts, which is
+/// tracked on its initial delivery attempt
+async fn update_event_delivery_status(
+    state: &AppState,
+    merchant_key_store: &domain::MerchantKeyStore,
+    merchant_id: &str,
+    initial_attempt_id: &str,
+    delivery_status: enums::WebhookDeliveryStatus,
+) -> CustomResult<(), errors::WebhooksFlowError> {
+    state
+        .store
+        .update_event_by_merchant_id_event_id(
+            merchant_id,
+            initial_attempt_id,
+            domain::EventUpdate::UpdateDeliveryStatus { delivery_status },
+            merchant_key_store,
+        )
+        .await
+        .change_context(errors::WebhooksFlowError::WebhookEventUpdationFailed)
+        .attach_printable("Failed to update the delivery status of the event")
+        .map(|_| ())
+}
+
 fn raise_webhooks_analytics_event(
     state: AppState,
     trigger_webhook_result: CustomResult<(), errors::WebhooksFlowError>,
@@ -1214,6 +1372,52 @@ pub async fn webhooks_wrapper<W: types::OutgoingWebhookType, Ctx: PaymentMethodR
 }
 
 #[instrument(skip_all)]
+async fn verify_webhook_source(
+    state: &AppState,
+    connector: &(dyn api::Connector + Sync),
+    is_source_verification_call: bool,
+    merchant_account: &domain::MerchantAccount,
+    merchant_connector_account: domain::MerchantConnectorAccount,
+    connector_name: &str,
+    request_details: &api::IncomingWebhookRequestDetails<'_>,
+) -> errors::RouterResult<bool> {
+    let verification_result = if is_source_verification_call {
+        connector
+            .verify_webhook_source_verification_call(
+                state,
+                merchant_account,
+                merchant_connector_account,
+                connector_name,
+                request_details,
+            )
+            .await
+    } else {
+        connector
+            .verify_webhook_source(
+                request_details,
+                merchant_account,
+                merchant_connector_account,
+                connector_name,
+            )
+            .await
+    };
+
+    verification_result
+        .or_else(|error| match error.current_context() {
+            errors::ConnectorError::WebhookSourceVerificationFailed => {
+                logger::error!(?error, "Source Verification Failed");
+                Ok(false)
+            }
+            _ => Err(error),
+        })
+        .switch()
+        .attach_printable("There was an issue in incoming webhook source verification")
+}
+
+#[instrument(
+    skip_all,
+    fields(hyperswitch.merchant_id_hash, hyperswitch.connector)
+)]
 pub async fn webhooks_core<W: types::OutgoingWebhookType, Ctx: PaymentMethodRetrieve>(
     state: AppState,
     req: &actix_web::HttpRequest,
@@ -1254,6 +1458,10 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType, Ctx: PaymentMethodRetr
     .await?;
 
     let connector_name = connector.connector_name.to_string();
+    router_env::record_span_attributes!(
+        merchant_id = &merchant_account.merchant_id,
+        connector = &connector_name,
+    );
 
     let connector = connector.connector;
 
@@ -1364,61 +1572,82 @@ pub async fn webhooks_core<W: types::OutgoingWebhookType, Ctx: PaymentMethodRetr
             }
         };
 
-        let source_verified = if connectors_with_source_verification_call
+        let is_source_verification_call = connectors_with_source_verification_call
             .connectors_with_webhook_source_verification_call
-            .contains(&connector_enum)
-        {
-            connector
-                .verify_webhook_source_verification_call(
+            .contains(&connector_enum);
+
+        let mut source_verified = verify_webhook_source(
+            &state,
+            *connector,
+            is_source_verification_call,
+            &merchant_account,
+            merchant_connector_account.clone(),
+            &connector_name,
+            &request_details,
+        )
+        .await?;
+
+        // During a webhook secret rotation, the connector may still sign webhooks with the
+        // previous secret, which is accepted until its overlap window expires
+        if !source_verified {
+            if let Some(previous_secret_mca) =
+                utils::get_mca_with_previous_webhook_secrets(&merchant_connector_account)
+            {
+                source_verified = verify_webhook_source(
                     &state,
+                    *connector,
+                    is_source_verification_call,
                     &merchant_account,
-                    merchant_connector_account.clone(),
+                    previous_secret_mca,
                     &connector_name,
                     &request_details,
                 )
-                .await
-                .or_else(|error| match error.current_context() {
-                    errors::ConnectorError::WebhookSourceVerificationFailed => {
-                        logger::error!(?error, "Source Verification Failed");
-                        Ok(false)
-                    }
-                    _ => Err(error),
-                })
-                .switch()
-                .attach_printable("There was an issue in incoming webhook source verification")?
-        } else {
-            connector
-                .verify_webhook_source(
-                    &request_details,
-                    &merchant_account,
-                    merchant_connector_account.clone(),
-                    connector_name.as_str(),
-                )
-                .await
-                .or_else(|error| match error.current_context() {
-                    errors::ConnectorError::WebhookSourceVerificationFailed => {
-                        logger::error!(?error, "Source Verification Failed");
-                        Ok(false)
-                    }
-                    _ => Err(error),
-                })
-                .switch()
-                .attach_printable("There was an issue in incoming webhook source verification")?
-        };
+                .await?;
+                logger::info!(
+                    verified_with_previous_webhook_secret=?source_verified
+                );
+            }
+        }
 
         if source_verified {
             metrics::WEBHOOK_SOURCE_VERIFIED_COUNT.add(
                 &metrics::CONTEXT,
                 1,
-                &[metrics::KeyValue::new(
-                    MERCHANT_ID,
-                    merchant_account.merchant_id.clone(),
-                )],
+                &[
+                    metrics::KeyValue::new(MERCHANT_ID, merchant_account.merchant_id.clone()),
+                    metrics::KeyValue::new("connector", connector_name.clone()),
+                ],
+            );
+        } else if connector.is_webhook_source_verification_supported() {
+            let is_verification_mandatory = connector.is_webhook_source_verification_mandatory()
+                || state
+                    .conf
+                    .webhook_source_verification
+                    .is_mandatory(&connector_enum);
+
+            metrics::WEBHOOK_SOURCE_VERIFICATION_FAILURE_COUNT.add(
+                &metrics::CONTEXT,
+                1,
+                &[
+                    metrics::KeyValue::new(MERCHANT_ID, merchant_account.merchant_id.clone()),
+                    metrics::KeyValue::new("connector", connector_name.clone()),
+                    metrics::KeyValue::new("rejected", is_verification_mandatory),
+                ],
+            );
+
+            if is_verification_mandatory {
+                // Fail the webhook, so that the merchant can retrigger it after updating the
+                // webhook secret of the merchant connector account
+                logger::error!(
+                    connector = %connector_name,
+                    "Rejecting incoming webhook as its source could not be verified"
+                );
+                return Err(errors::ApiErrorResponse::WebhookAuthenticationFailed.into());
+            }
+            logger::warn!(
+                connector = %connector_name,
+                "Source of the incoming webhook could not be verified, it is processed by syncing with the connector"
             );
-        } else if connector.is_webhook_source_verification_mandatory() {
-            // if webhook consumption is mandatory for connector, fail webhook
-            // so that merchant can retrigger it after updating merchant_secret
-            return Err(errors::ApiErrorResponse::WebhookAuthenticationFailed.into());
         }
 
         logger::info!(source_verified=?source_verified);
@@ -0,0 +1,343 @@
+//! Delivery of outgoing webhooks outside of the request path.
+//!
+//! Deliveries are queued per merchant and picked up by a dispatcher task in a round robin across
+//! merchants, so that a merchant with a large number of events does not delay the delivery of
+//! other merchants' events. The dispatcher runs at most the configured number of deliveries at
+//! once, and at most the configured number of deliveries of a single merchant at once. The events
+//! table along with the process tracker acts as the outbox of the worker: deliveries which cannot
+//! be queued are left to the automatic retries scheduled for the event.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    time::Instant,
+};
+
+use api_models::webhook_events::OutgoingWebhookRequestContent;
+use router_env::tracing::{self, Instrument};
+use tokio::sync::{Notify, Semaphore};
+
+use crate::{
+    configs::settings,
+    core::metrics,
+    logger,
+    routes::AppState,
+    types::{
+        api, domain,
+        storage::{self, enums},
+    },
+};
+
+/// Delivery of an outgoing webhook to the merchant
+pub struct WebhookDeliveryJob {
+    pub state: AppState,
+    pub business_profile: diesel_models::business_profile::BusinessProfile,
+    pub merchant_key_store: domain::MerchantKeyStore,
+    pub event: domain::Event,
+    pub request_content: OutgoingWebhookRequestContent,
+    pub delivery_attempt: enums::WebhookDeliveryAttempt,
+    pub content: Option<api::OutgoingWebhookContent>,
+    pub process_tracker: Option<storage::ProcessTracker>,
+}
+
+impl WebhookDeliveryJob {
+    async fn deliver(self) {
+        super::trigger_webhook_and_raise_event(
+            self.state,
+            self.business_profile,
+            &self.merchant_key_store,
+            self.event,
+            self.request_content,
+            self.delivery_attempt,
+            self.content,
+            self.process_tracker,
+        )
+        .await;
+    }
+
+    /// Queues the delivery in the worker pool of the application
+    pub fn schedule(self) {
+        let worker = self.state.webhook_delivery_worker.clone();
+        worker.enqueue(self);
+    }
+}
+
+struct QueuedDelivery<T> {
+    job: T,
+    queued_at: Instant,
+    span: tracing::Span,
+}
+
+/// Deliveries waiting to be picked up, grouped by merchant
+struct DeliveryQueues<T> {
+    /// Merchants having pending deliveries, in the order they are picked up in
+    merchants: VecDeque<String>,
+    pending: HashMap<String, VecDeque<QueuedDelivery<T>>>,
+    in_flight: HashMap<String, usize>,
+    queued_count: usize,
+}
+
+impl<T> DeliveryQueues<T> {
+    fn new() -> Self {
+        Self {
+            merchants: VecDeque::new(),
+            pending: HashMap::new(),
+            in_flight: HashMap::new(),
+            queued_count: 0,
+        }
+    }
+
+    /// Queues the delivery, the delivery is returned back if the queues are full
+    fn push(
+        &mut self,
+        merchant_id: String,
+        delivery: QueuedDelivery<T>,
+        queue_size: usize,
+    ) -> Result<(), QueuedDelivery<T>> {
+        if self.queued_count >= queue_size {
+            return Err(delivery);
+        }
+
+        if !self.pending.contains_key(&merchant_id) {
+            self.merchants.push_back(merchant_id.clone());
+        }
+        self.pending
+            .entry(merchant_id)
+            .or_default()
+            .push_back(delivery);
+        self.queued_count = self.queued_count.saturating_add(1);
+        Ok(())
+    }
+
+    /// Picks the next delivery of the first merchant in the rotation which is below its limit of
+    /// deliveries in flight
+    fn pop(&mut self, max_in_flight_per_merchant: usize) -> Option<(String, QueuedDelivery<T>)> {
+        for _ in 0..self.merchants.len() {
+            let merchant_id = self.merchants.pop_front()?;
+            let in_flight = self.in_flight.get(&merchant_id).copied().unwrap_or(0);
+            if in_flight >= max_in_flight_per_merchant {
+                self.merchants.push_back(merchant_id);
+                continue;
+            }
+
+            let Some(queue) = self.pending.get_mut(&merchant_id) else {
+                continue;
+            };
+            let Some(delivery) = queue.pop_front() else {
+                self.pending.remove(&merchant_id);
+                continue;
+            };
+            if queue.is_empty() {
+                self.pending.remove(&merchant_id);
+            } else {
+                self.merchants.push_back(merchant_id.clone());
+            }
+
+            let in_flight = self.in_flight.entry(merchant_id.clone()).or_default();
+            *in_flight = in_flight.saturating_add(1);
+            self.queued_count = self.queued_count.saturating_sub(1);
+            return Some((merchant_id, delivery));
+        }
+        None
+    }
+
+    fn complete(&mut self, merchant_id: &str) {
+        if let Some(in_flight) = self.in_flight.get_mut(merchant_id) {
+            *in_flight = in_flight.saturating_sub(1);
+            if *in_flight == 0 {
+                self.in_flight.remove(merchant_id);
+            }
+        }
+    }
+}
+
+struct WorkerInner {
+    config: settings::WebhookDeliveryWorker,
+    queues: Mutex<DeliveryQueues<WebhookDeliveryJob>>,
+    notify: Notify,
+}
+
+impl WorkerInner {
+    fn lock_queues(&self) -> MutexGuard<'_, DeliveryQueues<WebhookDeliveryJob>> {
+        // The queues are updated without any fallible operations in between, so they are
+        // consistent even if the lock was poisoned
+        self.queues.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+/// Worker pool delivering the outgoing webhooks of the application
+#[derive(Clone)]
+pub struct WebhookDeliveryWorker {
+    inner: Arc<WorkerInner>,
+}
+
+impl WebhookDeliveryWorker {
+    /// Creates the worker pool and spawns its dispatcher task, this must be called within a tokio
+    /// runtime
+    pub fn start(config: &settings::WebhookDeliveryWorker) -> Self {
+        let inner = Arc::new(WorkerInner {
+            config: config.clone(),
+            queues: Mutex::new(DeliveryQueues::new()),
+            notify: Notify::new(),
+        });
+        tokio::spawn(dispatch(inner.clone()));
+        Self { inner }
+    }
+
+    /// Queues the delivery of the webhook. When the queue is full the delivery is left to the
+    /// automatic retries of the event, or run right away if it won't be retried automatically.
+    pub fn enqueue(&self, job: WebhookDeliveryJob) {
+        let merchant_id = job.business_profile.merchant_id.clone();
+        let delivery = QueuedDelivery {
+            job,
+            queued_at: Instant::now(),
+            span: tracing::Span::current(),
+        };
+        let push_result = self.inner.lock_queues().push(
+            merchant_id.clone(),
+            delivery,
+            self.inner.config.queue_size,
+        );
+
+        match push_result {
+            Ok(()) => self.inner.notify.notify_one(),
+            Err(delivery) => {
+                metrics::WEBHOOK_OUTGOING_DELIVERY_QUEUE_FULL_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &[metrics::KeyValue::new("merchant_id", merchant_id)],
+                );
+                let event_id = delivery.job.event.event_id.clone();
+                if delivery.job.process_tracker.is_some() {
+                    logger::warn!(
+                        %event_id,
+                        "Webhook delivery queue is full, leaving the delivery to automatic retries"
+                    );
+                } else {
+                    logger::warn!(
+                        %event_id,
+                        "Webhook delivery queue is full, delivering outside of the worker pool"
+                    );
+                    tokio::spawn(delivery.job.deliver().instrument(delivery.span));
+                }
+            }
+        }
+    }
+}
+
+async fn dispatch(worker: Arc<WorkerInner>) {
+    let deliveries = Arc::new(Semaphore::new(worker.config.max_concurrent_deliveries));
+    loop {
+        // The semaphore is never closed
+        let Ok(permit) = deliveries.clone().acquire_owned().await else {
+            break;
+        };
+
+        let (merchant_id, delivery) = loop {
+            let next = worker
+                .lock_queues()
+                .pop(worker.config.max_concurrent_deliveries_per_merchant);
+            if let Some(next) = next {
+                break next;
+            }
+            worker.notify.notified().await;
+        };
+
+        metrics::WEBHOOK_OUTGOING_DELIVERY_QUEUE_TIME.record(
+            &metrics::CONTEXT,
+            delivery.queued_at.elapsed().as_secs_f64(),
+            &[],
+        );
+
+        let worker = worker.clone();
+        tokio::spawn(
+            async move {
+                delivery.job.deliver().await;
+                drop(permit);
+                worker.lock_queues().complete(&merchant_id);
+                worker.notify.notify_one();
+            }
+            .instrument(delivery.span),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    fn queue_delivery(queues: &mut DeliveryQueues<u32>, merchant_id: &str, job: u32) {
+        let delivery = QueuedDelivery {
+            job,
+            queued_at: Instant::now(),
+            span: tracing::Span::none(),
+        };
+        assert!(queues.push(merchant_id.to_string(), delivery, 10).is_ok());
+    }
+
+    fn pop_job(queues: &mut DeliveryQueues<u32>, max_in_flight: usize) -> Option<(String, u32)> {
+        queues
+            .pop(max_in_flight)
+            .map(|(merchant_id, delivery)| (merchant_id, delivery.job))
+    }
+
+    #[test]
+    fn test_deliveries_are_picked_in_round_robin_across_merchants() {
+        let mut queues = DeliveryQueues::new();
+        queue_delivery(&mut queues, "merchant_1", 1);
+        queue_delivery(&mut queues, "merchant_1", 2);
+        queue_delivery(&mut queues, "merchant_1", 3);
+        queue_delivery(&mut queues, "merchant_2", 4);
+
+        let order = std::iter::from_fn(|| pop_job(&mut queues, 10))
+            .map(|(_, job)| job)
+            .collect::<Vec<_>>();
+        assert_eq!(order, vec![1, 4, 2, 3]);
+    }
+
+    #[test]
+    fn test_merchant_at_limit_is_skipped_until_a_delivery_completes() {
+        let mut queues = DeliveryQueues::new();
+        queue_delivery(&mut queues, "merchant_1", 1);
+        queue_delivery(&mut queues, "merchant_1", 2);
+        queue_delivery(&mut queues, "merchant_2", 3);
+
+        assert_eq!(pop_job(&mut queues, 1), Some(("merchant_1".to_string(), 1)));
+        assert_eq!(pop_job(&mut queues, 1), Some(("merchant_2".to_string(), 3)));
+        assert_eq!(pop_job(&mut queues, 1), None);
+
+        queues.complete("merchant_1");
+        assert_eq!(pop_job(&mut queues, 1), Some(("merchant_1".to_string(), 2)));
+    }
+
+    #[test]
+    fn test_delivery_is_rejected_when_queues_are_full() {
+        let mut queues = DeliveryQueues::new();
+        for job in 0..2 {
+            let delivery = QueuedDelivery {
+                job,
+                queued_at: Instant::now(),
+                span: tracing::Span::none(),
+            };
+            assert!(queues.push("merchant_1".to_string(), delivery, 2).is_ok());
+        }
+
+        let delivery = QueuedDelivery {
+            job: 2,
+            queued_at: Instant::now(),
+            span: tracing::Span::none(),
+        };
+        let rejected = queues.push("merchant_2".to_string(), delivery, 2);
+        assert_eq!(rejected.err().map(|delivery| delivery.job), Some(2));
+
+        pop_job(&mut queues, 10).unwrap();
+        let delivery = QueuedDelivery {
+            job: 3,
+            queued_at: Instant::now(),
+            span: tracing::Span::none(),
+        };
+        assert!(queues.push("merchant_2".to_string(), delivery, 2).is_ok());
+    }
+}
@@ -0,0 +1,166 @@
+//! Health of the webhook endpoints of business profiles.
+//!
+//! The outcome of every delivery to the webhook endpoint of a business profile is recorded in
+//! Redis. The endpoint is considered unhealthy once the configured number of consecutive
+//! deliveries to it have failed, and healthy again as soon as a delivery succeeds.
+
+use api_models::webhook_events::{WebhookEndpointHealthResponse, WebhookEndpointHealthStatus};
+use error_stack::ResultExt;
+use redis_interface::RedisConnectionPool;
+
+use crate::{
+    core::errors::{self, CustomResult, RouterResult},
+    logger,
+    routes::AppState,
+};
+
+/// Duration for which the health of an endpoint is retained after the last delivery to it
+const ENDPOINT_HEALTH_TTL_IN_SECS: i64 = 7 * 24 * 60 * 60;
+
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+struct EndpointHealthState {
+    consecutive_failures: u32,
+    last_response_status_code: Option<u16>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    last_delivered_at: Option<time::PrimitiveDateTime>,
+    #[serde(default, with = "common_utils::custom_serde::iso8601::option")]
+    last_failed_at: Option<time::PrimitiveDateTime>,
+}
+
+fn get_endpoint_health_key(profile_id: &str) -> String {
+    format!("webhook_endpoint_health_{profile_id}")
+}
+
+fn get_health_status(
+    health_state: Option<&EndpointHealthState>,
+    unhealthy_threshold: u32,
+) -> WebhookEndpointHealthStatus {
+    match health_state {
+        None => WebhookEndpointHealthStatus::Unknown,
+        Some(health_state) if health_state.consecutive_failures == 0 => {
+            WebhookEndpointHealthStatus::Healthy
+        }
+        Some(health_state) if health_state.consecutive_failures >= unhealthy_threshold => {
+            WebhookEndpointHealthStatus::Unhealthy
+        }
+        Some(_) => WebhookEndpointHealthStatus::Degraded,
+    }
+}
+
+/// Records the outcome of a delivery to the webhook endpoint of the business profile. The status
+/// code is absent when the endpoint could not be reached.
+pub async fn record_delivery_outcome(state: &AppState, profile_id: &str, status_code: Option<u16>) {
+    if let Err(error) = update_endpoint_health(state, profile_id, status_code).await {
+        logger::error!(?error, %profile_id, "Failed to record the health of the webhook endpoint");
+    }
+}
+
+async fn update_endpoint_health(
+    state: &AppState,
+    profile_id: &str,
+    status_code: Option<u16>,
+) -> CustomResult<(), errors::RedisError> {
+    let redis_conn = state.store.get_redis_conn()?;
+    let key = get_endpoint_health_key(profile_id);
+    let mut health_state = get_health_state(&redis_conn, &key)
+        .await?
+        .unwrap_or_default();
+
+    let now = common_utils::date_time::now();
+    health_state.last_response_status_code = status_code;
+    if status_code.is_some_and(|status_code| (200..300).contains(&status_code)) {
+        health_state.consecutive_failures = 0;
+        health_state.last_delivered_at = Some(now);
+    } else {
+        health_state.consecutive_failures = health_state.consecutive_failures.saturating_add(1);
+        health_state.last_failed_at = Some(now);
+    }
+
+    redis_conn
+        .serialize_and_set_key_with_expiry(&key, health_state, ENDPOINT_HEALTH_TTL_IN_SECS)
+        .await
+}
+
+async fn get_health_state(
+    redis_conn: &RedisConnectionPool,
+    key: &str,
+) -> CustomResult<Option<EndpointHealthState>, errors::RedisError> {
+    match redis_conn
+        .get_and_deserialize_key::<EndpointHealthState>(key, "EndpointHealthState")
+        .await
+    {
+        Ok(health_state) => Ok(Some(health_state)),
+        Err(err) if matches!(err.current_context(), errors::RedisError::NotFound) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+/// Provides the health of the webhook endpoint of the business profile
+pub async fn get_endpoint_health(
+    state: &AppState,
+    profile_id: String,
+) -> RouterResult<WebhookEndpointHealthResponse> {
+    let redis_conn = state
+        .store
+        .get_redis_conn()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to get redis connection")?;
+    let health_state = get_health_state(&redis_conn, &get_endpoint_health_key(&profile_id))
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to fetch the health of the webhook endpoint")?;
+
+    Ok(WebhookEndpointHealthResponse {
+        status: get_health_status(
+            health_state.as_ref(),
+            state
+                .conf
+                .webhooks
+                .delivery_worker
+                .endpoint_unhealthy_threshold,
+        ),
+        profile_id,
+        consecutive_failures: health_state
+            .as_ref()
+            .map_or(0, |health_state| health_state.consecutive_failures),
+        last_response_status_code: health_state
+            .as_ref()
+            .and_then(|health_state| health_state.last_response_status_code),
+        last_delivered_at: health_state
+            .as_ref()
+            .and_then(|health_state| health_state.last_delivered_at),
+        last_failed_at: health_state.and_then(|health_state| health_state.last_failed_at),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn health_state_with_failures(consecutive_failures: u32) -> EndpointHealthState {
+        EndpointHealthState {
+            consecutive_failures,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_endpoint_health_status() {
+        assert_eq!(
+            get_health_status(None, 3),
+            WebhookEndpointHealthStatus::Unknown
+        );
+        assert_eq!(
+            get_health_status(Some(&health_state_with_failures(0)), 3),
+            WebhookEndpointHealthStatus::Healthy
+        );
+        assert_eq!(
+            get_health_status(Some(&health_state_with_failures(2)), 3),
+            WebhookEndpointHealthStatus::Degraded
+        );
+        assert_eq!(
+            get_health_status(Some(&health_state_with_failures(3)), 3),
+            WebhookEndpointHealthStatus::Unhealthy
+        );
+    }
+}
@@ -0,0 +1,391 @@
+//! Operational alerts posted to notification channels.
+//!
+//! Merchants configure Slack and Microsoft Teams incoming webhooks as notification channels in the
+//! webhook details of a business profile, along with the alerts each channel is subscribed to.
+//! Alerts are posted in the background and failures are only logged, as they must not affect the
+//! flow raising them.
+
+use api_models::{admin, enums as api_enums};
+use common_utils::{ext_traits::ValueExt, request::RequestContent};
+use error_stack::{IntoReport, ResultExt};
+use masking::PeekInterface;
+use router_env::tracing::Instrument;
+
+use crate::{
+    core::{
+        errors::{self, CustomResult, RouterResult},
+        metrics,
+        payments::PaymentData,
+    },
+    logger,
+    routes::AppState,
+    services,
+    types::storage::{self, business_profile::BusinessProfile},
+};
+
+const NOTIFICATION_CHANNEL_TIMEOUT_SECS: u64 = 5;
+
+/// An alert to be posted to the notification channels subscribed to it
+#[derive(Debug, Clone)]
+pub struct OperationalAlertContent {
+    pub alert: api_enums::OperationalAlert,
+    pub merchant_id: String,
+    pub title: String,
+    pub facts: Vec<(&'static str, String)>,
+    /// Amount the alert is about, compared against the threshold of the channel for high value
+    /// payment failures
+    pub amount: Option<i64>,
+}
+
+/// Alerts the failure of a payment, to the channels whose high value threshold its amount crosses
+pub async fn send_payment_failed_alert<F: Clone>(state: &AppState, payment_data: &PaymentData<F>) {
+    let Some(profile_id) = payment_data.payment_intent.profile_id.as_deref() else {
+        return;
+    };
+
+    let business_profile = match state
+        .store
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+    {
+        Ok(business_profile) => business_profile,
+        Err(error) => {
+            logger::error!(
+                ?error,
+                "Failed to fetch business profile for operational alert"
+            );
+            return;
+        }
+    };
+
+    let payment_attempt = &payment_data.payment_attempt;
+    let alert = OperationalAlertContent {
+        alert: api_enums::OperationalAlert::HighValuePaymentFailed,
+        merchant_id: payment_attempt.merchant_id.clone(),
+        title: "High value payment failed".to_string(),
+        facts: vec![
+            ("Payment ID", payment_attempt.payment_id.clone()),
+            ("Attempt ID", payment_attempt.attempt_id.clone()),
+            (
+                "Amount",
+                format!("{} {}", payment_attempt.amount, payment_data.currency),
+            ),
+            (
+                "Connector",
+                payment_attempt.connector.clone().unwrap_or_default(),
+            ),
+            (
+                "Error",
+                payment_attempt
+                    .error_message
+                    .clone()
+                    .or_else(|| payment_attempt.error_code.clone())
+                    .unwrap_or_default(),
+            ),
+        ],
+        amount: Some(payment_attempt.amount),
+    };
+
+    send_operational_alert(state, &business_profile, alert);
+}
+
+pub fn get_dispute_received_alert(dispute: &storage::Dispute) -> OperationalAlertContent {
+    OperationalAlertContent {
+        alert: api_enums::OperationalAlert::DisputeReceived,
+        merchant_id: dispute.merchant_id.clone(),
+        title: "Dispute received".to_string(),
+        facts: vec![
+            ("Dispute ID", dispute.dispute_id.clone()),
+            ("Payment ID", dispute.payment_id.clone()),
+            (
+                "Amount",
+                format!("{} {}", dispute.dispute_amount, dispute.currency),
+            ),
+            ("Connector", dispute.connector.clone()),
+            ("Stage", dispute.dispute_stage.to_string()),
+            (
+                "Reason",
+                dispute.connector_reason.clone().unwrap_or_default(),
+            ),
+            (
+                "Challenge required by",
+                dispute
+                    .challenge_required_by
+                    .map(|date| date.to_string())
+                    .unwrap_or_default(),
+            ),
+        ],
+        amount: Some(dispute.dispute_amount),
+    }
+}
+
+pub fn get_connector_circuit_opened_alert(
+    merchant_id: &str,
+    health: &admin::ConnectorHealthResponse,
+) -> OperationalAlertContent {
+    OperationalAlertContent {
+        alert: api_enums::OperationalAlert::ConnectorCircuitOpened,
+        merchant_id: merchant_id.to_string(),
+        title: "Connector degraded and excluded from routing".to_string(),
+        facts: vec![
+            ("Connector", health.connector_name.clone()),
+            (
+                "Error rate",
+                health
+                    .error_rate_percentage
+                    .map(|error_rate| format!("{error_rate}%"))
+                    .unwrap_or_default(),
+            ),
+            (
+                "Errors",
+                format!("{} of {}", health.error_count, health.total_count),
+            ),
+            (
+                "Excluded until",
+                health
+                    .excluded_until
+                    .map(|date| date.to_string())
+                    .unwrap_or_default(),
+            ),
+        ],
+        amount: None,
+    }
+}
+
+/// Posts the alert to each notification channel of the business profile subscribed to it, in the
+/// background
+pub fn send_operational_alert(
+    state: &AppState,
+    business_profile: &BusinessProfile,
+    alert: OperationalAlertContent,
+) {
+    let channels = match get_notification_channels(business_profile) {
+        Ok(channels) => channels
+            .into_iter()
+            .filter(|channel| is_channel_subscribed(channel, &alert))
+            .collect::<Vec<_>>(),
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch notification channels");
+            return;
+        }
+    };
+
+    if channels.is_empty() {
+        return;
+    }
+
+    let state = state.clone();
+    // Using a tokio spawn here and not arbiter because not all callers of this function may have
+    // an actix arbiter
+    tokio::spawn(
+        async move {
+            for channel in channels {
+                let attributes = [
+                    metrics::KeyValue::new("channel_type", channel.channel_type.to_string()),
+                    metrics::KeyValue::new("alert", alert.alert.to_string()),
+                ];
+                match post_to_channel(&state, &channel, &alert).await {
+                    Ok(()) => {
+                        metrics::OPERATIONAL_ALERT_SENT_COUNT.add(
+                            &metrics::CONTEXT,
+                            1,
+                            &attributes,
+                        );
+                    }
+                    Err(error) => {
+                        logger::error!(
+                            ?error,
+                            channel_type = %channel.channel_type,
+                            alert = %alert.alert,
+                            "Failed to post operational alert"
+                        );
+                        metrics::OPERATIONAL_ALERT_FAILURE_COUNT.add(
+                            &metrics::CONTEXT,
+                            1,
+                            &attributes,
+                        );
+                    }
+                }
+            }
+        }
+        .in_current_span(),
+    );
+}
+
+/// Validates the notification channels configured in the webhook details
+pub fn validate_notification_channels(webhook_details: &admin::WebhookDetails) -> RouterResult<()> {
+    for channel in webhook_details.notification_channels.iter().flatten() {
+        let webhook_url = url::Url::parse(channel.webhook_url.peek())
+            .into_report()
+            .change_context(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "webhook_url of the {} notification channel is not a valid URL",
+                    channel.channel_type
+                ),
+            })?;
+
+        if webhook_url.scheme() != "https" {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "webhook_url of the {} notification channel must be an https URL",
+                    channel.channel_type
+                ),
+            })?;
+        }
+
+        if channel
+            .alerts
+            .contains(&api_enums::OperationalAlert::HighValuePaymentFailed)
+            && channel.high_value_amount_threshold.is_none()
+        {
+            Err(errors::ApiErrorResponse::MissingRequiredField {
+                field_name: "notification_channels.high_value_amount_threshold",
+            })?;
+        }
+    }
+    Ok(())
+}
+
+fn get_notification_channels(
+    business_profile: &BusinessProfile,
+) -> CustomResult<Vec<admin::NotificationChannel>, errors::ApiErrorResponse> {
+    let Some(webhook_details) = business_profile.webhook_details.clone() else {
+        return Ok(Vec::new());
+    };
+
+    let webhook_details: admin::WebhookDetails = webhook_details
+        .parse_value("WebhookDetails")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse webhook details of the business profile")?;
+
+    Ok(webhook_details.notification_channels.unwrap_or_default())
+}
+
+fn is_channel_subscribed(
+    channel: &admin::NotificationChannel,
+    alert: &OperationalAlertContent,
+) -> bool {
+    channel.alerts.contains(&alert.alert)
+        && match alert.alert {
+            api_enums::OperationalAlert::HighValuePaymentFailed => channel
+                .high_value_amount_threshold
+                .zip(alert.amount)
+                .is_some_and(|(threshold, amount)| amount >= threshold),
+            api_enums::OperationalAlert::DisputeReceived
+            | api_enums::OperationalAlert::ConnectorCircuitOpened => true,
+        }
+}
+
+async fn post_to_channel(
+    state: &AppState,
+    channel: &admin::NotificationChannel,
+    alert: &OperationalAlertContent,
+) -> CustomResult<(), errors::ApiClientError> {
+    let body = match channel.channel_type {
+        api_enums::NotificationChannelType::Slack => get_slack_message(alert),
+        api_enums::NotificationChannelType::MsTeams => get_ms_teams_message(alert),
+    };
+
+    let request = services::RequestBuilder::new()
+        .method(services::Method::Post)
+        .url(channel.webhook_url.peek())
+        .attach_default_headers()
+        .set_body(RequestContent::Json(Box::new(body)))
+        .build();
+
+    let response =
+        services::send_request(state, request, Some(NOTIFICATION_CHANNEL_TIMEOUT_SECS)).await?;
+
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        Err(errors::ApiClientError::UnexpectedServerResponse)
+            .into_report()
+            .attach_printable_lazy(|| {
+                format!(
+                    "Notification channel responded with status code {}",
+                    response.status()
+                )
+            })
+    }
+}
+
+fn get_slack_message(alert: &OperationalAlertContent) -> serde_json::Value {
+    let facts = alert
+        .facts
+        .iter()
+        .map(|(name, value)| format!("*{name}:* {value}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    serde_json::json!({
+        "text": format!("{} for merchant {}", alert.title, alert.merchant_id),
+        "blocks": [
+            {
+                "type": "header",
+                "text": { "type": "plain_text", "text": alert.title }
+            },
+            {
+                "type": "section",
+                "text": {
+                    "type": "mrkdwn",
+                    "text": format!("*Merchant ID:* {}\n{facts}", alert.merchant_id)
+                }
+            }
+        ]
+    })
+}
+
+fn get_ms_teams_message(alert: &OperationalAlertContent) -> serde_json::Value {
+    let facts = std::iter::once(("Merchant ID", alert.merchant_id.as_str()))
+        .chain(
+            alert
+                .facts
+                .iter()
+                .map(|(name, value)| (*name, value.as_str())),
+        )
+        .map(|(name, value)| serde_json::json!({ "name": name, "value": value }))
+        .collect::<Vec<_>>();
+
+    serde_json::json!({
+        "@type": "MessageCard",
+        "@context": "https://schema.org/extensions",
+        "summary": alert.title,
+        "title": alert.title,
+        "sections": [{ "facts": facts }]
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use masking::Secret;
+
+    use super::*;
+
+    #[test]
+    fn test_high_value_payment_failed_subscription() {
+        let channel = admin::NotificationChannel {
+            channel_type: api_enums::NotificationChannelType::Slack,
+            webhook_url: Secret::new("https://hooks.slack.com/services/T000/B000/XXXX".to_string()),
+            alerts: vec![api_enums::OperationalAlert::HighValuePaymentFailed],
+            high_value_amount_threshold: Some(10000),
+        };
+        let alert = |amount| OperationalAlertContent {
+            alert: api_enums::OperationalAlert::HighValuePaymentFailed,
+            merchant_id: "merchant".to_string(),
+            title: "High value payment failed".to_string(),
+            facts: Vec::new(),
+            amount: Some(amount),
+        };
+
+        assert!(is_channel_subscribed(&channel, &alert(10000)));
+        assert!(!is_channel_subscribed(&channel, &alert(9999)));
+        assert!(!is_channel_subscribed(
+            &channel,
+            &OperationalAlertContent {
+                alert: api_enums::OperationalAlert::DisputeReceived,
+                ..alert(10000)
+            }
+        ));
+    }
+}
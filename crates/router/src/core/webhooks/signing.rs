@@ -0,0 +1,239 @@
+//! Timestamped signatures of outgoing webhooks.
+//!
+//! Outgoing webhooks are signed at the time of each delivery attempt with the webhook secret of the
+//! business profile, its `payment_response_hash_key`. The signature header carries the timestamp
+//! of the delivery attempt along with the HMAC-SHA256 of `{timestamp}.{payload}`, which allows
+//! merchants to reject replayed webhooks. Rotating the webhook secret retains the previous secret
+//! for an overlap window, during which webhooks carry signatures generated with both secrets.
+
+use api_models::admin as admin_types;
+use common_utils::{
+    crypto::{self, SignMessage},
+    date_time,
+    ext_traits::{Encode, StringExt},
+};
+use diesel_models::configs;
+use error_stack::{IntoReport, ResultExt};
+use router_env::{instrument, logger, tracing};
+use serde::{Deserialize, Serialize};
+use time::PrimitiveDateTime;
+
+use crate::{
+    consts,
+    core::errors::{self, CustomResult, RouterResponse, StorageErrorExt},
+    db::StorageInterface,
+    headers,
+    routes::AppState,
+    services::{api as service_api, request::Maskable},
+    types::{storage, transformers::ForeignTryFrom},
+    utils,
+};
+
+/// The previous webhook secret of a business profile, retained after rotation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct PreviousWebhookSecretConfig {
+    previous_secret: String,
+    #[serde(with = "common_utils::custom_serde::iso8601")]
+    expires_at: PrimitiveDateTime,
+}
+
+/// Provides the identifier of the config holding the previous webhook secret of the business
+/// profile
+#[inline(always)]
+fn get_previous_webhook_secret_config_key(profile_id: &str) -> String {
+    format!("previous_webhook_secret_{profile_id}")
+}
+
+/// Provides the previous webhook secret of the business profile, if it is still in its overlap
+/// window. Failures are only logged, as the webhook continues to be signed with the current secret.
+async fn get_previous_webhook_secret(
+    db: &dyn StorageInterface,
+    profile_id: &str,
+) -> Option<String> {
+    let config = match db
+        .find_config_by_key(&get_previous_webhook_secret_config_key(profile_id))
+        .await
+    {
+        Ok(config) => config,
+        Err(error) if error.current_context().is_db_not_found() => return None,
+        Err(error) => {
+            logger::error!(?error, "Failed to fetch the previous webhook secret");
+            return None;
+        }
+    };
+
+    config
+        .config
+        .parse_struct::<PreviousWebhookSecretConfig>("PreviousWebhookSecretConfig")
+        .map_err(|error| logger::error!(?error, "Failed to parse the previous webhook secret"))
+        .ok()
+        .filter(|config| date_time::now() < config.expires_at)
+        .map(|config| config.previous_secret)
+}
+
+/// Provides the timestamped signature header of the outgoing webhook payload, if the business
+/// profile has a webhook secret
+#[instrument(skip_all)]
+pub(crate) async fn get_webhook_signature_header(
+    db: &dyn StorageInterface,
+    business_profile: &storage::business_profile::BusinessProfile,
+    payload: &str,
+) -> CustomResult<Option<(String, Maskable<String>)>, errors::WebhooksFlowError> {
+    let Some(current_secret) = business_profile.payment_response_hash_key.as_deref() else {
+        return Ok(None);
+    };
+    let previous_secret = get_previous_webhook_secret(db, &business_profile.profile_id).await;
+
+    let signature = generate_webhook_signature(
+        date_time::now_unix_timestamp(),
+        payload,
+        std::iter::once(current_secret).chain(previous_secret.as_deref()),
+    )?;
+
+    Ok(Some((
+        headers::X_WEBHOOK_SIGNATURE_256.to_string(),
+        signature.into(),
+    )))
+}
+
+/// Generates the signature of the payload in the `t={timestamp},v1={signature}` format, with a
+/// `v1` entry for each of the secrets
+fn generate_webhook_signature<'a>(
+    timestamp: i64,
+    payload: &str,
+    secrets: impl Iterator<Item = &'a str>,
+) -> CustomResult<String, errors::WebhooksFlowError> {
+    let signature_payload = format!("{timestamp}.{payload}");
+
+    let signatures = secrets
+        .map(|secret| {
+            crypto::HmacSha256::sign_message(
+                &crypto::HmacSha256,
+                secret.as_bytes(),
+                signature_payload.as_bytes(),
+            )
+            .map(|signature| format!("v1={}", hex::encode(signature)))
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .change_context(errors::WebhooksFlowError::OutgoingWebhookSigningFailed)
+        .attach_printable("Failed to sign the outgoing webhook payload")?;
+
+    Ok(format!("t={timestamp},{}", signatures.join(",")))
+}
+
+#[instrument(skip(state))]
+pub async fn rotate_webhook_secret(
+    state: AppState,
+    merchant_id: &str,
+    profile_id: &str,
+    req: admin_types::BusinessProfileWebhookSecretRotateRequest,
+) -> RouterResponse<admin_types::BusinessProfileResponse> {
+    let overlap_window_in_secs = req
+        .overlap_window_in_secs
+        .unwrap_or(consts::DEFAULT_WEBHOOK_SECRET_OVERLAP_WINDOW_IN_SECS);
+    utils::when(
+        overlap_window_in_secs > consts::MAX_WEBHOOK_SECRET_OVERLAP_WINDOW_IN_SECS,
+        || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "overlap_window_in_secs must not exceed {}",
+                    consts::MAX_WEBHOOK_SECRET_OVERLAP_WINDOW_IN_SECS
+                ),
+            })
+            .into_report()
+        },
+    )?;
+
+    let db = state.store.as_ref();
+    let business_profile = db
+        .find_business_profile_by_profile_id(profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_owned(),
+        })?;
+
+    if business_profile.merchant_id != merchant_id {
+        Err(errors::ApiErrorResponse::AccessForbidden {
+            resource: profile_id.to_string(),
+        })?
+    }
+
+    // The previous secret is retained before the current one is replaced, so that webhooks are
+    // not left without a signature the merchant can verify if the business profile update fails
+    if let Some(current_secret) = business_profile.payment_response_hash_key.clone() {
+        let config = PreviousWebhookSecretConfig {
+            previous_secret: current_secret,
+            expires_at: date_time::now()
+                .saturating_add(time::Duration::seconds(i64::from(overlap_window_in_secs))),
+        }
+        .encode_to_string_of_json()
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to serialize the previous webhook secret")?;
+
+        let key = get_previous_webhook_secret_config_key(profile_id);
+        match db.find_config_by_key(&key).await {
+            Ok(_) => db
+                .update_config_by_key(
+                    &key,
+                    configs::ConfigUpdate::Update {
+                        config: Some(config),
+                    },
+                )
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to update the previous webhook secret")
+                .map(|_| ()),
+            Err(error) if error.current_context().is_db_not_found() => db
+                .insert_config(configs::ConfigNew { key, config })
+                .await
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to insert the previous webhook secret")
+                .map(|_| ()),
+            Err(error) => Err(error)
+                .change_context(errors::ApiErrorResponse::InternalServerError)
+                .attach_printable("Failed to fetch the previous webhook secret"),
+        }?;
+    }
+
+    let business_profile_update = storage::business_profile::BusinessProfileUpdateInternal {
+        modified_at: Some(date_time::now()),
+        payment_response_hash_key: Some(crypto::generate_cryptographically_secure_random_string(
+            64,
+        )),
+        ..Default::default()
+    };
+
+    let updated_business_profile = db
+        .update_business_profile_by_profile_id(business_profile, business_profile_update)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: profile_id.to_owned(),
+        })?;
+
+    Ok(service_api::ApplicationResponse::Json(
+        admin_types::BusinessProfileResponse::foreign_try_from(updated_business_profile)
+            .change_context(errors::ApiErrorResponse::InternalServerError)?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn test_webhook_signature_with_previous_secret() {
+        let payload = r#"{"event_type":"payment_succeeded"}"#;
+
+        let signature =
+            generate_webhook_signature(1712000000, payload, ["current"].into_iter()).unwrap();
+        let signature_during_overlap =
+            generate_webhook_signature(1712000000, payload, ["current", "previous"].into_iter())
+                .unwrap();
+
+        assert!(signature.starts_with("t=1712000000,v1="));
+        assert_eq!(signature.matches("v1=").count(), 1);
+        assert!(signature_during_overlap.starts_with(&signature));
+        assert_eq!(signature_during_overlap.matches("v1=").count(), 2);
+    }
+}
@@ -1,7 +1,11 @@
 use std::marker::PhantomData;
 
-use common_utils::{errors::CustomResult, ext_traits::ValueExt};
+use common_utils::{
+    errors::CustomResult,
+    ext_traits::{Encode, ValueExt},
+};
 use error_stack::ResultExt;
+use masking::{ExposeInterface, Secret};
 
 use crate::{
     core::{
@@ -58,6 +62,35 @@ pub async fn is_webhook_event_disabled(
     }
 }
 
+/// Returns a copy of the merchant connector account carrying the webhook secrets which were in use
+/// before the last rotation, if they are still within their overlap window
+pub fn get_mca_with_previous_webhook_secrets(
+    merchant_connector_account: &domain::MerchantConnectorAccount,
+) -> Option<domain::MerchantConnectorAccount> {
+    let webhook_details = merchant_connector_account
+        .connector_webhook_details
+        .clone()?
+        .expose()
+        .parse_value::<api_models::admin::MerchantConnectorWebhookDetails>(
+            "MerchantConnectorWebhookDetails",
+        )
+        .map_err(|err| logger::warn!(?err, "error while parsing connector webhook details"))
+        .ok()?;
+
+    let previous_webhook_details =
+        webhook_details.get_previous_webhook_details(common_utils::date_time::now())?;
+
+    let previous_webhook_details = previous_webhook_details
+        .encode_to_value()
+        .map_err(|err| logger::warn!(?err, "error while encoding connector webhook details"))
+        .ok()?;
+
+    Some(domain::MerchantConnectorAccount {
+        connector_webhook_details: Some(Secret::new(previous_webhook_details)),
+        ..merchant_connector_account.clone()
+    })
+}
+
 pub async fn construct_webhook_router_data<'a>(
     connector_name: &str,
     merchant_connector_account: domain::MerchantConnectorAccount,
@@ -1,14 +1,23 @@
-use error_stack::ResultExt;
+use std::collections::{hash_map::Entry, HashMap};
+
+use common_utils::ext_traits::StringExt;
+use error_stack::{IntoReport, ResultExt};
+use masking::PeekInterface;
 use router_env::{instrument, tracing};
 
 use crate::{
-    core::errors::{self, RouterResponse, StorageErrorExt},
+    core::{
+        errors::{self, RouterResponse, StorageErrorExt},
+        webhooks::{self as webhooks_core, utils as webhooks_utils},
+    },
     routes::AppState,
     services::ApplicationResponse,
-    types::{api, domain, transformers::ForeignTryFrom},
+    types::{api, domain, storage::enums as storage_enums, transformers::ForeignTryFrom},
+    utils,
 };
 
 const INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT: i64 = 100;
+const EVENT_REPLAY_MAX_EVENTS: usize = 100;
 
 #[derive(Debug)]
 enum MerchantIdOrProfileId {
@@ -54,6 +63,7 @@ pub async fn list_initial_delivery_attempts(
             created_before,
             limit,
             offset,
+            delivery_status,
         } => {
             let limit = match limit {
                 Some(limit) if  limit <= INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT => Ok(Some(limit)),
@@ -77,6 +87,7 @@ pub async fn list_initial_delivery_attempts(
                     created_before,
                     limit,
                     offset,
+                    delivery_status,
                     &key_store,
                 )
                 .await,
@@ -87,6 +98,7 @@ pub async fn list_initial_delivery_attempts(
                     created_before,
                     limit,
                     offset,
+                    delivery_status,
                     &key_store,
                 )
                 .await,
@@ -153,6 +165,346 @@ pub async fn list_delivery_attempts(
     }
 }
 
+#[instrument(skip(state))]
+pub async fn retry_delivery_attempt(
+    state: AppState,
+    merchant_id_or_profile_id: String,
+    event_id: String,
+) -> RouterResponse<api::webhook_events::EventRetrieveResponse> {
+    let store = state.store.as_ref();
+
+    let (identifier, key_store) =
+        determine_identifier_and_get_key_store(state.clone(), merchant_id_or_profile_id).await?;
+
+    let event_to_retry = store
+        .find_event_by_merchant_id_event_id(&key_store.merchant_id, &event_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+
+    let business_profile_id = get_accessible_business_profile_id(&event_to_retry, &identifier)?;
+
+    let business_profile = store
+        .find_business_profile_by_profile_id(&business_profile_id)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+            id: business_profile_id,
+        })?;
+
+    let delivery_attempt = storage_enums::WebhookDeliveryAttempt::ManualRetry;
+    let (event, request_content) =
+        insert_manual_delivery_attempt(&state, &key_store, &business_profile, event_to_retry)
+            .await?;
+    let new_event_id = event.event_id.clone();
+
+    webhooks_core::trigger_webhook_and_raise_event(
+        state.clone(),
+        business_profile,
+        &key_store,
+        event,
+        request_content,
+        delivery_attempt,
+        None,
+        None,
+    )
+    .await;
+
+    let updated_event = store
+        .find_event_by_merchant_id_event_id(&key_store.merchant_id, &new_event_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::EventNotFound)?;
+
+    Ok(ApplicationResponse::Json(
+        api::webhook_events::EventRetrieveResponse::try_from(updated_event)?,
+    ))
+}
+
+#[instrument(skip(state))]
+pub async fn replay_events(
+    state: AppState,
+    merchant_id_or_profile_id: String,
+    request: api::webhook_events::EventReplayRequest,
+) -> RouterResponse<Vec<api::webhook_events::EventListItemResponse>> {
+    let constraints =
+        api::webhook_events::EventReplayConstraintsInternal::foreign_try_from(request)?;
+
+    let store = state.store.as_ref();
+
+    let (identifier, key_store) =
+        determine_identifier_and_get_key_store(state.clone(), merchant_id_or_profile_id).await?;
+
+    let mut events_to_replay = match constraints {
+        api::webhook_events::EventReplayConstraintsInternal::EventIdFilter { event_ids } => {
+            utils::when(event_ids.len() > EVENT_REPLAY_MAX_EVENTS, || {
+                Err(errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "At most {EVENT_REPLAY_MAX_EVENTS} events can be replayed at once"
+                    ),
+                })
+                .into_report()
+            })?;
+
+            let mut events = Vec::with_capacity(event_ids.len());
+            for event_id in event_ids.iter() {
+                let event = store
+                    .find_event_by_merchant_id_event_id(
+                        &key_store.merchant_id,
+                        event_id,
+                        &key_store,
+                    )
+                    .await
+                    .to_not_found_response(errors::ApiErrorResponse::EventNotFound)
+                    .attach_printable_lazy(|| format!("Failed to find event `{event_id}`"))?;
+                get_accessible_business_profile_id(&event, &identifier)?;
+                events.push(event);
+            }
+            events
+        }
+        api::webhook_events::EventReplayConstraintsInternal::TimeRangeFilter {
+            created_after,
+            created_before,
+            event_types,
+        } => {
+            list_initial_events_in_time_range(
+                &state,
+                &identifier,
+                &key_store,
+                created_after,
+                created_before,
+                event_types.as_deref(),
+            )
+            .await?
+        }
+    };
+
+    // Events are replayed in the order they were originally created in
+    events_to_replay.sort_by_key(|event| event.created_at);
+
+    let mut business_profiles = HashMap::new();
+    let mut replayed_events = Vec::with_capacity(events_to_replay.len());
+    for event_to_replay in events_to_replay {
+        let business_profile_id =
+            get_accessible_business_profile_id(&event_to_replay, &identifier)?;
+        let business_profile = match business_profiles.entry(business_profile_id) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                let business_profile = store
+                    .find_business_profile_by_profile_id(entry.key())
+                    .await
+                    .to_not_found_response(errors::ApiErrorResponse::BusinessProfileNotFound {
+                        id: entry.key().clone(),
+                    })?;
+                entry.insert(business_profile)
+            }
+        };
+
+        let (event, request_content) =
+            insert_manual_delivery_attempt(&state, &key_store, business_profile, event_to_replay)
+                .await?;
+        replayed_events.push(api::webhook_events::EventListItemResponse::try_from(
+            event.clone(),
+        )?);
+
+        webhooks_core::delivery_worker::WebhookDeliveryJob {
+            state: state.clone(),
+            business_profile: business_profile.clone(),
+            merchant_key_store: key_store.clone(),
+            event,
+            request_content,
+            delivery_attempt: storage_enums::WebhookDeliveryAttempt::ManualRetry,
+            content: None,
+            process_tracker: None,
+        }
+        .schedule();
+    }
+
+    Ok(ApplicationResponse::Json(replayed_events))
+}
+
+/// Lists the initial delivery attempts of the events created in the time range, of the specified
+/// event types if any
+async fn list_initial_events_in_time_range(
+    state: &AppState,
+    identifier: &MerchantIdOrProfileId,
+    key_store: &domain::MerchantKeyStore,
+    created_after: time::PrimitiveDateTime,
+    created_before: time::PrimitiveDateTime,
+    event_types: Option<&[storage_enums::EventType]>,
+) -> errors::RouterResult<Vec<domain::Event>> {
+    let store = state.store.as_ref();
+    let mut events = Vec::new();
+    let mut offset = 0;
+
+    loop {
+        let page = match identifier {
+            MerchantIdOrProfileId::MerchantId(merchant_id) => {
+                store
+                    .list_initial_events_by_merchant_id_constraints(
+                        merchant_id,
+                        Some(created_after),
+                        Some(created_before),
+                        Some(INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT),
+                        Some(offset),
+                        None,
+                        key_store,
+                    )
+                    .await
+            }
+            MerchantIdOrProfileId::ProfileId(profile_id) => {
+                store
+                    .list_initial_events_by_profile_id_constraints(
+                        profile_id,
+                        Some(created_after),
+                        Some(created_before),
+                        Some(INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT),
+                        Some(offset),
+                        None,
+                        key_store,
+                    )
+                    .await
+            }
+        }
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to list events created in the time range")?;
+
+        let is_last_page = i64::try_from(page.len())
+            .map(|page_len| page_len < INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT)
+            .unwrap_or(true);
+
+        events.extend(page.into_iter().filter(|event| {
+            event_types.map_or(true, |event_types| event_types.contains(&event.event_type))
+        }));
+
+        utils::when(events.len() > EVENT_REPLAY_MAX_EVENTS, || {
+            Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: format!(
+                    "More than {EVENT_REPLAY_MAX_EVENTS} events match the specified constraints, \
+                     narrow the time range or the event types"
+                ),
+            })
+            .into_report()
+        })?;
+
+        if is_last_page {
+            break Ok(events);
+        }
+        offset += INITIAL_DELIVERY_ATTEMPTS_LIST_MAX_LIMIT;
+    }
+}
+
+/// Provides the business profile ID of the event, if the event is accessible with the identifier
+fn get_accessible_business_profile_id(
+    event: &domain::Event,
+    identifier: &MerchantIdOrProfileId,
+) -> errors::RouterResult<String> {
+    let business_profile_id = event
+        .business_profile_id
+        .clone()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("Failed to read business profile ID from event")?;
+
+    // Events of other business profiles of the merchant are not accessible with a profile ID
+    if let MerchantIdOrProfileId::ProfileId(profile_id) = identifier {
+        if *profile_id != business_profile_id {
+            Err(errors::ApiErrorResponse::EventNotFound)?;
+        }
+    }
+
+    Ok(business_profile_id)
+}
+
+/// Inserts a manual delivery attempt of the event, with the request content of the event
+async fn insert_manual_delivery_attempt(
+    state: &AppState,
+    key_store: &domain::MerchantKeyStore,
+    business_profile: &diesel_models::business_profile::BusinessProfile,
+    event: domain::Event,
+) -> errors::RouterResult<(
+    domain::Event,
+    api::webhook_events::OutgoingWebhookRequestContent,
+)> {
+    let request_content: api::webhook_events::OutgoingWebhookRequestContent = event
+        .request
+        .as_ref()
+        .ok_or(errors::ApiErrorResponse::InternalServerError)
+        .into_report()
+        .attach_printable("Failed to read request content from event")?
+        .get_inner()
+        .peek()
+        .parse_struct("OutgoingWebhookRequestContent")
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to parse request content of event")?;
+
+    let delivery_attempt = storage_enums::WebhookDeliveryAttempt::ManualRetry;
+    let idempotent_event_id = webhooks_utils::get_idempotent_event_id(
+        &event.primary_object_id,
+        event.event_type,
+        delivery_attempt,
+    );
+    let initial_attempt_id = event
+        .initial_attempt_id
+        .clone()
+        .unwrap_or_else(|| event.event_id.clone());
+
+    let new_event = domain::Event {
+        event_id: webhooks_utils::generate_event_id(),
+        event_type: event.event_type,
+        event_class: event.event_class,
+        is_webhook_notified: false,
+        primary_object_id: event.primary_object_id,
+        primary_object_type: event.primary_object_type,
+        created_at: common_utils::date_time::now(),
+        merchant_id: Some(business_profile.merchant_id.clone()),
+        business_profile_id: Some(business_profile.profile_id.clone()),
+        primary_object_created_at: event.primary_object_created_at,
+        idempotent_event_id: Some(idempotent_event_id),
+        initial_attempt_id: Some(initial_attempt_id),
+        request: event.request,
+        response: None,
+        delivery_attempt: Some(delivery_attempt),
+        delivery_status: None,
+    };
+
+    let event = state
+        .store
+        .insert_event(new_event, key_store)
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError)
+        .attach_printable("Failed to insert event")?;
+
+    Ok((event, request_content))
+}
+
+#[instrument(skip(state))]
+pub async fn retrieve_endpoint_health(
+    state: AppState,
+    merchant_id_or_profile_id: String,
+) -> RouterResponse<Vec<api::webhook_events::WebhookEndpointHealthResponse>> {
+    let (identifier, _key_store) =
+        determine_identifier_and_get_key_store(state.clone(), merchant_id_or_profile_id).await?;
+
+    let profile_ids = match identifier {
+        MerchantIdOrProfileId::MerchantId(merchant_id) => state
+            .store
+            .list_business_profile_by_merchant_id(&merchant_id)
+            .await
+            .change_context(errors::ApiErrorResponse::InternalServerError)
+            .attach_printable("Failed to list business profiles of the merchant")?
+            .into_iter()
+            .map(|business_profile| business_profile.profile_id)
+            .collect::<Vec<_>>(),
+        MerchantIdOrProfileId::ProfileId(profile_id) => vec![profile_id],
+    };
+
+    let endpoint_health =
+        futures::future::try_join_all(profile_ids.into_iter().map(|profile_id| {
+            webhooks_core::endpoint_health::get_endpoint_health(&state, profile_id)
+        }))
+        .await?;
+
+    Ok(ApplicationResponse::Json(endpoint_health))
+}
+
 async fn determine_identifier_and_get_key_store(
     state: AppState,
     merchant_id_or_profile_id: String,
@@ -1,5 +1,6 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_log;
 pub mod authentication;
 pub mod authorization;
 pub mod blocklist;
@@ -9,6 +10,7 @@ pub mod business_profile;
 pub mod cache;
 pub mod capture;
 pub mod cards_info;
+pub mod client_session;
 pub mod configs;
 pub mod customers;
 pub mod dashboard_metadata;
@@ -17,6 +19,7 @@ pub mod ephemeral_key;
 pub mod events;
 pub mod file;
 pub mod fraud_check;
+pub mod fx_rate;
 pub mod gsm;
 pub mod health_check;
 mod kafka_store;
@@ -29,9 +32,13 @@ pub mod organization;
 pub mod payment_link;
 pub mod payment_method;
 pub mod refund;
+pub mod report_run;
+pub mod report_schedule;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod settlement_record;
+pub mod success_rate_alert;
 pub mod user;
 pub mod user_role;
 
@@ -80,11 +87,13 @@ pub trait StorageInterface:
     + capture::CaptureInterface
     + customers::CustomerInterface
     + dashboard_metadata::DashboardMetadataInterface
+    + client_session::ClientSessionInterface
     + dispute::DisputeInterface
     + ephemeral_key::EphemeralKeyInterface
     + events::EventInterface
     + file::FileMetadataInterface
     + FraudCheckInterface
+    + fx_rate::FxRateInterface
     + locker_mock_up::LockerMockUpInterface
     + mandate::MandateInterface
     + merchant_account::MerchantAccountInterface
@@ -109,6 +118,11 @@ pub trait StorageInterface:
     + business_profile::BusinessProfileInterface
     + OrganizationInterface
     + routing_algorithm::RoutingAlgorithmInterface
+    + settlement_record::SettlementRecordInterface
+    + report_schedule::ReportScheduleInterface
+    + report_run::ReportRunInterface
+    + success_rate_alert::SuccessRateAlertInterface
+    + audit_log::AuditLogInterface
     + gsm::GsmInterface
     + user::UserInterface
     + user_role::UserRoleInterface
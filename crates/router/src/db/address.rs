@@ -511,6 +511,8 @@ mod storage {
                         payment_id: address_new.payment_id.clone(),
                         updated_by: storage_scheme.to_string(),
                         email: address_new.email.clone(),
+                        normalized_address: address_new.normalized_address.clone(),
+                        address_validation_status: address_new.address_validation_status,
                     };
 
                     let redis_entry = kv::TypedSql {
@@ -241,6 +241,8 @@ impl ApiKeyInterface for MockDb {
             created_at: api_key.created_at,
             expires_at: api_key.expires_at,
             last_used: api_key.last_used,
+            profile_id: api_key.profile_id,
+            scopes: api_key.scopes,
         };
         locked_api_keys.push(stored_key.clone());
 
@@ -266,6 +268,7 @@ impl ApiKeyInterface for MockDb {
                 description,
                 expires_at,
                 last_used,
+                scopes,
             } => {
                 if let Some(name) = name {
                     key_to_update.name = name;
@@ -280,6 +283,9 @@ impl ApiKeyInterface for MockDb {
                 if last_used.is_some() {
                     key_to_update.last_used = last_used
                 }
+                if let Some(scopes) = scopes {
+                    key_to_update.scopes = scopes;
+                }
             }
             storage::ApiKeyUpdate::LastUsedUpdate { last_used } => {
                 key_to_update.last_used = Some(last_used);
@@ -413,6 +419,8 @@ mod tests {
                 created_at: datetime!(2023-02-01 0:00),
                 expires_at: Some(datetime!(2023-03-01 0:00)),
                 last_used: None,
+                profile_id: None,
+                scopes: None,
             })
             .await
             .unwrap();
@@ -428,6 +436,8 @@ mod tests {
                 created_at: datetime!(2023-03-01 0:00),
                 expires_at: None,
                 last_used: None,
+                profile_id: None,
+                scopes: None,
             })
             .await
             .unwrap();
@@ -505,6 +515,8 @@ mod tests {
             created_at: datetime!(2023-06-01 0:00),
             expires_at: None,
             last_used: None,
+            profile_id: None,
+            scopes: None,
         };
 
         let api = db.insert_api_key(api).await.unwrap();
@@ -0,0 +1,128 @@
+use error_stack::IntoReport;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait AuditLogInterface {
+    async fn insert_audit_log(
+        &self,
+        audit_log: storage::AuditLogNew,
+    ) -> CustomResult<storage::AuditLog, errors::StorageError>;
+
+    async fn list_audit_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        constraints: storage::AuditLogListConstraints,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl AuditLogInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_audit_log(
+        &self,
+        audit_log: storage::AuditLogNew,
+    ) -> CustomResult<storage::AuditLog, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        audit_log
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn list_audit_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        constraints: storage::AuditLogListConstraints,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::AuditLog::list_by_merchant_id_constraints(&conn, merchant_id, constraints)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditLogInterface for MockDb {
+    async fn insert_audit_log(
+        &self,
+        audit_log: storage::AuditLogNew,
+    ) -> CustomResult<storage::AuditLog, errors::StorageError> {
+        let mut audit_logs = self.audit_logs.lock().await;
+        if audit_logs
+            .iter()
+            .any(|existing| existing.audit_id == audit_log.audit_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "audit_id",
+                key: Some(audit_log.audit_id.clone()),
+            })?
+        }
+        let audit_log = storage::AuditLog {
+            audit_id: audit_log.audit_id,
+            merchant_id: audit_log.merchant_id,
+            operation: audit_log.operation,
+            entity_id: audit_log.entity_id,
+            actor_type: audit_log.actor_type,
+            actor_id: audit_log.actor_id,
+            request_id: audit_log.request_id,
+            changes: audit_log.changes,
+            created_at: audit_log.created_at,
+        };
+        audit_logs.push(audit_log.clone());
+        Ok(audit_log)
+    }
+
+    async fn list_audit_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        constraints: storage::AuditLogListConstraints,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError> {
+        let mut audit_logs: Vec<storage::AuditLog> = self
+            .audit_logs
+            .lock()
+            .await
+            .iter()
+            .filter(|audit_log| {
+                audit_log.merchant_id == merchant_id
+                    && constraints
+                        .created_after
+                        .map_or(true, |created_after| audit_log.created_at >= created_after)
+                    && constraints.created_before.map_or(true, |created_before| {
+                        audit_log.created_at <= created_before
+                    })
+                    && constraints
+                        .operation
+                        .map_or(true, |operation| audit_log.operation == operation)
+                    && constraints
+                        .entity_id
+                        .as_ref()
+                        .map_or(true, |entity_id| &audit_log.entity_id == entity_id)
+                    && constraints.actor_id.as_ref().map_or(true, |actor_id| {
+                        audit_log.actor_id.as_ref() == Some(actor_id)
+                    })
+            })
+            .cloned()
+            .collect();
+        audit_logs.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        let offset = constraints
+            .offset
+            .and_then(|offset| usize::try_from(offset).ok())
+            .unwrap_or(0);
+        let limit = constraints
+            .limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+        Ok(audit_logs.into_iter().skip(offset).take(limit).collect())
+    }
+}
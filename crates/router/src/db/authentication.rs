@@ -127,6 +127,8 @@ impl AuthenticationInterface for MockDb {
             acs_trans_id: authentication.acs_trans_id,
             three_ds_server_trans_id: authentication.three_dsserver_trans_id,
             acs_signed_content: authentication.acs_signed_content,
+            previous_authentication_id: authentication.previous_authentication_id,
+            three_ds_method_completed: authentication.three_ds_method_completed,
         };
         authentications.push(authentication.clone());
         Ok(authentication)
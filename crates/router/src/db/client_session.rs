@@ -0,0 +1,216 @@
+use common_utils::date_time;
+use time::ext::NumericalDuration;
+
+use crate::{
+    core::errors::{self, CustomResult},
+    db::MockDb,
+    types::storage::client_session::{ClientSession, ClientSessionNew},
+};
+
+#[async_trait::async_trait]
+pub trait ClientSessionInterface {
+    async fn insert_client_session(
+        &self,
+        new: ClientSessionNew,
+        validity_in_seconds: i64,
+    ) -> CustomResult<ClientSession, errors::StorageError>;
+
+    async fn find_client_session(
+        &self,
+        token: &str,
+    ) -> CustomResult<ClientSession, errors::StorageError>;
+
+    /// Deletes the client session, failing if it was already deleted, so that a client session
+    /// is consumed only once even by concurrent requests.
+    async fn consume_client_session(
+        &self,
+        token: &str,
+    ) -> CustomResult<ClientSession, errors::StorageError>;
+}
+
+fn new_client_session(new: ClientSessionNew, validity_in_seconds: i64) -> ClientSession {
+    let created_at = date_time::now();
+    let expires = created_at.saturating_add(validity_in_seconds.seconds());
+    ClientSession {
+        token: new.token,
+        merchant_id: new.merchant_id,
+        payment_id: new.payment_id,
+        customer_id: new.customer_id,
+        created_at: created_at.assume_utc().unix_timestamp(),
+        expires: expires.assume_utc().unix_timestamp(),
+    }
+}
+
+mod storage {
+    use error_stack::{IntoReport, ResultExt};
+    use redis_interface::{DelReply, SetnxReply};
+    use router_env::{instrument, tracing};
+    use storage_impl::redis::kv_store::RedisConnInterface;
+
+    use super::ClientSessionInterface;
+    use crate::{
+        core::errors::{self, CustomResult},
+        services::Store,
+        types::storage::client_session::{ClientSession, ClientSessionNew},
+    };
+
+    fn client_session_key(token: &str) -> String {
+        format!("client_session_{token}")
+    }
+
+    #[async_trait::async_trait]
+    impl ClientSessionInterface for Store {
+        #[instrument(skip_all)]
+        async fn insert_client_session(
+            &self,
+            new: ClientSessionNew,
+            validity_in_seconds: i64,
+        ) -> CustomResult<ClientSession, errors::StorageError> {
+            let client_session = super::new_client_session(new, validity_in_seconds);
+
+            match self
+                .get_redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?
+                .serialize_and_set_key_if_not_exist(
+                    &client_session_key(&client_session.token),
+                    &client_session,
+                    Some(validity_in_seconds),
+                )
+                .await
+            {
+                Ok(SetnxReply::KeySet) => Ok(client_session),
+                Ok(SetnxReply::KeyNotSet) => Err(errors::StorageError::DuplicateValue {
+                    entity: "client session",
+                    key: None,
+                })
+                .into_report(),
+                Err(er) => Err(er).change_context(errors::StorageError::KVError),
+            }
+        }
+
+        #[instrument(skip_all)]
+        async fn find_client_session(
+            &self,
+            token: &str,
+        ) -> CustomResult<ClientSession, errors::StorageError> {
+            self.get_redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?
+                .get_and_deserialize_key(&client_session_key(token), "ClientSession")
+                .await
+                .change_context(errors::StorageError::KVError)
+        }
+
+        #[instrument(skip_all)]
+        async fn consume_client_session(
+            &self,
+            token: &str,
+        ) -> CustomResult<ClientSession, errors::StorageError> {
+            let client_session = self.find_client_session(token).await?;
+
+            match self
+                .get_redis_conn()
+                .map_err(Into::<errors::StorageError>::into)?
+                .delete_key(&client_session_key(token))
+                .await
+                .change_context(errors::StorageError::KVError)?
+            {
+                DelReply::KeyDeleted => Ok(client_session),
+                // Consumed by a concurrent request in the meantime
+                DelReply::KeyNotDeleted => Err(errors::StorageError::ValueNotFound(
+                    "client session not found".to_string(),
+                ))
+                .into_report(),
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ClientSessionInterface for MockDb {
+    async fn insert_client_session(
+        &self,
+        new: ClientSessionNew,
+        validity_in_seconds: i64,
+    ) -> CustomResult<ClientSession, errors::StorageError> {
+        let mut client_sessions = self.client_sessions.lock().await;
+        if client_sessions
+            .iter()
+            .any(|client_session| client_session.token == new.token)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "client session",
+                key: None,
+            })?;
+        }
+
+        let client_session = new_client_session(new, validity_in_seconds);
+        client_sessions.push(client_session.clone());
+        Ok(client_session)
+    }
+
+    async fn find_client_session(
+        &self,
+        token: &str,
+    ) -> CustomResult<ClientSession, errors::StorageError> {
+        self.client_sessions
+            .lock()
+            .await
+            .iter()
+            .find(|client_session| client_session.token == token)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound("client session not found".to_string()).into(),
+            )
+    }
+
+    async fn consume_client_session(
+        &self,
+        token: &str,
+    ) -> CustomResult<ClientSession, errors::StorageError> {
+        let mut client_sessions = self.client_sessions.lock().await;
+        client_sessions
+            .iter()
+            .position(|client_session| client_session.token == token)
+            .map(|position| client_sessions.remove(position))
+            .ok_or(
+                errors::StorageError::ValueNotFound("client session not found".to_string()).into(),
+            )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::ClientSessionInterface;
+    use crate::{db::MockDb, types::storage::client_session::ClientSessionNew};
+
+    #[tokio::test]
+    async fn test_mockdb_client_session_is_consumed_once() {
+        #[allow(clippy::expect_used)]
+        let mockdb = MockDb::new(&redis_interface::RedisSettings::default())
+            .await
+            .expect("Failed to create Mock store");
+
+        let client_session = mockdb
+            .insert_client_session(
+                ClientSessionNew {
+                    token: "cst_token".into(),
+                    merchant_id: "merchant1".into(),
+                    payment_id: "pay_1".into(),
+                    customer_id: Some("cus_1".into()),
+                },
+                900,
+            )
+            .await
+            .unwrap();
+        assert_eq!(client_session.expires - client_session.created_at, 900);
+
+        let found = mockdb.find_client_session("cst_token").await.unwrap();
+        assert_eq!(found.payment_id, "pay_1");
+
+        mockdb.consume_client_session("cst_token").await.unwrap();
+        assert!(mockdb.consume_client_session("cst_token").await.is_err());
+        assert!(mockdb.find_client_session("cst_token").await.is_err());
+    }
+}
@@ -181,6 +181,7 @@ impl DisputeInterface for MockDb {
             evidence,
             merchant_connector_id: dispute.merchant_connector_id,
             dispute_amount: dispute.dispute_amount,
+            representment_status: None,
         };
 
         locked_disputes.push(new_dispute.clone());
@@ -256,6 +257,10 @@ impl DisputeInterface for MockDb {
                         .as_ref()
                         .map(|connector| connector == &d.connector)
                         .unwrap_or(true)
+                    && dispute_constraints
+                        .representment_status
+                        .map(|status| Some(status) == d.representment_status)
+                        .unwrap_or(true)
                     && dispute_constraints
                         .received_time
                         .as_ref()
@@ -362,6 +367,11 @@ impl DisputeInterface for MockDb {
             storage::DisputeUpdate::EvidenceUpdate { evidence } => {
                 dispute_to_update.evidence = evidence;
             }
+            storage::DisputeUpdate::RepresentmentStatusUpdate {
+                representment_status,
+            } => {
+                dispute_to_update.representment_status = Some(representment_status);
+            }
         }
 
         dispute_to_update.modified_at = now;
@@ -574,6 +584,7 @@ mod tests {
                         received_time_lte: None,
                         received_time_gte: None,
                         profile_id: None,
+                        representment_status: None,
                     },
                 )
                 .await
@@ -48,6 +48,7 @@ where
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        delivery_status: Option<storage::enums::WebhookDeliveryStatus>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
 
@@ -72,6 +73,7 @@ where
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        delivery_status: Option<storage::enums::WebhookDeliveryStatus>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError>;
 
@@ -169,6 +171,7 @@ impl EventInterface for Store {
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        delivery_status: Option<storage::enums::WebhookDeliveryStatus>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let conn = connection::pg_connection_read(self).await?;
@@ -179,6 +182,7 @@ impl EventInterface for Store {
             created_before,
             limit,
             offset,
+            delivery_status,
         )
         .await
         .map_err(Into::into)
@@ -268,6 +272,7 @@ impl EventInterface for Store {
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        delivery_status: Option<storage::enums::WebhookDeliveryStatus>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let conn = connection::pg_connection_read(self).await?;
@@ -278,6 +283,7 @@ impl EventInterface for Store {
             created_before,
             limit,
             offset,
+            delivery_status,
         )
         .await
         .map_err(Into::into)
@@ -430,6 +436,7 @@ impl EventInterface for MockDb {
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        delivery_status: Option<storage::enums::WebhookDeliveryStatus>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let locked_events = self.events.lock().await;
@@ -445,6 +452,10 @@ impl EventInterface for MockDb {
                 check = check && (event.created_at <= created_before);
             }
 
+            if let Some(delivery_status) = delivery_status {
+                check = check && (event.delivery_status == Some(delivery_status));
+            }
+
             check
         });
 
@@ -553,6 +564,7 @@ impl EventInterface for MockDb {
         created_before: Option<time::PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        delivery_status: Option<storage::enums::WebhookDeliveryStatus>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         let locked_events = self.events.lock().await;
@@ -568,6 +580,10 @@ impl EventInterface for MockDb {
                 check = check && (event.created_at <= created_before);
             }
 
+            if let Some(delivery_status) = delivery_status {
+                check = check && (event.delivery_status == Some(delivery_status));
+            }
+
             check
         });
 
@@ -662,6 +678,9 @@ impl EventInterface for MockDb {
                 event_to_update.is_webhook_notified = is_webhook_notified;
                 event_to_update.response = response.map(Into::into);
             }
+            domain::EventUpdate::UpdateDeliveryStatus { delivery_status } => {
+                event_to_update.delivery_status = Some(delivery_status);
+            }
         }
 
         event_to_update
@@ -737,6 +756,7 @@ mod tests {
                     request: None,
                     response: None,
                     delivery_attempt: Some(enums::WebhookDeliveryAttempt::InitialAttempt),
+                    delivery_status: Some(enums::WebhookDeliveryStatus::Pending),
                 },
                 &merchant_key_store,
             )
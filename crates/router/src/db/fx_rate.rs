@@ -0,0 +1,175 @@
+use error_stack::IntoReport;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait FxRateInterface {
+    async fn insert_fx_rate(
+        &self,
+        fx_rate: storage::FxRateNew,
+    ) -> CustomResult<storage::FxRate, errors::StorageError>;
+
+    async fn update_fx_rate_by_rate_date(
+        &self,
+        rate_date: time::Date,
+        fx_rate_update: storage::FxRateUpdateInternal,
+    ) -> CustomResult<storage::FxRate, errors::StorageError>;
+
+    async fn find_fx_rates_by_rate_date_range(
+        &self,
+        start_date: time::Date,
+        end_date: time::Date,
+    ) -> CustomResult<Vec<storage::FxRate>, errors::StorageError>;
+
+    async fn find_latest_fx_rate_on_or_before_date(
+        &self,
+        rate_date: time::Date,
+    ) -> CustomResult<storage::FxRate, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl FxRateInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_fx_rate(
+        &self,
+        fx_rate: storage::FxRateNew,
+    ) -> CustomResult<storage::FxRate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        fx_rate
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn update_fx_rate_by_rate_date(
+        &self,
+        rate_date: time::Date,
+        fx_rate_update: storage::FxRateUpdateInternal,
+    ) -> CustomResult<storage::FxRate, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::FxRate::update_by_rate_date(&conn, rate_date, fx_rate_update)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn find_fx_rates_by_rate_date_range(
+        &self,
+        start_date: time::Date,
+        end_date: time::Date,
+    ) -> CustomResult<Vec<storage::FxRate>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::FxRate::find_by_rate_date_range(&conn, start_date, end_date)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn find_latest_fx_rate_on_or_before_date(
+        &self,
+        rate_date: time::Date,
+    ) -> CustomResult<storage::FxRate, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::FxRate::find_latest_on_or_before_date(&conn, rate_date)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl FxRateInterface for MockDb {
+    async fn insert_fx_rate(
+        &self,
+        fx_rate: storage::FxRateNew,
+    ) -> CustomResult<storage::FxRate, errors::StorageError> {
+        let mut fx_rates = self.fx_rates.lock().await;
+        if fx_rates
+            .iter()
+            .any(|fx_rate_inner| fx_rate_inner.rate_date == fx_rate.rate_date)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "rate_date",
+                key: Some(fx_rate.rate_date.to_string()),
+            })?
+        }
+        let fx_rate = storage::FxRate {
+            rate_date: fx_rate.rate_date,
+            base_currency: fx_rate.base_currency,
+            rates: fx_rate.rates,
+            created_at: fx_rate.created_at,
+            modified_at: fx_rate.modified_at,
+        };
+        fx_rates.push(fx_rate.clone());
+        Ok(fx_rate)
+    }
+
+    async fn update_fx_rate_by_rate_date(
+        &self,
+        rate_date: time::Date,
+        fx_rate_update: storage::FxRateUpdateInternal,
+    ) -> CustomResult<storage::FxRate, errors::StorageError> {
+        let mut fx_rates = self.fx_rates.lock().await;
+        fx_rates
+            .iter_mut()
+            .find(|fx_rate| fx_rate.rate_date == rate_date)
+            .map(|fx_rate| {
+                fx_rate.base_currency = fx_rate_update.base_currency;
+                fx_rate.rates = fx_rate_update.rates;
+                fx_rate.modified_at = fx_rate_update.modified_at;
+                fx_rate.clone()
+            })
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find fx rate for rate_date = {rate_date}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn find_fx_rates_by_rate_date_range(
+        &self,
+        start_date: time::Date,
+        end_date: time::Date,
+    ) -> CustomResult<Vec<storage::FxRate>, errors::StorageError> {
+        let mut fx_rates: Vec<storage::FxRate> = self
+            .fx_rates
+            .lock()
+            .await
+            .iter()
+            .filter(|fx_rate| fx_rate.rate_date >= start_date && fx_rate.rate_date <= end_date)
+            .cloned()
+            .collect();
+        fx_rates.sort_by_key(|fx_rate| fx_rate.rate_date);
+        Ok(fx_rates)
+    }
+
+    async fn find_latest_fx_rate_on_or_before_date(
+        &self,
+        rate_date: time::Date,
+    ) -> CustomResult<storage::FxRate, errors::StorageError> {
+        self.fx_rates
+            .lock()
+            .await
+            .iter()
+            .filter(|fx_rate| fx_rate.rate_date <= rate_date)
+            .max_by_key(|fx_rate| fx_rate.rate_date)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find fx rate on or before rate_date = {rate_date}"
+                ))
+                .into(),
+            )
+    }
+}
@@ -10,6 +10,7 @@ use data_models::payouts::{payout_attempt::PayoutAttemptInterface, payouts::Payo
 #[cfg(not(feature = "payouts"))]
 use data_models::{PayoutAttemptInterface, PayoutsInterface};
 use diesel_models::{
+    client_session::{ClientSession, ClientSessionNew},
     enums,
     enums::ProcessTrackerStatus,
     ephemeral_key::{EphemeralKey, EphemeralKeyNew},
@@ -37,17 +38,20 @@ use crate::{
     db::{
         address::AddressInterface,
         api_keys::ApiKeyInterface,
+        audit_log::AuditLogInterface,
         authentication::AuthenticationInterface,
         authorization::AuthorizationInterface,
         business_profile::BusinessProfileInterface,
         capture::CaptureInterface,
         cards_info::CardsInfoInterface,
+        client_session::ClientSessionInterface,
         configs::ConfigInterface,
         customers::CustomerInterface,
         dispute::DisputeInterface,
         ephemeral_key::EphemeralKeyInterface,
         events::EventInterface,
         file::FileMetadataInterface,
+        fx_rate::FxRateInterface,
         gsm::GsmInterface,
         health_check::HealthCheckDbInterface,
         locker_mock_up::LockerMockUpInterface,
@@ -58,11 +62,16 @@ use crate::{
         payment_link::PaymentLinkInterface,
         payment_method::PaymentMethodInterface,
         refund::RefundInterface,
+        report_run::ReportRunInterface,
+        report_schedule::ReportScheduleInterface,
         reverse_lookup::ReverseLookupInterface,
         routing_algorithm::RoutingAlgorithmInterface,
+        settlement_record::SettlementRecordInterface,
+        success_rate_alert::SuccessRateAlertInterface,
         MasterKeyInterface, StorageInterface,
     },
-    services::{authentication, kafka::KafkaProducer, Store},
+    events::EventsHandler,
+    services::{authentication, kafka::StorageEventProducer, Store},
     types::{
         domain,
         storage::{self, business_profile},
@@ -70,16 +79,18 @@ use crate::{
     },
 };
 
+/// Storage which logs the analytics events of the entities it writes to the configured event
+/// sink, Kafka or ClickHouse
 #[derive(Clone)]
 pub struct KafkaStore {
-    kafka_producer: KafkaProducer,
+    event_handler: EventsHandler,
     pub diesel_store: Store,
 }
 
 impl KafkaStore {
-    pub async fn new(store: Store, kafka_producer: KafkaProducer) -> Self {
+    pub async fn new(store: Store, event_handler: EventsHandler) -> Self {
         Self {
-            kafka_producer,
+            event_handler,
             diesel_store: store,
         }
     }
@@ -244,6 +255,45 @@ impl CardsInfoInterface for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl FxRateInterface for KafkaStore {
+    async fn insert_fx_rate(
+        &self,
+        fx_rate: storage::FxRateNew,
+    ) -> CustomResult<storage::FxRate, errors::StorageError> {
+        self.diesel_store.insert_fx_rate(fx_rate).await
+    }
+
+    async fn update_fx_rate_by_rate_date(
+        &self,
+        rate_date: time::Date,
+        fx_rate_update: storage::FxRateUpdateInternal,
+    ) -> CustomResult<storage::FxRate, errors::StorageError> {
+        self.diesel_store
+            .update_fx_rate_by_rate_date(rate_date, fx_rate_update)
+            .await
+    }
+
+    async fn find_fx_rates_by_rate_date_range(
+        &self,
+        start_date: time::Date,
+        end_date: time::Date,
+    ) -> CustomResult<Vec<storage::FxRate>, errors::StorageError> {
+        self.diesel_store
+            .find_fx_rates_by_rate_date_range(start_date, end_date)
+            .await
+    }
+
+    async fn find_latest_fx_rate_on_or_before_date(
+        &self,
+        rate_date: time::Date,
+    ) -> CustomResult<storage::FxRate, errors::StorageError> {
+        self.diesel_store
+            .find_latest_fx_rate_on_or_before_date(rate_date)
+            .await
+    }
+}
+
 #[async_trait::async_trait]
 impl ConfigInterface for KafkaStore {
     async fn insert_config(
@@ -385,7 +435,7 @@ impl DisputeInterface for KafkaStore {
     ) -> CustomResult<storage::Dispute, errors::StorageError> {
         let dispute = self.diesel_store.insert_dispute(dispute_new).await?;
 
-        if let Err(er) = self.kafka_producer.log_dispute(&dispute, None).await {
+        if let Err(er) = self.event_handler.log_dispute(&dispute, None).await {
             logger::error!(message="Failed to add analytics entry for Dispute {dispute:?}", error_message=?er);
         };
 
@@ -437,7 +487,7 @@ impl DisputeInterface for KafkaStore {
             .update_dispute(this.clone(), dispute)
             .await?;
         if let Err(er) = self
-            .kafka_producer
+            .event_handler
             .log_dispute(&dispute_new, Some(this))
             .await
         {
@@ -481,6 +531,33 @@ impl EphemeralKeyInterface for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl ClientSessionInterface for KafkaStore {
+    async fn insert_client_session(
+        &self,
+        new: ClientSessionNew,
+        validity_in_seconds: i64,
+    ) -> CustomResult<ClientSession, errors::StorageError> {
+        self.diesel_store
+            .insert_client_session(new, validity_in_seconds)
+            .await
+    }
+
+    async fn find_client_session(
+        &self,
+        token: &str,
+    ) -> CustomResult<ClientSession, errors::StorageError> {
+        self.diesel_store.find_client_session(token).await
+    }
+
+    async fn consume_client_session(
+        &self,
+        token: &str,
+    ) -> CustomResult<ClientSession, errors::StorageError> {
+        self.diesel_store.consume_client_session(token).await
+    }
+}
+
 #[async_trait::async_trait]
 impl EventInterface for KafkaStore {
     async fn insert_event(
@@ -526,6 +603,7 @@ impl EventInterface for KafkaStore {
         created_before: Option<PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        delivery_status: Option<enums::WebhookDeliveryStatus>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         self.diesel_store
@@ -535,6 +613,7 @@ impl EventInterface for KafkaStore {
                 created_before,
                 limit,
                 offset,
+                delivery_status,
                 merchant_key_store,
             )
             .await
@@ -577,6 +656,7 @@ impl EventInterface for KafkaStore {
         created_before: Option<PrimitiveDateTime>,
         limit: Option<i64>,
         offset: Option<i64>,
+        delivery_status: Option<enums::WebhookDeliveryStatus>,
         merchant_key_store: &domain::MerchantKeyStore,
     ) -> CustomResult<Vec<domain::Event>, errors::StorageError> {
         self.diesel_store
@@ -586,6 +666,7 @@ impl EventInterface for KafkaStore {
                 created_before,
                 limit,
                 offset,
+                delivery_status,
                 merchant_key_store,
             )
             .await
@@ -1062,11 +1143,7 @@ impl PaymentAttemptInterface for KafkaStore {
             .insert_payment_attempt(payment_attempt, storage_scheme)
             .await?;
 
-        if let Err(er) = self
-            .kafka_producer
-            .log_payment_attempt(&attempt, None)
-            .await
-        {
+        if let Err(er) = self.event_handler.log_payment_attempt(&attempt, None).await {
             logger::error!(message="Failed to log analytics event for payment attempt {attempt:?}", error_message=?er)
         }
 
@@ -1085,7 +1162,7 @@ impl PaymentAttemptInterface for KafkaStore {
             .await?;
 
         if let Err(er) = self
-            .kafka_producer
+            .event_handler
             .log_payment_attempt(&attempt, Some(this))
             .await
         {
@@ -1263,7 +1340,7 @@ impl PaymentIntentInterface for KafkaStore {
             .await?;
 
         if let Err(er) = self
-            .kafka_producer
+            .event_handler
             .log_payment_intent(&intent, Some(this))
             .await
         {
@@ -1284,7 +1361,7 @@ impl PaymentIntentInterface for KafkaStore {
             .insert_payment_intent(new, storage_scheme)
             .await?;
 
-        if let Err(er) = self.kafka_producer.log_payment_intent(&intent, None).await {
+        if let Err(er) = self.event_handler.log_payment_intent(&intent, None).await {
             logger::error!(message="Failed to add analytics entry for Payment Intent {intent:?}", error_message=?er);
         };
 
@@ -1302,6 +1379,22 @@ impl PaymentIntentInterface for KafkaStore {
             .await
     }
 
+    async fn find_payment_intent_with_active_attempt_by_payment_id_merchant_id(
+        &self,
+        payment_id: &str,
+        merchant_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> CustomResult<(storage::PaymentIntent, storage::PaymentAttempt), errors::DataStorageError>
+    {
+        self.diesel_store
+            .find_payment_intent_with_active_attempt_by_payment_id_merchant_id(
+                payment_id,
+                merchant_id,
+                storage_scheme,
+            )
+            .await
+    }
+
     #[cfg(feature = "olap")]
     async fn filter_payment_intent_by_constraints(
         &self,
@@ -1414,6 +1507,23 @@ impl PaymentMethodInterface for KafkaStore {
             .await
     }
 
+    async fn find_payment_method_by_payment_method_status_after_id(
+        &self,
+        payment_method: common_enums::PaymentMethod,
+        status: common_enums::PaymentMethodStatus,
+        after_id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_by_payment_method_status_after_id(
+                payment_method,
+                status,
+                after_id,
+                limit,
+            )
+            .await
+    }
+
     async fn get_payment_method_count_by_customer_id_merchant_id_status(
         &self,
         customer_id: &str,
@@ -1438,6 +1548,21 @@ impl PaymentMethodInterface for KafkaStore {
             .await
     }
 
+    async fn find_payment_method_by_customer_id_merchant_id_card_fingerprint(
+        &self,
+        customer_id: &str,
+        merchant_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        self.diesel_store
+            .find_payment_method_by_customer_id_merchant_id_card_fingerprint(
+                customer_id,
+                merchant_id,
+                card_fingerprint,
+            )
+            .await
+    }
+
     async fn insert_payment_method(
         &self,
         m: storage::PaymentMethodNew,
@@ -1801,7 +1926,7 @@ impl RefundInterface for KafkaStore {
             .update_refund(this.clone(), refund, storage_scheme)
             .await?;
 
-        if let Err(er) = self.kafka_producer.log_refund(&refund, Some(this)).await {
+        if let Err(er) = self.event_handler.log_refund(&refund, Some(this)).await {
             logger::error!(message="Failed to insert analytics event for Refund Update {refund?}", error_message=?er);
         }
         Ok(refund)
@@ -1829,7 +1954,7 @@ impl RefundInterface for KafkaStore {
     ) -> CustomResult<storage::Refund, errors::StorageError> {
         let refund = self.diesel_store.insert_refund(new, storage_scheme).await?;
 
-        if let Err(er) = self.kafka_producer.log_refund(&refund, None).await {
+        if let Err(er) = self.event_handler.log_refund(&refund, None).await {
             logger::error!(message="Failed to insert analytics event for Refund Create {refund?}", error_message=?er);
         }
         Ok(refund)
@@ -2006,6 +2131,223 @@ impl ReverseLookupInterface for KafkaStore {
     }
 }
 
+#[async_trait::async_trait]
+impl SettlementRecordInterface for KafkaStore {
+    async fn insert_settlement_record(
+        &self,
+        settlement_record: storage::SettlementRecordNew,
+    ) -> CustomResult<storage::SettlementRecord, errors::StorageError> {
+        self.diesel_store
+            .insert_settlement_record(settlement_record)
+            .await
+    }
+
+    async fn find_settlement_record_by_merchant_id_record_id(
+        &self,
+        merchant_id: &str,
+        record_id: &str,
+    ) -> CustomResult<storage::SettlementRecord, errors::StorageError> {
+        self.diesel_store
+            .find_settlement_record_by_merchant_id_record_id(merchant_id, record_id)
+            .await
+    }
+
+    async fn find_settlement_record_by_merchant_id_connector_transaction_id(
+        &self,
+        merchant_id: &str,
+        connector: &str,
+        record_type: enums::SettlementRecordType,
+        connector_transaction_id: &str,
+    ) -> CustomResult<storage::SettlementRecord, errors::StorageError> {
+        self.diesel_store
+            .find_settlement_record_by_merchant_id_connector_transaction_id(
+                merchant_id,
+                connector,
+                record_type,
+                connector_transaction_id,
+            )
+            .await
+    }
+
+    async fn update_settlement_record_by_merchant_id_record_id(
+        &self,
+        merchant_id: &str,
+        record_id: &str,
+        settlement_record_update: storage::SettlementRecordUpdateInternal,
+    ) -> CustomResult<storage::SettlementRecord, errors::StorageError> {
+        self.diesel_store
+            .update_settlement_record_by_merchant_id_record_id(
+                merchant_id,
+                record_id,
+                settlement_record_update,
+            )
+            .await
+    }
+
+    async fn list_settlement_records_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        constraints: storage::SettlementRecordListConstraints,
+    ) -> CustomResult<Vec<storage::SettlementRecord>, errors::StorageError> {
+        self.diesel_store
+            .list_settlement_records_by_merchant_id_constraints(merchant_id, constraints)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl ReportScheduleInterface for KafkaStore {
+    async fn insert_report_schedule(
+        &self,
+        report_schedule: storage::ReportScheduleNew,
+    ) -> CustomResult<storage::ReportSchedule, errors::StorageError> {
+        self.diesel_store
+            .insert_report_schedule(report_schedule)
+            .await
+    }
+
+    async fn find_report_schedule_by_merchant_id_schedule_id(
+        &self,
+        merchant_id: &str,
+        schedule_id: &str,
+    ) -> CustomResult<storage::ReportSchedule, errors::StorageError> {
+        self.diesel_store
+            .find_report_schedule_by_merchant_id_schedule_id(merchant_id, schedule_id)
+            .await
+    }
+
+    async fn list_active_report_schedules_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::ReportSchedule>, errors::StorageError> {
+        self.diesel_store
+            .list_active_report_schedules_by_merchant_id(merchant_id)
+            .await
+    }
+
+    async fn update_report_schedule_by_merchant_id_schedule_id(
+        &self,
+        merchant_id: &str,
+        schedule_id: &str,
+        report_schedule_update: storage::ReportScheduleUpdateInternal,
+    ) -> CustomResult<storage::ReportSchedule, errors::StorageError> {
+        self.diesel_store
+            .update_report_schedule_by_merchant_id_schedule_id(
+                merchant_id,
+                schedule_id,
+                report_schedule_update,
+            )
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl ReportRunInterface for KafkaStore {
+    async fn insert_report_run(
+        &self,
+        report_run: storage::ReportRunNew,
+    ) -> CustomResult<storage::ReportRun, errors::StorageError> {
+        self.diesel_store.insert_report_run(report_run).await
+    }
+
+    async fn find_report_run_by_merchant_id_run_id(
+        &self,
+        merchant_id: &str,
+        run_id: &str,
+    ) -> CustomResult<storage::ReportRun, errors::StorageError> {
+        self.diesel_store
+            .find_report_run_by_merchant_id_run_id(merchant_id, run_id)
+            .await
+    }
+
+    async fn update_report_run_by_merchant_id_run_id(
+        &self,
+        merchant_id: &str,
+        run_id: &str,
+        report_run_update: storage::ReportRunUpdateInternal,
+    ) -> CustomResult<storage::ReportRun, errors::StorageError> {
+        self.diesel_store
+            .update_report_run_by_merchant_id_run_id(merchant_id, run_id, report_run_update)
+            .await
+    }
+
+    async fn list_report_runs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        constraints: storage::ReportRunListConstraints,
+    ) -> CustomResult<Vec<storage::ReportRun>, errors::StorageError> {
+        self.diesel_store
+            .list_report_runs_by_merchant_id_constraints(merchant_id, constraints)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl AuditLogInterface for KafkaStore {
+    async fn insert_audit_log(
+        &self,
+        audit_log: storage::AuditLogNew,
+    ) -> CustomResult<storage::AuditLog, errors::StorageError> {
+        self.diesel_store.insert_audit_log(audit_log).await
+    }
+
+    async fn list_audit_logs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        constraints: storage::AuditLogListConstraints,
+    ) -> CustomResult<Vec<storage::AuditLog>, errors::StorageError> {
+        self.diesel_store
+            .list_audit_logs_by_merchant_id_constraints(merchant_id, constraints)
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl SuccessRateAlertInterface for KafkaStore {
+    async fn insert_success_rate_alert(
+        &self,
+        success_rate_alert: storage::SuccessRateAlertNew,
+    ) -> CustomResult<storage::SuccessRateAlert, errors::StorageError> {
+        self.diesel_store
+            .insert_success_rate_alert(success_rate_alert)
+            .await
+    }
+
+    async fn find_success_rate_alert_by_merchant_id_alert_id(
+        &self,
+        merchant_id: &str,
+        alert_id: &str,
+    ) -> CustomResult<storage::SuccessRateAlert, errors::StorageError> {
+        self.diesel_store
+            .find_success_rate_alert_by_merchant_id_alert_id(merchant_id, alert_id)
+            .await
+    }
+
+    async fn list_active_success_rate_alerts_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::SuccessRateAlert>, errors::StorageError> {
+        self.diesel_store
+            .list_active_success_rate_alerts_by_merchant_id(merchant_id)
+            .await
+    }
+
+    async fn update_success_rate_alert_by_merchant_id_alert_id(
+        &self,
+        merchant_id: &str,
+        alert_id: &str,
+        success_rate_alert_update: storage::SuccessRateAlertUpdateInternal,
+    ) -> CustomResult<storage::SuccessRateAlert, errors::StorageError> {
+        self.diesel_store
+            .update_success_rate_alert_by_merchant_id_alert_id(
+                merchant_id,
+                alert_id,
+                success_rate_alert_update,
+            )
+            .await
+    }
+}
+
 #[async_trait::async_trait]
 impl RoutingAlgorithmInterface for KafkaStore {
     async fn insert_routing_algorithm(
@@ -2399,7 +2741,7 @@ impl BatchSampleDataInterface for KafkaStore {
 
         for payment_intent in payment_intents_list.iter() {
             let _ = self
-                .kafka_producer
+                .event_handler
                 .log_payment_intent(payment_intent, None)
                 .await;
         }
@@ -2420,7 +2762,7 @@ impl BatchSampleDataInterface for KafkaStore {
 
         for payment_attempt in payment_attempts_list.iter() {
             let _ = self
-                .kafka_producer
+                .event_handler
                 .log_payment_attempt(payment_attempt, None)
                 .await;
         }
@@ -2437,11 +2779,26 @@ impl BatchSampleDataInterface for KafkaStore {
             .await?;
 
         for refund in refunds_list.iter() {
-            let _ = self.kafka_producer.log_refund(refund, None).await;
+            let _ = self.event_handler.log_refund(refund, None).await;
         }
         Ok(refunds_list)
     }
 
+    async fn insert_disputes_batch_for_sample_data(
+        &self,
+        batch: Vec<diesel_models::user::sample_data::DisputeBatchNew>,
+    ) -> CustomResult<Vec<diesel_models::Dispute>, data_models::errors::StorageError> {
+        let disputes_list = self
+            .diesel_store
+            .insert_disputes_batch_for_sample_data(batch)
+            .await?;
+
+        for dispute in disputes_list.iter() {
+            let _ = self.event_handler.log_dispute(dispute, None).await;
+        }
+        Ok(disputes_list)
+    }
+
     async fn delete_payment_intents_for_sample_data(
         &self,
         merchant_id: &str,
@@ -2454,7 +2811,7 @@ impl BatchSampleDataInterface for KafkaStore {
 
         for payment_intent in payment_intents_list.iter() {
             let _ = self
-                .kafka_producer
+                .event_handler
                 .log_payment_intent_delete(payment_intent)
                 .await;
         }
@@ -2475,7 +2832,7 @@ impl BatchSampleDataInterface for KafkaStore {
 
         for payment_attempt in payment_attempts_list.iter() {
             let _ = self
-                .kafka_producer
+                .event_handler
                 .log_payment_attempt_delete(payment_attempt)
                 .await;
         }
@@ -2493,11 +2850,27 @@ impl BatchSampleDataInterface for KafkaStore {
             .await?;
 
         for refund in refunds_list.iter() {
-            let _ = self.kafka_producer.log_refund_delete(refund).await;
+            let _ = self.event_handler.log_refund_delete(refund).await;
         }
 
         Ok(refunds_list)
     }
+
+    async fn delete_disputes_for_sample_data(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<diesel_models::Dispute>, data_models::errors::StorageError> {
+        let disputes_list = self
+            .diesel_store
+            .delete_disputes_for_sample_data(merchant_id)
+            .await?;
+
+        for dispute in disputes_list.iter() {
+            let _ = self.event_handler.log_dispute_delete(dispute).await;
+        }
+
+        Ok(disputes_list)
+    }
 }
 
 #[async_trait::async_trait]
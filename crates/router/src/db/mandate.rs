@@ -201,8 +201,14 @@ impl MandateInterface for MockDb {
                     storage::MandateUpdate::StatusUpdate { mandate_status } => {
                         mandate.mandate_status = mandate_status;
                     }
-                    storage::MandateUpdate::CaptureAmountUpdate { amount_captured } => {
+                    storage::MandateUpdate::CaptureAmountUpdate {
+                        amount_captured,
+                        period_amount_captured,
+                        period_start,
+                    } => {
                         mandate.amount_captured = amount_captured;
+                        mandate.period_amount_captured = period_amount_captured;
+                        mandate.period_start = period_start;
                     }
                     storage::MandateUpdate::ConnectorReferenceUpdate {
                         connector_mandate_ids,
@@ -221,6 +227,9 @@ impl MandateInterface for MockDb {
                         mandate.payment_method_id = payment_method_id;
                         mandate.original_payment_id = original_payment_id
                     }
+                    diesel_models::MandateUpdate::CustomerUpdate { customer_id } => {
+                        mandate.customer_id = customer_id;
+                    }
                 }
                 Ok(mandate.clone())
             }
@@ -318,6 +327,11 @@ impl MandateInterface for MockDb {
             metadata: mandate_new.metadata,
             connector_mandate_ids: mandate_new.connector_mandate_ids,
             merchant_connector_id: mandate_new.merchant_connector_id,
+            max_amount_per_charge: mandate_new.max_amount_per_charge,
+            max_amount_per_period: mandate_new.max_amount_per_period,
+            limit_period: mandate_new.limit_period,
+            period_amount_captured: mandate_new.period_amount_captured,
+            period_start: mandate_new.period_start,
         };
         mandates.push(mandate.clone());
         Ok(mandate)
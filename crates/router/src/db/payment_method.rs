@@ -21,6 +21,13 @@ pub trait PaymentMethodInterface {
         locker_id: &str,
     ) -> CustomResult<storage::PaymentMethod, errors::StorageError>;
 
+    async fn find_payment_method_by_customer_id_merchant_id_card_fingerprint(
+        &self,
+        customer_id: &str,
+        merchant_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError>;
+
     async fn find_payment_method_by_customer_id_merchant_id_list(
         &self,
         customer_id: &str,
@@ -43,6 +50,14 @@ pub trait PaymentMethodInterface {
         status: common_enums::PaymentMethodStatus,
     ) -> CustomResult<i64, errors::StorageError>;
 
+    async fn find_payment_method_by_payment_method_status_after_id(
+        &self,
+        payment_method: common_enums::PaymentMethod,
+        status: common_enums::PaymentMethodStatus,
+        after_id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError>;
+
     async fn insert_payment_method(
         &self,
         payment_method_new: storage::PaymentMethodNew,
@@ -87,6 +102,25 @@ impl PaymentMethodInterface for Store {
             .into_report()
     }
 
+    #[instrument(skip_all)]
+    async fn find_payment_method_by_customer_id_merchant_id_card_fingerprint(
+        &self,
+        customer_id: &str,
+        merchant_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentMethod::find_by_customer_id_merchant_id_card_fingerprint(
+            &conn,
+            customer_id,
+            merchant_id,
+            card_fingerprint,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
     #[instrument(skip_all)]
     async fn get_payment_method_count_by_customer_id_merchant_id_status(
         &self,
@@ -173,6 +207,27 @@ impl PaymentMethodInterface for Store {
         .into_report()
     }
 
+    #[instrument(skip_all)]
+    async fn find_payment_method_by_payment_method_status_after_id(
+        &self,
+        payment_method: common_enums::PaymentMethod,
+        status: common_enums::PaymentMethodStatus,
+        after_id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::PaymentMethod::find_by_payment_method_status_after_id(
+            &conn,
+            payment_method,
+            status,
+            after_id,
+            limit,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
     async fn delete_payment_method_by_merchant_id_payment_method_id(
         &self,
         merchant_id: &str,
@@ -230,6 +285,32 @@ impl PaymentMethodInterface for MockDb {
         }
     }
 
+    async fn find_payment_method_by_customer_id_merchant_id_card_fingerprint(
+        &self,
+        customer_id: &str,
+        merchant_id: &str,
+        card_fingerprint: &str,
+    ) -> CustomResult<storage::PaymentMethod, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        let payment_method = payment_methods
+            .iter()
+            .find(|pm| {
+                pm.customer_id == customer_id
+                    && pm.merchant_id == merchant_id
+                    && pm.card_fingerprint.as_deref() == Some(card_fingerprint)
+                    && pm.status == common_enums::PaymentMethodStatus::Active
+            })
+            .cloned();
+
+        match payment_method {
+            Some(pm) => Ok(pm),
+            None => Err(errors::StorageError::ValueNotFound(
+                "cannot find payment method".to_string(),
+            )
+            .into()),
+        }
+    }
+
     async fn get_payment_method_count_by_customer_id_merchant_id_status(
         &self,
         customer_id: &str,
@@ -289,6 +370,10 @@ impl PaymentMethodInterface for MockDb {
             connector_mandate_details: payment_method_new.connector_mandate_details,
             customer_acceptance: payment_method_new.customer_acceptance,
             status: payment_method_new.status,
+            card_fingerprint: payment_method_new.card_fingerprint,
+            network_token_reference_id: payment_method_new.network_token_reference_id,
+            network_token_locker_id: payment_method_new.network_token_locker_id,
+            network_token_requestor_id: payment_method_new.network_token_requestor_id,
         };
         payment_methods.push(payment_method.clone());
         Ok(payment_method)
@@ -345,6 +430,26 @@ impl PaymentMethodInterface for MockDb {
         }
     }
 
+    async fn find_payment_method_by_payment_method_status_after_id(
+        &self,
+        payment_method: common_enums::PaymentMethod,
+        status: common_enums::PaymentMethodStatus,
+        after_id: i32,
+        limit: i64,
+    ) -> CustomResult<Vec<storage::PaymentMethod>, errors::StorageError> {
+        let payment_methods = self.payment_methods.lock().await;
+        let mut payment_methods_found = payment_methods
+            .iter()
+            .filter(|pm| {
+                pm.payment_method == payment_method && pm.status == status && pm.id > after_id
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        payment_methods_found.sort_by_key(|pm| pm.id);
+        payment_methods_found.truncate(usize::try_from(limit).unwrap_or(0));
+        Ok(payment_methods_found)
+    }
+
     async fn delete_payment_method_by_merchant_id_payment_method_id(
         &self,
         merchant_id: &str,
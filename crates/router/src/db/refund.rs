@@ -384,6 +384,10 @@ mod storage {
                         profile_id: new.profile_id.clone(),
                         updated_by: new.updated_by.clone(),
                         merchant_connector_id: new.merchant_connector_id.clone(),
+                        refund_reason_code: new.refund_reason_code,
+                        payout_id: new.payout_id.clone(),
+                        retry_count: new.retry_count,
+                        retry_status: new.retry_status,
                     };
 
                     let field = format!(
@@ -817,6 +821,10 @@ impl RefundInterface for MockDb {
             profile_id: new.profile_id,
             updated_by: new.updated_by,
             merchant_connector_id: new.merchant_connector_id,
+            refund_reason_code: new.refund_reason_code,
+            payout_id: new.payout_id,
+            retry_count: new.retry_count,
+            retry_status: new.retry_status,
         };
         refunds.push(refund.clone());
         Ok(refund)
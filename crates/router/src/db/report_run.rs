@@ -0,0 +1,223 @@
+use error_stack::IntoReport;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ReportRunInterface {
+    async fn insert_report_run(
+        &self,
+        report_run: storage::ReportRunNew,
+    ) -> CustomResult<storage::ReportRun, errors::StorageError>;
+
+    async fn find_report_run_by_merchant_id_run_id(
+        &self,
+        merchant_id: &str,
+        run_id: &str,
+    ) -> CustomResult<storage::ReportRun, errors::StorageError>;
+
+    async fn update_report_run_by_merchant_id_run_id(
+        &self,
+        merchant_id: &str,
+        run_id: &str,
+        report_run_update: storage::ReportRunUpdateInternal,
+    ) -> CustomResult<storage::ReportRun, errors::StorageError>;
+
+    async fn list_report_runs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        constraints: storage::ReportRunListConstraints,
+    ) -> CustomResult<Vec<storage::ReportRun>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ReportRunInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_report_run(
+        &self,
+        report_run: storage::ReportRunNew,
+    ) -> CustomResult<storage::ReportRun, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        report_run
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn find_report_run_by_merchant_id_run_id(
+        &self,
+        merchant_id: &str,
+        run_id: &str,
+    ) -> CustomResult<storage::ReportRun, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReportRun::find_by_merchant_id_run_id(&conn, merchant_id, run_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn update_report_run_by_merchant_id_run_id(
+        &self,
+        merchant_id: &str,
+        run_id: &str,
+        report_run_update: storage::ReportRunUpdateInternal,
+    ) -> CustomResult<storage::ReportRun, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ReportRun::update_by_merchant_id_run_id(
+            &conn,
+            merchant_id,
+            run_id,
+            report_run_update,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn list_report_runs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        constraints: storage::ReportRunListConstraints,
+    ) -> CustomResult<Vec<storage::ReportRun>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReportRun::list_by_merchant_id_constraints(
+            &conn,
+            merchant_id,
+            constraints.schedule_id,
+            constraints.status,
+            constraints.limit,
+            constraints.offset,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl ReportRunInterface for MockDb {
+    async fn insert_report_run(
+        &self,
+        report_run: storage::ReportRunNew,
+    ) -> CustomResult<storage::ReportRun, errors::StorageError> {
+        let mut report_runs = self.report_runs.lock().await;
+        if report_runs
+            .iter()
+            .any(|run| run.run_id == report_run.run_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "run_id",
+                key: Some(report_run.run_id.clone()),
+            })?
+        }
+        let report_run = storage::ReportRun {
+            run_id: report_run.run_id,
+            schedule_id: report_run.schedule_id,
+            merchant_id: report_run.merchant_id,
+            report_type: report_run.report_type,
+            period_start: report_run.period_start,
+            period_end: report_run.period_end,
+            status: report_run.status,
+            file_id: report_run.file_id,
+            row_count: report_run.row_count,
+            error_message: report_run.error_message,
+            delivered_at: report_run.delivered_at,
+            created_at: report_run.created_at,
+            modified_at: report_run.modified_at,
+        };
+        report_runs.push(report_run.clone());
+        Ok(report_run)
+    }
+
+    async fn find_report_run_by_merchant_id_run_id(
+        &self,
+        merchant_id: &str,
+        run_id: &str,
+    ) -> CustomResult<storage::ReportRun, errors::StorageError> {
+        self.report_runs
+            .lock()
+            .await
+            .iter()
+            .find(|run| run.merchant_id == merchant_id && run.run_id == run_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find report run for run_id = {run_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn update_report_run_by_merchant_id_run_id(
+        &self,
+        merchant_id: &str,
+        run_id: &str,
+        report_run_update: storage::ReportRunUpdateInternal,
+    ) -> CustomResult<storage::ReportRun, errors::StorageError> {
+        let mut report_runs = self.report_runs.lock().await;
+        report_runs
+            .iter_mut()
+            .find(|run| run.merchant_id == merchant_id && run.run_id == run_id)
+            .map(|run| {
+                run.status = report_run_update.status;
+                run.file_id = report_run_update.file_id.or(run.file_id.take());
+                run.row_count = report_run_update.row_count.or(run.row_count);
+                run.error_message = report_run_update.error_message.or(run.error_message.take());
+                run.delivered_at = report_run_update.delivered_at.or(run.delivered_at);
+                run.modified_at = report_run_update.modified_at;
+                run.clone()
+            })
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find report run for run_id = {run_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_report_runs_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        constraints: storage::ReportRunListConstraints,
+    ) -> CustomResult<Vec<storage::ReportRun>, errors::StorageError> {
+        let mut report_runs: Vec<storage::ReportRun> = self
+            .report_runs
+            .lock()
+            .await
+            .iter()
+            .filter(|run| {
+                run.merchant_id == merchant_id
+                    && constraints
+                        .schedule_id
+                        .as_ref()
+                        .map_or(true, |schedule_id| &run.schedule_id == schedule_id)
+                    && constraints
+                        .status
+                        .as_ref()
+                        .map_or(true, |status| status.contains(&run.status))
+            })
+            .cloned()
+            .collect();
+        report_runs.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        let offset = constraints
+            .offset
+            .and_then(|offset| usize::try_from(offset).ok())
+            .unwrap_or(0);
+        let limit = constraints
+            .limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+
+        Ok(report_runs.into_iter().skip(offset).take(limit).collect())
+    }
+}
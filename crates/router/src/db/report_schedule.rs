@@ -0,0 +1,199 @@
+use error_stack::IntoReport;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait ReportScheduleInterface {
+    async fn insert_report_schedule(
+        &self,
+        report_schedule: storage::ReportScheduleNew,
+    ) -> CustomResult<storage::ReportSchedule, errors::StorageError>;
+
+    async fn find_report_schedule_by_merchant_id_schedule_id(
+        &self,
+        merchant_id: &str,
+        schedule_id: &str,
+    ) -> CustomResult<storage::ReportSchedule, errors::StorageError>;
+
+    async fn list_active_report_schedules_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::ReportSchedule>, errors::StorageError>;
+
+    async fn update_report_schedule_by_merchant_id_schedule_id(
+        &self,
+        merchant_id: &str,
+        schedule_id: &str,
+        report_schedule_update: storage::ReportScheduleUpdateInternal,
+    ) -> CustomResult<storage::ReportSchedule, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl ReportScheduleInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_report_schedule(
+        &self,
+        report_schedule: storage::ReportScheduleNew,
+    ) -> CustomResult<storage::ReportSchedule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        report_schedule
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn find_report_schedule_by_merchant_id_schedule_id(
+        &self,
+        merchant_id: &str,
+        schedule_id: &str,
+    ) -> CustomResult<storage::ReportSchedule, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReportSchedule::find_by_merchant_id_schedule_id(&conn, merchant_id, schedule_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn list_active_report_schedules_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::ReportSchedule>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::ReportSchedule::list_active_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn update_report_schedule_by_merchant_id_schedule_id(
+        &self,
+        merchant_id: &str,
+        schedule_id: &str,
+        report_schedule_update: storage::ReportScheduleUpdateInternal,
+    ) -> CustomResult<storage::ReportSchedule, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::ReportSchedule::update_by_merchant_id_schedule_id(
+            &conn,
+            merchant_id,
+            schedule_id,
+            report_schedule_update,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl ReportScheduleInterface for MockDb {
+    async fn insert_report_schedule(
+        &self,
+        report_schedule: storage::ReportScheduleNew,
+    ) -> CustomResult<storage::ReportSchedule, errors::StorageError> {
+        let mut report_schedules = self.report_schedules.lock().await;
+        if report_schedules
+            .iter()
+            .any(|schedule| schedule.schedule_id == report_schedule.schedule_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "schedule_id",
+                key: Some(report_schedule.schedule_id.clone()),
+            })?
+        }
+        let report_schedule = storage::ReportSchedule {
+            schedule_id: report_schedule.schedule_id,
+            merchant_id: report_schedule.merchant_id,
+            report_type: report_schedule.report_type,
+            frequency: report_schedule.frequency,
+            delivery_method: report_schedule.delivery_method,
+            delivery_config: report_schedule.delivery_config,
+            is_active: report_schedule.is_active,
+            next_run_at: report_schedule.next_run_at,
+            last_run_at: report_schedule.last_run_at,
+            created_at: report_schedule.created_at,
+            modified_at: report_schedule.modified_at,
+        };
+        report_schedules.push(report_schedule.clone());
+        Ok(report_schedule)
+    }
+
+    async fn find_report_schedule_by_merchant_id_schedule_id(
+        &self,
+        merchant_id: &str,
+        schedule_id: &str,
+    ) -> CustomResult<storage::ReportSchedule, errors::StorageError> {
+        self.report_schedules
+            .lock()
+            .await
+            .iter()
+            .find(|schedule| {
+                schedule.merchant_id == merchant_id && schedule.schedule_id == schedule_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find report schedule for schedule_id = {schedule_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_active_report_schedules_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::ReportSchedule>, errors::StorageError> {
+        let mut report_schedules: Vec<storage::ReportSchedule> = self
+            .report_schedules
+            .lock()
+            .await
+            .iter()
+            .filter(|schedule| schedule.merchant_id == merchant_id && schedule.is_active)
+            .cloned()
+            .collect();
+        report_schedules.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(report_schedules)
+    }
+
+    async fn update_report_schedule_by_merchant_id_schedule_id(
+        &self,
+        merchant_id: &str,
+        schedule_id: &str,
+        report_schedule_update: storage::ReportScheduleUpdateInternal,
+    ) -> CustomResult<storage::ReportSchedule, errors::StorageError> {
+        let mut report_schedules = self.report_schedules.lock().await;
+        report_schedules
+            .iter_mut()
+            .find(|schedule| {
+                schedule.merchant_id == merchant_id && schedule.schedule_id == schedule_id
+            })
+            .map(|schedule| {
+                if let Some(is_active) = report_schedule_update.is_active {
+                    schedule.is_active = is_active;
+                }
+                if let Some(next_run_at) = report_schedule_update.next_run_at {
+                    schedule.next_run_at = next_run_at;
+                }
+                schedule.last_run_at = report_schedule_update.last_run_at.or(schedule.last_run_at);
+                if let Some(modified_at) = report_schedule_update.modified_at {
+                    schedule.modified_at = modified_at;
+                }
+                schedule.clone()
+            })
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find report schedule for schedule_id = {schedule_id}"
+                ))
+                .into(),
+            )
+    }
+}
@@ -0,0 +1,310 @@
+use error_stack::IntoReport;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage::{self, enums},
+};
+
+#[async_trait::async_trait]
+pub trait SettlementRecordInterface {
+    async fn insert_settlement_record(
+        &self,
+        settlement_record: storage::SettlementRecordNew,
+    ) -> CustomResult<storage::SettlementRecord, errors::StorageError>;
+
+    async fn find_settlement_record_by_merchant_id_record_id(
+        &self,
+        merchant_id: &str,
+        record_id: &str,
+    ) -> CustomResult<storage::SettlementRecord, errors::StorageError>;
+
+    async fn find_settlement_record_by_merchant_id_connector_transaction_id(
+        &self,
+        merchant_id: &str,
+        connector: &str,
+        record_type: enums::SettlementRecordType,
+        connector_transaction_id: &str,
+    ) -> CustomResult<storage::SettlementRecord, errors::StorageError>;
+
+    async fn update_settlement_record_by_merchant_id_record_id(
+        &self,
+        merchant_id: &str,
+        record_id: &str,
+        settlement_record_update: storage::SettlementRecordUpdateInternal,
+    ) -> CustomResult<storage::SettlementRecord, errors::StorageError>;
+
+    async fn list_settlement_records_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        constraints: storage::SettlementRecordListConstraints,
+    ) -> CustomResult<Vec<storage::SettlementRecord>, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl SettlementRecordInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_settlement_record(
+        &self,
+        settlement_record: storage::SettlementRecordNew,
+    ) -> CustomResult<storage::SettlementRecord, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        settlement_record
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn find_settlement_record_by_merchant_id_record_id(
+        &self,
+        merchant_id: &str,
+        record_id: &str,
+    ) -> CustomResult<storage::SettlementRecord, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SettlementRecord::find_by_merchant_id_record_id(&conn, merchant_id, record_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn find_settlement_record_by_merchant_id_connector_transaction_id(
+        &self,
+        merchant_id: &str,
+        connector: &str,
+        record_type: enums::SettlementRecordType,
+        connector_transaction_id: &str,
+    ) -> CustomResult<storage::SettlementRecord, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SettlementRecord::find_by_merchant_id_connector_record_type_connector_transaction_id(
+            &conn,
+            merchant_id,
+            connector,
+            record_type,
+            connector_transaction_id,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn update_settlement_record_by_merchant_id_record_id(
+        &self,
+        merchant_id: &str,
+        record_id: &str,
+        settlement_record_update: storage::SettlementRecordUpdateInternal,
+    ) -> CustomResult<storage::SettlementRecord, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::SettlementRecord::update_by_merchant_id_record_id(
+            &conn,
+            merchant_id,
+            record_id,
+            settlement_record_update,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn list_settlement_records_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        constraints: storage::SettlementRecordListConstraints,
+    ) -> CustomResult<Vec<storage::SettlementRecord>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SettlementRecord::list_by_merchant_id_constraints(
+            &conn,
+            merchant_id,
+            constraints.report_id,
+            constraints.connector,
+            constraints.match_status,
+            constraints.limit,
+            constraints.offset,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl SettlementRecordInterface for MockDb {
+    async fn insert_settlement_record(
+        &self,
+        settlement_record: storage::SettlementRecordNew,
+    ) -> CustomResult<storage::SettlementRecord, errors::StorageError> {
+        let mut settlement_records = self.settlement_records.lock().await;
+        if settlement_records.iter().any(|record| {
+            record.merchant_id == settlement_record.merchant_id
+                && record.connector == settlement_record.connector
+                && record.record_type == settlement_record.record_type
+                && record.connector_transaction_id == settlement_record.connector_transaction_id
+        }) {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "connector_transaction_id",
+                key: Some(settlement_record.connector_transaction_id.clone()),
+            })?
+        }
+        let settlement_record = storage::SettlementRecord {
+            record_id: settlement_record.record_id,
+            merchant_id: settlement_record.merchant_id,
+            report_id: settlement_record.report_id,
+            connector: settlement_record.connector,
+            record_type: settlement_record.record_type,
+            connector_transaction_id: settlement_record.connector_transaction_id,
+            currency: settlement_record.currency,
+            gross_amount: settlement_record.gross_amount,
+            fee_amount: settlement_record.fee_amount,
+            net_amount: settlement_record.net_amount,
+            settled_at: settlement_record.settled_at,
+            payment_id: settlement_record.payment_id,
+            attempt_id: settlement_record.attempt_id,
+            refund_id: settlement_record.refund_id,
+            expected_amount: settlement_record.expected_amount,
+            match_status: settlement_record.match_status,
+            created_at: settlement_record.created_at,
+            modified_at: settlement_record.modified_at,
+        };
+        settlement_records.push(settlement_record.clone());
+        Ok(settlement_record)
+    }
+
+    async fn find_settlement_record_by_merchant_id_record_id(
+        &self,
+        merchant_id: &str,
+        record_id: &str,
+    ) -> CustomResult<storage::SettlementRecord, errors::StorageError> {
+        self.settlement_records
+            .lock()
+            .await
+            .iter()
+            .find(|record| record.merchant_id == merchant_id && record.record_id == record_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find settlement record for record_id = {record_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn find_settlement_record_by_merchant_id_connector_transaction_id(
+        &self,
+        merchant_id: &str,
+        connector: &str,
+        record_type: enums::SettlementRecordType,
+        connector_transaction_id: &str,
+    ) -> CustomResult<storage::SettlementRecord, errors::StorageError> {
+        self.settlement_records
+            .lock()
+            .await
+            .iter()
+            .find(|record| {
+                record.merchant_id == merchant_id
+                    && record.connector == connector
+                    && record.record_type == record_type
+                    && record.connector_transaction_id == connector_transaction_id
+            })
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find settlement record for connector_transaction_id = {connector_transaction_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn update_settlement_record_by_merchant_id_record_id(
+        &self,
+        merchant_id: &str,
+        record_id: &str,
+        settlement_record_update: storage::SettlementRecordUpdateInternal,
+    ) -> CustomResult<storage::SettlementRecord, errors::StorageError> {
+        let mut settlement_records = self.settlement_records.lock().await;
+        settlement_records
+            .iter_mut()
+            .find(|record| record.merchant_id == merchant_id && record.record_id == record_id)
+            .map(|record| {
+                record.report_id = settlement_record_update.report_id;
+                record.currency = settlement_record_update.currency;
+                record.gross_amount = settlement_record_update.gross_amount;
+                record.fee_amount = settlement_record_update.fee_amount;
+                record.net_amount = settlement_record_update.net_amount;
+                record.settled_at = settlement_record_update.settled_at.or(record.settled_at);
+                record.payment_id = settlement_record_update
+                    .payment_id
+                    .or(record.payment_id.take());
+                record.attempt_id = settlement_record_update
+                    .attempt_id
+                    .or(record.attempt_id.take());
+                record.refund_id = settlement_record_update
+                    .refund_id
+                    .or(record.refund_id.take());
+                record.expected_amount = settlement_record_update
+                    .expected_amount
+                    .or(record.expected_amount);
+                record.match_status = settlement_record_update.match_status;
+                record.modified_at = settlement_record_update.modified_at;
+                record.clone()
+            })
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find settlement record for record_id = {record_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_settlement_records_by_merchant_id_constraints(
+        &self,
+        merchant_id: &str,
+        constraints: storage::SettlementRecordListConstraints,
+    ) -> CustomResult<Vec<storage::SettlementRecord>, errors::StorageError> {
+        let mut settlement_records: Vec<storage::SettlementRecord> = self
+            .settlement_records
+            .lock()
+            .await
+            .iter()
+            .filter(|record| {
+                record.merchant_id == merchant_id
+                    && constraints
+                        .report_id
+                        .as_ref()
+                        .map_or(true, |report_id| &record.report_id == report_id)
+                    && constraints
+                        .connector
+                        .as_ref()
+                        .map_or(true, |connector| &record.connector == connector)
+                    && constraints
+                        .match_status
+                        .as_ref()
+                        .map_or(true, |match_status| {
+                            match_status.contains(&record.match_status)
+                        })
+            })
+            .cloned()
+            .collect();
+        settlement_records.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        let offset = constraints
+            .offset
+            .and_then(|offset| usize::try_from(offset).ok())
+            .unwrap_or(0);
+        let limit = constraints
+            .limit
+            .and_then(|limit| usize::try_from(limit).ok())
+            .unwrap_or(usize::MAX);
+
+        Ok(settlement_records
+            .into_iter()
+            .skip(offset)
+            .take(limit)
+            .collect())
+    }
+}
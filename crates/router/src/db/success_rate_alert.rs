@@ -0,0 +1,199 @@
+use error_stack::IntoReport;
+use router_env::{instrument, tracing};
+
+use super::{MockDb, Store};
+use crate::{
+    connection,
+    core::errors::{self, CustomResult},
+    types::storage,
+};
+
+#[async_trait::async_trait]
+pub trait SuccessRateAlertInterface {
+    async fn insert_success_rate_alert(
+        &self,
+        success_rate_alert: storage::SuccessRateAlertNew,
+    ) -> CustomResult<storage::SuccessRateAlert, errors::StorageError>;
+
+    async fn find_success_rate_alert_by_merchant_id_alert_id(
+        &self,
+        merchant_id: &str,
+        alert_id: &str,
+    ) -> CustomResult<storage::SuccessRateAlert, errors::StorageError>;
+
+    async fn list_active_success_rate_alerts_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::SuccessRateAlert>, errors::StorageError>;
+
+    async fn update_success_rate_alert_by_merchant_id_alert_id(
+        &self,
+        merchant_id: &str,
+        alert_id: &str,
+        success_rate_alert_update: storage::SuccessRateAlertUpdateInternal,
+    ) -> CustomResult<storage::SuccessRateAlert, errors::StorageError>;
+}
+
+#[async_trait::async_trait]
+impl SuccessRateAlertInterface for Store {
+    #[instrument(skip_all)]
+    async fn insert_success_rate_alert(
+        &self,
+        success_rate_alert: storage::SuccessRateAlertNew,
+    ) -> CustomResult<storage::SuccessRateAlert, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        success_rate_alert
+            .insert(&conn)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn find_success_rate_alert_by_merchant_id_alert_id(
+        &self,
+        merchant_id: &str,
+        alert_id: &str,
+    ) -> CustomResult<storage::SuccessRateAlert, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SuccessRateAlert::find_by_merchant_id_alert_id(&conn, merchant_id, alert_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn list_active_success_rate_alerts_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::SuccessRateAlert>, errors::StorageError> {
+        let conn = connection::pg_connection_read(self).await?;
+        storage::SuccessRateAlert::list_active_by_merchant_id(&conn, merchant_id)
+            .await
+            .map_err(Into::into)
+            .into_report()
+    }
+
+    #[instrument(skip_all)]
+    async fn update_success_rate_alert_by_merchant_id_alert_id(
+        &self,
+        merchant_id: &str,
+        alert_id: &str,
+        success_rate_alert_update: storage::SuccessRateAlertUpdateInternal,
+    ) -> CustomResult<storage::SuccessRateAlert, errors::StorageError> {
+        let conn = connection::pg_connection_write(self).await?;
+        storage::SuccessRateAlert::update_by_merchant_id_alert_id(
+            &conn,
+            merchant_id,
+            alert_id,
+            success_rate_alert_update,
+        )
+        .await
+        .map_err(Into::into)
+        .into_report()
+    }
+}
+
+#[async_trait::async_trait]
+impl SuccessRateAlertInterface for MockDb {
+    async fn insert_success_rate_alert(
+        &self,
+        success_rate_alert: storage::SuccessRateAlertNew,
+    ) -> CustomResult<storage::SuccessRateAlert, errors::StorageError> {
+        let mut success_rate_alerts = self.success_rate_alerts.lock().await;
+        if success_rate_alerts
+            .iter()
+            .any(|alert| alert.alert_id == success_rate_alert.alert_id)
+        {
+            Err(errors::StorageError::DuplicateValue {
+                entity: "alert_id",
+                key: Some(success_rate_alert.alert_id.clone()),
+            })?
+        }
+        let success_rate_alert = storage::SuccessRateAlert {
+            alert_id: success_rate_alert.alert_id,
+            merchant_id: success_rate_alert.merchant_id,
+            connector: success_rate_alert.connector,
+            evaluation_window_in_mins: success_rate_alert.evaluation_window_in_mins,
+            baseline_window_in_mins: success_rate_alert.baseline_window_in_mins,
+            drop_threshold: success_rate_alert.drop_threshold,
+            min_payment_count: success_rate_alert.min_payment_count,
+            email_recipients: success_rate_alert.email_recipients,
+            is_active: success_rate_alert.is_active,
+            triggered_drops: success_rate_alert.triggered_drops,
+            last_evaluated_at: success_rate_alert.last_evaluated_at,
+            created_at: success_rate_alert.created_at,
+            modified_at: success_rate_alert.modified_at,
+        };
+        success_rate_alerts.push(success_rate_alert.clone());
+        Ok(success_rate_alert)
+    }
+
+    async fn find_success_rate_alert_by_merchant_id_alert_id(
+        &self,
+        merchant_id: &str,
+        alert_id: &str,
+    ) -> CustomResult<storage::SuccessRateAlert, errors::StorageError> {
+        self.success_rate_alerts
+            .lock()
+            .await
+            .iter()
+            .find(|alert| alert.merchant_id == merchant_id && alert.alert_id == alert_id)
+            .cloned()
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find success rate alert for alert_id = {alert_id}"
+                ))
+                .into(),
+            )
+    }
+
+    async fn list_active_success_rate_alerts_by_merchant_id(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<storage::SuccessRateAlert>, errors::StorageError> {
+        let mut success_rate_alerts: Vec<storage::SuccessRateAlert> = self
+            .success_rate_alerts
+            .lock()
+            .await
+            .iter()
+            .filter(|alert| alert.merchant_id == merchant_id && alert.is_active)
+            .cloned()
+            .collect();
+        success_rate_alerts.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+        Ok(success_rate_alerts)
+    }
+
+    async fn update_success_rate_alert_by_merchant_id_alert_id(
+        &self,
+        merchant_id: &str,
+        alert_id: &str,
+        success_rate_alert_update: storage::SuccessRateAlertUpdateInternal,
+    ) -> CustomResult<storage::SuccessRateAlert, errors::StorageError> {
+        let mut success_rate_alerts = self.success_rate_alerts.lock().await;
+        success_rate_alerts
+            .iter_mut()
+            .find(|alert| alert.merchant_id == merchant_id && alert.alert_id == alert_id)
+            .map(|alert| {
+                if let Some(is_active) = success_rate_alert_update.is_active {
+                    alert.is_active = is_active;
+                }
+                if let Some(triggered_drops) = success_rate_alert_update.triggered_drops {
+                    alert.triggered_drops = triggered_drops;
+                }
+                alert.last_evaluated_at = success_rate_alert_update
+                    .last_evaluated_at
+                    .or(alert.last_evaluated_at);
+                if let Some(modified_at) = success_rate_alert_update.modified_at {
+                    alert.modified_at = modified_at;
+                }
+                alert.clone()
+            })
+            .ok_or(
+                errors::StorageError::ValueNotFound(format!(
+                    "cannot find success rate alert for alert_id = {alert_id}"
+                ))
+                .into(),
+            )
+    }
+}
@@ -3,10 +3,11 @@ use data_models::{
     payments::{payment_attempt::PaymentAttempt, payment_intent::PaymentIntentNew, PaymentIntent},
 };
 use diesel_models::{
+    dispute::Dispute,
     errors::DatabaseError,
     query::user::sample_data as sample_data_queries,
     refund::{Refund, RefundNew},
-    user::sample_data::PaymentAttemptBatchNew,
+    user::sample_data::{DisputeBatchNew, PaymentAttemptBatchNew},
 };
 use error_stack::{Report, ResultExt};
 use storage_impl::DataModelExt;
@@ -30,6 +31,11 @@ pub trait BatchSampleDataInterface {
         batch: Vec<RefundNew>,
     ) -> CustomResult<Vec<Refund>, StorageError>;
 
+    async fn insert_disputes_batch_for_sample_data(
+        &self,
+        batch: Vec<DisputeBatchNew>,
+    ) -> CustomResult<Vec<Dispute>, StorageError>;
+
     async fn delete_payment_intents_for_sample_data(
         &self,
         merchant_id: &str,
@@ -44,6 +50,11 @@ pub trait BatchSampleDataInterface {
         &self,
         merchant_id: &str,
     ) -> CustomResult<Vec<Refund>, StorageError>;
+
+    async fn delete_disputes_for_sample_data(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<Dispute>, StorageError>;
 }
 
 #[async_trait::async_trait]
@@ -94,6 +105,18 @@ impl BatchSampleDataInterface for Store {
             .map_err(diesel_error_to_data_error)
     }
 
+    async fn insert_disputes_batch_for_sample_data(
+        &self,
+        batch: Vec<DisputeBatchNew>,
+    ) -> CustomResult<Vec<Dispute>, StorageError> {
+        let conn = pg_connection_write(self)
+            .await
+            .change_context(StorageError::DatabaseConnectionError)?;
+        sample_data_queries::insert_disputes(&conn, batch)
+            .await
+            .map_err(diesel_error_to_data_error)
+    }
+
     async fn delete_payment_intents_for_sample_data(
         &self,
         merchant_id: &str,
@@ -138,6 +161,18 @@ impl BatchSampleDataInterface for Store {
             .await
             .map_err(diesel_error_to_data_error)
     }
+
+    async fn delete_disputes_for_sample_data(
+        &self,
+        merchant_id: &str,
+    ) -> CustomResult<Vec<Dispute>, StorageError> {
+        let conn = pg_connection_write(self)
+            .await
+            .change_context(StorageError::DatabaseConnectionError)?;
+        sample_data_queries::delete_disputes(&conn, merchant_id)
+            .await
+            .map_err(diesel_error_to_data_error)
+    }
 }
 
 #[async_trait::async_trait]
@@ -163,6 +198,13 @@ impl BatchSampleDataInterface for storage_impl::MockDb {
         Err(StorageError::MockDbError)?
     }
 
+    async fn insert_disputes_batch_for_sample_data(
+        &self,
+        _batch: Vec<DisputeBatchNew>,
+    ) -> CustomResult<Vec<Dispute>, StorageError> {
+        Err(StorageError::MockDbError)?
+    }
+
     async fn delete_payment_intents_for_sample_data(
         &self,
         _merchant_id: &str,
@@ -181,6 +223,12 @@ impl BatchSampleDataInterface for storage_impl::MockDb {
     ) -> CustomResult<Vec<Refund>, StorageError> {
         Err(StorageError::MockDbError)?
     }
+    async fn delete_disputes_for_sample_data(
+        &self,
+        _merchant_id: &str,
+    ) -> CustomResult<Vec<Dispute>, StorageError> {
+        Err(StorageError::MockDbError)?
+    }
 }
 
 // TODO: This error conversion is re-used from storage_impl and is not DRY when it should be
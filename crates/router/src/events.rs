@@ -4,16 +4,19 @@ use router_env::logger;
 use serde::{Deserialize, Serialize};
 use storage_impl::errors::ApplicationError;
 
+use self::clickhouse::{ClickhouseEventWriter, ClickhouseEventsSettings};
 use crate::{
-    db::KafkaProducer,
-    services::kafka::{KafkaMessage, KafkaSettings},
+    db::{KafkaError, KafkaProducer, MQResult},
+    services::kafka::{KafkaMessage, KafkaSettings, StorageEventProducer},
 };
 
 pub mod api_logs;
 pub mod audit_events;
+pub mod clickhouse;
 pub mod connector_api_logs;
 pub mod event_logger;
 pub mod outgoing_webhook_logs;
+pub mod sdk_events;
 
 #[derive(Debug, Serialize, Clone, Copy)]
 #[serde(rename_all = "snake_case")]
@@ -26,6 +29,7 @@ pub enum EventType {
     OutgoingWebhookLogs,
     Dispute,
     AuditEvent,
+    SdkEvent,
 }
 
 #[derive(Debug, Default, Deserialize, Clone)]
@@ -35,6 +39,9 @@ pub enum EventsConfig {
     Kafka {
         kafka: Box<KafkaSettings>,
     },
+    Clickhouse {
+        clickhouse: Box<ClickhouseEventsSettings>,
+    },
     #[default]
     Logs,
 }
@@ -42,6 +49,7 @@ pub enum EventsConfig {
 #[derive(Debug, Clone)]
 pub enum EventsHandler {
     Kafka(KafkaProducer),
+    Clickhouse(ClickhouseEventWriter),
     Logs(event_logger::EventLogger),
 }
 
@@ -59,6 +67,9 @@ impl EventsConfig {
                     .await
                     .change_context(StorageError::InitializationError)?,
             ),
+            Self::Clickhouse { clickhouse } => {
+                EventsHandler::Clickhouse(ClickhouseEventWriter::start(clickhouse))
+            }
             Self::Logs => EventsHandler::Logs(event_logger::EventLogger::default()),
         })
     }
@@ -66,6 +77,7 @@ impl EventsConfig {
     pub fn validate(&self) -> Result<(), ApplicationError> {
         match self {
             Self::Kafka { kafka } => kafka.validate(),
+            Self::Clickhouse { clickhouse } => clickhouse.validate(),
             Self::Logs => Ok(()),
         }
     }
@@ -77,7 +89,26 @@ impl EventsHandler {
             Self::Kafka(kafka) => kafka.log_event(event).map_or((), |e| {
                 logger::error!("Failed to log event: {:?}", e);
             }),
+            Self::Clickhouse(clickhouse) => clickhouse.log_event(event).map_or((), |e| {
+                logger::error!("Failed to log event: {:?}", e);
+            }),
             Self::Logs(logger) => logger.log_event(event),
         };
     }
 }
+
+#[async_trait::async_trait]
+impl StorageEventProducer for EventsHandler {
+    fn produce_event<T: KafkaMessage>(&self, event: &T) -> MQResult<()> {
+        match self {
+            Self::Kafka(kafka) => kafka.log_event(event),
+            Self::Clickhouse(clickhouse) => clickhouse
+                .log_event(event)
+                .change_context(KafkaError::GenericError),
+            Self::Logs(logger) => {
+                logger.log_event(event);
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,358 @@
+//! Delivery of the analytics events of payments, refunds and SDKs straight to ClickHouse.
+//!
+//! Events are queued on a bounded channel and inserted in batches per table by a background task,
+//! using the asynchronous inserts of ClickHouse. The request path never waits on ClickHouse: when
+//! the inserts fall behind and the queue fills up, events which cannot be queued are dropped and
+//! counted, instead of holding up the requests logging them.
+
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use common_utils::errors::CustomResult;
+use error_stack::{report, IntoReport, ResultExt};
+use masking::{PeekInterface, Secret};
+use router_env::logger;
+use serde::Deserialize;
+use storage_impl::errors::ApplicationError;
+use tokio::sync::mpsc;
+
+use super::{event_logger::EventLogger, EventType};
+use crate::{routes::metrics, services::kafka::KafkaMessage};
+
+/// Delay before the first retry of a failed insert, doubled on every subsequent retry
+const INITIAL_RETRY_DELAY: Duration = Duration::from_millis(100);
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct ClickhouseEventsSettings {
+    /// URL of the HTTP interface of ClickHouse
+    pub host: String,
+    pub username: String,
+    pub password: Option<Secret<String>>,
+    pub database: String,
+    pub payment_intent_table: String,
+    pub payment_attempt_table: String,
+    pub refund_table: String,
+    pub sdk_event_table: String,
+    /// Maximum number of rows inserted in a single insert
+    pub batch_size: usize,
+    /// Interval at which the rows queued so far are inserted, even if the batch is not full
+    pub flush_interval_in_ms: u64,
+    /// Maximum number of events waiting to be inserted, events are dropped once the queue is full
+    pub queue_size: usize,
+    /// Number of times a failed insert is retried before its rows are dropped
+    pub max_insert_retries: u32,
+}
+
+impl Default for ClickhouseEventsSettings {
+    fn default() -> Self {
+        Self {
+            host: "http://localhost:8123".to_string(),
+            username: "default".to_string(),
+            password: None,
+            database: "default".to_string(),
+            payment_intent_table: "payment_intents_dist".to_string(),
+            payment_attempt_table: "payment_attempt_dist".to_string(),
+            refund_table: "refund_dist".to_string(),
+            sdk_event_table: "sdk_events_audit".to_string(),
+            batch_size: 1000,
+            flush_interval_in_ms: 1000,
+            queue_size: 100000,
+            max_insert_retries: 3,
+        }
+    }
+}
+
+impl ClickhouseEventsSettings {
+    pub fn validate(&self) -> Result<(), ApplicationError> {
+        use common_utils::{ext_traits::ConfigExt, fp_utils::when};
+
+        when(self.host.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "ClickHouse events host must not be empty".into(),
+            ))
+        })?;
+
+        when(
+            self.payment_intent_table.is_default_or_empty()
+                || self.payment_attempt_table.is_default_or_empty()
+                || self.refund_table.is_default_or_empty()
+                || self.sdk_event_table.is_default_or_empty(),
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "ClickHouse events tables must not be empty".into(),
+                ))
+            },
+        )?;
+
+        when(
+            self.batch_size == 0 || self.queue_size == 0 || self.flush_interval_in_ms == 0,
+            || {
+                Err(ApplicationError::InvalidConfigurationValueError(
+                    "ClickHouse events batch_size, queue_size and flush_interval_in_ms must be \
+                     greater than 0"
+                        .into(),
+                ))
+            },
+        )
+    }
+
+    /// Table the events of the given type are inserted into, events of the other types are only
+    /// logged
+    fn get_table(&self, event_type: EventType) -> Option<&str> {
+        match event_type {
+            EventType::PaymentIntent => Some(&self.payment_intent_table),
+            EventType::PaymentAttempt => Some(&self.payment_attempt_table),
+            EventType::Refund => Some(&self.refund_table),
+            EventType::SdkEvent => Some(&self.sdk_event_table),
+            EventType::ApiLogs
+            | EventType::ConnectorApiLogs
+            | EventType::OutgoingWebhookLogs
+            | EventType::Dispute
+            | EventType::AuditEvent => None,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum ClickhouseEventsError {
+    #[error("Failed to serialize the event")]
+    SerializationFailed,
+    #[error("The queue of events to be inserted is full")]
+    QueueFull,
+    #[error("The ClickHouse event writer has stopped")]
+    WriterStopped,
+    #[error("Failed to insert the events into ClickHouse")]
+    InsertFailed,
+}
+
+#[derive(Debug)]
+struct QueuedEvent {
+    table: String,
+    row: Vec<u8>,
+}
+
+/// Writer inserting the analytics events into ClickHouse
+#[derive(Clone, Debug)]
+pub struct ClickhouseEventWriter {
+    config: Arc<ClickhouseEventsSettings>,
+    sender: mpsc::Sender<QueuedEvent>,
+}
+
+impl ClickhouseEventWriter {
+    /// Creates the writer and spawns its inserter task, this must be called within a tokio
+    /// runtime
+    pub fn start(conf: &ClickhouseEventsSettings) -> Self {
+        let config = Arc::new(conf.clone());
+        let (sender, receiver) = mpsc::channel(config.queue_size);
+        tokio::spawn(run_inserter(
+            config.clone(),
+            reqwest::Client::new(),
+            receiver,
+        ));
+        Self { config, sender }
+    }
+
+    /// Queues the event to be inserted, this never waits for space in the queue
+    pub fn log_event<T: KafkaMessage>(&self, event: &T) -> CustomResult<(), ClickhouseEventsError> {
+        let Some(table) = self.config.get_table(event.event_type()) else {
+            EventLogger::default().log_event(event);
+            return Ok(());
+        };
+        let row = event
+            .value()
+            .change_context(ClickhouseEventsError::SerializationFailed)?;
+
+        self.sender
+            .try_send(QueuedEvent {
+                table: table.to_owned(),
+                row,
+            })
+            .map_err(|error| {
+                metrics::CLICKHOUSE_EVENTS_DROPPED_COUNT.add(
+                    &metrics::CONTEXT,
+                    1,
+                    &[metrics::request::add_attributes("table", table.to_owned())],
+                );
+                match error {
+                    mpsc::error::TrySendError::Full(_) => report!(ClickhouseEventsError::QueueFull),
+                    mpsc::error::TrySendError::Closed(_) => {
+                        report!(ClickhouseEventsError::WriterStopped)
+                    }
+                }
+            })
+    }
+}
+
+/// Rows waiting to be inserted, grouped by table
+#[derive(Debug, Default)]
+struct EventBatches {
+    rows: HashMap<String, Vec<Vec<u8>>>,
+}
+
+impl EventBatches {
+    /// Adds the row to the batch of its table, the batch is returned once it is full
+    fn push(&mut self, event: QueuedEvent, batch_size: usize) -> Option<(String, Vec<Vec<u8>>)> {
+        let batch = self.rows.entry(event.table.clone()).or_default();
+        batch.push(event.row);
+        if batch.len() < batch_size {
+            return None;
+        }
+        self.rows.remove_entry(&event.table)
+    }
+
+    /// Takes the batches of all the tables having rows to be inserted
+    fn take_all(&mut self) -> Vec<(String, Vec<Vec<u8>>)> {
+        self.rows
+            .drain()
+            .filter(|(_, rows)| !rows.is_empty())
+            .collect()
+    }
+}
+
+/// Inserts the queued events until all the writers are dropped. Inserts are run one at a time,
+/// events keep getting queued while an insert is in flight and are dropped by the writers once
+/// the queue is full.
+async fn run_inserter(
+    config: Arc<ClickhouseEventsSettings>,
+    client: reqwest::Client,
+    mut receiver: mpsc::Receiver<QueuedEvent>,
+) {
+    let mut batches = EventBatches::default();
+    let mut flush_interval =
+        tokio::time::interval(Duration::from_millis(config.flush_interval_in_ms));
+    flush_interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
+    loop {
+        tokio::select! {
+            event = receiver.recv() => {
+                let Some(event) = event else {
+                    break;
+                };
+                if let Some((table, rows)) = batches.push(event, config.batch_size) {
+                    insert_batch(&config, &client, &table, rows).await;
+                }
+            }
+            _ = flush_interval.tick() => {
+                for (table, rows) in batches.take_all() {
+                    insert_batch(&config, &client, &table, rows).await;
+                }
+            }
+        }
+    }
+
+    // The writers are dropped when the application shuts down, insert whatever is left
+    for (table, rows) in batches.take_all() {
+        insert_batch(&config, &client, &table, rows).await;
+    }
+}
+
+/// Inserts the rows into the table, retrying with an exponential backoff. The rows are dropped if
+/// the insert still fails after the configured number of retries.
+async fn insert_batch(
+    config: &ClickhouseEventsSettings,
+    client: &reqwest::Client,
+    table: &str,
+    rows: Vec<Vec<u8>>,
+) {
+    let row_count = u64::try_from(rows.len()).unwrap_or(u64::MAX);
+    let attributes = &[metrics::request::add_attributes("table", table.to_owned())];
+    let body = rows.join(&b'\n');
+    let mut retry_delay = INITIAL_RETRY_DELAY;
+
+    for attempt in 0..=config.max_insert_retries {
+        if attempt > 0 {
+            tokio::time::sleep(retry_delay).await;
+            retry_delay = retry_delay.saturating_mul(2);
+        }
+
+        match execute_insert(config, client, table, body.clone()).await {
+            Ok(()) => {
+                metrics::CLICKHOUSE_EVENTS_INSERTED_COUNT.add(
+                    &metrics::CONTEXT,
+                    row_count,
+                    attributes,
+                );
+                return;
+            }
+            Err(error) => {
+                logger::warn!(?error, %table, attempt, "Failed to insert events into ClickHouse");
+            }
+        }
+    }
+
+    logger::error!(%table, row_count, "Dropping events which could not be inserted into ClickHouse");
+    metrics::CLICKHOUSE_EVENTS_DROPPED_COUNT.add(&metrics::CONTEXT, row_count, attributes);
+}
+
+async fn execute_insert(
+    config: &ClickhouseEventsSettings,
+    client: &reqwest::Client,
+    table: &str,
+    body: Vec<u8>,
+) -> CustomResult<(), ClickhouseEventsError> {
+    let query = format!("INSERT INTO {table} FORMAT JSONEachRow");
+    let response = client
+        .post(&config.host)
+        // Waiting for the asynchronous insert to be flushed surfaces its failures, so that the
+        // batch can be retried
+        .query(&[
+            ("database", config.database.as_str()),
+            ("query", query.as_str()),
+            ("async_insert", "1"),
+            ("wait_for_async_insert", "1"),
+            ("date_time_input_format", "best_effort"),
+        ])
+        .basic_auth(
+            &config.username,
+            config.password.as_ref().map(|password| password.peek()),
+        )
+        .body(body)
+        .send()
+        .await
+        .into_report()
+        .change_context(ClickhouseEventsError::InsertFailed)?;
+
+    let status = response.status();
+    if status.is_success() {
+        return Ok(());
+    }
+    let response_body = response.text().await.unwrap_or_default();
+    Err(
+        report!(ClickhouseEventsError::InsertFailed).attach_printable(format!(
+            "ClickHouse responded with {status}: {response_body}"
+        )),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EventBatches, QueuedEvent};
+
+    fn event(table: &str, row: &str) -> QueuedEvent {
+        QueuedEvent {
+            table: table.to_string(),
+            row: row.as_bytes().to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_batches_are_returned_once_full() {
+        let mut batches = EventBatches::default();
+
+        assert_eq!(batches.push(event("refund_dist", "{}"), 2), None);
+        assert_eq!(batches.push(event("payment_attempt_dist", "{}"), 2), None);
+        assert_eq!(
+            batches.push(event("refund_dist", "{\"a\":1}"), 2),
+            Some((
+                "refund_dist".to_string(),
+                vec![b"{}".to_vec(), b"{\"a\":1}".to_vec()]
+            ))
+        );
+
+        assert_eq!(
+            batches.take_all(),
+            vec![("payment_attempt_dist".to_string(), vec![b"{}".to_vec()])]
+        );
+        assert!(batches.take_all().is_empty());
+    }
+}
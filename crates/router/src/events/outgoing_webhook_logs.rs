@@ -43,6 +43,24 @@ pub enum OutgoingWebhookEventContent {
         mandate_id: String,
         content: Value,
     },
+    ConnectorHealth {
+        connector: String,
+        content: Value,
+    },
+    Authentication {
+        payment_id: Option<String>,
+        authentication_id: String,
+        content: Value,
+    },
+    PaymentMethod {
+        payment_method_id: String,
+        content: Value,
+    },
+    SuccessRateDrop {
+        alert_id: String,
+        connector: String,
+        content: Value,
+    },
 }
 pub trait OutgoingWebhookEventMetric {
     fn get_outgoing_webhook_event_content(&self) -> Option<OutgoingWebhookEventContent>;
@@ -74,6 +92,36 @@ impl OutgoingWebhookEventMetric for OutgoingWebhookContent {
                 content: masking::masked_serialize(&mandate_payload)
                     .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
             }),
+            Self::ConnectorHealthDetails(health_payload) => {
+                Some(OutgoingWebhookEventContent::ConnectorHealth {
+                    connector: health_payload.connector_name.clone(),
+                    content: masking::masked_serialize(&health_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
+            Self::AuthenticationDetails(authentication_payload) => {
+                Some(OutgoingWebhookEventContent::Authentication {
+                    payment_id: authentication_payload.payment_id.clone(),
+                    authentication_id: authentication_payload.authentication_id.clone(),
+                    content: masking::masked_serialize(&authentication_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
+            Self::PaymentMethodDetails(payment_method_payload) => {
+                Some(OutgoingWebhookEventContent::PaymentMethod {
+                    payment_method_id: payment_method_payload.payment_method_id.clone(),
+                    content: masking::masked_serialize(&payment_method_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
+            Self::SuccessRateDropDetails(success_rate_drop_payload) => {
+                Some(OutgoingWebhookEventContent::SuccessRateDrop {
+                    alert_id: success_rate_drop_payload.alert_id.clone(),
+                    connector: success_rate_drop_payload.connector.clone(),
+                    content: masking::masked_serialize(&success_rate_drop_payload)
+                        .unwrap_or(serde_json::json!({"error":"failed to serialize"})),
+                })
+            }
         }
     }
 }
@@ -0,0 +1,76 @@
+use api_models::analytics::sdk_events::{
+    SdkEventNames, SdkTelemetryEvent, SdkTelemetryEventsRequest,
+};
+use serde::Serialize;
+use time::OffsetDateTime;
+
+use super::EventType;
+use crate::services::kafka::KafkaMessage;
+
+/// Checkout telemetry event raised by the SDK, in the shape of the SDK events of the analytics
+/// store
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct SdkEvent {
+    /// Publishable key of the merchant, SDK events are looked up by the publishable key
+    merchant_id: String,
+    payment_id: String,
+    sdk_session_id: String,
+    event_name: SdkEventNames,
+    log_type: &'static str,
+    platform: Option<String>,
+    browser_name: Option<String>,
+    browser_version: Option<String>,
+    source: Option<String>,
+    component: Option<String>,
+    version: Option<String>,
+    payment_method: Option<String>,
+    payment_experience: Option<String>,
+    latency: Option<u64>,
+    value: Option<String>,
+    /// Time at which the event was raised as reported by the SDK, in milliseconds
+    created_at_precise: i128,
+    /// Time at which the event was received. Time ranges are applied on the time of receipt, so
+    /// that events of devices with skewed clocks are not lost.
+    #[serde(with = "time::serde::timestamp")]
+    created_at: OffsetDateTime,
+}
+
+impl SdkEvent {
+    pub fn new(
+        publishable_key: String,
+        request: &SdkTelemetryEventsRequest,
+        event: SdkTelemetryEvent,
+        received_at: OffsetDateTime,
+    ) -> Self {
+        Self {
+            merchant_id: publishable_key,
+            payment_id: request.payment_id.clone(),
+            sdk_session_id: request.sdk_session_id.clone(),
+            event_name: event.event_name,
+            log_type: "INFO",
+            platform: request.platform.clone(),
+            browser_name: request.browser_name.clone(),
+            browser_version: request.browser_version.clone(),
+            source: request.source.clone(),
+            component: request.component.clone(),
+            version: request.version.clone(),
+            payment_method: event.payment_method,
+            payment_experience: event.payment_experience,
+            latency: event.latency,
+            value: event.value,
+            created_at_precise: event.timestamp.assume_utc().unix_timestamp_nanos() / 1_000_000,
+            created_at: received_at,
+        }
+    }
+}
+
+impl KafkaMessage for SdkEvent {
+    fn event_type(&self) -> EventType {
+        EventType::SdkEvent
+    }
+
+    fn key(&self) -> String {
+        format!("{}_{}", self.payment_id, self.sdk_session_id)
+    }
+}
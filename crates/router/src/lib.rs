@@ -68,7 +68,12 @@ pub mod headers {
     pub const X_ACCEPT_VERSION: &str = "X-Accept-Version";
     pub const X_DATE: &str = "X-Date";
     pub const X_WEBHOOK_SIGNATURE: &str = "X-Webhook-Signature-512";
+    pub const X_WEBHOOK_SIGNATURE_256: &str = "X-Webhook-Signature-256";
+    pub const HYPERSWITCH_VERSION: &str = "Hyperswitch-Version";
+    pub const DEPRECATION: &str = "Deprecation";
+    pub const WARNING: &str = "Warning";
     pub const X_REQUEST_ID: &str = "X-Request-Id";
+    pub const X_REQUEST_TIMEOUT: &str = "X-Request-Timeout";
     pub const STRIPE_COMPATIBLE_WEBHOOK_SIGNATURE: &str = "Stripe-Signature";
 }
 
@@ -116,12 +121,15 @@ pub fn mk_app(
             .service(routes::Refunds::server(state.clone()))
             .service(routes::MerchantConnectorAccount::server(state.clone()))
             .service(routes::Mandates::server(state.clone()))
+            .service(routes::RecurringPayments::server(state.clone()))
+            .service(routes::BulkCharges::server(state.clone()))
     }
 
     #[cfg(feature = "oltp")]
     {
         server_app = server_app
             .service(routes::EphemeralKey::server(state.clone()))
+            .service(routes::ClientSessions::server(state.clone()))
             .service(routes::Webhooks::server(state.clone()))
             .service(routes::PaymentMethods::server(state.clone()))
     }
@@ -131,11 +139,13 @@ pub fn mk_app(
         server_app = server_app
             .service(routes::MerchantAccount::server(state.clone()))
             .service(routes::ApiKeys::server(state.clone()))
+            .service(routes::AuditLogs::server(state.clone()))
             .service(routes::Files::server(state.clone()))
             .service(routes::Disputes::server(state.clone()))
             .service(routes::Analytics::server(state.clone()))
             .service(routes::Routing::server(state.clone()))
             .service(routes::Blocklist::server(state.clone()))
+            .service(routes::VelocityRules::server(state.clone()))
             .service(routes::Gsm::server(state.clone()))
             .service(routes::PaymentLink::server(state.clone()))
             .service(routes::User::server(state.clone()))
@@ -161,6 +171,7 @@ pub fn mk_app(
 
     server_app = server_app.service(routes::Cards::server(state.clone()));
     server_app = server_app.service(routes::Cache::server(state.clone()));
+    server_app = server_app.service(routes::LogFilter::server(state.clone()));
     server_app = server_app.service(routes::Health::server(state));
 
     server_app
@@ -253,6 +264,8 @@ pub fn get_application_builder(
             StatusCode::METHOD_NOT_ALLOWED,
             errors::error_handlers::custom_error_handlers,
         ))
+        // this middleware translates request bodies only for Http1.1 requests
+        .wrap(middleware::ApiVersioning)
         .wrap(middleware::default_response_headers())
         .wrap(middleware::RequestId)
         .wrap(cors::cors(cors))
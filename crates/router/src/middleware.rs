@@ -3,6 +3,9 @@ use router_env::{
     logger,
     tracing::{field::Empty, Instrument},
 };
+
+use crate::services::api::versioning;
+
 /// Middleware to include request ID in response header.
 pub struct RequestId;
 
@@ -137,6 +140,8 @@ where
 
     // TODO: have a common source of truth for the list of top level fields
     // /crates/router_env/src/logger/storage.rs also has a list of fields  called PERSISTENT_KEYS
+    // The fields making up the context of the request are declared so that they are exported along
+    // with the traces, see `router_env::logger::request_context`
     fn call(&self, req: actix_web::dev::ServiceRequest) -> Self::Future {
         let response_fut = self.service.call(req);
 
@@ -150,6 +155,7 @@ where
                 router_env::tracing::info_span!(
                     "ROOT_SPAN",
                     payment_id = Empty,
+                    attempt_id = Empty,
                     merchant_id = Empty,
                     connector_name = Empty,
                     payment_method = Empty,
@@ -284,3 +290,103 @@ where
         })
     }
 }
+
+/// Middleware negotiating the version of the API used by the client. The bodies of requests made
+/// with an older version are translated into the shape of the current version, and the responses
+/// to them carry deprecation warnings.
+pub struct ApiVersioning;
+
+impl<S: 'static, B> actix_web::dev::Transform<S, actix_web::dev::ServiceRequest> for ApiVersioning
+where
+    S: actix_web::dev::Service<
+        actix_web::dev::ServiceRequest,
+        Response = actix_web::dev::ServiceResponse<B>,
+        Error = actix_web::Error,
+    >,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Transform = ApiVersioningMiddleware<S>;
+    type InitError = ();
+    type Future = std::future::Ready<Result<Self::Transform, Self::InitError>>;
+
+    fn new_transform(&self, service: S) -> Self::Future {
+        std::future::ready(Ok(ApiVersioningMiddleware {
+            service: std::rc::Rc::new(service),
+        }))
+    }
+}
+
+pub struct ApiVersioningMiddleware<S> {
+    service: std::rc::Rc<S>,
+}
+
+impl<S, B> actix_web::dev::Service<actix_web::dev::ServiceRequest> for ApiVersioningMiddleware<S>
+where
+    S: actix_web::dev::Service<
+            actix_web::dev::ServiceRequest,
+            Response = actix_web::dev::ServiceResponse<B>,
+            Error = actix_web::Error,
+        > + 'static,
+    S::Future: 'static,
+    B: 'static,
+{
+    type Response = actix_web::dev::ServiceResponse<B>;
+    type Error = actix_web::Error;
+    type Future = futures::future::LocalBoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    actix_web::dev::forward_ready!(service);
+
+    fn call(&self, req: actix_web::dev::ServiceRequest) -> Self::Future {
+        let svc = self.service.clone();
+        Box::pin(async move {
+            let version = versioning::get_api_version(req.headers())?;
+            let route = req.match_pattern();
+            let req = match route {
+                Some(route)
+                    if versioning::requires_transformation(version, req.method(), &route) =>
+                {
+                    let (http_req, payload) = req.into_parts();
+                    let body = payload
+                        .collect::<Vec<Result<bytes::Bytes, actix_web::error::PayloadError>>>()
+                        .await
+                        .into_iter()
+                        .collect::<Result<Vec<bytes::Bytes>, actix_web::error::PayloadError>>()?
+                        .concat();
+                    let body = versioning::transform_request_body(
+                        version,
+                        http_req.method(),
+                        &route,
+                        &body,
+                    )
+                    .unwrap_or(body);
+                    logger::debug!(api_version = %version, %route, "Translated request body");
+
+                    let content_length = http::HeaderValue::from(body.len());
+                    // we are creating h1 payload manually from bytes, currently there's no way to create http2 payload with actix
+                    let (_, mut new_payload) = actix_http::h1::Payload::create(true);
+                    new_payload.unread_data(body.into());
+                    let mut new_req =
+                        actix_web::dev::ServiceRequest::from_parts(http_req, new_payload.into());
+                    new_req
+                        .headers_mut()
+                        .insert(http::header::CONTENT_LENGTH, content_length);
+                    new_req
+                }
+                _ => req,
+            };
+
+            let mut response = svc.call(req).await?;
+            for (name, value) in versioning::get_deprecation_headers(version) {
+                response.headers_mut().insert(
+                    http::header::HeaderName::from_bytes(name.as_bytes())
+                        .map_err(actix_web::error::ErrorInternalServerError)?,
+                    http::HeaderValue::from_str(&value)?,
+                );
+            }
+            Ok(response)
+        })
+    }
+}
@@ -2,9 +2,14 @@ pub mod admin;
 pub mod api_keys;
 pub mod app;
 #[cfg(feature = "olap")]
+pub mod audit_logs;
+#[cfg(feature = "olap")]
 pub mod blocklist;
+pub mod bulk_charges;
 pub mod cache;
 pub mod cards_info;
+#[cfg(feature = "oltp")]
+pub mod client_sessions;
 pub mod configs;
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
@@ -22,6 +27,7 @@ pub mod gsm;
 pub mod health;
 pub mod lock_utils;
 pub mod locker_migration;
+pub mod log_filter;
 pub mod mandates;
 pub mod metrics;
 pub mod payment_link;
@@ -33,6 +39,7 @@ pub mod payouts;
 pub mod pm_auth;
 #[cfg(feature = "recon")]
 pub mod recon;
+pub mod recurring_payments;
 pub mod refunds;
 #[cfg(feature = "olap")]
 pub mod routing;
@@ -41,6 +48,8 @@ pub mod user;
 #[cfg(feature = "olap")]
 pub mod user_role;
 #[cfg(feature = "olap")]
+pub mod velocity_rules;
+#[cfg(feature = "olap")]
 pub mod verification;
 #[cfg(feature = "olap")]
 pub mod verify_connector;
@@ -57,12 +66,13 @@ pub use self::app::Payouts;
 #[cfg(all(feature = "olap", feature = "recon"))]
 pub use self::app::Recon;
 pub use self::app::{
-    ApiKeys, AppState, BusinessProfile, Cache, Cards, Configs, ConnectorOnboarding, Customers,
-    Disputes, EphemeralKey, Files, Gsm, Health, Mandates, MerchantAccount,
-    MerchantConnectorAccount, PaymentLink, PaymentMethods, Payments, Refunds, User, Webhooks,
+    ApiKeys, AppState, AuditLogs, BulkCharges, BusinessProfile, Cache, Cards, ClientSessions,
+    Configs, ConnectorOnboarding, Customers, Disputes, EphemeralKey, Files, Gsm, Health, LogFilter,
+    Mandates, MerchantAccount, MerchantConnectorAccount, PaymentLink, PaymentMethods, Payments,
+    RecurringPayments, Refunds, User, Webhooks,
 };
 #[cfg(feature = "olap")]
-pub use self::app::{Blocklist, Routing, Verify, WebhookEvents};
+pub use self::app::{Blocklist, Routing, VelocityRules, Verify, WebhookEvents};
 #[cfg(feature = "stripe")]
 pub use super::compatibility::stripe::StripeApis;
 #[cfg(feature = "olap")]
@@ -3,7 +3,14 @@ use router_env::{instrument, tracing, Flow};
 
 use super::app::AppState;
 use crate::{
-    core::{admin::*, api_locking},
+    core::{
+        account_templates,
+        admin::*,
+        api_locking,
+        payment_methods::{card_fingerprint, token_requestor},
+        webhooks::signing,
+    },
+    routes::lock_utils,
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::admin,
 };
@@ -155,7 +162,7 @@ pub async fn update_merchant_account(
 
 /// Merchant Account - Delete
 ///
-/// To delete a merchant account
+/// To delete a merchant account. The merchant account can be restored until the end of the retention period, after which it is permanently deleted
 #[utoipa::path(
     delete,
     path = "/accounts/{account_id}",
@@ -190,6 +197,394 @@ pub async fn delete_merchant_account(
     )
     .await
 }
+
+/// Merchant Account - Restore
+///
+/// To restore a deleted merchant account before the end of its retention period
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/restore",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Merchant Account Restored", body = MerchantAccountResponse),
+        (status = 400, description = "Merchant account is not deleted"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Restore a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantsAccountRestore))]
+pub async fn restore_merchant_account(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    mid: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::MerchantsAccountRestore;
+    let mid = mid.into_inner();
+
+    let payload = web::Json(admin::MerchantId { merchant_id: mid }).into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req| merchant_account_restore(state, req.merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Merchant Account - Export
+///
+/// To export the data of a merchant account, along with its business profiles, connector accounts and API keys
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/export",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Merchant Account Data Exported", body = MerchantAccountDataExport),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Export a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantsAccountDataExport))]
+pub async fn export_merchant_account(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    mid: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::MerchantsAccountDataExport;
+    let mid = mid.into_inner();
+
+    let payload = web::Json(admin::MerchantId { merchant_id: mid }).into_inner();
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req| merchant_account_data_export(state, req.merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Merchant Account - Rotate Card Fingerprint Key
+///
+/// Rotate the key used for generating the card fingerprints of a merchant account. Card fingerprints generated with the previous key continue to be matched for saved cards and blocklists until the end of the transition period.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/card_fingerprint_key/rotate",
+    request_body = CardFingerprintKeyRotateRequest,
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Card Fingerprint Key Rotated", body = CardFingerprintKeyResponse),
+        (status = 400, description = "Transition period of the previous key has not ended"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Rotate the Card Fingerprint Key of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantsAccountCardFingerprintKeyRotate))]
+pub async fn rotate_card_fingerprint_key(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    mid: web::Path<String>,
+    json_payload: web::Json<admin::CardFingerprintKeyRotateRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantsAccountCardFingerprintKeyRotate;
+    let merchant_id = mid.into_inner();
+    // Concurrent rotations would otherwise both read the current key version and bump it once
+    let lock_action = api_locking::LockAction::Hold {
+        input: api_locking::LockingInput {
+            unique_locking_key: merchant_id.clone(),
+            api_identifier: lock_utils::ApiIdentifier::from(flow.clone()),
+            override_lock_retries: None,
+        },
+    };
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| card_fingerprint::rotate_card_fingerprint_key(state, &merchant_id, req),
+        &auth::AdminApiAuth,
+        lock_action,
+    )
+    .await
+}
+
+/// Merchant Account - Create Token Requestor ID
+///
+/// Register the token requestor ID assigned to a merchant account by a card scheme. Network tokens of the merchant account are provisioned and used with the token requestor ID once it is active.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/token_requestor_ids",
+    request_body = TokenRequestorIdCreateRequest,
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Token Requestor ID Created", body = TokenRequestorIdResponse),
+        (status = 400, description = "The merchant account already has a token requestor ID for the card network"),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Create a Token Requestor ID of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantsAccountTokenRequestorIdCreate))]
+pub async fn create_token_requestor_id(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    mid: web::Path<String>,
+    json_payload: web::Json<admin::TokenRequestorIdCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantsAccountTokenRequestorIdCreate;
+    let merchant_id = mid.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| token_requestor::create_token_requestor_id(state, &merchant_id, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Merchant Account - List Token Requestor IDs
+///
+/// List the token requestor IDs of a merchant account, including the deactivated ones.
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/token_requestor_ids",
+    params (("account_id" = String, Path, description = "The unique identifier for the merchant account")),
+    responses(
+        (status = 200, description = "Token Requestor IDs Retrieved", body = Vec<TokenRequestorIdResponse>),
+        (status = 404, description = "Merchant account not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "List the Token Requestor IDs of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantsAccountTokenRequestorIdList))]
+pub async fn list_token_requestor_ids(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    mid: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::MerchantsAccountTokenRequestorIdList;
+    let payload = admin::MerchantId {
+        merchant_id: mid.into_inner(),
+    };
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, _, req| token_requestor::list_token_requestor_ids(state, &req.merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Merchant Account - Update Token Requestor ID
+///
+/// Update the status of the token requestor ID of a merchant account for a card network, as its registration with the card scheme progresses. Deactivated token requestor IDs cannot be updated.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/token_requestor_ids/{card_network}",
+    request_body = TokenRequestorIdUpdateRequest,
+    params (
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("card_network" = CardNetwork, Path, description = "The card network which assigned the token requestor ID"),
+    ),
+    responses(
+        (status = 200, description = "Token Requestor ID Updated", body = TokenRequestorIdResponse),
+        (status = 400, description = "Invalid status transition"),
+        (status = 404, description = "Token requestor ID not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Update a Token Requestor ID of a Merchant Account",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantsAccountTokenRequestorIdUpdate))]
+pub async fn update_token_requestor_id(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, api_models::enums::CardNetwork)>,
+    json_payload: web::Json<admin::TokenRequestorIdUpdateRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantsAccountTokenRequestorIdUpdate;
+    let (merchant_id, card_network) = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| {
+            token_requestor::update_token_requestor_id_status(
+                state,
+                &merchant_id,
+                card_network.clone(),
+                req,
+            )
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Account Template - Create
+///
+/// Create a template of the business profiles, connectors, routing and webhook settings of merchant accounts. Templates don't hold the credentials of the connectors, which are provided when the template is instantiated.
+#[utoipa::path(
+    post,
+    path = "/accounts/templates",
+    request_body = AccountTemplateCreate,
+    responses(
+        (status = 200, description = "Account Template Created", body = AccountTemplateResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Create an Account Template",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::AccountTemplateCreate))]
+pub async fn account_template_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<admin::AccountTemplateCreate>,
+) -> HttpResponse {
+    let flow = Flow::AccountTemplateCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| account_templates::create_account_template(state, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Account Template - Retrieve
+///
+/// Retrieve an account template
+#[utoipa::path(
+    get,
+    path = "/accounts/templates/{template_id}",
+    params (("template_id" = String, Path, description = "The unique identifier for the account template")),
+    responses(
+        (status = 200, description = "Account Template Retrieved", body = AccountTemplateResponse),
+        (status = 404, description = "Account template not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Retrieve an Account Template",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::AccountTemplateRetrieve))]
+pub async fn account_template_retrieve(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::AccountTemplateRetrieve;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, _, template_id| account_templates::retrieve_account_template(state, template_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Account Template - Delete
+///
+/// Delete an account template. Merchant accounts already instantiated from the template are not affected.
+#[utoipa::path(
+    delete,
+    path = "/accounts/templates/{template_id}",
+    params (("template_id" = String, Path, description = "The unique identifier for the account template")),
+    responses(
+        (status = 200, description = "Account Template Deleted"),
+        (status = 404, description = "Account template not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Delete an Account Template",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::AccountTemplateDelete))]
+pub async fn account_template_delete(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::AccountTemplateDelete;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, _, template_id| account_templates::delete_account_template(state, template_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Account Template - Instantiate
+///
+/// Create a merchant account along with the business profiles and connectors of an account template, with the credentials of each connector of the template provided against its connector label.
+#[utoipa::path(
+    post,
+    path = "/accounts/templates/{template_id}/instantiate",
+    request_body = AccountTemplateInstantiateRequest,
+    params (("template_id" = String, Path, description = "The unique identifier for the account template")),
+    responses(
+        (status = 200, description = "Merchant Account Created from the Account Template", body = AccountTemplateInstantiateResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 404, description = "Account template not found")
+    ),
+    tag = "Merchant Account",
+    operation_id = "Instantiate an Account Template",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::AccountTemplateInstantiate))]
+pub async fn account_template_instantiate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<admin::AccountTemplateInstantiateRequest>,
+) -> HttpResponse {
+    let flow = Flow::AccountTemplateInstantiate;
+    let template_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| account_templates::instantiate_account_template(state, &template_id, req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
 /// Merchant Connector - Create
 ///
 /// Create a new Merchant Connector for the merchant account. The connector could be a payment processor / facilitator / acquirer or specialized services like Fraud / Accounting etc."
@@ -232,6 +627,52 @@ pub async fn payment_connector_create(
     ))
     .await
 }
+/// Merchant Connector - Bulk Create or Update
+///
+/// Create and update several Merchant Connectors of the merchant account at once, across business profiles. Each item is processed independently, and its result is returned in the order of the request. With `dry_run` set, the items are only validated.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/connectors/bulk",
+    request_body = MerchantConnectorBulkRequest,
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account")
+    ),
+    responses(
+        (status = 200, description = "Merchant Connectors Processed", body = MerchantConnectorBulkResponse),
+        (status = 400, description = "Invalid data"),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "Create or Update Merchant Connectors in Bulk",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsBulkProvision))]
+pub async fn payment_connector_bulk_provision(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<admin::MerchantConnectorBulkRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorsBulkProvision;
+    let merchant_id = path.into_inner();
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| bulk_provision_payment_connectors(state, &merchant_id, req),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantConnectorAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
 /// Merchant Connector - Retrieve
 ///
 /// Retrieve Merchant Connector Details
@@ -330,6 +771,51 @@ pub async fn payment_connector_list(
     )
     .await
 }
+/// Merchant Connector - Health
+///
+/// List the health of the Merchant Connectors, derived from the rate of errors and timeouts in their recent responses
+#[utoipa::path(
+    get,
+    path = "/accounts/{account_id}/connectors/health",
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+    ),
+    responses(
+        (status = 200, description = "Merchant Connectors health retrieved successfully", body = Vec<ConnectorHealthResponse>),
+        (status = 404, description = "Merchant Account does not exist in records"),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Merchant Connector Account",
+    operation_id = "List the health of all Merchant Connectors",
+    security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsHealth))]
+pub async fn payment_connector_health(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorsHealth;
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        merchant_id.to_owned(),
+        |state, _, merchant_id| list_payment_connectors_health(state, merchant_id),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id,
+                required_permission: Permission::MerchantConnectorAccountRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
 /// Merchant Connector - Update
 ///
 /// To update an existing Merchant Connector. Helpful in enabling / disabling different payment methods and other settings for the connector etc.
@@ -378,6 +864,56 @@ pub async fn payment_connector_update(
     ))
     .await
 }
+/// Merchant Connector - Rotate Webhook Secret
+///
+/// Rotate the secret used for verifying incoming webhooks of a Merchant Connector. The previous secret continues to be accepted for the overlap window, so that no webhooks are dropped during the rotation.
+#[utoipa::path(
+    post,
+    path = "/accounts/{account_id}/connectors/{connector_id}/webhook_secret/rotate",
+    request_body = MerchantConnectorWebhookSecretRotateRequest,
+    params(
+        ("account_id" = String, Path, description = "The unique identifier for the merchant account"),
+        ("connector_id" = i32, Path, description = "The unique identifier for the Merchant Connector")
+    ),
+    responses(
+        (status = 200, description = "Merchant Connector Webhook Secret Rotated", body = MerchantConnectorResponse),
+        (status = 404, description = "Merchant Connector does not exist in records"),
+        (status = 401, description = "Unauthorized request")
+    ),
+   tag = "Merchant Connector Account",
+   operation_id = "Rotate the Webhook Secret of a Merchant Connector",
+   security(("admin_api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::MerchantConnectorsWebhookSecretRotate))]
+pub async fn payment_connector_webhook_secret_rotate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<api_models::admin::MerchantConnectorWebhookSecretRotateRequest>,
+) -> HttpResponse {
+    let flow = Flow::MerchantConnectorsWebhookSecretRotate;
+    let (merchant_id, merchant_connector_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| {
+            rotate_connector_webhook_secret(state, &merchant_id, &merchant_connector_id, req)
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantConnectorAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
 /// Merchant Connector - Delete
 ///
 /// Delete or Detach a Merchant Connector from Merchant Account
@@ -454,6 +990,62 @@ pub async fn merchant_account_toggle_kv(
     )
     .await
 }
+/// Merchant Account - Generate Sandbox Data
+///
+/// Generate synthetic payments, refunds and disputes for a sandbox merchant
+#[cfg(feature = "dummy_connector")]
+#[instrument(skip_all, fields(flow = ?Flow::GenerateSampleData))]
+pub async fn merchant_account_generate_sandbox_data(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<api_models::user::sample_data::SampleDataRequest>,
+) -> HttpResponse {
+    use crate::core::user::sample_data;
+
+    let flow = Flow::GenerateSampleData;
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, payload| {
+            sample_data::generate_sandbox_data_for_merchant(state, &merchant_id, payload)
+        },
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Merchant Account - Delete Sandbox Data
+///
+/// Delete the synthetic payments, refunds and disputes generated for a sandbox merchant
+#[cfg(feature = "dummy_connector")]
+#[instrument(skip_all, fields(flow = ?Flow::DeleteSampleData))]
+pub async fn merchant_account_delete_sandbox_data(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    use crate::core::user::sample_data;
+
+    let flow = Flow::DeleteSampleData;
+    let merchant_id = path.into_inner();
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, _, _| sample_data::delete_sandbox_data_for_merchant(state, &merchant_id),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
 #[instrument(skip_all, fields(flow = ?Flow::BusinessProfileCreate))]
 pub async fn business_profile_create(
     state: web::Data<AppState>,
@@ -538,6 +1130,34 @@ pub async fn business_profile_update(
     ))
     .await
 }
+#[instrument(skip_all, fields(flow = ?Flow::BusinessProfileWebhookSecretRotate))]
+pub async fn business_profile_webhook_secret_rotate(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+    json_payload: web::Json<api_models::admin::BusinessProfileWebhookSecretRotateRequest>,
+) -> HttpResponse {
+    let flow = Flow::BusinessProfileWebhookSecretRotate;
+    let (merchant_id, profile_id) = path.into_inner();
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, _, req| signing::rotate_webhook_secret(state, &merchant_id, &profile_id, req),
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantFromRoute {
+                merchant_id: merchant_id.clone(),
+                required_permission: Permission::MerchantAccountWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
 #[instrument(skip_all, fields(flow = ?Flow::BusinessProfileDelete))]
 pub async fn business_profile_delete(
     state: web::Data<AppState>,
@@ -14,9 +14,13 @@ use hyperswitch_interfaces::{
 };
 use router_env::tracing_actix_web::RequestId;
 use scheduler::SchedulerInterface;
+#[cfg(feature = "olap")]
+use storage_impl::redis::kv_store::RedisConnInterface;
 use storage_impl::MockDb;
 use tokio::sync::oneshot;
 
+#[cfg(feature = "olap")]
+use super::audit_logs;
 #[cfg(feature = "olap")]
 use super::blocklist;
 #[cfg(any(feature = "olap", feature = "oltp"))]
@@ -30,19 +34,24 @@ use super::pm_auth;
 #[cfg(feature = "olap")]
 use super::routing as cloud_routing;
 #[cfg(feature = "olap")]
+use super::velocity_rules;
+#[cfg(feature = "olap")]
 use super::verification::{apple_pay_merchant_registration, retrieve_apple_pay_verified_domains};
 #[cfg(feature = "olap")]
 use super::{
     admin::*, api_keys::*, connector_onboarding::*, disputes::*, files::*, gsm::*, payment_link::*,
     user::*, user_role::*, webhook_events::*,
 };
-use super::{cache::*, health::*};
 #[cfg(any(feature = "olap", feature = "oltp"))]
-use super::{configs::*, customers::*, mandates::*, payments::*, refunds::*};
+use super::{
+    bulk_charges::*, configs::*, customers::*, mandates::*, payments::*, recurring_payments::*,
+    refunds::*,
+};
+use super::{cache::*, health::*, log_filter::*};
 #[cfg(feature = "oltp")]
-use super::{ephemeral_key::*, payment_methods::*, webhooks::*};
+use super::{client_sessions::*, ephemeral_key::*, payment_methods::*, webhooks::*};
 use crate::configs::secrets_transformers;
-#[cfg(all(feature = "frm", feature = "oltp"))]
+#[cfg(all(feature = "frm", any(feature = "olap", feature = "oltp")))]
 use crate::routes::fraud_check as frm_routes;
 #[cfg(all(feature = "recon", feature = "olap"))]
 use crate::routes::recon as recon_routes;
@@ -56,6 +65,10 @@ pub use crate::{
     routes::cards_info::card_iin_info,
     services::get_store,
 };
+use crate::{
+    core::webhooks::delivery_worker::WebhookDeliveryWorker,
+    services::{api::deadline::RequestDeadline, authentication::AuthenticationType},
+};
 
 #[derive(Clone)]
 pub struct AppState {
@@ -69,6 +82,11 @@ pub struct AppState {
     #[cfg(feature = "olap")]
     pub pool: crate::analytics::AnalyticsProvider,
     pub request_id: Option<RequestId>,
+    pub request_deadline: Option<RequestDeadline>,
+    /// How the request was authenticated, identifying the actor of the operations recorded in
+    /// the audit log
+    pub authentication_type: Option<AuthenticationType>,
+    pub webhook_delivery_worker: WebhookDeliveryWorker,
     pub file_storage_client: Box<dyn FileStorageInterface>,
     pub encryption_client: Box<dyn EncryptionManagementInterface>,
 }
@@ -89,6 +107,9 @@ pub trait AppStateInfo {
     fn add_merchant_id(&mut self, merchant_id: Option<String>);
     fn add_flow_name(&mut self, flow_name: String);
     fn get_request_id(&self) -> Option<String>;
+    fn add_request_deadline(&mut self, headers: &actix_web::http::header::HeaderMap);
+    fn get_request_deadline(&self) -> Option<RequestDeadline>;
+    fn add_authentication_type(&mut self, authentication_type: AuthenticationType);
 }
 
 impl AppStateInfo for AppState {
@@ -120,6 +141,18 @@ impl AppStateInfo for AppState {
     fn get_request_id(&self) -> Option<String> {
         self.api_client.get_request_id()
     }
+    fn add_request_deadline(&mut self, headers: &actix_web::http::header::HeaderMap) {
+        self.request_deadline.replace(RequestDeadline::from_headers(
+            headers,
+            &self.conf.request_deadline,
+        ));
+    }
+    fn get_request_deadline(&self) -> Option<RequestDeadline> {
+        self.request_deadline
+    }
+    fn add_authentication_type(&mut self, authentication_type: AuthenticationType) {
+        self.authentication_type.replace(authentication_type);
+    }
 }
 
 impl AsRef<Self> for AppState {
@@ -174,13 +207,13 @@ impl AppState {
 
             let store: Box<dyn StorageInterface> = match storage_impl {
                 StorageImpl::Postgresql | StorageImpl::PostgresqlTest => match &event_handler {
-                    EventsHandler::Kafka(kafka_client) => Box::new(
+                    EventsHandler::Kafka(_) | EventsHandler::Clickhouse(_) => Box::new(
                         crate::db::KafkaStore::new(
                             #[allow(clippy::expect_used)]
                             get_store(&conf.clone(), shut_down_signal, testable)
                                 .await
                                 .expect("Failed to create store"),
-                            kafka_client.clone(),
+                            event_handler.clone(),
                         )
                         .await,
                     ),
@@ -200,14 +233,24 @@ impl AppState {
             };
 
             #[cfg(feature = "olap")]
-            let pool =
-                crate::analytics::AnalyticsProvider::from_conf(conf.analytics.get_inner()).await;
+            let pool = crate::analytics::AnalyticsProvider::from_conf(
+                conf.analytics.get_inner(),
+                &conf.analytics_guardrails,
+                crate::analytics::cache::AnalyticsCache::new(
+                    conf.analytics_cache.clone(),
+                    store.get_redis_conn().ok(),
+                ),
+            )
+            .await;
 
             #[cfg(feature = "email")]
             let email_client = Arc::new(create_email_client(&conf).await);
 
             let file_storage_client = conf.file_storage.get_file_storage_client().await;
 
+            let webhook_delivery_worker =
+                WebhookDeliveryWorker::start(&conf.webhooks.delivery_worker);
+
             Self {
                 flow_name: String::from("default"),
                 store,
@@ -219,6 +262,9 @@ impl AppState {
                 #[cfg(feature = "olap")]
                 pool,
                 request_id: None,
+                request_deadline: None,
+                authentication_type: None,
+                webhook_delivery_worker,
                 file_storage_client,
                 encryption_client,
             }
@@ -306,7 +352,15 @@ impl Payments {
                         .route(web::get().to(payments_list))
                         .route(web::post().to(payments_list_by_filter)),
                 )
-                .service(web::resource("/filter").route(web::post().to(get_filters_for_payments)))
+                .service(web::resource("/filter").route(web::post().to(get_filters_for_payments)));
+
+            #[cfg(feature = "frm")]
+            {
+                route = route.service(
+                    web::resource("/frm_review_queue")
+                        .route(web::get().to(frm_routes::frm_review_queue_list)),
+                );
+            }
         }
         #[cfg(feature = "oltp")]
         {
@@ -370,6 +424,9 @@ impl Payments {
                 )
                 .service(
                     web::resource("/{payment_id}/3ds/authentication").route(web::post().to(payments_external_authentication)),
+                )
+                .service(
+                    web::resource("/{payment_id}/sdk_events").route(web::post().to(payments_sdk_events)),
                 );
         }
         route
@@ -455,6 +512,9 @@ impl Routing {
                         )
                     })),
             )
+            .service(
+                web::resource("/simulate").route(web::post().to(cloud_routing::routing_simulate)),
+            )
             .service(web::resource("/deactivate").route(web::post().to(
                 |state, req, #[cfg(feature = "business_profile_routing")] payload| {
                     cloud_routing::routing_unlink_config(
@@ -480,6 +540,28 @@ impl Routing {
                         web::delete().to(cloud_routing::delete_surcharge_decision_manager_config),
                     ),
             )
+            .service(
+                web::resource("/decision/three_ds")
+                    .route(web::put().to(cloud_routing::upsert_three_ds_decision_manager_config))
+                    .route(web::get().to(cloud_routing::retrieve_three_ds_decision_manager_config))
+                    .route(
+                        web::delete().to(cloud_routing::delete_three_ds_decision_manager_config),
+                    ),
+            )
+            .service(
+                web::resource("/decision/manual_review")
+                    .route(
+                        web::put().to(cloud_routing::upsert_manual_review_decision_manager_config),
+                    )
+                    .route(
+                        web::get()
+                            .to(cloud_routing::retrieve_manual_review_decision_manager_config),
+                    )
+                    .route(
+                        web::delete()
+                            .to(cloud_routing::delete_manual_review_decision_manager_config),
+                    ),
+            )
             .service(
                 web::resource("/default/profile/{profile_id}").route(web::post().to(
                     |state, req, path, payload| {
@@ -645,6 +727,12 @@ impl Customers {
                         .route(web::get().to(get_customer_mandates)),
                 )
                 .service(web::resource("/list").route(web::get().to(customers_list)))
+                .service(web::resource("/merge").route(web::post().to(customers_merge)))
+                .service(
+                    web::resource("/merge/suggestions")
+                        .route(web::post().to(customers_merge_suggestions_scan))
+                        .route(web::get().to(customers_merge_suggestions_retrieve)),
+                )
         }
 
         #[cfg(feature = "oltp")]
@@ -776,6 +864,25 @@ impl Recon {
                 web::resource("/request").route(web::post().to(recon_routes::request_for_recon)),
             )
             .service(web::resource("/verify_token").route(web::get().to(verify_recon_token)))
+            .service(
+                web::resource("/settlement_reports")
+                    .route(web::post().to(recon_routes::upload_settlement_report)),
+            )
+            .service(
+                web::scope("/settlement_records")
+                    .service(
+                        web::resource("")
+                            .route(web::get().to(recon_routes::list_settlement_records)),
+                    )
+                    .service(
+                        web::resource("/unreconciled")
+                            .route(web::get().to(recon_routes::unreconciled_items_report)),
+                    )
+                    .service(
+                        web::resource("/{record_id}")
+                            .route(web::get().to(recon_routes::retrieve_settlement_record)),
+                    ),
+            )
     }
 }
 
@@ -799,15 +906,41 @@ impl Blocklist {
     }
 }
 
+pub struct VelocityRules;
+
+#[cfg(feature = "olap")]
+impl VelocityRules {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/velocity_rules")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::put().to(velocity_rules::upsert_velocity_rules))
+                    .route(web::get().to(velocity_rules::retrieve_velocity_rules))
+                    .route(web::delete().to(velocity_rules::delete_velocity_rules)),
+            )
+    }
+}
+
 pub struct MerchantAccount;
 
 #[cfg(feature = "olap")]
 impl MerchantAccount {
     pub fn server(state: AppState) -> Scope {
-        web::scope("/accounts")
+        let mut route = web::scope("/accounts")
             .app_data(web::Data::new(state))
             .service(web::resource("").route(web::post().to(merchant_account_create)))
             .service(web::resource("/list").route(web::get().to(merchant_account_list)))
+            .service(web::resource("/templates").route(web::post().to(account_template_create)))
+            .service(
+                web::resource("/templates/{template_id}")
+                    .route(web::get().to(account_template_retrieve))
+                    .route(web::delete().to(account_template_delete)),
+            )
+            .service(
+                web::resource("/templates/{template_id}/instantiate")
+                    .route(web::post().to(account_template_instantiate)),
+            )
             .service(
                 web::resource("/{id}/kv")
                     .route(web::post().to(merchant_account_toggle_kv))
@@ -819,6 +952,31 @@ impl MerchantAccount {
                     .route(web::post().to(update_merchant_account))
                     .route(web::delete().to(delete_merchant_account)),
             )
+            .service(web::resource("/{id}/restore").route(web::post().to(restore_merchant_account)))
+            .service(web::resource("/{id}/export").route(web::get().to(export_merchant_account)))
+            .service(
+                web::resource("/{id}/card_fingerprint_key/rotate")
+                    .route(web::post().to(rotate_card_fingerprint_key)),
+            )
+            .service(
+                web::resource("/{id}/token_requestor_ids")
+                    .route(web::post().to(create_token_requestor_id))
+                    .route(web::get().to(list_token_requestor_ids)),
+            )
+            .service(
+                web::resource("/{id}/token_requestor_ids/{card_network}")
+                    .route(web::post().to(update_token_requestor_id)),
+            );
+
+        #[cfg(feature = "dummy_connector")]
+        {
+            route = route.service(
+                web::resource("/{id}/sample_data")
+                    .route(web::post().to(merchant_account_generate_sandbox_data))
+                    .route(web::delete().to(merchant_account_delete_sandbox_data)),
+            )
+        }
+        route
     }
 }
 
@@ -843,11 +1001,25 @@ impl MerchantConnectorAccount {
                         .route(web::post().to(payment_connector_create))
                         .route(web::get().to(payment_connector_list)),
                 )
+                .service(
+                    web::resource("/{merchant_id}/connectors/bulk")
+                        .route(web::post().to(payment_connector_bulk_provision)),
+                )
+                .service(
+                    web::resource("/{merchant_id}/connectors/health")
+                        .route(web::get().to(payment_connector_health)),
+                )
                 .service(
                     web::resource("/{merchant_id}/connectors/{merchant_connector_id}")
                         .route(web::get().to(payment_connector_retrieve))
                         .route(web::post().to(payment_connector_update))
                         .route(web::delete().to(payment_connector_delete)),
+                )
+                .service(
+                    web::resource(
+                        "/{merchant_id}/connectors/{merchant_connector_id}/webhook_secret/rotate",
+                    )
+                    .route(web::post().to(payment_connector_webhook_secret_rotate)),
                 );
         }
         #[cfg(feature = "oltp")]
@@ -860,6 +1032,17 @@ impl MerchantConnectorAccount {
     }
 }
 
+pub struct ClientSessions;
+
+#[cfg(feature = "oltp")]
+impl ClientSessions {
+    pub fn server(config: AppState) -> Scope {
+        web::scope("/client_sessions")
+            .app_data(web::Data::new(config))
+            .service(web::resource("").route(web::post().to(client_session_create)))
+    }
+}
+
 pub struct EphemeralKey;
 
 #[cfg(feature = "oltp")]
@@ -894,6 +1077,41 @@ impl Mandates {
     }
 }
 
+pub struct RecurringPayments;
+
+#[cfg(any(feature = "olap", feature = "oltp"))]
+impl RecurringPayments {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/recurring_payments")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(create_recurring_payment_schedule)))
+            .service(
+                web::resource("/{schedule_id}")
+                    .route(web::get().to(retrieve_recurring_payment_schedule)),
+            )
+            .service(
+                web::resource("/{schedule_id}/cancel")
+                    .route(web::post().to(cancel_recurring_payment_schedule)),
+            )
+    }
+}
+
+pub struct BulkCharges;
+
+#[cfg(any(feature = "olap", feature = "oltp"))]
+impl BulkCharges {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/bulk_charges")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::post().to(create_bulk_charge)))
+            .service(web::resource("/{batch_id}").route(web::get().to(retrieve_bulk_charge)))
+            .service(
+                web::resource("/{batch_id}/results")
+                    .route(web::get().to(retrieve_bulk_charge_results)),
+            )
+    }
+}
+
 pub struct Webhooks;
 
 #[cfg(feature = "oltp")]
@@ -959,6 +1177,18 @@ impl ApiKeys {
                     .route(web::post().to(api_key_update))
                     .route(web::delete().to(api_key_revoke)),
             )
+            .service(web::resource("/{key_id}/rotate").route(web::post().to(api_key_rotate)))
+    }
+}
+
+pub struct AuditLogs;
+
+#[cfg(feature = "olap")]
+impl AuditLogs {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/audit_logs/{merchant_id}")
+            .app_data(web::Data::new(state))
+            .service(web::resource("").route(web::get().to(audit_logs::list_audit_logs)))
     }
 }
 
@@ -971,6 +1201,14 @@ impl Disputes {
             .app_data(web::Data::new(state))
             .service(web::resource("/list").route(web::get().to(retrieve_disputes_list)))
             .service(web::resource("/accept/{dispute_id}").route(web::post().to(accept_dispute)))
+            .service(
+                web::resource("/representment_rules")
+                    .route(web::get().to(retrieve_dispute_representment_rules))
+                    .route(web::post().to(update_dispute_representment_rules)),
+            )
+            .service(
+                web::resource("/review_queue").route(web::get().to(retrieve_dispute_review_queue)),
+            )
             .service(
                 web::resource("/evidence")
                     .route(web::post().to(submit_dispute_evidence))
@@ -1021,6 +1259,21 @@ impl Cache {
     }
 }
 
+pub struct LogFilter;
+
+impl LogFilter {
+    pub fn server(state: AppState) -> Scope {
+        web::scope("/log_filter")
+            .app_data(web::Data::new(state))
+            .service(
+                web::resource("")
+                    .route(web::get().to(retrieve_log_filter))
+                    .route(web::put().to(override_log_filter))
+                    .route(web::delete().to(revert_log_filter)),
+            )
+    }
+}
+
 pub struct PaymentLink;
 #[cfg(feature = "olap")]
 impl PaymentLink {
@@ -1060,6 +1313,10 @@ impl BusinessProfile {
                     .route(web::post().to(business_profile_update))
                     .route(web::delete().to(business_profile_delete)),
             )
+            .service(
+                web::resource("/{profile_id}/webhook_secret/rotate")
+                    .route(web::post().to(business_profile_webhook_secret_rotate)),
+            )
     }
 }
 
@@ -1112,6 +1369,7 @@ impl User {
             .service(web::resource("/change_password").route(web::post().to(change_password)))
             .service(web::resource("/internal_signup").route(web::post().to(internal_user_signup)))
             .service(web::resource("/switch_merchant").route(web::post().to(switch_merchant_id)))
+            .service(web::resource("/impersonate").route(web::post().to(impersonate_merchant)))
             .service(
                 web::resource("/create_merchant")
                     .route(web::post().to(user_merchant_account_create)),
@@ -1227,9 +1485,18 @@ impl WebhookEvents {
         web::scope("/events/{merchant_id_or_profile_id}")
             .app_data(web::Data::new(config))
             .service(web::resource("").route(web::get().to(list_initial_webhook_delivery_attempts)))
+            .service(web::resource("/replay").route(web::post().to(replay_webhook_events)))
+            .service(
+                web::resource("/endpoint_health")
+                    .route(web::get().to(retrieve_webhook_endpoint_health)),
+            )
             .service(
                 web::resource("/{event_id}/attempts")
                     .route(web::get().to(list_webhook_delivery_attempts)),
             )
+            .service(
+                web::resource("/{event_id}/retry")
+                    .route(web::post().to(retry_webhook_delivery_attempt)),
+            )
     }
 }
@@ -0,0 +1,124 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::bulk_charges as bulk_charge_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, bulk_charges},
+    services::{api, authentication as auth},
+};
+
+/// Bulk Charges - Create
+///
+/// Creates merchant initiated payments for every row of a bulk charge file
+#[utoipa::path(
+    post,
+    path = "/bulk_charges",
+    request_body = BulkChargeCreateRequest,
+    responses(
+        (status = 200, description = "Bulk charge created", body = BulkChargeResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Bulk Charges",
+    operation_id = "Create a Bulk Charge",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::BulkChargeCreate))]
+pub async fn create_bulk_charge(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<bulk_charge_types::BulkChargeCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::BulkChargeCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req| bulk_charges::create_bulk_charge(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Bulk Charges - Retrieve
+///
+/// Retrieves a bulk charge along with the progress of its rows
+#[utoipa::path(
+    get,
+    path = "/bulk_charges/{batch_id}",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the bulk charge")
+    ),
+    responses(
+        (status = 200, description = "Bulk charge retrieved", body = BulkChargeResponse),
+        (status = 404, description = "Bulk charge does not exist in our records")
+    ),
+    tag = "Bulk Charges",
+    operation_id = "Retrieve a Bulk Charge",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::BulkChargeRetrieve))]
+pub async fn retrieve_bulk_charge(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::BulkChargeRetrieve;
+    let batch_id = bulk_charge_types::BulkChargeId {
+        batch_id: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        batch_id,
+        |state, auth, req| bulk_charges::retrieve_bulk_charge(state, auth.merchant_account, req),
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Bulk Charges - Retrieve Results
+///
+/// Retrieves the result file of a bulk charge in CSV format, with the payment created for every
+/// row processed so far
+#[utoipa::path(
+    get,
+    path = "/bulk_charges/{batch_id}/results",
+    params(
+        ("batch_id" = String, Path, description = "The identifier for the bulk charge")
+    ),
+    responses(
+        (status = 200, description = "Result file of the bulk charge", content_type = "text/csv"),
+        (status = 404, description = "Bulk charge does not exist in our records")
+    ),
+    tag = "Bulk Charges",
+    operation_id = "Retrieve the Results of a Bulk Charge",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::BulkChargeResultsRetrieve))]
+pub async fn retrieve_bulk_charge_results(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::BulkChargeResultsRetrieve;
+    let batch_id = bulk_charge_types::BulkChargeId {
+        batch_id: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        batch_id,
+        |state, auth, req| {
+            bulk_charges::retrieve_bulk_charge_results(state, auth.merchant_account, req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
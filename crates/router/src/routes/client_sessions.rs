@@ -0,0 +1,35 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::client_sessions as client_session_types;
+use common_enums::ApiKeyScope;
+use router_env::{instrument, tracing, Flow};
+
+use super::AppState;
+use crate::{
+    core::{api_locking, client_sessions},
+    services::{api, authentication as auth},
+};
+
+/// Client Sessions - Create
+///
+/// Creates a short lived client session for a payment, to be used by the SDK instead of the
+/// client secret of the payment.
+#[instrument(skip_all, fields(flow = ?Flow::ClientSessionCreate))]
+pub async fn client_session_create(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<client_session_types::ClientSessionCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::ClientSessionCreate;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req| {
+            client_sessions::create_client_session(state, auth.merchant_account, req)
+        },
+        &auth::ScopedApiKeyAuth(ApiKeyScope::PaymentsWrite),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
@@ -43,7 +43,9 @@ pub async fn retrieve_dispute(
         state,
         &req,
         dispute_id,
-        |state, auth, req| disputes::retrieve_dispute(state, auth.merchant_account, req),
+        |state, auth, req| {
+            disputes::retrieve_dispute(state, auth.merchant_account, auth.profile_id, req)
+        },
         auth::auth_type(
             &auth::ApiKeyAuth,
             &auth::JWTAuth(Permission::DisputeRead),
@@ -68,6 +70,7 @@ pub async fn retrieve_dispute(
         ("received_time.gt" = Option<PrimitiveDateTime>, Query, description = "Time greater than the dispute received time"),
         ("received_time.lte" = Option<PrimitiveDateTime>, Query, description = "Time less than or equals to the dispute received time"),
         ("received_time.gte" = Option<PrimitiveDateTime>, Query, description = "Time greater than or equals to the dispute received time"),
+        ("representment_status" = Option<DisputeRepresentmentStatus>, Query, description = "Outcome of the automatic representment of the dispute"),
     ),
     responses(
         (status = 200, description = "The dispute list was retrieved successfully", body = Vec<DisputeResponse>),
@@ -90,7 +93,9 @@ pub async fn retrieve_disputes_list(
         state,
         &req,
         payload,
-        |state, auth, req| disputes::retrieve_disputes_list(state, auth.merchant_account, req),
+        |state, auth, req| {
+            disputes::retrieve_disputes_list(state, auth.merchant_account, auth.profile_id, req)
+        },
         auth::auth_type(
             &auth::ApiKeyAuth,
             &auth::JWTAuth(Permission::DisputeRead),
@@ -131,7 +136,13 @@ pub async fn accept_dispute(
         &req,
         dispute_id,
         |state, auth, req| {
-            disputes::accept_dispute(state, auth.merchant_account, auth.key_store, req)
+            disputes::accept_dispute(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile_id,
+                req,
+            )
         },
         auth::auth_type(
             &auth::ApiKeyAuth,
@@ -168,7 +179,13 @@ pub async fn submit_dispute_evidence(
         &req,
         json_payload.into_inner(),
         |state, auth, req| {
-            disputes::submit_evidence(state, auth.merchant_account, auth.key_store, req)
+            disputes::submit_evidence(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile_id,
+                req,
+            )
         },
         auth::auth_type(
             &auth::ApiKeyAuth,
@@ -213,7 +230,13 @@ pub async fn attach_dispute_evidence(
         &req,
         attach_evidence_request,
         |state, auth, req| {
-            disputes::attach_evidence(state, auth.merchant_account, auth.key_store, req)
+            disputes::attach_evidence(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile_id,
+                req,
+            )
         },
         auth::auth_type(
             &auth::ApiKeyAuth,
@@ -255,7 +278,9 @@ pub async fn retrieve_dispute_evidence(
         state,
         &req,
         dispute_id,
-        |state, auth, req| disputes::retrieve_dispute_evidence(state, auth.merchant_account, req),
+        |state, auth, req| {
+            disputes::retrieve_dispute_evidence(state, auth.merchant_account, auth.profile_id, req)
+        },
         auth::auth_type(
             &auth::ApiKeyAuth,
             &auth::JWTAuth(Permission::DisputeRead),
@@ -293,7 +318,9 @@ pub async fn delete_dispute_evidence(
         state,
         &req,
         json_payload.into_inner(),
-        |state, auth, req| disputes::delete_evidence(state, auth.merchant_account, req),
+        |state, auth, req| {
+            disputes::delete_evidence(state, auth.merchant_account, auth.profile_id, req)
+        },
         auth::auth_type(
             &auth::ApiKeyAuth,
             &auth::JWTAuth(Permission::DisputeWrite),
@@ -303,3 +330,120 @@ pub async fn delete_dispute_evidence(
     ))
     .await
 }
+
+/// Disputes - Retrieve Dispute Representment Rules
+///
+/// To retrieve the rules used to automatically submit evidence for disputes
+#[utoipa::path(
+    get,
+    path = "/disputes/representment_rules",
+    responses(
+        (status = 200, description = "The dispute representment rules were retrieved successfully", body = DisputeRepresentmentRules),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Disputes",
+    operation_id = "Retrieve Dispute Representment Rules",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeRepresentmentRulesRetrieve))]
+pub async fn retrieve_dispute_representment_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::DisputeRepresentmentRulesRetrieve;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth, _| {
+            disputes::representment::retrieve_representment_rules(state, auth.merchant_account)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::DisputeRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Disputes - Update Dispute Representment Rules
+///
+/// To update the rules used to automatically submit evidence for disputes, disputes which do not
+/// match any rule are added to the review queue
+#[utoipa::path(
+    post,
+    path = "/disputes/representment_rules",
+    request_body = DisputeRepresentmentRules,
+    responses(
+        (status = 200, description = "The dispute representment rules were updated successfully", body = DisputeRepresentmentRules),
+        (status = 400, description = "Invalid representment rules")
+    ),
+    tag = "Disputes",
+    operation_id = "Update Dispute Representment Rules",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeRepresentmentRulesUpdate))]
+pub async fn update_dispute_representment_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<dispute_models::DisputeRepresentmentRules>,
+) -> HttpResponse {
+    let flow = Flow::DisputeRepresentmentRulesUpdate;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req| {
+            disputes::representment::update_representment_rules(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::DisputeWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Disputes - Retrieve Dispute Review Queue
+///
+/// To list the open disputes which could not be represented automatically and need to be reviewed
+#[utoipa::path(
+    get,
+    path = "/disputes/review_queue",
+    responses(
+        (status = 200, description = "The dispute review queue was retrieved successfully", body = Vec<DisputeResponse>),
+        (status = 401, description = "Unauthorized request")
+    ),
+    tag = "Disputes",
+    operation_id = "Retrieve Dispute Review Queue",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::DisputeReviewQueue))]
+pub async fn retrieve_dispute_review_queue(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let flow = Flow::DisputeReviewQueue;
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth, _| {
+            disputes::representment::retrieve_dispute_review_queue(state, auth.merchant_account)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::DisputeRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
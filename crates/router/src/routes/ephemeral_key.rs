@@ -22,7 +22,12 @@ pub async fn ephemeral_key_create(
         &req,
         payload,
         |state, auth, req| {
-            helpers::make_ephemeral_key(state, req.customer_id, auth.merchant_account.merchant_id)
+            helpers::create_customer_ephemeral_key(
+                state,
+                req.customer_id,
+                auth.merchant_account,
+                auth.key_store,
+            )
         },
         &auth::ApiKeyAuth,
         api_locking::LockAction::NotApplicable,
@@ -42,7 +47,9 @@ pub async fn ephemeral_key_delete(
         state,
         &req,
         payload,
-        |state, _, req| helpers::delete_ephemeral_key(state, req),
+        |state, auth, req| {
+            helpers::delete_ephemeral_key(state, req, auth.merchant_account.merchant_id)
+        },
         &auth::ApiKeyAuth,
         api_locking::LockAction::NotApplicable,
     )
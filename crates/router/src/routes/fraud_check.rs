@@ -2,6 +2,8 @@ use actix_web::{web, HttpRequest, HttpResponse};
 use common_utils::events::{ApiEventMetric, ApiEventsType};
 use router_env::Flow;
 
+#[cfg(feature = "olap")]
+use crate::services::{authentication as auth, authorization::permissions::Permission};
 use crate::{
     core::{api_locking, fraud_check as frm_core},
     services::{self, api},
@@ -29,6 +31,31 @@ pub async fn frm_fulfillment(
     .await
 }
 
+#[cfg(feature = "olap")]
+pub async fn frm_review_queue_list(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    payload: web::Query<api_models::payments::FrmReviewQueueConstraints>,
+) -> HttpResponse {
+    let flow = Flow::FrmReviewQueueList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload.into_inner(),
+        |state, auth, req| {
+            frm_core::list_frm_review_queue(state, auth.merchant_account, auth.profile_id, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 impl ApiEventMetric for FraudCheckResponseData {
     fn get_api_event_type(&self) -> Option<ApiEventsType> {
         Some(ApiEventsType::FraudCheck)
@@ -13,6 +13,8 @@ pub enum ApiIdentifier {
     Ephemeral,
     Health,
     Mandates,
+    RecurringPayments,
+    BulkCharges,
     PaymentMethods,
     PaymentMethodAuth,
     Payouts,
@@ -20,12 +22,14 @@ pub enum ApiIdentifier {
     CardsInfo,
     Files,
     Cache,
+    LogFilter,
     Business,
     Verification,
     ApiKeys,
     PaymentLink,
     Routing,
     Blocklist,
+    VelocityRules,
     Forex,
     RustLockerMigration,
     Gsm,
@@ -34,6 +38,7 @@ pub enum ApiIdentifier {
     UserRole,
     ConnectorOnboarding,
     Recon,
+    AuditLogs,
 }
 
 impl From<Flow> for ApiIdentifier {
@@ -43,6 +48,16 @@ impl From<Flow> for ApiIdentifier {
             | Flow::MerchantsAccountRetrieve
             | Flow::MerchantsAccountUpdate
             | Flow::MerchantsAccountDelete
+            | Flow::MerchantsAccountRestore
+            | Flow::MerchantsAccountDataExport
+            | Flow::MerchantsAccountCardFingerprintKeyRotate
+            | Flow::MerchantsAccountTokenRequestorIdCreate
+            | Flow::MerchantsAccountTokenRequestorIdList
+            | Flow::MerchantsAccountTokenRequestorIdUpdate
+            | Flow::AccountTemplateCreate
+            | Flow::AccountTemplateRetrieve
+            | Flow::AccountTemplateDelete
+            | Flow::AccountTemplateInstantiate
             | Flow::MerchantAccountList => Self::MerchantAccount,
 
             Flow::RoutingCreateConfig
@@ -55,6 +70,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::RoutingUpdateConfig
             | Flow::RoutingUpdateDefaultConfig
             | Flow::RoutingDeleteConfig
+            | Flow::RoutingSimulate
             | Flow::DecisionManagerDeleteConfig
             | Flow::DecisionManagerRetrieveConfig
             | Flow::DecisionManagerUpsertConfig => Self::Routing,
@@ -66,11 +82,18 @@ impl From<Flow> for ApiIdentifier {
             Flow::ListBlocklist => Self::Blocklist,
             Flow::ToggleBlocklistGuard => Self::Blocklist,
 
+            Flow::VelocityRulesUpsert | Flow::VelocityRulesRetrieve | Flow::VelocityRulesDelete => {
+                Self::VelocityRules
+            }
+
             Flow::MerchantConnectorsCreate
+            | Flow::MerchantConnectorsBulkProvision
             | Flow::MerchantConnectorsRetrieve
             | Flow::MerchantConnectorsUpdate
+            | Flow::MerchantConnectorsWebhookSecretRotate
             | Flow::MerchantConnectorsDelete
-            | Flow::MerchantConnectorsList => Self::MerchantConnector,
+            | Flow::MerchantConnectorsList
+            | Flow::MerchantConnectorsHealth => Self::MerchantConnector,
 
             Flow::ConfigKeyCreate
             | Flow::ConfigKeyFetch
@@ -83,13 +106,26 @@ impl From<Flow> for ApiIdentifier {
             | Flow::CustomersUpdate
             | Flow::CustomersDelete
             | Flow::CustomersGetMandates
-            | Flow::CustomersList => Self::Customers,
+            | Flow::CustomersList
+            | Flow::CustomersMerge
+            | Flow::CustomersMergeSuggestionsScan
+            | Flow::CustomersMergeSuggestionsRetrieve => Self::Customers,
 
-            Flow::EphemeralKeyCreate | Flow::EphemeralKeyDelete => Self::Ephemeral,
+            Flow::EphemeralKeyCreate | Flow::EphemeralKeyDelete | Flow::ClientSessionCreate => {
+                Self::Ephemeral
+            }
 
             Flow::DeepHealthCheck | Flow::HealthCheck => Self::Health,
             Flow::MandatesRetrieve | Flow::MandatesRevoke | Flow::MandatesList => Self::Mandates,
 
+            Flow::RecurringPaymentScheduleCreate
+            | Flow::RecurringPaymentScheduleRetrieve
+            | Flow::RecurringPaymentScheduleCancel => Self::RecurringPayments,
+
+            Flow::BulkChargeCreate | Flow::BulkChargeRetrieve | Flow::BulkChargeResultsRetrieve => {
+                Self::BulkCharges
+            }
+
             Flow::PaymentMethodsCreate
             | Flow::PaymentMethodsList
             | Flow::CustomerPaymentMethodsList
@@ -113,9 +149,11 @@ impl From<Flow> for ApiIdentifier {
             | Flow::PaymentsSessionToken
             | Flow::PaymentsStart
             | Flow::PaymentsList
+            | Flow::FrmReviewQueueList
             | Flow::PaymentsRedirect
             | Flow::PaymentsIncrementalAuthorization
             | Flow::PaymentsExternalAuthentication
+            | Flow::PaymentsSdkEvents
             | Flow::PaymentsAuthorize => Self::Payments,
 
             Flow::PayoutsCreate
@@ -136,11 +174,15 @@ impl From<Flow> for ApiIdentifier {
             Flow::FrmFulfillment
             | Flow::IncomingWebhookReceive
             | Flow::WebhookEventInitialDeliveryAttemptList
-            | Flow::WebhookEventDeliveryAttemptList => Self::Webhooks,
+            | Flow::WebhookEventDeliveryAttemptList
+            | Flow::WebhookEventDeliveryRetry
+            | Flow::WebhookEventReplay
+            | Flow::WebhookEndpointHealthRetrieve => Self::Webhooks,
 
             Flow::ApiKeyCreate
             | Flow::ApiKeyRetrieve
             | Flow::ApiKeyUpdate
+            | Flow::ApiKeyRotate
             | Flow::ApiKeyRevoke
             | Flow::ApiKeyList => Self::ApiKeys,
 
@@ -149,7 +191,10 @@ impl From<Flow> for ApiIdentifier {
             | Flow::DisputesEvidenceSubmit
             | Flow::AttachDisputeEvidence
             | Flow::RetrieveDisputeEvidence
-            | Flow::DeleteDisputeEvidence => Self::Disputes,
+            | Flow::DeleteDisputeEvidence
+            | Flow::DisputeRepresentmentRulesUpdate
+            | Flow::DisputeRepresentmentRulesRetrieve
+            | Flow::DisputeReviewQueue => Self::Disputes,
 
             Flow::CardsInfo => Self::CardsInfo,
 
@@ -157,11 +202,16 @@ impl From<Flow> for ApiIdentifier {
 
             Flow::CacheInvalidate => Self::Cache,
 
+            Flow::LogFilterRetrieve | Flow::LogFilterOverride | Flow::LogFilterRevert => {
+                Self::LogFilter
+            }
+
             Flow::BusinessProfileCreate
             | Flow::BusinessProfileUpdate
             | Flow::BusinessProfileRetrieve
             | Flow::BusinessProfileDelete
-            | Flow::BusinessProfileList => Self::Business,
+            | Flow::BusinessProfileList
+            | Flow::BusinessProfileWebhookSecretRotate => Self::Business,
 
             Flow::PaymentLinkRetrieve
             | Flow::PaymentLinkInitiate
@@ -187,6 +237,7 @@ impl From<Flow> for ApiIdentifier {
             | Flow::VerifyPaymentConnector
             | Flow::InternalUserSignup
             | Flow::SwitchMerchant
+            | Flow::ImpersonateMerchant
             | Flow::UserMerchantAccountCreate
             | Flow::GenerateSampleData
             | Flow::DeleteSampleData
@@ -223,7 +274,13 @@ impl From<Flow> for ApiIdentifier {
             Flow::ReconMerchantUpdate
             | Flow::ReconTokenRequest
             | Flow::ReconServiceRequest
-            | Flow::ReconVerifyToken => Self::Recon,
+            | Flow::ReconVerifyToken
+            | Flow::ReconSettlementReportUpload
+            | Flow::ReconSettlementRecordRetrieve
+            | Flow::ReconSettlementRecordList
+            | Flow::ReconUnreconciledItemsReport => Self::Recon,
+
+            Flow::AuditLogList => Self::AuditLogs,
         }
     }
 }
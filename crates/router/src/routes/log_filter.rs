@@ -0,0 +1,61 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::log_filter::LogFilterOverrideRequest;
+use router_env::{instrument, tracing, Flow};
+
+use super::AppState;
+use crate::{
+    core::{api_locking, log_filter},
+    services::{api, authentication as auth},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::LogFilterRetrieve))]
+pub async fn retrieve_log_filter(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::LogFilterRetrieve;
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |_, _, _| log_filter::retrieve_log_filter(),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::LogFilterOverride))]
+pub async fn override_log_filter(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<LogFilterOverrideRequest>,
+) -> impl Responder {
+    let flow = Flow::LogFilterOverride;
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |_, _, req| log_filter::override_log_filter(req),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::LogFilterRevert))]
+pub async fn revert_log_filter(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::LogFilterRevert;
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |_, _, _| log_filter::revert_log_filter(),
+        &auth::AdminApiAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
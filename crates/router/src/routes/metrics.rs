@@ -9,6 +9,7 @@ counter_metric!(KV_MISS, GLOBAL_METER); // No. of KV misses
 // API Level Metrics
 counter_metric!(REQUESTS_RECEIVED, GLOBAL_METER);
 counter_metric!(REQUEST_STATUS, GLOBAL_METER);
+counter_metric!(REQUEST_DEADLINE_EXCEEDED, GLOBAL_METER); // No. of requests not processed further as their deadline was exceeded
 histogram_metric!(REQUEST_TIME, GLOBAL_METER);
 histogram_metric!(EXTERNAL_REQUEST_TIME, GLOBAL_METER);
 
@@ -27,6 +28,12 @@ counter_metric!(SUCCESSFUL_CANCEL, GLOBAL_METER);
 counter_metric!(MANDATE_COUNT, GLOBAL_METER);
 counter_metric!(SUBSEQUENT_MANDATE_PAYMENT, GLOBAL_METER);
 
+// Blocklist metrics
+counter_metric!(BLOCKLIST_HIT_COUNT, GLOBAL_METER); // Payments blocked, by the kind of data blocklisted
+
+// Velocity metrics
+counter_metric!(VELOCITY_RULE_TRIGGERED_COUNT, GLOBAL_METER); // Velocity rules exceeded, by dimension and action
+
 // Manual retry metrics
 counter_metric!(MANUAL_RETRY_REQUEST_COUNT, GLOBAL_METER);
 counter_metric!(MANUAL_RETRY_COUNT, GLOBAL_METER);
@@ -54,9 +61,11 @@ counter_metric!(MCA_CREATE, GLOBAL_METER);
 
 counter_metric!(ACCESS_TOKEN_CREATION, GLOBAL_METER);
 histogram_metric!(CONNECTOR_REQUEST_TIME, GLOBAL_METER);
+histogram_metric!(PAYMENT_GET_TRACKERS_TIME, GLOBAL_METER);
 counter_metric!(SESSION_TOKEN_CREATED, GLOBAL_METER);
 
 counter_metric!(CONNECTOR_CALL_COUNT, GLOBAL_METER); // Attributes needed
+counter_metric!(CONNECTOR_CALL_OUTCOME_COUNT, GLOBAL_METER); // Connector calls, by connector, flow, http status class and outcome
 
 counter_metric!(THREE_DS_PAYMENT_COUNT, GLOBAL_METER);
 counter_metric!(THREE_DS_DOWNGRADE_COUNT, GLOBAL_METER);
@@ -123,5 +132,9 @@ counter_metric!(TASKS_ADDED_COUNT, GLOBAL_METER); // Tasks added to process trac
 counter_metric!(TASK_ADDITION_FAILURES_COUNT, GLOBAL_METER); // Failures in task addition to process tracker
 counter_metric!(TASKS_RESET_COUNT, GLOBAL_METER); // Tasks reset in process tracker for requeue flow
 
+// ClickHouse event writer metrics
+counter_metric!(CLICKHOUSE_EVENTS_INSERTED_COUNT, GLOBAL_METER); // Events inserted into ClickHouse
+counter_metric!(CLICKHOUSE_EVENTS_DROPPED_COUNT, GLOBAL_METER); // Events dropped as the queue was full or their insert failed
+
 pub mod request;
 pub mod utils;
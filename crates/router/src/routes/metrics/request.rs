@@ -1,7 +1,13 @@
+use std::time::Duration;
+
 use router_env::opentelemetry;
 
 use super::utils as metric_utils;
-use crate::services::ApplicationResponse;
+use crate::{
+    core::errors::{self, CustomResult},
+    services::ApplicationResponse,
+    types, utils,
+};
 
 pub async fn record_request_time_metric<F, R>(
     future: F,
@@ -54,6 +60,37 @@ pub fn status_code_metrics(status_code: i64, flow: String, merchant_id: String)
     )
 }
 
+/// Records the latency and the outcome of a call to a connector, labeled by the connector, the flow
+/// and the class of the http status code received, `none` when no response was received.
+pub fn record_connector_call_metrics(
+    connector: &str,
+    flow: &str,
+    response: &CustomResult<Result<types::Response, types::Response>, errors::ApiClientError>,
+    latency: Duration,
+) {
+    let (status_code, outcome) = match response {
+        Ok(Ok(body)) => (Some(body.status_code), "success"),
+        Ok(Err(body)) => (Some(body.status_code), "error_response"),
+        Err(error) if error.current_context().is_upstream_timeout() => (None, "timeout"),
+        Err(_) => (None, "failure"),
+    };
+    let status_class = status_code
+        .and_then(|status_code| utils::get_http_status_code_type(status_code).ok())
+        .unwrap_or_else(|| "none".to_string());
+    let attributes = [
+        add_attributes("connector", connector.to_string()),
+        add_attributes("flow", flow.to_string()),
+        add_attributes("status_class", status_class),
+    ];
+
+    super::CONNECTOR_REQUEST_TIME.record(&super::CONTEXT, latency.as_secs_f64(), &attributes);
+    super::CONNECTOR_CALL_OUTCOME_COUNT.add(
+        &super::CONTEXT,
+        1,
+        &[attributes.as_slice(), &[add_attributes("outcome", outcome)]].concat(),
+    );
+}
+
 pub fn track_response_status_code<Q>(response: &ApplicationResponse<Q>) -> i64 {
     match response {
         ApplicationResponse::Json(_)
@@ -35,7 +35,8 @@ pub async fn payment_link_retrieve(
 ) -> impl Responder {
     let flow = Flow::PaymentLinkRetrieve;
     let payload = json_payload.into_inner();
-    let (auth_type, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
+    let (auth_type, _) = match auth::check_client_secret_and_get_auth(req.headers(), &payload, None)
+    {
         Ok(auth) => auth,
         Err(err) => return api::log_and_return_error_response(error_stack::report!(err)),
     };
@@ -6,6 +6,7 @@ pub mod helpers;
 
 use actix_web::{web, Responder};
 use api_models::payments::HeaderPayload;
+use common_enums::ApiKeyScope;
 use error_stack::{report, IntoReport};
 use router_env::{env, instrument, tracing, types, Flow};
 
@@ -129,15 +130,16 @@ pub async fn payments_create(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 payment_types::HeaderPayload::default(),
                 req,
                 api::AuthFlow::Merchant,
             )
         },
         match env::which() {
-            env::Env::Production => &auth::ApiKeyAuth,
+            env::Env::Production => &auth::ScopedApiKeyAuth(ApiKeyScope::PaymentsWrite),
             _ => auth::auth_type(
-                &auth::ApiKeyAuth,
+                &auth::ScopedApiKeyAuth(ApiKeyScope::PaymentsWrite),
                 &auth::JWTAuth(Permission::PaymentWrite),
                 req.headers(),
             ),
@@ -198,6 +200,7 @@ pub async fn payments_start(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 payments::operations::PaymentStart,
                 req,
                 api::AuthFlow::Client,
@@ -254,11 +257,14 @@ pub async fn payments_retrieve(
     tracing::Span::current().record("payment_id", &path.to_string());
     tracing::Span::current().record("flow", &flow.to_string());
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(err) => return api::log_and_return_error_response(report!(err)),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Some(ApiKeyScope::PaymentsRead),
+    ) {
+        Ok(auth) => auth,
+        Err(err) => return api::log_and_return_error_response(report!(err)),
+    };
 
     let locking_action = payload.get_locking_input(flow.clone());
 
@@ -272,6 +278,7 @@ pub async fn payments_retrieve(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 payments::PaymentStatus,
                 req,
                 auth_flow,
@@ -311,10 +318,11 @@ pub async fn payments_retrieve_with_gateway_creds(
     req: actix_web::HttpRequest,
     json_payload: web::Json<payment_types::PaymentRetrieveBodyWithCredentials>,
 ) -> impl Responder {
-    let (auth_type, _auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, _auth_flow) =
+        match auth::get_auth_type_and_flow(req.headers(), Some(ApiKeyScope::PaymentsRead)) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
     let payload = payment_types::PaymentsRetrieveRequest {
         resource_id: payment_types::PaymentIdType::PaymentIntentId(
             json_payload.payment_id.to_string(),
@@ -344,6 +352,7 @@ pub async fn payments_retrieve_with_gateway_creds(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 payments::PaymentStatus,
                 req,
                 api::AuthFlow::Merchant,
@@ -396,10 +405,11 @@ pub async fn payments_update(
 
     payload.payment_id = Some(payment_types::PaymentIdType::PaymentIntentId(payment_id));
 
-    let (auth_type, auth_flow) = match auth::get_auth_type_and_flow(req.headers()) {
-        Ok(auth) => auth,
-        Err(err) => return api::log_and_return_error_response(report!(err)),
-    };
+    let (auth_type, auth_flow) =
+        match auth::get_auth_type_and_flow(req.headers(), Some(ApiKeyScope::PaymentsWrite)) {
+            Ok(auth) => auth,
+            Err(err) => return api::log_and_return_error_response(report!(err)),
+        };
 
     let locking_action = payload.get_locking_input(flow.clone());
 
@@ -414,6 +424,7 @@ pub async fn payments_update(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 payment_types::HeaderPayload::default(),
                 req,
                 auth_flow,
@@ -472,11 +483,14 @@ pub async fn payments_confirm(
         }
     };
 
-    let (auth_type, auth_flow) =
-        match auth::check_client_secret_and_get_auth(req.headers(), &payload) {
-            Ok(auth) => auth,
-            Err(e) => return api::log_and_return_error_response(e),
-        };
+    let (auth_type, auth_flow) = match auth::check_client_secret_and_get_auth(
+        req.headers(),
+        &payload,
+        Some(ApiKeyScope::PaymentsWrite),
+    ) {
+        Ok(auth) => auth,
+        Err(e) => return api::log_and_return_error_response(e),
+    };
 
     let locking_action = payload.get_locking_input(flow.clone());
 
@@ -491,6 +505,7 @@ pub async fn payments_confirm(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 header_payload,
                 req,
                 auth_flow,
@@ -555,6 +570,7 @@ pub async fn payments_capture(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 payments::PaymentCapture,
                 payload,
                 api::AuthFlow::Merchant,
@@ -563,7 +579,7 @@ pub async fn payments_capture(
                 HeaderPayload::default(),
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ScopedApiKeyAuth(ApiKeyScope::PaymentsWrite),
         locking_action,
     ))
     .await
@@ -613,6 +629,7 @@ pub async fn payments_connector_session(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 payments::PaymentSession,
                 payload,
                 api::AuthFlow::Client,
@@ -833,6 +850,7 @@ pub async fn payments_cancel(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 payments::PaymentCancel,
                 req,
                 api::AuthFlow::Merchant,
@@ -841,7 +859,7 @@ pub async fn payments_cancel(
                 HeaderPayload::default(),
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ScopedApiKeyAuth(ApiKeyScope::PaymentsWrite),
         locking_action,
     ))
     .await
@@ -885,9 +903,11 @@ pub async fn payments_list(
         state,
         &req,
         payload,
-        |state, auth, req| payments::list_payments(state, auth.merchant_account, req),
+        |state, auth, req| {
+            payments::list_payments(state, auth.merchant_account, auth.profile_id, req)
+        },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ScopedApiKeyAuth(ApiKeyScope::PaymentsRead),
             &auth::JWTAuth(Permission::PaymentRead),
             req.headers(),
         ),
@@ -909,9 +929,11 @@ pub async fn payments_list_by_filter(
         state,
         &req,
         payload,
-        |state, auth, req| payments::apply_filters_on_payments(state, auth.merchant_account, req),
+        |state, auth, req| {
+            payments::apply_filters_on_payments(state, auth.merchant_account, auth.profile_id, req)
+        },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ScopedApiKeyAuth(ApiKeyScope::PaymentsRead),
             &auth::JWTAuth(Permission::PaymentRead),
             req.headers(),
         ),
@@ -933,9 +955,11 @@ pub async fn get_filters_for_payments(
         state,
         &req,
         payload,
-        |state, auth, req| payments::get_filters_for_payments(state, auth.merchant_account, req),
+        |state, auth, req| {
+            payments::get_filters_for_payments(state, auth.merchant_account, auth.profile_id, req)
+        },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ScopedApiKeyAuth(ApiKeyScope::PaymentsRead),
             &auth::JWTAuth(Permission::PaymentRead),
             req.headers(),
         ),
@@ -980,6 +1004,7 @@ pub async fn payments_approve(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 payments::PaymentApprove,
                 payment_types::PaymentsCaptureRequest {
                     payment_id: req.payment_id,
@@ -992,9 +1017,9 @@ pub async fn payments_approve(
             )
         },
         match env::which() {
-            env::Env::Production => &auth::ApiKeyAuth,
+            env::Env::Production => &auth::ScopedApiKeyAuth(ApiKeyScope::PaymentsWrite),
             _ => auth::auth_type(
-                &auth::ApiKeyAuth,
+                &auth::ScopedApiKeyAuth(ApiKeyScope::PaymentsWrite),
                 &auth::JWTAuth(Permission::PaymentWrite),
                 http_req.headers(),
             ),
@@ -1040,6 +1065,7 @@ pub async fn payments_reject(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 payments::PaymentReject,
                 payment_types::PaymentsCancelRequest {
                     payment_id: req.payment_id,
@@ -1053,9 +1079,9 @@ pub async fn payments_reject(
             )
         },
         match env::which() {
-            env::Env::Production => &auth::ApiKeyAuth,
+            env::Env::Production => &auth::ScopedApiKeyAuth(ApiKeyScope::PaymentsWrite),
             _ => auth::auth_type(
-                &auth::ApiKeyAuth,
+                &auth::ScopedApiKeyAuth(ApiKeyScope::PaymentsWrite),
                 &auth::JWTAuth(Permission::PaymentWrite),
                 http_req.headers(),
             ),
@@ -1070,8 +1096,9 @@ async fn authorize_verify_select<Op, Ctx>(
     state: app::AppState,
     merchant_account: domain::MerchantAccount,
     key_store: domain::MerchantKeyStore,
+    profile_id: Option<String>,
     header_payload: HeaderPayload,
-    req: api_models::payments::PaymentsRequest,
+    mut req: api_models::payments::PaymentsRequest,
     auth_flow: api::AuthFlow,
 ) -> app::core::errors::RouterResponse<api_models::payments::PaymentsResponse>
 where
@@ -1095,6 +1122,10 @@ where
     // the operation are flow agnostic, and the flow is only required in the post_update_tracker
     // Thus the flow can be generated just before calling the connector instead of explicitly passing it here.
 
+    // Payments can only be created in, or moved to, the business profile the API key is scoped to
+    req.profile_id =
+        core_utils::validate_profile_id_from_auth_layer(profile_id.clone(), req.profile_id)?;
+
     let eligible_connectors = req.connector.clone();
     match req.payment_type.unwrap_or_default() {
         api_models::enums::PaymentType::Normal
@@ -1111,6 +1142,7 @@ where
                 state,
                 merchant_account,
                 key_store,
+                profile_id,
                 operation,
                 req,
                 auth_flow,
@@ -1132,6 +1164,7 @@ where
                 state,
                 merchant_account,
                 key_store,
+                profile_id,
                 operation,
                 req,
                 auth_flow,
@@ -1194,6 +1227,7 @@ pub async fn payments_incremental_authorization(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 payments::PaymentIncrementalAuthorization,
                 req,
                 api::AuthFlow::Merchant,
@@ -1202,7 +1236,7 @@ pub async fn payments_incremental_authorization(
                 HeaderPayload::default(),
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ScopedApiKeyAuth(ApiKeyScope::PaymentsWrite),
         locking_action,
     ))
     .await
@@ -1260,6 +1294,37 @@ pub async fn payments_external_authentication(
     .await
 }
 
+/// Payments - SDK Events
+///
+/// Records the checkout telemetry events raised by the SDK while checking out the payment
+#[instrument(skip_all, fields(flow = ?Flow::PaymentsSdkEvents, payment_id))]
+pub async fn payments_sdk_events(
+    state: web::Data<app::AppState>,
+    req: actix_web::HttpRequest,
+    json_payload: web::Json<api_models::analytics::sdk_events::SdkTelemetryEventsRequest>,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::PaymentsSdkEvents;
+    let mut payload = json_payload.into_inner();
+    let payment_id = path.into_inner();
+
+    tracing::Span::current().record("payment_id", &payment_id);
+
+    payload.payment_id = payment_id;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        payload,
+        |state, auth, req| {
+            crate::core::sdk_events::ingest_sdk_events(state, auth.merchant_account, req)
+        },
+        &auth::PublishableKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 #[utoipa::path(
     post,
     path = "/payments/{payment_id}/{merchant_id}/authorize/{connector}",
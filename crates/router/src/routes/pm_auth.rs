@@ -15,6 +15,7 @@ pub async fn link_token_create(
     let (auth, _) = match crate::services::authentication::check_client_secret_and_get_auth(
         req.headers(),
         &payload,
+        None,
     ) {
         Ok((auth, _auth_flow)) => (auth, _auth_flow),
         Err(e) => return oss_api::log_and_return_error_response(e),
@@ -49,6 +50,7 @@ pub async fn exchange_token(
     let (auth, _) = match crate::services::authentication::check_client_secret_and_get_auth(
         req.headers(),
         &payload,
+        None,
     ) {
         Ok((auth, _auth_flow)) => (auth, _auth_flow),
         Err(e) => return oss_api::log_and_return_error_response(e),
@@ -10,10 +10,12 @@ use crate::{
     core::{
         api_locking,
         errors::{self, RouterResponse, RouterResult, StorageErrorExt, UserErrors},
+        recon as recon_core,
     },
     services::{
         api as service_api, api,
         authentication::{self as auth, ReconUser, UserFromToken},
+        authorization::permissions::Permission,
         email::types as email_types,
         recon::ReconToken,
     },
@@ -70,6 +72,100 @@ pub async fn get_recon_token(state: web::Data<AppState>, req: HttpRequest) -> Ht
     .await
 }
 
+pub async fn upload_settlement_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<recon_api::SettlementReportUploadRequest>,
+) -> HttpResponse {
+    let flow = Flow::ReconSettlementReportUpload;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req| recon_core::upload_settlement_report(state, auth.merchant_account, req),
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn retrieve_settlement_record(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::ReconSettlementRecordRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        path.into_inner(),
+        |state, auth, record_id| {
+            recon_core::retrieve_settlement_record(state, auth.merchant_account, record_id)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn list_settlement_records(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<recon_api::SettlementRecordListConstraints>,
+) -> HttpResponse {
+    let flow = Flow::ReconSettlementRecordList;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth, constraints| {
+            recon_core::list_settlement_records(state, auth.merchant_account, constraints)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+pub async fn unreconciled_items_report(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    query: web::Query<recon_api::UnreconciledItemsReportRequest>,
+) -> HttpResponse {
+    let flow = Flow::ReconUnreconciledItemsReport;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        query.into_inner(),
+        |state, auth, req| {
+            recon_core::get_unreconciled_items_report(state, auth.merchant_account, req)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::PaymentRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub async fn send_recon_request(
     state: AppState,
     user: UserFromToken,
@@ -0,0 +1,132 @@
+use actix_web::{web, HttpRequest, HttpResponse};
+use api_models::recurring_payments as recurring_payment_types;
+use router_env::{instrument, tracing, Flow};
+
+use super::app::AppState;
+use crate::{
+    core::{api_locking, recurring_payments},
+    services::{api, authentication as auth},
+};
+
+/// Recurring Payments - Create Schedule
+///
+/// Creates a schedule which charges the customer off-session using the given mandate
+#[utoipa::path(
+    post,
+    path = "/recurring_payments",
+    request_body = RecurringPaymentScheduleCreateRequest,
+    responses(
+        (status = 200, description = "Recurring payment schedule created", body = RecurringPaymentScheduleResponse),
+        (status = 400, description = "Invalid data")
+    ),
+    tag = "Recurring Payments",
+    operation_id = "Create a Recurring Payment Schedule",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RecurringPaymentScheduleCreate))]
+pub async fn create_recurring_payment_schedule(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<recurring_payment_types::RecurringPaymentScheduleCreateRequest>,
+) -> HttpResponse {
+    let flow = Flow::RecurringPaymentScheduleCreate;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth, req| {
+            recurring_payments::create_recurring_payment_schedule(state, auth.merchant_account, req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+/// Recurring Payments - Retrieve Schedule
+///
+/// Retrieves a recurring payment schedule along with the progress of its charges
+#[utoipa::path(
+    get,
+    path = "/recurring_payments/{schedule_id}",
+    params(
+        ("schedule_id" = String, Path, description = "The identifier for the recurring payment schedule")
+    ),
+    responses(
+        (status = 200, description = "Recurring payment schedule retrieved", body = RecurringPaymentScheduleResponse),
+        (status = 404, description = "Recurring payment schedule does not exist in our records")
+    ),
+    tag = "Recurring Payments",
+    operation_id = "Retrieve a Recurring Payment Schedule",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RecurringPaymentScheduleRetrieve))]
+pub async fn retrieve_recurring_payment_schedule(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RecurringPaymentScheduleRetrieve;
+    let schedule_id = recurring_payment_types::RecurringPaymentScheduleId {
+        schedule_id: path.into_inner(),
+    };
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        schedule_id,
+        |state, auth, req| {
+            recurring_payments::retrieve_recurring_payment_schedule(
+                state,
+                auth.merchant_account,
+                req,
+            )
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+/// Recurring Payments - Cancel Schedule
+///
+/// Cancels a recurring payment schedule, no further charges or dunning retries are made
+#[utoipa::path(
+    post,
+    path = "/recurring_payments/{schedule_id}/cancel",
+    params(
+        ("schedule_id" = String, Path, description = "The identifier for the recurring payment schedule")
+    ),
+    responses(
+        (status = 200, description = "Recurring payment schedule cancelled", body = RecurringPaymentScheduleResponse),
+        (status = 400, description = "Recurring payment schedule is no longer active"),
+        (status = 404, description = "Recurring payment schedule does not exist in our records")
+    ),
+    tag = "Recurring Payments",
+    operation_id = "Cancel a Recurring Payment Schedule",
+    security(("api_key" = []))
+)]
+#[instrument(skip_all, fields(flow = ?Flow::RecurringPaymentScheduleCancel))]
+pub async fn cancel_recurring_payment_schedule(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> HttpResponse {
+    let flow = Flow::RecurringPaymentScheduleCancel;
+    let schedule_id = recurring_payment_types::RecurringPaymentScheduleId {
+        schedule_id: path.into_inner(),
+    };
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        schedule_id,
+        |state, auth, req| {
+            recurring_payments::cancel_recurring_payment_schedule(state, auth.merchant_account, req)
+        },
+        &auth::ApiKeyAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
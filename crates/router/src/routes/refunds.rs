@@ -1,9 +1,13 @@
 use actix_web::{web, HttpRequest, HttpResponse};
-use router_env::{instrument, tracing, Flow};
+use common_enums::ApiKeyScope;
+use router_env::{instrument, tracing, types, Flow};
 
-use super::app::AppState;
+use super::{app::AppState, lock_utils};
 use crate::{
-    core::{api_locking, refunds::*},
+    core::{
+        api_locking::{self, GetLockingInput},
+        refunds::*,
+    },
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::refunds,
 };
@@ -31,18 +35,30 @@ pub async fn refunds_create(
     json_payload: web::Json<refunds::RefundRequest>,
 ) -> HttpResponse {
     let flow = Flow::RefundsCreate;
+    let payload = json_payload.into_inner();
+    // Refunds of a payment are created one at a time, so that the refund amounts are validated
+    // against the refunds already created for the payment
+    let locking_action = payload.get_locking_input(flow.clone());
     Box::pin(api::server_wrap(
         flow,
         state,
         &req,
-        json_payload.into_inner(),
-        |state, auth, req| refund_create_core(state, auth.merchant_account, auth.key_store, req),
+        payload,
+        |state, auth, req| {
+            refund_create_core(
+                state,
+                auth.merchant_account,
+                auth.key_store,
+                auth.profile_id,
+                req,
+            )
+        },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ScopedApiKeyAuth(ApiKeyScope::RefundsWrite),
             &auth::JWTAuth(Permission::RefundWrite),
             req.headers(),
         ),
-        api_locking::LockAction::NotApplicable,
+        locking_action,
     ))
     .await
 }
@@ -93,12 +109,13 @@ pub async fn refunds_retrieve(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 refund_request,
                 refund_retrieve_core,
             )
         },
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ScopedApiKeyAuth(ApiKeyScope::PaymentsRead),
             &auth::JWTAuth(Permission::RefundRead),
             req.headers(),
         ),
@@ -144,11 +161,12 @@ pub async fn refunds_retrieve_with_body(
                 state,
                 auth.merchant_account,
                 auth.key_store,
+                auth.profile_id,
                 req,
                 refund_retrieve_core,
             )
         },
-        &auth::ApiKeyAuth,
+        &auth::ScopedApiKeyAuth(ApiKeyScope::PaymentsRead),
         api_locking::LockAction::NotApplicable,
     ))
     .await
@@ -187,8 +205,8 @@ pub async fn refunds_update(
         state,
         &req,
         refund_update_req,
-        |state, auth, req| refund_update_core(state, auth.merchant_account, req),
-        &auth::ApiKeyAuth,
+        |state, auth, req| refund_update_core(state, auth.merchant_account, auth.profile_id, req),
+        &auth::ScopedApiKeyAuth(ApiKeyScope::RefundsWrite),
         api_locking::LockAction::NotApplicable,
     )
     .await
@@ -220,9 +238,9 @@ pub async fn refunds_list(
         state,
         &req,
         payload.into_inner(),
-        |state, auth, req| refund_list(state, auth.merchant_account, req),
+        |state, auth, req| refund_list(state, auth.merchant_account, auth.profile_id, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ScopedApiKeyAuth(ApiKeyScope::PaymentsRead),
             &auth::JWTAuth(Permission::RefundRead),
             req.headers(),
         ),
@@ -259,7 +277,7 @@ pub async fn refunds_filter_list(
         payload.into_inner(),
         |state, auth, req| refund_filter_list(state, auth.merchant_account, req),
         auth::auth_type(
-            &auth::ApiKeyAuth,
+            &auth::ScopedApiKeyAuth(ApiKeyScope::PaymentsRead),
             &auth::JWTAuth(Permission::RefundRead),
             req.headers(),
         ),
@@ -267,3 +285,19 @@ pub async fn refunds_filter_list(
     )
     .await
 }
+
+impl GetLockingInput for refunds::RefundRequest {
+    fn get_locking_input<F>(&self, flow: F) -> api_locking::LockAction
+    where
+        F: types::FlowMetric,
+        lock_utils::ApiIdentifier: From<F>,
+    {
+        api_locking::LockAction::Hold {
+            input: api_locking::LockingInput {
+                unique_locking_key: self.payment_id.to_owned(),
+                api_identifier: lock_utils::ApiIdentifier::from(flow),
+                override_lock_retries: None,
+            },
+        }
+    }
+}
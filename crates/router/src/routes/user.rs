@@ -236,6 +236,24 @@ pub async fn switch_merchant_id(
     .await
 }
 
+pub async fn impersonate_merchant(
+    state: web::Data<AppState>,
+    http_req: HttpRequest,
+    json_payload: web::Json<user_api::ImpersonateMerchantRequest>,
+) -> HttpResponse {
+    let flow = Flow::ImpersonateMerchant;
+    Box::pin(api::server_wrap(
+        flow,
+        state.clone(),
+        &http_req,
+        json_payload.into_inner(),
+        |state, user, req| user_core::impersonate_merchant(state, req, user),
+        &auth::DashboardNoPermissionAuth,
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
 pub async fn user_merchant_account_create(
     state: web::Data<AppState>,
     req: HttpRequest,
@@ -0,0 +1,79 @@
+use actix_web::{web, HttpRequest, Responder};
+use api_models::velocity_rules::VelocityRules;
+use router_env::{instrument, tracing, Flow};
+
+use crate::{
+    core::{api_locking, velocity_rules},
+    routes::AppState,
+    services::{api, authentication as auth, authorization::permissions::Permission},
+};
+
+#[instrument(skip_all, fields(flow = ?Flow::VelocityRulesUpsert))]
+pub async fn upsert_velocity_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    json_payload: web::Json<VelocityRules>,
+) -> impl Responder {
+    let flow = Flow::VelocityRulesUpsert;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        json_payload.into_inner(),
+        |state, auth: auth::AuthenticationData, rules| {
+            velocity_rules::upsert_velocity_rules(state, auth.merchant_account, rules)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::VelocityRulesRetrieve))]
+pub async fn retrieve_velocity_rules(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+) -> impl Responder {
+    let flow = Flow::VelocityRulesRetrieve;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _| {
+            velocity_rules::retrieve_velocity_rules(state, auth.merchant_account)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountRead),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::VelocityRulesDelete))]
+pub async fn delete_velocity_rules(state: web::Data<AppState>, req: HttpRequest) -> impl Responder {
+    let flow = Flow::VelocityRulesDelete;
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        (),
+        |state, auth: auth::AuthenticationData, _| {
+            velocity_rules::delete_velocity_rules(state, auth.merchant_account)
+        },
+        auth::auth_type(
+            &auth::ApiKeyAuth,
+            &auth::JWTAuth(Permission::MerchantAccountWrite),
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
@@ -6,7 +6,9 @@ use crate::{
     routes::AppState,
     services::{api, authentication as auth, authorization::permissions::Permission},
     types::api::webhook_events::{
-        EventListConstraints, EventListRequestInternal, WebhookDeliveryAttemptListRequestInternal,
+        EventListConstraints, EventListRequestInternal, EventReplayRequest,
+        EventReplayRequestInternal, WebhookDeliveryAttemptListRequestInternal,
+        WebhookDeliveryRetryRequestInternal, WebhookEndpointHealthRequestInternal,
     },
 };
 
@@ -89,3 +91,119 @@ pub async fn list_webhook_delivery_attempts(
     )
     .await
 }
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventDeliveryRetry))]
+pub async fn retry_webhook_delivery_attempt(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<(String, String)>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventDeliveryRetry;
+    let (merchant_id_or_profile_id, event_id) = path.into_inner();
+
+    let request_internal = WebhookDeliveryRetryRequestInternal {
+        merchant_id_or_profile_id: merchant_id_or_profile_id.clone(),
+        event_id,
+    };
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal| {
+            webhook_events::retry_delivery_attempt(
+                state,
+                request_internal.merchant_id_or_profile_id,
+                request_internal.event_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantOrProfileFromRoute {
+                merchant_id_or_profile_id,
+                required_permission: Permission::WebhookEventWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEventReplay))]
+pub async fn replay_webhook_events(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+    json_payload: web::Json<EventReplayRequest>,
+) -> impl Responder {
+    let flow = Flow::WebhookEventReplay;
+    let merchant_id_or_profile_id = path.into_inner();
+
+    let request_internal = EventReplayRequestInternal {
+        merchant_id_or_profile_id: merchant_id_or_profile_id.clone(),
+        request: json_payload.into_inner(),
+    };
+
+    Box::pin(api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal| {
+            webhook_events::replay_events(
+                state,
+                request_internal.merchant_id_or_profile_id,
+                request_internal.request,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantOrProfileFromRoute {
+                merchant_id_or_profile_id,
+                required_permission: Permission::WebhookEventWrite,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    ))
+    .await
+}
+
+#[instrument(skip_all, fields(flow = ?Flow::WebhookEndpointHealthRetrieve))]
+pub async fn retrieve_webhook_endpoint_health(
+    state: web::Data<AppState>,
+    req: HttpRequest,
+    path: web::Path<String>,
+) -> impl Responder {
+    let flow = Flow::WebhookEndpointHealthRetrieve;
+    let merchant_id_or_profile_id = path.into_inner();
+
+    let request_internal = WebhookEndpointHealthRequestInternal {
+        merchant_id_or_profile_id: merchant_id_or_profile_id.clone(),
+    };
+
+    api::server_wrap(
+        flow,
+        state,
+        &req,
+        request_internal,
+        |state, _, request_internal| {
+            webhook_events::retrieve_endpoint_health(
+                state,
+                request_internal.merchant_id_or_profile_id,
+            )
+        },
+        auth::auth_type(
+            &auth::AdminApiAuth,
+            &auth::JWTAuthMerchantOrProfileFromRoute {
+                merchant_id_or_profile_id,
+                required_permission: Permission::WebhookEventRead,
+            },
+            req.headers(),
+        ),
+        api_locking::LockAction::NotApplicable,
+    )
+    .await
+}
@@ -9,6 +9,8 @@ pub mod logger;
 pub mod pm_auth;
 #[cfg(feature = "recon")]
 pub mod recon;
+#[cfg(feature = "olap")]
+pub mod sftp;
 
 #[cfg(feature = "email")]
 pub mod email;
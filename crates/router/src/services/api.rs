@@ -1,5 +1,7 @@
 pub mod client;
+pub mod deadline;
 pub mod request;
+pub mod versioning;
 use std::{
     collections::HashMap,
     error::Error,
@@ -13,7 +15,7 @@ use actix_web::{
     body, http::header::HeaderValue, web, FromRequest, HttpRequest, HttpResponse, Responder,
     ResponseError,
 };
-use api_models::enums::{CaptureMethod, PaymentMethodType};
+use api_models::enums::{CaptureMethod, PaymentMethod, PaymentMethodType};
 pub use client::{proxy_bypass_urls, ApiClient, MockApiClient, ProxyClient};
 use common_enums::Currency;
 pub use common_utils::request::{ContentType, Method, Request, RequestBuilder};
@@ -105,6 +107,21 @@ pub trait ConnectorValidation: ConnectorCommon {
     fn is_webhook_source_verification_mandatory(&self) -> bool {
         false
     }
+
+    /// Whether the connector supports refunding a part of the captured amount of a payment
+    fn is_partial_refund_supported(&self) -> bool {
+        true
+    }
+
+    /// Whether the connector supports more than one refund against a payment
+    fn is_multiple_refunds_supported(&self) -> bool {
+        true
+    }
+
+    /// Whether the connector can refund the payments made with the payment method back to it
+    fn is_refund_supported(&self, _payment_method: Option<PaymentMethod>) -> bool {
+        true
+    }
 }
 
 #[async_trait::async_trait]
@@ -269,7 +286,17 @@ pub enum CaptureSyncMethod {
 /// Handle the flow by interacting with connector module
 /// `connector_request` is applicable only in case if the `CallConnectorAction` is `Trigger`
 /// In other cases, It will be created if required, even if it is not passed
-#[instrument(skip_all, fields(connector_name, payment_method))]
+#[instrument(
+    skip_all,
+    fields(
+        connector_name,
+        payment_method,
+        hyperswitch.merchant_id_hash,
+        hyperswitch.payment_id,
+        hyperswitch.connector,
+        hyperswitch.flow
+    )
+)]
 pub async fn execute_connector_processing_step<
     'b,
     'a,
@@ -291,6 +318,20 @@ where
     // connector_integration.build_request(req).attach_printable("Failed to build request");
     tracing::Span::current().record("connector_name", &req.connector);
     tracing::Span::current().record("payment_method", &req.payment_method.to_string());
+    router_env::record_request_context!(
+        merchant_id = &req.merchant_id,
+        payment_id = &req.payment_id,
+        attempt_id = &req.attempt_id,
+    );
+    router_env::record_span_attributes!(
+        merchant_id = &req.merchant_id,
+        payment_id = &req.payment_id,
+        connector = &req.connector,
+        flow = std::any::type_name::<T>()
+            .rsplit("::")
+            .next()
+            .unwrap_or_default(),
+    );
     logger::debug!(connector_request=?connector_request);
     let mut router_data = req.clone();
     match call_connector_action {
@@ -299,6 +340,7 @@ where
                 headers: None,
                 response: res.into(),
                 status_code: 200,
+                multipart_parts: None,
             };
             connector_integration.handle_response(req, None, response)
         }
@@ -325,19 +367,16 @@ where
             Ok(router_data)
         }
         payments::CallConnectorAction::Trigger => {
+            let flow = std::any::type_name::<T>()
+                .split("::")
+                .last()
+                .unwrap_or_default();
             metrics::CONNECTOR_CALL_COUNT.add(
                 &metrics::CONTEXT,
                 1,
                 &[
                     metrics::request::add_attributes("connector", req.connector.to_string()),
-                    metrics::request::add_attributes(
-                        "flow",
-                        std::any::type_name::<T>()
-                            .split("::")
-                            .last()
-                            .unwrap_or_default()
-                            .to_string(),
-                    ),
+                    metrics::request::add_attributes("flow", flow.to_string()),
                 ],
             );
 
@@ -365,7 +404,8 @@ where
             };
 
             match connector_request {
-                Some(request) => {
+                Some(mut request) => {
+                    add_trace_context_headers(state, &req.connector, &mut request);
                     let masked_request_body = match &request.body {
                         Some(request) => match request {
                             RequestContent::Json(i)
@@ -384,9 +424,23 @@ where
                     let response =
                         call_connector_api(state, request, "execute_connector_processing_step")
                             .await;
-                    let external_latency = current_time.elapsed().as_millis();
+                    let elapsed = current_time.elapsed();
+                    metrics::request::record_connector_call_metrics(
+                        &req.connector,
+                        flow,
+                        &response,
+                        elapsed,
+                    );
+                    let external_latency = elapsed.as_millis();
                     logger::info!(raw_connector_request=?masked_request_body);
-                    logger::info!(raw_connector_response=?response);
+                    match &response {
+                        Ok(Ok(body)) | Ok(Err(body)) => logger::info!(
+                            raw_connector_response = %get_response_body_preview(&body.response),
+                            response_size = body.response.len(),
+                            status_code = body.status_code,
+                        ),
+                        Err(error) => logger::info!(raw_connector_response=?error),
+                    }
                     let status_code = response
                         .as_ref()
                         .map(|i| {
@@ -534,6 +588,26 @@ where
     }
 }
 
+/// Adds the trace context of the current span to the request, if the trace context is propagated to
+/// the connector
+fn add_trace_context_headers(state: &AppState, connector: &str, request: &mut Request) {
+    let propagates_trace_context =
+        connector
+            .parse::<api_models::enums::Connector>()
+            .is_ok_and(|connector| {
+                state
+                    .conf
+                    .trace_context_propagation
+                    .connectors
+                    .contains(&connector)
+            });
+    if propagates_trace_context {
+        for (header, value) in router_env::trace_context_headers() {
+            request.add_header(&header, value.into());
+        }
+    }
+}
+
 #[instrument(skip_all)]
 pub async fn call_connector_api(
     state: &AppState,
@@ -543,9 +617,22 @@ pub async fn call_connector_api(
     let current_time = Instant::now();
     let headers = request.headers.clone();
     let url = request.url.clone();
+    let read_multipart_response = request.read_multipart_response;
+    // The connector is not called once the client has stopped waiting for the response
+    let option_timeout_secs = state
+        .request_deadline
+        .map(|request_deadline| {
+            request_deadline
+                .get_connector_timeout_secs(consts::REQUEST_TIME_OUT)
+                .ok_or_else(|| {
+                    deadline::record_deadline_exceeded("connector_call");
+                    report!(errors::ApiClientError::RequestDeadlineExceeded)
+                })
+        })
+        .transpose()?;
     let response = state
         .api_client
-        .send_request(state, request, None, true)
+        .send_request(state, request, option_timeout_secs, true)
         .await;
 
     match response.as_ref() {
@@ -567,7 +654,7 @@ pub async fn call_connector_api(
         }
     }
 
-    handle_response(response).await
+    handle_response(response, read_multipart_response).await
 }
 
 #[instrument(skip_all)]
@@ -759,9 +846,10 @@ fn is_connection_closed_before_message_could_complete(error: &reqwest::Error) ->
 #[instrument(skip_all)]
 async fn handle_response(
     response: CustomResult<reqwest::Response, errors::ApiClientError>,
+    read_multipart_response: bool,
 ) -> CustomResult<Result<types::Response, types::Response>, errors::ApiClientError> {
     response
-        .map(|response| async {
+        .map(|response| async move {
             logger::info!(?response);
             let status_code = response.status().as_u16();
             let headers = Some(response.headers().to_owned());
@@ -771,25 +859,23 @@ async fn handle_response(
                     logger::debug!(response=?response);
                     // If needed add log line
                     // logger:: error!( error_parsing_response=?err);
-                    let response = response
-                        .bytes()
-                        .await
-                        .into_report()
-                        .change_context(errors::ApiClientError::ResponseDecodingFailed)
-                        .attach_printable("Error while waiting for response")?;
+                    let (response, multipart_parts) =
+                        read_response_body(response, read_multipart_response)
+                            .await
+                            .attach_printable("Error while waiting for response")?;
                     Ok(Ok(types::Response {
                         headers,
                         response,
                         status_code,
+                        multipart_parts,
                     }))
                 }
 
                 status_code @ 500..=599 => {
-                    let bytes = response.bytes().await.map_err(|error| {
-                        report!(error)
-                            .change_context(errors::ApiClientError::ResponseDecodingFailed)
-                            .attach_printable("Client error response received")
-                    })?;
+                    let (bytes, multipart_parts) =
+                        read_response_body(response, read_multipart_response)
+                            .await
+                            .attach_printable("Client error response received")?;
                     // let error = match status_code {
                     //     500 => errors::ApiClientError::InternalServerErrorReceived,
                     //     502 => errors::ApiClientError::BadGatewayReceived,
@@ -801,15 +887,15 @@ async fn handle_response(
                         headers,
                         response: bytes,
                         status_code,
+                        multipart_parts,
                     }))
                 }
 
                 status_code @ 400..=499 => {
-                    let bytes = response.bytes().await.map_err(|error| {
-                        report!(error)
-                            .change_context(errors::ApiClientError::ResponseDecodingFailed)
-                            .attach_printable("Client error response received")
-                    })?;
+                    let (bytes, multipart_parts) =
+                        read_response_body(response, read_multipart_response)
+                            .await
+                            .attach_printable("Client error response received")?;
                     /* let error = match status_code {
                         400 => errors::ApiClientError::BadRequestReceived(bytes),
                         401 => errors::ApiClientError::UnauthorizedReceived(bytes),
@@ -827,6 +913,7 @@ async fn handle_response(
                         headers,
                         response: bytes,
                         status_code,
+                        multipart_parts,
                     }))
                 }
 
@@ -837,6 +924,135 @@ async fn handle_response(
         .await
 }
 
+/// Reads the body of the response. Bodies received in a single chunk are used as is, while bodies
+/// received in several chunks are read into a buffer allocated upfront from the content length of
+/// the response, rather than one grown as the chunks are received, which spikes memory usage for
+/// large responses. Multipart bodies are split into their parts only when requested by the
+/// connector request, see [`read_multipart_response_body`].
+async fn read_response_body(
+    mut response: reqwest::Response,
+    read_multipart_response: bool,
+) -> CustomResult<(bytes::Bytes, Option<Vec<types::MultipartResponsePart>>), errors::ApiClientError>
+{
+    if read_multipart_response {
+        if let Some(boundary) = get_multipart_boundary(&response) {
+            return read_multipart_response_body(response, boundary)
+                .await
+                .map(|(body, parts)| (body, Some(parts)));
+        }
+    }
+
+    let capacity = response
+        .content_length()
+        .and_then(|content_length| usize::try_from(content_length).ok())
+        .unwrap_or_default()
+        .min(consts::MAX_CONNECTOR_RESPONSE_PREALLOCATION_SIZE);
+
+    let Some(first_chunk) = read_response_chunk(&mut response).await? else {
+        return Ok((bytes::Bytes::new(), None));
+    };
+    let Some(second_chunk) = read_response_chunk(&mut response).await? else {
+        return Ok((first_chunk, None));
+    };
+
+    let mut body = bytes::BytesMut::with_capacity(
+        capacity.max(first_chunk.len().saturating_add(second_chunk.len())),
+    );
+    body.extend_from_slice(&first_chunk);
+    body.extend_from_slice(&second_chunk);
+    while let Some(chunk) = read_response_chunk(&mut response).await? {
+        body.extend_from_slice(&chunk);
+    }
+    Ok((body.freeze(), None))
+}
+
+fn get_multipart_boundary(response: &reqwest::Response) -> Option<String> {
+    response
+        .headers()
+        .get(http::header::CONTENT_TYPE)
+        .and_then(|content_type| content_type.to_str().ok())
+        .and_then(|content_type| content_type.parse::<mime::Mime>().ok())
+        .filter(|content_type| content_type.type_() == mime::MULTIPART)
+        .and_then(|content_type| {
+            content_type
+                .get_param(mime::BOUNDARY)
+                .map(|boundary| boundary.to_string())
+        })
+}
+
+/// Reads the parts of a multipart response, such as one carrying a report file, as they are
+/// received, rather than the entire body being buffered to be split. The content of the first part
+/// holding a file is used as the body of the response, while all the parts are kept along with it.
+async fn read_multipart_response_body(
+    response: reqwest::Response,
+    boundary: String,
+) -> CustomResult<(bytes::Bytes, Vec<types::MultipartResponsePart>), errors::ApiClientError> {
+    let mut multipart = multer::Multipart::new(response.bytes_stream(), boundary);
+    let mut parts = Vec::new();
+
+    while let Some(mut field) = multipart
+        .next_field()
+        .await
+        .into_report()
+        .change_context(errors::ApiClientError::ResponseDecodingFailed)?
+    {
+        let name = field.name().map(ToString::to_string);
+        let file_name = field.file_name().map(ToString::to_string);
+        let content_type = field.content_type().map(ToString::to_string);
+
+        let mut body = bytes::BytesMut::new();
+        while let Some(chunk) = read_multipart_field_chunk(&mut field).await? {
+            body.extend_from_slice(&chunk);
+        }
+
+        parts.push(types::MultipartResponsePart {
+            name,
+            file_name,
+            content_type,
+            body: body.freeze(),
+        });
+    }
+
+    let body = parts
+        .iter()
+        .find(|part| part.file_name.is_some())
+        .map(|part| part.body.clone())
+        .ok_or(errors::ApiClientError::ResponseDecodingFailed)
+        .into_report()
+        .attach_printable("No file part found in the multipart response")?;
+
+    Ok((body, parts))
+}
+
+async fn read_multipart_field_chunk(
+    field: &mut multer::Field<'_>,
+) -> CustomResult<Option<bytes::Bytes>, errors::ApiClientError> {
+    field
+        .chunk()
+        .await
+        .into_report()
+        .change_context(errors::ApiClientError::ResponseDecodingFailed)
+}
+
+async fn read_response_chunk(
+    response: &mut reqwest::Response,
+) -> CustomResult<Option<bytes::Bytes>, errors::ApiClientError> {
+    response
+        .chunk()
+        .await
+        .into_report()
+        .change_context(errors::ApiClientError::ResponseDecodingFailed)
+}
+
+/// Provides the beginning of the body of a connector response to be logged, as formatting large
+/// bodies entirely into log lines allocates several times their size
+fn get_response_body_preview(body: &[u8]) -> std::borrow::Cow<'_, str> {
+    String::from_utf8_lossy(
+        body.get(..consts::MAX_LOGGED_CONNECTOR_RESPONSE_SIZE)
+            .unwrap_or(body),
+    )
+}
+
 #[derive(Debug, Eq, PartialEq)]
 pub enum ApplicationResponse<R> {
     Json(R),
@@ -949,7 +1165,10 @@ pub enum AuthFlow {
     Merchant,
 }
 
-#[instrument(skip(request, payload, state, func, api_auth), fields(merchant_id))]
+#[instrument(
+    skip(request, payload, state, func, api_auth),
+    fields(merchant_id, hyperswitch.merchant_id_hash, hyperswitch.flow)
+)]
 pub async fn server_wrap_util<'a, 'b, A, U, T, Q, F, Fut, E, OErr>(
     flow: &'a impl router_env::types::FlowMetric,
     state: web::Data<A>,
@@ -1000,9 +1219,27 @@ where
 
     request_state.add_merchant_id(Some(merchant_id.clone()));
 
+    request_state.add_authentication_type(auth_type.clone());
+
     request_state.add_flow_name(flow.to_string());
 
-    tracing::Span::current().record("merchant_id", &merchant_id);
+    router_env::record_request_context!(merchant_id = &merchant_id, flow = flow);
+    router_env::record_span_attributes!(flow = flow);
+    if let Some(merchant_id) = auth_type.get_merchant_id() {
+        router_env::record_span_attributes!(merchant_id = merchant_id);
+    }
+
+    request_state.add_request_deadline(request.headers());
+    if request_state
+        .get_request_deadline()
+        .is_some_and(|request_deadline| request_deadline.is_exceeded())
+    {
+        deadline::record_deadline_exceeded("request_processing");
+        return Err(report!(
+            errors::ApiErrorResponse::RequestDeadlineExceeded.switch()
+        ))
+        .attach_printable_lazy(|| format!("Deadline of the request exceeded for flow {flow}"));
+    }
 
     let output = {
         lock_action
@@ -2081,8 +2318,66 @@ pub fn get_payment_link_status(
 
 #[cfg(test)]
 mod tests {
+    #![allow(clippy::unwrap_used)]
+
     #[test]
     fn test_mime_essence() {
         assert_eq!(mime::APPLICATION_JSON.essence_str(), "application/json");
     }
+
+    #[test]
+    fn test_response_body_preview() {
+        let body = vec![b'a'; crate::consts::MAX_LOGGED_CONNECTOR_RESPONSE_SIZE + 1];
+        assert_eq!(
+            super::get_response_body_preview(&body).len(),
+            crate::consts::MAX_LOGGED_CONNECTOR_RESPONSE_SIZE
+        );
+        assert_eq!(super::get_response_body_preview(b"{}"), "{}");
+    }
+
+    #[actix_rt::test]
+    async fn test_read_multipart_response_body() {
+        let body = concat!(
+            "--report\r\n",
+            "Content-Type: application/json\r\n\r\n",
+            "{\"report_id\":\"report_1\"}\r\n",
+            "--report\r\n",
+            "Content-Disposition: attachment; name=\"file\"; filename=\"report.csv\"\r\n",
+            "Content-Type: text/csv\r\n\r\n",
+            "payment_id,amount\r\npay_1,100\r\n",
+            "--report--\r\n"
+        );
+        let build_response = || {
+            reqwest::Response::from(
+                http::Response::builder()
+                    .header(
+                        http::header::CONTENT_TYPE,
+                        "multipart/mixed; boundary=report",
+                    )
+                    .body(body)
+                    .unwrap(),
+            )
+        };
+
+        let (response, parts) = super::read_response_body(build_response(), true)
+            .await
+            .unwrap();
+        assert_eq!(response, "payment_id,amount\r\npay_1,100");
+        let parts = parts.unwrap();
+        assert_eq!(parts.len(), 2);
+        assert_eq!(
+            parts.first().map(|part| part.body.clone()).unwrap(),
+            "{\"report_id\":\"report_1\"}"
+        );
+        assert_eq!(
+            parts.last().and_then(|part| part.file_name.clone()),
+            Some("report.csv".to_string())
+        );
+
+        let (response, parts) = super::read_response_body(build_response(), false)
+            .await
+            .unwrap();
+        assert_eq!(response, body);
+        assert!(parts.is_none());
+    }
 }
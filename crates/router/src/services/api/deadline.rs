@@ -0,0 +1,117 @@
+//! Deadlines of the requests received by the router.
+//!
+//! Clients can send the time in milliseconds they are willing to wait for a response in the
+//! `X-Request-Timeout` header, the default timeout configured for requests being used when the
+//! header is absent. The deadline derived from the timeout is carried by the state of the request
+//! through the core flows, bounding the timeouts of the calls made to connectors. Requests whose
+//! deadline is exceeded are not processed further, as their clients have stopped waiting for them.
+
+use std::time::{Duration, Instant};
+
+use actix_web::http::header::HeaderMap;
+
+use crate::{configs::settings, headers, routes::metrics};
+
+#[derive(Clone, Copy, Debug)]
+pub struct RequestDeadline {
+    deadline: Instant,
+}
+
+impl RequestDeadline {
+    pub fn from_headers(headers: &HeaderMap, config: &settings::RequestDeadlineConfig) -> Self {
+        let client_timeout_ms = headers
+            .get(headers::X_REQUEST_TIMEOUT)
+            .and_then(|timeout| timeout.to_str().ok())
+            .and_then(|timeout| timeout.trim().parse::<u64>().ok());
+
+        Self {
+            deadline: Instant::now() + get_request_timeout(client_timeout_ms, config),
+        }
+    }
+
+    /// Time remaining before the deadline, `None` once the deadline is exceeded
+    pub fn remaining(&self) -> Option<Duration> {
+        self.deadline
+            .checked_duration_since(Instant::now())
+            .filter(|remaining| !remaining.is_zero())
+    }
+
+    pub fn is_exceeded(&self) -> bool {
+        self.remaining().is_none()
+    }
+
+    /// Timeout in seconds of a call to a connector, bounded by the time remaining before the
+    /// deadline, `None` once the deadline is exceeded
+    pub fn get_connector_timeout_secs(&self, default_timeout_secs: u64) -> Option<u64> {
+        self.remaining()
+            .map(|remaining| get_timeout_secs(remaining).min(default_timeout_secs))
+    }
+}
+
+/// Timeout of the request requested by the client, bounded by the maximum timeout configured
+fn get_request_timeout(
+    client_timeout_ms: Option<u64>,
+    config: &settings::RequestDeadlineConfig,
+) -> Duration {
+    Duration::from_millis(
+        client_timeout_ms
+            .filter(|timeout| *timeout > 0)
+            .unwrap_or(config.default_timeout_ms)
+            .min(config.max_timeout_ms),
+    )
+}
+
+/// Duration rounded up to whole seconds, as the timeouts of connector calls are in seconds
+fn get_timeout_secs(duration: Duration) -> u64 {
+    if duration.subsec_nanos() > 0 {
+        duration.as_secs().saturating_add(1)
+    } else {
+        duration.as_secs()
+    }
+}
+
+/// Records a request whose deadline was exceeded at the stage of its processing
+pub fn record_deadline_exceeded(stage: &'static str) {
+    metrics::REQUEST_DEADLINE_EXCEEDED.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes("stage", stage)],
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_request_timeout() {
+        let config = settings::RequestDeadlineConfig {
+            default_timeout_ms: 60000,
+            max_timeout_ms: 120000,
+        };
+
+        assert_eq!(
+            get_request_timeout(Some(5000), &config),
+            Duration::from_millis(5000)
+        );
+        assert_eq!(
+            get_request_timeout(None, &config),
+            Duration::from_millis(60000)
+        );
+        assert_eq!(
+            get_request_timeout(Some(0), &config),
+            Duration::from_millis(60000)
+        );
+        assert_eq!(
+            get_request_timeout(Some(600000), &config),
+            Duration::from_millis(120000)
+        );
+    }
+
+    #[test]
+    fn test_get_timeout_secs() {
+        assert_eq!(get_timeout_secs(Duration::from_millis(2000)), 2);
+        assert_eq!(get_timeout_secs(Duration::from_millis(2001)), 3);
+        assert_eq!(get_timeout_secs(Duration::from_millis(1)), 1);
+    }
+}
@@ -0,0 +1,258 @@
+//! Versioning of the shapes of the request bodies accepted by the router.
+//!
+//! Clients pin the API version their integration was built against in the `Hyperswitch-Version`
+//! header. The bodies of requests made with an older version are translated into the shape of the
+//! current version by the transformers registered for the route, before being deserialized into
+//! the request types of `api_models`. Responses to such requests carry headers warning the client
+//! that the version it uses is deprecated.
+
+use std::str::FromStr;
+
+use actix_web::http::{header::HeaderMap, Method};
+use api_models::api_versioning::ApiVersion;
+use serde_json::{Map, Value};
+
+use crate::{core::errors, headers};
+
+/// Translates the body of a request from the shape of the previous version of the API into the
+/// shape of `introduced_in`
+struct RequestTransformer {
+    method: Method,
+    /// Pattern of the route as registered with the application
+    route: &'static str,
+    introduced_in: ApiVersion,
+    transform: fn(&mut Map<String, Value>),
+}
+
+fn get_request_transformers() -> [RequestTransformer; 3] {
+    [
+        RequestTransformer {
+            method: Method::POST,
+            route: "/payments",
+            introduced_in: ApiVersion::V2024_05_01,
+            transform: move_customer_details_into_customer_object,
+        },
+        RequestTransformer {
+            method: Method::POST,
+            route: "/payments/{payment_id}",
+            introduced_in: ApiVersion::V2024_05_01,
+            transform: move_customer_details_into_customer_object,
+        },
+        RequestTransformer {
+            method: Method::POST,
+            route: "/payments/{payment_id}/confirm",
+            introduced_in: ApiVersion::V2024_05_01,
+            transform: move_customer_details_into_customer_object,
+        },
+    ]
+}
+
+/// Provides the API version requested by the client, the current version being used when the
+/// header is absent
+pub fn get_api_version(headers: &HeaderMap) -> Result<ApiVersion, errors::ApiErrorResponse> {
+    headers
+        .get(headers::HYPERSWITCH_VERSION)
+        .map(|version| {
+            version
+                .to_str()
+                .ok()
+                .and_then(|version| ApiVersion::from_str(version.trim()).ok())
+                .ok_or_else(|| errors::ApiErrorResponse::InvalidRequestData {
+                    message: format!(
+                        "Unsupported value for the {} header, the current version is {}",
+                        headers::HYPERSWITCH_VERSION,
+                        ApiVersion::CURRENT
+                    ),
+                })
+        })
+        .transpose()
+        .map(|version| version.unwrap_or(ApiVersion::CURRENT))
+}
+
+/// Provides the transformers of the route for the versions released after the requested version,
+/// in the order the versions were released in
+fn get_applicable_transformers(
+    version: ApiVersion,
+    method: &Method,
+    route: &str,
+) -> Vec<RequestTransformer> {
+    let mut transformers = get_request_transformers()
+        .into_iter()
+        .filter(|transformer| {
+            version < transformer.introduced_in
+                && transformer.method == method
+                && transformer.route == route
+        })
+        .collect::<Vec<_>>();
+    transformers.sort_by_key(|transformer| transformer.introduced_in);
+    transformers
+}
+
+/// Whether the body of a request to the route must be translated for the version
+pub fn requires_transformation(version: ApiVersion, method: &Method, route: &str) -> bool {
+    !get_applicable_transformers(version, method, route).is_empty()
+}
+
+/// Translates the body of a request to the route into the shape of the current version. `None` is
+/// returned for bodies which are not JSON objects, which are to be passed on as is for the request
+/// handler to report them as invalid.
+pub fn transform_request_body(
+    version: ApiVersion,
+    method: &Method,
+    route: &str,
+    body: &[u8],
+) -> Option<Vec<u8>> {
+    let Ok(Value::Object(mut request)) = serde_json::from_slice::<Value>(body) else {
+        return None;
+    };
+
+    for transformer in get_applicable_transformers(version, method, route) {
+        (transformer.transform)(&mut request);
+    }
+
+    serde_json::to_vec(&request).ok()
+}
+
+/// Provides the headers to be added to the response of a request made with the version
+pub fn get_deprecation_headers(version: ApiVersion) -> Vec<(&'static str, String)> {
+    let mut response_headers = vec![(headers::HYPERSWITCH_VERSION, version.to_string())];
+    if version.is_deprecated() {
+        response_headers.push((headers::DEPRECATION, "true".to_string()));
+        response_headers.push((
+            headers::WARNING,
+            format!(
+                "299 - \"API version {version} is deprecated, migrate to API version {}\"",
+                ApiVersion::CURRENT
+            ),
+        ));
+    }
+    response_headers
+}
+
+/// The customer details of a payment were passed as top level fields before they were grouped in
+/// the `customer` object. The fields are moved only if the customer is identified and the
+/// `customer` object is not passed as well.
+fn move_customer_details_into_customer_object(request: &mut Map<String, Value>) {
+    const CUSTOMER_FIELDS: [(&str, &str); 5] = [
+        ("customer_id", "id"),
+        ("email", "email"),
+        ("name", "name"),
+        ("phone", "phone"),
+        ("phone_country_code", "phone_country_code"),
+    ];
+
+    let has_customer_object = request
+        .get("customer")
+        .is_some_and(|customer| !customer.is_null());
+    let has_customer_id = request
+        .get("customer_id")
+        .is_some_and(|customer_id| !customer_id.is_null());
+    if has_customer_object || !has_customer_id {
+        return;
+    }
+
+    let customer = CUSTOMER_FIELDS
+        .into_iter()
+        .filter_map(|(field, customer_field)| {
+            request
+                .remove(field)
+                .filter(|value| !value.is_null())
+                .map(|value| (customer_field.to_string(), value))
+        })
+        .collect::<Map<_, _>>();
+    request.insert("customer".to_string(), Value::Object(customer));
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use actix_web::http::header::HeaderValue;
+    use serde_json::json;
+
+    use super::*;
+
+    fn transform(version: ApiVersion, route: &str, request: Value) -> Value {
+        let body = serde_json::to_vec(&request).unwrap();
+        transform_request_body(version, &Method::POST, route, &body)
+            .map(|body| serde_json::from_slice(&body).unwrap())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_api_version_from_headers() {
+        let mut request_headers = HeaderMap::new();
+        assert_eq!(
+            get_api_version(&request_headers).unwrap(),
+            ApiVersion::CURRENT
+        );
+
+        request_headers.insert(
+            actix_web::http::header::HeaderName::from_static("hyperswitch-version"),
+            HeaderValue::from_static("2023-10-01"),
+        );
+        assert_eq!(
+            get_api_version(&request_headers).unwrap(),
+            ApiVersion::V2023_10_01
+        );
+
+        request_headers.insert(
+            actix_web::http::header::HeaderName::from_static("hyperswitch-version"),
+            HeaderValue::from_static("v1"),
+        );
+        assert!(get_api_version(&request_headers).is_err());
+    }
+
+    #[test]
+    fn test_customer_details_are_moved_into_customer_object() {
+        let request = json!({
+            "amount": 6540,
+            "customer_id": "cus_abcdefgh",
+            "email": "guest@example.com",
+            "name": null,
+        });
+
+        assert!(requires_transformation(
+            ApiVersion::V2023_10_01,
+            &Method::POST,
+            "/payments"
+        ));
+        assert_eq!(
+            transform(ApiVersion::V2023_10_01, "/payments", request),
+            json!({
+                "amount": 6540,
+                "customer": {"id": "cus_abcdefgh", "email": "guest@example.com"},
+            })
+        );
+    }
+
+    #[test]
+    fn test_customer_object_is_not_overwritten() {
+        let request = json!({
+            "customer": {"id": "cus_abcdefgh"},
+            "customer_id": "cus_abcdefgh",
+            "email": "guest@example.com",
+        });
+
+        assert_eq!(
+            transform(ApiVersion::V2023_10_01, "/payments", request.clone()),
+            request
+        );
+    }
+
+    #[test]
+    fn test_current_version_is_not_transformed() {
+        assert!(!requires_transformation(
+            ApiVersion::CURRENT,
+            &Method::POST,
+            "/payments"
+        ));
+        assert!(!requires_transformation(
+            ApiVersion::V2023_10_01,
+            &Method::GET,
+            "/payments/{payment_id}"
+        ));
+        assert_eq!(get_deprecation_headers(ApiVersion::CURRENT).len(), 1);
+        assert_eq!(get_deprecation_headers(ApiVersion::V2023_10_01).len(), 3);
+    }
+}
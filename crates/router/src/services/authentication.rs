@@ -1,6 +1,7 @@
 use actix_web::http::header::HeaderMap;
 use api_models::{payment_methods::PaymentMethodListRequest, payments};
 use async_trait::async_trait;
+use common_enums::{ApiKeyScope, PermissionGroup};
 use common_utils::date_time;
 use error_stack::{report, IntoReport, ResultExt};
 use jsonwebtoken::{decode, Algorithm, DecodingKey, Validation};
@@ -40,6 +41,9 @@ pub mod cookies;
 pub struct AuthenticationData {
     pub merchant_account: domain::MerchantAccount,
     pub key_store: domain::MerchantKeyStore,
+    /// The business profile to which the request is restricted, set when authenticated using an
+    /// API key scoped to a business profile
+    pub profile_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize)]
@@ -58,6 +62,11 @@ pub enum AuthenticationType {
         merchant_id: String,
         user_id: Option<String>,
     },
+    ImpersonatedJwt {
+        merchant_id: String,
+        impersonator_id: String,
+        session_id: String,
+    },
     UserJwt {
         user_id: String,
     },
@@ -67,6 +76,10 @@ pub enum AuthenticationType {
     PublishableKey {
         merchant_id: String,
     },
+    ClientSession {
+        merchant_id: String,
+        payment_id: String,
+    },
     WebhookAuth {
         merchant_id: String,
     },
@@ -86,6 +99,8 @@ impl AuthenticationType {
                 merchant_id,
                 user_id: _,
             }
+            | Self::ImpersonatedJwt { merchant_id, .. }
+            | Self::ClientSession { merchant_id, .. }
             | Self::WebhookAuth { merchant_id } => Some(merchant_id.as_ref()),
             Self::AdminApiKey | Self::UserJwt { .. } | Self::NoAuth => None,
         }
@@ -120,6 +135,17 @@ pub struct AuthToken {
     pub role_id: String,
     pub exp: u64,
     pub org_id: String,
+    /// Present when the token was issued to an internal user impersonating the merchant, in
+    /// which case `user_id` identifies the impersonator
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub impersonation: Option<ImpersonationClaims>,
+}
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ImpersonationClaims {
+    pub session_id: String,
+    /// The permission groups the impersonation session is restricted to
+    pub groups: Vec<PermissionGroup>,
 }
 
 #[cfg(feature = "olap")]
@@ -139,11 +165,50 @@ impl AuthToken {
             role_id,
             exp,
             org_id,
+            impersonation: None,
+        };
+        jwt::generate_jwt(&token_payload, settings).await
+    }
+
+    pub async fn new_impersonation_token(
+        impersonator_id: String,
+        merchant_id: String,
+        org_id: String,
+        impersonation: ImpersonationClaims,
+        duration: std::time::Duration,
+        settings: &Settings,
+    ) -> UserResult<String> {
+        let exp = jwt::generate_exp(duration)?.as_secs();
+        let token_payload = Self {
+            user_id: impersonator_id,
+            merchant_id,
+            role_id: consts::user_role::ROLE_ID_INTERNAL_IMPERSONATION.to_string(),
+            exp,
+            org_id,
+            impersonation: Some(impersonation),
         };
         jwt::generate_jwt(&token_payload, settings).await
     }
 }
 
+impl AuthToken {
+    /// Requests made in an impersonation session are attributed to the impersonator and the
+    /// session in the API event logs
+    fn get_authentication_type(&self, user_id: Option<String>) -> AuthenticationType {
+        match &self.impersonation {
+            Some(impersonation) => AuthenticationType::ImpersonatedJwt {
+                merchant_id: self.merchant_id.clone(),
+                impersonator_id: self.user_id.clone(),
+                session_id: impersonation.session_id.clone(),
+            },
+            None => AuthenticationType::MerchantJwt {
+                merchant_id: self.merchant_id.clone(),
+                user_id,
+            },
+        }
+    }
+}
+
 #[derive(Clone)]
 pub struct UserFromToken {
     pub user_id: String,
@@ -180,9 +245,15 @@ where
     ) -> RouterResult<(T, AuthenticationType)>;
 }
 
+/// Authentication with an API key which is not restricted to any scopes.
 #[derive(Debug)]
 pub struct ApiKeyAuth;
 
+/// Authentication with an API key which is either not restricted to any scopes, or is restricted
+/// to scopes including the given one.
+#[derive(Debug)]
+pub struct ScopedApiKeyAuth(pub ApiKeyScope);
+
 pub struct NoAuth;
 
 #[async_trait]
@@ -209,68 +280,133 @@ where
         request_headers: &HeaderMap,
         state: &A,
     ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
-        let api_key = get_api_key(request_headers)
-            .change_context(errors::ApiErrorResponse::Unauthorized)?
-            .trim();
-        if api_key.is_empty() {
-            return Err(errors::ApiErrorResponse::Unauthorized)
-                .into_report()
-                .attach_printable("API key is empty");
-        }
+        authenticate_api_key(request_headers, state, None).await
+    }
+}
 
-        let api_key = api_keys::PlaintextApiKey::from(api_key);
-        let hash_key = {
-            let config = state.conf();
-            config.api_keys.get_inner().get_hash_key()?
-        };
-        let hashed_api_key = api_key.keyed_hash(hash_key.peek());
+#[async_trait]
+impl<A> AuthenticateAndFetch<AuthenticationData, A> for ScopedApiKeyAuth
+where
+    A: AppStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
+        authenticate_api_key(request_headers, state, Some(self.0)).await
+    }
+}
 
-        let stored_api_key = state
-            .store()
-            .find_api_key_by_hash_optional(hashed_api_key.into())
-            .await
-            .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
-            .attach_printable("Failed to retrieve API key")?
-            .ok_or(report!(errors::ApiErrorResponse::Unauthorized)) // If retrieve returned `None`
-            .attach_printable("Merchant not authenticated")?;
-
-        if stored_api_key
-            .expires_at
-            .map(|expires_at| expires_at < date_time::now())
-            .unwrap_or(false)
-        {
-            return Err(report!(errors::ApiErrorResponse::Unauthorized))
-                .attach_printable("API key has expired");
-        }
+async fn authenticate_api_key<A>(
+    request_headers: &HeaderMap,
+    state: &A,
+    required_scope: Option<ApiKeyScope>,
+) -> RouterResult<(AuthenticationData, AuthenticationType)>
+where
+    A: AppStateInfo + Sync,
+{
+    let api_key = get_api_key(request_headers)
+        .change_context(errors::ApiErrorResponse::Unauthorized)?
+        .trim();
+    if api_key.is_empty() {
+        return Err(errors::ApiErrorResponse::Unauthorized)
+            .into_report()
+            .attach_printable("API key is empty");
+    }
 
-        let key_store = state
-            .store()
-            .get_merchant_key_store_by_merchant_id(
-                &stored_api_key.merchant_id,
-                &state.store().get_master_key().to_vec().into(),
-            )
-            .await
-            .change_context(errors::ApiErrorResponse::Unauthorized)
-            .attach_printable("Failed to fetch merchant key store for the merchant id")?;
+    let api_key = api_keys::PlaintextApiKey::from(api_key);
+    let hash_key = {
+        let config = state.conf();
+        config.api_keys.get_inner().get_hash_key()?
+    };
+    let hashed_api_key = api_key.keyed_hash(hash_key.peek());
 
-        let merchant = state
-            .store()
-            .find_merchant_account_by_merchant_id(&stored_api_key.merchant_id, &key_store)
-            .await
-            .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
+    let stored_api_key = state
+        .store()
+        .find_api_key_by_hash_optional(hashed_api_key.into())
+        .await
+        .change_context(errors::ApiErrorResponse::InternalServerError) // If retrieve failed
+        .attach_printable("Failed to retrieve API key")?
+        .ok_or(report!(errors::ApiErrorResponse::Unauthorized)) // If retrieve returned `None`
+        .attach_printable("Merchant not authenticated")?;
+
+    if stored_api_key
+        .expires_at
+        .map(|expires_at| expires_at < date_time::now())
+        .unwrap_or(false)
+    {
+        return Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("API key has expired");
+    }
 
-        let auth = AuthenticationData {
-            merchant_account: merchant,
-            key_store,
-        };
-        Ok((
-            auth.clone(),
-            AuthenticationType::ApiKey {
-                merchant_id: auth.merchant_account.merchant_id.clone(),
-                key_id: stored_api_key.key_id,
-            },
-        ))
+    ensure_api_key_has_scope(&stored_api_key, required_scope)?;
+    api_keys::update_last_used(&*state.store(), &stored_api_key).await;
+
+    let key_store = state
+        .store()
+        .get_merchant_key_store_by_merchant_id(
+            &stored_api_key.merchant_id,
+            &state.store().get_master_key().to_vec().into(),
+        )
+        .await
+        .change_context(errors::ApiErrorResponse::Unauthorized)
+        .attach_printable("Failed to fetch merchant key store for the merchant id")?;
+
+    let merchant = state
+        .store()
+        .find_merchant_account_by_merchant_id(&stored_api_key.merchant_id, &key_store)
+        .await
+        .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
+
+    ensure_merchant_account_is_active(&merchant)?;
+
+    let auth = AuthenticationData {
+        merchant_account: merchant,
+        key_store,
+        profile_id: stored_api_key.profile_id,
+    };
+    Ok((
+        auth.clone(),
+        AuthenticationType::ApiKey {
+            merchant_id: auth.merchant_account.merchant_id.clone(),
+            key_id: stored_api_key.key_id,
+        },
+    ))
+}
+
+/// API keys restricted to scopes can only access the routes requiring one of their scopes, while
+/// API keys without any scopes are not restricted
+fn ensure_api_key_has_scope(
+    api_key: &diesel_models::api_keys::ApiKey,
+    required_scope: Option<ApiKeyScope>,
+) -> RouterResult<()> {
+    let Some(scopes) = api_key.scopes.as_ref().filter(|scopes| !scopes.is_empty()) else {
+        return Ok(());
+    };
+
+    if required_scope.is_some_and(|required_scope| scopes.contains(&required_scope)) {
+        Ok(())
+    } else {
+        Err(report!(errors::ApiErrorResponse::AccessForbidden {
+            resource: required_scope
+                .map(|required_scope| required_scope.to_string())
+                .unwrap_or_else(|| "this API".to_string()),
+        }))
+        .attach_printable("API key is not permitted the scope required by the route")
+    }
+}
+
+/// Deleted merchant accounts are retained until they are permanently deleted, but can no longer
+/// be used to process any traffic
+fn ensure_merchant_account_is_active(
+    merchant_account: &domain::MerchantAccount,
+) -> RouterResult<()> {
+    if merchant_account.is_deleted() {
+        return Err(report!(errors::ApiErrorResponse::Unauthorized))
+            .attach_printable("Merchant account has been deleted");
     }
+    Ok(())
 }
 
 #[derive(Debug)]
@@ -372,9 +508,12 @@ where
                 }
             })?;
 
+        ensure_merchant_account_is_active(&merchant)?;
+
         let auth = AuthenticationData {
             merchant_account: merchant,
             key_store,
+            profile_id: None,
         };
         Ok((
             auth.clone(),
@@ -385,6 +524,73 @@ where
     }
 }
 
+/// Authentication with a client session minted from the publishable key, restricted to the
+/// payment and the customer the client session is bound to.
+#[derive(Debug)]
+pub struct ClientSessionAuth {
+    pub payment_id: String,
+    pub customer_id: Option<String>,
+    /// Whether the client session is consumed, for operations which must not be replayed
+    pub consume: bool,
+}
+
+#[async_trait]
+impl<A> AuthenticateAndFetch<AuthenticationData, A> for ClientSessionAuth
+where
+    A: AppStateInfo + Sync,
+{
+    async fn authenticate_and_fetch(
+        &self,
+        request_headers: &HeaderMap,
+        state: &A,
+    ) -> RouterResult<(AuthenticationData, AuthenticationType)> {
+        let token = get_api_key(request_headers)?;
+        let client_session = state
+            .store()
+            .find_client_session(token)
+            .await
+            .change_context(errors::ApiErrorResponse::Unauthorized)
+            .attach_printable("Failed to fetch the client session")?;
+
+        if client_session.expires < date_time::now_unix_timestamp() {
+            return Err(report!(errors::ApiErrorResponse::Unauthorized))
+                .attach_printable("Client session has expired");
+        }
+        if client_session.payment_id != self.payment_id {
+            return Err(report!(errors::ApiErrorResponse::Unauthorized))
+                .attach_printable("Client session is bound to another payment");
+        }
+        if self
+            .customer_id
+            .as_ref()
+            .is_some_and(|customer_id| client_session.customer_id.as_ref() != Some(customer_id))
+        {
+            return Err(report!(errors::ApiErrorResponse::Unauthorized))
+                .attach_printable("Client session is bound to another customer");
+        }
+
+        if self.consume {
+            state
+                .store()
+                .consume_client_session(token)
+                .await
+                .change_context(errors::ApiErrorResponse::Unauthorized)
+                .attach_printable("Client session was already used")?;
+        }
+
+        let (auth, _) = MerchantIdAuth(client_session.merchant_id)
+            .authenticate_and_fetch(request_headers, state)
+            .await?;
+        Ok((
+            auth.clone(),
+            AuthenticationType::ClientSession {
+                merchant_id: auth.merchant_account.merchant_id,
+                payment_id: client_session.payment_id,
+            },
+        ))
+    }
+}
+
 #[derive(Debug)]
 pub struct PublishableKeyAuth;
 
@@ -401,7 +607,7 @@ where
         let publishable_key =
             get_api_key(request_headers).change_context(errors::ApiErrorResponse::Unauthorized)?;
 
-        state
+        let auth = state
             .store()
             .find_merchant_account_by_publishable_key(publishable_key)
             .await
@@ -411,15 +617,16 @@ where
                 } else {
                     e.change_context(errors::ApiErrorResponse::InternalServerError)
                 }
-            })
-            .map(|auth| {
-                (
-                    auth.clone(),
-                    AuthenticationType::PublishableKey {
-                        merchant_id: auth.merchant_account.merchant_id.clone(),
-                    },
-                )
-            })
+            })?;
+
+        ensure_merchant_account_is_active(&auth.merchant_account)?;
+
+        Ok((
+            auth.clone(),
+            AuthenticationType::PublishableKey {
+                merchant_id: auth.merchant_account.merchant_id.clone(),
+            },
+        ))
     }
 }
 
@@ -452,10 +659,7 @@ where
 
         Ok((
             (),
-            AuthenticationType::MerchantJwt {
-                merchant_id: payload.merchant_id,
-                user_id: Some(payload.user_id),
-            },
+            payload.get_authentication_type(Some(payload.user_id.clone())),
         ))
     }
 }
@@ -479,17 +683,15 @@ where
         let permissions = authorization::get_permissions(state, &payload).await?;
         authorization::check_authorization(&self.0, &permissions)?;
 
+        let authentication_type = payload.get_authentication_type(Some(payload.user_id.clone()));
         Ok((
             UserFromToken {
-                user_id: payload.user_id.clone(),
-                merchant_id: payload.merchant_id.clone(),
+                user_id: payload.user_id,
+                merchant_id: payload.merchant_id,
                 org_id: payload.org_id,
                 role_id: payload.role_id,
             },
-            AuthenticationType::MerchantJwt {
-                merchant_id: payload.merchant_id,
-                user_id: Some(payload.user_id),
-            },
+            authentication_type,
         ))
     }
 }
@@ -523,10 +725,7 @@ where
         }
         Ok((
             (),
-            AuthenticationType::MerchantJwt {
-                merchant_id: payload.merchant_id,
-                user_id: Some(payload.user_id),
-            },
+            payload.get_authentication_type(Some(payload.user_id.clone())),
         ))
     }
 }
@@ -558,10 +757,7 @@ where
         if payload.merchant_id == self.merchant_id_or_profile_id {
             return Ok((
                 (),
-                AuthenticationType::MerchantJwt {
-                    merchant_id: payload.merchant_id,
-                    user_id: Some(payload.user_id),
-                },
+                payload.get_authentication_type(Some(payload.user_id.clone())),
             ));
         }
 
@@ -582,10 +778,7 @@ where
         if payload.merchant_id == business_profile.merchant_id {
             Ok((
                 (),
-                AuthenticationType::MerchantJwt {
-                    merchant_id: payload.merchant_id,
-                    user_id: Some(payload.user_id),
-                },
+                payload.get_authentication_type(Some(payload.user_id.clone())),
             ))
         } else {
             Err(report!(errors::ApiErrorResponse::InvalidJwtToken))
@@ -638,17 +831,14 @@ where
             .await
             .change_context(errors::ApiErrorResponse::InvalidJwtToken)?;
 
+        ensure_merchant_account_is_active(&merchant)?;
+
         let auth = AuthenticationData {
             merchant_account: merchant,
             key_store,
+            profile_id: None,
         };
-        Ok((
-            auth.clone(),
-            AuthenticationType::MerchantJwt {
-                merchant_id: auth.merchant_account.merchant_id.clone(),
-                user_id: None,
-            },
-        ))
+        Ok((auth.clone(), payload.get_authentication_type(None)))
     }
 }
 
@@ -688,16 +878,16 @@ where
             .await
             .change_context(errors::ApiErrorResponse::InvalidJwtToken)?;
 
+        ensure_merchant_account_is_active(&merchant)?;
+
         let auth = AuthenticationData {
             merchant_account: merchant,
             key_store,
+            profile_id: None,
         };
         Ok((
             (auth.clone(), payload.user_id.clone()),
-            AuthenticationType::MerchantJwt {
-                merchant_id: auth.merchant_account.merchant_id.clone(),
-                user_id: None,
-            },
+            payload.get_authentication_type(None),
         ))
     }
 }
@@ -720,17 +910,15 @@ where
             return Err(errors::ApiErrorResponse::InvalidJwtToken.into());
         }
 
+        let authentication_type = payload.get_authentication_type(Some(payload.user_id.clone()));
         Ok((
             UserFromToken {
-                user_id: payload.user_id.clone(),
-                merchant_id: payload.merchant_id.clone(),
+                user_id: payload.user_id,
+                merchant_id: payload.merchant_id,
                 org_id: payload.org_id,
                 role_id: payload.role_id,
             },
-            AuthenticationType::MerchantJwt {
-                merchant_id: payload.merchant_id,
-                user_id: Some(payload.user_id),
-            },
+            authentication_type,
         ))
     }
 }
@@ -783,28 +971,51 @@ where
             .await
             .to_not_found_response(errors::ApiErrorResponse::Unauthorized)?;
 
+        ensure_merchant_account_is_active(&merchant)?;
+
         let auth = AuthenticationData {
             merchant_account: merchant,
             key_store,
+            profile_id: None,
         };
         Ok((
             auth.clone(),
-            AuthenticationType::MerchantJwt {
-                merchant_id: auth.merchant_account.merchant_id.clone(),
-                user_id: Some(payload.user_id),
-            },
+            payload.get_authentication_type(Some(payload.user_id.clone())),
         ))
     }
 }
 
 pub trait ClientSecretFetch {
     fn get_client_secret(&self) -> Option<&String>;
+
+    /// The payment the request acts on, which a client session must be bound to
+    fn get_payment_id(&self) -> Option<&str> {
+        None
+    }
+
+    /// The customer of the request, which a client session must be bound to
+    fn get_customer_id(&self) -> Option<&str> {
+        None
+    }
 }
 
 impl ClientSecretFetch for payments::PaymentsRequest {
     fn get_client_secret(&self) -> Option<&String> {
         self.client_secret.as_ref()
     }
+
+    fn get_payment_id(&self) -> Option<&str> {
+        match self.payment_id.as_ref() {
+            Some(payments::PaymentIdType::PaymentIntentId(payment_id)) => Some(payment_id),
+            _ => None,
+        }
+    }
+
+    fn get_customer_id(&self) -> Option<&str> {
+        self.customer_id
+            .as_deref()
+            .or(self.customer.as_ref().map(|customer| customer.id.as_str()))
+    }
 }
 
 impl ClientSecretFetch for PaymentMethodListRequest {
@@ -823,6 +1034,13 @@ impl ClientSecretFetch for api_models::payments::PaymentsRetrieveRequest {
     fn get_client_secret(&self) -> Option<&String> {
         self.client_secret.as_ref()
     }
+
+    fn get_payment_id(&self) -> Option<&str> {
+        match &self.resource_id {
+            payments::PaymentIdType::PaymentIntentId(payment_id) => Some(payment_id),
+            _ => None,
+        }
+    }
 }
 
 impl ClientSecretFetch for api_models::payments::RetrievePaymentLinkRequest {
@@ -843,8 +1061,23 @@ impl ClientSecretFetch for api_models::pm_auth::ExchangeTokenCreateRequest {
     }
 }
 
+/// Authentication with an API key, requiring the given scope if any
+fn api_key_auth<T>(
+    required_scope: Option<ApiKeyScope>,
+) -> Box<dyn AuthenticateAndFetch<AuthenticationData, T>>
+where
+    ApiKeyAuth: AuthenticateAndFetch<AuthenticationData, T>,
+    ScopedApiKeyAuth: AuthenticateAndFetch<AuthenticationData, T>,
+{
+    match required_scope {
+        Some(required_scope) => Box::new(ScopedApiKeyAuth(required_scope)),
+        None => Box::new(ApiKeyAuth),
+    }
+}
+
 pub fn get_auth_type_and_flow<A: AppStateInfo + Sync>(
     headers: &HeaderMap,
+    required_scope: Option<ApiKeyScope>,
 ) -> RouterResult<(
     Box<dyn AuthenticateAndFetch<AuthenticationData, A>>,
     api::AuthFlow,
@@ -854,12 +1087,13 @@ pub fn get_auth_type_and_flow<A: AppStateInfo + Sync>(
     if api_key.starts_with("pk_") {
         return Ok((Box::new(PublishableKeyAuth), api::AuthFlow::Client));
     }
-    Ok((Box::new(ApiKeyAuth), api::AuthFlow::Merchant))
+    Ok((api_key_auth(required_scope), api::AuthFlow::Merchant))
 }
 
 pub fn check_client_secret_and_get_auth<T>(
     headers: &HeaderMap,
     payload: &impl ClientSecretFetch,
+    required_scope: Option<ApiKeyScope>,
 ) -> RouterResult<(
     Box<dyn AuthenticateAndFetch<AuthenticationData, T>>,
     api::AuthFlow,
@@ -867,10 +1101,34 @@ pub fn check_client_secret_and_get_auth<T>(
 where
     T: AppStateInfo,
     ApiKeyAuth: AuthenticateAndFetch<AuthenticationData, T>,
+    ScopedApiKeyAuth: AuthenticateAndFetch<AuthenticationData, T>,
     PublishableKeyAuth: AuthenticateAndFetch<AuthenticationData, T>,
+    ClientSessionAuth: AuthenticateAndFetch<AuthenticationData, T>,
 {
     let api_key = get_api_key(headers)?;
 
+    if api_key.starts_with("cst_") {
+        // Client sessions replace the client secret, binding the request to a single payment
+        if payload.get_client_secret().is_some() {
+            return Err(errors::ApiErrorResponse::InvalidRequestData {
+                message: "client_secret is not a valid parameter".to_owned(),
+            }
+            .into());
+        }
+        let payment_id = payload
+            .get_payment_id()
+            .get_required_value("payment_id")?
+            .to_owned();
+        return Ok((
+            Box::new(ClientSessionAuth {
+                payment_id,
+                customer_id: payload.get_customer_id().map(ToOwned::to_owned),
+                consume: required_scope == Some(ApiKeyScope::PaymentsWrite),
+            }),
+            api::AuthFlow::Client,
+        ));
+    }
+
     if api_key.starts_with("pk_") {
         payload
             .get_client_secret()
@@ -887,7 +1145,7 @@ where
         }
         .into());
     }
-    Ok((Box::new(ApiKeyAuth), api::AuthFlow::Merchant))
+    Ok((api_key_auth(required_scope), api::AuthFlow::Merchant))
 }
 
 pub async fn is_ephemeral_auth<A: AppStateInfo + Sync>(
@@ -909,6 +1167,11 @@ pub async fn is_ephemeral_auth<A: AppStateInfo + Sync>(
     if ephemeral_key.customer_id.ne(customer_id) {
         return Err(report!(errors::ApiErrorResponse::InvalidEphemeralKey));
     }
+    // The expiry of the key in redis is set separately from the key, and may have failed
+    if ephemeral_key.expires < date_time::now_unix_timestamp() {
+        return Err(report!(errors::ApiErrorResponse::InvalidEphemeralKey))
+            .attach_printable("Ephemeral key has expired");
+    }
 
     Ok(Box::new(MerchantIdAuth(ephemeral_key.merchant_id)))
 }
@@ -25,6 +25,10 @@ pub async fn get_permissions<A>(
 where
     A: AppStateInfo + Sync,
 {
+    if let Some(impersonation) = &token.impersonation {
+        return Ok(get_permissions_from_groups(&impersonation.groups));
+    }
+
     if let Some(permissions) = get_permissions_from_predefined_roles(&token.role_id) {
         return Ok(permissions);
     }
@@ -31,6 +31,7 @@ pub enum Permission {
     UsersWrite,
     MerchantAccountCreate,
     WebhookEventRead,
+    WebhookEventWrite,
     PayoutRead,
     PayoutWrite,
 }
@@ -71,6 +72,7 @@ impl Permission {
             Self::UsersWrite => "Invite users, assign and update roles",
             Self::MerchantAccountCreate => "Create merchant account",
             Self::WebhookEventRead => "View webhook events",
+            Self::WebhookEventWrite => "Retry delivery of webhook events",
             Self::PayoutRead => "View all payouts",
             Self::PayoutWrite => "Create payout, download payout data",
         }
@@ -53,6 +53,32 @@ pub enum EmailBody {
         api_key_name: String,
         prefix: String,
     },
+    ScheduledReport {
+        report_type: String,
+        frequency: String,
+        period_start: String,
+        period_end: String,
+        row_count: i32,
+        file_id: String,
+        link: String,
+    },
+    PaymentMethodExpiring {
+        merchant_name: String,
+        card_network: String,
+        last4: String,
+        expiry_month: String,
+        expiry_year: String,
+    },
+    SuccessRateDropped {
+        merchant_id: String,
+        alert_id: String,
+        connector: String,
+        success_rate: String,
+        baseline_success_rate: String,
+        evaluation_window_in_mins: i32,
+        baseline_window_in_mins: i32,
+        drop_threshold: f64,
+    },
 }
 
 pub mod html {
@@ -140,6 +166,58 @@ Email         : {user_email}
                 prefix = prefix,
                 expires_in = expires_in,
             ),
+            EmailBody::ScheduledReport {
+                report_type,
+                frequency,
+                period_start,
+                period_end,
+                row_count,
+                file_id,
+                link,
+            } => format!(
+                include_str!("assets/scheduled_report.html"),
+                report_type = report_type,
+                frequency = frequency,
+                period_start = period_start,
+                period_end = period_end,
+                row_count = row_count,
+                file_id = file_id,
+                link = link,
+            ),
+            EmailBody::PaymentMethodExpiring {
+                merchant_name,
+                card_network,
+                last4,
+                expiry_month,
+                expiry_year,
+            } => format!(
+                include_str!("assets/payment_method_expiring.html"),
+                merchant_name = merchant_name,
+                card_network = card_network,
+                last4 = last4,
+                expiry_month = expiry_month,
+                expiry_year = expiry_year,
+            ),
+            EmailBody::SuccessRateDropped {
+                merchant_id,
+                alert_id,
+                connector,
+                success_rate,
+                baseline_success_rate,
+                evaluation_window_in_mins,
+                baseline_window_in_mins,
+                drop_threshold,
+            } => format!(
+                include_str!("assets/success_rate_dropped.html"),
+                merchant_id = merchant_id,
+                alert_id = alert_id,
+                connector = connector,
+                success_rate = success_rate,
+                baseline_success_rate = baseline_success_rate,
+                evaluation_window_in_mins = evaluation_window_in_mins,
+                baseline_window_in_mins = baseline_window_in_mins,
+                drop_threshold = drop_threshold,
+            ),
         }
     }
 }
@@ -471,3 +549,100 @@ impl EmailData for ApiKeyExpiryReminder {
         })
     }
 }
+
+pub struct ScheduledReport {
+    pub recipient_email: common_utils::pii::Email,
+    pub settings: std::sync::Arc<configs::Settings>,
+    pub subject: &'static str,
+    pub report_type: String,
+    pub frequency: String,
+    pub period_start: String,
+    pub period_end: String,
+    pub row_count: i32,
+    pub file_id: String,
+}
+
+#[async_trait::async_trait]
+impl EmailData for ScheduledReport {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        let body = html::get_html_body(EmailBody::ScheduledReport {
+            report_type: self.report_type.clone(),
+            frequency: self.frequency.clone(),
+            period_start: self.period_start.clone(),
+            period_end: self.period_end.clone(),
+            row_count: self.row_count,
+            file_id: self.file_id.clone(),
+            link: self.settings.email.base_url.clone(),
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone(),
+        })
+    }
+}
+
+pub struct PaymentMethodExpiring {
+    pub recipient_email: common_utils::pii::Email,
+    pub subject: &'static str,
+    pub merchant_name: String,
+    pub card_network: String,
+    pub last4: String,
+    pub expiry_month: String,
+    pub expiry_year: String,
+}
+
+#[async_trait::async_trait]
+impl EmailData for PaymentMethodExpiring {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        let body = html::get_html_body(EmailBody::PaymentMethodExpiring {
+            merchant_name: self.merchant_name.clone(),
+            card_network: self.card_network.clone(),
+            last4: self.last4.clone(),
+            expiry_month: self.expiry_month.clone(),
+            expiry_year: self.expiry_year.clone(),
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone(),
+        })
+    }
+}
+
+pub struct SuccessRateDropped {
+    pub recipient_email: common_utils::pii::Email,
+    pub subject: &'static str,
+    pub merchant_id: String,
+    pub alert_id: String,
+    pub connector: String,
+    pub success_rate: f64,
+    pub baseline_success_rate: f64,
+    pub evaluation_window_in_mins: i32,
+    pub baseline_window_in_mins: i32,
+    pub drop_threshold: f64,
+}
+
+#[async_trait::async_trait]
+impl EmailData for SuccessRateDropped {
+    async fn get_email_data(&self) -> CustomResult<EmailContents, EmailError> {
+        let body = html::get_html_body(EmailBody::SuccessRateDropped {
+            merchant_id: self.merchant_id.clone(),
+            alert_id: self.alert_id.clone(),
+            connector: self.connector.clone(),
+            success_rate: format!("{:.2}", self.success_rate),
+            baseline_success_rate: format!("{:.2}", self.baseline_success_rate),
+            evaluation_window_in_mins: self.evaluation_window_in_mins,
+            baseline_window_in_mins: self.baseline_window_in_mins,
+            drop_threshold: self.drop_threshold,
+        });
+
+        Ok(EmailContents {
+            subject: self.subject.to_string(),
+            body: external_services::email::IntermediateString::new(body),
+            recipient: self.recipient_email.clone(),
+        })
+    }
+}
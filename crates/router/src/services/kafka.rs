@@ -93,6 +93,7 @@ pub struct KafkaSettings {
     outgoing_webhook_logs_topic: String,
     dispute_analytics_topic: String,
     audit_events_topic: String,
+    sdk_events_topic: String,
 }
 
 impl KafkaSettings {
@@ -158,6 +159,12 @@ impl KafkaSettings {
             ))
         })?;
 
+        common_utils::fp_utils::when(self.sdk_events_topic.is_default_or_empty(), || {
+            Err(ApplicationError::InvalidConfigurationValueError(
+                "Kafka SDK Events topic must not be empty".into(),
+            ))
+        })?;
+
         Ok(())
     }
 }
@@ -173,6 +180,7 @@ pub struct KafkaProducer {
     outgoing_webhook_logs_topic: String,
     dispute_analytics_topic: String,
     audit_events_topic: String,
+    sdk_events_topic: String,
 }
 
 struct RdKafkaProducer(ThreadedProducer<DefaultProducerContext>);
@@ -213,6 +221,7 @@ impl KafkaProducer {
             outgoing_webhook_logs_topic: conf.outgoing_webhook_logs_topic.clone(),
             dispute_analytics_topic: conf.dispute_analytics_topic.clone(),
             audit_events_topic: conf.audit_events_topic.clone(),
+            sdk_events_topic: conf.sdk_events_topic.clone(),
         })
     }
 
@@ -227,6 +236,7 @@ impl KafkaProducer {
             EventType::OutgoingWebhookLogs => &self.outgoing_webhook_logs_topic,
             EventType::Dispute => &self.dispute_analytics_topic,
             EventType::AuditEvent => &self.audit_events_topic,
+            EventType::SdkEvent => &self.sdk_events_topic,
         };
         self.producer
             .0
@@ -244,30 +254,52 @@ impl KafkaProducer {
             .change_context(KafkaError::GenericError)
     }
 
-    pub async fn log_payment_attempt(
+    pub fn get_topic(&self, event: EventType) -> &str {
+        match event {
+            EventType::ApiLogs => &self.api_logs_topic,
+            EventType::PaymentAttempt => &self.attempt_analytics_topic,
+            EventType::PaymentIntent => &self.intent_analytics_topic,
+            EventType::Refund => &self.refund_analytics_topic,
+            EventType::ConnectorApiLogs => &self.connector_logs_topic,
+            EventType::OutgoingWebhookLogs => &self.outgoing_webhook_logs_topic,
+            EventType::Dispute => &self.dispute_analytics_topic,
+            EventType::AuditEvent => &self.audit_events_topic,
+            EventType::SdkEvent => &self.sdk_events_topic,
+        }
+    }
+}
+
+/// Producer of the analytics events of the storage entities. An update is logged as a negative
+/// event of the previous version of the entity followed by a positive event of the new version,
+/// so that the collapsing tables of the analytics store only keep the latest version.
+#[async_trait::async_trait]
+pub trait StorageEventProducer: Sync {
+    fn produce_event<T: KafkaMessage>(&self, event: &T) -> MQResult<()>;
+
+    async fn log_payment_attempt(
         &self,
         attempt: &PaymentAttempt,
         old_attempt: Option<PaymentAttempt>,
     ) -> MQResult<()> {
         if let Some(negative_event) = old_attempt {
-            self.log_event(&KafkaEvent::old(&KafkaPaymentAttempt::from_storage(
+            self.produce_event(&KafkaEvent::old(&KafkaPaymentAttempt::from_storage(
                 &negative_event,
             )))
             .attach_printable_lazy(|| {
                 format!("Failed to add negative attempt event {negative_event:?}")
             })?;
         };
-        self.log_event(&KafkaEvent::new(&KafkaPaymentAttempt::from_storage(
+        self.produce_event(&KafkaEvent::new(&KafkaPaymentAttempt::from_storage(
             attempt,
         )))
         .attach_printable_lazy(|| format!("Failed to add positive attempt event {attempt:?}"))
     }
 
-    pub async fn log_payment_attempt_delete(
+    async fn log_payment_attempt_delete(
         &self,
         delete_old_attempt: &PaymentAttempt,
     ) -> MQResult<()> {
-        self.log_event(&KafkaEvent::old(&KafkaPaymentAttempt::from_storage(
+        self.produce_event(&KafkaEvent::old(&KafkaPaymentAttempt::from_storage(
             delete_old_attempt,
         )))
         .attach_printable_lazy(|| {
@@ -275,28 +307,25 @@ impl KafkaProducer {
         })
     }
 
-    pub async fn log_payment_intent(
+    async fn log_payment_intent(
         &self,
         intent: &PaymentIntent,
         old_intent: Option<PaymentIntent>,
     ) -> MQResult<()> {
         if let Some(negative_event) = old_intent {
-            self.log_event(&KafkaEvent::old(&KafkaPaymentIntent::from_storage(
+            self.produce_event(&KafkaEvent::old(&KafkaPaymentIntent::from_storage(
                 &negative_event,
             )))
             .attach_printable_lazy(|| {
                 format!("Failed to add negative intent event {negative_event:?}")
             })?;
         };
-        self.log_event(&KafkaEvent::new(&KafkaPaymentIntent::from_storage(intent)))
+        self.produce_event(&KafkaEvent::new(&KafkaPaymentIntent::from_storage(intent)))
             .attach_printable_lazy(|| format!("Failed to add positive intent event {intent:?}"))
     }
 
-    pub async fn log_payment_intent_delete(
-        &self,
-        delete_old_intent: &PaymentIntent,
-    ) -> MQResult<()> {
-        self.log_event(&KafkaEvent::old(&KafkaPaymentIntent::from_storage(
+    async fn log_payment_intent_delete(&self, delete_old_intent: &PaymentIntent) -> MQResult<()> {
+        self.produce_event(&KafkaEvent::old(&KafkaPaymentIntent::from_storage(
             delete_old_intent,
         )))
         .attach_printable_lazy(|| {
@@ -304,21 +333,21 @@ impl KafkaProducer {
         })
     }
 
-    pub async fn log_refund(&self, refund: &Refund, old_refund: Option<Refund>) -> MQResult<()> {
+    async fn log_refund(&self, refund: &Refund, old_refund: Option<Refund>) -> MQResult<()> {
         if let Some(negative_event) = old_refund {
-            self.log_event(&KafkaEvent::old(&KafkaRefund::from_storage(
+            self.produce_event(&KafkaEvent::old(&KafkaRefund::from_storage(
                 &negative_event,
             )))
             .attach_printable_lazy(|| {
                 format!("Failed to add negative refund event {negative_event:?}")
             })?;
         };
-        self.log_event(&KafkaEvent::new(&KafkaRefund::from_storage(refund)))
+        self.produce_event(&KafkaEvent::new(&KafkaRefund::from_storage(refund)))
             .attach_printable_lazy(|| format!("Failed to add positive refund event {refund:?}"))
     }
 
-    pub async fn log_refund_delete(&self, delete_old_refund: &Refund) -> MQResult<()> {
-        self.log_event(&KafkaEvent::old(&KafkaRefund::from_storage(
+    async fn log_refund_delete(&self, delete_old_refund: &Refund) -> MQResult<()> {
+        self.produce_event(&KafkaEvent::old(&KafkaRefund::from_storage(
             delete_old_refund,
         )))
         .attach_printable_lazy(|| {
@@ -326,34 +355,33 @@ impl KafkaProducer {
         })
     }
 
-    pub async fn log_dispute(
-        &self,
-        dispute: &Dispute,
-        old_dispute: Option<Dispute>,
-    ) -> MQResult<()> {
+    async fn log_dispute(&self, dispute: &Dispute, old_dispute: Option<Dispute>) -> MQResult<()> {
         if let Some(negative_event) = old_dispute {
-            self.log_event(&KafkaEvent::old(&KafkaDispute::from_storage(
+            self.produce_event(&KafkaEvent::old(&KafkaDispute::from_storage(
                 &negative_event,
             )))
             .attach_printable_lazy(|| {
                 format!("Failed to add negative dispute event {negative_event:?}")
             })?;
         };
-        self.log_event(&KafkaEvent::new(&KafkaDispute::from_storage(dispute)))
+        self.produce_event(&KafkaEvent::new(&KafkaDispute::from_storage(dispute)))
             .attach_printable_lazy(|| format!("Failed to add positive dispute event {dispute:?}"))
     }
 
-    pub fn get_topic(&self, event: EventType) -> &str {
-        match event {
-            EventType::ApiLogs => &self.api_logs_topic,
-            EventType::PaymentAttempt => &self.attempt_analytics_topic,
-            EventType::PaymentIntent => &self.intent_analytics_topic,
-            EventType::Refund => &self.refund_analytics_topic,
-            EventType::ConnectorApiLogs => &self.connector_logs_topic,
-            EventType::OutgoingWebhookLogs => &self.outgoing_webhook_logs_topic,
-            EventType::Dispute => &self.dispute_analytics_topic,
-            EventType::AuditEvent => &self.audit_events_topic,
-        }
+    async fn log_dispute_delete(&self, delete_old_dispute: &Dispute) -> MQResult<()> {
+        self.produce_event(&KafkaEvent::old(&KafkaDispute::from_storage(
+            delete_old_dispute,
+        )))
+        .attach_printable_lazy(|| {
+            format!("Failed to add negative dispute event {delete_old_dispute:?}")
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl StorageEventProducer for KafkaProducer {
+    fn produce_event<T: KafkaMessage>(&self, event: &T) -> MQResult<()> {
+        self.log_event(event)
     }
 }
 
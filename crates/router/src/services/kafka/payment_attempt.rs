@@ -3,6 +3,17 @@ use data_models::{mandates::MandateDetails, payments::payment_attempt::PaymentAt
 use diesel_models::enums as storage_enums;
 use time::OffsetDateTime;
 
+use crate::types::storage::PaymentRoutingInfo;
+
+/// Outcome of the 3DS authentication of the attempt, as seen from the attempt status
+#[derive(serde::Serialize, Debug, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum ThreeDsOutcome {
+    Pending,
+    Succeeded,
+    Failed,
+}
+
 #[derive(serde::Serialize, Debug)]
 pub struct KafkaPaymentAttempt<'a> {
     pub payment_id: &'a String,
@@ -49,6 +60,26 @@ pub struct KafkaPaymentAttempt<'a> {
     pub unified_code: Option<&'a String>,
     pub unified_message: Option<&'a String>,
     pub mandate_data: Option<&'a MandateDetails>,
+    pub routing_algorithm_id: Option<String>,
+    pub authentication_connector: Option<&'a String>,
+    pub authentication_id: Option<&'a String>,
+    pub external_three_ds_authentication_attempted: Option<bool>,
+    pub three_ds_outcome: Option<ThreeDsOutcome>,
+    pub retry_index: Option<i16>,
+    pub card_network: Option<&'a String>,
+    pub card_type: Option<&'a String>,
+    pub card_issuing_country: Option<&'a String>,
+    pub three_ds_decision: Option<storage_enums::ThreeDsDecision>,
+    pub sca_exemption: Option<storage_enums::ScaExemptionType>,
+    pub card_fingerprint: Option<&'a String>,
+    pub payment_method_selection: Option<storage_enums::PaymentMethodSelection>,
+    pub settlement_currency: Option<storage_enums::Currency>,
+    pub settlement_amount: Option<i64>,
+    pub fx_rate: Option<&'a String>,
+    pub avs_result_code: Option<&'a String>,
+    pub avs_result: Option<storage_enums::CardCheckResult>,
+    pub cvv_result_code: Option<&'a String>,
+    pub cvv_result: Option<storage_enums::CardCheckResult>,
 }
 
 impl<'a> KafkaPaymentAttempt<'a> {
@@ -93,10 +124,88 @@ impl<'a> KafkaPaymentAttempt<'a> {
             unified_code: attempt.unified_code.as_ref(),
             unified_message: attempt.unified_message.as_ref(),
             mandate_data: attempt.mandate_data.as_ref(),
+            routing_algorithm_id: get_routing_algorithm_id(attempt),
+            authentication_connector: attempt.authentication_connector.as_ref(),
+            authentication_id: attempt.authentication_id.as_ref(),
+            external_three_ds_authentication_attempted: attempt
+                .external_three_ds_authentication_attempted,
+            three_ds_outcome: get_three_ds_outcome(attempt),
+            retry_index: get_retry_index(attempt),
+            card_network: attempt.card_network.as_ref(),
+            card_type: attempt.card_type.as_ref(),
+            card_issuing_country: attempt.card_issuing_country.as_ref(),
+            three_ds_decision: attempt.three_ds_decision,
+            sca_exemption: attempt.sca_exemption,
+            card_fingerprint: attempt.card_fingerprint.as_ref(),
+            payment_method_selection: attempt.payment_method_selection,
+            settlement_currency: attempt.settlement_currency,
+            settlement_amount: attempt.settlement_amount,
+            fx_rate: attempt.fx_rate.as_ref(),
+            avs_result_code: attempt.avs_result_code.as_ref(),
+            avs_result: attempt.avs_result,
+            cvv_result_code: attempt.cvv_result_code.as_ref(),
+            cvv_result: attempt.cvv_result,
+        }
+    }
+}
+
+fn get_routing_algorithm_id(attempt: &PaymentAttempt) -> Option<String> {
+    attempt
+        .straight_through_algorithm
+        .clone()
+        .and_then(|routing_info| serde_json::from_value::<PaymentRoutingInfo>(routing_info).ok())
+        .and_then(|routing_info| routing_info.routing_algorithm_id)
+}
+
+fn get_three_ds_outcome(attempt: &PaymentAttempt) -> Option<ThreeDsOutcome> {
+    if attempt.authentication_type != Some(storage_enums::AuthenticationType::ThreeDs) {
+        return None;
+    }
+
+    match attempt.status {
+        storage_enums::AttemptStatus::AuthenticationPending
+        | storage_enums::AttemptStatus::DeviceDataCollectionPending => {
+            Some(ThreeDsOutcome::Pending)
         }
+        storage_enums::AttemptStatus::AuthenticationFailed => Some(ThreeDsOutcome::Failed),
+        storage_enums::AttemptStatus::AuthenticationSuccessful
+        | storage_enums::AttemptStatus::Authorized
+        | storage_enums::AttemptStatus::PartiallyAuthorized
+        | storage_enums::AttemptStatus::AuthorizationFailed
+        | storage_enums::AttemptStatus::Charged
+        | storage_enums::AttemptStatus::Authorizing
+        | storage_enums::AttemptStatus::Voided
+        | storage_enums::AttemptStatus::VoidInitiated
+        | storage_enums::AttemptStatus::VoidFailed
+        | storage_enums::AttemptStatus::CaptureInitiated
+        | storage_enums::AttemptStatus::CaptureFailed
+        | storage_enums::AttemptStatus::AutoRefunded
+        | storage_enums::AttemptStatus::PartialCharged
+        | storage_enums::AttemptStatus::PartialChargedAndChargeable => {
+            Some(ThreeDsOutcome::Succeeded)
+        }
+        storage_enums::AttemptStatus::Started
+        | storage_enums::AttemptStatus::RouterDeclined
+        | storage_enums::AttemptStatus::CodInitiated
+        | storage_enums::AttemptStatus::Unresolved
+        | storage_enums::AttemptStatus::Pending
+        | storage_enums::AttemptStatus::Failure
+        | storage_enums::AttemptStatus::PaymentMethodAwaited
+        | storage_enums::AttemptStatus::ConfirmationAwaited => None,
     }
 }
 
+/// Attempt ids are generated as `{payment_id}_{attempt_count}`, the first attempt of a payment
+/// has the retry index 0
+fn get_retry_index(attempt: &PaymentAttempt) -> Option<i16> {
+    attempt
+        .attempt_id
+        .strip_prefix(attempt.payment_id.as_str())
+        .and_then(|suffix| suffix.strip_prefix('_'))
+        .and_then(|attempt_count| attempt_count.parse::<i16>().ok())
+        .map(|attempt_count| attempt_count.saturating_sub(1))
+}
+
 impl<'a> super::KafkaMessage for KafkaPaymentAttempt<'a> {
     fn key(&self) -> String {
         format!(
@@ -26,7 +26,11 @@ pub struct KafkaRefund<'a> {
     pub description: Option<&'a String>,
     pub attempt_id: &'a String,
     pub refund_reason: Option<&'a String>,
+    pub refund_reason_code: Option<&'a storage_enums::RefundReasonCode>,
     pub refund_error_code: Option<&'a String>,
+    pub payout_id: Option<&'a String>,
+    pub retry_count: Option<i16>,
+    pub retry_status: Option<storage_enums::RefundRetryStatus>,
 }
 
 impl<'a> KafkaRefund<'a> {
@@ -53,7 +57,11 @@ impl<'a> KafkaRefund<'a> {
             description: refund.description.as_ref(),
             attempt_id: &refund.attempt_id,
             refund_reason: refund.refund_reason.as_ref(),
+            refund_reason_code: refund.refund_reason_code.as_ref(),
             refund_error_code: refund.refund_error_code.as_ref(),
+            payout_id: refund.payout_id.as_ref(),
+            retry_count: refund.retry_count,
+            retry_status: refund.retry_status,
         }
     }
 }
@@ -0,0 +1,152 @@
+//! Upload of files to the SFTP servers of merchants.
+//!
+//! The SSH session is blocking, so the upload runs on the blocking thread pool of the runtime.
+
+use std::{io::Write, net::ToSocketAddrs, path::PathBuf, time::Duration};
+
+use api_models::analytics::scheduled_reports::SftpDeliveryConfig;
+use base64::Engine;
+use error_stack::{report, IntoReport, ResultExt};
+use masking::PeekInterface;
+
+use crate::{
+    consts,
+    core::errors::{CustomResult, SftpError},
+};
+
+/// Uploads the file to the directory configured for the SFTP server, replacing any file of the
+/// same name
+pub async fn upload_file(
+    config: SftpDeliveryConfig,
+    file_name: String,
+    content: Vec<u8>,
+    timeout: Duration,
+) -> CustomResult<(), SftpError> {
+    tokio::task::spawn_blocking(move || {
+        upload_file_blocking(&config, &file_name, &content, timeout)
+    })
+    .await
+    .into_report()
+    .change_context(SftpError::UploadFailed)
+    .attach_printable("SFTP upload task panicked")?
+}
+
+fn upload_file_blocking(
+    config: &SftpDeliveryConfig,
+    file_name: &str,
+    content: &[u8],
+    timeout: Duration,
+) -> CustomResult<(), SftpError> {
+    let address = (config.host.as_str(), config.port)
+        .to_socket_addrs()
+        .into_report()
+        .change_context(SftpError::ConnectionFailed)
+        .attach_printable("Failed to resolve the address of the SFTP server")?
+        .next()
+        .ok_or(SftpError::ConnectionFailed)
+        .into_report()
+        .attach_printable("SFTP server host does not resolve to any address")?;
+    let tcp_stream = std::net::TcpStream::connect_timeout(&address, timeout)
+        .into_report()
+        .change_context(SftpError::ConnectionFailed)?;
+
+    let mut session = ssh2::Session::new()
+        .into_report()
+        .change_context(SftpError::ConnectionFailed)?;
+    session.set_timeout(u32::try_from(timeout.as_millis()).unwrap_or(u32::MAX));
+    session.set_tcp_stream(tcp_stream);
+    session
+        .handshake()
+        .into_report()
+        .change_context(SftpError::ConnectionFailed)
+        .attach_printable("SSH handshake with the SFTP server failed")?;
+
+    verify_host_key(
+        session.host_key_hash(ssh2::HashType::Sha256),
+        &config.host_key_fingerprint,
+    )?;
+
+    match (&config.private_key, &config.password) {
+        (Some(private_key), _) => session
+            .userauth_pubkey_memory(
+                &config.username,
+                None,
+                private_key.peek(),
+                config
+                    .private_key_passphrase
+                    .as_ref()
+                    .map(|passphrase| passphrase.peek().as_str()),
+            )
+            .into_report()
+            .change_context(SftpError::AuthenticationFailed),
+        (None, Some(password)) => session
+            .userauth_password(&config.username, password.peek())
+            .into_report()
+            .change_context(SftpError::AuthenticationFailed),
+        (None, None) => Err(report!(SftpError::AuthenticationFailed))
+            .attach_printable("Neither a password nor a private key is configured"),
+    }?;
+
+    let path = config
+        .directory
+        .as_ref()
+        .map_or_else(PathBuf::new, PathBuf::from)
+        .join(file_name);
+    let sftp = session
+        .sftp()
+        .into_report()
+        .change_context(SftpError::UploadFailed)
+        .attach_printable("Failed to start the SFTP subsystem")?;
+    let mut file = sftp
+        .create(&path)
+        .into_report()
+        .change_context(SftpError::UploadFailed)
+        .attach_printable_lazy(|| format!("Failed to create {}", path.display()))?;
+    file.write_all(content)
+        .and_then(|()| file.flush())
+        .into_report()
+        .change_context(SftpError::UploadFailed)
+        .attach_printable_lazy(|| format!("Failed to write {}", path.display()))
+}
+
+/// Compares the SHA-256 hash of the host key presented by the server with the fingerprint
+/// configured by the merchant, in the `SHA256:<base64>` format printed by `ssh-keygen -l`
+fn verify_host_key(
+    host_key_hash: Option<&[u8]>,
+    expected_fingerprint: &str,
+) -> CustomResult<(), SftpError> {
+    let expected_fingerprint = expected_fingerprint.trim();
+    let expected_fingerprint = expected_fingerprint
+        .strip_prefix("SHA256:")
+        .unwrap_or(expected_fingerprint)
+        .trim_end_matches('=');
+    let fingerprint = host_key_hash
+        .map(|hash| consts::BASE64_ENGINE_STD_NO_PAD.encode(hash))
+        .ok_or(SftpError::HostKeyMismatch)
+        .into_report()
+        .attach_printable("SFTP server did not present a host key")?;
+
+    if fingerprint == expected_fingerprint {
+        Ok(())
+    } else {
+        Err(report!(SftpError::HostKeyMismatch)).attach_printable(format!(
+            "SFTP server presented host key SHA256:{fingerprint}"
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_host_key_fingerprint_verification() {
+        let hash = [7_u8; 32];
+        let fingerprint = consts::BASE64_ENGINE_STD_NO_PAD.encode(hash);
+
+        assert!(verify_host_key(Some(&hash), &format!("SHA256:{fingerprint}")).is_ok());
+        assert!(verify_host_key(Some(&hash), &format!("{fingerprint}=")).is_ok());
+        assert!(verify_host_key(Some(&[8_u8; 32]), &fingerprint).is_err());
+        assert!(verify_host_key(None, &fingerprint).is_err());
+    }
+}
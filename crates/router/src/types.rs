@@ -39,7 +39,10 @@ use crate::{
         payments::{types, PaymentData, RecurringMandatePaymentData},
     },
     services,
-    types::{transformers::ForeignFrom, types::AuthenticationData},
+    types::{
+        transformers::ForeignFrom,
+        types::{AuthenticationData, NetworkTokenData},
+    },
     utils::OptionExt,
 };
 pub type PaymentsAuthorizeRouterData =
@@ -340,6 +343,13 @@ pub enum AdditionalPaymentMethodConnectorResponse {
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct ConnectorResponseData {
     pub additional_payment_method_data: Option<AdditionalPaymentMethodConnectorResponse>,
+    /// Details of the conversion of the payment amount to the settlement currency of the merchant
+    pub settlement_data: Option<SettlementData>,
+    /// Address verification and security code check results returned for card payments
+    pub card_verification_data: Option<CardVerificationData>,
+    /// Amount authorized by the issuer when the card is authorized for less than the amount of
+    /// the payment
+    pub partially_authorized_amount: Option<i64>,
 }
 
 impl ConnectorResponseData {
@@ -348,8 +358,61 @@ impl ConnectorResponseData {
     ) -> Self {
         Self {
             additional_payment_method_data: Some(additional_payment_method_data),
+            settlement_data: None,
+            card_verification_data: None,
+            partially_authorized_amount: None,
+        }
+    }
+
+    pub fn with_settlement_data(settlement_data: SettlementData) -> Self {
+        Self {
+            additional_payment_method_data: None,
+            settlement_data: Some(settlement_data),
+            card_verification_data: None,
+            partially_authorized_amount: None,
+        }
+    }
+
+    pub fn with_card_verification_data(card_verification_data: CardVerificationData) -> Self {
+        Self {
+            additional_payment_method_data: None,
+            settlement_data: None,
+            card_verification_data: Some(card_verification_data),
+            partially_authorized_amount: None,
         }
     }
+
+    pub fn set_card_verification_data(
+        mut self,
+        card_verification_data: Option<CardVerificationData>,
+    ) -> Self {
+        self.card_verification_data = card_verification_data;
+        self
+    }
+}
+
+/// Conversion data returned by connectors which settle the merchant in a currency other than the
+/// presentment currency of the payment
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SettlementData {
+    pub settlement_currency: storage_enums::Currency,
+    /// Amount in the lowest denomination of the settlement currency
+    pub settlement_amount: Option<i64>,
+    /// Exchange rate applied to convert the presentment currency to the settlement currency, as
+    /// returned by the connector
+    pub fx_rate: Option<String>,
+}
+
+/// Results of the address verification (AVS) and security code (CVV) checks performed by the
+/// issuer during the authorization of a card payment
+#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
+pub struct CardVerificationData {
+    /// AVS result code as returned by the connector
+    pub avs_result_code: Option<String>,
+    pub avs_result: Option<storage_enums::CardCheckResult>,
+    /// CVV result code as returned by the connector
+    pub cvv_result_code: Option<String>,
+    pub cvv_result: Option<storage_enums::CardCheckResult>,
 }
 
 #[derive(Debug, Clone, serde::Deserialize)]
@@ -451,6 +514,14 @@ pub struct PaymentsAuthorizeData {
     pub request_incremental_authorization: bool,
     pub metadata: Option<pii::SecretSerdeValue>,
     pub authentication_data: Option<AuthenticationData>,
+    /// Network token of the saved card along with the cryptogram, set only for connectors which
+    /// accept network tokens in place of the card number
+    pub network_token_data: Option<NetworkTokenData>,
+    /// Strong customer authentication exemption requested for the payment
+    pub sca_exemption: Option<storage_enums::ScaExemptionType>,
+    /// Tax identification or identity document of the customer, required by some connectors for
+    /// payments in the country issuing the document
+    pub customer_document: Option<api_models::payments::CustomerDocumentDetails>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -998,6 +1069,7 @@ pub struct RefundsData {
     /// Amount for the payment against which this refund is issued
     pub payment_amount: i64,
     pub reason: Option<String>,
+    pub reason_code: Option<storage_enums::RefundReasonCode>,
     pub webhook_url: Option<String>,
     /// Amount to be refunded
     pub refund_amount: i64,
@@ -1288,6 +1360,16 @@ pub struct Response {
     pub headers: Option<http::HeaderMap>,
     pub response: bytes::Bytes,
     pub status_code: u16,
+    /// Parts of a multipart response, read when requested by the connector request
+    pub multipart_parts: Option<Vec<MultipartResponsePart>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MultipartResponsePart {
+    pub name: Option<String>,
+    pub file_name: Option<String>,
+    pub content_type: Option<String>,
+    pub body: bytes::Bytes,
 }
 
 #[derive(Clone, Debug, serde::Serialize)]
@@ -1455,6 +1537,9 @@ impl From<&SetupMandateRouterData> for PaymentsAuthorizeData {
             request_incremental_authorization: data.request.request_incremental_authorization,
             metadata: None,
             authentication_data: None,
+            network_token_data: None,
+            sca_exemption: None,
+            customer_document: None,
             customer_acceptance: data.request.customer_acceptance.clone(),
         }
     }
@@ -1,15 +1,19 @@
 pub use api_models::admin::{
-    BusinessProfileCreate, BusinessProfileResponse, BusinessProfileUpdate, MerchantAccountCreate,
-    MerchantAccountDeleteResponse, MerchantAccountResponse, MerchantAccountUpdate,
+    AccountTemplateCreate, AccountTemplateInstantiateRequest, BusinessProfileCreate,
+    BusinessProfileResponse, BusinessProfileUpdate, CardFingerprintKeyRotateRequest,
+    MerchantAccountCreate, MerchantAccountDataExport, MerchantAccountDeleteResponse,
+    MerchantAccountResponse, MerchantAccountUpdate, MerchantConnectorBulkRequest,
     MerchantConnectorCreate, MerchantConnectorDeleteResponse, MerchantConnectorDetails,
     MerchantConnectorDetailsWrap, MerchantConnectorId, MerchantConnectorResponse, MerchantDetails,
-    MerchantId, PaymentMethodsEnabled, ToggleKVRequest, ToggleKVResponse, WebhookDetails,
+    MerchantId, PaymentMethodsEnabled, ToggleKVRequest, ToggleKVResponse,
+    TokenRequestorIdCreateRequest, TokenRequestorIdUpdateRequest, WebhookDetails,
 };
 use common_utils::ext_traits::{Encode, ValueExt};
 use error_stack::ResultExt;
 use masking::Secret;
 
 use crate::{
+    consts,
     core::errors,
     types::{domain, storage, transformers::ForeignTryFrom},
 };
@@ -45,6 +49,7 @@ impl TryFrom<domain::MerchantAccount> for MerchantAccountResponse {
             is_recon_enabled: item.is_recon_enabled,
             default_profile: item.default_profile,
             recon_status: item.recon_status,
+            deleted_at: item.deleted_at,
         })
     }
 }
@@ -79,6 +84,13 @@ impl ForeignTryFrom<storage::business_profile::BusinessProfile> for BusinessProf
                     authentication_connector_details.parse_value("AuthenticationDetails")
                 })
                 .transpose()?,
+            card_verification_policy: item
+                .card_verification_policy
+                .map(|card_verification_policy| {
+                    card_verification_policy.parse_value("CardVerificationPolicy")
+                })
+                .transpose()?,
+            partial_authorization_action: item.partial_authorization_action,
         })
     }
 }
@@ -127,7 +139,9 @@ impl ForeignTryFrom<(domain::MerchantAccount, BusinessProfileCreate)>
         Ok(Self {
             profile_id,
             merchant_id: merchant_account.merchant_id,
-            profile_name: request.profile_name.unwrap_or("default".to_string()),
+            profile_name: request
+                .profile_name
+                .unwrap_or(consts::DEFAULT_BUSINESS_PROFILE_NAME.to_string()),
             created_at: current_time,
             modified_at: current_time,
             return_url: request
@@ -175,6 +189,15 @@ impl ForeignTryFrom<(domain::MerchantAccount, BusinessProfileCreate)>
                 .change_context(errors::ApiErrorResponse::InvalidDataValue {
                     field_name: "authentication_connector_details",
                 })?,
+            card_verification_policy: request
+                .card_verification_policy
+                .as_ref()
+                .map(Encode::encode_to_value)
+                .transpose()
+                .change_context(errors::ApiErrorResponse::InvalidDataValue {
+                    field_name: "card_verification_policy",
+                })?,
+            partial_authorization_action: request.partial_authorization_action,
         })
     }
 }
@@ -1,5 +1,8 @@
 use api_models::customers;
-pub use api_models::customers::{CustomerDeleteResponse, CustomerId, CustomerRequest};
+pub use api_models::customers::{
+    CustomerDeleteResponse, CustomerId, CustomerMergeRequest, CustomerMergeResponse,
+    CustomerMergeSuggestionsResponse, CustomerRequest,
+};
 use serde::Serialize;
 
 use super::payments;
@@ -22,6 +25,7 @@ pub(crate) trait CustomerRequestExt: Sized {
 
 impl From<(domain::Customer, Option<payments::AddressDetails>)> for CustomerResponse {
     fn from((cust, address): (domain::Customer, Option<payments::AddressDetails>)) -> Self {
+        let document = cust.get_document_details();
         customers::CustomerResponse {
             customer_id: cust.customer_id,
             name: cust.name,
@@ -33,6 +37,7 @@ impl From<(domain::Customer, Option<payments::AddressDetails>)> for CustomerResp
             metadata: cust.metadata,
             address,
             default_payment_method_id: cust.default_payment_method_id,
+            document,
         }
         .into()
     }
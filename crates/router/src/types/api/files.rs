@@ -63,6 +63,7 @@ pub struct CreateFileRequest {
 #[strum(serialize_all = "snake_case")]
 pub enum FilePurpose {
     DisputeEvidence,
+    AnalyticsReport,
 }
 
 #[derive(Debug, Clone)]
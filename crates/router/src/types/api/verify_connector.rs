@@ -51,6 +51,9 @@ impl VerifyConnectorData {
             statement_descriptor_suffix: None,
             request_incremental_authorization: false,
             authentication_data: None,
+            network_token_data: None,
+            sca_exemption: None,
+            customer_document: None,
             customer_acceptance: None,
         }
     }
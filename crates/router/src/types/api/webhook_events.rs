@@ -1,5 +1,8 @@
 pub use api_models::webhook_events::{
     EventListConstraints, EventListConstraintsInternal, EventListItemResponse,
-    EventListRequestInternal, EventRetrieveResponse, OutgoingWebhookRequestContent,
+    EventListRequestInternal, EventReplayConstraintsInternal, EventReplayRequest,
+    EventReplayRequestInternal, EventRetrieveResponse, OutgoingWebhookRequestContent,
     OutgoingWebhookResponseContent, WebhookDeliveryAttemptListRequestInternal,
+    WebhookDeliveryRetryRequestInternal, WebhookEndpointHealthRequestInternal,
+    WebhookEndpointHealthResponse, WebhookEndpointHealthStatus,
 };
@@ -73,6 +73,17 @@ pub trait IncomingWebhook: ConnectorCommon + Sync {
             .change_context(errors::ConnectorError::WebhookBodyDecodingFailed)
     }
 
+    /// Whether the connector provides a means to verify the source of its webhooks. Webhooks of
+    /// connectors which don't are never rejected for failing source verification, and are
+    /// processed by syncing the resource with the connector.
+    fn is_webhook_source_verification_supported(&self) -> bool {
+        true
+    }
+
+    /// The algorithm the source of the webhook is verified with, using the secrets of the merchant
+    /// connector account. Connectors signing their webhooks provide the signature algorithm, such
+    /// as [`crypto::HmacSha256`], and connectors sending the merchant secret as is, such as with
+    /// basic auth, provide [`crypto::SharedSecret`].
     fn get_webhook_source_verification_algorithm(
         &self,
         _request: &IncomingWebhookRequestDetails<'_>,
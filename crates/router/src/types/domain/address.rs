@@ -2,10 +2,12 @@ use async_trait::async_trait;
 use common_utils::{
     crypto, date_time,
     errors::{CustomResult, ValidationError},
+    ext_traits::ValueExt,
 };
 use diesel_models::{address::AddressUpdateInternal, encryption::Encryption, enums};
 use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
+use router_env::logger;
 use time::{OffsetDateTime, PrimitiveDateTime};
 
 use super::{
@@ -40,6 +42,40 @@ pub struct Address {
     pub payment_id: Option<String>,
     pub updated_by: String,
     pub email: crypto::OptionalEncryptableEmail,
+    /// The address normalized by the address normalization provider, stored alongside the
+    /// address as it was received
+    pub normalized_address: crypto::OptionalEncryptableValue,
+    pub address_validation_status: Option<enums::AddressValidationStatus>,
+}
+
+impl Address {
+    /// Provides the normalized address, if the address was validated by the address normalization
+    /// provider and has not been updated since
+    pub fn get_normalized_address_details(&self) -> Option<api_models::payments::AddressDetails> {
+        if !matches!(
+            self.address_validation_status,
+            Some(
+                enums::AddressValidationStatus::Verified
+                    | enums::AddressValidationStatus::Corrected
+            )
+        ) {
+            return None;
+        }
+
+        self.normalized_address
+            .as_ref()
+            .and_then(|normalized_address| {
+                normalized_address
+                    .get_inner()
+                    .peek()
+                    .clone()
+                    .parse_value("AddressDetails")
+                    .map_err(|error| {
+                        logger::warn!(?error, "Failed to parse the normalized address")
+                    })
+                    .ok()
+            })
+    }
 }
 
 #[async_trait]
@@ -69,6 +105,8 @@ impl behaviour::Conversion for Address {
             payment_id: self.payment_id,
             updated_by: self.updated_by,
             email: self.email.map(Encryption::from),
+            normalized_address: self.normalized_address.map(Encryption::from),
+            address_validation_status: self.address_validation_status,
         })
     }
 
@@ -100,6 +138,8 @@ impl behaviour::Conversion for Address {
                 payment_id: other.payment_id,
                 updated_by: other.updated_by,
                 email: other.email.async_lift(inner_decrypt_email).await?,
+                normalized_address: types::decrypt(other.normalized_address, key.peek()).await?,
+                address_validation_status: other.address_validation_status,
             })
         }
         .await
@@ -130,6 +170,8 @@ impl behaviour::Conversion for Address {
             modified_at: now,
             updated_by: self.updated_by,
             email: self.email.map(Encryption::from),
+            normalized_address: self.normalized_address.map(Encryption::from),
+            address_validation_status: self.address_validation_status,
         })
     }
 }
@@ -185,6 +227,8 @@ impl From<AddressUpdate> for AddressUpdateInternal {
                 modified_at: date_time::convert_to_pdt(OffsetDateTime::now_utc()),
                 updated_by,
                 email: email.map(Encryption::from),
+                // The normalized address no longer matches the updated address
+                address_validation_status: Some(enums::AddressValidationStatus::Unverified),
             },
         }
     }
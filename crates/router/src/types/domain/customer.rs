@@ -1,5 +1,7 @@
 use common_utils::{crypto, date_time, pii};
-use diesel_models::{customers::CustomerUpdateInternal, encryption::Encryption};
+use diesel_models::{
+    customers::CustomerUpdateInternal, encryption::Encryption, enums as storage_enums,
+};
 use error_stack::ResultExt;
 use masking::{PeekInterface, Secret};
 use time::PrimitiveDateTime;
@@ -23,6 +25,20 @@ pub struct Customer {
     pub connector_customer: Option<serde_json::Value>,
     pub address_id: Option<String>,
     pub default_payment_method_id: Option<String>,
+    pub document_type: Option<storage_enums::CustomerDocumentType>,
+    pub document_number: crypto::OptionalEncryptableSecretString,
+}
+
+impl Customer {
+    /// Provides the tax identification or identity document of the customer, if any
+    pub fn get_document_details(&self) -> Option<api_models::payments::CustomerDocumentDetails> {
+        self.document_type.zip(self.document_number.clone()).map(
+            |(document_type, document_number)| api_models::payments::CustomerDocumentDetails {
+                document_type,
+                document_number: document_number.into_inner(),
+            },
+        )
+    }
 }
 
 #[async_trait::async_trait]
@@ -47,6 +63,8 @@ impl super::behaviour::Conversion for Customer {
             connector_customer: self.connector_customer,
             address_id: self.address_id,
             default_payment_method_id: self.default_payment_method_id,
+            document_type: self.document_type,
+            document_number: self.document_number.map(Encryption::from),
         })
     }
 
@@ -75,6 +93,8 @@ impl super::behaviour::Conversion for Customer {
                 connector_customer: item.connector_customer,
                 address_id: item.address_id,
                 default_payment_method_id: item.default_payment_method_id,
+                document_type: item.document_type,
+                document_number: item.document_number.async_lift(inner_decrypt).await?,
             })
         }
         .await
@@ -98,6 +118,8 @@ impl super::behaviour::Conversion for Customer {
             modified_at: now,
             connector_customer: self.connector_customer,
             address_id: self.address_id,
+            document_type: self.document_type,
+            document_number: self.document_number.map(Encryption::from),
         })
     }
 }
@@ -113,6 +135,8 @@ pub enum CustomerUpdate {
         metadata: Option<pii::SecretSerdeValue>,
         connector_customer: Option<serde_json::Value>,
         address_id: Option<String>,
+        document_type: Option<storage_enums::CustomerDocumentType>,
+        document_number: crypto::OptionalEncryptableSecretString,
     },
     ConnectorCustomer {
         connector_customer: Option<serde_json::Value>,
@@ -134,6 +158,8 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 metadata,
                 connector_customer,
                 address_id,
+                document_type,
+                document_number,
             } => Self {
                 name: name.map(Encryption::from),
                 email: email.map(Encryption::from),
@@ -144,6 +170,8 @@ impl From<CustomerUpdate> for CustomerUpdateInternal {
                 connector_customer,
                 modified_at: Some(date_time::now()),
                 address_id,
+                document_type,
+                document_number: document_number.map(Encryption::from),
                 ..Default::default()
             },
             CustomerUpdate::ConnectorCustomer { connector_customer } => Self {
@@ -1,6 +1,8 @@
 use common_utils::crypto::OptionalEncryptableSecretString;
 use diesel_models::{
-    enums::{EventClass, EventObjectType, EventType, WebhookDeliveryAttempt},
+    enums::{
+        EventClass, EventObjectType, EventType, WebhookDeliveryAttempt, WebhookDeliveryStatus,
+    },
     events::EventUpdateInternal,
 };
 use error_stack::ResultExt;
@@ -28,6 +30,9 @@ pub struct Event {
     pub request: OptionalEncryptableSecretString,
     pub response: OptionalEncryptableSecretString,
     pub delivery_attempt: Option<WebhookDeliveryAttempt>,
+    /// Status of the delivery of the event across all of its delivery attempts, tracked on the
+    /// initial delivery attempt only
+    pub delivery_status: Option<WebhookDeliveryStatus>,
 }
 
 #[derive(Debug)]
@@ -36,6 +41,9 @@ pub enum EventUpdate {
         is_webhook_notified: bool,
         response: OptionalEncryptableSecretString,
     },
+    UpdateDeliveryStatus {
+        delivery_status: WebhookDeliveryStatus,
+    },
 }
 
 impl From<EventUpdate> for EventUpdateInternal {
@@ -47,6 +55,12 @@ impl From<EventUpdate> for EventUpdateInternal {
             } => Self {
                 is_webhook_notified: Some(is_webhook_notified),
                 response: response.map(Into::into),
+                delivery_status: None,
+            },
+            EventUpdate::UpdateDeliveryStatus { delivery_status } => Self {
+                is_webhook_notified: None,
+                response: None,
+                delivery_status: Some(delivery_status),
             },
         }
     }
@@ -74,6 +88,7 @@ impl super::behaviour::Conversion for Event {
             request: self.request.map(Into::into),
             response: self.response.map(Into::into),
             delivery_attempt: self.delivery_attempt,
+            delivery_status: self.delivery_status,
         })
     }
 
@@ -107,6 +122,7 @@ impl super::behaviour::Conversion for Event {
                     .async_lift(|inner| types::decrypt(inner, key.peek()))
                     .await?,
                 delivery_attempt: item.delivery_attempt,
+                delivery_status: item.delivery_status,
             })
         }
         .await
@@ -132,6 +148,7 @@ impl super::behaviour::Conversion for Event {
             request: self.request.map(Into::into),
             response: self.response.map(Into::into),
             delivery_attempt: self.delivery_attempt,
+            delivery_status: self.delivery_status,
         })
     }
 }
@@ -46,6 +46,7 @@ pub struct MerchantAccount {
     pub default_profile: Option<String>,
     pub recon_status: diesel_models::enums::ReconStatus,
     pub payment_link_config: Option<serde_json::Value>,
+    pub deleted_at: Option<time::PrimitiveDateTime>,
 }
 
 #[allow(clippy::large_enum_variant)]
@@ -80,6 +81,10 @@ pub enum MerchantAccountUpdate {
     },
     UnsetDefaultProfile,
     ModifiedAtUpdate,
+    SoftDeleteUpdate {
+        deleted_at: time::PrimitiveDateTime,
+    },
+    RestoreUpdate,
 }
 
 impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
@@ -145,6 +150,16 @@ impl From<MerchantAccountUpdate> for MerchantAccountUpdateInternal {
                 modified_at: Some(date_time::now()),
                 ..Default::default()
             },
+            MerchantAccountUpdate::SoftDeleteUpdate { deleted_at } => Self {
+                deleted_at: Some(Some(deleted_at)),
+                modified_at: Some(date_time::now()),
+                ..Default::default()
+            },
+            MerchantAccountUpdate::RestoreUpdate => Self {
+                deleted_at: Some(None),
+                modified_at: Some(date_time::now()),
+                ..Default::default()
+            },
         }
     }
 }
@@ -184,6 +199,7 @@ impl super::behaviour::Conversion for MerchantAccount {
             default_profile: self.default_profile,
             recon_status: self.recon_status,
             payment_link_config: self.payment_link_config,
+            deleted_at: self.deleted_at,
         })
     }
 
@@ -229,6 +245,7 @@ impl super::behaviour::Conversion for MerchantAccount {
                 default_profile: item.default_profile,
                 recon_status: item.recon_status,
                 payment_link_config: item.payment_link_config,
+                deleted_at: item.deleted_at,
             })
         }
         .await
@@ -265,6 +282,7 @@ impl super::behaviour::Conversion for MerchantAccount {
             default_profile: self.default_profile,
             recon_status: self.recon_status,
             payment_link_config: self.payment_link_config,
+            deleted_at: self.deleted_at,
         })
     }
 }
@@ -280,4 +298,8 @@ impl MerchantAccount {
             });
         metadata.and_then(|a| a.compatible_connector)
     }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
 }
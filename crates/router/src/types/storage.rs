@@ -1,5 +1,6 @@
 pub mod address;
 pub mod api_keys;
+pub mod audit_log;
 pub mod authentication;
 pub mod authorization;
 pub mod blocklist;
@@ -8,6 +9,7 @@ pub mod blocklist_lookup;
 pub mod business_profile;
 pub mod capture;
 pub mod cards_info;
+pub mod client_session;
 pub mod configs;
 pub mod customers;
 pub mod dashboard_metadata;
@@ -17,6 +19,7 @@ pub mod ephemeral_key;
 pub mod events;
 pub mod file;
 pub mod fraud_check;
+pub mod fx_rate;
 pub mod gsm;
 #[cfg(feature = "kv_store")]
 pub mod kv;
@@ -31,9 +34,13 @@ pub mod payment_method;
 pub mod payout_attempt;
 pub mod payouts;
 pub mod refund;
+pub mod report_run;
+pub mod report_schedule;
 pub mod reverse_lookup;
 pub mod role;
 pub mod routing_algorithm;
+pub mod settlement_record;
+pub mod success_rate_alert;
 pub mod user;
 pub mod user_role;
 
@@ -55,13 +62,14 @@ pub use diesel_models::{
 pub use scheduler::db::process_tracker;
 
 pub use self::{
-    address::*, api_keys::*, authentication::*, authorization::*, blocklist::*,
+    address::*, api_keys::*, audit_log::*, authentication::*, authorization::*, blocklist::*,
     blocklist_fingerprint::*, blocklist_lookup::*, business_profile::*, capture::*, cards_info::*,
-    configs::*, customers::*, dashboard_metadata::*, dispute::*, ephemeral_key::*, events::*,
-    file::*, fraud_check::*, gsm::*, locker_mock_up::*, mandate::*, merchant_account::*,
-    merchant_connector_account::*, merchant_key_store::*, payment_link::*, payment_method::*,
-    process_tracker::*, refund::*, reverse_lookup::*, role::*, routing_algorithm::*, user::*,
-    user_role::*,
+    client_session::*, configs::*, customers::*, dashboard_metadata::*, dispute::*,
+    ephemeral_key::*, events::*, file::*, fraud_check::*, fx_rate::*, gsm::*, locker_mock_up::*,
+    mandate::*, merchant_account::*, merchant_connector_account::*, merchant_key_store::*,
+    payment_link::*, payment_method::*, process_tracker::*, refund::*, report_run::*,
+    report_schedule::*, reverse_lookup::*, role::*, routing_algorithm::*, settlement_record::*,
+    success_rate_alert::*, user::*, user_role::*,
 };
 use crate::types::api::routing;
 
@@ -82,6 +90,7 @@ pub struct PaymentRoutingInfo {
     pub algorithm: Option<routing::StraightThroughAlgorithm>,
     pub pre_routing_results:
         Option<HashMap<api_models::enums::PaymentMethodType, routing::RoutableConnectorChoice>>,
+    pub routing_algorithm_id: Option<String>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -89,6 +98,9 @@ pub struct PaymentRoutingInfoInner {
     pub algorithm: Option<routing::StraightThroughAlgorithm>,
     pub pre_routing_results:
         Option<HashMap<api_models::enums::PaymentMethodType, routing::RoutableConnectorChoice>>,
+    /// Id of the merchant routing algorithm which decided the connector for the attempt
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub routing_algorithm_id: Option<String>,
 }
 
 #[derive(Debug, serde::Serialize, serde::Deserialize)]
@@ -104,10 +116,12 @@ impl From<PaymentRoutingInfoSerde> for PaymentRoutingInfo {
             PaymentRoutingInfoSerde::OnlyAlgorithm(algo) => Self {
                 algorithm: Some(*algo),
                 pre_routing_results: None,
+                routing_algorithm_id: None,
             },
             PaymentRoutingInfoSerde::WithDetails(details) => Self {
                 algorithm: details.algorithm,
                 pre_routing_results: details.pre_routing_results,
+                routing_algorithm_id: details.routing_algorithm_id,
             },
         }
     }
@@ -118,6 +132,7 @@ impl From<PaymentRoutingInfo> for PaymentRoutingInfoSerde {
         Self::WithDetails(Box::new(PaymentRoutingInfoInner {
             algorithm: value.algorithm,
             pre_routing_results: value.pre_routing_results,
+            routing_algorithm_id: value.routing_algorithm_id,
         }))
     }
 }
@@ -0,0 +1 @@
+pub use diesel_models::client_session::{ClientSession, ClientSessionNew};
@@ -58,6 +58,9 @@ impl DisputeDbExt for Dispute {
         if let Some(dispute_status) = dispute_list_constraints.dispute_status {
             filter = filter.filter(dsl::dispute_status.eq(dispute_status));
         }
+        if let Some(representment_status) = dispute_list_constraints.representment_status {
+            filter = filter.filter(dsl::representment_status.eq(representment_status));
+        }
         if let Some(limit) = dispute_list_constraints.limit {
             filter = filter.limit(limit);
         }
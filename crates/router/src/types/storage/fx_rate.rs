@@ -0,0 +1 @@
+pub use diesel_models::fx_rate::{FxRate, FxRateNew, FxRateUpdateInternal};
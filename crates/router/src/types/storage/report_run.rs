@@ -0,0 +1,11 @@
+pub use diesel_models::report_run::{ReportRun, ReportRunNew, ReportRunUpdateInternal};
+
+use crate::types::storage::enums;
+
+#[derive(Debug, Clone, Default)]
+pub struct ReportRunListConstraints {
+    pub schedule_id: Option<String>,
+    pub status: Option<Vec<enums::ReportRunStatus>>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
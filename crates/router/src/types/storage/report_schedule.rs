@@ -0,0 +1,3 @@
+pub use diesel_models::report_schedule::{
+    ReportSchedule, ReportScheduleNew, ReportScheduleUpdateInternal,
+};
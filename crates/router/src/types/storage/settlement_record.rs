@@ -0,0 +1,14 @@
+pub use diesel_models::settlement_record::{
+    SettlementRecord, SettlementRecordNew, SettlementRecordUpdateInternal,
+};
+
+use crate::types::storage::enums;
+
+#[derive(Debug, Clone, Default)]
+pub struct SettlementRecordListConstraints {
+    pub report_id: Option<String>,
+    pub connector: Option<String>,
+    pub match_status: Option<Vec<enums::SettlementMatchStatus>>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
@@ -0,0 +1,3 @@
+pub use diesel_models::success_rate_alert::{
+    SuccessRateAlert, SuccessRateAlertNew, SuccessRateAlertUpdateInternal,
+};
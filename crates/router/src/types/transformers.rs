@@ -81,7 +81,8 @@ impl ForeignFrom<storage_enums::AttemptStatus> for storage_enums::IntentStatus {
             storage_enums::AttemptStatus::ConfirmationAwaited => Self::RequiresConfirmation,
             storage_enums::AttemptStatus::PaymentMethodAwaited => Self::RequiresPaymentMethod,
 
-            storage_enums::AttemptStatus::Authorized => Self::RequiresCapture,
+            storage_enums::AttemptStatus::Authorized
+            | storage_enums::AttemptStatus::PartiallyAuthorized => Self::RequiresCapture,
             storage_enums::AttemptStatus::AuthenticationPending
             | storage_enums::AttemptStatus::DeviceDataCollectionPending => {
                 Self::RequiresCustomerAction
@@ -111,6 +112,40 @@ impl ForeignFrom<storage_enums::AttemptStatus> for storage_enums::IntentStatus {
     }
 }
 
+impl ForeignFrom<storage_enums::AttemptStatus> for api_enums::CardVerificationStatus {
+    fn foreign_from(attempt_status: storage_enums::AttemptStatus) -> Self {
+        match attempt_status {
+            storage_enums::AttemptStatus::Charged
+            | storage_enums::AttemptStatus::Authorized
+            | storage_enums::AttemptStatus::PartiallyAuthorized
+            | storage_enums::AttemptStatus::Voided => Self::Verified,
+
+            storage_enums::AttemptStatus::AuthenticationFailed
+            | storage_enums::AttemptStatus::AuthorizationFailed
+            | storage_enums::AttemptStatus::RouterDeclined
+            | storage_enums::AttemptStatus::Failure => Self::Failed,
+
+            storage_enums::AttemptStatus::Started
+            | storage_enums::AttemptStatus::AuthenticationPending
+            | storage_enums::AttemptStatus::AuthenticationSuccessful
+            | storage_enums::AttemptStatus::Authorizing
+            | storage_enums::AttemptStatus::CodInitiated
+            | storage_enums::AttemptStatus::VoidInitiated
+            | storage_enums::AttemptStatus::CaptureInitiated
+            | storage_enums::AttemptStatus::CaptureFailed
+            | storage_enums::AttemptStatus::VoidFailed
+            | storage_enums::AttemptStatus::AutoRefunded
+            | storage_enums::AttemptStatus::PartialCharged
+            | storage_enums::AttemptStatus::PartialChargedAndChargeable
+            | storage_enums::AttemptStatus::Unresolved
+            | storage_enums::AttemptStatus::Pending
+            | storage_enums::AttemptStatus::PaymentMethodAwaited
+            | storage_enums::AttemptStatus::ConfirmationAwaited
+            | storage_enums::AttemptStatus::DeviceDataCollectionPending => Self::Pending,
+        }
+    }
+}
+
 impl ForeignTryFrom<storage_enums::AttemptStatus> for storage_enums::CaptureStatus {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
 
@@ -131,6 +166,7 @@ impl ForeignTryFrom<storage_enums::AttemptStatus> for storage_enums::CaptureStat
             | storage_enums::AttemptStatus::AuthenticationPending
             | storage_enums::AttemptStatus::AuthenticationSuccessful
             | storage_enums::AttemptStatus::Authorized
+            | storage_enums::AttemptStatus::PartiallyAuthorized
             | storage_enums::AttemptStatus::AuthorizationFailed
             | storage_enums::AttemptStatus::Authorizing
             | storage_enums::AttemptStatus::CodInitiated
@@ -281,6 +317,9 @@ impl ForeignFrom<storage_enums::MandateAmountData> for api_models::payments::Man
             start_date: from.start_date,
             end_date: from.end_date,
             metadata: from.metadata,
+            max_amount_per_charge: from.max_amount_per_charge,
+            max_amount_per_period: from.max_amount_per_period,
+            limit_period: from.limit_period,
         }
     }
 }
@@ -315,6 +354,9 @@ impl ForeignFrom<api_models::payments::MandateData> for data_models::mandates::M
                             start_date: i.start_date,
                             end_date: i.end_date,
                             metadata: i.metadata,
+                            max_amount_per_charge: i.max_amount_per_charge,
+                            max_amount_per_period: i.max_amount_per_period,
+                            limit_period: i.limit_period,
                         },
                     ))
                 }
@@ -326,6 +368,9 @@ impl ForeignFrom<api_models::payments::MandateData> for data_models::mandates::M
                             start_date: i.start_date,
                             end_date: i.end_date,
                             metadata: i.metadata,
+                            max_amount_per_charge: i.max_amount_per_charge,
+                            max_amount_per_period: i.max_amount_per_period,
+                            limit_period: i.limit_period,
                         },
                     )
                 }
@@ -346,6 +391,9 @@ impl ForeignFrom<api_models::payments::MandateAmountData> for storage_enums::Man
             start_date: from.start_date,
             end_date: from.end_date,
             metadata: from.metadata,
+            max_amount_per_charge: from.max_amount_per_charge,
+            max_amount_per_period: from.max_amount_per_period,
+            limit_period: from.limit_period,
         }
     }
 }
@@ -358,8 +406,10 @@ impl ForeignFrom<api_enums::IntentStatus> for Option<storage_enums::EventType> {
             api_enums::IntentStatus::Processing => {
                 Some(storage_enums::EventType::PaymentProcessing)
             }
-            api_enums::IntentStatus::RequiresMerchantAction
-            | api_enums::IntentStatus::RequiresCustomerAction => {
+            api_enums::IntentStatus::RequiresMerchantAction => {
+                Some(storage_enums::EventType::PaymentReviewRequired)
+            }
+            api_enums::IntentStatus::RequiresCustomerAction => {
                 Some(storage_enums::EventType::ActionRequired)
             }
             api_enums::IntentStatus::Cancelled => Some(storage_enums::EventType::PaymentCancelled),
@@ -583,30 +633,34 @@ impl<'a> ForeignFrom<&'a api_types::ConfigUpdate> for storage::ConfigUpdate {
 
 impl<'a> From<&'a domain::Address> for api_types::Address {
     fn from(address: &domain::Address) -> Self {
-        // If all the fields of address are none, then pass the address as None
-        let address_details = if address.city.is_none()
-            && address.line1.is_none()
-            && address.line2.is_none()
-            && address.line3.is_none()
-            && address.state.is_none()
-            && address.zip.is_none()
-            && address.first_name.is_none()
-            && address.last_name.is_none()
-        {
-            None
-        } else {
-            Some(api_types::AddressDetails {
-                city: address.city.clone(),
-                country: address.country,
-                line1: address.line1.clone().map(Encryptable::into_inner),
-                line2: address.line2.clone().map(Encryptable::into_inner),
-                line3: address.line3.clone().map(Encryptable::into_inner),
-                state: address.state.clone().map(Encryptable::into_inner),
-                zip: address.zip.clone().map(Encryptable::into_inner),
-                first_name: address.first_name.clone().map(Encryptable::into_inner),
-                last_name: address.last_name.clone().map(Encryptable::into_inner),
-            })
-        };
+        // Addresses validated by the address normalization provider are passed in their
+        // normalized form. If all the fields of address are none, then pass the address as None
+        let address_details =
+            if let Some(normalized_address_details) = address.get_normalized_address_details() {
+                Some(normalized_address_details)
+            } else if address.city.is_none()
+                && address.line1.is_none()
+                && address.line2.is_none()
+                && address.line3.is_none()
+                && address.state.is_none()
+                && address.zip.is_none()
+                && address.first_name.is_none()
+                && address.last_name.is_none()
+            {
+                None
+            } else {
+                Some(api_types::AddressDetails {
+                    city: address.city.clone(),
+                    country: address.country,
+                    line1: address.line1.clone().map(Encryptable::into_inner),
+                    line2: address.line2.clone().map(Encryptable::into_inner),
+                    line3: address.line3.clone().map(Encryptable::into_inner),
+                    state: address.state.clone().map(Encryptable::into_inner),
+                    zip: address.zip.clone().map(Encryptable::into_inner),
+                    first_name: address.first_name.clone().map(Encryptable::into_inner),
+                    last_name: address.last_name.clone().map(Encryptable::into_inner),
+                })
+            };
 
         // If all the fields of phone are none, then pass the phone as None
         let phone_details = if address.phone_number.is_none() && address.country_code.is_none() {
@@ -649,6 +703,8 @@ impl
             api_key: StrongSecret::from(plaintext_api_key.peek().to_owned()),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            profile_id: api_key.profile_id,
+            scopes: api_key.scopes,
         }
     }
 }
@@ -663,6 +719,9 @@ impl ForeignFrom<diesel_models::api_keys::ApiKey> for api_models::api_keys::Retr
             prefix: api_key.prefix.into(),
             created: api_key.created_at,
             expiration: api_key.expires_at.into(),
+            profile_id: api_key.profile_id,
+            scopes: api_key.scopes,
+            last_used: api_key.last_used,
         }
     }
 }
@@ -676,6 +735,10 @@ impl ForeignFrom<api_models::api_keys::UpdateApiKeyRequest>
             description: api_key.description,
             expires_at: api_key.expiration.map(Into::into),
             last_used: None,
+            // An empty list of scopes removes the restriction of the API key
+            scopes: api_key
+                .scopes
+                .map(|scopes| (!scopes.is_empty()).then_some(scopes)),
         }
     }
 }
@@ -728,6 +791,7 @@ impl ForeignFrom<storage::Dispute> for api_models::disputes::DisputeResponse {
             created_at: dispute.created_at,
             profile_id: dispute.profile_id,
             merchant_connector_id: dispute.merchant_connector_id,
+            representment_status: dispute.representment_status,
         }
     }
 }
@@ -889,6 +953,20 @@ impl ForeignFrom<storage::PaymentAttempt> for api_models::payments::PaymentAttem
             reference_id: payment_attempt.connector_response_reference_id,
             unified_code: payment_attempt.unified_code,
             unified_message: payment_attempt.unified_message,
+            card_network: payment_attempt.card_network,
+            card_type: payment_attempt.card_type,
+            card_issuing_country: payment_attempt.card_issuing_country,
+            three_ds_decision: payment_attempt.three_ds_decision,
+            sca_exemption: payment_attempt.sca_exemption,
+            card_fingerprint: payment_attempt.card_fingerprint,
+            payment_method_selection: payment_attempt.payment_method_selection,
+            settlement_currency: payment_attempt.settlement_currency,
+            settlement_amount: payment_attempt.settlement_amount,
+            fx_rate: payment_attempt.fx_rate,
+            avs_result_code: payment_attempt.avs_result_code,
+            avs_result: payment_attempt.avs_result,
+            cvv_result_code: payment_attempt.cvv_result_code,
+            cvv_result: payment_attempt.cvv_result,
         }
     }
 }
@@ -1133,6 +1211,22 @@ impl ForeignFrom<storage::GatewayStatusMap> for gsm_api_types::GsmResponse {
     }
 }
 
+impl ForeignFrom<storage::AuditLog> for api_models::audit_logs::AuditLogResponse {
+    fn foreign_from(value: storage::AuditLog) -> Self {
+        Self {
+            audit_id: value.audit_id,
+            merchant_id: value.merchant_id,
+            operation: value.operation,
+            entity_id: value.entity_id,
+            actor_type: value.actor_type,
+            actor_id: value.actor_id,
+            request_id: value.request_id,
+            changes: value.changes,
+            created_at: value.created_at,
+        }
+    }
+}
+
 #[cfg(feature = "olap")]
 impl ForeignTryFrom<api_types::webhook_events::EventListConstraints>
     for api_types::webhook_events::EventListConstraintsInternal
@@ -1146,13 +1240,14 @@ impl ForeignTryFrom<api_types::webhook_events::EventListConstraints>
             && (item.created_after.is_some()
                 || item.created_before.is_some()
                 || item.limit.is_some()
-                || item.offset.is_some())
+                || item.offset.is_some()
+                || item.delivery_status.is_some())
         {
             return Err(report!(errors::ApiErrorResponse::PreconditionFailed {
-                message:
-                    "Either only `object_id` must be specified, or one or more of \
-                          `created_after`, `created_before`, `limit` and `offset` must be specified"
-                        .to_string()
+                message: "Either only `object_id` must be specified, or one or more of \
+                          `created_after`, `created_before`, `limit`, `offset` and \
+                          `delivery_status` must be specified"
+                    .to_string()
             }));
         }
 
@@ -1163,11 +1258,49 @@ impl ForeignTryFrom<api_types::webhook_events::EventListConstraints>
                 created_before: item.created_before,
                 limit: item.limit.map(i64::from),
                 offset: item.offset.map(i64::from),
+                delivery_status: item.delivery_status,
             }),
         }
     }
 }
 
+#[cfg(feature = "olap")]
+impl ForeignTryFrom<api_types::webhook_events::EventReplayRequest>
+    for api_types::webhook_events::EventReplayConstraintsInternal
+{
+    type Error = error_stack::Report<errors::ApiErrorResponse>;
+
+    fn foreign_try_from(
+        item: api_types::webhook_events::EventReplayRequest,
+    ) -> Result<Self, Self::Error> {
+        match (
+            item.event_ids,
+            item.created_after,
+            item.created_before,
+            item.event_types,
+        ) {
+            (Some(event_ids), None, None, None) if !event_ids.is_empty() => {
+                Ok(Self::EventIdFilter { event_ids })
+            }
+            (None, Some(created_after), Some(created_before), event_types)
+                if created_after < created_before =>
+            {
+                Ok(Self::TimeRangeFilter {
+                    created_after,
+                    created_before,
+                    event_types,
+                })
+            }
+            _ => Err(report!(errors::ApiErrorResponse::PreconditionFailed {
+                message: "Either only a non-empty `event_ids` must be specified, or \
+                          `created_after` earlier than `created_before` must be specified, \
+                          optionally along with `event_types`"
+                    .to_string()
+            })),
+        }
+    }
+}
+
 #[cfg(feature = "olap")]
 impl TryFrom<domain::Event> for api_models::webhook_events::EventListItemResponse {
     type Error = error_stack::Report<errors::ApiErrorResponse>;
@@ -1199,6 +1332,7 @@ impl TryFrom<domain::Event> for api_models::webhook_events::EventListItemRespons
             event_type: item.event_type,
             event_class: item.event_class,
             is_delivery_successful: item.is_webhook_notified,
+            delivery_status: item.delivery_status,
             initial_attempt_id,
             created: item.created_at,
         })
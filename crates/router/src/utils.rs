@@ -1,5 +1,6 @@
 #[cfg(feature = "olap")]
 pub mod connector_onboarding;
+pub mod csv;
 pub mod currency;
 pub mod custom_serde;
 pub mod db_utils;
@@ -636,6 +637,8 @@ impl CustomerAddress for api_models::customers::CustomerRequest {
                     .cloned()
                     .async_lift(|inner| encrypt_optional(inner.map(|inner| inner.expose()), key))
                     .await?,
+                normalized_address: None,
+                address_validation_status: None,
             })
         }
         .await
@@ -771,12 +774,23 @@ where
                 .collect()
         });
 
-    if matches!(
-        status,
-        enums::IntentStatus::Succeeded
-            | enums::IntentStatus::Failed
-            | enums::IntentStatus::PartiallyCaptured
-    ) {
+    // Decisions of the merchant on payments held for a manual review are notified along with the
+    // resulting status of the payment
+    let review_event_type = match format!("{operation:?}").as_str() {
+        "PaymentApprove" => Some(enums::EventType::PaymentReviewApproved),
+        "PaymentReject" => Some(enums::EventType::PaymentReviewRejected),
+        _ => None,
+    };
+
+    if review_event_type.is_some()
+        || matches!(
+            status,
+            enums::IntentStatus::Succeeded
+                | enums::IntentStatus::Failed
+                | enums::IntentStatus::PartiallyCaptured
+                | enums::IntentStatus::RequiresMerchantAction
+        )
+    {
         let payments_response = crate::core::payments::transformers::payments_to_payments_response(
             req,
             payment_data,
@@ -791,18 +805,27 @@ where
             None,
         )?;
 
-        let event_type = ForeignFrom::foreign_from(status);
+        let event_type: Option<enums::EventType> = ForeignFrom::foreign_from(status);
 
         if let services::ApplicationResponse::JsonWithHeaders((payments_response_json, _)) =
             payments_response
         {
-            let cloned_state = state.clone();
-            let cloned_key_store = key_store.clone();
-            // This spawns this futures in a background thread, the exception inside this future won't affect
-            // the current thread and the lifecycle of spawn thread is not handled by runtime.
-            // So when server shutdown won't wait for this thread's completion.
+            if event_type.is_none() && review_event_type.is_none() {
+                logger::warn!(
+                    "Outgoing webhook not sent because of missing event type status mapping"
+                );
+            }
 
-            if let Some(event_type) = event_type {
+            for event_type in review_event_type.into_iter().chain(event_type) {
+                let cloned_state = state.clone();
+                let cloned_key_store = key_store.clone();
+                let merchant_account = merchant_account.clone();
+                let business_profile = business_profile.clone();
+                let payment_id = payment_id.clone();
+                let payments_response_json = payments_response_json.clone();
+                // This spawns this futures in a background thread, the exception inside this future won't affect
+                // the current thread and the lifecycle of spawn thread is not handled by runtime.
+                // So when server shutdown won't wait for this thread's completion.
                 tokio::spawn(
                     async move {
                         let primary_object_created_at = payments_response_json.created;
@@ -824,10 +847,6 @@ where
                     }
                     .in_current_span(),
                 );
-            } else {
-                logger::warn!(
-                    "Outgoing webhook not sent because of missing event type status mapping"
-                );
             }
         }
     }
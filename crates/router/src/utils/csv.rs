@@ -0,0 +1,58 @@
+//! Minimal helpers for the CSV files exchanged with merchants.
+
+/// Splits a line of a CSV file into its fields, unquoting the quoted fields
+pub fn split_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field).trim().to_string()),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field.trim().to_string());
+
+    fields
+}
+
+/// Quotes a field of a CSV file if it contains a separator, a quote or a line break
+pub fn escape_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_line() {
+        assert_eq!(
+            split_line(r#"payment, txn_1,"1,000","say ""hi""","#),
+            vec!["payment", "txn_1", "1,000", r#"say "hi""#, ""]
+        );
+    }
+
+    #[test]
+    fn test_escaped_field_is_split_back() {
+        let fields = ["plain", "with, comma", "with \"quotes\""];
+        let line = fields
+            .iter()
+            .map(|field| escape_field(field))
+            .collect::<Vec<_>>()
+            .join(",");
+
+        assert_eq!(split_line(&line), fields);
+    }
+}
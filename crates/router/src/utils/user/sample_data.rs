@@ -3,7 +3,10 @@ use api_models::{
     user::sample_data::SampleDataRequest,
 };
 use data_models::payments::payment_intent::PaymentIntentNew;
-use diesel_models::{user::sample_data::PaymentAttemptBatchNew, RefundNew};
+use diesel_models::{
+    user::sample_data::{DisputeBatchNew, PaymentAttemptBatchNew},
+    RefundNew,
+};
 use error_stack::{IntoReport, ResultExt};
 use rand::{prelude::SliceRandom, thread_rng, Rng};
 use time::OffsetDateTime;
@@ -19,7 +22,14 @@ pub async fn generate_sample_data(
     state: &AppState,
     req: SampleDataRequest,
     merchant_id: &str,
-) -> SampleDataResult<Vec<(PaymentIntentNew, PaymentAttemptBatchNew, Option<RefundNew>)>> {
+) -> SampleDataResult<
+    Vec<(
+        PaymentIntentNew,
+        PaymentAttemptBatchNew,
+        Option<RefundNew>,
+        Option<DisputeBatchNew>,
+    )>,
+> {
     let merchant_id = merchant_id.to_string();
     let sample_data_size: usize = req.record.unwrap_or(100);
 
@@ -97,13 +107,26 @@ pub async fn generate_sample_data(
 
     let mut refunds_count = 0;
 
+    // 5 percent disputes for payments
+    #[allow(clippy::as_conversions)]
+    let number_of_disputes = usize::try_from((sample_data_size as f32 / 20.0).round() as i64)
+        .into_report()
+        .change_context(SampleDataError::InvalidParameters)?;
+
+    let mut disputes_count = 0;
+
     let mut random_array: Vec<usize> = (1..=sample_data_size).collect();
 
     // Shuffle the array
     let mut rng = thread_rng();
     random_array.shuffle(&mut rng);
 
-    let mut res: Vec<(PaymentIntentNew, PaymentAttemptBatchNew, Option<RefundNew>)> = Vec::new();
+    let mut res: Vec<(
+        PaymentIntentNew,
+        PaymentAttemptBatchNew,
+        Option<RefundNew>,
+        Option<DisputeBatchNew>,
+    )> = Vec::new();
     let start_time = req
         .start_time
         .unwrap_or(common_utils::date_time::now() - time::Duration::days(7))
@@ -142,7 +165,17 @@ pub async fn generate_sample_data(
     ]);
     let auth_type_len = auth_type.len();
 
-    if currency_vec_len == 0 || connector_vec_len == 0 || auth_type_len == 0 {
+    let payment_method_type_vec = req.payment_method_type.unwrap_or(vec![
+        common_enums::PaymentMethodType::Credit,
+        common_enums::PaymentMethodType::Debit,
+    ]);
+    let payment_method_type_vec_len = payment_method_type_vec.len();
+
+    if currency_vec_len == 0
+        || connector_vec_len == 0
+        || auth_type_len == 0
+        || payment_method_type_vec_len == 0
+    {
         return Err(SampleDataError::InvalidParameters.into());
     }
 
@@ -154,6 +187,9 @@ pub async fn generate_sample_data(
             format!("{}_secret", payment_id.clone()).as_str(),
         );
         let amount = thread_rng().gen_range(min_amount..=max_amount);
+        let payment_method_type = *payment_method_type_vec
+            .choose(&mut thread_rng())
+            .unwrap_or(&common_enums::PaymentMethodType::Credit);
 
         let created_at @ modified_at @ last_synced =
             OffsetDateTime::from_unix_timestamp(thread_rng().gen_range(start_time..=end_time))
@@ -237,8 +273,8 @@ pub async fn generate_sample_data(
                     .unwrap_or(&DummyConnector4))
                 .to_string(),
             ),
-            payment_method: Some(common_enums::PaymentMethod::Card),
-            payment_method_type: Some(get_payment_method_type(thread_rng().gen_range(1..=2))),
+            payment_method: Some(common_enums::PaymentMethod::from(payment_method_type)),
+            payment_method_type: Some(payment_method_type),
             authentication_type: Some(
                 *auth_type
                     .get((num - 1) % auth_type_len)
@@ -278,6 +314,7 @@ pub async fn generate_sample_data(
                 created_at: Some(created_at),
                 modified_at: Some(modified_at),
                 refund_reason: Some("Sample Refund".to_string()),
+                refund_reason_code: Some(common_enums::RefundReasonCode::RequestedByCustomer),
                 connector: payment_attempt
                     .connector
                     .clone()
@@ -295,20 +332,58 @@ pub async fn generate_sample_data(
                 profile_id: payment_intent.profile_id.clone(),
                 updated_by: merchant_from_db.storage_scheme.to_string(),
                 merchant_connector_id: payment_attempt.merchant_connector_id.clone(),
+                payout_id: None,
+                retry_count: None,
+                retry_status: None,
             })
         } else {
             None
         };
 
-        res.push((payment_intent, payment_attempt, refund));
+        let dispute =
+            if disputes_count < number_of_disputes && !is_failed_payment && refund.is_none() {
+                disputes_count += 1;
+                Some(DisputeBatchNew {
+                    dispute_id: common_utils::generate_id_with_default_len("test"),
+                    amount: (amount * 100).to_string(),
+                    currency: payment_intent
+                        .currency
+                        .unwrap_or(common_enums::Currency::USD)
+                        .to_string(),
+                    dispute_stage: common_enums::DisputeStage::Dispute,
+                    dispute_status: *[
+                        common_enums::DisputeStatus::DisputeOpened,
+                        common_enums::DisputeStatus::DisputeChallenged,
+                        common_enums::DisputeStatus::DisputeWon,
+                        common_enums::DisputeStatus::DisputeLost,
+                    ]
+                    .choose(&mut thread_rng())
+                    .unwrap_or(&common_enums::DisputeStatus::DisputeOpened),
+                    payment_id: payment_id.clone(),
+                    attempt_id: attempt_id.clone(),
+                    merchant_id: merchant_id.clone(),
+                    connector_status: "Sample connector status".into(),
+                    connector_dispute_id: common_utils::generate_id_with_default_len("test"),
+                    connector_reason: Some("Sample Dispute".into()),
+                    connector_reason_code: Some("123".into()),
+                    challenge_required_by: None,
+                    connector_created_at: None,
+                    connector_updated_at: None,
+                    created_at: Some(created_at),
+                    modified_at: Some(modified_at),
+                    connector: payment_attempt
+                        .connector
+                        .clone()
+                        .unwrap_or(DummyConnector4.to_string()),
+                    profile_id: payment_intent.profile_id.clone(),
+                    merchant_connector_id: payment_attempt.merchant_connector_id.clone(),
+                    dispute_amount: amount * 100,
+                })
+            } else {
+                None
+            };
+
+        res.push((payment_intent, payment_attempt, refund, dispute));
     }
     Ok(res)
 }
-
-fn get_payment_method_type(num: u8) -> common_enums::PaymentMethodType {
-    let rem: u8 = (num) % 2;
-    match rem {
-        0 => common_enums::PaymentMethodType::Debit,
-        _ => common_enums::PaymentMethodType::Credit,
-    }
-}
@@ -44,6 +44,7 @@ impl From<Permission> for user_role_api::Permission {
             Permission::UsersWrite => Self::UsersWrite,
             Permission::MerchantAccountCreate => Self::MerchantAccountCreate,
             Permission::WebhookEventRead => Self::WebhookEventRead,
+            Permission::WebhookEventWrite => Self::WebhookEventWrite,
             Permission::PayoutRead => Self::PayoutRead,
             Permission::PayoutWrite => Self::PayoutWrite,
         }
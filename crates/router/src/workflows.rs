@@ -1,6 +1,19 @@
 #[cfg(feature = "email")]
 pub mod api_key_expiry;
+pub mod bulk_charge;
+pub mod card_verification_void;
+pub mod customer_dedup;
+pub mod dispute_representment;
+pub mod frm_decision;
+pub mod fx_rates_sync;
+pub mod merchant_account_deletion;
 pub mod outgoing_webhook_retry;
+pub mod payment_method_expiry;
 pub mod payment_sync;
+pub mod recurring_payment;
 pub mod refund_router;
+#[cfg(feature = "olap")]
+pub mod scheduled_report;
+#[cfg(feature = "olap")]
+pub mod success_rate_alert;
 pub mod tokenized_data;
@@ -0,0 +1,138 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::enums as storage_enums;
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::{
+        bulk_charges::{BulkChargeTrackingData, BULK_CHARGE_COMPLETED},
+        payment_methods::Oss,
+        payments,
+    },
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    services,
+    types::{api, domain, storage},
+};
+
+pub struct BulkChargeWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for BulkChargeWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let mut tracking_data: BulkChargeTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("BulkChargeTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let mut process = process;
+        let pending_rows = tracking_data.get_pending_rows();
+        for rows in pending_rows.chunks(state.conf.bulk_charges.max_concurrency) {
+            let results =
+                futures::future::join_all(rows.iter().cloned().map(|(row_number, row)| {
+                    let payment_request = tracking_data.get_payment_request(row_number, &row);
+                    let merchant_account = merchant_account.clone();
+                    let key_store = key_store.clone();
+                    let tracking_data = &tracking_data;
+                    async move {
+                        let payment_response =
+                            create_payment(state, merchant_account, key_store, payment_request)
+                                .await;
+                        tracking_data.get_row_result(row_number, row, payment_response)
+                    }
+                }))
+                .await;
+            tracking_data.results.extend(results);
+
+            // The progress is persisted after every chunk of rows, so that rows which were
+            // already charged are not charged again if the task is picked up again
+            process = update_bulk_charge_task(db, process, &tracking_data, None).await?;
+        }
+
+        update_bulk_charge_task(db, process, &tracking_data, Some(BULK_CHARGE_COMPLETED)).await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+// Outgoing webhooks for the payment outcome are triggered by the payments core, the bulk charge
+// and the reference of the row are available to the merchant in the payment metadata
+async fn create_payment(
+    state: &AppState,
+    merchant_account: domain::MerchantAccount,
+    key_store: domain::MerchantKeyStore,
+    payment_request: api::PaymentsRequest,
+) -> errors::RouterResponse<api::PaymentsResponse> {
+    Box::pin(payments::payments_core::<
+        api::Authorize,
+        api::PaymentsResponse,
+        _,
+        _,
+        _,
+        Oss,
+    >(
+        state.clone(),
+        merchant_account,
+        key_store,
+        None,
+        payments::PaymentCreate,
+        payment_request,
+        services::AuthFlow::Merchant,
+        payments::CallConnectorAction::Trigger,
+        None,
+        api::HeaderPayload::default(),
+    ))
+    .await
+}
+
+/// Stores the results of the rows in the task, finishing the task when a business status is
+/// provided
+async fn update_bulk_charge_task(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+    tracking_data: &BulkChargeTrackingData,
+    business_status: Option<&str>,
+) -> Result<storage::ProcessTracker, errors::ProcessTrackerError> {
+    let tracking_data = serde_json::to_value(tracking_data)
+        .map_err(|_| errors::ProcessTrackerError::SerializationFailed)?;
+
+    Ok(db
+        .update_process(
+            process,
+            storage::ProcessTrackerUpdate::Update {
+                name: None,
+                retry_count: None,
+                schedule_time: None,
+                tracking_data: Some(tracking_data),
+                business_status: business_status.map(ToString::to_string),
+                status: business_status.map(|_| storage_enums::ProcessTrackerStatus::Finish),
+                updated_at: Some(common_utils::date_time::now()),
+            },
+        )
+        .await?)
+}
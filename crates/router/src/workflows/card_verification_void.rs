@@ -0,0 +1,87 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::logger;
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::{
+        payment_methods::Oss,
+        payments::{self, card_verification::CardVerificationVoidTrackingData},
+    },
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    services,
+    types::{api, storage},
+};
+
+pub struct CardVerificationVoidWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for CardVerificationVoidWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: CardVerificationVoidTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("CardVerificationVoidTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        Box::pin(payments::payments_core::<
+            api::Void,
+            api::PaymentsResponse,
+            _,
+            _,
+            _,
+            Oss,
+        >(
+            state.clone(),
+            merchant_account,
+            key_store,
+            None,
+            payments::PaymentCancel,
+            api::PaymentsCancelRequest {
+                payment_id: tracking_data.payment_id.clone(),
+                cancellation_reason: Some(tracking_data.reason.clone()),
+                ..Default::default()
+            },
+            services::AuthFlow::Merchant,
+            payments::CallConnectorAction::Trigger,
+            None,
+            api::HeaderPayload::default(),
+        ))
+        .await?;
+        logger::info!(
+            payment_id = %tracking_data.payment_id,
+            reason = %tracking_data.reason,
+            "Voided the authorization rejected by the policy of the profile"
+        );
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,57 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::logger;
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::customers::dedup::{self, CustomerDedupTrackingData},
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    types::storage,
+};
+
+pub struct CustomerDedupWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for CustomerDedupWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: CustomerDedupTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("CustomerDedupTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let suggestions_count =
+            dedup::compute_customer_merge_suggestions(state, &key_store).await?;
+        logger::info!(
+            merchant_id = %tracking_data.merchant_id,
+            suggestions_count,
+            "Computed the customer merge suggestions"
+        );
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,94 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::enums as storage_enums;
+use router_env::logger;
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::disputes::representment::{
+        self, DisputeRepresentmentTrackingData, REPRESENTMENT_AUTO_SUBMITTED,
+        REPRESENTMENT_DISPUTE_NOT_OPEN, REPRESENTMENT_PENDING_REVIEW,
+    },
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    types::storage,
+};
+
+pub struct DisputeRepresentmentWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for DisputeRepresentmentWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: DisputeRepresentmentTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("DisputeRepresentmentTrackingData")?;
+
+        let dispute = db
+            .find_dispute_by_merchant_id_dispute_id(
+                &tracking_data.merchant_id,
+                &tracking_data.dispute_id,
+            )
+            .await?;
+
+        // The merchant may have already accepted or challenged the dispute by the time the task
+        // is picked up
+        if !(dispute.dispute_stage == storage_enums::DisputeStage::Dispute
+            && dispute.dispute_status == storage_enums::DisputeStatus::DisputeOpened)
+        {
+            logger::info!(
+                dispute_id = %tracking_data.dispute_id,
+                "Dispute is no longer open, skipping automatic representment"
+            );
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    REPRESENTMENT_DISPUTE_NOT_OPEN.to_string(),
+                )
+                .await
+                .map_err(Into::into);
+        }
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let representment_status =
+            representment::represent_dispute(state, merchant_account, key_store, dispute).await?;
+
+        let business_status = match representment_status {
+            storage_enums::DisputeRepresentmentStatus::AutoSubmitted => {
+                REPRESENTMENT_AUTO_SUBMITTED
+            }
+            storage_enums::DisputeRepresentmentStatus::PendingReview => {
+                REPRESENTMENT_PENDING_REVIEW
+            }
+        };
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status.to_string())
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
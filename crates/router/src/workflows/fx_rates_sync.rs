@@ -0,0 +1,60 @@
+use router_env::logger;
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::currency, errors, routes::AppState, types::storage, utils::currency::get_forex_rates,
+};
+
+/// Time after the start of the day at which the exchange rates are synced, so that the rates
+/// published by the forex provider for the new day are available
+const FX_RATES_SYNC_OFFSET: time::Duration = time::Duration::minutes(5);
+
+pub struct FxRatesSyncWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for FxRatesSyncWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let forex_api = state.conf.forex_api.get_inner();
+        let rates = get_forex_rates(
+            state,
+            forex_api.call_delay,
+            forex_api.local_fetch_retry_delay,
+            forex_api.local_fetch_retry_count,
+        )
+        .await
+        .map_err(|error| {
+            logger::error!(?error, "Failed to fetch exchange rates");
+            errors::ProcessTrackerError::EApiErrorResponse
+        })?;
+
+        let today = common_utils::date_time::now().date();
+        currency::store_fx_rates(&*state.store, today, rates.get_exchange_rates()).await?;
+        logger::info!(rate_date = %today, "Stored exchange rates");
+
+        let next_schedule_time = today
+            .next_day()
+            .ok_or(errors::ProcessTrackerError::UnexpectedFlow)?
+            .midnight()
+            .saturating_add(FX_RATES_SYNC_OFFSET);
+        state
+            .store
+            .as_scheduler()
+            .reset_process(process, next_schedule_time)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
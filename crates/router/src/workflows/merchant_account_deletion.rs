@@ -0,0 +1,75 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::logger;
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::admin::{
+        self, MerchantAccountDeletionTrackingData, MERCHANT_ACCOUNT_DELETED,
+        MERCHANT_ACCOUNT_RESTORED,
+    },
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    types::storage,
+};
+
+pub struct MerchantAccountDeletionWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for MerchantAccountDeletionWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: MerchantAccountDeletionTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("MerchantAccountDeletionTrackingData")?;
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        // The merchant account may have been restored during the retention period, or restored
+        // and deleted again, in which case a newer task takes care of the deletion
+        let retention_period =
+            time::Duration::days(state.conf.merchant_deletion.retention_period_in_days);
+        let is_retention_period_over = merchant_account.deleted_at.map_or(false, |deleted_at| {
+            deleted_at.saturating_add(retention_period) <= common_utils::date_time::now()
+        });
+
+        let business_status = if is_retention_period_over {
+            admin::hard_delete_merchant_account(db, &tracking_data.merchant_id).await?;
+            logger::info!(
+                merchant_id = %tracking_data.merchant_id,
+                "Merchant account permanently deleted"
+            );
+            MERCHANT_ACCOUNT_DELETED
+        } else {
+            MERCHANT_ACCOUNT_RESTORED
+        };
+
+        db.as_scheduler()
+            .finish_process_with_business_status(process, business_status.to_string())
+            .await
+            .map_err(Into::into)
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
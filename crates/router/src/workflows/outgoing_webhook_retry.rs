@@ -80,6 +80,19 @@ impl ProcessTrackerWorkflow<AppState> for OutgoingWebhookRetryWorkflow {
             }
         };
 
+        // The event may have been delivered by a manual retry since the task was scheduled
+        if initial_event.delivery_status == Some(storage::enums::WebhookDeliveryStatus::Delivered) {
+            logger::info!(
+                initial_attempt_id = %initial_event.event_id,
+                "Event has already been delivered, finishing task"
+            );
+            return db
+                .as_scheduler()
+                .finish_process_with_business_status(process, "COMPLETED_BY_PT".to_string())
+                .await
+                .map_err(Into::into);
+        }
+
         let now = common_utils::date_time::now();
         let new_event = domain::Event {
             event_id,
@@ -97,6 +110,7 @@ impl ProcessTrackerWorkflow<AppState> for OutgoingWebhookRetryWorkflow {
             request: initial_event.request,
             response: None,
             delivery_attempt: Some(delivery_attempt),
+            delivery_status: None,
         };
 
         let event = db
@@ -241,6 +255,21 @@ impl ProcessTrackerWorkflow<AppState> for OutgoingWebhookRetryWorkflow {
 ///   seconds between them by default.
 /// - `custom_merchant_mapping.merchant_id1`: Merchant-specific retry configuration for merchant
 ///   with merchant ID `merchant_id1`.
+///
+/// Retries can instead back off exponentially, by specifying a mapping of this form:
+///
+/// ```json
+/// {
+///   "start_after": 60,
+///   "multiplier": 2,
+///   "max_delay": 21600,
+///   "max_retries": 16
+/// }
+/// ```
+///
+/// The first retry attempt happens after `start_after` seconds, and the interval is multiplied by
+/// `multiplier` for each subsequent attempt, up to `max_delay` seconds. Retries are stopped after
+/// `max_retries` attempts.
 #[instrument(skip_all)]
 pub(crate) async fn get_webhook_delivery_retry_schedule_time(
     db: &dyn StorageInterface,
@@ -285,27 +314,28 @@ pub(crate) async fn get_webhook_delivery_retry_schedule_time(
     scheduler_utils::get_time_from_delta(time_delta)
 }
 
-/// Schedule the webhook delivery task for retry
+/// Schedule the webhook delivery task for retry, provides whether a retry was scheduled, which is
+/// not the case once the retries are exhausted
 #[instrument(skip_all)]
 pub(crate) async fn retry_webhook_delivery_task(
     db: &dyn StorageInterface,
     merchant_id: &str,
     process: storage::ProcessTracker,
-) -> errors::CustomResult<(), errors::StorageError> {
+) -> errors::CustomResult<bool, errors::StorageError> {
     let schedule_time =
         get_webhook_delivery_retry_schedule_time(db, merchant_id, process.retry_count + 1).await;
 
     match schedule_time {
-        Some(schedule_time) => {
-            db.as_scheduler()
-                .retry_process(process, schedule_time)
-                .await
-        }
-        None => {
-            db.as_scheduler()
-                .finish_process_with_business_status(process, "RETRIES_EXCEEDED".to_string())
-                .await
-        }
+        Some(schedule_time) => db
+            .as_scheduler()
+            .retry_process(process, schedule_time)
+            .await
+            .map(|_| true),
+        None => db
+            .as_scheduler()
+            .finish_process_with_business_status(process, "RETRIES_EXCEEDED".to_string())
+            .await
+            .map(|_| false),
     }
 }
 
@@ -324,11 +354,15 @@ async fn get_outgoing_webhook_content_and_event_type(
 
     use crate::{
         core::{
+            authentication::utils as authentication_utils,
             disputes::retrieve_dispute,
             mandate::get_mandate,
-            payment_methods::Oss,
-            payments::{payments_core, CallConnectorAction, PaymentStatus},
+            payment_methods::{expiry as payment_method_expiry, Oss},
+            payments::{
+                payments_core, routing::connector_health, CallConnectorAction, PaymentStatus,
+            },
             refunds::refund_retrieve_core,
+            success_rate_alerts,
         },
         services::{ApplicationResponse, AuthFlow},
         types::{
@@ -352,6 +386,7 @@ async fn get_outgoing_webhook_content_and_event_type(
                     state,
                     merchant_account,
                     key_store,
+                    None,
                     PaymentStatus,
                     request,
                     AuthFlow::Client,
@@ -397,6 +432,7 @@ async fn get_outgoing_webhook_content_and_event_type(
                 state,
                 merchant_account,
                 key_store,
+                None,
                 request,
             ))
             .await?;
@@ -415,7 +451,7 @@ async fn get_outgoing_webhook_content_and_event_type(
             let request = DisputeId { dispute_id };
 
             let dispute_response =
-                match retrieve_dispute(state, merchant_account, request).await? {
+                match retrieve_dispute(state, merchant_account, None, request).await? {
                     ApplicationResponse::Json(dispute_response)
                     | ApplicationResponse::JsonWithHeaders((dispute_response, _)) => {
                         Ok(dispute_response)
@@ -471,5 +507,91 @@ async fn get_outgoing_webhook_content_and_event_type(
                 event_type,
             ))
         }
+
+        diesel_models::enums::EventClass::Connectors => {
+            let connector =
+                connector_health::get_connector_from_object_id(&tracking_data.primary_object_id)
+                    .ok_or_else(|| errors::ProcessTrackerError::ResourceFetchingFailed {
+                        resource_name: tracking_data.primary_object_id.clone(),
+                    })?;
+
+            let health_response = connector_health::get_connector_health(
+                &state,
+                &tracking_data.merchant_id,
+                Some(&tracking_data.business_profile_id),
+                connector,
+            )
+            .await
+            .map(Box::new)?;
+            let event_type = Some(connector_health::get_event_type(health_response.status));
+            logger::debug!(current_resource_status=%health_response.status);
+
+            Ok((
+                OutgoingWebhookContent::ConnectorHealthDetails(health_response),
+                event_type,
+            ))
+        }
+
+        diesel_models::enums::EventClass::Authentications => {
+            let authentication = state
+                .store
+                .find_authentication_by_merchant_id_authentication_id(
+                    tracking_data.merchant_id.clone(),
+                    tracking_data.primary_object_id.clone(),
+                )
+                .await?;
+            let event_type = Some(authentication_utils::get_authentication_event_type(
+                &authentication,
+            ));
+            logger::debug!(current_resource_status=%authentication.authentication_status);
+
+            // The authentication does not hold the identifier of the payment it is performed for
+            Ok((
+                OutgoingWebhookContent::AuthenticationDetails(Box::new(
+                    authentication_utils::get_authentication_response(&authentication, None),
+                )),
+                event_type,
+            ))
+        }
+
+        diesel_models::enums::EventClass::PaymentMethods => {
+            let payment_method_id = payment_method_expiry::get_payment_method_id_from_object_id(
+                &tracking_data.primary_object_id,
+            )
+            .ok_or_else(|| errors::ProcessTrackerError::ResourceFetchingFailed {
+                resource_name: tracking_data.primary_object_id.clone(),
+            })?;
+
+            let payment_method_response =
+                payment_method_expiry::get_payment_method_expiring_content(
+                    &state,
+                    &key_store,
+                    payment_method_id,
+                )
+                .await
+                .map(Box::new)?;
+
+            // The expiry of the payment method is the only event of the class
+            Ok((
+                OutgoingWebhookContent::PaymentMethodDetails(payment_method_response),
+                Some(EventType::PaymentMethodExpiring),
+            ))
+        }
+
+        diesel_models::enums::EventClass::Alerts => {
+            // The drop is delivered as it was detected, even if the success rate recovered since
+            let success_rate_drop = success_rate_alerts::get_success_rate_drop_content(
+                &state,
+                &tracking_data.merchant_id,
+                &tracking_data.primary_object_id,
+            )
+            .await
+            .map(Box::new)?;
+
+            Ok((
+                OutgoingWebhookContent::SuccessRateDropDetails(success_rate_drop),
+                Some(EventType::SuccessRateDropped),
+            ))
+        }
     }
 }
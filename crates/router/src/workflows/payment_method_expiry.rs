@@ -0,0 +1,53 @@
+use router_env::logger;
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::payment_methods::expiry::{self, CardExpiry},
+    errors,
+    routes::AppState,
+    types::storage,
+};
+
+pub struct PaymentMethodExpiryWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for PaymentMethodExpiryWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let today = common_utils::date_time::now().date();
+
+        // The task remains scheduled while the notifications are disabled, so that enabling them
+        // again does not require adding the task again
+        if state.conf.payment_method_expiry.enabled {
+            let notified_count = expiry::notify_expiring_payment_methods(state, today).await?;
+            logger::info!(
+                notified_count,
+                "Notified the saved cards expiring next month"
+            );
+        }
+
+        let next_schedule_time = CardExpiry::from_date(today)
+            .next()
+            .get_month_start()
+            .ok_or(errors::ProcessTrackerError::UnexpectedFlow)?;
+        state
+            .store
+            .as_scheduler()
+            .reset_process(process, next_schedule_time)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -70,6 +70,7 @@ impl ProcessTrackerWorkflow<AppState> for PaymentsSyncWorkflow {
                 state,
                 merchant_account.clone(),
                 key_store.clone(),
+                None,
                 operations::PaymentStatus,
                 tracking_data.clone(),
                 payment_flows::CallConnectorAction::Trigger,
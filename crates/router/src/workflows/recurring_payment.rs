@@ -0,0 +1,270 @@
+use common_utils::ext_traits::ValueExt;
+use diesel_models::enums as storage_enums;
+use router_env::logger;
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::{
+        errors::StorageErrorExt,
+        payment_methods::Oss,
+        payments,
+        recurring_payments::{
+            RecurringPaymentTrackingData, RECURRING_PAYMENT_COMPLETED,
+            RECURRING_PAYMENT_DUNNING_EXHAUSTED, RECURRING_PAYMENT_MANDATE_INACTIVE,
+        },
+    },
+    db::StorageInterface,
+    errors,
+    routes::{metrics, AppState},
+    services,
+    types::{api, storage},
+};
+
+pub struct RecurringPaymentWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for RecurringPaymentWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let mut tracking_data: RecurringPaymentTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("RecurringPaymentTrackingData")?;
+
+        let mandate = db
+            .find_mandate_by_merchant_id_mandate_id(
+                &tracking_data.merchant_id,
+                &tracking_data.mandate_id,
+            )
+            .await?;
+
+        // The mandate may have been revoked after the schedule was created, there is no point
+        // in charging or dunning against it anymore
+        if mandate.mandate_status != storage_enums::MandateStatus::Active {
+            logger::info!(
+                schedule_id = %tracking_data.schedule_id,
+                "Mandate is no longer active, ending recurring payment schedule"
+            );
+            return finish_recurring_payment_task(
+                db,
+                process,
+                &tracking_data,
+                RECURRING_PAYMENT_MANDATE_INACTIVE,
+            )
+            .await;
+        }
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let payment_request = tracking_data.get_payment_request();
+        let payment_id = tracking_data.get_payment_id_for_current_attempt();
+        tracking_data.last_payment_id = Some(payment_id.clone());
+        let storage_scheme = merchant_account.storage_scheme;
+
+        // Outgoing webhooks for the payment outcome are triggered by the payments core, the
+        // schedule details are available to the merchant in the payment metadata
+        let payment_response = Box::pin(payments::payments_core::<
+            api::Authorize,
+            api::PaymentsResponse,
+            _,
+            _,
+            _,
+            Oss,
+        >(
+            state.clone(),
+            merchant_account,
+            key_store,
+            None,
+            payments::PaymentCreate,
+            payment_request,
+            services::AuthFlow::Merchant,
+            payments::CallConnectorAction::Trigger,
+            None,
+            api::HeaderPayload::default(),
+        ))
+        .await;
+
+        let payment_status = match payment_response {
+            Ok(services::ApplicationResponse::JsonWithHeaders((response, _)))
+            | Ok(services::ApplicationResponse::Json(response)) => Some(response.status),
+            Ok(_) => None,
+            // The payment of the current attempt was created by an earlier run of the task that
+            // failed before updating the schedule, its outcome is used instead of dunning so that
+            // the customer is not charged twice
+            Err(error)
+                if matches!(
+                    error.current_context(),
+                    errors::ApiErrorResponse::DuplicatePayment { .. }
+                ) =>
+            {
+                logger::info!(
+                    schedule_id = %tracking_data.schedule_id,
+                    %payment_id,
+                    "Payment of the current attempt already exists, using its status"
+                );
+                let payment_intent = db
+                    .find_payment_intent_by_payment_id_merchant_id(
+                        &payment_id,
+                        &tracking_data.merchant_id,
+                        storage_scheme,
+                    )
+                    .await
+                    .to_not_found_response(errors::ApiErrorResponse::PaymentNotFound)?;
+                Some(payment_intent.status)
+            }
+            Err(error) => {
+                logger::error!(?error, schedule_id = %tracking_data.schedule_id, "Failed to create recurring payment");
+                None
+            }
+        };
+
+        let is_charge_successful = matches!(
+            payment_status,
+            Some(
+                api_models::enums::IntentStatus::Succeeded
+                    | api_models::enums::IntentStatus::Processing
+                    | api_models::enums::IntentStatus::RequiresCapture
+            )
+        );
+
+        if is_charge_successful {
+            tracking_data.charges_made = tracking_data.charges_made.saturating_add(1);
+            tracking_data.dunning_attempts_made = 0;
+
+            if tracking_data.is_schedule_complete() {
+                return finish_recurring_payment_task(
+                    db,
+                    process,
+                    &tracking_data,
+                    RECURRING_PAYMENT_COMPLETED,
+                )
+                .await;
+            }
+
+            let next_charge_time = tracking_data
+                .get_next_charge_time()
+                .ok_or(errors::ProcessTrackerError::TypeConversionError)?;
+
+            reschedule_recurring_payment_task(db, process, &tracking_data, next_charge_time, 0)
+                .await
+        } else {
+            let retry_interval = usize::try_from(tracking_data.dunning_attempts_made)
+                .ok()
+                .and_then(|dunning_attempt| {
+                    tracking_data
+                        .dunning_retry_intervals_in_hours
+                        .get(dunning_attempt)
+                        .copied()
+                });
+
+            match retry_interval {
+                Some(retry_interval) => {
+                    tracking_data.dunning_attempts_made =
+                        tracking_data.dunning_attempts_made.saturating_add(1);
+                    let retry_time = common_utils::date_time::now()
+                        .saturating_add(time::Duration::hours(i64::from(retry_interval)));
+                    let retry_count = process.retry_count.saturating_add(1);
+
+                    reschedule_recurring_payment_task(
+                        db,
+                        process,
+                        &tracking_data,
+                        retry_time,
+                        retry_count,
+                    )
+                    .await
+                }
+                None => {
+                    finish_recurring_payment_task(
+                        db,
+                        process,
+                        &tracking_data,
+                        RECURRING_PAYMENT_DUNNING_EXHAUSTED,
+                    )
+                    .await
+                }
+            }
+        }
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
+
+async fn reschedule_recurring_payment_task(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+    tracking_data: &RecurringPaymentTrackingData,
+    schedule_time: time::PrimitiveDateTime,
+    retry_count: i32,
+) -> Result<(), errors::ProcessTrackerError> {
+    let tracking_data = serde_json::to_value(tracking_data)
+        .map_err(|_| errors::ProcessTrackerError::SerializationFailed)?;
+
+    db.update_process(
+        process,
+        storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: Some(retry_count),
+            schedule_time: Some(schedule_time),
+            tracking_data: Some(tracking_data),
+            business_status: None,
+            status: Some(storage_enums::ProcessTrackerStatus::New),
+            updated_at: Some(common_utils::date_time::now()),
+        },
+    )
+    .await?;
+    // The task is picked up again at the next charge or dunning retry, so resetting the added count
+    metrics::TASKS_RESET_COUNT.add(
+        &metrics::CONTEXT,
+        1,
+        &[metrics::request::add_attributes("flow", "RecurringPayment")],
+    );
+
+    Ok(())
+}
+
+async fn finish_recurring_payment_task(
+    db: &dyn StorageInterface,
+    process: storage::ProcessTracker,
+    tracking_data: &RecurringPaymentTrackingData,
+    business_status: &str,
+) -> Result<(), errors::ProcessTrackerError> {
+    let tracking_data = serde_json::to_value(tracking_data)
+        .map_err(|_| errors::ProcessTrackerError::SerializationFailed)?;
+
+    db.update_process(
+        process,
+        storage::ProcessTrackerUpdate::Update {
+            name: None,
+            retry_count: None,
+            schedule_time: None,
+            tracking_data: Some(tracking_data),
+            business_status: Some(business_status.to_string()),
+            status: Some(storage_enums::ProcessTrackerStatus::Finish),
+            updated_at: Some(common_utils::date_time::now()),
+        },
+    )
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use common_utils::ext_traits::ValueExt;
+use router_env::logger;
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::scheduled_reports::{self, ScheduledReportTrackingData, REPORT_SCHEDULE_DEACTIVATED},
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    types::storage,
+};
+
+pub struct ScheduledReportWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for ScheduledReportWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: ScheduledReportTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("ScheduledReportTrackingData")?;
+
+        let schedule = db
+            .find_report_schedule_by_merchant_id_schedule_id(
+                &tracking_data.merchant_id,
+                &tracking_data.schedule_id,
+            )
+            .await?;
+        if !schedule.is_active {
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    REPORT_SCHEDULE_DEACTIVATED.to_string(),
+                )
+                .await?;
+            return Ok(());
+        }
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        let report_run =
+            scheduled_reports::run_report_schedule(state, &merchant_account, &key_store, &schedule)
+                .await?;
+        logger::info!(
+            run_id = %report_run.run_id,
+            status = %report_run.status,
+            "Completed scheduled report run"
+        );
+
+        // Periods missed while the scheduler was unavailable are run one after the other, as
+        // the next run time is in the past until the schedule catches up
+        let next_run_at =
+            scheduled_reports::get_next_period_end(schedule.frequency, schedule.next_run_at);
+        db.update_report_schedule_by_merchant_id_schedule_id(
+            &schedule.merchant_id,
+            &schedule.schedule_id,
+            storage::ReportScheduleUpdateInternal {
+                is_active: None,
+                next_run_at: Some(next_run_at),
+                last_run_at: Some(common_utils::date_time::now()),
+                modified_at: Some(common_utils::date_time::now()),
+            },
+        )
+        .await?;
+        db.as_scheduler()
+            .reset_process(process, next_run_at)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
@@ -0,0 +1,82 @@
+use common_utils::ext_traits::ValueExt;
+use scheduler::consumer::{self, workflows::ProcessTrackerWorkflow};
+
+use crate::{
+    core::success_rate_alerts::{
+        self, SuccessRateAlertTrackingData, SUCCESS_RATE_ALERT_DEACTIVATED,
+    },
+    db::StorageInterface,
+    errors,
+    routes::AppState,
+    types::storage,
+};
+
+pub struct SuccessRateAlertWorkflow;
+
+#[async_trait::async_trait]
+impl ProcessTrackerWorkflow<AppState> for SuccessRateAlertWorkflow {
+    async fn execute_workflow<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+    ) -> Result<(), errors::ProcessTrackerError> {
+        let db: &dyn StorageInterface = &*state.store;
+        let tracking_data: SuccessRateAlertTrackingData = process
+            .tracking_data
+            .clone()
+            .parse_value("SuccessRateAlertTrackingData")?;
+
+        let alert = db
+            .find_success_rate_alert_by_merchant_id_alert_id(
+                &tracking_data.merchant_id,
+                &tracking_data.alert_id,
+            )
+            .await?;
+        if !alert.is_active {
+            db.as_scheduler()
+                .finish_process_with_business_status(
+                    process,
+                    SUCCESS_RATE_ALERT_DEACTIVATED.to_string(),
+                )
+                .await?;
+            return Ok(());
+        }
+
+        let key_store = db
+            .get_merchant_key_store_by_merchant_id(
+                &tracking_data.merchant_id,
+                &db.get_master_key().to_vec().into(),
+            )
+            .await?;
+        let merchant_account = db
+            .find_merchant_account_by_merchant_id(&tracking_data.merchant_id, &key_store)
+            .await?;
+
+        success_rate_alerts::evaluate_success_rate_alert(
+            state,
+            &merchant_account,
+            &key_store,
+            &alert,
+        )
+        .await?;
+
+        // Evaluations missed while the scheduler was unavailable are not caught up with, as
+        // every evaluation covers the most recent window
+        let next_evaluation_at = common_utils::date_time::now()
+            .saturating_add(success_rate_alerts::get_evaluation_interval(state));
+        db.as_scheduler()
+            .reset_process(process, next_evaluation_at)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn error_handler<'a>(
+        &'a self,
+        state: &'a AppState,
+        process: storage::ProcessTracker,
+        error: errors::ProcessTrackerError,
+    ) -> errors::CustomResult<(), errors::ProcessTrackerError> {
+        consumer::consumer_error_handler(state.store.as_scheduler(), process, error).await
+    }
+}
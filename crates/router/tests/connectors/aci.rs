@@ -75,6 +75,9 @@ fn construct_payment_router_data() -> types::PaymentsAuthorizeRouterData {
             request_incremental_authorization: false,
             metadata: None,
             authentication_data: None,
+            network_token_data: None,
+            sca_exemption: None,
+            customer_document: None,
             customer_acceptance: None,
         },
         response: Err(types::ErrorResponse::default()),
@@ -136,6 +139,7 @@ fn construct_refund_router_data<F>() -> types::RefundsRouterData<F> {
             webhook_url: None,
             connector_metadata: None,
             reason: None,
+            reason_code: None,
             connector_refund_id: None,
             browser_info: None,
         },
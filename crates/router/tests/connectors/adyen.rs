@@ -2,16 +2,22 @@ use std::str::FromStr;
 
 use api_models::payments::{Address, AddressDetails};
 use masking::Secret;
-use router::types::{self, api, storage::enums, PaymentAddress};
+use router::types::{
+    self,
+    api::{self, IncomingWebhook},
+    storage::enums,
+    PaymentAddress,
+};
 
 use crate::{
     connector_auth,
-    utils::{self, ConnectorActions, PaymentInfo},
+    utils::{self, ConnectorActions, LocalMock, PaymentInfo},
 };
 
 #[derive(Clone, Copy)]
 struct AdyenTest;
 impl ConnectorActions for AdyenTest {}
+impl LocalMock for AdyenTest {}
 impl utils::Connector for AdyenTest {
     fn get_data(&self) -> types::api::ConnectorData {
         use router::connector::Adyen;
@@ -173,6 +179,9 @@ impl AdyenTest {
             request_incremental_authorization: false,
             metadata: None,
             authentication_data: None,
+            network_token_data: None,
+            sca_exemption: None,
+            customer_document: None,
             customer_acceptance: None,
         })
     }
@@ -182,6 +191,7 @@ static CONNECTOR: AdyenTest = AdyenTest {};
 
 // Cards Positive Tests
 // Creates a payment using the manual capture flow (Non 3DS).
+#[serial_test::parallel]
 #[actix_web::test]
 async fn should_only_authorize_payment() {
     let response = CONNECTOR
@@ -201,6 +211,7 @@ async fn should_only_authorize_payment() {
 }
 
 // Captures a payment using the manual capture flow (Non 3DS).
+#[serial_test::parallel]
 #[actix_web::test]
 async fn should_capture_authorized_payment() {
     let response = CONNECTOR
@@ -221,6 +232,7 @@ async fn should_capture_authorized_payment() {
 }
 
 // Partially captures a payment using the manual capture flow (Non 3DS).
+#[serial_test::parallel]
 #[actix_web::test]
 async fn should_partially_capture_authorized_payment() {
     let response = CONNECTOR
@@ -244,6 +256,7 @@ async fn should_partially_capture_authorized_payment() {
 }
 
 // Voids a payment using the manual capture flow (Non 3DS).
+#[serial_test::parallel]
 #[actix_web::test]
 async fn should_void_authorized_payment() {
     let response = CONNECTOR
@@ -268,6 +281,7 @@ async fn should_void_authorized_payment() {
 }
 
 // Refunds a payment using the manual capture flow (Non 3DS).
+#[serial_test::parallel]
 #[actix_web::test]
 async fn should_refund_manually_captured_payment() {
     let response = CONNECTOR
@@ -296,6 +310,7 @@ async fn should_refund_manually_captured_payment() {
 }
 
 // Partially refunds a payment using the manual capture flow (Non 3DS).
+#[serial_test::parallel]
 #[actix_web::test]
 async fn should_partially_refund_manually_captured_payment() {
     let response = CONNECTOR
@@ -324,6 +339,7 @@ async fn should_partially_refund_manually_captured_payment() {
 }
 
 // Creates a payment using the automatic capture flow (Non 3DS).
+#[serial_test::parallel]
 #[actix_web::test]
 async fn should_make_payment() {
     let authorize_response = CONNECTOR
@@ -343,6 +359,7 @@ async fn should_make_payment() {
 }
 
 // Refunds a payment using the automatic capture flow (Non 3DS).
+#[serial_test::parallel]
 #[actix_web::test]
 async fn should_refund_auto_captured_payment() {
     let response = CONNECTOR
@@ -370,6 +387,7 @@ async fn should_refund_auto_captured_payment() {
 }
 
 // Partially refunds a payment using the automatic capture flow (Non 3DS).
+#[serial_test::parallel]
 #[actix_web::test]
 async fn should_partially_refund_succeeded_payment() {
     let refund_response = CONNECTOR
@@ -397,6 +415,7 @@ async fn should_partially_refund_succeeded_payment() {
 }
 
 // Creates multiple refunds against a payment using the automatic capture flow (Non 3DS).
+#[serial_test::parallel]
 #[actix_web::test]
 async fn should_refund_succeeded_payment_multiple_times() {
     let payment_info = AdyenTest::get_payment_info();
@@ -439,6 +458,7 @@ async fn should_refund_succeeded_payment_multiple_times() {
 
 // Cards Negative scenerios
 // Creates a payment with incorrect card number.
+#[serial_test::parallel]
 #[actix_web::test]
 async fn should_fail_payment_for_incorrect_card_number() {
     let response = CONNECTOR
@@ -459,6 +479,7 @@ async fn should_fail_payment_for_incorrect_card_number() {
 }
 
 // Creates a payment with incorrect CVC.
+#[serial_test::parallel]
 #[actix_web::test]
 async fn should_fail_payment_for_incorrect_cvc() {
     let response = CONNECTOR
@@ -482,6 +503,7 @@ async fn should_fail_payment_for_incorrect_cvc() {
 }
 
 // Creates a payment with incorrect expiry month.
+#[serial_test::parallel]
 #[actix_web::test]
 async fn should_fail_payment_for_invalid_exp_month() {
     let response = CONNECTOR
@@ -503,6 +525,7 @@ async fn should_fail_payment_for_invalid_exp_month() {
 }
 
 // Creates a payment with incorrect expiry year.
+#[serial_test::parallel]
 #[actix_web::test]
 async fn should_fail_payment_for_incorrect_expiry_year() {
     let response = CONNECTOR
@@ -523,6 +546,7 @@ async fn should_fail_payment_for_incorrect_expiry_year() {
 }
 
 // Captures a payment using invalid connector payment id.
+#[serial_test::parallel]
 #[actix_web::test]
 async fn should_fail_capture_for_invalid_payment() {
     let capture_response = CONNECTOR
@@ -539,6 +563,7 @@ async fn should_fail_capture_for_invalid_payment() {
 // Create SEPA payout
 #[ignore]
 #[cfg(feature = "payouts")]
+#[serial_test::parallel]
 #[actix_web::test]
 async fn should_create_sepa_payout() {
     let payout_type = enums::PayoutType::Bank;
@@ -556,6 +581,7 @@ async fn should_create_sepa_payout() {
 // Create and fulfill SEPA payout
 #[ignore]
 #[cfg(feature = "payouts")]
+#[serial_test::parallel]
 #[actix_web::test]
 async fn should_create_and_fulfill_sepa_payout() {
     let payout_type = enums::PayoutType::Bank;
@@ -570,6 +596,7 @@ async fn should_create_and_fulfill_sepa_payout() {
 // Verifies if card is eligible for payout
 #[ignore]
 #[cfg(feature = "payouts")]
+#[serial_test::parallel]
 #[actix_web::test]
 async fn should_verify_payout_eligibility() {
     let payout_type = enums::PayoutType::Card;
@@ -587,6 +614,7 @@ async fn should_verify_payout_eligibility() {
 // Fulfills card payout
 #[ignore]
 #[cfg(feature = "payouts")]
+#[serial_test::parallel]
 #[actix_web::test]
 async fn should_fulfill_card_payout() {
     let payout_type = enums::PayoutType::Card;
@@ -601,6 +629,7 @@ async fn should_fulfill_card_payout() {
 // Cancels a created bank payout
 #[ignore]
 #[cfg(feature = "payouts")]
+#[serial_test::parallel]
 #[actix_web::test]
 async fn should_create_and_cancel_created_payout() {
     let payout_type = enums::PayoutType::Bank;
@@ -614,4 +643,113 @@ async fn should_create_and_cancel_created_payout() {
 
 // Connector dependent test cases goes here
 
+// Mock server test cases, run against the fixtures of the connector
+// Creates a payment using the manual capture flow.
+#[serial_test::serial]
+#[actix_web::test]
+async fn should_only_authorize_payment_with_mock_server() {
+    let _mock = CONNECTOR.start_server().await;
+    let response = CONNECTOR
+        .authorize_payment(
+            AdyenTest::get_payment_authorize_data(
+                "4111111111111111",
+                "03",
+                "2030",
+                "737",
+                enums::CaptureMethod::Manual,
+            ),
+            AdyenTest::get_payment_info(),
+        )
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::Authorized);
+    assert_eq!(
+        utils::get_connector_transaction_id(response.response),
+        Some("MOCKPSP000000001".to_string())
+    );
+}
+
+// Captures a payment using the manual capture flow.
+#[serial_test::serial]
+#[actix_web::test]
+async fn should_capture_authorized_payment_with_mock_server() {
+    let _mock = CONNECTOR.start_server().await;
+    let response = CONNECTOR
+        .authorize_and_capture_payment(
+            AdyenTest::get_payment_authorize_data(
+                "4111111111111111",
+                "03",
+                "2030",
+                "737",
+                enums::CaptureMethod::Manual,
+            ),
+            None,
+            AdyenTest::get_payment_info(),
+        )
+        .await
+        .expect("Capture payment response");
+    assert_eq!(response.status, enums::AttemptStatus::Pending);
+    assert_eq!(response.amount_captured, Some(3500));
+}
+
+// Refunds a payment using the automatic capture flow.
+#[serial_test::serial]
+#[actix_web::test]
+async fn should_refund_auto_captured_payment_with_mock_server() {
+    let _mock = CONNECTOR.start_server().await;
+    let response = CONNECTOR
+        .make_payment_and_refund(
+            AdyenTest::get_payment_authorize_data(
+                "4111111111111111",
+                "03",
+                "2030",
+                "737",
+                enums::CaptureMethod::Automatic,
+            ),
+            None,
+            AdyenTest::get_payment_info(),
+        )
+        .await
+        .unwrap();
+    let refund_response = response.response.unwrap();
+    assert_eq!(refund_response.refund_status, enums::RefundStatus::Pending);
+    assert_eq!(refund_response.connector_refund_id, "MOCKPSP000000003");
+}
+
+// Gets the event type of the webhooks sent by the connector.
+#[serial_test::serial]
+#[actix_web::test]
+async fn should_get_event_type_of_webhooks_with_mock_server() {
+    let server = CONNECTOR.start_server().await;
+    let headers = actix_web::http::header::HeaderMap::new();
+
+    for (webhook, expected_event) in [
+        (
+            "payment_succeeded",
+            api::IncomingWebhookEvent::PaymentIntentSuccess,
+        ),
+        (
+            "payment_failed",
+            api::IncomingWebhookEvent::PaymentIntentFailure,
+        ),
+        ("refund_succeeded", api::IncomingWebhookEvent::RefundSuccess),
+    ] {
+        let body = server
+            .fixtures()
+            .webhook(webhook)
+            .expect("Missing webhook fixture");
+        let request = api::IncomingWebhookRequestDetails {
+            method: actix_web::http::Method::POST,
+            uri: actix_web::http::Uri::from_static("/webhooks/merchant_id/adyen"),
+            headers: &headers,
+            body: &body,
+            query_params: String::new(),
+        };
+        let event = router::connector::Adyen
+            .get_webhook_event_type(&request)
+            .unwrap();
+        assert_eq!(event, expected_event);
+    }
+}
+
 // [#478]: add unit tests for non 3DS, wallets & webhooks in connector tests
@@ -71,6 +71,9 @@ impl CashtocodeTest {
             request_incremental_authorization: false,
             metadata: None,
             authentication_data: None,
+            network_token_data: None,
+            sca_exemption: None,
+            customer_document: None,
             customer_acceptance: None,
         })
     }
@@ -1,13 +1,18 @@
 use masking::Secret;
-use router::types::{self, api, storage::enums};
+use router::types::{
+    self,
+    api::{self, IncomingWebhook},
+    storage::enums,
+};
 
 use crate::{
     connector_auth,
-    utils::{self, ConnectorActions},
+    utils::{self, ConnectorActions, LocalMock},
 };
 #[derive(Clone, Copy)]
 struct CheckoutTest;
 impl ConnectorActions for CheckoutTest {}
+impl LocalMock for CheckoutTest {}
 impl utils::Connector for CheckoutTest {
     fn get_data(&self) -> types::api::ConnectorData {
         use router::connector::Checkout;
@@ -170,6 +175,7 @@ async fn should_partially_refund_manually_captured_payment() {
 }
 
 // Synchronizes a refund using the manual capture flow (Non 3DS).
+#[serial_test::parallel]
 #[actix_web::test]
 #[ignore = "Connector Error, needs to be looked into and fixed"]
 async fn should_sync_manually_captured_refund() {
@@ -288,6 +294,7 @@ async fn should_refund_succeeded_payment_multiple_times() {
 }
 
 // Synchronizes a refund using the automatic capture flow (Non 3DS).
+#[serial_test::parallel]
 #[actix_web::test]
 #[ignore = "Connector Error, needs to be looked into and fixed"]
 async fn should_sync_refund() {
@@ -434,4 +441,83 @@ async fn should_fail_for_refund_amount_higher_than_payment_amount() {
 
 // Connector dependent test cases goes here
 
+// Mock server test cases, run against the fixtures of the connector
+// Creates a payment using the manual capture flow.
+#[serial_test::serial]
+#[actix_web::test]
+async fn should_only_authorize_payment_with_mock_server() {
+    let _mock = CONNECTOR.start_server().await;
+    let response = CONNECTOR
+        .authorize_payment(payment_method_details(), get_default_payment_info())
+        .await
+        .expect("Authorize payment response");
+    assert_eq!(response.status, enums::AttemptStatus::Authorized);
+    assert_eq!(
+        utils::get_connector_transaction_id(response.response),
+        Some("pay_mock_authorized".to_string())
+    );
+}
+
+// Captures a payment using the manual capture flow.
+#[serial_test::serial]
+#[actix_web::test]
+async fn should_capture_authorized_payment_with_mock_server() {
+    let _mock = CONNECTOR.start_server().await;
+    let response = CONNECTOR
+        .authorize_and_capture_payment(payment_method_details(), None, get_default_payment_info())
+        .await
+        .expect("Capture payment response");
+    assert_eq!(response.status, enums::AttemptStatus::Charged);
+}
+
+// Refunds a payment using the automatic capture flow.
+#[serial_test::serial]
+#[actix_web::test]
+async fn should_refund_auto_captured_payment_with_mock_server() {
+    let _mock = CONNECTOR.start_server().await;
+    let response = CONNECTOR
+        .make_payment_and_refund(payment_method_details(), None, get_default_payment_info())
+        .await
+        .unwrap();
+    let refund_response = response.response.unwrap();
+    assert_eq!(refund_response.refund_status, enums::RefundStatus::Success);
+    assert_eq!(refund_response.connector_refund_id, "act_mock_refund");
+}
+
+// Gets the event type of the webhooks sent by the connector.
+#[serial_test::serial]
+#[actix_web::test]
+async fn should_get_event_type_of_webhooks_with_mock_server() {
+    let server = CONNECTOR.start_server().await;
+    let headers = actix_web::http::header::HeaderMap::new();
+
+    for (webhook, expected_event) in [
+        (
+            "payment_captured",
+            api::IncomingWebhookEvent::PaymentIntentSuccess,
+        ),
+        (
+            "payment_declined",
+            api::IncomingWebhookEvent::PaymentIntentFailure,
+        ),
+        ("payment_refunded", api::IncomingWebhookEvent::RefundSuccess),
+    ] {
+        let body = server
+            .fixtures()
+            .webhook(webhook)
+            .expect("Missing webhook fixture");
+        let request = api::IncomingWebhookRequestDetails {
+            method: actix_web::http::Method::POST,
+            uri: actix_web::http::Uri::from_static("/webhooks/merchant_id/checkout"),
+            headers: &headers,
+            body: &body,
+            query_params: String::new(),
+        };
+        let event = router::connector::Checkout
+            .get_webhook_event_type(&request)
+            .unwrap();
+        assert_eq!(event, expected_event);
+    }
+}
+
 // [#478]: add unit tests for non 3DS, wallets & webhooks in connector tests
@@ -98,6 +98,9 @@ fn payment_method_details() -> Option<types::PaymentsAuthorizeData> {
         request_incremental_authorization: false,
         metadata: None,
         authentication_data: None,
+        network_token_data: None,
+        sca_exemption: None,
+        customer_document: None,
         customer_acceptance: None,
     })
 }
@@ -2,6 +2,7 @@ use std::{fmt::Debug, marker::PhantomData, str::FromStr, time::Duration};
 
 use async_trait::async_trait;
 use common_utils::pii::Email;
+use connector_mock_server::MockConnectorServer;
 use error_stack::Report;
 use masking::Secret;
 #[cfg(feature = "payouts")]
@@ -15,7 +16,6 @@ use router::{
 };
 use test_utils::connector_auth::ConnectorAuthType;
 use tokio::sync::oneshot;
-use wiremock::{Mock, MockServer};
 
 pub trait Connector {
     fn get_data(&self) -> types::api::ConnectorData;
@@ -399,6 +399,7 @@ pub trait ConnectorActions: Connector {
                 refund_amount: 100,
                 connector_metadata: None,
                 reason: None,
+                reason_code: None,
                 connector_refund_id: Some(refund_id),
                 browser_info: None,
             }),
@@ -462,6 +463,7 @@ pub trait ConnectorActions: Connector {
                     email: Email::from_str("john.doe@example").ok(),
                     phone: Some(Secret::new("620874518".to_string())),
                     phone_country_code: Some("+31".to_string()),
+                    document: None,
                 }),
             },
             payment_info,
@@ -835,27 +837,14 @@ async fn call_connector<
     .await
 }
 
-pub struct MockConfig {
-    pub address: Option<String>,
-    pub mocks: Vec<Mock>,
-}
-
 #[async_trait]
-pub trait LocalMock {
-    async fn start_server(&self, config: MockConfig) -> MockServer {
-        let address = config
-            .address
-            .unwrap_or_else(|| "127.0.0.1:9090".to_string());
-        let listener = std::net::TcpListener::bind(address).unwrap();
-        let expected_server_address = listener
-            .local_addr()
-            .expect("Failed to get server address.");
-        let mock_server = MockServer::builder().listener(listener).start().await;
-        assert_eq!(&expected_server_address, mock_server.address());
-        for mock in config.mocks {
-            mock_server.register(mock).await;
-        }
-        mock_server
+pub trait LocalMock: Connector {
+    /// Starts a mock server serving the fixtures of the connector, the requests to the connector
+    /// being sent to the server until it is dropped
+    async fn start_server(&self) -> MockConnectorServer {
+        MockConnectorServer::start(&self.get_name())
+            .await
+            .expect("Failed to start the mock connector server")
     }
 }
 
@@ -919,6 +908,9 @@ impl Default for PaymentAuthorizeType {
             request_incremental_authorization: false,
             metadata: None,
             authentication_data: None,
+            network_token_data: None,
+            sca_exemption: None,
+            customer_document: None,
             customer_acceptance: None,
         };
         Self(data)
@@ -993,6 +985,7 @@ impl Default for PaymentRefundType {
             webhook_url: None,
             connector_metadata: None,
             reason: Some("Customer returned product".to_string()),
+            reason_code: None,
             connector_refund_id: None,
             browser_info: None,
         };
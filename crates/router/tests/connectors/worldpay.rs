@@ -1,15 +1,14 @@
 use futures::future::OptionFuture;
-use router::types::{self, api, storage::enums};
-use serde_json::json;
-use serial_test::serial;
-use wiremock::{
-    matchers::{body_json, method, path},
-    Mock, ResponseTemplate,
+use router::types::{
+    self,
+    api::{self, IncomingWebhook},
+    storage::enums,
 };
+use serial_test::serial;
 
 use crate::{
     connector_auth,
-    utils::{self, ConnectorActions, LocalMock, MockConfig},
+    utils::{self, ConnectorActions, LocalMock},
 };
 
 struct Worldpay;
@@ -45,7 +44,7 @@ impl utils::Connector for Worldpay {
 #[serial]
 async fn should_authorize_card_payment() {
     let conn = Worldpay {};
-    let _mock = conn.start_server(get_mock_config()).await;
+    let _mock = conn.start_server().await;
     let response = conn.authorize_payment(None, None).await.unwrap();
     assert_eq!(response.status, enums::AttemptStatus::Authorized);
     assert_eq!(
@@ -58,7 +57,7 @@ async fn should_authorize_card_payment() {
 #[serial]
 async fn should_authorize_gpay_payment() {
     let conn = Worldpay {};
-    let _mock = conn.start_server(get_mock_config()).await;
+    let _mock = conn.start_server().await;
     let response = conn
         .authorize_payment(
             Some(types::PaymentsAuthorizeData {
@@ -93,7 +92,7 @@ async fn should_authorize_gpay_payment() {
 #[serial]
 async fn should_authorize_applepay_payment() {
     let conn = Worldpay {};
-    let _mock = conn.start_server(get_mock_config()).await;
+    let _mock = conn.start_server().await;
     let response = conn
         .authorize_payment(
             Some(types::PaymentsAuthorizeData {
@@ -125,7 +124,7 @@ async fn should_authorize_applepay_payment() {
 #[serial]
 async fn should_capture_already_authorized_payment() {
     let connector = Worldpay {};
-    let _mock = connector.start_server(get_mock_config()).await;
+    let _mock = connector.start_server().await;
     let authorize_response = connector.authorize_payment(None, None).await.unwrap();
     assert_eq!(authorize_response.status, enums::AttemptStatus::Authorized);
     let txn_id = utils::get_connector_transaction_id(authorize_response.response);
@@ -145,7 +144,7 @@ async fn should_capture_already_authorized_payment() {
 #[serial]
 async fn should_sync_payment() {
     let connector = Worldpay {};
-    let _mock = connector.start_server(get_mock_config()).await;
+    let _mock = connector.start_server().await;
     let response = connector
         .sync_payment(
             Some(types::PaymentsSyncData {
@@ -165,7 +164,7 @@ async fn should_sync_payment() {
 #[serial]
 async fn should_void_already_authorized_payment() {
     let connector = Worldpay {};
-    let _mock = connector.start_server(get_mock_config()).await;
+    let _mock = connector.start_server().await;
     let authorize_response = connector.authorize_payment(None, None).await.unwrap();
     assert_eq!(authorize_response.status, enums::AttemptStatus::Authorized);
     let txn_id = utils::get_connector_transaction_id(authorize_response.response);
@@ -185,7 +184,7 @@ async fn should_void_already_authorized_payment() {
 #[serial]
 async fn should_fail_capture_for_invalid_payment() {
     let connector = Worldpay {};
-    let _mock = connector.start_server(get_mock_config()).await;
+    let _mock = connector.start_server().await;
     let authorize_response = connector.authorize_payment(None, None).await.unwrap();
     assert_eq!(authorize_response.status, enums::AttemptStatus::Authorized);
     let response = connector
@@ -204,7 +203,7 @@ async fn should_fail_capture_for_invalid_payment() {
 #[serial]
 async fn should_refund_succeeded_payment() {
     let connector = Worldpay {};
-    let _mock = connector.start_server(get_mock_config()).await;
+    let _mock = connector.start_server().await;
     //make a successful payment
     let response = connector.make_payment(None, None).await.unwrap();
 
@@ -224,7 +223,7 @@ async fn should_refund_succeeded_payment() {
 #[serial]
 async fn should_sync_refund() {
     let connector = Worldpay {};
-    let _mock = connector.start_server(get_mock_config()).await;
+    let _mock = connector.start_server().await;
     let response = connector
         .sync_refund("654321".to_string(), None, None)
         .await
@@ -235,129 +234,41 @@ async fn should_sync_refund() {
     );
 }
 
-fn get_mock_config() -> MockConfig {
-    let authorized = json!({
-        "outcome": "authorized",
-        "_links": {
-            "payments:cancel": {
-                "href": "/payments/authorizations/cancellations/123456"
-            },
-            "payments:settle": {
-                "href": "/payments/settlements/123456"
-            },
-            "payments:partialSettle": {
-                "href": "/payments/settlements/partials/123456"
-            },
-            "payments:events": {
-                "href": "/payments/events/123456"
-            },
-            "curies": [
-                {
-                    "name": "payments",
-                    "href": "/rels/payments/{rel}",
-                    "templated": true
-                }
-            ]
-        }
-    });
-    let settled = json!({
-        "_links": {
-            "payments:refund": {
-                "href": "/payments/settlements/refunds/full/654321"
-            },
-            "payments:partialRefund": {
-                "href": "/payments/settlements/refunds/partials/654321"
-            },
-            "payments:events": {
-                "href": "/payments/events/654321"
-            },
-            "curies": [
-                {
-                    "name": "payments",
-                    "href": "/rels/payments/{rel}",
-                    "templated": true
-                }
-            ]
-        }
-    });
-    let error_resp = json!({
-        "errorName": "invalid-id",
-        "message": "You must provide valid transaction id to capture payment"
-    });
-    let partial_refund = json!({
-        "_links": {
-            "payments:events": {
-                "href": "https://try.access.worldpay.com/payments/events/eyJrIjoiazNhYjYzMiJ9"
-            },
-            "curies": [{
-                "name": "payments",
-                "href": "https://try.access.worldpay.com/rels/payments/{rel}",
-                "templated": true
-            }]
-        }
-    });
-    let partial_refund_req_body = json!({
-        "value": {
-            "amount": 100,
-            "currency": "USD"
-        },
-        "reference": "123456"
-    });
-    let refunded = json!({
-        "lastEvent": "refunded",
-        "_links": {
-            "payments:cancel": "/payments/authorizations/cancellations/654321",
-            "payments:settle": "/payments/settlements/full/654321",
-            "payments:partialSettle": "/payments/settlements/partials/654321",
-            "curies": [
-                {
-                    "name": "payments",
-                    "href": "/rels/payments/{rel}",
-                    "templated": true
-                }
-            ]
-        }
-    });
-    let sync_payment = json!({
-        "lastEvent": "authorized",
-        "_links": {
-            "payments:events": "/payments/authorizations/events/654321",
-            "payments:settle": "/payments/settlements/full/654321",
-            "payments:partialSettle": "/payments/settlements/partials/654321",
-            "curies": [
-                {
-                    "name": "payments",
-                    "href": "/rels/payments/{rel}",
-                    "templated": true
-                }
-            ]
-        }
-    });
+#[actix_web::test]
+#[serial]
+async fn should_get_event_type_of_webhooks() {
+    let connector = Worldpay {};
+    let server = connector.start_server().await;
+    let headers = actix_web::http::header::HeaderMap::new();
 
-    MockConfig {
-        address: Some("127.0.0.1:9090".to_string()),
-        mocks: vec![
-            Mock::given(method("POST"))
-                .and(path("/payments/authorizations".to_string()))
-                .respond_with(ResponseTemplate::new(201).set_body_json(authorized)),
-            Mock::given(method("POST"))
-                .and(path("/payments/settlements/123456".to_string()))
-                .respond_with(ResponseTemplate::new(202).set_body_json(settled)),
-            Mock::given(method("GET"))
-                .and(path("/payments/events/112233".to_string()))
-                .respond_with(ResponseTemplate::new(200).set_body_json(sync_payment)),
-            Mock::given(method("POST"))
-                .and(path("/payments/settlements/12345".to_string()))
-                .respond_with(ResponseTemplate::new(400).set_body_json(error_resp)),
-            Mock::given(method("POST"))
-                .and(path(
-                    "/payments/settlements/refunds/partials/123456".to_string(),
-                ))
-                .and(body_json(partial_refund_req_body))
-                .respond_with(ResponseTemplate::new(202).set_body_json(partial_refund)),
-            Mock::given(method("GET"))
-                .and(path("/payments/events/654321".to_string()))
-                .respond_with(ResponseTemplate::new(200).set_body_json(refunded)),
-        ],
+    for (webhook, expected_event) in [
+        (
+            "payment_succeeded",
+            api::IncomingWebhookEvent::PaymentIntentSuccess,
+        ),
+        (
+            "payment_failed",
+            api::IncomingWebhookEvent::PaymentIntentFailure,
+        ),
+        (
+            "refund_succeeded",
+            api::IncomingWebhookEvent::EventNotSupported,
+        ),
+    ] {
+        let body = server
+            .fixtures()
+            .webhook(webhook)
+            .expect("Missing webhook fixture");
+        let request = api::IncomingWebhookRequestDetails {
+            method: actix_web::http::Method::POST,
+            uri: actix_web::http::Uri::from_static("/webhooks/merchant_id/worldpay"),
+            headers: &headers,
+            body: &body,
+            query_params: String::new(),
+        };
+        let event = router::connector::Worldpay
+            .get_webhook_event_type(&request)
+            .unwrap();
+        assert_eq!(event, expected_event);
     }
 }
@@ -376,6 +376,7 @@ async fn payments_create_core() {
         state,
         merchant_account,
         key_store,
+        None,
         payments::PaymentCreate,
         req,
         services::AuthFlow::Merchant,
@@ -558,6 +559,7 @@ async fn payments_create_core_adyen_no_redirect() {
         state,
         merchant_account,
         key_store,
+        None,
         payments::PaymentCreate,
         req,
         services::AuthFlow::Merchant,
@@ -136,6 +136,7 @@ async fn payments_create_core() {
         state,
         merchant_account,
         key_store,
+        None,
         payments::PaymentCreate,
         req,
         services::AuthFlow::Merchant,
@@ -326,6 +327,7 @@ async fn payments_create_core_adyen_no_redirect() {
         state,
         merchant_account,
         key_store,
+        None,
         payments::PaymentCreate,
         req,
         services::AuthFlow::Merchant,
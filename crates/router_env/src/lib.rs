@@ -40,7 +40,10 @@ use crate::types::FlowMetric;
 pub enum AnalyticsFlow {
     GetInfo,
     GetPaymentMetrics,
+    GetOrgPaymentMetrics,
     GetRefundsMetrics,
+    GetRefundTopReasons,
+    GetConnectorUptime,
     GetSdkMetrics,
     GetPaymentFilters,
     GetRefundFilters,
@@ -58,6 +61,15 @@ pub enum AnalyticsFlow {
     GetSearchResults,
     GetDisputeFilters,
     GetDisputeMetrics,
+    GetAuthEventMetrics,
+    CreateReportSchedule,
+    ListReportSchedules,
+    DeleteReportSchedule,
+    ListReportRuns,
+    GetLivePaymentActivity,
+    CreateSuccessRateAlert,
+    ListSuccessRateAlerts,
+    DeleteSuccessRateAlert,
 }
 
 impl FlowMetric for AnalyticsFlow {}
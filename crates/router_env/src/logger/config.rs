@@ -2,7 +2,7 @@
 //! Logger-specific config.
 //!
 
-use std::path::PathBuf;
+use std::{collections::HashMap, path::PathBuf};
 
 use serde::Deserialize;
 
@@ -23,6 +23,12 @@ pub struct Log {
     pub console: LogConsole,
     /// Telemetry / tracing.
     pub telemetry: LogTelemetry,
+    /// Overriding the filtering directives at runtime.
+    pub runtime_filter: LogRuntimeFilter,
+    /// Sampling of the logs of noisy targets.
+    pub sampling: LogSampling,
+    /// Redaction of sensitive values from the logs.
+    pub redaction: LogRedaction,
 }
 
 /// Logging to a file.
@@ -39,8 +45,28 @@ pub struct LogFile {
     pub level: Level,
     /// Directive which sets the log level for one or more crates/modules.
     pub filtering_directive: Option<String>,
+    /// When log files are rotated.
+    pub rotation: LogRotation,
+    /// Size (in MB) at which the log file is rotated, when rotating by size.
+    pub max_file_size_in_mb: u64,
+    /// Maximum number of rotated log files kept when rotating by size, the oldest ones are
+    /// deleted first.
+    pub max_files: Option<usize>,
+    /// Maximum total size (in MB) of the rotated log files kept when rotating by size, the oldest
+    /// ones are deleted first.
+    pub max_total_size_in_mb: Option<u64>,
     // pub do_async: bool, // is not used
-    // pub rotation: u16,
+}
+
+/// When log files are rotated.
+#[derive(Debug, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LogRotation {
+    /// A new log file is started every hour.
+    #[default]
+    Hourly,
+    /// A new log file is started once the log file reaches its maximum size.
+    Size,
 }
 
 /// Describes the level of verbosity of a span or event.
@@ -99,12 +125,63 @@ pub struct LogTelemetry {
     pub otel_exporter_otlp_endpoint: Option<String>,
     /// Timeout (in milliseconds) for sending metrics and traces.
     pub otel_exporter_otlp_timeout: Option<u64>,
+    /// Maximum number of spans queued for export, spans are dropped once the queue is full.
+    pub span_queue_size: Option<usize>,
+    /// Maximum number of spans exported in a single batch.
+    pub span_batch_size: Option<usize>,
+    /// Interval (in milliseconds) at which the queued spans are exported.
+    pub span_export_interval_in_ms: Option<u64>,
+    /// Time (in milliseconds) allowed for flushing the spans and metrics on shutdown.
+    pub shutdown_timeout_in_ms: Option<u64>,
+    /// Bucket boundaries of the histograms without boundaries of their own.
+    pub histogram_buckets: Option<Vec<f64>>,
+    /// Bucket boundaries of the histograms, by name of the histogram (case insensitive).
+    pub histogram_buckets_per_instrument: HashMap<String, Vec<f64>>,
     /// Whether to use xray ID generator, (enable this if you plan to use AWS-XRAY)
     pub use_xray_generator: bool,
     /// Route Based Tracing
     pub route_to_trace: Option<Vec<String>>,
 }
 
+/// Overriding the filtering directives at runtime.
+#[derive(Debug, Deserialize, Clone)]
+#[serde(default)]
+pub struct LogRuntimeFilter {
+    /// Seconds after which overridden directives are reverted, unless specified when overriding.
+    pub default_revert_after_in_secs: u64,
+    /// Maximum number of seconds for which directives can be overridden.
+    pub max_revert_after_in_secs: u64,
+}
+
+/// Sampling of the logs of noisy targets.
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct LogSampling {
+    /// Sampling rules, the rule of the most specific target is applied to an event.
+    pub rules: Vec<LogSamplingRule>,
+}
+
+/// Sampling of the logs of a target. Warnings and errors are never sampled.
+#[derive(Debug, Deserialize, Clone)]
+pub struct LogSamplingRule {
+    /// Target (module path) the rule applies to, along with its sub-modules.
+    pub target: String,
+    /// Only one in every `one_in` events is emitted.
+    pub one_in: Option<u64>,
+    /// Maximum number of events emitted per second.
+    pub max_per_second: Option<u64>,
+}
+
+/// Redaction of sensitive values from the JSON formatted logs. Card numbers and the values of
+/// built-in sensitive fields such as `card_number`, `cvc`, `api_key` and `authorization` are
+/// always redacted.
+#[derive(Debug, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct LogRedaction {
+    /// Names of the fields whose values are redacted, in addition to the built-in ones.
+    pub extra_fields: Vec<String>,
+}
+
 /// Telemetry / tracing.
 #[derive(Default, Debug, Deserialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "snake_case")]
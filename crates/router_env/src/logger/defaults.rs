@@ -6,6 +6,10 @@ impl Default for super::config::LogFile {
             file_name: "debug.log".into(),
             level: super::config::Level(tracing::Level::DEBUG),
             filtering_directive: None,
+            rotation: super::config::LogRotation::Hourly,
+            max_file_size_in_mb: 100,
+            max_files: None,
+            max_total_size_in_mb: None,
         }
     }
 }
@@ -20,3 +24,12 @@ impl Default for super::config::LogConsole {
         }
     }
 }
+
+impl Default for super::config::LogRuntimeFilter {
+    fn default() -> Self {
+        Self {
+            default_revert_after_in_secs: 900,
+            max_revert_after_in_secs: 3600,
+        }
+    }
+}
@@ -21,7 +21,7 @@ use tracing_subscriber::{
     Layer,
 };
 
-use crate::Storage;
+use crate::{Redactor, Storage};
 // TODO: Documentation coverage for this crate
 
 // Implicit keys
@@ -137,6 +137,7 @@ where
     build: String,
     default_fields: HashMap<String, Value>,
     formatter: F,
+    redactor: Redactor,
 }
 
 impl<W, F> FormattingLayer<W, F>
@@ -187,9 +188,17 @@ where
             build,
             default_fields,
             formatter,
+            redactor: Redactor::default(),
         }
     }
 
+    /// Redact the records with the given redactor, instead of the one redacting the built-in
+    /// sensitive fields only.
+    pub fn with_redactor(mut self, redactor: Redactor) -> Self {
+        self.redactor = redactor;
+        self
+    }
+
     /// Serialize common for both span and event entries.
     fn common_serialize<S>(
         &self,
@@ -206,7 +215,7 @@ where
         let is_extra = |s: &str| !IMPLICIT_KEYS.contains(s);
         let is_extra_implicit = |s: &str| is_extra(s) && EXTRA_IMPLICIT_KEYS.contains(s);
 
-        map_serializer.serialize_entry(MESSAGE, &message)?;
+        map_serializer.serialize_entry(MESSAGE, &self.redactor.redact_text(message))?;
         map_serializer.serialize_entry(HOSTNAME, &self.hostname)?;
         map_serializer.serialize_entry(PID, &self.pid)?;
         map_serializer.serialize_entry(ENV, &self.env)?;
@@ -241,15 +250,16 @@ where
         // Write down explicit event's entries.
         if let Some(storage) = storage {
             for (key, value) in storage.values.iter() {
+                let value = self.redactor.redact_value(key, value);
                 if is_extra_implicit(key) {
                     #[cfg(feature = "log_extra_implicit_fields")]
-                    map_serializer.serialize_entry(key, value)?;
+                    map_serializer.serialize_entry(key, &value)?;
                     explicit_entries_set.insert(key);
                 } else if is_extra(key) {
                     #[cfg(feature = "log_custom_entries_to_extra")]
-                    extra.insert(key.to_string(), value.clone());
+                    extra.insert(key.to_string(), value.into_owned());
                     #[cfg(not(feature = "log_custom_entries_to_extra"))]
-                    map_serializer.serialize_entry(key, value)?;
+                    map_serializer.serialize_entry(key, &value)?;
                     explicit_entries_set.insert(key);
                 }
             }
@@ -260,14 +270,15 @@ where
             let extensions = span.extensions();
             if let Some(visitor) = extensions.get::<Storage<'_>>() {
                 for (key, value) in &visitor.values {
+                    let value = self.redactor.redact_value(key, value);
                     if is_extra_implicit(key) && !explicit_entries_set.contains(key) {
                         #[cfg(feature = "log_extra_implicit_fields")]
-                        map_serializer.serialize_entry(key, value)?;
+                        map_serializer.serialize_entry(key, &value)?;
                     } else if is_extra(key) && !explicit_entries_set.contains(key) {
                         #[cfg(feature = "log_custom_entries_to_extra")]
-                        extra.insert(key.to_string(), value.clone());
+                        extra.insert(key.to_string(), value.into_owned());
                         #[cfg(not(feature = "log_custom_entries_to_extra"))]
-                        map_serializer.serialize_entry(key, value)?;
+                        map_serializer.serialize_entry(key, &value)?;
                     } else {
                         tracing::debug!("{} is a reserved entry. Skipping it.", key);
                     }
@@ -16,8 +16,24 @@ pub use types::{Category, Flow, Level, Tag};
 mod setup;
 pub use setup::{setup, TelemetryGuard};
 
+mod rotation;
+mod sampling;
+
+pub mod runtime_filter;
+pub use runtime_filter::{log_filter_handle, LogFilterError, LogFilterHandle, LogFilterOverride};
+
 pub mod formatter;
 pub use formatter::FormattingLayer;
 
+pub mod redaction;
+pub use redaction::Redactor;
+
 pub mod storage;
 pub use storage::{Storage, StorageSubscription};
+
+pub mod span_attributes;
+
+pub mod request_context;
+
+pub mod propagation;
+pub use propagation::trace_context_headers;
@@ -0,0 +1,17 @@
+//!
+//! Propagation of the trace context to the services called.
+//!
+
+use std::collections::HashMap;
+
+use opentelemetry::{global, propagation::TextMapPropagator};
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+
+/// Headers carrying the trace context of the current span in the W3C trace context format, such as
+/// `traceparent`. No headers are returned when the current span is not being traced.
+pub fn trace_context_headers() -> HashMap<String, String> {
+    let context = tracing::Span::current().context();
+    let mut headers = HashMap::new();
+    global::get_text_map_propagator(|propagator| propagator.inject_context(&context, &mut headers));
+    headers
+}
@@ -0,0 +1,269 @@
+//!
+//! Redaction of sensitive values from the log records.
+//!
+//! This is defense-in-depth beyond the `masking` crate, for values which were logged without being
+//! wrapped in a `Secret`: values of fields having a sensitive name are replaced entirely, and card
+//! numbers found in string values and messages are replaced wherever they appear.
+//!
+
+use std::{borrow::Cow, ops::Range};
+
+use serde_json::Value;
+
+use crate::config;
+
+/// Replacement of the redacted values.
+const REDACTED: &str = "*** redacted ***";
+
+/// Names of the fields which are always redacted, in addition to the configured ones.
+const SENSITIVE_FIELDS: [&str; 7] = [
+    "card_number",
+    "card_cvc",
+    "cvc",
+    "cvv",
+    "api_key",
+    "authorization",
+    "password",
+];
+
+const MIN_CARD_NUMBER_DIGITS: usize = 13;
+const MAX_CARD_NUMBER_DIGITS: usize = 19;
+
+/// Redacts the values of sensitive fields and the card numbers from the log records.
+#[derive(Debug, Clone)]
+pub struct Redactor {
+    fields: Vec<String>,
+}
+
+impl Default for Redactor {
+    fn default() -> Self {
+        Self::new(&config::LogRedaction::default())
+    }
+}
+
+impl Redactor {
+    /// Redactor of the built-in sensitive fields along with the configured ones.
+    pub fn new(config: &config::LogRedaction) -> Self {
+        let fields = SENSITIVE_FIELDS
+            .iter()
+            .map(|field| field.to_string())
+            .chain(config.extra_fields.iter().cloned())
+            .collect();
+        Self { fields }
+    }
+
+    /// Whether the values of the field are redacted entirely. Fields are matched without regard
+    /// to case, on the last segment of dotted names such as `headers.authorization`.
+    fn is_sensitive(&self, field: &str) -> bool {
+        let name = field.rsplit('.').next().unwrap_or(field);
+        self.fields
+            .iter()
+            .any(|sensitive| sensitive.eq_ignore_ascii_case(name))
+    }
+
+    /// Value of the field as it is to be logged.
+    pub fn redact_value<'a>(&self, field: &str, value: &'a Value) -> Cow<'a, Value> {
+        if self.is_sensitive(field) {
+            return Cow::Owned(Value::from(REDACTED));
+        }
+
+        match value {
+            Value::String(text) => match redact_card_numbers(text) {
+                Cow::Borrowed(_) => Cow::Borrowed(value),
+                Cow::Owned(text) => Cow::Owned(Value::String(text)),
+            },
+            _ => Cow::Borrowed(value),
+        }
+    }
+
+    /// Text with the card numbers it contains redacted.
+    pub fn redact_text<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        redact_card_numbers(text)
+    }
+}
+
+fn redact_card_numbers(text: &str) -> Cow<'_, str> {
+    let card_numbers = find_card_numbers(text);
+    if card_numbers.is_empty() {
+        return Cow::Borrowed(text);
+    }
+
+    let mut redacted = String::with_capacity(text.len());
+    let mut copied_up_to = 0;
+    for card_number in card_numbers {
+        redacted.push_str(
+            text.get(copied_up_to..card_number.start)
+                .unwrap_or_default(),
+        );
+        redacted.push_str(REDACTED);
+        copied_up_to = card_number.end;
+    }
+    redacted.push_str(text.get(copied_up_to..).unwrap_or_default());
+
+    Cow::Owned(redacted)
+}
+
+/// Byte ranges of the runs of digits which look like card numbers: 13 to 19 digits, optionally
+/// grouped by single spaces or hyphens, passing the Luhn check.
+fn find_card_numbers(text: &str) -> Vec<Range<usize>> {
+    let mut card_numbers = Vec::new();
+    let mut chars = text.char_indices().peekable();
+
+    while let Some((start, c)) = chars.next() {
+        let Some(first_digit) = c.to_digit(10) else {
+            continue;
+        };
+        let mut digits = vec![first_digit];
+        let mut end = start + 1;
+
+        loop {
+            match chars.peek() {
+                Some(&(index, c)) if c.is_ascii_digit() => {
+                    digits.extend(c.to_digit(10));
+                    end = index + 1;
+                    chars.next();
+                }
+                // A separator is only part of the run when it is followed by a digit
+                Some(&(_, ' ' | '-')) => {
+                    let mut lookahead = chars.clone();
+                    lookahead.next();
+                    if !lookahead.peek().is_some_and(|(_, c)| c.is_ascii_digit()) {
+                        break;
+                    }
+                    chars.next();
+                }
+                _ => break,
+            }
+        }
+
+        if (MIN_CARD_NUMBER_DIGITS..=MAX_CARD_NUMBER_DIGITS).contains(&digits.len())
+            && passes_luhn_check(&digits)
+        {
+            card_numbers.push(start..end);
+        }
+    }
+
+    card_numbers
+}
+
+fn passes_luhn_check(digits: &[u32]) -> bool {
+    let sum: u32 = digits
+        .iter()
+        .rev()
+        .enumerate()
+        .map(|(position, &digit)| {
+            if position % 2 == 1 {
+                let doubled = digit * 2;
+                if doubled > 9 {
+                    doubled - 9
+                } else {
+                    doubled
+                }
+            } else {
+                digit
+            }
+        })
+        .sum();
+    sum % 10 == 0
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::{
+        io::Write,
+        sync::{Arc, Mutex},
+    };
+
+    use serde_json::{ser::CompactFormatter, Value};
+    use tracing_subscriber::prelude::*;
+
+    use super::{Redactor, REDACTED};
+    use crate::{config::LogRedaction, FormattingLayer, StorageSubscription};
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_card_numbers_are_redacted() {
+        let redactor = Redactor::default();
+
+        assert_eq!(
+            redactor.redact_text("card 4242424242424242 declined"),
+            format!("card {REDACTED} declined")
+        );
+        assert_eq!(
+            redactor.redact_text("card 4111-1111-1111-1111, 5555 5555 5555 4444."),
+            format!("card {REDACTED}, {REDACTED}.")
+        );
+        // Numbers failing the Luhn check, or too short to be card numbers, are kept
+        assert_eq!(
+            redactor.redact_text("order 4242424242424241 of 123456"),
+            "order 4242424242424241 of 123456"
+        );
+    }
+
+    #[test]
+    fn test_sensitive_fields_are_redacted() {
+        let redactor = Redactor::new(&LogRedaction {
+            extra_fields: vec!["bank_account_number".to_string()],
+        });
+        let value = Value::from("value");
+
+        assert_eq!(*redactor.redact_value("CVC", &value), Value::from(REDACTED));
+        assert_eq!(
+            *redactor.redact_value("headers.authorization", &value),
+            Value::from(REDACTED)
+        );
+        assert_eq!(
+            *redactor.redact_value("bank_account_number", &value),
+            Value::from(REDACTED)
+        );
+        assert_eq!(*redactor.redact_value("payment_id", &value), value);
+    }
+
+    #[test]
+    fn test_formatted_events_are_redacted() {
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::registry()
+            .with(StorageSubscription)
+            .with(FormattingLayer::new(
+                "test",
+                move || writer.clone(),
+                CompactFormatter,
+            ));
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info_span!("payment", api_key = "secret_key").in_scope(|| {
+                tracing::info!(
+                    card_number = "4242424242424242",
+                    card_holder_name = "John Doe",
+                    "Charging card 4242 4242 4242 4242"
+                );
+            });
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let event: Value = serde_json::from_str(output.lines().next().unwrap()).unwrap();
+        assert_eq!(event["card_number"], REDACTED);
+        assert_eq!(event["api_key"], REDACTED);
+        assert_eq!(event["card_holder_name"], "John Doe");
+        assert!(event["message"]
+            .as_str()
+            .unwrap()
+            .ends_with(&format!("Charging card {REDACTED}")));
+        assert!(!output.contains("4242"));
+    }
+}
@@ -0,0 +1,166 @@
+//!
+//! Context of the request being served, attached to every log line of the request.
+//!
+//! The merchant, payment, attempt and flow of a request are only known part way through serving
+//! it, deep within the spans of the request. Recording them with
+//! [`record_request_context!`](crate::record_request_context) attaches them to the current span
+//! and to all of its ancestors up to the root span of the request, so that they are logged with
+//! every event of the request, including the events of the spans entered afterwards such as the
+//! connector calls. Spans declaring the fields, such as the root span of the request, have them
+//! recorded as span fields, which exports them along with the traces.
+//!
+
+use tracing::field::Value;
+use tracing_subscriber::{registry::LookupSpan, Registry};
+
+use crate::Storage;
+
+/// Identifier of the merchant
+pub const MERCHANT_ID: &str = "merchant_id";
+/// Identifier of the payment
+pub const PAYMENT_ID: &str = "payment_id";
+/// Identifier of the payment attempt
+pub const ATTEMPT_ID: &str = "attempt_id";
+/// Name of the flow
+pub const FLOW: &str = "flow";
+
+/// Fields making up the context of a request.
+pub const REQUEST_CONTEXT_KEYS: [&str; 4] = [MERCHANT_ID, PAYMENT_ID, ATTEMPT_ID, FLOW];
+
+/// Records the context of the request on the current span and on all of its ancestors.
+///
+/// Prefer [`record_request_context!`](crate::record_request_context), which only accepts the
+/// fields making up the context of a request.
+pub fn record(context: &[(&'static str, &str)]) {
+    let Some(current_id) = tracing::Span::current().id() else {
+        return;
+    };
+
+    tracing::dispatcher::get_default(|dispatch| {
+        let Some(current) = dispatch
+            .downcast_ref::<Registry>()
+            .and_then(|registry| registry.span(&current_id))
+        else {
+            return;
+        };
+
+        for span in current.scope() {
+            for &(key, value) in context {
+                match span.metadata().fields().field(key) {
+                    // Recording through the subscriber lets every layer see the value, including
+                    // the layer exporting the traces
+                    Some(field) => {
+                        let value: &dyn Value = &value;
+                        let values = [(&field, Some(value))];
+                        let value_set = span.metadata().fields().value_set(&values);
+                        dispatch.record(&span.id(), &tracing::span::Record::new(&value_set));
+                    }
+                    // Values of undeclared fields are dropped by the subscriber, they are only
+                    // attached to the logs of the span
+                    None => {
+                        if let Some(storage) = span.extensions_mut().get_mut::<Storage<'_>>() {
+                            storage.values.insert(key, serde_json::Value::from(value));
+                        }
+                    }
+                }
+            }
+        }
+    });
+}
+
+/// Records the context of the request on the current span and on all of its ancestors, so that it
+/// is attached to every log line of the request.
+///
+/// The values are recorded by their display representation.
+///
+/// ```
+/// router_env::record_request_context!(merchant_id = "merchant_1", payment_id = "pay_1");
+/// ```
+#[macro_export]
+macro_rules! record_request_context {
+    ($($key:ident = $value:expr),+ $(,)?) => {
+        $crate::logger::request_context::record(&[
+            $((
+                $crate::record_request_context!(@key $key),
+                ::std::string::ToString::to_string($value).as_str(),
+            ),)+
+        ])
+    };
+    (@key merchant_id) => {
+        $crate::logger::request_context::MERCHANT_ID
+    };
+    (@key payment_id) => {
+        $crate::logger::request_context::PAYMENT_ID
+    };
+    (@key attempt_id) => {
+        $crate::logger::request_context::ATTEMPT_ID
+    };
+    (@key flow) => {
+        $crate::logger::request_context::FLOW
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::{
+        io::Write,
+        sync::{Arc, Mutex},
+    };
+
+    use serde_json::{ser::CompactFormatter, Value};
+    use tracing_subscriber::prelude::*;
+
+    use crate::{FormattingLayer, StorageSubscription};
+
+    #[derive(Clone, Default)]
+    struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Buffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().unwrap().write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_request_context_is_attached_to_the_whole_request() {
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::registry()
+            .with(StorageSubscription)
+            .with(FormattingLayer::new(
+                "test",
+                move || writer.clone(),
+                CompactFormatter,
+            ));
+
+        tracing::subscriber::with_default(subscriber, || {
+            let _root =
+                tracing::info_span!("ROOT_SPAN", payment_id = tracing::field::Empty).entered();
+            tracing::info_span!("payments_core").in_scope(|| {
+                crate::record_request_context!(payment_id = "pay_1", attempt_id = "pay_1_1");
+            });
+            tracing::info_span!("call_connector").in_scope(|| {
+                tracing::info!("Calling the connector");
+            });
+            tracing::info!("Request completed");
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let events = output
+            .lines()
+            .map(|line| serde_json::from_str::<Value>(line).unwrap())
+            .filter(|line| line["message"].as_str().unwrap().contains("EVENT"))
+            .collect::<Vec<_>>();
+        assert_eq!(events.len(), 2);
+        for event in events {
+            assert_eq!(event["payment_id"], "pay_1");
+            assert_eq!(event["attempt_id"], "pay_1_1");
+        }
+    }
+}
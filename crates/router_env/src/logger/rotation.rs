@@ -0,0 +1,309 @@
+//!
+//! Size based rotation of the log files.
+//!
+//! The log file is renamed with the time of its rotation once it reaches the maximum size, and a
+//! new log file is started. The rotated files exceeding the retention limits are deleted on every
+//! rotation, oldest first.
+//!
+
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use crate::config;
+
+const BYTES_PER_MB: u64 = 1024 * 1024;
+
+impl config::LogFile {
+    /// Validates the size based rotation settings, a maximum file size of zero would rotate the
+    /// log file on every write.
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if self.enabled
+            && self.rotation == config::LogRotation::Size
+            && self.max_file_size_in_mb == 0
+        {
+            return Err("log file max_file_size_in_mb must be greater than 0");
+        }
+        Ok(())
+    }
+}
+
+/// Limits on the rotated log files kept, the file being written to is not counted.
+#[derive(Debug, Clone, Copy)]
+struct Retention {
+    max_files: Option<usize>,
+    max_total_size: Option<u64>,
+}
+
+/// Writer to a log file rotated by size.
+#[derive(Debug)]
+pub(super) struct SizeRotatingFileWriter {
+    directory: PathBuf,
+    file_name: String,
+    max_file_size: u64,
+    retention: Retention,
+    file: File,
+    file_size: u64,
+}
+
+impl SizeRotatingFileWriter {
+    pub(super) fn new(directory: &Path, config: &config::LogFile) -> io::Result<Self> {
+        Self::with_limits(
+            directory,
+            &config.file_name,
+            config.max_file_size_in_mb.saturating_mul(BYTES_PER_MB),
+            Retention {
+                max_files: config.max_files,
+                max_total_size: config
+                    .max_total_size_in_mb
+                    .map(|size| size.saturating_mul(BYTES_PER_MB)),
+            },
+        )
+    }
+
+    fn with_limits(
+        directory: &Path,
+        file_name: &str,
+        max_file_size: u64,
+        retention: Retention,
+    ) -> io::Result<Self> {
+        fs::create_dir_all(directory)?;
+        let file = open_log_file(&directory.join(file_name))?;
+        let file_size = file.metadata()?.len();
+
+        Ok(Self {
+            directory: directory.to_path_buf(),
+            file_name: file_name.to_owned(),
+            max_file_size,
+            retention,
+            file,
+            file_size,
+        })
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+
+        let rotated_file_name = format!("{}.{}", self.file_name, rotation_timestamp());
+        let mut rotated_path = self.directory.join(&rotated_file_name);
+        // Files may be rotated more than once within a second
+        let mut suffix = 1;
+        while rotated_path.exists() {
+            rotated_path = self.directory.join(format!("{rotated_file_name}.{suffix}"));
+            suffix += 1;
+        }
+        fs::rename(self.directory.join(&self.file_name), rotated_path)?;
+
+        self.file = open_log_file(&self.directory.join(&self.file_name))?;
+        self.file_size = 0;
+
+        if let Err(error) = self.enforce_retention() {
+            eprintln!("Failed to delete the log files exceeding the retention limits: {error}");
+        }
+        Ok(())
+    }
+
+    /// Deletes the oldest rotated files exceeding the maximum number of files or total size.
+    fn enforce_retention(&self) -> io::Result<()> {
+        if self.retention.max_files.is_none() && self.retention.max_total_size.is_none() {
+            return Ok(());
+        }
+
+        let prefix = format!("{}.", self.file_name);
+        let mut rotated_files = fs::read_dir(&self.directory)?
+            .filter_map(Result::ok)
+            .filter(|entry| {
+                entry
+                    .file_name()
+                    .to_str()
+                    .is_some_and(|name| name.starts_with(&prefix))
+            })
+            .filter_map(|entry| {
+                let metadata = entry
+                    .metadata()
+                    .ok()
+                    .filter(|metadata| metadata.is_file())?;
+                let modified_at = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+                Some((entry.path(), modified_at, metadata.len()))
+            })
+            .collect::<Vec<_>>();
+        // Newest first, so that the oldest files are the ones exceeding the limits
+        rotated_files.sort_by(|(_, a, _), (_, b, _)| b.cmp(a));
+
+        let mut total_size: u64 = 0;
+        for (index, (path, _, size)) in rotated_files.into_iter().enumerate() {
+            total_size = total_size.saturating_add(size);
+            let exceeds_max_files = self
+                .retention
+                .max_files
+                .is_some_and(|max_files| index >= max_files);
+            let exceeds_max_total_size = self
+                .retention
+                .max_total_size
+                .is_some_and(|max_total_size| total_size > max_total_size);
+            if exceeds_max_files || exceeds_max_total_size {
+                fs::remove_file(path)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Write for SizeRotatingFileWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let length = u64::try_from(buf.len()).unwrap_or(u64::MAX);
+        // Records are not split across files, the file is rotated before the record which would
+        // take it over the maximum size
+        if self.file_size > 0 && self.file_size.saturating_add(length) > self.max_file_size {
+            self.rotate()?;
+        }
+
+        let written = self.file.write(buf)?;
+        self.file_size = self
+            .file_size
+            .saturating_add(u64::try_from(written).unwrap_or(u64::MAX));
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
+}
+
+fn open_log_file(path: &Path) -> io::Result<File> {
+    OpenOptions::new().create(true).append(true).open(path)
+}
+
+/// UTC time of the rotation, formatted so that the rotated files sort by name in time order.
+fn rotation_timestamp() -> String {
+    let now = time::OffsetDateTime::now_utc();
+    format!(
+        "{:04}-{:02}-{:02}-{:02}-{:02}-{:02}",
+        now.year(),
+        u8::from(now.month()),
+        now.day(),
+        now.hour(),
+        now.minute(),
+        now.second()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use std::{
+        fs,
+        io::Write,
+        path::{Path, PathBuf},
+    };
+
+    use super::{Retention, SizeRotatingFileWriter};
+    use crate::config::{LogFile, LogRotation};
+
+    fn test_directory(name: &str) -> PathBuf {
+        let directory =
+            std::env::temp_dir().join(format!("router_env_{name}_{}", std::process::id()));
+        let _ = fs::remove_dir_all(&directory);
+        directory
+    }
+
+    fn log_files(directory: &Path) -> Vec<(String, u64)> {
+        let mut files = fs::read_dir(directory)
+            .unwrap()
+            .map(|entry| {
+                let entry = entry.unwrap();
+                (
+                    entry.file_name().into_string().unwrap(),
+                    entry.metadata().unwrap().len(),
+                )
+            })
+            .collect::<Vec<_>>();
+        files.sort();
+        files
+    }
+
+    #[test]
+    fn test_zero_max_file_size_is_rejected_when_rotating_by_size() {
+        let config = LogFile {
+            rotation: LogRotation::Size,
+            max_file_size_in_mb: 0,
+            ..LogFile::default()
+        };
+        assert!(config.validate().is_err());
+
+        let config = LogFile {
+            rotation: LogRotation::Size,
+            max_file_size_in_mb: 1,
+            ..LogFile::default()
+        };
+        assert!(config.validate().is_ok());
+
+        // The maximum size is not used when rotating hourly
+        let config = LogFile {
+            rotation: LogRotation::Hourly,
+            max_file_size_in_mb: 0,
+            ..LogFile::default()
+        };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn test_log_file_is_rotated_by_size() {
+        let directory = test_directory("rotated_by_size");
+        let mut writer = SizeRotatingFileWriter::with_limits(
+            &directory,
+            "debug.log",
+            12,
+            Retention {
+                max_files: None,
+                max_total_size: None,
+            },
+        )
+        .unwrap();
+
+        writer.write_all(b"0123456\n").unwrap();
+        writer.write_all(b"0123456\n").unwrap();
+        writer.write_all(b"01\n").unwrap();
+        writer.flush().unwrap();
+
+        let files = log_files(&directory);
+        assert_eq!(files.len(), 2);
+        assert_eq!(files.first(), Some(&("debug.log".to_string(), 11)));
+        let (rotated_file_name, rotated_file_size) = files.last().unwrap();
+        assert!(rotated_file_name.starts_with("debug.log."));
+        assert_eq!(*rotated_file_size, 8);
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+
+    #[test]
+    fn test_rotated_files_exceeding_the_retention_limits_are_deleted() {
+        let directory = test_directory("retention");
+        let mut writer = SizeRotatingFileWriter::with_limits(
+            &directory,
+            "debug.log",
+            8,
+            Retention {
+                max_files: Some(2),
+                max_total_size: None,
+            },
+        )
+        .unwrap();
+
+        for _ in 0..5 {
+            writer.write_all(b"0123456\n").unwrap();
+        }
+        writer.flush().unwrap();
+
+        let files = log_files(&directory);
+        // The file being written to and the two most recently rotated files are kept
+        assert_eq!(files.len(), 3);
+        assert_eq!(files.first(), Some(&("debug.log".to_string(), 8)));
+
+        fs::remove_dir_all(&directory).unwrap();
+    }
+}
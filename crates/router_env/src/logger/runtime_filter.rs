@@ -0,0 +1,247 @@
+//!
+//! Overriding the filtering directives of the log layers at runtime.
+//!
+//! Overrides are added on top of the directives the log layers were set up with, so that a module
+//! can be made more verbose for a while without restarting the application. Overrides are
+//! reverted automatically once their duration elapses.
+//!
+
+use std::{
+    fmt,
+    sync::{Mutex, MutexGuard},
+    time::Duration,
+};
+
+use once_cell::sync::OnceCell;
+use time::OffsetDateTime;
+use tracing_subscriber::{reload, EnvFilter};
+
+use crate::config;
+
+static LOG_FILTER_HANDLE: OnceCell<LogFilterHandle> = OnceCell::new();
+
+/// Handle to the filters of the log layers, available once the logging sub-system is set up.
+pub fn log_filter_handle() -> Option<&'static LogFilterHandle> {
+    LOG_FILTER_HANDLE.get()
+}
+
+pub(super) fn set_log_filter_handle(handle: LogFilterHandle) {
+    // The logging sub-system is only set up once, the handle set first is kept otherwise
+    let _ = LOG_FILTER_HANDLE.set(handle);
+}
+
+/// Errors raised while overriding the filtering directives.
+#[derive(Debug)]
+pub enum LogFilterError {
+    /// The directives could not be parsed.
+    InvalidDirectives(String),
+    /// The duration of the override exceeds the configured maximum.
+    DurationTooLong {
+        /// Maximum duration of an override.
+        max: Duration,
+    },
+    /// The filter of a log layer could not be replaced.
+    ReloadFailed(String),
+}
+
+impl fmt::Display for LogFilterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidDirectives(error) => write!(f, "Invalid filtering directives: {error}"),
+            Self::DurationTooLong { max } => write!(
+                f,
+                "Filtering directives cannot be overridden for more than {} seconds",
+                max.as_secs()
+            ),
+            Self::ReloadFailed(error) => write!(f, "Failed to reload the log filter: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for LogFilterError {}
+
+type ReloadFn = Box<dyn Fn(EnvFilter) -> Result<(), reload::Error> + Send + Sync>;
+
+/// Log layer whose filter can be replaced at runtime.
+pub(super) struct ReloadableLayer {
+    name: &'static str,
+    default_directives: String,
+    reload: ReloadFn,
+}
+
+impl ReloadableLayer {
+    pub(super) fn new<S: 'static>(
+        name: &'static str,
+        default_directives: String,
+        handle: reload::Handle<EnvFilter, S>,
+    ) -> Self {
+        Self {
+            name,
+            default_directives,
+            reload: Box::new(move |filter| handle.reload(filter)),
+        }
+    }
+}
+
+/// Directives currently added on top of the directives of the log layers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogFilterOverride {
+    /// Directives added on top of the directives of the log layers.
+    pub directives: String,
+    /// Time at which the directives are reverted.
+    pub reverts_at: OffsetDateTime,
+}
+
+#[derive(Default)]
+struct OverrideState {
+    active: Option<LogFilterOverride>,
+    /// Incremented on every change, so that a pending revert does not undo a later override.
+    generation: u64,
+    revert_task: Option<tokio::task::JoinHandle<()>>,
+}
+
+/// Handle to the filters of the log layers.
+pub struct LogFilterHandle {
+    layers: Vec<ReloadableLayer>,
+    config: config::LogRuntimeFilter,
+    state: Mutex<OverrideState>,
+}
+
+impl fmt::Debug for LogFilterHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("LogFilterHandle")
+            .field(
+                "layers",
+                &self
+                    .layers
+                    .iter()
+                    .map(|layer| layer.name)
+                    .collect::<Vec<_>>(),
+            )
+            .field("config", &self.config)
+            .finish()
+    }
+}
+
+impl LogFilterHandle {
+    pub(super) fn new(layers: Vec<ReloadableLayer>, config: config::LogRuntimeFilter) -> Self {
+        Self {
+            layers,
+            config,
+            state: Mutex::new(OverrideState::default()),
+        }
+    }
+
+    /// Directives of each log layer, as they were set up.
+    pub fn default_directives(&self) -> Vec<(&'static str, &str)> {
+        self.layers
+            .iter()
+            .map(|layer| (layer.name, layer.default_directives.as_str()))
+            .collect()
+    }
+
+    /// The override currently in effect, if any.
+    pub fn active_override(&self) -> Option<LogFilterOverride> {
+        self.lock_state().active.clone()
+    }
+
+    /// Adds the directives on top of the directives of every log layer, replacing any override in
+    /// effect. The directives are reverted after the given duration, or after the configured
+    /// default duration if none is given.
+    ///
+    /// This must be called within a tokio runtime, which the revert is scheduled on.
+    pub fn apply_override(
+        &'static self,
+        directives: &str,
+        revert_after: Option<Duration>,
+    ) -> Result<LogFilterOverride, LogFilterError> {
+        let revert_after = revert_after.unwrap_or(Duration::from_secs(
+            self.config.default_revert_after_in_secs,
+        ));
+        let max_revert_after = Duration::from_secs(self.config.max_revert_after_in_secs);
+        if revert_after > max_revert_after {
+            return Err(LogFilterError::DurationTooLong {
+                max: max_revert_after,
+            });
+        }
+
+        // All the filters are parsed before any is replaced, so that invalid directives leave
+        // every layer untouched
+        let filters = self
+            .layers
+            .iter()
+            .map(|layer| parse_filter(&format!("{},{directives}", layer.default_directives)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut state = self.lock_state();
+        for (layer, filter) in self.layers.iter().zip(filters) {
+            (layer.reload)(filter)
+                .map_err(|error| LogFilterError::ReloadFailed(error.to_string()))?;
+        }
+
+        let active = LogFilterOverride {
+            directives: directives.to_owned(),
+            reverts_at: OffsetDateTime::now_utc() + revert_after,
+        };
+        state.generation = state.generation.wrapping_add(1);
+        let generation = state.generation;
+        if let Some(revert_task) = state.revert_task.take() {
+            revert_task.abort();
+        }
+        state.revert_task = Some(tokio::spawn(async move {
+            tokio::time::sleep(revert_after).await;
+            if let Err(error) = self.revert(Some(generation)) {
+                tracing::error!(%error, "Failed to revert the overridden log filter");
+            }
+        }));
+        state.active = Some(active.clone());
+        tracing::warn!(directives, ?revert_after, "Overrode the log filter");
+
+        Ok(active)
+    }
+
+    /// Restores the directives the log layers were set up with.
+    pub fn revert_override(&self) -> Result<(), LogFilterError> {
+        self.revert(None)
+    }
+
+    /// Restores the default directives, unless the override was replaced after the given
+    /// generation.
+    fn revert(&self, generation: Option<u64>) -> Result<(), LogFilterError> {
+        let mut state = self.lock_state();
+        if generation.is_some_and(|generation| generation != state.generation) {
+            return Ok(());
+        }
+
+        for layer in self.layers.iter() {
+            (layer.reload)(parse_filter(&layer.default_directives)?)
+                .map_err(|error| LogFilterError::ReloadFailed(error.to_string()))?;
+        }
+
+        state.generation = state.generation.wrapping_add(1);
+        if let Some(revert_task) = state.revert_task.take() {
+            // The task reverting the override is not aborted when it is the one reverting it
+            if generation.is_none() {
+                revert_task.abort();
+            }
+        }
+        if state.active.take().is_some() {
+            tracing::warn!("Reverted the overridden log filter");
+        }
+        Ok(())
+    }
+
+    fn lock_state(&self) -> MutexGuard<'_, OverrideState> {
+        // The state is left consistent even if a holder of the lock panicked
+        self.state
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+fn parse_filter(directives: &str) -> Result<EnvFilter, LogFilterError> {
+    EnvFilter::builder()
+        .with_default_directive(tracing::Level::WARN.into())
+        .parse(directives)
+        .map_err(|error| LogFilterError::InvalidDirectives(error.to_string()))
+}
@@ -0,0 +1,177 @@
+//!
+//! Sampling and rate limiting of the events of noisy targets.
+//!
+//! Events of the targets having a sampling rule are dropped before they reach any of the log
+//! layers, so that high-volume logs of a module do not overwhelm the file, console or OTLP sinks.
+//! Warnings and errors are never dropped.
+//!
+
+use std::{
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use tracing::{Event, Subscriber};
+use tracing_subscriber::{layer::Context, Layer};
+
+use crate::config;
+
+/// Layer dropping the events of the targets having a sampling rule.
+#[derive(Debug)]
+pub(super) struct SamplingLayer {
+    /// Samplers ordered from the most specific target to the least specific one.
+    samplers: Vec<TargetSampler>,
+}
+
+impl SamplingLayer {
+    pub(super) fn new(config: &config::LogSampling) -> Self {
+        let mut samplers = config
+            .rules
+            .iter()
+            .map(TargetSampler::new)
+            .collect::<Vec<_>>();
+        samplers.sort_by(|a, b| b.target.len().cmp(&a.target.len()));
+        Self { samplers }
+    }
+
+    fn sampler_for(&self, target: &str) -> Option<&TargetSampler> {
+        self.samplers.iter().find(|sampler| sampler.matches(target))
+    }
+}
+
+impl<S: Subscriber> Layer<S> for SamplingLayer {
+    fn event_enabled(&self, event: &Event<'_>, _ctx: Context<'_, S>) -> bool {
+        let metadata = event.metadata();
+        if *metadata.level() <= tracing::Level::WARN {
+            return true;
+        }
+
+        self.sampler_for(metadata.target())
+            .map_or(true, TargetSampler::sample)
+    }
+}
+
+/// Number of events emitted within the current one second window.
+#[derive(Debug)]
+struct RateWindow {
+    started_at: Instant,
+    emitted: u64,
+}
+
+#[derive(Debug)]
+struct TargetSampler {
+    target: String,
+    one_in: Option<u64>,
+    max_per_second: Option<u64>,
+    seen: AtomicU64,
+    window: Mutex<RateWindow>,
+}
+
+impl TargetSampler {
+    fn new(rule: &config::LogSamplingRule) -> Self {
+        Self {
+            target: rule.target.clone(),
+            // Keeping one in every event, or one in no event, is no sampling at all
+            one_in: rule.one_in.filter(|one_in| *one_in > 1),
+            max_per_second: rule.max_per_second,
+            seen: AtomicU64::new(0),
+            window: Mutex::new(RateWindow {
+                started_at: Instant::now(),
+                emitted: 0,
+            }),
+        }
+    }
+
+    /// Whether the target is the target of the rule, or one of its sub-modules.
+    fn matches(&self, target: &str) -> bool {
+        target
+            .strip_prefix(self.target.as_str())
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with("::"))
+    }
+
+    /// Whether the event is to be emitted, events which are not sampled are not counted towards
+    /// the rate limit.
+    fn sample(&self) -> bool {
+        if let Some(one_in) = self.one_in {
+            if self.seen.fetch_add(1, Ordering::Relaxed) % one_in != 0 {
+                return false;
+            }
+        }
+
+        let Some(max_per_second) = self.max_per_second else {
+            return true;
+        };
+        let mut window = self
+            .window
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let now = Instant::now();
+        if now.duration_since(window.started_at) >= Duration::from_secs(1) {
+            *window = RateWindow {
+                started_at: now,
+                emitted: 0,
+            };
+        }
+        if window.emitted >= max_per_second {
+            return false;
+        }
+        window.emitted += 1;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SamplingLayer, TargetSampler};
+    use crate::config::{LogSampling, LogSamplingRule};
+
+    fn rule(target: &str, one_in: Option<u64>, max_per_second: Option<u64>) -> LogSamplingRule {
+        LogSamplingRule {
+            target: target.to_string(),
+            one_in,
+            max_per_second,
+        }
+    }
+
+    #[test]
+    fn test_most_specific_rule_is_applied() {
+        let layer = SamplingLayer::new(&LogSampling {
+            rules: vec![
+                rule("router", Some(10), None),
+                rule("router::connector", Some(100), None),
+            ],
+        });
+
+        let target_of = |target| {
+            layer
+                .sampler_for(target)
+                .map(|sampler| sampler.target.as_str())
+        };
+        assert_eq!(
+            target_of("router::connector::stripe"),
+            Some("router::connector")
+        );
+        assert_eq!(target_of("router::core"), Some("router"));
+        assert_eq!(target_of("router"), Some("router"));
+        assert_eq!(target_of("router_env"), None);
+    }
+
+    #[test]
+    fn test_one_in_n_events_are_sampled() {
+        let sampler = TargetSampler::new(&rule("router", Some(3), None));
+
+        let sampled = (0..9).filter(|_| sampler.sample()).count();
+        assert_eq!(sampled, 3);
+    }
+
+    #[test]
+    fn test_events_are_rate_limited() {
+        let sampler = TargetSampler::new(&rule("router", None, Some(2)));
+
+        let sampled = (0..10).filter(|_| sampler.sample()).count();
+        assert_eq!(sampled, 2);
+    }
+}
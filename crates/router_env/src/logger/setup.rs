@@ -1,12 +1,16 @@
 //! Setup logging subsystem.
 
-use std::time::Duration;
+use std::{collections::HashMap, sync::Arc, time::Duration};
 
 use opentelemetry::{
     global, runtime,
     sdk::{
-        export::metrics::aggregation::cumulative_temporality_selector,
-        metrics::{controllers::BasicController, selectors::simple},
+        export::metrics::{aggregation::cumulative_temporality_selector, AggregatorSelector},
+        metrics::{
+            aggregators::{self, Aggregator},
+            controllers::BasicController,
+            sdk_api::{Descriptor, InstrumentKind},
+        },
         propagation::TraceContextPropagator,
         trace,
         trace::BatchConfig,
@@ -18,15 +22,59 @@ use opentelemetry::{
 use opentelemetry_otlp::{TonicExporterBuilder, WithExportConfig};
 use serde_json::ser::{CompactFormatter, PrettyFormatter};
 use tracing_appender::non_blocking::WorkerGuard;
-use tracing_subscriber::{fmt, prelude::*, util::SubscriberInitExt, EnvFilter, Layer};
+use tracing_subscriber::{fmt, prelude::*, reload, util::SubscriberInitExt, EnvFilter, Layer};
 
-use crate::{config, FormattingLayer, StorageSubscription};
+use super::{
+    rotation::SizeRotatingFileWriter,
+    runtime_filter::{self, LogFilterHandle, ReloadableLayer},
+    sampling::SamplingLayer,
+};
+use crate::{config, FormattingLayer, Redactor, StorageSubscription};
+
+const DEFAULT_SPAN_QUEUE_SIZE: usize = 2048;
+const DEFAULT_SPAN_BATCH_SIZE: usize = 512;
+// Spans are exported every second instead of the default of every 5 seconds
+const DEFAULT_SPAN_EXPORT_INTERVAL_IN_MS: u64 = 1000;
+const DEFAULT_SHUTDOWN_TIMEOUT_IN_MS: u64 = 5000;
 
 /// Contains guards necessary for logging and metrics collection.
 #[derive(Debug)]
 pub struct TelemetryGuard {
-    _log_guards: Vec<WorkerGuard>,
-    _metrics_controller: Option<BasicController>,
+    log_guards: Vec<WorkerGuard>,
+    metrics_controller: Option<BasicController>,
+    shutdown_timeout: Duration,
+}
+
+impl TelemetryGuard {
+    /// Flushes the spans and metrics yet to be exported and the logs yet to be written, so that
+    /// they are not lost when the application exits. Flushing the spans and metrics is abandoned
+    /// once the configured shutdown timeout elapses.
+    pub fn shutdown(self) {
+        let Self {
+            log_guards,
+            metrics_controller,
+            shutdown_timeout,
+        } = self;
+
+        // The exporters block until they are flushed, they are shut down on a separate thread so
+        // that they can be abandoned once the timeout elapses
+        let (sender, receiver) = std::sync::mpsc::channel();
+        std::thread::spawn(move || {
+            global::shutdown_tracer_provider();
+            if let Some(metrics_controller) = metrics_controller {
+                if let Err(error) = metrics_controller.stop(&opentelemetry::Context::current()) {
+                    eprintln!("Failed to flush the metrics: {error:?}");
+                }
+            }
+            let _ = sender.send(());
+        });
+        if receiver.recv_timeout(shutdown_timeout).is_err() {
+            eprintln!("Timed out after {shutdown_timeout:?} flushing the spans and metrics");
+        }
+
+        // The non-blocking writers write the pending logs when their guards are dropped
+        drop(log_guards);
+    }
 }
 
 /// Setup logging sub-system specifying the logging configuration, service (binary) name, and a
@@ -38,6 +86,8 @@ pub fn setup(
     crates_to_filter: impl AsRef<[&'static str]>,
 ) -> TelemetryGuard {
     let mut guards = Vec::new();
+    let mut reloadable_layers = Vec::new();
+    let redactor = Redactor::new(&config.redaction);
 
     // Setup OpenTelemetry traces and metrics
     let traces_layer = if config.telemetry.traces_enabled {
@@ -45,7 +95,7 @@ pub fn setup(
     } else {
         None
     };
-    let _metrics_controller = if config.telemetry.metrics_enabled {
+    let metrics_controller = if config.telemetry.metrics_enabled {
         setup_metrics_pipeline(&config.telemetry)
     } else {
         None
@@ -58,8 +108,19 @@ pub fn setup(
         // which is the intended behavior for us.
         path.push(&config.file.path);
 
-        let file_appender = tracing_appender::rolling::hourly(&path, &config.file.file_name);
-        let (file_writer, guard) = tracing_appender::non_blocking(file_appender);
+        let (file_writer, guard) = match config.file.rotation {
+            config::LogRotation::Hourly => tracing_appender::non_blocking(
+                tracing_appender::rolling::hourly(&path, &config.file.file_name),
+            ),
+            config::LogRotation::Size => {
+                // Safety: Logging to files is enabled, the application must not run without the
+                // log file.
+                #[allow(clippy::expect_used)]
+                let file_appender = SizeRotatingFileWriter::new(&path, &config.file)
+                    .expect("Failed to open the log file");
+                tracing_appender::non_blocking(file_appender)
+            }
+        };
         guards.push(guard);
 
         let file_filter = get_envfilter(
@@ -70,15 +131,26 @@ pub fn setup(
         );
         println!("Using file logging filter: {file_filter}");
 
+        let default_directives = file_filter.to_string();
+        let (reloadable_filter, reload_handle) = reload::Layer::new(file_filter);
+        reloadable_layers.push(ReloadableLayer::new(
+            "file",
+            default_directives,
+            reload_handle,
+        ));
+
         Some(
             FormattingLayer::new(service_name, file_writer, CompactFormatter)
-                .with_filter(file_filter),
+                .with_redactor(redactor.clone())
+                .with_filter(reloadable_filter),
         )
     } else {
         None
     };
 
+    // Events dropped by the sampling layer are not seen by any of the other layers
     let subscriber = tracing_subscriber::registry()
+        .with(SamplingLayer::new(&config.sampling))
         .with(traces_layer)
         .with(StorageSubscription)
         .with(file_writer);
@@ -96,27 +168,37 @@ pub fn setup(
         );
         println!("Using console logging filter: {console_filter}");
 
+        let default_directives = console_filter.to_string();
+        let (reloadable_filter, reload_handle) = reload::Layer::new(console_filter);
+        reloadable_layers.push(ReloadableLayer::new(
+            "console",
+            default_directives,
+            reload_handle,
+        ));
+
         match config.console.log_format {
             config::LogFormat::Default => {
                 let logging_layer = fmt::layer()
                     .with_timer(fmt::time::time())
                     .pretty()
                     .with_writer(console_writer)
-                    .with_filter(console_filter);
+                    .with_filter(reloadable_filter);
                 subscriber.with(logging_layer).init();
             }
             config::LogFormat::Json => {
                 error_stack::Report::set_color_mode(error_stack::fmt::ColorMode::None);
                 let logging_layer =
                     FormattingLayer::new(service_name, console_writer, CompactFormatter)
-                        .with_filter(console_filter);
+                        .with_redactor(redactor)
+                        .with_filter(reloadable_filter);
                 subscriber.with(logging_layer).init();
             }
             config::LogFormat::PrettyJson => {
                 error_stack::Report::set_color_mode(error_stack::fmt::ColorMode::None);
                 let logging_layer =
                     FormattingLayer::new(service_name, console_writer, PrettyFormatter::new())
-                        .with_filter(console_filter);
+                        .with_redactor(redactor)
+                        .with_filter(reloadable_filter);
                 subscriber.with(logging_layer).init();
             }
         }
@@ -124,11 +206,22 @@ pub fn setup(
         subscriber.init();
     };
 
+    runtime_filter::set_log_filter_handle(LogFilterHandle::new(
+        reloadable_layers,
+        config.runtime_filter.clone(),
+    ));
+
     // Returning the TelemetryGuard for logs to be printed and metrics to be collected until it is
     // dropped
     TelemetryGuard {
-        _log_guards: guards,
-        _metrics_controller,
+        log_guards: guards,
+        metrics_controller,
+        shutdown_timeout: Duration::from_millis(
+            config
+                .telemetry
+                .shutdown_timeout_in_ms
+                .unwrap_or(DEFAULT_SHUTDOWN_TIMEOUT_IN_MS),
+        ),
     }
 }
 
@@ -231,6 +324,27 @@ impl<T: trace::ShouldSample + Clone + 'static> trace::ShouldSample for Condition
     }
 }
 
+/// Spans are exported in batches from a background task, so that the spans being closed never wait
+/// on the export.
+fn get_span_batch_config(config: &config::LogTelemetry) -> BatchConfig {
+    let max_queue_size = config.span_queue_size.unwrap_or(DEFAULT_SPAN_QUEUE_SIZE);
+
+    BatchConfig::default()
+        .with_max_queue_size(max_queue_size)
+        // Batches larger than the queue could never be filled
+        .with_max_export_batch_size(
+            config
+                .span_batch_size
+                .unwrap_or(DEFAULT_SPAN_BATCH_SIZE)
+                .min(max_queue_size),
+        )
+        .with_scheduled_delay(Duration::from_millis(
+            config
+                .span_export_interval_in_ms
+                .unwrap_or(DEFAULT_SPAN_EXPORT_INTERVAL_IN_MS),
+        ))
+}
+
 fn setup_tracing_pipeline(
     config: &config::LogTelemetry,
     service_name: &str,
@@ -257,8 +371,7 @@ fn setup_tracing_pipeline(
         trace_config = trace_config.with_id_generator(trace::XrayIdGenerator::default());
     }
 
-    // Change the default export interval from 5 seconds to 1 second
-    let batch_config = BatchConfig::default().with_scheduled_delay(Duration::from_millis(1000));
+    let batch_config = get_span_batch_config(config);
 
     let traces_layer_result = opentelemetry_otlp::new_pipeline()
         .tracing()
@@ -281,21 +394,67 @@ fn setup_tracing_pipeline(
     }
 }
 
-fn setup_metrics_pipeline(config: &config::LogTelemetry) -> Option<BasicController> {
-    let histogram_buckets = {
-        let mut init = 0.01;
-        let mut buckets: [f64; 15] = [0.0; 15];
+/// Selects the aggregator of each instrument, histograms being aggregated into the buckets
+/// configured for them.
+#[derive(Debug)]
+struct HistogramBucketsSelector {
+    default_buckets: Vec<f64>,
+    /// Buckets by lowercase name of the histogram, as the configuration keys are lowercased
+    buckets_per_instrument: HashMap<String, Vec<f64>>,
+}
 
-        for bucket in &mut buckets {
-            init *= 2.0;
-            *bucket = init;
+impl HistogramBucketsSelector {
+    fn new(config: &config::LogTelemetry) -> Self {
+        let default_buckets = config
+            .histogram_buckets
+            .clone()
+            .map(normalize_buckets)
+            .unwrap_or_else(|| {
+                // 15 buckets doubling from 0.02
+                std::iter::successors(Some(0.02), |bucket| Some(bucket * 2.0))
+                    .take(15)
+                    .collect()
+            });
+        let buckets_per_instrument = config
+            .histogram_buckets_per_instrument
+            .iter()
+            .map(|(name, buckets)| (name.to_lowercase(), normalize_buckets(buckets.clone())))
+            .collect();
+
+        Self {
+            default_buckets,
+            buckets_per_instrument,
         }
-        buckets
-    };
+    }
+}
 
+/// Boundaries are expected in increasing order, without duplicates.
+fn normalize_buckets(mut buckets: Vec<f64>) -> Vec<f64> {
+    buckets.sort_by(f64::total_cmp);
+    buckets.dedup();
+    buckets
+}
+
+impl AggregatorSelector for HistogramBucketsSelector {
+    fn aggregator_for(&self, descriptor: &Descriptor) -> Option<Arc<dyn Aggregator + Send + Sync>> {
+        match descriptor.instrument_kind() {
+            InstrumentKind::GaugeObserver => Some(Arc::new(aggregators::last_value())),
+            InstrumentKind::Histogram => {
+                let buckets = self
+                    .buckets_per_instrument
+                    .get(&descriptor.name().to_lowercase())
+                    .unwrap_or(&self.default_buckets);
+                Some(Arc::new(aggregators::histogram(buckets)))
+            }
+            _ => Some(Arc::new(aggregators::sum())),
+        }
+    }
+}
+
+fn setup_metrics_pipeline(config: &config::LogTelemetry) -> Option<BasicController> {
     let metrics_controller_result = opentelemetry_otlp::new_pipeline()
         .metrics(
-            simple::histogram(histogram_buckets),
+            HistogramBucketsSelector::new(config),
             cumulative_temporality_selector(),
             // This would have to be updated if a different web framework is used
             runtime::TokioCurrentThread,
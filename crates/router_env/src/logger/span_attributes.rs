@@ -0,0 +1,92 @@
+//!
+//! Span attributes following the payment semantic conventions.
+//!
+//! Core flows and connector calls record the same set of attributes on their spans, so that traces
+//! can be queried uniformly in any APM backend. Spans declare the attributes as empty fields when
+//! created, as the values of undeclared fields are dropped, and record them with
+//! [`record_span_attributes!`](crate::record_span_attributes).
+//!
+
+use sha2::{Digest, Sha256};
+
+/// Hash of the merchant identifier, the merchant identifier itself is not exported to APM backends
+pub const MERCHANT_ID_HASH: &str = "hyperswitch.merchant_id_hash";
+/// Identifier of the payment
+pub const PAYMENT_ID: &str = "hyperswitch.payment_id";
+/// Name of the connector called
+pub const CONNECTOR: &str = "hyperswitch.connector";
+/// Name of the flow
+pub const FLOW: &str = "hyperswitch.flow";
+/// Status of the payment attempt
+pub const ATTEMPT_STATUS: &str = "hyperswitch.attempt_status";
+
+/// Hashes the merchant identifier recorded on spans, the first 16 bytes of its SHA-256 hex encoded
+pub fn hash_merchant_id(merchant_id: &str) -> String {
+    Sha256::digest(merchant_id.as_bytes())
+        .iter()
+        .take(16)
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// Records the payment semantic convention attributes on the current span.
+///
+/// The merchant identifier is hashed before it is recorded, and the flow and attempt status are
+/// recorded by their display representation.
+///
+/// ```
+/// router_env::record_span_attributes!(merchant_id = "merchant_1", payment_id = "pay_1");
+/// ```
+#[macro_export]
+macro_rules! record_span_attributes {
+    ($($attribute:ident = $value:expr),+ $(,)?) => {{
+        let span = $crate::tracing::Span::current();
+        $($crate::record_span_attributes!(@record span, $attribute, $value);)+
+    }};
+    (@record $span:ident, merchant_id, $value:expr) => {
+        $span.record(
+            $crate::logger::span_attributes::MERCHANT_ID_HASH,
+            $crate::logger::span_attributes::hash_merchant_id(::core::convert::AsRef::<str>::as_ref(
+                $value,
+            ))
+            .as_str(),
+        );
+    };
+    (@record $span:ident, payment_id, $value:expr) => {
+        $span.record(
+            $crate::logger::span_attributes::PAYMENT_ID,
+            ::core::convert::AsRef::<str>::as_ref($value),
+        );
+    };
+    (@record $span:ident, connector, $value:expr) => {
+        $span.record(
+            $crate::logger::span_attributes::CONNECTOR,
+            ::core::convert::AsRef::<str>::as_ref($value),
+        );
+    };
+    (@record $span:ident, flow, $value:expr) => {
+        $span.record(
+            $crate::logger::span_attributes::FLOW,
+            ::std::string::ToString::to_string($value).as_str(),
+        );
+    };
+    (@record $span:ident, attempt_status, $value:expr) => {
+        $span.record(
+            $crate::logger::span_attributes::ATTEMPT_STATUS,
+            ::std::string::ToString::to_string($value).as_str(),
+        );
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hash_merchant_id() {
+        let hash = hash_merchant_id("merchant_1");
+        assert_eq!(hash.len(), 32);
+        assert_eq!(hash, hash_merchant_id("merchant_1"));
+        assert_ne!(hash, hash_merchant_id("merchant_2"));
+    }
+}
@@ -92,8 +92,9 @@ impl Visit for Storage<'_> {
     }
 }
 
-const PERSISTENT_KEYS: [&str; 6] = [
+const PERSISTENT_KEYS: [&str; 7] = [
     "payment_id",
+    "attempt_id",
     "connector_name",
     "merchant_id",
     "flow",
@@ -69,18 +69,44 @@ pub enum Flow {
     MerchantsAccountUpdate,
     /// Merchants account delete flow.
     MerchantsAccountDelete,
+    /// Merchants account restore flow.
+    MerchantsAccountRestore,
+    /// Merchants account data export flow.
+    MerchantsAccountDataExport,
+    /// Merchants account card fingerprint key rotate flow.
+    MerchantsAccountCardFingerprintKeyRotate,
+    /// Merchants account token requestor ID create flow.
+    MerchantsAccountTokenRequestorIdCreate,
+    /// Merchants account token requestor ID list flow.
+    MerchantsAccountTokenRequestorIdList,
+    /// Merchants account token requestor ID update flow.
+    MerchantsAccountTokenRequestorIdUpdate,
+    /// Account template create flow.
+    AccountTemplateCreate,
+    /// Account template retrieve flow.
+    AccountTemplateRetrieve,
+    /// Account template delete flow.
+    AccountTemplateDelete,
+    /// Account template instantiate flow.
+    AccountTemplateInstantiate,
     /// Merchant Connectors create flow.
     MerchantConnectorsCreate,
+    /// Merchant Connectors bulk create and update flow.
+    MerchantConnectorsBulkProvision,
     /// Merchant Connectors retrieve flow.
     MerchantConnectorsRetrieve,
     /// Merchant account list
     MerchantAccountList,
     /// Merchant Connectors update flow.
     MerchantConnectorsUpdate,
+    /// Merchant Connectors webhook secret rotate flow.
+    MerchantConnectorsWebhookSecretRotate,
     /// Merchant Connectors delete flow.
     MerchantConnectorsDelete,
     /// Merchant Connectors list flow.
     MerchantConnectorsList,
+    /// Merchant Connectors health flow.
+    MerchantConnectorsHealth,
     /// ConfigKey create flow.
     ConfigKeyCreate,
     /// ConfigKey fetch flow.
@@ -103,12 +129,26 @@ pub enum Flow {
     EphemeralKeyCreate,
     /// Delete an Ephemeral Key.
     EphemeralKeyDelete,
+    /// Create a Client Session for a payment.
+    ClientSessionCreate,
     /// Mandates retrieve flow.
     MandatesRetrieve,
     /// Mandates revoke flow.
     MandatesRevoke,
     /// Mandates list flow.
     MandatesList,
+    /// Recurring payment schedule create flow.
+    RecurringPaymentScheduleCreate,
+    /// Recurring payment schedule retrieve flow.
+    RecurringPaymentScheduleRetrieve,
+    /// Recurring payment schedule cancel flow.
+    RecurringPaymentScheduleCancel,
+    /// Bulk charge create flow.
+    BulkChargeCreate,
+    /// Bulk charge retrieve flow.
+    BulkChargeRetrieve,
+    /// Bulk charge results retrieve flow.
+    BulkChargeResultsRetrieve,
     /// Payment methods create flow.
     PaymentMethodsCreate,
     /// Payment methods list flow.
@@ -117,6 +157,12 @@ pub enum Flow {
     CustomerPaymentMethodsList,
     /// List Customers for a merchant
     CustomersList,
+    /// Merge duplicate customers into a customer
+    CustomersMerge,
+    /// Schedule a scan for duplicate customers
+    CustomersMergeSuggestionsScan,
+    /// Retrieve the customers suggested to be merged
+    CustomersMergeSuggestionsRetrieve,
     /// Payment methods retrieve flow.
     PaymentMethodsRetrieve,
     /// Payment methods update flow.
@@ -194,6 +240,14 @@ pub enum Flow {
     ReconServiceRequest,
     /// Recon token verification flow
     ReconVerifyToken,
+    /// Settlement report upload flow
+    ReconSettlementReportUpload,
+    /// Settlement record retrieve flow
+    ReconSettlementRecordRetrieve,
+    /// Settlement records list flow
+    ReconSettlementRecordList,
+    /// Unreconciled items report flow
+    ReconUnreconciledItemsReport,
     /// Routing create flow,
     RoutingCreateConfig,
     /// Routing link config
@@ -214,6 +268,8 @@ pub enum Flow {
     RoutingUpdateDefaultConfig,
     /// Routing delete config
     RoutingDeleteConfig,
+    /// Routing simulate
+    RoutingSimulate,
     /// Add record to blocklist
     AddToBlocklist,
     /// Delete record from blocklist
@@ -222,6 +278,12 @@ pub enum Flow {
     ListBlocklist,
     /// Toggle blocklist for merchant
     ToggleBlocklistGuard,
+    /// Upsert the velocity rules of a merchant
+    VelocityRulesUpsert,
+    /// Retrieve the velocity rules of a merchant
+    VelocityRulesRetrieve,
+    /// Delete the velocity rules of a merchant
+    VelocityRulesDelete,
     /// Incoming Webhook Receive
     IncomingWebhookReceive,
     /// Validate payment method flow
@@ -232,6 +294,8 @@ pub enum Flow {
     ApiKeyRetrieve,
     /// API Key update flow
     ApiKeyUpdate,
+    /// API Key rotate flow
+    ApiKeyRotate,
     /// API Key revoke flow
     ApiKeyRevoke,
     /// API Key list flow
@@ -258,8 +322,20 @@ pub enum Flow {
     DeleteDisputeEvidence,
     /// Retrieve Dispute Evidence flow
     RetrieveDisputeEvidence,
+    /// Dispute representment rules update flow
+    DisputeRepresentmentRulesUpdate,
+    /// Dispute representment rules retrieve flow
+    DisputeRepresentmentRulesRetrieve,
+    /// Dispute review queue flow
+    DisputeReviewQueue,
     /// Invalidate cache flow
     CacheInvalidate,
+    /// Log filter retrieve flow
+    LogFilterRetrieve,
+    /// Log filter override flow
+    LogFilterOverride,
+    /// Log filter revert flow
+    LogFilterRevert,
     /// Payment Link Retrieve flow
     PaymentLinkRetrieve,
     /// payment Link Initiate flow
@@ -278,6 +354,8 @@ pub enum Flow {
     BusinessProfileDelete,
     /// List all the business profiles for a merchant
     BusinessProfileList,
+    /// Business Profile webhook secret rotate flow
+    BusinessProfileWebhookSecretRotate,
     /// Different verification flows
     Verification,
     /// Rust locker migration
@@ -308,6 +386,8 @@ pub enum Flow {
     DecisionManagerRetrieveConfig,
     /// Manual payment fulfillment acknowledgement
     FrmFulfillment,
+    /// List the payments held for manual review after their fraud check
+    FrmReviewQueueList,
     /// Change password flow
     ChangePassword,
     /// Signout flow
@@ -322,6 +402,8 @@ pub enum Flow {
     InternalUserSignup,
     /// Switch merchant
     SwitchMerchant,
+    /// Impersonate merchant
+    ImpersonateMerchant,
     /// Get permission info
     GetAuthorizationInfo,
     /// List roles
@@ -384,6 +466,8 @@ pub enum Flow {
     AcceptInvitation,
     /// Initiate external authentication for a payment
     PaymentsExternalAuthentication,
+    /// Record the checkout telemetry events of the SDK for a payment
+    PaymentsSdkEvents,
     /// Authorize the payment after external 3ds authentication
     PaymentsAuthorize,
     /// Create Role
@@ -394,6 +478,14 @@ pub enum Flow {
     WebhookEventInitialDeliveryAttemptList,
     /// List delivery attempts for a webhook event
     WebhookEventDeliveryAttemptList,
+    /// Manually retry the delivery of a webhook event
+    WebhookEventDeliveryRetry,
+    /// Replay the delivery of historical webhook events
+    WebhookEventReplay,
+    /// Retrieve the health of webhook endpoints
+    WebhookEndpointHealthRetrieve,
+    /// List the audit logs of a merchant
+    AuditLogList,
 }
 
 ///
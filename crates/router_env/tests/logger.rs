@@ -25,3 +25,24 @@ async fn basic() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
 
     Ok(())
 }
+
+#[tokio::test]
+async fn log_filter_override() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    logger();
+    let handle = router_env::log_filter_handle().unwrap();
+
+    assert!(matches!(
+        handle.apply_override("router_env=not_a_level", None),
+        Err(router_env::LogFilterError::InvalidDirectives(_))
+    ));
+    assert!(handle.active_override().is_none());
+
+    let active_override =
+        handle.apply_override("router_env=trace", Some(std::time::Duration::from_secs(60)))?;
+    assert_eq!(handle.active_override(), Some(active_override));
+
+    handle.revert_override()?;
+    assert!(handle.active_override().is_none());
+
+    Ok(())
+}
@@ -52,21 +52,46 @@ impl Default for PaymentMethodsPTMapping {
     }
 }
 
+/// Retry configuration where the interval between retries grows exponentially.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ExponentialBackoffMapping {
+    /// Delay in seconds before the first retry attempt.
+    pub start_after: i32,
+
+    /// Factor the delay is multiplied by for each subsequent retry attempt.
+    pub multiplier: i32,
+
+    /// Upper bound in seconds for the delay between retry attempts.
+    pub max_delay: i32,
+
+    /// Maximum number of retry attempts, after which retries are stopped.
+    pub max_retries: i32,
+}
+
+/// Retry configuration for outgoing webhooks, either with fixed intervals between retries, or with
+/// intervals growing exponentially.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(untagged)]
+pub enum OutgoingWebhookRetryMapping {
+    Fixed(RetryMapping),
+    ExponentialBackoff(ExponentialBackoffMapping),
+}
+
 /// Configuration for outgoing webhook retries.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct OutgoingWebhookRetryProcessTrackerMapping {
     /// Default (fallback) retry configuration used when no merchant-specific retry configuration
     /// exists.
-    pub default_mapping: RetryMapping,
+    pub default_mapping: OutgoingWebhookRetryMapping,
 
     /// Merchant-specific retry configuration.
-    pub custom_merchant_mapping: HashMap<String, RetryMapping>,
+    pub custom_merchant_mapping: HashMap<String, OutgoingWebhookRetryMapping>,
 }
 
 impl Default for OutgoingWebhookRetryProcessTrackerMapping {
     fn default() -> Self {
         Self {
-            default_mapping: RetryMapping {
+            default_mapping: OutgoingWebhookRetryMapping::Fixed(RetryMapping {
                 // 1st attempt happens after 1 minute
                 start_after: 60,
 
@@ -86,7 +111,7 @@ impl Default for OutgoingWebhookRetryProcessTrackerMapping {
                     5, // 9th, 10th, 11th, 12th and 13th attempts
                     3, // 14th, 15th and 16th attempts
                 ],
-            },
+            }),
             custom_merchant_mapping: HashMap::new(),
         }
     }
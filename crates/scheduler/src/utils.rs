@@ -352,20 +352,47 @@ pub fn get_outgoing_webhook_retry_schedule_time(
         None => mapping.default_mapping,
     };
 
-    // For first try, get the `start_after` time
-    if retry_count == 0 {
-        Some(retry_mapping.start_after)
-    } else {
-        get_delay(
-            retry_count,
-            retry_mapping
-                .count
-                .iter()
-                .zip(retry_mapping.frequency.iter()),
-        )
+    match retry_mapping {
+        process_data::OutgoingWebhookRetryMapping::Fixed(retry_mapping) => {
+            // For first try, get the `start_after` time
+            if retry_count == 0 {
+                Some(retry_mapping.start_after)
+            } else {
+                get_delay(
+                    retry_count,
+                    retry_mapping
+                        .count
+                        .iter()
+                        .zip(retry_mapping.frequency.iter()),
+                )
+            }
+        }
+        process_data::OutgoingWebhookRetryMapping::ExponentialBackoff(retry_mapping) => {
+            get_exponential_backoff_delay(retry_count, &retry_mapping)
+        }
     }
 }
 
+/// Get the delay based on the retry count, growing exponentially from the `start_after` time up
+/// to the `max_delay`
+fn get_exponential_backoff_delay(
+    retry_count: i32,
+    retry_mapping: &process_data::ExponentialBackoffMapping,
+) -> Option<i32> {
+    if retry_count >= retry_mapping.max_retries {
+        return None;
+    }
+
+    let exponent = u32::try_from(retry_count).ok()?;
+    let delay = retry_mapping
+        .multiplier
+        .checked_pow(exponent)
+        .and_then(|factor| retry_mapping.start_after.checked_mul(factor))
+        .unwrap_or(retry_mapping.max_delay);
+
+    Some(delay.min(retry_mapping.max_delay))
+}
+
 /// Get the delay based on the retry count
 fn get_delay<'a>(retry_count: i32, array: impl Iterator<Item = (&'a i32, &'a i32)>) -> Option<i32> {
     // Preferably, fix this by using unsigned ints
@@ -450,4 +477,34 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_get_exponential_backoff_delay() {
+        let retry_mapping = process_data::ExponentialBackoffMapping {
+            start_after: 60,
+            multiplier: 2,
+            max_delay: 3600,
+            max_retries: 40,
+        };
+
+        let retry_counts_and_expected_delays = [
+            (-1, None),
+            (0, Some(60)),
+            (1, Some(120)),
+            (2, Some(240)),
+            (5, Some(1920)),
+            (6, Some(3600)),
+            (39, Some(3600)),
+            (40, None),
+        ];
+
+        for (retry_count, expected_delay) in retry_counts_and_expected_delays {
+            let delay = get_exponential_backoff_delay(retry_count, &retry_mapping);
+
+            assert_eq!(
+                delay, expected_delay,
+                "Delay and expected delay differ for `retry_count` = {retry_count}"
+            );
+        }
+    }
 }
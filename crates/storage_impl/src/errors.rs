@@ -268,6 +268,8 @@ pub enum ApiClientError {
 
     #[error("Server responded with Request Timeout")]
     RequestTimeoutReceived,
+    #[error("Deadline of the request exceeded before the request could be sent")]
+    RequestDeadlineExceeded,
 
     #[error("connection closed before a message could complete")]
     ConnectionClosedIncompleteMessage,
@@ -36,6 +36,7 @@ pub struct MockDb {
     pub redis: Arc<RedisStore>,
     pub api_keys: Arc<Mutex<Vec<store::ApiKey>>>,
     pub ephemeral_keys: Arc<Mutex<Vec<store::EphemeralKey>>>,
+    pub client_sessions: Arc<Mutex<Vec<store::client_session::ClientSession>>>,
     pub cards_info: Arc<Mutex<Vec<store::CardInfo>>>,
     pub events: Arc<Mutex<Vec<store::Event>>>,
     pub disputes: Arc<Mutex<Vec<store::Dispute>>>,
@@ -57,6 +58,12 @@ pub struct MockDb {
     pub payouts: Arc<Mutex<Vec<store::payouts::Payouts>>>,
     pub authentications: Arc<Mutex<Vec<store::authentication::Authentication>>>,
     pub roles: Arc<Mutex<Vec<store::role::Role>>>,
+    pub fx_rates: Arc<Mutex<Vec<store::fx_rate::FxRate>>>,
+    pub settlement_records: Arc<Mutex<Vec<store::settlement_record::SettlementRecord>>>,
+    pub report_schedules: Arc<Mutex<Vec<store::report_schedule::ReportSchedule>>>,
+    pub report_runs: Arc<Mutex<Vec<store::report_run::ReportRun>>>,
+    pub success_rate_alerts: Arc<Mutex<Vec<store::success_rate_alert::SuccessRateAlert>>>,
+    pub audit_logs: Arc<Mutex<Vec<store::audit_log::AuditLog>>>,
 }
 
 impl MockDb {
@@ -79,6 +86,7 @@ impl MockDb {
             ),
             api_keys: Default::default(),
             ephemeral_keys: Default::default(),
+            client_sessions: Default::default(),
             cards_info: Default::default(),
             events: Default::default(),
             disputes: Default::default(),
@@ -100,6 +108,12 @@ impl MockDb {
             payouts: Default::default(),
             authentications: Default::default(),
             roles: Default::default(),
+            fx_rates: Default::default(),
+            settlement_records: Default::default(),
+            report_schedules: Default::default(),
+            report_runs: Default::default(),
+            success_rate_alerts: Default::default(),
+            audit_logs: Default::default(),
         })
     }
 }
@@ -154,6 +154,20 @@ impl PaymentAttemptInterface for MockDb {
             mandate_data: payment_attempt.mandate_data,
             payment_method_billing_address_id: payment_attempt.payment_method_billing_address_id,
             fingerprint_id: payment_attempt.fingerprint_id,
+            card_network: payment_attempt.card_network,
+            card_type: payment_attempt.card_type,
+            card_issuing_country: payment_attempt.card_issuing_country,
+            three_ds_decision: payment_attempt.three_ds_decision,
+            sca_exemption: payment_attempt.sca_exemption,
+            card_fingerprint: payment_attempt.card_fingerprint,
+            payment_method_selection: payment_attempt.payment_method_selection,
+            settlement_currency: payment_attempt.settlement_currency,
+            settlement_amount: payment_attempt.settlement_amount,
+            fx_rate: payment_attempt.fx_rate,
+            avs_result_code: payment_attempt.avs_result_code,
+            avs_result: payment_attempt.avs_result,
+            cvv_result_code: payment_attempt.cvv_result_code,
+            cvv_result: payment_attempt.cvv_result,
         };
         payment_attempts.push(payment_attempt.clone());
         Ok(payment_attempt)
@@ -157,6 +157,21 @@ impl PaymentIntentInterface for MockDb {
             .unwrap())
     }
 
+    async fn find_payment_intent_with_active_attempt_by_payment_id_merchant_id(
+        &self,
+        payment_id: &str,
+        merchant_id: &str,
+        storage_scheme: storage_enums::MerchantStorageScheme,
+    ) -> error_stack::Result<(PaymentIntent, PaymentAttempt), StorageError> {
+        let mut payment_intent = self
+            .find_payment_intent_by_payment_id_merchant_id(payment_id, merchant_id, storage_scheme)
+            .await?;
+        let payment_attempt = self
+            .get_active_payment_attempt(&mut payment_intent, storage_scheme)
+            .await?;
+        Ok((payment_intent, payment_attempt))
+    }
+
     async fn get_active_payment_attempt(
         &self,
         payment: &mut PaymentIntent,
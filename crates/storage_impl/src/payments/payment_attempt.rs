@@ -407,6 +407,20 @@ impl<T: DatabaseStore> PaymentAttemptInterface for KVRouterStore<T> {
                         .payment_method_billing_address_id
                         .clone(),
                     fingerprint_id: payment_attempt.fingerprint_id.clone(),
+                    card_network: payment_attempt.card_network.clone(),
+                    card_type: payment_attempt.card_type.clone(),
+                    card_issuing_country: payment_attempt.card_issuing_country.clone(),
+                    three_ds_decision: payment_attempt.three_ds_decision,
+                    sca_exemption: payment_attempt.sca_exemption,
+                    card_fingerprint: payment_attempt.card_fingerprint.clone(),
+                    payment_method_selection: payment_attempt.payment_method_selection,
+                    settlement_currency: payment_attempt.settlement_currency,
+                    settlement_amount: payment_attempt.settlement_amount,
+                    fx_rate: payment_attempt.fx_rate.clone(),
+                    avs_result_code: payment_attempt.avs_result_code.clone(),
+                    avs_result: payment_attempt.avs_result,
+                    cvv_result_code: payment_attempt.cvv_result_code.clone(),
+                    cvv_result: payment_attempt.cvv_result,
                 };
 
                 let field = format!("pa_{}", created_attempt.attempt_id);
@@ -1023,6 +1037,9 @@ impl DataModelExt for MandateAmountData {
             start_date: self.start_date,
             end_date: self.end_date,
             metadata: self.metadata,
+            max_amount_per_charge: self.max_amount_per_charge,
+            max_amount_per_period: self.max_amount_per_period,
+            limit_period: self.limit_period,
         }
     }
 
@@ -1033,6 +1050,9 @@ impl DataModelExt for MandateAmountData {
             start_date: storage_model.start_date,
             end_date: storage_model.end_date,
             metadata: storage_model.metadata,
+            max_amount_per_charge: storage_model.max_amount_per_charge,
+            max_amount_per_period: storage_model.max_amount_per_period,
+            limit_period: storage_model.limit_period,
         }
     }
 }
@@ -1136,6 +1156,20 @@ impl DataModelExt for PaymentAttempt {
             mandate_data: self.mandate_data.map(|d| d.to_storage_model()),
             payment_method_billing_address_id: self.payment_method_billing_address_id,
             fingerprint_id: self.fingerprint_id,
+            card_network: self.card_network,
+            card_type: self.card_type,
+            card_issuing_country: self.card_issuing_country,
+            three_ds_decision: self.three_ds_decision,
+            sca_exemption: self.sca_exemption,
+            card_fingerprint: self.card_fingerprint,
+            payment_method_selection: self.payment_method_selection,
+            settlement_currency: self.settlement_currency,
+            settlement_amount: self.settlement_amount,
+            fx_rate: self.fx_rate,
+            avs_result_code: self.avs_result_code,
+            avs_result: self.avs_result,
+            cvv_result_code: self.cvv_result_code,
+            cvv_result: self.cvv_result,
         }
     }
 
@@ -1200,6 +1234,20 @@ impl DataModelExt for PaymentAttempt {
                 .map(MandateDetails::from_storage_model),
             payment_method_billing_address_id: storage_model.payment_method_billing_address_id,
             fingerprint_id: storage_model.fingerprint_id,
+            card_network: storage_model.card_network,
+            card_type: storage_model.card_type,
+            card_issuing_country: storage_model.card_issuing_country,
+            three_ds_decision: storage_model.three_ds_decision,
+            sca_exemption: storage_model.sca_exemption,
+            card_fingerprint: storage_model.card_fingerprint,
+            payment_method_selection: storage_model.payment_method_selection,
+            settlement_currency: storage_model.settlement_currency,
+            settlement_amount: storage_model.settlement_amount,
+            fx_rate: storage_model.fx_rate,
+            avs_result_code: storage_model.avs_result_code,
+            avs_result: storage_model.avs_result,
+            cvv_result_code: storage_model.cvv_result_code,
+            cvv_result: storage_model.cvv_result,
         }
     }
 }
@@ -1262,6 +1310,20 @@ impl DataModelExt for PaymentAttemptNew {
             mandate_data: self.mandate_data.map(|d| d.to_storage_model()),
             payment_method_billing_address_id: self.payment_method_billing_address_id,
             fingerprint_id: self.fingerprint_id,
+            card_network: self.card_network,
+            card_type: self.card_type,
+            card_issuing_country: self.card_issuing_country,
+            three_ds_decision: self.three_ds_decision,
+            sca_exemption: self.sca_exemption,
+            card_fingerprint: self.card_fingerprint,
+            payment_method_selection: self.payment_method_selection,
+            settlement_currency: self.settlement_currency,
+            settlement_amount: self.settlement_amount,
+            fx_rate: self.fx_rate,
+            avs_result_code: self.avs_result_code,
+            avs_result: self.avs_result,
+            cvv_result_code: self.cvv_result_code,
+            cvv_result: self.cvv_result,
         }
     }
 
@@ -1324,6 +1386,20 @@ impl DataModelExt for PaymentAttemptNew {
                 .map(MandateDetails::from_storage_model),
             payment_method_billing_address_id: storage_model.payment_method_billing_address_id,
             fingerprint_id: storage_model.fingerprint_id,
+            card_network: storage_model.card_network,
+            card_type: storage_model.card_type,
+            card_issuing_country: storage_model.card_issuing_country,
+            three_ds_decision: storage_model.three_ds_decision,
+            sca_exemption: storage_model.sca_exemption,
+            card_fingerprint: storage_model.card_fingerprint,
+            payment_method_selection: storage_model.payment_method_selection,
+            settlement_currency: storage_model.settlement_currency,
+            settlement_amount: storage_model.settlement_amount,
+            fx_rate: storage_model.fx_rate,
+            avs_result_code: storage_model.avs_result_code,
+            avs_result: storage_model.avs_result,
+            cvv_result_code: storage_model.cvv_result_code,
+            cvv_result: storage_model.cvv_result,
         }
     }
 }
@@ -1349,6 +1425,9 @@ impl DataModelExt for PaymentAttemptUpdate {
                 surcharge_amount,
                 tax_amount,
                 fingerprint_id,
+                card_network,
+                card_type,
+                card_issuing_country,
                 updated_by,
             } => DieselPaymentAttemptUpdate::Update {
                 amount,
@@ -1366,6 +1445,9 @@ impl DataModelExt for PaymentAttemptUpdate {
                 surcharge_amount,
                 tax_amount,
                 fingerprint_id,
+                card_network,
+                card_type,
+                card_issuing_country,
                 updated_by,
             },
             Self::UpdateTrackers {
@@ -1377,6 +1459,9 @@ impl DataModelExt for PaymentAttemptUpdate {
                 surcharge_amount,
                 tax_amount,
                 merchant_connector_id,
+                three_ds_decision,
+                sca_exemption,
+                card_fingerprint,
             } => DieselPaymentAttemptUpdate::UpdateTrackers {
                 payment_token,
                 connector,
@@ -1386,6 +1471,9 @@ impl DataModelExt for PaymentAttemptUpdate {
                 tax_amount,
                 updated_by,
                 merchant_connector_id,
+                three_ds_decision,
+                sca_exemption,
+                card_fingerprint,
             },
             Self::AuthenticationTypeUpdate {
                 authentication_type,
@@ -1425,6 +1513,13 @@ impl DataModelExt for PaymentAttemptUpdate {
                 surcharge_amount,
                 tax_amount,
                 fingerprint_id,
+                card_network,
+                card_type,
+                card_issuing_country,
+                three_ds_decision,
+                sca_exemption,
+                card_fingerprint,
+                payment_method_selection,
                 updated_by,
                 merchant_connector_id: connector_id,
                 payment_method_id,
@@ -1452,6 +1547,13 @@ impl DataModelExt for PaymentAttemptUpdate {
                 surcharge_amount,
                 tax_amount,
                 fingerprint_id,
+                card_network,
+                card_type,
+                card_issuing_country,
+                three_ds_decision,
+                sca_exemption,
+                card_fingerprint,
+                payment_method_selection,
                 updated_by,
                 merchant_connector_id: connector_id,
                 payment_method_id,
@@ -1489,6 +1591,13 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_code,
                 unified_message,
                 payment_method_data,
+                settlement_currency,
+                settlement_amount,
+                fx_rate,
+                avs_result_code,
+                avs_result,
+                cvv_result_code,
+                cvv_result,
             } => DieselPaymentAttemptUpdate::ResponseUpdate {
                 status,
                 connector,
@@ -1509,6 +1618,13 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_code,
                 unified_message,
                 payment_method_data,
+                settlement_currency,
+                settlement_amount,
+                fx_rate,
+                avs_result_code,
+                avs_result,
+                cvv_result_code,
+                cvv_result,
             },
             Self::UnresolvedResponseUpdate {
                 status,
@@ -1659,6 +1775,9 @@ impl DataModelExt for PaymentAttemptUpdate {
                 surcharge_amount,
                 tax_amount,
                 fingerprint_id,
+                card_network,
+                card_type,
+                card_issuing_country,
                 updated_by,
             } => Self::Update {
                 amount,
@@ -1676,6 +1795,9 @@ impl DataModelExt for PaymentAttemptUpdate {
                 surcharge_amount,
                 tax_amount,
                 fingerprint_id,
+                card_network,
+                card_type,
+                card_issuing_country,
                 updated_by,
             },
             DieselPaymentAttemptUpdate::UpdateTrackers {
@@ -1687,6 +1809,9 @@ impl DataModelExt for PaymentAttemptUpdate {
                 surcharge_amount,
                 tax_amount,
                 merchant_connector_id: connector_id,
+                three_ds_decision,
+                sca_exemption,
+                card_fingerprint,
             } => Self::UpdateTrackers {
                 payment_token,
                 connector,
@@ -1696,6 +1821,9 @@ impl DataModelExt for PaymentAttemptUpdate {
                 tax_amount,
                 updated_by,
                 merchant_connector_id: connector_id,
+                three_ds_decision,
+                sca_exemption,
+                card_fingerprint,
             },
             DieselPaymentAttemptUpdate::AuthenticationTypeUpdate {
                 authentication_type,
@@ -1724,6 +1852,13 @@ impl DataModelExt for PaymentAttemptUpdate {
                 surcharge_amount,
                 tax_amount,
                 fingerprint_id,
+                card_network,
+                card_type,
+                card_issuing_country,
+                three_ds_decision,
+                sca_exemption,
+                card_fingerprint,
+                payment_method_selection,
                 updated_by,
                 merchant_connector_id: connector_id,
                 payment_method_id,
@@ -1751,6 +1886,13 @@ impl DataModelExt for PaymentAttemptUpdate {
                 surcharge_amount,
                 tax_amount,
                 fingerprint_id,
+                card_network,
+                card_type,
+                card_issuing_country,
+                three_ds_decision,
+                sca_exemption,
+                card_fingerprint,
+                payment_method_selection,
                 updated_by,
                 merchant_connector_id: connector_id,
                 payment_method_id,
@@ -1799,6 +1941,13 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_code,
                 unified_message,
                 payment_method_data,
+                settlement_currency,
+                settlement_amount,
+                fx_rate,
+                avs_result_code,
+                avs_result,
+                cvv_result_code,
+                cvv_result,
             } => Self::ResponseUpdate {
                 status,
                 connector,
@@ -1819,6 +1968,13 @@ impl DataModelExt for PaymentAttemptUpdate {
                 unified_code,
                 unified_message,
                 payment_method_data,
+                settlement_currency,
+                settlement_amount,
+                fx_rate,
+                avs_result_code,
+                avs_result,
+                cvv_result_code,
+                cvv_result,
             },
             DieselPaymentAttemptUpdate::UnresolvedResponseUpdate {
                 status,
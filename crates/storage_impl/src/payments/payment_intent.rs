@@ -6,7 +6,7 @@ use data_models::payments::payment_intent::PaymentIntentFetchConstraints;
 use data_models::{
     errors::StorageError,
     payments::{
-        payment_attempt::PaymentAttempt,
+        payment_attempt::{PaymentAttempt, PaymentAttemptInterface},
         payment_intent::{PaymentIntentInterface, PaymentIntentNew, PaymentIntentUpdate},
         PaymentIntent,
     },
@@ -232,6 +232,46 @@ impl<T: DatabaseStore> PaymentIntentInterface for KVRouterStore<T> {
         .map(PaymentIntent::from_storage_model)
     }
 
+    #[instrument(skip_all)]
+    async fn find_payment_intent_with_active_attempt_by_payment_id_merchant_id(
+        &self,
+        payment_id: &str,
+        merchant_id: &str,
+        storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<(PaymentIntent, PaymentAttempt), StorageError> {
+        match storage_scheme {
+            MerchantStorageScheme::PostgresOnly => {
+                self.router_store
+                    .find_payment_intent_with_active_attempt_by_payment_id_merchant_id(
+                        payment_id,
+                        merchant_id,
+                        storage_scheme,
+                    )
+                    .await
+            }
+            // The intent and its attempts may not have been drained to the database yet, they are
+            // read from the KV store one after the other
+            MerchantStorageScheme::RedisKv => {
+                let payment_intent = self
+                    .find_payment_intent_by_payment_id_merchant_id(
+                        payment_id,
+                        merchant_id,
+                        storage_scheme,
+                    )
+                    .await?;
+                let payment_attempt = self
+                    .find_payment_attempt_by_payment_id_merchant_id_attempt_id(
+                        payment_id,
+                        merchant_id,
+                        payment_intent.active_attempt.get_id().as_str(),
+                        storage_scheme,
+                    )
+                    .await?;
+                Ok((payment_intent, payment_attempt))
+            }
+        }
+    }
+
     async fn get_active_payment_attempt(
         &self,
         payment: &mut PaymentIntent,
@@ -370,6 +410,32 @@ impl<T: DatabaseStore> PaymentIntentInterface for crate::RouterStore<T> {
             })
     }
 
+    #[instrument(skip_all)]
+    async fn find_payment_intent_with_active_attempt_by_payment_id_merchant_id(
+        &self,
+        payment_id: &str,
+        merchant_id: &str,
+        _storage_scheme: MerchantStorageScheme,
+    ) -> error_stack::Result<(PaymentIntent, PaymentAttempt), StorageError> {
+        let conn = pg_connection_read(self).await?;
+        DieselPaymentIntent::find_with_active_attempt_by_payment_id_merchant_id(
+            &conn,
+            payment_id,
+            merchant_id,
+        )
+        .await
+        .map(|(payment_intent, payment_attempt)| {
+            (
+                PaymentIntent::from_storage_model(payment_intent),
+                PaymentAttempt::from_storage_model(payment_attempt),
+            )
+        })
+        .map_err(|er| {
+            let new_err = diesel_error_to_data_error(er.current_context());
+            er.change_context(new_err)
+        })
+    }
+
     #[instrument(skip_all)]
     async fn get_active_payment_attempt(
         &self,
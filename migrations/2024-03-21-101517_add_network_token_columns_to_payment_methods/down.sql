@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_methods
+DROP COLUMN IF EXISTS network_token_reference_id,
+DROP COLUMN IF EXISTS network_token_locker_id;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_methods
+ADD COLUMN IF NOT EXISTS network_token_reference_id VARCHAR(128) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS network_token_locker_id VARCHAR(64) DEFAULT NULL;
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt
+DROP COLUMN IF EXISTS card_network,
+DROP COLUMN IF EXISTS card_type,
+DROP COLUMN IF EXISTS card_issuing_country;
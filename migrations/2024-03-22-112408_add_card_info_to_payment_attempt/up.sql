@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS card_network VARCHAR(32) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS card_type VARCHAR(64) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS card_issuing_country VARCHAR(64) DEFAULT NULL;
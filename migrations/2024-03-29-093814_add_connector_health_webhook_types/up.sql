@@ -0,0 +1,8 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'connectors';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'connector_health_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'connector_degraded';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'connector_recovered';
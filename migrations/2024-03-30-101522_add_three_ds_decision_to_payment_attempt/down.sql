@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt
+DROP COLUMN IF EXISTS three_ds_decision,
+DROP COLUMN IF EXISTS sca_exemption;
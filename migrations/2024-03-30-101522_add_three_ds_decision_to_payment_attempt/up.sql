@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS three_ds_decision VARCHAR(32) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS sca_exemption VARCHAR(32) DEFAULT NULL;
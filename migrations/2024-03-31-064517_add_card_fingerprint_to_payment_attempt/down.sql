@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX IF EXISTS payment_attempt_merchant_id_card_fingerprint_index;
+
+ALTER TABLE payment_attempt
+DROP COLUMN IF EXISTS card_fingerprint;
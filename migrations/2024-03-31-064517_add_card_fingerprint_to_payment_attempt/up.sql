@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS card_fingerprint VARCHAR(64) DEFAULT NULL;
+
+CREATE INDEX IF NOT EXISTS payment_attempt_merchant_id_card_fingerprint_index ON payment_attempt (merchant_id, card_fingerprint);
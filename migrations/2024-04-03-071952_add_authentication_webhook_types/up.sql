@@ -0,0 +1,12 @@
+-- Your SQL goes here
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'authentications';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'authentication_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'authentication_started';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'challenge_required';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'authentication_succeeded';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'authentication_failed';
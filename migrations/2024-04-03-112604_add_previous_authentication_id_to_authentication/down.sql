@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE authentication
+DROP COLUMN IF EXISTS previous_authentication_id;
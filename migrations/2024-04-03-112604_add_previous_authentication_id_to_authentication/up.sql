@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE authentication
+ADD COLUMN IF NOT EXISTS previous_authentication_id VARCHAR(64);
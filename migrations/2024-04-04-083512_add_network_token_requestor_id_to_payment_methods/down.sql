@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_methods
+DROP COLUMN IF EXISTS network_token_requestor_id;
@@ -0,0 +1,6 @@
+-- Your SQL goes here
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_review_required';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_review_approved';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'payment_review_rejected';
@@ -0,0 +1,5 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt
+DROP COLUMN IF EXISTS settlement_currency,
+DROP COLUMN IF EXISTS settlement_amount,
+DROP COLUMN IF EXISTS fx_rate;
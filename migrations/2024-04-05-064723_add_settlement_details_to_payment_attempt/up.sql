@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS settlement_currency "Currency" DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS settlement_amount BIGINT DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS fx_rate VARCHAR(32) DEFAULT NULL;
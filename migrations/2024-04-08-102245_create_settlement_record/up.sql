@@ -0,0 +1,25 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS settlement_record (
+    record_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    report_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64) NOT NULL,
+    record_type VARCHAR(16) NOT NULL,
+    connector_transaction_id VARCHAR(128) NOT NULL,
+    currency "Currency" NOT NULL,
+    gross_amount BIGINT NOT NULL,
+    fee_amount BIGINT NOT NULL DEFAULT 0,
+    net_amount BIGINT NOT NULL,
+    settled_at TIMESTAMP,
+    payment_id VARCHAR(64),
+    attempt_id VARCHAR(64),
+    refund_id VARCHAR(64),
+    expected_amount BIGINT,
+    match_status VARCHAR(32) NOT NULL,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS settlement_record_merchant_id_connector_transaction_index ON settlement_record (merchant_id, connector, record_type, connector_transaction_id);
+
+CREATE INDEX IF NOT EXISTS settlement_record_merchant_id_match_status_index ON settlement_record (merchant_id, match_status);
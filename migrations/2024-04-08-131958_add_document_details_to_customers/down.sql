@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE customers
+DROP COLUMN IF EXISTS document_type,
+DROP COLUMN IF EXISTS document_number;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE customers
+ADD COLUMN IF NOT EXISTS document_type VARCHAR(16) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS document_number BYTEA DEFAULT NULL;
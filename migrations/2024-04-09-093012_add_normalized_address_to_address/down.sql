@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE address
+DROP COLUMN IF EXISTS normalized_address,
+DROP COLUMN IF EXISTS address_validation_status;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE address
+ADD COLUMN IF NOT EXISTS normalized_address BYTEA DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS address_validation_status VARCHAR(32) DEFAULT NULL;
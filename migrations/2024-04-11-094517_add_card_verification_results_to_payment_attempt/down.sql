@@ -0,0 +1,9 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt
+DROP COLUMN IF EXISTS avs_result_code,
+DROP COLUMN IF EXISTS avs_result,
+DROP COLUMN IF EXISTS cvv_result_code,
+DROP COLUMN IF EXISTS cvv_result;
+
+ALTER TABLE business_profile
+DROP COLUMN IF EXISTS card_verification_policy;
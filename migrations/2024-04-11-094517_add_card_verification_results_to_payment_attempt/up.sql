@@ -0,0 +1,9 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ADD COLUMN IF NOT EXISTS avs_result_code VARCHAR(8) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS avs_result VARCHAR(32) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS cvv_result_code VARCHAR(8) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS cvv_result VARCHAR(32) DEFAULT NULL;
+
+ALTER TABLE business_profile
+ADD COLUMN IF NOT EXISTS card_verification_policy JSONB DEFAULT NULL;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TYPE "RefundType" ADD VALUE IF NOT EXISTS 'payout_refund';
+
+ALTER TABLE refund ADD COLUMN IF NOT EXISTS payout_id VARCHAR(64) DEFAULT NULL;
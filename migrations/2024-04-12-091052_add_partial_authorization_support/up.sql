@@ -0,0 +1,5 @@
+-- Your SQL goes here
+ALTER TYPE "AttemptStatus" ADD VALUE IF NOT EXISTS 'partially_authorized';
+
+ALTER TABLE business_profile
+ADD COLUMN IF NOT EXISTS partial_authorization_action VARCHAR(32) DEFAULT NULL;
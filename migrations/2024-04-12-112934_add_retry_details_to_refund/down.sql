@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE refund
+DROP COLUMN IF EXISTS retry_count,
+DROP COLUMN IF EXISTS retry_status;
@@ -0,0 +1,4 @@
+-- Your SQL goes here
+ALTER TABLE refund
+ADD COLUMN IF NOT EXISTS retry_count SMALLINT DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS retry_status VARCHAR(32) DEFAULT NULL;
@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE mandate
+DROP COLUMN IF EXISTS max_amount_per_charge,
+DROP COLUMN IF EXISTS max_amount_per_period,
+DROP COLUMN IF EXISTS limit_period,
+DROP COLUMN IF EXISTS period_amount_captured,
+DROP COLUMN IF EXISTS period_start;
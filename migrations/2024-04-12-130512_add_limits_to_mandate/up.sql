@@ -0,0 +1,9 @@
+-- Your SQL goes here
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'mandate_limit_exceeded';
+
+ALTER TABLE mandate
+ADD COLUMN IF NOT EXISTS max_amount_per_charge BIGINT DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS max_amount_per_period BIGINT DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS limit_period VARCHAR(32) DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS period_amount_captured BIGINT DEFAULT NULL,
+ADD COLUMN IF NOT EXISTS period_start TIMESTAMP DEFAULT NULL;
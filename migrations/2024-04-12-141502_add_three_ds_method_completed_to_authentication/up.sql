@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE authentication
+ADD COLUMN IF NOT EXISTS three_ds_method_completed BOOLEAN DEFAULT NULL;
@@ -0,0 +1,4 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE IF EXISTS report_run;
+
+DROP TABLE IF EXISTS report_schedule;
@@ -0,0 +1,34 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS report_schedule (
+    schedule_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    report_type VARCHAR(16) NOT NULL,
+    frequency VARCHAR(16) NOT NULL,
+    delivery_method VARCHAR(16) NOT NULL,
+    delivery_config BYTEA NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    next_run_at TIMESTAMP NOT NULL,
+    last_run_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS report_schedule_merchant_id_index ON report_schedule (merchant_id);
+
+CREATE TABLE IF NOT EXISTS report_run (
+    run_id VARCHAR(64) PRIMARY KEY,
+    schedule_id VARCHAR(64) NOT NULL,
+    merchant_id VARCHAR(64) NOT NULL,
+    report_type VARCHAR(16) NOT NULL,
+    period_start TIMESTAMP NOT NULL,
+    period_end TIMESTAMP NOT NULL,
+    status VARCHAR(16) NOT NULL,
+    file_id VARCHAR(64),
+    row_count INTEGER,
+    error_message TEXT,
+    delivered_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS report_run_merchant_id_schedule_id_index ON report_run (merchant_id, schedule_id);
@@ -0,0 +1,24 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS success_rate_alert (
+    alert_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    connector VARCHAR(64),
+    evaluation_window_in_mins INTEGER NOT NULL,
+    baseline_window_in_mins INTEGER NOT NULL,
+    drop_threshold DOUBLE PRECISION NOT NULL,
+    min_payment_count INTEGER NOT NULL,
+    email_recipients BYTEA,
+    is_active BOOLEAN NOT NULL DEFAULT TRUE,
+    triggered_drops JSONB NOT NULL DEFAULT '[]'::JSONB,
+    last_evaluated_at TIMESTAMP,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP,
+    modified_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS success_rate_alert_merchant_id_index ON success_rate_alert (merchant_id);
+
+ALTER TYPE "EventClass" ADD VALUE IF NOT EXISTS 'alerts';
+
+ALTER TYPE "EventObjectType" ADD VALUE IF NOT EXISTS 'success_rate_drop_details';
+
+ALTER TYPE "EventType" ADD VALUE IF NOT EXISTS 'success_rate_dropped';
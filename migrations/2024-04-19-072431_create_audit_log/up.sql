@@ -0,0 +1,14 @@
+-- Your SQL goes here
+CREATE TABLE IF NOT EXISTS audit_log (
+    audit_id VARCHAR(64) PRIMARY KEY,
+    merchant_id VARCHAR(64) NOT NULL,
+    operation VARCHAR(64) NOT NULL,
+    entity_id VARCHAR(64) NOT NULL,
+    actor_type VARCHAR(32) NOT NULL,
+    actor_id VARCHAR(64),
+    request_id VARCHAR(64),
+    changes JSONB NOT NULL DEFAULT '{}'::JSONB,
+    created_at TIMESTAMP NOT NULL DEFAULT now()::TIMESTAMP
+);
+
+CREATE INDEX IF NOT EXISTS audit_log_merchant_id_created_at_index ON audit_log (merchant_id, created_at);
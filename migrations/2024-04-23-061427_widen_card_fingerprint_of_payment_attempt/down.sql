@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE payment_attempt
+ALTER COLUMN card_fingerprint TYPE VARCHAR(64);
@@ -0,0 +1,3 @@
+-- Your SQL goes here
+ALTER TABLE payment_attempt
+ALTER COLUMN card_fingerprint TYPE VARCHAR(128);